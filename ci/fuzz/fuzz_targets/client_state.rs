@@ -0,0 +1,39 @@
+//! Decodes the client states, consensus states, headers and misbehaviours
+//! carried by the client messages, and asserts their round-trip through
+//! their encoding.
+#![no_main]
+
+use ibc::clients::tendermint::types::{
+    ClientState as TmClientState, ConsensusState as TmConsensusState, Header as TmHeader,
+    Misbehaviour as TmMisbehaviour, TENDERMINT_CLIENT_STATE_TYPE_URL,
+    TENDERMINT_CONSENSUS_STATE_TYPE_URL, TENDERMINT_HEADER_TYPE_URL,
+    TENDERMINT_MISBEHAVIOUR_TYPE_URL,
+};
+use ibc_fuzz::{assert_any_round_trip, AnyInput};
+use ibc_testkit::testapp::ibc::clients::mock::client_state::MOCK_CLIENT_STATE_TYPE_URL;
+use ibc_testkit::testapp::ibc::clients::mock::consensus_state::MOCK_CONSENSUS_STATE_TYPE_URL;
+use ibc_testkit::testapp::ibc::clients::mock::header::{MockHeader, MOCK_HEADER_TYPE_URL};
+use ibc_testkit::testapp::ibc::clients::{AnyClientState, AnyConsensusState};
+use libfuzzer_sys::fuzz_target;
+
+const TYPE_URLS: &[&str] = &[
+    TENDERMINT_CLIENT_STATE_TYPE_URL,
+    TENDERMINT_CONSENSUS_STATE_TYPE_URL,
+    TENDERMINT_HEADER_TYPE_URL,
+    TENDERMINT_MISBEHAVIOUR_TYPE_URL,
+    MOCK_CLIENT_STATE_TYPE_URL,
+    MOCK_CONSENSUS_STATE_TYPE_URL,
+    MOCK_HEADER_TYPE_URL,
+];
+
+fuzz_target!(|input: AnyInput| {
+    let any = input.into_any(TYPE_URLS);
+
+    assert_any_round_trip::<TmClientState>(&any);
+    assert_any_round_trip::<TmConsensusState>(&any);
+    assert_any_round_trip::<TmHeader>(&any);
+    assert_any_round_trip::<TmMisbehaviour>(&any);
+    assert_any_round_trip::<MockHeader>(&any);
+    assert_any_round_trip::<AnyClientState>(&any);
+    assert_any_round_trip::<AnyConsensusState>(&any);
+});
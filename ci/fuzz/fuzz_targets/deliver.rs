@@ -0,0 +1,32 @@
+//! Delivers the decoded messages to the handlers of a mock host with a mock
+//! client, asserting that no malformed message panics the validation or the
+//! execution of the message.
+#![no_main]
+
+use ibc::core::channel::types::v2::msgs::PacketMsgV2;
+use ibc::core::client::types::Height;
+use ibc::core::entrypoint::{dispatch, dispatch_v2};
+use ibc::core::handler::types::msgs::MsgEnvelope;
+use ibc_fuzz::{AnyInput, MSG_TYPE_URLS};
+use ibc_testkit::testapp::ibc::clients::mock::client_state::client_type as mock_client_type;
+use ibc_testkit::testapp::ibc::core::router::MockRouter;
+use ibc_testkit::testapp::ibc::core::types::MockContext;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: AnyInput| {
+    let any = input.into_any(MSG_TYPE_URLS);
+
+    let mut ctx = MockContext::default().with_client(
+        &mock_client_type().build_client_id(0),
+        Height::new(0, 2).expect("Never fails"),
+    );
+    let mut router = MockRouter::new_with_transfer();
+
+    if let Ok(msg) = MsgEnvelope::try_from(any.clone()) {
+        let _ = dispatch(&mut ctx, &mut router, msg);
+    }
+
+    if let Ok(msg) = PacketMsgV2::try_from(any) {
+        let _ = dispatch_v2(&mut ctx, &mut router, msg);
+    }
+});
@@ -0,0 +1,51 @@
+//! Decodes the Merkle proofs carried by the messages, asserts their
+//! round-trip through their encoding, and verifies them against arbitrary
+//! roots and paths, as the Tendermint clients do.
+#![no_main]
+
+use arbitrary::Arbitrary;
+use ibc::core::commitment_types::commitment::{CommitmentPrefix, CommitmentProofBytes};
+use ibc::core::commitment_types::merkle::{apply_prefix, MerkleProof};
+use ibc::core::commitment_types::proto::v1::{MerkleProof as RawMerkleProof, MerkleRoot};
+use ibc::core::commitment_types::specs::ProofSpecs;
+use ibc_fuzz::assert_round_trip;
+use libfuzzer_sys::fuzz_target;
+
+#[derive(Arbitrary, Debug)]
+struct ProofInput {
+    proof: Vec<u8>,
+    root: Vec<u8>,
+    prefix: Vec<u8>,
+    path: String,
+    value: Vec<u8>,
+}
+
+fuzz_target!(|input: ProofInput| {
+    assert_round_trip::<MerkleProof, RawMerkleProof>(&input.proof);
+
+    let Ok(proof_bytes) = CommitmentProofBytes::try_from(input.proof) else {
+        return;
+    };
+
+    let Ok(proof) = MerkleProof::try_from(&proof_bytes) else {
+        return;
+    };
+
+    let Ok(prefix) = CommitmentPrefix::try_from(input.prefix) else {
+        return;
+    };
+
+    let root = MerkleRoot { hash: input.root };
+
+    let merkle_path = apply_prefix(&prefix, vec![input.path]);
+
+    let _ = proof.verify_membership(
+        &ProofSpecs::cosmos(),
+        root.clone(),
+        merkle_path.clone(),
+        input.value,
+        0,
+    );
+
+    let _ = proof.verify_non_membership(&ProofSpecs::cosmos(), root, merkle_path);
+});
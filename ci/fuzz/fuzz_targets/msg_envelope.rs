@@ -0,0 +1,37 @@
+//! Decodes the messages routed by the IBC handlers, and asserts their
+//! round-trip through their encoding.
+#![no_main]
+
+use ibc::core::channel::types::v2::msgs::PacketMsgV2;
+use ibc::core::handler::types::msgs::MsgEnvelope;
+use ibc::primitives::proto::Any;
+use ibc_fuzz::{encode_msg_envelope, encode_packet_msg_v2, AnyInput, MSG_TYPE_URLS};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: AnyInput| {
+    let any = input.into_any(MSG_TYPE_URLS);
+
+    if let Ok(envelope) = MsgEnvelope::try_from(any.clone()) {
+        let encoded = Any {
+            type_url: any.type_url.clone(),
+            value: encode_msg_envelope(&envelope),
+        };
+
+        let decoded = MsgEnvelope::try_from(encoded)
+            .unwrap_or_else(|e| panic!("the re-encoded {envelope:?} fails to decode: {e}"));
+
+        assert_eq!(envelope, decoded);
+    }
+
+    if let Ok(msg) = PacketMsgV2::try_from(any.clone()) {
+        let encoded = Any {
+            type_url: any.type_url,
+            value: encode_packet_msg_v2(&msg),
+        };
+
+        let decoded = PacketMsgV2::try_from(encoded)
+            .unwrap_or_else(|e| panic!("the re-encoded {msg:?} fails to decode: {e}"));
+
+        assert_eq!(msg, decoded);
+    }
+});
@@ -0,0 +1,34 @@
+//! Decodes the JSON packet data of the token and NFT transfers, as their
+//! modules do on receiving packets, and asserts their round-trip through
+//! their encoding.
+#![no_main]
+
+use core::fmt::Debug;
+
+use ibc::apps::nft_transfer::types::packet::PacketData as NftPacketData;
+use ibc::apps::transfer::types::packet::{PacketData, PacketDataV2};
+use libfuzzer_sys::fuzz_target;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+fn assert_json_round_trip<T>(bytes: &[u8])
+where
+    T: Serialize + DeserializeOwned + Debug + PartialEq,
+{
+    let Ok(value) = serde_json::from_slice::<T>(bytes) else {
+        return;
+    };
+
+    let encoded = serde_json::to_vec(&value).expect("the packet data must encode");
+
+    let decoded = serde_json::from_slice::<T>(&encoded)
+        .unwrap_or_else(|e| panic!("the re-encoded {value:?} fails to decode: {e}"));
+
+    assert_eq!(value, decoded);
+}
+
+fuzz_target!(|data: &[u8]| {
+    assert_json_round_trip::<PacketData>(data);
+    assert_json_round_trip::<PacketDataV2>(data);
+    assert_json_round_trip::<NftPacketData>(data);
+});
@@ -0,0 +1,187 @@
+//! Inputs and properties shared by the fuzz targets of ibc-rs.
+//!
+//! The targets decode the bytes a relayer, or any user submitting
+//! transactions, controls: the messages, the client and consensus states they
+//! carry, the packet data and the proofs. Decoding must never panic, and every
+//! decoded value must be encoded back into bytes decoding into the same value.
+#![allow(deprecated)]
+
+use core::fmt::{Debug, Display};
+
+use arbitrary::Arbitrary;
+use ibc::core::channel::types::msgs::{
+    ChannelMsg, PacketMsg, ACKNOWLEDGEMENT_TYPE_URL, CHAN_CLOSE_CONFIRM_TYPE_URL,
+    CHAN_CLOSE_INIT_TYPE_URL, CHAN_OPEN_ACK_TYPE_URL, CHAN_OPEN_CONFIRM_TYPE_URL,
+    CHAN_OPEN_INIT_TYPE_URL, CHAN_OPEN_TRY_TYPE_URL, CHAN_UPGRADE_ACK_TYPE_URL,
+    CHAN_UPGRADE_CANCEL_TYPE_URL, CHAN_UPGRADE_CONFIRM_TYPE_URL, CHAN_UPGRADE_INIT_TYPE_URL,
+    CHAN_UPGRADE_OPEN_TYPE_URL, CHAN_UPGRADE_TIMEOUT_TYPE_URL, CHAN_UPGRADE_TRY_TYPE_URL,
+    RECV_PACKET_TYPE_URL, TIMEOUT_ON_CLOSE_TYPE_URL, TIMEOUT_TYPE_URL,
+};
+use ibc::core::channel::types::v2::msgs::{
+    PacketMsgV2, ACKNOWLEDGEMENT_V2_TYPE_URL, RECV_PACKET_V2_TYPE_URL, SEND_PACKET_V2_TYPE_URL,
+    TIMEOUT_V2_TYPE_URL,
+};
+use ibc::core::client::types::msgs::{
+    ClientMsg, CREATE_CLIENT_TYPE_URL, SUBMIT_MISBEHAVIOUR_TYPE_URL, UPDATE_CLIENT_TYPE_URL,
+    UPGRADE_CLIENT_TYPE_URL,
+};
+use ibc::core::connection::types::msgs::{
+    ConnectionMsg, CONN_OPEN_ACK_TYPE_URL, CONN_OPEN_CONFIRM_TYPE_URL, CONN_OPEN_INIT_TYPE_URL,
+    CONN_OPEN_TRY_TYPE_URL,
+};
+use ibc::core::handler::types::msgs::MsgEnvelope;
+use ibc::primitives::proto::{Any, Protobuf};
+
+/// The type URLs of all the messages routed by the IBC handlers.
+pub const MSG_TYPE_URLS: &[&str] = &[
+    CREATE_CLIENT_TYPE_URL,
+    UPDATE_CLIENT_TYPE_URL,
+    UPGRADE_CLIENT_TYPE_URL,
+    SUBMIT_MISBEHAVIOUR_TYPE_URL,
+    CONN_OPEN_INIT_TYPE_URL,
+    CONN_OPEN_TRY_TYPE_URL,
+    CONN_OPEN_ACK_TYPE_URL,
+    CONN_OPEN_CONFIRM_TYPE_URL,
+    CHAN_OPEN_INIT_TYPE_URL,
+    CHAN_OPEN_TRY_TYPE_URL,
+    CHAN_OPEN_ACK_TYPE_URL,
+    CHAN_OPEN_CONFIRM_TYPE_URL,
+    CHAN_CLOSE_INIT_TYPE_URL,
+    CHAN_CLOSE_CONFIRM_TYPE_URL,
+    CHAN_UPGRADE_INIT_TYPE_URL,
+    CHAN_UPGRADE_TRY_TYPE_URL,
+    CHAN_UPGRADE_ACK_TYPE_URL,
+    CHAN_UPGRADE_CONFIRM_TYPE_URL,
+    CHAN_UPGRADE_OPEN_TYPE_URL,
+    CHAN_UPGRADE_TIMEOUT_TYPE_URL,
+    CHAN_UPGRADE_CANCEL_TYPE_URL,
+    RECV_PACKET_TYPE_URL,
+    ACKNOWLEDGEMENT_TYPE_URL,
+    TIMEOUT_TYPE_URL,
+    TIMEOUT_ON_CLOSE_TYPE_URL,
+    SEND_PACKET_V2_TYPE_URL,
+    RECV_PACKET_V2_TYPE_URL,
+    ACKNOWLEDGEMENT_V2_TYPE_URL,
+    TIMEOUT_V2_TYPE_URL,
+];
+
+/// The type URL of an [`AnyInput`], mostly one of the known type URLs the
+/// target decodes, so that the fuzzer reaches their decoders instead of
+/// guessing the URLs, and sometimes an arbitrary one.
+#[derive(Arbitrary, Debug)]
+pub enum TypeUrl {
+    Known(u8),
+    Unknown(String),
+}
+
+/// An arbitrary `Any`, over the given known type URLs.
+#[derive(Arbitrary, Debug)]
+pub struct AnyInput {
+    pub type_url: TypeUrl,
+    pub value: Vec<u8>,
+}
+
+impl AnyInput {
+    pub fn into_any(self, type_urls: &[&str]) -> Any {
+        let type_url = match self.type_url {
+            TypeUrl::Known(index) => type_urls[index as usize % type_urls.len()].to_string(),
+            TypeUrl::Unknown(type_url) => type_url,
+        };
+
+        Any {
+            type_url,
+            value: self.value,
+        }
+    }
+}
+
+/// Asserts that the value decoded from the bytes, if it decodes, is encoded
+/// back into bytes decoding into the same value.
+pub fn assert_round_trip<T, Raw>(bytes: &[u8])
+where
+    T: Protobuf<Raw> + Debug + PartialEq,
+    Raw: prost::Message + From<T> + Default,
+    <T as TryFrom<Raw>>::Error: Display,
+{
+    let Ok(value) = <T as Protobuf<Raw>>::decode_vec(bytes) else {
+        return;
+    };
+
+    let encoded = <T as Protobuf<Raw>>::encode_vec(value.clone());
+
+    let decoded = <T as Protobuf<Raw>>::decode_vec(&encoded)
+        .unwrap_or_else(|e| panic!("the re-encoded {value:?} fails to decode: {e}"));
+
+    assert_eq!(value, decoded);
+}
+
+/// Asserts that the value decoded from the `Any`, if it decodes, is encoded
+/// back into an `Any` decoding into the same value.
+pub fn assert_any_round_trip<T>(any: &Any)
+where
+    T: Protobuf<Any> + Debug + PartialEq,
+    Any: From<T>,
+    <T as TryFrom<Any>>::Error: Display,
+{
+    let Ok(value) = T::try_from(any.clone()) else {
+        return;
+    };
+
+    let encoded = Any::from(value.clone());
+
+    let decoded = T::try_from(encoded)
+        .unwrap_or_else(|e| panic!("the re-encoded {value:?} fails to decode: {e}"));
+
+    assert_eq!(value, decoded);
+}
+
+/// Returns the encoding of the message of the envelope, under the type URL
+/// of the message.
+pub fn encode_msg_envelope(envelope: &MsgEnvelope) -> Vec<u8> {
+    match envelope.clone() {
+        MsgEnvelope::Client(msg) => match msg {
+            ClientMsg::CreateClient(msg) => msg.encode_vec(),
+            ClientMsg::UpdateClient(msg) => msg.encode_vec(),
+            ClientMsg::Misbehaviour(msg) => msg.encode_vec(),
+            ClientMsg::UpgradeClient(msg) => msg.encode_vec(),
+        },
+        MsgEnvelope::Connection(msg) => match msg {
+            ConnectionMsg::OpenInit(msg) => msg.encode_vec(),
+            ConnectionMsg::OpenTry(msg) => msg.encode_vec(),
+            ConnectionMsg::OpenAck(msg) => msg.encode_vec(),
+            ConnectionMsg::OpenConfirm(msg) => msg.encode_vec(),
+        },
+        MsgEnvelope::Channel(msg) => match msg {
+            ChannelMsg::OpenInit(msg) => msg.encode_vec(),
+            ChannelMsg::OpenTry(msg) => msg.encode_vec(),
+            ChannelMsg::OpenAck(msg) => msg.encode_vec(),
+            ChannelMsg::OpenConfirm(msg) => msg.encode_vec(),
+            ChannelMsg::CloseInit(msg) => msg.encode_vec(),
+            ChannelMsg::CloseConfirm(msg) => msg.encode_vec(),
+            ChannelMsg::UpgradeInit(msg) => msg.encode_vec(),
+            ChannelMsg::UpgradeTry(msg) => msg.encode_vec(),
+            ChannelMsg::UpgradeAck(msg) => msg.encode_vec(),
+            ChannelMsg::UpgradeConfirm(msg) => msg.encode_vec(),
+            ChannelMsg::UpgradeOpen(msg) => msg.encode_vec(),
+            ChannelMsg::UpgradeTimeout(msg) => msg.encode_vec(),
+            ChannelMsg::UpgradeCancel(msg) => msg.encode_vec(),
+        },
+        MsgEnvelope::Packet(msg) => match msg {
+            PacketMsg::Recv(msg) => msg.encode_vec(),
+            PacketMsg::Ack(msg) => msg.encode_vec(),
+            PacketMsg::Timeout(msg) => msg.encode_vec(),
+            PacketMsg::TimeoutOnClose(msg) => msg.encode_vec(),
+        },
+    }
+}
+
+/// Returns the encoding of the IBC v2 packet message, under the type URL of
+/// the message.
+pub fn encode_packet_msg_v2(msg: &PacketMsgV2) -> Vec<u8> {
+    match msg.clone() {
+        PacketMsgV2::Send(msg) => msg.encode_vec(),
+        PacketMsgV2::Recv(msg) => msg.encode_vec(),
+        PacketMsgV2::Ack(msg) => msg.encode_vec(),
+        PacketMsgV2::Timeout(msg) => msg.encode_vec(),
+    }
+}
@@ -8,6 +8,8 @@ extern crate alloc;
 
 use ibc;
 use ibc_proto;
+#[cfg(feature = "full")]
+use ibc_query;
 use tendermint;
 use tendermint_light_client_verifier;
 use tendermint_proto;
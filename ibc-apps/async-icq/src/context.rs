@@ -0,0 +1,29 @@
+//! Defines the context trait the interchain queries host implements to serve
+//! the queries of the controllers.
+use crate::error::InterchainQueryError;
+use crate::packet::{RequestQuery, ResponseQuery};
+use crate::params::HostParams;
+
+/// Methods required by the interchain queries host, to be implemented by the
+/// host.
+///
+/// The queries only read the state of the host, so the same methods serve
+/// both the validation and the execution of the module callbacks.
+pub trait InterchainQueryHostContext {
+    /// Returns the parameters of the interchain queries host.
+    fn host_params(&self) -> Result<HostParams, InterchainQueryError>;
+
+    /// Returns `true` if the host proves the responses of the queries which
+    /// ask for a proof. Otherwise, the packets of such queries fail.
+    fn query_proofs_enabled(&self) -> bool {
+        false
+    }
+
+    /// Executes the given query against the state of the host, as its ABCI
+    /// query router would. The path of the query is allowed by the
+    /// parameters of the host.
+    ///
+    /// The response must be proven if the query asks for it, which only
+    /// happens if [`query_proofs_enabled`](Self::query_proofs_enabled).
+    fn execute_query(&self, request: &RequestQuery) -> Result<ResponseQuery, InterchainQueryError>;
+}
@@ -0,0 +1,78 @@
+//! Defines the interchain queries error type
+use displaydoc::Display;
+use ibc_core::channel::types::acknowledgement::StatusValue;
+use ibc_core::channel::types::channel::Order;
+use ibc_core::channel::types::error::{ChannelError, PacketError};
+use ibc_core::handler::types::error::ContextError;
+use ibc_core::host::types::identifiers::PortId;
+use ibc_core::primitives::prelude::*;
+
+#[derive(Display, Debug)]
+pub enum InterchainQueryError {
+    /// context error: `{0}`
+    ContextError(ContextError),
+    /// the interchain queries host is disabled
+    HostDisabled,
+    /// expected `{expect_order}` channel, got `{got_order}`
+    InvalidChannelOrder {
+        expect_order: Order,
+        got_order: Order,
+    },
+    /// invalid host port_id `{port_id}`
+    InvalidHostPort { port_id: PortId },
+    /// unsupported interchain queries version `{version}`
+    UnsupportedVersion { version: String },
+    /// the packet has no queries
+    EmptyQueries,
+    /// query of path `{path}` is not allowed on the host
+    QueryNotAllowed { path: String },
+    /// the host does not prove the query of path `{path}`
+    QueryProofNotSupported { path: String },
+    /// failed to execute the query of path `{path}`: `{reason}`
+    QueryExecution { path: String, reason: String },
+    /// unsupported operation: `{description}`
+    UnsupportedOperation { description: String },
+    /// failed to deserialize packet data
+    PacketDataDeserialization,
+    /// failed to deserialize acknowledgement
+    AckDeserialization,
+    /// failed to decode the queries: `{reason}`
+    DecodeQueries { reason: String },
+}
+
+impl core::error::Error for InterchainQueryError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match &self {
+            Self::ContextError(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<ContextError> for InterchainQueryError {
+    fn from(err: ContextError) -> InterchainQueryError {
+        Self::ContextError(err)
+    }
+}
+
+impl From<InterchainQueryError> for ChannelError {
+    fn from(err: InterchainQueryError) -> Self {
+        ChannelError::AppModule {
+            description: err.to_string(),
+        }
+    }
+}
+
+impl From<InterchainQueryError> for PacketError {
+    fn from(err: InterchainQueryError) -> Self {
+        PacketError::AppModule {
+            description: err.to_string(),
+        }
+    }
+}
+
+impl From<InterchainQueryError> for StatusValue {
+    fn from(err: InterchainQueryError) -> Self {
+        StatusValue::new(err.to_string()).expect("error message must not be empty")
+    }
+}
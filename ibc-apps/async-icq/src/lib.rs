@@ -0,0 +1,40 @@
+//! Implementation of the host side of the asynchronous [Interchain
+//! Queries](https://github.com/cosmos/ibc-apps/tree/main/modules/async-icq)
+//! application, with which a chain serves the ABCI queries that controller
+//! chains send in packets to its `icqhost` port.
+//!
+//! The queries of a packet are executed against the state of the host, as
+//! long as their paths are allowed by its [`HostParams`](params::HostParams).
+//! Their responses are sent back in the acknowledgement of the packet, with
+//! proofs only if the host supports them.
+#![no_std]
+#![forbid(unsafe_code)]
+#![cfg_attr(not(test), deny(clippy::unwrap_used))]
+#![cfg_attr(not(test), deny(clippy::disallowed_methods, clippy::disallowed_types))]
+#![deny(
+    warnings,
+    trivial_casts,
+    trivial_numeric_casts,
+    unused_import_braces,
+    unused_qualifications,
+    rust_2018_idioms
+)]
+#![allow(clippy::result_large_err)]
+
+#[cfg(any(test, feature = "std"))]
+extern crate std;
+
+pub mod context;
+pub mod error;
+pub mod module;
+pub mod packet;
+pub mod params;
+
+/// The module identifier of the interchain queries application.
+pub const MODULE_ID_STR: &str = "interchainquery";
+
+/// The version of the interchain queries channels.
+pub const VERSION: &str = "icq-1";
+
+/// The port of the interchain queries host.
+pub const HOST_PORT_ID_STR: &str = "icqhost";
@@ -0,0 +1,295 @@
+//! Implements the module callbacks of the interchain queries host, which
+//! accepts the channels opened by the controllers, and serves the queries
+//! they send over them.
+use ibc_core::channel::types::acknowledgement::{Acknowledgement, AcknowledgementStatus};
+use ibc_core::channel::types::channel::{Counterparty, Order};
+use ibc_core::channel::types::packet::Packet;
+use ibc_core::channel::types::Version;
+use ibc_core::host::types::identifiers::{ChannelId, ConnectionId, PortId};
+use ibc_core::primitives::prelude::*;
+use ibc_core::primitives::Signer;
+use ibc_core::router::types::event::ModuleEvent;
+use ibc_core::router::types::module::ModuleExtras;
+
+use crate::context::InterchainQueryHostContext;
+use crate::error::InterchainQueryError;
+use crate::packet::{decode_packet_data, query_acknowledgement, RequestQuery, ResponseQuery};
+use crate::params::HostParams;
+use crate::{HOST_PORT_ID_STR, MODULE_ID_STR, VERSION};
+
+/// The kind of the events emitted for the received packets.
+pub const EVENT_TYPE_PACKET: &str = "icq_packet";
+
+fn unsupported(description: &str) -> InterchainQueryError {
+    InterchainQueryError::UnsupportedOperation {
+        description: description.to_string(),
+    }
+}
+
+pub fn on_chan_open_init_validate(
+    _ctx: &impl InterchainQueryHostContext,
+    _order: Order,
+    _connection_hops: &[ConnectionId],
+    _port_id: &PortId,
+    _channel_id: &ChannelId,
+    _counterparty: &Counterparty,
+    _version: &Version,
+) -> Result<(), InterchainQueryError> {
+    Err(unsupported(
+        "channel handshakes must be initiated by the controller",
+    ))
+}
+
+pub fn on_chan_open_init_execute(
+    _ctx: &mut impl InterchainQueryHostContext,
+    _order: Order,
+    _connection_hops: &[ConnectionId],
+    _port_id: &PortId,
+    _channel_id: &ChannelId,
+    _counterparty: &Counterparty,
+    _version: &Version,
+) -> Result<(ModuleExtras, Version), InterchainQueryError> {
+    Err(unsupported(
+        "channel handshakes must be initiated by the controller",
+    ))
+}
+
+/// Validates the unordered channel proposed by a controller to the host
+/// port, with the interchain queries version.
+pub fn on_chan_open_try_validate(
+    ctx: &impl InterchainQueryHostContext,
+    order: Order,
+    _connection_hops: &[ConnectionId],
+    port_id: &PortId,
+    _channel_id: &ChannelId,
+    _counterparty: &Counterparty,
+    counterparty_version: &Version,
+) -> Result<(), InterchainQueryError> {
+    if !ctx.host_params()?.host_enabled {
+        return Err(InterchainQueryError::HostDisabled);
+    }
+
+    if order != Order::Unordered {
+        return Err(InterchainQueryError::InvalidChannelOrder {
+            expect_order: Order::Unordered,
+            got_order: order,
+        });
+    }
+
+    if port_id.as_str() != HOST_PORT_ID_STR {
+        return Err(InterchainQueryError::InvalidHostPort {
+            port_id: port_id.clone(),
+        });
+    }
+
+    if counterparty_version.as_str() != VERSION {
+        return Err(InterchainQueryError::UnsupportedVersion {
+            version: counterparty_version.to_string(),
+        });
+    }
+
+    Ok(())
+}
+
+/// Returns the version of the channel, [`VERSION`].
+pub fn on_chan_open_try_execute(
+    _ctx: &mut impl InterchainQueryHostContext,
+    _order: Order,
+    _connection_hops: &[ConnectionId],
+    _port_id: &PortId,
+    _channel_id: &ChannelId,
+    _counterparty: &Counterparty,
+    _counterparty_version: &Version,
+) -> Result<(ModuleExtras, Version), InterchainQueryError> {
+    Ok((ModuleExtras::empty(), Version::new(VERSION.to_string())))
+}
+
+pub fn on_chan_open_ack_validate(
+    _ctx: &impl InterchainQueryHostContext,
+    _port_id: &PortId,
+    _channel_id: &ChannelId,
+    _counterparty_version: &Version,
+) -> Result<(), InterchainQueryError> {
+    Err(unsupported(
+        "channel handshakes must be initiated by the controller",
+    ))
+}
+
+pub fn on_chan_open_ack_execute(
+    _ctx: &mut impl InterchainQueryHostContext,
+    _port_id: &PortId,
+    _channel_id: &ChannelId,
+    _counterparty_version: &Version,
+) -> Result<ModuleExtras, InterchainQueryError> {
+    Err(unsupported(
+        "channel handshakes must be initiated by the controller",
+    ))
+}
+
+pub fn on_chan_open_confirm_validate(
+    _ctx: &impl InterchainQueryHostContext,
+    _port_id: &PortId,
+    _channel_id: &ChannelId,
+) -> Result<(), InterchainQueryError> {
+    Ok(())
+}
+
+pub fn on_chan_open_confirm_execute(
+    _ctx: &mut impl InterchainQueryHostContext,
+    _port_id: &PortId,
+    _channel_id: &ChannelId,
+) -> Result<ModuleExtras, InterchainQueryError> {
+    Ok(ModuleExtras::empty())
+}
+
+pub fn on_chan_close_init_validate(
+    _ctx: &impl InterchainQueryHostContext,
+    _port_id: &PortId,
+    _channel_id: &ChannelId,
+) -> Result<(), InterchainQueryError> {
+    Err(unsupported("the host must not close the channels"))
+}
+
+pub fn on_chan_close_init_execute(
+    _ctx: &mut impl InterchainQueryHostContext,
+    _port_id: &PortId,
+    _channel_id: &ChannelId,
+) -> Result<ModuleExtras, InterchainQueryError> {
+    Err(unsupported("the host must not close the channels"))
+}
+
+pub fn on_chan_close_confirm_validate(
+    _ctx: &impl InterchainQueryHostContext,
+    _port_id: &PortId,
+    _channel_id: &ChannelId,
+) -> Result<(), InterchainQueryError> {
+    Ok(())
+}
+
+pub fn on_chan_close_confirm_execute(
+    _ctx: &mut impl InterchainQueryHostContext,
+    _port_id: &PortId,
+    _channel_id: &ChannelId,
+) -> Result<ModuleExtras, InterchainQueryError> {
+    Ok(ModuleExtras::empty())
+}
+
+/// Serves the queries of the given packet, and acknowledges it with their
+/// responses, or with the error which failed any of them.
+pub fn on_recv_packet_execute(
+    ctx_b: &mut impl InterchainQueryHostContext,
+    packet: &Packet,
+) -> (ModuleExtras, Acknowledgement) {
+    let result = execute_queries(ctx_b, packet);
+
+    let mut attributes = vec![
+        ("module", MODULE_ID_STR).into(),
+        ("host_channel_id", packet.chan_id_on_b.as_str()).into(),
+        ("success", result.is_ok()).into(),
+    ];
+
+    let ack = match result {
+        Ok(responses) => query_acknowledgement(responses),
+        Err(err) => {
+            attributes.push(("error", err.to_string()).into());
+
+            AcknowledgementStatus::error(err.into()).into()
+        }
+    };
+
+    let extras = ModuleExtras {
+        events: vec![ModuleEvent {
+            kind: EVENT_TYPE_PACKET.to_string(),
+            attributes,
+        }],
+        log: Vec::new(),
+    };
+
+    (extras, ack)
+}
+
+/// Validates all the queries of the given packet against the parameters of
+/// the host, then executes them, returning their responses.
+fn execute_queries(
+    ctx_b: &impl InterchainQueryHostContext,
+    packet: &Packet,
+) -> Result<Vec<ResponseQuery>, InterchainQueryError> {
+    let params = ctx_b.host_params()?;
+
+    if !params.host_enabled {
+        return Err(InterchainQueryError::HostDisabled);
+    }
+
+    let requests = decode_packet_data(&packet.data)?.requests()?;
+
+    for request in &requests {
+        validate_query(ctx_b, &params, request)?;
+    }
+
+    requests
+        .iter()
+        .map(|request| ctx_b.execute_query(request))
+        .collect()
+}
+
+/// Validates the given query, whose path must be allowed, and which must not
+/// ask for a proof unless the host proves its queries.
+fn validate_query(
+    ctx_b: &impl InterchainQueryHostContext,
+    params: &HostParams,
+    request: &RequestQuery,
+) -> Result<(), InterchainQueryError> {
+    if !params.is_query_allowed(&request.path) {
+        return Err(InterchainQueryError::QueryNotAllowed {
+            path: request.path.clone(),
+        });
+    }
+
+    if request.prove && !ctx_b.query_proofs_enabled() {
+        return Err(InterchainQueryError::QueryProofNotSupported {
+            path: request.path.clone(),
+        });
+    }
+
+    Ok(())
+}
+
+pub fn on_acknowledgement_packet_validate(
+    _ctx: &impl InterchainQueryHostContext,
+    _packet: &Packet,
+    _acknowledgement: &Acknowledgement,
+    _relayer: &Signer,
+) -> Result<(), InterchainQueryError> {
+    Err(unsupported("the host does not send packets"))
+}
+
+pub fn on_acknowledgement_packet_execute(
+    _ctx: &mut impl InterchainQueryHostContext,
+    _packet: &Packet,
+    _acknowledgement: &Acknowledgement,
+    _relayer: &Signer,
+) -> (ModuleExtras, Result<(), InterchainQueryError>) {
+    (
+        ModuleExtras::empty(),
+        Err(unsupported("the host does not send packets")),
+    )
+}
+
+pub fn on_timeout_packet_validate(
+    _ctx: &impl InterchainQueryHostContext,
+    _packet: &Packet,
+    _relayer: &Signer,
+) -> Result<(), InterchainQueryError> {
+    Err(unsupported("the host does not send packets"))
+}
+
+pub fn on_timeout_packet_execute(
+    _ctx: &mut impl InterchainQueryHostContext,
+    _packet: &Packet,
+    _relayer: &Signer,
+) -> (ModuleExtras, Result<(), InterchainQueryError>) {
+    (
+        ModuleExtras::empty(),
+        Err(unsupported("the host does not send packets")),
+    )
+}
@@ -0,0 +1,315 @@
+//! Defines the packet data of the interchain queries channels, which carries
+//! the ABCI queries of the controller, and the acknowledgement which carries
+//! their responses.
+//!
+//! The packet data uses the JSON format of `async-icq`, whose `data` is the
+//! base64 encoding of a protobuf [`CosmosQuery`]:
+//!
+//! ```json
+//! {
+//!   "data": "CjMKLWNvc21vcy5iYW5rLnYxYmV0YTEuUXVlcnkvQWxsQmFsYW5jZXMS...",
+//!   "memo": ""
+//! }
+//! ```
+//!
+//! The result of a successful acknowledgement is the JSON encoding of a
+//! [`InterchainQueryPacketAck`], whose `data` is the base64 encoding of a
+//! protobuf [`CosmosResponse`].
+use base64::prelude::BASE64_STANDARD;
+use base64::Engine;
+use ibc_core::channel::types::acknowledgement::{
+    Acknowledgement, AcknowledgementStatus, StatusValue,
+};
+use ibc_core::primitives::prelude::*;
+use prost::Message;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::error::InterchainQueryError;
+
+/// An ABCI query, as defined by CometBFT.
+///
+/// ```protobuf
+/// message RequestQuery {
+///   bytes  data   = 1;
+///   string path   = 2;
+///   int64  height = 3;
+///   bool   prove  = 4;
+/// }
+/// ```
+#[derive(Clone, PartialEq, Eq, prost::Message)]
+pub struct RequestQuery {
+    #[prost(bytes = "vec", tag = "1")]
+    pub data: Vec<u8>,
+    #[prost(string, tag = "2")]
+    pub path: String,
+    #[prost(int64, tag = "3")]
+    pub height: i64,
+    #[prost(bool, tag = "4")]
+    pub prove: bool,
+}
+
+/// The response of an ABCI query, as defined by CometBFT.
+///
+/// ```protobuf
+/// message ResponseQuery {
+///   uint32   code      = 1;
+///   string   log       = 3;
+///   string   info      = 4;
+///   int64    index     = 5;
+///   bytes    key       = 6;
+///   bytes    value     = 7;
+///   ProofOps proof_ops = 8;
+///   int64    height    = 9;
+///   string   codespace = 10;
+/// }
+/// ```
+#[derive(Clone, PartialEq, Eq, prost::Message)]
+pub struct ResponseQuery {
+    #[prost(uint32, tag = "1")]
+    pub code: u32,
+    #[prost(string, tag = "3")]
+    pub log: String,
+    #[prost(string, tag = "4")]
+    pub info: String,
+    #[prost(int64, tag = "5")]
+    pub index: i64,
+    #[prost(bytes = "vec", tag = "6")]
+    pub key: Vec<u8>,
+    #[prost(bytes = "vec", tag = "7")]
+    pub value: Vec<u8>,
+    /// The proof of the response, set only if the query asked for it.
+    #[prost(message, optional, tag = "8")]
+    pub proof_ops: Option<ProofOps>,
+    #[prost(int64, tag = "9")]
+    pub height: i64,
+    #[prost(string, tag = "10")]
+    pub codespace: String,
+}
+
+/// The Merkle proof of the response of an ABCI query.
+///
+/// ```protobuf
+/// message ProofOps {
+///   repeated ProofOp ops = 1;
+/// }
+/// ```
+#[derive(Clone, PartialEq, Eq, prost::Message)]
+pub struct ProofOps {
+    #[prost(message, repeated, tag = "1")]
+    pub ops: Vec<ProofOp>,
+}
+
+/// ```protobuf
+/// message ProofOp {
+///   string type = 1;
+///   bytes  key  = 2;
+///   bytes  data = 3;
+/// }
+/// ```
+#[derive(Clone, PartialEq, Eq, prost::Message)]
+pub struct ProofOp {
+    #[prost(string, tag = "1")]
+    pub r#type: String,
+    #[prost(bytes = "vec", tag = "2")]
+    pub key: Vec<u8>,
+    #[prost(bytes = "vec", tag = "3")]
+    pub data: Vec<u8>,
+}
+
+/// The queries of a packet.
+///
+/// ```protobuf
+/// message CosmosQuery {
+///   repeated tendermint.abci.RequestQuery requests = 1;
+/// }
+/// ```
+#[derive(Clone, PartialEq, Eq, prost::Message)]
+pub struct CosmosQuery {
+    #[prost(message, repeated, tag = "1")]
+    pub requests: Vec<RequestQuery>,
+}
+
+/// The responses of the queries of a packet, in their order.
+///
+/// ```protobuf
+/// message CosmosResponse {
+///   repeated tendermint.abci.ResponseQuery responses = 1;
+/// }
+/// ```
+#[derive(Clone, PartialEq, Eq, prost::Message)]
+pub struct CosmosResponse {
+    #[prost(message, repeated, tag = "1")]
+    pub responses: Vec<ResponseQuery>,
+}
+
+/// The packet data of the interchain queries channels.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct InterchainQueryPacketData {
+    /// The encoded [`CosmosQuery`].
+    #[serde(
+        serialize_with = "serialize_base64",
+        deserialize_with = "deserialize_base64"
+    )]
+    pub data: Vec<u8>,
+    #[serde(default)]
+    pub memo: String,
+}
+
+impl InterchainQueryPacketData {
+    /// Returns the packet data of the given queries.
+    pub fn new(requests: Vec<RequestQuery>, memo: String) -> Self {
+        Self {
+            data: CosmosQuery { requests }.encode_to_vec(),
+            memo,
+        }
+    }
+
+    /// Decodes the queries of the packet data, of which there must be at
+    /// least one.
+    pub fn requests(&self) -> Result<Vec<RequestQuery>, InterchainQueryError> {
+        let requests = CosmosQuery::decode(self.data.as_slice())
+            .map_err(|e| InterchainQueryError::DecodeQueries {
+                reason: e.to_string(),
+            })?
+            .requests;
+
+        if requests.is_empty() {
+            return Err(InterchainQueryError::EmptyQueries);
+        }
+
+        Ok(requests)
+    }
+
+    /// Returns the packet data as the bytes of a packet.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        serde_json::to_vec(self)
+            .expect("InterchainQueryPacketData's infallible Serialize impl failed")
+    }
+}
+
+/// Decodes the packet data from the bytes of a packet.
+pub fn decode_packet_data(data: &[u8]) -> Result<InterchainQueryPacketData, InterchainQueryError> {
+    serde_json::from_slice(data).map_err(|_| InterchainQueryError::PacketDataDeserialization)
+}
+
+/// The result of the acknowledgement of a packet whose queries were served.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct InterchainQueryPacketAck {
+    /// The encoded [`CosmosResponse`].
+    #[serde(
+        serialize_with = "serialize_base64",
+        deserialize_with = "deserialize_base64"
+    )]
+    pub data: Vec<u8>,
+}
+
+impl InterchainQueryPacketAck {
+    /// Returns the result of the given responses.
+    pub fn new(responses: Vec<ResponseQuery>) -> Self {
+        Self {
+            data: CosmosResponse { responses }.encode_to_vec(),
+        }
+    }
+
+    /// Decodes the responses of the result.
+    pub fn responses(&self) -> Result<Vec<ResponseQuery>, InterchainQueryError> {
+        CosmosResponse::decode(self.data.as_slice())
+            .map(|response| response.responses)
+            .map_err(|_| InterchainQueryError::AckDeserialization)
+    }
+}
+
+/// Returns the successful acknowledgement of the given responses.
+pub fn query_acknowledgement(responses: Vec<ResponseQuery>) -> Acknowledgement {
+    let result = serde_json::to_vec(&InterchainQueryPacketAck::new(responses))
+        .expect("InterchainQueryPacketAck's infallible Serialize impl failed");
+
+    let result = StatusValue::from_result(result).expect("the JSON encoding is not empty");
+
+    AcknowledgementStatus::success(result).into()
+}
+
+/// Decodes the responses of the given acknowledgement, or the error the
+/// queries failed with, for the controllers to process the outcome of their
+/// queries.
+pub fn decode_query_acknowledgement(
+    acknowledgement: &Acknowledgement,
+) -> Result<Result<Vec<ResponseQuery>, StatusValue>, InterchainQueryError> {
+    match AcknowledgementStatus::try_from(acknowledgement)
+        .map_err(|_| InterchainQueryError::AckDeserialization)?
+    {
+        AcknowledgementStatus::Success(result) => {
+            let result = result
+                .decoded_result()
+                .map_err(|_| InterchainQueryError::AckDeserialization)?;
+            let ack: InterchainQueryPacketAck = serde_json::from_slice(&result)
+                .map_err(|_| InterchainQueryError::AckDeserialization)?;
+
+            ack.responses().map(Ok)
+        }
+        AcknowledgementStatus::Error(error) => Ok(Err(error)),
+    }
+}
+
+fn serialize_base64<S: Serializer>(bytes: &[u8], serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_str(&BASE64_STANDARD.encode(bytes))
+}
+
+fn deserialize_base64<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<u8>, D::Error> {
+    let encoded = String::deserialize(deserializer)?;
+
+    BASE64_STANDARD
+        .decode(encoded)
+        .map_err(serde::de::Error::custom)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn dummy_request() -> RequestQuery {
+        RequestQuery {
+            data: vec![1, 2, 3],
+            path: "/cosmos.bank.v1beta1.Query/AllBalances".to_string(),
+            height: 0,
+            prove: false,
+        }
+    }
+
+    #[test]
+    fn test_packet_data_roundtrip() {
+        let data = InterchainQueryPacketData::new(vec![dummy_request()], String::new());
+
+        let decoded = decode_packet_data(&data.to_bytes()).unwrap();
+        assert_eq!(decoded, data);
+        assert_eq!(decoded.requests().unwrap(), vec![dummy_request()]);
+
+        // The memo is omitted by some controllers.
+        let json = format!(r#"{{"data":"{}"}}"#, BASE64_STANDARD.encode(&data.data));
+        assert_eq!(decode_packet_data(json.as_bytes()).unwrap(), data);
+
+        let empty = InterchainQueryPacketData::new(Vec::new(), String::new());
+        assert!(matches!(
+            empty.requests(),
+            Err(InterchainQueryError::EmptyQueries)
+        ));
+
+        assert!(decode_packet_data(br#"{"data":"not base64"}"#).is_err());
+    }
+
+    #[test]
+    fn test_query_acknowledgement_roundtrip() {
+        let responses = vec![ResponseQuery {
+            value: vec![4, 5, 6],
+            height: 10,
+            ..Default::default()
+        }];
+
+        let ack = query_acknowledgement(responses.clone());
+        assert_eq!(decode_query_acknowledgement(&ack).unwrap(), Ok(responses));
+
+        let error = StatusValue::new("query not allowed").unwrap();
+        let ack = AcknowledgementStatus::error(error.clone()).into();
+        assert_eq!(decode_query_acknowledgement(&ack).unwrap(), Err(error));
+    }
+}
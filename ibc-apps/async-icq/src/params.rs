@@ -0,0 +1,50 @@
+//! Defines the parameters of the interchain queries host.
+use ibc_core::primitives::prelude::*;
+
+/// The parameters of the interchain queries host.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct HostParams {
+    /// Whether the host accepts the channels of the controllers and serves
+    /// their queries.
+    pub host_enabled: bool,
+    /// The paths of the queries the host serves, e.g.
+    /// `/cosmos.bank.v1beta1.Query/AllBalances`.
+    pub allow_queries: Vec<String>,
+}
+
+impl Default for HostParams {
+    /// The host is enabled, but serves no queries until they are allowed.
+    fn default() -> Self {
+        Self {
+            host_enabled: true,
+            allow_queries: Vec::new(),
+        }
+    }
+}
+
+impl HostParams {
+    /// Returns `true` if the host serves the queries of the given path.
+    pub fn is_query_allowed(&self, path: &str) -> bool {
+        self.allow_queries.iter().any(|allowed| allowed == path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ALL_BALANCES: &str = "/cosmos.bank.v1beta1.Query/AllBalances";
+
+    #[test]
+    fn test_query_allowlist() {
+        assert!(!HostParams::default().is_query_allowed(ALL_BALANCES));
+
+        let params = HostParams {
+            allow_queries: vec![ALL_BALANCES.to_string()],
+            ..Default::default()
+        };
+        assert!(params.is_query_allowed(ALL_BALANCES));
+        assert!(!params.is_query_allowed("/cosmos.bank.v1beta1.Query/Balance"));
+        assert!(!params.is_query_allowed("/cosmos.bank.v1beta1.Query/AllBalances/"));
+    }
+}
@@ -0,0 +1,105 @@
+//! Implements the transfer authorizations, with which an account grants
+//! another account to transfer its tokens up to spend limits over given
+//! channels, and the transfers the grantees send on behalf of the granters.
+use ibc_app_transfer_types::error::TokenTransferError;
+use ibc_app_transfer_types::msgs::multi_token_transfer::MsgMultiTokenTransfer;
+use ibc_app_transfer_types::TransferAuthorization;
+use ibc_core::channel::context::{SendPacketExecutionContext, SendPacketValidationContext};
+use ibc_core::primitives::Signer;
+
+use crate::context::{TokenTransferExecutionContext, TokenTransferValidationContext};
+use crate::handler::{send_multi_token_transfer_execute, send_multi_token_transfer_validate};
+
+pub fn grant_transfer_authorization_validate(
+    _ctx: &impl TokenTransferValidationContext,
+    _granter: &Signer,
+    _grantee: &Signer,
+    authorization: &TransferAuthorization,
+) -> Result<(), TokenTransferError> {
+    authorization.validate_basic()
+}
+
+/// Grants the given authorization to the given grantee, replacing the one
+/// the granter previously granted to it, if any.
+pub fn grant_transfer_authorization_execute(
+    ctx: &mut impl TokenTransferExecutionContext,
+    granter: &Signer,
+    grantee: &Signer,
+    authorization: TransferAuthorization,
+) -> Result<(), TokenTransferError> {
+    ctx.store_transfer_authorization(granter, grantee, authorization)
+}
+
+pub fn revoke_transfer_authorization_validate(
+    ctx: &impl TokenTransferValidationContext,
+    granter: &Signer,
+    grantee: &Signer,
+) -> Result<(), TokenTransferError> {
+    granted_authorization(ctx, granter, grantee)?;
+
+    Ok(())
+}
+
+/// Revokes the authorization the granter granted to the given grantee.
+pub fn revoke_transfer_authorization_execute(
+    ctx: &mut impl TokenTransferExecutionContext,
+    granter: &Signer,
+    grantee: &Signer,
+) -> Result<(), TokenTransferError> {
+    ctx.delete_transfer_authorization(granter, grantee)
+}
+
+/// Validates the transfer the given grantee sends on behalf of its sender,
+/// which must be authorized by the authorization the sender granted to the
+/// grantee.
+pub fn send_authorized_transfer_validate<SendPacketCtx, TokenCtx>(
+    send_packet_ctx_a: &SendPacketCtx,
+    token_ctx_a: &TokenCtx,
+    grantee: &Signer,
+    msg: MsgMultiTokenTransfer,
+) -> Result<(), TokenTransferError>
+where
+    SendPacketCtx: SendPacketValidationContext,
+    TokenCtx: TokenTransferValidationContext,
+{
+    granted_authorization(token_ctx_a, &msg.packet_data.sender, grantee)?.accept(&msg)?;
+
+    send_multi_token_transfer_validate(send_packet_ctx_a, token_ctx_a, msg)
+}
+
+/// Executes the transfer the given grantee sends on behalf of its sender,
+/// spending it from the authorization, which is deleted once spent. A prior
+/// call to [`send_authorized_transfer_validate`] MUST have succeeded.
+pub fn send_authorized_transfer_execute<SendPacketCtx, TokenCtx>(
+    send_packet_ctx_a: &mut SendPacketCtx,
+    token_ctx_a: &mut TokenCtx,
+    grantee: &Signer,
+    msg: MsgMultiTokenTransfer,
+) -> Result<(), TokenTransferError>
+where
+    SendPacketCtx: SendPacketExecutionContext,
+    TokenCtx: TokenTransferExecutionContext,
+{
+    let granter = &msg.packet_data.sender;
+
+    match granted_authorization(token_ctx_a, granter, grantee)?.accept(&msg)? {
+        Some(authorization) => {
+            token_ctx_a.store_transfer_authorization(granter, grantee, authorization)?
+        }
+        None => token_ctx_a.delete_transfer_authorization(granter, grantee)?,
+    }
+
+    send_multi_token_transfer_execute(send_packet_ctx_a, token_ctx_a, msg)
+}
+
+fn granted_authorization(
+    ctx: &impl TokenTransferValidationContext,
+    granter: &Signer,
+    grantee: &Signer,
+) -> Result<TransferAuthorization, TokenTransferError> {
+    ctx.transfer_authorization(granter, grantee)?
+        .ok_or_else(|| TokenTransferError::TransferAuthorizationNotFound {
+            granter: granter.clone(),
+            grantee: grantee.clone(),
+        })
+}
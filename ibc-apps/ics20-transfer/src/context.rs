@@ -1,11 +1,29 @@
 //! Defines the main context traits and IBC module callbacks
 
 use ibc_app_transfer_types::error::TokenTransferError;
-use ibc_app_transfer_types::{Memo, PrefixedCoin, PrefixedDenom};
+use ibc_app_transfer_types::{
+    Amount, DecimalConversion, DenomTraceHash, Memo, PrefixedCoin, PrefixedDenom,
+    TransferAuthorization, TransferParams, TransferPolicy,
+};
 use ibc_core::host::types::identifiers::{ChannelId, PortId};
 use ibc_core::primitives::prelude::*;
 use ibc_core::primitives::Signer;
 
+/// How the tokens of the transfers sent over a channel are handled when the
+/// transfers fail or time out.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum RefundMode {
+    /// The tokens are refunded to the sender.
+    #[default]
+    Refund,
+    /// The tokens are burnt, e.g. by the one-way bridges and the token
+    /// migrations.
+    Burn,
+    /// The tokens are burnt if the memo of the transfer flags it as
+    /// non-refundable under its `non_refundable` key, and refunded otherwise.
+    Memo,
+}
+
 /// Methods required in token transfer validation, to be implemented by the host
 pub trait TokenTransferValidationContext {
     type AccountId: TryFrom<Signer>;
@@ -19,6 +37,12 @@ pub trait TokenTransferValidationContext {
     /// Returns Ok() if the host chain supports receiving coins.
     fn can_receive_coins(&self) -> Result<(), TokenTransferError>;
 
+    /// Returns the parameters of the token transfer application, enabling
+    /// the transfers by default. Implement only if the host stores them.
+    fn transfer_params(&self) -> Result<TransferParams, TokenTransferError> {
+        Ok(TransferParams::default())
+    }
+
     /// Validates that the tokens can be escrowed successfully.
     ///
     /// `memo` field allows to incorporate additional contextual details in the
@@ -59,11 +83,103 @@ pub trait TokenTransferValidationContext {
         memo: &Memo,
     ) -> Result<(), TokenTransferError>;
 
+    /// Returns how the tokens of the failed or timed out transfers sent over
+    /// the given channel are handled, which is refunding them by default.
+    fn refund_mode(
+        &self,
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+    ) -> Result<RefundMode, TokenTransferError> {
+        Ok(RefundMode::Refund)
+    }
+
+    /// Validates that the tokens escrowed by the given channel can be burnt.
+    /// Implement only if the host does not refund all the transfers.
+    fn burn_escrowed_coins_validate(
+        &self,
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+        _coin: &PrefixedCoin,
+    ) -> Result<(), TokenTransferError> {
+        Err(TokenTransferError::EscrowBurnUnsupported)
+    }
+
+    /// Returns the transfer policy of the given channel, restricting the
+    /// denominations sent and received over it, which allows all of them by
+    /// default. Implement only if the host restricts the transfers.
+    fn transfer_policy(
+        &self,
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+    ) -> Result<TransferPolicy, TokenTransferError> {
+        Ok(TransferPolicy::default())
+    }
+
+    /// Returns the transfer authorization the given granter granted to the
+    /// given grantee, if any. Implement only if the host supports the
+    /// transfer authorizations.
+    fn transfer_authorization(
+        &self,
+        _granter: &Signer,
+        _grantee: &Signer,
+    ) -> Result<Option<TransferAuthorization>, TokenTransferError> {
+        Ok(None)
+    }
+
+    /// Returns the conversion of the amounts of the given denomination, as
+    /// denominated on the host, transferred over the given channel, if its
+    /// packets carry them with another precision than the host holds them
+    /// with. Implement only if the host converts the precision of the
+    /// transfers, e.g. of the channels to EVM chains.
+    fn decimal_conversion(
+        &self,
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+        _denom: &PrefixedDenom,
+    ) -> Result<Option<DecimalConversion>, TokenTransferError> {
+        Ok(None)
+    }
+
+    /// Returns true if the host tracks the total amount escrowed by all the
+    /// channels, by denomination. Implement only if the host tracks the total
+    /// escrow, as `ibc-go` does.
+    fn tracks_total_escrow(&self) -> bool {
+        false
+    }
+
+    /// Returns the total amount of the given denomination escrowed by all the
+    /// channels, as tracked by the token transfer application, or zero if
+    /// none was escrowed yet. Only called if the host tracks the total escrow.
+    fn total_escrow(&self, _denom: &PrefixedDenom) -> Result<Amount, TokenTransferError> {
+        Ok(Amount::zero())
+    }
+
+    /// Returns all the tracked total escrows, by denomination.
+    /// Implement only if the host tracks the total escrow.
+    fn total_escrows(&self) -> Result<Vec<(PrefixedDenom, Amount)>, TokenTransferError> {
+        Ok(Vec::new())
+    }
+
     /// Returns a hash of the prefixed denom.
     /// Implement only if the host chain supports hashed denominations.
     fn denom_hash_string(&self, _denom: &PrefixedDenom) -> Option<String> {
         None
     }
+
+    /// Returns the denomination whose trace has the given hash, if stored.
+    /// Implement only if the host chain stores the denom traces.
+    fn denom_trace(
+        &self,
+        _hash: &DenomTraceHash,
+    ) -> Result<Option<PrefixedDenom>, TokenTransferError> {
+        Ok(None)
+    }
+
+    /// Returns all the stored denom traces.
+    /// Implement only if the host chain stores the denom traces.
+    fn denom_traces(&self) -> Result<Vec<PrefixedDenom>, TokenTransferError> {
+        Ok(Vec::new())
+    }
 }
 
 /// Methods required in token transfer execution, to be implemented by the host.
@@ -107,4 +223,187 @@ pub trait TokenTransferExecutionContext: TokenTransferValidationContext {
         coin: &PrefixedCoin,
         memo: &Memo,
     ) -> Result<(), TokenTransferError>;
+
+    /// Burns the tokens escrowed by the given channel, instead of refunding
+    /// them. Implement only if the host does not refund all the transfers.
+    fn burn_escrowed_coins_execute(
+        &mut self,
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+        _coin: &PrefixedCoin,
+    ) -> Result<(), TokenTransferError> {
+        Err(TokenTransferError::EscrowBurnUnsupported)
+    }
+
+    /// Stores the transfer authorization the given granter grants to the
+    /// given grantee, replacing the previous one. Implement only if the host
+    /// supports the transfer authorizations.
+    fn store_transfer_authorization(
+        &mut self,
+        _granter: &Signer,
+        _grantee: &Signer,
+        _authorization: TransferAuthorization,
+    ) -> Result<(), TokenTransferError> {
+        Err(TokenTransferError::TransferAuthorizationUnsupported)
+    }
+
+    /// Deletes the transfer authorization the given granter granted to the
+    /// given grantee, once revoked or spent. Implement only if the host
+    /// supports the transfer authorizations.
+    fn delete_transfer_authorization(
+        &mut self,
+        _granter: &Signer,
+        _grantee: &Signer,
+    ) -> Result<(), TokenTransferError> {
+        Err(TokenTransferError::TransferAuthorizationUnsupported)
+    }
+
+    /// Stores the total amount of the given denomination escrowed by all the
+    /// channels, updated whenever tokens are escrowed, unescrowed or burnt
+    /// from the escrow. Implement only if the host tracks the total escrow.
+    fn store_total_escrow(
+        &mut self,
+        _denom: &PrefixedDenom,
+        _amount: Amount,
+    ) -> Result<(), TokenTransferError> {
+        Ok(())
+    }
+
+    /// Stores the parameters of the token transfer application, as updated
+    /// by the governance of the host. Implement only if the host stores them.
+    fn store_transfer_params(&mut self, _params: TransferParams) -> Result<(), TokenTransferError> {
+        Err(TokenTransferError::ParamsUnsupported)
+    }
+
+    /// Stores the transfer policy of the given channel, as updated by the
+    /// governance of the host. An unrestricted policy may be deleted instead.
+    /// Implement only if the host restricts the transfers.
+    fn store_transfer_policy(
+        &mut self,
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+        _policy: TransferPolicy,
+    ) -> Result<(), TokenTransferError> {
+        Err(TokenTransferError::TransferPolicyUnsupported)
+    }
+
+    /// Called before the tokens are escrowed, e.g. to skim a fee off the
+    /// transfer or record it. Failing aborts the escrow.
+    fn before_escrow_coins_execute(
+        &mut self,
+        _from_account: &Self::AccountId,
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+        _coin: &PrefixedCoin,
+        _memo: &Memo,
+    ) -> Result<(), TokenTransferError> {
+        Ok(())
+    }
+
+    /// Called after the tokens are escrowed.
+    fn after_escrow_coins_execute(
+        &mut self,
+        _from_account: &Self::AccountId,
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+        _coin: &PrefixedCoin,
+        _memo: &Memo,
+    ) -> Result<(), TokenTransferError> {
+        Ok(())
+    }
+
+    /// Called before the tokens are unescrowed. Failing aborts the unescrow.
+    fn before_unescrow_coins_execute(
+        &mut self,
+        _to_account: &Self::AccountId,
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+        _coin: &PrefixedCoin,
+    ) -> Result<(), TokenTransferError> {
+        Ok(())
+    }
+
+    /// Called after the tokens are unescrowed.
+    fn after_unescrow_coins_execute(
+        &mut self,
+        _to_account: &Self::AccountId,
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+        _coin: &PrefixedCoin,
+    ) -> Result<(), TokenTransferError> {
+        Ok(())
+    }
+
+    /// Called before the tokens are minted. Failing aborts the mint.
+    fn before_mint_coins_execute(
+        &mut self,
+        _account: &Self::AccountId,
+        _coin: &PrefixedCoin,
+    ) -> Result<(), TokenTransferError> {
+        Ok(())
+    }
+
+    /// Called after the tokens are minted.
+    fn after_mint_coins_execute(
+        &mut self,
+        _account: &Self::AccountId,
+        _coin: &PrefixedCoin,
+    ) -> Result<(), TokenTransferError> {
+        Ok(())
+    }
+
+    /// Called before the tokens are burnt. Failing aborts the burn.
+    fn before_burn_coins_execute(
+        &mut self,
+        _account: &Self::AccountId,
+        _coin: &PrefixedCoin,
+        _memo: &Memo,
+    ) -> Result<(), TokenTransferError> {
+        Ok(())
+    }
+
+    /// Called after the tokens are burnt.
+    fn after_burn_coins_execute(
+        &mut self,
+        _account: &Self::AccountId,
+        _coin: &PrefixedCoin,
+        _memo: &Memo,
+    ) -> Result<(), TokenTransferError> {
+        Ok(())
+    }
+
+    /// Stores the trace of the given denomination under its
+    /// [`trace_hash`](PrefixedDenom::trace_hash), before its vouchers are
+    /// minted. Implement only if the host chain stores the denom traces.
+    fn store_denom_trace(&mut self, _denom: &PrefixedDenom) -> Result<(), TokenTransferError> {
+        Ok(())
+    }
+}
+
+/// Methods to read the tokens escrowed by the channels, to be implemented by
+/// the hosts which expose them, e.g. to chain frontends and auditors.
+pub trait TokenTransferEscrowContext: TokenTransferValidationContext {
+    /// Returns the address of the account escrowing the tokens sent over the
+    /// given channel, as encoded by the host.
+    ///
+    /// The hosts following the Cosmos SDK derive it with
+    /// [`escrow_address`](ibc_app_transfer_types::escrow_address).
+    fn escrow_address(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<Signer, TokenTransferError>;
+
+    /// Returns the amount of the given denomination escrowed by the given
+    /// channel.
+    fn escrowed_amount(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        denom: &PrefixedDenom,
+    ) -> Result<Amount, TokenTransferError>;
+
+    /// Returns the total amount of the given denomination escrowed by all the
+    /// channels.
+    fn total_escrowed_amount(&self, denom: &PrefixedDenom) -> Result<Amount, TokenTransferError>;
 }
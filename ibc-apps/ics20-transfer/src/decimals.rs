@@ -0,0 +1,63 @@
+//! Converts the amounts of the transfers between the precision of the host
+//! and the precision of the packets, following the [`DecimalConversion`] of
+//! their denomination and channel.
+//!
+//! The tokens sent are converted to the precision of the packets, and only
+//! the amount which converts back exactly is moved from the sender, the
+//! remainder staying with the sender. The tokens received and refunded are
+//! converted to the precision of the host, rounding down.
+use ibc_app_transfer_types::error::TokenTransferError;
+use ibc_app_transfer_types::{Amount, PrefixedCoin};
+use ibc_core::host::types::identifiers::{ChannelId, PortId};
+
+use crate::context::TokenTransferValidationContext;
+
+/// Returns the token sent over the given channel as carried by the packet,
+/// and the token moved from the sender, in the precision of the host.
+pub fn to_packet_coin(
+    ctx: &impl TokenTransferValidationContext,
+    port_id: &PortId,
+    channel_id: &ChannelId,
+    coin: &PrefixedCoin,
+) -> Result<(PrefixedCoin, PrefixedCoin), TokenTransferError> {
+    let Some(conversion) = ctx.decimal_conversion(port_id, channel_id, &coin.denom)? else {
+        return Ok((coin.clone(), coin.clone()));
+    };
+
+    let packet_amount = conversion.to_packet_amount(coin.amount)?;
+
+    if packet_amount == Amount::from(0) {
+        return Err(TokenTransferError::ZeroConvertedAmount {
+            denom: coin.denom.clone(),
+        });
+    }
+
+    let packet_coin = PrefixedCoin {
+        denom: coin.denom.clone(),
+        amount: packet_amount,
+    };
+    let moved_coin = PrefixedCoin {
+        denom: coin.denom.clone(),
+        amount: conversion.to_host_amount(packet_amount)?,
+    };
+
+    Ok((packet_coin, moved_coin))
+}
+
+/// Returns the given token of a packet sent or received over the given
+/// channel, as denominated on the host, in the precision of the host.
+pub fn to_host_coin(
+    ctx: &impl TokenTransferValidationContext,
+    port_id: &PortId,
+    channel_id: &ChannelId,
+    coin: &PrefixedCoin,
+) -> Result<PrefixedCoin, TokenTransferError> {
+    let Some(conversion) = ctx.decimal_conversion(port_id, channel_id, &coin.denom)? else {
+        return Ok(coin.clone());
+    };
+
+    Ok(PrefixedCoin {
+        denom: coin.denom.clone(),
+        amount: conversion.to_host_amount(coin.amount)?,
+    })
+}
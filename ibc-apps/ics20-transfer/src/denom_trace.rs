@@ -0,0 +1,30 @@
+//! Resolves the `ibc/{hash}` denominations of the tokens received over IBC to
+//! their traces, so that hosts can display the original denominations.
+use core::str::FromStr;
+
+use ibc_app_transfer_types::error::TokenTransferError;
+use ibc_app_transfer_types::{DenomTraceHash, PrefixedDenom, IBC_DENOM_PREFIX};
+
+use crate::context::TokenTransferValidationContext;
+
+/// Returns the stored denomination whose trace has the given hash.
+pub fn denom_trace(
+    ctx: &impl TokenTransferValidationContext,
+    hash: &DenomTraceHash,
+) -> Result<PrefixedDenom, TokenTransferError> {
+    ctx.denom_trace(hash)?
+        .ok_or(TokenTransferError::DenomTraceNotFound { hash: *hash })
+}
+
+/// Resolves the given denomination, held on the host as `ibc/{hash}` if it
+/// was received over IBC, and as the base denomination otherwise, to its
+/// trace.
+pub fn resolve_denom(
+    ctx: &impl TokenTransferValidationContext,
+    denom: &str,
+) -> Result<PrefixedDenom, TokenTransferError> {
+    match denom.split_once('/') {
+        Some((IBC_DENOM_PREFIX, _)) => denom_trace(ctx, &DenomTraceHash::from_ibc_denom(denom)?),
+        _ => PrefixedDenom::from_str(denom),
+    }
+}
@@ -0,0 +1,368 @@
+//! Hands the received transfers whose memo carries forward instructions off to
+//! a [`ForwardHook`], so that the host is the middle hop of multi-hop
+//! transfers without stacking the packet forward middleware on top of the
+//! token transfer module.
+//!
+//! The tokens of a forwarded transfer are received by an intermediate account
+//! of the host, from which the hook forwards them to the next hop. The
+//! received packet is acknowledged asynchronously by the hook, once the
+//! forwarded transfer is acknowledged or timed out. When forwarding fails,
+//! the tokens are returned from the intermediate account to where they were
+//! received from with [`refund_forwarded_tokens_execute`], so that they are
+//! refunded to the original sender along the path.
+
+use ibc_app_transfer_types::error::TokenTransferError;
+use ibc_app_transfer_types::packet::PacketDataV2;
+use ibc_app_transfer_types::{is_sender_chain_source, Memo, PrefixedCoin};
+use ibc_core::channel::types::acknowledgement::{
+    Acknowledgement, AcknowledgementStatus, StatusValue,
+};
+use ibc_core::channel::types::packet::Packet;
+use ibc_core::channel::types::Version;
+use ibc_core::primitives::prelude::*;
+use ibc_core::primitives::Signer;
+use ibc_core::router::types::module::ModuleExtras;
+
+use crate::context::{TokenTransferExecutionContext, TokenTransferValidationContext};
+use crate::decimals::to_host_coin;
+use crate::handler::{
+    burn_coins_with_hooks, decode_packet_data, escrow_coins_with_hooks,
+    process_multi_token_recv_packet_execute, received_coin,
+};
+use crate::memo::{memo_keys, FORWARD_MEMO_KEY};
+use crate::module::{
+    on_acknowledgement_packet_execute, on_recv_packet_execute, on_timeout_packet_execute,
+    push_recv_events,
+};
+
+/// The tokens of a received transfer held by the intermediate account, to be
+/// forwarded to the next hop.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ForwardedTransfer {
+    /// The received tokens, as denominated on the host and in its precision.
+    pub tokens: Vec<PrefixedCoin>,
+    /// The account of the host which holds the tokens until they are
+    /// forwarded.
+    pub intermediate_receiver: Signer,
+    /// The sender of the received transfer.
+    pub original_sender: Signer,
+    /// The memo of the received transfer, carrying the forward instructions
+    /// under its `forward` key.
+    pub memo: Memo,
+}
+
+/// Forwards the tokens of the received transfers whose memo carries forward
+/// instructions to the next hop.
+pub trait ForwardHook<Ctx> {
+    /// Returns the account which receives the tokens of the transfers
+    /// forwarded from the channel of the given packet and original sender,
+    /// before they are forwarded to the next hop.
+    ///
+    /// The account should not be controlled by anyone, e.g. by deriving it
+    /// from the hash of the channel and the sender, as the packet forward
+    /// middleware of `ibc-go` does.
+    fn intermediate_receiver(
+        &self,
+        ctx: &Ctx,
+        packet: &Packet,
+        original_sender: &Signer,
+    ) -> Result<Signer, TokenTransferError>;
+
+    /// Forwards the tokens of the given transfer, which the intermediate
+    /// account holds, to the next hop, and tracks the forwarded transfer to
+    /// acknowledge the received packet once the forwarded one is.
+    ///
+    /// On error, the tokens are returned to where they were received from,
+    /// and the received packet is acknowledged with the error.
+    fn forward_execute(
+        &mut self,
+        ctx: &mut Ctx,
+        packet: &Packet,
+        transfer: &ForwardedTransfer,
+    ) -> Result<ModuleExtras, TokenTransferError>;
+
+    /// Called once the given packet sent by the token transfer module is
+    /// acknowledged, and its tokens refunded to their sender on error, which
+    /// is the intermediate account for the forwarded transfers.
+    fn on_forward_acknowledgement_execute(
+        &mut self,
+        _ctx: &mut Ctx,
+        _packet: &Packet,
+        _acknowledgement: &AcknowledgementStatus,
+    ) -> Result<ModuleExtras, TokenTransferError> {
+        Ok(ModuleExtras::empty())
+    }
+
+    /// Called once the given packet sent by the token transfer module timed
+    /// out, and its tokens are refunded to their sender, which is the
+    /// intermediate account for the forwarded transfers.
+    fn on_forward_timeout_execute(
+        &mut self,
+        _ctx: &mut Ctx,
+        _packet: &Packet,
+    ) -> Result<ModuleExtras, TokenTransferError> {
+        Ok(ModuleExtras::empty())
+    }
+}
+
+fn error_ack(err: &TokenTransferError) -> Acknowledgement {
+    AcknowledgementStatus::error(StatusValue::from_abci_error_code(err.ack_error_code())).into()
+}
+
+/// Receives the given packet as [`on_recv_packet_execute`] does, unless its
+/// memo carries forward instructions, in which case the tokens are received
+/// by the intermediate account and handed off to the hook.
+///
+/// Returns no acknowledgement if the tokens were forwarded, the hook writing
+/// it once the forwarded transfer is acknowledged.
+pub fn on_recv_packet_forward_execute<Ctx, Hook>(
+    ctx_b: &mut Ctx,
+    hook: &mut Hook,
+    packet: &Packet,
+    version: &Version,
+) -> (ModuleExtras, Option<Acknowledgement>)
+where
+    Ctx: TokenTransferExecutionContext,
+    Hook: ForwardHook<Ctx>,
+{
+    let Some(data) = decode_packet_data(version, &packet.data)
+        .ok()
+        .filter(|data| {
+            memo_keys(&data.memo)
+                .iter()
+                .any(|key| key == FORWARD_MEMO_KEY)
+        })
+    else {
+        let (extras, ack) = on_recv_packet_execute(ctx_b, packet, version);
+        return (extras, Some(ack));
+    };
+
+    let intermediate_receiver = match hook.intermediate_receiver(ctx_b, packet, &data.sender) {
+        Ok(intermediate_receiver) => intermediate_receiver,
+        Err(err) => return (ModuleExtras::empty(), Some(error_ack(&err))),
+    };
+
+    // The tokens are received by the intermediate account, without the
+    // forward instructions.
+    let intermediate_data = PacketDataV2 {
+        tokens: data.tokens.clone(),
+        sender: data.sender.clone(),
+        receiver: intermediate_receiver.clone(),
+        memo: Memo::from(""),
+    };
+
+    let (mut extras, result) =
+        match process_multi_token_recv_packet_execute(ctx_b, packet, intermediate_data) {
+            Ok(extras) => {
+                let result = data
+                    .tokens
+                    .iter()
+                    .map(|token| {
+                        to_host_coin(
+                            ctx_b,
+                            &packet.port_id_on_b,
+                            &packet.chan_id_on_b,
+                            &received_coin(packet, token),
+                        )
+                    })
+                    .collect::<Result<Vec<_>, _>>()
+                    .and_then(|tokens| {
+                        let transfer = ForwardedTransfer {
+                            tokens,
+                            intermediate_receiver,
+                            original_sender: data.sender.clone(),
+                            memo: data.memo.clone(),
+                        };
+
+                        forward(ctx_b, hook, packet, &transfer)
+                    });
+
+                (extras, result)
+            }
+            Err((extras, err)) => (extras, Err(err)),
+        };
+
+    let ack = match result {
+        Ok(forward_extras) => {
+            extras.events.extend(forward_extras.events);
+            extras.log.extend(forward_extras.log);
+            push_recv_events(&mut extras, data, true, None);
+
+            None
+        }
+        Err(err) => {
+            let ack = error_ack(&err);
+            push_recv_events(&mut extras, data, false, Some(err.to_string()));
+
+            Some(ack)
+        }
+    };
+
+    (extras, ack)
+}
+
+/// Hands the tokens held by the intermediate account off to the hook, and
+/// returns them to where they were received from if the hook fails.
+fn forward<Ctx, Hook>(
+    ctx_b: &mut Ctx,
+    hook: &mut Hook,
+    packet: &Packet,
+    transfer: &ForwardedTransfer,
+) -> Result<ModuleExtras, TokenTransferError>
+where
+    Ctx: TokenTransferExecutionContext,
+    Hook: ForwardHook<Ctx>,
+{
+    match hook.forward_execute(ctx_b, packet, transfer) {
+        Ok(extras) => Ok(extras),
+        Err(err) => {
+            refund_forwarded_tokens_validate(ctx_b, packet, transfer)?;
+            refund_forwarded_tokens_execute(ctx_b, packet, transfer)?;
+
+            Err(err)
+        }
+    }
+}
+
+/// Acknowledges the given packet as [`on_acknowledgement_packet_execute`]
+/// does, then notifies the hook.
+pub fn on_acknowledgement_packet_forward_execute<Ctx, Hook>(
+    ctx: &mut Ctx,
+    hook: &mut Hook,
+    packet: &Packet,
+    version: &Version,
+    acknowledgement: &Acknowledgement,
+    relayer: &Signer,
+) -> (ModuleExtras, Result<(), TokenTransferError>)
+where
+    Ctx: TokenTransferExecutionContext,
+    Hook: ForwardHook<Ctx>,
+{
+    let (mut extras, result) =
+        on_acknowledgement_packet_execute(ctx, packet, version, acknowledgement, relayer);
+
+    if result.is_err() {
+        return (extras, result);
+    }
+
+    let Ok(acknowledgement) =
+        serde_json::from_slice::<AcknowledgementStatus>(acknowledgement.as_ref())
+    else {
+        return (extras, Err(TokenTransferError::AckDeserialization));
+    };
+
+    match hook.on_forward_acknowledgement_execute(ctx, packet, &acknowledgement) {
+        Ok(hook_extras) => {
+            extras.events.extend(hook_extras.events);
+            extras.log.extend(hook_extras.log);
+
+            (extras, Ok(()))
+        }
+        Err(err) => (extras, Err(err)),
+    }
+}
+
+/// Times the given packet out as [`on_timeout_packet_execute`] does, then
+/// notifies the hook.
+pub fn on_timeout_packet_forward_execute<Ctx, Hook>(
+    ctx: &mut Ctx,
+    hook: &mut Hook,
+    packet: &Packet,
+    version: &Version,
+    relayer: &Signer,
+) -> (ModuleExtras, Result<(), TokenTransferError>)
+where
+    Ctx: TokenTransferExecutionContext,
+    Hook: ForwardHook<Ctx>,
+{
+    let (mut extras, result) = on_timeout_packet_execute(ctx, packet, version, relayer);
+
+    if result.is_err() {
+        return (extras, result);
+    }
+
+    match hook.on_forward_timeout_execute(ctx, packet) {
+        Ok(hook_extras) => {
+            extras.events.extend(hook_extras.events);
+            extras.log.extend(hook_extras.log);
+
+            (extras, Ok(()))
+        }
+        Err(err) => (extras, Err(err)),
+    }
+}
+
+/// Validates the return of the tokens held by the intermediate account to
+/// where they were received from with the given packet.
+pub fn refund_forwarded_tokens_validate(
+    ctx_b: &impl TokenTransferValidationContext,
+    packet: &Packet,
+    transfer: &ForwardedTransfer,
+) -> Result<(), TokenTransferError> {
+    let intermediate = transfer
+        .intermediate_receiver
+        .clone()
+        .try_into()
+        .map_err(|_| TokenTransferError::ParseAccountFailure)?;
+
+    for token in &transfer.tokens {
+        // The tokens are escrowed back if they were unescrowed on receipt,
+        // and burnt if they were minted.
+        if is_sender_chain_source(
+            packet.port_id_on_b.clone(),
+            packet.chan_id_on_b.clone(),
+            &token.denom,
+        ) {
+            ctx_b.escrow_coins_validate(
+                &intermediate,
+                &packet.port_id_on_b,
+                &packet.chan_id_on_b,
+                token,
+                &transfer.memo,
+            )?;
+        } else {
+            ctx_b.burn_coins_validate(&intermediate, token, &transfer.memo)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Returns the tokens held by the intermediate account to where they were
+/// received from with the given packet, so that they are refunded to the
+/// original sender once the packet is acknowledged with an error.
+///
+/// Hooks call it when the forwarded transfer fails, before writing the error
+/// acknowledgement of the received packet.
+pub fn refund_forwarded_tokens_execute(
+    ctx_b: &mut impl TokenTransferExecutionContext,
+    packet: &Packet,
+    transfer: &ForwardedTransfer,
+) -> Result<(), TokenTransferError> {
+    let intermediate = transfer
+        .intermediate_receiver
+        .clone()
+        .try_into()
+        .map_err(|_| TokenTransferError::ParseAccountFailure)?;
+
+    for token in &transfer.tokens {
+        if is_sender_chain_source(
+            packet.port_id_on_b.clone(),
+            packet.chan_id_on_b.clone(),
+            &token.denom,
+        ) {
+            escrow_coins_with_hooks(
+                ctx_b,
+                &intermediate,
+                &packet.port_id_on_b,
+                &packet.chan_id_on_b,
+                token,
+                &transfer.memo,
+            )?;
+        } else {
+            burn_coins_with_hooks(ctx_b, &intermediate, token, &transfer.memo)?;
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,133 @@
+//! Moves the tokens of the transfers with the host context, calling its hooks
+//! around each move and tracking the total escrow of the moved denominations.
+use ibc_app_transfer_types::error::TokenTransferError;
+use ibc_app_transfer_types::{Amount, Memo, PrefixedCoin};
+use ibc_core::host::types::identifiers::{ChannelId, PortId};
+
+use crate::context::{TokenTransferExecutionContext, TokenTransferValidationContext};
+
+/// Escrows the tokens in between the
+/// [`before`](TokenTransferExecutionContext::before_escrow_coins_execute) and
+/// [`after`](TokenTransferExecutionContext::after_escrow_coins_execute)
+/// escrow hooks.
+pub fn escrow_coins_with_hooks<Ctx: TokenTransferExecutionContext>(
+    ctx: &mut Ctx,
+    from_account: &Ctx::AccountId,
+    port_id: &PortId,
+    channel_id: &ChannelId,
+    coin: &PrefixedCoin,
+    memo: &Memo,
+) -> Result<(), TokenTransferError> {
+    ctx.before_escrow_coins_execute(from_account, port_id, channel_id, coin, memo)?;
+    ctx.escrow_coins_execute(from_account, port_id, channel_id, coin, memo)?;
+    add_to_total_escrow(ctx, coin)?;
+    ctx.after_escrow_coins_execute(from_account, port_id, channel_id, coin, memo)
+}
+
+/// Unescrows the tokens in between the
+/// [`before`](TokenTransferExecutionContext::before_unescrow_coins_execute)
+/// and [`after`](TokenTransferExecutionContext::after_unescrow_coins_execute)
+/// unescrow hooks.
+pub fn unescrow_coins_with_hooks<Ctx: TokenTransferExecutionContext>(
+    ctx: &mut Ctx,
+    to_account: &Ctx::AccountId,
+    port_id: &PortId,
+    channel_id: &ChannelId,
+    coin: &PrefixedCoin,
+) -> Result<(), TokenTransferError> {
+    ctx.before_unescrow_coins_execute(to_account, port_id, channel_id, coin)?;
+    ctx.unescrow_coins_execute(to_account, port_id, channel_id, coin)?;
+    subtract_from_total_escrow(ctx, coin)?;
+    ctx.after_unescrow_coins_execute(to_account, port_id, channel_id, coin)
+}
+
+/// Mints the tokens in between the
+/// [`before`](TokenTransferExecutionContext::before_mint_coins_execute) and
+/// [`after`](TokenTransferExecutionContext::after_mint_coins_execute) mint
+/// hooks.
+pub fn mint_coins_with_hooks<Ctx: TokenTransferExecutionContext>(
+    ctx: &mut Ctx,
+    account: &Ctx::AccountId,
+    coin: &PrefixedCoin,
+) -> Result<(), TokenTransferError> {
+    ctx.before_mint_coins_execute(account, coin)?;
+    ctx.mint_coins_execute(account, coin)?;
+    ctx.after_mint_coins_execute(account, coin)
+}
+
+/// Burns the tokens in between the
+/// [`before`](TokenTransferExecutionContext::before_burn_coins_execute) and
+/// [`after`](TokenTransferExecutionContext::after_burn_coins_execute) burn
+/// hooks.
+pub fn burn_coins_with_hooks<Ctx: TokenTransferExecutionContext>(
+    ctx: &mut Ctx,
+    account: &Ctx::AccountId,
+    coin: &PrefixedCoin,
+    memo: &Memo,
+) -> Result<(), TokenTransferError> {
+    ctx.before_burn_coins_execute(account, coin, memo)?;
+    ctx.burn_coins_execute(account, coin, memo)?;
+    ctx.after_burn_coins_execute(account, coin, memo)
+}
+
+/// Adds the escrowed tokens to the total escrow of their denomination, if the
+/// host tracks it.
+pub(crate) fn add_to_total_escrow(
+    ctx: &mut impl TokenTransferExecutionContext,
+    coin: &PrefixedCoin,
+) -> Result<(), TokenTransferError> {
+    if !ctx.tracks_total_escrow() {
+        return Ok(());
+    }
+
+    let total = ctx.total_escrow(&coin.denom)?;
+
+    let total =
+        total
+            .checked_add(coin.amount)
+            .ok_or_else(|| TokenTransferError::TotalEscrowOverflow {
+                denom: coin.denom.clone(),
+            })?;
+
+    ctx.store_total_escrow(&coin.denom, total)
+}
+
+/// Subtracts the tokens released from the escrow, either unescrowed or burnt,
+/// from the total escrow of their denomination, if the host tracks it.
+pub(crate) fn subtract_from_total_escrow(
+    ctx: &mut impl TokenTransferExecutionContext,
+    coin: &PrefixedCoin,
+) -> Result<(), TokenTransferError> {
+    match released_total_escrow(ctx, coin)? {
+        Some(total) => ctx.store_total_escrow(&coin.denom, total),
+        None => Ok(()),
+    }
+}
+
+/// Checks the tracked total escrow of the denomination covers the tokens to
+/// release from the escrow, so that it cannot underflow once they moved.
+pub(crate) fn subtract_from_total_escrow_validate(
+    ctx: &impl TokenTransferValidationContext,
+    coin: &PrefixedCoin,
+) -> Result<(), TokenTransferError> {
+    released_total_escrow(ctx, coin).map(|_| ())
+}
+
+/// Returns the total escrow of the denomination once the tokens are released
+/// from the escrow, or none if the host does not track it.
+fn released_total_escrow(
+    ctx: &impl TokenTransferValidationContext,
+    coin: &PrefixedCoin,
+) -> Result<Option<Amount>, TokenTransferError> {
+    if !ctx.tracks_total_escrow() {
+        return Ok(None);
+    }
+
+    ctx.total_escrow(&coin.denom)?
+        .checked_sub(coin.amount)
+        .map(Some)
+        .ok_or_else(|| TokenTransferError::TotalEscrowUnderflow {
+            denom: coin.denom.clone(),
+            amount: coin.amount,
+        })
+}
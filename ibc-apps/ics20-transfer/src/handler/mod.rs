@@ -1,68 +1,219 @@
 //! Implements the processing logic for ICS20 (token transfer) message.
+mod coins;
 mod on_recv_packet;
 mod send_transfer;
 
+pub use coins::*;
 use ibc_app_transfer_types::error::TokenTransferError;
-use ibc_app_transfer_types::is_sender_chain_source;
-use ibc_app_transfer_types::packet::PacketData;
+use ibc_app_transfer_types::packet::{PacketData, PacketDataV2};
+use ibc_app_transfer_types::{is_sender_chain_source, VERSION_V2};
 use ibc_core::channel::types::packet::Packet;
+use ibc_core::channel::types::Version;
+use ibc_core::primitives::prelude::*;
 pub use on_recv_packet::*;
 pub use send_transfer::*;
 
-use crate::context::{TokenTransferExecutionContext, TokenTransferValidationContext};
+use crate::context::{RefundMode, TokenTransferExecutionContext, TokenTransferValidationContext};
+use crate::decimals::to_host_coin;
+use crate::memo::{memo_value, NON_REFUNDABLE_MEMO_KEY};
+
+/// Encodes the packet data to the packet bytes of the channels of the given
+/// version, the channels of version `ics20-1` carrying a single token.
+pub fn encode_packet_data(
+    version: &Version,
+    data: PacketDataV2,
+) -> Result<Vec<u8>, TokenTransferError> {
+    let data = if version.as_str() == VERSION_V2 {
+        serde_json::to_vec(&data).expect("PacketDataV2's infallible Serialize impl failed")
+    } else {
+        serde_json::to_vec(&PacketData::try_from(data)?)
+            .expect("PacketData's infallible Serialize impl failed")
+    };
+
+    Ok(data)
+}
+
+/// Decodes the packet bytes of the channels of the given version, the
+/// channels of version `ics20-1` carrying a single token.
+///
+/// Only the packet data of the version negotiated by the channel are
+/// accepted.
+pub fn decode_packet_data(
+    version: &Version,
+    bytes: &[u8],
+) -> Result<PacketDataV2, TokenTransferError> {
+    let data = if version.as_str() == VERSION_V2 {
+        serde_json::from_slice::<PacketDataV2>(bytes)
+    } else {
+        serde_json::from_slice::<PacketData>(bytes).map(PacketDataV2::from)
+    };
+
+    data.map_err(|_| TokenTransferError::PacketDataDeserialization)
+}
 
 pub fn refund_packet_token_execute(
     ctx_a: &mut impl TokenTransferExecutionContext,
     packet: &Packet,
     data: &PacketData,
 ) -> Result<(), TokenTransferError> {
+    refund_packet_tokens_execute(ctx_a, packet, &data.clone().into())
+}
+
+pub fn refund_packet_token_validate(
+    ctx_a: &impl TokenTransferValidationContext,
+    packet: &Packet,
+    data: &PacketData,
+) -> Result<(), TokenTransferError> {
+    refund_packet_tokens_validate(ctx_a, packet, &data.clone().into())
+}
+
+/// Returns `true` if the tokens of the given packet are burnt instead of
+/// refunded when it fails or times out, as set by the [`RefundMode`] of its
+/// channel.
+pub fn is_non_refundable(
+    ctx_a: &impl TokenTransferValidationContext,
+    packet: &Packet,
+    data: &PacketDataV2,
+) -> Result<bool, TokenTransferError> {
+    let non_refundable = match ctx_a.refund_mode(&packet.port_id_on_a, &packet.chan_id_on_a)? {
+        RefundMode::Refund => false,
+        RefundMode::Burn => true,
+        // An invalid flag does not prevent the refund.
+        RefundMode::Memo => matches!(
+            memo_value::<bool>(&data.memo, NON_REFUNDABLE_MEMO_KEY),
+            Ok(Some(true))
+        ),
+    };
+
+    Ok(non_refundable)
+}
+
+/// Refunds each of the tokens of the packet to its sender, unless the packet
+/// is [non-refundable](is_non_refundable), in which case the escrowed tokens
+/// are burnt.
+pub fn refund_packet_tokens_execute(
+    ctx_a: &mut impl TokenTransferExecutionContext,
+    packet: &Packet,
+    data: &PacketDataV2,
+) -> Result<(), TokenTransferError> {
+    if is_non_refundable(ctx_a, packet, data)? {
+        return burn_escrowed_packet_tokens_execute(ctx_a, packet, data);
+    }
+
     let sender = data
         .sender
         .clone()
         .try_into()
         .map_err(|_| TokenTransferError::ParseAccountFailure)?;
 
-    if is_sender_chain_source(
-        packet.port_id_on_a.clone(),
-        packet.chan_id_on_a.clone(),
-        &data.token.denom,
-    ) {
-        ctx_a.unescrow_coins_execute(
-            &sender,
-            &packet.port_id_on_a,
-            &packet.chan_id_on_a,
-            &data.token,
-        )
-    }
-    // mint vouchers back to sender
-    else {
-        ctx_a.mint_coins_execute(&sender, &data.token)
+    for token in &data.tokens {
+        let token = &to_host_coin(ctx_a, &packet.port_id_on_a, &packet.chan_id_on_a, token)?;
+
+        if is_sender_chain_source(
+            packet.port_id_on_a.clone(),
+            packet.chan_id_on_a.clone(),
+            &token.denom,
+        ) {
+            unescrow_coins_with_hooks(
+                ctx_a,
+                &sender,
+                &packet.port_id_on_a,
+                &packet.chan_id_on_a,
+                token,
+            )?;
+        }
+        // mint vouchers back to sender
+        else {
+            mint_coins_with_hooks(ctx_a, &sender, token)?;
+        }
     }
+
+    Ok(())
 }
 
-pub fn refund_packet_token_validate(
+/// Validates the refund of each of the tokens of the packet to its sender.
+pub fn refund_packet_tokens_validate(
     ctx_a: &impl TokenTransferValidationContext,
     packet: &Packet,
-    data: &PacketData,
+    data: &PacketDataV2,
 ) -> Result<(), TokenTransferError> {
+    if is_non_refundable(ctx_a, packet, data)? {
+        return burn_escrowed_packet_tokens_validate(ctx_a, packet, data);
+    }
+
     let sender = data
         .sender
         .clone()
         .try_into()
         .map_err(|_| TokenTransferError::ParseAccountFailure)?;
 
-    if is_sender_chain_source(
-        packet.port_id_on_a.clone(),
-        packet.chan_id_on_a.clone(),
-        &data.token.denom,
-    ) {
-        ctx_a.unescrow_coins_validate(
-            &sender,
-            &packet.port_id_on_a,
-            &packet.chan_id_on_a,
-            &data.token,
-        )
-    } else {
-        ctx_a.mint_coins_validate(&sender, &data.token)
+    for token in &data.tokens {
+        let token = &to_host_coin(ctx_a, &packet.port_id_on_a, &packet.chan_id_on_a, token)?;
+
+        if is_sender_chain_source(
+            packet.port_id_on_a.clone(),
+            packet.chan_id_on_a.clone(),
+            &token.denom,
+        ) {
+            ctx_a.unescrow_coins_validate(
+                &sender,
+                &packet.port_id_on_a,
+                &packet.chan_id_on_a,
+                token,
+            )?;
+            subtract_from_total_escrow_validate(ctx_a, token)?;
+        } else {
+            ctx_a.mint_coins_validate(&sender, token)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Burns the tokens of the non-refundable packet which were escrowed when
+/// sent. The vouchers were burnt when sent, and are not minted back.
+fn burn_escrowed_packet_tokens_execute(
+    ctx_a: &mut impl TokenTransferExecutionContext,
+    packet: &Packet,
+    data: &PacketDataV2,
+) -> Result<(), TokenTransferError> {
+    for token in &data.tokens {
+        let token = &to_host_coin(ctx_a, &packet.port_id_on_a, &packet.chan_id_on_a, token)?;
+
+        if is_sender_chain_source(
+            packet.port_id_on_a.clone(),
+            packet.chan_id_on_a.clone(),
+            &token.denom,
+        ) {
+            ctx_a.burn_escrowed_coins_execute(&packet.port_id_on_a, &packet.chan_id_on_a, token)?;
+            subtract_from_total_escrow(ctx_a, token)?;
+        }
     }
+
+    Ok(())
+}
+
+fn burn_escrowed_packet_tokens_validate(
+    ctx_a: &impl TokenTransferValidationContext,
+    packet: &Packet,
+    data: &PacketDataV2,
+) -> Result<(), TokenTransferError> {
+    for token in &data.tokens {
+        let token = &to_host_coin(ctx_a, &packet.port_id_on_a, &packet.chan_id_on_a, token)?;
+
+        if is_sender_chain_source(
+            packet.port_id_on_a.clone(),
+            packet.chan_id_on_a.clone(),
+            &token.denom,
+        ) {
+            ctx_a.burn_escrowed_coins_validate(
+                &packet.port_id_on_a,
+                &packet.chan_id_on_a,
+                token,
+            )?;
+            subtract_from_total_escrow_validate(ctx_a, token)?;
+        }
+    }
+
+    Ok(())
 }
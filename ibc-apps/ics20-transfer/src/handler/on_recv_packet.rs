@@ -1,12 +1,19 @@
 use ibc_app_transfer_types::error::TokenTransferError;
 use ibc_app_transfer_types::events::DenomTraceEvent;
-use ibc_app_transfer_types::packet::PacketData;
-use ibc_app_transfer_types::{is_receiver_chain_source, TracePrefix};
+use ibc_app_transfer_types::packet::{PacketData, PacketDataV2};
+use ibc_app_transfer_types::{is_receiver_chain_source, PrefixedCoin, TracePrefix};
 use ibc_core::channel::types::packet::Packet;
 use ibc_core::primitives::prelude::*;
+use ibc_core::router::types::event::ModuleEvent;
 use ibc_core::router::types::module::ModuleExtras;
 
+use super::{
+    mint_coins_with_hooks, subtract_from_total_escrow_validate, unescrow_coins_with_hooks,
+};
 use crate::context::TokenTransferExecutionContext;
+use crate::decimals::to_host_coin;
+use crate::params::receive_enabled_validate;
+use crate::policy::receive_policy_validate;
 
 /// This function handles the transfer receiving logic.
 ///
@@ -18,6 +25,52 @@ pub fn process_recv_packet_execute<Ctx: TokenTransferExecutionContext>(
     ctx_b: &mut Ctx,
     packet: &Packet,
     data: PacketData,
+) -> Result<ModuleExtras, (ModuleExtras, TokenTransferError)> {
+    process_multi_token_recv_packet_execute(ctx_b, packet, data.into())
+}
+
+/// Returns the given token of a received packet, as denominated on the
+/// receiving chain.
+pub fn received_coin(packet: &Packet, token: &PrefixedCoin) -> PrefixedCoin {
+    let mut coin = token.clone();
+
+    // The prefix of the sender is removed if the receiver chain is the source
+    // of the token, and the prefix of the receiver is added otherwise.
+    if !coin
+        .denom
+        .try_remove_trace_prefix(&packet.port_id_on_a, &packet.chan_id_on_a)
+    {
+        let prefix = TracePrefix::new(packet.port_id_on_b.clone(), packet.chan_id_on_b.clone());
+        coin.denom.add_trace_prefix(prefix);
+    }
+
+    coin
+}
+
+/// How a token of a received packet is credited to the receiver.
+enum ReceivedToken {
+    /// The receiver chain is the source of the token, which is unescrowed.
+    Unescrowed(PrefixedCoin),
+    /// The sender chain is the source of the token, whose vouchers are minted.
+    Minted(PrefixedCoin),
+}
+
+impl ReceivedToken {
+    fn coin(&self) -> &PrefixedCoin {
+        match self {
+            Self::Unescrowed(coin) | Self::Minted(coin) => coin,
+        }
+    }
+}
+
+/// Handles the receiving logic of the transfers of several tokens.
+///
+/// All the tokens are validated before any of them is credited to the
+/// receiver, so that the packet is either received as a whole or not at all.
+pub fn process_multi_token_recv_packet_execute<Ctx: TokenTransferExecutionContext>(
+    ctx_b: &mut Ctx,
+    packet: &Packet,
+    data: PacketDataV2,
 ) -> Result<ModuleExtras, (ModuleExtras, TokenTransferError)> {
     ctx_b
         .can_receive_coins()
@@ -30,87 +83,102 @@ pub fn process_recv_packet_execute<Ctx: TokenTransferExecutionContext>(
         )
     })?;
 
-    let extras = if is_receiver_chain_source(
-        packet.port_id_on_a.clone(),
-        packet.chan_id_on_a.clone(),
-        &data.token.denom,
-    ) {
-        // sender chain is not the source, unescrow tokens
-        let prefix = TracePrefix::new(packet.port_id_on_a.clone(), packet.chan_id_on_a.clone());
-        let coin = {
-            let mut c = data.token;
-            c.denom.remove_trace_prefix(&prefix);
-            c
-        };
-
-        // Note: it is correct to do the validation here because `recv_packet()`
-        // works slightly differently. We do not have a
-        // `on_recv_packet_validate()` callback because regardless of whether or
-        // not the app succeeds to receive the packet, we want to run the
-        // `execute()` phase. And this is because the app failing to receive
-        // does not constitute a failure of the message processing.
-        // Specifically, when the app fails to receive, we need to return
-        // a `TokenTransferAcknowledgement::Error` acknowledgement, which
-        // gets relayed back to the sender so that the escrowed tokens
-        // can be refunded.
-        ctx_b
-            .unescrow_coins_validate(
-                &receiver_account,
-                &packet.port_id_on_b,
-                &packet.chan_id_on_b,
-                &coin,
-            )
-            .map_err(|token_err| (ModuleExtras::empty(), token_err))?;
-        ctx_b
-            .unescrow_coins_execute(
-                &receiver_account,
-                &packet.port_id_on_b,
-                &packet.chan_id_on_b,
-                &coin,
-            )
-            .map_err(|token_err| (ModuleExtras::empty(), token_err))?;
+    // The tokens are converted to the precision of the host once denominated
+    // on it.
+    let received_tokens = data
+        .tokens
+        .into_iter()
+        .map(|token| {
+            let coin = received_coin(packet, &token);
+            let coin = to_host_coin(ctx_b, &packet.port_id_on_b, &packet.chan_id_on_b, &coin)?;
 
-        ModuleExtras::empty()
-    } else {
-        // sender chain is the source, mint vouchers
-        let prefix = TracePrefix::new(packet.port_id_on_b.clone(), packet.chan_id_on_b.clone());
-        let coin = {
-            let mut c = data.token;
-            c.denom.add_trace_prefix(prefix);
-            c
-        };
-
-        let extras = {
-            let denom_trace_event = DenomTraceEvent {
-                trace_hash: ctx_b.denom_hash_string(&coin.denom),
-                denom: coin.denom.clone(),
-            };
-            ModuleExtras {
-                events: vec![denom_trace_event.into()],
-                log: Vec::new(),
+            if is_receiver_chain_source(
+                packet.port_id_on_a.clone(),
+                packet.chan_id_on_a.clone(),
+                &token.denom,
+            ) {
+                // sender chain is not the source, unescrow tokens
+                Ok(ReceivedToken::Unescrowed(coin))
+            } else {
+                // sender chain is the source, mint vouchers
+                Ok(ReceivedToken::Minted(coin))
             }
-        };
-
-        // Note: it is correct to do the validation here because `recv_packet()`
-        // works slightly differently. We do not have a
-        // `on_recv_packet_validate()` callback because regardless of whether or
-        // not the app succeeds to receive the packet, we want to run the
-        // `execute()` phase. And this is because the app failing to receive
-        // does not constitute a failure of the message processing.
-        // Specifically, when the app fails to receive, we need to return
-        // a `TokenTransferAcknowledgement::Error` acknowledgement, which
-        // gets relayed back to the sender so that the escrowed tokens
-        // can be refunded.
-        ctx_b
-            .mint_coins_validate(&receiver_account, &coin)
-            .map_err(|token_err| (extras.clone(), token_err))?;
-
-        ctx_b
-            .mint_coins_execute(&receiver_account, &coin)
-            .map_err(|token_err| (extras.clone(), token_err))?;
-
-        extras
+        })
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|err| (ModuleExtras::empty(), err))?;
+
+    receive_enabled_validate(ctx_b, received_tokens.iter().map(ReceivedToken::coin))
+        .map_err(|err| (ModuleExtras::empty(), err))?;
+
+    receive_policy_validate(
+        ctx_b,
+        &packet.port_id_on_b,
+        &packet.chan_id_on_b,
+        received_tokens.iter().map(ReceivedToken::coin),
+    )
+    .map_err(|err| (ModuleExtras::empty(), err))?;
+
+    let extras = {
+        let events: Vec<ModuleEvent> = received_tokens
+            .iter()
+            .filter_map(|received_token| match received_token {
+                ReceivedToken::Minted(coin) => Some(
+                    DenomTraceEvent {
+                        trace_hash: ctx_b.denom_hash_string(&coin.denom),
+                        denom: coin.denom.clone(),
+                    }
+                    .into(),
+                ),
+                ReceivedToken::Unescrowed(_) => None,
+            })
+            .collect();
+
+        ModuleExtras {
+            events,
+            log: Vec::new(),
+        }
     };
 
+    // Note: it is correct to do the validation here because `recv_packet()`
+    // works slightly differently. We do not have a
+    // `on_recv_packet_validate()` callback because regardless of whether or
+    // not the app succeeds to receive the packet, we want to run the
+    // `execute()` phase. And this is because the app failing to receive
+    // does not constitute a failure of the message processing.
+    // Specifically, when the app fails to receive, we need to return
+    // a `TokenTransferAcknowledgement::Error` acknowledgement, which
+    // gets relayed back to the sender so that the escrowed tokens
+    // can be refunded.
+    for received_token in &received_tokens {
+        match received_token {
+            ReceivedToken::Unescrowed(coin) => ctx_b
+                .unescrow_coins_validate(
+                    &receiver_account,
+                    &packet.port_id_on_b,
+                    &packet.chan_id_on_b,
+                    coin,
+                )
+                .and_then(|()| subtract_from_total_escrow_validate(ctx_b, coin)),
+            ReceivedToken::Minted(coin) => ctx_b.mint_coins_validate(&receiver_account, coin),
+        }
+        .map_err(|token_err| (extras.clone(), token_err))?;
+    }
+
+    for received_token in &received_tokens {
+        match received_token {
+            ReceivedToken::Unescrowed(coin) => unescrow_coins_with_hooks(
+                ctx_b,
+                &receiver_account,
+                &packet.port_id_on_b,
+                &packet.chan_id_on_b,
+                coin,
+            ),
+            ReceivedToken::Minted(coin) => ctx_b
+                .store_denom_trace(&coin.denom)
+                .and_then(|()| mint_coins_with_hooks(ctx_b, &receiver_account, coin)),
+        }
+        .map_err(|token_err| (extras.clone(), token_err))?;
+    }
+
     Ok(extras)
 }
@@ -1,5 +1,6 @@
 use ibc_app_transfer_types::error::TokenTransferError;
 use ibc_app_transfer_types::events::TransferEvent;
+use ibc_app_transfer_types::msgs::multi_token_transfer::MsgMultiTokenTransfer;
 use ibc_app_transfer_types::msgs::transfer::MsgTransfer;
 use ibc_app_transfer_types::{is_sender_chain_source, MODULE_ID_STR};
 use ibc_core::channel::context::{SendPacketExecutionContext, SendPacketValidationContext};
@@ -10,7 +11,11 @@ use ibc_core::host::types::path::{ChannelEndPath, SeqSendPath};
 use ibc_core::primitives::prelude::*;
 use ibc_core::router::types::event::ModuleEvent;
 
+use super::{burn_coins_with_hooks, encode_packet_data, escrow_coins_with_hooks};
 use crate::context::{TokenTransferExecutionContext, TokenTransferValidationContext};
+use crate::decimals::to_packet_coin;
+use crate::params::send_enabled_validate;
+use crate::policy::send_policy_validate;
 
 /// Initiate a token transfer. Equivalent to calling [`send_transfer_validate`], followed by [`send_transfer_execute`].
 pub fn send_transfer<SendPacketCtx, TokenCtx>(
@@ -32,11 +37,61 @@ pub fn send_transfer_validate<SendPacketCtx, TokenCtx>(
     token_ctx_a: &TokenCtx,
     msg: MsgTransfer,
 ) -> Result<(), TokenTransferError>
+where
+    SendPacketCtx: SendPacketValidationContext,
+    TokenCtx: TokenTransferValidationContext,
+{
+    send_multi_token_transfer_validate(send_packet_ctx_a, token_ctx_a, msg.into())
+}
+
+/// Executes the token transfer. A prior call to [`send_transfer_validate`] MUST have succeeded.
+pub fn send_transfer_execute<SendPacketCtx, TokenCtx>(
+    send_packet_ctx_a: &mut SendPacketCtx,
+    token_ctx_a: &mut TokenCtx,
+    msg: MsgTransfer,
+) -> Result<(), TokenTransferError>
+where
+    SendPacketCtx: SendPacketExecutionContext,
+    TokenCtx: TokenTransferExecutionContext,
+{
+    send_multi_token_transfer_execute(send_packet_ctx_a, token_ctx_a, msg.into())
+}
+
+/// Initiate a token transfer of several tokens. Equivalent to calling
+/// [`send_multi_token_transfer_validate`], followed by
+/// [`send_multi_token_transfer_execute`].
+pub fn send_multi_token_transfer<SendPacketCtx, TokenCtx>(
+    send_packet_ctx_a: &mut SendPacketCtx,
+    token_ctx_a: &mut TokenCtx,
+    msg: MsgMultiTokenTransfer,
+) -> Result<(), TokenTransferError>
+where
+    SendPacketCtx: SendPacketExecutionContext,
+    TokenCtx: TokenTransferExecutionContext,
+{
+    send_multi_token_transfer_validate(send_packet_ctx_a, token_ctx_a, msg.clone())?;
+    send_multi_token_transfer_execute(send_packet_ctx_a, token_ctx_a, msg)
+}
+
+/// Validates the token transfer of several tokens. If this succeeds, then it
+/// is legal to initiate the transfer with [`send_multi_token_transfer_execute`].
+///
+/// The packet data is encoded according to the version of the channel, the
+/// channels of version `ics20-1` only accepting the transfers of a single
+/// token.
+pub fn send_multi_token_transfer_validate<SendPacketCtx, TokenCtx>(
+    send_packet_ctx_a: &SendPacketCtx,
+    token_ctx_a: &TokenCtx,
+    mut msg: MsgMultiTokenTransfer,
+) -> Result<(), TokenTransferError>
 where
     SendPacketCtx: SendPacketValidationContext,
     TokenCtx: TokenTransferValidationContext,
 {
     token_ctx_a.can_send_coins()?;
+    send_enabled_validate(token_ctx_a, &msg.packet_data.tokens)?;
+
+    msg.packet_data.validate_tokens()?;
 
     let chan_end_path_on_a = ChannelEndPath::new(&msg.port_id_on_a, &msg.chan_id_on_a);
     let chan_end_on_a = send_packet_ctx_a.channel_end(&chan_end_path_on_a)?;
@@ -54,8 +109,6 @@ where
     let seq_send_path_on_a = SeqSendPath::new(&msg.port_id_on_a, &msg.chan_id_on_a);
     let sequence = send_packet_ctx_a.get_next_sequence_send(&seq_send_path_on_a)?;
 
-    let token = &msg.packet_data.token;
-
     let sender: TokenCtx::AccountId = msg
         .packet_data
         .sender
@@ -63,25 +116,45 @@ where
         .try_into()
         .map_err(|_| TokenTransferError::ParseAccountFailure)?;
 
-    if is_sender_chain_source(
-        msg.port_id_on_a.clone(),
-        msg.chan_id_on_a.clone(),
-        &token.denom,
-    ) {
-        token_ctx_a.escrow_coins_validate(
-            &sender,
-            &msg.port_id_on_a,
-            &msg.chan_id_on_a,
-            token,
-            &msg.packet_data.memo,
-        )?;
-    } else {
-        token_ctx_a.burn_coins_validate(&sender, token, &msg.packet_data.memo)?;
+    send_policy_validate(
+        token_ctx_a,
+        &msg.port_id_on_a,
+        &msg.chan_id_on_a,
+        &msg.packet_data.tokens,
+    )?;
+
+    // The packet carries the tokens in its precision, and the remainders
+    // which do not convert to it stay with the sender.
+    let (packet_tokens, moved_tokens): (Vec<_>, Vec<_>) = msg
+        .packet_data
+        .tokens
+        .iter()
+        .map(|token| to_packet_coin(token_ctx_a, &msg.port_id_on_a, &msg.chan_id_on_a, token))
+        .collect::<Result<Vec<_>, _>>()?
+        .into_iter()
+        .unzip();
+    msg.packet_data.tokens = packet_tokens;
+
+    for token in &moved_tokens {
+        if is_sender_chain_source(
+            msg.port_id_on_a.clone(),
+            msg.chan_id_on_a.clone(),
+            &token.denom,
+        ) {
+            token_ctx_a.escrow_coins_validate(
+                &sender,
+                &msg.port_id_on_a,
+                &msg.chan_id_on_a,
+                token,
+                &msg.packet_data.memo,
+            )?;
+        } else {
+            token_ctx_a.burn_coins_validate(&sender, token, &msg.packet_data.memo)?;
+        }
     }
 
     let packet = {
-        let data = serde_json::to_vec(&msg.packet_data)
-            .expect("PacketData's infallible Serialize impl failed");
+        let data = encode_packet_data(chan_end_on_a.version(), msg.packet_data)?;
 
         Packet {
             seq_on_a: sequence,
@@ -100,11 +173,12 @@ where
     Ok(())
 }
 
-/// Executes the token transfer. A prior call to [`send_transfer_validate`] MUST have succeeded.
-pub fn send_transfer_execute<SendPacketCtx, TokenCtx>(
+/// Executes the token transfer of several tokens. A prior call to
+/// [`send_multi_token_transfer_validate`] MUST have succeeded.
+pub fn send_multi_token_transfer_execute<SendPacketCtx, TokenCtx>(
     send_packet_ctx_a: &mut SendPacketCtx,
     token_ctx_a: &mut TokenCtx,
-    msg: MsgTransfer,
+    mut msg: MsgMultiTokenTransfer,
 ) -> Result<(), TokenTransferError>
 where
     SendPacketCtx: SendPacketExecutionContext,
@@ -127,8 +201,6 @@ where
     let seq_send_path_on_a = SeqSendPath::new(&msg.port_id_on_a, &msg.chan_id_on_a);
     let sequence = send_packet_ctx_a.get_next_sequence_send(&seq_send_path_on_a)?;
 
-    let token = &msg.packet_data.token;
-
     let sender = msg
         .packet_data
         .sender
@@ -136,27 +208,39 @@ where
         .try_into()
         .map_err(|_| TokenTransferError::ParseAccountFailure)?;
 
-    if is_sender_chain_source(
-        msg.port_id_on_a.clone(),
-        msg.chan_id_on_a.clone(),
-        &token.denom,
-    ) {
-        token_ctx_a.escrow_coins_execute(
-            &sender,
-            &msg.port_id_on_a,
-            &msg.chan_id_on_a,
-            token,
-            &msg.packet_data.memo,
-        )?;
-    } else {
-        token_ctx_a.burn_coins_execute(&sender, token, &msg.packet_data.memo)?;
+    // The packet carries the tokens in its precision, and the remainders
+    // which do not convert to it stay with the sender.
+    let (packet_tokens, moved_tokens): (Vec<_>, Vec<_>) = msg
+        .packet_data
+        .tokens
+        .iter()
+        .map(|token| to_packet_coin(token_ctx_a, &msg.port_id_on_a, &msg.chan_id_on_a, token))
+        .collect::<Result<Vec<_>, _>>()?
+        .into_iter()
+        .unzip();
+    msg.packet_data.tokens = packet_tokens;
+
+    for token in &moved_tokens {
+        if is_sender_chain_source(
+            msg.port_id_on_a.clone(),
+            msg.chan_id_on_a.clone(),
+            &token.denom,
+        ) {
+            escrow_coins_with_hooks(
+                token_ctx_a,
+                &sender,
+                &msg.port_id_on_a,
+                &msg.chan_id_on_a,
+                token,
+                &msg.packet_data.memo,
+            )?;
+        } else {
+            burn_coins_with_hooks(token_ctx_a, &sender, token, &msg.packet_data.memo)?;
+        }
     }
 
     let packet = {
-        let data = {
-            serde_json::to_vec(&msg.packet_data)
-                .expect("PacketData's infallible Serialize impl failed")
-        };
+        let data = encode_packet_data(chan_end_on_a.version(), msg.packet_data.clone())?;
 
         Packet {
             seq_on_a: sequence,
@@ -173,19 +257,29 @@ where
     send_packet_execute(send_packet_ctx_a, packet)?;
 
     {
+        let tokens = msg
+            .packet_data
+            .tokens
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>()
+            .join(",");
+
         send_packet_ctx_a.log_message(format!(
             "IBC fungible token transfer: {} --({})--> {}",
-            msg.packet_data.sender, token, msg.packet_data.receiver
+            msg.packet_data.sender, tokens, msg.packet_data.receiver
         ))?;
 
-        let transfer_event = TransferEvent {
-            sender: msg.packet_data.sender,
-            receiver: msg.packet_data.receiver,
-            amount: msg.packet_data.token.amount,
-            denom: msg.packet_data.token.denom,
-            memo: msg.packet_data.memo,
-        };
-        send_packet_ctx_a.emit_ibc_event(ModuleEvent::from(transfer_event).into())?;
+        for token in msg.packet_data.tokens {
+            let transfer_event = TransferEvent {
+                sender: msg.packet_data.sender.clone(),
+                receiver: msg.packet_data.receiver.clone(),
+                amount: token.amount,
+                denom: token.denom,
+                memo: msg.packet_data.memo.clone(),
+            };
+            send_packet_ctx_a.emit_ibc_event(ModuleEvent::from(transfer_event).into())?;
+        }
 
         send_packet_ctx_a.emit_ibc_event(MessageEvent::Module(MODULE_ID_STR.to_string()).into())?;
     }
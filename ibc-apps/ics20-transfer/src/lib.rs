@@ -24,8 +24,19 @@ pub mod types {
     pub use ibc_app_transfer_types::*;
 }
 
+#[cfg(feature = "serde")]
+pub mod authorization;
 pub mod context;
+pub mod decimals;
+pub mod denom_trace;
+#[cfg(feature = "serde")]
+pub mod forward;
 #[cfg(feature = "serde")]
 pub mod handler;
 #[cfg(feature = "serde")]
+pub mod memo;
+#[cfg(feature = "serde")]
 pub mod module;
+pub mod params;
+pub mod policy;
+pub mod total_escrow;
@@ -0,0 +1,326 @@
+//! Parses the JSON memos of the token transfers, and dispatches the values of
+//! their top-level keys to the handlers registered for them, as middlewares
+//! such as the packet forwarding or the callbacks expect.
+//!
+//! Memos which are not JSON objects are plain text memos, which carry no
+//! instructions for the handlers, and the keys with no registered handler are
+//! ignored.
+
+use ibc_app_transfer_types::error::TokenTransferError;
+use ibc_app_transfer_types::packet::PacketDataV2;
+use ibc_app_transfer_types::Memo;
+use ibc_core::channel::types::acknowledgement::AcknowledgementStatus;
+use ibc_core::channel::types::packet::Packet;
+use ibc_core::primitives::prelude::*;
+use ibc_core::router::types::module::ModuleExtras;
+use serde::de::{DeserializeOwned, IgnoredAny};
+
+/// The memo key of the contract calls of the wasm hooks.
+pub const WASM_MEMO_KEY: &str = "wasm";
+
+/// The memo key of the packet forwarding middleware.
+pub const FORWARD_MEMO_KEY: &str = "forward";
+
+/// The memo key of the callbacks middleware.
+pub const CALLBACK_MEMO_KEY: &str = "callback";
+
+/// The memo key flagging the transfers as non-refundable, with a `true`
+/// value, over the channels of [`RefundMode::Memo`](crate::context::RefundMode::Memo).
+pub const NON_REFUNDABLE_MEMO_KEY: &str = "non_refundable";
+
+/// Returns the top-level keys of the given memo, in lexicographic order, or
+/// none if the memo is not a JSON object.
+pub fn memo_keys(memo: &Memo) -> Vec<String> {
+    serde_json::from_str::<BTreeMap<String, IgnoredAny>>(memo.as_ref())
+        .map(|keys| keys.into_keys().collect())
+        .unwrap_or_default()
+}
+
+/// Returns the value of the given top-level key of the memo, if the memo is a
+/// JSON object which has the key.
+pub fn memo_value<T: DeserializeOwned>(
+    memo: &Memo,
+    key: &str,
+) -> Result<Option<T>, TokenTransferError> {
+    let Ok(mut entries) = serde_json::from_str::<BTreeMap<String, MemoEntry<T>>>(memo.as_ref())
+    else {
+        return Ok(None);
+    };
+
+    match entries.remove(key) {
+        Some(MemoEntry::Value(value)) => Ok(Some(value)),
+        Some(MemoEntry::Other(_)) => Err(TokenTransferError::InvalidMemo {
+            key: key.to_string(),
+        }),
+        None => Ok(None),
+    }
+}
+
+/// The value of a top-level key of a memo, which is deserialized to the
+/// value of the key looked up, unless it is the value of another key.
+#[derive(serde::Deserialize)]
+#[serde(untagged)]
+enum MemoEntry<T> {
+    Value(T),
+    Other(IgnoredAny),
+}
+
+/// Handles the value of a top-level key of the memos of the token transfers,
+/// which the handler deserializes with [`memo_value`].
+///
+/// The handlers are called once the transfer application processed the
+/// packet, and only for the packets whose memo has their key.
+pub trait MemoHandler<Ctx> {
+    fn on_recv_packet_execute(
+        &self,
+        ctx: &mut Ctx,
+        packet: &Packet,
+        data: &PacketDataV2,
+    ) -> Result<ModuleExtras, TokenTransferError>;
+
+    fn on_acknowledgement_packet_execute(
+        &self,
+        _ctx: &mut Ctx,
+        _packet: &Packet,
+        _data: &PacketDataV2,
+        _acknowledgement: &AcknowledgementStatus,
+    ) -> Result<ModuleExtras, TokenTransferError> {
+        Ok(ModuleExtras::empty())
+    }
+
+    fn on_timeout_packet_execute(
+        &self,
+        _ctx: &mut Ctx,
+        _packet: &Packet,
+        _data: &PacketDataV2,
+    ) -> Result<ModuleExtras, TokenTransferError> {
+        Ok(ModuleExtras::empty())
+    }
+}
+
+/// Registry of the handlers of the memo keys, which dispatches the memos of
+/// the token transfers to the handlers of their keys.
+///
+/// The handlers are called in the lexicographic order of their keys, and the
+/// extras they return are merged.
+pub struct MemoRouter<Ctx> {
+    handlers: BTreeMap<String, Box<dyn MemoHandler<Ctx>>>,
+}
+
+impl<Ctx> Default for MemoRouter<Ctx> {
+    fn default() -> Self {
+        Self {
+            handlers: BTreeMap::new(),
+        }
+    }
+}
+
+impl<Ctx> MemoRouter<Ctx> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers the handler of the given memo key, with a single handler per
+    /// key.
+    pub fn add_handler(
+        &mut self,
+        key: impl Into<String>,
+        handler: impl MemoHandler<Ctx> + 'static,
+    ) -> Result<(), TokenTransferError> {
+        let key = key.into();
+
+        if self.handlers.contains_key(&key) {
+            return Err(TokenTransferError::DuplicateMemoHandler { key });
+        }
+
+        self.handlers.insert(key, Box::new(handler));
+
+        Ok(())
+    }
+
+    /// Returns `true` if a handler is registered for the given memo key.
+    pub fn has_handler(&self, key: &str) -> bool {
+        self.handlers.contains_key(key)
+    }
+
+    /// Returns the handlers of the keys of the given memo.
+    fn handlers_of<'a>(
+        &'a self,
+        memo: &Memo,
+    ) -> impl Iterator<Item = &'a dyn MemoHandler<Ctx>> + 'a {
+        memo_keys(memo)
+            .into_iter()
+            .filter_map(|key| self.handlers.get(&key))
+            .map(|handler| handler.as_ref())
+    }
+
+    pub fn on_recv_packet_execute(
+        &self,
+        ctx: &mut Ctx,
+        packet: &Packet,
+        data: &PacketDataV2,
+    ) -> Result<ModuleExtras, TokenTransferError> {
+        let mut extras = ModuleExtras::empty();
+
+        for handler in self.handlers_of(&data.memo) {
+            merge_extras(
+                &mut extras,
+                handler.on_recv_packet_execute(ctx, packet, data)?,
+            );
+        }
+
+        Ok(extras)
+    }
+
+    pub fn on_acknowledgement_packet_execute(
+        &self,
+        ctx: &mut Ctx,
+        packet: &Packet,
+        data: &PacketDataV2,
+        acknowledgement: &AcknowledgementStatus,
+    ) -> Result<ModuleExtras, TokenTransferError> {
+        let mut extras = ModuleExtras::empty();
+
+        for handler in self.handlers_of(&data.memo) {
+            merge_extras(
+                &mut extras,
+                handler.on_acknowledgement_packet_execute(ctx, packet, data, acknowledgement)?,
+            );
+        }
+
+        Ok(extras)
+    }
+
+    pub fn on_timeout_packet_execute(
+        &self,
+        ctx: &mut Ctx,
+        packet: &Packet,
+        data: &PacketDataV2,
+    ) -> Result<ModuleExtras, TokenTransferError> {
+        let mut extras = ModuleExtras::empty();
+
+        for handler in self.handlers_of(&data.memo) {
+            merge_extras(
+                &mut extras,
+                handler.on_timeout_packet_execute(ctx, packet, data)?,
+            );
+        }
+
+        Ok(extras)
+    }
+}
+
+fn merge_extras(extras: &mut ModuleExtras, other: ModuleExtras) {
+    extras.events.extend(other.events);
+    extras.log.extend(other.log);
+}
+
+#[cfg(test)]
+mod tests {
+    use ibc_core::channel::types::timeout::TimeoutHeight;
+    use ibc_core::host::types::identifiers::{ChannelId, PortId};
+    use ibc_core::primitives::Timestamp;
+
+    use super::*;
+
+    #[derive(Debug, PartialEq, Eq, serde::Deserialize)]
+    struct ForwardMemo {
+        receiver: String,
+        port: String,
+        channel: String,
+    }
+
+    const MEMO: &str = r#"{"wasm":{"contract":"cosmos1contract","msg":{"a":[1,2]}},"forward":{"receiver":"cosmos1receiver","port":"transfer","channel":"channel-1"}}"#;
+
+    /// Records the memo values it handled.
+    struct ForwardHandler;
+
+    impl MemoHandler<Vec<ForwardMemo>> for ForwardHandler {
+        fn on_recv_packet_execute(
+            &self,
+            ctx: &mut Vec<ForwardMemo>,
+            _packet: &Packet,
+            data: &PacketDataV2,
+        ) -> Result<ModuleExtras, TokenTransferError> {
+            if let Some(value) = memo_value(&data.memo, FORWARD_MEMO_KEY)? {
+                ctx.push(value);
+            }
+
+            Ok(ModuleExtras::empty())
+        }
+    }
+
+    fn dummy_packet_data(memo: &str) -> PacketDataV2 {
+        PacketDataV2 {
+            tokens: vec!["10uatom".parse().unwrap()],
+            sender: "sender".to_string().into(),
+            receiver: "receiver".to_string().into(),
+            memo: memo.into(),
+        }
+    }
+
+    fn dummy_packet() -> Packet {
+        Packet {
+            seq_on_a: 1.into(),
+            port_id_on_a: PortId::transfer(),
+            chan_id_on_a: ChannelId::zero(),
+            port_id_on_b: PortId::transfer(),
+            chan_id_on_b: ChannelId::new(1),
+            data: Vec::new(),
+            timeout_height_on_b: TimeoutHeight::Never,
+            timeout_timestamp_on_b: Timestamp::none(),
+        }
+    }
+
+    #[test]
+    fn test_memo_keys_and_values() {
+        assert_eq!(memo_keys(&MEMO.into()), vec!["forward", "wasm"]);
+
+        // Plain text memos have no keys.
+        assert!(memo_keys(&"a plain memo".into()).is_empty());
+        assert!(memo_keys(&"".into()).is_empty());
+        assert!(memo_keys(&"[1, 2]".into()).is_empty());
+
+        assert_eq!(
+            memo_value::<ForwardMemo>(&MEMO.into(), FORWARD_MEMO_KEY).unwrap(),
+            Some(ForwardMemo {
+                receiver: "cosmos1receiver".to_string(),
+                port: "transfer".to_string(),
+                channel: "channel-1".to_string(),
+            })
+        );
+        assert_eq!(
+            memo_value::<ForwardMemo>(&MEMO.into(), CALLBACK_MEMO_KEY).unwrap(),
+            None
+        );
+        assert!(matches!(
+            memo_value::<ForwardMemo>(&MEMO.into(), WASM_MEMO_KEY),
+            Err(TokenTransferError::InvalidMemo { .. })
+        ));
+    }
+
+    #[test]
+    fn test_memo_router_dispatch() {
+        let mut router = MemoRouter::new();
+        router
+            .add_handler(FORWARD_MEMO_KEY, ForwardHandler)
+            .unwrap();
+
+        assert!(router.has_handler(FORWARD_MEMO_KEY));
+        assert!(router
+            .add_handler(FORWARD_MEMO_KEY, ForwardHandler)
+            .is_err());
+
+        let mut ctx = Vec::new();
+
+        // The `wasm` key has no handler, and is ignored.
+        for memo in [MEMO, "a plain memo"] {
+            router
+                .on_recv_packet_execute(&mut ctx, &dummy_packet(), &dummy_packet_data(memo))
+                .unwrap();
+        }
+
+        assert_eq!(ctx.len(), 1);
+        assert_eq!(ctx[0].channel, "channel-1");
+    }
+}
@@ -1,22 +1,36 @@
 use ibc_app_transfer_types::error::TokenTransferError;
 use ibc_app_transfer_types::events::{AckEvent, AckStatusEvent, RecvEvent, TimeoutEvent};
-use ibc_app_transfer_types::packet::PacketData;
-use ibc_app_transfer_types::{ack_success_b64, VERSION};
-use ibc_core::channel::types::acknowledgement::{Acknowledgement, AcknowledgementStatus};
+use ibc_app_transfer_types::packet::PacketDataV2;
+use ibc_app_transfer_types::{ack_success_b64, is_supported_version, VERSION};
+use ibc_core::channel::types::acknowledgement::{
+    Acknowledgement, AcknowledgementStatus, StatusValue,
+};
 use ibc_core::channel::types::channel::{Counterparty, Order};
 use ibc_core::channel::types::packet::Packet;
 use ibc_core::channel::types::Version;
-use ibc_core::handler::types::error::ContextError;
 use ibc_core::host::types::identifiers::{ChannelId, ConnectionId, PortId};
 use ibc_core::primitives::prelude::*;
 use ibc_core::primitives::Signer;
+use ibc_core::router::types::event::ModuleEvent;
 use ibc_core::router::types::module::ModuleExtras;
 
 use crate::context::{TokenTransferExecutionContext, TokenTransferValidationContext};
 use crate::handler::{
-    process_recv_packet_execute, refund_packet_token_execute, refund_packet_token_validate,
+    decode_packet_data, process_multi_token_recv_packet_execute, refund_packet_tokens_execute,
+    refund_packet_tokens_validate,
 };
 
+/// Returns an error if the given channel version is not supported.
+fn verify_version_supported(version: &Version) -> Result<(), TokenTransferError> {
+    if !is_supported_version(version) {
+        return Err(TokenTransferError::UnsupportedVersion {
+            version: version.clone(),
+        });
+    }
+
+    Ok(())
+}
+
 pub fn on_chan_open_init_validate(
     ctx: &impl TokenTransferValidationContext,
     order: Order,
@@ -41,9 +55,7 @@ pub fn on_chan_open_init_validate(
     }
 
     if !version.is_empty() {
-        version
-            .verify_is_expected(Version::new(VERSION.to_string()))
-            .map_err(ContextError::from)?;
+        verify_version_supported(version)?;
     }
 
     Ok(())
@@ -56,9 +68,17 @@ pub fn on_chan_open_init_execute(
     _port_id: &PortId,
     _channel_id: &ChannelId,
     _counterparty: &Counterparty,
-    _version: &Version,
+    version: &Version,
 ) -> Result<(ModuleExtras, Version), TokenTransferError> {
-    Ok((ModuleExtras::empty(), Version::new(VERSION.to_string())))
+    // Channels default to the version of the single token transfers, which
+    // all the counterparties support.
+    let version = if version.is_empty() {
+        Version::new(VERSION.to_string())
+    } else {
+        version.clone()
+    };
+
+    Ok((ModuleExtras::empty(), version))
 }
 
 pub fn on_chan_open_try_validate(
@@ -77,9 +97,7 @@ pub fn on_chan_open_try_validate(
         });
     }
 
-    counterparty_version
-        .verify_is_expected(Version::new(VERSION.to_string()))
-        .map_err(ContextError::from)?;
+    verify_version_supported(counterparty_version)?;
 
     Ok(())
 }
@@ -91,9 +109,9 @@ pub fn on_chan_open_try_execute(
     _port_id: &PortId,
     _channel_id: &ChannelId,
     _counterparty: &Counterparty,
-    _counterparty_version: &Version,
+    counterparty_version: &Version,
 ) -> Result<(ModuleExtras, Version), TokenTransferError> {
-    Ok((ModuleExtras::empty(), Version::new(VERSION.to_string())))
+    Ok((ModuleExtras::empty(), counterparty_version.clone()))
 }
 
 pub fn on_chan_open_ack_validate(
@@ -102,9 +120,7 @@ pub fn on_chan_open_ack_validate(
     _channel_id: &ChannelId,
     counterparty_version: &Version,
 ) -> Result<(), TokenTransferError> {
-    counterparty_version
-        .verify_is_expected(Version::new(VERSION.to_string()))
-        .map_err(ContextError::from)?;
+    verify_version_supported(counterparty_version)?;
 
     Ok(())
 }
@@ -169,48 +185,77 @@ pub fn on_chan_close_confirm_execute(
 pub fn on_recv_packet_execute(
     ctx_b: &mut impl TokenTransferExecutionContext,
     packet: &Packet,
+    version: &Version,
 ) -> (ModuleExtras, Acknowledgement) {
-    let Ok(data) = serde_json::from_slice::<PacketData>(&packet.data) else {
-        let ack =
-            AcknowledgementStatus::error(TokenTransferError::PacketDataDeserialization.into());
+    // The error acknowledgements only carry the ABCI code of the error, as
+    // ibc-go writes them, the details being emitted in the events.
+    let Ok(data) = decode_packet_data(version, &packet.data) else {
+        let ack = AcknowledgementStatus::error(StatusValue::from_abci_error_code(
+            TokenTransferError::PacketDataDeserialization.ack_error_code(),
+        ));
         return (ModuleExtras::empty(), ack.into());
     };
 
-    let (mut extras, ack) = match process_recv_packet_execute(ctx_b, packet, data.clone()) {
-        Ok(extras) => (extras, AcknowledgementStatus::success(ack_success_b64())),
-        Err((extras, error)) => (extras, AcknowledgementStatus::error(error.into())),
-    };
-
-    let recv_event = RecvEvent {
-        sender: data.sender,
-        receiver: data.receiver,
-        denom: data.token.denom,
-        amount: data.token.amount,
-        memo: data.memo,
-        success: ack.is_successful(),
-    };
-    extras.events.push(recv_event.into());
+    let (mut extras, ack, error) =
+        match process_multi_token_recv_packet_execute(ctx_b, packet, data.clone()) {
+            Ok(extras) => (
+                extras,
+                AcknowledgementStatus::success(ack_success_b64()),
+                None,
+            ),
+            Err((extras, error)) => (
+                extras,
+                AcknowledgementStatus::error(StatusValue::from_abci_error_code(
+                    error.ack_error_code(),
+                )),
+                Some(error.to_string()),
+            ),
+        };
+
+    push_recv_events(&mut extras, data, ack.is_successful(), error);
 
     (extras, ack.into())
 }
 
+/// Pushes the receive event of each of the tokens of the packet to the
+/// given extras.
+pub(crate) fn push_recv_events(
+    extras: &mut ModuleExtras,
+    data: PacketDataV2,
+    success: bool,
+    error: Option<String>,
+) {
+    for token in data.tokens {
+        let recv_event = RecvEvent {
+            sender: data.sender.clone(),
+            receiver: data.receiver.clone(),
+            denom: token.denom,
+            amount: token.amount,
+            memo: data.memo.clone(),
+            success,
+            error: error.clone(),
+        };
+        extras.events.push(recv_event.into());
+    }
+}
+
 pub fn on_acknowledgement_packet_validate<Ctx>(
     ctx: &Ctx,
     packet: &Packet,
+    version: &Version,
     acknowledgement: &Acknowledgement,
     _relayer: &Signer,
 ) -> Result<(), TokenTransferError>
 where
     Ctx: TokenTransferValidationContext,
 {
-    let data = serde_json::from_slice::<PacketData>(&packet.data)
-        .map_err(|_| TokenTransferError::PacketDataDeserialization)?;
+    let data = decode_packet_data(version, &packet.data)?;
 
     let acknowledgement = serde_json::from_slice::<AcknowledgementStatus>(acknowledgement.as_ref())
         .map_err(|_| TokenTransferError::AckDeserialization)?;
 
     if !acknowledgement.is_successful() {
-        refund_packet_token_validate(ctx, packet, &data)?;
+        refund_packet_tokens_validate(ctx, packet, &data)?;
     }
 
     Ok(())
@@ -219,14 +264,13 @@ where
 pub fn on_acknowledgement_packet_execute(
     ctx: &mut impl TokenTransferExecutionContext,
     packet: &Packet,
+    version: &Version,
     acknowledgement: &Acknowledgement,
     _relayer: &Signer,
 ) -> (ModuleExtras, Result<(), TokenTransferError>) {
-    let Ok(data) = serde_json::from_slice::<PacketData>(&packet.data) else {
-        return (
-            ModuleExtras::empty(),
-            Err(TokenTransferError::PacketDataDeserialization),
-        );
+    let data = match decode_packet_data(version, &packet.data) {
+        Ok(data) => data,
+        Err(err) => return (ModuleExtras::empty(), Err(err)),
     };
 
     let Ok(acknowledgement) =
@@ -239,22 +283,30 @@ pub fn on_acknowledgement_packet_execute(
     };
 
     if !acknowledgement.is_successful() {
-        if let Err(err) = refund_packet_token_execute(ctx, packet, &data) {
+        if let Err(err) = refund_packet_tokens_execute(ctx, packet, &data) {
             return (ModuleExtras::empty(), Err(err));
         }
     }
 
-    let ack_event = AckEvent {
-        sender: data.sender,
-        receiver: data.receiver,
-        denom: data.token.denom,
-        amount: data.token.amount,
-        memo: data.memo,
-        acknowledgement: acknowledgement.clone(),
-    };
+    let mut events: Vec<ModuleEvent> = data
+        .tokens
+        .into_iter()
+        .map(|token| {
+            AckEvent {
+                sender: data.sender.clone(),
+                receiver: data.receiver.clone(),
+                denom: token.denom,
+                amount: token.amount,
+                memo: data.memo.clone(),
+                acknowledgement: acknowledgement.clone(),
+            }
+            .into()
+        })
+        .collect();
+    events.push(AckStatusEvent { acknowledgement }.into());
 
     let extras = ModuleExtras {
-        events: vec![ack_event.into(), AckStatusEvent { acknowledgement }.into()],
+        events,
         log: Vec::new(),
     };
 
@@ -264,15 +316,15 @@ pub fn on_acknowledgement_packet_execute(
 pub fn on_timeout_packet_validate<Ctx>(
     ctx: &Ctx,
     packet: &Packet,
+    version: &Version,
     _relayer: &Signer,
 ) -> Result<(), TokenTransferError>
 where
     Ctx: TokenTransferValidationContext,
 {
-    let data = serde_json::from_slice::<PacketData>(&packet.data)
-        .map_err(|_| TokenTransferError::PacketDataDeserialization)?;
+    let data = decode_packet_data(version, &packet.data)?;
 
-    refund_packet_token_validate(ctx, packet, &data)?;
+    refund_packet_tokens_validate(ctx, packet, &data)?;
 
     Ok(())
 }
@@ -280,28 +332,34 @@ where
 pub fn on_timeout_packet_execute(
     ctx: &mut impl TokenTransferExecutionContext,
     packet: &Packet,
+    version: &Version,
     _relayer: &Signer,
 ) -> (ModuleExtras, Result<(), TokenTransferError>) {
-    let Ok(data) = serde_json::from_slice::<PacketData>(&packet.data) else {
-        return (
-            ModuleExtras::empty(),
-            Err(TokenTransferError::PacketDataDeserialization),
-        );
+    let data = match decode_packet_data(version, &packet.data) {
+        Ok(data) => data,
+        Err(err) => return (ModuleExtras::empty(), Err(err)),
     };
 
-    if let Err(err) = refund_packet_token_execute(ctx, packet, &data) {
+    if let Err(err) = refund_packet_tokens_execute(ctx, packet, &data) {
         return (ModuleExtras::empty(), Err(err));
     }
 
-    let timeout_event = TimeoutEvent {
-        refund_receiver: data.sender,
-        refund_denom: data.token.denom,
-        refund_amount: data.token.amount,
-        memo: data.memo,
-    };
+    let events: Vec<ModuleEvent> = data
+        .tokens
+        .into_iter()
+        .map(|token| {
+            TimeoutEvent {
+                refund_receiver: data.sender.clone(),
+                refund_denom: token.denom,
+                refund_amount: token.amount,
+                memo: data.memo.clone(),
+            }
+            .into()
+        })
+        .collect();
 
     let extras = ModuleExtras {
-        events: vec![timeout_event.into()],
+        events,
         log: Vec::new(),
     };
 
@@ -354,6 +412,20 @@ mod test {
         );
     }
 
+    #[test]
+    fn test_ack_error_code_to_vec() {
+        let ack_error: Vec<u8> = AcknowledgementStatus::error(StatusValue::from_abci_error_code(
+            TokenTransferError::PacketDataDeserialization.ack_error_code(),
+        ))
+        .into();
+
+        // Check that it's the same output as ibc-go
+        assert_eq!(
+            ack_error,
+            br#"{"error":"ABCI code: 12: error handling packet: see events for details"}"#
+        );
+    }
+
     #[test]
     fn test_ack_de() {
         fn de_json_assert_eq(json_str: &str, ack: AcknowledgementStatus) {
@@ -0,0 +1,75 @@
+//! Enforces the parameters of the token transfer application, which enable or
+//! disable the transfers, and implements their updates by the governance of
+//! the host.
+use ibc_app_transfer_types::error::TokenTransferError;
+use ibc_app_transfer_types::{PrefixedCoin, TransferParams};
+use ibc_core::host::Authority;
+use ibc_core::primitives::prelude::*;
+use ibc_core::primitives::Signer;
+
+use crate::context::{TokenTransferExecutionContext, TokenTransferValidationContext};
+
+/// Validates that the parameters enable sending the given tokens.
+pub fn send_enabled_validate<'a>(
+    ctx: &impl TokenTransferValidationContext,
+    tokens: impl IntoIterator<Item = &'a PrefixedCoin>,
+) -> Result<(), TokenTransferError> {
+    let params = ctx.transfer_params()?;
+
+    match tokens
+        .into_iter()
+        .find(|token| !params.is_send_enabled(&token.denom))
+    {
+        Some(token) => Err(TokenTransferError::SendDisabled {
+            reason: format!("sending `{}` is disabled", token.denom),
+        }),
+        None => Ok(()),
+    }
+}
+
+/// Validates that the parameters enable receiving the given tokens, as
+/// denominated on the host.
+pub fn receive_enabled_validate<'a>(
+    ctx: &impl TokenTransferValidationContext,
+    tokens: impl IntoIterator<Item = &'a PrefixedCoin>,
+) -> Result<(), TokenTransferError> {
+    let params = ctx.transfer_params()?;
+
+    match tokens
+        .into_iter()
+        .find(|token| !params.is_receive_enabled(&token.denom))
+    {
+        Some(token) => Err(TokenTransferError::ReceiveDisabled {
+            reason: format!("receiving `{}` is disabled", token.denom),
+        }),
+        None => Ok(()),
+    }
+}
+
+/// Validates the update of the parameters, which must be signed by the
+/// authority of the host.
+pub fn update_transfer_params_validate<Ctx>(
+    ctx: &Ctx,
+    signer: &Signer,
+    params: &TransferParams,
+) -> Result<(), TokenTransferError>
+where
+    Ctx: TokenTransferValidationContext + Authority,
+{
+    if !ctx.is_authorized(signer) {
+        return Err(TokenTransferError::Unauthorized {
+            signer: signer.clone(),
+        });
+    }
+
+    params.validate_basic()
+}
+
+/// Replaces the parameters of the token transfer application, applying to
+/// the transfers sent and received from now on.
+pub fn update_transfer_params_execute(
+    ctx: &mut impl TokenTransferExecutionContext,
+    params: TransferParams,
+) -> Result<(), TokenTransferError> {
+    ctx.store_transfer_params(params)
+}
@@ -0,0 +1,86 @@
+//! Enforces the transfer policies of the channels, which restrict the
+//! denominations sent and received over them, and implements their updates
+//! by the governance of the host.
+
+use ibc_app_transfer_types::error::TokenTransferError;
+use ibc_app_transfer_types::{PrefixedCoin, TransferPolicy};
+use ibc_core::host::types::identifiers::{ChannelId, PortId};
+
+use crate::context::{TokenTransferExecutionContext, TokenTransferValidationContext};
+
+/// Validates that the transfer policy of the given channel allows sending the
+/// given tokens.
+pub fn send_policy_validate<'a>(
+    ctx: &impl TokenTransferValidationContext,
+    port_id: &PortId,
+    channel_id: &ChannelId,
+    tokens: impl IntoIterator<Item = &'a PrefixedCoin>,
+) -> Result<(), TokenTransferError> {
+    let policy = ctx.transfer_policy(port_id, channel_id)?;
+
+    match tokens
+        .into_iter()
+        .find(|token| !policy.permits_send(&token.denom))
+    {
+        Some(token) => Err(TokenTransferError::DenomSendNotAllowed {
+            port_id: port_id.clone(),
+            channel_id: channel_id.clone(),
+            denom: token.denom.clone(),
+        }),
+        None => Ok(()),
+    }
+}
+
+/// Validates that the transfer policy of the given channel allows receiving
+/// the given tokens, as denominated on the host.
+pub fn receive_policy_validate<'a>(
+    ctx: &impl TokenTransferValidationContext,
+    port_id: &PortId,
+    channel_id: &ChannelId,
+    tokens: impl IntoIterator<Item = &'a PrefixedCoin>,
+) -> Result<(), TokenTransferError> {
+    let policy = ctx.transfer_policy(port_id, channel_id)?;
+
+    match tokens
+        .into_iter()
+        .find(|token| !policy.permits_receive(&token.denom))
+    {
+        Some(token) => Err(TokenTransferError::DenomReceiveNotAllowed {
+            port_id: port_id.clone(),
+            channel_id: channel_id.clone(),
+            denom: token.denom.clone(),
+        }),
+        None => Ok(()),
+    }
+}
+
+/// Validates the update of the transfer policy of the given channel, which
+/// must be bound to the port of the token transfer module.
+pub fn update_transfer_policy_validate(
+    ctx: &impl TokenTransferValidationContext,
+    port_id: &PortId,
+    _channel_id: &ChannelId,
+    _policy: &TransferPolicy,
+) -> Result<(), TokenTransferError> {
+    let exp_port_id = ctx.get_port()?;
+
+    if port_id != &exp_port_id {
+        return Err(TokenTransferError::InvalidPort {
+            port_id: port_id.clone(),
+            exp_port_id,
+        });
+    }
+
+    Ok(())
+}
+
+/// Replaces the transfer policy of the given channel, applying to the
+/// transfers sent and received from now on.
+pub fn update_transfer_policy_execute(
+    ctx: &mut impl TokenTransferExecutionContext,
+    port_id: &PortId,
+    channel_id: &ChannelId,
+    policy: TransferPolicy,
+) -> Result<(), TokenTransferError> {
+    ctx.store_transfer_policy(port_id, channel_id, policy)
+}
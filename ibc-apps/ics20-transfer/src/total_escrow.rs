@@ -0,0 +1,44 @@
+//! Checks the total escrow tracked by the token transfer application against
+//! the tokens held by the escrow accounts, so that hosts can detect accounting
+//! bugs, e.g. in their upgrade handlers or in background audits.
+use ibc_app_transfer_types::error::TokenTransferError;
+use ibc_app_transfer_types::PrefixedDenom;
+
+use crate::context::{TokenTransferEscrowContext, TokenTransferExecutionContext};
+
+/// Returns an error if the tracked total escrow of any denomination differs
+/// from the total amount of the denomination held by the escrow accounts.
+///
+/// Only the denominations whose total escrow is tracked are checked.
+pub fn total_escrow_invariant(
+    ctx: &impl TokenTransferEscrowContext,
+) -> Result<(), TokenTransferError> {
+    for (denom, tracked) in ctx.total_escrows()? {
+        let escrowed = ctx.total_escrowed_amount(&denom)?;
+
+        if tracked != escrowed {
+            return Err(TokenTransferError::TotalEscrowMismatch {
+                denom,
+                tracked,
+                escrowed,
+            });
+        }
+    }
+
+    Ok(())
+}
+
+/// Sets the tracked total escrow of the given denomination to the amount held
+/// by the escrow accounts, e.g. when a host starts tracking the total escrow
+/// of the tokens escrowed before.
+pub fn init_total_escrow_execute<Ctx>(
+    ctx: &mut Ctx,
+    denom: &PrefixedDenom,
+) -> Result<(), TokenTransferError>
+where
+    Ctx: TokenTransferEscrowContext + TokenTransferExecutionContext,
+{
+    let escrowed = ctx.total_escrowed_amount(denom)?;
+
+    ctx.store_total_escrow(denom, escrowed)
+}
@@ -6,7 +6,7 @@ use derive_more::{Display, From, Into};
 use ibc_core::primitives::prelude::*;
 #[cfg(feature = "serde")]
 use ibc_core::primitives::serializers;
-use primitive_types::U256;
+use primitive_types::{U256, U512};
 
 use super::error::TokenTransferError;
 
@@ -92,6 +92,22 @@ impl From<[u64; 4]> for Amount {
 }
 
 impl Amount {
+    pub fn zero() -> Self {
+        Self(U256::zero())
+    }
+
+    /// Returns the given `u128` amount.
+    ///
+    /// Not implemented as `From<u128>`, which would make the type of the
+    /// integer literals of `Amount::from` ambiguous.
+    pub fn from_u128(value: u128) -> Self {
+        Self(value.into())
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.0.is_zero()
+    }
+
     pub fn checked_add(self, rhs: Self) -> Option<Self> {
         self.0.checked_add(rhs.0).map(Self)
     }
@@ -99,6 +115,140 @@ impl Amount {
     pub fn checked_sub(self, rhs: Self) -> Option<Self> {
         self.0.checked_sub(rhs.0).map(Self)
     }
+
+    pub fn checked_mul(self, rhs: Self) -> Option<Self> {
+        self.0.checked_mul(rhs.0).map(Self)
+    }
+
+    /// Returns the quotient rounded down, or none if `rhs` is zero.
+    pub fn checked_div(self, rhs: Self) -> Option<Self> {
+        self.0.checked_div(rhs.0).map(Self)
+    }
+
+    /// Returns the remainder of the division, or none if `rhs` is zero.
+    pub fn checked_rem(self, rhs: Self) -> Option<Self> {
+        self.0.checked_rem(rhs.0).map(Self)
+    }
+
+    pub fn saturating_add(self, rhs: Self) -> Self {
+        Self(self.0.saturating_add(rhs.0))
+    }
+
+    pub fn saturating_sub(self, rhs: Self) -> Self {
+        Self(self.0.saturating_sub(rhs.0))
+    }
+
+    pub fn saturating_mul(self, rhs: Self) -> Self {
+        Self(self.0.saturating_mul(rhs.0))
+    }
+
+    /// Returns `self * numerator / denominator` rounded down, or none if the
+    /// denominator is zero or the result overflows.
+    ///
+    /// The product does not overflow, so that e.g. fractions of the largest
+    /// amounts can be computed.
+    pub fn checked_mul_div(self, numerator: Self, denominator: Self) -> Option<Self> {
+        if denominator.is_zero() {
+            return None;
+        }
+
+        let quotient = self.0.full_mul(numerator.0) / U512::from(denominator.0);
+
+        U256::try_from(quotient).ok().map(Self)
+    }
+
+    /// Returns the given percentage of the amount, rounded down.
+    pub fn checked_percent(self, percent: u64) -> Option<Self> {
+        self.checked_mul_div(percent.into(), 100u64.into())
+    }
+
+    /// Returns the given basis points, i.e. hundredths of a percent, of the
+    /// amount, rounded down.
+    pub fn checked_basis_points(self, basis_points: u64) -> Option<Self> {
+        self.checked_mul_div(basis_points.into(), 10_000u64.into())
+    }
+
+    /// Returns the amount of the given big-endian bytes, as the EVM encodes
+    /// its `uint256` words.
+    pub fn from_be_bytes(bytes: [u8; 32]) -> Self {
+        Self(U256::from_big_endian(&bytes))
+    }
+
+    /// Returns the big-endian bytes of the amount, as the EVM encodes its
+    /// `uint256` words.
+    pub fn to_be_bytes(self) -> [u8; 32] {
+        let mut bytes = [0; 32];
+        self.0.to_big_endian(&mut bytes);
+
+        bytes
+    }
+
+    /// Parses the amount of base units of the given decimal amount, e.g.
+    /// `1.5` with 6 decimals into `1500000`.
+    ///
+    /// The amounts with more fractional digits than `decimals`, which cannot
+    /// be represented exactly, and the ones overflowing once scaled are
+    /// rejected.
+    pub fn from_decimal_str(s: &str, decimals: u8) -> Result<Self, TokenTransferError> {
+        let invalid = || TokenTransferError::InvalidDecimalAmount {
+            amount: s.to_string(),
+            decimals,
+        };
+
+        let (integer, fraction) = match s.split_once('.') {
+            Some((integer, fraction)) if !fraction.is_empty() => (integer, fraction),
+            Some(_) => return Err(invalid()),
+            None => (s, ""),
+        };
+
+        if integer.is_empty()
+            || fraction.len() > usize::from(decimals)
+            || !fraction.bytes().all(|b| b.is_ascii_digit())
+        {
+            return Err(invalid());
+        }
+
+        let scale = U256::from(10)
+            .checked_pow(decimals.into())
+            .ok_or_else(invalid)?;
+        let integer = U256::from_dec_str(integer).map_err(TokenTransferError::InvalidAmount)?;
+        // The fraction padded to the given decimals is lower than the scale,
+        // and so does not overflow.
+        let fraction = if fraction.is_empty() {
+            U256::zero()
+        } else {
+            let padding = decimals - fraction.len() as u8;
+            U256::from_dec_str(fraction).map_err(TokenTransferError::InvalidAmount)?
+                * U256::from(10).pow(padding.into())
+        };
+
+        integer
+            .checked_mul(scale)
+            .and_then(|integer| integer.checked_add(fraction))
+            .map(Self)
+            .ok_or_else(invalid)
+    }
+
+    /// Formats the amount of base units as a decimal amount, e.g. `1500000`
+    /// with 6 decimals as `1.5`, without the trailing zeros of the fraction.
+    pub fn to_decimal_string(&self, decimals: u8) -> String {
+        let digits = self.0.to_string();
+        let decimals = usize::from(decimals);
+
+        if decimals == 0 {
+            return digits;
+        }
+
+        let digits = format!("{digits:0>width$}", width = decimals + 1);
+        let (integer, fraction) = digits.split_at(digits.len() - decimals);
+        let fraction = fraction.trim_end_matches('0');
+
+        if fraction.is_empty() {
+            integer.to_string()
+        } else {
+            format!("{integer}.{fraction}")
+        }
+    }
 }
 
 impl AsRef<U256> for Amount {
@@ -122,6 +272,38 @@ impl From<u64> for Amount {
     }
 }
 
+impl TryFrom<Amount> for u64 {
+    type Error = TokenTransferError;
+
+    fn try_from(amount: Amount) -> Result<Self, Self::Error> {
+        u64::try_from(amount.0).map_err(|_| TokenTransferError::AmountOverflow { amount })
+    }
+}
+
+impl TryFrom<Amount> for u128 {
+    type Error = TokenTransferError;
+
+    fn try_from(amount: Amount) -> Result<Self, Self::Error> {
+        u128::try_from(amount.0).map_err(|_| TokenTransferError::AmountOverflow { amount })
+    }
+}
+
+/// Converts the `uint256` of the EVM tooling losslessly, as both integers are
+/// made of four little-endian 64-bit limbs.
+#[cfg(feature = "alloy")]
+impl From<alloy_primitives::U256> for Amount {
+    fn from(value: alloy_primitives::U256) -> Self {
+        Self::from(*value.as_limbs())
+    }
+}
+
+#[cfg(feature = "alloy")]
+impl From<Amount> for alloy_primitives::U256 {
+    fn from(amount: Amount) -> Self {
+        Self::from_limbs(*amount)
+    }
+}
+
 #[cfg(feature = "serde")]
 fn deserialize<'de, D>(deserializer: D) -> Result<U256, D::Error>
 where
@@ -134,7 +316,121 @@ where
 
 #[cfg(test)]
 mod tests {
-    use super::Amount;
+    use super::*;
+
+    #[test]
+    fn test_amount_arithmetic() {
+        let max = Amount::from(U256::MAX);
+
+        assert_eq!(
+            Amount::from(6).checked_mul(Amount::from(7)),
+            Some(Amount::from(42))
+        );
+        assert_eq!(max.checked_mul(Amount::from(2)), None);
+        assert_eq!(
+            Amount::from(43).checked_div(Amount::from(6)),
+            Some(Amount::from(7))
+        );
+        assert_eq!(
+            Amount::from(43).checked_rem(Amount::from(6)),
+            Some(Amount::from(1))
+        );
+        assert_eq!(Amount::from(1).checked_div(Amount::zero()), None);
+
+        assert_eq!(max.saturating_add(Amount::from(1)), max);
+        assert_eq!(
+            Amount::from(1).saturating_sub(Amount::from(2)),
+            Amount::zero()
+        );
+        assert_eq!(max.saturating_mul(Amount::from(2)), max);
+
+        assert_eq!(
+            Amount::from(999).checked_percent(10),
+            Some(Amount::from(99))
+        );
+        assert_eq!(
+            Amount::from(10_000).checked_basis_points(25),
+            Some(Amount::from(25))
+        );
+        // The intermediate product of the largest amounts does not overflow.
+        assert_eq!(
+            max.checked_mul_div(Amount::from(3), Amount::from(4)),
+            Some(Amount::from(U256::MAX / 4 * 3 + 2))
+        );
+        assert_eq!(max.checked_mul_div(Amount::from(2), Amount::from(1)), None);
+    }
+
+    #[test]
+    fn test_amount_primitive_conversions() {
+        assert_eq!(
+            Amount::from_u128(u128::MAX),
+            Amount::from(U256::from(u128::MAX))
+        );
+        assert_eq!(
+            u128::try_from(Amount::from_u128(u128::MAX)).unwrap(),
+            u128::MAX
+        );
+        assert_eq!(u64::try_from(Amount::from(42)).unwrap(), 42);
+        assert!(u64::try_from(Amount::from_u128(u128::MAX)).is_err());
+        assert!(u128::try_from(Amount::from(U256::MAX)).is_err());
+    }
+
+    #[test]
+    fn test_amount_evm_conversions() {
+        let amount = Amount::from_u128(0x0102_0304_0506_0708_090a_0b0c_0d0e_0f10);
+        let bytes = amount.to_be_bytes();
+
+        assert_eq!(bytes[..16], [0; 16]);
+        assert_eq!(bytes[31], 0x10);
+        assert_eq!(Amount::from_be_bytes(bytes), amount);
+        assert_eq!(Amount::from_be_bytes([0xff; 32]), Amount::from(U256::MAX));
+    }
+
+    #[test]
+    fn test_amount_decimal_strings() {
+        assert_eq!(
+            Amount::from_decimal_str("1.5", 6).unwrap(),
+            Amount::from(1_500_000)
+        );
+        assert_eq!(Amount::from_decimal_str("42", 0).unwrap(), Amount::from(42));
+        assert_eq!(
+            Amount::from_decimal_str("0.000001", 6).unwrap(),
+            Amount::from(1)
+        );
+        assert_eq!(
+            Amount::from_decimal_str("1", 18).unwrap(),
+            Amount::from(10u64.pow(18))
+        );
+
+        assert!(Amount::from_decimal_str("0.0000001", 6).is_err());
+        assert!(Amount::from_decimal_str("1.", 6).is_err());
+        assert!(Amount::from_decimal_str(".5", 6).is_err());
+        assert!(Amount::from_decimal_str("1.-5", 6).is_err());
+        assert!(Amount::from_decimal_str("-1", 6).is_err());
+        assert!(Amount::from_decimal_str(&U256::MAX.to_string(), 0).is_ok());
+        assert!(Amount::from_decimal_str(&U256::MAX.to_string(), 1).is_err());
+        assert!(Amount::from_decimal_str("1", 78).is_err());
+
+        assert_eq!(Amount::from(1_500_000).to_decimal_string(6), "1.5");
+        assert_eq!(Amount::from(1).to_decimal_string(6), "0.000001");
+        assert_eq!(Amount::from(2_000_000).to_decimal_string(6), "2");
+        assert_eq!(Amount::zero().to_decimal_string(6), "0");
+        assert_eq!(Amount::from(42).to_decimal_string(0), "42");
+    }
+
+    #[cfg(feature = "alloy")]
+    #[test]
+    fn test_amount_alloy_conversions() {
+        let value = alloy_primitives::U256::from(u128::MAX) << 64;
+        let amount = Amount::from(value);
+
+        assert_eq!(amount, Amount::from(U256::from(u128::MAX) << 64));
+        assert_eq!(alloy_primitives::U256::from(amount), value);
+        assert_eq!(
+            Amount::from(alloy_primitives::U256::MAX),
+            Amount::from(U256::MAX)
+        );
+    }
 
     #[cfg(feature = "serde")]
     #[test]
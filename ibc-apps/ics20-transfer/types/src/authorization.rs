@@ -0,0 +1,307 @@
+//! Defines the transfer authorizations, with which an account grants another
+//! account to transfer its tokens up to spend limits over given channels, as
+//! the `TransferAuthorization` of `ibc-go` does.
+use ibc_core::host::types::identifiers::{ChannelId, PortId};
+use ibc_core::primitives::prelude::*;
+use ibc_core::primitives::Signer;
+use primitive_types::U256;
+
+use super::amount::Amount;
+use super::coin::PrefixedCoin;
+use super::error::TokenTransferError;
+use super::memo::Memo;
+use super::msgs::multi_token_transfer::MsgMultiTokenTransfer;
+
+/// The memo allowed by the [`Allocation`]s to carry any packet data.
+pub const ANY_PACKET_DATA: &str = "*";
+
+/// The tokens the grantee of a [`TransferAuthorization`] can transfer over a
+/// channel.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(parity_scale_codec::Encode, parity_scale_codec::Decode,)
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Allocation {
+    /// The port over which the tokens can be sent.
+    pub port_id: PortId,
+    /// The channel over which the tokens can be sent.
+    pub channel_id: ChannelId,
+    /// The amounts of the denominations which can be sent, a spend limit of
+    /// the maximum amount being unlimited.
+    pub spend_limit: Vec<PrefixedCoin>,
+    /// The receivers the tokens can be sent to, any receiver if empty.
+    pub allow_list: Vec<Signer>,
+    /// The memos the transfers can carry, any memo if it has
+    /// [`ANY_PACKET_DATA`]. The transfers without memo are always allowed.
+    pub allowed_packet_data: Vec<String>,
+}
+
+impl Allocation {
+    fn validate_basic(&self) -> Result<(), TokenTransferError> {
+        if self.spend_limit.is_empty() {
+            return Err(invalid_authorization("spend limit must not be empty"));
+        }
+
+        for (i, coin) in self.spend_limit.iter().enumerate() {
+            if coin.amount.is_zero() {
+                return Err(invalid_authorization(format!(
+                    "spend limit of `{}` must not be zero",
+                    coin.denom
+                )));
+            }
+
+            if self.spend_limit[..i]
+                .iter()
+                .any(|other| other.denom == coin.denom)
+            {
+                return Err(invalid_authorization(format!(
+                    "duplicate spend limit of `{}`",
+                    coin.denom
+                )));
+            }
+        }
+
+        for (i, receiver) in self.allow_list.iter().enumerate() {
+            if self.allow_list[..i].contains(receiver) {
+                return Err(invalid_authorization(format!(
+                    "duplicate allowed receiver `{receiver}`"
+                )));
+            }
+        }
+
+        Ok(())
+    }
+
+    fn allows_receiver(&self, receiver: &Signer) -> bool {
+        self.allow_list.is_empty() || self.allow_list.contains(receiver)
+    }
+
+    fn allows_memo(&self, memo: &Memo) -> bool {
+        memo.as_ref().is_empty()
+            || self
+                .allowed_packet_data
+                .iter()
+                .any(|data| data == ANY_PACKET_DATA || data == memo.as_ref())
+    }
+
+    /// Subtracts the given token from the spend limit of its denomination,
+    /// which is removed once spent.
+    fn spend(&mut self, token: &PrefixedCoin) -> Result<(), TokenTransferError> {
+        let Some(index) = self
+            .spend_limit
+            .iter()
+            .position(|limit| limit.denom == token.denom)
+        else {
+            return Err(not_authorized(format!(
+                "no spend limit of `{}`",
+                token.denom
+            )));
+        };
+
+        let limit = &mut self.spend_limit[index];
+
+        if limit.amount == Amount::from(U256::MAX) {
+            return Ok(());
+        }
+
+        limit.amount = limit.amount.checked_sub(token.amount).ok_or_else(|| {
+            not_authorized(format!(
+                "spend limit of `{}` is lower than `{}`",
+                limit.denom, token.amount
+            ))
+        })?;
+
+        if limit.amount.is_zero() {
+            self.spend_limit.remove(index);
+        }
+
+        Ok(())
+    }
+}
+
+/// The authorization granted by an account to another account to transfer
+/// its tokens, within the [`Allocation`]s of the channels.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(parity_scale_codec::Encode, parity_scale_codec::Decode,)
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TransferAuthorization {
+    pub allocations: Vec<Allocation>,
+}
+
+impl TransferAuthorization {
+    pub fn validate_basic(&self) -> Result<(), TokenTransferError> {
+        if self.allocations.is_empty() {
+            return Err(invalid_authorization("allocations must not be empty"));
+        }
+
+        for (i, allocation) in self.allocations.iter().enumerate() {
+            allocation.validate_basic()?;
+
+            if self.allocations[..i].iter().any(|other| {
+                other.port_id == allocation.port_id && other.channel_id == allocation.channel_id
+            }) {
+                return Err(invalid_authorization(format!(
+                    "duplicate allocation of port `{}` and channel `{}`",
+                    allocation.port_id, allocation.channel_id
+                )));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Returns the authorization left once the given transfer is spent from
+    /// the allocation of its channel, or none if all the allocations are
+    /// spent, or an error if the transfer is not authorized.
+    pub fn accept(&self, msg: &MsgMultiTokenTransfer) -> Result<Option<Self>, TokenTransferError> {
+        let mut allocations = self.allocations.clone();
+
+        let Some(index) = allocations.iter().position(|allocation| {
+            allocation.port_id == msg.port_id_on_a && allocation.channel_id == msg.chan_id_on_a
+        }) else {
+            return Err(not_authorized(format!(
+                "no allocation of port `{}` and channel `{}`",
+                msg.port_id_on_a, msg.chan_id_on_a
+            )));
+        };
+
+        let allocation = &mut allocations[index];
+
+        if !allocation.allows_receiver(&msg.packet_data.receiver) {
+            return Err(not_authorized(format!(
+                "receiver `{}` is not allowed",
+                msg.packet_data.receiver
+            )));
+        }
+
+        if !allocation.allows_memo(&msg.packet_data.memo) {
+            return Err(not_authorized("memo is not allowed"));
+        }
+
+        for token in &msg.packet_data.tokens {
+            allocation.spend(token)?;
+        }
+
+        if allocation.spend_limit.is_empty() {
+            allocations.remove(index);
+        }
+
+        Ok((!allocations.is_empty()).then_some(Self { allocations }))
+    }
+}
+
+fn invalid_authorization(reason: impl Into<String>) -> TokenTransferError {
+    TokenTransferError::InvalidTransferAuthorization {
+        reason: reason.into(),
+    }
+}
+
+fn not_authorized(reason: impl Into<String>) -> TokenTransferError {
+    TokenTransferError::TransferNotAuthorized {
+        reason: reason.into(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ibc_core::channel::types::timeout::TimeoutHeight;
+    use ibc_core::primitives::Timestamp;
+
+    use super::*;
+    use crate::packet::PacketDataV2;
+
+    fn allocation(spend_limit: &str) -> Allocation {
+        Allocation {
+            port_id: PortId::transfer(),
+            channel_id: ChannelId::zero(),
+            spend_limit: PrefixedCoin::from_string_list(spend_limit).unwrap(),
+            allow_list: vec!["receiver".to_string().into()],
+            allowed_packet_data: vec!["a memo".to_string()],
+        }
+    }
+
+    fn msg(tokens: &str, receiver: &str, memo: &str) -> MsgMultiTokenTransfer {
+        MsgMultiTokenTransfer {
+            port_id_on_a: PortId::transfer(),
+            chan_id_on_a: ChannelId::zero(),
+            packet_data: PacketDataV2 {
+                tokens: PrefixedCoin::from_string_list(tokens).unwrap(),
+                sender: "granter".to_string().into(),
+                receiver: receiver.to_string().into(),
+                memo: memo.into(),
+            },
+            timeout_height_on_b: TimeoutHeight::Never,
+            timeout_timestamp_on_b: Timestamp::none(),
+        }
+    }
+
+    #[test]
+    fn test_transfer_authorization_accept() {
+        let authorization = TransferAuthorization {
+            allocations: vec![allocation("100uatom,10uosmo")],
+        };
+        assert!(authorization.validate_basic().is_ok());
+
+        let left = authorization
+            .accept(&msg("40uatom,10uosmo", "receiver", "a memo"))
+            .unwrap();
+        assert_eq!(
+            left,
+            Some(TransferAuthorization {
+                allocations: vec![allocation("60uatom")],
+            })
+        );
+
+        // The authorization is spent once all its spend limits are.
+        assert_eq!(
+            authorization
+                .accept(&msg("100uatom,10uosmo", "receiver", ""))
+                .unwrap(),
+            None
+        );
+
+        for msg in [
+            msg("101uatom", "receiver", ""),
+            msg("1ustake", "receiver", ""),
+            msg("1uatom", "other", ""),
+            msg("1uatom", "receiver", "another memo"),
+        ] {
+            assert!(matches!(
+                authorization.accept(&msg),
+                Err(TokenTransferError::TransferNotAuthorized { .. })
+            ));
+        }
+    }
+
+    #[test]
+    fn test_transfer_authorization_validate_basic() {
+        let mut duplicate = allocation("100uatom");
+        duplicate.spend_limit = PrefixedCoin::from_string_list("1uosmo").unwrap();
+
+        for allocations in [
+            vec![],
+            vec![allocation("0uatom")],
+            vec![allocation("1uatom,2uatom")],
+            vec![allocation("100uatom"), duplicate],
+        ] {
+            assert!(TransferAuthorization { allocations }
+                .validate_basic()
+                .is_err());
+        }
+    }
+}
@@ -0,0 +1,105 @@
+//! Defines the decimal conversions, which scale the amounts of a denomination
+//! between its precision on the host and the precision of the packets, e.g.
+//! between 6-decimal Cosmos denominations and 18-decimal EVM representations.
+use primitive_types::U256;
+
+use super::amount::Amount;
+use super::error::TokenTransferError;
+
+/// The conversion of the amounts of a denomination transferred over a
+/// channel whose packets carry them with another number of decimals than the
+/// host holds them with.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DecimalConversion {
+    /// The number of decimals of the denomination on the host.
+    pub host_decimals: u8,
+    /// The number of decimals of the amounts in the packets.
+    pub packet_decimals: u8,
+}
+
+impl DecimalConversion {
+    /// Converts the given amount held on the host to the precision of the
+    /// packets.
+    ///
+    /// The amount is rounded down if the packets carry fewer decimals, the
+    /// remainder being left out of the transfer.
+    pub fn to_packet_amount(&self, amount: Amount) -> Result<Amount, TokenTransferError> {
+        scale(amount, self.host_decimals, self.packet_decimals)
+    }
+
+    /// Converts the given amount of a packet to the precision of the host.
+    ///
+    /// The amount is rounded down if the host holds fewer decimals, the
+    /// remainder being left out of the transfer.
+    pub fn to_host_amount(&self, amount: Amount) -> Result<Amount, TokenTransferError> {
+        scale(amount, self.packet_decimals, self.host_decimals)
+    }
+}
+
+fn scale(amount: Amount, from_decimals: u8, to_decimals: u8) -> Result<Amount, TokenTransferError> {
+    let amount: U256 = amount.into();
+
+    let factor = U256::from(10).checked_pow(U256::from(from_decimals.abs_diff(to_decimals)));
+
+    let scaled = if to_decimals >= from_decimals {
+        factor
+            .and_then(|factor| amount.checked_mul(factor))
+            .ok_or(TokenTransferError::DecimalConversionOverflow)?
+    } else {
+        // The factors which overflow are greater than any amount.
+        factor.map_or_else(U256::zero, |factor| amount / factor)
+    };
+
+    Ok(scaled.into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decimal_conversion() {
+        let conversion = DecimalConversion {
+            host_decimals: 18,
+            packet_decimals: 6,
+        };
+
+        // The remainder below the precision of the packets is rounded down.
+        let packet_amount = conversion
+            .to_packet_amount(Amount::from(1_234_567_000_000_000_001))
+            .unwrap();
+        assert_eq!(packet_amount, Amount::from(1_234_567));
+        assert_eq!(
+            conversion.to_host_amount(packet_amount).unwrap(),
+            Amount::from(1_234_567_000_000_000_000)
+        );
+        assert_eq!(
+            conversion.to_packet_amount(Amount::from(999)).unwrap(),
+            Amount::from(0)
+        );
+
+        let conversion = DecimalConversion {
+            host_decimals: 0,
+            packet_decimals: 100,
+        };
+        assert!(conversion.to_packet_amount(Amount::from(1)).is_err());
+        assert_eq!(
+            conversion.to_host_amount(Amount::from(1)).unwrap(),
+            Amount::from(0)
+        );
+    }
+}
@@ -1,5 +1,5 @@
 //! Defines types to represent "denominations" [as defined in ICS-20](https://github.com/cosmos/ibc/blob/main/spec/app/ics-020-fungible-token-transfer/README.md#data-structures)
-use core::fmt::{Display, Error as FmtError, Formatter};
+use core::fmt::{Display, Error as FmtError, Formatter, Write};
 use core::str::FromStr;
 
 use derive_more::{Display, From};
@@ -8,6 +8,8 @@ use ibc_core::primitives::prelude::*;
 #[cfg(feature = "serde")]
 use ibc_core::primitives::serializers;
 use ibc_proto::ibc::applications::transfer::v1::DenomTrace as RawDenomTrace;
+use sha2::{Digest, Sha256};
+use subtle_encoding::hex;
 
 use super::error::TokenTransferError;
 
@@ -83,6 +85,14 @@ impl TracePrefix {
             channel_id,
         }
     }
+
+    pub fn port_id(&self) -> &PortId {
+        &self.port_id
+    }
+
+    pub fn channel_id(&self) -> &ChannelId {
+        &self.channel_id
+    }
 }
 
 impl Display for TracePrefix {
@@ -118,6 +128,15 @@ impl TracePath {
         self.0.last().map(|p| p == prefix).unwrap_or(false)
     }
 
+    /// Returns true iff this path starts with the prefix of the given port
+    /// and channel, without building the prefix out of their clones.
+    pub fn starts_with_ids(&self, port_id: &PortId, channel_id: &ChannelId) -> bool {
+        self.0
+            .last()
+            .map(|p| &p.port_id == port_id && &p.channel_id == channel_id)
+            .unwrap_or(false)
+    }
+
     /// Removes the specified prefix from the path if there is a match, otherwise does nothing.
     pub fn remove_prefix(&mut self, prefix: &TracePrefix) {
         if self.starts_with(prefix) {
@@ -125,6 +144,17 @@ impl TracePath {
         }
     }
 
+    /// Removes the prefix of the given port and channel from the path if
+    /// there is a match, and returns whether it did.
+    pub fn try_remove_prefix(&mut self, port_id: &PortId, channel_id: &ChannelId) -> bool {
+        let starts_with = self.starts_with_ids(port_id, channel_id);
+        if starts_with {
+            self.0.pop();
+        }
+
+        starts_with
+    }
+
     /// Adds the specified prefix to the path.
     pub fn add_prefix(&mut self, prefix: TracePrefix) {
         self.0.push(prefix)
@@ -139,29 +169,53 @@ impl TracePath {
     pub fn empty() -> Self {
         Self(vec![])
     }
-}
 
-impl<'a> TryFrom<Vec<&'a str>> for TracePath {
-    type Error = TokenTransferError;
+    /// Returns the prefixes of the path, in the order they appear in the
+    /// prefixed denomination, i.e. starting with the latest added one.
+    pub fn prefixes(&self) -> impl Iterator<Item = &TracePrefix> {
+        self.0.iter().rev()
+    }
 
-    fn try_from(v: Vec<&'a str>) -> Result<Self, Self::Error> {
-        if v.len() % 2 != 0 {
-            return Err(TokenTransferError::InvalidTraceLength {
-                len: v.len() as u64,
-            });
+    /// Builds a path from its prefixes, given in the order they appear in the
+    /// prefixed denomination, i.e. starting with the latest added one.
+    pub fn from_prefixes(prefixes: impl IntoIterator<Item = TracePrefix>) -> Self {
+        let mut prefixes: Vec<_> = prefixes.into_iter().collect();
+        prefixes.reverse();
+
+        Self(prefixes)
+    }
+}
+
+impl TracePath {
+    /// Parses the `{port-id}/{channel-id}` pairs of the given segments, in
+    /// the order they appear in the prefixed denomination.
+    ///
+    /// The segments are borrowed from the parsed string, and the pairs are
+    /// parsed in a single pass, so that only the prefixes are allocated.
+    fn from_segments<'a, I>(segments: I) -> Result<Self, TokenTransferError>
+    where
+        I: Iterator<Item = &'a str> + Clone,
+    {
+        let len = segments.clone().count();
+        if len % 2 != 0 {
+            return Err(TokenTransferError::InvalidTraceLength { len: len as u64 });
         }
 
-        let mut trace = vec![];
-        let id_pairs = v.chunks_exact(2).map(|paths| (paths[0], paths[1]));
-        for (pos, (port_id, channel_id)) in id_pairs.rev().enumerate() {
+        // The positions of the prefixes are counted from the end of the path,
+        // i.e. from the first prefix that was added.
+        let pairs = len / 2;
+        let mut trace = Vec::with_capacity(pairs);
+        let mut segments = segments;
+        while let (Some(port_id), Some(channel_id)) = (segments.next(), segments.next()) {
+            let pos = (pairs - trace.len() - 1) as u64;
             let port_id =
                 PortId::from_str(port_id).map_err(|e| TokenTransferError::InvalidTracePortId {
-                    pos: pos as u64,
+                    pos,
                     validation_error: e,
                 })?;
             let channel_id = ChannelId::from_str(channel_id).map_err(|e| {
                 TokenTransferError::InvalidTraceChannelId {
-                    pos: pos as u64,
+                    pos,
                     validation_error: e,
                 }
             })?;
@@ -170,37 +224,42 @@ impl<'a> TryFrom<Vec<&'a str>> for TracePath {
                 channel_id,
             });
         }
+        trace.reverse();
 
         Ok(trace.into())
     }
 }
 
+impl<'a> TryFrom<Vec<&'a str>> for TracePath {
+    type Error = TokenTransferError;
+
+    fn try_from(v: Vec<&'a str>) -> Result<Self, Self::Error> {
+        Self::from_segments(v.into_iter())
+    }
+}
+
 impl FromStr for TracePath {
     type Err = TokenTransferError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let parts = {
-            let parts: Vec<&str> = s.split('/').collect();
-            if parts.len() == 1 && parts[0].trim().is_empty() {
-                vec![]
-            } else {
-                parts
-            }
-        };
-        parts.try_into()
+        if s.trim().is_empty() {
+            return Ok(Self::empty());
+        }
+
+        Self::from_segments(s.split('/'))
     }
 }
 
 impl Display for TracePath {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), FmtError> {
-        let path = self
-            .0
-            .iter()
-            .rev()
-            .map(|prefix| prefix.to_string())
-            .collect::<Vec<String>>()
-            .join("/");
-        write!(f, "{path}")
+        for (i, prefix) in self.prefixes().enumerate() {
+            if i > 0 {
+                f.write_str("/")?;
+            }
+            write!(f, "{prefix}")?;
+        }
+
+        Ok(())
     }
 }
 
@@ -235,10 +294,104 @@ impl PrefixedDenom {
         self.trace_path.remove_prefix(prefix)
     }
 
+    /// Removes the prefix of the given port and channel from the trace path
+    /// if there is a match, and returns whether it did.
+    pub fn try_remove_trace_prefix(&mut self, port_id: &PortId, channel_id: &ChannelId) -> bool {
+        self.trace_path.try_remove_prefix(port_id, channel_id)
+    }
+
     /// Adds the specified prefix to the trace path.
     pub fn add_trace_prefix(&mut self, prefix: TracePrefix) {
         self.trace_path.add_prefix(prefix)
     }
+
+    /// Returns the hash of the denomination, as `ibc-go` hashes its denom
+    /// traces.
+    ///
+    /// The denomination is written straight into the hasher, rather than
+    /// into an intermediate string.
+    pub fn trace_hash(&self) -> DenomTraceHash {
+        let mut hasher = HashWriter(Sha256::new());
+        write!(hasher, "{self}").expect("Never fails");
+
+        DenomTraceHash(hasher.0.finalize().into())
+    }
+
+    /// Returns the denomination the tokens are held with on a chain running
+    /// `ibc-go`, which is `ibc/{hash}` for the tokens received over IBC, and
+    /// the base denomination for the native ones.
+    pub fn ibc_denom(&self) -> String {
+        if self.trace_path.is_empty() {
+            self.base_denom.to_string()
+        } else {
+            format!("{IBC_DENOM_PREFIX}/{}", self.trace_hash())
+        }
+    }
+}
+
+/// Feeds the formatted denominations to the hasher of their trace hash.
+struct HashWriter(Sha256);
+
+impl Write for HashWriter {
+    fn write_str(&mut self, s: &str) -> Result<(), FmtError> {
+        self.0.update(s.as_bytes());
+        Ok(())
+    }
+}
+
+/// The prefix of the hashed denominations of the tokens received over IBC.
+pub const IBC_DENOM_PREFIX: &str = "ibc";
+
+/// The SHA-256 hash of the full path of a [`PrefixedDenom`], with which the
+/// denominations of the tokens received over IBC are shortened to
+/// `ibc/{hash}`.
+///
+/// It is displayed and parsed as upper case hexadecimal, as `ibc-go` does.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct DenomTraceHash([u8; 32]);
+
+impl DenomTraceHash {
+    pub fn new(hash: [u8; 32]) -> Self {
+        Self(hash)
+    }
+
+    pub fn as_bytes(&self) -> &[u8; 32] {
+        &self.0
+    }
+
+    /// Parses the hash of the given `ibc/{hash}` denomination.
+    pub fn from_ibc_denom(denom: &str) -> Result<Self, TokenTransferError> {
+        match denom.split_once('/') {
+            Some((IBC_DENOM_PREFIX, hash)) => hash.parse(),
+            _ => Err(TokenTransferError::InvalidDenomTraceHash {
+                hash: denom.to_string(),
+            }),
+        }
+    }
+}
+
+impl FromStr for DenomTraceHash {
+    type Err = TokenTransferError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid_hash = || TokenTransferError::InvalidDenomTraceHash {
+            hash: s.to_string(),
+        };
+
+        let bytes = hex::decode(s.to_ascii_lowercase()).map_err(|_| invalid_hash())?;
+        let hash = <[u8; 32]>::try_from(bytes).map_err(|_| invalid_hash())?;
+
+        Ok(Self(hash))
+    }
+}
+
+impl Display for DenomTraceHash {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), FmtError> {
+        let hash = hex::encode_upper(self.0);
+        let hash = core::str::from_utf8(&hash).map_err(|_| FmtError)?;
+
+        write!(f, "{hash}")
+    }
 }
 
 /// Returns true if the denomination originally came from the sender chain and
@@ -286,25 +439,21 @@ pub fn is_receiver_chain_source(
     // If B had originally sent the token in a previous transfer, then A would have stored the token as
     // "transfer/c2b/{token_denom}". Now, A is sending to B, so to check if B is the source of the token,
     // we need to check if the token starts with "transfer/c2b".
-    let prefix = TracePrefix::new(source_port, source_channel);
-    denom.trace_path.starts_with(&prefix)
+    denom
+        .trace_path
+        .starts_with_ids(&source_port, &source_channel)
 }
 
 impl FromStr for PrefixedDenom {
     type Err = TokenTransferError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let mut parts: Vec<&str> = s.split('/').collect();
-        let last_part = parts.pop().expect("split() returned an empty iterator");
-
-        let (base_denom, trace_path) = {
-            if last_part == s {
-                (BaseDenom::from_str(s)?, TracePath::empty())
-            } else {
-                let base_denom = BaseDenom::from_str(last_part)?;
-                let trace_path = TracePath::try_from(parts)?;
-                (base_denom, trace_path)
-            }
+        let (trace_path, base_denom) = match s.rsplit_once('/') {
+            Some((trace_path, base_denom)) => (
+                TracePath::from_segments(trace_path.split('/'))?,
+                BaseDenom::from_str(base_denom)?,
+            ),
+            None => (TracePath::empty(), BaseDenom::from_str(s)?),
         };
 
         Ok(Self {
@@ -472,4 +621,59 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_multi_hop_trace_path() -> Result<(), TokenTransferError> {
+        let path = (0..64)
+            .map(|i| format!("transfer/channel-{i}"))
+            .collect::<Vec<_>>()
+            .join("/");
+        let denom_str = format!("{path}/uatom");
+
+        let mut denom = PrefixedDenom::from_str(&denom_str)?;
+        assert_eq!(denom.to_string(), denom_str);
+        assert_eq!(denom.trace_path.prefixes().count(), 64);
+
+        let port_id = PortId::transfer();
+        assert!(!denom.try_remove_trace_prefix(&port_id, &ChannelId::new(63)));
+        assert!(denom.try_remove_trace_prefix(&port_id, &ChannelId::new(0)));
+        assert!(denom.to_string().starts_with("transfer/channel-1/"));
+
+        // The positions of the invalid prefixes are counted from the first
+        // added one, at the end of the path.
+        assert!(matches!(
+            PrefixedDenom::from_str("transfer/(channel-0)/transfer/channel-1/uatom"),
+            Err(TokenTransferError::InvalidTraceChannelId { pos: 1, .. })
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_denom_trace_hash() -> Result<(), TokenTransferError> {
+        // The denomination of the ATOMs received by Osmosis over `channel-0`.
+        let denom = PrefixedDenom::from_str("transfer/channel-0/uatom")?;
+        let hash = "27394FB092D2ECCD56123C74F36E4C1F926001CEADA9CA97EA622B25F41E5EB2";
+
+        assert_eq!(denom.trace_hash().to_string(), hash);
+        assert_eq!(denom.ibc_denom(), format!("ibc/{hash}"));
+        assert_eq!(DenomTraceHash::from_str(hash)?, denom.trace_hash());
+        assert_eq!(
+            DenomTraceHash::from_str(&hash.to_lowercase())?,
+            denom.trace_hash()
+        );
+        assert_eq!(
+            DenomTraceHash::from_ibc_denom(&format!("ibc/{hash}"))?,
+            denom.trace_hash()
+        );
+
+        // Native denominations are not hashed.
+        assert_eq!(PrefixedDenom::from_str("uatom")?.ibc_denom(), "uatom");
+
+        assert!(DenomTraceHash::from_str("27394FB0").is_err());
+        assert!(DenomTraceHash::from_ibc_denom(hash).is_err());
+        assert!(DenomTraceHash::from_ibc_denom(&format!("factory/{hash}")).is_err());
+
+        Ok(())
+    }
 }
@@ -5,12 +5,16 @@ use core::str::Utf8Error;
 use displaydoc::Display;
 use ibc_core::channel::types::acknowledgement::StatusValue;
 use ibc_core::channel::types::channel::Order;
+use ibc_core::channel::types::Version;
 use ibc_core::handler::types::error::ContextError;
 use ibc_core::host::types::error::IdentifierError;
 use ibc_core::host::types::identifiers::{ChannelId, PortId};
 use ibc_core::primitives::prelude::*;
+use ibc_core::primitives::Signer;
 use uint::FromDecStrErr;
 
+use crate::{Amount, DenomTraceHash, PrefixedDenom};
+
 #[derive(Display, Debug)]
 pub enum TokenTransferError {
     /// context error: `{0}`
@@ -43,8 +47,18 @@ pub enum TokenTransferError {
     InvalidTraceLength { len: u64 },
     /// invalid amount error: `{0}`
     InvalidAmount(FromDecStrErr),
+    /// amount `{amount}` overflows the integer type it is converted to
+    AmountOverflow { amount: Amount },
+    /// invalid decimal amount `{amount}` with `{decimals}` decimals
+    InvalidDecimalAmount { amount: String, decimals: u8 },
     /// invalid token
     InvalidToken,
+    /// the packet data carries no tokens
+    MissingTokens,
+    /// the packet data carries several tokens of the denomination `{denom}`
+    DuplicateDenom { denom: PrefixedDenom },
+    /// the packet data of the channels of version `ics20-1` carries a single token, got `{count}`
+    SingleTokenExpected { count: usize },
     /// expected `{expect_order}` channel, got `{got_order}`
     ChannelNotUnordered {
         expect_order: Order,
@@ -52,6 +66,8 @@ pub enum TokenTransferError {
     },
     /// channel cannot be closed
     CantCloseChannel,
+    /// unsupported version `{version}`, expected `ics20-1` or `ics20-2`
+    UnsupportedVersion { version: Version },
     /// failed to deserialize packet data
     PacketDataDeserialization,
     /// failed to deserialize acknowledgement
@@ -73,15 +89,114 @@ pub enum TokenTransferError {
     UnknownMsgType { msg_type: String },
     /// invalid coin string: `{coin}`
     InvalidCoin { coin: String },
+    /// invalid denom trace hash: `{hash}`
+    InvalidDenomTraceHash { hash: String },
+    /// denom trace not found for hash: `{hash}`
+    DenomTraceNotFound { hash: DenomTraceHash },
+    /// invalid value of the memo key `{key}`
+    InvalidMemo { key: String },
+    /// a memo handler is already registered for the key `{key}`
+    DuplicateMemoHandler { key: String },
+    /// the transfer policy of port_id `{port_id}` and channel_id `{channel_id}` does not allow sending `{denom}`
+    DenomSendNotAllowed {
+        port_id: PortId,
+        channel_id: ChannelId,
+        denom: PrefixedDenom,
+    },
+    /// the transfer policy of port_id `{port_id}` and channel_id `{channel_id}` does not allow receiving `{denom}`
+    DenomReceiveNotAllowed {
+        port_id: PortId,
+        channel_id: ChannelId,
+        denom: PrefixedDenom,
+    },
+    /// invalid parameters: `{reason}`
+    InvalidParams { reason: String },
+    /// storing the parameters is not supported by the host
+    ParamsUnsupported,
+    /// signer `{signer}` is not authorized to update the parameters
+    Unauthorized { signer: Signer },
+    /// storing the transfer policies is not supported by the host
+    TransferPolicyUnsupported,
+    /// the amount overflows once converted to the precision of the host or the packets
+    DecimalConversionOverflow,
+    /// the amount of `{denom}` is zero once converted to the precision of the packets
+    ZeroConvertedAmount { denom: PrefixedDenom },
+    /// the total escrow of `{denom}` overflows
+    TotalEscrowOverflow { denom: PrefixedDenom },
+    /// the total escrow of `{denom}` is lower than the `{amount}` released from the escrow
+    TotalEscrowUnderflow {
+        denom: PrefixedDenom,
+        amount: Amount,
+    },
+    /// the tracked total escrow of `{denom}` is `{tracked}`, but `{escrowed}` are escrowed
+    TotalEscrowMismatch {
+        denom: PrefixedDenom,
+        tracked: Amount,
+        escrowed: Amount,
+    },
+    /// invalid transfer authorization: `{reason}`
+    InvalidTransferAuthorization { reason: String },
+    /// transfer not authorized: `{reason}`
+    TransferNotAuthorized { reason: String },
+    /// no transfer authorization granted by `{granter}` to `{grantee}`
+    TransferAuthorizationNotFound { granter: Signer, grantee: Signer },
+    /// storing the transfer authorizations is not supported by the host
+    TransferAuthorizationUnsupported,
+    /// burning the escrowed tokens is not supported by the host
+    EscrowBurnUnsupported,
     /// decoding raw bytes as UTF8 string error: `{0}`
     Utf8Decode(Utf8Error),
     /// other error: `{0}`
     Other(String),
 }
 
-#[cfg(feature = "std")]
-impl std::error::Error for TokenTransferError {
-    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+impl TokenTransferError {
+    /// Returns the ABCI code ibc-go writes in the error acknowledgements for
+    /// the equivalent error, so that both stacks write the same
+    /// acknowledgements.
+    ///
+    /// The errors ibc-go does not register are written with the code of its
+    /// internal errors.
+    pub fn ack_error_code(&self) -> u32 {
+        match self {
+            // `ibcerrors.ErrInvalidType`
+            Self::PacketDataDeserialization => 12,
+            // `ibcerrors.ErrInvalidAddress`
+            Self::ParseAccountFailure => 5,
+            // `transfertypes.ErrInvalidDenomForTransfer`
+            Self::EmptyBaseDenom
+            | Self::InvalidTracePortId { .. }
+            | Self::InvalidTraceChannelId { .. }
+            | Self::InvalidTraceLength { .. }
+            | Self::InvalidCoin { .. }
+            | Self::InvalidDenomTraceHash { .. }
+            | Self::DenomTraceNotFound { .. }
+            | Self::DuplicateDenom { .. } => 3,
+            // `transfertypes.ErrInvalidAmount`, and the
+            // `sdkerrors.ErrInsufficientFunds` of the bank module
+            Self::InvalidAmount(_)
+            | Self::AmountOverflow { .. }
+            | Self::InvalidDecimalAmount { .. }
+            | Self::InvalidToken
+            | Self::MissingTokens
+            | Self::SingleTokenExpected { .. }
+            | Self::DecimalConversionOverflow
+            | Self::ZeroConvertedAmount { .. }
+            | Self::InsufficientFunds { .. } => 5,
+            // `transfertypes.ErrInvalidVersion`
+            Self::UnsupportedVersion { .. } => 4,
+            // `transfertypes.ErrSendDisabled`
+            Self::SendDisabled { .. } | Self::DenomSendNotAllowed { .. } => 7,
+            // `transfertypes.ErrReceiveDisabled`
+            Self::ReceiveDisabled { .. } | Self::DenomReceiveNotAllowed { .. } => 8,
+            // `errorsmod.ErrInternal`
+            _ => 1,
+        }
+    }
+}
+
+impl core::error::Error for TokenTransferError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
         match &self {
             Self::ContextError(e) => Some(e),
             Self::InvalidIdentifier(e)
@@ -93,6 +208,8 @@ impl std::error::Error for TokenTransferError {
                 validation_error: e,
                 ..
             } => Some(e),
+            // `uint` implements the trait only with `std`
+            #[cfg(feature = "std")]
             Self::InvalidAmount(e) => Some(e),
             Self::Utf8Decode(e) => Some(e),
             _ => None,
@@ -0,0 +1,26 @@
+//! Derives the addresses of the escrow accounts of the channels.
+use ibc_core::host::types::identifiers::{ChannelId, PortId};
+use ibc_core::primitives::prelude::*;
+use sha2::{Digest, Sha256};
+
+use crate::VERSION;
+
+/// Returns the address of the account escrowing the tokens sent over the given
+/// channel, derived as the token transfer module of the Cosmos SDK derives it,
+/// following [`ADR-028`](https://github.com/cosmos/cosmos-sdk/blob/main/docs/architecture/adr-028-public-key-addresses.md).
+///
+/// The address is the first 20 bytes of the hash of the transfer version,
+/// followed by a zero byte and the `{port_id}/{channel_id}` path, which hosts
+/// encode as they encode their account addresses, e.g. in bech32.
+pub fn escrow_address(port_id: &PortId, channel_id: &ChannelId) -> Vec<u8> {
+    let contents = format!("{port_id}/{channel_id}");
+
+    let mut hasher = Sha256::new();
+    hasher.update(VERSION.as_bytes());
+    hasher.update([0]);
+    hasher.update(contents.as_bytes());
+
+    let mut hash = hasher.finalize().to_vec();
+    hash.truncate(20);
+    hash
+}
@@ -2,7 +2,7 @@
 use ibc_core::channel::types::acknowledgement::AcknowledgementStatus;
 use ibc_core::primitives::prelude::*;
 use ibc_core::primitives::Signer;
-use ibc_core::router::types::event::ModuleEvent;
+use ibc_core::router::types::event::{ModuleEvent, ModuleEventAttribute};
 
 use super::Memo;
 use crate::{Amount, PrefixedDenom, MODULE_ID_STR};
@@ -31,6 +31,9 @@ pub struct RecvEvent {
     pub amount: Amount,
     pub memo: Memo,
     pub success: bool,
+    /// The details of the error the packet failed to be processed with, which
+    /// the error acknowledgement does not carry.
+    pub error: Option<String>,
 }
 
 impl From<RecvEvent> for ModuleEvent {
@@ -42,18 +45,23 @@ impl From<RecvEvent> for ModuleEvent {
             amount,
             memo,
             success,
+            error,
         } = ev;
+        let mut attributes: Vec<ModuleEventAttribute> = vec![
+            ("module", MODULE_ID_STR).into(),
+            ("sender", sender).into(),
+            ("receiver", receiver).into(),
+            ("denom", denom).into(),
+            ("amount", amount).into(),
+            ("memo", memo).into(),
+            ("success", success).into(),
+        ];
+        if let Some(error) = error {
+            attributes.push(("error", error).into());
+        }
         Self {
             kind: EVENT_TYPE_PACKET.to_string(),
-            attributes: vec![
-                ("module", MODULE_ID_STR).into(),
-                ("sender", sender).into(),
-                ("receiver", receiver).into(),
-                ("denom", denom).into(),
-                ("amount", amount).into(),
-                ("memo", memo).into(),
-                ("success", success).into(),
-            ],
+            attributes,
         }
     }
 }
@@ -12,17 +12,29 @@
     rust_2018_idioms
 )]
 
+extern crate alloc;
+
 #[cfg(any(test, feature = "std"))]
 extern crate std;
 
 mod amount;
+mod authorization;
 mod coin;
+mod decimals;
 mod denom;
+mod escrow;
 mod memo;
+mod params;
+mod policy;
 
 pub use amount::*;
+pub use authorization::*;
 pub use coin::*;
+pub use decimals::*;
 pub use denom::*;
+pub use escrow::*;
+pub use params::*;
+pub use policy::*;
 pub mod error;
 pub mod events;
 pub mod msgs;
@@ -46,13 +58,24 @@ pub const PORT_ID_STR: &str = "transfer";
 /// ICS20 application current version.
 pub const VERSION: &str = "ics20-1";
 
+/// ICS20 application version of the channels whose packets carry several
+/// tokens.
+pub const VERSION_V2: &str = "ics20-2";
+
 /// The successful string used for creating an acknowledgement status,
 /// equivalent to `base64::encode(0x01)`.
 pub const ACK_SUCCESS_B64: &str = "AQ==";
 
 use ibc_core::channel::types::acknowledgement::StatusValue;
+use ibc_core::channel::types::Version;
 
 /// Returns a successful acknowledgement status for the token transfer application.
 pub fn ack_success_b64() -> StatusValue {
     StatusValue::new(ACK_SUCCESS_B64).expect("ack status value is never supposed to be empty")
 }
+
+/// Returns `true` if the given channel version is one of the versions
+/// supported by the ICS20 application.
+pub fn is_supported_version(version: &Version) -> bool {
+    version.as_str() == VERSION || version.as_str() == VERSION_V2
+}
@@ -1,2 +1,3 @@
 //! Defines the token transfer message type
+pub mod multi_token_transfer;
 pub mod transfer;
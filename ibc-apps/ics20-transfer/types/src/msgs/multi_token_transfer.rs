@@ -0,0 +1,53 @@
+//! Defines the message type of the token transfers of several tokens
+
+use ibc_core::channel::types::timeout::TimeoutHeight;
+use ibc_core::host::types::identifiers::{ChannelId, PortId};
+use ibc_core::primitives::Timestamp;
+
+use crate::msgs::transfer::MsgTransfer;
+use crate::packet::PacketDataV2;
+
+/// Message used to build an ICS20 token transfer packet of several tokens,
+/// which are sent over channels of version `ics20-2`.
+///
+/// Channels of version `ics20-1` only accept the messages of a single token,
+/// which are sent as [`MsgTransfer`] would. Note that the message has no
+/// counterpart in the proto definitions yet, and hence cannot be submitted
+/// in a transaction.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(parity_scale_codec::Encode, parity_scale_codec::Decode,)
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct MsgMultiTokenTransfer {
+    /// the port on which the packet will be sent
+    pub port_id_on_a: PortId,
+    /// the channel by which the packet will be sent
+    pub chan_id_on_a: ChannelId,
+    /// token transfer packet data of the packet that will be sent
+    pub packet_data: PacketDataV2,
+    /// Timeout height relative to the current block height.
+    /// The timeout is disabled when set to None.
+    pub timeout_height_on_b: TimeoutHeight,
+    /// Timeout timestamp relative to the current block timestamp.
+    /// The timeout is disabled when set to 0.
+    pub timeout_timestamp_on_b: Timestamp,
+}
+
+impl From<MsgTransfer> for MsgMultiTokenTransfer {
+    fn from(msg: MsgTransfer) -> Self {
+        Self {
+            port_id_on_a: msg.port_id_on_a,
+            chan_id_on_a: msg.chan_id_on_a,
+            packet_data: msg.packet_data.into(),
+            timeout_height_on_b: msg.timeout_height_on_b,
+            timeout_timestamp_on_b: msg.timeout_timestamp_on_b,
+        }
+    }
+}
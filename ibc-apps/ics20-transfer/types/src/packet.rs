@@ -1,5 +1,7 @@
-//! Contains the `PacketData` type that defines the structure of token transfers' packet bytes
+//! Contains the `PacketData` and `PacketDataV2` types that define the structure of token
+//! transfers' packet bytes
 
+use alloc::collections::BTreeSet;
 use core::str::FromStr;
 
 use ibc_core::primitives::prelude::*;
@@ -7,7 +9,7 @@ use ibc_core::primitives::Signer;
 use ibc_proto::ibc::applications::transfer::v2::FungibleTokenPacketData as RawPacketData;
 
 use super::error::TokenTransferError;
-use super::{Amount, Memo, PrefixedCoin, PrefixedDenom};
+use super::{Amount, BaseDenom, Memo, PrefixedCoin, PrefixedDenom, TracePath, TracePrefix};
 
 /// Defines the structure of token transfers' packet bytes
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -60,6 +62,225 @@ impl From<PacketData> for RawPacketData {
     }
 }
 
+/// Defines the structure of the packet bytes of the token transfers over
+/// channels of version `ics20-2`, which carry several tokens per packet.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(try_from = "RawPacketDataV2", into = "RawPacketDataV2")
+)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(parity_scale_codec::Encode, parity_scale_codec::Decode,)
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PacketDataV2 {
+    pub tokens: Vec<PrefixedCoin>,
+    pub sender: Signer,
+    pub receiver: Signer,
+    pub memo: Memo,
+}
+
+impl PacketDataV2 {
+    /// Checks the packet data carries at least one token, and at most one
+    /// token of each denomination, so that the amount of a denomination is
+    /// not counted several times.
+    pub fn validate_tokens(&self) -> Result<(), TokenTransferError> {
+        if self.tokens.is_empty() {
+            return Err(TokenTransferError::MissingTokens);
+        }
+
+        let mut denoms = BTreeSet::new();
+        for token in &self.tokens {
+            if !denoms.insert(&token.denom) {
+                return Err(TokenTransferError::DuplicateDenom {
+                    denom: token.denom.clone(),
+                });
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl From<PacketData> for PacketDataV2 {
+    fn from(pkt_data: PacketData) -> Self {
+        Self {
+            tokens: vec![pkt_data.token],
+            sender: pkt_data.sender,
+            receiver: pkt_data.receiver,
+            memo: pkt_data.memo,
+        }
+    }
+}
+
+impl TryFrom<PacketDataV2> for PacketData {
+    type Error = TokenTransferError;
+
+    /// Converts the packet data to the one of the channels of version
+    /// `ics20-1`, which carries a single token.
+    fn try_from(pkt_data: PacketDataV2) -> Result<Self, Self::Error> {
+        let count = pkt_data.tokens.len();
+        let token = match <[PrefixedCoin; 1]>::try_from(pkt_data.tokens) {
+            Ok([token]) => token,
+            Err(_) => return Err(TokenTransferError::SingleTokenExpected { count }),
+        };
+
+        Ok(Self {
+            token,
+            sender: pkt_data.sender,
+            receiver: pkt_data.receiver,
+            memo: pkt_data.memo,
+        })
+    }
+}
+
+/// The packet data of version `ics20-2`, as encoded by ibc-go, of which the
+/// denominations of the tokens are objects rather than strings.
+///
+/// There is no such type in the proto definitions, ibc-go encoding the
+/// packet data to JSON directly.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RawPacketDataV2 {
+    pub tokens: Vec<RawToken>,
+    pub sender: String,
+    pub receiver: String,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub memo: String,
+}
+
+/// A token of the packet data of version `ics20-2`.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RawToken {
+    pub denom: RawDenom,
+    pub amount: String,
+}
+
+/// The denomination of a token of the packet data of version `ics20-2`,
+/// whose trace starts with the latest hop the token went through.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RawDenom {
+    pub base: String,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub trace: Vec<RawHop>,
+}
+
+/// A hop of the trace of a denomination, identifying the channel end the
+/// token was received on.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RawHop {
+    pub port_id: String,
+    pub channel_id: String,
+}
+
+impl TryFrom<RawDenom> for PrefixedDenom {
+    type Error = TokenTransferError;
+
+    fn try_from(raw_denom: RawDenom) -> Result<Self, Self::Error> {
+        let trace_path = raw_denom
+            .trace
+            .into_iter()
+            .enumerate()
+            .map(|(pos, hop)| {
+                let port_id =
+                    hop.port_id
+                        .parse()
+                        .map_err(|e| TokenTransferError::InvalidTracePortId {
+                            pos: pos as u64,
+                            validation_error: e,
+                        })?;
+                let channel_id = hop.channel_id.parse().map_err(|e| {
+                    TokenTransferError::InvalidTraceChannelId {
+                        pos: pos as u64,
+                        validation_error: e,
+                    }
+                })?;
+
+                Ok(TracePrefix::new(port_id, channel_id))
+            })
+            .collect::<Result<Vec<_>, TokenTransferError>>()?;
+
+        Ok(Self {
+            trace_path: TracePath::from_prefixes(trace_path),
+            base_denom: BaseDenom::from_str(&raw_denom.base)?,
+        })
+    }
+}
+
+impl From<PrefixedDenom> for RawDenom {
+    fn from(denom: PrefixedDenom) -> Self {
+        Self {
+            base: denom.base_denom.to_string(),
+            trace: denom
+                .trace_path
+                .prefixes()
+                .map(|prefix| RawHop {
+                    port_id: prefix.port_id().to_string(),
+                    channel_id: prefix.channel_id().to_string(),
+                })
+                .collect(),
+        }
+    }
+}
+
+impl TryFrom<RawPacketDataV2> for PacketDataV2 {
+    type Error = TokenTransferError;
+
+    fn try_from(raw_pkt_data: RawPacketDataV2) -> Result<Self, Self::Error> {
+        let tokens = raw_pkt_data
+            .tokens
+            .into_iter()
+            .map(|raw_token| {
+                Ok(PrefixedCoin {
+                    denom: raw_token.denom.try_into()?,
+                    amount: Amount::from_str(&raw_token.amount)?,
+                })
+            })
+            .collect::<Result<_, TokenTransferError>>()?;
+
+        let pkt_data = Self {
+            tokens,
+            sender: raw_pkt_data.sender.into(),
+            receiver: raw_pkt_data.receiver.into(),
+            memo: raw_pkt_data.memo.into(),
+        };
+        pkt_data.validate_tokens()?;
+
+        Ok(pkt_data)
+    }
+}
+
+impl From<PacketDataV2> for RawPacketDataV2 {
+    fn from(pkt_data: PacketDataV2) -> Self {
+        Self {
+            tokens: pkt_data
+                .tokens
+                .into_iter()
+                .map(|token| RawToken {
+                    denom: token.denom.into(),
+                    amount: token.amount.to_string(),
+                })
+                .collect(),
+            sender: pkt_data.sender.to_string(),
+            receiver: pkt_data.receiver.to_string(),
+            memo: pkt_data.memo.to_string(),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use primitive_types::U256;
@@ -118,4 +339,71 @@ mod tests {
         PacketData::new_dummy().deser_json_assert_eq(dummy_json_packet_data());
         PacketData::new_dummy().deser_json_assert_eq(dummy_json_packet_data_without_memo());
     }
+
+    fn dummy_packet_data_v2() -> PacketDataV2 {
+        let mut packet_data = PacketDataV2::from(PacketData::new_dummy());
+        packet_data.tokens.push(PrefixedCoin {
+            denom: "transfer/channel-1/transfer/channel-0/uatom"
+                .parse()
+                .unwrap(),
+            amount: U256::from(20).into(),
+        });
+        packet_data
+    }
+
+    pub fn dummy_json_packet_data_v2() -> &'static str {
+        r#"{"tokens":[{"denom":{"base":"uatom","trace":[]},"amount":"10"},{"denom":{"base":"uatom","trace":[{"port_id":"transfer","channel_id":"channel-1"},{"port_id":"transfer","channel_id":"channel-0"}]},"amount":"20"}],"sender":"cosmos1wxeyh7zgn4tctjzs0vtqpc6p5cxq5t2muzl7ng","receiver":"cosmos1wxeyh7zgn4tctjzs0vtqpc6p5cxq5t2muzl7ng","memo":""}"#
+    }
+
+    /// Ensures `PacketDataV2` encodes the denominations of its tokens to
+    /// objects, starting their trace with the latest hop, and decodes back.
+    #[test]
+    fn test_packet_data_v2_ser_deser() {
+        let packet_data = dummy_packet_data_v2();
+
+        let ser = serde_json::to_string(&packet_data).unwrap();
+        assert_eq!(ser, dummy_json_packet_data_v2());
+
+        let deser: PacketDataV2 = serde_json::from_str(dummy_json_packet_data_v2()).unwrap();
+        assert_eq!(deser, packet_data);
+    }
+
+    #[test]
+    fn test_packet_data_v2_deser_fails() {
+        // The packet data of version `ics20-1` have no tokens.
+        assert!(serde_json::from_str::<PacketDataV2>(dummy_json_packet_data()).is_err());
+
+        let json = r#"{"tokens":[],"sender":"cosmos1wxeyh7zgn4tctjzs0vtqpc6p5cxq5t2muzl7ng","receiver":"cosmos1wxeyh7zgn4tctjzs0vtqpc6p5cxq5t2muzl7ng"}"#;
+        assert!(serde_json::from_str::<PacketDataV2>(json).is_err());
+
+        // The tokens of the same denomination are not merged.
+        let json = r#"{"tokens":[{"denom":{"base":"uatom","trace":[]},"amount":"10"},{"denom":{"base":"uatom","trace":[]},"amount":"20"}],"sender":"cosmos1wxeyh7zgn4tctjzs0vtqpc6p5cxq5t2muzl7ng","receiver":"cosmos1wxeyh7zgn4tctjzs0vtqpc6p5cxq5t2muzl7ng"}"#;
+        assert!(serde_json::from_str::<PacketDataV2>(json).is_err());
+    }
+
+    #[test]
+    fn test_packet_data_v2_duplicate_denom() {
+        let mut packet_data = dummy_packet_data_v2();
+        assert!(packet_data.validate_tokens().is_ok());
+
+        packet_data.tokens.push(packet_data.tokens[0].clone());
+        assert!(matches!(
+            packet_data.validate_tokens(),
+            Err(TokenTransferError::DuplicateDenom { .. })
+        ));
+    }
+
+    #[test]
+    fn test_packet_data_v2_to_v1() {
+        let packet_data = PacketDataV2::from(PacketData::new_dummy());
+        assert_eq!(
+            PacketData::try_from(packet_data).unwrap(),
+            PacketData::new_dummy()
+        );
+
+        assert!(matches!(
+            PacketData::try_from(dummy_packet_data_v2()),
+            Err(TokenTransferError::SingleTokenExpected { count: 2 })
+        ));
+    }
 }
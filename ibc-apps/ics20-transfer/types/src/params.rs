@@ -0,0 +1,140 @@
+//! Defines the parameters of the token transfer application, which the
+//! governance of the host updates to enable or disable the transfers, e.g. to
+//! halt them in emergencies.
+use ibc_core::primitives::prelude::*;
+
+use super::denom::PrefixedDenom;
+use super::error::TokenTransferError;
+
+/// Enables or disables the transfers of a denomination, on top of the
+/// switches of all the denominations.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DenomParams {
+    /// The denomination, as denominated on the host.
+    pub denom: PrefixedDenom,
+    pub send_enabled: bool,
+    pub receive_enabled: bool,
+}
+
+/// The parameters of the token transfer application.
+///
+/// A denomination can be sent, respectively received, only if the transfers
+/// of all the denominations and of the denomination itself are enabled. The
+/// default parameters enable all the transfers.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TransferParams {
+    pub send_enabled: bool,
+    pub receive_enabled: bool,
+    /// The switches of the denominations, which are enabled if not listed.
+    pub denoms: Vec<DenomParams>,
+}
+
+impl Default for TransferParams {
+    fn default() -> Self {
+        Self {
+            send_enabled: true,
+            receive_enabled: true,
+            denoms: Vec::new(),
+        }
+    }
+}
+
+impl TransferParams {
+    pub fn validate_basic(&self) -> Result<(), TokenTransferError> {
+        for (i, params) in self.denoms.iter().enumerate() {
+            if self.denoms[..i]
+                .iter()
+                .any(|other| other.denom == params.denom)
+            {
+                return Err(TokenTransferError::InvalidParams {
+                    reason: format!("duplicate parameters of `{}`", params.denom),
+                });
+            }
+        }
+
+        Ok(())
+    }
+
+    fn denom_params(&self, denom: &PrefixedDenom) -> Option<&DenomParams> {
+        self.denoms.iter().find(|params| &params.denom == denom)
+    }
+
+    /// Returns `true` if the given denomination can be sent.
+    pub fn is_send_enabled(&self, denom: &PrefixedDenom) -> bool {
+        self.send_enabled
+            && self
+                .denom_params(denom)
+                .map_or(true, |params| params.send_enabled)
+    }
+
+    /// Returns `true` if the given denomination can be received.
+    pub fn is_receive_enabled(&self, denom: &PrefixedDenom) -> bool {
+        self.receive_enabled
+            && self
+                .denom_params(denom)
+                .map_or(true, |params| params.receive_enabled)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_transfer_params() {
+        let uatom: PrefixedDenom = "uatom".parse().unwrap();
+        let uosmo: PrefixedDenom = "uosmo".parse().unwrap();
+
+        let mut params = TransferParams {
+            denoms: vec![DenomParams {
+                denom: uatom.clone(),
+                send_enabled: false,
+                receive_enabled: true,
+            }],
+            ..Default::default()
+        };
+        assert!(params.validate_basic().is_ok());
+
+        assert!(!params.is_send_enabled(&uatom));
+        assert!(params.is_receive_enabled(&uatom));
+        assert!(params.is_send_enabled(&uosmo));
+
+        // The switches of all the denominations halt the transfers of every
+        // denomination.
+        params.receive_enabled = false;
+        assert!(!params.is_receive_enabled(&uatom));
+        assert!(!params.is_receive_enabled(&uosmo));
+        assert!(params.is_send_enabled(&uosmo));
+
+        params.denoms.push(params.denoms[0].clone());
+        assert!(params.validate_basic().is_err());
+    }
+}
@@ -0,0 +1,119 @@
+//! Defines the transfer policies, which restrict the denominations sent and
+//! received over a channel.
+use ibc_core::primitives::prelude::*;
+
+use super::denom::PrefixedDenom;
+
+/// Restricts the denominations of the tokens transferred in a direction.
+///
+/// The denominations are the ones of the tokens on the host, i.e. the
+/// denominations of the tokens sent, and of the tokens or vouchers credited
+/// to the receivers of the received transfers.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub enum DenomFilter {
+    /// All the denominations are allowed.
+    #[default]
+    AllowAll,
+    /// Only the listed denominations are allowed.
+    Allow(Vec<PrefixedDenom>),
+    /// All the denominations are allowed, except the listed ones.
+    Deny(Vec<PrefixedDenom>),
+}
+
+impl DenomFilter {
+    /// Returns `true` if the given denomination passes the filter.
+    pub fn permits(&self, denom: &PrefixedDenom) -> bool {
+        match self {
+            Self::AllowAll => true,
+            Self::Allow(denoms) => denoms.contains(denom),
+            Self::Deny(denoms) => !denoms.contains(denom),
+        }
+    }
+}
+
+/// The transfer policy of a channel, restricting the denominations which can
+/// be sent and received over it.
+///
+/// The default policy allows all the denominations in both directions.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct TransferPolicy {
+    /// The filter of the denominations sent over the channel.
+    pub send: DenomFilter,
+    /// The filter of the denominations received over the channel.
+    pub receive: DenomFilter,
+}
+
+impl TransferPolicy {
+    /// Returns `true` if the policy allows all the denominations in both
+    /// directions, in which case it does not need to be stored.
+    pub fn is_unrestricted(&self) -> bool {
+        self == &Self::default()
+    }
+
+    /// Returns `true` if the given denomination can be sent.
+    pub fn permits_send(&self, denom: &PrefixedDenom) -> bool {
+        self.send.permits(denom)
+    }
+
+    /// Returns `true` if the given denomination can be received.
+    pub fn permits_receive(&self, denom: &PrefixedDenom) -> bool {
+        self.receive.permits(denom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_transfer_policy() {
+        let uatom: PrefixedDenom = "uatom".parse().unwrap();
+        let voucher: PrefixedDenom = "transfer/channel-0/uosmo".parse().unwrap();
+
+        // Only the native token is sent, and everything but it received.
+        let policy = TransferPolicy {
+            send: DenomFilter::Allow(vec![uatom.clone()]),
+            receive: DenomFilter::Deny(vec![uatom.clone()]),
+        };
+
+        assert!(policy.permits_send(&uatom));
+        assert!(!policy.permits_send(&voucher));
+        assert!(!policy.permits_receive(&uatom));
+        assert!(policy.permits_receive(&voucher));
+        assert!(!policy.is_unrestricted());
+
+        let policy = TransferPolicy::default();
+
+        assert!(policy.permits_send(&voucher));
+        assert!(policy.permits_receive(&uatom));
+        assert!(policy.is_unrestricted());
+    }
+}
@@ -0,0 +1,75 @@
+//! Derives the addresses of the interchain accounts, as `ibc-go` does.
+use ibc_core::host::types::identifiers::{ConnectionId, PortId};
+use sha2::{Digest, Sha256};
+
+use crate::MODULE_NAME;
+
+/// Derives the address of the interchain account of the given controller port
+/// over the given connection of the host.
+///
+/// The address is the module account address of the Cosmos SDK, `address.Module`,
+/// of the interchain accounts module and the connection and port, followed by
+/// the given salt:
+///
+/// ```text
+/// sha256(sha256("module") || "interchainaccounts" || 0x00 || connection_id || port_id || salt)
+/// ```
+///
+/// The Cosmos SDK hosts salt the address with the app and data hashes of the
+/// block in which the account is registered, so that it cannot be predicted
+/// and claimed by someone else beforehand.
+pub fn derive_account_address(
+    connection_id: &ConnectionId,
+    controller_port_id: &PortId,
+    salt: &[u8],
+) -> [u8; 32] {
+    let mut hasher = Sha256::new();
+    hasher.update(Sha256::digest(b"module"));
+    hasher.update(MODULE_NAME.as_bytes());
+    hasher.update([0]);
+    hasher.update(connection_id.as_bytes());
+    hasher.update(controller_port_id.as_bytes());
+    hasher.update(salt);
+
+    hasher.finalize().into()
+}
+
+#[cfg(test)]
+mod tests {
+    use ibc_core::primitives::prelude::*;
+
+    use super::*;
+
+    #[test]
+    fn test_derive_account_address() {
+        let port_id = PortId::new("icacontroller-owner".to_string()).unwrap();
+
+        assert_eq!(
+            derive_account_address(&ConnectionId::zero(), &port_id, &[]),
+            [
+                0x14, 0xc8, 0xa3, 0xd5, 0xee, 0x6b, 0x2b, 0x33, 0xfa, 0xa1, 0x5b, 0x92, 0x89, 0x5c,
+                0xd0, 0x22, 0xe2, 0x3c, 0xd1, 0x5e, 0x00, 0xa8, 0xbd, 0x8a, 0xcd, 0x05, 0xf7, 0x22,
+                0x0b, 0x8d, 0xe0, 0x21
+            ]
+        );
+
+        // The connection, the port and the salt all change the address.
+        let address = derive_account_address(&ConnectionId::zero(), &port_id, b"salt");
+        assert_ne!(
+            address,
+            derive_account_address(&ConnectionId::zero(), &port_id, &[])
+        );
+        assert_ne!(
+            address,
+            derive_account_address(&ConnectionId::new(1), &port_id, b"salt")
+        );
+        assert_ne!(
+            address,
+            derive_account_address(
+                &ConnectionId::zero(),
+                &PortId::new("icacontroller-other".to_string()).unwrap(),
+                b"salt"
+            )
+        );
+    }
+}
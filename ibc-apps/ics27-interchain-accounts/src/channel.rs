@@ -0,0 +1,72 @@
+//! Helpers shared by the host and the controller to look up the channels of
+//! the interchain accounts.
+use ibc_core::channel::types::channel::State;
+use ibc_core::host::types::identifiers::{ChannelId, ConnectionId, PortId};
+use ibc_core::host::types::path::ChannelEndPath;
+use ibc_core::host::ValidationContext;
+use ibc_core::primitives::prelude::*;
+
+use crate::error::InterchainAccountError;
+use crate::metadata::Metadata;
+
+/// Returns the connection of a channel over the given connection hops, and
+/// its counterparty connection.
+pub(crate) fn channel_connections(
+    ctx: &impl ValidationContext,
+    connection_hops: &[ConnectionId],
+) -> Result<(ConnectionId, ConnectionId), InterchainAccountError> {
+    let connection_id = connection_hops.first().cloned().ok_or_else(|| {
+        InterchainAccountError::InvalidMetadata {
+            reason: "channel has no connection hop".to_string(),
+        }
+    })?;
+
+    let counterparty_connection_id = ctx
+        .connection_end(&connection_id)?
+        .counterparty()
+        .connection_id()
+        .cloned()
+        .ok_or_else(|| InterchainAccountError::InvalidMetadata {
+            reason: format!("connection {connection_id} has no counterparty"),
+        })?;
+
+    Ok((connection_id, counterparty_connection_id))
+}
+
+/// Validates the opening of a new channel for the interchain account whose
+/// active channel is the given one, if any.
+///
+/// The active channel must be closed, and the new channel must keep the
+/// encoding and the transaction type of its metadata.
+pub(crate) fn validate_reopened_channel(
+    ctx: &impl ValidationContext,
+    connection_id: &ConnectionId,
+    port_id: &PortId,
+    controller_port_id: &PortId,
+    active_channel_id: Option<ChannelId>,
+    metadata: &Metadata,
+) -> Result<(), InterchainAccountError> {
+    let Some(channel_id) = active_channel_id else {
+        return Ok(());
+    };
+
+    let channel_end = ctx.channel_end(&ChannelEndPath::new(port_id, &channel_id))?;
+
+    if channel_end.state() != &State::Closed {
+        return Err(InterchainAccountError::ActiveChannelAlreadySet {
+            connection_id: connection_id.clone(),
+            port_id: controller_port_id.clone(),
+            channel_id,
+        });
+    }
+
+    let previous = Metadata::from_version(channel_end.version())?;
+
+    if previous.encoding != metadata.encoding || previous.tx_type != metadata.tx_type {
+        return Err(InterchainAccountError::InvalidMetadata {
+            reason: "encoding and tx_type must match the previous channel".to_string(),
+        });
+    }
+
+    Ok(())
+}
@@ -0,0 +1,424 @@
+//! Defines the codecs of the transactions executed by the interchain
+//! accounts, in the encoding negotiated in the metadata of their channels.
+//!
+//! The Cosmos SDK hosts support the `proto3` encoding, the protobuf encoding
+//! of a `CosmosTx`, and the `proto3json` encoding, its JSON encoding, in
+//! which each message is a JSON object of its fields with its type URL as
+//! `@type`:
+//!
+//! ```json
+//! {
+//!   "messages": [
+//!     {
+//!       "@type": "/cosmos.bank.v1beta1.MsgSend",
+//!       "from_address": "cosmos1...",
+//!       "to_address": "cosmos1...",
+//!       "amount": [{ "denom": "stake", "amount": "1000" }]
+//!     }
+//!   ]
+//! }
+//! ```
+//!
+//! Hosts whose transactions are not Cosmos SDK ones implement [`TxCodec`]
+//! with their own encodings.
+use core::fmt;
+
+use ibc_core::primitives::prelude::*;
+use ibc_core::primitives::proto::Any;
+use serde::de::{Deserializer, MapAccess, SeqAccess, Visitor};
+use serde::{Deserialize, Serialize, Serializer};
+
+use crate::error::InterchainAccountError;
+use crate::metadata::{ENCODING_PROTO3_JSON, ENCODING_PROTOBUF};
+use crate::packet::{decode_cosmos_tx, encode_cosmos_tx};
+
+/// The key of the type URL of the messages in the `proto3json` encoding.
+const JSON_TYPE_URL_KEY: &str = "@type";
+
+/// Encodes and decodes the messages of the transactions executed by the
+/// interchain accounts, in the encodings it supports.
+pub trait TxCodec {
+    /// Returns whether the given encoding is supported.
+    fn supports_encoding(&self, encoding: &str) -> bool;
+
+    /// Encodes the given messages as a transaction in the given encoding.
+    fn encode_tx(
+        &self,
+        encoding: &str,
+        messages: Vec<Any>,
+    ) -> Result<Vec<u8>, InterchainAccountError>;
+
+    /// Decodes the messages of the given transaction in the given encoding,
+    /// which must have some.
+    fn decode_tx(&self, encoding: &str, data: &[u8]) -> Result<Vec<Any>, InterchainAccountError>;
+
+    /// Returns an error if the given encoding is not supported.
+    fn validate_encoding(&self, encoding: &str) -> Result<(), InterchainAccountError> {
+        if !self.supports_encoding(encoding) {
+            return Err(unsupported_encoding(encoding));
+        }
+
+        Ok(())
+    }
+}
+
+fn unsupported_encoding(encoding: &str) -> InterchainAccountError {
+    InterchainAccountError::UnsupportedEncoding {
+        encoding: encoding.to_string(),
+    }
+}
+
+/// The codec of the `proto3` encoding of the Cosmos SDK transactions, the
+/// default one of the hosts and controllers.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct ProtobufTxCodec;
+
+impl TxCodec for ProtobufTxCodec {
+    fn supports_encoding(&self, encoding: &str) -> bool {
+        encoding == ENCODING_PROTOBUF
+    }
+
+    fn encode_tx(
+        &self,
+        encoding: &str,
+        messages: Vec<Any>,
+    ) -> Result<Vec<u8>, InterchainAccountError> {
+        self.validate_encoding(encoding)?;
+
+        Ok(encode_cosmos_tx(messages))
+    }
+
+    fn decode_tx(&self, encoding: &str, data: &[u8]) -> Result<Vec<Any>, InterchainAccountError> {
+        self.validate_encoding(encoding)?;
+
+        decode_cosmos_tx(data)
+    }
+}
+
+/// Converts the messages known to the host between their protobuf encoding
+/// and the JSON object of their fields, as the Cosmos SDK codecs do with the
+/// registered message types.
+pub trait JsonMessageCodec {
+    /// Encodes the fields of the given message as a JSON object, without its
+    /// type URL.
+    fn message_to_json(&self, message: &Any) -> Result<Vec<u8>, InterchainAccountError>;
+
+    /// Decodes the message of the given type URL from the JSON object of its
+    /// fields.
+    fn message_from_json(&self, type_url: &str, json: &[u8])
+        -> Result<Any, InterchainAccountError>;
+}
+
+/// The codec of both the `proto3` and the `proto3json` encodings of the
+/// Cosmos SDK transactions, whose messages are converted to and from JSON by
+/// the given [`JsonMessageCodec`].
+#[derive(Clone, Debug, Default)]
+pub struct CosmosTxCodec<J> {
+    pub json_codec: J,
+}
+
+impl<J> CosmosTxCodec<J> {
+    pub fn new(json_codec: J) -> Self {
+        Self { json_codec }
+    }
+}
+
+impl<J: JsonMessageCodec> TxCodec for CosmosTxCodec<J> {
+    fn supports_encoding(&self, encoding: &str) -> bool {
+        encoding == ENCODING_PROTOBUF || encoding == ENCODING_PROTO3_JSON
+    }
+
+    fn encode_tx(
+        &self,
+        encoding: &str,
+        messages: Vec<Any>,
+    ) -> Result<Vec<u8>, InterchainAccountError> {
+        match encoding {
+            ENCODING_PROTOBUF => Ok(encode_cosmos_tx(messages)),
+            ENCODING_PROTO3_JSON => encode_json_tx(&self.json_codec, messages),
+            _ => Err(unsupported_encoding(encoding)),
+        }
+    }
+
+    fn decode_tx(&self, encoding: &str, data: &[u8]) -> Result<Vec<Any>, InterchainAccountError> {
+        match encoding {
+            ENCODING_PROTOBUF => decode_cosmos_tx(data),
+            ENCODING_PROTO3_JSON => decode_json_tx(&self.json_codec, data),
+            _ => Err(unsupported_encoding(encoding)),
+        }
+    }
+}
+
+/// The JSON encoding of a `CosmosTx`.
+#[derive(Serialize, Deserialize)]
+struct JsonCosmosTx {
+    messages: Vec<BTreeMap<String, JsonValue>>,
+}
+
+fn encode_json_tx(
+    json_codec: &impl JsonMessageCodec,
+    messages: Vec<Any>,
+) -> Result<Vec<u8>, InterchainAccountError> {
+    let tx_encoding = |reason: String| InterchainAccountError::TxEncoding { reason };
+
+    let messages = messages
+        .iter()
+        .map(|message| {
+            let json = json_codec.message_to_json(message)?;
+
+            let mut fields = serde_json::from_slice::<BTreeMap<String, JsonValue>>(&json)
+                .map_err(|e| tx_encoding(e.to_string()))?;
+            fields.insert(
+                JSON_TYPE_URL_KEY.to_string(),
+                JsonValue::String(message.type_url.clone()),
+            );
+
+            Ok(fields)
+        })
+        .collect::<Result<_, InterchainAccountError>>()?;
+
+    serde_json::to_vec(&JsonCosmosTx { messages }).map_err(|e| tx_encoding(e.to_string()))
+}
+
+fn decode_json_tx(
+    json_codec: &impl JsonMessageCodec,
+    data: &[u8],
+) -> Result<Vec<Any>, InterchainAccountError> {
+    let tx_decoding = |reason: String| InterchainAccountError::TxDecoding { reason };
+
+    let tx =
+        serde_json::from_slice::<JsonCosmosTx>(data).map_err(|e| tx_decoding(e.to_string()))?;
+
+    if tx.messages.is_empty() {
+        return Err(InterchainAccountError::EmptyTx);
+    }
+
+    tx.messages
+        .into_iter()
+        .map(|mut fields| {
+            let type_url = match fields.remove(JSON_TYPE_URL_KEY) {
+                Some(JsonValue::String(type_url)) => type_url,
+                _ => {
+                    return Err(tx_decoding(format!(
+                        "message without `{JSON_TYPE_URL_KEY}`"
+                    )))
+                }
+            };
+
+            let json = serde_json::to_vec(&fields).map_err(|e| tx_decoding(e.to_string()))?;
+
+            json_codec.message_from_json(&type_url, &json)
+        })
+        .collect()
+}
+
+/// An arbitrary JSON value, in which the fields of the messages are carried
+/// between the transactions and the [`JsonMessageCodec`].
+#[derive(Clone, Debug, PartialEq)]
+enum JsonValue {
+    Null,
+    Bool(bool),
+    Unsigned(u64),
+    Signed(i64),
+    Float(f64),
+    String(String),
+    Array(Vec<JsonValue>),
+    Object(BTreeMap<String, JsonValue>),
+}
+
+impl Serialize for JsonValue {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Self::Null => serializer.serialize_unit(),
+            Self::Bool(value) => serializer.serialize_bool(*value),
+            Self::Unsigned(value) => serializer.serialize_u64(*value),
+            Self::Signed(value) => serializer.serialize_i64(*value),
+            Self::Float(value) => serializer.serialize_f64(*value),
+            Self::String(value) => serializer.serialize_str(value),
+            Self::Array(values) => serializer.collect_seq(values),
+            Self::Object(fields) => serializer.collect_map(fields),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for JsonValue {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_any(JsonValueVisitor)
+    }
+}
+
+struct JsonValueVisitor;
+
+impl<'de> Visitor<'de> for JsonValueVisitor {
+    type Value = JsonValue;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("a JSON value")
+    }
+
+    fn visit_unit<E>(self) -> Result<JsonValue, E> {
+        Ok(JsonValue::Null)
+    }
+
+    fn visit_none<E>(self) -> Result<JsonValue, E> {
+        Ok(JsonValue::Null)
+    }
+
+    fn visit_some<D: Deserializer<'de>>(self, deserializer: D) -> Result<JsonValue, D::Error> {
+        JsonValue::deserialize(deserializer)
+    }
+
+    fn visit_bool<E>(self, value: bool) -> Result<JsonValue, E> {
+        Ok(JsonValue::Bool(value))
+    }
+
+    fn visit_u64<E>(self, value: u64) -> Result<JsonValue, E> {
+        Ok(JsonValue::Unsigned(value))
+    }
+
+    fn visit_i64<E>(self, value: i64) -> Result<JsonValue, E> {
+        Ok(JsonValue::Signed(value))
+    }
+
+    fn visit_f64<E>(self, value: f64) -> Result<JsonValue, E> {
+        Ok(JsonValue::Float(value))
+    }
+
+    fn visit_str<E>(self, value: &str) -> Result<JsonValue, E> {
+        Ok(JsonValue::String(value.to_string()))
+    }
+
+    fn visit_string<E>(self, value: String) -> Result<JsonValue, E> {
+        Ok(JsonValue::String(value))
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<JsonValue, A::Error> {
+        let mut values = Vec::new();
+
+        while let Some(value) = seq.next_element()? {
+            values.push(value);
+        }
+
+        Ok(JsonValue::Array(values))
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<JsonValue, A::Error> {
+        let mut fields = BTreeMap::new();
+
+        while let Some((key, value)) = map.next_entry()? {
+            fields.insert(key, value);
+        }
+
+        Ok(JsonValue::Object(fields))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Carries the JSON object of the fields of the messages as their value.
+    struct DummyJsonCodec;
+
+    impl JsonMessageCodec for DummyJsonCodec {
+        fn message_to_json(&self, message: &Any) -> Result<Vec<u8>, InterchainAccountError> {
+            Ok(message.value.clone())
+        }
+
+        fn message_from_json(
+            &self,
+            type_url: &str,
+            json: &[u8],
+        ) -> Result<Any, InterchainAccountError> {
+            Ok(Any {
+                type_url: type_url.to_string(),
+                value: json.to_vec(),
+            })
+        }
+    }
+
+    fn dummy_messages() -> Vec<Any> {
+        vec![Any {
+            type_url: "/cosmos.bank.v1beta1.MsgSend".to_string(),
+            value: br#"{"amount":[{"amount":"1000","denom":"stake"}],"from_address":"cosmos1from","to_address":"cosmos1to"}"#.to_vec(),
+        }]
+    }
+
+    #[test]
+    fn test_protobuf_codec() {
+        let codec = ProtobufTxCodec;
+        assert!(codec.supports_encoding(ENCODING_PROTOBUF));
+        assert!(!codec.supports_encoding(ENCODING_PROTO3_JSON));
+
+        let tx = codec
+            .encode_tx(ENCODING_PROTOBUF, dummy_messages())
+            .unwrap();
+        assert_eq!(
+            codec.decode_tx(ENCODING_PROTOBUF, &tx).unwrap(),
+            dummy_messages()
+        );
+
+        assert!(matches!(
+            codec.decode_tx(ENCODING_PROTO3_JSON, &tx),
+            Err(InterchainAccountError::UnsupportedEncoding { .. })
+        ));
+        assert!(matches!(
+            codec.validate_encoding("amino"),
+            Err(InterchainAccountError::UnsupportedEncoding { .. })
+        ));
+    }
+
+    #[test]
+    fn test_cosmos_codec() {
+        let codec = CosmosTxCodec::new(DummyJsonCodec);
+        assert!(codec.supports_encoding(ENCODING_PROTOBUF));
+        assert!(codec.supports_encoding(ENCODING_PROTO3_JSON));
+
+        let tx = codec
+            .encode_tx(ENCODING_PROTO3_JSON, dummy_messages())
+            .unwrap();
+        assert_eq!(
+            String::from_utf8(tx.clone()).unwrap(),
+            r#"{"messages":[{"@type":"/cosmos.bank.v1beta1.MsgSend","amount":[{"amount":"1000","denom":"stake"}],"from_address":"cosmos1from","to_address":"cosmos1to"}]}"#
+        );
+        assert_eq!(
+            codec.decode_tx(ENCODING_PROTO3_JSON, &tx).unwrap(),
+            dummy_messages()
+        );
+
+        let tx = codec
+            .encode_tx(ENCODING_PROTOBUF, dummy_messages())
+            .unwrap();
+        assert_eq!(
+            codec.decode_tx(ENCODING_PROTOBUF, &tx).unwrap(),
+            dummy_messages()
+        );
+    }
+
+    #[test]
+    fn test_invalid_json_tx() {
+        let codec = CosmosTxCodec::new(DummyJsonCodec);
+
+        assert!(matches!(
+            codec.decode_tx(ENCODING_PROTO3_JSON, br#"{"messages":[]}"#),
+            Err(InterchainAccountError::EmptyTx)
+        ));
+        assert!(matches!(
+            codec.decode_tx(ENCODING_PROTO3_JSON, br#"{"messages":[{"amount":"1"}]}"#),
+            Err(InterchainAccountError::TxDecoding { .. })
+        ));
+        assert!(matches!(
+            codec.decode_tx(ENCODING_PROTO3_JSON, b"\x0a\x00"),
+            Err(InterchainAccountError::TxDecoding { .. })
+        ));
+
+        let message = Any {
+            type_url: "/cosmos.bank.v1beta1.MsgSend".to_string(),
+            value: b"[1]".to_vec(),
+        };
+        assert!(matches!(
+            codec.encode_tx(ENCODING_PROTO3_JSON, vec![message]),
+            Err(InterchainAccountError::TxEncoding { .. })
+        ));
+    }
+}
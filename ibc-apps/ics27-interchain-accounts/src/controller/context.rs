@@ -0,0 +1,106 @@
+//! Defines the context traits the interchain accounts controller implements
+//! to persist the channels of the interchain accounts of its owners, and to
+//! notify the applications driving them.
+use ibc_core::channel::types::packet::Packet;
+use ibc_core::host::types::identifiers::{ChannelId, ConnectionId, PortId};
+use ibc_core::primitives::prelude::*;
+use ibc_core::router::types::module::ModuleExtras;
+
+use crate::codec::{ProtobufTxCodec, TxCodec};
+use crate::controller::ControllerParams;
+use crate::error::InterchainAccountError;
+use crate::packet::TxAcknowledgement;
+
+/// Methods required in interchain accounts controller validation, to be
+/// implemented by the host.
+pub trait InterchainAccountControllerValidationContext {
+    /// Returns the parameters of the interchain accounts controller.
+    fn controller_params(&self) -> Result<ControllerParams, InterchainAccountError>;
+
+    /// Returns the active channel of the interchain account of the given
+    /// controller port over the given connection of the controller, if any.
+    fn controller_active_channel(
+        &self,
+        connection_id: &ConnectionId,
+        controller_port_id: &PortId,
+    ) -> Result<Option<ChannelId>, InterchainAccountError>;
+
+    /// Returns the address on the host of the interchain account of the given
+    /// controller port over the given connection of the controller, if
+    /// registered.
+    fn controller_account_address(
+        &self,
+        connection_id: &ConnectionId,
+        controller_port_id: &PortId,
+    ) -> Result<Option<String>, InterchainAccountError>;
+
+    /// Returns the codec of the transactions sent to the interchain accounts,
+    /// whose encodings the controller proposes for their channels.
+    ///
+    /// Defaults to the `proto3` encoding of the Cosmos SDK transactions.
+    fn controller_tx_codec(&self) -> &dyn TxCodec {
+        &ProtobufTxCodec
+    }
+}
+
+/// Methods required in interchain accounts controller execution, to be
+/// implemented by the host.
+///
+/// The callbacks notify the applications which drive the interchain accounts
+/// of their owners, as the underlying applications of the controller
+/// middleware of `ibc-go` are.
+pub trait InterchainAccountControllerExecutionContext:
+    InterchainAccountControllerValidationContext
+{
+    /// Stores the active channel of the interchain account of the given
+    /// controller port over the given connection of the controller.
+    fn store_controller_active_channel(
+        &mut self,
+        connection_id: &ConnectionId,
+        controller_port_id: &PortId,
+        channel_id: ChannelId,
+    ) -> Result<(), InterchainAccountError>;
+
+    /// Stores the address on the host of the interchain account of the given
+    /// controller port over the given connection of the controller.
+    fn store_controller_account_address(
+        &mut self,
+        connection_id: &ConnectionId,
+        controller_port_id: &PortId,
+        address: String,
+    ) -> Result<(), InterchainAccountError>;
+
+    /// Called once the channel of the interchain account of the given
+    /// controller port is opened, with the address of the account on the
+    /// host.
+    fn on_account_registered_execute(
+        &mut self,
+        _connection_id: &ConnectionId,
+        _controller_port_id: &PortId,
+        _address: &str,
+    ) -> Result<ModuleExtras, InterchainAccountError> {
+        Ok(ModuleExtras::empty())
+    }
+
+    /// Called once the transaction of the given packet is acknowledged by the
+    /// host.
+    fn on_tx_acknowledgement_execute(
+        &mut self,
+        _packet: &Packet,
+        _acknowledgement: &TxAcknowledgement,
+    ) -> Result<ModuleExtras, InterchainAccountError> {
+        Ok(ModuleExtras::empty())
+    }
+
+    /// Called once the transaction of the given packet timed out, which
+    /// closes the channel of the interchain account.
+    ///
+    /// See [`reactivate_interchain_account`](crate::controller::reactivate_interchain_account)
+    /// to reopen a channel for the account.
+    fn on_tx_timeout_execute(
+        &mut self,
+        _packet: &Packet,
+    ) -> Result<ModuleExtras, InterchainAccountError> {
+        Ok(ModuleExtras::empty())
+    }
+}
@@ -0,0 +1,292 @@
+//! Implements the processing of the messages of the owners of the controller,
+//! which register interchain accounts and send them transactions.
+use ibc_core::channel::handler::{send_packet_execute, send_packet_validate};
+use ibc_core::channel::types::channel::{ChannelEnd, State};
+use ibc_core::channel::types::msgs::MsgChannelOpenInit;
+use ibc_core::channel::types::packet::Packet;
+use ibc_core::channel::types::timeout::TimeoutHeight;
+use ibc_core::host::types::identifiers::{ChannelId, ConnectionId, PortId, Sequence};
+use ibc_core::host::types::path::{ChannelEndPath, SeqSendPath};
+use ibc_core::host::{ExecutionContext, ValidationContext};
+use ibc_core::primitives::prelude::*;
+use ibc_core::primitives::Signer;
+
+use crate::channel::channel_connections;
+use crate::controller::{
+    InterchainAccountControllerExecutionContext, InterchainAccountControllerValidationContext,
+    MsgRegisterInterchainAccount, MsgSendTx,
+};
+use crate::error::InterchainAccountError;
+use crate::metadata::Metadata;
+use crate::{CONTROLLER_PORT_PREFIX, HOST_PORT_ID_STR};
+
+/// Returns the controller port of the given owner, `icacontroller-{owner}`.
+pub fn controller_port_id(owner: &Signer) -> Result<PortId, InterchainAccountError> {
+    if owner.as_ref().trim().is_empty() {
+        return Err(InterchainAccountError::InvalidOwner {
+            owner: owner.to_string(),
+        });
+    }
+
+    Ok(PortId::new(format!("{CONTROLLER_PORT_PREFIX}{owner}"))?)
+}
+
+fn controller_enabled_validate(
+    ctx: &impl InterchainAccountControllerValidationContext,
+) -> Result<(), InterchainAccountError> {
+    if !ctx.controller_params()?.controller_enabled {
+        return Err(InterchainAccountError::ControllerDisabled);
+    }
+
+    Ok(())
+}
+
+/// Validates the registration of an interchain account, and returns the
+/// message opening its channel from the controller port of the owner.
+///
+/// The host submits the returned message to the core handler within the
+/// same transaction, the router routing the controller port to the
+/// controller module, whose channel handshake callbacks register the
+/// interchain account once the channel is opened.
+///
+/// Registering an interchain account whose active channel is closed, as
+/// ordered channels are once a packet times out, reopens a channel for the
+/// account. Unless a version is given, the metadata of the closed channel is
+/// proposed again, so that the host executes the transactions of the new
+/// channel by the same account, in the same encoding.
+pub fn register_interchain_account<Ctx>(
+    ctx_a: &Ctx,
+    msg: MsgRegisterInterchainAccount,
+) -> Result<MsgChannelOpenInit, InterchainAccountError>
+where
+    Ctx: InterchainAccountControllerValidationContext + ValidationContext,
+{
+    controller_enabled_validate(ctx_a)?;
+
+    let port_id_on_a = controller_port_id(&msg.owner)?;
+    let closed_chan_end_on_a = closed_active_channel(ctx_a, &msg.connection_id, &port_id_on_a)?;
+
+    let version_proposal = match closed_chan_end_on_a {
+        _ if !msg.version.is_empty() => msg.version,
+        Some(chan_end_on_a) => chan_end_on_a.version().clone(),
+        None => {
+            let (_, host_connection_id) =
+                channel_connections(ctx_a, core::slice::from_ref(&msg.connection_id))?;
+
+            Metadata::new(msg.connection_id.clone(), host_connection_id).to_version()
+        }
+    };
+
+    Ok(MsgChannelOpenInit {
+        port_id_on_a,
+        connection_hops_on_a: vec![msg.connection_id],
+        port_id_on_b: PortId::new(HOST_PORT_ID_STR.to_string())?,
+        ordering: msg.ordering,
+        signer: msg.owner,
+        version_proposal,
+    })
+}
+
+/// Returns the message reopening the closed active channel of the interchain
+/// account of the given owner over the given connection, with the ordering
+/// and the metadata of the closed channel, which preserves the address of
+/// the account.
+///
+/// The core handler closes the channel once the
+/// [`on_tx_timeout_execute`](InterchainAccountControllerExecutionContext::on_tx_timeout_execute)
+/// callback returns, so the host submits the returned message after the
+/// timeout is processed, for instance right after the timeout message of the
+/// transaction, so that the account recovers without its owner registering
+/// it again.
+pub fn reactivate_interchain_account<Ctx>(
+    ctx_a: &Ctx,
+    owner: Signer,
+    connection_id: ConnectionId,
+) -> Result<MsgChannelOpenInit, InterchainAccountError>
+where
+    Ctx: InterchainAccountControllerValidationContext + ValidationContext,
+{
+    let port_id_on_a = controller_port_id(&owner)?;
+
+    let chan_end_on_a =
+        closed_active_channel(ctx_a, &connection_id, &port_id_on_a)?.ok_or_else(|| {
+            InterchainAccountError::ActiveChannelNotFound {
+                connection_id: connection_id.clone(),
+                port_id: port_id_on_a,
+            }
+        })?;
+
+    register_interchain_account(
+        ctx_a,
+        MsgRegisterInterchainAccount {
+            owner,
+            connection_id,
+            version: chan_end_on_a.version().clone(),
+            ordering: *chan_end_on_a.ordering(),
+        },
+    )
+}
+
+/// Returns the active channel of the interchain account of the given
+/// controller port over the given connection, if any, which must be closed.
+fn closed_active_channel<Ctx>(
+    ctx_a: &Ctx,
+    connection_id: &ConnectionId,
+    port_id_on_a: &PortId,
+) -> Result<Option<ChannelEnd>, InterchainAccountError>
+where
+    Ctx: InterchainAccountControllerValidationContext + ValidationContext,
+{
+    let Some(channel_id) = ctx_a.controller_active_channel(connection_id, port_id_on_a)? else {
+        return Ok(None);
+    };
+
+    let channel_end = ctx_a.channel_end(&ChannelEndPath::new(port_id_on_a, &channel_id))?;
+
+    if channel_end.state() != &State::Closed {
+        return Err(InterchainAccountError::ActiveChannelAlreadySet {
+            connection_id: connection_id.clone(),
+            port_id: port_id_on_a.clone(),
+            channel_id,
+        });
+    }
+
+    Ok(Some(channel_end))
+}
+
+/// Sends a transaction to an interchain account. Equivalent to calling
+/// [`send_tx_validate`], followed by [`send_tx_execute`].
+pub fn send_tx<Ctx>(ctx_a: &mut Ctx, msg: MsgSendTx) -> Result<Sequence, InterchainAccountError>
+where
+    Ctx: InterchainAccountControllerExecutionContext + ExecutionContext,
+{
+    send_tx_validate(ctx_a, msg.clone())?;
+    send_tx_execute(ctx_a, msg)
+}
+
+/// Validates the transaction sent to an interchain account. If this
+/// succeeds, then it is legal to send it with [`send_tx_execute`].
+pub fn send_tx_validate<Ctx>(ctx_a: &Ctx, msg: MsgSendTx) -> Result<(), InterchainAccountError>
+where
+    Ctx: InterchainAccountControllerValidationContext + ValidationContext,
+{
+    controller_enabled_validate(ctx_a)?;
+
+    let packet = tx_packet(ctx_a, msg)?;
+
+    send_packet_validate(ctx_a, &packet)?;
+
+    Ok(())
+}
+
+/// Sends the transaction to the interchain account of the owner over the
+/// active channel of its account, and returns the sequence of its packet.
+pub fn send_tx_execute<Ctx>(
+    ctx_a: &mut Ctx,
+    msg: MsgSendTx,
+) -> Result<Sequence, InterchainAccountError>
+where
+    Ctx: InterchainAccountControllerExecutionContext + ExecutionContext,
+{
+    let packet = tx_packet(ctx_a, msg)?;
+    let sequence = packet.seq_on_a;
+
+    send_packet_execute(ctx_a, packet)?;
+
+    Ok(sequence)
+}
+
+/// Returns the packet of the given transaction over the active channel of
+/// the interchain account of its owner, which times out after the relative
+/// timeout of the message.
+fn tx_packet<Ctx>(ctx_a: &Ctx, msg: MsgSendTx) -> Result<Packet, InterchainAccountError>
+where
+    Ctx: InterchainAccountControllerValidationContext + ValidationContext,
+{
+    if msg.relative_timeout.is_zero() {
+        return Err(InterchainAccountError::ZeroRelativeTimeout);
+    }
+
+    msg.packet_data.validate_basic()?;
+
+    let port_id_on_a = controller_port_id(&msg.owner)?;
+    let chan_id_on_a = open_active_channel(ctx_a, &msg, &port_id_on_a)?;
+
+    let chan_end_on_a = ctx_a.channel_end(&ChannelEndPath::new(&port_id_on_a, &chan_id_on_a))?;
+    let port_id_on_b = chan_end_on_a.counterparty().port_id().clone();
+    let chan_id_on_b = chan_end_on_a
+        .counterparty()
+        .channel_id()
+        .cloned()
+        .ok_or_else(|| InterchainAccountError::ActiveChannelNotFound {
+            connection_id: msg.connection_id.clone(),
+            port_id: port_id_on_a.clone(),
+        })?;
+
+    let seq_on_a = ctx_a.get_next_sequence_send(&SeqSendPath::new(&port_id_on_a, &chan_id_on_a))?;
+    let timeout_timestamp_on_b = (ctx_a.host_timestamp()? + msg.relative_timeout)?;
+
+    Ok(Packet {
+        seq_on_a,
+        port_id_on_a,
+        chan_id_on_a,
+        port_id_on_b,
+        chan_id_on_b,
+        data: msg.packet_data.to_bytes(),
+        timeout_height_on_b: TimeoutHeight::Never,
+        timeout_timestamp_on_b,
+    })
+}
+
+/// Returns the active channel of the interchain account of the owner of the
+/// given message, which must be open.
+fn open_active_channel<Ctx>(
+    ctx_a: &Ctx,
+    msg: &MsgSendTx,
+    port_id_on_a: &PortId,
+) -> Result<ChannelId, InterchainAccountError>
+where
+    Ctx: InterchainAccountControllerValidationContext + ValidationContext,
+{
+    let not_found = || InterchainAccountError::ActiveChannelNotFound {
+        connection_id: msg.connection_id.clone(),
+        port_id: port_id_on_a.clone(),
+    };
+
+    let channel_id = ctx_a
+        .controller_active_channel(&msg.connection_id, port_id_on_a)?
+        .ok_or_else(not_found)?;
+
+    if !ctx_a
+        .channel_end(&ChannelEndPath::new(port_id_on_a, &channel_id))?
+        .is_open()
+    {
+        return Err(not_found());
+    }
+
+    Ok(channel_id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_controller_port_id() {
+        assert_eq!(
+            controller_port_id(&"cosmos1owner".to_string().into())
+                .unwrap()
+                .as_str(),
+            "icacontroller-cosmos1owner"
+        );
+
+        assert!(matches!(
+            controller_port_id(&" ".to_string().into()),
+            Err(InterchainAccountError::InvalidOwner { .. })
+        ));
+        assert!(matches!(
+            controller_port_id(&"a".repeat(128).into()),
+            Err(InterchainAccountError::InvalidIdentifier(_))
+        ));
+    }
+}
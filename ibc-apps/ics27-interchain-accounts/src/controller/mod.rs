@@ -0,0 +1,17 @@
+//! Implements the controller side of the interchain accounts, with which the
+//! owners of the controller register interchain accounts on the hosts and
+//! send them the transactions to execute.
+//!
+//! Each owner has its own controller port, `icacontroller-{owner}`, which the
+//! router of the host must route to the controller module.
+mod context;
+mod handler;
+mod module;
+mod msgs;
+mod params;
+
+pub use context::*;
+pub use handler::*;
+pub use module::*;
+pub use msgs::*;
+pub use params::*;
@@ -0,0 +1,364 @@
+//! Implements the module callbacks of the interchain accounts controller,
+//! which opens the channels of the interchain accounts of its owners, and
+//! notifies the applications driving them of the outcome of their
+//! transactions.
+use ibc_core::channel::types::acknowledgement::{Acknowledgement, AcknowledgementStatus};
+use ibc_core::channel::types::channel::{Counterparty, Order};
+use ibc_core::channel::types::packet::Packet;
+use ibc_core::channel::types::Version;
+use ibc_core::host::types::identifiers::{ChannelId, ConnectionId, PortId};
+use ibc_core::host::types::path::ChannelEndPath;
+use ibc_core::host::ValidationContext;
+use ibc_core::primitives::prelude::*;
+use ibc_core::primitives::Signer;
+use ibc_core::router::types::module::ModuleExtras;
+
+use crate::channel::{channel_connections, validate_reopened_channel};
+use crate::controller::{
+    InterchainAccountControllerExecutionContext, InterchainAccountControllerValidationContext,
+};
+use crate::error::InterchainAccountError;
+use crate::metadata::Metadata;
+use crate::packet::decode_tx_acknowledgement;
+use crate::{CONTROLLER_PORT_PREFIX, HOST_PORT_ID_STR};
+
+fn unsupported(description: &str) -> InterchainAccountError {
+    InterchainAccountError::UnsupportedOperation {
+        description: description.to_string(),
+    }
+}
+
+/// Returns the metadata proposed for a channel over the given connection
+/// hops, the default one of the connection if the version is empty.
+fn proposed_metadata(
+    ctx: &impl ValidationContext,
+    connection_hops: &[ConnectionId],
+    version: &Version,
+) -> Result<Metadata, InterchainAccountError> {
+    if version.is_empty() {
+        let (controller_connection_id, host_connection_id) =
+            channel_connections(ctx, connection_hops)?;
+
+        return Ok(Metadata::new(controller_connection_id, host_connection_id));
+    }
+
+    Metadata::from_version(version)
+}
+
+/// Validates the channel opened for an interchain account from the controller
+/// port of its owner, which either registers the account or replaces its
+/// closed active channel.
+pub fn on_chan_open_init_validate<Ctx>(
+    ctx: &Ctx,
+    order: Order,
+    connection_hops: &[ConnectionId],
+    port_id: &PortId,
+    _channel_id: &ChannelId,
+    counterparty: &Counterparty,
+    version: &Version,
+) -> Result<(), InterchainAccountError>
+where
+    Ctx: InterchainAccountControllerValidationContext + ValidationContext,
+{
+    if !ctx.controller_params()?.controller_enabled {
+        return Err(InterchainAccountError::ControllerDisabled);
+    }
+
+    if order != Order::Ordered {
+        return Err(InterchainAccountError::ChannelNotOrdered {
+            expect_order: Order::Ordered,
+            got_order: order,
+        });
+    }
+
+    if !port_id.as_str().starts_with(CONTROLLER_PORT_PREFIX) {
+        return Err(InterchainAccountError::InvalidControllerPort {
+            port_id: port_id.clone(),
+        });
+    }
+
+    if counterparty.port_id().as_str() != HOST_PORT_ID_STR {
+        return Err(InterchainAccountError::InvalidHostPort {
+            port_id: counterparty.port_id().clone(),
+        });
+    }
+
+    let metadata = proposed_metadata(ctx, connection_hops, version)?;
+    metadata.validate_basic()?;
+    ctx.controller_tx_codec()
+        .validate_encoding(&metadata.encoding)?;
+
+    let (controller_connection_id, host_connection_id) = channel_connections(ctx, connection_hops)?;
+    metadata.validate_connections(&controller_connection_id, &host_connection_id)?;
+
+    validate_reopened_channel(
+        ctx,
+        &controller_connection_id,
+        port_id,
+        port_id,
+        ctx.controller_active_channel(&controller_connection_id, port_id)?,
+        &metadata,
+    )?;
+
+    if let Some(address) = ctx.controller_account_address(&controller_connection_id, port_id)? {
+        if !metadata.address.is_empty() && metadata.address != address {
+            return Err(InterchainAccountError::AddressMismatch {
+                expected: address,
+                actual: metadata.address,
+            });
+        }
+    }
+
+    Ok(())
+}
+
+/// Returns the proposed metadata of the channel, as its version.
+pub fn on_chan_open_init_execute<Ctx>(
+    ctx: &mut Ctx,
+    _order: Order,
+    connection_hops: &[ConnectionId],
+    _port_id: &PortId,
+    _channel_id: &ChannelId,
+    _counterparty: &Counterparty,
+    version: &Version,
+) -> Result<(ModuleExtras, Version), InterchainAccountError>
+where
+    Ctx: InterchainAccountControllerExecutionContext + ValidationContext,
+{
+    let metadata = proposed_metadata(ctx, connection_hops, version)?;
+
+    Ok((ModuleExtras::empty(), metadata.to_version()))
+}
+
+pub fn on_chan_open_try_validate(
+    _ctx: &impl InterchainAccountControllerValidationContext,
+    _order: Order,
+    _connection_hops: &[ConnectionId],
+    _port_id: &PortId,
+    _channel_id: &ChannelId,
+    _counterparty: &Counterparty,
+    _counterparty_version: &Version,
+) -> Result<(), InterchainAccountError> {
+    Err(unsupported(
+        "channel handshakes must be initiated by the controller",
+    ))
+}
+
+pub fn on_chan_open_try_execute(
+    _ctx: &mut impl InterchainAccountControllerExecutionContext,
+    _order: Order,
+    _connection_hops: &[ConnectionId],
+    _port_id: &PortId,
+    _channel_id: &ChannelId,
+    _counterparty: &Counterparty,
+    _counterparty_version: &Version,
+) -> Result<(ModuleExtras, Version), InterchainAccountError> {
+    Err(unsupported(
+        "channel handshakes must be initiated by the controller",
+    ))
+}
+
+/// Validates the metadata of the host, which sets the address of the
+/// interchain account it registered.
+pub fn on_chan_open_ack_validate<Ctx>(
+    ctx: &Ctx,
+    port_id: &PortId,
+    channel_id: &ChannelId,
+    counterparty_version: &Version,
+) -> Result<(), InterchainAccountError>
+where
+    Ctx: InterchainAccountControllerValidationContext + ValidationContext,
+{
+    let metadata = Metadata::from_version(counterparty_version)?;
+    metadata.validate_basic()?;
+    ctx.controller_tx_codec()
+        .validate_encoding(&metadata.encoding)?;
+
+    if metadata.address.is_empty() {
+        return Err(InterchainAccountError::InvalidMetadata {
+            reason: "the host must set the interchain account address".to_string(),
+        });
+    }
+
+    let channel_end = ctx.channel_end(&ChannelEndPath::new(port_id, channel_id))?;
+    let (controller_connection_id, host_connection_id) =
+        channel_connections(ctx, channel_end.connection_hops())?;
+    metadata.validate_connections(&controller_connection_id, &host_connection_id)?;
+
+    if let Some(active_channel_id) =
+        ctx.controller_active_channel(&controller_connection_id, port_id)?
+    {
+        let active_channel_end =
+            ctx.channel_end(&ChannelEndPath::new(port_id, &active_channel_id))?;
+
+        if active_channel_end.is_open() {
+            return Err(InterchainAccountError::ActiveChannelAlreadySet {
+                connection_id: controller_connection_id,
+                port_id: port_id.clone(),
+                channel_id: active_channel_id,
+            });
+        }
+    }
+
+    if let Some(address) = ctx.controller_account_address(&controller_connection_id, port_id)? {
+        if metadata.address != address {
+            return Err(InterchainAccountError::AddressMismatch {
+                expected: address,
+                actual: metadata.address,
+            });
+        }
+    }
+
+    Ok(())
+}
+
+/// Sets the opened channel as the active channel of the interchain account,
+/// whose address is stored, and notifies its application.
+pub fn on_chan_open_ack_execute<Ctx>(
+    ctx: &mut Ctx,
+    port_id: &PortId,
+    channel_id: &ChannelId,
+    counterparty_version: &Version,
+) -> Result<ModuleExtras, InterchainAccountError>
+where
+    Ctx: InterchainAccountControllerExecutionContext + ValidationContext,
+{
+    let metadata = Metadata::from_version(counterparty_version)?;
+
+    let channel_end = ctx.channel_end(&ChannelEndPath::new(port_id, channel_id))?;
+    let (controller_connection_id, _) = channel_connections(ctx, channel_end.connection_hops())?;
+
+    ctx.store_controller_active_channel(&controller_connection_id, port_id, channel_id.clone())?;
+    ctx.store_controller_account_address(
+        &controller_connection_id,
+        port_id,
+        metadata.address.clone(),
+    )?;
+
+    ctx.on_account_registered_execute(&controller_connection_id, port_id, &metadata.address)
+}
+
+pub fn on_chan_open_confirm_validate(
+    _ctx: &impl InterchainAccountControllerValidationContext,
+    _port_id: &PortId,
+    _channel_id: &ChannelId,
+) -> Result<(), InterchainAccountError> {
+    Err(unsupported(
+        "channel handshakes must be initiated by the controller",
+    ))
+}
+
+pub fn on_chan_open_confirm_execute(
+    _ctx: &mut impl InterchainAccountControllerExecutionContext,
+    _port_id: &PortId,
+    _channel_id: &ChannelId,
+) -> Result<ModuleExtras, InterchainAccountError> {
+    Err(unsupported(
+        "channel handshakes must be initiated by the controller",
+    ))
+}
+
+pub fn on_chan_close_init_validate(
+    _ctx: &impl InterchainAccountControllerValidationContext,
+    _port_id: &PortId,
+    _channel_id: &ChannelId,
+) -> Result<(), InterchainAccountError> {
+    Err(unsupported("interchain accounts channels cannot be closed"))
+}
+
+pub fn on_chan_close_init_execute(
+    _ctx: &mut impl InterchainAccountControllerExecutionContext,
+    _port_id: &PortId,
+    _channel_id: &ChannelId,
+) -> Result<ModuleExtras, InterchainAccountError> {
+    Err(unsupported("interchain accounts channels cannot be closed"))
+}
+
+pub fn on_chan_close_confirm_validate(
+    _ctx: &impl InterchainAccountControllerValidationContext,
+    _port_id: &PortId,
+    _channel_id: &ChannelId,
+) -> Result<(), InterchainAccountError> {
+    Ok(())
+}
+
+pub fn on_chan_close_confirm_execute(
+    _ctx: &mut impl InterchainAccountControllerExecutionContext,
+    _port_id: &PortId,
+    _channel_id: &ChannelId,
+) -> Result<ModuleExtras, InterchainAccountError> {
+    Ok(ModuleExtras::empty())
+}
+
+/// Acknowledges the given packet with an error, since the controller does
+/// not receive packets.
+pub fn on_recv_packet_execute(
+    _ctx_b: &mut impl InterchainAccountControllerExecutionContext,
+    _packet: &Packet,
+) -> (ModuleExtras, Acknowledgement) {
+    let ack =
+        AcknowledgementStatus::error(unsupported("the controller does not receive packets").into());
+
+    (ModuleExtras::empty(), ack.into())
+}
+
+pub fn on_acknowledgement_packet_validate(
+    _ctx: &impl InterchainAccountControllerValidationContext,
+    _packet: &Packet,
+    acknowledgement: &Acknowledgement,
+    _relayer: &Signer,
+) -> Result<(), InterchainAccountError> {
+    decode_tx_acknowledgement(acknowledgement)?;
+
+    Ok(())
+}
+
+/// Notifies the application driving the interchain account of the outcome of
+/// the transaction of the given packet.
+pub fn on_acknowledgement_packet_execute(
+    ctx: &mut impl InterchainAccountControllerExecutionContext,
+    packet: &Packet,
+    acknowledgement: &Acknowledgement,
+    _relayer: &Signer,
+) -> (ModuleExtras, Result<(), InterchainAccountError>) {
+    let result = decode_tx_acknowledgement(acknowledgement)
+        .and_then(|acknowledgement| ctx.on_tx_acknowledgement_execute(packet, &acknowledgement));
+
+    match result {
+        Ok(extras) => (extras, Ok(())),
+        Err(err) => (ModuleExtras::empty(), Err(err)),
+    }
+}
+
+pub fn on_timeout_packet_validate(
+    _ctx: &impl InterchainAccountControllerValidationContext,
+    _packet: &Packet,
+    _relayer: &Signer,
+) -> Result<(), InterchainAccountError> {
+    Ok(())
+}
+
+/// Notifies the application driving the interchain account that the
+/// transaction of the given packet timed out, which closes the channel of
+/// the account.
+///
+/// The account keeps its address on the host, and is reactivated over a new
+/// channel with
+/// [`reactivate_interchain_account`](crate::controller::reactivate_interchain_account).
+pub fn on_timeout_packet_execute(
+    ctx: &mut impl InterchainAccountControllerExecutionContext,
+    packet: &Packet,
+    _relayer: &Signer,
+) -> (ModuleExtras, Result<(), InterchainAccountError>) {
+    match ctx.on_tx_timeout_execute(packet) {
+        Ok(mut extras) => {
+            extras.log.push(format!(
+                "closed channel {} of port {} after the timeout of packet {}",
+                packet.chan_id_on_a, packet.port_id_on_a, packet.seq_on_a
+            ));
+
+            (extras, Ok(()))
+        }
+        Err(err) => (ModuleExtras::empty(), Err(err)),
+    }
+}
@@ -0,0 +1,281 @@
+//! Defines the messages with which the owners of the controller register
+//! interchain accounts and send them transactions.
+use core::time::Duration;
+
+use ibc_core::channel::types::channel::Order;
+use ibc_core::channel::types::Version;
+use ibc_core::host::types::identifiers::ConnectionId;
+use ibc_core::primitives::prelude::*;
+use ibc_core::primitives::proto::{Any, Protobuf};
+use ibc_core::primitives::Signer;
+
+use crate::error::InterchainAccountError;
+use crate::packet::{InterchainAccountPacketData, RawInterchainAccountPacketData};
+
+pub(crate) const REGISTER_TYPE_URL: &str =
+    "/ibc.applications.interchain_accounts.controller.v1.MsgRegisterInterchainAccount";
+
+pub(crate) const SEND_TX_TYPE_URL: &str =
+    "/ibc.applications.interchain_accounts.controller.v1.MsgSendTx";
+
+macro_rules! impl_name {
+    ($ty:ty, $name:literal) => {
+        impl prost::Name for $ty {
+            const NAME: &'static str = $name;
+            const PACKAGE: &'static str = "ibc.applications.interchain_accounts.controller.v1";
+
+            fn full_name() -> String {
+                format!("{}.{}", Self::PACKAGE, Self::NAME)
+            }
+        }
+    };
+}
+
+/// ```protobuf
+/// message MsgRegisterInterchainAccount {
+///   string owner = 1;
+///   string connection_id = 2;
+///   string version = 3;
+///   ibc.core.channel.v1.Order ordering = 4;
+/// }
+/// ```
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct RawMsgRegisterInterchainAccount {
+    #[prost(string, tag = "1")]
+    pub owner: String,
+    #[prost(string, tag = "2")]
+    pub connection_id: String,
+    #[prost(string, tag = "3")]
+    pub version: String,
+    #[prost(int32, tag = "4")]
+    pub ordering: i32,
+}
+
+impl_name!(
+    RawMsgRegisterInterchainAccount,
+    "MsgRegisterInterchainAccount"
+);
+
+/// ```protobuf
+/// message MsgSendTx {
+///   string owner = 1;
+///   string connection_id = 2;
+///   InterchainAccountPacketData packet_data = 3;
+///   uint64 relative_timeout = 4;
+/// }
+/// ```
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct RawMsgSendTx {
+    #[prost(string, tag = "1")]
+    pub owner: String,
+    #[prost(string, tag = "2")]
+    pub connection_id: String,
+    #[prost(message, optional, tag = "3")]
+    pub packet_data: Option<RawInterchainAccountPacketData>,
+    /// The timeout of the packet relative to the timestamp of the
+    /// controller, in nanoseconds.
+    #[prost(uint64, tag = "4")]
+    pub relative_timeout: u64,
+}
+
+impl_name!(RawMsgSendTx, "MsgSendTx");
+
+/// Message with which an owner registers an interchain account over a
+/// connection, by opening a channel with the host from its controller port.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MsgRegisterInterchainAccount {
+    pub owner: Signer,
+    /// The connection of the controller with the host.
+    pub connection_id: ConnectionId,
+    /// The proposed metadata of the channel, the default one of the
+    /// connection if empty.
+    pub version: Version,
+    pub ordering: Order,
+}
+
+impl TryFrom<RawMsgRegisterInterchainAccount> for MsgRegisterInterchainAccount {
+    type Error = InterchainAccountError;
+
+    fn try_from(raw_msg: RawMsgRegisterInterchainAccount) -> Result<Self, Self::Error> {
+        // The channels of the interchain accounts are ordered unless
+        // specified otherwise.
+        let ordering = match raw_msg.ordering {
+            0 => Order::Ordered,
+            ordering => {
+                Order::from_i32(ordering).map_err(|e| InterchainAccountError::DecodeRawMsg {
+                    reason: e.to_string(),
+                })?
+            }
+        };
+
+        Ok(Self {
+            owner: raw_msg.owner.into(),
+            connection_id: raw_msg.connection_id.parse()?,
+            version: Version::new(raw_msg.version),
+            ordering,
+        })
+    }
+}
+
+impl From<MsgRegisterInterchainAccount> for RawMsgRegisterInterchainAccount {
+    fn from(domain_msg: MsgRegisterInterchainAccount) -> Self {
+        Self {
+            owner: domain_msg.owner.to_string(),
+            connection_id: domain_msg.connection_id.to_string(),
+            version: domain_msg.version.to_string(),
+            ordering: domain_msg.ordering as i32,
+        }
+    }
+}
+
+impl Protobuf<RawMsgRegisterInterchainAccount> for MsgRegisterInterchainAccount {}
+
+impl TryFrom<Any> for MsgRegisterInterchainAccount {
+    type Error = InterchainAccountError;
+
+    fn try_from(raw: Any) -> Result<Self, Self::Error> {
+        match raw.type_url.as_str() {
+            REGISTER_TYPE_URL => {
+                MsgRegisterInterchainAccount::decode_vec(&raw.value).map_err(|e| {
+                    InterchainAccountError::DecodeRawMsg {
+                        reason: e.to_string(),
+                    }
+                })
+            }
+            _ => Err(InterchainAccountError::UnknownMsgType {
+                msg_type: raw.type_url,
+            }),
+        }
+    }
+}
+
+/// Message with which an owner sends a transaction to its interchain account
+/// over a connection, to be executed on the host.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MsgSendTx {
+    pub owner: Signer,
+    /// The connection of the controller with the host.
+    pub connection_id: ConnectionId,
+    pub packet_data: InterchainAccountPacketData,
+    /// The timeout of the packet relative to the timestamp of the
+    /// controller.
+    pub relative_timeout: Duration,
+}
+
+impl TryFrom<RawMsgSendTx> for MsgSendTx {
+    type Error = InterchainAccountError;
+
+    fn try_from(raw_msg: RawMsgSendTx) -> Result<Self, Self::Error> {
+        if raw_msg.relative_timeout == 0 {
+            return Err(InterchainAccountError::ZeroRelativeTimeout);
+        }
+
+        Ok(Self {
+            owner: raw_msg.owner.into(),
+            connection_id: raw_msg.connection_id.parse()?,
+            packet_data: raw_msg
+                .packet_data
+                .ok_or(InterchainAccountError::PacketDataDeserialization)?
+                .try_into()?,
+            relative_timeout: Duration::from_nanos(raw_msg.relative_timeout),
+        })
+    }
+}
+
+impl From<MsgSendTx> for RawMsgSendTx {
+    fn from(domain_msg: MsgSendTx) -> Self {
+        Self {
+            owner: domain_msg.owner.to_string(),
+            connection_id: domain_msg.connection_id.to_string(),
+            packet_data: Some(domain_msg.packet_data.into()),
+            // Relative timeouts beyond `u64::MAX` nanoseconds, about 584
+            // years, are saturated.
+            relative_timeout: u64::try_from(domain_msg.relative_timeout.as_nanos())
+                .unwrap_or(u64::MAX),
+        }
+    }
+}
+
+impl Protobuf<RawMsgSendTx> for MsgSendTx {}
+
+impl TryFrom<Any> for MsgSendTx {
+    type Error = InterchainAccountError;
+
+    fn try_from(raw: Any) -> Result<Self, Self::Error> {
+        match raw.type_url.as_str() {
+            SEND_TX_TYPE_URL => MsgSendTx::decode_vec(&raw.value).map_err(|e| {
+                InterchainAccountError::DecodeRawMsg {
+                    reason: e.to_string(),
+                }
+            }),
+            _ => Err(InterchainAccountError::UnknownMsgType {
+                msg_type: raw.type_url,
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_msgs_roundtrip() {
+        let msg = MsgRegisterInterchainAccount {
+            owner: "owner".to_string().into(),
+            connection_id: ConnectionId::zero(),
+            version: Version::empty(),
+            ordering: Order::Ordered,
+        };
+        let any = Any {
+            type_url: REGISTER_TYPE_URL.to_string(),
+            value: msg.clone().encode_vec(),
+        };
+        assert_eq!(MsgRegisterInterchainAccount::try_from(any).unwrap(), msg);
+
+        let msg = MsgSendTx {
+            owner: "owner".to_string().into(),
+            connection_id: ConnectionId::zero(),
+            packet_data: InterchainAccountPacketData::execute_tx(
+                vec![Any {
+                    type_url: "/cosmos.bank.v1beta1.MsgSend".to_string(),
+                    value: vec![1, 2, 3],
+                }],
+                String::new(),
+            ),
+            relative_timeout: Duration::from_secs(600),
+        };
+        let any = Any {
+            type_url: SEND_TX_TYPE_URL.to_string(),
+            value: msg.clone().encode_vec(),
+        };
+        assert_eq!(MsgSendTx::try_from(any).unwrap(), msg);
+    }
+
+    #[test]
+    fn test_raw_msgs_defaults() {
+        let msg = MsgRegisterInterchainAccount::try_from(RawMsgRegisterInterchainAccount {
+            owner: "owner".to_string(),
+            connection_id: "connection-0".to_string(),
+            version: String::new(),
+            ordering: 0,
+        })
+        .unwrap();
+        assert_eq!(msg.ordering, Order::Ordered);
+
+        let raw = RawMsgSendTx {
+            owner: "owner".to_string(),
+            connection_id: "connection-0".to_string(),
+            packet_data: Some(RawInterchainAccountPacketData {
+                r#type: 1,
+                data: vec![1],
+                memo: String::new(),
+            }),
+            relative_timeout: 0,
+        };
+        assert!(matches!(
+            MsgSendTx::try_from(raw),
+            Err(InterchainAccountError::ZeroRelativeTimeout)
+        ));
+    }
+}
@@ -0,0 +1,17 @@
+//! Defines the parameters of the interchain accounts controller.
+
+/// The parameters of the interchain accounts controller.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ControllerParams {
+    /// Whether the owners register interchain accounts and send them
+    /// transactions.
+    pub controller_enabled: bool,
+}
+
+impl Default for ControllerParams {
+    fn default() -> Self {
+        Self {
+            controller_enabled: true,
+        }
+    }
+}
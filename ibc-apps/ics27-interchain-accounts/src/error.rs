@@ -0,0 +1,180 @@
+//! Defines the interchain accounts error type
+use displaydoc::Display;
+use ibc_core::channel::types::acknowledgement::StatusValue;
+use ibc_core::channel::types::channel::Order;
+use ibc_core::channel::types::error::{ChannelError, PacketError};
+use ibc_core::handler::types::error::ContextError;
+use ibc_core::host::types::error::IdentifierError;
+use ibc_core::host::types::identifiers::{ChannelId, ConnectionId, PortId};
+use ibc_core::primitives::prelude::*;
+use ibc_core::primitives::TimestampOverflowError;
+
+#[derive(Display, Debug)]
+pub enum InterchainAccountError {
+    /// context error: `{0}`
+    ContextError(ContextError),
+    /// invalid identifier: `{0}`
+    InvalidIdentifier(IdentifierError),
+    /// the interchain accounts host is disabled
+    HostDisabled,
+    /// the interchain accounts controller is disabled
+    ControllerDisabled,
+    /// invalid interchain account owner `{owner}`
+    InvalidOwner { owner: String },
+    /// message of type_url `{type_url}` is not allowed on the host for connection_id `{connection_id}`
+    MessageNotAllowed {
+        connection_id: ConnectionId,
+        type_url: String,
+    },
+    /// expected `{expect_order}` channel, got `{got_order}`
+    ChannelNotOrdered {
+        expect_order: Order,
+        got_order: Order,
+    },
+    /// invalid host port_id `{port_id}`
+    InvalidHostPort { port_id: PortId },
+    /// invalid controller port_id `{port_id}`
+    InvalidControllerPort { port_id: PortId },
+    /// unsupported interchain accounts version `{version}`
+    UnsupportedVersion { version: String },
+    /// invalid interchain accounts metadata: `{reason}`
+    InvalidMetadata { reason: String },
+    /// unsupported transaction encoding `{encoding}`
+    UnsupportedEncoding { encoding: String },
+    /// unsupported transaction type `{tx_type}`
+    UnsupportedTxType { tx_type: String },
+    /// expected connection_id `{expected}`, got `{actual}`
+    ConnectionMismatch {
+        expected: ConnectionId,
+        actual: ConnectionId,
+    },
+    /// expected interchain account address `{expected}`, got `{actual}`
+    AddressMismatch { expected: String, actual: String },
+    /// channel_id `{channel_id}` is already active for connection_id `{connection_id}` and port_id `{port_id}`
+    ActiveChannelAlreadySet {
+        connection_id: ConnectionId,
+        port_id: PortId,
+        channel_id: ChannelId,
+    },
+    /// no interchain account registered for connection_id `{connection_id}` and port_id `{port_id}`
+    InterchainAccountNotFound {
+        connection_id: ConnectionId,
+        port_id: PortId,
+    },
+    /// no active channel for connection_id `{connection_id}` and port_id `{port_id}`
+    ActiveChannelNotFound {
+        connection_id: ConnectionId,
+        port_id: PortId,
+    },
+    /// relative timeout must not be zero
+    ZeroRelativeTimeout,
+    /// timeout overflows the host timestamp: `{0}`
+    TimestampOverflow(TimestampOverflowError),
+    /// unsupported operation: `{description}`
+    UnsupportedOperation { description: String },
+    /// failed to deserialize packet data
+    PacketDataDeserialization,
+    /// failed to deserialize acknowledgement
+    AckDeserialization,
+    /// failed to decode raw msg: `{reason}`
+    DecodeRawMsg { reason: String },
+    /// unknown msg type: `{msg_type}`
+    UnknownMsgType { msg_type: String },
+    /// failed to encode the transaction: `{reason}`
+    TxEncoding { reason: String },
+    /// failed to decode the transaction: `{reason}`
+    TxDecoding { reason: String },
+    /// transaction has no messages
+    EmptyTx,
+    /// failed to execute message of type_url `{type_url}`: `{reason}`
+    MessageExecution { type_url: String, reason: String },
+}
+
+impl InterchainAccountError {
+    /// Returns the ABCI code ibc-go writes in the error acknowledgements for
+    /// the equivalent error, so that both stacks write the same
+    /// acknowledgements.
+    ///
+    /// The errors ibc-go does not register are written with the code of its
+    /// internal errors.
+    pub fn ack_error_code(&self) -> u32 {
+        // The codes are registered by ibc-go in
+        // `modules/apps/27-interchain-accounts/types/errors.go` (`icatypes`),
+        // `modules/apps/27-interchain-accounts/host/types/errors.go`
+        // (`icahosttypes`) and `modules/core/errors/errors.go` (`ibcerrors`).
+        match self {
+            // `icatypes.ErrUnknownDataType`, which ibc-go also returns for
+            // the transactions failing to decode from proto3 JSON
+            Self::PacketDataDeserialization
+            | Self::UnsupportedTxType { .. }
+            | Self::TxDecoding { .. } => 2,
+            // `ibcerrors.ErrUnauthorized`
+            Self::MessageNotAllowed { .. } => 2,
+            // `icatypes.ErrInvalidChannelFlow`
+            Self::UnsupportedOperation { .. } => 5,
+            // `icatypes.ErrInvalidOutgoingData`
+            Self::EmptyTx => 6,
+            // `icatypes.ErrInterchainAccountNotFound`
+            Self::InterchainAccountNotFound { .. } => 8,
+            // `icatypes.ErrActiveChannelNotFound`
+            Self::ActiveChannelNotFound { .. } => 11,
+            // `icatypes.ErrInvalidCodecMetadata`
+            Self::UnsupportedEncoding { .. } => 18,
+            // `icahosttypes.ErrHostSubModuleDisabled`
+            Self::HostDisabled => 2,
+            // `errorsmod.ErrInternal`
+            _ => 1,
+        }
+    }
+}
+
+impl core::error::Error for InterchainAccountError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match &self {
+            Self::ContextError(e) => Some(e),
+            Self::InvalidIdentifier(e) => Some(e),
+            Self::TimestampOverflow(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<ContextError> for InterchainAccountError {
+    fn from(err: ContextError) -> InterchainAccountError {
+        Self::ContextError(err)
+    }
+}
+
+impl From<IdentifierError> for InterchainAccountError {
+    fn from(err: IdentifierError) -> InterchainAccountError {
+        Self::InvalidIdentifier(err)
+    }
+}
+
+impl From<TimestampOverflowError> for InterchainAccountError {
+    fn from(err: TimestampOverflowError) -> InterchainAccountError {
+        Self::TimestampOverflow(err)
+    }
+}
+
+impl From<InterchainAccountError> for ChannelError {
+    fn from(err: InterchainAccountError) -> Self {
+        ChannelError::AppModule {
+            description: err.to_string(),
+        }
+    }
+}
+
+impl From<InterchainAccountError> for PacketError {
+    fn from(err: InterchainAccountError) -> Self {
+        PacketError::AppModule {
+            description: err.to_string(),
+        }
+    }
+}
+
+impl From<InterchainAccountError> for StatusValue {
+    fn from(err: InterchainAccountError) -> Self {
+        StatusValue::new(err.to_string()).expect("error message must not be empty")
+    }
+}
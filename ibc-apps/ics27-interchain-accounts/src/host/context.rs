@@ -0,0 +1,125 @@
+//! Defines the context traits the interchain accounts host implements to
+//! persist the interchain accounts and execute their messages.
+use ibc_core::host::types::identifiers::{ChannelId, ConnectionId, PortId};
+use ibc_core::primitives::prelude::*;
+use ibc_core::primitives::proto::Any;
+use ibc_core::primitives::Signer;
+
+use crate::codec::{ProtobufTxCodec, TxCodec};
+use crate::error::InterchainAccountError;
+use crate::host::HostParams;
+
+/// Methods required in interchain accounts host validation, to be implemented
+/// by the host.
+pub trait InterchainAccountHostValidationContext {
+    /// Returns the parameters of the interchain accounts host.
+    fn host_params(&self) -> Result<HostParams, InterchainAccountError>;
+
+    /// Returns the interchain account of the given controller port over the
+    /// given connection of the host, if registered.
+    fn interchain_account(
+        &self,
+        connection_id: &ConnectionId,
+        controller_port_id: &PortId,
+    ) -> Result<Option<Signer>, InterchainAccountError>;
+
+    /// Returns the active channel of the interchain account of the given
+    /// controller port over the given connection of the host, if any.
+    fn active_channel(
+        &self,
+        connection_id: &ConnectionId,
+        controller_port_id: &PortId,
+    ) -> Result<Option<ChannelId>, InterchainAccountError>;
+
+    /// Returns the salt of the addresses of the interchain accounts
+    /// registered now.
+    ///
+    /// See [`derive_account_address`](crate::account::derive_account_address).
+    fn account_address_salt(&self) -> Result<Vec<u8>, InterchainAccountError> {
+        Ok(Vec::new())
+    }
+
+    /// Returns the codec of the transactions executed by the interchain
+    /// accounts, whose encodings the host accepts for their channels.
+    ///
+    /// Defaults to the `proto3` encoding of the Cosmos SDK transactions.
+    fn tx_codec(&self) -> &dyn TxCodec {
+        &ProtobufTxCodec
+    }
+
+    /// Validates the execution of the given message by the given interchain
+    /// account.
+    ///
+    /// The host must check that the interchain account is the signer of the
+    /// message.
+    fn execute_message_validate(
+        &self,
+        account: &Signer,
+        message: &Any,
+    ) -> Result<(), InterchainAccountError>;
+}
+
+/// Methods required in interchain accounts host execution, to be implemented
+/// by the host.
+pub trait InterchainAccountHostExecutionContext: InterchainAccountHostValidationContext {
+    /// Creates the account of the given derived address, and returns it as
+    /// encoded by the host.
+    fn new_interchain_account_execute(
+        &mut self,
+        address: &[u8],
+    ) -> Result<Signer, InterchainAccountError>;
+
+    /// Stores the interchain account of the given controller port over the
+    /// given connection of the host.
+    fn store_interchain_account(
+        &mut self,
+        connection_id: &ConnectionId,
+        controller_port_id: &PortId,
+        account: Signer,
+    ) -> Result<(), InterchainAccountError>;
+
+    /// Stores the active channel of the interchain account of the given
+    /// controller port over the given connection of the host.
+    fn store_active_channel(
+        &mut self,
+        connection_id: &ConnectionId,
+        controller_port_id: &PortId,
+        channel_id: ChannelId,
+    ) -> Result<(), InterchainAccountError>;
+
+    /// Executes the given message by the given interchain account, and
+    /// returns its response.
+    ///
+    /// The messages of a transaction are all validated before any is
+    /// executed, between the calls to
+    /// [`begin_tx_execute`](Self::begin_tx_execute) and either
+    /// [`commit_tx_execute`](Self::commit_tx_execute) or, once one fails,
+    /// [`discard_tx_execute`](Self::discard_tx_execute).
+    fn execute_message_execute(
+        &mut self,
+        account: &Signer,
+        message: &Any,
+    ) -> Result<Any, InterchainAccountError>;
+
+    /// Called before the messages of a transaction are executed, for the host
+    /// to branch its state, e.g. with a cache of its store, so that the
+    /// transaction is atomic.
+    ///
+    /// The hosts which do not implement the hooks must execute the whole
+    /// packet in a branch of their state, which they discard when the
+    /// acknowledgement is an error.
+    fn begin_tx_execute(&mut self) -> Result<(), InterchainAccountError> {
+        Ok(())
+    }
+
+    /// Called once all the messages of the transaction were executed, for the
+    /// host to write the branch of its state.
+    fn commit_tx_execute(&mut self) -> Result<(), InterchainAccountError> {
+        Ok(())
+    }
+
+    /// Called once a message of the transaction failed, for the host to
+    /// discard the branch of its state, reverting the messages executed
+    /// before it.
+    fn discard_tx_execute(&mut self) {}
+}
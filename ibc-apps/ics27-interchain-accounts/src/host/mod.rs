@@ -0,0 +1,10 @@
+//! Implements the host side of the interchain accounts, which registers the
+//! interchain accounts of the controllers and executes the transactions they
+//! send.
+mod context;
+mod module;
+mod params;
+
+pub use context::*;
+pub use module::*;
+pub use params::*;
@@ -0,0 +1,600 @@
+//! Implements the module callbacks of the interchain accounts host, which
+//! registers the interchain accounts in the channel handshakes initiated by
+//! the controllers, and executes the transactions they send over them.
+use base64::prelude::BASE64_STANDARD;
+use base64::Engine;
+use ibc_core::channel::types::acknowledgement::{
+    Acknowledgement, AcknowledgementStatus, StatusValue,
+};
+use ibc_core::channel::types::channel::{Counterparty, Order};
+use ibc_core::channel::types::packet::Packet;
+use ibc_core::channel::types::Version;
+use ibc_core::host::types::identifiers::{ChannelId, ConnectionId, PortId};
+use ibc_core::host::types::path::ChannelEndPath;
+use ibc_core::host::ValidationContext;
+use ibc_core::primitives::prelude::*;
+use ibc_core::primitives::proto::Any;
+use ibc_core::primitives::Signer;
+use ibc_core::router::types::event::ModuleEvent;
+use ibc_core::router::types::module::ModuleExtras;
+
+use crate::account::derive_account_address;
+use crate::channel::{channel_connections, validate_reopened_channel};
+use crate::error::InterchainAccountError;
+use crate::host::{InterchainAccountHostExecutionContext, InterchainAccountHostValidationContext};
+use crate::metadata::Metadata;
+use crate::packet::{decode_packet_data, encode_tx_msg_data};
+use crate::{CONTROLLER_PORT_PREFIX, HOST_PORT_ID_STR, MODULE_NAME};
+
+/// The kind of the events emitted for the received packets.
+pub const EVENT_TYPE_PACKET: &str = "ics27_packet";
+
+fn unsupported(description: &str) -> InterchainAccountError {
+    InterchainAccountError::UnsupportedOperation {
+        description: description.to_string(),
+    }
+}
+
+pub fn on_chan_open_init_validate(
+    _ctx: &impl InterchainAccountHostValidationContext,
+    _order: Order,
+    _connection_hops: &[ConnectionId],
+    _port_id: &PortId,
+    _channel_id: &ChannelId,
+    _counterparty: &Counterparty,
+    _version: &Version,
+) -> Result<(), InterchainAccountError> {
+    Err(unsupported(
+        "channel handshakes must be initiated by the controller",
+    ))
+}
+
+pub fn on_chan_open_init_execute(
+    _ctx: &mut impl InterchainAccountHostExecutionContext,
+    _order: Order,
+    _connection_hops: &[ConnectionId],
+    _port_id: &PortId,
+    _channel_id: &ChannelId,
+    _counterparty: &Counterparty,
+    _version: &Version,
+) -> Result<(ModuleExtras, Version), InterchainAccountError> {
+    Err(unsupported(
+        "channel handshakes must be initiated by the controller",
+    ))
+}
+
+/// Validates the metadata proposed by the controller, which the host accepts
+/// for a new interchain account, or for an existing one whose active channel
+/// is closed.
+pub fn on_chan_open_try_validate<Ctx>(
+    ctx: &Ctx,
+    order: Order,
+    connection_hops: &[ConnectionId],
+    port_id: &PortId,
+    _channel_id: &ChannelId,
+    counterparty: &Counterparty,
+    counterparty_version: &Version,
+) -> Result<(), InterchainAccountError>
+where
+    Ctx: InterchainAccountHostValidationContext + ValidationContext,
+{
+    if !ctx.host_params()?.host_enabled {
+        return Err(InterchainAccountError::HostDisabled);
+    }
+
+    if order != Order::Ordered {
+        return Err(InterchainAccountError::ChannelNotOrdered {
+            expect_order: Order::Ordered,
+            got_order: order,
+        });
+    }
+
+    if port_id.as_str() != HOST_PORT_ID_STR {
+        return Err(InterchainAccountError::InvalidHostPort {
+            port_id: port_id.clone(),
+        });
+    }
+
+    let controller_port_id = counterparty.port_id();
+
+    if !controller_port_id
+        .as_str()
+        .starts_with(CONTROLLER_PORT_PREFIX)
+    {
+        return Err(InterchainAccountError::InvalidControllerPort {
+            port_id: controller_port_id.clone(),
+        });
+    }
+
+    let metadata = Metadata::from_version(counterparty_version)?;
+    metadata.validate_basic()?;
+    ctx.tx_codec().validate_encoding(&metadata.encoding)?;
+
+    let (host_connection_id, controller_connection_id) = channel_connections(ctx, connection_hops)?;
+    metadata.validate_connections(&controller_connection_id, &host_connection_id)?;
+
+    validate_reopened_channel(
+        ctx,
+        &host_connection_id,
+        port_id,
+        controller_port_id,
+        ctx.active_channel(&host_connection_id, controller_port_id)?,
+        &metadata,
+    )?;
+
+    if let Some(account) = ctx.interchain_account(&host_connection_id, controller_port_id)? {
+        if !metadata.address.is_empty() && metadata.address != account.as_ref() {
+            return Err(InterchainAccountError::AddressMismatch {
+                expected: account.to_string(),
+                actual: metadata.address,
+            });
+        }
+    }
+
+    Ok(())
+}
+
+/// Registers the interchain account of the controller port, unless it is
+/// already, and returns the metadata of the channel with its address.
+pub fn on_chan_open_try_execute<Ctx>(
+    ctx: &mut Ctx,
+    _order: Order,
+    connection_hops: &[ConnectionId],
+    _port_id: &PortId,
+    _channel_id: &ChannelId,
+    counterparty: &Counterparty,
+    counterparty_version: &Version,
+) -> Result<(ModuleExtras, Version), InterchainAccountError>
+where
+    Ctx: InterchainAccountHostExecutionContext + ValidationContext,
+{
+    let mut metadata = Metadata::from_version(counterparty_version)?;
+    let controller_port_id = counterparty.port_id();
+    let (host_connection_id, _) = channel_connections(ctx, connection_hops)?;
+
+    let mut extras = ModuleExtras::empty();
+
+    let account = match ctx.interchain_account(&host_connection_id, controller_port_id)? {
+        Some(account) => account,
+        None => {
+            let address = derive_account_address(
+                &host_connection_id,
+                controller_port_id,
+                &ctx.account_address_salt()?,
+            );
+            let account = ctx.new_interchain_account_execute(&address)?;
+
+            ctx.store_interchain_account(&host_connection_id, controller_port_id, account.clone())?;
+
+            extras.log.push(format!(
+                "registered interchain account {account} for port {controller_port_id} over connection {host_connection_id}"
+            ));
+
+            account
+        }
+    };
+
+    metadata.address = account.to_string();
+
+    Ok((extras, metadata.to_version()))
+}
+
+pub fn on_chan_open_ack_validate(
+    _ctx: &impl InterchainAccountHostValidationContext,
+    _port_id: &PortId,
+    _channel_id: &ChannelId,
+    _counterparty_version: &Version,
+) -> Result<(), InterchainAccountError> {
+    Err(unsupported(
+        "channel handshakes must be initiated by the controller",
+    ))
+}
+
+pub fn on_chan_open_ack_execute(
+    _ctx: &mut impl InterchainAccountHostExecutionContext,
+    _port_id: &PortId,
+    _channel_id: &ChannelId,
+    _counterparty_version: &Version,
+) -> Result<ModuleExtras, InterchainAccountError> {
+    Err(unsupported(
+        "channel handshakes must be initiated by the controller",
+    ))
+}
+
+pub fn on_chan_open_confirm_validate(
+    _ctx: &impl InterchainAccountHostValidationContext,
+    _port_id: &PortId,
+    _channel_id: &ChannelId,
+) -> Result<(), InterchainAccountError> {
+    Ok(())
+}
+
+/// Sets the opened channel as the active channel of its interchain account.
+pub fn on_chan_open_confirm_execute<Ctx>(
+    ctx: &mut Ctx,
+    port_id: &PortId,
+    channel_id: &ChannelId,
+) -> Result<ModuleExtras, InterchainAccountError>
+where
+    Ctx: InterchainAccountHostExecutionContext + ValidationContext,
+{
+    let channel_end = ctx.channel_end(&ChannelEndPath::new(port_id, channel_id))?;
+    let (host_connection_id, _) = channel_connections(ctx, channel_end.connection_hops())?;
+
+    ctx.store_active_channel(
+        &host_connection_id,
+        channel_end.counterparty().port_id(),
+        channel_id.clone(),
+    )?;
+
+    Ok(ModuleExtras::empty())
+}
+
+pub fn on_chan_close_init_validate(
+    _ctx: &impl InterchainAccountHostValidationContext,
+    _port_id: &PortId,
+    _channel_id: &ChannelId,
+) -> Result<(), InterchainAccountError> {
+    Err(unsupported("interchain accounts channels cannot be closed"))
+}
+
+pub fn on_chan_close_init_execute(
+    _ctx: &mut impl InterchainAccountHostExecutionContext,
+    _port_id: &PortId,
+    _channel_id: &ChannelId,
+) -> Result<ModuleExtras, InterchainAccountError> {
+    Err(unsupported("interchain accounts channels cannot be closed"))
+}
+
+pub fn on_chan_close_confirm_validate(
+    _ctx: &impl InterchainAccountHostValidationContext,
+    _port_id: &PortId,
+    _channel_id: &ChannelId,
+) -> Result<(), InterchainAccountError> {
+    Ok(())
+}
+
+pub fn on_chan_close_confirm_execute(
+    _ctx: &mut impl InterchainAccountHostExecutionContext,
+    _port_id: &PortId,
+    _channel_id: &ChannelId,
+) -> Result<ModuleExtras, InterchainAccountError> {
+    Ok(ModuleExtras::empty())
+}
+
+/// Executes the transaction of the given packet by its interchain account,
+/// and acknowledges it with the responses of its messages, or with the error
+/// which failed it.
+pub fn on_recv_packet_execute<Ctx>(
+    ctx_b: &mut Ctx,
+    packet: &Packet,
+) -> (ModuleExtras, Acknowledgement)
+where
+    Ctx: InterchainAccountHostExecutionContext + ValidationContext,
+{
+    // The transactions have some messages, whose responses are encoded to
+    // non-empty bytes.
+    let result = execute_tx(ctx_b, packet).map(|msg_responses| {
+        StatusValue::new(BASE64_STANDARD.encode(encode_tx_msg_data(msg_responses)))
+            .expect("the responses of the messages are encoded to non-empty bytes")
+    });
+
+    let mut attributes = vec![
+        ("module", MODULE_NAME).into(),
+        ("host_channel_id", packet.chan_id_on_b.as_str()).into(),
+        ("success", result.is_ok()).into(),
+    ];
+
+    // The error acknowledgements only carry the ABCI code of the error, as
+    // ibc-go writes them, the details being emitted in the event.
+    let ack = match result {
+        Ok(value) => AcknowledgementStatus::success(value),
+        Err(err) => {
+            let ack = AcknowledgementStatus::error(StatusValue::from_abci_error_code(
+                err.ack_error_code(),
+            ));
+            attributes.push(("error", err.to_string()).into());
+
+            ack
+        }
+    };
+
+    let extras = ModuleExtras {
+        events: vec![ModuleEvent {
+            kind: EVENT_TYPE_PACKET.to_string(),
+            attributes,
+        }],
+        log: Vec::new(),
+    };
+
+    (extras, ack.into())
+}
+
+/// Validates all the messages of the transaction of the given packet, then
+/// executes them, returning their responses.
+fn execute_tx<Ctx>(ctx_b: &mut Ctx, packet: &Packet) -> Result<Vec<Any>, InterchainAccountError>
+where
+    Ctx: InterchainAccountHostExecutionContext + ValidationContext,
+{
+    let params = ctx_b.host_params()?;
+
+    if !params.host_enabled {
+        return Err(InterchainAccountError::HostDisabled);
+    }
+
+    let data = decode_packet_data(&packet.data)?;
+    data.validate_basic()?;
+
+    let channel_end = ctx_b.channel_end(&ChannelEndPath::new(
+        &packet.port_id_on_b,
+        &packet.chan_id_on_b,
+    ))?;
+
+    // The transaction is encoded as negotiated in the metadata of the
+    // channel.
+    let metadata = Metadata::from_version(channel_end.version())?;
+    let messages = ctx_b.tx_codec().decode_tx(&metadata.encoding, &data.data)?;
+
+    let (host_connection_id, _) = channel_connections(ctx_b, channel_end.connection_hops())?;
+
+    // The messages must all be allowed over the connection before any is
+    // dispatched to the host.
+    if let Some(message) = messages
+        .iter()
+        .find(|message| !params.is_message_allowed(&host_connection_id, &message.type_url))
+    {
+        return Err(InterchainAccountError::MessageNotAllowed {
+            connection_id: host_connection_id,
+            type_url: message.type_url.clone(),
+        });
+    }
+
+    let account = packet_account(ctx_b, &host_connection_id, &packet.port_id_on_a)?;
+
+    execute_messages(ctx_b, &account, &messages)
+}
+
+/// Validates all the given messages, then executes them by the given
+/// interchain account in a branch of the state of the host, which is
+/// discarded if any fails, and returns their responses.
+fn execute_messages<Ctx>(
+    ctx_b: &mut Ctx,
+    account: &Signer,
+    messages: &[Any],
+) -> Result<Vec<Any>, InterchainAccountError>
+where
+    Ctx: InterchainAccountHostExecutionContext,
+{
+    for message in messages {
+        ctx_b.execute_message_validate(account, message)?;
+    }
+
+    ctx_b.begin_tx_execute()?;
+
+    let result = messages
+        .iter()
+        .map(|message| ctx_b.execute_message_execute(account, message))
+        .collect::<Result<Vec<_>, _>>();
+
+    match result {
+        Ok(msg_responses) => {
+            ctx_b.commit_tx_execute()?;
+
+            Ok(msg_responses)
+        }
+        Err(err) => {
+            ctx_b.discard_tx_execute();
+
+            Err(err)
+        }
+    }
+}
+
+/// Returns the interchain account of the given controller port over the given
+/// connection of the host, which executes the transactions it receives.
+fn packet_account(
+    ctx_b: &impl InterchainAccountHostValidationContext,
+    host_connection_id: &ConnectionId,
+    controller_port_id: &PortId,
+) -> Result<Signer, InterchainAccountError> {
+    ctx_b
+        .interchain_account(host_connection_id, controller_port_id)?
+        .ok_or_else(|| InterchainAccountError::InterchainAccountNotFound {
+            connection_id: host_connection_id.clone(),
+            port_id: controller_port_id.clone(),
+        })
+}
+
+pub fn on_acknowledgement_packet_validate(
+    _ctx: &impl InterchainAccountHostValidationContext,
+    _packet: &Packet,
+    _acknowledgement: &Acknowledgement,
+    _relayer: &Signer,
+) -> Result<(), InterchainAccountError> {
+    Err(unsupported("the host does not send packets"))
+}
+
+pub fn on_acknowledgement_packet_execute(
+    _ctx: &mut impl InterchainAccountHostExecutionContext,
+    _packet: &Packet,
+    _acknowledgement: &Acknowledgement,
+    _relayer: &Signer,
+) -> (ModuleExtras, Result<(), InterchainAccountError>) {
+    (
+        ModuleExtras::empty(),
+        Err(unsupported("the host does not send packets")),
+    )
+}
+
+pub fn on_timeout_packet_validate(
+    _ctx: &impl InterchainAccountHostValidationContext,
+    _packet: &Packet,
+    _relayer: &Signer,
+) -> Result<(), InterchainAccountError> {
+    Err(unsupported("the host does not send packets"))
+}
+
+pub fn on_timeout_packet_execute(
+    _ctx: &mut impl InterchainAccountHostExecutionContext,
+    _packet: &Packet,
+    _relayer: &Signer,
+) -> (ModuleExtras, Result<(), InterchainAccountError>) {
+    (
+        ModuleExtras::empty(),
+        Err(unsupported("the host does not send packets")),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::host::HostParams;
+
+    const MSG_SEND: &str = "/cosmos.bank.v1beta1.MsgSend";
+    const MSG_FAILING: &str = "/test.MsgFailing";
+
+    /// Executes the messages of the transactions in a branch of its state,
+    /// which it writes once the transaction succeeds.
+    #[derive(Default)]
+    struct MockHost {
+        executed: Vec<Any>,
+        branch: Option<Vec<Any>>,
+    }
+
+    impl InterchainAccountHostValidationContext for MockHost {
+        fn host_params(&self) -> Result<HostParams, InterchainAccountError> {
+            Ok(HostParams::default())
+        }
+
+        fn interchain_account(
+            &self,
+            _connection_id: &ConnectionId,
+            _controller_port_id: &PortId,
+        ) -> Result<Option<Signer>, InterchainAccountError> {
+            Ok(None)
+        }
+
+        fn active_channel(
+            &self,
+            _connection_id: &ConnectionId,
+            _controller_port_id: &PortId,
+        ) -> Result<Option<ChannelId>, InterchainAccountError> {
+            Ok(None)
+        }
+
+        fn execute_message_validate(
+            &self,
+            _account: &Signer,
+            _message: &Any,
+        ) -> Result<(), InterchainAccountError> {
+            Ok(())
+        }
+    }
+
+    impl InterchainAccountHostExecutionContext for MockHost {
+        fn new_interchain_account_execute(
+            &mut self,
+            _address: &[u8],
+        ) -> Result<Signer, InterchainAccountError> {
+            Err(unsupported("the mock host does not create accounts"))
+        }
+
+        fn store_interchain_account(
+            &mut self,
+            _connection_id: &ConnectionId,
+            _controller_port_id: &PortId,
+            _account: Signer,
+        ) -> Result<(), InterchainAccountError> {
+            Ok(())
+        }
+
+        fn store_active_channel(
+            &mut self,
+            _connection_id: &ConnectionId,
+            _controller_port_id: &PortId,
+            _channel_id: ChannelId,
+        ) -> Result<(), InterchainAccountError> {
+            Ok(())
+        }
+
+        fn execute_message_execute(
+            &mut self,
+            _account: &Signer,
+            message: &Any,
+        ) -> Result<Any, InterchainAccountError> {
+            if message.type_url == MSG_FAILING {
+                return Err(InterchainAccountError::MessageExecution {
+                    type_url: message.type_url.clone(),
+                    reason: "failing message".to_string(),
+                });
+            }
+
+            self.branch
+                .as_mut()
+                .expect("the messages are executed in a branch")
+                .push(message.clone());
+
+            Ok(Any {
+                type_url: format!("{}Response", message.type_url),
+                value: Vec::new(),
+            })
+        }
+
+        fn begin_tx_execute(&mut self) -> Result<(), InterchainAccountError> {
+            self.branch = Some(Vec::new());
+
+            Ok(())
+        }
+
+        fn commit_tx_execute(&mut self) -> Result<(), InterchainAccountError> {
+            self.executed
+                .extend(self.branch.take().expect("the branch is open"));
+
+            Ok(())
+        }
+
+        fn discard_tx_execute(&mut self) {
+            self.branch = None;
+        }
+    }
+
+    fn message(type_url: &str) -> Any {
+        Any {
+            type_url: type_url.to_string(),
+            value: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_execute_messages() {
+        let mut host = MockHost::default();
+        let account = Signer::from("cosmos1account".to_string());
+
+        let msg_responses =
+            execute_messages(&mut host, &account, &[message(MSG_SEND), message(MSG_SEND)]).unwrap();
+
+        assert_eq!(msg_responses.len(), 2);
+        assert_eq!(host.executed.len(), 2);
+        assert!(host.branch.is_none());
+    }
+
+    #[test]
+    fn test_execute_messages_reverted_on_failure() {
+        let mut host = MockHost::default();
+        let account = Signer::from("cosmos1account".to_string());
+
+        // The first message is executed, then reverted once the second fails.
+        let result = execute_messages(
+            &mut host,
+            &account,
+            &[message(MSG_SEND), message(MSG_FAILING)],
+        );
+
+        assert!(matches!(
+            result,
+            Err(InterchainAccountError::MessageExecution { .. })
+        ));
+        assert!(host.executed.is_empty());
+        assert!(host.branch.is_none());
+    }
+}
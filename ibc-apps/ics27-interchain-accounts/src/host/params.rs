@@ -0,0 +1,106 @@
+//! Defines the parameters of the interchain accounts host.
+use ibc_core::host::types::identifiers::ConnectionId;
+use ibc_core::primitives::prelude::*;
+
+/// The entry of the allowed messages which allows all the messages.
+pub const ALLOW_ALL_HOST_MSGS: &str = "*";
+
+/// The parameters of the interchain accounts host.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct HostParams {
+    /// Whether the host registers interchain accounts and executes their
+    /// transactions.
+    pub host_enabled: bool,
+    /// The type URLs of the messages the interchain accounts are allowed to
+    /// execute, [`ALLOW_ALL_HOST_MSGS`] allowing all of them.
+    pub allow_messages: Vec<String>,
+    /// The type URLs of the messages the interchain accounts registered over
+    /// the given connections are allowed to execute, instead of
+    /// `allow_messages`.
+    pub connection_allow_messages: BTreeMap<ConnectionId, Vec<String>>,
+}
+
+impl Default for HostParams {
+    fn default() -> Self {
+        Self {
+            host_enabled: true,
+            allow_messages: vec![ALLOW_ALL_HOST_MSGS.to_string()],
+            connection_allow_messages: BTreeMap::new(),
+        }
+    }
+}
+
+impl HostParams {
+    /// Returns the type URLs of the messages the interchain accounts
+    /// registered over the given connection of the host are allowed to
+    /// execute.
+    pub fn allowed_messages(&self, connection_id: &ConnectionId) -> &[String] {
+        self.connection_allow_messages
+            .get(connection_id)
+            .unwrap_or(&self.allow_messages)
+    }
+
+    /// Returns `true` if the messages of the given type URL are allowed for
+    /// the interchain accounts registered over the given connection.
+    pub fn is_message_allowed(&self, connection_id: &ConnectionId, type_url: &str) -> bool {
+        self.allowed_messages(connection_id)
+            .iter()
+            .any(|allowed| allowed == ALLOW_ALL_HOST_MSGS || allowed == type_url)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MSG_SEND: &str = "/cosmos.bank.v1beta1.MsgSend";
+    const MSG_DELEGATE: &str = "/cosmos.staking.v1beta1.MsgDelegate";
+
+    #[test]
+    fn test_message_allowlist() {
+        let connection_id = ConnectionId::zero();
+
+        assert!(HostParams::default().is_message_allowed(&connection_id, MSG_SEND));
+
+        let params = HostParams {
+            allow_messages: vec![MSG_SEND.to_string()],
+            ..Default::default()
+        };
+        assert!(params.is_message_allowed(&connection_id, MSG_SEND));
+        assert!(!params.is_message_allowed(&connection_id, MSG_DELEGATE));
+
+        let params = HostParams {
+            allow_messages: Vec::new(),
+            ..Default::default()
+        };
+        assert!(!params.is_message_allowed(&connection_id, MSG_SEND));
+    }
+
+    #[test]
+    fn test_connection_message_allowlist() {
+        let restricted = ConnectionId::new(1);
+
+        let params = HostParams {
+            connection_allow_messages: [(restricted.clone(), vec![MSG_DELEGATE.to_string()])]
+                .into_iter()
+                .collect(),
+            ..Default::default()
+        };
+        assert!(params.is_message_allowed(&ConnectionId::zero(), MSG_SEND));
+        assert!(params.is_message_allowed(&restricted, MSG_DELEGATE));
+        assert!(!params.is_message_allowed(&restricted, MSG_SEND));
+
+        let params = HostParams {
+            allow_messages: Vec::new(),
+            connection_allow_messages: [(
+                restricted.clone(),
+                vec![ALLOW_ALL_HOST_MSGS.to_string()],
+            )]
+            .into_iter()
+            .collect(),
+            ..Default::default()
+        };
+        assert!(!params.is_message_allowed(&ConnectionId::zero(), MSG_SEND));
+        assert!(params.is_message_allowed(&restricted, MSG_SEND));
+    }
+}
@@ -0,0 +1,44 @@
+//! Implementation of the IBC [Interchain
+//! Accounts](https://github.com/cosmos/ibc/blob/main/spec/app/ics-027-interchain-accounts/README.md)
+//! (ICS-27) application logic, with which a controller chain executes
+//! transactions on a host chain through accounts of the host it registers.
+#![no_std]
+#![forbid(unsafe_code)]
+#![cfg_attr(not(test), deny(clippy::unwrap_used))]
+#![cfg_attr(not(test), deny(clippy::disallowed_methods, clippy::disallowed_types))]
+#![deny(
+    warnings,
+    trivial_casts,
+    trivial_numeric_casts,
+    unused_import_braces,
+    unused_qualifications,
+    rust_2018_idioms
+)]
+#![allow(clippy::result_large_err)]
+
+#[cfg(any(test, feature = "std"))]
+extern crate std;
+
+pub mod account;
+mod channel;
+pub mod codec;
+pub mod controller;
+pub mod error;
+pub mod host;
+pub mod metadata;
+pub mod packet;
+
+/// The module name of the interchain accounts application, from which the
+/// addresses of the interchain accounts are derived.
+pub const MODULE_NAME: &str = "interchainaccounts";
+
+/// The version of the interchain accounts channels.
+pub const VERSION: &str = "ics27-1";
+
+/// The port of the interchain accounts host, over which the channels of all
+/// the interchain accounts are opened.
+pub const HOST_PORT_ID_STR: &str = "icahost";
+
+/// The prefix of the ports of the interchain accounts controller, each owner
+/// of interchain accounts having its own port.
+pub const CONTROLLER_PORT_PREFIX: &str = "icacontroller-";
@@ -0,0 +1,186 @@
+//! Defines the metadata of the interchain accounts channels, which is carried
+//! as their version.
+//!
+//! The metadata is negotiated in the channel handshake, using the JSON format
+//! of `ibc-go`:
+//!
+//! ```json
+//! {
+//!   "version": "ics27-1",
+//!   "controller_connection_id": "connection-0",
+//!   "host_connection_id": "connection-1",
+//!   "address": "cosmos1...",
+//!   "encoding": "proto3",
+//!   "tx_type": "sdk_multi_msg"
+//! }
+//! ```
+//!
+//! The controller proposes the metadata without an address, which the host
+//! sets to the address of the interchain account it registers.
+use ibc_core::channel::types::Version;
+use ibc_core::host::types::identifiers::ConnectionId;
+use ibc_core::primitives::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::error::InterchainAccountError;
+use crate::VERSION;
+
+/// The encoding of the transactions as protobuf messages.
+pub const ENCODING_PROTOBUF: &str = "proto3";
+
+/// The encoding of the transactions as the JSON mapping of protobuf messages.
+pub const ENCODING_PROTO3_JSON: &str = "proto3json";
+
+/// The type of the transactions made of multiple Cosmos SDK messages, which
+/// are executed atomically.
+pub const TX_TYPE_SDK_MULTI_MSG: &str = "sdk_multi_msg";
+
+/// The metadata of an interchain accounts channel.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Metadata {
+    /// The version of the interchain accounts application.
+    pub version: String,
+    /// The connection of the channel on the controller.
+    pub controller_connection_id: ConnectionId,
+    /// The connection of the channel on the host.
+    pub host_connection_id: ConnectionId,
+    /// The address of the interchain account on the host, empty until the
+    /// host registers it.
+    #[serde(default)]
+    pub address: String,
+    /// The encoding of the transactions sent over the channel.
+    pub encoding: String,
+    /// The type of the transactions sent over the channel.
+    pub tx_type: String,
+}
+
+impl Metadata {
+    /// Returns the metadata proposed by a controller, with the default
+    /// encoding and transaction type, and no address.
+    pub fn new(controller_connection_id: ConnectionId, host_connection_id: ConnectionId) -> Self {
+        Self {
+            version: VERSION.to_string(),
+            controller_connection_id,
+            host_connection_id,
+            address: String::new(),
+            encoding: ENCODING_PROTOBUF.to_string(),
+            tx_type: TX_TYPE_SDK_MULTI_MSG.to_string(),
+        }
+    }
+
+    /// Parses the metadata from the version of a channel.
+    pub fn from_version(version: &Version) -> Result<Self, InterchainAccountError> {
+        serde_json::from_str(version.as_str()).map_err(|e| {
+            InterchainAccountError::InvalidMetadata {
+                reason: e.to_string(),
+            }
+        })
+    }
+
+    /// Returns the metadata as the version of a channel.
+    pub fn to_version(&self) -> Version {
+        Version::new(
+            serde_json::to_string(self).expect("Metadata's infallible Serialize impl failed"),
+        )
+    }
+
+    /// Returns an error if the version or the transaction type of the
+    /// metadata are not supported.
+    ///
+    /// The encoding is validated against the
+    /// [`TxCodec`](crate::codec::TxCodec) of the chain.
+    pub fn validate_basic(&self) -> Result<(), InterchainAccountError> {
+        if self.version != VERSION {
+            return Err(InterchainAccountError::UnsupportedVersion {
+                version: self.version.clone(),
+            });
+        }
+
+        if self.tx_type != TX_TYPE_SDK_MULTI_MSG {
+            return Err(InterchainAccountError::UnsupportedTxType {
+                tx_type: self.tx_type.clone(),
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Returns an error if the connections of the metadata are not the given
+    /// ones of the channel.
+    pub fn validate_connections(
+        &self,
+        controller_connection_id: &ConnectionId,
+        host_connection_id: &ConnectionId,
+    ) -> Result<(), InterchainAccountError> {
+        if &self.controller_connection_id != controller_connection_id {
+            return Err(InterchainAccountError::ConnectionMismatch {
+                expected: controller_connection_id.clone(),
+                actual: self.controller_connection_id.clone(),
+            });
+        }
+
+        if &self.host_connection_id != host_connection_id {
+            return Err(InterchainAccountError::ConnectionMismatch {
+                expected: host_connection_id.clone(),
+                actual: self.host_connection_id.clone(),
+            });
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const METADATA: &str = r#"{"version":"ics27-1","controller_connection_id":"connection-0","host_connection_id":"connection-1","address":"","encoding":"proto3","tx_type":"sdk_multi_msg"}"#;
+
+    #[test]
+    fn test_metadata_version_roundtrip() {
+        let metadata = Metadata::new(ConnectionId::new(0), ConnectionId::new(1));
+
+        assert_eq!(metadata.to_version(), Version::new(METADATA.to_string()));
+        assert_eq!(
+            Metadata::from_version(&metadata.to_version()).unwrap(),
+            metadata
+        );
+
+        // The address is omitted by the controllers proposing the metadata.
+        let version = Version::new(METADATA.replace(r#""address":"","#, ""));
+        assert_eq!(Metadata::from_version(&version).unwrap(), metadata);
+
+        assert!(Metadata::from_version(&Version::new("ics27-1".to_string())).is_err());
+    }
+
+    #[test]
+    fn test_metadata_validation() {
+        let metadata = Metadata::new(ConnectionId::new(0), ConnectionId::new(1));
+        assert!(metadata.validate_basic().is_ok());
+        assert!(metadata
+            .validate_connections(&ConnectionId::new(0), &ConnectionId::new(1))
+            .is_ok());
+        assert!(matches!(
+            metadata.validate_connections(&ConnectionId::new(1), &ConnectionId::new(1)),
+            Err(InterchainAccountError::ConnectionMismatch { .. })
+        ));
+
+        let mut invalid = metadata.clone();
+        invalid.version = "ics20-1".to_string();
+        assert!(matches!(
+            invalid.validate_basic(),
+            Err(InterchainAccountError::UnsupportedVersion { .. })
+        ));
+
+        let mut json_encoded = metadata.clone();
+        json_encoded.encoding = ENCODING_PROTO3_JSON.to_string();
+        assert!(json_encoded.validate_basic().is_ok());
+
+        let mut invalid = metadata;
+        invalid.tx_type = "single_msg".to_string();
+        assert!(matches!(
+            invalid.validate_basic(),
+            Err(InterchainAccountError::UnsupportedTxType { .. })
+        ));
+    }
+}
@@ -0,0 +1,367 @@
+//! Defines the packet data of the interchain accounts channels, which carries
+//! the transactions the controller executes on the host, and the encoding of
+//! these transactions and of their results.
+//!
+//! The packet data uses the JSON format of `ibc-go`, whose `data` is the
+//! base64 encoding of the transaction:
+//!
+//! ```json
+//! {
+//!   "data": "CkQKHC9jb3Ntb3MuYmFuay52MWJldGExLk1zZ1NlbmQS...",
+//!   "memo": "",
+//!   "type": "TYPE_EXECUTE_TX"
+//! }
+//! ```
+use base64::prelude::BASE64_STANDARD;
+use base64::Engine;
+use ibc_core::channel::types::acknowledgement::{Acknowledgement, AcknowledgementStatus};
+use ibc_core::primitives::prelude::*;
+use ibc_core::primitives::proto::Any;
+use prost::Message;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::codec::TxCodec;
+use crate::error::InterchainAccountError;
+
+/// The type of the interchain accounts packets.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Type {
+    #[serde(rename = "TYPE_UNSPECIFIED")]
+    Unspecified,
+    /// The packets carrying a transaction to execute on the host.
+    #[serde(rename = "TYPE_EXECUTE_TX")]
+    ExecuteTx,
+}
+
+/// The packet data of the interchain accounts channels.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct InterchainAccountPacketData {
+    /// The encoded transaction.
+    #[serde(
+        serialize_with = "serialize_base64",
+        deserialize_with = "deserialize_base64"
+    )]
+    pub data: Vec<u8>,
+    #[serde(default)]
+    pub memo: String,
+    #[serde(rename = "type")]
+    pub packet_type: Type,
+}
+
+impl InterchainAccountPacketData {
+    /// Returns the packet data executing the given messages on the host, in
+    /// the `proto3` encoding.
+    pub fn execute_tx(messages: Vec<Any>, memo: String) -> Self {
+        Self {
+            data: encode_cosmos_tx(messages),
+            memo,
+            packet_type: Type::ExecuteTx,
+        }
+    }
+
+    /// Returns the packet data executing the given messages on the host,
+    /// encoded by the given codec in the encoding of the channel.
+    pub fn execute_encoded_tx(
+        codec: &dyn TxCodec,
+        encoding: &str,
+        messages: Vec<Any>,
+        memo: String,
+    ) -> Result<Self, InterchainAccountError> {
+        Ok(Self {
+            data: codec.encode_tx(encoding, messages)?,
+            memo,
+            packet_type: Type::ExecuteTx,
+        })
+    }
+
+    /// Returns the packet data as the bytes of a packet.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        serde_json::to_vec(self)
+            .expect("InterchainAccountPacketData's infallible Serialize impl failed")
+    }
+
+    /// Returns an error if the packet data carries no transaction to execute.
+    pub fn validate_basic(&self) -> Result<(), InterchainAccountError> {
+        if self.packet_type != Type::ExecuteTx {
+            return Err(InterchainAccountError::PacketDataDeserialization);
+        }
+
+        if self.data.is_empty() {
+            return Err(InterchainAccountError::EmptyTx);
+        }
+
+        Ok(())
+    }
+}
+
+/// Decodes the packet data from the bytes of a packet.
+pub fn decode_packet_data(
+    data: &[u8],
+) -> Result<InterchainAccountPacketData, InterchainAccountError> {
+    serde_json::from_slice(data).map_err(|_| InterchainAccountError::PacketDataDeserialization)
+}
+
+fn serialize_base64<S: Serializer>(bytes: &[u8], serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_str(&BASE64_STANDARD.encode(bytes))
+}
+
+fn deserialize_base64<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<u8>, D::Error> {
+    let encoded = String::deserialize(deserializer)?;
+
+    BASE64_STANDARD
+        .decode(encoded)
+        .map_err(serde::de::Error::custom)
+}
+
+/// The raw packet data of the interchain accounts channels, as carried by
+/// [`MsgSendTx`](crate::controller::MsgSendTx).
+///
+/// ```protobuf
+/// message InterchainAccountPacketData {
+///   Type type = 1;
+///   bytes data = 2;
+///   string memo = 3;
+/// }
+/// ```
+#[derive(Clone, PartialEq, Message)]
+pub struct RawInterchainAccountPacketData {
+    #[prost(int32, tag = "1")]
+    pub r#type: i32,
+    #[prost(bytes = "vec", tag = "2")]
+    pub data: Vec<u8>,
+    #[prost(string, tag = "3")]
+    pub memo: String,
+}
+
+impl TryFrom<RawInterchainAccountPacketData> for InterchainAccountPacketData {
+    type Error = InterchainAccountError;
+
+    fn try_from(raw: RawInterchainAccountPacketData) -> Result<Self, Self::Error> {
+        let packet_type = match raw.r#type {
+            0 => Type::Unspecified,
+            1 => Type::ExecuteTx,
+            _ => return Err(InterchainAccountError::PacketDataDeserialization),
+        };
+
+        Ok(Self {
+            data: raw.data,
+            memo: raw.memo,
+            packet_type,
+        })
+    }
+}
+
+impl From<InterchainAccountPacketData> for RawInterchainAccountPacketData {
+    fn from(data: InterchainAccountPacketData) -> Self {
+        Self {
+            r#type: match data.packet_type {
+                Type::Unspecified => 0,
+                Type::ExecuteTx => 1,
+            },
+            data: data.data,
+            memo: data.memo,
+        }
+    }
+}
+
+/// The raw transaction of the Cosmos SDK hosts, as defined by `ibc-go`.
+///
+/// ```protobuf
+/// message CosmosTx {
+///   repeated google.protobuf.Any messages = 1;
+/// }
+/// ```
+#[derive(Clone, PartialEq, Message)]
+pub struct RawCosmosTx {
+    #[prost(message, repeated, tag = "1")]
+    pub messages: Vec<Any>,
+}
+
+/// The raw results of the messages of a transaction, as defined by the Cosmos
+/// SDK, whose encoding is the result of the successful acknowledgements.
+///
+/// ```protobuf
+/// message TxMsgData {
+///   repeated MsgData data = 1 [deprecated = true];
+///   repeated google.protobuf.Any msg_responses = 2;
+/// }
+/// ```
+#[derive(Clone, PartialEq, Message)]
+pub struct RawTxMsgData {
+    #[prost(message, repeated, tag = "2")]
+    pub msg_responses: Vec<Any>,
+}
+
+/// Encodes the given messages as a transaction.
+pub fn encode_cosmos_tx(messages: Vec<Any>) -> Vec<u8> {
+    RawCosmosTx { messages }.encode_to_vec()
+}
+
+/// Decodes the messages of the given transaction, which must have some.
+pub fn decode_cosmos_tx(data: &[u8]) -> Result<Vec<Any>, InterchainAccountError> {
+    let tx = RawCosmosTx::decode(data).map_err(|e| InterchainAccountError::TxDecoding {
+        reason: e.to_string(),
+    })?;
+
+    if tx.messages.is_empty() {
+        return Err(InterchainAccountError::EmptyTx);
+    }
+
+    Ok(tx.messages)
+}
+
+/// Encodes the responses of the messages of an executed transaction as the
+/// result of its acknowledgement.
+pub fn encode_tx_msg_data(msg_responses: Vec<Any>) -> Vec<u8> {
+    RawTxMsgData { msg_responses }.encode_to_vec()
+}
+
+/// Decodes the responses of the messages of an executed transaction from the
+/// result of its acknowledgement.
+pub fn decode_tx_msg_data(data: &[u8]) -> Result<Vec<Any>, InterchainAccountError> {
+    RawTxMsgData::decode(data)
+        .map(|tx_msg_data| tx_msg_data.msg_responses)
+        .map_err(|e| InterchainAccountError::TxDecoding {
+            reason: e.to_string(),
+        })
+}
+
+/// The outcome of a transaction executed on the host, as acknowledged.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum TxAcknowledgement {
+    /// The responses of the messages of the executed transaction.
+    Result(Vec<Any>),
+    /// The error which failed the transaction, which executed none of its
+    /// messages.
+    Error(String),
+}
+
+/// Decodes the outcome of the transaction of a packet from its
+/// acknowledgement.
+pub fn decode_tx_acknowledgement(
+    acknowledgement: &Acknowledgement,
+) -> Result<TxAcknowledgement, InterchainAccountError> {
+    let status = serde_json::from_slice::<AcknowledgementStatus>(acknowledgement.as_bytes())
+        .map_err(|_| InterchainAccountError::AckDeserialization)?;
+
+    match status {
+        AcknowledgementStatus::Success(value) => {
+            let tx_msg_data = BASE64_STANDARD
+                .decode(value.to_string())
+                .map_err(|_| InterchainAccountError::AckDeserialization)?;
+
+            decode_tx_msg_data(&tx_msg_data).map(TxAcknowledgement::Result)
+        }
+        AcknowledgementStatus::Error(value) => Ok(TxAcknowledgement::Error(value.to_string())),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ibc_core::channel::types::acknowledgement::StatusValue;
+
+    use super::*;
+    use crate::codec::ProtobufTxCodec;
+    use crate::metadata::ENCODING_PROTOBUF;
+
+    fn dummy_messages() -> Vec<Any> {
+        vec![
+            Any {
+                type_url: "/cosmos.bank.v1beta1.MsgSend".to_string(),
+                value: vec![1, 2, 3],
+            },
+            Any {
+                type_url: "/cosmos.staking.v1beta1.MsgDelegate".to_string(),
+                value: vec![4, 5],
+            },
+        ]
+    }
+
+    #[test]
+    fn test_packet_data_roundtrip() {
+        let data = InterchainAccountPacketData::execute_tx(dummy_messages(), "memo".to_string());
+        assert!(data.validate_basic().is_ok());
+
+        let bytes = data.to_bytes();
+        assert_eq!(
+            String::from_utf8(bytes.clone()).unwrap(),
+            format!(
+                r#"{{"data":"{}","memo":"memo","type":"TYPE_EXECUTE_TX"}}"#,
+                BASE64_STANDARD.encode(&data.data)
+            )
+        );
+
+        let decoded = decode_packet_data(&bytes).unwrap();
+        assert_eq!(decoded, data);
+        assert_eq!(decode_cosmos_tx(&decoded.data).unwrap(), dummy_messages());
+
+        assert!(decode_packet_data(br#"{"type":"TYPE_BURN","data":""}"#).is_err());
+
+        let encoded = InterchainAccountPacketData::execute_encoded_tx(
+            &ProtobufTxCodec,
+            ENCODING_PROTOBUF,
+            dummy_messages(),
+            "memo".to_string(),
+        )
+        .unwrap();
+        assert_eq!(encoded, data);
+    }
+
+    #[test]
+    fn test_invalid_tx() {
+        let data = InterchainAccountPacketData::execute_tx(Vec::new(), String::new());
+        assert!(matches!(
+            data.validate_basic(),
+            Err(InterchainAccountError::EmptyTx)
+        ));
+        assert!(matches!(
+            decode_cosmos_tx(&encode_cosmos_tx(Vec::new())),
+            Err(InterchainAccountError::EmptyTx)
+        ));
+        assert!(matches!(
+            decode_cosmos_tx(&[0xff]),
+            Err(InterchainAccountError::TxDecoding { .. })
+        ));
+    }
+
+    #[test]
+    fn test_tx_msg_data_roundtrip() {
+        let encoded = encode_tx_msg_data(dummy_messages());
+
+        assert_eq!(decode_tx_msg_data(&encoded).unwrap(), dummy_messages());
+    }
+
+    #[test]
+    fn test_decode_tx_acknowledgement() {
+        let ack = Acknowledgement::from(AcknowledgementStatus::success(
+            StatusValue::new(BASE64_STANDARD.encode(encode_tx_msg_data(dummy_messages()))).unwrap(),
+        ));
+        assert_eq!(
+            decode_tx_acknowledgement(&ack).unwrap(),
+            TxAcknowledgement::Result(dummy_messages())
+        );
+
+        let ack = Acknowledgement::from(AcknowledgementStatus::error(
+            StatusValue::new("host disabled").unwrap(),
+        ));
+        assert_eq!(
+            decode_tx_acknowledgement(&ack).unwrap(),
+            TxAcknowledgement::Error("host disabled".to_string())
+        );
+
+        let ack = Acknowledgement::try_from(b"not json".to_vec()).unwrap();
+        assert!(matches!(
+            decode_tx_acknowledgement(&ack),
+            Err(InterchainAccountError::AckDeserialization)
+        ));
+    }
+
+    #[test]
+    fn test_raw_packet_data_roundtrip() {
+        let data = InterchainAccountPacketData::execute_tx(dummy_messages(), "memo".to_string());
+        let raw = RawInterchainAccountPacketData::from(data.clone());
+
+        assert_eq!(raw.r#type, 1);
+        assert_eq!(InterchainAccountPacketData::try_from(raw).unwrap(), data);
+    }
+}
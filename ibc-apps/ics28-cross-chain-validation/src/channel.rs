@@ -0,0 +1,100 @@
+//! Helpers shared by the consumer and the provider to look up the CCV
+//! channels and to build their packets.
+use core::time::Duration;
+
+use ibc_core::channel::types::channel::Order;
+use ibc_core::channel::types::packet::Packet;
+use ibc_core::channel::types::timeout::TimeoutHeight;
+use ibc_core::host::types::identifiers::{ChannelId, ClientId, ConnectionId, PortId};
+use ibc_core::host::types::path::{ChannelEndPath, SeqSendPath};
+use ibc_core::host::ValidationContext;
+use ibc_core::primitives::prelude::*;
+
+use crate::error::CcvError;
+
+/// Returns an error unless the given channel is ordered, between the given
+/// ports.
+pub(crate) fn validate_channel(
+    order: Order,
+    port_id: &PortId,
+    expected_port_id: &str,
+    counterparty_port_id: &PortId,
+    expected_counterparty_port_id: &str,
+) -> Result<(), CcvError> {
+    if order != Order::Ordered {
+        return Err(CcvError::ChannelNotOrdered {
+            expect_order: Order::Ordered,
+            got_order: order,
+        });
+    }
+
+    for (port_id, expected) in [
+        (port_id, expected_port_id),
+        (counterparty_port_id, expected_counterparty_port_id),
+    ] {
+        if port_id.as_str() != expected {
+            return Err(CcvError::InvalidPort {
+                expected: PortId::new(expected.to_string())?,
+                actual: port_id.clone(),
+            });
+        }
+    }
+
+    Ok(())
+}
+
+/// Returns the client of the connection of a channel over the given
+/// connection hops, which tracks the counterparty chain.
+pub(crate) fn channel_client(
+    ctx: &impl ValidationContext,
+    connection_hops: &[ConnectionId],
+) -> Result<ClientId, CcvError> {
+    let connection_id = connection_hops
+        .first()
+        .ok_or(CcvError::MissingConnectionHop)?;
+
+    Ok(ctx.connection_end(connection_id)?.client_id().clone())
+}
+
+/// Returns the client of the connection of the given channel.
+pub(crate) fn channel_end_client(
+    ctx: &impl ValidationContext,
+    port_id: &PortId,
+    channel_id: &ChannelId,
+) -> Result<ClientId, CcvError> {
+    let channel_end = ctx.channel_end(&ChannelEndPath::new(port_id, channel_id))?;
+
+    channel_client(ctx, channel_end.connection_hops())
+}
+
+/// Returns the packet of the given data over the given CCV channel, which
+/// times out after the given period.
+pub(crate) fn ccv_packet(
+    ctx: &impl ValidationContext,
+    port_id_on_a: PortId,
+    chan_id_on_a: ChannelId,
+    data: Vec<u8>,
+    timeout_period: Duration,
+) -> Result<Packet, CcvError> {
+    let chan_end_on_a = ctx.channel_end(&ChannelEndPath::new(&port_id_on_a, &chan_id_on_a))?;
+    let port_id_on_b = chan_end_on_a.counterparty().port_id().clone();
+    let chan_id_on_b = chan_end_on_a
+        .counterparty()
+        .channel_id()
+        .cloned()
+        .ok_or(CcvError::ChannelNotEstablished)?;
+
+    let seq_on_a = ctx.get_next_sequence_send(&SeqSendPath::new(&port_id_on_a, &chan_id_on_a))?;
+    let timeout_timestamp_on_b = (ctx.host_timestamp()? + timeout_period)?;
+
+    Ok(Packet {
+        seq_on_a,
+        port_id_on_a,
+        chan_id_on_a,
+        port_id_on_b,
+        chan_id_on_b,
+        data,
+        timeout_height_on_b: TimeoutHeight::Never,
+        timeout_timestamp_on_b,
+    })
+}
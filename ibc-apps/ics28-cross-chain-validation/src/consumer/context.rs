@@ -0,0 +1,90 @@
+//! Defines the context traits the consumer implements to track its provider
+//! and apply its validator set changes.
+use core::time::Duration;
+
+use ibc_core::client::types::Height;
+use ibc_core::host::types::identifiers::{ChannelId, ClientId};
+use ibc_core::primitives::prelude::*;
+use ibc_core::primitives::Timestamp;
+use ibc_core::router::types::module::ModuleExtras;
+
+use crate::error::CcvError;
+use crate::packet::{SlashPacketData, ValidatorUpdate};
+
+/// Methods required in the validation of the consumer callbacks and
+/// messages, to be implemented by the host.
+pub trait ConsumerValidationContext {
+    /// Returns the client of the provider, created at the genesis of the
+    /// consumer, over whose connection the CCV channel must be opened.
+    fn provider_client_id(&self) -> Result<ClientId, CcvError>;
+
+    /// Returns the CCV channel with the provider, once established by the
+    /// first validator set change received over it.
+    fn provider_channel(&self) -> Result<Option<ChannelId>, CcvError>;
+
+    /// Returns the unbonding period of the consumer, after which the
+    /// validator set changes it applies mature.
+    fn unbonding_period(&self) -> Result<Duration, CcvError>;
+
+    /// Returns the period after which the packets sent to the provider time
+    /// out.
+    fn ccv_timeout_period(&self) -> Result<Duration, CcvError>;
+}
+
+/// Methods required in the execution of the consumer callbacks and messages,
+/// to be implemented by the host.
+pub trait ConsumerExecutionContext: ConsumerValidationContext {
+    /// Stores the CCV channel with the provider.
+    fn store_provider_channel(&mut self, channel_id: ChannelId) -> Result<(), CcvError>;
+
+    /// Stores the address of the fee pool of the provider, to which the
+    /// consumer transfers the share of its rewards due to the provider.
+    fn store_provider_fee_pool_address(&mut self, address: String) -> Result<(), CcvError>;
+
+    /// Applies the given updates received from the provider to the validator
+    /// set of the consumer, typically by returning them to the consensus at
+    /// the end of the block.
+    fn apply_validator_updates(&mut self, updates: Vec<ValidatorUpdate>) -> Result<(), CcvError>;
+
+    /// Stores the time at which the validator set change of the given
+    /// identifier matures, after which the consumer sends a
+    /// [`VscMaturedPacketData`](crate::packet::VscMaturedPacketData) for it.
+    fn store_maturity_time(
+        &mut self,
+        valset_update_id: u64,
+        maturity_time: Timestamp,
+    ) -> Result<(), CcvError>;
+
+    /// Stores the identifier of the validator set change applied at the given
+    /// height, to which the infractions committed at that height are
+    /// attributed.
+    fn store_height_valset_update_id(
+        &mut self,
+        height: Height,
+        valset_update_id: u64,
+    ) -> Result<(), CcvError>;
+
+    /// Called with the consensus addresses of the validators the provider
+    /// slashed or jailed for their downtime on the consumer, whose pending
+    /// slashing requests can be cleared.
+    fn on_slash_acks_execute(&mut self, _slash_acks: &[String]) -> Result<(), CcvError> {
+        Ok(())
+    }
+
+    /// Called when the provider bounced the given slashing request, which the
+    /// consumer must send again later.
+    fn on_slash_packet_bounced_execute(
+        &mut self,
+        data: &SlashPacketData,
+    ) -> Result<ModuleExtras, CcvError>;
+
+    /// Called when the CCV channel fails, either by the timeout of a packet,
+    /// which closes it, or by an error acknowledgement of the provider. The
+    /// consumer is no longer secured by the provider, and typically halts.
+    fn on_ccv_channel_failure_execute(
+        &mut self,
+        _reason: String,
+    ) -> Result<ModuleExtras, CcvError> {
+        Ok(ModuleExtras::empty())
+    }
+}
@@ -0,0 +1,82 @@
+//! Implements the sending of the packets of the consumer, which notify the
+//! provider of the maturity of its validator set changes and of the
+//! infractions committed on the consumer.
+use ibc_core::channel::handler::{send_packet_execute, send_packet_validate};
+use ibc_core::channel::types::packet::Packet;
+use ibc_core::host::types::identifiers::{PortId, Sequence};
+use ibc_core::host::{ExecutionContext, ValidationContext};
+use ibc_core::primitives::prelude::*;
+
+use crate::channel::ccv_packet;
+use crate::consumer::{ConsumerExecutionContext, ConsumerValidationContext};
+use crate::error::CcvError;
+use crate::packet::{encode_packet_data, ConsumerPacketData};
+use crate::CONSUMER_PORT_ID_STR;
+
+/// Sends the given packet data to the provider. Equivalent to calling
+/// [`send_consumer_packet_validate`], followed by
+/// [`send_consumer_packet_execute`].
+pub fn send_consumer_packet<Ctx>(
+    ctx_a: &mut Ctx,
+    data: ConsumerPacketData,
+) -> Result<Sequence, CcvError>
+where
+    Ctx: ConsumerExecutionContext + ExecutionContext,
+{
+    send_consumer_packet_validate(ctx_a, data.clone())?;
+    send_consumer_packet_execute(ctx_a, data)
+}
+
+/// Validates the packet data sent to the provider. If this succeeds, then it
+/// is legal to send it with [`send_consumer_packet_execute`].
+pub fn send_consumer_packet_validate<Ctx>(
+    ctx_a: &Ctx,
+    data: ConsumerPacketData,
+) -> Result<(), CcvError>
+where
+    Ctx: ConsumerValidationContext + ValidationContext,
+{
+    let packet = consumer_packet(ctx_a, data)?;
+
+    send_packet_validate(ctx_a, &packet)?;
+
+    Ok(())
+}
+
+/// Sends the given packet data to the provider over the CCV channel, and
+/// returns the sequence of its packet.
+pub fn send_consumer_packet_execute<Ctx>(
+    ctx_a: &mut Ctx,
+    data: ConsumerPacketData,
+) -> Result<Sequence, CcvError>
+where
+    Ctx: ConsumerExecutionContext + ExecutionContext,
+{
+    let packet = consumer_packet(ctx_a, data)?;
+    let sequence = packet.seq_on_a;
+
+    send_packet_execute(ctx_a, packet)?;
+
+    Ok(sequence)
+}
+
+/// Returns the packet of the given data over the established CCV channel,
+/// which times out after the CCV timeout period.
+fn consumer_packet<Ctx>(ctx_a: &Ctx, data: ConsumerPacketData) -> Result<Packet, CcvError>
+where
+    Ctx: ConsumerValidationContext + ValidationContext,
+{
+    data.validate_basic()?;
+
+    let chan_id_on_a = ctx_a
+        .provider_channel()?
+        .ok_or(CcvError::ChannelNotEstablished)?;
+
+    ccv_packet(
+        ctx_a,
+        PortId::new(CONSUMER_PORT_ID_STR.to_string())?,
+        chan_id_on_a,
+        encode_packet_data(&data),
+        ctx_a.ccv_timeout_period()?,
+    )
+}
@@ -0,0 +1,10 @@
+//! Implements the consumer side of the cross-chain validation, which applies
+//! the validator set changes of the provider and notifies it of their
+//! maturity and of the infractions committed on the consumer.
+mod context;
+mod handler;
+mod module;
+
+pub use context::*;
+pub use handler::*;
+pub use module::*;
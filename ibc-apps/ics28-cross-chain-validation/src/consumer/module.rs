@@ -0,0 +1,337 @@
+//! Implements the module callbacks of the consumer, which initiates the CCV
+//! channel with its provider, and applies the validator set changes it
+//! receives over it.
+use ibc_core::channel::types::acknowledgement::{Acknowledgement, AcknowledgementStatus};
+use ibc_core::channel::types::channel::{Counterparty, Order};
+use ibc_core::channel::types::packet::Packet;
+use ibc_core::channel::types::Version;
+use ibc_core::host::types::identifiers::{ChannelId, ConnectionId, PortId};
+use ibc_core::host::ValidationContext;
+use ibc_core::primitives::prelude::*;
+use ibc_core::primitives::Signer;
+use ibc_core::router::types::event::ModuleEvent;
+use ibc_core::router::types::module::ModuleExtras;
+
+use crate::channel::{channel_client, validate_channel};
+use crate::consumer::{ConsumerExecutionContext, ConsumerValidationContext};
+use crate::error::CcvError;
+use crate::metadata::{validate_version, HandshakeMetadata};
+use crate::packet::{
+    decode_packet_data, decode_result_acknowledgement, result_acknowledgement, ConsumerPacketData,
+    ValidatorSetChangePacketData, RESULT_HANDLED, RESULT_SLASH_BOUNCED,
+};
+use crate::{CONSUMER_PORT_ID_STR, MODULE_ID_STR, PROVIDER_PORT_ID_STR, VERSION};
+
+/// The kind of the events emitted for the received validator set changes.
+pub const EVENT_TYPE_VSC_PACKET: &str = "vsc_packet";
+
+fn unsupported(description: &str) -> CcvError {
+    CcvError::UnsupportedOperation {
+        description: description.to_string(),
+    }
+}
+
+/// Validates the CCV channel initiated by the consumer, which must be the
+/// first one, over a connection of the client of the provider.
+pub fn on_chan_open_init_validate<Ctx>(
+    ctx: &Ctx,
+    order: Order,
+    connection_hops: &[ConnectionId],
+    port_id: &PortId,
+    _channel_id: &ChannelId,
+    counterparty: &Counterparty,
+    version: &Version,
+) -> Result<(), CcvError>
+where
+    Ctx: ConsumerValidationContext + ValidationContext,
+{
+    validate_channel(
+        order,
+        port_id,
+        CONSUMER_PORT_ID_STR,
+        counterparty.port_id(),
+        PROVIDER_PORT_ID_STR,
+    )?;
+
+    if !version.is_empty() {
+        validate_version(version)?;
+    }
+
+    if let Some(channel_id) = ctx.provider_channel()? {
+        return Err(CcvError::ProviderChannelAlreadySet { channel_id });
+    }
+
+    let expected = ctx.provider_client_id()?;
+    let actual = channel_client(ctx, connection_hops)?;
+
+    if actual != expected {
+        return Err(CcvError::InvalidProviderClient { expected, actual });
+    }
+
+    Ok(())
+}
+
+/// Returns the version of the CCV channel, [`VERSION`].
+pub fn on_chan_open_init_execute(
+    _ctx: &mut impl ConsumerExecutionContext,
+    _order: Order,
+    _connection_hops: &[ConnectionId],
+    _port_id: &PortId,
+    _channel_id: &ChannelId,
+    _counterparty: &Counterparty,
+    _version: &Version,
+) -> Result<(ModuleExtras, Version), CcvError> {
+    Ok((ModuleExtras::empty(), Version::new(VERSION.to_string())))
+}
+
+pub fn on_chan_open_try_validate(
+    _ctx: &impl ConsumerValidationContext,
+    _order: Order,
+    _connection_hops: &[ConnectionId],
+    _port_id: &PortId,
+    _channel_id: &ChannelId,
+    _counterparty: &Counterparty,
+    _counterparty_version: &Version,
+) -> Result<(), CcvError> {
+    Err(unsupported(
+        "the CCV channel must be initiated by the consumer",
+    ))
+}
+
+pub fn on_chan_open_try_execute(
+    _ctx: &mut impl ConsumerExecutionContext,
+    _order: Order,
+    _connection_hops: &[ConnectionId],
+    _port_id: &PortId,
+    _channel_id: &ChannelId,
+    _counterparty: &Counterparty,
+    _counterparty_version: &Version,
+) -> Result<(ModuleExtras, Version), CcvError> {
+    Err(unsupported(
+        "the CCV channel must be initiated by the consumer",
+    ))
+}
+
+/// Validates the metadata with which the provider answered, which must have
+/// its fee pool.
+pub fn on_chan_open_ack_validate(
+    _ctx: &impl ConsumerValidationContext,
+    _port_id: &PortId,
+    _channel_id: &ChannelId,
+    counterparty_version: &Version,
+) -> Result<(), CcvError> {
+    HandshakeMetadata::from_version(counterparty_version)?.validate_basic()
+}
+
+/// Stores the fee pool of the provider. The CCV channel is established once
+/// the first validator set change is received over it.
+pub fn on_chan_open_ack_execute(
+    ctx: &mut impl ConsumerExecutionContext,
+    _port_id: &PortId,
+    _channel_id: &ChannelId,
+    counterparty_version: &Version,
+) -> Result<ModuleExtras, CcvError> {
+    let metadata = HandshakeMetadata::from_version(counterparty_version)?;
+
+    ctx.store_provider_fee_pool_address(metadata.provider_fee_pool_addr)?;
+
+    Ok(ModuleExtras::empty())
+}
+
+pub fn on_chan_open_confirm_validate(
+    _ctx: &impl ConsumerValidationContext,
+    _port_id: &PortId,
+    _channel_id: &ChannelId,
+) -> Result<(), CcvError> {
+    Err(unsupported(
+        "the CCV channel must be initiated by the consumer",
+    ))
+}
+
+pub fn on_chan_open_confirm_execute(
+    _ctx: &mut impl ConsumerExecutionContext,
+    _port_id: &PortId,
+    _channel_id: &ChannelId,
+) -> Result<ModuleExtras, CcvError> {
+    Err(unsupported(
+        "the CCV channel must be initiated by the consumer",
+    ))
+}
+
+/// Disallows the closing of the established CCV channel, while the channels
+/// which failed to be established may be closed.
+pub fn on_chan_close_init_validate(
+    ctx: &impl ConsumerValidationContext,
+    _port_id: &PortId,
+    channel_id: &ChannelId,
+) -> Result<(), CcvError> {
+    if ctx.provider_channel()?.as_ref() == Some(channel_id) {
+        return Err(unsupported("the CCV channel must not be closed"));
+    }
+
+    Ok(())
+}
+
+pub fn on_chan_close_init_execute(
+    _ctx: &mut impl ConsumerExecutionContext,
+    _port_id: &PortId,
+    _channel_id: &ChannelId,
+) -> Result<ModuleExtras, CcvError> {
+    Ok(ModuleExtras::empty())
+}
+
+pub fn on_chan_close_confirm_validate(
+    _ctx: &impl ConsumerValidationContext,
+    _port_id: &PortId,
+    _channel_id: &ChannelId,
+) -> Result<(), CcvError> {
+    Ok(())
+}
+
+pub fn on_chan_close_confirm_execute(
+    _ctx: &mut impl ConsumerExecutionContext,
+    _port_id: &PortId,
+    _channel_id: &ChannelId,
+) -> Result<ModuleExtras, CcvError> {
+    Ok(ModuleExtras::empty())
+}
+
+/// Applies the validator set change of the given packet, and acknowledges it
+/// as handled, or with the error which failed it.
+///
+/// The first validator set change establishes the CCV channel over which it
+/// is received. The change matures after the unbonding period of the
+/// consumer, and the infractions committed from the next height on are
+/// attributed to it.
+pub fn on_recv_packet_execute<Ctx>(
+    ctx_b: &mut Ctx,
+    packet: &Packet,
+) -> (ModuleExtras, Acknowledgement)
+where
+    Ctx: ConsumerExecutionContext + ValidationContext,
+{
+    let result = apply_vsc_packet(ctx_b, packet);
+
+    let mut attributes = vec![
+        ("module", MODULE_ID_STR).into(),
+        ("channel_id", packet.chan_id_on_b.as_str()).into(),
+        ("success", result.is_ok()).into(),
+    ];
+
+    let ack = match result {
+        Ok(valset_update_id) => {
+            attributes.push(("valset_update_id", valset_update_id).into());
+
+            result_acknowledgement(RESULT_HANDLED)
+        }
+        Err(err) => {
+            attributes.push(("error", err.to_string()).into());
+
+            AcknowledgementStatus::error(err.into()).into()
+        }
+    };
+
+    let extras = ModuleExtras {
+        events: vec![ModuleEvent {
+            kind: EVENT_TYPE_VSC_PACKET.to_string(),
+            attributes,
+        }],
+        log: Vec::new(),
+    };
+
+    (extras, ack)
+}
+
+/// Applies the validator set change of the given packet, returning its
+/// identifier.
+fn apply_vsc_packet<Ctx>(ctx_b: &mut Ctx, packet: &Packet) -> Result<u64, CcvError>
+where
+    Ctx: ConsumerExecutionContext + ValidationContext,
+{
+    let data: ValidatorSetChangePacketData = decode_packet_data(&packet.data)?;
+    data.validate_basic()?;
+
+    match ctx_b.provider_channel()? {
+        Some(channel_id) if channel_id != packet.chan_id_on_b => {
+            return Err(CcvError::UnexpectedChannel {
+                channel_id: packet.chan_id_on_b.clone(),
+            });
+        }
+        Some(_) => {}
+        None => ctx_b.store_provider_channel(packet.chan_id_on_b.clone())?,
+    }
+
+    let maturity_time = (ctx_b.host_timestamp()? + ctx_b.unbonding_period()?)?;
+    let next_height = ctx_b.host_height()?.increment();
+
+    ctx_b.store_maturity_time(data.valset_update_id, maturity_time)?;
+    ctx_b.store_height_valset_update_id(next_height, data.valset_update_id)?;
+    ctx_b.apply_validator_updates(data.validator_updates)?;
+    ctx_b.on_slash_acks_execute(&data.slash_acks)?;
+
+    Ok(data.valset_update_id)
+}
+
+pub fn on_acknowledgement_packet_validate(
+    _ctx: &impl ConsumerValidationContext,
+    _packet: &Packet,
+    acknowledgement: &Acknowledgement,
+    _relayer: &Signer,
+) -> Result<(), CcvError> {
+    decode_result_acknowledgement(acknowledgement)?;
+
+    Ok(())
+}
+
+/// Notifies the consumer of the slashing requests the provider bounced, and
+/// of the error acknowledgements, after which the CCV channel must be
+/// closed.
+pub fn on_acknowledgement_packet_execute(
+    ctx: &mut impl ConsumerExecutionContext,
+    packet: &Packet,
+    acknowledgement: &Acknowledgement,
+    _relayer: &Signer,
+) -> (ModuleExtras, Result<(), CcvError>) {
+    let result = decode_result_acknowledgement(acknowledgement).and_then(|result| match result {
+        Ok(RESULT_SLASH_BOUNCED) => match decode_packet_data(&packet.data)? {
+            ConsumerPacketData::Slash(data) => ctx.on_slash_packet_bounced_execute(&data),
+            ConsumerPacketData::VscMatured(_) => Ok(ModuleExtras::empty()),
+        },
+        Ok(_) => Ok(ModuleExtras::empty()),
+        Err(error) => ctx.on_ccv_channel_failure_execute(format!(
+            "the provider failed to handle packet {}: {error}",
+            packet.seq_on_a
+        )),
+    });
+
+    match result {
+        Ok(extras) => (extras, Ok(())),
+        Err(err) => (ModuleExtras::empty(), Err(err)),
+    }
+}
+
+pub fn on_timeout_packet_validate(
+    _ctx: &impl ConsumerValidationContext,
+    _packet: &Packet,
+    _relayer: &Signer,
+) -> Result<(), CcvError> {
+    Ok(())
+}
+
+/// Notifies the consumer that the CCV channel, closed by the timeout of the
+/// given packet, failed.
+pub fn on_timeout_packet_execute(
+    ctx: &mut impl ConsumerExecutionContext,
+    packet: &Packet,
+    _relayer: &Signer,
+) -> (ModuleExtras, Result<(), CcvError>) {
+    let reason = format!(
+        "packet {} timed out on channel {}",
+        packet.seq_on_a, packet.chan_id_on_a
+    );
+
+    match ctx.on_ccv_channel_failure_execute(reason) {
+        Ok(extras) => (extras, Ok(())),
+        Err(err) => (ModuleExtras::empty(), Err(err)),
+    }
+}
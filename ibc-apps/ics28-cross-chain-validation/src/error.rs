@@ -0,0 +1,110 @@
+//! Defines the cross-chain validation error type
+use displaydoc::Display;
+use ibc_core::channel::types::acknowledgement::StatusValue;
+use ibc_core::channel::types::channel::Order;
+use ibc_core::channel::types::error::{ChannelError, PacketError};
+use ibc_core::handler::types::error::ContextError;
+use ibc_core::host::types::error::IdentifierError;
+use ibc_core::host::types::identifiers::{ChainId, ChannelId, ClientId, PortId};
+use ibc_core::primitives::prelude::*;
+use ibc_core::primitives::TimestampOverflowError;
+
+#[derive(Display, Debug)]
+pub enum CcvError {
+    /// context error: `{0}`
+    ContextError(ContextError),
+    /// invalid identifier: `{0}`
+    InvalidIdentifier(IdentifierError),
+    /// expected `{expect_order}` channel, got `{got_order}`
+    ChannelNotOrdered {
+        expect_order: Order,
+        got_order: Order,
+    },
+    /// expected port_id `{expected}`, got `{actual}`
+    InvalidPort { expected: PortId, actual: PortId },
+    /// unsupported CCV version `{version}`
+    UnsupportedVersion { version: String },
+    /// invalid CCV handshake metadata: `{reason}`
+    InvalidMetadata { reason: String },
+    /// channel has no connection hop
+    MissingConnectionHop,
+    /// expected the client_id `{expected}` of the provider, got `{actual}`
+    InvalidProviderClient {
+        expected: ClientId,
+        actual: ClientId,
+    },
+    /// client_id `{client_id}` is not the client of a consumer chain
+    UnknownConsumerClient { client_id: ClientId },
+    /// the CCV channel of the consumer is already channel_id `{channel_id}`
+    ProviderChannelAlreadySet { channel_id: ChannelId },
+    /// the CCV channel of the consumer chain `{chain_id}` is already channel_id `{channel_id}`
+    ConsumerChannelAlreadySet {
+        chain_id: ChainId,
+        channel_id: ChannelId,
+    },
+    /// the CCV channel is not established
+    ChannelNotEstablished,
+    /// channel_id `{channel_id}` is not the CCV channel
+    UnexpectedChannel { channel_id: ChannelId },
+    /// invalid packet data: `{reason}`
+    InvalidPacketData { reason: String },
+    /// timeout overflows the host timestamp: `{0}`
+    TimestampOverflow(TimestampOverflowError),
+    /// unsupported operation: `{description}`
+    UnsupportedOperation { description: String },
+    /// failed to deserialize packet data
+    PacketDataDeserialization,
+    /// failed to deserialize acknowledgement
+    AckDeserialization,
+}
+
+impl core::error::Error for CcvError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match &self {
+            Self::ContextError(e) => Some(e),
+            Self::InvalidIdentifier(e) => Some(e),
+            Self::TimestampOverflow(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<ContextError> for CcvError {
+    fn from(err: ContextError) -> CcvError {
+        Self::ContextError(err)
+    }
+}
+
+impl From<IdentifierError> for CcvError {
+    fn from(err: IdentifierError) -> CcvError {
+        Self::InvalidIdentifier(err)
+    }
+}
+
+impl From<TimestampOverflowError> for CcvError {
+    fn from(err: TimestampOverflowError) -> CcvError {
+        Self::TimestampOverflow(err)
+    }
+}
+
+impl From<CcvError> for ChannelError {
+    fn from(err: CcvError) -> Self {
+        ChannelError::AppModule {
+            description: err.to_string(),
+        }
+    }
+}
+
+impl From<CcvError> for PacketError {
+    fn from(err: CcvError) -> Self {
+        PacketError::AppModule {
+            description: err.to_string(),
+        }
+    }
+}
+
+impl From<CcvError> for StatusValue {
+    fn from(err: CcvError) -> Self {
+        StatusValue::new(err.to_string()).expect("error message must not be empty")
+    }
+}
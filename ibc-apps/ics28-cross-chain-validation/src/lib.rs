@@ -0,0 +1,50 @@
+//! Implementation of the packet data and the application logic scaffolding
+//! of the IBC [Cross-Chain
+//! Validation](https://github.com/cosmos/ibc/blob/main/spec/app/ics-028-cross-chain-validation/README.md)
+//! (ICS-28), with which a provider chain secures consumer chains with its
+//! validator set, as in the Interchain Security of the Cosmos Hub.
+//!
+//! The provider sends the changes of its validator set to each consumer over
+//! an ordered CCV channel, initiated by the consumer. The consumer applies
+//! them, and notifies the provider of their maturity once their unbonding
+//! period elapsed, and of the infractions of the validators committed on it.
+//!
+//! The [`consumer`] and [`provider`] modules implement the module callbacks
+//! of the CCV channels and the sending of their packets, while the staking,
+//! slashing and consensus logic is left to the host through their context
+//! traits.
+#![no_std]
+#![forbid(unsafe_code)]
+#![cfg_attr(not(test), deny(clippy::unwrap_used))]
+#![cfg_attr(not(test), deny(clippy::disallowed_methods, clippy::disallowed_types))]
+#![deny(
+    warnings,
+    trivial_casts,
+    trivial_numeric_casts,
+    unused_import_braces,
+    unused_qualifications,
+    rust_2018_idioms
+)]
+#![allow(clippy::result_large_err)]
+
+#[cfg(any(test, feature = "std"))]
+extern crate std;
+
+mod channel;
+pub mod consumer;
+pub mod error;
+pub mod metadata;
+pub mod packet;
+pub mod provider;
+
+/// The module identifier of the cross-chain validation application.
+pub const MODULE_ID_STR: &str = "ccv";
+
+/// The version of the CCV channels.
+pub const VERSION: &str = "1";
+
+/// The port of the consumer, which initiates the CCV channel.
+pub const CONSUMER_PORT_ID_STR: &str = "consumer";
+
+/// The port of the provider, with one CCV channel for each consumer.
+pub const PROVIDER_PORT_ID_STR: &str = "provider";
@@ -0,0 +1,117 @@
+//! Defines the metadata with which the provider answers the opening of a CCV
+//! channel, carried as its version.
+//!
+//! The consumer proposes the plain [`VERSION`], and the provider answers with
+//! the JSON format of `interchain-security`:
+//!
+//! ```json
+//! {
+//!   "provider_fee_pool_addr": "cosmos1...",
+//!   "version": "1"
+//! }
+//! ```
+use ibc_core::channel::types::Version;
+use ibc_core::primitives::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::error::CcvError;
+use crate::VERSION;
+
+/// The metadata of a CCV channel, set by the provider.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HandshakeMetadata {
+    /// The address of the fee pool of the provider, to which the consumer
+    /// transfers the share of its rewards due to the provider.
+    pub provider_fee_pool_addr: String,
+    /// The version of the CCV channel.
+    pub version: String,
+}
+
+impl HandshakeMetadata {
+    /// Returns the metadata of the given fee pool of the provider, with the
+    /// supported version.
+    pub fn new(provider_fee_pool_addr: String) -> Self {
+        Self {
+            provider_fee_pool_addr,
+            version: VERSION.to_string(),
+        }
+    }
+
+    /// Parses the metadata from the version of a channel.
+    pub fn from_version(version: &Version) -> Result<Self, CcvError> {
+        serde_json::from_str(version.as_str()).map_err(|e| CcvError::InvalidMetadata {
+            reason: e.to_string(),
+        })
+    }
+
+    /// Returns the metadata as the version of a channel.
+    pub fn to_version(&self) -> Version {
+        Version::new(
+            serde_json::to_string(self)
+                .expect("HandshakeMetadata's infallible Serialize impl failed"),
+        )
+    }
+
+    /// Returns an error if the version of the metadata is not supported, or
+    /// if it has no fee pool.
+    pub fn validate_basic(&self) -> Result<(), CcvError> {
+        if self.version != VERSION {
+            return Err(CcvError::UnsupportedVersion {
+                version: self.version.clone(),
+            });
+        }
+
+        if self.provider_fee_pool_addr.trim().is_empty() {
+            return Err(CcvError::InvalidMetadata {
+                reason: "the provider fee pool address must not be empty".to_string(),
+            });
+        }
+
+        Ok(())
+    }
+}
+
+/// Returns an error unless the given version, proposed by the consumer, is
+/// the supported [`VERSION`].
+pub(crate) fn validate_version(version: &Version) -> Result<(), CcvError> {
+    if version.as_str() != VERSION {
+        return Err(CcvError::UnsupportedVersion {
+            version: version.to_string(),
+        });
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_metadata_version_roundtrip() {
+        let metadata = HandshakeMetadata::new("cosmos1".to_string());
+
+        assert_eq!(
+            metadata.to_version(),
+            Version::new(r#"{"provider_fee_pool_addr":"cosmos1","version":"1"}"#.to_string())
+        );
+        assert_eq!(
+            HandshakeMetadata::from_version(&metadata.to_version()).unwrap(),
+            metadata
+        );
+        assert!(metadata.validate_basic().is_ok());
+
+        assert!(HandshakeMetadata::from_version(&Version::new(VERSION.to_string())).is_err());
+
+        let mut invalid = metadata.clone();
+        invalid.version = "2".to_string();
+        assert!(matches!(
+            invalid.validate_basic(),
+            Err(CcvError::UnsupportedVersion { .. })
+        ));
+
+        let mut invalid = metadata;
+        invalid.provider_fee_pool_addr = String::new();
+        assert!(invalid.validate_basic().is_err());
+    }
+}
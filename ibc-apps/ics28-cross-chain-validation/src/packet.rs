@@ -0,0 +1,410 @@
+//! Defines the packet data of the CCV channels, and their acknowledgements.
+//!
+//! The packet data uses the JSON format of the `interchain-security` Go
+//! implementation, where the 64-bit integers are strings and the bytes are
+//! base64-encoded. The provider sends the validator set changes:
+//!
+//! ```json
+//! {
+//!   "validator_updates": [{ "pub_key": { "ed25519": "..." }, "power": "10" }],
+//!   "valset_update_id": "1",
+//!   "slash_acks": []
+//! }
+//! ```
+//!
+//! and the consumer sends the maturities of the validator set changes, and the
+//! slashing requests of the infractions committed on it:
+//!
+//! ```json
+//! {
+//!   "type": "CONSUMER_PACKET_TYPE_SLASH",
+//!   "slashPacketData": {
+//!     "validator": { "address": "...", "power": "10" },
+//!     "valset_update_id": "1",
+//!     "infraction": "INFRACTION_DOWNTIME"
+//!   }
+//! }
+//! ```
+use base64::prelude::BASE64_STANDARD;
+use base64::Engine;
+use ibc_core::channel::types::acknowledgement::{
+    Acknowledgement, AcknowledgementStatus, StatusValue,
+};
+use ibc_core::primitives::prelude::*;
+use ibc_core::primitives::serializers;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::error::CcvError;
+
+/// The public key of a validator of a consumer chain.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PublicKey {
+    Ed25519(
+        #[serde(
+            serialize_with = "serialize_base64",
+            deserialize_with = "deserialize_base64"
+        )]
+        Vec<u8>,
+    ),
+    Secp256k1(
+        #[serde(
+            serialize_with = "serialize_base64",
+            deserialize_with = "deserialize_base64"
+        )]
+        Vec<u8>,
+    ),
+}
+
+impl PublicKey {
+    pub fn as_bytes(&self) -> &[u8] {
+        match self {
+            Self::Ed25519(key) | Self::Secp256k1(key) => key,
+        }
+    }
+}
+
+/// An update of the voting power of a validator, which removes it from the
+/// validator set if zero.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ValidatorUpdate {
+    pub pub_key: PublicKey,
+    #[serde(with = "serializers")]
+    pub power: i64,
+}
+
+/// The packet data of the validator set changes the provider sends to a
+/// consumer.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ValidatorSetChangePacketData {
+    #[serde(default)]
+    pub validator_updates: Vec<ValidatorUpdate>,
+    /// The identifier of the validator set change, increasing on the
+    /// provider.
+    #[serde(with = "serializers")]
+    pub valset_update_id: u64,
+    /// The consensus addresses of the validators the provider slashed or
+    /// jailed for downtime on the consumer since the previous change.
+    #[serde(default)]
+    pub slash_acks: Vec<String>,
+}
+
+impl ValidatorSetChangePacketData {
+    /// Returns an error if the packet data has no identifier, or negative
+    /// voting powers.
+    pub fn validate_basic(&self) -> Result<(), CcvError> {
+        if self.valset_update_id == 0 {
+            return Err(CcvError::InvalidPacketData {
+                reason: "the valset_update_id must not be zero".to_string(),
+            });
+        }
+
+        if let Some(update) = self
+            .validator_updates
+            .iter()
+            .find(|update| update.power < 0)
+        {
+            return Err(CcvError::InvalidPacketData {
+                reason: format!("negative voting power {}", update.power),
+            });
+        }
+
+        Ok(())
+    }
+}
+
+/// A validator of a consumer chain, as identified by its consensus address.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Validator {
+    #[serde(
+        serialize_with = "serialize_base64",
+        deserialize_with = "deserialize_base64"
+    )]
+    pub address: Vec<u8>,
+    #[serde(with = "serializers")]
+    pub power: i64,
+}
+
+/// The kind of the infractions a consumer requests the provider to slash.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Infraction {
+    #[serde(rename = "INFRACTION_UNSPECIFIED")]
+    Unspecified,
+    #[serde(rename = "INFRACTION_DOUBLE_SIGN")]
+    DoubleSign,
+    #[serde(rename = "INFRACTION_DOWNTIME")]
+    Downtime,
+}
+
+/// The packet data of an infraction committed on a consumer, which the
+/// provider slashes the validator for.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SlashPacketData {
+    pub validator: Validator,
+    /// The identifier of the validator set change which was applied on the
+    /// consumer at the height of the infraction.
+    #[serde(with = "serializers")]
+    pub valset_update_id: u64,
+    pub infraction: Infraction,
+}
+
+impl SlashPacketData {
+    /// Returns an error if the packet data has no validator or no infraction.
+    pub fn validate_basic(&self) -> Result<(), CcvError> {
+        if self.validator.address.is_empty() {
+            return Err(CcvError::InvalidPacketData {
+                reason: "the validator address must not be empty".to_string(),
+            });
+        }
+
+        if self.validator.power <= 0 {
+            return Err(CcvError::InvalidPacketData {
+                reason: format!("non-positive voting power {}", self.validator.power),
+            });
+        }
+
+        if self.infraction == Infraction::Unspecified {
+            return Err(CcvError::InvalidPacketData {
+                reason: "the infraction must be specified".to_string(),
+            });
+        }
+
+        Ok(())
+    }
+}
+
+/// The packet data of the maturity of a validator set change on a consumer,
+/// whose unbonding period elapsed since it was applied.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct VscMaturedPacketData {
+    #[serde(with = "serializers")]
+    pub valset_update_id: u64,
+}
+
+/// The packet data a consumer sends to the provider.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(try_from = "RawConsumerPacketData", into = "RawConsumerPacketData")]
+pub enum ConsumerPacketData {
+    Slash(SlashPacketData),
+    VscMatured(VscMaturedPacketData),
+}
+
+impl ConsumerPacketData {
+    /// Returns an error if the slashing request is invalid.
+    pub fn validate_basic(&self) -> Result<(), CcvError> {
+        match self {
+            Self::Slash(data) => data.validate_basic(),
+            Self::VscMatured(data) if data.valset_update_id == 0 => {
+                Err(CcvError::InvalidPacketData {
+                    reason: "the valset_update_id must not be zero".to_string(),
+                })
+            }
+            Self::VscMatured(_) => Ok(()),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+enum ConsumerPacketDataType {
+    #[serde(rename = "CONSUMER_PACKET_TYPE_UNSPECIFIED")]
+    Unspecified,
+    #[serde(rename = "CONSUMER_PACKET_TYPE_SLASH")]
+    Slash,
+    #[serde(rename = "CONSUMER_PACKET_TYPE_VSCM")]
+    VscMatured,
+}
+
+/// The JSON mapping of the `ConsumerPacketData` message, whose data is one of
+/// its fields.
+#[derive(Serialize, Deserialize)]
+struct RawConsumerPacketData {
+    #[serde(rename = "type")]
+    packet_type: ConsumerPacketDataType,
+    #[serde(
+        rename = "slashPacketData",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    slash_packet_data: Option<SlashPacketData>,
+    #[serde(
+        rename = "vscMaturedPacketData",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    vsc_matured_packet_data: Option<VscMaturedPacketData>,
+}
+
+impl TryFrom<RawConsumerPacketData> for ConsumerPacketData {
+    type Error = CcvError;
+
+    fn try_from(raw: RawConsumerPacketData) -> Result<Self, Self::Error> {
+        match (
+            raw.packet_type,
+            raw.slash_packet_data,
+            raw.vsc_matured_packet_data,
+        ) {
+            (ConsumerPacketDataType::Slash, Some(data), None) => Ok(Self::Slash(data)),
+            (ConsumerPacketDataType::VscMatured, None, Some(data)) => Ok(Self::VscMatured(data)),
+            _ => Err(CcvError::InvalidPacketData {
+                reason: "the packet data must match its type".to_string(),
+            }),
+        }
+    }
+}
+
+impl From<ConsumerPacketData> for RawConsumerPacketData {
+    fn from(data: ConsumerPacketData) -> Self {
+        match data {
+            ConsumerPacketData::Slash(data) => Self {
+                packet_type: ConsumerPacketDataType::Slash,
+                slash_packet_data: Some(data),
+                vsc_matured_packet_data: None,
+            },
+            ConsumerPacketData::VscMatured(data) => Self {
+                packet_type: ConsumerPacketDataType::VscMatured,
+                slash_packet_data: None,
+                vsc_matured_packet_data: Some(data),
+            },
+        }
+    }
+}
+
+/// Encodes the given packet data as the bytes of a packet.
+pub fn encode_packet_data(data: &impl Serialize) -> Vec<u8> {
+    serde_json::to_vec(data).expect("the infallible Serialize impl of the packet data failed")
+}
+
+/// Decodes the packet data from the bytes of a packet.
+pub fn decode_packet_data<'a, T: Deserialize<'a>>(data: &'a [u8]) -> Result<T, CcvError> {
+    serde_json::from_slice(data).map_err(|_| CcvError::PacketDataDeserialization)
+}
+
+/// The result of the acknowledgements of the packets which were handled.
+pub const RESULT_HANDLED: u8 = 1;
+
+/// The result of the acknowledgements of the slashing requests which the
+/// provider bounced, for the consumer to send them again later.
+pub const RESULT_SLASH_BOUNCED: u8 = 2;
+
+/// Returns the successful acknowledgement of the given result.
+pub fn result_acknowledgement(result: u8) -> Acknowledgement {
+    let result = StatusValue::from_result([result]).expect("the result is not empty");
+
+    AcknowledgementStatus::success(result).into()
+}
+
+/// Decodes the result of the given acknowledgement, which is either a
+/// successful result or the error the packet failed to be handled with.
+pub fn decode_result_acknowledgement(
+    acknowledgement: &Acknowledgement,
+) -> Result<Result<u8, StatusValue>, CcvError> {
+    match AcknowledgementStatus::try_from(acknowledgement)
+        .map_err(|_| CcvError::AckDeserialization)?
+    {
+        AcknowledgementStatus::Success(result) => {
+            let result = result
+                .decoded_result()
+                .map_err(|_| CcvError::AckDeserialization)?;
+
+            match result.as_slice() {
+                [result] => Ok(Ok(*result)),
+                _ => Err(CcvError::AckDeserialization),
+            }
+        }
+        AcknowledgementStatus::Error(error) => Ok(Err(error)),
+    }
+}
+
+fn serialize_base64<S: Serializer>(bytes: &[u8], serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_str(&BASE64_STANDARD.encode(bytes))
+}
+
+fn deserialize_base64<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<u8>, D::Error> {
+    let encoded = String::deserialize(deserializer)?;
+
+    BASE64_STANDARD
+        .decode(encoded)
+        .map_err(serde::de::Error::custom)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_vsc_packet_data_json() {
+        let data = ValidatorSetChangePacketData {
+            validator_updates: vec![ValidatorUpdate {
+                pub_key: PublicKey::Ed25519(vec![1, 2, 3]),
+                power: 10,
+            }],
+            valset_update_id: 1,
+            slash_acks: vec!["cosmosvalcons1".to_string()],
+        };
+        let json = r#"{"validator_updates":[{"pub_key":{"ed25519":"AQID"},"power":"10"}],"valset_update_id":"1","slash_acks":["cosmosvalcons1"]}"#;
+
+        assert_eq!(String::from_utf8(encode_packet_data(&data)).unwrap(), json);
+        assert_eq!(
+            decode_packet_data::<ValidatorSetChangePacketData>(json.as_bytes()).unwrap(),
+            data
+        );
+        assert!(data.validate_basic().is_ok());
+
+        // The empty lists are omitted by the provider.
+        let data =
+            decode_packet_data::<ValidatorSetChangePacketData>(br#"{"valset_update_id":"2"}"#)
+                .unwrap();
+        assert!(data.validator_updates.is_empty() && data.slash_acks.is_empty());
+
+        let mut invalid = data;
+        invalid.valset_update_id = 0;
+        assert!(invalid.validate_basic().is_err());
+    }
+
+    #[test]
+    fn test_consumer_packet_data_json() {
+        let data = ConsumerPacketData::Slash(SlashPacketData {
+            validator: Validator {
+                address: vec![1, 2, 3],
+                power: 10,
+            },
+            valset_update_id: 1,
+            infraction: Infraction::Downtime,
+        });
+        let json = r#"{"type":"CONSUMER_PACKET_TYPE_SLASH","slashPacketData":{"validator":{"address":"AQID","power":"10"},"valset_update_id":"1","infraction":"INFRACTION_DOWNTIME"}}"#;
+
+        assert_eq!(String::from_utf8(encode_packet_data(&data)).unwrap(), json);
+        assert_eq!(
+            decode_packet_data::<ConsumerPacketData>(json.as_bytes()).unwrap(),
+            data
+        );
+        assert!(data.validate_basic().is_ok());
+
+        let data = ConsumerPacketData::VscMatured(VscMaturedPacketData {
+            valset_update_id: 1,
+        });
+        let json = r#"{"type":"CONSUMER_PACKET_TYPE_VSCM","vscMaturedPacketData":{"valset_update_id":"1"}}"#;
+
+        assert_eq!(String::from_utf8(encode_packet_data(&data)).unwrap(), json);
+        assert_eq!(
+            decode_packet_data::<ConsumerPacketData>(json.as_bytes()).unwrap(),
+            data
+        );
+
+        assert!(decode_packet_data::<ConsumerPacketData>(
+            br#"{"type":"CONSUMER_PACKET_TYPE_SLASH","vscMaturedPacketData":{"valset_update_id":"1"}}"#
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_result_acknowledgement() {
+        let ack = result_acknowledgement(RESULT_SLASH_BOUNCED);
+        assert_eq!(ack.as_bytes(), br#"{"result":"Ag=="}"#);
+        assert_eq!(decode_result_acknowledgement(&ack).unwrap(), Ok(2));
+
+        let error = StatusValue::new("failed").unwrap();
+        let ack = AcknowledgementStatus::error(error.clone()).into();
+        assert_eq!(decode_result_acknowledgement(&ack).unwrap(), Err(error));
+    }
+}
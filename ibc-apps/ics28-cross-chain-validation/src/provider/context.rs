@@ -0,0 +1,76 @@
+//! Defines the context traits the provider implements to track its consumers
+//! and handle the packets they send.
+use core::time::Duration;
+
+use ibc_core::host::types::identifiers::{ChainId, ChannelId, ClientId};
+use ibc_core::primitives::prelude::*;
+use ibc_core::router::types::module::ModuleExtras;
+
+use crate::error::CcvError;
+use crate::packet::{SlashPacketData, VscMaturedPacketData};
+
+/// The outcome of a slashing request handled by the provider.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SlashPacketHandling {
+    /// The request was handled, and the validator slashed or jailed.
+    Handled,
+    /// The request was bounced, e.g. by the throttling of the slashing
+    /// requests, for the consumer to send it again later.
+    Bounced,
+}
+
+/// Methods required in the validation of the provider callbacks and
+/// messages, to be implemented by the host.
+pub trait ProviderValidationContext {
+    /// Returns the consumer chain tracked by the given client, if the
+    /// provider created it for a consumer.
+    fn consumer_chain(&self, client_id: &ClientId) -> Result<Option<ChainId>, CcvError>;
+
+    /// Returns the CCV channel of the given consumer chain, once established.
+    fn consumer_channel(&self, chain_id: &ChainId) -> Result<Option<ChannelId>, CcvError>;
+
+    /// Returns the address of the fee pool of the provider, which is sent to
+    /// the consumers in the metadata of their CCV channel.
+    fn provider_fee_pool_address(&self) -> Result<String, CcvError>;
+
+    /// Returns the period after which the packets sent to the consumers time
+    /// out.
+    fn ccv_timeout_period(&self) -> Result<Duration, CcvError>;
+}
+
+/// Methods required in the execution of the provider callbacks and messages,
+/// to be implemented by the host.
+pub trait ProviderExecutionContext: ProviderValidationContext {
+    /// Stores the established CCV channel of the given consumer chain.
+    fn store_consumer_channel(
+        &mut self,
+        chain_id: &ChainId,
+        channel_id: ChannelId,
+    ) -> Result<(), CcvError>;
+
+    /// Called when the given consumer chain notifies the maturity of a
+    /// validator set change, whose unbonding operations can complete once
+    /// they matured on all the consumers.
+    fn on_vsc_matured_execute(
+        &mut self,
+        chain_id: &ChainId,
+        data: VscMaturedPacketData,
+    ) -> Result<(), CcvError>;
+
+    /// Called with a slashing request of the given consumer chain, which the
+    /// provider handles by slashing or jailing the validator, or bounces.
+    fn on_slash_execute(
+        &mut self,
+        chain_id: &ChainId,
+        data: SlashPacketData,
+    ) -> Result<SlashPacketHandling, CcvError>;
+
+    /// Stops the given consumer chain, whose CCV channel failed, either by
+    /// the timeout of a packet, which closes it, or by an error
+    /// acknowledgement of the consumer.
+    fn stop_consumer_chain(
+        &mut self,
+        chain_id: &ChainId,
+        reason: String,
+    ) -> Result<ModuleExtras, CcvError>;
+}
@@ -0,0 +1,89 @@
+//! Implements the sending of the validator set changes of the provider to its
+//! consumers.
+use ibc_core::channel::handler::{send_packet_execute, send_packet_validate};
+use ibc_core::channel::types::packet::Packet;
+use ibc_core::host::types::identifiers::{ChainId, PortId, Sequence};
+use ibc_core::host::{ExecutionContext, ValidationContext};
+use ibc_core::primitives::prelude::*;
+
+use crate::channel::ccv_packet;
+use crate::error::CcvError;
+use crate::packet::{encode_packet_data, ValidatorSetChangePacketData};
+use crate::provider::{ProviderExecutionContext, ProviderValidationContext};
+use crate::PROVIDER_PORT_ID_STR;
+
+/// Sends the given validator set change to a consumer chain. Equivalent to
+/// calling [`send_vsc_packet_validate`], followed by
+/// [`send_vsc_packet_execute`].
+pub fn send_vsc_packet<Ctx>(
+    ctx_a: &mut Ctx,
+    chain_id: &ChainId,
+    data: ValidatorSetChangePacketData,
+) -> Result<Sequence, CcvError>
+where
+    Ctx: ProviderExecutionContext + ExecutionContext,
+{
+    send_vsc_packet_validate(ctx_a, chain_id, data.clone())?;
+    send_vsc_packet_execute(ctx_a, chain_id, data)
+}
+
+/// Validates the validator set change sent to a consumer chain. If this
+/// succeeds, then it is legal to send it with [`send_vsc_packet_execute`].
+pub fn send_vsc_packet_validate<Ctx>(
+    ctx_a: &Ctx,
+    chain_id: &ChainId,
+    data: ValidatorSetChangePacketData,
+) -> Result<(), CcvError>
+where
+    Ctx: ProviderValidationContext + ValidationContext,
+{
+    let packet = vsc_packet(ctx_a, chain_id, data)?;
+
+    send_packet_validate(ctx_a, &packet)?;
+
+    Ok(())
+}
+
+/// Sends the given validator set change to a consumer chain over its CCV
+/// channel, and returns the sequence of its packet.
+pub fn send_vsc_packet_execute<Ctx>(
+    ctx_a: &mut Ctx,
+    chain_id: &ChainId,
+    data: ValidatorSetChangePacketData,
+) -> Result<Sequence, CcvError>
+where
+    Ctx: ProviderExecutionContext + ExecutionContext,
+{
+    let packet = vsc_packet(ctx_a, chain_id, data)?;
+    let sequence = packet.seq_on_a;
+
+    send_packet_execute(ctx_a, packet)?;
+
+    Ok(sequence)
+}
+
+/// Returns the packet of the given validator set change over the established
+/// CCV channel of the consumer chain, which times out after the CCV timeout
+/// period.
+fn vsc_packet<Ctx>(
+    ctx_a: &Ctx,
+    chain_id: &ChainId,
+    data: ValidatorSetChangePacketData,
+) -> Result<Packet, CcvError>
+where
+    Ctx: ProviderValidationContext + ValidationContext,
+{
+    data.validate_basic()?;
+
+    let chan_id_on_a = ctx_a
+        .consumer_channel(chain_id)?
+        .ok_or(CcvError::ChannelNotEstablished)?;
+
+    ccv_packet(
+        ctx_a,
+        PortId::new(PROVIDER_PORT_ID_STR.to_string())?,
+        chan_id_on_a,
+        encode_packet_data(&data),
+        ctx_a.ccv_timeout_period()?,
+    )
+}
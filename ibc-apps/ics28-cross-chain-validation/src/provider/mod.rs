@@ -0,0 +1,10 @@
+//! Implements the provider side of the cross-chain validation, which sends
+//! the changes of its validator set to the consumers, and handles the
+//! maturities and the slashing requests they send back.
+mod context;
+mod handler;
+mod module;
+
+pub use context::*;
+pub use handler::*;
+pub use module::*;
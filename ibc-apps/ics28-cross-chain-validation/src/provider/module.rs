@@ -0,0 +1,383 @@
+//! Implements the module callbacks of the provider, which accepts a CCV
+//! channel from each of its consumers, and handles the packets they send
+//! over it.
+use ibc_core::channel::types::acknowledgement::{Acknowledgement, AcknowledgementStatus};
+use ibc_core::channel::types::channel::{Counterparty, Order};
+use ibc_core::channel::types::packet::Packet;
+use ibc_core::channel::types::Version;
+use ibc_core::host::types::identifiers::{ChainId, ChannelId, ClientId, ConnectionId, PortId};
+use ibc_core::host::ValidationContext;
+use ibc_core::primitives::prelude::*;
+use ibc_core::primitives::Signer;
+use ibc_core::router::types::event::ModuleEvent;
+use ibc_core::router::types::module::ModuleExtras;
+
+use crate::channel::{channel_client, channel_end_client, validate_channel};
+use crate::error::CcvError;
+use crate::metadata::{validate_version, HandshakeMetadata};
+use crate::packet::{
+    decode_packet_data, decode_result_acknowledgement, result_acknowledgement, ConsumerPacketData,
+    RESULT_HANDLED, RESULT_SLASH_BOUNCED,
+};
+use crate::provider::{ProviderExecutionContext, ProviderValidationContext, SlashPacketHandling};
+use crate::{CONSUMER_PORT_ID_STR, MODULE_ID_STR, PROVIDER_PORT_ID_STR};
+
+/// The kind of the events emitted for the received consumer packets.
+pub const EVENT_TYPE_CONSUMER_PACKET: &str = "consumer_packet";
+
+fn unsupported(description: &str) -> CcvError {
+    CcvError::UnsupportedOperation {
+        description: description.to_string(),
+    }
+}
+
+pub fn on_chan_open_init_validate(
+    _ctx: &impl ProviderValidationContext,
+    _order: Order,
+    _connection_hops: &[ConnectionId],
+    _port_id: &PortId,
+    _channel_id: &ChannelId,
+    _counterparty: &Counterparty,
+    _version: &Version,
+) -> Result<(), CcvError> {
+    Err(unsupported(
+        "the CCV channel must be initiated by the consumer",
+    ))
+}
+
+pub fn on_chan_open_init_execute(
+    _ctx: &mut impl ProviderExecutionContext,
+    _order: Order,
+    _connection_hops: &[ConnectionId],
+    _port_id: &PortId,
+    _channel_id: &ChannelId,
+    _counterparty: &Counterparty,
+    _version: &Version,
+) -> Result<(ModuleExtras, Version), CcvError> {
+    Err(unsupported(
+        "the CCV channel must be initiated by the consumer",
+    ))
+}
+
+/// Validates the CCV channel initiated by a consumer, which must be opened
+/// over a connection of the client the provider created for it, and must be
+/// its first one.
+pub fn on_chan_open_try_validate<Ctx>(
+    ctx: &Ctx,
+    order: Order,
+    connection_hops: &[ConnectionId],
+    port_id: &PortId,
+    _channel_id: &ChannelId,
+    counterparty: &Counterparty,
+    counterparty_version: &Version,
+) -> Result<(), CcvError>
+where
+    Ctx: ProviderValidationContext + ValidationContext,
+{
+    validate_channel(
+        order,
+        port_id,
+        PROVIDER_PORT_ID_STR,
+        counterparty.port_id(),
+        CONSUMER_PORT_ID_STR,
+    )?;
+
+    validate_version(counterparty_version)?;
+
+    let chain_id = consumer_chain(ctx, &channel_client(ctx, connection_hops)?)?;
+
+    validate_no_consumer_channel(ctx, chain_id)
+}
+
+/// Returns the metadata of the CCV channel, with the fee pool of the
+/// provider.
+pub fn on_chan_open_try_execute(
+    ctx: &mut impl ProviderExecutionContext,
+    _order: Order,
+    _connection_hops: &[ConnectionId],
+    _port_id: &PortId,
+    _channel_id: &ChannelId,
+    _counterparty: &Counterparty,
+    _counterparty_version: &Version,
+) -> Result<(ModuleExtras, Version), CcvError> {
+    let metadata = HandshakeMetadata::new(ctx.provider_fee_pool_address()?);
+
+    Ok((ModuleExtras::empty(), metadata.to_version()))
+}
+
+pub fn on_chan_open_ack_validate(
+    _ctx: &impl ProviderValidationContext,
+    _port_id: &PortId,
+    _channel_id: &ChannelId,
+    _counterparty_version: &Version,
+) -> Result<(), CcvError> {
+    Err(unsupported(
+        "the CCV channel must be initiated by the consumer",
+    ))
+}
+
+pub fn on_chan_open_ack_execute(
+    _ctx: &mut impl ProviderExecutionContext,
+    _port_id: &PortId,
+    _channel_id: &ChannelId,
+    _counterparty_version: &Version,
+) -> Result<ModuleExtras, CcvError> {
+    Err(unsupported(
+        "the CCV channel must be initiated by the consumer",
+    ))
+}
+
+/// Validates that the consumer of the opened channel has no CCV channel yet.
+pub fn on_chan_open_confirm_validate<Ctx>(
+    ctx: &Ctx,
+    port_id: &PortId,
+    channel_id: &ChannelId,
+) -> Result<(), CcvError>
+where
+    Ctx: ProviderValidationContext + ValidationContext,
+{
+    let chain_id = consumer_chain(ctx, &channel_end_client(ctx, port_id, channel_id)?)?;
+
+    validate_no_consumer_channel(ctx, chain_id)
+}
+
+/// Establishes the opened channel as the CCV channel of its consumer.
+pub fn on_chan_open_confirm_execute<Ctx>(
+    ctx: &mut Ctx,
+    port_id: &PortId,
+    channel_id: &ChannelId,
+) -> Result<ModuleExtras, CcvError>
+where
+    Ctx: ProviderExecutionContext + ValidationContext,
+{
+    let chain_id = consumer_chain(ctx, &channel_end_client(ctx, port_id, channel_id)?)?;
+
+    ctx.store_consumer_channel(&chain_id, channel_id.clone())?;
+
+    Ok(ModuleExtras {
+        events: Vec::new(),
+        log: vec![format!(
+            "established CCV channel {channel_id} with consumer chain {chain_id}"
+        )],
+    })
+}
+
+/// Disallows the closing of the CCV channels of the consumers.
+pub fn on_chan_close_init_validate(
+    _ctx: &impl ProviderValidationContext,
+    _port_id: &PortId,
+    _channel_id: &ChannelId,
+) -> Result<(), CcvError> {
+    Err(unsupported("the CCV channel must not be closed"))
+}
+
+pub fn on_chan_close_init_execute(
+    _ctx: &mut impl ProviderExecutionContext,
+    _port_id: &PortId,
+    _channel_id: &ChannelId,
+) -> Result<ModuleExtras, CcvError> {
+    Err(unsupported("the CCV channel must not be closed"))
+}
+
+pub fn on_chan_close_confirm_validate(
+    _ctx: &impl ProviderValidationContext,
+    _port_id: &PortId,
+    _channel_id: &ChannelId,
+) -> Result<(), CcvError> {
+    Ok(())
+}
+
+pub fn on_chan_close_confirm_execute(
+    _ctx: &mut impl ProviderExecutionContext,
+    _port_id: &PortId,
+    _channel_id: &ChannelId,
+) -> Result<ModuleExtras, CcvError> {
+    Ok(ModuleExtras::empty())
+}
+
+/// Handles the maturity or the slashing request of the given packet, and
+/// acknowledges it as handled, as bounced, or with the error which failed
+/// it.
+pub fn on_recv_packet_execute<Ctx>(
+    ctx_b: &mut Ctx,
+    packet: &Packet,
+) -> (ModuleExtras, Acknowledgement)
+where
+    Ctx: ProviderExecutionContext + ValidationContext,
+{
+    let result = handle_consumer_packet(ctx_b, packet);
+
+    let mut attributes = vec![
+        ("module", MODULE_ID_STR).into(),
+        ("channel_id", packet.chan_id_on_b.as_str()).into(),
+        ("success", result.is_ok()).into(),
+    ];
+
+    let ack = match result {
+        Ok(result) => result_acknowledgement(result),
+        Err(err) => {
+            attributes.push(("error", err.to_string()).into());
+
+            AcknowledgementStatus::error(err.into()).into()
+        }
+    };
+
+    let extras = ModuleExtras {
+        events: vec![ModuleEvent {
+            kind: EVENT_TYPE_CONSUMER_PACKET.to_string(),
+            attributes,
+        }],
+        log: Vec::new(),
+    };
+
+    (extras, ack)
+}
+
+/// Dispatches the data of the given packet to the provider, returning the
+/// result of its acknowledgement.
+fn handle_consumer_packet<Ctx>(ctx_b: &mut Ctx, packet: &Packet) -> Result<u8, CcvError>
+where
+    Ctx: ProviderExecutionContext + ValidationContext,
+{
+    let data: ConsumerPacketData = decode_packet_data(&packet.data)?;
+    data.validate_basic()?;
+
+    let chain_id = established_consumer_chain(ctx_b, &packet.port_id_on_b, &packet.chan_id_on_b)?;
+
+    match data {
+        ConsumerPacketData::VscMatured(data) => {
+            ctx_b.on_vsc_matured_execute(&chain_id, data)?;
+
+            Ok(RESULT_HANDLED)
+        }
+        ConsumerPacketData::Slash(data) => match ctx_b.on_slash_execute(&chain_id, data)? {
+            SlashPacketHandling::Handled => Ok(RESULT_HANDLED),
+            SlashPacketHandling::Bounced => Ok(RESULT_SLASH_BOUNCED),
+        },
+    }
+}
+
+pub fn on_acknowledgement_packet_validate(
+    _ctx: &impl ProviderValidationContext,
+    _packet: &Packet,
+    acknowledgement: &Acknowledgement,
+    _relayer: &Signer,
+) -> Result<(), CcvError> {
+    decode_result_acknowledgement(acknowledgement)?;
+
+    Ok(())
+}
+
+/// Stops the consumer which failed to apply the validator set change of the
+/// given packet.
+pub fn on_acknowledgement_packet_execute<Ctx>(
+    ctx: &mut Ctx,
+    packet: &Packet,
+    acknowledgement: &Acknowledgement,
+    _relayer: &Signer,
+) -> (ModuleExtras, Result<(), CcvError>)
+where
+    Ctx: ProviderExecutionContext + ValidationContext,
+{
+    let result = decode_result_acknowledgement(acknowledgement).and_then(|result| match result {
+        Ok(_) => Ok(ModuleExtras::empty()),
+        Err(error) => {
+            let chain_id =
+                established_consumer_chain(ctx, &packet.port_id_on_a, &packet.chan_id_on_a)?;
+
+            ctx.stop_consumer_chain(
+                &chain_id,
+                format!(
+                    "the consumer failed to handle packet {}: {error}",
+                    packet.seq_on_a
+                ),
+            )
+        }
+    });
+
+    match result {
+        Ok(extras) => (extras, Ok(())),
+        Err(err) => (ModuleExtras::empty(), Err(err)),
+    }
+}
+
+pub fn on_timeout_packet_validate(
+    _ctx: &impl ProviderValidationContext,
+    _packet: &Packet,
+    _relayer: &Signer,
+) -> Result<(), CcvError> {
+    Ok(())
+}
+
+/// Stops the consumer whose CCV channel is closed by the timeout of the given
+/// packet.
+pub fn on_timeout_packet_execute<Ctx>(
+    ctx: &mut Ctx,
+    packet: &Packet,
+    _relayer: &Signer,
+) -> (ModuleExtras, Result<(), CcvError>)
+where
+    Ctx: ProviderExecutionContext + ValidationContext,
+{
+    let result = established_consumer_chain(ctx, &packet.port_id_on_a, &packet.chan_id_on_a)
+        .and_then(|chain_id| {
+            ctx.stop_consumer_chain(
+                &chain_id,
+                format!(
+                    "packet {} timed out on channel {}",
+                    packet.seq_on_a, packet.chan_id_on_a
+                ),
+            )
+        });
+
+    match result {
+        Ok(extras) => (extras, Ok(())),
+        Err(err) => (ModuleExtras::empty(), Err(err)),
+    }
+}
+
+/// Returns the consumer chain tracked by the given client.
+fn consumer_chain(
+    ctx: &impl ProviderValidationContext,
+    client_id: &ClientId,
+) -> Result<ChainId, CcvError> {
+    ctx.consumer_chain(client_id)?
+        .ok_or_else(|| CcvError::UnknownConsumerClient {
+            client_id: client_id.clone(),
+        })
+}
+
+/// Returns an error if the given consumer chain has a CCV channel already.
+fn validate_no_consumer_channel(
+    ctx: &impl ProviderValidationContext,
+    chain_id: ChainId,
+) -> Result<(), CcvError> {
+    match ctx.consumer_channel(&chain_id)? {
+        Some(channel_id) => Err(CcvError::ConsumerChannelAlreadySet {
+            chain_id,
+            channel_id,
+        }),
+        None => Ok(()),
+    }
+}
+
+/// Returns the consumer chain of the given channel, which must be its
+/// established CCV channel.
+fn established_consumer_chain<Ctx>(
+    ctx: &Ctx,
+    port_id: &PortId,
+    channel_id: &ChannelId,
+) -> Result<ChainId, CcvError>
+where
+    Ctx: ProviderValidationContext + ValidationContext,
+{
+    let chain_id = consumer_chain(ctx, &channel_end_client(ctx, port_id, channel_id)?)?;
+
+    match ctx.consumer_channel(&chain_id)? {
+        Some(consumer_channel_id) if &consumer_channel_id == channel_id => Ok(chain_id),
+        Some(_) => Err(CcvError::UnexpectedChannel {
+            channel_id: channel_id.clone(),
+        }),
+        None => Err(CcvError::ChannelNotEstablished),
+    }
+}
@@ -0,0 +1,135 @@
+//! Defines the main context traits of the fee middleware
+
+use ibc_app_fee_types::error::FeeError;
+use ibc_app_fee_types::{PacketFee, PacketId};
+use ibc_app_transfer_types::PrefixedCoin;
+use ibc_core::host::types::identifiers::{ChannelId, PortId};
+use ibc_core::primitives::prelude::*;
+use ibc_core::primitives::Signer;
+
+/// Methods required in fee middleware validation, to be implemented by the host
+pub trait FeeValidationContext {
+    type AccountId: TryFrom<Signer>;
+
+    /// Returns true if the channel is fee-enabled, i.e. it was opened with, or
+    /// upgraded to, a fee version.
+    fn is_fee_enabled(&self, port_id: &PortId, channel_id: &ChannelId) -> Result<bool, FeeError>;
+
+    /// Returns the address the relayer registered to be paid the `ack_fee`
+    /// and `timeout_fee` at on the given channel, if any.
+    fn get_payee(
+        &self,
+        relayer: &Signer,
+        channel_id: &ChannelId,
+    ) -> Result<Option<Signer>, FeeError>;
+
+    /// Returns the address the relayer registered to be paid the `recv_fee`
+    /// at on the counterparty chain of the given channel, if any.
+    fn get_counterparty_payee(
+        &self,
+        relayer: &Signer,
+        channel_id: &ChannelId,
+    ) -> Result<Option<Signer>, FeeError>;
+
+    /// Returns the fees escrowed for the given packet, which is empty if none
+    /// is.
+    fn get_fees_in_escrow(&self, packet_id: &PacketId) -> Result<Vec<PacketFee>, FeeError>;
+
+    /// Returns the address the relayer of the given packet is paid the
+    /// `recv_fee` at, recorded until the application acknowledges the packet
+    /// asynchronously.
+    fn get_relayer_address_for_async_ack(
+        &self,
+        packet_id: &PacketId,
+    ) -> Result<Option<Signer>, FeeError>;
+
+    /// Returns the fees escrowed for all the packets, along with their
+    /// identifiers.
+    /// Implement only if the host chain serves the queries of the
+    /// incentivized packets.
+    fn incentivized_packets(&self) -> Result<Vec<(PacketId, Vec<PacketFee>)>, FeeError> {
+        Ok(Vec::new())
+    }
+
+    /// Returns the port and channel ids of all the fee-enabled channels.
+    /// Implement only if the host chain serves the queries of the fee-enabled
+    /// channels.
+    fn fee_enabled_channels(&self) -> Result<Vec<(PortId, ChannelId)>, FeeError> {
+        Ok(Vec::new())
+    }
+
+    /// Validates that the fee can be escrowed from the given account.
+    fn escrow_fee_validate(
+        &self,
+        from_account: &Self::AccountId,
+        coins: &[PrefixedCoin],
+    ) -> Result<(), FeeError>;
+}
+
+/// Methods required in fee middleware execution, to be implemented by the host.
+pub trait FeeExecutionContext: FeeValidationContext {
+    /// Marks the channel as fee-enabled.
+    fn store_fee_enabled(
+        &mut self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<(), FeeError>;
+
+    /// Marks the channel as no longer fee-enabled, e.g. once it is upgraded
+    /// to a version without fees.
+    fn delete_fee_enabled(
+        &mut self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<(), FeeError>;
+
+    fn store_payee(
+        &mut self,
+        relayer: &Signer,
+        channel_id: &ChannelId,
+        payee: &Signer,
+    ) -> Result<(), FeeError>;
+
+    fn store_counterparty_payee(
+        &mut self,
+        relayer: &Signer,
+        channel_id: &ChannelId,
+        counterparty_payee: &Signer,
+    ) -> Result<(), FeeError>;
+
+    fn store_fees_in_escrow(
+        &mut self,
+        packet_id: &PacketId,
+        packet_fees: Vec<PacketFee>,
+    ) -> Result<(), FeeError>;
+
+    fn delete_fees_in_escrow(&mut self, packet_id: &PacketId) -> Result<(), FeeError>;
+
+    /// Records the address the relayer of the given packet is paid the
+    /// `recv_fee` at, once the application acknowledges it asynchronously.
+    fn store_relayer_address_for_async_ack(
+        &mut self,
+        packet_id: &PacketId,
+        address: &Signer,
+    ) -> Result<(), FeeError>;
+
+    fn delete_relayer_address_for_async_ack(
+        &mut self,
+        packet_id: &PacketId,
+    ) -> Result<(), FeeError>;
+
+    /// Executes the escrow of the fee from the given account.
+    fn escrow_fee_execute(
+        &mut self,
+        from_account: &Self::AccountId,
+        coins: &[PrefixedCoin],
+    ) -> Result<(), FeeError>;
+
+    /// Pays the given account, a relayer or the refund address of a fee, from
+    /// the escrowed fees.
+    fn unescrow_fee_execute(
+        &mut self,
+        to_account: &Self::AccountId,
+        coins: &[PrefixedCoin],
+    ) -> Result<(), FeeError>;
+}
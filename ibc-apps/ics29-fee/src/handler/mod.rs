@@ -0,0 +1,6 @@
+//! Implements the processing logic for ICS29 (fee middleware) messages.
+mod pay_packet_fee;
+mod register_payee;
+
+pub use pay_packet_fee::*;
+pub use register_payee::*;
@@ -0,0 +1,211 @@
+use ibc_app_fee_types::error::FeeError;
+use ibc_app_fee_types::events::IncentivizedPacketEvent;
+use ibc_app_fee_types::msgs::{MsgPayPacketFee, MsgPayPacketFeeAsync};
+use ibc_app_fee_types::{Fee, PacketFee, PacketId, MODULE_ID_STR};
+use ibc_core::handler::types::events::MessageEvent;
+use ibc_core::host::types::identifiers::{ChannelId, PortId};
+use ibc_core::host::types::path::{ChannelEndPath, CommitmentPath, SeqSendPath};
+use ibc_core::host::{ExecutionContext, ValidationContext};
+use ibc_core::primitives::prelude::*;
+use ibc_core::router::types::event::ModuleEvent;
+
+use crate::context::{FeeExecutionContext, FeeValidationContext};
+
+/// Escrows a fee for the next packet sent on the channel. Equivalent to
+/// calling [`pay_packet_fee_validate`], followed by [`pay_packet_fee_execute`].
+pub fn pay_packet_fee<Ctx, FeeCtx>(
+    ctx_a: &mut Ctx,
+    fee_ctx_a: &mut FeeCtx,
+    msg: MsgPayPacketFee,
+) -> Result<(), FeeError>
+where
+    Ctx: ExecutionContext,
+    FeeCtx: FeeExecutionContext,
+{
+    pay_packet_fee_validate(ctx_a, fee_ctx_a, msg.clone())?;
+    pay_packet_fee_execute(ctx_a, fee_ctx_a, msg)
+}
+
+/// Validates the escrow of the fee. If this succeeds, then it is legal to
+/// escrow it with [`pay_packet_fee_execute`].
+pub fn pay_packet_fee_validate<Ctx, FeeCtx>(
+    ctx_a: &Ctx,
+    fee_ctx_a: &FeeCtx,
+    msg: MsgPayPacketFee,
+) -> Result<(), FeeError>
+where
+    Ctx: ValidationContext,
+    FeeCtx: FeeValidationContext,
+{
+    validate_packet_fee(
+        ctx_a,
+        fee_ctx_a,
+        &msg.port_id_on_a,
+        &msg.chan_id_on_a,
+        &msg.packet_fee(),
+    )
+}
+
+/// Escrows the fee for the next packet sent on the channel. A prior call to
+/// [`pay_packet_fee_validate`] MUST have succeeded.
+pub fn pay_packet_fee_execute<Ctx, FeeCtx>(
+    ctx_a: &mut Ctx,
+    fee_ctx_a: &mut FeeCtx,
+    msg: MsgPayPacketFee,
+) -> Result<(), FeeError>
+where
+    Ctx: ExecutionContext,
+    FeeCtx: FeeExecutionContext,
+{
+    let seq_send_path_on_a = SeqSendPath::new(&msg.port_id_on_a, &msg.chan_id_on_a);
+    let sequence = ctx_a.get_next_sequence_send(&seq_send_path_on_a)?;
+
+    let packet_id = PacketId::new(msg.port_id_on_a.clone(), msg.chan_id_on_a.clone(), sequence);
+
+    escrow_packet_fee(ctx_a, fee_ctx_a, packet_id, msg.packet_fee())
+}
+
+/// Escrows a fee for a packet already in flight. Equivalent to calling
+/// [`pay_packet_fee_async_validate`], followed by
+/// [`pay_packet_fee_async_execute`].
+pub fn pay_packet_fee_async<Ctx, FeeCtx>(
+    ctx_a: &mut Ctx,
+    fee_ctx_a: &mut FeeCtx,
+    msg: MsgPayPacketFeeAsync,
+) -> Result<(), FeeError>
+where
+    Ctx: ExecutionContext,
+    FeeCtx: FeeExecutionContext,
+{
+    pay_packet_fee_async_validate(ctx_a, fee_ctx_a, msg.clone())?;
+    pay_packet_fee_async_execute(ctx_a, fee_ctx_a, msg)
+}
+
+/// Validates the escrow of the fee. If this succeeds, then it is legal to
+/// escrow it with [`pay_packet_fee_async_execute`].
+pub fn pay_packet_fee_async_validate<Ctx, FeeCtx>(
+    ctx_a: &Ctx,
+    fee_ctx_a: &FeeCtx,
+    msg: MsgPayPacketFeeAsync,
+) -> Result<(), FeeError>
+where
+    Ctx: ValidationContext,
+    FeeCtx: FeeValidationContext,
+{
+    let packet_id = &msg.packet_id;
+
+    validate_packet_fee(
+        ctx_a,
+        fee_ctx_a,
+        &packet_id.port_id,
+        &packet_id.channel_id,
+        &msg.packet_fee,
+    )?;
+
+    // Fees can only be escrowed for packets which are neither acknowledged nor
+    // timed out yet, i.e. which commitment is still stored.
+    let commitment_path_on_a = CommitmentPath::new(
+        &packet_id.port_id,
+        &packet_id.channel_id,
+        packet_id.sequence,
+    );
+    if ctx_a.get_packet_commitment(&commitment_path_on_a).is_err() {
+        return Err(FeeError::PacketNotInFlight {
+            sequence: packet_id.sequence,
+        });
+    }
+
+    Ok(())
+}
+
+/// Escrows the fee for the packet in flight. A prior call to
+/// [`pay_packet_fee_async_validate`] MUST have succeeded.
+pub fn pay_packet_fee_async_execute<Ctx, FeeCtx>(
+    ctx_a: &mut Ctx,
+    fee_ctx_a: &mut FeeCtx,
+    msg: MsgPayPacketFeeAsync,
+) -> Result<(), FeeError>
+where
+    Ctx: ExecutionContext,
+    FeeCtx: FeeExecutionContext,
+{
+    escrow_packet_fee(ctx_a, fee_ctx_a, msg.packet_id, msg.packet_fee)
+}
+
+fn validate_packet_fee<Ctx, FeeCtx>(
+    ctx_a: &Ctx,
+    fee_ctx_a: &FeeCtx,
+    port_id_on_a: &PortId,
+    chan_id_on_a: &ChannelId,
+    packet_fee: &PacketFee,
+) -> Result<(), FeeError>
+where
+    Ctx: ValidationContext,
+    FeeCtx: FeeValidationContext,
+{
+    packet_fee.validate()?;
+
+    let chan_end_path_on_a = ChannelEndPath::new(port_id_on_a, chan_id_on_a);
+    ctx_a.channel_end(&chan_end_path_on_a)?;
+
+    if !fee_ctx_a.is_fee_enabled(port_id_on_a, chan_id_on_a)? {
+        return Err(FeeError::FeeNotEnabled {
+            port_id: port_id_on_a.clone(),
+            channel_id: chan_id_on_a.clone(),
+        });
+    }
+
+    let refund_account: FeeCtx::AccountId = packet_fee
+        .refund_address
+        .clone()
+        .try_into()
+        .map_err(|_| FeeError::ParseAccountFailure)?;
+
+    fee_ctx_a.escrow_fee_validate(&refund_account, &packet_fee.fee.total()?)
+}
+
+fn escrow_packet_fee<Ctx, FeeCtx>(
+    ctx_a: &mut Ctx,
+    fee_ctx_a: &mut FeeCtx,
+    packet_id: PacketId,
+    packet_fee: PacketFee,
+) -> Result<(), FeeError>
+where
+    Ctx: ExecutionContext,
+    FeeCtx: FeeExecutionContext,
+{
+    let refund_account = packet_fee
+        .refund_address
+        .clone()
+        .try_into()
+        .map_err(|_| FeeError::ParseAccountFailure)?;
+
+    fee_ctx_a.escrow_fee_execute(&refund_account, &packet_fee.fee.total()?)?;
+
+    let mut packet_fees = fee_ctx_a.get_fees_in_escrow(&packet_id)?;
+    packet_fees.push(packet_fee);
+
+    let total_fee = packet_fees
+        .iter()
+        .try_fold(Fee::default(), |total, packet_fee| {
+            total.checked_add(&packet_fee.fee)
+        })?;
+
+    fee_ctx_a.store_fees_in_escrow(&packet_id, packet_fees)?;
+
+    {
+        ctx_a.log_message(format!(
+            "IBC fee middleware: escrowed fee for packet {packet_id}"
+        ))?;
+
+        let incentivized_packet_event = IncentivizedPacketEvent {
+            packet_id,
+            total_fee,
+        };
+        ctx_a.emit_ibc_event(ModuleEvent::from(incentivized_packet_event).into())?;
+
+        ctx_a.emit_ibc_event(MessageEvent::Module(MODULE_ID_STR.to_string()).into())?;
+    }
+
+    Ok(())
+}
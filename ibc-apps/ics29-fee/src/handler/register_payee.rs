@@ -0,0 +1,160 @@
+use ibc_app_fee_types::error::FeeError;
+use ibc_app_fee_types::events::{RegisterCounterpartyPayeeEvent, RegisterPayeeEvent};
+use ibc_app_fee_types::msgs::{MsgRegisterCounterpartyPayee, MsgRegisterPayee};
+use ibc_app_fee_types::MODULE_ID_STR;
+use ibc_core::handler::types::events::MessageEvent;
+use ibc_core::host::types::identifiers::{ChannelId, PortId};
+use ibc_core::host::types::path::ChannelEndPath;
+use ibc_core::host::{ExecutionContext, ValidationContext};
+use ibc_core::primitives::prelude::*;
+use ibc_core::router::types::event::ModuleEvent;
+
+use crate::context::{FeeExecutionContext, FeeValidationContext};
+
+/// Registers the address the relayer is paid at. Equivalent to calling
+/// [`register_payee_validate`], followed by [`register_payee_execute`].
+pub fn register_payee<Ctx, FeeCtx>(
+    ctx: &mut Ctx,
+    fee_ctx: &mut FeeCtx,
+    msg: MsgRegisterPayee,
+) -> Result<(), FeeError>
+where
+    Ctx: ExecutionContext,
+    FeeCtx: FeeExecutionContext,
+{
+    register_payee_validate(ctx, fee_ctx, msg.clone())?;
+    register_payee_execute(ctx, fee_ctx, msg)
+}
+
+pub fn register_payee_validate<Ctx, FeeCtx>(
+    ctx: &Ctx,
+    fee_ctx: &FeeCtx,
+    msg: MsgRegisterPayee,
+) -> Result<(), FeeError>
+where
+    Ctx: ValidationContext,
+    FeeCtx: FeeValidationContext,
+{
+    validate_fee_enabled_channel(ctx, fee_ctx, &msg.port_id, &msg.chan_id)?;
+
+    // The payee is paid on this chain, so it must be a valid account.
+    let _payee: FeeCtx::AccountId = msg
+        .payee
+        .try_into()
+        .map_err(|_| FeeError::ParseAccountFailure)?;
+
+    Ok(())
+}
+
+pub fn register_payee_execute<Ctx, FeeCtx>(
+    ctx: &mut Ctx,
+    fee_ctx: &mut FeeCtx,
+    msg: MsgRegisterPayee,
+) -> Result<(), FeeError>
+where
+    Ctx: ExecutionContext,
+    FeeCtx: FeeExecutionContext,
+{
+    fee_ctx.store_payee(&msg.relayer, &msg.chan_id, &msg.payee)?;
+
+    {
+        ctx.log_message(format!(
+            "IBC fee middleware: registered payee {} for relayer {} on channel {}",
+            msg.payee, msg.relayer, msg.chan_id
+        ))?;
+
+        let register_payee_event = RegisterPayeeEvent {
+            relayer: msg.relayer,
+            payee: msg.payee,
+            channel_id: msg.chan_id,
+        };
+        ctx.emit_ibc_event(ModuleEvent::from(register_payee_event).into())?;
+
+        ctx.emit_ibc_event(MessageEvent::Module(MODULE_ID_STR.to_string()).into())?;
+    }
+
+    Ok(())
+}
+
+/// Registers the address the relayer is paid at on the counterparty chain.
+/// Equivalent to calling [`register_counterparty_payee_validate`], followed by
+/// [`register_counterparty_payee_execute`].
+pub fn register_counterparty_payee<Ctx, FeeCtx>(
+    ctx: &mut Ctx,
+    fee_ctx: &mut FeeCtx,
+    msg: MsgRegisterCounterpartyPayee,
+) -> Result<(), FeeError>
+where
+    Ctx: ExecutionContext,
+    FeeCtx: FeeExecutionContext,
+{
+    register_counterparty_payee_validate(ctx, fee_ctx, msg.clone())?;
+    register_counterparty_payee_execute(ctx, fee_ctx, msg)
+}
+
+pub fn register_counterparty_payee_validate<Ctx, FeeCtx>(
+    ctx: &Ctx,
+    fee_ctx: &FeeCtx,
+    msg: MsgRegisterCounterpartyPayee,
+) -> Result<(), FeeError>
+where
+    Ctx: ValidationContext,
+    FeeCtx: FeeValidationContext,
+{
+    // The counterparty payee is an account of the counterparty chain, which
+    // can't be validated here.
+    validate_fee_enabled_channel(ctx, fee_ctx, &msg.port_id, &msg.chan_id)
+}
+
+pub fn register_counterparty_payee_execute<Ctx, FeeCtx>(
+    ctx: &mut Ctx,
+    fee_ctx: &mut FeeCtx,
+    msg: MsgRegisterCounterpartyPayee,
+) -> Result<(), FeeError>
+where
+    Ctx: ExecutionContext,
+    FeeCtx: FeeExecutionContext,
+{
+    fee_ctx.store_counterparty_payee(&msg.relayer, &msg.chan_id, &msg.counterparty_payee)?;
+
+    {
+        ctx.log_message(format!(
+            "IBC fee middleware: registered counterparty payee {} for relayer {} on channel {}",
+            msg.counterparty_payee, msg.relayer, msg.chan_id
+        ))?;
+
+        let register_counterparty_payee_event = RegisterCounterpartyPayeeEvent {
+            relayer: msg.relayer,
+            counterparty_payee: msg.counterparty_payee,
+            channel_id: msg.chan_id,
+        };
+        ctx.emit_ibc_event(ModuleEvent::from(register_counterparty_payee_event).into())?;
+
+        ctx.emit_ibc_event(MessageEvent::Module(MODULE_ID_STR.to_string()).into())?;
+    }
+
+    Ok(())
+}
+
+fn validate_fee_enabled_channel<Ctx, FeeCtx>(
+    ctx: &Ctx,
+    fee_ctx: &FeeCtx,
+    port_id: &PortId,
+    chan_id: &ChannelId,
+) -> Result<(), FeeError>
+where
+    Ctx: ValidationContext,
+    FeeCtx: FeeValidationContext,
+{
+    let chan_end_path = ChannelEndPath::new(port_id, chan_id);
+    ctx.channel_end(&chan_end_path)?;
+
+    if !fee_ctx.is_fee_enabled(port_id, chan_id)? {
+        return Err(FeeError::FeeNotEnabled {
+            port_id: port_id.clone(),
+            channel_id: chan_id.clone(),
+        });
+    }
+
+    Ok(())
+}
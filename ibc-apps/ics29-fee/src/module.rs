@@ -0,0 +1,766 @@
+//! Defines the fee middleware, which wraps the IBC module of an application
+//! to incentivize the relaying of its packets.
+//!
+//! It is stacked on top of the application with
+//! [`ModuleStack`](ibc_core::router::middleware::ModuleStack).
+use core::fmt::Debug;
+
+use ibc_app_fee_types::acknowledgement::IncentivizedAcknowledgement;
+use ibc_app_fee_types::error::FeeError;
+use ibc_app_fee_types::events::DistributeFeeEvent;
+use ibc_app_fee_types::metadata::Metadata;
+use ibc_app_fee_types::PacketId;
+use ibc_app_transfer_types::PrefixedCoin;
+use ibc_core::channel::context::WriteAcknowledgementExecutionContext;
+use ibc_core::channel::handler::{
+    write_acknowledgement, write_acknowledgement_execute, write_acknowledgement_validate,
+};
+use ibc_core::channel::types::acknowledgement::{Acknowledgement, AcknowledgementStatus};
+use ibc_core::channel::types::channel::{Counterparty, Order};
+use ibc_core::channel::types::error::{ChannelError, PacketError};
+use ibc_core::channel::types::packet::Packet;
+use ibc_core::channel::types::Version;
+use ibc_core::host::types::identifiers::{ChannelId, ConnectionId, PortId};
+use ibc_core::primitives::prelude::*;
+use ibc_core::primitives::Signer;
+use ibc_core::router::middleware::{merge_extras, Middleware};
+use ibc_core::router::module::Module;
+use ibc_core::router::types::module::ModuleExtras;
+
+use crate::context::FeeExecutionContext;
+
+/// The fee middleware, stacked on top of the IBC module of an application.
+///
+/// Apart from the host context to escrow and pay fees, it holds no state.
+///
+/// On fee-enabled channels, it negotiates the fee version wrapping the version
+/// of the application, wraps the acknowledgements of the application into
+/// [`IncentivizedAcknowledgement`]s, and pays the relayers the fees escrowed
+/// for the packets once they are acknowledged or timed out. On other channels,
+/// it passes all callbacks through to the application.
+///
+/// Applications acknowledging packets asynchronously write their
+/// acknowledgements with [`FeeMiddleware::write_acknowledgement`], which wraps
+/// them as well on fee-enabled channels.
+#[derive(Debug)]
+pub struct FeeMiddleware<C> {
+    ctx: C,
+}
+
+impl<C> FeeMiddleware<C> {
+    pub fn new(ctx: C) -> Self {
+        Self { ctx }
+    }
+
+    pub fn ctx(&self) -> &C {
+        &self.ctx
+    }
+
+    pub fn ctx_mut(&mut self) -> &mut C {
+        &mut self.ctx
+    }
+}
+
+/// Parses the channel version as the version of a fee-enabled channel.
+///
+/// Returns `None` if the version is not fee-enabled, in which case it is the
+/// version of the underlying application.
+fn parse_metadata(version: &Version) -> Result<Option<Metadata>, FeeError> {
+    match Metadata::try_from(version) {
+        Ok(metadata) => {
+            metadata.verify_fee_version()?;
+            Ok(Some(metadata))
+        }
+        Err(_) => Ok(None),
+    }
+}
+
+/// Parses the version proposed by the initiator of a channel handshake, which
+/// defaults to a fee-enabled channel when left empty.
+fn parse_proposed_metadata(version: &Version) -> Result<Option<Metadata>, FeeError> {
+    if version.is_empty() {
+        return Ok(Some(Metadata::new("")));
+    }
+
+    parse_metadata(version)
+}
+
+impl<C> FeeMiddleware<C>
+where
+    C: FeeExecutionContext,
+{
+    fn is_fee_enabled(&self, port_id: &PortId, channel_id: &ChannelId) -> bool {
+        self.ctx
+            .is_fee_enabled(port_id, channel_id)
+            .unwrap_or(false)
+    }
+
+    /// Pays the given coins from the escrowed fees to the receiver, or to the
+    /// refund address if the receiver isn't a valid account.
+    fn distribute_fee(
+        &mut self,
+        receiver: &Signer,
+        refund_address: &Signer,
+        coins: &[PrefixedCoin],
+        extras: &mut ModuleExtras,
+    ) -> Result<(), FeeError> {
+        if coins.is_empty() {
+            return Ok(());
+        }
+
+        let (receiver, account) = match C::AccountId::try_from(receiver.clone()) {
+            Ok(account) => (receiver.clone(), account),
+            Err(_) => (
+                refund_address.clone(),
+                C::AccountId::try_from(refund_address.clone())
+                    .map_err(|_| FeeError::ParseAccountFailure)?,
+            ),
+        };
+
+        self.ctx.unescrow_fee_execute(&account, coins)?;
+
+        extras.events.push(
+            DistributeFeeEvent {
+                receiver,
+                fee: coins.to_vec(),
+            }
+            .into(),
+        );
+
+        Ok(())
+    }
+
+    /// Returns the address the relayer of a received packet is paid the
+    /// `recv_fee` at on the counterparty chain, which is the address it
+    /// registered there if any.
+    fn forward_relayer_address(&self, relayer: &Signer, channel_id: &ChannelId) -> Signer {
+        self.ctx
+            .get_counterparty_payee(relayer, channel_id)
+            .ok()
+            .flatten()
+            .unwrap_or_else(|| relayer.clone())
+    }
+
+    /// Writes the acknowledgement of a packet the application acknowledges
+    /// asynchronously, wrapped into an [`IncentivizedAcknowledgement`] on
+    /// fee-enabled channels.
+    ///
+    /// Applications stacked below the fee middleware call it, instead of the
+    /// `write_acknowledgement` handler of the channel, once the
+    /// acknowledgement is known.
+    pub fn write_acknowledgement(
+        &mut self,
+        ctx_b: &mut impl WriteAcknowledgementExecutionContext,
+        packet: Packet,
+        acknowledgement: Acknowledgement,
+    ) -> Result<(), FeeError> {
+        if !self.is_fee_enabled(&packet.port_id_on_b, &packet.chan_id_on_b) {
+            return Ok(write_acknowledgement(ctx_b, packet, acknowledgement)?);
+        }
+
+        let packet_id = PacketId::new(
+            packet.port_id_on_b.clone(),
+            packet.chan_id_on_b.clone(),
+            packet.seq_on_a,
+        );
+        let forward_relayer_address = self
+            .ctx
+            .get_relayer_address_for_async_ack(&packet_id)?
+            .ok_or_else(|| FeeError::RelayerNotFoundForAsyncAck {
+                packet_id: packet_id.clone(),
+            })?;
+
+        write_acknowledgement_validate(ctx_b, &packet)?;
+
+        self.ctx.delete_relayer_address_for_async_ack(&packet_id)?;
+
+        let ack = incentivized_acknowledgement(acknowledgement, forward_relayer_address);
+        write_acknowledgement_execute(ctx_b, packet, ack.into())?;
+
+        Ok(())
+    }
+
+    /// Returns the address the relayer is paid the `ack_fee` and
+    /// `timeout_fee` at.
+    fn payee(&self, relayer: &Signer, channel_id: &ChannelId) -> Result<Signer, FeeError> {
+        Ok(self
+            .ctx
+            .get_payee(relayer, channel_id)?
+            .unwrap_or_else(|| relayer.clone()))
+    }
+
+    /// Pays the relayers of an acknowledged packet and refunds the timeout
+    /// fees.
+    fn distribute_packet_fees_on_ack(
+        &mut self,
+        packet: &Packet,
+        forward_relayer: &Signer,
+        relayer: &Signer,
+    ) -> Result<ModuleExtras, FeeError> {
+        let packet_id = PacketId::new(
+            packet.port_id_on_a.clone(),
+            packet.chan_id_on_a.clone(),
+            packet.seq_on_a,
+        );
+        let payee = self.payee(relayer, &packet.chan_id_on_a)?;
+        let mut extras = ModuleExtras::empty();
+
+        for packet_fee in self.ctx.get_fees_in_escrow(&packet_id)? {
+            let refund_address = &packet_fee.refund_address;
+
+            self.distribute_fee(
+                forward_relayer,
+                refund_address,
+                &packet_fee.fee.recv_fee,
+                &mut extras,
+            )?;
+            self.distribute_fee(&payee, refund_address, &packet_fee.fee.ack_fee, &mut extras)?;
+            self.distribute_fee(
+                refund_address,
+                refund_address,
+                &packet_fee.fee.timeout_fee,
+                &mut extras,
+            )?;
+        }
+
+        self.ctx.delete_fees_in_escrow(&packet_id)?;
+
+        Ok(extras)
+    }
+
+    /// Pays the relayer of a timed out packet and refunds the receive and
+    /// acknowledgement fees.
+    fn distribute_packet_fees_on_timeout(
+        &mut self,
+        packet: &Packet,
+        relayer: &Signer,
+    ) -> Result<ModuleExtras, FeeError> {
+        let packet_id = PacketId::new(
+            packet.port_id_on_a.clone(),
+            packet.chan_id_on_a.clone(),
+            packet.seq_on_a,
+        );
+        let payee = self.payee(relayer, &packet.chan_id_on_a)?;
+        let mut extras = ModuleExtras::empty();
+
+        for packet_fee in self.ctx.get_fees_in_escrow(&packet_id)? {
+            let refund_address = &packet_fee.refund_address;
+
+            self.distribute_fee(
+                refund_address,
+                refund_address,
+                &packet_fee.fee.recv_fee,
+                &mut extras,
+            )?;
+            self.distribute_fee(
+                refund_address,
+                refund_address,
+                &packet_fee.fee.ack_fee,
+                &mut extras,
+            )?;
+            self.distribute_fee(
+                &payee,
+                refund_address,
+                &packet_fee.fee.timeout_fee,
+                &mut extras,
+            )?;
+        }
+
+        self.ctx.delete_fees_in_escrow(&packet_id)?;
+
+        Ok(extras)
+    }
+}
+
+impl<C> Middleware for FeeMiddleware<C>
+where
+    C: FeeExecutionContext + Debug,
+{
+    fn on_chan_open_init_validate(
+        &self,
+        next: &dyn Module,
+        order: Order,
+        connection_hops: &[ConnectionId],
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        counterparty: &Counterparty,
+        version: &Version,
+    ) -> Result<Version, ChannelError> {
+        match parse_proposed_metadata(version)? {
+            Some(metadata) => {
+                let app_version = next.on_chan_open_init_validate(
+                    order,
+                    connection_hops,
+                    port_id,
+                    channel_id,
+                    counterparty,
+                    &metadata.app_version(),
+                )?;
+                Ok(Metadata::new(app_version).into())
+            }
+            None => next.on_chan_open_init_validate(
+                order,
+                connection_hops,
+                port_id,
+                channel_id,
+                counterparty,
+                version,
+            ),
+        }
+    }
+
+    fn on_chan_open_init_execute(
+        &mut self,
+        next: &mut dyn Module,
+        order: Order,
+        connection_hops: &[ConnectionId],
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        counterparty: &Counterparty,
+        version: &Version,
+    ) -> Result<(ModuleExtras, Version), ChannelError> {
+        match parse_proposed_metadata(version)? {
+            Some(metadata) => {
+                let (extras, app_version) = next.on_chan_open_init_execute(
+                    order,
+                    connection_hops,
+                    port_id,
+                    channel_id,
+                    counterparty,
+                    &metadata.app_version(),
+                )?;
+                self.ctx.store_fee_enabled(port_id, channel_id)?;
+                Ok((extras, Metadata::new(app_version).into()))
+            }
+            None => next.on_chan_open_init_execute(
+                order,
+                connection_hops,
+                port_id,
+                channel_id,
+                counterparty,
+                version,
+            ),
+        }
+    }
+
+    fn on_chan_open_try_validate(
+        &self,
+        next: &dyn Module,
+        order: Order,
+        connection_hops: &[ConnectionId],
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        counterparty: &Counterparty,
+        counterparty_version: &Version,
+    ) -> Result<Version, ChannelError> {
+        match parse_metadata(counterparty_version)? {
+            Some(metadata) => {
+                let app_version = next.on_chan_open_try_validate(
+                    order,
+                    connection_hops,
+                    port_id,
+                    channel_id,
+                    counterparty,
+                    &metadata.app_version(),
+                )?;
+                Ok(Metadata::new(app_version).into())
+            }
+            None => next.on_chan_open_try_validate(
+                order,
+                connection_hops,
+                port_id,
+                channel_id,
+                counterparty,
+                counterparty_version,
+            ),
+        }
+    }
+
+    fn on_chan_open_try_execute(
+        &mut self,
+        next: &mut dyn Module,
+        order: Order,
+        connection_hops: &[ConnectionId],
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        counterparty: &Counterparty,
+        counterparty_version: &Version,
+    ) -> Result<(ModuleExtras, Version), ChannelError> {
+        match parse_metadata(counterparty_version)? {
+            Some(metadata) => {
+                let (extras, app_version) = next.on_chan_open_try_execute(
+                    order,
+                    connection_hops,
+                    port_id,
+                    channel_id,
+                    counterparty,
+                    &metadata.app_version(),
+                )?;
+                self.ctx.store_fee_enabled(port_id, channel_id)?;
+                Ok((extras, Metadata::new(app_version).into()))
+            }
+            None => next.on_chan_open_try_execute(
+                order,
+                connection_hops,
+                port_id,
+                channel_id,
+                counterparty,
+                counterparty_version,
+            ),
+        }
+    }
+
+    fn on_chan_open_ack_validate(
+        &self,
+        next: &dyn Module,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        counterparty_version: &Version,
+    ) -> Result<(), ChannelError> {
+        if !self.is_fee_enabled(port_id, channel_id) {
+            return next.on_chan_open_ack_validate(port_id, channel_id, counterparty_version);
+        }
+
+        // The counterparty must have agreed to the fee-enabled channel.
+        let metadata =
+            parse_metadata(counterparty_version)?.ok_or_else(|| FeeError::InvalidVersion {
+                reason: "the counterparty version is not fee-enabled".to_string(),
+            })?;
+
+        next.on_chan_open_ack_validate(port_id, channel_id, &metadata.app_version())
+    }
+
+    fn on_chan_open_ack_execute(
+        &mut self,
+        next: &mut dyn Module,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        counterparty_version: &Version,
+    ) -> Result<ModuleExtras, ChannelError> {
+        match parse_metadata(counterparty_version)? {
+            Some(metadata) if self.is_fee_enabled(port_id, channel_id) => {
+                next.on_chan_open_ack_execute(port_id, channel_id, &metadata.app_version())
+            }
+            _ => next.on_chan_open_ack_execute(port_id, channel_id, counterparty_version),
+        }
+    }
+
+    fn on_chan_upgrade_init_validate(
+        &self,
+        next: &dyn Module,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        proposed_order: Order,
+        proposed_connection_hops: &[ConnectionId],
+        proposed_version: &Version,
+    ) -> Result<Version, ChannelError> {
+        match parse_metadata(proposed_version)? {
+            Some(metadata) => {
+                let app_version = next.on_chan_upgrade_init_validate(
+                    port_id,
+                    channel_id,
+                    proposed_order,
+                    proposed_connection_hops,
+                    &metadata.app_version(),
+                )?;
+                Ok(Metadata::new(app_version).into())
+            }
+            None => next.on_chan_upgrade_init_validate(
+                port_id,
+                channel_id,
+                proposed_order,
+                proposed_connection_hops,
+                proposed_version,
+            ),
+        }
+    }
+
+    fn on_chan_upgrade_init_execute(
+        &mut self,
+        next: &mut dyn Module,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        proposed_order: Order,
+        proposed_connection_hops: &[ConnectionId],
+        proposed_version: &Version,
+    ) -> Result<(ModuleExtras, Version), ChannelError> {
+        match parse_metadata(proposed_version)? {
+            Some(metadata) => {
+                let (extras, app_version) = next.on_chan_upgrade_init_execute(
+                    port_id,
+                    channel_id,
+                    proposed_order,
+                    proposed_connection_hops,
+                    &metadata.app_version(),
+                )?;
+                Ok((extras, Metadata::new(app_version).into()))
+            }
+            None => next.on_chan_upgrade_init_execute(
+                port_id,
+                channel_id,
+                proposed_order,
+                proposed_connection_hops,
+                proposed_version,
+            ),
+        }
+    }
+
+    fn on_chan_upgrade_try_validate(
+        &self,
+        next: &dyn Module,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        proposed_order: Order,
+        proposed_connection_hops: &[ConnectionId],
+        counterparty_version: &Version,
+    ) -> Result<Version, ChannelError> {
+        match parse_metadata(counterparty_version)? {
+            Some(metadata) => {
+                let app_version = next.on_chan_upgrade_try_validate(
+                    port_id,
+                    channel_id,
+                    proposed_order,
+                    proposed_connection_hops,
+                    &metadata.app_version(),
+                )?;
+                Ok(Metadata::new(app_version).into())
+            }
+            None => next.on_chan_upgrade_try_validate(
+                port_id,
+                channel_id,
+                proposed_order,
+                proposed_connection_hops,
+                counterparty_version,
+            ),
+        }
+    }
+
+    fn on_chan_upgrade_try_execute(
+        &mut self,
+        next: &mut dyn Module,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        proposed_order: Order,
+        proposed_connection_hops: &[ConnectionId],
+        counterparty_version: &Version,
+    ) -> Result<(ModuleExtras, Version), ChannelError> {
+        match parse_metadata(counterparty_version)? {
+            Some(metadata) => {
+                let (extras, app_version) = next.on_chan_upgrade_try_execute(
+                    port_id,
+                    channel_id,
+                    proposed_order,
+                    proposed_connection_hops,
+                    &metadata.app_version(),
+                )?;
+                Ok((extras, Metadata::new(app_version).into()))
+            }
+            None => next.on_chan_upgrade_try_execute(
+                port_id,
+                channel_id,
+                proposed_order,
+                proposed_connection_hops,
+                counterparty_version,
+            ),
+        }
+    }
+
+    fn on_chan_upgrade_ack_validate(
+        &self,
+        next: &dyn Module,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        counterparty_version: &Version,
+    ) -> Result<(), ChannelError> {
+        match parse_metadata(counterparty_version)? {
+            Some(metadata) => {
+                next.on_chan_upgrade_ack_validate(port_id, channel_id, &metadata.app_version())
+            }
+            None => next.on_chan_upgrade_ack_validate(port_id, channel_id, counterparty_version),
+        }
+    }
+
+    fn on_chan_upgrade_ack_execute(
+        &mut self,
+        next: &mut dyn Module,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        counterparty_version: &Version,
+    ) -> Result<ModuleExtras, ChannelError> {
+        match parse_metadata(counterparty_version)? {
+            Some(metadata) => {
+                next.on_chan_upgrade_ack_execute(port_id, channel_id, &metadata.app_version())
+            }
+            None => next.on_chan_upgrade_ack_execute(port_id, channel_id, counterparty_version),
+        }
+    }
+
+    fn on_chan_upgrade_open_validate(
+        &self,
+        next: &dyn Module,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        order: Order,
+        connection_hops: &[ConnectionId],
+        version: &Version,
+    ) -> Result<(), ChannelError> {
+        match parse_metadata(version)? {
+            Some(metadata) => next.on_chan_upgrade_open_validate(
+                port_id,
+                channel_id,
+                order,
+                connection_hops,
+                &metadata.app_version(),
+            ),
+            None => next.on_chan_upgrade_open_validate(
+                port_id,
+                channel_id,
+                order,
+                connection_hops,
+                version,
+            ),
+        }
+    }
+
+    /// Enables or disables fees on the channel, depending on the version it is
+    /// upgraded to.
+    fn on_chan_upgrade_open_execute(
+        &mut self,
+        next: &mut dyn Module,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        order: Order,
+        connection_hops: &[ConnectionId],
+        version: &Version,
+    ) -> Result<ModuleExtras, ChannelError> {
+        match parse_metadata(version)? {
+            Some(metadata) => {
+                self.ctx.store_fee_enabled(port_id, channel_id)?;
+                next.on_chan_upgrade_open_execute(
+                    port_id,
+                    channel_id,
+                    order,
+                    connection_hops,
+                    &metadata.app_version(),
+                )
+            }
+            None => {
+                self.ctx.delete_fee_enabled(port_id, channel_id)?;
+                next.on_chan_upgrade_open_execute(
+                    port_id,
+                    channel_id,
+                    order,
+                    connection_hops,
+                    version,
+                )
+            }
+        }
+    }
+
+    fn on_recv_packet_execute(
+        &mut self,
+        next: &mut dyn Module,
+        packet: &Packet,
+        relayer: &Signer,
+    ) -> (ModuleExtras, Option<Acknowledgement>) {
+        let (extras, ack) = next.on_recv_packet_execute(packet, relayer);
+
+        if !self.is_fee_enabled(&packet.port_id_on_b, &packet.chan_id_on_b) {
+            return (extras, ack);
+        }
+
+        let forward_relayer_address = self.forward_relayer_address(relayer, &packet.chan_id_on_b);
+
+        let Some(app_ack) = ack else {
+            // The relayer is recorded until the application acknowledges the
+            // packet asynchronously with `write_acknowledgement`.
+            let packet_id = PacketId::new(
+                packet.port_id_on_b.clone(),
+                packet.chan_id_on_b.clone(),
+                packet.seq_on_a,
+            );
+
+            return match self
+                .ctx
+                .store_relayer_address_for_async_ack(&packet_id, &forward_relayer_address)
+            {
+                Ok(()) => (extras, None),
+                Err(e) => {
+                    let app_ack = AcknowledgementStatus::error(e.into()).into();
+                    let ack = incentivized_acknowledgement(app_ack, forward_relayer_address);
+
+                    (extras, Some(ack.into()))
+                }
+            };
+        };
+
+        let ack = incentivized_acknowledgement(app_ack, forward_relayer_address);
+
+        (extras, Some(ack.into()))
+    }
+
+    fn on_acknowledgement_packet_validate(
+        &self,
+        next: &dyn Module,
+        packet: &Packet,
+        acknowledgement: &Acknowledgement,
+        relayer: &Signer,
+    ) -> Result<(), PacketError> {
+        if !self.is_fee_enabled(&packet.port_id_on_a, &packet.chan_id_on_a) {
+            return next.on_acknowledgement_packet_validate(packet, acknowledgement, relayer);
+        }
+
+        let ack = IncentivizedAcknowledgement::try_from(acknowledgement)?;
+
+        next.on_acknowledgement_packet_validate(packet, &ack.app_acknowledgement, relayer)
+    }
+
+    fn on_acknowledgement_packet_execute(
+        &mut self,
+        next: &mut dyn Module,
+        packet: &Packet,
+        acknowledgement: &Acknowledgement,
+        relayer: &Signer,
+    ) -> (ModuleExtras, Result<(), PacketError>) {
+        if !self.is_fee_enabled(&packet.port_id_on_a, &packet.chan_id_on_a) {
+            return next.on_acknowledgement_packet_execute(packet, acknowledgement, relayer);
+        }
+
+        let ack = match IncentivizedAcknowledgement::try_from(acknowledgement) {
+            Ok(ack) => ack,
+            Err(e) => return (ModuleExtras::empty(), Err(e.into())),
+        };
+
+        let extras =
+            match self.distribute_packet_fees_on_ack(packet, &ack.forward_relayer_address, relayer)
+            {
+                Ok(extras) => extras,
+                Err(e) => return (ModuleExtras::empty(), Err(e.into())),
+            };
+
+        let (app_extras, result) =
+            next.on_acknowledgement_packet_execute(packet, &ack.app_acknowledgement, relayer);
+
+        (merge_extras(extras, app_extras), result)
+    }
+
+    fn on_timeout_packet_execute(
+        &mut self,
+        next: &mut dyn Module,
+        packet: &Packet,
+        relayer: &Signer,
+    ) -> (ModuleExtras, Result<(), PacketError>) {
+        if !self.is_fee_enabled(&packet.port_id_on_a, &packet.chan_id_on_a) {
+            return next.on_timeout_packet_execute(packet, relayer);
+        }
+
+        let extras = match self.distribute_packet_fees_on_timeout(packet, relayer) {
+            Ok(extras) => extras,
+            Err(e) => return (ModuleExtras::empty(), Err(e.into())),
+        };
+
+        let (app_extras, result) = next.on_timeout_packet_execute(packet, relayer);
+
+        (merge_extras(extras, app_extras), result)
+    }
+}
@@ -0,0 +1,98 @@
+//! Defines the acknowledgement written by the fee middleware.
+#[cfg(feature = "serde")]
+use base64::prelude::BASE64_STANDARD;
+#[cfg(feature = "serde")]
+use base64::Engine;
+use ibc_core::channel::types::acknowledgement::Acknowledgement;
+#[cfg(feature = "serde")]
+use ibc_core::channel::types::error::PacketError;
+#[cfg(feature = "serde")]
+use ibc_core::primitives::prelude::*;
+use ibc_core::primitives::Signer;
+
+#[cfg(feature = "serde")]
+use crate::error::FeeError;
+
+/// The acknowledgement of a packet received on a fee-enabled channel, which
+/// wraps the acknowledgement of the underlying application.
+///
+/// It carries the address the relayer of the `MsgRecvPacket` is paid the
+/// `recv_fee` at, on the chain the packet was sent from.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct IncentivizedAcknowledgement {
+    pub app_acknowledgement: Acknowledgement,
+    pub forward_relayer_address: Signer,
+    pub underlying_app_success: bool,
+}
+
+/// The JSON encoding of [`IncentivizedAcknowledgement`], in which the
+/// acknowledgement of the underlying application is base64 encoded.
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+struct JsonIncentivizedAcknowledgement {
+    app_acknowledgement: String,
+    forward_relayer_address: String,
+    underlying_app_success: bool,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<&Acknowledgement> for IncentivizedAcknowledgement {
+    type Error = FeeError;
+
+    fn try_from(ack: &Acknowledgement) -> Result<Self, Self::Error> {
+        let json: JsonIncentivizedAcknowledgement = serde_json::from_slice(ack.as_bytes())
+            .map_err(|e| FeeError::InvalidAcknowledgement {
+                reason: e.to_string(),
+            })?;
+
+        let app_acknowledgement = BASE64_STANDARD
+            .decode(json.app_acknowledgement)
+            .map_err(|e| FeeError::InvalidAcknowledgement {
+                reason: e.to_string(),
+            })?
+            .try_into()
+            .map_err(|e: PacketError| FeeError::InvalidAcknowledgement {
+                reason: e.to_string(),
+            })?;
+
+        Ok(Self {
+            app_acknowledgement,
+            forward_relayer_address: json.forward_relayer_address.into(),
+            underlying_app_success: json.underlying_app_success,
+        })
+    }
+}
+
+#[cfg(feature = "serde")]
+impl From<IncentivizedAcknowledgement> for Acknowledgement {
+    fn from(ack: IncentivizedAcknowledgement) -> Self {
+        let json = JsonIncentivizedAcknowledgement {
+            app_acknowledgement: BASE64_STANDARD.encode(ack.app_acknowledgement.as_bytes()),
+            forward_relayer_address: ack.forward_relayer_address.to_string(),
+            underlying_app_success: ack.underlying_app_success,
+        };
+
+        serde_json::to_vec(&json)
+            .expect("infallible serialization of the acknowledgement")
+            .try_into()
+            .expect("the JSON encoded acknowledgement is never empty")
+    }
+}
+
+#[cfg(all(test, feature = "serde"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn incentivized_ack_roundtrip() {
+        let ack = IncentivizedAcknowledgement {
+            app_acknowledgement: br#"{"result":"AQ=="}"#.to_vec().try_into().unwrap(),
+            forward_relayer_address: Signer::from("cosmos1relayer".to_string()),
+            underlying_app_success: true,
+        };
+
+        let raw = Acknowledgement::from(ack.clone());
+
+        assert_eq!(IncentivizedAcknowledgement::try_from(&raw).unwrap(), ack);
+    }
+}
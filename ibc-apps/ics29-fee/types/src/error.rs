@@ -0,0 +1,120 @@
+//! Defines the fee middleware error type
+use displaydoc::Display;
+use ibc_app_transfer_types::error::TokenTransferError;
+use ibc_core::channel::types::acknowledgement::StatusValue;
+use ibc_core::channel::types::error::{ChannelError, PacketError};
+use ibc_core::handler::types::error::ContextError;
+use ibc_core::host::types::error::IdentifierError;
+use ibc_core::host::types::identifiers::{ChannelId, PortId, Sequence};
+use ibc_core::primitives::prelude::*;
+use ibc_core::primitives::Signer;
+
+use crate::PacketId;
+
+#[derive(Display, Debug)]
+pub enum FeeError {
+    /// context error: `{0}`
+    ContextError(ContextError),
+    /// invalid identifier: `{0}`
+    InvalidIdentifier(IdentifierError),
+    /// invalid fee coin: `{0}`
+    InvalidCoin(TokenTransferError),
+    /// fee is empty: at least one of the recv, ack or timeout fees must be set
+    EmptyFee,
+    /// fee amount overflow
+    FeeOverflow,
+    /// relayer restrictions on the fee are not supported
+    RelayersNotSupported,
+    /// missing fee in the packet fee
+    MissingFee,
+    /// missing packet identifier
+    MissingPacketId,
+    /// missing packet fee
+    MissingPacketFee,
+    /// empty relayer, payee or refund address
+    EmptyAddress,
+    /// fee is not enabled on port `{port_id}` and channel `{channel_id}`
+    FeeNotEnabled {
+        port_id: PortId,
+        channel_id: ChannelId,
+    },
+    /// no packet with sequence `{sequence}` in flight, which fees could be paid for
+    PacketNotInFlight { sequence: Sequence },
+    /// no fees escrowed for packet `{packet_id}`
+    FeesNotFound { packet_id: PacketId },
+    /// no relayer recorded for the asynchronous acknowledgement of packet `{packet_id}`
+    RelayerNotFoundForAsyncAck { packet_id: PacketId },
+    /// no payee registered by relayer `{relayer}` on channel `{channel_id}`
+    PayeeNotFound {
+        relayer: Signer,
+        channel_id: ChannelId,
+    },
+    /// no counterparty payee registered by relayer `{relayer}` on channel `{channel_id}`
+    CounterpartyPayeeNotFound {
+        relayer: Signer,
+        channel_id: ChannelId,
+    },
+    /// invalid channel version: `{reason}`
+    InvalidVersion { reason: String },
+    /// invalid incentivized acknowledgement: `{reason}`
+    InvalidAcknowledgement { reason: String },
+    /// failed to parse as AccountId
+    ParseAccountFailure,
+    /// decoding raw msg error: `{reason}`
+    DecodeRawMsg { reason: String },
+    /// unknown msg type: `{msg_type}`
+    UnknownMsgType { msg_type: String },
+    /// other error: `{0}`
+    Other(String),
+}
+
+impl core::error::Error for FeeError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match &self {
+            Self::ContextError(e) => Some(e),
+            Self::InvalidIdentifier(e) => Some(e),
+            Self::InvalidCoin(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<ContextError> for FeeError {
+    fn from(err: ContextError) -> FeeError {
+        Self::ContextError(err)
+    }
+}
+
+impl From<IdentifierError> for FeeError {
+    fn from(err: IdentifierError) -> FeeError {
+        Self::InvalidIdentifier(err)
+    }
+}
+
+impl From<TokenTransferError> for FeeError {
+    fn from(err: TokenTransferError) -> FeeError {
+        Self::InvalidCoin(err)
+    }
+}
+
+impl From<FeeError> for ChannelError {
+    fn from(err: FeeError) -> Self {
+        ChannelError::AppModule {
+            description: err.to_string(),
+        }
+    }
+}
+
+impl From<FeeError> for PacketError {
+    fn from(err: FeeError) -> Self {
+        PacketError::AppModule {
+            description: err.to_string(),
+        }
+    }
+}
+
+impl From<FeeError> for StatusValue {
+    fn from(err: FeeError) -> Self {
+        StatusValue::new(err.to_string()).expect("error message must not be empty")
+    }
+}
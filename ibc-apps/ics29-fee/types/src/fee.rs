@@ -0,0 +1,223 @@
+//! Defines the fees incentivizing the relaying of a packet.
+use ibc_app_transfer_types::PrefixedCoin;
+use ibc_core::primitives::prelude::*;
+use ibc_core::primitives::Signer;
+use ibc_proto::cosmos::base::v1beta1::Coin as ProtoCoin;
+use ibc_proto::ibc::apps::fee::v1::{Fee as RawFee, PacketFee as RawPacketFee};
+use ibc_proto::Protobuf;
+
+use crate::error::FeeError;
+
+/// The fees paid to the relayers of a packet, for each step of its lifecycle.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(parity_scale_codec::Encode, parity_scale_codec::Decode,)
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Fee {
+    /// Paid to the relayer which relays the `MsgRecvPacket` to the counterparty.
+    pub recv_fee: Vec<PrefixedCoin>,
+    /// Paid to the relayer which relays the `MsgAcknowledgement` back.
+    pub ack_fee: Vec<PrefixedCoin>,
+    /// Paid to the relayer which relays the `MsgTimeout` if the packet times out.
+    pub timeout_fee: Vec<PrefixedCoin>,
+}
+
+impl Fee {
+    /// Checks that at least one of the fees is set.
+    pub fn validate(&self) -> Result<(), FeeError> {
+        if self.recv_fee.is_empty() && self.ack_fee.is_empty() && self.timeout_fee.is_empty() {
+            return Err(FeeError::EmptyFee);
+        }
+
+        Ok(())
+    }
+
+    /// Returns the coins to escrow to pay for the fee, whichever way the
+    /// packet lifecycle ends.
+    ///
+    /// Since only one of the `recv_fee` plus `ack_fee`, or the `timeout_fee`
+    /// is ever paid, the escrowed amount covers all of them and the rest is
+    /// refunded once the packet lifecycle ends.
+    pub fn total(&self) -> Result<Vec<PrefixedCoin>, FeeError> {
+        let mut total = Vec::new();
+
+        add_coins(&mut total, &self.recv_fee)?;
+        add_coins(&mut total, &self.ack_fee)?;
+        add_coins(&mut total, &self.timeout_fee)?;
+
+        Ok(total)
+    }
+
+    /// Adds up the two fees, for each step of the packet lifecycle.
+    pub fn checked_add(&self, rhs: &Fee) -> Result<Fee, FeeError> {
+        let mut sum = self.clone();
+
+        add_coins(&mut sum.recv_fee, &rhs.recv_fee)?;
+        add_coins(&mut sum.ack_fee, &rhs.ack_fee)?;
+        add_coins(&mut sum.timeout_fee, &rhs.timeout_fee)?;
+
+        Ok(sum)
+    }
+}
+
+/// Adds the coins to the given ones, summing up the amounts of the same
+/// denomination.
+fn add_coins(coins: &mut Vec<PrefixedCoin>, rhs: &[PrefixedCoin]) -> Result<(), FeeError> {
+    for coin in rhs {
+        match coins.iter_mut().find(|c| c.denom == coin.denom) {
+            Some(c) => {
+                c.amount = c
+                    .amount
+                    .checked_add(coin.amount)
+                    .ok_or(FeeError::FeeOverflow)?;
+            }
+            None => coins.push(coin.clone()),
+        }
+    }
+
+    Ok(())
+}
+
+fn coins_from_raw(raw: Vec<ProtoCoin>) -> Result<Vec<PrefixedCoin>, FeeError> {
+    raw.into_iter()
+        .map(|coin| coin.try_into().map_err(FeeError::from))
+        .collect()
+}
+
+impl TryFrom<RawFee> for Fee {
+    type Error = FeeError;
+
+    fn try_from(raw: RawFee) -> Result<Self, Self::Error> {
+        Ok(Fee {
+            recv_fee: coins_from_raw(raw.recv_fee)?,
+            ack_fee: coins_from_raw(raw.ack_fee)?,
+            timeout_fee: coins_from_raw(raw.timeout_fee)?,
+        })
+    }
+}
+
+impl From<Fee> for RawFee {
+    fn from(fee: Fee) -> Self {
+        RawFee {
+            recv_fee: fee.recv_fee.into_iter().map(Into::into).collect(),
+            ack_fee: fee.ack_fee.into_iter().map(Into::into).collect(),
+            timeout_fee: fee.timeout_fee.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl Protobuf<RawFee> for Fee {}
+
+/// A fee escrowed for a packet, along with the account to refund the unpaid
+/// part of it to.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(parity_scale_codec::Encode, parity_scale_codec::Decode,)
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PacketFee {
+    pub fee: Fee,
+    pub refund_address: Signer,
+    /// The relayers allowed to be paid the fee.
+    ///
+    /// Relayer restrictions are not supported yet, so it must be empty.
+    pub relayers: Vec<Signer>,
+}
+
+impl PacketFee {
+    pub fn new(fee: Fee, refund_address: Signer) -> Self {
+        Self {
+            fee,
+            refund_address,
+            relayers: Vec::new(),
+        }
+    }
+
+    /// Checks that the fee is set, the refund address is not empty and no
+    /// relayer restrictions are asked for.
+    pub fn validate(&self) -> Result<(), FeeError> {
+        self.fee.validate()?;
+
+        if self.refund_address.as_ref().is_empty() {
+            return Err(FeeError::EmptyAddress);
+        }
+
+        if !self.relayers.is_empty() {
+            return Err(FeeError::RelayersNotSupported);
+        }
+
+        Ok(())
+    }
+}
+
+impl TryFrom<RawPacketFee> for PacketFee {
+    type Error = FeeError;
+
+    fn try_from(raw: RawPacketFee) -> Result<Self, Self::Error> {
+        Ok(PacketFee {
+            fee: raw.fee.ok_or(FeeError::MissingFee)?.try_into()?,
+            refund_address: raw.refund_address.into(),
+            relayers: raw.relayers.into_iter().map(Into::into).collect(),
+        })
+    }
+}
+
+impl From<PacketFee> for RawPacketFee {
+    fn from(packet_fee: PacketFee) -> Self {
+        RawPacketFee {
+            fee: Some(packet_fee.fee.into()),
+            refund_address: packet_fee.refund_address.to_string(),
+            relayers: packet_fee
+                .relayers
+                .into_iter()
+                .map(|relayer| relayer.to_string())
+                .collect(),
+        }
+    }
+}
+
+impl Protobuf<RawPacketFee> for PacketFee {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn coin(amount: u64, denom: &str) -> PrefixedCoin {
+        PrefixedCoin {
+            denom: denom.parse().expect("valid denom"),
+            amount: amount.into(),
+        }
+    }
+
+    #[test]
+    fn empty_fee_is_invalid() {
+        assert!(Fee::default().validate().is_err());
+    }
+
+    #[test]
+    fn total_fee_sums_coins_by_denom() {
+        let fee = Fee {
+            recv_fee: vec![coin(10, "uatom")],
+            ack_fee: vec![coin(5, "uatom"), coin(1, "stake")],
+            timeout_fee: vec![coin(3, "stake")],
+        };
+
+        assert_eq!(
+            fee.total().unwrap(),
+            vec![coin(15, "uatom"), coin(4, "stake")]
+        );
+    }
+}
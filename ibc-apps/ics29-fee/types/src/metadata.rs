@@ -0,0 +1,99 @@
+//! Defines the version of fee-enabled channels.
+#[cfg(feature = "serde")]
+use core::str::FromStr;
+
+use ibc_core::channel::types::Version;
+use ibc_core::primitives::prelude::*;
+
+use crate::error::FeeError;
+use crate::VERSION;
+
+/// The version of a fee-enabled channel, which wraps the version of the
+/// application the fee middleware is stacked on.
+///
+/// It is JSON encoded into the channel version, e.g.
+/// `{"fee_version":"ics29-1","app_version":"ics20-1"}`.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Metadata {
+    pub fee_version: String,
+    pub app_version: String,
+}
+
+impl Metadata {
+    /// Wraps the given application version into the current fee version.
+    pub fn new(app_version: impl ToString) -> Self {
+        Self {
+            fee_version: VERSION.to_string(),
+            app_version: app_version.to_string(),
+        }
+    }
+
+    /// Checks that the fee version is the one supported by the middleware.
+    pub fn verify_fee_version(&self) -> Result<(), FeeError> {
+        if self.fee_version != VERSION {
+            return Err(FeeError::InvalidVersion {
+                reason: format!("expected fee version {VERSION}, got {}", self.fee_version),
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Returns the version of the underlying application.
+    pub fn app_version(&self) -> Version {
+        Version::new(self.app_version.clone())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl FromStr for Metadata {
+    type Err = FeeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        serde_json::from_str(s).map_err(|e| FeeError::InvalidVersion {
+            reason: e.to_string(),
+        })
+    }
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<&Version> for Metadata {
+    type Error = FeeError;
+
+    fn try_from(version: &Version) -> Result<Self, Self::Error> {
+        version.as_str().parse()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl From<Metadata> for Version {
+    fn from(metadata: Metadata) -> Self {
+        Version::new(
+            serde_json::to_string(&metadata).expect("infallible serialization of the metadata"),
+        )
+    }
+}
+
+#[cfg(all(test, feature = "serde"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn metadata_version_roundtrip() {
+        let version =
+            Version::new(r#"{"fee_version":"ics29-1","app_version":"ics20-1"}"#.to_string());
+
+        let metadata = Metadata::try_from(&version).unwrap();
+
+        assert_eq!(metadata, Metadata::new("ics20-1"));
+        assert!(metadata.verify_fee_version().is_ok());
+        assert_eq!(Version::from(metadata), version);
+    }
+
+    #[test]
+    fn plain_app_version_is_not_metadata() {
+        assert!(Metadata::try_from(&Version::new("ics20-1".to_string())).is_err());
+    }
+}
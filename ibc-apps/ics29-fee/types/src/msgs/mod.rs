@@ -0,0 +1,6 @@
+//! Defines the fee middleware message types
+mod pay_packet_fee;
+mod register_payee;
+
+pub use pay_packet_fee::*;
+pub use register_payee::*;
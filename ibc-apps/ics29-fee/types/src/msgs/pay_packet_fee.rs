@@ -0,0 +1,175 @@
+//! Defines the messages escrowing fees to incentivize the relaying of packets
+
+use ibc_core::host::types::identifiers::{ChannelId, PortId};
+use ibc_core::primitives::prelude::*;
+use ibc_core::primitives::Signer;
+use ibc_proto::google::protobuf::Any;
+use ibc_proto::ibc::apps::fee::v1::{
+    MsgPayPacketFee as RawMsgPayPacketFee, MsgPayPacketFeeAsync as RawMsgPayPacketFeeAsync,
+};
+use ibc_proto::Protobuf;
+
+use crate::error::FeeError;
+use crate::{Fee, PacketFee, PacketId};
+
+pub(crate) const PAY_PACKET_FEE_TYPE_URL: &str = "/ibc.applications.fee.v1.MsgPayPacketFee";
+pub(crate) const PAY_PACKET_FEE_ASYNC_TYPE_URL: &str =
+    "/ibc.applications.fee.v1.MsgPayPacketFeeAsync";
+
+/// Message escrowing a fee for the next packet sent on the given channel.
+///
+/// It is meant to be submitted in the same transaction as, and right before,
+/// the message which sends the packet.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "proto-json",
+    serde(try_from = "RawMsgPayPacketFee", into = "RawMsgPayPacketFee")
+)]
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(parity_scale_codec::Encode, parity_scale_codec::Decode,)
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct MsgPayPacketFee {
+    pub fee: Fee,
+    /// the port on which the packet will be sent
+    pub port_id_on_a: PortId,
+    /// the channel by which the packet will be sent
+    pub chan_id_on_a: ChannelId,
+    /// the account paying the fee, which is refunded the unpaid part of it
+    pub signer: Signer,
+    /// the relayers allowed to be paid the fee, which must be empty
+    pub relayers: Vec<Signer>,
+}
+
+impl MsgPayPacketFee {
+    /// Returns the packet fee escrowed by the message.
+    pub fn packet_fee(&self) -> PacketFee {
+        PacketFee {
+            fee: self.fee.clone(),
+            refund_address: self.signer.clone(),
+            relayers: self.relayers.clone(),
+        }
+    }
+}
+
+impl TryFrom<RawMsgPayPacketFee> for MsgPayPacketFee {
+    type Error = FeeError;
+
+    fn try_from(raw_msg: RawMsgPayPacketFee) -> Result<Self, Self::Error> {
+        Ok(MsgPayPacketFee {
+            fee: raw_msg.fee.ok_or(FeeError::MissingFee)?.try_into()?,
+            port_id_on_a: raw_msg.source_port_id.parse()?,
+            chan_id_on_a: raw_msg.source_channel_id.parse()?,
+            signer: raw_msg.signer.into(),
+            relayers: raw_msg.relayers.into_iter().map(Into::into).collect(),
+        })
+    }
+}
+
+impl From<MsgPayPacketFee> for RawMsgPayPacketFee {
+    fn from(domain_msg: MsgPayPacketFee) -> Self {
+        RawMsgPayPacketFee {
+            fee: Some(domain_msg.fee.into()),
+            source_port_id: domain_msg.port_id_on_a.to_string(),
+            source_channel_id: domain_msg.chan_id_on_a.to_string(),
+            signer: domain_msg.signer.to_string(),
+            relayers: domain_msg
+                .relayers
+                .into_iter()
+                .map(|relayer| relayer.to_string())
+                .collect(),
+        }
+    }
+}
+
+impl Protobuf<RawMsgPayPacketFee> for MsgPayPacketFee {}
+
+impl TryFrom<Any> for MsgPayPacketFee {
+    type Error = FeeError;
+
+    fn try_from(raw: Any) -> Result<Self, Self::Error> {
+        match raw.type_url.as_str() {
+            PAY_PACKET_FEE_TYPE_URL => {
+                MsgPayPacketFee::decode_vec(&raw.value).map_err(|e| FeeError::DecodeRawMsg {
+                    reason: e.to_string(),
+                })
+            }
+            _ => Err(FeeError::UnknownMsgType {
+                msg_type: raw.type_url,
+            }),
+        }
+    }
+}
+
+/// Message escrowing a fee for a packet which is already sent, and not
+/// acknowledged or timed out yet.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "proto-json",
+    serde(try_from = "RawMsgPayPacketFeeAsync", into = "RawMsgPayPacketFeeAsync")
+)]
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(parity_scale_codec::Encode, parity_scale_codec::Decode,)
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct MsgPayPacketFeeAsync {
+    pub packet_id: PacketId,
+    pub packet_fee: PacketFee,
+}
+
+impl TryFrom<RawMsgPayPacketFeeAsync> for MsgPayPacketFeeAsync {
+    type Error = FeeError;
+
+    fn try_from(raw_msg: RawMsgPayPacketFeeAsync) -> Result<Self, Self::Error> {
+        Ok(MsgPayPacketFeeAsync {
+            packet_id: raw_msg
+                .packet_id
+                .ok_or(FeeError::MissingPacketId)?
+                .try_into()?,
+            packet_fee: raw_msg
+                .packet_fee
+                .ok_or(FeeError::MissingPacketFee)?
+                .try_into()?,
+        })
+    }
+}
+
+impl From<MsgPayPacketFeeAsync> for RawMsgPayPacketFeeAsync {
+    fn from(domain_msg: MsgPayPacketFeeAsync) -> Self {
+        RawMsgPayPacketFeeAsync {
+            packet_id: Some(domain_msg.packet_id.into()),
+            packet_fee: Some(domain_msg.packet_fee.into()),
+        }
+    }
+}
+
+impl Protobuf<RawMsgPayPacketFeeAsync> for MsgPayPacketFeeAsync {}
+
+impl TryFrom<Any> for MsgPayPacketFeeAsync {
+    type Error = FeeError;
+
+    fn try_from(raw: Any) -> Result<Self, Self::Error> {
+        match raw.type_url.as_str() {
+            PAY_PACKET_FEE_ASYNC_TYPE_URL => {
+                MsgPayPacketFeeAsync::decode_vec(&raw.value).map_err(|e| FeeError::DecodeRawMsg {
+                    reason: e.to_string(),
+                })
+            }
+            _ => Err(FeeError::UnknownMsgType {
+                msg_type: raw.type_url,
+            }),
+        }
+    }
+}
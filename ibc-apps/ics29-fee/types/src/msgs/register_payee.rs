@@ -0,0 +1,166 @@
+//! Defines the messages registering the addresses relayers are paid at
+
+use ibc_core::host::types::identifiers::{ChannelId, PortId};
+use ibc_core::primitives::prelude::*;
+use ibc_core::primitives::Signer;
+use ibc_proto::google::protobuf::Any;
+use ibc_proto::ibc::apps::fee::v1::{
+    MsgRegisterCounterpartyPayee as RawMsgRegisterCounterpartyPayee,
+    MsgRegisterPayee as RawMsgRegisterPayee,
+};
+use ibc_proto::Protobuf;
+
+use crate::error::FeeError;
+
+pub(crate) const REGISTER_PAYEE_TYPE_URL: &str = "/ibc.applications.fee.v1.MsgRegisterPayee";
+pub(crate) const REGISTER_COUNTERPARTY_PAYEE_TYPE_URL: &str =
+    "/ibc.applications.fee.v1.MsgRegisterCounterpartyPayee";
+
+/// Message registering the address the relayer is paid the `ack_fee` and
+/// `timeout_fee` at, for the packets relayed on the given channel.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "proto-json",
+    serde(try_from = "RawMsgRegisterPayee", into = "RawMsgRegisterPayee")
+)]
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(parity_scale_codec::Encode, parity_scale_codec::Decode,)
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct MsgRegisterPayee {
+    pub port_id: PortId,
+    pub chan_id: ChannelId,
+    /// the address of the relayer, which signs the message
+    pub relayer: Signer,
+    pub payee: Signer,
+}
+
+impl TryFrom<RawMsgRegisterPayee> for MsgRegisterPayee {
+    type Error = FeeError;
+
+    fn try_from(raw_msg: RawMsgRegisterPayee) -> Result<Self, Self::Error> {
+        if raw_msg.relayer.is_empty() || raw_msg.payee.is_empty() {
+            return Err(FeeError::EmptyAddress);
+        }
+
+        Ok(MsgRegisterPayee {
+            port_id: raw_msg.port_id.parse()?,
+            chan_id: raw_msg.channel_id.parse()?,
+            relayer: raw_msg.relayer.into(),
+            payee: raw_msg.payee.into(),
+        })
+    }
+}
+
+impl From<MsgRegisterPayee> for RawMsgRegisterPayee {
+    fn from(domain_msg: MsgRegisterPayee) -> Self {
+        RawMsgRegisterPayee {
+            port_id: domain_msg.port_id.to_string(),
+            channel_id: domain_msg.chan_id.to_string(),
+            relayer: domain_msg.relayer.to_string(),
+            payee: domain_msg.payee.to_string(),
+        }
+    }
+}
+
+impl Protobuf<RawMsgRegisterPayee> for MsgRegisterPayee {}
+
+impl TryFrom<Any> for MsgRegisterPayee {
+    type Error = FeeError;
+
+    fn try_from(raw: Any) -> Result<Self, Self::Error> {
+        match raw.type_url.as_str() {
+            REGISTER_PAYEE_TYPE_URL => {
+                MsgRegisterPayee::decode_vec(&raw.value).map_err(|e| FeeError::DecodeRawMsg {
+                    reason: e.to_string(),
+                })
+            }
+            _ => Err(FeeError::UnknownMsgType {
+                msg_type: raw.type_url,
+            }),
+        }
+    }
+}
+
+/// Message registering the address the relayer is paid the `recv_fee` at, on
+/// the counterparty chain, for the packets it receives on the given channel.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "proto-json",
+    serde(
+        try_from = "RawMsgRegisterCounterpartyPayee",
+        into = "RawMsgRegisterCounterpartyPayee"
+    )
+)]
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(parity_scale_codec::Encode, parity_scale_codec::Decode,)
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct MsgRegisterCounterpartyPayee {
+    pub port_id: PortId,
+    pub chan_id: ChannelId,
+    /// the address of the relayer, which signs the message
+    pub relayer: Signer,
+    pub counterparty_payee: Signer,
+}
+
+impl TryFrom<RawMsgRegisterCounterpartyPayee> for MsgRegisterCounterpartyPayee {
+    type Error = FeeError;
+
+    fn try_from(raw_msg: RawMsgRegisterCounterpartyPayee) -> Result<Self, Self::Error> {
+        if raw_msg.relayer.is_empty() || raw_msg.counterparty_payee.is_empty() {
+            return Err(FeeError::EmptyAddress);
+        }
+
+        Ok(MsgRegisterCounterpartyPayee {
+            port_id: raw_msg.port_id.parse()?,
+            chan_id: raw_msg.channel_id.parse()?,
+            relayer: raw_msg.relayer.into(),
+            counterparty_payee: raw_msg.counterparty_payee.into(),
+        })
+    }
+}
+
+impl From<MsgRegisterCounterpartyPayee> for RawMsgRegisterCounterpartyPayee {
+    fn from(domain_msg: MsgRegisterCounterpartyPayee) -> Self {
+        RawMsgRegisterCounterpartyPayee {
+            port_id: domain_msg.port_id.to_string(),
+            channel_id: domain_msg.chan_id.to_string(),
+            relayer: domain_msg.relayer.to_string(),
+            counterparty_payee: domain_msg.counterparty_payee.to_string(),
+        }
+    }
+}
+
+impl Protobuf<RawMsgRegisterCounterpartyPayee> for MsgRegisterCounterpartyPayee {}
+
+impl TryFrom<Any> for MsgRegisterCounterpartyPayee {
+    type Error = FeeError;
+
+    fn try_from(raw: Any) -> Result<Self, Self::Error> {
+        match raw.type_url.as_str() {
+            REGISTER_COUNTERPARTY_PAYEE_TYPE_URL => {
+                MsgRegisterCounterpartyPayee::decode_vec(&raw.value).map_err(|e| {
+                    FeeError::DecodeRawMsg {
+                        reason: e.to_string(),
+                    }
+                })
+            }
+            _ => Err(FeeError::UnknownMsgType {
+                msg_type: raw.type_url,
+            }),
+        }
+    }
+}
@@ -0,0 +1,47 @@
+//! Defines the context traits the querying chain implements to persist the
+//! cross-chain queries and their results.
+use ibc_core::primitives::prelude::*;
+use ibc_core::router::types::module::ModuleExtras;
+
+use crate::error::CrossChainQueryError;
+use crate::query::{CrossChainQuery, CrossChainQueryResult, QueryId};
+
+/// Methods required in cross-chain queries validation, to be implemented by
+/// the host.
+pub trait CrossChainQueryValidationContext {
+    /// Returns the pending query of the given identifier, if any.
+    fn query(&self, query_id: &QueryId) -> Result<Option<CrossChainQuery>, CrossChainQueryError>;
+
+    /// Returns the sequence of the next submitted query.
+    fn next_query_sequence(&self) -> Result<u64, CrossChainQueryError>;
+}
+
+/// Methods required in cross-chain queries execution, to be implemented by
+/// the host.
+pub trait CrossChainQueryExecutionContext: CrossChainQueryValidationContext {
+    /// Stores the given pending query.
+    fn store_query(&mut self, query: CrossChainQuery) -> Result<(), CrossChainQueryError>;
+
+    /// Deletes the pending query of the given identifier, once its result is
+    /// submitted.
+    fn delete_query(&mut self, query_id: &QueryId) -> Result<(), CrossChainQueryError>;
+
+    /// Increases the sequence of the next submitted query.
+    fn increase_query_sequence(&mut self) -> Result<(), CrossChainQueryError>;
+
+    /// Stores the result of a query.
+    fn store_query_result(
+        &mut self,
+        result: CrossChainQueryResult,
+    ) -> Result<(), CrossChainQueryError>;
+
+    /// Called once the result of the given query is submitted, to notify the
+    /// sender of the query, e.g. a contract.
+    fn on_query_result_execute(
+        &mut self,
+        _query: &CrossChainQuery,
+        _result: &CrossChainQueryResult,
+    ) -> Result<ModuleExtras, CrossChainQueryError> {
+        Ok(ModuleExtras::empty())
+    }
+}
@@ -0,0 +1,79 @@
+//! Defines the cross-chain queries error type
+use displaydoc::Display;
+use ibc_core::client::types::error::ClientError;
+use ibc_core::client::types::Height;
+use ibc_core::handler::types::error::ContextError;
+use ibc_core::host::types::error::IdentifierError;
+use ibc_core::host::types::identifiers::ConnectionId;
+use ibc_core::primitives::prelude::*;
+
+use crate::query::{QueryId, QueryResult};
+
+#[derive(Display, Debug)]
+pub enum CrossChainQueryError {
+    /// context error: `{0}`
+    ContextError(ContextError),
+    /// client error: `{0}`
+    ClientError(ClientError),
+    /// invalid identifier: `{0}`
+    InvalidIdentifier(IdentifierError),
+    /// invalid query id `{query_id}`
+    InvalidQueryId { query_id: String },
+    /// invalid query path `{path}`
+    InvalidPath { path: String },
+    /// the query has neither a timeout height nor a timeout timestamp
+    MissingTimeout,
+    /// the timeout of the query elapsed at height `{height}`
+    QueryExpired { height: Height },
+    /// connection_id `{connection_id}` is not open
+    ConnectionNotOpen { connection_id: ConnectionId },
+    /// no pending query with id `{query_id}`
+    QueryNotFound { query_id: QueryId },
+    /// the timeout of the query `{query_id}` elapsed, its result must be `{expected}`, got `{actual}`
+    UnexpectedQueryResult {
+        query_id: QueryId,
+        expected: QueryResult,
+        actual: QueryResult,
+    },
+    /// the timeout of the query `{query_id}` has not elapsed
+    QueryNotExpired { query_id: QueryId },
+    /// the result of the query `{query_id}` has no proof
+    MissingProof { query_id: QueryId },
+    /// the successful result of the query `{query_id}` has no data
+    EmptyResultData { query_id: QueryId },
+    /// the failed result of the query `{query_id}` must have no data
+    UnexpectedResultData { query_id: QueryId },
+    /// failed to decode raw msg: `{reason}`
+    DecodeRawMsg { reason: String },
+    /// unknown msg type: `{msg_type}`
+    UnknownMsgType { msg_type: String },
+}
+
+impl core::error::Error for CrossChainQueryError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match &self {
+            Self::ContextError(e) => Some(e),
+            Self::ClientError(e) => Some(e),
+            Self::InvalidIdentifier(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<ContextError> for CrossChainQueryError {
+    fn from(err: ContextError) -> CrossChainQueryError {
+        Self::ContextError(err)
+    }
+}
+
+impl From<ClientError> for CrossChainQueryError {
+    fn from(err: ClientError) -> CrossChainQueryError {
+        Self::ClientError(err)
+    }
+}
+
+impl From<IdentifierError> for CrossChainQueryError {
+    fn from(err: IdentifierError) -> CrossChainQueryError {
+        Self::InvalidIdentifier(err)
+    }
+}
@@ -0,0 +1,308 @@
+//! Implements the processing of the submitted queries and of their results.
+use ibc_core::client::context::prelude::*;
+use ibc_core::commitment_types::commitment::CommitmentProofBytes;
+use ibc_core::handler::types::events::{IbcEvent, MessageEvent};
+use ibc_core::host::types::path::ClientConsensusStatePath;
+use ibc_core::host::{ExecutionContext, ValidationContext};
+use ibc_core::primitives::prelude::*;
+use ibc_core::router::types::event::ModuleEvent;
+
+use crate::context::{CrossChainQueryExecutionContext, CrossChainQueryValidationContext};
+use crate::error::CrossChainQueryError;
+use crate::msgs::{MsgSubmitCrossChainQuery, MsgSubmitCrossChainQueryResult};
+use crate::query::{CrossChainQuery, CrossChainQueryResult, QueryId, QueryResult};
+use crate::MODULE_ID_STR;
+
+/// The kind of the events emitted for the submitted queries, which the
+/// relayers fetch the results of.
+pub const EVENT_TYPE_QUERY: &str = "cross_chain_query";
+
+/// The kind of the events emitted for the submitted results of the queries.
+pub const EVENT_TYPE_QUERY_RESULT: &str = "cross_chain_query_result";
+
+/// Submits a query of the state of the counterparty of a connection.
+/// Equivalent to calling [`submit_query_validate`], followed by
+/// [`submit_query_execute`].
+pub fn submit_query<Ctx>(
+    ctx: &mut Ctx,
+    msg: MsgSubmitCrossChainQuery,
+) -> Result<QueryId, CrossChainQueryError>
+where
+    Ctx: CrossChainQueryExecutionContext + ExecutionContext,
+{
+    submit_query_validate(ctx, msg.clone())?;
+    submit_query_execute(ctx, msg)
+}
+
+/// Validates the submitted query, whose connection must be open, and whose
+/// local timeout must be set and not yet elapsed.
+pub fn submit_query_validate<Ctx>(
+    ctx: &Ctx,
+    msg: MsgSubmitCrossChainQuery,
+) -> Result<(), CrossChainQueryError>
+where
+    Ctx: CrossChainQueryValidationContext + ValidationContext,
+{
+    ctx.validate_message_signer(&msg.sender)?;
+
+    if !msg.local_timeout_height.is_set() && !msg.local_timeout_timestamp.is_set() {
+        return Err(CrossChainQueryError::MissingTimeout);
+    }
+
+    let host_height = ctx.host_height()?;
+    let query = pending_query(QueryId::new(ctx.next_query_sequence()?), msg);
+
+    if query.has_expired(host_height, &ctx.host_timestamp()?) {
+        return Err(CrossChainQueryError::QueryExpired {
+            height: host_height,
+        });
+    }
+
+    if !ctx.connection_end(&query.connection_id)?.is_open() {
+        return Err(CrossChainQueryError::ConnectionNotOpen {
+            connection_id: query.connection_id,
+        });
+    }
+
+    Ok(())
+}
+
+/// Stores the submitted query, and emits the event the relayers fetch its
+/// result from. Returns the identifier of the query.
+pub fn submit_query_execute<Ctx>(
+    ctx: &mut Ctx,
+    msg: MsgSubmitCrossChainQuery,
+) -> Result<QueryId, CrossChainQueryError>
+where
+    Ctx: CrossChainQueryExecutionContext + ExecutionContext,
+{
+    let query = pending_query(QueryId::new(ctx.next_query_sequence()?), msg);
+    let query_id = query.id.clone();
+
+    ctx.store_query(query.clone())?;
+    ctx.increase_query_sequence()?;
+
+    {
+        ctx.log_message(format!(
+            "cross-chain queries: submitted query {} of path {} over connection {}",
+            query.id, query.path, query.connection_id
+        ))?;
+
+        let event = ModuleEvent {
+            kind: EVENT_TYPE_QUERY.to_string(),
+            attributes: vec![
+                ("query_id", query.id.as_str()).into(),
+                ("connection_id", query.connection_id.as_str()).into(),
+                ("path", query.path.to_string()).into(),
+                ("query_height", query.query_height).into(),
+                (
+                    "local_timeout_height",
+                    query.local_timeout_height.to_event_attribute_value(),
+                )
+                    .into(),
+                (
+                    "local_timeout_timestamp",
+                    query.local_timeout_timestamp.nanoseconds(),
+                )
+                    .into(),
+                ("sender", query.sender.as_ref()).into(),
+            ],
+        };
+        ctx.emit_ibc_event(IbcEvent::Module(event))?;
+
+        ctx.emit_ibc_event(MessageEvent::Module(MODULE_ID_STR.to_string()).into())?;
+    }
+
+    Ok(query_id)
+}
+
+fn pending_query(id: QueryId, msg: MsgSubmitCrossChainQuery) -> CrossChainQuery {
+    CrossChainQuery {
+        id,
+        path: msg.path,
+        local_timeout_height: msg.local_timeout_height,
+        local_timeout_timestamp: msg.local_timeout_timestamp,
+        query_height: msg.query_height,
+        connection_id: msg.connection_id,
+        sender: msg.sender,
+    }
+}
+
+/// Submits the result of a pending query. Equivalent to calling
+/// [`submit_query_result_validate`], followed by
+/// [`submit_query_result_execute`].
+pub fn submit_query_result<Ctx>(
+    ctx: &mut Ctx,
+    msg: MsgSubmitCrossChainQueryResult,
+) -> Result<(), CrossChainQueryError>
+where
+    Ctx: CrossChainQueryExecutionContext + ExecutionContext,
+{
+    submit_query_result_validate(ctx, msg.clone())?;
+    submit_query_result_execute(ctx, msg)
+}
+
+/// Validates the submitted result of a pending query.
+///
+/// The result of a query whose local timeout elapsed must be
+/// [`QueryResult::Timeout`]. Otherwise, the value of the path, or its
+/// absence, must be proven at the height of the query against the client of
+/// its connection, under the commitment prefix of the counterparty.
+pub fn submit_query_result_validate<Ctx>(
+    ctx: &Ctx,
+    msg: MsgSubmitCrossChainQueryResult,
+) -> Result<(), CrossChainQueryError>
+where
+    Ctx: CrossChainQueryValidationContext + ValidationContext,
+{
+    ctx.validate_message_signer(&msg.relayer)?;
+
+    let query = ctx
+        .query(&msg.id)?
+        .ok_or_else(|| CrossChainQueryError::QueryNotFound {
+            query_id: msg.id.clone(),
+        })?;
+
+    if query.has_expired(ctx.host_height()?, &ctx.host_timestamp()?) {
+        if msg.result != QueryResult::Timeout {
+            return Err(CrossChainQueryError::UnexpectedQueryResult {
+                query_id: msg.id,
+                expected: QueryResult::Timeout,
+                actual: msg.result,
+            });
+        }
+
+        return Ok(());
+    }
+
+    let proof = msg
+        .proof
+        .as_ref()
+        .ok_or_else(|| CrossChainQueryError::MissingProof {
+            query_id: msg.id.clone(),
+        })?;
+
+    match msg.result {
+        QueryResult::Success if msg.data.is_empty() => {
+            Err(CrossChainQueryError::EmptyResultData { query_id: msg.id })
+        }
+        QueryResult::Success => verify_query_result(ctx, &query, proof, Some(msg.data)),
+        QueryResult::Failure if !msg.data.is_empty() => {
+            Err(CrossChainQueryError::UnexpectedResultData { query_id: msg.id })
+        }
+        QueryResult::Failure => verify_query_result(ctx, &query, proof, None),
+        QueryResult::Timeout => Err(CrossChainQueryError::QueryNotExpired { query_id: msg.id }),
+    }
+}
+
+/// Verifies the proof of the given value of the path of the query, or of its
+/// absence, against the client of the connection of the query.
+fn verify_query_result<Ctx>(
+    ctx: &Ctx,
+    query: &CrossChainQuery,
+    proof: &CommitmentProofBytes,
+    value: Option<Vec<u8>>,
+) -> Result<(), CrossChainQueryError>
+where
+    Ctx: ValidationContext,
+{
+    let conn_end = ctx.connection_end(&query.connection_id)?;
+
+    if !conn_end.is_open() {
+        return Err(CrossChainQueryError::ConnectionNotOpen {
+            connection_id: query.connection_id.clone(),
+        });
+    }
+
+    let client_id = conn_end.client_id();
+    let client_val_ctx = ctx.get_client_validation_context();
+    let client_state = client_val_ctx.client_state(client_id)?;
+
+    client_state
+        .status(client_val_ctx, client_id)?
+        .verify_is_active()?;
+    client_state.validate_proof_height(query.query_height)?;
+
+    let consensus_state = client_val_ctx.consensus_state(&ClientConsensusStatePath::new(
+        client_id.clone(),
+        query.query_height.revision_number(),
+        query.query_height.revision_height(),
+    ))?;
+    let prefix = conn_end.counterparty().prefix();
+
+    match value {
+        Some(value) => client_state.verify_membership_with_context(
+            client_val_ctx,
+            prefix,
+            proof,
+            consensus_state.root(),
+            query.path.clone(),
+            value,
+        )?,
+        None => client_state.verify_non_membership_with_context(
+            client_val_ctx,
+            prefix,
+            proof,
+            consensus_state.root(),
+            query.path.clone(),
+        )?,
+    }
+
+    Ok(())
+}
+
+/// Resolves the query with the submitted result, which is stored, and
+/// notifies the host of it.
+pub fn submit_query_result_execute<Ctx>(
+    ctx: &mut Ctx,
+    msg: MsgSubmitCrossChainQueryResult,
+) -> Result<(), CrossChainQueryError>
+where
+    Ctx: CrossChainQueryExecutionContext + ExecutionContext,
+{
+    let query = ctx
+        .query(&msg.id)?
+        .ok_or_else(|| CrossChainQueryError::QueryNotFound {
+            query_id: msg.id.clone(),
+        })?;
+
+    let result = CrossChainQueryResult {
+        id: msg.id,
+        result: msg.result,
+        data: msg.data,
+    };
+
+    ctx.delete_query(&query.id)?;
+    ctx.store_query_result(result.clone())?;
+
+    let extras = ctx.on_query_result_execute(&query, &result)?;
+
+    {
+        ctx.log_message(format!(
+            "cross-chain queries: resolved query {} with result {}",
+            result.id, result.result
+        ))?;
+
+        let event = ModuleEvent {
+            kind: EVENT_TYPE_QUERY_RESULT.to_string(),
+            attributes: vec![
+                ("query_id", result.id.as_str()).into(),
+                ("result", result.result).into(),
+                ("relayer", msg.relayer.as_ref()).into(),
+            ],
+        };
+        ctx.emit_ibc_event(IbcEvent::Module(event))?;
+
+        for module_event in extras.events {
+            ctx.emit_ibc_event(IbcEvent::Module(module_event))?;
+        }
+
+        for log_message in extras.log {
+            ctx.log_message(log_message)?;
+        }
+
+        ctx.emit_ibc_event(MessageEvent::Module(MODULE_ID_STR.to_string()).into())?;
+    }
+
+    Ok(())
+}
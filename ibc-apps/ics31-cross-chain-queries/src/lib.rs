@@ -0,0 +1,38 @@
+//! Implementation of the IBC [Cross-Chain
+//! Queries](https://github.com/cosmos/ibc/blob/main/spec/app/ics-031-crosschain-queries/README.md)
+//! (ICS-31) application logic, with which a chain reads the state of a
+//! counterparty chain, as proven by relayers against the light client of the
+//! counterparty.
+//!
+//! A query is submitted on the querying chain with
+//! [`submit_query`](handler::submit_query), which emits an event for the
+//! relayers to fetch the value of its path on the counterparty, with a proof
+//! at the height of the query. The relayers submit the result with
+//! [`submit_query_result`](handler::submit_query_result), whose proof is
+//! verified against the client of the connection of the query before the
+//! result is stored and the host is notified of it.
+#![no_std]
+#![forbid(unsafe_code)]
+#![cfg_attr(not(test), deny(clippy::unwrap_used))]
+#![cfg_attr(not(test), deny(clippy::disallowed_methods, clippy::disallowed_types))]
+#![deny(
+    warnings,
+    trivial_casts,
+    trivial_numeric_casts,
+    unused_import_braces,
+    unused_qualifications,
+    rust_2018_idioms
+)]
+#![allow(clippy::result_large_err)]
+
+#[cfg(any(test, feature = "std"))]
+extern crate std;
+
+pub mod context;
+pub mod error;
+pub mod handler;
+pub mod msgs;
+pub mod query;
+
+/// The module identifier of the cross-chain queries application.
+pub const MODULE_ID_STR: &str = "crosschainqueries";
@@ -0,0 +1,306 @@
+//! Defines the messages with which the queries are submitted on the querying
+//! chain, and with which the relayers submit their results.
+use ibc_core::channel::types::timeout::TimeoutHeight;
+use ibc_core::client::types::proto::v1::Height as RawHeight;
+use ibc_core::client::types::Height;
+use ibc_core::commitment_types::commitment::CommitmentProofBytes;
+use ibc_core::host::types::identifiers::ConnectionId;
+use ibc_core::host::types::path::Path;
+use ibc_core::primitives::prelude::*;
+use ibc_core::primitives::proto::{Any, Protobuf};
+use ibc_core::primitives::{Signer, Timestamp};
+
+use crate::error::CrossChainQueryError;
+use crate::query::{QueryId, QueryResult};
+
+pub(crate) const SUBMIT_QUERY_TYPE_URL: &str =
+    "/ibc.applications.ibc_query.v1.MsgSubmitCrossChainQuery";
+
+pub(crate) const SUBMIT_QUERY_RESULT_TYPE_URL: &str =
+    "/ibc.applications.ibc_query.v1.MsgSubmitCrossChainQueryResult";
+
+macro_rules! impl_name {
+    ($ty:ty, $name:literal) => {
+        impl prost::Name for $ty {
+            const NAME: &'static str = $name;
+            const PACKAGE: &'static str = "ibc.applications.ibc_query.v1";
+
+            fn full_name() -> String {
+                format!("{}.{}", Self::PACKAGE, Self::NAME)
+            }
+        }
+    };
+}
+
+/// ```protobuf
+/// message MsgSubmitCrossChainQuery {
+///   string path = 1;
+///   ibc.core.client.v1.Height local_timeout_height = 2;
+///   uint64 local_timeout_timestamp = 3;
+///   ibc.core.client.v1.Height query_height = 4;
+///   string connection_id = 5;
+///   string sender = 6;
+/// }
+/// ```
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct RawMsgSubmitCrossChainQuery {
+    #[prost(string, tag = "1")]
+    pub path: String,
+    #[prost(message, optional, tag = "2")]
+    pub local_timeout_height: Option<RawHeight>,
+    #[prost(uint64, tag = "3")]
+    pub local_timeout_timestamp: u64,
+    #[prost(message, optional, tag = "4")]
+    pub query_height: Option<RawHeight>,
+    #[prost(string, tag = "5")]
+    pub connection_id: String,
+    #[prost(string, tag = "6")]
+    pub sender: String,
+}
+
+impl_name!(RawMsgSubmitCrossChainQuery, "MsgSubmitCrossChainQuery");
+
+/// ```protobuf
+/// message MsgSubmitCrossChainQueryResult {
+///   string id = 1;
+///   QueryResult result = 2;
+///   bytes data = 3;
+///   bytes proof = 4;
+///   string relayer = 5;
+/// }
+/// ```
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct RawMsgSubmitCrossChainQueryResult {
+    #[prost(string, tag = "1")]
+    pub id: String,
+    #[prost(int32, tag = "2")]
+    pub result: i32,
+    #[prost(bytes = "vec", tag = "3")]
+    pub data: Vec<u8>,
+    #[prost(bytes = "vec", tag = "4")]
+    pub proof: Vec<u8>,
+    #[prost(string, tag = "5")]
+    pub relayer: String,
+}
+
+impl_name!(
+    RawMsgSubmitCrossChainQueryResult,
+    "MsgSubmitCrossChainQueryResult"
+);
+
+fn decode_error(reason: impl ToString) -> CrossChainQueryError {
+    CrossChainQueryError::DecodeRawMsg {
+        reason: reason.to_string(),
+    }
+}
+
+/// Message with which a query of the given path of the counterparty of a
+/// connection is submitted on the querying chain.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MsgSubmitCrossChainQuery {
+    pub path: Path,
+    pub local_timeout_height: TimeoutHeight,
+    pub local_timeout_timestamp: Timestamp,
+    pub query_height: Height,
+    pub connection_id: ConnectionId,
+    pub sender: Signer,
+}
+
+impl TryFrom<RawMsgSubmitCrossChainQuery> for MsgSubmitCrossChainQuery {
+    type Error = CrossChainQueryError;
+
+    fn try_from(raw_msg: RawMsgSubmitCrossChainQuery) -> Result<Self, Self::Error> {
+        let path = raw_msg
+            .path
+            .parse()
+            .map_err(|_| CrossChainQueryError::InvalidPath {
+                path: raw_msg.path.clone(),
+            })?;
+
+        Ok(Self {
+            path,
+            local_timeout_height: raw_msg.local_timeout_height.try_into()?,
+            local_timeout_timestamp: Timestamp::from_nanoseconds(raw_msg.local_timeout_timestamp)
+                .map_err(decode_error)?,
+            query_height: raw_msg
+                .query_height
+                .ok_or_else(|| decode_error("missing query height"))?
+                .try_into()?,
+            connection_id: raw_msg.connection_id.parse()?,
+            sender: raw_msg.sender.into(),
+        })
+    }
+}
+
+impl From<MsgSubmitCrossChainQuery> for RawMsgSubmitCrossChainQuery {
+    fn from(domain_msg: MsgSubmitCrossChainQuery) -> Self {
+        Self {
+            path: domain_msg.path.to_string(),
+            local_timeout_height: domain_msg.local_timeout_height.into(),
+            local_timeout_timestamp: domain_msg.local_timeout_timestamp.nanoseconds(),
+            query_height: Some(domain_msg.query_height.into()),
+            connection_id: domain_msg.connection_id.to_string(),
+            sender: domain_msg.sender.to_string(),
+        }
+    }
+}
+
+impl Protobuf<RawMsgSubmitCrossChainQuery> for MsgSubmitCrossChainQuery {}
+
+impl TryFrom<Any> for MsgSubmitCrossChainQuery {
+    type Error = CrossChainQueryError;
+
+    fn try_from(raw: Any) -> Result<Self, Self::Error> {
+        match raw.type_url.as_str() {
+            SUBMIT_QUERY_TYPE_URL => {
+                MsgSubmitCrossChainQuery::decode_vec(&raw.value).map_err(decode_error)
+            }
+            _ => Err(CrossChainQueryError::UnknownMsgType {
+                msg_type: raw.type_url,
+            }),
+        }
+    }
+}
+
+/// Message with which a relayer submits the result of a query, proven at the
+/// height of the query unless it timed out.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MsgSubmitCrossChainQueryResult {
+    pub id: QueryId,
+    pub result: QueryResult,
+    /// The value of the path, empty unless the query succeeded.
+    pub data: Vec<u8>,
+    /// The proof of the result, absent if the query timed out.
+    pub proof: Option<CommitmentProofBytes>,
+    pub relayer: Signer,
+}
+
+impl TryFrom<RawMsgSubmitCrossChainQueryResult> for MsgSubmitCrossChainQueryResult {
+    type Error = CrossChainQueryError;
+
+    fn try_from(raw_msg: RawMsgSubmitCrossChainQueryResult) -> Result<Self, Self::Error> {
+        let proof = if raw_msg.proof.is_empty() {
+            None
+        } else {
+            Some(raw_msg.proof.try_into().map_err(decode_error)?)
+        };
+
+        Ok(Self {
+            id: raw_msg.id.parse()?,
+            result: QueryResult::from_i32(raw_msg.result)?,
+            data: raw_msg.data,
+            proof,
+            relayer: raw_msg.relayer.into(),
+        })
+    }
+}
+
+impl From<MsgSubmitCrossChainQueryResult> for RawMsgSubmitCrossChainQueryResult {
+    fn from(domain_msg: MsgSubmitCrossChainQueryResult) -> Self {
+        Self {
+            id: domain_msg.id.to_string(),
+            result: domain_msg.result as i32,
+            data: domain_msg.data,
+            proof: domain_msg
+                .proof
+                .map(CommitmentProofBytes::into_vec)
+                .unwrap_or_default(),
+            relayer: domain_msg.relayer.to_string(),
+        }
+    }
+}
+
+impl Protobuf<RawMsgSubmitCrossChainQueryResult> for MsgSubmitCrossChainQueryResult {}
+
+impl TryFrom<Any> for MsgSubmitCrossChainQueryResult {
+    type Error = CrossChainQueryError;
+
+    fn try_from(raw: Any) -> Result<Self, Self::Error> {
+        match raw.type_url.as_str() {
+            SUBMIT_QUERY_RESULT_TYPE_URL => {
+                MsgSubmitCrossChainQueryResult::decode_vec(&raw.value).map_err(decode_error)
+            }
+            _ => Err(CrossChainQueryError::UnknownMsgType {
+                msg_type: raw.type_url,
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn dummy_raw_query() -> RawMsgSubmitCrossChainQuery {
+        RawMsgSubmitCrossChainQuery {
+            path: "channelEnds/ports/transfer/channels/channel-0".to_string(),
+            local_timeout_height: Some(RawHeight {
+                revision_number: 0,
+                revision_height: 100,
+            }),
+            local_timeout_timestamp: 0,
+            query_height: Some(RawHeight {
+                revision_number: 0,
+                revision_height: 10,
+            }),
+            connection_id: "connection-0".to_string(),
+            sender: "sender".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_msgs_roundtrip() {
+        let msg = MsgSubmitCrossChainQuery::try_from(dummy_raw_query()).unwrap();
+        assert_eq!(
+            msg.local_timeout_height,
+            TimeoutHeight::At(Height::new(0, 100).unwrap())
+        );
+        assert!(!msg.local_timeout_timestamp.is_set());
+
+        let any = Any {
+            type_url: SUBMIT_QUERY_TYPE_URL.to_string(),
+            value: msg.clone().encode_vec(),
+        };
+        assert_eq!(MsgSubmitCrossChainQuery::try_from(any).unwrap(), msg);
+
+        let msg = MsgSubmitCrossChainQueryResult {
+            id: QueryId::new(0),
+            result: QueryResult::Success,
+            data: vec![1, 2, 3],
+            proof: Some(vec![4, 5, 6].try_into().unwrap()),
+            relayer: "relayer".to_string().into(),
+        };
+        let any = Any {
+            type_url: SUBMIT_QUERY_RESULT_TYPE_URL.to_string(),
+            value: msg.clone().encode_vec(),
+        };
+        assert_eq!(MsgSubmitCrossChainQueryResult::try_from(any).unwrap(), msg);
+    }
+
+    #[test]
+    fn test_invalid_raw_msgs() {
+        let raw = RawMsgSubmitCrossChainQuery {
+            path: "balances/cosmos1".to_string(),
+            ..dummy_raw_query()
+        };
+        assert!(matches!(
+            MsgSubmitCrossChainQuery::try_from(raw),
+            Err(CrossChainQueryError::InvalidPath { .. })
+        ));
+
+        let raw = RawMsgSubmitCrossChainQuery {
+            query_height: None,
+            ..dummy_raw_query()
+        };
+        assert!(MsgSubmitCrossChainQuery::try_from(raw).is_err());
+
+        let raw = RawMsgSubmitCrossChainQueryResult {
+            id: "query-0".to_string(),
+            result: 0,
+            data: Vec::new(),
+            proof: Vec::new(),
+            relayer: "relayer".to_string(),
+        };
+        assert!(MsgSubmitCrossChainQueryResult::try_from(raw).is_err());
+    }
+}
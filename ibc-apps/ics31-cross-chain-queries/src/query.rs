@@ -0,0 +1,178 @@
+//! Defines the cross-chain queries pending on the querying chain, and their
+//! results.
+use core::fmt::{Display, Error as FmtError, Formatter};
+use core::str::FromStr;
+
+use ibc_core::channel::types::timeout::TimeoutHeight;
+use ibc_core::client::types::Height;
+use ibc_core::host::types::identifiers::ConnectionId;
+use ibc_core::host::types::path::Path;
+use ibc_core::primitives::prelude::*;
+use ibc_core::primitives::{Expiry, Signer, Timestamp};
+
+use crate::error::CrossChainQueryError;
+
+/// The prefix of the identifiers of the cross-chain queries.
+const QUERY_ID_PREFIX: &str = "query";
+
+/// The identifier of a cross-chain query, `query-{sequence}`.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct QueryId(String);
+
+impl QueryId {
+    /// Returns the identifier of the query of the given sequence.
+    pub fn new(sequence: u64) -> Self {
+        Self(format!("{QUERY_ID_PREFIX}-{sequence}"))
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Display for QueryId {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), FmtError> {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl FromStr for QueryId {
+    type Err = CrossChainQueryError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let sequence = s
+            .strip_prefix(QUERY_ID_PREFIX)
+            .and_then(|s| s.strip_prefix('-'))
+            .and_then(|sequence| sequence.parse::<u64>().ok())
+            .ok_or_else(|| CrossChainQueryError::InvalidQueryId {
+                query_id: s.to_string(),
+            })?;
+
+        Ok(Self::new(sequence))
+    }
+}
+
+/// The outcome of a cross-chain query.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum QueryResult {
+    /// The path has a value on the counterparty, proven by a membership
+    /// proof.
+    Success = 1,
+    /// The path has no value on the counterparty, proven by a non-membership
+    /// proof.
+    Failure = 2,
+    /// The local timeout of the query elapsed before its result was
+    /// submitted.
+    Timeout = 3,
+}
+
+impl QueryResult {
+    pub fn from_i32(result: i32) -> Result<Self, CrossChainQueryError> {
+        match result {
+            1 => Ok(Self::Success),
+            2 => Ok(Self::Failure),
+            3 => Ok(Self::Timeout),
+            _ => Err(CrossChainQueryError::DecodeRawMsg {
+                reason: format!("unknown query result {result}"),
+            }),
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Success => "success",
+            Self::Failure => "failure",
+            Self::Timeout => "timeout",
+        }
+    }
+}
+
+impl Display for QueryResult {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), FmtError> {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// A cross-chain query pending on the querying chain, until its result is
+/// submitted.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CrossChainQuery {
+    pub id: QueryId,
+    /// The path queried on the counterparty, under the commitment prefix of
+    /// the connection of the query.
+    pub path: Path,
+    /// The height of the querying chain after which the query times out.
+    pub local_timeout_height: TimeoutHeight,
+    /// The timestamp of the querying chain after which the query times out.
+    pub local_timeout_timestamp: Timestamp,
+    /// The height of the counterparty at which the path is queried.
+    pub query_height: Height,
+    /// The connection with the counterparty, whose client verifies the
+    /// result of the query.
+    pub connection_id: ConnectionId,
+    pub sender: Signer,
+}
+
+impl CrossChainQuery {
+    /// Returns `true` if the query timed out at the given height and
+    /// timestamp of the querying chain.
+    pub fn has_expired(&self, host_height: Height, host_timestamp: &Timestamp) -> bool {
+        self.local_timeout_height.has_expired(host_height)
+            || (self.local_timeout_timestamp.is_set()
+                && host_timestamp.check_expiry(&self.local_timeout_timestamp) == Expiry::Expired)
+    }
+}
+
+/// The result of a cross-chain query, stored once submitted.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CrossChainQueryResult {
+    pub id: QueryId,
+    pub result: QueryResult,
+    /// The value of the path on the counterparty, empty unless the query
+    /// succeeded.
+    pub data: Vec<u8>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn dummy_query(
+        local_timeout_height: TimeoutHeight,
+        local_timeout_timestamp: u64,
+    ) -> CrossChainQuery {
+        CrossChainQuery {
+            id: QueryId::new(0),
+            path: "connections/connection-0".parse().unwrap(),
+            local_timeout_height,
+            local_timeout_timestamp: Timestamp::from_nanoseconds(local_timeout_timestamp).unwrap(),
+            query_height: Height::new(0, 10).unwrap(),
+            connection_id: ConnectionId::zero(),
+            sender: "sender".to_string().into(),
+        }
+    }
+
+    #[test]
+    fn test_query_id() {
+        assert_eq!(QueryId::new(7).as_str(), "query-7");
+        assert_eq!("query-7".parse::<QueryId>().unwrap(), QueryId::new(7));
+
+        for invalid in ["query-", "query7", "query-x", "channel-7"] {
+            assert!(invalid.parse::<QueryId>().is_err());
+        }
+    }
+
+    #[test]
+    fn test_query_expiry() {
+        let height = Height::new(0, 100).unwrap();
+        let timestamp = Timestamp::from_nanoseconds(1_000).unwrap();
+
+        let query = dummy_query(TimeoutHeight::At(height), 0);
+        assert!(!query.has_expired(height, &timestamp));
+        assert!(query.has_expired(height.increment(), &timestamp));
+
+        let query = dummy_query(TimeoutHeight::Never, 1_000);
+        assert!(!query.has_expired(height, &timestamp));
+        assert!(query.has_expired(height, &Timestamp::from_nanoseconds(1_001).unwrap()));
+    }
+}
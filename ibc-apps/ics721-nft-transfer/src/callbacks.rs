@@ -0,0 +1,345 @@
+//! Implements the callbacks of
+//! [ADR-8](https://github.com/cosmos/ibc-go/blob/main/docs/architecture/adr-008-app-caller-cbs.md)
+//! for the NFT transfers, with which the memo of a transfer asks for contracts
+//! to be called once its NFTs are received, e.g. to list them on a
+//! marketplace, or once it is acknowledged or timed out on the sender chain.
+//!
+//! The callbacks are handlers of the [`MemoRouter`], registered with
+//! [`add_callback_handlers`], which pass the callbacks of the memos to the
+//! [`CallbackExecutor`] of the host.
+
+use ibc_core::channel::types::acknowledgement::AcknowledgementStatus;
+use ibc_core::channel::types::packet::Packet;
+use ibc_core::primitives::prelude::*;
+use ibc_core::router::types::module::ModuleExtras;
+
+use crate::memo::{memo_value, MemoHandler, MemoRouter};
+use crate::types::error::NftTransferError;
+use crate::types::packet::PacketData;
+use crate::types::Memo;
+
+/// The memo key of the callback called on the sender chain once the packet
+/// is acknowledged or timed out.
+pub const SRC_CALLBACK_MEMO_KEY: &str = "src_callback";
+
+/// The memo key of the callback called on the receiver chain once the NFTs
+/// are received.
+pub const DEST_CALLBACK_MEMO_KEY: &str = "dest_callback";
+
+/// A callback of a memo, e.g.
+/// `{"dest_callback":{"address":"cosmos1contract","gas_limit":"200000"}}`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CallbackData {
+    /// The address of the contract called.
+    pub address: String,
+    /// The gas the call may use, capped by the host, or the host limit if
+    /// unset.
+    pub gas_limit: Option<u64>,
+}
+
+impl CallbackData {
+    /// Returns the callback of the given key of the memo, if any.
+    pub fn from_memo(memo: &Memo, key: &str) -> Result<Option<Self>, NftTransferError> {
+        #[derive(serde::Deserialize)]
+        struct RawCallbackData {
+            address: String,
+            #[serde(default)]
+            gas_limit: Option<String>,
+        }
+
+        let Some(raw) = memo_value::<RawCallbackData>(memo, key)? else {
+            return Ok(None);
+        };
+
+        let invalid_memo = || NftTransferError::InvalidMemo {
+            key: key.to_string(),
+        };
+
+        if raw.address.trim().is_empty() {
+            return Err(invalid_memo());
+        }
+
+        let gas_limit = raw
+            .gas_limit
+            .map(|gas_limit| gas_limit.parse::<u64>().map_err(|_| invalid_memo()))
+            .transpose()?;
+
+        Ok(Some(Self {
+            address: raw.address,
+            gas_limit,
+        }))
+    }
+}
+
+/// How the sent packet whose source callback is called ended.
+#[derive(Clone, Copy, Debug)]
+pub enum PacketOutcome<'a> {
+    Acknowledged(&'a AcknowledgementStatus),
+    TimedOut,
+}
+
+/// Calls the contracts of the callbacks of the NFT transfers on the host.
+pub trait CallbackExecutor<Ctx> {
+    /// Calls the contract of the destination callback once the NFTs of the
+    /// given packet are received.
+    ///
+    /// On error, the host should acknowledge the packet with an error, so
+    /// that the NFTs are refunded.
+    fn dest_callback_execute(
+        &self,
+        ctx: &mut Ctx,
+        packet: &Packet,
+        data: &PacketData,
+        callback: &CallbackData,
+    ) -> Result<ModuleExtras, NftTransferError>;
+
+    /// Calls the contract of the source callback once the given sent packet
+    /// is acknowledged or timed out, and its NFTs refunded on failure.
+    fn src_callback_execute(
+        &self,
+        _ctx: &mut Ctx,
+        _packet: &Packet,
+        _data: &PacketData,
+        _callback: &CallbackData,
+        _outcome: PacketOutcome<'_>,
+    ) -> Result<ModuleExtras, NftTransferError> {
+        Ok(ModuleExtras::empty())
+    }
+}
+
+/// The memo handler of the destination callbacks.
+#[derive(Clone, Debug)]
+pub struct DestCallbackHandler<E>(pub E);
+
+impl<Ctx, E: CallbackExecutor<Ctx>> MemoHandler<Ctx> for DestCallbackHandler<E> {
+    fn on_recv_packet_execute(
+        &self,
+        ctx: &mut Ctx,
+        packet: &Packet,
+        data: &PacketData,
+    ) -> Result<ModuleExtras, NftTransferError> {
+        match memo_callback(data, DEST_CALLBACK_MEMO_KEY)? {
+            Some(callback) => self.0.dest_callback_execute(ctx, packet, data, &callback),
+            None => Ok(ModuleExtras::empty()),
+        }
+    }
+}
+
+/// The memo handler of the source callbacks.
+#[derive(Clone, Debug)]
+pub struct SrcCallbackHandler<E>(pub E);
+
+impl<E> SrcCallbackHandler<E> {
+    fn execute<Ctx>(
+        &self,
+        ctx: &mut Ctx,
+        packet: &Packet,
+        data: &PacketData,
+        outcome: PacketOutcome<'_>,
+    ) -> Result<ModuleExtras, NftTransferError>
+    where
+        E: CallbackExecutor<Ctx>,
+    {
+        match memo_callback(data, SRC_CALLBACK_MEMO_KEY)? {
+            Some(callback) => self
+                .0
+                .src_callback_execute(ctx, packet, data, &callback, outcome),
+            None => Ok(ModuleExtras::empty()),
+        }
+    }
+}
+
+impl<Ctx, E: CallbackExecutor<Ctx>> MemoHandler<Ctx> for SrcCallbackHandler<E> {
+    fn on_recv_packet_execute(
+        &self,
+        _ctx: &mut Ctx,
+        _packet: &Packet,
+        _data: &PacketData,
+    ) -> Result<ModuleExtras, NftTransferError> {
+        Ok(ModuleExtras::empty())
+    }
+
+    fn on_acknowledgement_packet_execute(
+        &self,
+        ctx: &mut Ctx,
+        packet: &Packet,
+        data: &PacketData,
+        acknowledgement: &AcknowledgementStatus,
+    ) -> Result<ModuleExtras, NftTransferError> {
+        self.execute(
+            ctx,
+            packet,
+            data,
+            PacketOutcome::Acknowledged(acknowledgement),
+        )
+    }
+
+    fn on_timeout_packet_execute(
+        &self,
+        ctx: &mut Ctx,
+        packet: &Packet,
+        data: &PacketData,
+    ) -> Result<ModuleExtras, NftTransferError> {
+        self.execute(ctx, packet, data, PacketOutcome::TimedOut)
+    }
+}
+
+/// Registers the handlers of the source and destination callbacks, which
+/// both pass the callbacks to the given executor.
+pub fn add_callback_handlers<Ctx, E>(
+    router: &mut MemoRouter<Ctx>,
+    executor: E,
+) -> Result<(), NftTransferError>
+where
+    E: CallbackExecutor<Ctx> + Clone + 'static,
+{
+    router.add_handler(SRC_CALLBACK_MEMO_KEY, SrcCallbackHandler(executor.clone()))?;
+    router.add_handler(DEST_CALLBACK_MEMO_KEY, DestCallbackHandler(executor))
+}
+
+fn memo_callback(data: &PacketData, key: &str) -> Result<Option<CallbackData>, NftTransferError> {
+    match &data.memo {
+        Some(memo) => CallbackData::from_memo(memo, key),
+        None => Ok(None),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ibc_core::channel::types::timeout::TimeoutHeight;
+    use ibc_core::host::types::identifiers::{ChannelId, PortId};
+    use ibc_core::primitives::Timestamp;
+
+    use super::*;
+    use crate::types::{ack_success_b64, TokenIds};
+
+    const MEMO: &str = r#"{"src_callback":{"address":"cosmos1source"},"dest_callback":{"address":"cosmos1market","gas_limit":"200000"}}"#;
+
+    /// Records the contracts it called.
+    #[derive(Clone)]
+    struct RecordingExecutor;
+
+    impl CallbackExecutor<Vec<String>> for RecordingExecutor {
+        fn dest_callback_execute(
+            &self,
+            ctx: &mut Vec<String>,
+            _packet: &Packet,
+            _data: &PacketData,
+            callback: &CallbackData,
+        ) -> Result<ModuleExtras, NftTransferError> {
+            ctx.push(format!("dest {}", callback.address));
+
+            Ok(ModuleExtras::empty())
+        }
+
+        fn src_callback_execute(
+            &self,
+            ctx: &mut Vec<String>,
+            _packet: &Packet,
+            _data: &PacketData,
+            callback: &CallbackData,
+            outcome: PacketOutcome<'_>,
+        ) -> Result<ModuleExtras, NftTransferError> {
+            let outcome = match outcome {
+                PacketOutcome::Acknowledged(_) => "acknowledged",
+                PacketOutcome::TimedOut => "timed out",
+            };
+            ctx.push(format!("src {} {outcome}", callback.address));
+
+            Ok(ModuleExtras::empty())
+        }
+    }
+
+    fn dummy_packet_data(memo: &str) -> PacketData {
+        PacketData::new(
+            "class_0".parse().unwrap(),
+            None,
+            None,
+            TokenIds::try_from(vec!["token_0".to_string()]).unwrap(),
+            vec![],
+            vec![],
+            "sender".to_string().into(),
+            "receiver".to_string().into(),
+            memo.into(),
+        )
+        .unwrap()
+    }
+
+    fn dummy_packet() -> Packet {
+        Packet {
+            seq_on_a: 1.into(),
+            port_id_on_a: PortId::transfer(),
+            chan_id_on_a: ChannelId::zero(),
+            port_id_on_b: PortId::transfer(),
+            chan_id_on_b: ChannelId::new(1),
+            data: Vec::new(),
+            timeout_height_on_b: TimeoutHeight::Never,
+            timeout_timestamp_on_b: Timestamp::none(),
+        }
+    }
+
+    #[test]
+    fn test_callback_data_from_memo() {
+        assert_eq!(
+            CallbackData::from_memo(&MEMO.into(), DEST_CALLBACK_MEMO_KEY).unwrap(),
+            Some(CallbackData {
+                address: "cosmos1market".to_string(),
+                gas_limit: Some(200_000),
+            })
+        );
+        assert_eq!(
+            CallbackData::from_memo(&"a plain memo".into(), DEST_CALLBACK_MEMO_KEY).unwrap(),
+            None
+        );
+
+        for memo in [
+            r#"{"dest_callback":{"address":""}}"#,
+            r#"{"dest_callback":{"address":"cosmos1market","gas_limit":"a lot"}}"#,
+            r#"{"dest_callback":"cosmos1market"}"#,
+        ] {
+            assert!(CallbackData::from_memo(&memo.into(), DEST_CALLBACK_MEMO_KEY).is_err());
+        }
+    }
+
+    #[test]
+    fn test_callbacks_dispatch() {
+        let mut router = MemoRouter::new();
+        add_callback_handlers(&mut router, RecordingExecutor).unwrap();
+
+        assert!(router.has_handler(SRC_CALLBACK_MEMO_KEY));
+        assert!(router.has_handler(DEST_CALLBACK_MEMO_KEY));
+
+        let mut ctx = Vec::new();
+        let packet = dummy_packet();
+        let data = dummy_packet_data(MEMO);
+
+        router
+            .on_recv_packet_execute(&mut ctx, &packet, &data)
+            .unwrap();
+        router
+            .on_acknowledgement_packet_execute(
+                &mut ctx,
+                &packet,
+                &data,
+                &AcknowledgementStatus::success(ack_success_b64()),
+            )
+            .unwrap();
+        router
+            .on_timeout_packet_execute(&mut ctx, &packet, &data)
+            .unwrap();
+
+        // The transfers without callbacks call none.
+        router
+            .on_recv_packet_execute(&mut ctx, &packet, &dummy_packet_data(""))
+            .unwrap();
+
+        assert_eq!(
+            ctx,
+            [
+                "dest cosmos1market",
+                "src cosmos1source acknowledged",
+                "src cosmos1source timed out",
+            ]
+        );
+    }
+}
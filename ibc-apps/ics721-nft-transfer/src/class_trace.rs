@@ -0,0 +1,30 @@
+//! Resolves the `ibc/{hash}` class IDs of the NFTs received over IBC to their
+//! traces, so that hosts can display the original class IDs.
+use core::str::FromStr;
+
+use crate::context::NftTransferValidationContext;
+use crate::types::error::NftTransferError;
+use crate::types::{ClassTraceHash, PrefixedClassId, IBC_CLASS_ID_PREFIX};
+
+/// Returns the stored class ID whose trace has the given hash.
+pub fn class_trace(
+    ctx: &impl NftTransferValidationContext,
+    hash: &ClassTraceHash,
+) -> Result<PrefixedClassId, NftTransferError> {
+    ctx.class_trace(hash)?
+        .ok_or(NftTransferError::ClassTraceNotFound { hash: *hash })
+}
+
+/// Resolves the given class ID, held on the host as `ibc/{hash}` if it was
+/// received over IBC, and as the base class ID otherwise, to its trace.
+pub fn resolve_class_id(
+    ctx: &impl NftTransferValidationContext,
+    class_id: &str,
+) -> Result<PrefixedClassId, NftTransferError> {
+    match class_id.split_once('/') {
+        Some((IBC_CLASS_ID_PREFIX, _)) => {
+            class_trace(ctx, &ClassTraceHash::from_ibc_class_id(class_id)?)
+        }
+        _ => PrefixedClassId::from_str(class_id),
+    }
+}
@@ -6,7 +6,8 @@ use ibc_core::primitives::Signer;
 
 use crate::types::error::NftTransferError;
 use crate::types::{
-    ClassData, ClassId, ClassUri, Memo, PrefixedClassId, TokenData, TokenId, TokenUri,
+    ClassData, ClassId, ClassTraceHash, ClassUri, Memo, PrefixedClassId, TokenData, TokenId,
+    TokenUri,
 };
 
 pub trait NftContext {
@@ -139,13 +140,28 @@ pub trait NftTransferValidationContext {
     /// Returns the NFT class
     fn get_nft_class(&self, class_id: &PrefixedClassId)
         -> Result<Self::NftClass, NftTransferError>;
+
+    /// Returns the class ID whose trace has the given hash, if stored.
+    /// Implement only if the host chain stores the class traces.
+    fn class_trace(
+        &self,
+        _hash: &ClassTraceHash,
+    ) -> Result<Option<PrefixedClassId>, NftTransferError> {
+        Ok(None)
+    }
+
+    /// Returns all the stored class traces.
+    /// Implement only if the host chain stores the class traces.
+    fn class_traces(&self) -> Result<Vec<PrefixedClassId>, NftTransferError> {
+        Ok(Vec::new())
+    }
 }
 
 /// Read-write methods required in NFT transfer execution context.
 pub trait NftTransferExecutionContext: NftTransferValidationContext {
     /// Creates a new NFT Class identified by classId. If the class ID already exists, it updates the class metadata.
     fn create_or_update_class_execute(
-        &self,
+        &mut self,
         class_id: &PrefixedClassId,
         class_uri: Option<&ClassUri>,
         class_data: Option<&ClassData>,
@@ -196,4 +212,110 @@ pub trait NftTransferExecutionContext: NftTransferValidationContext {
         token_id: &TokenId,
         memo: &Memo,
     ) -> Result<(), NftTransferError>;
+
+    /// Called before the NFT is escrowed, e.g. to notify a marketplace that
+    /// the NFT leaves the chain or enforce custody rules. Failing aborts the
+    /// escrow.
+    fn before_escrow_nft_execute(
+        &mut self,
+        _from_account: &Self::AccountId,
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+        _class_id: &PrefixedClassId,
+        _token_id: &TokenId,
+        _memo: &Memo,
+    ) -> Result<(), NftTransferError> {
+        Ok(())
+    }
+
+    /// Called after the NFT is escrowed.
+    fn after_escrow_nft_execute(
+        &mut self,
+        _from_account: &Self::AccountId,
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+        _class_id: &PrefixedClassId,
+        _token_id: &TokenId,
+        _memo: &Memo,
+    ) -> Result<(), NftTransferError> {
+        Ok(())
+    }
+
+    /// Called before the NFT is unescrowed. Failing aborts the unescrow.
+    fn before_unescrow_nft_execute(
+        &mut self,
+        _to_account: &Self::AccountId,
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+        _class_id: &PrefixedClassId,
+        _token_id: &TokenId,
+    ) -> Result<(), NftTransferError> {
+        Ok(())
+    }
+
+    /// Called after the NFT is unescrowed.
+    fn after_unescrow_nft_execute(
+        &mut self,
+        _to_account: &Self::AccountId,
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+        _class_id: &PrefixedClassId,
+        _token_id: &TokenId,
+    ) -> Result<(), NftTransferError> {
+        Ok(())
+    }
+
+    /// Called before the NFT is minted, e.g. to check its data against a
+    /// royalty registry. Failing aborts the mint.
+    fn before_mint_nft_execute(
+        &mut self,
+        _account: &Self::AccountId,
+        _class_id: &PrefixedClassId,
+        _token_id: &TokenId,
+        _token_uri: Option<&TokenUri>,
+        _token_data: Option<&TokenData>,
+    ) -> Result<(), NftTransferError> {
+        Ok(())
+    }
+
+    /// Called after the NFT is minted, e.g. to register its royalty.
+    fn after_mint_nft_execute(
+        &mut self,
+        _account: &Self::AccountId,
+        _class_id: &PrefixedClassId,
+        _token_id: &TokenId,
+        _token_uri: Option<&TokenUri>,
+        _token_data: Option<&TokenData>,
+    ) -> Result<(), NftTransferError> {
+        Ok(())
+    }
+
+    /// Called before the NFT is burnt. Failing aborts the burn.
+    fn before_burn_nft_execute(
+        &mut self,
+        _account: &Self::AccountId,
+        _class_id: &PrefixedClassId,
+        _token_id: &TokenId,
+        _memo: &Memo,
+    ) -> Result<(), NftTransferError> {
+        Ok(())
+    }
+
+    /// Called after the NFT is burnt, e.g. to delist it from a marketplace.
+    fn after_burn_nft_execute(
+        &mut self,
+        _account: &Self::AccountId,
+        _class_id: &PrefixedClassId,
+        _token_id: &TokenId,
+        _memo: &Memo,
+    ) -> Result<(), NftTransferError> {
+        Ok(())
+    }
+
+    /// Stores the trace of the given class ID under its
+    /// [`trace_hash`](PrefixedClassId::trace_hash), before its vouchers are
+    /// minted. Implement only if the host chain stores the class traces.
+    fn store_class_trace(&mut self, _class_id: &PrefixedClassId) -> Result<(), NftTransferError> {
+        Ok(())
+    }
 }
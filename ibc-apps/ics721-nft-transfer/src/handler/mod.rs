@@ -1,9 +1,11 @@
 //! Implements IBC handlers responsible for processing Non-Fungible Token
 //! Transfers (ICS-721) messages.
+mod nfts;
 mod on_recv_packet;
 mod send_transfer;
 
 use ibc_core::channel::types::packet::Packet;
+pub use nfts::*;
 pub use on_recv_packet::*;
 pub use send_transfer::*;
 
@@ -29,7 +31,8 @@ pub fn refund_packet_nft_execute(
         &data.class_id,
     ) {
         data.token_ids.as_ref().iter().try_for_each(|token_id| {
-            ctx_a.unescrow_nft_execute(
+            unescrow_nft_with_hooks(
+                ctx_a,
                 &sender,
                 &packet.port_id_on_a,
                 &packet.chan_id_on_a,
@@ -41,9 +44,16 @@ pub fn refund_packet_nft_execute(
     // mint vouchers back to sender
     else {
         for (i, token_id) in data.token_ids.0.iter().enumerate() {
-            let token_uri = data.token_uris.as_ref().and_then(|uris| uris.get(i));
-            let token_data = data.token_data.as_ref().and_then(|data| data.get(i));
-            ctx_a.mint_nft_execute(&sender, &data.class_id, token_id, token_uri, token_data)?;
+            let token_uri = data.token_uri_at(i);
+            let token_data = data.token_data_at(i);
+            mint_nft_with_hooks(
+                ctx_a,
+                &sender,
+                &data.class_id,
+                token_id,
+                token_uri,
+                token_data,
+            )?;
         }
         Ok(())
     }
@@ -76,8 +86,8 @@ pub fn refund_packet_nft_validate(
         })
     } else {
         for (i, token_id) in data.token_ids.0.iter().enumerate() {
-            let token_uri = data.token_uris.as_ref().and_then(|uris| uris.get(i));
-            let token_data = data.token_data.as_ref().and_then(|data| data.get(i));
+            let token_uri = data.token_uri_at(i);
+            let token_data = data.token_data_at(i);
             ctx_a.mint_nft_validate(&sender, &data.class_id, token_id, token_uri, token_data)?;
         }
         Ok(())
@@ -0,0 +1,73 @@
+//! Moves the NFTs of the transfers with the host context, calling its hooks
+//! around each move.
+use ibc_core::host::types::identifiers::{ChannelId, PortId};
+
+use crate::context::NftTransferExecutionContext;
+use crate::types::error::NftTransferError;
+use crate::types::{Memo, PrefixedClassId, TokenData, TokenId, TokenUri};
+
+/// Escrows the NFT in between the
+/// [`before`](NftTransferExecutionContext::before_escrow_nft_execute) and
+/// [`after`](NftTransferExecutionContext::after_escrow_nft_execute) escrow
+/// hooks.
+pub fn escrow_nft_with_hooks<Ctx: NftTransferExecutionContext>(
+    ctx: &mut Ctx,
+    from_account: &Ctx::AccountId,
+    port_id: &PortId,
+    channel_id: &ChannelId,
+    class_id: &PrefixedClassId,
+    token_id: &TokenId,
+    memo: &Memo,
+) -> Result<(), NftTransferError> {
+    ctx.before_escrow_nft_execute(from_account, port_id, channel_id, class_id, token_id, memo)?;
+    ctx.escrow_nft_execute(from_account, port_id, channel_id, class_id, token_id, memo)?;
+    ctx.after_escrow_nft_execute(from_account, port_id, channel_id, class_id, token_id, memo)
+}
+
+/// Unescrows the NFT in between the
+/// [`before`](NftTransferExecutionContext::before_unescrow_nft_execute) and
+/// [`after`](NftTransferExecutionContext::after_unescrow_nft_execute)
+/// unescrow hooks.
+pub fn unescrow_nft_with_hooks<Ctx: NftTransferExecutionContext>(
+    ctx: &mut Ctx,
+    to_account: &Ctx::AccountId,
+    port_id: &PortId,
+    channel_id: &ChannelId,
+    class_id: &PrefixedClassId,
+    token_id: &TokenId,
+) -> Result<(), NftTransferError> {
+    ctx.before_unescrow_nft_execute(to_account, port_id, channel_id, class_id, token_id)?;
+    ctx.unescrow_nft_execute(to_account, port_id, channel_id, class_id, token_id)?;
+    ctx.after_unescrow_nft_execute(to_account, port_id, channel_id, class_id, token_id)
+}
+
+/// Mints the NFT in between the
+/// [`before`](NftTransferExecutionContext::before_mint_nft_execute) and
+/// [`after`](NftTransferExecutionContext::after_mint_nft_execute) mint hooks.
+pub fn mint_nft_with_hooks<Ctx: NftTransferExecutionContext>(
+    ctx: &mut Ctx,
+    account: &Ctx::AccountId,
+    class_id: &PrefixedClassId,
+    token_id: &TokenId,
+    token_uri: Option<&TokenUri>,
+    token_data: Option<&TokenData>,
+) -> Result<(), NftTransferError> {
+    ctx.before_mint_nft_execute(account, class_id, token_id, token_uri, token_data)?;
+    ctx.mint_nft_execute(account, class_id, token_id, token_uri, token_data)?;
+    ctx.after_mint_nft_execute(account, class_id, token_id, token_uri, token_data)
+}
+
+/// Burns the NFT in between the
+/// [`before`](NftTransferExecutionContext::before_burn_nft_execute) and
+/// [`after`](NftTransferExecutionContext::after_burn_nft_execute) burn hooks.
+pub fn burn_nft_with_hooks<Ctx: NftTransferExecutionContext>(
+    ctx: &mut Ctx,
+    account: &Ctx::AccountId,
+    class_id: &PrefixedClassId,
+    token_id: &TokenId,
+    memo: &Memo,
+) -> Result<(), NftTransferError> {
+    ctx.before_burn_nft_execute(account, class_id, token_id, memo)?;
+    ctx.burn_nft_execute(account, class_id, token_id, memo)?;
+    ctx.after_burn_nft_execute(account, class_id, token_id, memo)
+}
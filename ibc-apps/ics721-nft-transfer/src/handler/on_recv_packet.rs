@@ -3,6 +3,7 @@ use ibc_core::primitives::prelude::*;
 use ibc_core::router::types::module::ModuleExtras;
 
 use crate::context::NftTransferExecutionContext;
+use crate::handler::{mint_nft_with_hooks, unescrow_nft_with_hooks};
 use crate::types::error::NftTransferError;
 use crate::types::events::TokenTraceEvent;
 use crate::types::packet::PacketData;
@@ -45,7 +46,9 @@ where
         };
 
         // Note: the validation is called before the execution.
-        // Refer to ICS-20 `process_recv_packet_execute()`.
+        // Refer to ICS-20 `process_recv_packet_execute()`. All the NFTs are
+        // validated before any of them is unescrowed, so that the packet is
+        // either received as a whole or not at all.
         for token_id in data.token_ids.as_ref() {
             ctx_b
                 .unescrow_nft_validate(
@@ -56,15 +59,18 @@ where
                     token_id,
                 )
                 .map_err(|nft_error| (ModuleExtras::empty(), nft_error))?;
-            ctx_b
-                .unescrow_nft_execute(
-                    &receiver_account,
-                    &packet.port_id_on_b,
-                    &packet.chan_id_on_b,
-                    &class_id,
-                    token_id,
-                )
-                .map_err(|nft_error| (ModuleExtras::empty(), nft_error))?;
+        }
+
+        for token_id in data.token_ids.as_ref() {
+            unescrow_nft_with_hooks(
+                ctx_b,
+                &receiver_account,
+                &packet.port_id_on_b,
+                &packet.chan_id_on_b,
+                &class_id,
+                token_id,
+            )
+            .map_err(|nft_error| (ModuleExtras::empty(), nft_error))?;
         }
 
         ModuleExtras::empty()
@@ -81,34 +87,29 @@ where
             events: vec![],
             log: Vec::new(),
         };
-        for (i, token_id) in data.token_ids.0.iter().enumerate() {
-            let token_uri = data.token_uris.as_ref().and_then(|uris| uris.get(i));
-            let token_data = data.token_data.as_ref().and_then(|data| data.get(i));
-
+        for token_id in data.token_ids.as_ref() {
             let trace_event = TokenTraceEvent {
                 trace_hash: ctx_b.token_hash_string(&class_id, token_id),
                 class: class_id.clone(),
                 token: token_id.clone(),
             };
             extras.events.push(trace_event.into());
+        }
 
-            // Note: the validation is called before the execution.
-            // Refer to ICS-20 `process_recv_packet_execute()`.
+        // Note: the validation is called before the execution.
+        // Refer to ICS-20 `process_recv_packet_execute()`. The class and all
+        // the NFTs are validated before any of them is created.
+        ctx_b
+            .create_or_update_class_validate(
+                &class_id,
+                data.class_uri.as_ref(),
+                data.class_data.as_ref(),
+            )
+            .map_err(|nft_error| (ModuleExtras::empty(), nft_error))?;
 
-            ctx_b
-                .create_or_update_class_validate(
-                    &class_id,
-                    data.class_uri.as_ref(),
-                    data.class_data.as_ref(),
-                )
-                .map_err(|nft_error| (ModuleExtras::empty(), nft_error))?;
-            ctx_b
-                .create_or_update_class_execute(
-                    &class_id,
-                    data.class_uri.as_ref(),
-                    data.class_data.as_ref(),
-                )
-                .map_err(|nft_error| (ModuleExtras::empty(), nft_error))?;
+        for (i, token_id) in data.token_ids.0.iter().enumerate() {
+            let token_uri = data.token_uri_at(i);
+            let token_data = data.token_data_at(i);
 
             ctx_b
                 .mint_nft_validate(
@@ -119,15 +120,33 @@ where
                     token_data,
                 )
                 .map_err(|nft_error| (extras.clone(), nft_error))?;
-            ctx_b
-                .mint_nft_execute(
-                    &receiver_account,
-                    &class_id,
-                    token_id,
-                    token_uri,
-                    token_data,
-                )
-                .map_err(|nft_error| (extras.clone(), nft_error))?;
+        }
+
+        ctx_b
+            .store_class_trace(&class_id)
+            .map_err(|nft_error| (ModuleExtras::empty(), nft_error))?;
+
+        ctx_b
+            .create_or_update_class_execute(
+                &class_id,
+                data.class_uri.as_ref(),
+                data.class_data.as_ref(),
+            )
+            .map_err(|nft_error| (ModuleExtras::empty(), nft_error))?;
+
+        for (i, token_id) in data.token_ids.0.iter().enumerate() {
+            let token_uri = data.token_uri_at(i);
+            let token_data = data.token_data_at(i);
+
+            mint_nft_with_hooks(
+                ctx_b,
+                &receiver_account,
+                &class_id,
+                token_id,
+                token_uri,
+                token_data,
+            )
+            .map_err(|nft_error| (extras.clone(), nft_error))?;
         }
 
         extras
@@ -9,9 +9,11 @@ use ibc_core::router::types::event::ModuleEvent;
 use crate::context::{
     NftClassContext, NftContext, NftTransferExecutionContext, NftTransferValidationContext,
 };
+use crate::handler::{burn_nft_with_hooks, escrow_nft_with_hooks};
 use crate::types::error::NftTransferError;
 use crate::types::events::TransferEvent;
 use crate::types::msgs::transfer::MsgTransfer;
+use crate::types::packet::PacketData;
 use crate::types::{is_sender_chain_source, MODULE_ID_STR};
 
 /// Initiate a token transfer. Equivalent to calling [`send_nft_transfer_validate`], followed by [`send_nft_transfer_execute`].
@@ -63,17 +65,11 @@ where
         .try_into()
         .map_err(|_| NftTransferError::ParseAccountFailure)?;
 
-    let mut packet_data = msg.packet_data;
+    let packet_data = packet_data_with_metadata(transfer_ctx, msg.packet_data)?;
     let class_id = &packet_data.class_id;
-    let token_ids = &packet_data.token_ids;
-    // overwrite even if they are set in MsgTransfer
-    if let Some(uris) = &mut packet_data.token_uris {
-        uris.clear();
-    }
-    if let Some(data) = &mut packet_data.token_data {
-        data.clear();
-    }
-    for token_id in token_ids.as_ref() {
+    let memo = packet_data.memo.clone().unwrap_or("".into());
+
+    for token_id in packet_data.token_ids.as_ref() {
         if is_sender_chain_source(msg.port_id_on_a.clone(), msg.chan_id_on_a.clone(), class_id) {
             transfer_ctx.escrow_nft_validate(
                 &sender,
@@ -81,36 +77,13 @@ where
                 &msg.chan_id_on_a,
                 class_id,
                 token_id,
-                &packet_data.memo.clone().unwrap_or("".into()),
+                &memo,
             )?;
         } else {
-            transfer_ctx.burn_nft_validate(
-                &sender,
-                class_id,
-                token_id,
-                &packet_data.memo.clone().unwrap_or("".into()),
-            )?;
-        }
-        let nft = transfer_ctx.get_nft(class_id, token_id)?;
-        // Set the URI and the data if both exists
-        if let (Some(uri), Some(data)) = (nft.get_uri(), nft.get_data()) {
-            match &mut packet_data.token_uris {
-                Some(uris) => uris.push(uri.clone()),
-                None => packet_data.token_uris = Some(vec![uri.clone()]),
-            }
-            match &mut packet_data.token_data {
-                Some(token_data) => token_data.push(data.clone()),
-                None => packet_data.token_data = Some(vec![data.clone()]),
-            }
+            transfer_ctx.burn_nft_validate(&sender, class_id, token_id, &memo)?;
         }
     }
 
-    packet_data.validate_basic()?;
-
-    let nft_class = transfer_ctx.get_nft_class(class_id)?;
-    packet_data.class_uri = nft_class.get_uri().cloned();
-    packet_data.class_data = nft_class.get_data().cloned();
-
     let packet = {
         let data = serde_json::to_vec(&packet_data)
             .expect("PacketData's infallible Serialize impl failed");
@@ -166,52 +139,28 @@ where
         .try_into()
         .map_err(|_| NftTransferError::ParseAccountFailure)?;
 
-    let mut packet_data = msg.packet_data;
+    // The metadata are read before the NFTs are burnt.
+    let packet_data = packet_data_with_metadata(transfer_ctx, msg.packet_data)?;
     let class_id = &packet_data.class_id;
     let token_ids = &packet_data.token_ids;
-    // overwrite even if they are set in MsgTransfer
-    if let Some(uris) = &mut packet_data.token_uris {
-        uris.clear();
-    }
-    if let Some(data) = &mut packet_data.token_data {
-        data.clear();
-    }
+    let memo = packet_data.memo.clone().unwrap_or("".into());
+
     for token_id in token_ids.as_ref() {
         if is_sender_chain_source(msg.port_id_on_a.clone(), msg.chan_id_on_a.clone(), class_id) {
-            transfer_ctx.escrow_nft_execute(
+            escrow_nft_with_hooks(
+                transfer_ctx,
                 &sender,
                 &msg.port_id_on_a,
                 &msg.chan_id_on_a,
                 class_id,
                 token_id,
-                &packet_data.memo.clone().unwrap_or("".into()),
+                &memo,
             )?;
         } else {
-            transfer_ctx.burn_nft_execute(
-                &sender,
-                class_id,
-                token_id,
-                &packet_data.memo.clone().unwrap_or("".into()),
-            )?;
-        }
-        let nft = transfer_ctx.get_nft(class_id, token_id)?;
-        // Set the URI and the data if both exists
-        if let (Some(uri), Some(data)) = (nft.get_uri(), nft.get_data()) {
-            match &mut packet_data.token_uris {
-                Some(uris) => uris.push(uri.clone()),
-                None => packet_data.token_uris = Some(vec![uri.clone()]),
-            }
-            match &mut packet_data.token_data {
-                Some(token_data) => token_data.push(data.clone()),
-                None => packet_data.token_data = Some(vec![data.clone()]),
-            }
+            burn_nft_with_hooks(transfer_ctx, &sender, class_id, token_id, &memo)?;
         }
     }
 
-    let nft_class = transfer_ctx.get_nft_class(class_id)?;
-    packet_data.class_uri = nft_class.get_uri().cloned();
-    packet_data.class_data = nft_class.get_data().cloned();
-
     let packet = {
         let data = {
             serde_json::to_vec(&packet_data).expect("PacketData's infallible Serialize impl failed")
@@ -251,3 +200,42 @@ where
 
     Ok(())
 }
+
+/// Returns the given packet data carrying the metadata of the class and of the
+/// NFTs as stored on the host, which overwrite the ones of the message.
+///
+/// The URIs of the NFTs are carried only if all the NFTs have one, so that
+/// they are aligned with the token IDs. The data of the NFTs are carried if
+/// any NFT has one, the NFTs without data carrying an empty one, so that no
+/// data is lost along the hops.
+fn packet_data_with_metadata<TransferCtx>(
+    transfer_ctx: &TransferCtx,
+    mut packet_data: PacketData,
+) -> Result<PacketData, NftTransferError>
+where
+    TransferCtx: NftTransferValidationContext,
+{
+    let mut token_uris = Vec::new();
+    let mut token_data = Vec::new();
+
+    for token_id in packet_data.token_ids.as_ref() {
+        let nft = transfer_ctx.get_nft(&packet_data.class_id, token_id)?;
+        token_uris.extend(nft.get_uri().cloned());
+        token_data.push(nft.get_data().cloned().unwrap_or_default());
+    }
+
+    let num = packet_data.token_ids.0.len();
+    packet_data.token_uris = (token_uris.len() == num).then_some(token_uris);
+    packet_data.token_data = token_data
+        .iter()
+        .any(|data| !data.is_empty())
+        .then_some(token_data);
+
+    let nft_class = transfer_ctx.get_nft_class(&packet_data.class_id)?;
+    packet_data.class_uri = nft_class.get_uri().cloned();
+    packet_data.class_data = nft_class.get_data().cloned();
+
+    packet_data.validate_basic()?;
+
+    Ok(packet_data)
+}
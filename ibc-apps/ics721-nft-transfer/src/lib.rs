@@ -17,11 +17,17 @@
 #[cfg(any(test, feature = "std"))]
 extern crate std;
 
+#[cfg(feature = "serde")]
+pub mod callbacks;
+#[cfg(feature = "serde")]
+pub mod class_trace;
 #[cfg(feature = "serde")]
 pub mod context;
 #[cfg(feature = "serde")]
 pub mod handler;
 #[cfg(feature = "serde")]
+pub mod memo;
+#[cfg(feature = "serde")]
 pub mod module;
 
 /// Re-exports the implementation of the IBC [Non-Fungible Token
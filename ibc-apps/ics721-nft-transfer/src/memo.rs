@@ -0,0 +1,208 @@
+//! Parses the JSON memos of the NFT transfers, and dispatches the values of
+//! their top-level keys to the handlers registered for them, as the
+//! [callbacks](crate::callbacks) expect.
+//!
+//! Memos which are not JSON objects are plain text memos, which carry no
+//! instructions for the handlers, and the keys with no registered handler are
+//! ignored.
+
+use ibc_core::channel::types::acknowledgement::AcknowledgementStatus;
+use ibc_core::channel::types::packet::Packet;
+use ibc_core::primitives::prelude::*;
+use ibc_core::router::types::module::ModuleExtras;
+use serde::de::{DeserializeOwned, IgnoredAny};
+
+use crate::types::error::NftTransferError;
+use crate::types::packet::PacketData;
+use crate::types::Memo;
+
+/// Returns the top-level keys of the given memo, in lexicographic order, or
+/// none if the memo is not a JSON object.
+pub fn memo_keys(memo: &Memo) -> Vec<String> {
+    serde_json::from_str::<BTreeMap<String, IgnoredAny>>(memo.as_ref())
+        .map(|keys| keys.into_keys().collect())
+        .unwrap_or_default()
+}
+
+/// Returns the value of the given top-level key of the memo, if the memo is a
+/// JSON object which has the key.
+pub fn memo_value<T: DeserializeOwned>(
+    memo: &Memo,
+    key: &str,
+) -> Result<Option<T>, NftTransferError> {
+    let Ok(mut entries) = serde_json::from_str::<BTreeMap<String, MemoEntry<T>>>(memo.as_ref())
+    else {
+        return Ok(None);
+    };
+
+    match entries.remove(key) {
+        Some(MemoEntry::Value(value)) => Ok(Some(value)),
+        Some(MemoEntry::Other(_)) => Err(NftTransferError::InvalidMemo {
+            key: key.to_string(),
+        }),
+        None => Ok(None),
+    }
+}
+
+/// The value of a top-level key of a memo, which is deserialized to the
+/// value of the key looked up, unless it is the value of another key.
+#[derive(serde::Deserialize)]
+#[serde(untagged)]
+enum MemoEntry<T> {
+    Value(T),
+    Other(IgnoredAny),
+}
+
+/// Handles the value of a top-level key of the memos of the NFT transfers,
+/// which the handler deserializes with [`memo_value`].
+///
+/// The handlers are called once the NFT transfer application processed the
+/// packet, and only for the packets whose memo has their key.
+pub trait MemoHandler<Ctx> {
+    fn on_recv_packet_execute(
+        &self,
+        ctx: &mut Ctx,
+        packet: &Packet,
+        data: &PacketData,
+    ) -> Result<ModuleExtras, NftTransferError>;
+
+    fn on_acknowledgement_packet_execute(
+        &self,
+        _ctx: &mut Ctx,
+        _packet: &Packet,
+        _data: &PacketData,
+        _acknowledgement: &AcknowledgementStatus,
+    ) -> Result<ModuleExtras, NftTransferError> {
+        Ok(ModuleExtras::empty())
+    }
+
+    fn on_timeout_packet_execute(
+        &self,
+        _ctx: &mut Ctx,
+        _packet: &Packet,
+        _data: &PacketData,
+    ) -> Result<ModuleExtras, NftTransferError> {
+        Ok(ModuleExtras::empty())
+    }
+}
+
+/// Registry of the handlers of the memo keys, which dispatches the memos of
+/// the NFT transfers to the handlers of their keys.
+///
+/// The handlers are called in the lexicographic order of their keys, and the
+/// extras they return are merged.
+pub struct MemoRouter<Ctx> {
+    handlers: BTreeMap<String, Box<dyn MemoHandler<Ctx>>>,
+}
+
+impl<Ctx> Default for MemoRouter<Ctx> {
+    fn default() -> Self {
+        Self {
+            handlers: BTreeMap::new(),
+        }
+    }
+}
+
+impl<Ctx> MemoRouter<Ctx> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers the handler of the given memo key, with a single handler per
+    /// key.
+    pub fn add_handler(
+        &mut self,
+        key: impl Into<String>,
+        handler: impl MemoHandler<Ctx> + 'static,
+    ) -> Result<(), NftTransferError> {
+        let key = key.into();
+
+        if self.handlers.contains_key(&key) {
+            return Err(NftTransferError::DuplicateMemoHandler { key });
+        }
+
+        self.handlers.insert(key, Box::new(handler));
+
+        Ok(())
+    }
+
+    /// Returns `true` if a handler is registered for the given memo key.
+    pub fn has_handler(&self, key: &str) -> bool {
+        self.handlers.contains_key(key)
+    }
+
+    /// Returns the handlers of the keys of the memo of the given packet data,
+    /// if any.
+    fn handlers_of<'a>(
+        &'a self,
+        data: &PacketData,
+    ) -> impl Iterator<Item = &'a dyn MemoHandler<Ctx>> + 'a {
+        data.memo
+            .as_ref()
+            .map(memo_keys)
+            .unwrap_or_default()
+            .into_iter()
+            .filter_map(|key| self.handlers.get(&key))
+            .map(|handler| handler.as_ref())
+    }
+
+    pub fn on_recv_packet_execute(
+        &self,
+        ctx: &mut Ctx,
+        packet: &Packet,
+        data: &PacketData,
+    ) -> Result<ModuleExtras, NftTransferError> {
+        let mut extras = ModuleExtras::empty();
+
+        for handler in self.handlers_of(data) {
+            merge_extras(
+                &mut extras,
+                handler.on_recv_packet_execute(ctx, packet, data)?,
+            );
+        }
+
+        Ok(extras)
+    }
+
+    pub fn on_acknowledgement_packet_execute(
+        &self,
+        ctx: &mut Ctx,
+        packet: &Packet,
+        data: &PacketData,
+        acknowledgement: &AcknowledgementStatus,
+    ) -> Result<ModuleExtras, NftTransferError> {
+        let mut extras = ModuleExtras::empty();
+
+        for handler in self.handlers_of(data) {
+            merge_extras(
+                &mut extras,
+                handler.on_acknowledgement_packet_execute(ctx, packet, data, acknowledgement)?,
+            );
+        }
+
+        Ok(extras)
+    }
+
+    pub fn on_timeout_packet_execute(
+        &self,
+        ctx: &mut Ctx,
+        packet: &Packet,
+        data: &PacketData,
+    ) -> Result<ModuleExtras, NftTransferError> {
+        let mut extras = ModuleExtras::empty();
+
+        for handler in self.handlers_of(data) {
+            merge_extras(
+                &mut extras,
+                handler.on_timeout_packet_execute(ctx, packet, data)?,
+            );
+        }
+
+        Ok(extras)
+    }
+}
+
+fn merge_extras(extras: &mut ModuleExtras, other: ModuleExtras) {
+    extras.events.extend(other.events);
+    extras.log.extend(other.log);
+}
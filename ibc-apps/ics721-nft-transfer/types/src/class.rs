@@ -9,6 +9,8 @@ use ibc_core::primitives::prelude::*;
 #[cfg(feature = "serde")]
 use ibc_core::primitives::serializers;
 use ibc_proto::ibc::applications::nft_transfer::v1::ClassTrace as RawClassTrace;
+use sha2::{Digest, Sha256};
+use subtle_encoding::hex;
 
 use crate::data::Data;
 use crate::error::NftTransferError;
@@ -236,6 +238,76 @@ impl PrefixedClassId {
     pub fn add_trace_prefix(&mut self, prefix: TracePrefix) {
         self.trace_path.add_prefix(prefix)
     }
+
+    /// Returns the hash of the class ID, as `ibc-go` hashes its class traces.
+    pub fn trace_hash(&self) -> ClassTraceHash {
+        ClassTraceHash(Sha256::digest(self.to_string().as_bytes()).into())
+    }
+
+    /// Returns the class ID the NFTs are held with on a chain running
+    /// `ibc-go`, which is `ibc/{hash}` for the NFTs received over IBC, and the
+    /// base class ID for the native ones.
+    pub fn ibc_class_id(&self) -> String {
+        if self.trace_path.is_empty() {
+            self.base_class_id.to_string()
+        } else {
+            format!("{IBC_CLASS_ID_PREFIX}/{}", self.trace_hash())
+        }
+    }
+}
+
+/// The prefix of the hashed class IDs of the NFTs received over IBC.
+pub const IBC_CLASS_ID_PREFIX: &str = "ibc";
+
+/// The SHA-256 hash of the full path of a [`PrefixedClassId`], with which the
+/// class IDs of the NFTs received over IBC are shortened to `ibc/{hash}`.
+///
+/// It is displayed and parsed as upper case hexadecimal, as `ibc-go` does.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ClassTraceHash([u8; 32]);
+
+impl ClassTraceHash {
+    pub fn new(hash: [u8; 32]) -> Self {
+        Self(hash)
+    }
+
+    pub fn as_bytes(&self) -> &[u8; 32] {
+        &self.0
+    }
+
+    /// Parses the hash of the given `ibc/{hash}` class ID.
+    pub fn from_ibc_class_id(class_id: &str) -> Result<Self, NftTransferError> {
+        match class_id.split_once('/') {
+            Some((IBC_CLASS_ID_PREFIX, hash)) => hash.parse(),
+            _ => Err(NftTransferError::InvalidClassTraceHash {
+                hash: class_id.to_string(),
+            }),
+        }
+    }
+}
+
+impl FromStr for ClassTraceHash {
+    type Err = NftTransferError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid_hash = || NftTransferError::InvalidClassTraceHash {
+            hash: s.to_string(),
+        };
+
+        let bytes = hex::decode(s.to_ascii_lowercase()).map_err(|_| invalid_hash())?;
+        let hash = <[u8; 32]>::try_from(bytes).map_err(|_| invalid_hash())?;
+
+        Ok(Self(hash))
+    }
+}
+
+impl Display for ClassTraceHash {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), FmtError> {
+        let hash = hex::encode_upper(self.0);
+        let hash = core::str::from_utf8(&hash).map_err(|_| FmtError)?;
+
+        write!(f, "{hash}")
+    }
 }
 
 /// Returns true if the class ID originally came from the sender chain and false otherwise.
@@ -512,6 +584,36 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_class_trace_hash() -> Result<(), NftTransferError> {
+        let class_id = PrefixedClassId::from_str("nft-transfer/channel-0/class_0")?;
+        let hash = "8B139C4255FE73F3232157E926944923A6EDBEAA5E7A375400FE39E07DE771D2";
+
+        assert_eq!(class_id.trace_hash().to_string(), hash);
+        assert_eq!(class_id.ibc_class_id(), format!("ibc/{hash}"));
+        assert_eq!(ClassTraceHash::from_str(hash)?, class_id.trace_hash());
+        assert_eq!(
+            ClassTraceHash::from_str(&hash.to_lowercase())?,
+            class_id.trace_hash()
+        );
+        assert_eq!(
+            ClassTraceHash::from_ibc_class_id(&format!("ibc/{hash}"))?,
+            class_id.trace_hash()
+        );
+
+        // Native class IDs are not hashed.
+        assert_eq!(
+            PrefixedClassId::from_str("class_0")?.ibc_class_id(),
+            "class_0"
+        );
+
+        assert!(ClassTraceHash::from_str("8B139C42").is_err());
+        assert!(ClassTraceHash::from_ibc_class_id(hash).is_err());
+        assert!(ClassTraceHash::from_ibc_class_id(&format!("nft/{hash}")).is_err());
+
+        Ok(())
+    }
+
     #[test]
     fn test_class_id_serde() -> Result<(), NftTransferError> {
         let dt_str = "transfer/channel-0/myclass";
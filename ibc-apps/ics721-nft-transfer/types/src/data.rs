@@ -8,6 +8,8 @@ use base64::prelude::BASE64_STANDARD;
 use base64::Engine;
 use ibc_core::primitives::prelude::*;
 use mime::Mime;
+#[cfg(feature = "serde")]
+use serde::de::IgnoredAny;
 
 use crate::error::NftTransferError;
 
@@ -24,15 +26,129 @@ use crate::error::NftTransferError;
     derive(borsh::BorshSerialize, borsh::BorshDeserialize)
 )]
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
-#[derive(Clone, Debug, PartialEq, Eq, derive_more::From)]
+#[derive(Clone, Debug, Default, PartialEq, Eq, derive_more::From)]
 pub struct Data(String);
 
+impl Data {
+    /// Returns `true` if the data is empty, which stands for no data.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
 #[cfg(feature = "serde")]
 impl Data {
     /// Parses the data in the format specified by ICS-721.
     pub fn parse_as_ics721_data(&self) -> Result<Ics721Data, NftTransferError> {
         self.0.parse::<Ics721Data>()
     }
+
+    /// Validates that the data is either empty or JSON, and that the royalty
+    /// it carries, if any, is valid.
+    ///
+    /// The data is otherwise kept as is, so that it is relayed unchanged
+    /// across the hops of the NFT.
+    pub fn validate_basic(&self) -> Result<(), NftTransferError> {
+        if self.is_empty() {
+            return Ok(());
+        }
+
+        serde_json::from_str::<IgnoredAny>(&self.0)
+            .map_err(|_| NftTransferError::InvalidJsonData)?;
+
+        self.royalty()?;
+
+        Ok(())
+    }
+
+    /// Returns the royalty of the data, which is a JSON object carrying the
+    /// [`ROYALTY_PAYMENT_ADDRESS_KEY`] and [`ROYALTY_PERCENTAGE_KEY`] keys,
+    /// with either plain values, as the CW-2981 extension of CW-721 does, or
+    /// values in the ICS-721 data format.
+    ///
+    /// Returns none if the data is not a JSON object or has neither key.
+    pub fn royalty(&self) -> Result<Option<Royalty>, NftTransferError> {
+        #[derive(serde::Deserialize)]
+        struct RoyaltyFields {
+            royalty_payment_address: Option<RoyaltyField>,
+            royalty_percentage: Option<RoyaltyField>,
+        }
+
+        #[derive(serde::Deserialize)]
+        #[serde(untagged)]
+        enum RoyaltyField {
+            Ics721 { value: String },
+            String(String),
+            Number(u64),
+        }
+
+        impl RoyaltyField {
+            fn into_value(self) -> String {
+                match self {
+                    Self::Ics721 { value } | Self::String(value) => value,
+                    Self::Number(value) => value.to_string(),
+                }
+            }
+        }
+
+        if serde_json::from_str::<BTreeMap<String, IgnoredAny>>(&self.0).is_err() {
+            return Ok(None);
+        }
+
+        let fields = serde_json::from_str::<RoyaltyFields>(&self.0).map_err(|_| {
+            NftTransferError::InvalidRoyalty {
+                reason: "the royalty values must be strings or numbers".to_string(),
+            }
+        })?;
+
+        let (payment_address, percentage) =
+            match (fields.royalty_payment_address, fields.royalty_percentage) {
+                (None, None) => return Ok(None),
+                (Some(payment_address), Some(percentage)) => {
+                    (payment_address.into_value(), percentage.into_value())
+                }
+                _ => {
+                    return Err(NftTransferError::InvalidRoyalty {
+                        reason: "the royalty payment address and percentage must be set together"
+                            .to_string(),
+                    })
+                }
+            };
+
+        if payment_address.trim().is_empty() {
+            return Err(NftTransferError::InvalidRoyalty {
+                reason: "empty royalty payment address".to_string(),
+            });
+        }
+
+        let percentage = percentage
+            .parse::<u8>()
+            .ok()
+            .filter(|percentage| *percentage <= 100)
+            .ok_or_else(|| NftTransferError::InvalidRoyalty {
+                reason: format!("royalty percentage `{percentage}` is not between 0 and 100"),
+            })?;
+
+        Ok(Some(Royalty {
+            payment_address,
+            percentage,
+        }))
+    }
+}
+
+/// The key of the address receiving the royalties of an NFT in its data.
+pub const ROYALTY_PAYMENT_ADDRESS_KEY: &str = "royalty_payment_address";
+
+/// The key of the percentage of the sale prices of an NFT paid as royalties
+/// in its data.
+pub const ROYALTY_PERCENTAGE_KEY: &str = "royalty_percentage";
+
+/// The royalty of an NFT, paid to the payment address on its sales.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Royalty {
+    pub payment_address: String,
+    /// The percentage of the sale prices, between 0 and 100.
+    pub percentage: u8,
 }
 
 impl Display for Data {
@@ -290,6 +406,41 @@ mod tests {
         });
     }
 
+    #[cfg(feature = "serde")]
+    #[rstest]
+    #[case("", None)]
+    #[case(r#"{"name":{"value":"Crypto Creatures"}}"#, None)]
+    #[case(r#"["royalty_percentage"]"#, None)]
+    #[case(
+        r#"{"royalty_payment_address":"cosmos1creator","royalty_percentage":5}"#,
+        Some(5)
+    )]
+    #[case(
+        r#"{"royalty_payment_address":{"value":"cosmos1creator"},"royalty_percentage":{"value":"100"}}"#,
+        Some(100)
+    )]
+    fn test_valid_royalty(#[case] data: &str, #[case] percentage: Option<u8>) {
+        let data = Data::from_str(data).unwrap();
+
+        assert!(data.validate_basic().is_ok());
+        assert_eq!(
+            data.royalty().unwrap().map(|royalty| royalty.percentage),
+            percentage
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[rstest]
+    #[case("not json")]
+    #[case(r#"{"royalty_payment_address":"cosmos1creator"}"#)]
+    #[case(r#"{"royalty_payment_address":"","royalty_percentage":5}"#)]
+    #[case(r#"{"royalty_payment_address":"cosmos1creator","royalty_percentage":101}"#)]
+    #[case(r#"{"royalty_payment_address":"cosmos1creator","royalty_percentage":"5.5"}"#)]
+    #[case(r#"{"royalty_payment_address":"cosmos1creator","royalty_percentage":[5]}"#)]
+    fn test_invalid_royalty(#[case] data: &str) {
+        assert!(Data::from_str(data).unwrap().validate_basic().is_err());
+    }
+
     #[cfg(feature = "borsh")]
     #[test]
     fn test_borsh_roundtrip() {
@@ -10,6 +10,8 @@ use ibc_core::host::types::error::IdentifierError;
 use ibc_core::host::types::identifiers::{ChannelId, PortId};
 use ibc_core::primitives::prelude::*;
 
+use crate::ClassTraceHash;
+
 #[derive(Display, Debug)]
 pub enum NftTransferError {
     /// context error: `{0}`
@@ -52,6 +54,8 @@ pub enum NftTransferError {
     InvalidJsonData,
     /// the data is not in the JSON format specified by ICS-721
     InvalidIcs721Data,
+    /// invalid royalty: `{reason}`
+    InvalidRoyalty { reason: String },
     /// expected `{expect_order}` channel, got `{got_order}`
     ChannelNotUnordered {
         expect_order: Order,
@@ -67,6 +71,10 @@ pub enum NftTransferError {
     NftNotFound,
     /// nft class is not found
     NftClassNotFound,
+    /// invalid class trace hash: `{hash}`
+    InvalidClassTraceHash { hash: String },
+    /// class trace not found for hash: `{hash}`
+    ClassTraceNotFound { hash: ClassTraceHash },
     /// failed to deserialize packet data
     PacketDataDeserialization,
     /// failed to deserialize acknowledgement
@@ -82,6 +90,10 @@ pub enum NftTransferError {
         port_id: PortId,
         exp_port_id: PortId,
     },
+    /// invalid value of the memo key `{key}`
+    InvalidMemo { key: String },
+    /// a memo handler is already registered for the key `{key}`
+    DuplicateMemoHandler { key: String },
     /// decoding raw msg error: `{reason}`
     DecodeRawMsg { reason: String },
     /// unknown msg type: `{msg_type}`
@@ -92,9 +104,8 @@ pub enum NftTransferError {
     Other(String),
 }
 
-#[cfg(feature = "std")]
-impl std::error::Error for NftTransferError {
-    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+impl core::error::Error for NftTransferError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
         match &self {
             Self::ContextError(e) => Some(e),
             Self::InvalidUri {
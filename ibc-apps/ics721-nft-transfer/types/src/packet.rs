@@ -107,8 +107,32 @@ impl PacketData {
         if (num_uri != 0 && num_uri != num) || (num_data != 0 && num_data != num) {
             return Err(NftTransferError::TokenMismatched);
         }
+        #[cfg(feature = "serde")]
+        {
+            if let Some(class_data) = &self.class_data {
+                class_data.as_ref().validate_basic()?;
+            }
+            for token_data in self.token_data.iter().flatten() {
+                token_data.as_ref().validate_basic()?;
+            }
+        }
         Ok(())
     }
+
+    /// Returns the URI of the token at the given index of the token IDs, if
+    /// any.
+    pub fn token_uri_at(&self, index: usize) -> Option<&TokenUri> {
+        self.token_uris.as_ref().and_then(|uris| uris.get(index))
+    }
+
+    /// Returns the data of the token at the given index of the token IDs, if
+    /// any. The empty data, standing for no data, is skipped.
+    pub fn token_data_at(&self, index: usize) -> Option<&TokenData> {
+        self.token_data
+            .as_ref()
+            .and_then(|data| data.get(index))
+            .filter(|data| !data.is_empty())
+    }
 }
 
 impl TryFrom<RawPacketData> for PacketData {
@@ -319,5 +343,58 @@ mod tests {
             serde_json::from_str::<PacketData>(packet_data).is_err(),
             "no token ID"
         );
+
+        // The token data is not JSON
+        let mut packet_data = PacketData::new_dummy(None);
+        packet_data.token_data = Some(vec![
+            TokenData::from_str(DUMMY_DATA).unwrap(),
+            TokenData::from_str("not json").unwrap(),
+        ]);
+        assert!(packet_data.validate_basic().is_err(), "invalid token data");
+
+        // The royalty percentage is out of range
+        packet_data.token_data = Some(vec![
+            TokenData::from_str(DUMMY_DATA).unwrap(),
+            TokenData::from_str(
+                r#"{"royalty_payment_address":{"value":"cosmos1creator"},"royalty_percentage":{"value":"150"}}"#,
+            )
+            .unwrap(),
+        ]);
+        assert!(packet_data.validate_basic().is_err(), "invalid royalty");
+    }
+
+    /// Ensures that the token data, including the royalties and the empty data
+    /// of the NFTs without data, is relayed unchanged.
+    #[test]
+    fn test_token_data_roundtrip() {
+        let royalty_data = r#"{"royalty_payment_address":"cosmos1creator","royalty_percentage":5,"name":{"value":"Crypto Creatures"}}"#;
+
+        let mut packet_data = PacketData::new_dummy(None);
+        packet_data.token_data = Some(vec![
+            TokenData::from_str(royalty_data).unwrap(),
+            TokenData::default(),
+        ]);
+        assert!(packet_data.validate_basic().is_ok());
+
+        let json = serde_json::to_string(&packet_data).unwrap();
+        let deser: PacketData = serde_json::from_str(&json).unwrap();
+        assert_eq!(deser, packet_data);
+
+        assert_eq!(
+            deser.token_data_at(0).unwrap().to_string(),
+            royalty_data,
+            "the data is not reordered"
+        );
+        assert_eq!(deser.token_data_at(1), None);
+        assert_eq!(
+            deser
+                .token_data_at(0)
+                .unwrap()
+                .as_ref()
+                .royalty()
+                .unwrap()
+                .map(|royalty| royalty.percentage),
+            Some(5)
+        );
     }
 }
@@ -203,9 +203,17 @@ impl FromStr for TokenUri {
 )]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
-#[derive(Clone, Debug, PartialEq, Eq, derive_more::AsRef)]
+#[derive(Clone, Debug, Default, PartialEq, Eq, derive_more::AsRef)]
 pub struct TokenData(Data);
 
+impl TokenData {
+    /// Returns `true` if the token data is empty, which the packets carry for
+    /// the NFTs without data sent along with NFTs with data.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
 impl Display for TokenData {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "{}", self.0)
@@ -0,0 +1,55 @@
+//! Defines the context traits the host implements to forward packets
+use ibc_core::host::types::identifiers::{ChannelId, PortId, Sequence};
+use ibc_core::primitives::Signer;
+
+use crate::error::PacketForwardError;
+use crate::packet::InFlightPacket;
+
+/// Methods required in packet forwarding validation, to be implemented by the
+/// host.
+pub trait PacketForwardValidationContext {
+    /// Returns the account which receives the tokens of the transfers
+    /// forwarded from the given channel and original sender, before they are
+    /// forwarded to the next hop.
+    ///
+    /// The account should not be controlled by anyone, e.g. by deriving it
+    /// from the hash of the channel and the sender, as the middleware of
+    /// `ibc-go` does.
+    fn intermediate_receiver(
+        &self,
+        chan_id_on_b: &ChannelId,
+        original_sender: &Signer,
+    ) -> Result<Signer, PacketForwardError>;
+
+    /// Returns the in-flight packet forwarded with the given port, channel and
+    /// sequence, if any.
+    fn get_in_flight_packet(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        sequence: Sequence,
+    ) -> Result<Option<InFlightPacket>, PacketForwardError>;
+}
+
+/// Methods required in packet forwarding execution, to be implemented by the
+/// host.
+pub trait PacketForwardExecutionContext: PacketForwardValidationContext {
+    /// Stores the in-flight packet forwarded with the given port, channel and
+    /// sequence.
+    fn store_in_flight_packet(
+        &mut self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        sequence: Sequence,
+        in_flight_packet: InFlightPacket,
+    ) -> Result<(), PacketForwardError>;
+
+    /// Deletes the in-flight packet forwarded with the given port, channel and
+    /// sequence.
+    fn delete_in_flight_packet(
+        &mut self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        sequence: Sequence,
+    ) -> Result<(), PacketForwardError>;
+}
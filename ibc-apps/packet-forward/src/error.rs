@@ -0,0 +1,94 @@
+//! Defines the packet forward middleware error type
+use displaydoc::Display;
+use ibc_app_transfer::types::error::TokenTransferError;
+use ibc_core::channel::types::acknowledgement::StatusValue;
+use ibc_core::channel::types::error::PacketError;
+use ibc_core::handler::types::error::ContextError;
+use ibc_core::host::types::error::IdentifierError;
+use ibc_core::host::types::identifiers::{ChannelId, PortId};
+use ibc_core::primitives::prelude::*;
+use ibc_core::primitives::TimestampOverflowError;
+
+#[derive(Display, Debug)]
+pub enum PacketForwardError {
+    /// context error: `{0}`
+    ContextError(ContextError),
+    /// token transfer error: `{0}`
+    TokenTransfer(TokenTransferError),
+    /// invalid identifier: `{0}`
+    InvalidIdentifier(IdentifierError),
+    /// invalid forward metadata: `{reason}`
+    InvalidMetadata { reason: String },
+    /// invalid forward timeout: `{timeout}`
+    InvalidTimeout { timeout: String },
+    /// empty forward receiver
+    EmptyReceiver,
+    /// forward timeout overflows the host timestamp: `{0}`
+    TimestampOverflow(TimestampOverflowError),
+    /// destination channel not found in the counterparty of port_id `{port_id}` and channel_id `{channel_id}`
+    DestinationChannelNotFound {
+        port_id: PortId,
+        channel_id: ChannelId,
+    },
+    /// failed to deserialize packet data
+    PacketDataDeserialization,
+    /// failed to deserialize acknowledgement
+    AckDeserialization,
+    /// failed to parse account ID
+    ParseAccountFailure,
+    /// forwarded packet over port_id `{port_id}` and channel_id `{channel_id}` timed out
+    ForwardTimedOut {
+        port_id: PortId,
+        channel_id: ChannelId,
+    },
+}
+
+impl core::error::Error for PacketForwardError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match &self {
+            Self::ContextError(e) => Some(e),
+            Self::TokenTransfer(e) => Some(e),
+            Self::InvalidIdentifier(e) => Some(e),
+            Self::TimestampOverflow(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<ContextError> for PacketForwardError {
+    fn from(err: ContextError) -> PacketForwardError {
+        Self::ContextError(err)
+    }
+}
+
+impl From<TokenTransferError> for PacketForwardError {
+    fn from(err: TokenTransferError) -> PacketForwardError {
+        Self::TokenTransfer(err)
+    }
+}
+
+impl From<IdentifierError> for PacketForwardError {
+    fn from(err: IdentifierError) -> PacketForwardError {
+        Self::InvalidIdentifier(err)
+    }
+}
+
+impl From<TimestampOverflowError> for PacketForwardError {
+    fn from(err: TimestampOverflowError) -> PacketForwardError {
+        Self::TimestampOverflow(err)
+    }
+}
+
+impl From<PacketForwardError> for PacketError {
+    fn from(err: PacketForwardError) -> Self {
+        PacketError::AppModule {
+            description: err.to_string(),
+        }
+    }
+}
+
+impl From<PacketForwardError> for StatusValue {
+    fn from(err: PacketForwardError) -> Self {
+        StatusValue::new(err.to_string()).expect("error message must not be empty")
+    }
+}
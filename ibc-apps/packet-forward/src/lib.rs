@@ -0,0 +1,26 @@
+//! Implementation of the IBC [packet forward
+//! middleware](https://github.com/cosmos/ibc-apps/tree/main/middleware/packet-forward-middleware)
+//! logic, which forwards the tokens received over ICS-20 to the next hop of a
+//! multi-hop transfer.
+#![no_std]
+#![forbid(unsafe_code)]
+#![cfg_attr(not(test), deny(clippy::unwrap_used))]
+#![cfg_attr(not(test), deny(clippy::disallowed_methods, clippy::disallowed_types))]
+#![deny(
+    warnings,
+    trivial_casts,
+    trivial_numeric_casts,
+    unused_import_braces,
+    unused_qualifications,
+    rust_2018_idioms
+)]
+#![allow(clippy::result_large_err)]
+
+#[cfg(any(test, feature = "std"))]
+extern crate std;
+
+pub mod context;
+pub mod error;
+pub mod metadata;
+pub mod middleware;
+pub mod packet;
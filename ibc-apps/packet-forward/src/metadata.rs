@@ -0,0 +1,309 @@
+//! Defines the forward metadata, which instructs the middleware to forward the
+//! tokens of a transfer to the next hop.
+//!
+//! The forward metadata is carried by the memo of the transfer, using the
+//! format of the packet forward middleware of `ibc-go`:
+//!
+//! ```json
+//! {
+//!   "forward": {
+//!     "receiver": "cosmos1...",
+//!     "port": "transfer",
+//!     "channel": "channel-1",
+//!     "timeout": "10m",
+//!     "retries": 2,
+//!     "next": { "forward": { ... } }
+//!   }
+//! }
+//! ```
+//!
+//! The `timeout` is either a duration string, such as `"10m"` or `"1h30m"`,
+//! or a number of nanoseconds. The `next` memo is either the forward metadata
+//! of the next hop, or a string which is used as is.
+use core::str::FromStr;
+use core::time::Duration;
+
+use ibc_app_transfer::types::Memo;
+use ibc_core::host::types::identifiers::{ChannelId, PortId};
+use ibc_core::primitives::prelude::*;
+use ibc_core::primitives::Signer;
+use serde::de::IgnoredAny;
+use serde::{Deserialize, Serialize};
+
+use crate::error::PacketForwardError;
+
+/// The timeout of the forwarded transfers which do not set one.
+pub const DEFAULT_FORWARD_TIMEOUT: Duration = Duration::from_secs(10 * 60);
+
+/// The number of times the forwarded transfers which do not set one are
+/// retried when they time out.
+pub const DEFAULT_FORWARD_RETRIES: u8 = 0;
+
+/// The instructions to forward the tokens of a transfer to the next hop.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ForwardMetadata {
+    /// The receiver of the tokens on the next hop.
+    pub receiver: Signer,
+    /// The port the tokens are forwarded over.
+    pub port_id: PortId,
+    /// The channel the tokens are forwarded over.
+    pub channel_id: ChannelId,
+    /// The timeout of the forwarded transfer, relative to the host timestamp.
+    pub timeout: Duration,
+    /// The number of times the forwarded transfer is retried when it times
+    /// out.
+    pub retries: u8,
+    /// The memo of the forwarded transfer.
+    pub next: Option<Memo>,
+}
+
+impl ForwardMetadata {
+    /// Parses the forward metadata from the memo of a transfer.
+    ///
+    /// Returns `None` if the memo does not carry forward instructions, in
+    /// which case the transfer is not forwarded, and an error if it carries
+    /// invalid ones.
+    pub fn from_memo(memo: &Memo) -> Result<Option<Self>, PacketForwardError> {
+        #[derive(Deserialize)]
+        struct Probe {
+            #[serde(default)]
+            forward: Option<IgnoredAny>,
+        }
+
+        match serde_json::from_str::<Probe>(memo.as_ref()) {
+            Ok(Probe { forward: Some(_) }) => {}
+            _ => return Ok(None),
+        }
+
+        let metadata = serde_json::from_str::<RawNextMetadata>(memo.as_ref()).map_err(|e| {
+            PacketForwardError::InvalidMetadata {
+                reason: e.to_string(),
+            }
+        })?;
+
+        metadata.forward.try_into().map(Some)
+    }
+}
+
+/// The memo carrying the forward metadata of the next hop.
+#[derive(Serialize, Deserialize)]
+struct RawNextMetadata {
+    forward: RawForwardMetadata,
+}
+
+#[derive(Serialize, Deserialize)]
+struct RawForwardMetadata {
+    receiver: String,
+    port: String,
+    channel: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    timeout: Option<RawDuration>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    retries: Option<u8>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    next: Option<RawNext>,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(untagged)]
+enum RawDuration {
+    Nanos(u64),
+    Text(String),
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(untagged)]
+enum RawNext {
+    Metadata(Box<RawNextMetadata>),
+    Memo(String),
+}
+
+impl TryFrom<RawForwardMetadata> for ForwardMetadata {
+    type Error = PacketForwardError;
+
+    fn try_from(raw: RawForwardMetadata) -> Result<Self, Self::Error> {
+        if raw.receiver.is_empty() {
+            return Err(PacketForwardError::EmptyReceiver);
+        }
+
+        let timeout = match raw.timeout {
+            None => Duration::ZERO,
+            Some(RawDuration::Nanos(nanos)) => Duration::from_nanos(nanos),
+            Some(RawDuration::Text(text)) => {
+                parse_duration(&text).ok_or(PacketForwardError::InvalidTimeout { timeout: text })?
+            }
+        };
+
+        let next = match raw.next {
+            None => None,
+            Some(RawNext::Metadata(metadata)) => {
+                let memo = serde_json::to_string(&metadata).map_err(|e| {
+                    PacketForwardError::InvalidMetadata {
+                        reason: e.to_string(),
+                    }
+                })?;
+                Some(Memo::from(memo))
+            }
+            Some(RawNext::Memo(memo)) => Some(Memo::from(memo)),
+        };
+
+        Ok(Self {
+            receiver: Signer::from(raw.receiver),
+            port_id: PortId::from_str(&raw.port)?,
+            channel_id: ChannelId::from_str(&raw.channel)?,
+            timeout: if timeout.is_zero() {
+                DEFAULT_FORWARD_TIMEOUT
+            } else {
+                timeout
+            },
+            retries: raw.retries.unwrap_or(DEFAULT_FORWARD_RETRIES),
+            next,
+        })
+    }
+}
+
+/// Parses a duration string in the format of Go's `time.ParseDuration`, such
+/// as `"300ms"`, `"1.5h"` or `"2h45m"`.
+fn parse_duration(text: &str) -> Option<Duration> {
+    if text == "0" {
+        return Some(Duration::ZERO);
+    }
+    if text.is_empty() {
+        return None;
+    }
+
+    let mut nanos: u128 = 0;
+    let mut rest = text;
+
+    while !rest.is_empty() {
+        let number_len = rest
+            .find(|c: char| !(c.is_ascii_digit() || c == '.'))
+            .unwrap_or(rest.len());
+        let (number, tail) = rest.split_at(number_len);
+        let unit_len = tail
+            .find(|c: char| c.is_ascii_digit() || c == '.')
+            .unwrap_or(tail.len());
+        let (unit, tail) = tail.split_at(unit_len);
+
+        let unit_nanos: u128 = match unit {
+            "ns" => 1,
+            "us" | "µs" | "μs" => 1_000,
+            "ms" => 1_000_000,
+            "s" => 1_000_000_000,
+            "m" => 60_000_000_000,
+            "h" => 3_600_000_000_000,
+            _ => return None,
+        };
+
+        let (integer, fraction) = number.split_once('.').unwrap_or((number, ""));
+        if integer.is_empty() && fraction.is_empty() {
+            return None;
+        }
+
+        if !integer.is_empty() {
+            let integer: u128 = integer.parse().ok()?;
+            nanos = nanos.checked_add(integer.checked_mul(unit_nanos)?)?;
+        }
+
+        if !fraction.is_empty() {
+            let scale = 10u128.checked_pow(u32::try_from(fraction.len()).ok()?)?;
+            let fraction: u128 = fraction.parse().ok()?;
+            nanos = nanos.checked_add(fraction.checked_mul(unit_nanos)? / scale)?;
+        }
+
+        rest = tail;
+    }
+
+    u64::try_from(nanos).ok().map(Duration::from_nanos)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_duration() {
+        assert_eq!(parse_duration("0"), Some(Duration::ZERO));
+        assert_eq!(parse_duration("300ms"), Some(Duration::from_millis(300)));
+        assert_eq!(parse_duration("10m"), Some(Duration::from_secs(600)));
+        assert_eq!(parse_duration("1.5h"), Some(Duration::from_secs(5400)));
+        assert_eq!(
+            parse_duration("2h45m30s"),
+            Some(Duration::from_secs(2 * 3600 + 45 * 60 + 30))
+        );
+        assert_eq!(parse_duration("1µs"), Some(Duration::from_micros(1)));
+
+        assert_eq!(parse_duration(""), None);
+        assert_eq!(parse_duration("10"), None);
+        assert_eq!(parse_duration("10d"), None);
+        assert_eq!(parse_duration(".s"), None);
+        assert_eq!(parse_duration("1..5s"), None);
+    }
+
+    #[test]
+    fn test_forward_metadata_from_memo() {
+        let memo = Memo::from(
+            r#"{"forward":{"receiver":"cosmos1receiver","port":"transfer","channel":"channel-1","timeout":"1m","retries":2}}"#,
+        );
+        let forward = ForwardMetadata::from_memo(&memo).unwrap().unwrap();
+
+        assert_eq!(
+            forward.receiver,
+            Signer::from("cosmos1receiver".to_string())
+        );
+        assert_eq!(forward.port_id, PortId::transfer());
+        assert_eq!(forward.channel_id, ChannelId::new(1));
+        assert_eq!(forward.timeout, Duration::from_secs(60));
+        assert_eq!(forward.retries, 2);
+        assert_eq!(forward.next, None);
+
+        let memo = Memo::from(
+            r#"{"forward":{"receiver":"cosmos1receiver","port":"transfer","channel":"channel-1"}}"#,
+        );
+        let forward = ForwardMetadata::from_memo(&memo).unwrap().unwrap();
+
+        assert_eq!(forward.timeout, DEFAULT_FORWARD_TIMEOUT);
+        assert_eq!(forward.retries, DEFAULT_FORWARD_RETRIES);
+    }
+
+    #[test]
+    fn test_forward_metadata_next() {
+        let memo = Memo::from(
+            r#"{"forward":{"receiver":"pfm","port":"transfer","channel":"channel-1","next":{"forward":{"receiver":"cosmos1receiver","port":"transfer","channel":"channel-2","timeout":600000000000}}}}"#,
+        );
+        let forward = ForwardMetadata::from_memo(&memo).unwrap().unwrap();
+        let next = ForwardMetadata::from_memo(&forward.next.unwrap())
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(next.receiver, Signer::from("cosmos1receiver".to_string()));
+        assert_eq!(next.channel_id, ChannelId::new(2));
+        assert_eq!(next.timeout, Duration::from_secs(600));
+
+        let memo = Memo::from(
+            r#"{"forward":{"receiver":"pfm","port":"transfer","channel":"channel-1","next":"hello"}}"#,
+        );
+        let forward = ForwardMetadata::from_memo(&memo).unwrap().unwrap();
+
+        assert_eq!(forward.next, Some(Memo::from("hello")));
+    }
+
+    #[test]
+    fn test_forward_metadata_not_forwarded() {
+        for memo in ["", "hello", r#"{"wasm":{"contract":"cosmos1contract"}}"#] {
+            assert_eq!(ForwardMetadata::from_memo(&Memo::from(memo)).unwrap(), None);
+        }
+    }
+
+    #[test]
+    fn test_forward_metadata_invalid() {
+        for memo in [
+            r#"{"forward":{"port":"transfer","channel":"channel-1"}}"#,
+            r#"{"forward":{"receiver":"","port":"transfer","channel":"channel-1"}}"#,
+            r#"{"forward":{"receiver":"cosmos1receiver","port":"transfer","channel":"chan"}}"#,
+            r#"{"forward":{"receiver":"cosmos1receiver","port":"transfer","channel":"channel-1","timeout":"1d"}}"#,
+        ] {
+            assert!(ForwardMetadata::from_memo(&Memo::from(memo)).is_err());
+        }
+    }
+}
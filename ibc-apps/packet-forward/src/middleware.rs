@@ -0,0 +1,441 @@
+//! Defines the packet forward middleware, which wraps the ICS-20 module to
+//! forward the tokens it receives to the next hop of a multi-hop transfer.
+//!
+//! It is stacked on top of the token transfer module with
+//! [`ModuleStack`](ibc_core::router::middleware::ModuleStack).
+use core::fmt::Debug;
+use core::time::Duration;
+
+use ibc_app_transfer::context::{TokenTransferExecutionContext, TokenTransferValidationContext};
+use ibc_app_transfer::handler::{burn_coins_with_hooks, escrow_coins_with_hooks, received_coin};
+use ibc_app_transfer::types::packet::PacketData;
+use ibc_app_transfer::types::{is_sender_chain_source, Memo};
+use ibc_core::channel::handler::{
+    send_packet_execute, send_packet_validate, write_acknowledgement,
+};
+use ibc_core::channel::types::acknowledgement::{Acknowledgement, AcknowledgementStatus};
+use ibc_core::channel::types::error::PacketError;
+use ibc_core::channel::types::packet::Packet;
+use ibc_core::channel::types::timeout::TimeoutHeight;
+use ibc_core::host::types::identifiers::{ChannelId, PortId, Sequence};
+use ibc_core::host::types::path::{ChannelEndPath, SeqSendPath};
+use ibc_core::host::ExecutionContext;
+use ibc_core::primitives::prelude::*;
+use ibc_core::primitives::Signer;
+use ibc_core::router::middleware::{merge_extras, Middleware};
+use ibc_core::router::module::Module;
+use ibc_core::router::types::module::ModuleExtras;
+
+use crate::context::PacketForwardExecutionContext;
+use crate::error::PacketForwardError;
+use crate::metadata::ForwardMetadata;
+use crate::packet::InFlightPacket;
+
+/// The packet forward middleware, stacked on top of the token transfer module.
+///
+/// When a received transfer carries forward instructions in its memo, the
+/// tokens are received by an intermediate account of the host, then sent from
+/// it to the next hop. The received packet is acknowledged asynchronously,
+/// with the acknowledgement of the forwarded transfer, so that a failure on
+/// any hop refunds the tokens along the path back to the original sender.
+/// Forwarded transfers which time out are retried as many times as
+/// instructed, and then fail. Other transfers are passed through to the token
+/// transfer module.
+///
+/// The host context must refer to the same state as the context of the token
+/// transfer module below.
+#[derive(Debug)]
+pub struct PacketForwardMiddleware<C> {
+    ctx: C,
+}
+
+impl<C> PacketForwardMiddleware<C> {
+    pub fn new(ctx: C) -> Self {
+        Self { ctx }
+    }
+
+    pub fn ctx(&self) -> &C {
+        &self.ctx
+    }
+
+    pub fn ctx_mut(&mut self) -> &mut C {
+        &mut self.ctx
+    }
+}
+
+fn error_ack(err: PacketForwardError) -> Acknowledgement {
+    AcknowledgementStatus::error(err.into()).into()
+}
+
+impl<C> PacketForwardMiddleware<C>
+where
+    C: ExecutionContext + TokenTransferExecutionContext + PacketForwardExecutionContext,
+{
+    /// Sends the given transfer from the intermediate account over the given
+    /// channel, and returns the sequence it is sent with.
+    fn send_forward(
+        &mut self,
+        port_id_on_b: &PortId,
+        chan_id_on_b: &ChannelId,
+        data: &PacketData,
+        timeout: Duration,
+    ) -> Result<Sequence, PacketForwardError> {
+        let chan_end_path_on_b = ChannelEndPath::new(port_id_on_b, chan_id_on_b);
+        let chan_end_on_b = self.ctx.channel_end(&chan_end_path_on_b)?;
+
+        let port_id_on_c = chan_end_on_b.counterparty().port_id().clone();
+        let chan_id_on_c = chan_end_on_b
+            .counterparty()
+            .channel_id()
+            .ok_or_else(|| PacketForwardError::DestinationChannelNotFound {
+                port_id: port_id_on_b.clone(),
+                channel_id: chan_id_on_b.clone(),
+            })?
+            .clone();
+
+        let seq_send_path_on_b = SeqSendPath::new(port_id_on_b, chan_id_on_b);
+        let sequence = self.ctx.get_next_sequence_send(&seq_send_path_on_b)?;
+
+        let sender: <C as TokenTransferValidationContext>::AccountId = data
+            .sender
+            .clone()
+            .try_into()
+            .map_err(|_| PacketForwardError::ParseAccountFailure)?;
+
+        let packet = Packet {
+            seq_on_a: sequence,
+            port_id_on_a: port_id_on_b.clone(),
+            chan_id_on_a: chan_id_on_b.clone(),
+            port_id_on_b: port_id_on_c,
+            chan_id_on_b: chan_id_on_c,
+            data: serde_json::to_vec(data).expect("PacketData's infallible Serialize impl failed"),
+            timeout_height_on_b: TimeoutHeight::Never,
+            timeout_timestamp_on_b: (self.ctx.host_timestamp()? + timeout)?,
+        };
+
+        let is_source = is_sender_chain_source(
+            port_id_on_b.clone(),
+            chan_id_on_b.clone(),
+            &data.token.denom,
+        );
+
+        if is_source {
+            self.ctx.escrow_coins_validate(
+                &sender,
+                port_id_on_b,
+                chan_id_on_b,
+                &data.token,
+                &data.memo,
+            )?;
+        } else {
+            self.ctx
+                .burn_coins_validate(&sender, &data.token, &data.memo)?;
+        }
+
+        send_packet_validate(&self.ctx, &packet)?;
+
+        if is_source {
+            escrow_coins_with_hooks(
+                &mut self.ctx,
+                &sender,
+                port_id_on_b,
+                chan_id_on_b,
+                &data.token,
+                &data.memo,
+            )?;
+        } else {
+            burn_coins_with_hooks(&mut self.ctx, &sender, &data.token, &data.memo)?;
+        }
+
+        send_packet_execute(&mut self.ctx, packet)?;
+
+        Ok(sequence)
+    }
+
+    /// Forwards the tokens received with the given packet, which the
+    /// intermediate account holds, to the next hop.
+    fn forward(
+        &mut self,
+        packet: &Packet,
+        data: &PacketData,
+        forward: ForwardMetadata,
+    ) -> Result<ModuleExtras, PacketForwardError> {
+        let forward_data = PacketData {
+            token: received_coin(packet, &data.token),
+            sender: data.receiver.clone(),
+            receiver: forward.receiver,
+            memo: forward.next.unwrap_or_else(|| Memo::from("")),
+        };
+
+        let sequence = self.send_forward(
+            &forward.port_id,
+            &forward.channel_id,
+            &forward_data,
+            forward.timeout,
+        )?;
+
+        self.ctx.store_in_flight_packet(
+            &forward.port_id,
+            &forward.channel_id,
+            sequence,
+            InFlightPacket {
+                original_packet: packet.clone(),
+                retries_left: forward.retries,
+                timeout: forward.timeout,
+            },
+        )?;
+
+        Ok(ModuleExtras {
+            events: Vec::new(),
+            log: vec![format!(
+                "packet forward: {} --({})--> {} over {}/{}",
+                forward_data.sender,
+                forward_data.token,
+                forward_data.receiver,
+                forward.port_id,
+                forward.channel_id,
+            )],
+        })
+    }
+
+    /// Returns the tokens of a failed forward, which the intermediate account
+    /// holds, to where they were received from, so that they are refunded to
+    /// the original sender once the original packet is acknowledged with an
+    /// error.
+    fn refund_intermediate(
+        &mut self,
+        original_packet: &Packet,
+        data: &PacketData,
+    ) -> Result<(), PacketForwardError> {
+        let intermediate: <C as TokenTransferValidationContext>::AccountId = data
+            .sender
+            .clone()
+            .try_into()
+            .map_err(|_| PacketForwardError::ParseAccountFailure)?;
+
+        // The tokens are escrowed back if they were unescrowed on receipt,
+        // and burnt if they were minted.
+        if is_sender_chain_source(
+            original_packet.port_id_on_b.clone(),
+            original_packet.chan_id_on_b.clone(),
+            &data.token.denom,
+        ) {
+            escrow_coins_with_hooks(
+                &mut self.ctx,
+                &intermediate,
+                &original_packet.port_id_on_b,
+                &original_packet.chan_id_on_b,
+                &data.token,
+                &data.memo,
+            )?;
+        } else {
+            burn_coins_with_hooks(&mut self.ctx, &intermediate, &data.token, &data.memo)?;
+        }
+
+        Ok(())
+    }
+
+    fn on_forward_acknowledged(
+        &mut self,
+        packet: &Packet,
+        in_flight_packet: InFlightPacket,
+        acknowledgement: &Acknowledgement,
+    ) -> Result<(), PacketForwardError> {
+        self.ctx.delete_in_flight_packet(
+            &packet.port_id_on_a,
+            &packet.chan_id_on_a,
+            packet.seq_on_a,
+        )?;
+
+        let status = serde_json::from_slice::<AcknowledgementStatus>(acknowledgement.as_bytes())
+            .map_err(|_| PacketForwardError::AckDeserialization)?;
+
+        if !status.is_successful() {
+            let data = serde_json::from_slice::<PacketData>(&packet.data)
+                .map_err(|_| PacketForwardError::PacketDataDeserialization)?;
+
+            self.refund_intermediate(&in_flight_packet.original_packet, &data)?;
+        }
+
+        write_acknowledgement(
+            &mut self.ctx,
+            in_flight_packet.original_packet,
+            acknowledgement.clone(),
+        )?;
+
+        Ok(())
+    }
+
+    fn on_forward_timed_out(
+        &mut self,
+        packet: &Packet,
+        mut in_flight_packet: InFlightPacket,
+    ) -> Result<(), PacketForwardError> {
+        self.ctx.delete_in_flight_packet(
+            &packet.port_id_on_a,
+            &packet.chan_id_on_a,
+            packet.seq_on_a,
+        )?;
+
+        let data = serde_json::from_slice::<PacketData>(&packet.data)
+            .map_err(|_| PacketForwardError::PacketDataDeserialization)?;
+
+        let mut err = PacketForwardError::ForwardTimedOut {
+            port_id: packet.port_id_on_a.clone(),
+            channel_id: packet.chan_id_on_a.clone(),
+        };
+
+        if in_flight_packet.retries_left > 0 {
+            in_flight_packet.retries_left -= 1;
+
+            match self.send_forward(
+                &packet.port_id_on_a,
+                &packet.chan_id_on_a,
+                &data,
+                in_flight_packet.timeout,
+            ) {
+                Ok(sequence) => {
+                    return self.ctx.store_in_flight_packet(
+                        &packet.port_id_on_a,
+                        &packet.chan_id_on_a,
+                        sequence,
+                        in_flight_packet,
+                    );
+                }
+                Err(retry_err) => err = retry_err,
+            }
+        }
+
+        self.refund_intermediate(&in_flight_packet.original_packet, &data)?;
+
+        write_acknowledgement(
+            &mut self.ctx,
+            in_flight_packet.original_packet,
+            error_ack(err),
+        )?;
+
+        Ok(())
+    }
+}
+
+impl<C> Middleware for PacketForwardMiddleware<C>
+where
+    C: ExecutionContext + TokenTransferExecutionContext + PacketForwardExecutionContext + Debug,
+{
+    fn on_recv_packet_execute(
+        &mut self,
+        next: &mut dyn Module,
+        packet: &Packet,
+        relayer: &Signer,
+    ) -> (ModuleExtras, Option<Acknowledgement>) {
+        let Ok(mut data) = serde_json::from_slice::<PacketData>(&packet.data) else {
+            return next.on_recv_packet_execute(packet, relayer);
+        };
+
+        let forward = match ForwardMetadata::from_memo(&data.memo) {
+            Ok(Some(forward)) => forward,
+            Ok(None) => return next.on_recv_packet_execute(packet, relayer),
+            Err(err) => return (ModuleExtras::empty(), Some(error_ack(err))),
+        };
+
+        // The tokens are received by the intermediate account, without the
+        // forward instructions.
+        data.receiver = match self
+            .ctx
+            .intermediate_receiver(&packet.chan_id_on_b, &data.sender)
+        {
+            Ok(receiver) => receiver,
+            Err(err) => return (ModuleExtras::empty(), Some(error_ack(err))),
+        };
+        data.memo = Memo::from("");
+
+        let intermediate_packet = Packet {
+            data: serde_json::to_vec(&data).expect("PacketData's infallible Serialize impl failed"),
+            ..packet.clone()
+        };
+
+        let (extras, ack) = next.on_recv_packet_execute(&intermediate_packet, relayer);
+
+        match &ack {
+            Some(ack)
+                if serde_json::from_slice::<AcknowledgementStatus>(ack.as_bytes())
+                    .map(|status| status.is_successful())
+                    .unwrap_or(false) => {}
+            _ => return (extras, ack),
+        }
+
+        match self.forward(packet, &data, forward) {
+            // The packet is acknowledged once the forwarded transfer is.
+            Ok(forward_extras) => (merge_extras(extras, forward_extras), None),
+            Err(err) => {
+                let mut forward_data = data.clone();
+                forward_data.sender = data.receiver;
+                forward_data.token = received_coin(packet, &data.token);
+
+                let ack = match self.refund_intermediate(packet, &forward_data) {
+                    Ok(()) => error_ack(err),
+                    Err(refund_err) => error_ack(refund_err),
+                };
+
+                (extras, Some(ack))
+            }
+        }
+    }
+
+    fn on_acknowledgement_packet_execute(
+        &mut self,
+        next: &mut dyn Module,
+        packet: &Packet,
+        acknowledgement: &Acknowledgement,
+        relayer: &Signer,
+    ) -> (ModuleExtras, Result<(), PacketError>) {
+        // The token transfer module refunds the intermediate account first if
+        // the forwarded transfer failed.
+        let (extras, result) =
+            next.on_acknowledgement_packet_execute(packet, acknowledgement, relayer);
+
+        if result.is_err() {
+            return (extras, result);
+        }
+
+        let result = match self.ctx.get_in_flight_packet(
+            &packet.port_id_on_a,
+            &packet.chan_id_on_a,
+            packet.seq_on_a,
+        ) {
+            Ok(Some(in_flight_packet)) => {
+                self.on_forward_acknowledged(packet, in_flight_packet, acknowledgement)
+            }
+            Ok(None) => Ok(()),
+            Err(err) => Err(err),
+        };
+
+        (extras, result.map_err(PacketError::from))
+    }
+
+    fn on_timeout_packet_execute(
+        &mut self,
+        next: &mut dyn Module,
+        packet: &Packet,
+        relayer: &Signer,
+    ) -> (ModuleExtras, Result<(), PacketError>) {
+        // The token transfer module refunds the intermediate account first.
+        let (extras, result) = next.on_timeout_packet_execute(packet, relayer);
+
+        if result.is_err() {
+            return (extras, result);
+        }
+
+        let result = match self.ctx.get_in_flight_packet(
+            &packet.port_id_on_a,
+            &packet.chan_id_on_a,
+            packet.seq_on_a,
+        ) {
+            Ok(Some(in_flight_packet)) => self.on_forward_timed_out(packet, in_flight_packet),
+            Ok(None) => Ok(()),
+            Err(err) => Err(err),
+        };
+
+        (extras, result.map_err(PacketError::from))
+    }
+}
@@ -0,0 +1,23 @@
+//! Defines the in-flight packet, which tracks a forwarded transfer until it is
+//! acknowledged or timed out.
+use core::time::Duration;
+
+use ibc_core::channel::types::packet::Packet;
+
+/// A transfer forwarded to the next hop, whose acknowledgement is awaited to
+/// acknowledge the packet it was received with.
+///
+/// It is stored under the port, channel and sequence the transfer was
+/// forwarded with.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct InFlightPacket {
+    /// The packet the tokens were received with, which is acknowledged once
+    /// the forwarded transfer is.
+    pub original_packet: Packet,
+    /// The number of times the forwarded transfer is still retried when it
+    /// times out.
+    pub retries_left: u8,
+    /// The timeout of the forwarded transfer, which its retries are sent
+    /// with.
+    pub timeout: Duration,
+}
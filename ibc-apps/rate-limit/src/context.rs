@@ -0,0 +1,80 @@
+//! Defines the context traits the host implements to persist the rate limits
+use ibc_app_transfer::types::PrefixedDenom;
+use ibc_core::host::types::identifiers::{ChannelId, PortId};
+
+use crate::error::RateLimitError;
+use crate::packet_rate_limit::{PacketFlow, PacketQuota};
+use crate::rate_limit::RateLimit;
+
+/// Methods required in rate limiting validation, to be implemented by the
+/// host.
+pub trait RateLimitValidationContext {
+    /// Returns the rate limit of the given denomination over the given
+    /// channel, if any.
+    ///
+    /// The denominations are the ones of the host, i.e. the ones the tokens
+    /// are sent with, and received as.
+    fn rate_limit(
+        &self,
+        channel_id: &ChannelId,
+        denom: &PrefixedDenom,
+    ) -> Result<Option<RateLimit>, RateLimitError>;
+}
+
+/// Methods required in rate limiting execution, to be implemented by the
+/// host.
+pub trait RateLimitExecutionContext: RateLimitValidationContext {
+    /// Stores the rate limit of the given denomination over the given
+    /// channel.
+    fn store_rate_limit(
+        &mut self,
+        channel_id: &ChannelId,
+        denom: &PrefixedDenom,
+        rate_limit: RateLimit,
+    ) -> Result<(), RateLimitError>;
+
+    /// Deletes the rate limit of the given denomination over the given
+    /// channel.
+    fn delete_rate_limit(
+        &mut self,
+        channel_id: &ChannelId,
+        denom: &PrefixedDenom,
+    ) -> Result<(), RateLimitError>;
+}
+
+/// The parameters of the packet rate limits, to be implemented by the host.
+pub trait PacketRateLimitParams {
+    /// Returns the quota of the packets received and sent over the given
+    /// channel end of the host, if any.
+    ///
+    /// The channels without a quota are not rate limited.
+    fn packet_quota(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<Option<PacketQuota>, RateLimitError>;
+}
+
+/// Methods required in packet rate limiting validation, to be implemented by
+/// the host.
+pub trait PacketRateLimitValidationContext: PacketRateLimitParams {
+    /// Returns the flow of the packets over the given channel end of the
+    /// host, if any packet was recorded.
+    fn packet_flow(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<Option<PacketFlow>, RateLimitError>;
+}
+
+/// Methods required in packet rate limiting execution, to be implemented by
+/// the host.
+pub trait PacketRateLimitExecutionContext: PacketRateLimitValidationContext {
+    /// Stores the flow of the packets over the given channel end of the host.
+    fn store_packet_flow(
+        &mut self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        flow: PacketFlow,
+    ) -> Result<(), RateLimitError>;
+}
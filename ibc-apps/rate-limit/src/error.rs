@@ -0,0 +1,113 @@
+//! Defines the rate limit middleware error type
+use displaydoc::Display;
+use ibc_app_transfer::types::error::TokenTransferError;
+use ibc_app_transfer::types::{Amount, PrefixedDenom};
+use ibc_core::channel::types::acknowledgement::StatusValue;
+use ibc_core::channel::types::error::PacketError;
+use ibc_core::handler::types::error::ContextError;
+use ibc_core::host::types::identifiers::{ChannelId, PortId};
+use ibc_core::primitives::prelude::*;
+
+use crate::packet_rate_limit::PacketVolume;
+
+#[derive(Display, Debug)]
+pub enum RateLimitError {
+    /// context error: `{0}`
+    ContextError(ContextError),
+    /// token transfer error: `{0}`
+    TokenTransfer(TokenTransferError),
+    /// invalid quota: `{reason}`
+    InvalidQuota { reason: String },
+    /// inflow of `{denom}` over channel `{channel_id}` exceeds its quota of `{max_inflow}` per window
+    InflowQuotaExceeded {
+        channel_id: ChannelId,
+        denom: PrefixedDenom,
+        max_inflow: Amount,
+    },
+    /// outflow of `{denom}` over channel `{channel_id}` exceeds its quota of `{max_outflow}` per window
+    OutflowQuotaExceeded {
+        channel_id: ChannelId,
+        denom: PrefixedDenom,
+        max_outflow: Amount,
+    },
+    /// inflow of packets over channel `{port_id}/{channel_id}` exceeds its quota of `{max_inflow}` per window
+    PacketInflowQuotaExceeded {
+        port_id: PortId,
+        channel_id: ChannelId,
+        max_inflow: PacketVolume,
+    },
+    /// outflow of packets over channel `{port_id}/{channel_id}` exceeds its quota of `{max_outflow}` per window
+    PacketOutflowQuotaExceeded {
+        port_id: PortId,
+        channel_id: ChannelId,
+        max_outflow: PacketVolume,
+    },
+    /// rate limit of `{denom}` over channel `{channel_id}` already exists
+    RateLimitAlreadyExists {
+        channel_id: ChannelId,
+        denom: PrefixedDenom,
+    },
+    /// rate limit of `{denom}` over channel `{channel_id}` not found
+    RateLimitNotFound {
+        channel_id: ChannelId,
+        denom: PrefixedDenom,
+    },
+}
+
+impl RateLimitError {
+    /// Returns the ABCI code the rate limit module of the Cosmos SDK chains
+    /// writes in the error acknowledgements for the equivalent error, so that
+    /// the acknowledgements are the same on both stacks.
+    ///
+    /// The other errors are written with the code of the internal errors.
+    pub fn ack_error_code(&self) -> u32 {
+        match self {
+            Self::TokenTransfer(err) => err.ack_error_code(),
+            // `ratelimittypes.ErrRateLimitNotFound`
+            Self::RateLimitNotFound { .. } => 2,
+            // `ratelimittypes.ErrQuotaExceeded`
+            Self::InflowQuotaExceeded { .. }
+            | Self::OutflowQuotaExceeded { .. }
+            | Self::PacketInflowQuotaExceeded { .. }
+            | Self::PacketOutflowQuotaExceeded { .. } => 4,
+            // `errorsmod.ErrInternal`
+            _ => 1,
+        }
+    }
+}
+
+impl core::error::Error for RateLimitError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match &self {
+            Self::ContextError(e) => Some(e),
+            Self::TokenTransfer(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<ContextError> for RateLimitError {
+    fn from(err: ContextError) -> RateLimitError {
+        Self::ContextError(err)
+    }
+}
+
+impl From<TokenTransferError> for RateLimitError {
+    fn from(err: TokenTransferError) -> RateLimitError {
+        Self::TokenTransfer(err)
+    }
+}
+
+impl From<RateLimitError> for PacketError {
+    fn from(err: RateLimitError) -> Self {
+        PacketError::AppModule {
+            description: err.to_string(),
+        }
+    }
+}
+
+impl From<RateLimitError> for StatusValue {
+    fn from(err: RateLimitError) -> Self {
+        StatusValue::new(err.to_string()).expect("error message must not be empty")
+    }
+}
@@ -0,0 +1,128 @@
+//! Implements the updates of the rate limits, which the governance of the host
+//! calls to add, update, remove or reset them.
+use ibc_app_transfer::types::PrefixedDenom;
+use ibc_core::host::types::identifiers::ChannelId;
+use ibc_core::host::ValidationContext;
+
+use crate::context::{RateLimitExecutionContext, RateLimitValidationContext};
+use crate::error::RateLimitError;
+use crate::rate_limit::{Flow, Quota, RateLimit};
+
+pub fn add_rate_limit_validate(
+    ctx: &impl RateLimitValidationContext,
+    channel_id: &ChannelId,
+    denom: &PrefixedDenom,
+    quota: &Quota,
+) -> Result<(), RateLimitError> {
+    quota.validate_basic()?;
+
+    if ctx.rate_limit(channel_id, denom)?.is_some() {
+        return Err(RateLimitError::RateLimitAlreadyExists {
+            channel_id: channel_id.clone(),
+            denom: denom.clone(),
+        });
+    }
+
+    Ok(())
+}
+
+/// Adds the rate limit of the given denomination over the given channel,
+/// whose first window starts now.
+pub fn add_rate_limit_execute<Ctx>(
+    ctx: &mut Ctx,
+    channel_id: &ChannelId,
+    denom: &PrefixedDenom,
+    quota: Quota,
+) -> Result<(), RateLimitError>
+where
+    Ctx: RateLimitExecutionContext + ValidationContext,
+{
+    let rate_limit = RateLimit::new(quota, ctx.host_timestamp()?);
+
+    ctx.store_rate_limit(channel_id, denom, rate_limit)
+}
+
+pub fn update_rate_limit_validate(
+    ctx: &impl RateLimitValidationContext,
+    channel_id: &ChannelId,
+    denom: &PrefixedDenom,
+    quota: &Quota,
+) -> Result<(), RateLimitError> {
+    quota.validate_basic()?;
+
+    existing_rate_limit(ctx, channel_id, denom)?;
+
+    Ok(())
+}
+
+/// Replaces the quota of the rate limit of the given denomination over the
+/// given channel, keeping the flow of the current window.
+pub fn update_rate_limit_execute(
+    ctx: &mut impl RateLimitExecutionContext,
+    channel_id: &ChannelId,
+    denom: &PrefixedDenom,
+    quota: Quota,
+) -> Result<(), RateLimitError> {
+    let mut rate_limit = existing_rate_limit(ctx, channel_id, denom)?;
+    rate_limit.quota = quota;
+
+    ctx.store_rate_limit(channel_id, denom, rate_limit)
+}
+
+pub fn remove_rate_limit_validate(
+    ctx: &impl RateLimitValidationContext,
+    channel_id: &ChannelId,
+    denom: &PrefixedDenom,
+) -> Result<(), RateLimitError> {
+    existing_rate_limit(ctx, channel_id, denom)?;
+
+    Ok(())
+}
+
+/// Removes the rate limit of the given denomination over the given channel,
+/// which is not rate limited anymore.
+pub fn remove_rate_limit_execute(
+    ctx: &mut impl RateLimitExecutionContext,
+    channel_id: &ChannelId,
+    denom: &PrefixedDenom,
+) -> Result<(), RateLimitError> {
+    ctx.delete_rate_limit(channel_id, denom)
+}
+
+pub fn reset_rate_limit_validate(
+    ctx: &impl RateLimitValidationContext,
+    channel_id: &ChannelId,
+    denom: &PrefixedDenom,
+) -> Result<(), RateLimitError> {
+    existing_rate_limit(ctx, channel_id, denom)?;
+
+    Ok(())
+}
+
+/// Resets the flow of the rate limit of the given denomination over the given
+/// channel, whose new window starts now.
+pub fn reset_rate_limit_execute<Ctx>(
+    ctx: &mut Ctx,
+    channel_id: &ChannelId,
+    denom: &PrefixedDenom,
+) -> Result<(), RateLimitError>
+where
+    Ctx: RateLimitExecutionContext + ValidationContext,
+{
+    let mut rate_limit = existing_rate_limit(ctx, channel_id, denom)?;
+    rate_limit.flow = Flow::new(ctx.host_timestamp()?);
+
+    ctx.store_rate_limit(channel_id, denom, rate_limit)
+}
+
+fn existing_rate_limit(
+    ctx: &impl RateLimitValidationContext,
+    channel_id: &ChannelId,
+    denom: &PrefixedDenom,
+) -> Result<RateLimit, RateLimitError> {
+    ctx.rate_limit(channel_id, denom)?
+        .ok_or_else(|| RateLimitError::RateLimitNotFound {
+            channel_id: channel_id.clone(),
+            denom: denom.clone(),
+        })
+}
@@ -0,0 +1,33 @@
+//! Implementation of the IBC rate limit middleware logic, which rejects the
+//! ICS-20 transfers whose inflow or outflow of a denomination over a channel
+//! exceeds the quotas set by the governance of the host, to bound the value
+//! which can be drained over a bridge within a time window.
+//!
+//! It also implements a packet rate limit middleware, which bounds the number
+//! of packets, and of their bytes, received and sent over a channel within a
+//! sliding window, in front of any application, e.g. interchain accounts, NFT
+//! transfers or custom applications.
+#![no_std]
+#![forbid(unsafe_code)]
+#![cfg_attr(not(test), deny(clippy::unwrap_used))]
+#![cfg_attr(not(test), deny(clippy::disallowed_methods, clippy::disallowed_types))]
+#![deny(
+    warnings,
+    trivial_casts,
+    trivial_numeric_casts,
+    unused_import_braces,
+    unused_qualifications,
+    rust_2018_idioms
+)]
+#![allow(clippy::result_large_err)]
+
+#[cfg(any(test, feature = "std"))]
+extern crate std;
+
+pub mod context;
+pub mod error;
+pub mod handler;
+pub mod middleware;
+pub mod packet_middleware;
+pub mod packet_rate_limit;
+pub mod rate_limit;
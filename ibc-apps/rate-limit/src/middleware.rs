@@ -0,0 +1,307 @@
+//! Defines the rate limit middleware, which wraps the ICS-20 module to reject
+//! the transfers exceeding the quotas of their denominations.
+//!
+//! It is stacked on top of the token transfer module with
+//! [`ModuleStack`](ibc_core::router::middleware::ModuleStack).
+use core::fmt::Debug;
+
+use ibc_app_transfer::handler::{decode_packet_data, received_coin};
+use ibc_app_transfer::types::packet::PacketDataV2;
+use ibc_app_transfer::types::{PrefixedCoin, PrefixedDenom};
+use ibc_core::channel::types::acknowledgement::{
+    Acknowledgement, AcknowledgementStatus, StatusValue,
+};
+use ibc_core::channel::types::error::PacketError;
+use ibc_core::channel::types::packet::Packet;
+use ibc_core::host::types::identifiers::{ChannelId, PortId};
+use ibc_core::host::types::path::ChannelEndPath;
+use ibc_core::host::ValidationContext;
+use ibc_core::primitives::prelude::*;
+use ibc_core::primitives::Signer;
+use ibc_core::router::middleware::Middleware;
+use ibc_core::router::module::Module;
+use ibc_core::router::types::event::ModuleEvent;
+use ibc_core::router::types::module::ModuleExtras;
+
+use crate::context::RateLimitExecutionContext;
+use crate::error::RateLimitError;
+use crate::rate_limit::RateLimit;
+
+/// The rate limit middleware, stacked on top of the token transfer module.
+///
+/// The tokens sent and received over a channel are recorded in the flow of
+/// the rate limit of their denomination, if any, and the transfers which
+/// would exceed its quota within the current window are rejected: sent
+/// transfers fail, and received ones are acknowledged with an error without
+/// being passed to the token transfer module. The outflow of the sent
+/// transfers which are refunded, on an error acknowledgement or a timeout,
+/// is given back. Denominations without a rate limit are not limited.
+///
+/// The host context must refer to the same state as the context of the token
+/// transfer module below.
+#[derive(Debug)]
+pub struct RateLimitMiddleware<C> {
+    ctx: C,
+}
+
+impl<C> RateLimitMiddleware<C> {
+    pub fn new(ctx: C) -> Self {
+        Self { ctx }
+    }
+
+    pub fn ctx(&self) -> &C {
+        &self.ctx
+    }
+
+    pub fn ctx_mut(&mut self) -> &mut C {
+        &mut self.ctx
+    }
+}
+
+/// The kind of the events emitted for the received packets which are
+/// rejected by a rate limit.
+pub const EVENT_TYPE_RECV_REJECTED: &str = "rate_limit_recv_rejected";
+
+/// Acknowledges the given received packet with the given error, without
+/// passing it to the next module.
+///
+/// The error acknowledgement only carries the ABCI code of the error, as the
+/// Cosmos SDK chains write it, the details being emitted in the event.
+pub(crate) fn reject_recv_packet(
+    packet: &Packet,
+    err: &RateLimitError,
+) -> (ModuleExtras, Option<Acknowledgement>) {
+    let ack = AcknowledgementStatus::error(StatusValue::from_abci_error_code(err.ack_error_code()));
+
+    let extras = ModuleExtras {
+        events: vec![ModuleEvent {
+            kind: EVENT_TYPE_RECV_REJECTED.to_string(),
+            attributes: vec![
+                ("port_id", packet.port_id_on_b.as_str()).into(),
+                ("channel_id", packet.chan_id_on_b.as_str()).into(),
+                ("sequence", packet.seq_on_a).into(),
+                ("error", err.to_string()).into(),
+            ],
+        }],
+        log: Vec::new(),
+    };
+
+    (extras, Some(ack.into()))
+}
+
+/// The direction of the tokens recorded in a flow.
+#[derive(Clone, Copy, Debug)]
+pub(crate) enum FlowDirection {
+    In,
+    Out,
+}
+
+impl<C> RateLimitMiddleware<C>
+where
+    C: ValidationContext + RateLimitExecutionContext,
+{
+    /// Decodes the packet data of the given packet, as encoded for the
+    /// version of the given channel end it is sent or received on.
+    fn packet_data(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        packet: &Packet,
+    ) -> Result<PacketDataV2, RateLimitError> {
+        let chan_end = self
+            .ctx
+            .channel_end(&ChannelEndPath::new(port_id, channel_id))?;
+
+        Ok(decode_packet_data(chan_end.version(), &packet.data)?)
+    }
+
+    /// Returns the rate limits of the denominations of the given coins over
+    /// the given channel, with the coins recorded in their flow, or an error
+    /// if any quota is exceeded.
+    fn updated_rate_limits(
+        &self,
+        channel_id: &ChannelId,
+        coins: &[PrefixedCoin],
+        direction: FlowDirection,
+    ) -> Result<BTreeMap<PrefixedDenom, RateLimit>, RateLimitError> {
+        let now = self.ctx.host_timestamp()?;
+        let mut rate_limits = BTreeMap::new();
+
+        for coin in coins {
+            // A denomination may occur multiple times in a packet.
+            let rate_limit = match rate_limits.remove(&coin.denom) {
+                Some(rate_limit) => Some(rate_limit),
+                None => self.ctx.rate_limit(channel_id, &coin.denom)?,
+            };
+
+            let Some(mut rate_limit) = rate_limit else {
+                continue;
+            };
+
+            match direction {
+                FlowDirection::In if !rate_limit.try_add_inflow(now, coin.amount) => {
+                    return Err(RateLimitError::InflowQuotaExceeded {
+                        channel_id: channel_id.clone(),
+                        denom: coin.denom.clone(),
+                        max_inflow: rate_limit.quota.max_inflow,
+                    });
+                }
+                FlowDirection::Out if !rate_limit.try_add_outflow(now, coin.amount) => {
+                    return Err(RateLimitError::OutflowQuotaExceeded {
+                        channel_id: channel_id.clone(),
+                        denom: coin.denom.clone(),
+                        max_outflow: rate_limit.quota.max_outflow,
+                    });
+                }
+                _ => {}
+            }
+
+            rate_limits.insert(coin.denom.clone(), rate_limit);
+        }
+
+        Ok(rate_limits)
+    }
+
+    fn store_rate_limits(
+        &mut self,
+        channel_id: &ChannelId,
+        rate_limits: BTreeMap<PrefixedDenom, RateLimit>,
+    ) -> Result<(), RateLimitError> {
+        for (denom, rate_limit) in rate_limits {
+            self.ctx.store_rate_limit(channel_id, &denom, rate_limit)?;
+        }
+
+        Ok(())
+    }
+
+    /// Gives back the outflow of the given packet, whose tokens are refunded
+    /// to the sender.
+    fn undo_outflow(&mut self, packet: &Packet) -> Result<(), RateLimitError> {
+        let data = self.packet_data(&packet.port_id_on_a, &packet.chan_id_on_a, packet)?;
+
+        for token in &data.tokens {
+            if let Some(mut rate_limit) = self.ctx.rate_limit(&packet.chan_id_on_a, &token.denom)? {
+                rate_limit.undo_outflow(token.amount);
+
+                self.ctx
+                    .store_rate_limit(&packet.chan_id_on_a, &token.denom, rate_limit)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl<C> Middleware for RateLimitMiddleware<C>
+where
+    C: ValidationContext + RateLimitExecutionContext + Debug,
+{
+    fn on_send_packet_validate(&self, packet: &Packet) -> Result<(), PacketError> {
+        let Ok(data) = self.packet_data(&packet.port_id_on_a, &packet.chan_id_on_a, packet) else {
+            return Ok(());
+        };
+
+        self.updated_rate_limits(&packet.chan_id_on_a, &data.tokens, FlowDirection::Out)?;
+
+        Ok(())
+    }
+
+    fn on_send_packet_execute(&mut self, packet: &Packet) -> Result<ModuleExtras, PacketError> {
+        let Ok(data) = self.packet_data(&packet.port_id_on_a, &packet.chan_id_on_a, packet) else {
+            return Ok(ModuleExtras::empty());
+        };
+
+        let rate_limits =
+            self.updated_rate_limits(&packet.chan_id_on_a, &data.tokens, FlowDirection::Out)?;
+        self.store_rate_limits(&packet.chan_id_on_a, rate_limits)?;
+
+        Ok(ModuleExtras::empty())
+    }
+
+    fn on_recv_packet_execute(
+        &mut self,
+        next: &mut dyn Module,
+        packet: &Packet,
+        relayer: &Signer,
+    ) -> (ModuleExtras, Option<Acknowledgement>) {
+        let Ok(data) = self.packet_data(&packet.port_id_on_b, &packet.chan_id_on_b, packet) else {
+            return next.on_recv_packet_execute(packet, relayer);
+        };
+
+        // The received tokens are rate limited as denominated on the host.
+        let coins: Vec<PrefixedCoin> = data
+            .tokens
+            .iter()
+            .map(|token| received_coin(packet, token))
+            .collect();
+
+        let rate_limits =
+            match self.updated_rate_limits(&packet.chan_id_on_b, &coins, FlowDirection::In) {
+                Ok(rate_limits) => rate_limits,
+                Err(err) => return reject_recv_packet(packet, &err),
+            };
+
+        let (mut extras, ack) = next.on_recv_packet_execute(packet, relayer);
+
+        // The packets acknowledged asynchronously, e.g. forwarded ones, were
+        // received.
+        let received = match &ack {
+            Some(ack) => serde_json::from_slice::<AcknowledgementStatus>(ack.as_bytes())
+                .map(|status| status.is_successful())
+                .unwrap_or(false),
+            None => true,
+        };
+
+        if received {
+            // The tokens were received by the token transfer module, whose
+            // acknowledgement is kept even if the inflow is not recorded.
+            if let Err(err) = self.store_rate_limits(&packet.chan_id_on_b, rate_limits) {
+                extras
+                    .log
+                    .push(format!("rate limit: failed to record the inflow: {err}"));
+            }
+        }
+
+        (extras, ack)
+    }
+
+    fn on_acknowledgement_packet_execute(
+        &mut self,
+        next: &mut dyn Module,
+        packet: &Packet,
+        acknowledgement: &Acknowledgement,
+        relayer: &Signer,
+    ) -> (ModuleExtras, Result<(), PacketError>) {
+        let (extras, result) =
+            next.on_acknowledgement_packet_execute(packet, acknowledgement, relayer);
+
+        if result.is_err() {
+            return (extras, result);
+        }
+
+        let refunded = serde_json::from_slice::<AcknowledgementStatus>(acknowledgement.as_bytes())
+            .map(|status| !status.is_successful())
+            .unwrap_or(false);
+
+        if !refunded {
+            return (extras, result);
+        }
+
+        (extras, self.undo_outflow(packet).map_err(PacketError::from))
+    }
+
+    fn on_timeout_packet_execute(
+        &mut self,
+        next: &mut dyn Module,
+        packet: &Packet,
+        relayer: &Signer,
+    ) -> (ModuleExtras, Result<(), PacketError>) {
+        let (extras, result) = next.on_timeout_packet_execute(packet, relayer);
+
+        if result.is_err() {
+            return (extras, result);
+        }
+
+        (extras, self.undo_outflow(packet).map_err(PacketError::from))
+    }
+}
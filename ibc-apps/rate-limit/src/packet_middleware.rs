@@ -0,0 +1,169 @@
+//! Defines the packet rate limit middleware, which wraps any IBC module to
+//! reject the packets exceeding the quotas of their channels.
+//!
+//! It is stacked on top of the application with
+//! [`ModuleStack`](ibc_core::router::middleware::ModuleStack), e.g. the
+//! interchain accounts host, the NFT transfer module or a custom application.
+use core::fmt::Debug;
+
+use ibc_core::channel::types::acknowledgement::Acknowledgement;
+use ibc_core::channel::types::error::PacketError;
+use ibc_core::channel::types::packet::Packet;
+use ibc_core::host::types::identifiers::{ChannelId, PortId};
+use ibc_core::host::ValidationContext;
+use ibc_core::primitives::prelude::*;
+use ibc_core::primitives::Signer;
+use ibc_core::router::middleware::Middleware;
+use ibc_core::router::module::Module;
+use ibc_core::router::types::module::ModuleExtras;
+
+use crate::context::PacketRateLimitExecutionContext;
+use crate::error::RateLimitError;
+use crate::middleware::{reject_recv_packet, FlowDirection};
+use crate::packet_rate_limit::{PacketFlow, PacketVolume};
+
+/// The packet rate limit middleware, stacked on top of any application.
+///
+/// The packets sent and received over a channel are recorded in its flow if
+/// the channel has a quota, and the packets which would exceed it within the
+/// sliding window ending now are rejected: sent packets fail, and received
+/// ones are acknowledged with an error without being passed to the
+/// application. Unlike the rate limits of the token transfers, the outflow is
+/// not given back when the packets are acknowledged with an error or time
+/// out, since they were relayed nonetheless.
+///
+/// The host context must refer to the same state as the context of the
+/// application below.
+#[derive(Debug)]
+pub struct PacketRateLimitMiddleware<C> {
+    ctx: C,
+}
+
+impl<C> PacketRateLimitMiddleware<C> {
+    pub fn new(ctx: C) -> Self {
+        Self { ctx }
+    }
+
+    pub fn ctx(&self) -> &C {
+        &self.ctx
+    }
+
+    pub fn ctx_mut(&mut self) -> &mut C {
+        &mut self.ctx
+    }
+}
+
+impl<C> PacketRateLimitMiddleware<C>
+where
+    C: ValidationContext + PacketRateLimitExecutionContext,
+{
+    /// Returns the flow of the given channel end with the given packet
+    /// recorded in it, if the channel has a quota, or an error if it is
+    /// exceeded.
+    fn updated_flow(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        packet: &Packet,
+        direction: FlowDirection,
+    ) -> Result<Option<PacketFlow>, RateLimitError> {
+        let Some(quota) = self.ctx.packet_quota(port_id, channel_id)? else {
+            return Ok(None);
+        };
+
+        let now = self.ctx.host_timestamp()?;
+        let volume = PacketVolume::of(packet);
+
+        let mut flow = self
+            .ctx
+            .packet_flow(port_id, channel_id)?
+            .unwrap_or_else(|| PacketFlow::new(now));
+
+        match direction {
+            FlowDirection::In if !flow.try_add_inflow(now, &quota, volume) => {
+                return Err(RateLimitError::PacketInflowQuotaExceeded {
+                    port_id: port_id.clone(),
+                    channel_id: channel_id.clone(),
+                    max_inflow: quota.max_inflow,
+                });
+            }
+            FlowDirection::Out if !flow.try_add_outflow(now, &quota, volume) => {
+                return Err(RateLimitError::PacketOutflowQuotaExceeded {
+                    port_id: port_id.clone(),
+                    channel_id: channel_id.clone(),
+                    max_outflow: quota.max_outflow,
+                });
+            }
+            _ => {}
+        }
+
+        Ok(Some(flow))
+    }
+}
+
+impl<C> Middleware for PacketRateLimitMiddleware<C>
+where
+    C: ValidationContext + PacketRateLimitExecutionContext + Debug,
+{
+    fn on_send_packet_validate(&self, packet: &Packet) -> Result<(), PacketError> {
+        self.updated_flow(
+            &packet.port_id_on_a,
+            &packet.chan_id_on_a,
+            packet,
+            FlowDirection::Out,
+        )?;
+
+        Ok(())
+    }
+
+    fn on_send_packet_execute(&mut self, packet: &Packet) -> Result<ModuleExtras, PacketError> {
+        let flow = self.updated_flow(
+            &packet.port_id_on_a,
+            &packet.chan_id_on_a,
+            packet,
+            FlowDirection::Out,
+        )?;
+
+        if let Some(flow) = flow {
+            self.ctx
+                .store_packet_flow(&packet.port_id_on_a, &packet.chan_id_on_a, flow)?;
+        }
+
+        Ok(ModuleExtras::empty())
+    }
+
+    fn on_recv_packet_execute(
+        &mut self,
+        next: &mut dyn Module,
+        packet: &Packet,
+        relayer: &Signer,
+    ) -> (ModuleExtras, Option<Acknowledgement>) {
+        let flow = match self.updated_flow(
+            &packet.port_id_on_b,
+            &packet.chan_id_on_b,
+            packet,
+            FlowDirection::In,
+        ) {
+            Ok(flow) => flow,
+            Err(err) => return reject_recv_packet(packet, &err),
+        };
+
+        let (mut extras, ack) = next.on_recv_packet_execute(packet, relayer);
+
+        // The packet was received whatever the application acknowledged it
+        // with, and its acknowledgement is kept even if the inflow is not
+        // recorded.
+        if let Some(flow) = flow {
+            if let Err(err) =
+                self.ctx
+                    .store_packet_flow(&packet.port_id_on_b, &packet.chan_id_on_b, flow)
+            {
+                extras.log.push(format!(
+                    "packet rate limit: failed to record the inflow: {err}"
+                ));
+            }
+        }
+
+        (extras, ack)
+    }
+}
@@ -0,0 +1,318 @@
+//! Defines the packet rate limits, which bound the number of packets, and of
+//! their bytes, received and sent over a channel within a sliding window,
+//! whatever the application they are routed to.
+use core::fmt::{Display, Error as FmtError, Formatter};
+use core::time::Duration;
+
+use ibc_core::channel::types::packet::Packet;
+use ibc_core::primitives::prelude::*;
+use ibc_core::primitives::Timestamp;
+
+use crate::error::RateLimitError;
+
+/// A number of packets, and the number of bytes of their data.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct PacketVolume {
+    pub packets: u64,
+    pub bytes: u64,
+}
+
+impl PacketVolume {
+    pub fn new(packets: u64, bytes: u64) -> Self {
+        Self { packets, bytes }
+    }
+
+    /// Returns the volume of the given packet.
+    pub fn of(packet: &Packet) -> Self {
+        Self {
+            packets: 1,
+            bytes: packet.data.len() as u64,
+        }
+    }
+
+    pub fn checked_add(self, other: Self) -> Option<Self> {
+        Some(Self {
+            packets: self.packets.checked_add(other.packets)?,
+            bytes: self.bytes.checked_add(other.bytes)?,
+        })
+    }
+
+    /// Returns whether neither the packets nor the bytes of the volume exceed
+    /// the ones of the given maximum.
+    pub fn is_within(&self, max: &Self) -> bool {
+        self.packets <= max.packets && self.bytes <= max.bytes
+    }
+
+    /// Returns the given fraction of the volume, rounded down.
+    fn scaled(self, numerator: u128, denominator: u128) -> Self {
+        let scale = |value: u64| {
+            u64::try_from(u128::from(value) * numerator / denominator)
+                .expect("the fraction is at most one")
+        };
+
+        Self {
+            packets: scale(self.packets),
+            bytes: scale(self.bytes),
+        }
+    }
+}
+
+impl Display for PacketVolume {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), FmtError> {
+        write!(f, "{} packets and {} bytes", self.packets, self.bytes)
+    }
+}
+
+/// The quota of the packets over a channel, bounding the volumes which can be
+/// received and sent within any window.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PacketQuota {
+    /// The volume which can be received within a window.
+    pub max_inflow: PacketVolume,
+    /// The volume which can be sent within a window.
+    pub max_outflow: PacketVolume,
+    /// The duration of the sliding window.
+    pub window: Duration,
+}
+
+impl PacketQuota {
+    pub fn validate_basic(&self) -> Result<(), RateLimitError> {
+        if self.window.is_zero() {
+            return Err(RateLimitError::InvalidQuota {
+                reason: "window must not be zero".to_string(),
+            });
+        }
+
+        Ok(())
+    }
+}
+
+/// The volume of the packets within a sliding window.
+///
+/// The window is approximated from the volumes of the current and of the
+/// previous fixed windows: the volume of the previous window is counted in
+/// proportion of its overlap with the sliding window ending now, assuming its
+/// packets were evenly spread. Unlike a fixed window, whose flow is reset at
+/// once, this bounds the volume of the bursts around the boundaries of the
+/// windows.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SlidingWindow {
+    /// The time at which the current fixed window started.
+    pub window_start: Timestamp,
+    /// The volume of the previous fixed window.
+    pub previous: PacketVolume,
+    /// The volume of the current fixed window.
+    pub current: PacketVolume,
+}
+
+impl SlidingWindow {
+    /// Returns an empty sliding window, whose first fixed window starts at
+    /// the given time.
+    pub fn new(window_start: Timestamp) -> Self {
+        Self {
+            window_start,
+            previous: PacketVolume::default(),
+            current: PacketVolume::default(),
+        }
+    }
+
+    /// Moves over to the fixed window of the given time, if the current one
+    /// elapsed.
+    ///
+    /// The windows are not moved back if the given time precedes the start
+    /// of the current window.
+    pub fn slide(&mut self, now: Timestamp, window: Duration) {
+        let Some(elapsed) = now.duration_since(&self.window_start) else {
+            if !self.window_start.is_set() {
+                *self = Self::new(now);
+            }
+
+            return;
+        };
+
+        let window = window.as_nanos();
+        let elapsed = elapsed.as_nanos();
+
+        match elapsed / window {
+            0 => return,
+            1 => self.previous = self.current,
+            _ => self.previous = PacketVolume::default(),
+        }
+
+        self.current = PacketVolume::default();
+
+        let offset =
+            u64::try_from(elapsed % window).expect("the offset is less than the elapsed time");
+        self.window_start = (now - Duration::from_nanos(offset)).unwrap_or(now);
+    }
+
+    /// Returns the volume of the sliding window ending at the given time,
+    /// after moving over to its fixed window.
+    pub fn volume(&mut self, now: Timestamp, window: Duration) -> PacketVolume {
+        self.slide(now, window);
+
+        let elapsed = now
+            .duration_since(&self.window_start)
+            .unwrap_or_default()
+            .as_nanos();
+        let window = window.as_nanos();
+
+        let previous = self.previous.scaled(window.saturating_sub(elapsed), window);
+
+        previous.checked_add(self.current).unwrap_or(PacketVolume {
+            packets: u64::MAX,
+            bytes: u64::MAX,
+        })
+    }
+
+    /// Records the given volume at the given time.
+    ///
+    /// Returns `false`, leaving the volumes unchanged, if the volume of the
+    /// sliding window would exceed the given maximum.
+    pub fn try_add(
+        &mut self,
+        now: Timestamp,
+        window: Duration,
+        volume: PacketVolume,
+        max: &PacketVolume,
+    ) -> bool {
+        let within = self
+            .volume(now, window)
+            .checked_add(volume)
+            .is_some_and(|total| total.is_within(max));
+
+        if !within {
+            return false;
+        }
+
+        match self.current.checked_add(volume) {
+            Some(current) => {
+                self.current = current;
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+/// The volumes of the packets received and sent over a channel.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PacketFlow {
+    pub inflow: SlidingWindow,
+    pub outflow: SlidingWindow,
+}
+
+impl PacketFlow {
+    /// Returns an empty flow, whose windows start at the given time.
+    pub fn new(now: Timestamp) -> Self {
+        Self {
+            inflow: SlidingWindow::new(now),
+            outflow: SlidingWindow::new(now),
+        }
+    }
+
+    /// Records the given volume as received at the given time.
+    ///
+    /// Returns `false`, leaving the flow unchanged, if the inflow would
+    /// exceed the quota.
+    pub fn try_add_inflow(
+        &mut self,
+        now: Timestamp,
+        quota: &PacketQuota,
+        volume: PacketVolume,
+    ) -> bool {
+        self.inflow
+            .try_add(now, quota.window, volume, &quota.max_inflow)
+    }
+
+    /// Records the given volume as sent at the given time.
+    ///
+    /// Returns `false`, leaving the flow unchanged, if the outflow would
+    /// exceed the quota.
+    pub fn try_add_outflow(
+        &mut self,
+        now: Timestamp,
+        quota: &PacketQuota,
+        volume: PacketVolume,
+    ) -> bool {
+        self.outflow
+            .try_add(now, quota.window, volume, &quota.max_outflow)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const WINDOW: Duration = Duration::from_secs(100);
+
+    fn timestamp(secs: u64) -> Timestamp {
+        Timestamp::from_nanoseconds(secs * 1_000_000_000).unwrap()
+    }
+
+    fn quota() -> PacketQuota {
+        PacketQuota {
+            max_inflow: PacketVolume::new(10, 1000),
+            max_outflow: PacketVolume::new(2, 1000),
+            window: WINDOW,
+        }
+    }
+
+    #[test]
+    fn test_packets_and_bytes_quotas() {
+        let mut flow = PacketFlow::new(timestamp(1));
+
+        // The bytes are bounded along with the packets.
+        assert!(flow.try_add_inflow(timestamp(2), &quota(), PacketVolume::new(1, 600)));
+        assert!(!flow.try_add_inflow(timestamp(3), &quota(), PacketVolume::new(1, 401)));
+        assert!(flow.try_add_inflow(timestamp(3), &quota(), PacketVolume::new(1, 400)));
+        assert_eq!(flow.inflow.current, PacketVolume::new(2, 1000));
+
+        // The inflow and the outflow have separate quotas.
+        assert!(flow.try_add_outflow(timestamp(4), &quota(), PacketVolume::new(1, 10)));
+        assert!(flow.try_add_outflow(timestamp(4), &quota(), PacketVolume::new(1, 10)));
+        assert!(!flow.try_add_outflow(timestamp(4), &quota(), PacketVolume::new(1, 10)));
+    }
+
+    #[test]
+    fn test_sliding_window() {
+        let mut outflow = SlidingWindow::new(timestamp(1));
+        let max = PacketVolume::new(10, u64::MAX);
+        let ten = PacketVolume::new(10, 0);
+
+        assert!(outflow.try_add(timestamp(91), WINDOW, ten, &max));
+
+        // Half of the previous window overlaps the sliding window, whose
+        // volume is half of the one of the previous window.
+        let now = timestamp(151);
+        assert_eq!(outflow.volume(now, WINDOW), PacketVolume::new(5, 0));
+        assert_eq!(outflow.window_start, timestamp(101));
+        assert!(!outflow.try_add(now, WINDOW, PacketVolume::new(6, 0), &max));
+        assert!(outflow.try_add(now, WINDOW, PacketVolume::new(5, 0), &max));
+
+        // The volumes of the windows which elapsed are not counted.
+        let now = timestamp(301);
+        assert_eq!(outflow.volume(now, WINDOW), PacketVolume::default());
+        assert_eq!(outflow.window_start, now);
+        assert!(outflow.try_add(now, WINDOW, ten, &max));
+    }
+
+    #[test]
+    fn test_window_not_moved_back() {
+        let mut inflow = SlidingWindow::new(timestamp(50));
+        let max = PacketVolume::new(1, u64::MAX);
+
+        assert!(inflow.try_add(timestamp(10), WINDOW, PacketVolume::new(1, 0), &max));
+        assert_eq!(inflow.window_start, timestamp(50));
+        assert!(!inflow.try_add(timestamp(60), WINDOW, PacketVolume::new(1, 0), &max));
+    }
+
+    #[test]
+    fn test_quota_validate_basic() {
+        let mut quota = quota();
+        assert!(quota.validate_basic().is_ok());
+
+        quota.window = Duration::ZERO;
+        assert!(quota.validate_basic().is_err());
+    }
+}
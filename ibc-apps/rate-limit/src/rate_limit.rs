@@ -0,0 +1,210 @@
+//! Defines the rate limits, which bound the amounts of a denomination received
+//! and sent over a channel within a time window.
+use core::time::Duration;
+
+use ibc_app_transfer::types::Amount;
+use ibc_core::primitives::prelude::*;
+use ibc_core::primitives::Timestamp;
+
+use crate::error::RateLimitError;
+
+/// The quota of a rate limit, bounding the amounts which can be received and
+/// sent within each window.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Quota {
+    /// The amount which can be received within a window.
+    pub max_inflow: Amount,
+    /// The amount which can be sent within a window.
+    pub max_outflow: Amount,
+    /// The duration of the windows, after which the flow is reset.
+    pub window: Duration,
+}
+
+impl Quota {
+    pub fn validate_basic(&self) -> Result<(), RateLimitError> {
+        if self.window.is_zero() {
+            return Err(RateLimitError::InvalidQuota {
+                reason: "window must not be zero".to_string(),
+            });
+        }
+
+        Ok(())
+    }
+}
+
+/// The amounts received and sent within the current window.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Flow {
+    pub inflow: Amount,
+    pub outflow: Amount,
+    /// The time at which the current window started.
+    pub window_start: Timestamp,
+}
+
+impl Flow {
+    /// Returns an empty flow, whose window starts at the given time.
+    pub fn new(window_start: Timestamp) -> Self {
+        Self {
+            inflow: Amount::from(0),
+            outflow: Amount::from(0),
+            window_start,
+        }
+    }
+}
+
+/// The rate limit of a denomination over a channel.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RateLimit {
+    pub quota: Quota,
+    pub flow: Flow,
+}
+
+impl RateLimit {
+    /// Returns the rate limit of the given quota, whose first window starts at
+    /// the given time.
+    pub fn new(quota: Quota, now: Timestamp) -> Self {
+        Self {
+            quota,
+            flow: Flow::new(now),
+        }
+    }
+
+    /// Resets the flow if its window elapsed at the given time.
+    pub fn refresh(&mut self, now: Timestamp) {
+        let elapsed = match now.duration_since(&self.flow.window_start) {
+            Some(elapsed) => elapsed >= self.quota.window,
+            None => !self.flow.window_start.is_set(),
+        };
+
+        if elapsed {
+            self.flow = Flow::new(now);
+        }
+    }
+
+    /// Records the given amount as received at the given time.
+    ///
+    /// Returns `false`, leaving the flow unchanged, if the inflow of the
+    /// window would exceed the quota.
+    pub fn try_add_inflow(&mut self, now: Timestamp, amount: Amount) -> bool {
+        self.refresh(now);
+
+        match self
+            .flow
+            .inflow
+            .checked_add(amount)
+            .filter(|inflow| *inflow <= self.quota.max_inflow)
+        {
+            Some(inflow) => {
+                self.flow.inflow = inflow;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Records the given amount as sent at the given time.
+    ///
+    /// Returns `false`, leaving the flow unchanged, if the outflow of the
+    /// window would exceed the quota.
+    pub fn try_add_outflow(&mut self, now: Timestamp, amount: Amount) -> bool {
+        self.refresh(now);
+
+        match self
+            .flow
+            .outflow
+            .checked_add(amount)
+            .filter(|outflow| *outflow <= self.quota.max_outflow)
+        {
+            Some(outflow) => {
+                self.flow.outflow = outflow;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Removes the given amount from the outflow, when the tokens sent are
+    /// refunded.
+    ///
+    /// The outflow saturates at zero, since the tokens may have been sent in
+    /// a previous window.
+    pub fn undo_outflow(&mut self, amount: Amount) {
+        self.flow.outflow = self.flow.outflow.saturating_sub(amount);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const WINDOW: Duration = Duration::from_secs(3600);
+
+    fn timestamp(secs: u64) -> Timestamp {
+        Timestamp::from_nanoseconds(secs * 1_000_000_000).unwrap()
+    }
+
+    fn rate_limit() -> RateLimit {
+        RateLimit::new(
+            Quota {
+                max_inflow: Amount::from(100),
+                max_outflow: Amount::from(50),
+                window: WINDOW,
+            },
+            timestamp(1),
+        )
+    }
+
+    #[test]
+    fn test_quota_exceeded_within_window() {
+        let mut rate_limit = rate_limit();
+
+        assert!(rate_limit.try_add_inflow(timestamp(2), Amount::from(60)));
+        assert!(rate_limit.try_add_inflow(timestamp(3), Amount::from(40)));
+        assert!(!rate_limit.try_add_inflow(timestamp(4), Amount::from(1)));
+        assert_eq!(rate_limit.flow.inflow, Amount::from(100));
+
+        // The inflow and the outflow have separate quotas.
+        assert!(rate_limit.try_add_outflow(timestamp(4), Amount::from(50)));
+        assert!(!rate_limit.try_add_outflow(timestamp(4), Amount::from(1)));
+    }
+
+    #[test]
+    fn test_flow_reset_once_window_elapsed() {
+        let mut rate_limit = rate_limit();
+
+        assert!(rate_limit.try_add_outflow(timestamp(2), Amount::from(50)));
+        assert!(!rate_limit.try_add_outflow(timestamp(WINDOW.as_secs()), Amount::from(1)));
+
+        let now = timestamp(1 + WINDOW.as_secs());
+        assert!(rate_limit.try_add_outflow(now, Amount::from(1)));
+        assert_eq!(
+            rate_limit.flow,
+            Flow {
+                inflow: Amount::from(0),
+                outflow: Amount::from(1),
+                window_start: now,
+            }
+        );
+    }
+
+    #[test]
+    fn test_undo_outflow_saturates() {
+        let mut rate_limit = rate_limit();
+
+        assert!(rate_limit.try_add_outflow(timestamp(2), Amount::from(30)));
+        rate_limit.undo_outflow(Amount::from(20));
+        assert_eq!(rate_limit.flow.outflow, Amount::from(10));
+
+        rate_limit.undo_outflow(Amount::from(20));
+        assert_eq!(rate_limit.flow.outflow, Amount::from(0));
+    }
+
+    #[test]
+    fn test_quota_validate_basic() {
+        let mut quota = rate_limit().quota;
+        assert!(quota.validate_basic().is_ok());
+
+        quota.window = Duration::ZERO;
+        assert!(quota.validate_basic().is_err());
+    }
+}
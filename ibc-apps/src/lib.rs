@@ -27,3 +27,83 @@ pub mod nft_transfer {
     #[cfg(feature = "nft-transfer")]
     pub use ibc_app_nft_transfer::*;
 }
+
+/// Re-exports the implementation of the IBC [fee
+/// middleware](https://github.com/cosmos/ibc/blob/main/spec/app/ics-029-fee-payment/README.md)
+/// (ICS-29) logic.
+pub mod fee {
+    #[doc(inline)]
+    pub use ibc_app_fee::*;
+}
+
+/// Re-exports the implementation of the IBC [packet forward
+/// middleware](https://github.com/cosmos/ibc-apps/tree/main/middleware/packet-forward-middleware)
+/// logic.
+pub mod packet_forward {
+    #[doc(inline)]
+    #[cfg(feature = "packet-forward")]
+    pub use ibc_app_packet_forward::*;
+}
+
+/// Re-exports the implementation of the IBC rate limit middleware logic, which
+/// bounds the ICS-20 inflow and outflow of the denominations over the channels.
+pub mod rate_limit {
+    #[doc(inline)]
+    #[cfg(feature = "rate-limit")]
+    pub use ibc_app_rate_limit::*;
+}
+
+/// Re-exports the implementation of the IBC [Interchain
+/// Accounts](https://github.com/cosmos/ibc/blob/main/spec/app/ics-027-interchain-accounts/README.md)
+/// (ICS-27) application logic.
+pub mod interchain_accounts {
+    #[doc(inline)]
+    #[cfg(feature = "interchain-accounts")]
+    pub use ibc_app_interchain_accounts::*;
+}
+
+/// Re-exports the implementation of the IBC [Cross-Chain
+/// Queries](https://github.com/cosmos/ibc/blob/main/spec/app/ics-031-crosschain-queries/README.md)
+/// (ICS-31) application logic.
+pub mod cross_chain_queries {
+    #[doc(inline)]
+    #[cfg(feature = "cross-chain-queries")]
+    pub use ibc_app_cross_chain_queries::*;
+}
+
+/// Re-exports the packet data and the application logic scaffolding of the
+/// IBC [Cross-Chain
+/// Validation](https://github.com/cosmos/ibc/blob/main/spec/app/ics-028-cross-chain-validation/README.md)
+/// (ICS-28).
+pub mod cross_chain_validation {
+    #[doc(inline)]
+    #[cfg(feature = "cross-chain-validation")]
+    pub use ibc_app_cross_chain_validation::*;
+}
+
+/// Re-exports the implementation of the host side of the asynchronous
+/// [Interchain
+/// Queries](https://github.com/cosmos/ibc-apps/tree/main/modules/async-icq)
+/// application.
+pub mod async_icq {
+    #[doc(inline)]
+    #[cfg(feature = "async-icq")]
+    pub use ibc_app_async_icq::*;
+}
+
+/// Re-exports the implementation of the IBC wasm hooks middleware logic,
+/// which executes the contracts called by the memo of the received ICS-20
+/// transfers, as the [ibc-hooks](https://github.com/osmosis-labs/osmosis/tree/main/x/ibc-hooks)
+/// module of Osmosis does.
+pub mod wasm_hooks {
+    #[doc(inline)]
+    #[cfg(feature = "wasm-hooks")]
+    pub use ibc_app_wasm_hooks::*;
+}
+
+/// Re-exports the scaffolding of custom IBC applications.
+pub mod template {
+    #[doc(inline)]
+    #[cfg(feature = "template")]
+    pub use ibc_app_template::*;
+}
@@ -0,0 +1,116 @@
+//! Defines the trait of the custom IBC applications, and their
+//! configuration.
+use core::fmt::{Debug, Display};
+
+use ibc_core::channel::types::acknowledgement::AcknowledgementStatus;
+use ibc_core::channel::types::channel::Order;
+use ibc_core::channel::types::packet::Packet;
+use ibc_core::channel::types::Version;
+use ibc_core::host::types::identifiers::{ChannelId, PortId};
+use ibc_core::primitives::prelude::*;
+use ibc_core::primitives::Signer;
+use ibc_core::router::types::module::{ModuleExtras, ModuleId};
+
+/// The configuration of an application, against which the channel
+/// handshakes on its port are validated.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AppConfig {
+    /// The identifier of the module of the application in the router.
+    pub module_id: ModuleId,
+    /// The port the application is bound to.
+    pub port_id: PortId,
+    /// The version of the application, which both ends of its channels must
+    /// agree on.
+    pub version: Version,
+    /// The ordering of the channels of the application.
+    pub order: Order,
+    /// Whether the channels of the application may be closed by its users.
+    pub closable: bool,
+}
+
+impl AppConfig {
+    /// Returns the configuration of an application with unordered channels,
+    /// which its users may close.
+    pub fn new(module_id: ModuleId, port_id: PortId, version: Version) -> Self {
+        Self {
+            module_id,
+            port_id,
+            version,
+            order: Order::Unordered,
+            closable: true,
+        }
+    }
+
+    /// Returns the configuration with the given ordering of the channels.
+    pub fn with_order(self, order: Order) -> Self {
+        Self { order, ..self }
+    }
+
+    /// Returns the configuration with channels which may be closed by their
+    /// users, or not.
+    pub fn with_closable(self, closable: bool) -> Self {
+        Self { closable, ..self }
+    }
+}
+
+/// The packet handlers of a custom IBC application, from which
+/// [`AppModule`](crate::module::AppModule) implements its module callbacks.
+///
+/// Only the packets received by the application must be handled; the other
+/// handlers default to doing nothing.
+pub trait Application: Debug {
+    /// The error the handlers of the application fail with.
+    type Error: Display;
+
+    /// Returns the configuration of the application.
+    fn config(&self) -> &AppConfig;
+
+    /// Called once a channel of the application is open, on both ends of the
+    /// channel.
+    fn on_channel_open_execute(
+        &mut self,
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+        _version: &Version,
+    ) -> Result<ModuleExtras, Self::Error> {
+        Ok(ModuleExtras::empty())
+    }
+
+    /// Called once a channel of the application is closed, on both ends of
+    /// the channel.
+    fn on_channel_close_execute(
+        &mut self,
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+    ) -> Result<ModuleExtras, Self::Error> {
+        Ok(ModuleExtras::empty())
+    }
+
+    /// Handles the given received packet, returning the result of its
+    /// successful acknowledgement. The packet is acknowledged with the error
+    /// the handler fails with otherwise.
+    fn on_recv_packet_execute(
+        &mut self,
+        packet: &Packet,
+        relayer: &Signer,
+    ) -> Result<(ModuleExtras, Vec<u8>), Self::Error>;
+
+    /// Called with the acknowledgement of a packet the application sent.
+    fn on_acknowledgement_packet_execute(
+        &mut self,
+        _packet: &Packet,
+        _acknowledgement: AcknowledgementStatus,
+        _relayer: &Signer,
+    ) -> Result<ModuleExtras, Self::Error> {
+        Ok(ModuleExtras::empty())
+    }
+
+    /// Called when a packet the application sent timed out.
+    fn on_timeout_packet_execute(
+        &mut self,
+        _packet: &Packet,
+        _relayer: &Signer,
+    ) -> Result<ModuleExtras, Self::Error> {
+        Ok(ModuleExtras::empty())
+    }
+}
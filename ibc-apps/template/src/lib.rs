@@ -0,0 +1,33 @@
+//! Scaffolding of custom IBC applications, which implements the module
+//! callbacks of an application from its packet handlers.
+//!
+//! An application implements [`Application`](app::Application), whose
+//! [`AppConfig`](app::AppConfig) sets its port, its version and the ordering
+//! of its channels, and is routed by the host as an
+//! [`AppModule`](module::AppModule). The module validates the channel
+//! handshakes against the configuration, negotiates the version of the
+//! application, and wraps the results of the packet handlers in the standard
+//! acknowledgement envelope, so that the application only implements the
+//! handlers of its own packet protocol.
+//!
+//! The modules are bound to their ports at runtime with
+//! [`bind_application`](module::bind_application).
+#![no_std]
+#![forbid(unsafe_code)]
+#![cfg_attr(not(test), deny(clippy::unwrap_used))]
+#![cfg_attr(not(test), deny(clippy::disallowed_methods, clippy::disallowed_types))]
+#![deny(
+    warnings,
+    trivial_casts,
+    trivial_numeric_casts,
+    unused_import_braces,
+    unused_qualifications,
+    rust_2018_idioms
+)]
+#![allow(clippy::result_large_err)]
+
+#[cfg(any(test, feature = "std"))]
+extern crate std;
+
+pub mod app;
+pub mod module;
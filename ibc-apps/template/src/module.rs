@@ -0,0 +1,487 @@
+//! Implements the module callbacks of the custom IBC applications, and their
+//! binding to the router of the host.
+use core::fmt::Display;
+
+use ibc_core::channel::types::acknowledgement::{
+    Acknowledgement, AcknowledgementStatus, StatusValue,
+};
+use ibc_core::channel::types::channel::{Counterparty, Order};
+use ibc_core::channel::types::error::{ChannelError, PacketError};
+use ibc_core::channel::types::packet::Packet;
+use ibc_core::channel::types::Version;
+use ibc_core::host::types::identifiers::{ChannelId, ConnectionId, PortId};
+use ibc_core::primitives::prelude::*;
+use ibc_core::primitives::Signer;
+use ibc_core::router::module::Module;
+use ibc_core::router::router::PortRouter;
+use ibc_core::router::types::error::RouterError;
+use ibc_core::router::types::module::ModuleExtras;
+use ibc_core::router::types::port::PortCapability;
+
+use crate::app::{AppConfig, Application};
+
+/// The module of a custom IBC application, to be routed by the host.
+#[derive(Debug)]
+pub struct AppModule<A> {
+    app: A,
+}
+
+impl<A: Application> AppModule<A> {
+    pub fn new(app: A) -> Self {
+        Self { app }
+    }
+
+    pub fn app(&self) -> &A {
+        &self.app
+    }
+
+    pub fn app_mut(&mut self) -> &mut A {
+        &mut self.app
+    }
+
+    pub fn into_inner(self) -> A {
+        self.app
+    }
+
+    fn config(&self) -> &AppConfig {
+        self.app.config()
+    }
+
+    /// Returns an error unless the given channel end is on the port of the
+    /// application, with the ordering of its channels.
+    fn validate_channel(&self, order: Order, port_id: &PortId) -> Result<(), ChannelError> {
+        let config = self.config();
+
+        if port_id != &config.port_id {
+            return Err(ChannelError::AppModule {
+                description: format!("expected port_id {}, got {port_id}", config.port_id),
+            });
+        }
+
+        if order != config.order {
+            return Err(ChannelError::InvalidOrderType {
+                expected: config.order.to_string(),
+                actual: order.to_string(),
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Returns the version of a channel initiated with the given version,
+    /// which defaults to the version of the application if empty.
+    fn init_version(&self, version: &Version) -> Result<Version, ChannelError> {
+        if version.is_empty() {
+            return Ok(self.config().version.clone());
+        }
+
+        version.verify_is_expected(self.config().version.clone())?;
+
+        Ok(version.clone())
+    }
+}
+
+/// Binds the port of the given application to its module, which the host
+/// routes under the module identifier of the application.
+pub fn bind_application<R: PortRouter>(
+    router: &mut R,
+    config: &AppConfig,
+) -> Result<PortCapability, RouterError> {
+    router.bind_port(config.port_id.clone(), config.module_id.clone())
+}
+
+fn channel_error(err: impl Display) -> ChannelError {
+    ChannelError::AppModule {
+        description: err.to_string(),
+    }
+}
+
+fn packet_error(err: impl Display) -> PacketError {
+    PacketError::AppModule {
+        description: err.to_string(),
+    }
+}
+
+fn error_status(err: impl Display) -> AcknowledgementStatus {
+    let error = StatusValue::new(format!("application error: {err}"))
+        .expect("the error message is not empty");
+
+    AcknowledgementStatus::error(error)
+}
+
+impl<A: Application> Module for AppModule<A> {
+    fn on_chan_open_init_validate(
+        &self,
+        order: Order,
+        _connection_hops: &[ConnectionId],
+        port_id: &PortId,
+        _channel_id: &ChannelId,
+        _counterparty: &Counterparty,
+        version: &Version,
+    ) -> Result<Version, ChannelError> {
+        self.validate_channel(order, port_id)?;
+
+        self.init_version(version)
+    }
+
+    fn on_chan_open_init_execute(
+        &mut self,
+        _order: Order,
+        _connection_hops: &[ConnectionId],
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+        _counterparty: &Counterparty,
+        version: &Version,
+    ) -> Result<(ModuleExtras, Version), ChannelError> {
+        Ok((ModuleExtras::empty(), self.init_version(version)?))
+    }
+
+    fn on_chan_open_try_validate(
+        &self,
+        order: Order,
+        _connection_hops: &[ConnectionId],
+        port_id: &PortId,
+        _channel_id: &ChannelId,
+        _counterparty: &Counterparty,
+        counterparty_version: &Version,
+    ) -> Result<Version, ChannelError> {
+        self.validate_channel(order, port_id)?;
+
+        counterparty_version.verify_is_expected(self.config().version.clone())?;
+
+        Ok(self.config().version.clone())
+    }
+
+    fn on_chan_open_try_execute(
+        &mut self,
+        _order: Order,
+        _connection_hops: &[ConnectionId],
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+        _counterparty: &Counterparty,
+        _counterparty_version: &Version,
+    ) -> Result<(ModuleExtras, Version), ChannelError> {
+        Ok((ModuleExtras::empty(), self.config().version.clone()))
+    }
+
+    fn on_chan_open_ack_validate(
+        &self,
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+        counterparty_version: &Version,
+    ) -> Result<(), ChannelError> {
+        counterparty_version.verify_is_expected(self.config().version.clone())
+    }
+
+    fn on_chan_open_ack_execute(
+        &mut self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        counterparty_version: &Version,
+    ) -> Result<ModuleExtras, ChannelError> {
+        self.app
+            .on_channel_open_execute(port_id, channel_id, counterparty_version)
+            .map_err(channel_error)
+    }
+
+    fn on_chan_open_confirm_execute(
+        &mut self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<ModuleExtras, ChannelError> {
+        let version = self.config().version.clone();
+
+        self.app
+            .on_channel_open_execute(port_id, channel_id, &version)
+            .map_err(channel_error)
+    }
+
+    fn on_chan_close_init_validate(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<(), ChannelError> {
+        if !self.config().closable {
+            return Err(ChannelError::UnauthorizedChannelClose {
+                port_id: port_id.clone(),
+                channel_id: channel_id.clone(),
+            });
+        }
+
+        Ok(())
+    }
+
+    fn on_chan_close_init_execute(
+        &mut self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<ModuleExtras, ChannelError> {
+        self.app
+            .on_channel_close_execute(port_id, channel_id)
+            .map_err(channel_error)
+    }
+
+    fn on_chan_close_confirm_execute(
+        &mut self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<ModuleExtras, ChannelError> {
+        self.app
+            .on_channel_close_execute(port_id, channel_id)
+            .map_err(channel_error)
+    }
+
+    fn on_recv_packet_execute(
+        &mut self,
+        packet: &Packet,
+        relayer: &Signer,
+    ) -> (ModuleExtras, Option<Acknowledgement>) {
+        let (extras, ack) = match self.app.on_recv_packet_execute(packet, relayer) {
+            // The empty results are rejected, as ibc-go does.
+            Ok((extras, result)) => match StatusValue::from_result(result) {
+                Ok(result) => (extras, AcknowledgementStatus::success(result)),
+                Err(err) => (ModuleExtras::empty(), error_status(err)),
+            },
+            Err(err) => (ModuleExtras::empty(), error_status(err)),
+        };
+
+        (extras, Some(ack.into()))
+    }
+
+    fn on_acknowledgement_packet_validate(
+        &self,
+        _packet: &Packet,
+        acknowledgement: &Acknowledgement,
+        _relayer: &Signer,
+    ) -> Result<(), PacketError> {
+        AcknowledgementStatus::try_from(acknowledgement)?;
+
+        Ok(())
+    }
+
+    fn on_acknowledgement_packet_execute(
+        &mut self,
+        packet: &Packet,
+        acknowledgement: &Acknowledgement,
+        relayer: &Signer,
+    ) -> (ModuleExtras, Result<(), PacketError>) {
+        let result = AcknowledgementStatus::try_from(acknowledgement).and_then(|ack| {
+            self.app
+                .on_acknowledgement_packet_execute(packet, ack, relayer)
+                .map_err(packet_error)
+        });
+
+        match result {
+            Ok(extras) => (extras, Ok(())),
+            Err(err) => (ModuleExtras::empty(), Err(err)),
+        }
+    }
+
+    fn on_timeout_packet_validate(
+        &self,
+        _packet: &Packet,
+        _relayer: &Signer,
+    ) -> Result<(), PacketError> {
+        Ok(())
+    }
+
+    fn on_timeout_packet_execute(
+        &mut self,
+        packet: &Packet,
+        relayer: &Signer,
+    ) -> (ModuleExtras, Result<(), PacketError>) {
+        match self.app.on_timeout_packet_execute(packet, relayer) {
+            Ok(extras) => (extras, Ok(())),
+            Err(err) => (ModuleExtras::empty(), Err(packet_error(err))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ibc_core::channel::types::timeout::TimeoutHeight;
+    use ibc_core::primitives::Timestamp;
+    use ibc_core::router::types::module::ModuleId;
+
+    use super::*;
+
+    /// An application which echoes the data of the packets it receives,
+    /// unless empty.
+    #[derive(Debug)]
+    struct EchoApp {
+        config: AppConfig,
+        open_channels: Vec<ChannelId>,
+        acknowledgements: Vec<AcknowledgementStatus>,
+    }
+
+    impl Application for EchoApp {
+        type Error = &'static str;
+
+        fn config(&self) -> &AppConfig {
+            &self.config
+        }
+
+        fn on_channel_open_execute(
+            &mut self,
+            _port_id: &PortId,
+            channel_id: &ChannelId,
+            _version: &Version,
+        ) -> Result<ModuleExtras, Self::Error> {
+            self.open_channels.push(channel_id.clone());
+
+            Ok(ModuleExtras::empty())
+        }
+
+        fn on_recv_packet_execute(
+            &mut self,
+            packet: &Packet,
+            _relayer: &Signer,
+        ) -> Result<(ModuleExtras, Vec<u8>), Self::Error> {
+            if packet.data.is_empty() {
+                return Err("empty packet");
+            }
+
+            Ok((ModuleExtras::empty(), packet.data.clone()))
+        }
+
+        fn on_acknowledgement_packet_execute(
+            &mut self,
+            _packet: &Packet,
+            acknowledgement: AcknowledgementStatus,
+            _relayer: &Signer,
+        ) -> Result<ModuleExtras, Self::Error> {
+            self.acknowledgements.push(acknowledgement);
+
+            Ok(ModuleExtras::empty())
+        }
+    }
+
+    fn echo_module(config: AppConfig) -> AppModule<EchoApp> {
+        AppModule::new(EchoApp {
+            config,
+            open_channels: Vec::new(),
+            acknowledgements: Vec::new(),
+        })
+    }
+
+    fn echo_config() -> AppConfig {
+        AppConfig::new(
+            ModuleId::new("echo".to_string()),
+            PortId::new("echo".to_string()).unwrap(),
+            Version::new("echo-1".to_string()),
+        )
+    }
+
+    fn dummy_packet(data: Vec<u8>) -> Packet {
+        Packet {
+            seq_on_a: 1.into(),
+            port_id_on_a: echo_config().port_id,
+            chan_id_on_a: ChannelId::new(0),
+            port_id_on_b: echo_config().port_id,
+            chan_id_on_b: ChannelId::new(1),
+            data,
+            timeout_height_on_b: TimeoutHeight::Never,
+            timeout_timestamp_on_b: Timestamp::none(),
+        }
+    }
+
+    #[test]
+    fn test_version_negotiation() {
+        let module = echo_module(echo_config());
+        let port_id = echo_config().port_id;
+        let counterparty = Counterparty::new(port_id.clone(), None);
+        let version = echo_config().version;
+
+        let init = |order, port_id: &PortId, version: &Version| {
+            module.on_chan_open_init_validate(
+                order,
+                &[ConnectionId::zero()],
+                port_id,
+                &ChannelId::new(0),
+                &counterparty,
+                version,
+            )
+        };
+
+        assert_eq!(
+            init(Order::Unordered, &port_id, &Version::empty()).unwrap(),
+            version
+        );
+        assert_eq!(init(Order::Unordered, &port_id, &version).unwrap(), version);
+        assert!(init(
+            Order::Unordered,
+            &port_id,
+            &Version::new("echo-2".to_string())
+        )
+        .is_err());
+        assert!(init(Order::Ordered, &port_id, &version).is_err());
+        assert!(init(Order::Unordered, &PortId::transfer(), &version).is_err());
+
+        assert!(module
+            .on_chan_open_try_validate(
+                Order::Unordered,
+                &[ConnectionId::zero()],
+                &port_id,
+                &ChannelId::new(0),
+                &counterparty,
+                &Version::empty(),
+            )
+            .is_err());
+        assert!(module
+            .on_chan_open_ack_validate(&port_id, &ChannelId::new(0), &version)
+            .is_ok());
+    }
+
+    #[test]
+    fn test_channel_lifecycle() {
+        let mut module = echo_module(echo_config().with_closable(false));
+        let port_id = echo_config().port_id;
+
+        module
+            .on_chan_open_confirm_execute(&port_id, &ChannelId::new(1))
+            .unwrap();
+        assert_eq!(module.app().open_channels, vec![ChannelId::new(1)]);
+
+        assert!(matches!(
+            module.on_chan_close_init_validate(&port_id, &ChannelId::new(1)),
+            Err(ChannelError::UnauthorizedChannelClose { .. })
+        ));
+    }
+
+    #[test]
+    fn test_packet_acknowledgements() {
+        let mut module = echo_module(echo_config());
+        let relayer: Signer = "relayer".to_string().into();
+
+        let (_, ack) = module.on_recv_packet_execute(&dummy_packet(vec![1, 2, 3]), &relayer);
+        let ack = ack.unwrap();
+        assert_eq!(
+            AcknowledgementStatus::try_from(&ack).unwrap(),
+            AcknowledgementStatus::success(StatusValue::from_result([1, 2, 3]).unwrap())
+        );
+
+        let (_, error_ack) = module.on_recv_packet_execute(&dummy_packet(Vec::new()), &relayer);
+        let error_ack = error_ack.unwrap();
+        assert!(!AcknowledgementStatus::try_from(&error_ack)
+            .unwrap()
+            .is_successful());
+
+        let packet = dummy_packet(vec![1, 2, 3]);
+        assert!(module
+            .on_acknowledgement_packet_validate(&packet, &ack, &relayer)
+            .is_ok());
+        let (_, result) = module.on_acknowledgement_packet_execute(&packet, &ack, &relayer);
+        assert!(result.is_ok());
+        assert_eq!(
+            module.app().acknowledgements,
+            vec![AcknowledgementStatus::success(
+                StatusValue::from_result([1, 2, 3]).unwrap()
+            )]
+        );
+
+        let invalid = Acknowledgement::try_from(vec![1u8]).unwrap();
+        assert!(module
+            .on_acknowledgement_packet_validate(&packet, &invalid, &relayer)
+            .is_err());
+    }
+}
@@ -0,0 +1,93 @@
+//! Defines the result of the acknowledgements of the transfers whose contract
+//! call was executed, which carries the result of the contract along with the
+//! acknowledgement of the token transfer module.
+//!
+//! The result is encoded in JSON as the ibc-hooks module of Osmosis does,
+//! both fields being base64 encoded:
+//!
+//! ```json
+//! { "contract_result": "eyJhbW91bnQiOiIxMCJ9", "ibc_ack": "eyJyZXN1bHQiOiJBUT09In0=" }
+//! ```
+use base64::prelude::BASE64_STANDARD;
+use base64::Engine;
+use ibc_core::channel::types::acknowledgement::{
+    Acknowledgement, AcknowledgementStatus, StatusValue,
+};
+use ibc_core::primitives::prelude::*;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// The result of the acknowledgement of a transfer whose contract call was
+/// executed.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ContractAck {
+    /// The data the contract returned.
+    #[serde(
+        serialize_with = "serialize_base64",
+        deserialize_with = "deserialize_base64"
+    )]
+    pub contract_result: Vec<u8>,
+    /// The acknowledgement of the token transfer module.
+    #[serde(
+        serialize_with = "serialize_base64",
+        deserialize_with = "deserialize_base64"
+    )]
+    pub ibc_ack: Vec<u8>,
+}
+
+impl ContractAck {
+    /// Decodes the contract acknowledgement from the result of a successful
+    /// acknowledgement, if it carries one.
+    pub fn from_result(result: &[u8]) -> Option<Self> {
+        serde_json::from_slice(result).ok()
+    }
+}
+
+impl From<ContractAck> for Acknowledgement {
+    fn from(ack: ContractAck) -> Self {
+        let result =
+            serde_json::to_vec(&ack).expect("ContractAck's infallible Serialize impl failed");
+
+        let result = StatusValue::from_result(result).expect("the JSON encoding is not empty");
+
+        AcknowledgementStatus::success(result).into()
+    }
+}
+
+fn serialize_base64<S: Serializer>(bytes: &[u8], serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_str(&BASE64_STANDARD.encode(bytes))
+}
+
+fn deserialize_base64<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<u8>, D::Error> {
+    let encoded = String::deserialize(deserializer)?;
+
+    BASE64_STANDARD
+        .decode(encoded)
+        .map_err(serde::de::Error::custom)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_contract_ack_roundtrip() {
+        let contract_ack = ContractAck {
+            contract_result: br#"{"amount":"10"}"#.to_vec(),
+            ibc_ack: br#"{"result":"AQ=="}"#.to_vec(),
+        };
+
+        let ack: Acknowledgement = contract_ack.clone().into();
+
+        let AcknowledgementStatus::Success(result) = AcknowledgementStatus::try_from(&ack).unwrap()
+        else {
+            panic!("the contract acknowledgement is successful");
+        };
+        let result = result.decoded_result().unwrap();
+
+        assert_eq!(
+            result,
+            br#"{"contract_result":"eyJhbW91bnQiOiIxMCJ9","ibc_ack":"eyJyZXN1bHQiOiJBUT09In0="}"#
+        );
+        assert_eq!(ContractAck::from_result(&result), Some(contract_ack));
+    }
+}
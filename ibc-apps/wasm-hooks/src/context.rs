@@ -0,0 +1,40 @@
+//! Defines the context trait the host implements to execute contracts
+use ibc_app_transfer::types::PrefixedCoin;
+use ibc_core::host::types::identifiers::ChannelId;
+use ibc_core::primitives::prelude::*;
+use ibc_core::primitives::Signer;
+
+use crate::error::WasmHooksError;
+
+/// Methods required to execute the contracts called by the received
+/// transfers, to be implemented by the host.
+pub trait ContractExecutor {
+    /// Returns the account which receives the tokens of the transfers from
+    /// the given channel and original sender, and executes the contracts
+    /// they call with them.
+    ///
+    /// The account should not be controlled by anyone, e.g. by deriving it
+    /// from the hash of the channel and the sender, as the ibc-hooks module
+    /// of Osmosis does, so that the contracts do not trust the original
+    /// sender as if it were an account of the host.
+    fn intermediate_sender(
+        &self,
+        chan_id_on_b: &ChannelId,
+        original_sender: &Signer,
+    ) -> Result<Signer, WasmHooksError>;
+
+    /// Executes the given contract with the given JSON message on behalf of
+    /// the given sender, which sends it the given funds, and returns the data
+    /// the contract returned.
+    ///
+    /// The execution must be atomic: if it fails, none of its state changes
+    /// are applied, and the funds stay with the sender, from which the
+    /// middleware refunds them.
+    fn execute_contract(
+        &mut self,
+        sender: &Signer,
+        contract: &Signer,
+        msg: &[u8],
+        funds: &[PrefixedCoin],
+    ) -> Result<Vec<u8>, WasmHooksError>;
+}
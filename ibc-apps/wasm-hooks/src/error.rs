@@ -0,0 +1,58 @@
+//! Defines the wasm hooks middleware error type
+use displaydoc::Display;
+use ibc_app_transfer::types::error::TokenTransferError;
+use ibc_core::channel::types::acknowledgement::StatusValue;
+use ibc_core::channel::types::error::PacketError;
+use ibc_core::handler::types::error::ContextError;
+use ibc_core::primitives::prelude::*;
+use ibc_core::primitives::Signer;
+
+#[derive(Display, Debug)]
+pub enum WasmHooksError {
+    /// context error: `{0}`
+    ContextError(ContextError),
+    /// token transfer error: `{0}`
+    TokenTransfer(TokenTransferError),
+    /// invalid wasm metadata: `{reason}`
+    InvalidMetadata { reason: String },
+    /// receiver `{receiver}` of the transfer is not the contract `{contract}` it calls
+    ReceiverMismatch { receiver: Signer, contract: Signer },
+    /// execution of contract `{contract}` failed: `{reason}`
+    ContractExecution { contract: Signer, reason: String },
+}
+
+impl core::error::Error for WasmHooksError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match &self {
+            Self::ContextError(e) => Some(e),
+            Self::TokenTransfer(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<ContextError> for WasmHooksError {
+    fn from(err: ContextError) -> WasmHooksError {
+        Self::ContextError(err)
+    }
+}
+
+impl From<TokenTransferError> for WasmHooksError {
+    fn from(err: TokenTransferError) -> WasmHooksError {
+        Self::TokenTransfer(err)
+    }
+}
+
+impl From<WasmHooksError> for PacketError {
+    fn from(err: WasmHooksError) -> Self {
+        PacketError::AppModule {
+            description: err.to_string(),
+        }
+    }
+}
+
+impl From<WasmHooksError> for StatusValue {
+    fn from(err: WasmHooksError) -> Self {
+        StatusValue::new(err.to_string()).expect("error message must not be empty")
+    }
+}
@@ -0,0 +1,31 @@
+//! Implementation of the IBC wasm hooks middleware logic, which executes the
+//! contracts called by the memo of the received ICS-20 transfers, with the
+//! received tokens as their funds, as the
+//! [ibc-hooks](https://github.com/osmosis-labs/osmosis/tree/main/x/ibc-hooks)
+//! module of Osmosis does.
+//!
+//! The contracts are executed by the host through its
+//! [`ContractExecutor`](context::ContractExecutor).
+#![no_std]
+#![forbid(unsafe_code)]
+#![cfg_attr(not(test), deny(clippy::unwrap_used))]
+#![cfg_attr(not(test), deny(clippy::disallowed_methods, clippy::disallowed_types))]
+#![deny(
+    warnings,
+    trivial_casts,
+    trivial_numeric_casts,
+    unused_import_braces,
+    unused_qualifications,
+    rust_2018_idioms
+)]
+#![allow(clippy::result_large_err)]
+
+#[cfg(any(test, feature = "std"))]
+extern crate std;
+
+pub mod acknowledgement;
+pub mod context;
+pub mod error;
+mod json;
+pub mod metadata;
+pub mod middleware;
@@ -0,0 +1,116 @@
+//! Defines the wasm metadata, which instructs the middleware to execute a
+//! contract with the tokens of a transfer.
+//!
+//! The wasm metadata is carried by the memo of the transfer, using the format
+//! of the ibc-hooks module of Osmosis:
+//!
+//! ```json
+//! {
+//!   "wasm": {
+//!     "contract": "osmo1contract...",
+//!     "msg": { "swap": { "min_amount": "100" } }
+//!   }
+//! }
+//! ```
+//!
+//! The `msg` is the JSON object the contract is executed with, and the
+//! `contract` must be the receiver of the transfer.
+use ibc_app_transfer::memo::{memo_value, WASM_MEMO_KEY};
+use ibc_app_transfer::types::Memo;
+use ibc_core::primitives::prelude::*;
+use ibc_core::primitives::Signer;
+use serde::Deserialize;
+
+use crate::error::WasmHooksError;
+use crate::json::JsonValue;
+
+/// The instructions to execute a contract with the tokens of a transfer.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct WasmMetadata {
+    /// The contract to execute.
+    pub contract: Signer,
+    /// The JSON encoding of the message the contract is executed with.
+    ///
+    /// The fields of its objects are encoded in lexicographic order,
+    /// whatever their order in the memo.
+    pub msg: Vec<u8>,
+}
+
+impl WasmMetadata {
+    /// Parses the wasm metadata from the memo of a transfer.
+    ///
+    /// Returns `None` if the memo does not carry a contract call, in which
+    /// case no contract is executed, and an error if it carries an invalid
+    /// one.
+    pub fn from_memo(memo: &Memo) -> Result<Option<Self>, WasmHooksError> {
+        let Some(raw) = memo_value::<RawWasmMetadata>(memo, WASM_MEMO_KEY).map_err(|e| {
+            WasmHooksError::InvalidMetadata {
+                reason: e.to_string(),
+            }
+        })?
+        else {
+            return Ok(None);
+        };
+
+        if raw.contract.is_empty() {
+            return Err(WasmHooksError::InvalidMetadata {
+                reason: "empty contract".to_string(),
+            });
+        }
+
+        let msg = serde_json::to_vec(&raw.msg).map_err(|e| WasmHooksError::InvalidMetadata {
+            reason: e.to_string(),
+        })?;
+
+        Ok(Some(Self {
+            contract: raw.contract.into(),
+            msg,
+        }))
+    }
+}
+
+#[derive(Deserialize)]
+struct RawWasmMetadata {
+    contract: String,
+    msg: BTreeMap<String, JsonValue>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_memo() {
+        let memo = Memo::from(
+            r#"{"wasm":{"contract":"osmo1contract","msg":{"swap":{"route":[1,2],"min":"10"}}}}"#,
+        );
+
+        assert_eq!(
+            WasmMetadata::from_memo(&memo).unwrap(),
+            Some(WasmMetadata {
+                contract: "osmo1contract".to_string().into(),
+                msg: br#"{"swap":{"min":"10","route":[1,2]}}"#.to_vec(),
+            })
+        );
+
+        // Memos without a contract call are not handled.
+        for memo in ["", "hello", r#"{"forward":{"receiver":"cosmos1"}}"#] {
+            assert_eq!(WasmMetadata::from_memo(&memo.into()).unwrap(), None);
+        }
+    }
+
+    #[test]
+    fn test_invalid_metadata() {
+        for memo in [
+            r#"{"wasm":{"contract":"osmo1contract"}}"#,
+            r#"{"wasm":{"contract":"","msg":{}}}"#,
+            r#"{"wasm":{"contract":"osmo1contract","msg":"swap"}}"#,
+            r#"{"wasm":"osmo1contract"}"#,
+        ] {
+            assert!(matches!(
+                WasmMetadata::from_memo(&memo.into()),
+                Err(WasmHooksError::InvalidMetadata { .. })
+            ));
+        }
+    }
+}
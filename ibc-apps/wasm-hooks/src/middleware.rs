@@ -0,0 +1,234 @@
+//! Defines the wasm hooks middleware, which wraps the ICS-20 module to execute
+//! the contracts called by the memo of the transfers it receives.
+//!
+//! It is stacked on top of the token transfer module with
+//! [`ModuleStack`](ibc_core::router::middleware::ModuleStack).
+use core::fmt::Debug;
+
+use ibc_app_transfer::context::TokenTransferExecutionContext;
+use ibc_app_transfer::decimals::to_host_coin;
+use ibc_app_transfer::forward::{
+    refund_forwarded_tokens_execute, refund_forwarded_tokens_validate, ForwardedTransfer,
+};
+use ibc_app_transfer::handler::{decode_packet_data, encode_packet_data, received_coin};
+use ibc_app_transfer::types::packet::PacketDataV2;
+use ibc_app_transfer::types::{Memo, PrefixedCoin};
+use ibc_core::channel::types::acknowledgement::{Acknowledgement, AcknowledgementStatus};
+use ibc_core::channel::types::packet::Packet;
+use ibc_core::host::types::path::ChannelEndPath;
+use ibc_core::host::ValidationContext;
+use ibc_core::primitives::prelude::*;
+use ibc_core::primitives::Signer;
+use ibc_core::router::middleware::Middleware;
+use ibc_core::router::module::Module;
+use ibc_core::router::types::module::ModuleExtras;
+
+use crate::acknowledgement::ContractAck;
+use crate::context::ContractExecutor;
+use crate::error::WasmHooksError;
+use crate::metadata::WasmMetadata;
+
+/// The wasm hooks middleware, stacked on top of the token transfer module.
+///
+/// When a received transfer carries a contract call in its memo, its
+/// receiver must be the called contract. The tokens are received by an
+/// intermediate account of the host instead, derived from the channel and the
+/// original sender, which then executes the contract with them as its funds.
+/// The received packet is acknowledged with the result of the contract along
+/// with the acknowledgement of the token transfer module. If the contract
+/// fails, the tokens are returned from the intermediate account to where they
+/// were received from, and the packet is acknowledged with the error, so that
+/// they are refunded to the original sender. Other transfers are passed
+/// through to the token transfer module.
+///
+/// The host context must refer to the same state as the context of the token
+/// transfer module below.
+#[derive(Debug)]
+pub struct WasmHooksMiddleware<C> {
+    ctx: C,
+}
+
+impl<C> WasmHooksMiddleware<C> {
+    pub fn new(ctx: C) -> Self {
+        Self { ctx }
+    }
+
+    pub fn ctx(&self) -> &C {
+        &self.ctx
+    }
+
+    pub fn ctx_mut(&mut self) -> &mut C {
+        &mut self.ctx
+    }
+}
+
+fn error_ack(err: WasmHooksError) -> Acknowledgement {
+    AcknowledgementStatus::error(err.into()).into()
+}
+
+/// The contract call of a received transfer, whose tokens the intermediate
+/// account receives.
+struct ContractCall {
+    metadata: WasmMetadata,
+    intermediate_sender: Signer,
+    /// The received tokens, as denominated on the host and in its precision.
+    funds: Vec<PrefixedCoin>,
+    /// The packet which carries the tokens to the intermediate account,
+    /// without the contract call.
+    intermediate_packet: Packet,
+}
+
+impl<C> WasmHooksMiddleware<C>
+where
+    C: ValidationContext + TokenTransferExecutionContext + ContractExecutor,
+{
+    /// Decodes the packet data of the given received packet, as encoded for
+    /// the version of its channel.
+    fn packet_data(&self, packet: &Packet) -> Result<PacketDataV2, WasmHooksError> {
+        let chan_end_path_on_b = ChannelEndPath::new(&packet.port_id_on_b, &packet.chan_id_on_b);
+        let chan_end_on_b = self.ctx.channel_end(&chan_end_path_on_b)?;
+
+        Ok(decode_packet_data(chan_end_on_b.version(), &packet.data)?)
+    }
+
+    /// Returns the contract call of the given transfer, if any.
+    fn contract_call(
+        &self,
+        packet: &Packet,
+        mut data: PacketDataV2,
+    ) -> Result<Option<ContractCall>, WasmHooksError> {
+        let Some(metadata) = WasmMetadata::from_memo(&data.memo)? else {
+            return Ok(None);
+        };
+
+        if data.receiver != metadata.contract {
+            return Err(WasmHooksError::ReceiverMismatch {
+                receiver: data.receiver,
+                contract: metadata.contract,
+            });
+        }
+
+        let intermediate_sender = self
+            .ctx
+            .intermediate_sender(&packet.chan_id_on_b, &data.sender)?;
+
+        let funds = data
+            .tokens
+            .iter()
+            .map(|token| {
+                to_host_coin(
+                    &self.ctx,
+                    &packet.port_id_on_b,
+                    &packet.chan_id_on_b,
+                    &received_coin(packet, token),
+                )
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let chan_end_path_on_b = ChannelEndPath::new(&packet.port_id_on_b, &packet.chan_id_on_b);
+        let chan_end_on_b = self.ctx.channel_end(&chan_end_path_on_b)?;
+
+        data.receiver = intermediate_sender.clone();
+        data.memo = Memo::from("");
+
+        let intermediate_packet = Packet {
+            data: encode_packet_data(chan_end_on_b.version(), data)?,
+            ..packet.clone()
+        };
+
+        Ok(Some(ContractCall {
+            metadata,
+            intermediate_sender,
+            funds,
+            intermediate_packet,
+        }))
+    }
+
+    /// Executes the given contract call, with the tokens the intermediate
+    /// account received, and returns them to where they were received from
+    /// if the contract fails.
+    fn execute(
+        &mut self,
+        packet: &Packet,
+        original_sender: &Signer,
+        call: &ContractCall,
+    ) -> Result<Vec<u8>, WasmHooksError> {
+        let result = self.ctx.execute_contract(
+            &call.intermediate_sender,
+            &call.metadata.contract,
+            &call.metadata.msg,
+            &call.funds,
+        );
+
+        if result.is_err() {
+            let transfer = ForwardedTransfer {
+                tokens: call.funds.clone(),
+                intermediate_receiver: call.intermediate_sender.clone(),
+                original_sender: original_sender.clone(),
+                memo: Memo::from(""),
+            };
+
+            refund_forwarded_tokens_validate(&self.ctx, packet, &transfer)?;
+            refund_forwarded_tokens_execute(&mut self.ctx, packet, &transfer)?;
+        }
+
+        result
+    }
+}
+
+impl<C> Middleware for WasmHooksMiddleware<C>
+where
+    C: ValidationContext + TokenTransferExecutionContext + ContractExecutor + Debug,
+{
+    fn on_recv_packet_execute(
+        &mut self,
+        next: &mut dyn Module,
+        packet: &Packet,
+        relayer: &Signer,
+    ) -> (ModuleExtras, Option<Acknowledgement>) {
+        let Ok(data) = self.packet_data(packet) else {
+            return next.on_recv_packet_execute(packet, relayer);
+        };
+
+        let original_sender = data.sender.clone();
+
+        let call = match self.contract_call(packet, data) {
+            Ok(Some(call)) => call,
+            Ok(None) => return next.on_recv_packet_execute(packet, relayer),
+            Err(err) => return (ModuleExtras::empty(), Some(error_ack(err))),
+        };
+
+        let (mut extras, ack) = next.on_recv_packet_execute(&call.intermediate_packet, relayer);
+
+        // The contract is executed only once the tokens were received, and
+        // the packets acknowledged asynchronously below are not.
+        let ibc_ack = match ack {
+            Some(ack)
+                if serde_json::from_slice::<AcknowledgementStatus>(ack.as_bytes())
+                    .map(|status| status.is_successful())
+                    .unwrap_or(false) =>
+            {
+                ack
+            }
+            ack => return (extras, ack),
+        };
+
+        let ack = match self.execute(packet, &original_sender, &call) {
+            Ok(contract_result) => {
+                extras.log.push(format!(
+                    "wasm hooks: {} executed contract {}",
+                    call.intermediate_sender, call.metadata.contract
+                ));
+
+                ContractAck {
+                    contract_result,
+                    ibc_ack: ibc_ack.into(),
+                }
+                .into()
+            }
+            Err(err) => error_ack(err),
+        };
+
+        (extras, Some(ack))
+    }
+}
@@ -0,0 +1,227 @@
+//! This module includes the implementations of the `ClientStateCommon`,
+//! `ClientStateValidation`, and `ClientStateExecution` traits for the
+//! attestor client state.
+//!
+//! As with the other clients, the logic behind each trait method is also
+//! exposed as a standalone function, so that hosts wrapping the attestor
+//! client can reuse it.
+
+mod common;
+mod execution;
+mod validation;
+
+pub use common::*;
+pub use execution::*;
+pub use validation::*;
+
+#[cfg(test)]
+mod tests {
+    use core::str::FromStr;
+
+    use ed25519_consensus::SigningKey;
+    use ibc_core_client::types::Height;
+    use ibc_core_commitment_types::commitment::CommitmentRoot;
+    use ibc_core_commitment_types::specs::ProofSpecs;
+    use ibc_core_host::types::identifiers::ChainId;
+    use ibc_primitives::proto::Any;
+    use ibc_primitives::Timestamp;
+
+    use super::*;
+    use crate::error::Error;
+    use crate::types::{
+        attestor_set, sign, signing_keys, Attestation, AttestorSet, ClientState, Header,
+        Misbehaviour, Quorum,
+    };
+
+    const TIMESTAMP: u64 = 1_700_000_000_000_000_000;
+
+    fn chain_id() -> ChainId {
+        ChainId::from_str("counterparty-0").expect("never fails")
+    }
+
+    fn height(revision_height: u64) -> Height {
+        Height::new(0, revision_height).expect("never fails")
+    }
+
+    fn dummy_client_state(keys: &[SigningKey]) -> ClientState {
+        ClientState::new(
+            chain_id(),
+            height(100),
+            attestor_set(1, keys),
+            Quorum::TWO_THIRDS,
+            ProofSpecs::cosmos(),
+        )
+        .expect("never fails")
+    }
+
+    /// Returns a header attesting to `root` at `revision_height`, signed by
+    /// `keys` as members of the attestor set `attestor_set_id`.
+    fn dummy_header(
+        keys: &[SigningKey],
+        attestor_set_id: u64,
+        revision_height: u64,
+        root: [u8; 32],
+        next_attestor_set: Option<AttestorSet>,
+    ) -> Header {
+        let attestation = Attestation {
+            chain_id: chain_id(),
+            attestor_set_id,
+            height: height(revision_height),
+            timestamp: Timestamp::from_nanoseconds(TIMESTAMP + revision_height)
+                .expect("never fails"),
+            root: CommitmentRoot::from_bytes(&root),
+            next_attestor_set,
+        };
+        let signatures = sign(keys, &attestation.sign_bytes());
+
+        Header {
+            attestation,
+            signatures,
+        }
+    }
+
+    #[test]
+    fn client_state_any_roundtrip() {
+        let client_state = dummy_client_state(&signing_keys());
+        let any: Any = client_state.clone().into();
+        assert_eq!(
+            ClientState::try_from(any).expect("never fails"),
+            client_state
+        );
+    }
+
+    #[test]
+    fn header_any_roundtrip() {
+        let keys = signing_keys();
+        let header = dummy_header(&keys[..3], 1, 110, [9; 32], Some(attestor_set(2, &keys)));
+        let any: Any = header.clone().into();
+        assert_eq!(Header::try_from(any).expect("never fails"), header);
+    }
+
+    #[test]
+    fn verify_valid_header() {
+        let keys = signing_keys();
+        let client_state = dummy_client_state(&keys);
+        let header = dummy_header(&keys[..3], 1, 110, [9; 32], None);
+
+        assert!(verify_client_message(&client_state, header.clone().into()).is_ok());
+
+        let consensus_state = verify_header(&client_state, &header).expect("never fails");
+        assert_eq!(consensus_state.root.as_bytes(), [9; 32]);
+        assert_eq!(consensus_state.timestamp.nanoseconds(), TIMESTAMP + 110);
+
+        // heights below the latest one can be attested to as well
+        let past = dummy_header(&keys[..3], 1, 90, [9; 32], None);
+        assert!(verify_header(&client_state, &past).is_ok());
+    }
+
+    #[test]
+    fn verify_header_rejects_invalid_attestations() {
+        let keys = signing_keys();
+        let client_state = dummy_client_state(&keys);
+
+        let header = dummy_header(&keys[..2], 1, 110, [9; 32], None);
+        assert!(matches!(
+            verify_header(&client_state, &header),
+            Err(Error::InsufficientSignedWeight { .. })
+        ));
+
+        let header = dummy_header(&keys[..3], 2, 110, [9; 32], None);
+        assert!(matches!(
+            verify_header(&client_state, &header),
+            Err(Error::UnknownAttestorSet { .. })
+        ));
+
+        let mut header = dummy_header(&keys[..3], 1, 110, [9; 32], None);
+        header.attestation.chain_id = ChainId::from_str("other-0").expect("never fails");
+        header.signatures = sign(&keys[..3], &header.attestation.sign_bytes());
+        assert!(matches!(
+            verify_header(&client_state, &header),
+            Err(Error::MismatchedChainId { .. })
+        ));
+
+        // signatures do not carry over to a different root
+        let mut header = dummy_header(&keys[..3], 1, 110, [9; 32], None);
+        header.attestation.root = CommitmentRoot::from_bytes(&[8; 32]);
+        assert!(matches!(
+            verify_header(&client_state, &header),
+            Err(Error::InvalidSignature { .. })
+        ));
+
+        let frozen = client_state.with_frozen_height(height(100));
+        let header = dummy_header(&keys[..3], 1, 110, [9; 32], None);
+        assert!(matches!(
+            verify_header(&frozen, &header),
+            Err(Error::FrozenClient)
+        ));
+    }
+
+    #[test]
+    fn verify_header_with_rotation() {
+        let keys = signing_keys();
+        let client_state = dummy_client_state(&keys[..3]);
+
+        let next = attestor_set(2, &keys[1..]);
+        let header = dummy_header(&keys[..3], 1, 110, [9; 32], Some(next.clone()));
+        assert!(verify_header(&client_state, &header).is_ok());
+
+        let skipped = attestor_set(3, &keys[1..]);
+        let header = dummy_header(&keys[..3], 1, 110, [9; 32], Some(skipped));
+        assert!(matches!(
+            verify_header(&client_state, &header),
+            Err(Error::InvalidNextAttestorSetId {
+                expected: 2,
+                actual: 3
+            })
+        ));
+
+        let header = dummy_header(&keys[..3], 1, 100, [9; 32], Some(next.clone()));
+        assert!(matches!(
+            verify_header(&client_state, &header),
+            Err(Error::StaleRotation { .. })
+        ));
+
+        // once rotated, only the next set is trusted
+        let mut rotated = client_state.clone();
+        rotated.attestor_set = next;
+        rotated.latest_height = height(110);
+
+        let header = dummy_header(&keys[1..], 2, 120, [9; 32], None);
+        assert!(verify_header(&rotated, &header).is_ok());
+
+        let header = dummy_header(&keys[..3], 1, 120, [9; 32], None);
+        assert!(matches!(
+            verify_header(&rotated, &header),
+            Err(Error::UnknownAttestorSet { .. })
+        ));
+    }
+
+    #[test]
+    fn verify_misbehaviour_with_conflicting_attestations() {
+        let keys = signing_keys();
+        let client_state = dummy_client_state(&keys);
+
+        let header_1 = dummy_header(&keys[..3], 1, 110, [9; 32], None);
+        let header_2 = dummy_header(&keys[1..], 1, 110, [8; 32], None);
+
+        let misbehaviour = Misbehaviour {
+            header_1: header_1.clone(),
+            header_2: header_2.clone(),
+        };
+        assert!(verify_client_message(&client_state, misbehaviour.into()).is_ok());
+
+        let same = Misbehaviour {
+            header_1: header_1.clone(),
+            header_2: header_1.clone(),
+        };
+        assert!(verify_misbehaviour(&client_state, &same).is_err());
+
+        let mut forged = header_2;
+        forged.signatures = header_1.signatures.clone();
+        let forged = Misbehaviour {
+            header_1,
+            header_2: forged,
+        };
+        assert!(verify_misbehaviour(&client_state, &forged).is_err());
+    }
+}
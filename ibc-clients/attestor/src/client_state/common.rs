@@ -0,0 +1,147 @@
+use ibc_core_client::context::client_state::ClientStateCommon;
+use ibc_core_client::types::error::ClientError;
+use ibc_core_client::types::Height;
+use ibc_core_commitment_types::commitment::{
+    CommitmentPrefix, CommitmentProofBytes, CommitmentRoot,
+};
+use ibc_core_commitment_types::merkle::{apply_prefix, MerkleProof};
+use ibc_core_host::types::identifiers::ClientType;
+use ibc_core_host::types::path::Path;
+use ibc_primitives::prelude::*;
+use ibc_primitives::proto::Any;
+
+use crate::client_type as attestor_client_type;
+use crate::error::Error;
+use crate::types::{ClientState as ClientStateType, ConsensusState as ConsensusStateType};
+
+impl ClientStateCommon for ClientStateType {
+    fn verify_consensus_state(&self, consensus_state: Any) -> Result<(), ClientError> {
+        verify_consensus_state(consensus_state)
+    }
+
+    fn client_type(&self) -> ClientType {
+        attestor_client_type()
+    }
+
+    fn latest_height(&self) -> Height {
+        ClientStateType::latest_height(self)
+    }
+
+    fn validate_proof_height(&self, proof_height: Height) -> Result<(), ClientError> {
+        validate_proof_height(self, proof_height)
+    }
+
+    fn verify_upgrade_client(
+        &self,
+        _upgraded_client_state: Any,
+        _upgraded_consensus_state: Any,
+        _proof_upgrade_client: CommitmentProofBytes,
+        _proof_upgrade_consensus_state: CommitmentProofBytes,
+        _root: &CommitmentRoot,
+    ) -> Result<(), ClientError> {
+        Err(Error::UpgradeNotSupported.into())
+    }
+
+    fn verify_membership(
+        &self,
+        prefix: &CommitmentPrefix,
+        proof: &CommitmentProofBytes,
+        root: &CommitmentRoot,
+        path: Path,
+        value: Vec<u8>,
+    ) -> Result<(), ClientError> {
+        verify_membership(self, prefix, proof, root, path, value)
+    }
+
+    fn verify_non_membership(
+        &self,
+        prefix: &CommitmentPrefix,
+        proof: &CommitmentProofBytes,
+        root: &CommitmentRoot,
+        path: Path,
+    ) -> Result<(), ClientError> {
+        verify_non_membership(self, prefix, proof, root, path)
+    }
+}
+
+/// Verify an `Any` consensus state by attempting to convert it to an
+/// attestor `ConsensusState`.
+///
+/// Note that this function is typically implemented as part of the
+/// [`ClientStateCommon`] trait, but has been made a standalone function
+/// in order to make the ClientState APIs more flexible.
+pub fn verify_consensus_state(consensus_state: Any) -> Result<(), ClientError> {
+    ConsensusStateType::try_from(consensus_state)?;
+
+    Ok(())
+}
+
+/// Validate the given proof height against the client state's latest height,
+/// returning an error if the proof height is greater than the latest height
+/// of the client state.
+pub fn validate_proof_height(
+    client_state: &ClientStateType,
+    proof_height: Height,
+) -> Result<(), ClientError> {
+    let latest_height = client_state.latest_height();
+
+    if latest_height.revision_number() != proof_height.revision_number()
+        || latest_height < proof_height
+    {
+        return Err(ClientError::InvalidProofHeight {
+            latest_height,
+            proof_height,
+        });
+    }
+
+    Ok(())
+}
+
+/// Verifies that `value` is committed at the given `path` under the attested
+/// state `root`, using the ICS-23 specs of the client state.
+pub fn verify_membership(
+    client_state: &ClientStateType,
+    prefix: &CommitmentPrefix,
+    proof: &CommitmentProofBytes,
+    root: &CommitmentRoot,
+    path: Path,
+    value: Vec<u8>,
+) -> Result<(), ClientError> {
+    if client_state.is_frozen() {
+        return Err(Error::FrozenClient.into());
+    }
+
+    let merkle_path = apply_prefix(prefix, vec![path.to_string()]);
+    let merkle_proof = MerkleProof::try_from(proof).map_err(ClientError::InvalidCommitmentProof)?;
+
+    merkle_proof
+        .verify_membership(
+            &client_state.proof_specs,
+            root.clone().into(),
+            merkle_path,
+            value,
+            0,
+        )
+        .map_err(ClientError::Ics23Verification)
+}
+
+/// Verifies that nothing is committed at the given `path` under the attested
+/// state `root`.
+pub fn verify_non_membership(
+    client_state: &ClientStateType,
+    prefix: &CommitmentPrefix,
+    proof: &CommitmentProofBytes,
+    root: &CommitmentRoot,
+    path: Path,
+) -> Result<(), ClientError> {
+    if client_state.is_frozen() {
+        return Err(Error::FrozenClient.into());
+    }
+
+    let merkle_path = apply_prefix(prefix, vec![path.to_string()]);
+    let merkle_proof = MerkleProof::try_from(proof).map_err(ClientError::InvalidCommitmentProof)?;
+
+    merkle_proof
+        .verify_non_membership(&client_state.proof_specs, root.clone().into(), merkle_path)
+        .map_err(ClientError::Ics23Verification)
+}
@@ -0,0 +1,198 @@
+use ibc_core_client::context::client_state::ClientStateValidation;
+use ibc_core_client::context::consensus_state::ConsensusState as _;
+use ibc_core_client::context::ClientValidationContext;
+use ibc_core_client::types::error::ClientError;
+use ibc_core_client::types::Status;
+use ibc_core_host::types::identifiers::ClientId;
+use ibc_core_host::types::path::ClientConsensusStatePath;
+use ibc_primitives::prelude::*;
+use ibc_primitives::proto::Any;
+
+use crate::error::Error;
+use crate::types::{
+    verify_attestation, ClientState as ClientStateType, ConsensusState as ConsensusStateType,
+    Header as AttestorHeader, Misbehaviour as AttestorMisbehaviour, ATTESTOR_HEADER_TYPE_URL,
+    ATTESTOR_MISBEHAVIOUR_TYPE_URL,
+};
+
+impl<V> ClientStateValidation<V> for ClientStateType
+where
+    V: ClientValidationContext,
+{
+    fn verify_client_message(
+        &self,
+        _ctx: &V,
+        _client_id: &ClientId,
+        client_message: Any,
+    ) -> Result<(), ClientError> {
+        verify_client_message(self, client_message)
+    }
+
+    fn check_for_misbehaviour(
+        &self,
+        ctx: &V,
+        client_id: &ClientId,
+        client_message: Any,
+    ) -> Result<bool, ClientError> {
+        check_for_misbehaviour(ctx, client_id, client_message)
+    }
+
+    fn status(&self, _ctx: &V, _client_id: &ClientId) -> Result<Status, ClientError> {
+        Ok(status(self))
+    }
+}
+
+/// Verify the client message as part of the client state validation process.
+///
+/// Note that this function is typically implemented as part of the
+/// [`ClientStateValidation`] trait, but has been made a standalone function
+/// in order to make the ClientState APIs more flexible.
+pub fn verify_client_message(
+    client_state: &ClientStateType,
+    client_message: Any,
+) -> Result<(), ClientError> {
+    match client_message.type_url.as_str() {
+        ATTESTOR_HEADER_TYPE_URL => {
+            let header = AttestorHeader::try_from(client_message)?;
+            verify_header(client_state, &header)?;
+            Ok(())
+        }
+        ATTESTOR_MISBEHAVIOUR_TYPE_URL => {
+            let misbehaviour = AttestorMisbehaviour::try_from(client_message)?;
+            verify_misbehaviour(client_state, &misbehaviour)
+        }
+        _ => Err(ClientError::InvalidUpdateClientMessage),
+    }
+}
+
+/// Verifies that the attestation of `header` is signed by a quorum of the
+/// current attestor set, and returns the consensus state it implies.
+///
+/// Headers may attest to heights below the latest one, but only a header
+/// above the latest height may rotate the attestor set, and only to the set
+/// following the current one.
+pub fn verify_header(
+    client_state: &ClientStateType,
+    header: &AttestorHeader,
+) -> Result<ConsensusStateType, Error> {
+    if client_state.is_frozen() {
+        return Err(Error::FrozenClient);
+    }
+
+    verify_header_signatures(client_state, header)?;
+
+    let attestation = &header.attestation;
+    if let Some(next_attestor_set) = &attestation.next_attestor_set {
+        if attestation.height <= client_state.latest_height {
+            return Err(Error::StaleRotation {
+                height: attestation.height,
+                latest: client_state.latest_height,
+            });
+        }
+
+        let expected = client_state.attestor_set.id.saturating_add(1);
+        if next_attestor_set.id != expected {
+            return Err(Error::InvalidNextAttestorSetId {
+                expected,
+                actual: next_attestor_set.id,
+            });
+        }
+
+        next_attestor_set.validate()?;
+    }
+
+    Ok(attestation.clone().into())
+}
+
+/// Verifies that `header` attests to the client's chain and is signed by a
+/// quorum of the current attestor set.
+pub fn verify_header_signatures(
+    client_state: &ClientStateType,
+    header: &AttestorHeader,
+) -> Result<(), Error> {
+    let attestation = &header.attestation;
+
+    if attestation.chain_id != client_state.chain_id {
+        return Err(Error::MismatchedChainId {
+            expected: client_state.chain_id.to_string(),
+            actual: attestation.chain_id.to_string(),
+        });
+    }
+
+    if attestation.attestor_set_id != client_state.attestor_set.id {
+        return Err(Error::UnknownAttestorSet {
+            expected: client_state.attestor_set.id,
+            actual: attestation.attestor_set_id,
+        });
+    }
+
+    verify_attestation(
+        &client_state.attestor_set,
+        &client_state.quorum,
+        &header.signatures,
+        &attestation.sign_bytes(),
+    )
+}
+
+/// Verifies that both headers of `misbehaviour` are signed by a quorum of
+/// the current attestor set.
+pub fn verify_misbehaviour(
+    client_state: &ClientStateType,
+    misbehaviour: &AttestorMisbehaviour,
+) -> Result<(), ClientError> {
+    misbehaviour.validate_basic()?;
+
+    for header in [&misbehaviour.header_1, &misbehaviour.header_2] {
+        verify_header_signatures(client_state, header)?;
+    }
+
+    Ok(())
+}
+
+/// Checks for misbehaviour in the given client message. A verified
+/// `Misbehaviour` is always evidence of misbehaviour, while a verified
+/// `Header` is if a different consensus state is already stored at its
+/// height.
+pub fn check_for_misbehaviour<V>(
+    ctx: &V,
+    client_id: &ClientId,
+    client_message: Any,
+) -> Result<bool, ClientError>
+where
+    V: ClientValidationContext,
+{
+    match client_message.type_url.as_str() {
+        ATTESTOR_HEADER_TYPE_URL => {
+            let header = AttestorHeader::try_from(client_message)?;
+            let height = header.height();
+            let path = ClientConsensusStatePath::new(
+                client_id.clone(),
+                height.revision_number(),
+                height.revision_height(),
+            );
+
+            if let Ok(existing) = ctx.consensus_state(&path) {
+                let attestation = &header.attestation;
+                if existing.root() != &attestation.root
+                    || existing.timestamp() != attestation.timestamp
+                {
+                    return Ok(true);
+                }
+            }
+
+            Ok(false)
+        }
+        ATTESTOR_MISBEHAVIOUR_TYPE_URL => Ok(true),
+        _ => Err(ClientError::InvalidUpdateClientMessage),
+    }
+}
+
+/// Returns the status of the attestor client: it is active unless it has
+/// been frozen.
+pub fn status(client_state: &ClientStateType) -> Status {
+    if client_state.is_frozen() {
+        Status::Frozen
+    } else {
+        Status::Active
+    }
+}
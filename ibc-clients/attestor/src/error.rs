@@ -0,0 +1,83 @@
+//! Defines the attestor light client's error type
+
+use displaydoc::Display;
+use ibc_core_client::types::error::ClientError;
+use ibc_core_client::types::Height;
+use ibc_primitives::prelude::*;
+use prost::DecodeError;
+
+/// The main error type
+#[derive(Debug, Display)]
+pub enum Error {
+    /// invalid raw client state: `{reason}`
+    InvalidRawClientState { reason: String },
+    /// invalid raw consensus state: `{reason}`
+    InvalidRawConsensusState { reason: String },
+    /// invalid raw header: `{reason}`
+    InvalidRawHeader { reason: String },
+    /// invalid raw misbehaviour: `{reason}`
+    InvalidRawMisbehaviour { reason: String },
+    /// protobuf decoding error: `{0}`
+    Decode(DecodeError),
+    /// invalid length for `{field}`: expected `{expected}` bytes, got `{actual}`
+    InvalidLength {
+        field: &'static str,
+        expected: usize,
+        actual: usize,
+    },
+    /// invalid attestor set: `{reason}`
+    InvalidAttestorSet { reason: String },
+    /// invalid quorum `{numerator}/{denominator}`
+    InvalidQuorum { numerator: u64, denominator: u64 },
+    /// attestation is for chain `{actual}` instead of `{expected}`
+    MismatchedChainId { expected: String, actual: String },
+    /// attestation is signed by attestor set `{actual}` instead of the current set `{expected}`
+    UnknownAttestorSet { expected: u64, actual: u64 },
+    /// next attestor set has id `{actual}` instead of `{expected}`
+    InvalidNextAttestorSetId { expected: u64, actual: u64 },
+    /// attestor sets can only be rotated above the latest height `{latest}`, got `{height}`
+    StaleRotation { height: Height, latest: Height },
+    /// `{public_key}` is not an attestor of the current set
+    UnknownAttestor { public_key: String },
+    /// duplicate signature by attestor `{public_key}`
+    DuplicateSignature { public_key: String },
+    /// invalid signature by attestor `{public_key}`
+    InvalidSignature { public_key: String },
+    /// attestor weight overflow
+    WeightOverflow,
+    /// insufficient signed weight: `{signed}` out of `{total}` does not meet the quorum
+    InsufficientSignedWeight { signed: u64, total: u64 },
+    /// misbehaviour headers are at different heights `{first}` and `{second}`
+    MismatchedHeights { first: Height, second: Height },
+    /// misbehaviour headers at height `{height}` attest to the same state
+    NotMisbehaviour { height: Height },
+    /// client is frozen
+    FrozenClient,
+    /// attestor clients cannot be upgraded
+    UpgradeNotSupported,
+}
+
+impl core::error::Error for Error {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match &self {
+            // `prost` implements the trait only with `std`
+            #[cfg(feature = "std")]
+            Self::Decode(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<Error> for ClientError {
+    fn from(e: Error) -> Self {
+        Self::ClientSpecific {
+            description: e.to_string(),
+        }
+    }
+}
+
+impl From<DecodeError> for Error {
+    fn from(e: DecodeError) -> Self {
+        Self::Decode(e)
+    }
+}
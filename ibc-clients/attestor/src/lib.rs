@@ -0,0 +1,49 @@
+//! Attestation-based light client implementation.
+//!
+//! Some chains cannot cheaply verify the consensus of their counterparty,
+//! e.g. because it relies on signature schemes or proofs too expensive to
+//! check on-chain. This client instead trusts a configurable set of
+//! attestors, each with a weight, to sign the state roots of the
+//! counterparty: a header is accepted once it is signed by attestors whose
+//! combined weight meets the quorum of the client.
+//!
+//! Attestor sets are rotated through headers: the current set signs the
+//! next one along with a state root, and the next set takes over once the
+//! header is applied. Attestors signing two different attestations for the
+//! same height are evidence of misbehaviour, which freezes the client.
+//!
+//! IBC commitments are verified against the attested state roots with
+//! ICS-23 proofs.
+#![no_std]
+#![forbid(unsafe_code)]
+#![cfg_attr(not(test), deny(clippy::unwrap_used))]
+#![cfg_attr(not(test), deny(clippy::disallowed_methods, clippy::disallowed_types))]
+#![deny(
+    warnings,
+    trivial_casts,
+    trivial_numeric_casts,
+    unused_import_braces,
+    unused_qualifications,
+    rust_2018_idioms
+)]
+
+extern crate alloc;
+
+#[cfg(any(test, feature = "std"))]
+extern crate std;
+
+pub mod client_state;
+pub mod consensus_state;
+pub mod error;
+pub mod types;
+
+use core::str::FromStr;
+
+use ibc_core_host::types::identifiers::ClientType;
+
+pub const ATTESTOR_CLIENT_TYPE: &str = "attestor";
+
+/// Returns the attestor `ClientType`
+pub fn client_type() -> ClientType {
+    ClientType::from_str(ATTESTOR_CLIENT_TYPE).expect("Never fails because it's valid")
+}
@@ -0,0 +1,18 @@
+//! Data structures and domain types of the attestor light client.
+
+mod attestor;
+mod client_state;
+mod consensus_state;
+mod header;
+mod misbehaviour;
+
+pub use attestor::*;
+pub use client_state::*;
+pub use consensus_state::*;
+pub use header::*;
+pub use misbehaviour::*;
+
+#[cfg(test)]
+pub(crate) use attestor::tests::{attestor_set, sign, signing_keys};
+
+pub mod proto;
@@ -0,0 +1,391 @@
+//! Attestors, attestor sets and quorums, and the verification of their
+//! signatures.
+
+use alloc::collections::BTreeSet;
+
+use ed25519_consensus::{Signature, VerificationKey};
+use ibc_primitives::prelude::*;
+
+use super::proto::{
+    Attestor as RawAttestor, AttestorSet as RawAttestorSet,
+    AttestorSignature as RawAttestorSignature, Quorum as RawQuorum,
+};
+use crate::error::Error;
+
+/// An ed25519 public key.
+pub type PublicKey = [u8; 32];
+
+/// An attestor and the weight of its signatures.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Attestor {
+    pub public_key: PublicKey,
+    pub weight: u64,
+}
+
+/// A set of attestors, identified by an id incremented on every rotation.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AttestorSet {
+    pub id: u64,
+    pub attestors: Vec<Attestor>,
+}
+
+impl AttestorSet {
+    pub fn new(id: u64, attestors: Vec<Attestor>) -> Result<Self, Error> {
+        let set = Self { id, attestors };
+        set.validate()?;
+        Ok(set)
+    }
+
+    /// Checks that the set is non-empty, that its attestors are distinct and
+    /// have a non-zero weight, and that its total weight fits in a `u64`.
+    pub fn validate(&self) -> Result<(), Error> {
+        if self.attestors.is_empty() {
+            return Err(Error::InvalidAttestorSet {
+                reason: "attestor set cannot be empty".to_string(),
+            });
+        }
+
+        let mut public_keys = BTreeSet::new();
+        for attestor in &self.attestors {
+            if attestor.weight == 0 {
+                return Err(Error::InvalidAttestorSet {
+                    reason: format!("attestor {} has no weight", hex(&attestor.public_key)),
+                });
+            }
+
+            if !public_keys.insert(attestor.public_key) {
+                return Err(Error::InvalidAttestorSet {
+                    reason: format!("duplicate attestor {}", hex(&attestor.public_key)),
+                });
+            }
+        }
+
+        self.total_weight().map(|_| ())
+    }
+
+    pub fn total_weight(&self) -> Result<u64, Error> {
+        self.attestors
+            .iter()
+            .try_fold(0u64, |total, attestor| total.checked_add(attestor.weight))
+            .ok_or(Error::WeightOverflow)
+    }
+
+    pub fn attestor(&self, public_key: &PublicKey) -> Option<&Attestor> {
+        self.attestors
+            .iter()
+            .find(|attestor| attestor.public_key == *public_key)
+    }
+}
+
+/// The fraction of the total weight of an attestor set whose signatures are
+/// needed to accept an attestation.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Quorum {
+    pub numerator: u64,
+    pub denominator: u64,
+}
+
+impl Quorum {
+    /// A quorum of two thirds of the weight, as in most BFT protocols.
+    pub const TWO_THIRDS: Self = Self {
+        numerator: 2,
+        denominator: 3,
+    };
+
+    pub fn new(numerator: u64, denominator: u64) -> Result<Self, Error> {
+        let quorum = Self {
+            numerator,
+            denominator,
+        };
+        quorum.validate()?;
+        Ok(quorum)
+    }
+
+    /// Checks that the quorum is a fraction in `(0, 1]`.
+    pub fn validate(&self) -> Result<(), Error> {
+        if self.numerator == 0 || self.numerator > self.denominator {
+            return Err(Error::InvalidQuorum {
+                numerator: self.numerator,
+                denominator: self.denominator,
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Returns whether `signed` out of `total` weight meets the quorum.
+    pub fn is_met(&self, signed: u64, total: u64) -> bool {
+        u128::from(signed) * u128::from(self.denominator)
+            >= u128::from(total) * u128::from(self.numerator)
+    }
+}
+
+/// An attestor's signature of an attestation.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AttestorSignature {
+    pub public_key: PublicKey,
+    pub signature: [u8; 64],
+}
+
+/// Verifies that `signatures` are valid signatures of `message` by attestors
+/// of `attestor_set` whose combined weight meets `quorum`.
+pub fn verify_attestation(
+    attestor_set: &AttestorSet,
+    quorum: &Quorum,
+    signatures: &[AttestorSignature],
+    message: &[u8],
+) -> Result<(), Error> {
+    let total_weight = attestor_set.total_weight()?;
+
+    let mut signers = BTreeSet::new();
+    let mut signed_weight = 0u64;
+
+    for signature in signatures {
+        let public_key = hex(&signature.public_key);
+
+        let attestor = attestor_set
+            .attestor(&signature.public_key)
+            .ok_or_else(|| Error::UnknownAttestor {
+                public_key: public_key.clone(),
+            })?;
+
+        if !signers.insert(signature.public_key) {
+            return Err(Error::DuplicateSignature { public_key });
+        }
+
+        VerificationKey::try_from(signature.public_key)
+            .and_then(|key| key.verify(&Signature::from(signature.signature), message))
+            .map_err(|_| Error::InvalidSignature { public_key })?;
+
+        signed_weight = signed_weight
+            .checked_add(attestor.weight)
+            .ok_or(Error::WeightOverflow)?;
+    }
+
+    if !quorum.is_met(signed_weight, total_weight) {
+        return Err(Error::InsufficientSignedWeight {
+            signed: signed_weight,
+            total: total_weight,
+        });
+    }
+
+    Ok(())
+}
+
+/// Returns the lowercase hexadecimal encoding of `bytes`.
+pub(crate) fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{byte:02x}")).collect()
+}
+
+pub(crate) fn fixed_bytes<const N: usize>(
+    raw: Vec<u8>,
+    field: &'static str,
+) -> Result<[u8; N], Error> {
+    raw.try_into().map_err(|raw: Vec<u8>| Error::InvalidLength {
+        field,
+        expected: N,
+        actual: raw.len(),
+    })
+}
+
+impl TryFrom<RawAttestor> for Attestor {
+    type Error = Error;
+
+    fn try_from(raw: RawAttestor) -> Result<Self, Self::Error> {
+        Ok(Self {
+            public_key: fixed_bytes(raw.public_key, "attestor public key")?,
+            weight: raw.weight,
+        })
+    }
+}
+
+impl From<Attestor> for RawAttestor {
+    fn from(value: Attestor) -> Self {
+        Self {
+            public_key: value.public_key.to_vec(),
+            weight: value.weight,
+        }
+    }
+}
+
+impl TryFrom<RawAttestorSet> for AttestorSet {
+    type Error = Error;
+
+    fn try_from(raw: RawAttestorSet) -> Result<Self, Self::Error> {
+        let attestors = raw
+            .attestors
+            .into_iter()
+            .map(TryInto::try_into)
+            .collect::<Result<_, _>>()?;
+
+        Self::new(raw.id, attestors)
+    }
+}
+
+impl From<AttestorSet> for RawAttestorSet {
+    fn from(value: AttestorSet) -> Self {
+        Self {
+            id: value.id,
+            attestors: value.attestors.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl TryFrom<RawQuorum> for Quorum {
+    type Error = Error;
+
+    fn try_from(raw: RawQuorum) -> Result<Self, Self::Error> {
+        Self::new(raw.numerator, raw.denominator)
+    }
+}
+
+impl From<Quorum> for RawQuorum {
+    fn from(value: Quorum) -> Self {
+        Self {
+            numerator: value.numerator,
+            denominator: value.denominator,
+        }
+    }
+}
+
+impl TryFrom<RawAttestorSignature> for AttestorSignature {
+    type Error = Error;
+
+    fn try_from(raw: RawAttestorSignature) -> Result<Self, Self::Error> {
+        Ok(Self {
+            public_key: fixed_bytes(raw.public_key, "attestor public key")?,
+            signature: fixed_bytes(raw.signature, "attestor signature")?,
+        })
+    }
+}
+
+impl From<AttestorSignature> for RawAttestorSignature {
+    fn from(value: AttestorSignature) -> Self {
+        Self {
+            public_key: value.public_key.to_vec(),
+            signature: value.signature.to_vec(),
+        }
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use ed25519_consensus::SigningKey;
+
+    use super::*;
+
+    pub(crate) fn signing_keys() -> Vec<SigningKey> {
+        (1..=4).map(|seed| SigningKey::from([seed; 32])).collect()
+    }
+
+    pub(crate) fn attestor_set(id: u64, keys: &[SigningKey]) -> AttestorSet {
+        let attestors = keys
+            .iter()
+            .map(|key| Attestor {
+                public_key: key.verification_key().to_bytes(),
+                weight: 100,
+            })
+            .collect();
+
+        AttestorSet::new(id, attestors).expect("valid attestor set")
+    }
+
+    pub(crate) fn sign(keys: &[SigningKey], message: &[u8]) -> Vec<AttestorSignature> {
+        keys.iter()
+            .map(|key| AttestorSignature {
+                public_key: key.verification_key().to_bytes(),
+                signature: key.sign(message).to_bytes(),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn validate_attestor_set() {
+        let keys = signing_keys();
+        let mut set = attestor_set(1, &keys);
+
+        set.attestors[1].public_key = set.attestors[0].public_key;
+        assert!(matches!(
+            set.validate(),
+            Err(Error::InvalidAttestorSet { .. })
+        ));
+
+        let mut set = attestor_set(1, &keys);
+        set.attestors[0].weight = 0;
+        assert!(matches!(
+            set.validate(),
+            Err(Error::InvalidAttestorSet { .. })
+        ));
+
+        let mut set = attestor_set(1, &keys);
+        set.attestors[0].weight = u64::MAX;
+        assert!(matches!(set.validate(), Err(Error::WeightOverflow)));
+
+        assert!(matches!(
+            AttestorSet::new(1, vec![]),
+            Err(Error::InvalidAttestorSet { .. })
+        ));
+    }
+
+    #[test]
+    fn validate_quorum() {
+        assert!(Quorum::new(2, 3).is_ok());
+        assert!(Quorum::new(1, 1).is_ok());
+        assert!(matches!(
+            Quorum::new(0, 3),
+            Err(Error::InvalidQuorum { .. })
+        ));
+        assert!(matches!(
+            Quorum::new(4, 3),
+            Err(Error::InvalidQuorum { .. })
+        ));
+    }
+
+    #[test]
+    fn verify_attestation_thresholds() {
+        let keys = signing_keys();
+        let set = attestor_set(1, &keys);
+        let message = b"attestation";
+        let verify = |quorum: &Quorum, signatures: &[AttestorSignature]| {
+            verify_attestation(&set, quorum, signatures, message)
+        };
+        let two_thirds = Quorum::TWO_THIRDS;
+
+        // three quarters meet a two thirds quorum, half does not
+        assert!(verify(&two_thirds, &sign(&keys[..3], message)).is_ok());
+        assert!(matches!(
+            verify(&two_thirds, &sign(&keys[..2], message)),
+            Err(Error::InsufficientSignedWeight {
+                signed: 200,
+                total: 400
+            })
+        ));
+
+        // a quorum is met by exactly its fraction of the weight
+        let half = Quorum::new(1, 2).expect("valid quorum");
+        assert!(verify(&half, &sign(&keys[..2], message)).is_ok());
+
+        // duplicate signatures do not count twice
+        let mut signatures = sign(&keys[..2], message);
+        signatures.push(signatures[0].clone());
+        assert!(matches!(
+            verify(&two_thirds, &signatures),
+            Err(Error::DuplicateSignature { .. })
+        ));
+
+        // signature of another message
+        let mut signatures = sign(&keys[..3], message);
+        signatures[2] = sign(&keys[2..3], b"other").remove(0);
+        assert!(matches!(
+            verify(&two_thirds, &signatures),
+            Err(Error::InvalidSignature { .. })
+        ));
+
+        // signature by a key outside of the set
+        let outsider = SigningKey::from([9; 32]);
+        assert!(matches!(
+            verify(&two_thirds, &sign(&[outsider], message)),
+            Err(Error::UnknownAttestor { .. })
+        ));
+    }
+}
@@ -0,0 +1,163 @@
+//! Defines the client state type for the attestor light client.
+
+use core::str::FromStr;
+
+use ibc_core_client::types::error::ClientError;
+use ibc_core_client::types::Height;
+use ibc_core_commitment_types::specs::ProofSpecs;
+use ibc_core_host::types::identifiers::ChainId;
+use ibc_primitives::prelude::*;
+use ibc_primitives::proto::{Any, Protobuf};
+
+use super::attestor::{AttestorSet, Quorum};
+use super::proto::ClientState as RawAttestorClientState;
+use crate::error::Error;
+
+pub const ATTESTOR_CLIENT_STATE_TYPE_URL: &str = "/ibc.lightclients.attestor.v1.ClientState";
+
+/// Contains the state of an attestor client.
+///
+/// The client tracks the latest attested height and the attestor set whose
+/// signatures are currently trusted, along with the quorum they must meet.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ClientState {
+    pub chain_id: ChainId,
+    pub latest_height: Height,
+    pub attestor_set: AttestorSet,
+    pub quorum: Quorum,
+    /// The ICS-23 specs of the attested state roots.
+    pub proof_specs: ProofSpecs,
+    pub frozen_height: Option<Height>,
+}
+
+impl ClientState {
+    pub fn new(
+        chain_id: ChainId,
+        latest_height: Height,
+        attestor_set: AttestorSet,
+        quorum: Quorum,
+        proof_specs: ProofSpecs,
+    ) -> Result<Self, Error> {
+        let client_state = Self {
+            chain_id,
+            latest_height,
+            attestor_set,
+            quorum,
+            proof_specs,
+            frozen_height: None,
+        };
+        client_state.validate()?;
+        Ok(client_state)
+    }
+
+    pub fn validate(&self) -> Result<(), Error> {
+        self.attestor_set.validate()?;
+        self.quorum.validate()?;
+
+        self.proof_specs
+            .validate()
+            .map_err(|e| Error::InvalidRawClientState {
+                reason: e.to_string(),
+            })?;
+
+        Ok(())
+    }
+
+    pub fn latest_height(&self) -> Height {
+        self.latest_height
+    }
+
+    pub fn is_frozen(&self) -> bool {
+        self.frozen_height.is_some()
+    }
+
+    pub fn with_frozen_height(self, h: Height) -> Self {
+        Self {
+            frozen_height: Some(h),
+            ..self
+        }
+    }
+}
+
+impl Protobuf<RawAttestorClientState> for ClientState {}
+
+impl TryFrom<RawAttestorClientState> for ClientState {
+    type Error = Error;
+
+    fn try_from(raw: RawAttestorClientState) -> Result<Self, Self::Error> {
+        let invalid = |reason: String| Error::InvalidRawClientState { reason };
+
+        let chain_id = ChainId::from_str(&raw.chain_id).map_err(|e| invalid(e.to_string()))?;
+
+        let latest_height = raw
+            .latest_height
+            .ok_or_else(|| invalid("missing latest height".to_string()))?
+            .try_into()
+            .map_err(|e: ClientError| invalid(e.to_string()))?;
+
+        let client_state = Self {
+            chain_id,
+            latest_height,
+            attestor_set: raw
+                .attestor_set
+                .ok_or_else(|| invalid("missing attestor set".to_string()))?
+                .try_into()?,
+            quorum: raw
+                .quorum
+                .ok_or_else(|| invalid("missing quorum".to_string()))?
+                .try_into()?,
+            proof_specs: raw.proof_specs.into(),
+            frozen_height: raw
+                .frozen_height
+                .and_then(|raw_height| raw_height.try_into().ok()),
+        };
+        client_state.validate()?;
+        Ok(client_state)
+    }
+}
+
+impl From<ClientState> for RawAttestorClientState {
+    fn from(value: ClientState) -> Self {
+        Self {
+            chain_id: value.chain_id.to_string(),
+            latest_height: Some(value.latest_height.into()),
+            attestor_set: Some(value.attestor_set.into()),
+            quorum: Some(value.quorum.into()),
+            proof_specs: value.proof_specs.into(),
+            frozen_height: value.frozen_height.map(|height| height.into()),
+        }
+    }
+}
+
+impl Protobuf<Any> for ClientState {}
+
+impl TryFrom<Any> for ClientState {
+    type Error = ClientError;
+
+    fn try_from(raw: Any) -> Result<Self, Self::Error> {
+        fn decode_client_state(value: &[u8]) -> Result<ClientState, ClientError> {
+            let client_state = Protobuf::<RawAttestorClientState>::decode(value).map_err(|e| {
+                ClientError::Other {
+                    description: e.to_string(),
+                }
+            })?;
+            Ok(client_state)
+        }
+
+        match raw.type_url.as_str() {
+            ATTESTOR_CLIENT_STATE_TYPE_URL => decode_client_state(&raw.value),
+            _ => Err(ClientError::UnknownClientStateType {
+                client_state_type: raw.type_url,
+            }),
+        }
+    }
+}
+
+impl From<ClientState> for Any {
+    fn from(client_state: ClientState) -> Self {
+        Any {
+            type_url: ATTESTOR_CLIENT_STATE_TYPE_URL.to_string(),
+            value: Protobuf::<RawAttestorClientState>::encode_vec(client_state),
+        }
+    }
+}
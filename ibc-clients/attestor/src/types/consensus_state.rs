@@ -0,0 +1,106 @@
+//! Defines the consensus state type for the attestor light client.
+
+use ibc_core_client::types::error::ClientError;
+use ibc_core_commitment_types::commitment::CommitmentRoot;
+use ibc_primitives::prelude::*;
+use ibc_primitives::proto::{Any, Protobuf};
+use ibc_primitives::Timestamp;
+
+use super::header::Attestation;
+use super::proto::ConsensusState as RawAttestorConsensusState;
+use crate::error::Error;
+
+pub const ATTESTOR_CONSENSUS_STATE_TYPE_URL: &str = "/ibc.lightclients.attestor.v1.ConsensusState";
+
+/// Defines the attestor consensus state of an attested height: the state
+/// root and timestamp signed by the attestors.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ConsensusState {
+    pub root: CommitmentRoot,
+    pub timestamp: Timestamp,
+}
+
+impl ConsensusState {
+    pub fn new(root: CommitmentRoot, timestamp: Timestamp) -> Self {
+        Self { root, timestamp }
+    }
+
+    pub fn root(&self) -> &CommitmentRoot {
+        &self.root
+    }
+
+    pub fn timestamp(&self) -> Timestamp {
+        self.timestamp
+    }
+}
+
+impl From<Attestation> for ConsensusState {
+    fn from(attestation: Attestation) -> Self {
+        Self::new(attestation.root, attestation.timestamp)
+    }
+}
+
+impl Protobuf<RawAttestorConsensusState> for ConsensusState {}
+
+impl TryFrom<RawAttestorConsensusState> for ConsensusState {
+    type Error = Error;
+
+    fn try_from(raw: RawAttestorConsensusState) -> Result<Self, Self::Error> {
+        if raw.root.is_empty() {
+            return Err(Error::InvalidRawConsensusState {
+                reason: "root cannot be empty".to_string(),
+            });
+        }
+
+        let timestamp = Timestamp::from_nanoseconds(raw.timestamp).map_err(|e| {
+            Error::InvalidRawConsensusState {
+                reason: e.to_string(),
+            }
+        })?;
+
+        Ok(Self::new(CommitmentRoot::from_bytes(&raw.root), timestamp))
+    }
+}
+
+impl From<ConsensusState> for RawAttestorConsensusState {
+    fn from(value: ConsensusState) -> Self {
+        Self {
+            root: value.root.into_vec(),
+            timestamp: value.timestamp.nanoseconds(),
+        }
+    }
+}
+
+impl Protobuf<Any> for ConsensusState {}
+
+impl TryFrom<Any> for ConsensusState {
+    type Error = ClientError;
+
+    fn try_from(raw: Any) -> Result<Self, Self::Error> {
+        fn decode_consensus_state(value: &[u8]) -> Result<ConsensusState, ClientError> {
+            let consensus_state =
+                Protobuf::<RawAttestorConsensusState>::decode(value).map_err(|e| {
+                    ClientError::Other {
+                        description: e.to_string(),
+                    }
+                })?;
+            Ok(consensus_state)
+        }
+
+        match raw.type_url.as_str() {
+            ATTESTOR_CONSENSUS_STATE_TYPE_URL => decode_consensus_state(&raw.value),
+            _ => Err(ClientError::UnknownConsensusStateType {
+                consensus_state_type: raw.type_url,
+            }),
+        }
+    }
+}
+
+impl From<ConsensusState> for Any {
+    fn from(consensus_state: ConsensusState) -> Self {
+        Any {
+            type_url: ATTESTOR_CONSENSUS_STATE_TYPE_URL.to_string(),
+            value: Protobuf::<RawAttestorConsensusState>::encode_vec(consensus_state),
+        }
+    }
+}
@@ -0,0 +1,167 @@
+//! Defines the header type for the attestor light client.
+
+use core::str::FromStr;
+
+use ibc_core_client::types::error::ClientError;
+use ibc_core_client::types::Height;
+use ibc_core_commitment_types::commitment::CommitmentRoot;
+use ibc_core_host::types::identifiers::ChainId;
+use ibc_primitives::prelude::*;
+use ibc_primitives::proto::{Any, Protobuf};
+use ibc_primitives::Timestamp;
+use prost::Message;
+
+use super::attestor::{AttestorSet, AttestorSignature};
+use super::proto::{Attestation as RawAttestation, Header as RawAttestorHeader};
+use crate::error::Error;
+
+pub const ATTESTOR_HEADER_TYPE_URL: &str = "/ibc.lightclients.attestor.v1.Header";
+
+/// Domain separator of the messages signed by attestors.
+pub const ATTESTATION_DOMAIN: &[u8] = b"ibc-attestation";
+
+/// The statement signed by attestors: the state root and timestamp of the
+/// counterparty chain at a height, and optionally the attestor set taking
+/// over once the attestation is applied.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Attestation {
+    pub chain_id: ChainId,
+    /// The id of the attestor set signing the attestation.
+    pub attestor_set_id: u64,
+    pub height: Height,
+    pub timestamp: Timestamp,
+    pub root: CommitmentRoot,
+    pub next_attestor_set: Option<AttestorSet>,
+}
+
+impl Attestation {
+    /// Returns the message signed by attestors: the protobuf encoding of the
+    /// attestation, prefixed with [`ATTESTATION_DOMAIN`].
+    pub fn sign_bytes(&self) -> Vec<u8> {
+        let mut message = ATTESTATION_DOMAIN.to_vec();
+        message.extend(RawAttestation::from(self.clone()).encode_to_vec());
+        message
+    }
+}
+
+/// Defines the attestor client header: an attestation and the signatures of
+/// the attestors of the current set.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Header {
+    pub attestation: Attestation,
+    pub signatures: Vec<AttestorSignature>,
+}
+
+impl Header {
+    pub fn height(&self) -> Height {
+        self.attestation.height
+    }
+}
+
+impl TryFrom<RawAttestation> for Attestation {
+    type Error = Error;
+
+    fn try_from(raw: RawAttestation) -> Result<Self, Self::Error> {
+        let invalid = |reason: String| Error::InvalidRawHeader { reason };
+
+        let chain_id = ChainId::from_str(&raw.chain_id).map_err(|e| invalid(e.to_string()))?;
+
+        let height = raw
+            .height
+            .ok_or_else(|| invalid("missing height".to_string()))?
+            .try_into()
+            .map_err(|e: ClientError| invalid(e.to_string()))?;
+
+        let timestamp =
+            Timestamp::from_nanoseconds(raw.timestamp).map_err(|e| invalid(e.to_string()))?;
+
+        if raw.root.is_empty() {
+            return Err(invalid("root cannot be empty".to_string()));
+        }
+
+        Ok(Self {
+            chain_id,
+            attestor_set_id: raw.attestor_set_id,
+            height,
+            timestamp,
+            root: CommitmentRoot::from_bytes(&raw.root),
+            next_attestor_set: raw.next_attestor_set.map(TryInto::try_into).transpose()?,
+        })
+    }
+}
+
+impl From<Attestation> for RawAttestation {
+    fn from(value: Attestation) -> Self {
+        Self {
+            chain_id: value.chain_id.to_string(),
+            attestor_set_id: value.attestor_set_id,
+            height: Some(value.height.into()),
+            timestamp: value.timestamp.nanoseconds(),
+            root: value.root.into_vec(),
+            next_attestor_set: value.next_attestor_set.map(Into::into),
+        }
+    }
+}
+
+impl Protobuf<RawAttestorHeader> for Header {}
+
+impl TryFrom<RawAttestorHeader> for Header {
+    type Error = Error;
+
+    fn try_from(raw: RawAttestorHeader) -> Result<Self, Self::Error> {
+        Ok(Self {
+            attestation: raw
+                .attestation
+                .ok_or_else(|| Error::InvalidRawHeader {
+                    reason: "missing attestation".to_string(),
+                })?
+                .try_into()?,
+            signatures: raw
+                .signatures
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<Result<_, Error>>()?,
+        })
+    }
+}
+
+impl From<Header> for RawAttestorHeader {
+    fn from(value: Header) -> Self {
+        Self {
+            attestation: Some(value.attestation.into()),
+            signatures: value.signatures.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl Protobuf<Any> for Header {}
+
+impl TryFrom<Any> for Header {
+    type Error = ClientError;
+
+    fn try_from(raw: Any) -> Result<Self, Self::Error> {
+        fn decode_header(value: &[u8]) -> Result<Header, ClientError> {
+            let header =
+                Protobuf::<RawAttestorHeader>::decode(value).map_err(|e| ClientError::Other {
+                    description: e.to_string(),
+                })?;
+            Ok(header)
+        }
+
+        match raw.type_url.as_str() {
+            ATTESTOR_HEADER_TYPE_URL => decode_header(&raw.value),
+            _ => Err(ClientError::UnknownHeaderType {
+                header_type: raw.type_url,
+            }),
+        }
+    }
+}
+
+impl From<Header> for Any {
+    fn from(header: Header) -> Self {
+        Any {
+            type_url: ATTESTOR_HEADER_TYPE_URL.to_string(),
+            value: Protobuf::<RawAttestorHeader>::encode_vec(header),
+        }
+    }
+}
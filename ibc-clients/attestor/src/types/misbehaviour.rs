@@ -0,0 +1,101 @@
+//! Defines the misbehaviour type for the attestor light client.
+
+use ibc_core_client::types::error::ClientError;
+use ibc_primitives::prelude::*;
+use ibc_primitives::proto::{Any, Protobuf};
+
+use super::header::Header;
+use super::proto::Misbehaviour as RawAttestorMisbehaviour;
+use crate::error::Error;
+
+pub const ATTESTOR_MISBEHAVIOUR_TYPE_URL: &str = "/ibc.lightclients.attestor.v1.Misbehaviour";
+
+/// Evidence of attestors signing two different attestations for the same
+/// height.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Misbehaviour {
+    pub header_1: Header,
+    pub header_2: Header,
+}
+
+impl Misbehaviour {
+    /// Checks that both headers are at the same height with distinct
+    /// attestations, without verifying their signatures.
+    pub fn validate_basic(&self) -> Result<(), Error> {
+        if self.header_1.height() != self.header_2.height() {
+            return Err(Error::MismatchedHeights {
+                first: self.header_1.height(),
+                second: self.header_2.height(),
+            });
+        }
+
+        if self.header_1.attestation == self.header_2.attestation {
+            return Err(Error::NotMisbehaviour {
+                height: self.header_1.height(),
+            });
+        }
+
+        Ok(())
+    }
+}
+
+impl Protobuf<RawAttestorMisbehaviour> for Misbehaviour {}
+
+impl TryFrom<RawAttestorMisbehaviour> for Misbehaviour {
+    type Error = Error;
+
+    fn try_from(raw: RawAttestorMisbehaviour) -> Result<Self, Self::Error> {
+        let missing = || Error::InvalidRawMisbehaviour {
+            reason: "missing header".to_string(),
+        };
+
+        let misbehaviour = Self {
+            header_1: raw.header_1.ok_or_else(missing)?.try_into()?,
+            header_2: raw.header_2.ok_or_else(missing)?.try_into()?,
+        };
+        misbehaviour.validate_basic()?;
+        Ok(misbehaviour)
+    }
+}
+
+impl From<Misbehaviour> for RawAttestorMisbehaviour {
+    fn from(value: Misbehaviour) -> Self {
+        Self {
+            header_1: Some(value.header_1.into()),
+            header_2: Some(value.header_2.into()),
+        }
+    }
+}
+
+impl Protobuf<Any> for Misbehaviour {}
+
+impl TryFrom<Any> for Misbehaviour {
+    type Error = ClientError;
+
+    fn try_from(raw: Any) -> Result<Self, Self::Error> {
+        fn decode_misbehaviour(value: &[u8]) -> Result<Misbehaviour, ClientError> {
+            let misbehaviour = Protobuf::<RawAttestorMisbehaviour>::decode(value).map_err(|e| {
+                ClientError::Other {
+                    description: e.to_string(),
+                }
+            })?;
+            Ok(misbehaviour)
+        }
+
+        match raw.type_url.as_str() {
+            ATTESTOR_MISBEHAVIOUR_TYPE_URL => decode_misbehaviour(&raw.value),
+            _ => Err(ClientError::UnknownMisbehaviourType {
+                misbehaviour_type: raw.type_url,
+            }),
+        }
+    }
+}
+
+impl From<Misbehaviour> for Any {
+    fn from(misbehaviour: Misbehaviour) -> Self {
+        Any {
+            type_url: ATTESTOR_MISBEHAVIOUR_TYPE_URL.to_string(),
+            value: Protobuf::<RawAttestorMisbehaviour>::encode_vec(misbehaviour),
+        }
+    }
+}
@@ -0,0 +1,192 @@
+//! Raw (protobuf) representations of the attestor client types.
+//!
+//! There is no upstream `ibc-proto` definition for these messages yet, so
+//! they are defined here under the `ibc.lightclients.attestor.v1` package.
+
+use ibc_primitives::prelude::*;
+use ibc_proto::ibc::core::client::v1::Height as RawHeight;
+use ibc_proto::ics23::ProofSpec as RawProofSpec;
+
+macro_rules! impl_name {
+    ($ty:ty, $name:literal) => {
+        impl prost::Name for $ty {
+            const NAME: &'static str = $name;
+            const PACKAGE: &'static str = "ibc.lightclients.attestor.v1";
+
+            fn full_name() -> String {
+                format!("{}.{}", Self::PACKAGE, Self::NAME)
+            }
+        }
+    };
+}
+
+/// ```protobuf
+/// message Attestor {
+///   bytes public_key = 1;
+///   uint64 weight = 2;
+/// }
+/// ```
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct Attestor {
+    /// The ed25519 public key of the attestor.
+    #[prost(bytes = "vec", tag = "1")]
+    pub public_key: Vec<u8>,
+    #[prost(uint64, tag = "2")]
+    pub weight: u64,
+}
+
+impl_name!(Attestor, "Attestor");
+
+/// ```protobuf
+/// message AttestorSet {
+///   uint64 id = 1;
+///   repeated Attestor attestors = 2;
+/// }
+/// ```
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct AttestorSet {
+    #[prost(uint64, tag = "1")]
+    pub id: u64,
+    #[prost(message, repeated, tag = "2")]
+    pub attestors: Vec<Attestor>,
+}
+
+impl_name!(AttestorSet, "AttestorSet");
+
+/// ```protobuf
+/// message Quorum {
+///   uint64 numerator = 1;
+///   uint64 denominator = 2;
+/// }
+/// ```
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct Quorum {
+    #[prost(uint64, tag = "1")]
+    pub numerator: u64,
+    #[prost(uint64, tag = "2")]
+    pub denominator: u64,
+}
+
+impl_name!(Quorum, "Quorum");
+
+/// ```protobuf
+/// message ClientState {
+///   string chain_id = 1;
+///   ibc.core.client.v1.Height latest_height = 2;
+///   AttestorSet attestor_set = 3;
+///   Quorum quorum = 4;
+///   repeated ics23.ProofSpec proof_specs = 5;
+///   ibc.core.client.v1.Height frozen_height = 6;
+/// }
+/// ```
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct ClientState {
+    #[prost(string, tag = "1")]
+    pub chain_id: String,
+    #[prost(message, optional, tag = "2")]
+    pub latest_height: Option<RawHeight>,
+    #[prost(message, optional, tag = "3")]
+    pub attestor_set: Option<AttestorSet>,
+    #[prost(message, optional, tag = "4")]
+    pub quorum: Option<Quorum>,
+    #[prost(message, repeated, tag = "5")]
+    pub proof_specs: Vec<RawProofSpec>,
+    #[prost(message, optional, tag = "6")]
+    pub frozen_height: Option<RawHeight>,
+}
+
+impl_name!(ClientState, "ClientState");
+
+/// ```protobuf
+/// message ConsensusState {
+///   bytes root = 1;
+///   uint64 timestamp = 2;
+/// }
+/// ```
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct ConsensusState {
+    #[prost(bytes = "vec", tag = "1")]
+    pub root: Vec<u8>,
+    /// Unix timestamp in nanoseconds.
+    #[prost(uint64, tag = "2")]
+    pub timestamp: u64,
+}
+
+impl_name!(ConsensusState, "ConsensusState");
+
+/// ```protobuf
+/// message Attestation {
+///   string chain_id = 1;
+///   uint64 attestor_set_id = 2;
+///   ibc.core.client.v1.Height height = 3;
+///   uint64 timestamp = 4;
+///   bytes root = 5;
+///   AttestorSet next_attestor_set = 6;
+/// }
+/// ```
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct Attestation {
+    #[prost(string, tag = "1")]
+    pub chain_id: String,
+    #[prost(uint64, tag = "2")]
+    pub attestor_set_id: u64,
+    #[prost(message, optional, tag = "3")]
+    pub height: Option<RawHeight>,
+    /// Unix timestamp in nanoseconds.
+    #[prost(uint64, tag = "4")]
+    pub timestamp: u64,
+    #[prost(bytes = "vec", tag = "5")]
+    pub root: Vec<u8>,
+    #[prost(message, optional, tag = "6")]
+    pub next_attestor_set: Option<AttestorSet>,
+}
+
+impl_name!(Attestation, "Attestation");
+
+/// ```protobuf
+/// message AttestorSignature {
+///   bytes public_key = 1;
+///   bytes signature = 2;
+/// }
+/// ```
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct AttestorSignature {
+    #[prost(bytes = "vec", tag = "1")]
+    pub public_key: Vec<u8>,
+    #[prost(bytes = "vec", tag = "2")]
+    pub signature: Vec<u8>,
+}
+
+impl_name!(AttestorSignature, "AttestorSignature");
+
+/// ```protobuf
+/// message Header {
+///   Attestation attestation = 1;
+///   repeated AttestorSignature signatures = 2;
+/// }
+/// ```
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct Header {
+    #[prost(message, optional, tag = "1")]
+    pub attestation: Option<Attestation>,
+    #[prost(message, repeated, tag = "2")]
+    pub signatures: Vec<AttestorSignature>,
+}
+
+impl_name!(Header, "Header");
+
+/// ```protobuf
+/// message Misbehaviour {
+///   Header header_1 = 1;
+///   Header header_2 = 2;
+/// }
+/// ```
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct Misbehaviour {
+    #[prost(message, optional, tag = "1")]
+    pub header_1: Option<Header>,
+    #[prost(message, optional, tag = "2")]
+    pub header_2: Option<Header>,
+}
+
+impl_name!(Misbehaviour, "Misbehaviour");
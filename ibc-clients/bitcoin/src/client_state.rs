@@ -0,0 +1,222 @@
+//! This module includes the implementations of the `ClientStateCommon`,
+//! `ClientStateValidation`, and `ClientStateExecution` traits for the Bitcoin
+//! client state.
+//!
+//! As with the other clients, the logic behind each trait method is also
+//! exposed as a standalone function, so that hosts wrapping the Bitcoin
+//! client can reuse it.
+
+mod common;
+mod execution;
+mod validation;
+
+pub use common::*;
+pub use execution::*;
+pub use validation::*;
+
+#[cfg(test)]
+mod tests {
+    use core::str::FromStr;
+
+    use ibc_core_client::context::client_state::ClientStateCommon;
+    use ibc_core_commitment_types::commitment::{CommitmentPrefix, CommitmentProofBytes};
+    use ibc_core_host::types::identifiers::{ChainId, ClientId};
+    use ibc_core_host::types::path::{ClientStatePath, Path};
+    use ibc_primitives::prelude::*;
+    use ibc_primitives::proto::Any;
+
+    use super::*;
+    use crate::error::Error;
+    use crate::hashing::{merkle_parent, Hash};
+    use crate::pow::{retarget, TARGET_TIMESPAN, U256};
+    use crate::types::{
+        commitment_hash, mine, op_return_tx, BlockHeader, ClientState, CommitmentProof,
+        ConsensusState, Header, MerkleBranch, Transaction,
+    };
+
+    /// The proof of work limit of regtest, which any other hash meets.
+    const REGTEST_POW_LIMIT: u32 = 0x207fffff;
+    const GENESIS_TIME: u32 = 1_700_000_000;
+
+    fn next_block(prev: &BlockHeader, merkle_root: Hash, bits: u32) -> BlockHeader {
+        mine(BlockHeader {
+            version: 0x2000_0000,
+            prev_blockhash: prev.hash(),
+            merkle_root,
+            time: prev.time + 600,
+            bits,
+            nonce: 0,
+        })
+    }
+
+    fn dummy_client_state(no_retargeting: bool) -> ClientState {
+        let latest_header = mine(BlockHeader {
+            version: 1,
+            prev_blockhash: [0; 32],
+            merkle_root: [1; 32],
+            time: GENESIS_TIME,
+            bits: REGTEST_POW_LIMIT,
+            nonce: 0,
+        });
+
+        let client_state = ClientState {
+            chain_id: ChainId::from_str("bitcoin-regtest").expect("never fails"),
+            latest_height: 1,
+            latest_header,
+            epoch_start_time: GENESIS_TIME,
+            pow_limit: REGTEST_POW_LIMIT,
+            no_retargeting,
+            frozen_height: None,
+        };
+        client_state.validate().expect("never fails");
+        client_state
+    }
+
+    fn dummy_header(client_state: &ClientState, merkle_roots: &[Hash]) -> Header {
+        let mut prev = client_state.latest_header.clone();
+        let headers = merkle_roots
+            .iter()
+            .map(|merkle_root| {
+                prev = next_block(&prev, *merkle_root, prev.bits);
+                prev.clone()
+            })
+            .collect();
+
+        Header { headers }
+    }
+
+    #[test]
+    fn client_state_any_roundtrip() {
+        let client_state = dummy_client_state(true);
+        let any: Any = client_state.clone().into();
+        assert_eq!(
+            ClientState::try_from(any).expect("never fails"),
+            client_state
+        );
+    }
+
+    #[test]
+    fn verify_valid_header() {
+        let client_state = dummy_client_state(true);
+        let header = dummy_header(&client_state, &[[2; 32], [3; 32], [4; 32]]);
+
+        let any: Any = header.clone().into();
+        assert_eq!(Header::try_from(any.clone()).expect("never fails"), header);
+        assert!(verify_client_message(&client_state, any).is_ok());
+
+        let new_client_state = verify_header(&client_state, &header).expect("never fails");
+        assert_eq!(new_client_state.latest_height, 4);
+        assert_eq!(new_client_state.latest_header, header.headers[2]);
+    }
+
+    #[test]
+    fn verify_header_rejects_invalid_chains() {
+        let client_state = dummy_client_state(true);
+
+        let mut header = dummy_header(&client_state, &[[2; 32], [3; 32]]);
+        header.headers.swap(0, 1);
+        assert!(matches!(
+            verify_header(&client_state, &header),
+            Err(Error::MismatchedPrevBlockHash { .. })
+        ));
+
+        let header = Header {
+            headers: vec![next_block(
+                &client_state.latest_header,
+                [2; 32],
+                0x2000_ffff,
+            )],
+        };
+        assert!(matches!(
+            verify_header(&client_state, &header),
+            Err(Error::UnexpectedBits { .. })
+        ));
+
+        let mut block = next_block(&client_state.latest_header, [2; 32], REGTEST_POW_LIMIT);
+        let target = block.target().expect("valid target");
+        while U256::from_le_bytes(&block.hash()) <= target {
+            block.nonce += 1;
+        }
+        let header = Header {
+            headers: vec![block],
+        };
+        assert!(matches!(
+            verify_header(&client_state, &header),
+            Err(Error::InsufficientWork { .. })
+        ));
+    }
+
+    #[test]
+    fn verify_header_with_retarget() {
+        let mut client_state = dummy_client_state(false);
+        client_state.latest_height = 2015;
+        client_state.latest_header.time = GENESIS_TIME + TARGET_TIMESPAN as u32 / 2;
+
+        let pow_limit = client_state.pow_limit_target().expect("valid target");
+        let bits = retarget(
+            REGTEST_POW_LIMIT,
+            GENESIS_TIME,
+            client_state.latest_header.time,
+            &pow_limit,
+        );
+        assert_ne!(bits, REGTEST_POW_LIMIT);
+
+        let header = dummy_header(&client_state, &[[2; 32]]);
+        assert!(matches!(
+            verify_header(&client_state, &header),
+            Err(Error::UnexpectedBits { height: 2016, .. })
+        ));
+
+        let block = next_block(&client_state.latest_header, [2; 32], bits);
+        let header = Header {
+            headers: vec![block.clone()],
+        };
+        let new_client_state = verify_header(&client_state, &header).expect("never fails");
+        assert_eq!(new_client_state.epoch_start_time, block.time);
+    }
+
+    #[test]
+    fn verify_commitment_membership() {
+        let client_state = dummy_client_state(true);
+        let client_id = ClientId::from_str("07-tendermint-0").expect("never fails");
+        let prefix = CommitmentPrefix::try_from(b"ibc".to_vec()).expect("never fails");
+        let path: Path = ClientStatePath::new(client_id).into();
+
+        let tx = Transaction::decode(op_return_tx(&commitment_hash(&prefix, &path, b"value")))
+            .expect("never fails");
+        let sibling = [5; 32];
+        let header = dummy_header(&client_state, &[merkle_parent(&tx.txid(), &sibling)]);
+        let consensus_state = ConsensusState::from_header(&header.headers[0]).expect("never fails");
+
+        let proof = CommitmentProofBytes::try_from(CommitmentProof {
+            tx,
+            branch: MerkleBranch {
+                index: 0,
+                siblings: vec![sibling],
+            },
+        })
+        .expect("never fails");
+
+        assert!(client_state
+            .verify_membership(
+                &prefix,
+                &proof,
+                consensus_state.root(),
+                path.clone(),
+                b"value".to_vec()
+            )
+            .is_ok());
+        assert!(client_state
+            .verify_membership(
+                &prefix,
+                &proof,
+                consensus_state.root(),
+                path.clone(),
+                b"other".to_vec()
+            )
+            .is_err());
+        assert!(client_state
+            .verify_non_membership(&prefix, &proof, consensus_state.root(), path)
+            .is_err());
+    }
+}
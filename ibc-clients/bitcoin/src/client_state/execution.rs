@@ -0,0 +1,154 @@
+use ibc_core_client::context::prelude::*;
+use ibc_core_client::types::error::ClientError;
+use ibc_core_client::types::Height;
+use ibc_core_host::types::identifiers::ClientId;
+use ibc_core_host::types::path::{ClientConsensusStatePath, ClientStatePath};
+use ibc_primitives::prelude::*;
+use ibc_primitives::proto::Any;
+
+use super::verify_header;
+use crate::error::Error;
+use crate::types::{
+    ClientState as ClientStateType, ConsensusState as ConsensusStateType, Header as BitcoinHeader,
+};
+
+impl<E> ClientStateExecution<E> for ClientStateType
+where
+    E: ClientExecutionContext,
+    E::ClientStateRef: From<ClientStateType>,
+    E::ConsensusStateRef: From<ConsensusStateType>,
+{
+    fn initialise(
+        &self,
+        ctx: &mut E,
+        client_id: &ClientId,
+        consensus_state: Any,
+    ) -> Result<(), ClientError> {
+        initialise(self, ctx, client_id, consensus_state)
+    }
+
+    fn update_state(
+        &self,
+        ctx: &mut E,
+        client_id: &ClientId,
+        header: Any,
+    ) -> Result<Vec<Height>, ClientError> {
+        update_state(self, ctx, client_id, header)
+    }
+
+    fn update_state_on_misbehaviour(
+        &self,
+        ctx: &mut E,
+        client_id: &ClientId,
+        _client_message: Any,
+    ) -> Result<(), ClientError> {
+        update_on_misbehaviour(self, ctx, client_id)
+    }
+
+    fn update_state_on_upgrade(
+        &self,
+        _ctx: &mut E,
+        _client_id: &ClientId,
+        _upgraded_client_state: Any,
+        _upgraded_consensus_state: Any,
+    ) -> Result<Height, ClientError> {
+        Err(Error::UpgradeNotSupported.into())
+    }
+}
+
+/// Seed the host store with initial client and consensus states.
+pub fn initialise<E>(
+    client_state: &ClientStateType,
+    ctx: &mut E,
+    client_id: &ClientId,
+    consensus_state: Any,
+) -> Result<(), ClientError>
+where
+    E: ClientExecutionContext,
+    E::ClientStateRef: From<ClientStateType>,
+    E::ConsensusStateRef: From<ConsensusStateType>,
+{
+    let bitcoin_consensus_state = ConsensusStateType::try_from(consensus_state)?;
+    let latest_height = client_state.latest_height();
+
+    ctx.store_client_state(
+        ClientStatePath::new(client_id.clone()),
+        client_state.clone().into(),
+    )?;
+    ctx.store_consensus_state(
+        ClientConsensusStatePath::new(
+            client_id.clone(),
+            latest_height.revision_number(),
+            latest_height.revision_height(),
+        ),
+        bitcoin_consensus_state.into(),
+    )?;
+
+    Ok(())
+}
+
+/// Applies a verified header: stores the consensus state of each of its
+/// blocks and advances the latest known block to its last one.
+///
+/// Returns the heights of the newly stored consensus states.
+pub fn update_state<E>(
+    client_state: &ClientStateType,
+    ctx: &mut E,
+    client_id: &ClientId,
+    header: Any,
+) -> Result<Vec<Height>, ClientError>
+where
+    E: ClientExecutionContext,
+    E::ClientStateRef: From<ClientStateType>,
+    E::ConsensusStateRef: From<ConsensusStateType>,
+{
+    let header = BitcoinHeader::try_from(header)?;
+    let new_client_state = verify_header(client_state, &header)?;
+
+    let mut heights = Vec::with_capacity(header.headers.len());
+
+    for (block_header, block_height) in header.headers.iter().zip(client_state.latest_height + 1..)
+    {
+        let height = Height::new(0, block_height)?;
+
+        ctx.store_consensus_state(
+            ClientConsensusStatePath::new(
+                client_id.clone(),
+                height.revision_number(),
+                height.revision_height(),
+            ),
+            ConsensusStateType::from_header(block_header)?.into(),
+        )?;
+
+        heights.push(height);
+    }
+
+    ctx.store_client_state(
+        ClientStatePath::new(client_id.clone()),
+        new_client_state.into(),
+    )?;
+
+    Ok(heights)
+}
+
+/// Freezes the client after misbehaviour has been detected and verified.
+pub fn update_on_misbehaviour<E>(
+    client_state: &ClientStateType,
+    ctx: &mut E,
+    client_id: &ClientId,
+) -> Result<(), ClientError>
+where
+    E: ClientExecutionContext,
+    E::ClientStateRef: From<ClientStateType>,
+{
+    let frozen_client_state = client_state
+        .clone()
+        .with_frozen_height(client_state.latest_height());
+
+    ctx.store_client_state(
+        ClientStatePath::new(client_id.clone()),
+        frozen_client_state.into(),
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,92 @@
+use ibc_core_client::context::client_state::ClientStateValidation;
+use ibc_core_client::context::ClientValidationContext;
+use ibc_core_client::types::error::ClientError;
+use ibc_core_client::types::Status;
+use ibc_core_host::types::identifiers::ClientId;
+use ibc_primitives::proto::Any;
+
+use crate::error::Error;
+use crate::types::{
+    ClientState as ClientStateType, Header as BitcoinHeader, BITCOIN_HEADER_TYPE_URL,
+};
+
+impl<V> ClientStateValidation<V> for ClientStateType
+where
+    V: ClientValidationContext,
+{
+    fn verify_client_message(
+        &self,
+        _ctx: &V,
+        _client_id: &ClientId,
+        client_message: Any,
+    ) -> Result<(), ClientError> {
+        verify_client_message(self, client_message)
+    }
+
+    fn check_for_misbehaviour(
+        &self,
+        _ctx: &V,
+        _client_id: &ClientId,
+        client_message: Any,
+    ) -> Result<bool, ClientError> {
+        check_for_misbehaviour(client_message)
+    }
+
+    fn status(&self, _ctx: &V, _client_id: &ClientId) -> Result<Status, ClientError> {
+        Ok(status(self))
+    }
+}
+
+/// Verify the client message as part of the client state validation process.
+///
+/// Note that this function is typically implemented as part of the
+/// [`ClientStateValidation`] trait, but has been made a standalone function
+/// in order to make the ClientState APIs more flexible.
+pub fn verify_client_message(
+    client_state: &ClientStateType,
+    client_message: Any,
+) -> Result<(), ClientError> {
+    match client_message.type_url.as_str() {
+        BITCOIN_HEADER_TYPE_URL => {
+            let header = BitcoinHeader::try_from(client_message)?;
+            verify_header(client_state, &header)?;
+            Ok(())
+        }
+        _ => Err(ClientError::InvalidUpdateClientMessage),
+    }
+}
+
+/// Verifies that the block headers of `header` extend the latest known block
+/// with enough proof of work, and returns the client state they lead to.
+pub fn verify_header(
+    client_state: &ClientStateType,
+    header: &BitcoinHeader,
+) -> Result<ClientStateType, Error> {
+    if client_state.is_frozen() {
+        return Err(Error::FrozenClient);
+    }
+
+    client_state.extend(&header.headers)
+}
+
+/// Checks for misbehaviour in the given client message.
+///
+/// Competing forks are part of the normal operation of Bitcoin rather than
+/// misbehaviour, and headers must extend the latest known block, so a
+/// verified header is never evidence of misbehaviour.
+pub fn check_for_misbehaviour(client_message: Any) -> Result<bool, ClientError> {
+    match client_message.type_url.as_str() {
+        BITCOIN_HEADER_TYPE_URL => Ok(false),
+        _ => Err(ClientError::InvalidUpdateClientMessage),
+    }
+}
+
+/// Returns the status of the Bitcoin client: it is active unless it has been
+/// frozen.
+pub fn status(client_state: &ClientStateType) -> Status {
+    if client_state.is_frozen() {
+        Status::Frozen
+    } else {
+        Status::Active
+    }
+}
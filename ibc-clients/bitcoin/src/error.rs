@@ -0,0 +1,80 @@
+//! Defines the Bitcoin light client's error type
+
+use displaydoc::Display;
+use ibc_core_client::types::error::ClientError;
+use ibc_primitives::prelude::*;
+use prost::DecodeError;
+
+/// The main error type
+#[derive(Debug, Display)]
+pub enum Error {
+    /// invalid raw client state: `{reason}`
+    InvalidRawClientState { reason: String },
+    /// invalid raw consensus state: `{reason}`
+    InvalidRawConsensusState { reason: String },
+    /// invalid raw header: `{reason}`
+    InvalidRawHeader { reason: String },
+    /// protobuf decoding error: `{0}`
+    Decode(DecodeError),
+    /// invalid length for `{field}`: expected `{expected}` bytes, got `{actual}`
+    InvalidLength {
+        field: &'static str,
+        expected: usize,
+        actual: usize,
+    },
+    /// header contains no block headers
+    EmptyHeader,
+    /// block `{hash}` does not extend block `{expected}`
+    MismatchedPrevBlockHash { hash: String, expected: String },
+    /// unexpected target at height `{height}`: expected bits `{expected}`, got `{actual}`
+    UnexpectedBits {
+        height: u64,
+        expected: u32,
+        actual: u32,
+    },
+    /// invalid compact target `{bits}`
+    InvalidTarget { bits: u32 },
+    /// target `{bits}` is above the proof of work limit
+    TargetAboveLimit { bits: u32 },
+    /// hash of block `{hash}` is above its target
+    InsufficientWork { hash: String },
+    /// invalid transaction: `{reason}`
+    InvalidTransaction { reason: String },
+    /// invalid merkle branch: `{reason}`
+    InvalidMerkleBranch { reason: String },
+    /// merkle branch does not match the merkle root of the block
+    MismatchedMerkleRoot,
+    /// transaction carries no output committing to the path and value
+    MissingCommitment,
+    /// non-membership cannot be proven for Bitcoin transactions
+    NonMembershipNotSupported,
+    /// client is frozen
+    FrozenClient,
+    /// Bitcoin clients cannot be upgraded
+    UpgradeNotSupported,
+}
+
+impl core::error::Error for Error {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match &self {
+            // `prost` implements the trait only with `std`
+            #[cfg(feature = "std")]
+            Self::Decode(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<Error> for ClientError {
+    fn from(e: Error) -> Self {
+        Self::ClientSpecific {
+            description: e.to_string(),
+        }
+    }
+}
+
+impl From<DecodeError> for Error {
+    fn from(e: DecodeError) -> Self {
+        Self::Decode(e)
+    }
+}
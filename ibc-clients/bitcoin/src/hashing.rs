@@ -0,0 +1,51 @@
+//! Hash functions used by Bitcoin: block hashes, transaction ids and merkle
+//! nodes are double `sha256` hashes.
+
+use sha2::{Digest, Sha256};
+
+use ibc_primitives::prelude::*;
+
+pub type Hash = [u8; 32];
+
+pub fn sha256(data: &[u8]) -> Hash {
+    Sha256::digest(data).into()
+}
+
+pub fn sha256d(data: &[u8]) -> Hash {
+    sha256(&sha256(data))
+}
+
+/// Returns the parent of two nodes of a transaction merkle tree.
+pub fn merkle_parent(left: &Hash, right: &Hash) -> Hash {
+    let mut concat = [0u8; 64];
+    concat[..32].copy_from_slice(left);
+    concat[32..].copy_from_slice(right);
+    sha256d(&concat)
+}
+
+/// Displays a hash as Bitcoin does, i.e. as the hex encoding of its bytes in
+/// reverse order.
+pub fn display_hash(hash: &Hash) -> String {
+    hash.iter()
+        .rev()
+        .map(|byte| format!("{byte:02x}"))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn double_sha256() {
+        // sha256d("hello")
+        assert_eq!(
+            hex(&sha256d(b"hello")),
+            "9595c9df90075148eb06860365df33584b75bff782a510c6cd4883a419833d50"
+        );
+    }
+
+    fn hex(bytes: &[u8]) -> String {
+        bytes.iter().map(|byte| format!("{byte:02x}")).collect()
+    }
+}
@@ -0,0 +1,53 @@
+//! Bitcoin SPV light client implementation.
+//!
+//! The client follows a Bitcoin header chain as described in section 8 of the
+//! Bitcoin whitepaper: headers must extend the latest known block and carry
+//! enough proof of work for the difficulty the chain requires at their
+//! height, including difficulty retargets every 2016 blocks.
+//!
+//! Bitcoin has no IBC store, so the commitments verified by the client are
+//! transactions: a commitment to a path and value is proven by a transaction
+//! included in a block, through its merkle branch, and carrying an
+//! `OP_RETURN` output with the commitment hash. The inclusion of arbitrary
+//! transactions can also be checked with [`types::verify_tx_inclusion`], for
+//! bridging applications.
+//!
+//! The client does not implement fork choice: a header chain conflicting with
+//! the latest known block is rejected, and applications should only rely on
+//! blocks buried deep enough in the chain, e.g. through the delay period of
+//! their connection.
+#![no_std]
+#![forbid(unsafe_code)]
+#![cfg_attr(not(test), deny(clippy::unwrap_used))]
+#![cfg_attr(not(test), deny(clippy::disallowed_methods, clippy::disallowed_types))]
+#![deny(
+    warnings,
+    trivial_casts,
+    trivial_numeric_casts,
+    unused_import_braces,
+    unused_qualifications,
+    rust_2018_idioms
+)]
+
+extern crate alloc;
+
+#[cfg(any(test, feature = "std"))]
+extern crate std;
+
+pub mod client_state;
+pub mod consensus_state;
+pub mod error;
+pub mod hashing;
+pub mod pow;
+pub mod types;
+
+use core::str::FromStr;
+
+use ibc_core_host::types::identifiers::ClientType;
+
+pub const BITCOIN_CLIENT_TYPE: &str = "bitcoin-spv";
+
+/// Returns the Bitcoin `ClientType`
+pub fn client_type() -> ClientType {
+    ClientType::from_str(BITCOIN_CLIENT_TYPE).expect("Never fails because it's valid")
+}
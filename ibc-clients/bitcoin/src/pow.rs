@@ -0,0 +1,223 @@
+//! Proof of work: 256-bit targets, their compact encoding in block headers,
+//! and difficulty retargeting.
+
+use core::cmp::Ordering;
+
+use crate::hashing::Hash;
+
+/// The number of blocks between two difficulty retargets.
+pub const DIFFICULTY_ADJUSTMENT_INTERVAL: u64 = 2016;
+
+/// The expected duration of a retarget period, in seconds.
+pub const TARGET_TIMESPAN: u64 = 14 * 24 * 60 * 60;
+
+/// The proof of work limit of the Bitcoin main network, in compact form.
+pub const MAINNET_POW_LIMIT: u32 = 0x1d00ffff;
+
+/// An unsigned 256-bit integer, as little-endian 64-bit limbs.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct U256([u64; 4]);
+
+impl U256 {
+    pub const ZERO: Self = Self([0; 4]);
+
+    /// Interprets a hash as a little-endian number, as Bitcoin does when
+    /// comparing block hashes to targets.
+    pub fn from_le_bytes(bytes: &Hash) -> Self {
+        let mut limbs = [0u64; 4];
+        for (limb, chunk) in limbs.iter_mut().zip(bytes.chunks_exact(8)) {
+            let mut limb_bytes = [0u8; 8];
+            limb_bytes.copy_from_slice(chunk);
+            *limb = u64::from_le_bytes(limb_bytes);
+        }
+        Self(limbs)
+    }
+
+    pub fn from_u64(value: u64) -> Self {
+        Self([value, 0, 0, 0])
+    }
+
+    /// Decodes a target from its compact form, returning `None` for negative
+    /// or overflowing encodings.
+    pub fn from_compact(bits: u32) -> Option<Self> {
+        let exponent = bits >> 24;
+        let mantissa = bits & 0x007f_ffff;
+
+        if bits & 0x0080_0000 != 0 && mantissa != 0 {
+            return None;
+        }
+
+        if exponent <= 3 {
+            return Some(Self::from_u64(u64::from(mantissa >> (8 * (3 - exponent)))));
+        }
+
+        let overflows = mantissa != 0
+            && (exponent > 34
+                || (mantissa > 0xff && exponent > 33)
+                || (mantissa > 0xffff && exponent > 32));
+        if overflows {
+            return None;
+        }
+
+        Some(Self::from_u64(u64::from(mantissa)).shl(8 * (exponent - 3)))
+    }
+
+    /// Encodes the target in compact form.
+    pub fn to_compact(&self) -> u32 {
+        let mut size = (self.bits() + 7) / 8;
+        let mut compact = if size <= 3 {
+            (self.0[0] << (8 * (3 - size))) as u32
+        } else {
+            self.shr(8 * (size - 3)).0[0] as u32
+        };
+
+        // the mantissa is signed, so its top bit must be clear
+        if compact & 0x0080_0000 != 0 {
+            compact >>= 8;
+            size += 1;
+        }
+
+        compact | (size << 24)
+    }
+
+    /// Returns the number of significant bits.
+    pub fn bits(&self) -> u32 {
+        self.0
+            .iter()
+            .enumerate()
+            .rev()
+            .find(|(_, limb)| **limb != 0)
+            .map_or(0, |(i, limb)| 64 * i as u32 + (64 - limb.leading_zeros()))
+    }
+
+    pub fn shl(&self, shift: u32) -> Self {
+        let mut limbs = [0u64; 4];
+        let (limb_shift, bit_shift) = ((shift / 64) as usize, shift % 64);
+
+        for (i, limb) in limbs.iter_mut().enumerate().skip(limb_shift) {
+            *limb = self.0[i - limb_shift] << bit_shift;
+            if bit_shift > 0 && i > limb_shift {
+                *limb |= self.0[i - limb_shift - 1] >> (64 - bit_shift);
+            }
+        }
+        Self(limbs)
+    }
+
+    pub fn shr(&self, shift: u32) -> Self {
+        let mut limbs = [0u64; 4];
+        let (limb_shift, bit_shift) = ((shift / 64) as usize, shift % 64);
+
+        for (i, limb) in limbs
+            .iter_mut()
+            .enumerate()
+            .take(4usize.saturating_sub(limb_shift))
+        {
+            *limb = self.0[i + limb_shift] >> bit_shift;
+            if bit_shift > 0 && i + limb_shift + 1 < 4 {
+                *limb |= self.0[i + limb_shift + 1] << (64 - bit_shift);
+            }
+        }
+        Self(limbs)
+    }
+
+    pub fn checked_mul_u64(&self, rhs: u64) -> Option<Self> {
+        let mut limbs = [0u64; 4];
+        let mut carry = 0u128;
+
+        for (limb, value) in limbs.iter_mut().zip(self.0) {
+            let product = u128::from(value) * u128::from(rhs) + carry;
+            *limb = product as u64;
+            carry = product >> 64;
+        }
+
+        (carry == 0).then_some(Self(limbs))
+    }
+
+    /// Divides by a non-zero `rhs`.
+    pub fn div_u64(&self, rhs: u64) -> Self {
+        let mut limbs = [0u64; 4];
+        let mut remainder = 0u128;
+
+        for (limb, value) in limbs.iter_mut().zip(self.0).rev() {
+            let dividend = (remainder << 64) | u128::from(value);
+            *limb = (dividend / u128::from(rhs)) as u64;
+            remainder = dividend % u128::from(rhs);
+        }
+
+        Self(limbs)
+    }
+}
+
+impl PartialOrd for U256 {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for U256 {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.iter().rev().cmp(other.0.iter().rev())
+    }
+}
+
+/// Returns the compact target of the block starting a new retarget period,
+/// given the compact target of the previous period and the timestamps of its
+/// first and last blocks, as `CalculateNextWorkRequired` does.
+pub fn retarget(bits: u32, first_block_time: u32, last_block_time: u32, pow_limit: &U256) -> u32 {
+    let timespan = i64::from(last_block_time) - i64::from(first_block_time);
+    let timespan = (timespan.max(0) as u64).clamp(TARGET_TIMESPAN / 4, TARGET_TIMESPAN * 4);
+
+    let new_target = U256::from_compact(bits)
+        .and_then(|target| target.checked_mul_u64(timespan))
+        .map_or(*pow_limit, |target| target.div_u64(TARGET_TIMESPAN));
+
+    new_target.min(*pow_limit).to_compact()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn compact_roundtrip() {
+        let pow_limit = U256::from_compact(MAINNET_POW_LIMIT).expect("valid target");
+        assert_eq!(pow_limit, U256::from_u64(0xffff).shl(208));
+        assert_eq!(pow_limit.to_compact(), MAINNET_POW_LIMIT);
+
+        assert_eq!(
+            U256::from_compact(0x0300_8000).map(|t| t.to_compact()),
+            Some(0x0300_8000)
+        );
+        assert_eq!(U256::from_compact(0x0180_0000), Some(U256::ZERO));
+        assert_eq!(U256::from_compact(0x0392_3456), None);
+        assert_eq!(U256::from_compact(0xff12_3456), None);
+        assert_eq!(U256::from_u64(0x80).to_compact(), 0x0200_8000);
+    }
+
+    #[test]
+    fn retarget_first_mainnet_adjustment() {
+        // the retarget at height 32256, from the timestamps of blocks 30240
+        // and 32255
+        let pow_limit = U256::from_compact(MAINNET_POW_LIMIT).expect("valid target");
+        assert_eq!(
+            retarget(0x1d00ffff, 1_261_130_161, 1_262_152_739, &pow_limit),
+            0x1d00d86a
+        );
+
+        // the target never exceeds the proof of work limit
+        assert_eq!(
+            retarget(0x1d00ffff, 0, TARGET_TIMESPAN as u32 * 10, &pow_limit),
+            MAINNET_POW_LIMIT
+        );
+    }
+
+    #[test]
+    fn arithmetic() {
+        let value = U256::from_u64(u64::MAX).shl(100);
+        assert_eq!(value.shr(100), U256::from_u64(u64::MAX));
+        assert_eq!(value.bits(), 164);
+        assert_eq!(value.checked_mul_u64(4).map(|v| v.div_u64(4)), Some(value));
+        assert_eq!(U256::from_u64(1).shl(255).checked_mul_u64(2), None);
+        assert!(U256::from_u64(1).shl(64) > U256::from_u64(u64::MAX));
+    }
+}
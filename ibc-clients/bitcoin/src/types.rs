@@ -0,0 +1,22 @@
+//! Data structures and domain types of the Bitcoin light client.
+
+mod block;
+mod client_state;
+mod consensus_state;
+mod header;
+mod proof;
+mod transaction;
+
+pub use block::*;
+pub use client_state::*;
+pub use consensus_state::*;
+pub use header::*;
+pub use proof::*;
+pub use transaction::*;
+
+#[cfg(test)]
+pub(crate) use block::tests::mine;
+#[cfg(test)]
+pub(crate) use transaction::tests::op_return_tx;
+
+pub mod proto;
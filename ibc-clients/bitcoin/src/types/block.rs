@@ -0,0 +1,158 @@
+//! Bitcoin block headers and their proof of work.
+
+use ibc_primitives::prelude::*;
+
+use crate::error::Error;
+use crate::hashing::{display_hash, sha256d, Hash};
+use crate::pow::U256;
+
+/// The length of a serialized block header.
+pub const BLOCK_HEADER_LEN: usize = 80;
+
+/// A Bitcoin block header.
+///
+/// Hashes are in their internal byte order, i.e. reversed compared to how
+/// they are usually displayed.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BlockHeader {
+    pub version: i32,
+    pub prev_blockhash: Hash,
+    pub merkle_root: Hash,
+    /// Unix timestamp in seconds.
+    pub time: u32,
+    /// The target of the block, in compact form.
+    pub bits: u32,
+    pub nonce: u32,
+}
+
+impl BlockHeader {
+    /// Decodes a header from its 80-byte serialization.
+    pub fn decode(bytes: &[u8]) -> Result<Self, Error> {
+        if bytes.len() != BLOCK_HEADER_LEN {
+            return Err(Error::InvalidLength {
+                field: "block header",
+                expected: BLOCK_HEADER_LEN,
+                actual: bytes.len(),
+            });
+        }
+
+        let u32_at = |offset: usize| {
+            let mut word = [0u8; 4];
+            word.copy_from_slice(&bytes[offset..offset + 4]);
+            u32::from_le_bytes(word)
+        };
+        let hash_at = |offset: usize| {
+            let mut hash = [0u8; 32];
+            hash.copy_from_slice(&bytes[offset..offset + 32]);
+            hash
+        };
+
+        Ok(Self {
+            version: u32_at(0) as i32,
+            prev_blockhash: hash_at(4),
+            merkle_root: hash_at(36),
+            time: u32_at(68),
+            bits: u32_at(72),
+            nonce: u32_at(76),
+        })
+    }
+
+    pub fn encode(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(BLOCK_HEADER_LEN);
+        bytes.extend_from_slice(&self.version.to_le_bytes());
+        bytes.extend_from_slice(&self.prev_blockhash);
+        bytes.extend_from_slice(&self.merkle_root);
+        bytes.extend_from_slice(&self.time.to_le_bytes());
+        bytes.extend_from_slice(&self.bits.to_le_bytes());
+        bytes.extend_from_slice(&self.nonce.to_le_bytes());
+        bytes
+    }
+
+    pub fn hash(&self) -> Hash {
+        sha256d(&self.encode())
+    }
+
+    pub fn target(&self) -> Result<U256, Error> {
+        U256::from_compact(self.bits).ok_or(Error::InvalidTarget { bits: self.bits })
+    }
+
+    /// Verifies that the hash of the header meets its target, and that the
+    /// target does not exceed `pow_limit`.
+    pub fn verify_pow(&self, pow_limit: &U256) -> Result<(), Error> {
+        let target = self.target()?;
+
+        if target > *pow_limit {
+            return Err(Error::TargetAboveLimit { bits: self.bits });
+        }
+
+        let hash = self.hash();
+        if U256::from_le_bytes(&hash) > target {
+            return Err(Error::InsufficientWork {
+                hash: display_hash(&hash),
+            });
+        }
+
+        Ok(())
+    }
+}
+
+pub(crate) fn fixed_bytes<const N: usize>(
+    raw: Vec<u8>,
+    field: &'static str,
+) -> Result<[u8; N], Error> {
+    raw.try_into().map_err(|raw: Vec<u8>| Error::InvalidLength {
+        field,
+        expected: N,
+        actual: raw.len(),
+    })
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use crate::pow::MAINNET_POW_LIMIT;
+
+    /// The serialized mainnet genesis block header.
+    const GENESIS_HEADER: &str = "0100000000000000000000000000000000000000000000000000000000000000\
+        000000003ba3edfd7a7b12b27ac72c3e67768f617fc81bc3888a51323a9fb8aa\
+        4b1e5e4a29ab5f49ffff001d1dac2b7c";
+
+    pub(crate) fn unhex(hex: &str) -> Vec<u8> {
+        (0..hex.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).expect("valid hex"))
+            .collect()
+    }
+
+    /// Searches a nonce for which `header` meets its target.
+    pub(crate) fn mine(mut header: BlockHeader) -> BlockHeader {
+        let target = header.target().expect("valid target");
+        while U256::from_le_bytes(&header.hash()) > target {
+            header.nonce += 1;
+        }
+        header
+    }
+
+    #[test]
+    fn genesis_header() {
+        let bytes = unhex(GENESIS_HEADER);
+        let header = BlockHeader::decode(&bytes).expect("valid header");
+
+        assert_eq!(header.time, 1_231_006_505);
+        assert_eq!(header.encode(), bytes);
+        assert_eq!(
+            display_hash(&header.hash()),
+            "000000000019d6689c085ae165831e934ff763ae46a2a6c172b3f1b60a8ce26f"
+        );
+
+        let pow_limit = U256::from_compact(MAINNET_POW_LIMIT).expect("valid target");
+        assert!(header.verify_pow(&pow_limit).is_ok());
+
+        let mut invalid = header;
+        invalid.nonce += 1;
+        assert!(matches!(
+            invalid.verify_pow(&pow_limit),
+            Err(Error::InsufficientWork { .. })
+        ));
+    }
+}
@@ -0,0 +1,207 @@
+//! Defines the client state type for the Bitcoin light client.
+
+use core::str::FromStr;
+
+use ibc_core_client::types::error::ClientError;
+use ibc_core_client::types::Height;
+use ibc_core_host::types::identifiers::ChainId;
+use ibc_primitives::prelude::*;
+use ibc_primitives::proto::{Any, Protobuf};
+
+use super::block::BlockHeader;
+use super::proto::ClientState as RawBitcoinClientState;
+use crate::error::Error;
+use crate::hashing::{display_hash, Hash};
+use crate::pow::{retarget, DIFFICULTY_ADJUSTMENT_INTERVAL, U256};
+
+pub const BITCOIN_CLIENT_STATE_TYPE_URL: &str = "/ibc.lightclients.bitcoin.v1.ClientState";
+
+/// Contains the state of a Bitcoin client.
+///
+/// The client tracks the latest verified block and the timestamp of the first
+/// block of its retarget period, from which the target of the next period is
+/// computed. Bitcoin has no notion of revisions, so client heights have a
+/// revision number of 0 and the block height as their revision height.
+///
+/// Timestamps are not checked against the median time of past blocks, so the
+/// timestamp of a block is only as trustworthy as its proof of work.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ClientState {
+    pub chain_id: ChainId,
+    pub latest_height: u64,
+    pub latest_header: BlockHeader,
+    /// The timestamp of the first block of the retarget period of the latest
+    /// block.
+    pub epoch_start_time: u32,
+    /// The proof of work limit of the network, in compact form.
+    pub pow_limit: u32,
+    /// Whether the network never retargets, as regtest.
+    pub no_retargeting: bool,
+    pub frozen_height: Option<Height>,
+}
+
+impl ClientState {
+    pub fn validate(&self) -> Result<(), Error> {
+        if self.latest_height == 0 {
+            return Err(Error::InvalidRawClientState {
+                reason: "latest height cannot be zero".to_string(),
+            });
+        }
+
+        self.pow_limit_target()?;
+        self.latest_header.target()?;
+
+        Ok(())
+    }
+
+    pub fn latest_height(&self) -> Height {
+        Height::new(0, self.latest_height).expect("latest height is validated to be non-zero")
+    }
+
+    pub fn latest_hash(&self) -> Hash {
+        self.latest_header.hash()
+    }
+
+    pub fn pow_limit_target(&self) -> Result<U256, Error> {
+        U256::from_compact(self.pow_limit).ok_or(Error::InvalidTarget {
+            bits: self.pow_limit,
+        })
+    }
+
+    /// Returns the client state after extending the latest block with
+    /// `headers`, verifying that they form a chain with enough work.
+    pub fn extend(&self, headers: &[BlockHeader]) -> Result<Self, Error> {
+        if headers.is_empty() {
+            return Err(Error::EmptyHeader);
+        }
+
+        let pow_limit = self.pow_limit_target()?;
+        let mut state = self.clone();
+
+        for header in headers {
+            if header.prev_blockhash != state.latest_hash() {
+                return Err(Error::MismatchedPrevBlockHash {
+                    hash: display_hash(&header.hash()),
+                    expected: display_hash(&state.latest_hash()),
+                });
+            }
+
+            let height = state.latest_height.saturating_add(1);
+            let starts_epoch = height % DIFFICULTY_ADJUSTMENT_INTERVAL == 0;
+
+            let expected_bits = if starts_epoch && !state.no_retargeting {
+                retarget(
+                    state.latest_header.bits,
+                    state.epoch_start_time,
+                    state.latest_header.time,
+                    &pow_limit,
+                )
+            } else {
+                state.latest_header.bits
+            };
+
+            if header.bits != expected_bits {
+                return Err(Error::UnexpectedBits {
+                    height,
+                    expected: expected_bits,
+                    actual: header.bits,
+                });
+            }
+
+            header.verify_pow(&pow_limit)?;
+
+            if starts_epoch {
+                state.epoch_start_time = header.time;
+            }
+            state.latest_height = height;
+            state.latest_header = header.clone();
+        }
+
+        Ok(state)
+    }
+
+    pub fn is_frozen(&self) -> bool {
+        self.frozen_height.is_some()
+    }
+
+    pub fn with_frozen_height(self, h: Height) -> Self {
+        Self {
+            frozen_height: Some(h),
+            ..self
+        }
+    }
+}
+
+impl Protobuf<RawBitcoinClientState> for ClientState {}
+
+impl TryFrom<RawBitcoinClientState> for ClientState {
+    type Error = Error;
+
+    fn try_from(raw: RawBitcoinClientState) -> Result<Self, Self::Error> {
+        let chain_id =
+            ChainId::from_str(&raw.chain_id).map_err(|e| Error::InvalidRawClientState {
+                reason: e.to_string(),
+            })?;
+
+        let client_state = Self {
+            chain_id,
+            latest_height: raw.latest_height,
+            latest_header: BlockHeader::decode(&raw.latest_header)?,
+            epoch_start_time: raw.epoch_start_time,
+            pow_limit: raw.pow_limit,
+            no_retargeting: raw.no_retargeting,
+            frozen_height: raw
+                .frozen_height
+                .and_then(|raw_height| raw_height.try_into().ok()),
+        };
+        client_state.validate()?;
+        Ok(client_state)
+    }
+}
+
+impl From<ClientState> for RawBitcoinClientState {
+    fn from(value: ClientState) -> Self {
+        Self {
+            chain_id: value.chain_id.to_string(),
+            latest_height: value.latest_height,
+            latest_header: value.latest_header.encode(),
+            epoch_start_time: value.epoch_start_time,
+            pow_limit: value.pow_limit,
+            no_retargeting: value.no_retargeting,
+            frozen_height: value.frozen_height.map(|height| height.into()),
+        }
+    }
+}
+
+impl Protobuf<Any> for ClientState {}
+
+impl TryFrom<Any> for ClientState {
+    type Error = ClientError;
+
+    fn try_from(raw: Any) -> Result<Self, Self::Error> {
+        fn decode_client_state(value: &[u8]) -> Result<ClientState, ClientError> {
+            let client_state = Protobuf::<RawBitcoinClientState>::decode(value).map_err(|e| {
+                ClientError::Other {
+                    description: e.to_string(),
+                }
+            })?;
+            Ok(client_state)
+        }
+
+        match raw.type_url.as_str() {
+            BITCOIN_CLIENT_STATE_TYPE_URL => decode_client_state(&raw.value),
+            _ => Err(ClientError::UnknownClientStateType {
+                client_state_type: raw.type_url,
+            }),
+        }
+    }
+}
+
+impl From<ClientState> for Any {
+    fn from(client_state: ClientState) -> Self {
+        Any {
+            type_url: BITCOIN_CLIENT_STATE_TYPE_URL.to_string(),
+            value: Protobuf::<RawBitcoinClientState>::encode_vec(client_state),
+        }
+    }
+}
@@ -0,0 +1,132 @@
+//! Defines the consensus state type for the Bitcoin light client.
+
+use ibc_core_client::types::error::ClientError;
+use ibc_core_commitment_types::commitment::CommitmentRoot;
+use ibc_primitives::prelude::*;
+use ibc_primitives::proto::{Any, Protobuf};
+use ibc_primitives::Timestamp;
+
+use super::block::{fixed_bytes, BlockHeader};
+use super::proto::ConsensusState as RawBitcoinConsensusState;
+use crate::error::Error;
+use crate::hashing::Hash;
+
+pub const BITCOIN_CONSENSUS_STATE_TYPE_URL: &str = "/ibc.lightclients.bitcoin.v1.ConsensusState";
+
+/// Defines the Bitcoin consensus state of a verified block: the merkle root
+/// of its transactions, its timestamp and its hash.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ConsensusState {
+    pub root: CommitmentRoot,
+    pub timestamp: Timestamp,
+    pub block_hash: Hash,
+}
+
+impl ConsensusState {
+    pub fn new(root: CommitmentRoot, timestamp: Timestamp, block_hash: Hash) -> Self {
+        Self {
+            root,
+            timestamp,
+            block_hash,
+        }
+    }
+
+    /// Returns the consensus state of the block with the given header.
+    pub fn from_header(header: &BlockHeader) -> Result<Self, Error> {
+        let timestamp = Timestamp::from_seconds(u64::from(header.time)).map_err(|e| {
+            Error::InvalidRawHeader {
+                reason: e.to_string(),
+            }
+        })?;
+
+        Ok(Self::new(
+            CommitmentRoot::from_bytes(&header.merkle_root),
+            timestamp,
+            header.hash(),
+        ))
+    }
+
+    pub fn root(&self) -> &CommitmentRoot {
+        &self.root
+    }
+
+    pub fn timestamp(&self) -> Timestamp {
+        self.timestamp
+    }
+}
+
+/// Converts a commitment root into the 32-byte merkle root of the
+/// transactions of a block.
+pub fn merkle_root(root: &CommitmentRoot) -> Result<Hash, Error> {
+    root.as_bytes()
+        .try_into()
+        .map_err(|_| Error::InvalidMerkleBranch {
+            reason: "merkle root must be 32 bytes".to_string(),
+        })
+}
+
+impl Protobuf<RawBitcoinConsensusState> for ConsensusState {}
+
+impl TryFrom<RawBitcoinConsensusState> for ConsensusState {
+    type Error = Error;
+
+    fn try_from(raw: RawBitcoinConsensusState) -> Result<Self, Self::Error> {
+        let merkle_root: Hash = fixed_bytes(raw.merkle_root, "merkle root")?;
+
+        let timestamp = Timestamp::from_nanoseconds(raw.timestamp).map_err(|e| {
+            Error::InvalidRawConsensusState {
+                reason: e.to_string(),
+            }
+        })?;
+
+        Ok(Self::new(
+            CommitmentRoot::from_bytes(&merkle_root),
+            timestamp,
+            fixed_bytes(raw.block_hash, "block hash")?,
+        ))
+    }
+}
+
+impl From<ConsensusState> for RawBitcoinConsensusState {
+    fn from(value: ConsensusState) -> Self {
+        Self {
+            merkle_root: value.root.into_vec(),
+            timestamp: value.timestamp.nanoseconds(),
+            block_hash: value.block_hash.to_vec(),
+        }
+    }
+}
+
+impl Protobuf<Any> for ConsensusState {}
+
+impl TryFrom<Any> for ConsensusState {
+    type Error = ClientError;
+
+    fn try_from(raw: Any) -> Result<Self, Self::Error> {
+        fn decode_consensus_state(value: &[u8]) -> Result<ConsensusState, ClientError> {
+            let consensus_state =
+                Protobuf::<RawBitcoinConsensusState>::decode(value).map_err(|e| {
+                    ClientError::Other {
+                        description: e.to_string(),
+                    }
+                })?;
+            Ok(consensus_state)
+        }
+
+        match raw.type_url.as_str() {
+            BITCOIN_CONSENSUS_STATE_TYPE_URL => decode_consensus_state(&raw.value),
+            _ => Err(ClientError::UnknownConsensusStateType {
+                consensus_state_type: raw.type_url,
+            }),
+        }
+    }
+}
+
+impl From<ConsensusState> for Any {
+    fn from(consensus_state: ConsensusState) -> Self {
+        Any {
+            type_url: BITCOIN_CONSENSUS_STATE_TYPE_URL.to_string(),
+            value: Protobuf::<RawBitcoinConsensusState>::encode_vec(consensus_state),
+        }
+    }
+}
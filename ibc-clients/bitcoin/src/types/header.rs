@@ -0,0 +1,78 @@
+//! Defines the header type for the Bitcoin light client.
+
+use ibc_core_client::types::error::ClientError;
+use ibc_primitives::prelude::*;
+use ibc_primitives::proto::{Any, Protobuf};
+
+use super::block::BlockHeader;
+use super::proto::Header as RawBitcoinHeader;
+use crate::error::Error;
+
+pub const BITCOIN_HEADER_TYPE_URL: &str = "/ibc.lightclients.bitcoin.v1.Header";
+
+/// Defines the Bitcoin client header: a chain of block headers extending the
+/// latest block known to the client.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Header {
+    pub headers: Vec<BlockHeader>,
+}
+
+impl Protobuf<RawBitcoinHeader> for Header {}
+
+impl TryFrom<RawBitcoinHeader> for Header {
+    type Error = Error;
+
+    fn try_from(raw: RawBitcoinHeader) -> Result<Self, Self::Error> {
+        if raw.headers.is_empty() {
+            return Err(Error::EmptyHeader);
+        }
+
+        Ok(Self {
+            headers: raw
+                .headers
+                .iter()
+                .map(|header| BlockHeader::decode(header))
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+impl From<Header> for RawBitcoinHeader {
+    fn from(value: Header) -> Self {
+        Self {
+            headers: value.headers.iter().map(BlockHeader::encode).collect(),
+        }
+    }
+}
+
+impl Protobuf<Any> for Header {}
+
+impl TryFrom<Any> for Header {
+    type Error = ClientError;
+
+    fn try_from(raw: Any) -> Result<Self, Self::Error> {
+        fn decode_header(value: &[u8]) -> Result<Header, ClientError> {
+            let header =
+                Protobuf::<RawBitcoinHeader>::decode(value).map_err(|e| ClientError::Other {
+                    description: e.to_string(),
+                })?;
+            Ok(header)
+        }
+
+        match raw.type_url.as_str() {
+            BITCOIN_HEADER_TYPE_URL => decode_header(&raw.value),
+            _ => Err(ClientError::UnknownHeaderType {
+                header_type: raw.type_url,
+            }),
+        }
+    }
+}
+
+impl From<Header> for Any {
+    fn from(header: Header) -> Self {
+        Any {
+            type_url: BITCOIN_HEADER_TYPE_URL.to_string(),
+            value: Protobuf::<RawBitcoinHeader>::encode_vec(header),
+        }
+    }
+}
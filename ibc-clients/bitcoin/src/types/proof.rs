@@ -0,0 +1,224 @@
+//! Transaction inclusion proofs, and the commitment proofs built on them.
+
+use ibc_core_commitment_types::commitment::{CommitmentPrefix, CommitmentProofBytes};
+use ibc_core_host::types::path::Path;
+use ibc_primitives::prelude::*;
+use prost::Message;
+
+use super::block::fixed_bytes;
+use super::proto::CommitmentProof as RawCommitmentProof;
+use super::transaction::Transaction;
+use crate::error::Error;
+use crate::hashing::{merkle_parent, sha256, Hash};
+
+/// The merkle branch of a transaction in the transaction merkle tree of a
+/// block.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MerkleBranch {
+    /// The position of the transaction in the block.
+    pub index: u32,
+    /// The siblings of the path from the transaction to the merkle root,
+    /// starting at the leaves. Where a node has no sibling, the node itself
+    /// is its sibling, as Bitcoin duplicates the last node of odd levels.
+    pub siblings: Vec<Hash>,
+}
+
+impl MerkleBranch {
+    /// Computes the merkle root the transaction `txid` is included in.
+    pub fn compute_root(&self, txid: &Hash) -> Result<Hash, Error> {
+        if self.siblings.len() < 32 && u64::from(self.index) >> self.siblings.len() != 0 {
+            return Err(Error::InvalidMerkleBranch {
+                reason: format!(
+                    "index {} out of range for a branch of length {}",
+                    self.index,
+                    self.siblings.len()
+                ),
+            });
+        }
+
+        let root = self
+            .siblings
+            .iter()
+            .enumerate()
+            .fold(*txid, |node, (level, sibling)| {
+                if level < 32 && (self.index >> level) & 1 == 1 {
+                    merkle_parent(sibling, &node)
+                } else {
+                    merkle_parent(&node, sibling)
+                }
+            });
+
+        Ok(root)
+    }
+}
+
+/// Verifies that the transaction `txid` is included in the block with the
+/// given merkle root.
+pub fn verify_tx_inclusion(
+    merkle_root: &Hash,
+    txid: &Hash,
+    branch: &MerkleBranch,
+) -> Result<(), Error> {
+    if branch.compute_root(txid)? != *merkle_root {
+        return Err(Error::MismatchedMerkleRoot);
+    }
+
+    Ok(())
+}
+
+/// Returns the hash an `OP_RETURN` output must carry to commit to `value` at
+/// `path`.
+pub fn commitment_hash(prefix: &CommitmentPrefix, path: &Path, value: &[u8]) -> Hash {
+    let mut key = prefix.as_bytes().to_vec();
+    key.extend_from_slice(path.to_string().as_bytes());
+
+    let mut commitment = sha256(&key).to_vec();
+    commitment.extend_from_slice(&sha256(value));
+    sha256(&commitment)
+}
+
+/// The proof of a commitment: a transaction carrying the commitment hash in
+/// an `OP_RETURN` output, and its merkle branch.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CommitmentProof {
+    pub tx: Transaction,
+    pub branch: MerkleBranch,
+}
+
+impl CommitmentProof {
+    /// Verifies that the transaction is included in the block with the given
+    /// merkle root, and that it carries `commitment`.
+    pub fn verify(&self, merkle_root: &Hash, commitment: &Hash) -> Result<(), Error> {
+        // 64-byte transactions can be mistaken for inner nodes of the tree
+        if self.tx.as_bytes().len() == 64 {
+            return Err(Error::InvalidTransaction {
+                reason: "transactions of 64 bytes are ambiguous".to_string(),
+            });
+        }
+
+        verify_tx_inclusion(merkle_root, &self.tx.txid(), &self.branch)?;
+
+        if !self
+            .tx
+            .outputs()
+            .iter()
+            .any(|output| output.op_return_data() == Some(&commitment[..]))
+        {
+            return Err(Error::MissingCommitment);
+        }
+
+        Ok(())
+    }
+}
+
+impl TryFrom<RawCommitmentProof> for CommitmentProof {
+    type Error = Error;
+
+    fn try_from(raw: RawCommitmentProof) -> Result<Self, Self::Error> {
+        Ok(Self {
+            tx: Transaction::decode(raw.tx)?,
+            branch: MerkleBranch {
+                index: raw.index,
+                siblings: raw
+                    .merkle_branch
+                    .into_iter()
+                    .map(|sibling| fixed_bytes(sibling, "merkle branch sibling"))
+                    .collect::<Result<_, _>>()?,
+            },
+        })
+    }
+}
+
+impl From<CommitmentProof> for RawCommitmentProof {
+    fn from(value: CommitmentProof) -> Self {
+        Self {
+            tx: value.tx.as_bytes().to_vec(),
+            merkle_branch: value
+                .branch
+                .siblings
+                .into_iter()
+                .map(|sibling| sibling.to_vec())
+                .collect(),
+            index: value.branch.index,
+        }
+    }
+}
+
+impl TryFrom<&CommitmentProofBytes> for CommitmentProof {
+    type Error = Error;
+
+    fn try_from(value: &CommitmentProofBytes) -> Result<Self, Self::Error> {
+        RawCommitmentProof::decode(value.as_ref())?.try_into()
+    }
+}
+
+impl TryFrom<CommitmentProof> for CommitmentProofBytes {
+    type Error = Error;
+
+    fn try_from(value: CommitmentProof) -> Result<Self, Self::Error> {
+        CommitmentProofBytes::try_from(RawCommitmentProof::from(value).encode_to_vec()).map_err(
+            |e| Error::InvalidTransaction {
+                reason: e.to_string(),
+            },
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::transaction::tests::op_return_tx;
+
+    #[test]
+    fn merkle_branch() {
+        let leaves: Vec<Hash> = (0u8..3).map(|i| [i; 32]).collect();
+        // the last leaf of the odd level is duplicated
+        let root = merkle_parent(
+            &merkle_parent(&leaves[0], &leaves[1]),
+            &merkle_parent(&leaves[2], &leaves[2]),
+        );
+
+        let branch = MerkleBranch {
+            index: 1,
+            siblings: vec![leaves[0], merkle_parent(&leaves[2], &leaves[2])],
+        };
+        assert!(verify_tx_inclusion(&root, &leaves[1], &branch).is_ok());
+        assert!(verify_tx_inclusion(&root, &leaves[0], &branch).is_err());
+
+        let out_of_range = MerkleBranch { index: 4, ..branch };
+        assert!(out_of_range.compute_root(&leaves[1]).is_err());
+    }
+
+    #[test]
+    fn commitment_proof() {
+        let prefix = CommitmentPrefix::try_from(b"ibc".to_vec()).expect("valid prefix");
+        let path: Path = ibc_core_host::types::path::ClientStatePath::new(
+            "07-tendermint-0".parse().expect("valid client id"),
+        )
+        .into();
+        let commitment = commitment_hash(&prefix, &path, b"value");
+
+        let tx = Transaction::decode(op_return_tx(&commitment)).expect("valid transaction");
+        let other = [9; 32];
+        let proof = CommitmentProof {
+            branch: MerkleBranch {
+                index: 0,
+                siblings: vec![other],
+            },
+            tx,
+        };
+        let merkle_root = merkle_parent(&proof.tx.txid(), &other);
+
+        assert!(proof.verify(&merkle_root, &commitment).is_ok());
+        assert!(matches!(
+            proof.verify(&merkle_root, &commitment_hash(&prefix, &path, b"other")),
+            Err(Error::MissingCommitment)
+        ));
+
+        let bytes = CommitmentProofBytes::try_from(proof.clone()).expect("non-empty proof");
+        assert_eq!(
+            CommitmentProof::try_from(&bytes).expect("valid proof"),
+            proof
+        );
+    }
+}
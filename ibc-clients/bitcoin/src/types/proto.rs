@@ -0,0 +1,106 @@
+//! Raw (protobuf) representations of the Bitcoin client types.
+//!
+//! There is no upstream `ibc-proto` definition for these messages yet, so
+//! they are defined here under the `ibc.lightclients.bitcoin.v1` package.
+
+use ibc_primitives::prelude::*;
+use ibc_proto::ibc::core::client::v1::Height as RawHeight;
+
+macro_rules! impl_name {
+    ($ty:ty, $name:literal) => {
+        impl prost::Name for $ty {
+            const NAME: &'static str = $name;
+            const PACKAGE: &'static str = "ibc.lightclients.bitcoin.v1";
+
+            fn full_name() -> String {
+                format!("{}.{}", Self::PACKAGE, Self::NAME)
+            }
+        }
+    };
+}
+
+/// ```protobuf
+/// message ClientState {
+///   string chain_id = 1;
+///   uint64 latest_height = 2;
+///   bytes latest_header = 3;
+///   uint32 epoch_start_time = 4;
+///   uint32 pow_limit = 5;
+///   bool no_retargeting = 6;
+///   ibc.core.client.v1.Height frozen_height = 7;
+/// }
+/// ```
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct ClientState {
+    #[prost(string, tag = "1")]
+    pub chain_id: String,
+    #[prost(uint64, tag = "2")]
+    pub latest_height: u64,
+    /// The serialized header of the latest block.
+    #[prost(bytes = "vec", tag = "3")]
+    pub latest_header: Vec<u8>,
+    #[prost(uint32, tag = "4")]
+    pub epoch_start_time: u32,
+    #[prost(uint32, tag = "5")]
+    pub pow_limit: u32,
+    #[prost(bool, tag = "6")]
+    pub no_retargeting: bool,
+    #[prost(message, optional, tag = "7")]
+    pub frozen_height: Option<RawHeight>,
+}
+
+impl_name!(ClientState, "ClientState");
+
+/// ```protobuf
+/// message ConsensusState {
+///   bytes merkle_root = 1;
+///   uint64 timestamp = 2;
+///   bytes block_hash = 3;
+/// }
+/// ```
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct ConsensusState {
+    #[prost(bytes = "vec", tag = "1")]
+    pub merkle_root: Vec<u8>,
+    /// Unix timestamp in nanoseconds.
+    #[prost(uint64, tag = "2")]
+    pub timestamp: u64,
+    #[prost(bytes = "vec", tag = "3")]
+    pub block_hash: Vec<u8>,
+}
+
+impl_name!(ConsensusState, "ConsensusState");
+
+/// ```protobuf
+/// message Header {
+///   repeated bytes headers = 1;
+/// }
+/// ```
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct Header {
+    /// Serialized block headers, in chain order.
+    #[prost(bytes = "vec", repeated, tag = "1")]
+    pub headers: Vec<Vec<u8>>,
+}
+
+impl_name!(Header, "Header");
+
+/// ```protobuf
+/// message CommitmentProof {
+///   bytes tx = 1;
+///   repeated bytes merkle_branch = 2;
+///   uint32 index = 3;
+/// }
+/// ```
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct CommitmentProof {
+    /// The transaction, without witness data.
+    #[prost(bytes = "vec", tag = "1")]
+    pub tx: Vec<u8>,
+    #[prost(bytes = "vec", repeated, tag = "2")]
+    pub merkle_branch: Vec<Vec<u8>>,
+    #[prost(uint32, tag = "3")]
+    pub index: u32,
+}
+
+impl_name!(CommitmentProof, "CommitmentProof");
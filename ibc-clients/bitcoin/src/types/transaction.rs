@@ -0,0 +1,176 @@
+//! Bitcoin transactions, as far as the client needs to read them: their ids
+//! and outputs.
+
+use ibc_primitives::prelude::*;
+
+use crate::error::Error;
+use crate::hashing::{sha256d, Hash};
+
+/// The `OP_RETURN` opcode, marking provably unspendable outputs carrying
+/// data.
+pub const OP_RETURN: u8 = 0x6a;
+
+/// An output of a transaction.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TxOut {
+    /// The amount of the output, in satoshis.
+    pub value: u64,
+    pub script_pubkey: Vec<u8>,
+}
+
+impl TxOut {
+    /// Returns the 32 bytes pushed by an `OP_RETURN <32 bytes>` output.
+    pub fn op_return_data(&self) -> Option<&[u8]> {
+        match self.script_pubkey.as_slice() {
+            [OP_RETURN, 32, data @ ..] if data.len() == 32 => Some(data),
+            _ => None,
+        }
+    }
+}
+
+/// A transaction in its legacy serialization, i.e. without witness data,
+/// which is the serialization its id commits to.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Transaction {
+    raw: Vec<u8>,
+    outputs: Vec<TxOut>,
+}
+
+impl Transaction {
+    pub fn decode(raw: Vec<u8>) -> Result<Self, Error> {
+        let mut reader = Reader { bytes: &raw };
+
+        reader.take(4)?; // version
+
+        let input_count = reader.compact_size()?;
+        if input_count == 0 {
+            return Err(Error::InvalidTransaction {
+                reason: "transactions must be given without witness data".to_string(),
+            });
+        }
+        for _ in 0..input_count {
+            reader.take(36)?; // previous output
+            let script_len = reader.compact_size()?;
+            reader.take_len(script_len)?; // script sig
+            reader.take(4)?; // sequence
+        }
+
+        let output_count = reader.compact_size()?;
+        let mut outputs = Vec::new();
+        for _ in 0..output_count {
+            let mut value = [0u8; 8];
+            value.copy_from_slice(reader.take(8)?);
+            let script_len = reader.compact_size()?;
+            outputs.push(TxOut {
+                value: u64::from_le_bytes(value),
+                script_pubkey: reader.take_len(script_len)?.to_vec(),
+            });
+        }
+
+        reader.take(4)?; // lock time
+
+        if !reader.bytes.is_empty() {
+            return Err(Error::InvalidTransaction {
+                reason: "trailing bytes".to_string(),
+            });
+        }
+
+        Ok(Self { raw, outputs })
+    }
+
+    pub fn txid(&self) -> Hash {
+        sha256d(&self.raw)
+    }
+
+    pub fn outputs(&self) -> &[TxOut] {
+        &self.outputs
+    }
+
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.raw
+    }
+}
+
+struct Reader<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> Reader<'a> {
+    fn take(&mut self, len: usize) -> Result<&'a [u8], Error> {
+        if self.bytes.len() < len {
+            return Err(Error::InvalidTransaction {
+                reason: "unexpected end of transaction".to_string(),
+            });
+        }
+        let (taken, rest) = self.bytes.split_at(len);
+        self.bytes = rest;
+        Ok(taken)
+    }
+
+    fn take_len(&mut self, len: u64) -> Result<&'a [u8], Error> {
+        let len = usize::try_from(len).map_err(|_| Error::InvalidTransaction {
+            reason: "length overflow".to_string(),
+        })?;
+        self.take(len)
+    }
+
+    /// Reads a `CompactSize` unsigned integer.
+    fn compact_size(&mut self) -> Result<u64, Error> {
+        let value = match self.take(1)?[0] {
+            0xfd => {
+                let mut word = [0u8; 2];
+                word.copy_from_slice(self.take(2)?);
+                u64::from(u16::from_le_bytes(word))
+            }
+            0xfe => {
+                let mut word = [0u8; 4];
+                word.copy_from_slice(self.take(4)?);
+                u64::from(u32::from_le_bytes(word))
+            }
+            0xff => {
+                let mut word = [0u8; 8];
+                word.copy_from_slice(self.take(8)?);
+                u64::from_le_bytes(word)
+            }
+            byte => u64::from(byte),
+        };
+        Ok(value)
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+
+    /// Returns a transaction with one input and an `OP_RETURN` output
+    /// carrying `data`.
+    pub(crate) fn op_return_tx(data: &[u8; 32]) -> Vec<u8> {
+        let mut raw = 2u32.to_le_bytes().to_vec();
+        raw.push(1);
+        raw.extend_from_slice(&[7; 32]);
+        raw.extend_from_slice(&0u32.to_le_bytes());
+        raw.extend_from_slice(&[1, 0x51]);
+        raw.extend_from_slice(&u32::MAX.to_le_bytes());
+        raw.push(1);
+        raw.extend_from_slice(&0u64.to_le_bytes());
+        raw.extend_from_slice(&[34, OP_RETURN, 32]);
+        raw.extend_from_slice(data);
+        raw.extend_from_slice(&0u32.to_le_bytes());
+        raw
+    }
+
+    #[test]
+    fn decode_transaction() {
+        let tx = Transaction::decode(op_return_tx(&[3; 32])).expect("valid transaction");
+        assert_eq!(tx.outputs().len(), 1);
+        assert_eq!(tx.outputs()[0].op_return_data(), Some(&[3u8; 32][..]));
+
+        let mut truncated = op_return_tx(&[3; 32]);
+        truncated.pop();
+        assert!(Transaction::decode(truncated).is_err());
+
+        let mut trailing = op_return_tx(&[3; 32]);
+        trailing.push(0);
+        assert!(Transaction::decode(trailing).is_err());
+    }
+}
@@ -0,0 +1,27 @@
+//! Defines the trait selecting the light client a contract runs.
+
+use ibc_core_client::context::client_state::ClientStateExecution;
+use ibc_core_client::context::consensus_state::ConsensusState as ConsensusStateTrait;
+use ibc_core_client::types::error::ClientError;
+use ibc_primitives::proto::Any;
+
+use crate::context::Context;
+
+/// Selects the client and consensus states of the light client a contract
+/// runs, for the [`Context`] to decode them from, and encode them into, the
+/// `data` of the Wasm client and consensus states.
+///
+/// Implemented by a unit struct of the contract, e.g.:
+///
+/// ```ignore
+/// pub struct TendermintClient;
+///
+/// impl<'a> ClientType<'a> for TendermintClient {
+///     type ClientState = ClientState;
+///     type ConsensusState = ConsensusState;
+/// }
+/// ```
+pub trait ClientType<'a>: Sized {
+    type ClientState: ClientStateExecution<Context<'a, Self>> + Clone;
+    type ConsensusState: ConsensusStateTrait + Clone + Into<Any> + TryFrom<Any, Error = ClientError>;
+}
@@ -0,0 +1,233 @@
+use ibc_client_wasm_types::client_state::ClientState as WasmClientState;
+use ibc_client_wasm_types::consensus_state::ConsensusState as WasmConsensusState;
+use ibc_core_client::context::prelude::*;
+use ibc_core_client::types::error::ClientError;
+use ibc_core_client::types::Height;
+use ibc_core_handler_types::error::ContextError;
+use ibc_core_host::types::identifiers::ClientId;
+use ibc_core_host::types::path::{
+    iteration_key, ClientConsensusStatePath, ClientStatePath, ClientUpdateHeightPath,
+    ClientUpdateTimePath, CLIENT_STATE,
+};
+use ibc_primitives::prelude::*;
+use ibc_primitives::proto::{Any, Protobuf};
+use ibc_primitives::Timestamp;
+use prost::Message;
+
+use super::Context;
+use crate::api::ClientType;
+
+impl<'a, C: ClientType<'a>> ClientValidationContext for Context<'a, C> {
+    type ClientStateRef = C::ClientState;
+    type ConsensusStateRef = C::ConsensusState;
+
+    fn client_state(&self, _client_id: &ClientId) -> Result<Self::ClientStateRef, ContextError> {
+        let wasm_client_state = self.wasm_client_state()?;
+
+        let any = decode_any(&wasm_client_state.data)?;
+
+        Ok(C::ClientState::try_from(any)?)
+    }
+
+    fn consensus_state(
+        &self,
+        client_cons_state_path: &ClientConsensusStatePath,
+    ) -> Result<Self::ConsensusStateRef, ContextError> {
+        let height = Height::new(
+            client_cons_state_path.revision_number,
+            client_cons_state_path.revision_height,
+        )?;
+
+        let bytes = self
+            .storage()
+            .get(client_cons_state_path.leaf().as_bytes())
+            .ok_or(ClientError::ConsensusStateNotFound {
+                client_id: client_cons_state_path.client_id.clone(),
+                height,
+            })?;
+
+        let wasm_consensus_state: WasmConsensusState = Protobuf::<Any>::decode_vec(&bytes)
+            .map_err(|e| ClientError::Other {
+                description: e.to_string(),
+            })?;
+
+        let any = decode_any(&wasm_consensus_state.data)?;
+
+        Ok(C::ConsensusState::try_from(any)?)
+    }
+
+    fn client_update_meta(
+        &self,
+        client_id: &ClientId,
+        height: &Height,
+    ) -> Result<(Timestamp, Height), ContextError> {
+        let time_path = ClientUpdateTimePath::new(
+            client_id.clone(),
+            height.revision_number(),
+            height.revision_height(),
+        );
+        let height_path = ClientUpdateHeightPath::new(
+            client_id.clone(),
+            height.revision_number(),
+            height.revision_height(),
+        );
+
+        let missing = || ClientError::UpdateMetaDataNotFound {
+            client_id: client_id.clone(),
+            height: *height,
+        };
+
+        let time_bytes = self
+            .storage()
+            .get(time_path.leaf().as_bytes())
+            .ok_or_else(missing)?;
+        let nanoseconds =
+            u64::from_be_bytes(time_bytes.try_into().map_err(|_| ClientError::Other {
+                description: "invalid processed time".to_string(),
+            })?);
+        let timestamp =
+            Timestamp::from_nanoseconds(nanoseconds).map_err(|e| ClientError::Other {
+                description: e.to_string(),
+            })?;
+
+        let height_bytes = self
+            .storage()
+            .get(height_path.leaf().as_bytes())
+            .ok_or_else(missing)?;
+        let host_height = String::from_utf8(height_bytes)
+            .ok()
+            .and_then(|height| height.parse().ok())
+            .ok_or_else(|| ClientError::Other {
+                description: "invalid processed height".to_string(),
+            })?;
+
+        Ok((timestamp, host_height))
+    }
+}
+
+impl<'a, C: ClientType<'a>> ClientExecutionContext for Context<'a, C> {
+    type ClientStateMut = C::ClientState;
+
+    fn store_client_state(
+        &mut self,
+        _client_state_path: ClientStatePath,
+        client_state: Self::ClientStateRef,
+    ) -> Result<(), ContextError> {
+        let wasm_client_state = WasmClientState {
+            checksum: self.checksum()?,
+            latest_height: client_state.latest_height(),
+            data: Any::from(client_state).encode_to_vec(),
+        };
+
+        self.insert(CLIENT_STATE, Protobuf::<Any>::encode_vec(wasm_client_state))?;
+
+        Ok(())
+    }
+
+    fn store_consensus_state(
+        &mut self,
+        consensus_state_path: ClientConsensusStatePath,
+        consensus_state: Self::ConsensusStateRef,
+    ) -> Result<(), ContextError> {
+        let any: Any = consensus_state.into();
+        let wasm_consensus_state = WasmConsensusState::new(any.encode_to_vec());
+
+        let key = consensus_state_path.leaf();
+
+        self.insert(
+            iteration_key(
+                consensus_state_path.revision_number,
+                consensus_state_path.revision_height,
+            ),
+            key.as_bytes(),
+        )?;
+        self.insert(key, Protobuf::<Any>::encode_vec(wasm_consensus_state))?;
+
+        Ok(())
+    }
+
+    fn delete_consensus_state(
+        &mut self,
+        consensus_state_path: ClientConsensusStatePath,
+    ) -> Result<(), ContextError> {
+        self.remove(iteration_key(
+            consensus_state_path.revision_number,
+            consensus_state_path.revision_height,
+        ))?;
+        self.remove(consensus_state_path.leaf())?;
+
+        Ok(())
+    }
+
+    fn store_update_meta(
+        &mut self,
+        client_id: ClientId,
+        height: Height,
+        host_timestamp: Timestamp,
+        host_height: Height,
+    ) -> Result<(), ContextError> {
+        let time_path = ClientUpdateTimePath::new(
+            client_id.clone(),
+            height.revision_number(),
+            height.revision_height(),
+        );
+        let height_path = ClientUpdateHeightPath::new(
+            client_id,
+            height.revision_number(),
+            height.revision_height(),
+        );
+
+        self.insert(time_path.leaf(), host_timestamp.nanoseconds().to_be_bytes())?;
+        self.insert(height_path.leaf(), host_height.to_string())?;
+
+        Ok(())
+    }
+
+    fn delete_update_meta(
+        &mut self,
+        client_id: ClientId,
+        height: Height,
+    ) -> Result<(), ContextError> {
+        let time_path = ClientUpdateTimePath::new(
+            client_id.clone(),
+            height.revision_number(),
+            height.revision_height(),
+        );
+        let height_path = ClientUpdateHeightPath::new(
+            client_id,
+            height.revision_number(),
+            height.revision_height(),
+        );
+
+        self.remove(time_path.leaf())?;
+        self.remove(height_path.leaf())?;
+
+        Ok(())
+    }
+}
+
+impl<'a, C> HostClock for Context<'a, C> {
+    /// The contract only knows the block height of the host, and not its
+    /// revision, as ibc-go's `08-wasm` module does.
+    fn host_height(&self) -> Result<Height, ContextError> {
+        Ok(Height::new(0, self.env().block.height)?)
+    }
+
+    fn host_timestamp(&self) -> Result<Timestamp, ContextError> {
+        let nanoseconds = self.env().block.time.nanos();
+
+        Timestamp::from_nanoseconds(nanoseconds).map_err(|e| {
+            ClientError::Other {
+                description: e.to_string(),
+            }
+            .into()
+        })
+    }
+}
+
+/// Decodes the `Any`-encoded state in the `data` of a Wasm state.
+pub(crate) fn decode_any(data: &[u8]) -> Result<Any, ClientError> {
+    Any::decode(data).map_err(|e| ClientError::Other {
+        description: e.to_string(),
+    })
+}
@@ -0,0 +1,220 @@
+//! Implements the client contexts over the storage of the contract.
+//!
+//! The host gives each contract the store of the client it backs, in which
+//! the states are laid out as ibc-go's `08-wasm` module does:
+//!
+//! - `clientState`: the Wasm client state, whose `data` is the client state of
+//!   the light client, both `Any`-encoded,
+//! - `consensusStates/{revision_number}-{revision_height}`: the Wasm consensus
+//!   states, whose `data` are the consensus states of the light client,
+//! - `consensusStates/{revision_number}-{revision_height}/processedTime` and
+//!   `processedHeight`: the update metadata, as the big-endian nanoseconds of
+//!   the host timestamp and the `{revision_number}-{revision_height}` of the
+//!   host height,
+//! - `iterateConsensusStates{revision_number}{revision_height}`: the heights
+//!   of the consensus states, in big-endian order for their iteration.
+
+pub(crate) mod client_ctx;
+#[cfg(feature = "tendermint")]
+mod tendermint_ctx;
+
+use core::marker::PhantomData;
+
+use cosmwasm_std::{Deps, DepsMut, Env, Order, Storage};
+use ibc_client_wasm::context::Checksum;
+use ibc_client_wasm_types::client_state::ClientState as WasmClientState;
+use ibc_client_wasm_types::WASM_CLIENT_TYPE;
+use ibc_core_client::types::error::ClientError;
+use ibc_core_client::types::Height;
+use ibc_core_host::types::identifiers::ClientId;
+use ibc_core_host::types::path::{iteration_key, CLIENT_STATE, ITERATE_CONSENSUS_STATE_PREFIX};
+use ibc_primitives::prelude::*;
+use ibc_primitives::proto::{Any, Protobuf};
+
+/// The storage of the contract, mutable only when the contract is
+/// instantiated or called through `sudo`.
+enum Store<'a> {
+    Ref(&'a dyn Storage),
+    Mut(&'a mut dyn Storage),
+}
+
+/// The context of a contract running the light client `C`.
+pub struct Context<'a, C> {
+    store: Store<'a>,
+    env: Env,
+    checksum: Option<Checksum>,
+    client_type: PhantomData<C>,
+}
+
+impl<'a, C> Context<'a, C> {
+    /// Creates the read-only context of the `query` entry point.
+    pub fn new_ref(deps: Deps<'a>, env: Env) -> Self {
+        Self {
+            store: Store::Ref(deps.storage),
+            env,
+            checksum: None,
+            client_type: PhantomData,
+        }
+    }
+
+    /// Creates the context of the `instantiate` and `sudo` entry points.
+    pub fn new_mut(deps: DepsMut<'a>, env: Env) -> Self {
+        Self {
+            store: Store::Mut(deps.storage),
+            env,
+            checksum: None,
+            client_type: PhantomData,
+        }
+    }
+
+    pub fn env(&self) -> &Env {
+        &self.env
+    }
+
+    /// Sets the checksum of the byte code of the contract, stored along with
+    /// the client states, before the first client state is stored.
+    pub fn set_checksum(&mut self, checksum: Checksum) {
+        self.checksum = Some(checksum);
+    }
+
+    /// Returns the checksum of the byte code of the contract, as set on
+    /// instantiation or as stored along with the latest client state.
+    pub fn checksum(&self) -> Result<Checksum, ClientError> {
+        match &self.checksum {
+            Some(checksum) => Ok(checksum.clone()),
+            None => Ok(self.wasm_client_state()?.checksum),
+        }
+    }
+
+    /// Returns the identifier the contract refers to its client by.
+    ///
+    /// The contract is unaware of the identifier the host assigned to the
+    /// client, as the host scopes the store of the contract to the client
+    /// anyway, and so refers to it by the first identifier of its type, as
+    /// ibc-go does.
+    pub fn client_id(&self) -> ClientId {
+        ClientId::new(WASM_CLIENT_TYPE, 0).expect("Never fails")
+    }
+
+    /// Returns the Wasm client state stored by the contract.
+    pub fn wasm_client_state(&self) -> Result<WasmClientState, ClientError> {
+        let bytes = self.retrieve(CLIENT_STATE)?;
+
+        Protobuf::<Any>::decode_vec(&bytes).map_err(|e| ClientError::Other {
+            description: e.to_string(),
+        })
+    }
+
+    /// Returns the heights of all the consensus states stored by the
+    /// contract, in ascending order.
+    pub fn consensus_state_heights(&self) -> Result<Vec<Height>, ClientError> {
+        self.iteration_heights(None, None, Order::Ascending)
+            .collect()
+    }
+
+    /// Returns the height of the lowest consensus state higher than `height`.
+    pub fn next_consensus_state_height(
+        &self,
+        height: &Height,
+    ) -> Result<Option<Height>, ClientError> {
+        let next = height.increment();
+        let start = iteration_key(next.revision_number(), next.revision_height());
+
+        self.iteration_heights(Some(start), None, Order::Ascending)
+            .next()
+            .transpose()
+    }
+
+    /// Returns the height of the highest consensus state lower than `height`.
+    pub fn prev_consensus_state_height(
+        &self,
+        height: &Height,
+    ) -> Result<Option<Height>, ClientError> {
+        let end = iteration_key(height.revision_number(), height.revision_height());
+
+        self.iteration_heights(None, Some(end), Order::Descending)
+            .next()
+            .transpose()
+    }
+
+    /// Iterates over the heights of the iteration keys from `start`, included,
+    /// to `end`, excluded, defaulting to all of them.
+    fn iteration_heights(
+        &self,
+        start: Option<Vec<u8>>,
+        end: Option<Vec<u8>>,
+        order: Order,
+    ) -> impl Iterator<Item = Result<Height, ClientError>> + '_ {
+        let prefix = ITERATE_CONSENSUS_STATE_PREFIX.as_bytes();
+
+        let start = start.unwrap_or_else(|| prefix.to_vec());
+        let end = end.unwrap_or_else(|| {
+            // The keys of the prefix are lower than the prefix with its last
+            // byte incremented
+            let mut end = prefix.to_vec();
+            if let Some(last) = end.last_mut() {
+                *last += 1;
+            }
+            end
+        });
+
+        self.storage()
+            .range(Some(start.as_slice()), Some(end.as_slice()), order)
+            .map(move |(key, _)| {
+                let height = key
+                    .strip_prefix(prefix)
+                    .filter(|height| height.len() == 16)
+                    .ok_or_else(|| ClientError::Other {
+                        description: "invalid consensus state iteration key".to_string(),
+                    })?;
+
+                let (revision_number, revision_height) = height.split_at(8);
+
+                Height::new(
+                    u64::from_be_bytes(revision_number.try_into().expect("Never fails")),
+                    u64::from_be_bytes(revision_height.try_into().expect("Never fails")),
+                )
+            })
+    }
+
+    fn storage(&self) -> &dyn Storage {
+        match &self.store {
+            Store::Ref(storage) => *storage,
+            Store::Mut(storage) => &**storage,
+        }
+    }
+
+    fn storage_mut(&mut self) -> Result<&mut dyn Storage, ClientError> {
+        match &mut self.store {
+            Store::Ref(_) => Err(ClientError::Other {
+                description: "the storage of the contract is read-only on queries".to_string(),
+            }),
+            Store::Mut(storage) => Ok(&mut **storage),
+        }
+    }
+
+    /// Reads the value stored at `key`, failing if it is absent.
+    fn retrieve(&self, key: impl AsRef<[u8]>) -> Result<Vec<u8>, ClientError> {
+        let key = key.as_ref();
+
+        self.storage().get(key).ok_or_else(|| ClientError::Other {
+            description: format!("no value stored at `{}`", String::from_utf8_lossy(key)),
+        })
+    }
+
+    fn insert(
+        &mut self,
+        key: impl AsRef<[u8]>,
+        value: impl AsRef<[u8]>,
+    ) -> Result<(), ClientError> {
+        self.storage_mut()?.set(key.as_ref(), value.as_ref());
+
+        Ok(())
+    }
+
+    fn remove(&mut self, key: impl AsRef<[u8]>) -> Result<(), ClientError> {
+        self.storage_mut()?.remove(key.as_ref());
+
+        Ok(())
+    }
+}
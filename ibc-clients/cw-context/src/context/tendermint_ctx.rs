@@ -0,0 +1,53 @@
+use ibc_client_tendermint::context::{ConsensusStateConverter, ValidationContext};
+use ibc_core_client::context::ClientValidationContext;
+use ibc_core_client::types::Height;
+use ibc_core_handler_types::error::ContextError;
+use ibc_core_host::types::identifiers::ClientId;
+use ibc_core_host::types::path::ClientConsensusStatePath;
+use ibc_primitives::prelude::*;
+
+use super::Context;
+use crate::api::ClientType;
+
+impl<'a, C: ClientType<'a>> ValidationContext for Context<'a, C>
+where
+    C::ConsensusState: ConsensusStateConverter,
+{
+    fn consensus_state_heights(&self, _client_id: &ClientId) -> Result<Vec<Height>, ContextError> {
+        Ok(Context::consensus_state_heights(self)?)
+    }
+
+    fn next_consensus_state(
+        &self,
+        client_id: &ClientId,
+        height: &Height,
+    ) -> Result<Option<Self::ConsensusStateRef>, ContextError> {
+        self.next_consensus_state_height(height)?
+            .map(|next| self.consensus_state_at(client_id, &next))
+            .transpose()
+    }
+
+    fn prev_consensus_state(
+        &self,
+        client_id: &ClientId,
+        height: &Height,
+    ) -> Result<Option<Self::ConsensusStateRef>, ContextError> {
+        self.prev_consensus_state_height(height)?
+            .map(|prev| self.consensus_state_at(client_id, &prev))
+            .transpose()
+    }
+}
+
+impl<'a, C: ClientType<'a>> Context<'a, C> {
+    fn consensus_state_at(
+        &self,
+        client_id: &ClientId,
+        height: &Height,
+    ) -> Result<C::ConsensusState, ContextError> {
+        self.consensus_state(&ClientConsensusStatePath::new(
+            client_id.clone(),
+            height.revision_number(),
+            height.revision_height(),
+        ))
+    }
+}
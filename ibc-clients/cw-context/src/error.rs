@@ -0,0 +1,49 @@
+//! Defines the errors raised by the contracts of the light clients.
+
+use cosmwasm_std::StdError;
+use displaydoc::Display;
+use ibc_core_client::types::error::ClientError;
+use ibc_core_handler_types::error::ContextError;
+use ibc_primitives::prelude::*;
+
+/// The error returned by the entry points of the contracts.
+#[derive(Debug, Display)]
+pub enum ContractError {
+    /// CosmWasm error: `{0}`
+    Std(StdError),
+    /// ICS02 Client error: `{0}`
+    Client(ClientError),
+    /// context error: `{0}`
+    Context(ContextError),
+    /// invalid message: `{reason}`
+    InvalidMsg { reason: String },
+}
+
+impl core::error::Error for ContractError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            Self::Std(e) => Some(e),
+            Self::Client(e) => Some(e),
+            Self::Context(e) => Some(e),
+            Self::InvalidMsg { .. } => None,
+        }
+    }
+}
+
+impl From<StdError> for ContractError {
+    fn from(e: StdError) -> Self {
+        Self::Std(e)
+    }
+}
+
+impl From<ClientError> for ContractError {
+    fn from(e: ClientError) -> Self {
+        Self::Client(e)
+    }
+}
+
+impl From<ContextError> for ContractError {
+    fn from(e: ContextError) -> Self {
+        Self::Context(e)
+    }
+}
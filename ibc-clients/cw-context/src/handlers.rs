@@ -0,0 +1,223 @@
+//! Dispatches the messages of the Wasm client to the light client run by the
+//! contract.
+
+use core::str::FromStr;
+
+use cosmwasm_std::{to_json_binary, Binary};
+use ibc_client_wasm::contract::{
+    CheckForMisbehaviourResult, InstantiateMsg, MerklePath, QueryMsg, StatusResult, SudoMsg,
+    TimestampAtHeightResult, UpdateStateResult,
+};
+use ibc_core_client::context::prelude::*;
+use ibc_core_client::types::Height;
+use ibc_core_commitment_types::commitment::{
+    CommitmentPrefix, CommitmentProofBytes, CommitmentRoot,
+};
+use ibc_core_host::types::path::{ClientConsensusStatePath, Path};
+use ibc_primitives::prelude::*;
+
+use crate::api::ClientType;
+use crate::context::client_ctx::decode_any;
+use crate::context::Context;
+use crate::error::ContractError;
+
+impl<'a, C: ClientType<'a>> Context<'a, C> {
+    /// Handles the `instantiate` entry point, called by the host once it
+    /// stored the Wasm client state, initialising the light client with its
+    /// client and consensus states.
+    pub fn instantiate(&mut self, msg: InstantiateMsg) -> Result<Binary, ContractError> {
+        self.set_checksum(msg.checksum);
+
+        let client_state = C::ClientState::try_from(decode_any(&msg.client_state)?)?;
+        let consensus_state = decode_any(&msg.consensus_state)?;
+
+        let client_id = self.client_id();
+        client_state.initialise(self, &client_id, consensus_state)?;
+
+        Ok(Binary::default())
+    }
+
+    /// Handles the `sudo` entry point, through which the host changes the
+    /// state of the client or verifies proofs against it.
+    pub fn sudo(&mut self, msg: SudoMsg) -> Result<Binary, ContractError> {
+        let client_id = self.client_id();
+        let client_state = self.client_state(&client_id)?;
+
+        let data = match msg {
+            SudoMsg::UpdateState { client_message } => {
+                let heights =
+                    client_state.update_state(self, &client_id, decode_any(&client_message)?)?;
+
+                to_json_binary(&UpdateStateResult { heights })?
+            }
+            SudoMsg::UpdateStateOnMisbehaviour { client_message } => {
+                client_state.update_state_on_misbehaviour(
+                    self,
+                    &client_id,
+                    decode_any(&client_message)?,
+                )?;
+
+                Binary::default()
+            }
+            SudoMsg::VerifyUpgradeAndUpdateState {
+                upgrade_client_state,
+                upgrade_consensus_state,
+                proof_upgrade_client,
+                proof_upgrade_consensus_state,
+            } => {
+                let upgraded_client_state = decode_any(&upgrade_client_state)?;
+                let upgraded_consensus_state = decode_any(&upgrade_consensus_state)?;
+
+                let root = self.root_at(&client_state.latest_height())?;
+
+                client_state.verify_upgrade_client(
+                    upgraded_client_state.clone(),
+                    upgraded_consensus_state.clone(),
+                    proof_bytes(proof_upgrade_client)?,
+                    proof_bytes(proof_upgrade_consensus_state)?,
+                    &root,
+                )?;
+
+                client_state.update_state_on_upgrade(
+                    self,
+                    &client_id,
+                    upgraded_client_state,
+                    upgraded_consensus_state,
+                )?;
+
+                Binary::default()
+            }
+            SudoMsg::VerifyMembership {
+                height,
+                proof,
+                merkle_path,
+                value,
+                ..
+            } => {
+                let (prefix, path) = parse_merkle_path(merkle_path)?;
+                let root = self.root_at(&height)?;
+
+                client_state.verify_membership_with_context(
+                    self,
+                    &prefix,
+                    &proof_bytes(proof)?,
+                    &root,
+                    path,
+                    value,
+                )?;
+
+                Binary::default()
+            }
+            SudoMsg::VerifyNonMembership {
+                height,
+                proof,
+                merkle_path,
+                ..
+            } => {
+                let (prefix, path) = parse_merkle_path(merkle_path)?;
+                let root = self.root_at(&height)?;
+
+                client_state.verify_non_membership_with_context(
+                    self,
+                    &prefix,
+                    &proof_bytes(proof)?,
+                    &root,
+                    path,
+                )?;
+
+                Binary::default()
+            }
+        };
+
+        Ok(data)
+    }
+
+    /// Handles the `query` entry point, through which the host reads the
+    /// state of the client, or verifies client messages before submitting
+    /// them through `sudo`.
+    pub fn query(&self, msg: QueryMsg) -> Result<Binary, ContractError> {
+        let client_id = self.client_id();
+        let client_state = self.client_state(&client_id)?;
+
+        let data = match msg {
+            QueryMsg::Status {} => {
+                let status = client_state.status(self, &client_id)?;
+
+                to_json_binary(&StatusResult {
+                    status: status.to_string(),
+                })?
+            }
+            QueryMsg::TimestampAtHeight { height } => {
+                let consensus_state = self.consensus_state(&ClientConsensusStatePath::new(
+                    client_id,
+                    height.revision_number(),
+                    height.revision_height(),
+                ))?;
+
+                to_json_binary(&TimestampAtHeightResult {
+                    timestamp: consensus_state.timestamp().nanoseconds(),
+                })?
+            }
+            QueryMsg::VerifyClientMessage { client_message } => {
+                client_state.verify_client_message(
+                    self,
+                    &client_id,
+                    decode_any(&client_message)?,
+                )?;
+
+                Binary::default()
+            }
+            QueryMsg::CheckForMisbehaviour { client_message } => {
+                let found_misbehaviour = client_state.check_for_misbehaviour(
+                    self,
+                    &client_id,
+                    decode_any(&client_message)?,
+                )?;
+
+                to_json_binary(&CheckForMisbehaviourResult { found_misbehaviour })?
+            }
+        };
+
+        Ok(data)
+    }
+
+    /// Returns the commitment root of the consensus state at `height`.
+    fn root_at(&self, height: &Height) -> Result<CommitmentRoot, ContractError> {
+        let consensus_state = self.consensus_state(&ClientConsensusStatePath::new(
+            self.client_id(),
+            height.revision_number(),
+            height.revision_height(),
+        ))?;
+
+        Ok(consensus_state.root().clone())
+    }
+}
+
+fn proof_bytes(proof: Vec<u8>) -> Result<CommitmentProofBytes, ContractError> {
+    CommitmentProofBytes::try_from(proof).map_err(|e| ContractError::InvalidMsg {
+        reason: e.to_string(),
+    })
+}
+
+/// Splits the merkle path built by the host into the commitment prefix of the
+/// counterparty and the path of the proven value.
+fn parse_merkle_path(merkle_path: MerklePath) -> Result<(CommitmentPrefix, Path), ContractError> {
+    let invalid = |reason: String| ContractError::InvalidMsg { reason };
+
+    let [prefix, path]: [String; 2] =
+        merkle_path
+            .key_path
+            .try_into()
+            .map_err(|key_path: Vec<String>| {
+                invalid(format!(
+                    "merkle path must have 2 keys, a prefix and a path, but has {}",
+                    key_path.len()
+                ))
+            })?;
+
+    let prefix =
+        CommitmentPrefix::try_from(prefix.into_bytes()).map_err(|e| invalid(e.to_string()))?;
+    let path = Path::from_str(&path).map_err(|e| invalid(e.to_string()))?;
+
+    Ok((prefix, path))
+}
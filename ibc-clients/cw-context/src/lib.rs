@@ -0,0 +1,34 @@
+//! CosmWasm adapter running ibc-rs light clients as contracts of the ICS-08
+//! Wasm light client.
+//!
+//! The [`Context`](context::Context) implements the client contexts over the
+//! storage of the contract, laid out as ibc-go's `08-wasm` module expects, and
+//! dispatches the [`InstantiateMsg`](ibc_client_wasm::contract::InstantiateMsg),
+//! [`SudoMsg`](ibc_client_wasm::contract::SudoMsg) and
+//! [`QueryMsg`](ibc_client_wasm::contract::QueryMsg) of the Wasm client to the
+//! light client selected by a [`ClientType`](api::ClientType). A light client
+//! ships as a contract by forwarding its entry points to the context, as the
+//! `ibc-client-tendermint-cw` contract does for the Tendermint client.
+#![forbid(unsafe_code)]
+#![cfg_attr(not(test), deny(clippy::unwrap_used))]
+#![cfg_attr(not(test), deny(clippy::disallowed_methods, clippy::disallowed_types))]
+#![deny(
+    warnings,
+    trivial_casts,
+    trivial_numeric_casts,
+    unused_import_braces,
+    unused_qualifications,
+    rust_2018_idioms
+)]
+
+pub mod api;
+pub mod context;
+pub mod error;
+pub mod handlers;
+
+/// Re-exports the messages exchanged with the contracts, from the host-side
+/// implementation of the Wasm client.
+pub mod types {
+    #[doc(inline)]
+    pub use ibc_client_wasm::contract::*;
+}
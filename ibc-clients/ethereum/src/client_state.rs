@@ -0,0 +1,398 @@
+//! This module includes the implementations of the `ClientStateCommon`,
+//! `ClientStateValidation`, and `ClientStateExecution` traits for the
+//! Ethereum client state.
+//!
+//! As with the other clients, the logic behind each trait method is also
+//! exposed as a standalone function, so that hosts wrapping the Ethereum
+//! client can reuse it.
+
+mod common;
+mod execution;
+mod validation;
+
+pub use common::*;
+pub use execution::*;
+pub use validation::*;
+
+#[cfg(test)]
+mod tests {
+    use ibc_core_client::types::Status;
+    use ibc_core_commitment_types::commitment::{
+        CommitmentPrefix, CommitmentProofBytes, CommitmentRoot,
+    };
+    use ibc_core_host::types::identifiers::ClientId;
+    use ibc_core_host::types::path::{ClientStatePath, Path};
+    use ibc_primitives::prelude::*;
+    use ibc_primitives::proto::Any;
+    use prost::Message;
+
+    use super::*;
+    use crate::context::BlsVerifier;
+    use crate::error::Error;
+    use crate::mpt::keccak256;
+    use crate::mpt::tests::single_entry_trie;
+    use crate::rlp;
+    use crate::ssz::{hash_pair, Root};
+    use crate::types::proto::StorageProof;
+    use crate::types::{
+        compute_domain, compute_signing_root, AccountUpdate, BeaconBlockHeader, BlsPublicKey,
+        BlsSignature, ClientState, ExecutionPayloadHeader, Fork, ForkParameters, Header,
+        LightClientHeader, LightClientUpdate, Misbehaviour, SyncAggregate, SyncCommittee,
+        DOMAIN_SYNC_COMMITTEE,
+    };
+
+    const IBC_ADDRESS: [u8; 20] = [0xbc; 20];
+    const GENESIS_TIME: u64 = 1_600_000_000;
+    const SECONDS_PER_SLOT: u64 = 12;
+    /// Slots per sync committee period with the test configuration.
+    const PERIOD: u64 = 64;
+
+    /// A verifier accepting signatures made of the signing root followed by
+    /// the number of signers.
+    struct MockVerifier;
+
+    impl BlsVerifier for MockVerifier {
+        fn fast_aggregate_verify(
+            &self,
+            public_keys: &[&BlsPublicKey],
+            message: &Root,
+            signature: &BlsSignature,
+        ) -> Result<(), Error> {
+            if signature[..32] != message[..] || usize::from(signature[32]) != public_keys.len() {
+                return Err(Error::InvalidSignature {
+                    reason: "mock signature mismatch".to_string(),
+                });
+            }
+            Ok(())
+        }
+    }
+
+    fn sync_committee(seed: u8) -> SyncCommittee {
+        SyncCommittee {
+            pubkeys: (0u8..16).map(|i| [seed.wrapping_add(i); 48]).collect(),
+            aggregate_pubkey: [seed; 48],
+        }
+    }
+
+    fn fork(version: u8) -> Fork {
+        Fork {
+            version: [version, 0, 0, 1],
+            epoch: 0,
+        }
+    }
+
+    fn dummy_client_state() -> ClientState {
+        ClientState {
+            chain_id: 1,
+            genesis_validators_root: [0x47; 32],
+            genesis_time: GENESIS_TIME,
+            fork_parameters: ForkParameters {
+                genesis_fork_version: [0, 0, 0, 1],
+                altair: fork(1),
+                bellatrix: fork(2),
+                capella: fork(3),
+                deneb: fork(4),
+            },
+            seconds_per_slot: SECONDS_PER_SLOT,
+            slots_per_epoch: 8,
+            epochs_per_sync_committee_period: 8,
+            latest_slot: PERIOD + 10,
+            latest_execution_block_number: 100,
+            ibc_address: IBC_ADDRESS,
+            ibc_commitment_slot: [0; 32],
+            current_sync_committee: sync_committee(1),
+            next_sync_committee: Some(sync_committee(2)),
+            frozen_height: None,
+        }
+    }
+
+    /// Returns the root of the subtree in which `leaf` is at `index`.
+    fn branch_root(leaf: &Root, branch: &[Root], index: u64) -> Root {
+        branch.iter().enumerate().fold(*leaf, |node, (i, sibling)| {
+            if (index >> i) & 1 == 1 {
+                hash_pair(sibling, &node)
+            } else {
+                hash_pair(&node, sibling)
+            }
+        })
+    }
+
+    fn light_client_header(slot: u64, block_number: u64, state_root: Root) -> LightClientHeader {
+        let execution = ExecutionPayloadHeader {
+            parent_hash: [1; 32],
+            fee_recipient: [2; 20],
+            state_root,
+            receipts_root: [3; 32],
+            logs_bloom: [0; 256],
+            prev_randao: [4; 32],
+            block_number,
+            gas_limit: 30_000_000,
+            gas_used: 21_000,
+            timestamp: GENESIS_TIME + slot * SECONDS_PER_SLOT,
+            extra_data: b"ibc".to_vec(),
+            base_fee_per_gas: [7; 32],
+            block_hash: [5; 32],
+            transactions_root: [6; 32],
+            withdrawals_root: [8; 32],
+            blob_gas_used: 0,
+            excess_blob_gas: 0,
+        };
+        let execution_branch: Vec<Root> = (10u8..14).map(|i| [i; 32]).collect();
+        let body_root = branch_root(&execution.hash_tree_root(true), &execution_branch, 9);
+
+        LightClientHeader {
+            beacon: BeaconBlockHeader {
+                slot,
+                proposer_index: 42,
+                parent_root: [9; 32],
+                state_root: [slot as u8; 32],
+                body_root,
+            },
+            execution,
+            execution_branch,
+        }
+    }
+
+    fn sign(
+        client_state: &ClientState,
+        attested: &LightClientHeader,
+        signers: usize,
+    ) -> SyncAggregate {
+        let domain = compute_domain(
+            DOMAIN_SYNC_COMMITTEE,
+            client_state.fork_parameters.deneb.version,
+            &client_state.genesis_validators_root,
+        );
+        let signing_root = compute_signing_root(&attested.beacon.hash_tree_root(), &domain);
+
+        let mut bits = vec![0u8; 2];
+        for index in 0..signers {
+            bits[index / 8] |= 1 << (index % 8);
+        }
+        let mut signature = [0u8; 96];
+        signature[..32].copy_from_slice(&signing_root);
+        signature[32] = signers as u8;
+
+        SyncAggregate {
+            sync_committee_bits: bits,
+            sync_committee_signature: signature,
+        }
+    }
+
+    /// Returns an update finalizing the block at `finalized_slot`, signed by
+    /// `signers` members of the client's current sync committee.
+    fn light_client_update(
+        client_state: &ClientState,
+        finalized_slot: u64,
+        execution_state_root: Root,
+        signers: usize,
+    ) -> LightClientUpdate {
+        let finalized = light_client_header(finalized_slot, 101, execution_state_root);
+        let finality_branch: Vec<Root> = (20u8..26).map(|i| [i; 32]).collect();
+
+        let mut attested = light_client_header(finalized_slot + 8, 102, [0; 32]);
+        attested.beacon.state_root =
+            branch_root(&finalized.beacon.hash_tree_root(), &finality_branch, 41);
+
+        let sync_aggregate = sign(client_state, &attested, signers);
+
+        LightClientUpdate {
+            attested_header: attested,
+            next_sync_committee: None,
+            next_sync_committee_branch: vec![],
+            finalized_header: finalized,
+            finality_branch,
+            sync_aggregate,
+            signature_slot: finalized_slot + 9,
+        }
+    }
+
+    fn ibc_account(storage_root: &Root) -> Vec<u8> {
+        rlp::encode_list(&[
+            rlp::encode_bytes(&[1]),
+            rlp::encode_bytes(&[]),
+            rlp::encode_bytes(storage_root),
+            rlp::encode_bytes(&keccak256(&[])),
+        ])
+    }
+
+    fn dummy_header(client_state: &ClientState, signers: usize) -> Header {
+        let storage_root = [0x5e; 32];
+        let (state_root, account_proof) =
+            single_entry_trie(&keccak256(&IBC_ADDRESS), &ibc_account(&storage_root));
+
+        Header {
+            consensus_update: light_client_update(client_state, PERIOD + 20, state_root, signers),
+            account_update: AccountUpdate {
+                account_proof,
+                storage_root,
+            },
+        }
+    }
+
+    fn current_slot() -> u64 {
+        PERIOD + 40
+    }
+
+    #[test]
+    fn client_state_any_roundtrip() {
+        let client_state = dummy_client_state();
+        let any: Any = client_state.clone().into();
+        assert_eq!(
+            ClientState::try_from(any).expect("never fails"),
+            client_state
+        );
+    }
+
+    #[test]
+    fn header_any_roundtrip() {
+        let header = dummy_header(&dummy_client_state(), 16);
+        let any: Any = header.clone().into();
+        assert_eq!(Header::try_from(any).expect("never fails"), header);
+    }
+
+    #[test]
+    fn verify_valid_header() {
+        let client_state = dummy_client_state();
+        let header = dummy_header(&client_state, 11);
+
+        assert!(verify_header(&client_state, &header, current_slot(), &MockVerifier).is_ok());
+    }
+
+    #[test]
+    fn verify_header_rejects_insufficient_participation() {
+        let client_state = dummy_client_state();
+        let header = dummy_header(&client_state, 10);
+
+        assert!(verify_header(&client_state, &header, current_slot(), &MockVerifier).is_err());
+    }
+
+    #[test]
+    fn verify_header_rejects_invalid_proofs() {
+        let client_state = dummy_client_state();
+
+        let mut header = dummy_header(&client_state, 16);
+        header.consensus_update.finality_branch[0] = [0; 32];
+        assert!(verify_header(&client_state, &header, current_slot(), &MockVerifier).is_err());
+
+        let mut header = dummy_header(&client_state, 16);
+        header.consensus_update.finalized_header.execution.gas_used += 1;
+        assert!(verify_header(&client_state, &header, current_slot(), &MockVerifier).is_err());
+
+        let mut header = dummy_header(&client_state, 16);
+        header.account_update.storage_root = [0; 32];
+        assert!(verify_header(&client_state, &header, current_slot(), &MockVerifier).is_err());
+    }
+
+    #[test]
+    fn verify_header_rejects_signature_from_other_fork() {
+        let mut client_state = dummy_client_state();
+        let header = dummy_header(&client_state, 16);
+
+        client_state.fork_parameters.deneb.epoch = 1_000;
+        assert!(verify_header(&client_state, &header, current_slot(), &MockVerifier).is_err());
+    }
+
+    #[test]
+    fn verify_header_rejects_stale_or_future_updates() {
+        let client_state = dummy_client_state();
+        let header = dummy_header(&client_state, 16);
+
+        let mut advanced = client_state.clone();
+        advanced.latest_slot = PERIOD + 20;
+        assert!(verify_header(&advanced, &header, current_slot(), &MockVerifier).is_err());
+
+        assert!(verify_header(&client_state, &header, PERIOD + 28, &MockVerifier).is_err());
+    }
+
+    #[test]
+    fn apply_update_rotates_sync_committees() {
+        let client_state = dummy_client_state();
+
+        let update = light_client_update(&client_state, PERIOD + 20, [0; 32], 16);
+        let new_client_state = apply_light_client_update(&client_state, &update);
+        assert_eq!(new_client_state.latest_slot, PERIOD + 20);
+        assert_eq!(new_client_state.latest_execution_block_number, 101);
+        assert_eq!(
+            new_client_state.current_sync_committee,
+            client_state.current_sync_committee
+        );
+
+        let mut update = light_client_update(&client_state, 2 * PERIOD + 1, [0; 32], 16);
+        update.next_sync_committee = Some(sync_committee(3));
+        let new_client_state = apply_light_client_update(&client_state, &update);
+        assert_eq!(new_client_state.current_sync_committee, sync_committee(2));
+        assert_eq!(
+            new_client_state.next_sync_committee,
+            Some(sync_committee(3))
+        );
+    }
+
+    #[test]
+    fn verify_misbehaviour_with_conflicting_finality() {
+        let client_state = dummy_client_state();
+        let misbehaviour = Misbehaviour {
+            update_1: light_client_update(&client_state, PERIOD + 20, [1; 32], 16),
+            update_2: light_client_update(&client_state, PERIOD + 20, [2; 32], 16),
+        };
+        assert!(
+            verify_misbehaviour(&client_state, &misbehaviour, current_slot(), &MockVerifier)
+                .is_ok()
+        );
+
+        let same = Misbehaviour {
+            update_2: misbehaviour.update_1.clone(),
+            ..misbehaviour.clone()
+        };
+        assert!(verify_misbehaviour(&client_state, &same, current_slot(), &MockVerifier).is_err());
+
+        let mut forged = misbehaviour;
+        forged.update_2.sync_aggregate.sync_committee_signature = [0; 96];
+        assert!(
+            verify_misbehaviour(&client_state, &forged, current_slot(), &MockVerifier).is_err()
+        );
+    }
+
+    #[test]
+    fn verify_commitment_membership() {
+        let client_state = dummy_client_state();
+        let prefix = CommitmentPrefix::try_from(b"ibc".to_vec()).expect("never fails");
+        let client_id = ClientId::new("ethereum", 0).expect("never fails");
+        let path = Path::ClientState(ClientStatePath::new(client_id));
+        let value = b"commitment".to_vec();
+
+        let slot = commitment_storage_slot(&client_state, &prefix, &path);
+        let (root, proof) =
+            single_entry_trie(&keccak256(&slot), &rlp::encode_bytes(&keccak256(&value)));
+        let proof = CommitmentProofBytes::try_from(StorageProof { proof }.encode_to_vec())
+            .expect("never fails");
+        let root = CommitmentRoot::from_bytes(&root);
+
+        assert!(
+            verify_membership(&client_state, &prefix, &proof, &root, path.clone(), value).is_ok()
+        );
+        assert!(verify_membership(
+            &client_state,
+            &prefix,
+            &proof,
+            &root,
+            path.clone(),
+            b"other".to_vec()
+        )
+        .is_err());
+        assert!(verify_non_membership(&client_state, &prefix, &proof, &root, path).is_err());
+    }
+
+    #[test]
+    fn status_expires_after_next_period() {
+        let client_state = dummy_client_state();
+
+        assert_eq!(status(&client_state, 2 * PERIOD + 5), Status::Active);
+        assert_eq!(status(&client_state, 3 * PERIOD), Status::Expired);
+
+        let frozen = client_state
+            .clone()
+            .with_frozen_height(client_state.latest_height());
+        assert_eq!(status(&frozen, current_slot()), Status::Frozen);
+    }
+}
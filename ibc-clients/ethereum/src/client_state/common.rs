@@ -0,0 +1,184 @@
+use ibc_core_client::context::client_state::ClientStateCommon;
+use ibc_core_client::types::error::ClientError;
+use ibc_core_client::types::Height;
+use ibc_core_commitment_types::commitment::{
+    CommitmentPrefix, CommitmentProofBytes, CommitmentRoot,
+};
+use ibc_core_host::types::identifiers::ClientType;
+use ibc_core_host::types::path::Path;
+use ibc_primitives::prelude::*;
+use ibc_primitives::proto::Any;
+use prost::Message;
+
+use crate::client_type as ethereum_client_type;
+use crate::error::Error;
+use crate::mpt::{self, keccak256, H256};
+use crate::types::proto::StorageProof as RawStorageProof;
+use crate::types::{
+    storage_root, ClientState as ClientStateType, ConsensusState as ConsensusStateType,
+};
+
+impl ClientStateCommon for ClientStateType {
+    fn verify_consensus_state(&self, consensus_state: Any) -> Result<(), ClientError> {
+        verify_consensus_state(consensus_state)
+    }
+
+    fn client_type(&self) -> ClientType {
+        ethereum_client_type()
+    }
+
+    fn latest_height(&self) -> Height {
+        ClientStateType::latest_height(self)
+    }
+
+    fn validate_proof_height(&self, proof_height: Height) -> Result<(), ClientError> {
+        validate_proof_height(self, proof_height)
+    }
+
+    fn verify_upgrade_client(
+        &self,
+        _upgraded_client_state: Any,
+        _upgraded_consensus_state: Any,
+        _proof_upgrade_client: CommitmentProofBytes,
+        _proof_upgrade_consensus_state: CommitmentProofBytes,
+        _root: &CommitmentRoot,
+    ) -> Result<(), ClientError> {
+        Err(Error::UpgradeNotSupported.into())
+    }
+
+    fn verify_membership(
+        &self,
+        prefix: &CommitmentPrefix,
+        proof: &CommitmentProofBytes,
+        root: &CommitmentRoot,
+        path: Path,
+        value: Vec<u8>,
+    ) -> Result<(), ClientError> {
+        verify_membership(self, prefix, proof, root, path, value)
+    }
+
+    fn verify_non_membership(
+        &self,
+        prefix: &CommitmentPrefix,
+        proof: &CommitmentProofBytes,
+        root: &CommitmentRoot,
+        path: Path,
+    ) -> Result<(), ClientError> {
+        verify_non_membership(self, prefix, proof, root, path)
+    }
+}
+
+/// Verify an `Any` consensus state by attempting to convert it to an
+/// Ethereum `ConsensusState`.
+///
+/// Note that this function is typically implemented as part of the
+/// [`ClientStateCommon`] trait, but has been made a standalone function
+/// in order to make the ClientState APIs more flexible.
+pub fn verify_consensus_state(consensus_state: Any) -> Result<(), ClientError> {
+    ConsensusStateType::try_from(consensus_state)?;
+
+    Ok(())
+}
+
+/// Validate the given proof height against the client state's latest height,
+/// returning an error if the proof height is greater than the latest height
+/// of the client state.
+pub fn validate_proof_height(
+    client_state: &ClientStateType,
+    proof_height: Height,
+) -> Result<(), ClientError> {
+    let latest_height = client_state.latest_height();
+
+    if latest_height.revision_number() != proof_height.revision_number()
+        || latest_height < proof_height
+    {
+        return Err(ClientError::InvalidProofHeight {
+            latest_height,
+            proof_height,
+        });
+    }
+
+    Ok(())
+}
+
+/// Returns the storage slot of the IBC contract holding the commitment at
+/// `path`.
+///
+/// Commitments are kept in a `mapping(bytes32 => bytes32)` at
+/// `ibc_commitment_slot`, keyed by the hash of the commitment prefix
+/// followed by the path, so their slot is `keccak256(key ++ mapping_slot)`.
+pub fn commitment_storage_slot(
+    client_state: &ClientStateType,
+    prefix: &CommitmentPrefix,
+    path: &Path,
+) -> H256 {
+    let mut key = prefix.as_bytes().to_vec();
+    key.extend_from_slice(path.to_string().as_bytes());
+
+    let mut preimage = keccak256(&key).to_vec();
+    preimage.extend_from_slice(&client_state.ibc_commitment_slot);
+    keccak256(&preimage)
+}
+
+/// Decodes a commitment proof into the trie nodes of an `eth_getProof`
+/// storage proof.
+pub fn decode_storage_proof(proof: &CommitmentProofBytes) -> Result<Vec<Vec<u8>>, Error> {
+    Ok(RawStorageProof::decode(proof.as_ref())?.proof)
+}
+
+/// Verifies that the hash of `value` is committed at the given `path` in the
+/// storage of the IBC contract with the given `root`.
+pub fn verify_membership(
+    client_state: &ClientStateType,
+    prefix: &CommitmentPrefix,
+    proof: &CommitmentProofBytes,
+    root: &CommitmentRoot,
+    path: Path,
+    value: Vec<u8>,
+) -> Result<(), ClientError> {
+    if client_state.is_frozen() {
+        return Err(Error::FrozenClient.into());
+    }
+
+    let proof = decode_storage_proof(proof)?;
+    let slot = commitment_storage_slot(client_state, prefix, &path);
+    let expected = keccak256(&value);
+
+    let actual = mpt::verify_storage(&storage_root(root)?, &slot, &proof)?;
+    if actual != Some(expected) {
+        return Err(Error::TrieValueMismatch {
+            expected: expected.to_vec(),
+            actual: actual.map(|word| word.to_vec()),
+        }
+        .into());
+    }
+
+    Ok(())
+}
+
+/// Verifies that nothing is committed at the given `path` in the storage of
+/// the IBC contract with the given `root`.
+pub fn verify_non_membership(
+    client_state: &ClientStateType,
+    prefix: &CommitmentPrefix,
+    proof: &CommitmentProofBytes,
+    root: &CommitmentRoot,
+    path: Path,
+) -> Result<(), ClientError> {
+    if client_state.is_frozen() {
+        return Err(Error::FrozenClient.into());
+    }
+
+    let proof = decode_storage_proof(proof)?;
+    let slot = commitment_storage_slot(client_state, prefix, &path);
+
+    if let Some(actual) = mpt::verify_storage(&storage_root(root)?, &slot, &proof)? {
+        return Err(Error::TrieValueMismatch {
+            expected: vec![],
+            actual: Some(actual.to_vec()),
+        }
+        .into());
+    }
+
+    Ok(())
+}
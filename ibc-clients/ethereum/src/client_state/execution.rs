@@ -0,0 +1,191 @@
+use ibc_core_client::context::prelude::*;
+use ibc_core_client::types::error::ClientError;
+use ibc_core_client::types::Height;
+use ibc_core_host::types::identifiers::ClientId;
+use ibc_core_host::types::path::{ClientConsensusStatePath, ClientStatePath};
+use ibc_primitives::prelude::*;
+use ibc_primitives::proto::Any;
+use ibc_primitives::Timestamp;
+
+use crate::context::ExecutionContext;
+use crate::error::Error;
+use crate::types::{
+    ClientState as ClientStateType, ConsensusState as ConsensusStateType, Header as EthereumHeader,
+    LightClientUpdate,
+};
+
+impl<E> ClientStateExecution<E> for ClientStateType
+where
+    E: ExecutionContext,
+    E::ClientStateRef: From<ClientStateType>,
+    E::ConsensusStateRef: From<ConsensusStateType>,
+{
+    fn initialise(
+        &self,
+        ctx: &mut E,
+        client_id: &ClientId,
+        consensus_state: Any,
+    ) -> Result<(), ClientError> {
+        initialise(self, ctx, client_id, consensus_state)
+    }
+
+    fn update_state(
+        &self,
+        ctx: &mut E,
+        client_id: &ClientId,
+        header: Any,
+    ) -> Result<Vec<Height>, ClientError> {
+        update_state(self, ctx, client_id, header)
+    }
+
+    fn update_state_on_misbehaviour(
+        &self,
+        ctx: &mut E,
+        client_id: &ClientId,
+        _client_message: Any,
+    ) -> Result<(), ClientError> {
+        update_on_misbehaviour(self, ctx, client_id)
+    }
+
+    fn update_state_on_upgrade(
+        &self,
+        _ctx: &mut E,
+        _client_id: &ClientId,
+        _upgraded_client_state: Any,
+        _upgraded_consensus_state: Any,
+    ) -> Result<Height, ClientError> {
+        Err(Error::UpgradeNotSupported.into())
+    }
+}
+
+/// Seed the host store with initial client and consensus states.
+pub fn initialise<E>(
+    client_state: &ClientStateType,
+    ctx: &mut E,
+    client_id: &ClientId,
+    consensus_state: Any,
+) -> Result<(), ClientError>
+where
+    E: ClientExecutionContext,
+    E::ClientStateRef: From<ClientStateType>,
+    E::ConsensusStateRef: From<ConsensusStateType>,
+{
+    let ethereum_consensus_state = ConsensusStateType::try_from(consensus_state)?;
+    let latest_height = client_state.latest_height();
+
+    ctx.store_client_state(
+        ClientStatePath::new(client_id.clone()),
+        client_state.clone().into(),
+    )?;
+    ctx.store_consensus_state(
+        ClientConsensusStatePath::new(
+            client_id.clone(),
+            latest_height.revision_number(),
+            latest_height.revision_height(),
+        ),
+        ethereum_consensus_state.into(),
+    )?;
+
+    Ok(())
+}
+
+/// Applies a verified header: stores the consensus state of the finalized
+/// execution block and advances the client state, rotating sync committees
+/// when the finalized block enters the next period.
+///
+/// Returns the height of the newly stored consensus state.
+pub fn update_state<E>(
+    client_state: &ClientStateType,
+    ctx: &mut E,
+    client_id: &ClientId,
+    header: Any,
+) -> Result<Vec<Height>, ClientError>
+where
+    E: ClientExecutionContext,
+    E::ClientStateRef: From<ClientStateType>,
+    E::ConsensusStateRef: From<ConsensusStateType>,
+{
+    let header = EthereumHeader::try_from(header)?;
+    let height = header.height()?;
+    let execution = &header.consensus_update.finalized_header.execution;
+
+    let timestamp =
+        Timestamp::from_seconds(execution.timestamp).map_err(|_| Error::InvalidRawHeader {
+            reason: format!("invalid execution timestamp {}", execution.timestamp),
+        })?;
+
+    let consensus_state = ConsensusStateType::new(
+        header.consensus_update.finalized_header.beacon.slot,
+        execution.state_root,
+        header.account_update.storage_root,
+        timestamp,
+    );
+
+    ctx.store_consensus_state(
+        ClientConsensusStatePath::new(
+            client_id.clone(),
+            height.revision_number(),
+            height.revision_height(),
+        ),
+        consensus_state.into(),
+    )?;
+
+    let new_client_state = apply_light_client_update(client_state, &header.consensus_update);
+
+    ctx.store_client_state(
+        ClientStatePath::new(client_id.clone()),
+        new_client_state.into(),
+    )?;
+
+    Ok(vec![height])
+}
+
+/// Returns the client state after applying a verified light client update,
+/// as in `apply_light_client_update` of the sync protocol.
+pub fn apply_light_client_update(
+    client_state: &ClientStateType,
+    update: &LightClientUpdate,
+) -> ClientStateType {
+    let finalized = &update.finalized_header;
+    let store_period = client_state.sync_committee_period(client_state.latest_slot);
+    let attested_period = client_state.sync_committee_period(update.attested_header.beacon.slot);
+    let finalized_period = client_state.sync_committee_period(finalized.beacon.slot);
+
+    let mut new_client_state = client_state.clone();
+
+    if finalized_period > store_period {
+        if let Some(next) = new_client_state.next_sync_committee.take() {
+            new_client_state.current_sync_committee = next;
+        }
+        new_client_state.next_sync_committee = update.next_sync_committee.clone();
+    } else if attested_period == store_period && new_client_state.next_sync_committee.is_none() {
+        new_client_state.next_sync_committee = update.next_sync_committee.clone();
+    }
+
+    new_client_state.latest_slot = finalized.beacon.slot;
+    new_client_state.latest_execution_block_number = finalized.execution.block_number;
+
+    new_client_state
+}
+
+/// Freezes the client after misbehaviour has been detected and verified.
+pub fn update_on_misbehaviour<E>(
+    client_state: &ClientStateType,
+    ctx: &mut E,
+    client_id: &ClientId,
+) -> Result<(), ClientError>
+where
+    E: ClientExecutionContext,
+    E::ClientStateRef: From<ClientStateType>,
+{
+    let frozen_client_state = client_state
+        .clone()
+        .with_frozen_height(client_state.latest_height());
+
+    ctx.store_client_state(
+        ClientStatePath::new(client_id.clone()),
+        frozen_client_state.into(),
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,318 @@
+use ibc_core_client::context::client_state::ClientStateValidation;
+use ibc_core_client::context::consensus_state::ConsensusState as _;
+use ibc_core_client::types::error::ClientError;
+use ibc_core_client::types::Status;
+use ibc_core_host::types::identifiers::ClientId;
+use ibc_core_host::types::path::ClientConsensusStatePath;
+use ibc_primitives::prelude::*;
+use ibc_primitives::proto::Any;
+
+use crate::context::{BlsVerifier, ValidationContext};
+use crate::error::Error;
+use crate::mpt;
+use crate::types::{
+    compute_domain, compute_signing_root, ClientState as ClientStateType, Header as EthereumHeader,
+    LightClientUpdate, Misbehaviour as EthereumMisbehaviour, DOMAIN_SYNC_COMMITTEE,
+    ETHEREUM_HEADER_TYPE_URL, ETHEREUM_MISBEHAVIOUR_TYPE_URL,
+};
+
+impl<V> ClientStateValidation<V> for ClientStateType
+where
+    V: ValidationContext,
+{
+    fn verify_client_message(
+        &self,
+        ctx: &V,
+        _client_id: &ClientId,
+        client_message: Any,
+    ) -> Result<(), ClientError> {
+        verify_client_message(self, ctx, client_message)
+    }
+
+    fn check_for_misbehaviour(
+        &self,
+        ctx: &V,
+        client_id: &ClientId,
+        client_message: Any,
+    ) -> Result<bool, ClientError> {
+        check_for_misbehaviour(self, ctx, client_id, client_message)
+    }
+
+    fn status(&self, ctx: &V, _client_id: &ClientId) -> Result<Status, ClientError> {
+        let current_slot = self.slot_at(ctx.host_timestamp()?);
+
+        Ok(status(self, current_slot))
+    }
+}
+
+/// Verify the client message as part of the client state validation process.
+///
+/// Note that this function is typically implemented as part of the
+/// [`ClientStateValidation`] trait, but has been made a standalone function
+/// in order to make the ClientState APIs more flexible.
+pub fn verify_client_message<V>(
+    client_state: &ClientStateType,
+    ctx: &V,
+    client_message: Any,
+) -> Result<(), ClientError>
+where
+    V: ValidationContext,
+{
+    let current_slot = client_state.slot_at(ctx.host_timestamp()?);
+
+    match client_message.type_url.as_str() {
+        ETHEREUM_HEADER_TYPE_URL => {
+            let header = EthereumHeader::try_from(client_message)?;
+            verify_header(client_state, &header, current_slot, ctx)
+        }
+        ETHEREUM_MISBEHAVIOUR_TYPE_URL => {
+            let misbehaviour = EthereumMisbehaviour::try_from(client_message)?;
+            verify_misbehaviour(client_state, &misbehaviour, current_slot, ctx)
+        }
+        _ => Err(ClientError::InvalidUpdateClientMessage),
+    }
+}
+
+/// Verifies that `header` finalizes a beacon block more recent than the
+/// latest known one, and that it carries the storage root of the IBC
+/// contract at the finalized execution block.
+pub fn verify_header(
+    client_state: &ClientStateType,
+    header: &EthereumHeader,
+    current_slot: u64,
+    verifier: &impl BlsVerifier,
+) -> Result<(), ClientError> {
+    if client_state.is_frozen() {
+        return Err(Error::FrozenClient.into());
+    }
+
+    let update = &header.consensus_update;
+    let finalized_slot = update.finalized_header.beacon.slot;
+
+    if finalized_slot <= client_state.latest_slot {
+        return Err(Error::StaleUpdate {
+            slot: finalized_slot,
+            latest: client_state.latest_slot,
+        }
+        .into());
+    }
+
+    verify_light_client_update(client_state, update, current_slot, verifier)?;
+    verify_account_update(client_state, header)?;
+
+    Ok(())
+}
+
+/// Verifies a light client update against the sync committees known to the
+/// client, following `validate_light_client_update` of the sync protocol.
+///
+/// Only finality updates are accepted, and they must be signed by a
+/// supermajority of the sync committee, which the sync protocol requires
+/// before applying an update without a timeout.
+pub fn verify_light_client_update(
+    client_state: &ClientStateType,
+    update: &LightClientUpdate,
+    current_slot: u64,
+    verifier: &impl BlsVerifier,
+) -> Result<(), Error> {
+    let attested = &update.attested_header;
+    let finalized = &update.finalized_header;
+
+    if update.signature_slot > current_slot {
+        return Err(Error::InvalidSlots {
+            reason: format!(
+                "signature slot {} is past the current slot {current_slot}",
+                update.signature_slot
+            ),
+        });
+    }
+
+    if !(update.signature_slot > attested.beacon.slot
+        && attested.beacon.slot >= finalized.beacon.slot)
+    {
+        return Err(Error::InvalidSlots {
+            reason: format!(
+                "expected signature slot {} > attested slot {} >= finalized slot {}",
+                update.signature_slot, attested.beacon.slot, finalized.beacon.slot
+            ),
+        });
+    }
+
+    let store_period = client_state.sync_committee_period(client_state.latest_slot);
+    let signature_period = client_state.sync_committee_period(update.signature_slot);
+
+    let sync_committee = match client_state.sync_committee(signature_period) {
+        Some(sync_committee) => sync_committee,
+        None if Some(signature_period) == store_period.checked_add(1) => {
+            return Err(Error::UnknownNextSyncCommittee)
+        }
+        None => {
+            return Err(Error::InvalidSignaturePeriod {
+                period: signature_period,
+                current: store_period,
+            })
+        }
+    };
+
+    let size = sync_committee.pubkeys.len();
+    let aggregate = &update.sync_aggregate;
+
+    if aggregate.sync_committee_bits.len() != size / 8 {
+        return Err(Error::InvalidLength {
+            field: "sync committee bits",
+            expected: size / 8,
+            actual: aggregate.sync_committee_bits.len(),
+        });
+    }
+
+    let participants = aggregate.num_participants();
+    if participants * 3 < size * 2 {
+        return Err(Error::InsufficientParticipation { participants, size });
+    }
+
+    let fork_parameters = &client_state.fork_parameters;
+    for header in [attested, finalized] {
+        let epoch = client_state.compute_epoch(header.beacon.slot);
+
+        if !fork_parameters.is_capella(epoch) {
+            return Err(Error::InvalidSlots {
+                reason: format!("slot {} precedes Capella", header.beacon.slot),
+            });
+        }
+
+        header.verify_execution_branch(fork_parameters.is_deneb(epoch))?;
+    }
+
+    update.verify_finality_branch()?;
+    update.verify_next_sync_committee_branch()?;
+
+    let attested_period = client_state.sync_committee_period(attested.beacon.slot);
+    let finalized_period = client_state.sync_committee_period(finalized.beacon.slot);
+
+    match (
+        &update.next_sync_committee,
+        &client_state.next_sync_committee,
+    ) {
+        (Some(update_next), Some(known_next))
+            if attested_period == store_period && update_next != known_next =>
+        {
+            return Err(Error::MismatchedNextSyncCommittee)
+        }
+        (None, _) if finalized_period != store_period => {
+            return Err(Error::MissingNextSyncCommittee)
+        }
+        _ => {}
+    }
+
+    let signature_epoch = client_state.compute_epoch(update.signature_slot.saturating_sub(1));
+    let domain = compute_domain(
+        DOMAIN_SYNC_COMMITTEE,
+        fork_parameters.fork_version(signature_epoch),
+        &client_state.genesis_validators_root,
+    );
+    let signing_root = compute_signing_root(&attested.beacon.hash_tree_root(), &domain);
+
+    verifier.fast_aggregate_verify(
+        &aggregate.participants(sync_committee),
+        &signing_root,
+        &aggregate.sync_committee_signature,
+    )
+}
+
+/// Verifies the account proof of the IBC contract against the execution
+/// state root of the finalized block.
+pub fn verify_account_update(
+    client_state: &ClientStateType,
+    header: &EthereumHeader,
+) -> Result<(), Error> {
+    let state_root = header
+        .consensus_update
+        .finalized_header
+        .execution
+        .state_root;
+
+    let account = mpt::verify_account(
+        &state_root,
+        &client_state.ibc_address,
+        &header.account_update.account_proof,
+    )?
+    .ok_or_else(|| Error::InvalidTrieProof {
+        reason: "IBC contract account is absent from the state".to_string(),
+    })?;
+
+    if account.storage_root != header.account_update.storage_root {
+        return Err(Error::MismatchedStorageRoot);
+    }
+
+    Ok(())
+}
+
+/// Verifies that both updates of `misbehaviour` are signed by a sync
+/// committee known to the client.
+pub fn verify_misbehaviour(
+    client_state: &ClientStateType,
+    misbehaviour: &EthereumMisbehaviour,
+    current_slot: u64,
+    verifier: &impl BlsVerifier,
+) -> Result<(), ClientError> {
+    misbehaviour.validate_basic()?;
+
+    for update in [&misbehaviour.update_1, &misbehaviour.update_2] {
+        verify_light_client_update(client_state, update, current_slot, verifier)?;
+    }
+
+    Ok(())
+}
+
+/// Checks for misbehaviour in the given client message. A verified
+/// `Misbehaviour` is always evidence of misbehaviour, while a verified
+/// `Header` is if it implies a storage root conflicting with the one of a
+/// consensus state already stored at the same block number.
+pub fn check_for_misbehaviour<V>(
+    _client_state: &ClientStateType,
+    ctx: &V,
+    client_id: &ClientId,
+    client_message: Any,
+) -> Result<bool, ClientError>
+where
+    V: ValidationContext,
+{
+    match client_message.type_url.as_str() {
+        ETHEREUM_HEADER_TYPE_URL => {
+            let header = EthereumHeader::try_from(client_message)?;
+            let height = header.height()?;
+            let path = ClientConsensusStatePath::new(
+                client_id.clone(),
+                height.revision_number(),
+                height.revision_height(),
+            );
+
+            match ctx.consensus_state(&path) {
+                Ok(existing) => {
+                    Ok(existing.root().as_bytes() != header.account_update.storage_root)
+                }
+                Err(_) => Ok(false),
+            }
+        }
+        ETHEREUM_MISBEHAVIOUR_TYPE_URL => Ok(true),
+        _ => Err(ClientError::InvalidUpdateClientMessage),
+    }
+}
+
+/// Returns the status of the Ethereum client at `current_slot`.
+///
+/// Updates must be signed by the sync committee of the latest finalized
+/// block's period or of the next one, so the client expires once the
+/// current slot is past the next period.
+pub fn status(client_state: &ClientStateType, current_slot: u64) -> Status {
+    if client_state.is_frozen() {
+        return Status::Frozen;
+    }
+
+    let store_period = client_state.sync_committee_period(client_state.latest_slot);
+    if client_state.sync_committee_period(current_slot) > store_period.saturating_add(1) {
+        return Status::Expired;
+    }
+
+    Status::Active
+}
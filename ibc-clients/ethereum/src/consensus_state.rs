@@ -0,0 +1,24 @@
+//! Implements the core [`ConsensusState`](ConsensusStateTrait) trait for the
+//! Ethereum consensus state.
+
+use ibc_core_client::context::consensus_state::ConsensusState as ConsensusStateTrait;
+use ibc_core_commitment_types::commitment::CommitmentRoot;
+use ibc_primitives::prelude::*;
+use ibc_primitives::proto::{Any, Protobuf};
+use ibc_primitives::Timestamp;
+
+use crate::types::ConsensusState;
+
+impl ConsensusStateTrait for ConsensusState {
+    fn root(&self) -> &CommitmentRoot {
+        ConsensusState::root(self)
+    }
+
+    fn timestamp(&self) -> Timestamp {
+        ConsensusState::timestamp(self)
+    }
+
+    fn encode_vec(self) -> Vec<u8> {
+        <Self as Protobuf<Any>>::encode_vec(self)
+    }
+}
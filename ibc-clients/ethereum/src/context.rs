@@ -0,0 +1,42 @@
+use ibc_core_client::context::prelude::*;
+
+use crate::error::Error;
+use crate::ssz::Root;
+use crate::types::{BlsPublicKey, BlsSignature};
+
+/// Specifies the BLS12-381 signature verification the Ethereum client needs
+/// from its host.
+///
+/// Hosts typically implement this on top of a precompile or a native
+/// library (e.g. `blst`); the client itself ships no pairing-based
+/// cryptography.
+pub trait BlsVerifier {
+    /// Verifies that `signature` is the aggregate of the signatures of
+    /// `message` by each of `public_keys`, as in `FastAggregateVerify` of
+    /// the BLS signature standard used by the consensus specs.
+    fn fast_aggregate_verify(
+        &self,
+        public_keys: &[&BlsPublicKey],
+        message: &Root,
+        signature: &BlsSignature,
+    ) -> Result<(), Error>;
+}
+
+/// Client's context required during validation
+///
+/// The current timestamp of the host, read from its [`HostClock`], is used to
+/// bound the slots of updates, which cannot be signed in the future.
+///
+/// This trait is automatically implemented for all types that implement
+/// [`ClientValidationContext`], [`BlsVerifier`] and [`HostClock`]
+pub trait ValidationContext: ClientValidationContext + BlsVerifier + HostClock {}
+
+impl<T> ValidationContext for T where T: ClientValidationContext + BlsVerifier + HostClock {}
+
+/// Client's context required during execution.
+///
+/// This trait is automatically implemented for all types that implement
+/// [`ValidationContext`] and [`ClientExecutionContext`]
+pub trait ExecutionContext: ValidationContext + ClientExecutionContext {}
+
+impl<T> ExecutionContext for T where T: ValidationContext + ClientExecutionContext {}
@@ -0,0 +1,89 @@
+//! Defines the Ethereum light client's error type
+
+use displaydoc::Display;
+use ibc_core_client::types::error::ClientError;
+use ibc_primitives::prelude::*;
+use prost::DecodeError;
+
+/// The main error type
+#[derive(Debug, Display)]
+pub enum Error {
+    /// invalid raw client state: `{reason}`
+    InvalidRawClientState { reason: String },
+    /// invalid raw consensus state: `{reason}`
+    InvalidRawConsensusState { reason: String },
+    /// invalid raw header: `{reason}`
+    InvalidRawHeader { reason: String },
+    /// invalid raw misbehaviour: `{reason}`
+    InvalidRawMisbehaviour { reason: String },
+    /// protobuf decoding error: `{0}`
+    Decode(DecodeError),
+    /// invalid length for `{field}`: expected `{expected}` bytes, got `{actual}`
+    InvalidLength {
+        field: &'static str,
+        expected: usize,
+        actual: usize,
+    },
+    /// sync committee size `{size}` is not a non-zero power of two
+    InvalidSyncCommitteeSize { size: usize },
+    /// insufficient sync committee participation: `{participants}` out of `{size}`
+    InsufficientParticipation { participants: usize, size: usize },
+    /// invalid slot ordering: `{reason}`
+    InvalidSlots { reason: String },
+    /// finalized slot `{slot}` is not higher than the latest finalized slot `{latest}`
+    StaleUpdate { slot: u64, latest: u64 },
+    /// signature period `{period}` is neither the current period `{current}` nor the next one
+    InvalidSignaturePeriod { period: u64, current: u64 },
+    /// the next sync committee is unknown
+    UnknownNextSyncCommittee,
+    /// update to the next period does not carry the next sync committee
+    MissingNextSyncCommittee,
+    /// next sync committee does not match the known next sync committee
+    MismatchedNextSyncCommittee,
+    /// invalid merkle branch for `{field}`
+    InvalidMerkleBranch { field: &'static str },
+    /// invalid sync committee signature: `{reason}`
+    InvalidSignature { reason: String },
+    /// invalid RLP encoding: `{reason}`
+    InvalidRlp { reason: String },
+    /// invalid trie proof: `{reason}`
+    InvalidTrieProof { reason: String },
+    /// trie value mismatch: expected `{expected:?}`, got `{actual:?}`
+    TrieValueMismatch {
+        expected: Vec<u8>,
+        actual: Option<Vec<u8>>,
+    },
+    /// account storage root does not match the account proof
+    MismatchedStorageRoot,
+    /// both updates finalize the same header at slot `{slot}`
+    NotMisbehaviour { slot: u64 },
+    /// client is frozen
+    FrozenClient,
+    /// Ethereum clients cannot be upgraded
+    UpgradeNotSupported,
+}
+
+impl core::error::Error for Error {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match &self {
+            // `prost` implements the trait only with `std`
+            #[cfg(feature = "std")]
+            Self::Decode(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<Error> for ClientError {
+    fn from(e: Error) -> Self {
+        Self::ClientSpecific {
+            description: e.to_string(),
+        }
+    }
+}
+
+impl From<DecodeError> for Error {
+    fn from(e: DecodeError) -> Self {
+        Self::Decode(e)
+    }
+}
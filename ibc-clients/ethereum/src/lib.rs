@@ -0,0 +1,53 @@
+//! Ethereum light client implementation.
+//!
+//! The Ethereum client follows the beacon chain through the [Altair light
+//! client sync protocol][sync-protocol], as amended by Capella and Deneb:
+//! finalized beacon block headers are signed by the current sync committee,
+//! which rotates every sync committee period. The execution payload header
+//! of each finalized block is proven against its beacon block body, and the
+//! storage of the IBC contract against the execution state root through
+//! Merkle-Patricia trie proofs.
+//!
+//! Verifying sync committee signatures requires BLS12-381 pairings, which
+//! hosts commonly provide through precompiles. The client therefore leaves
+//! BLS verification to the host through the [`BlsVerifier`](context::BlsVerifier)
+//! trait.
+//!
+//! [sync-protocol]: https://github.com/ethereum/consensus-specs/blob/dev/specs/altair/light-client/sync-protocol.md
+#![no_std]
+#![forbid(unsafe_code)]
+#![cfg_attr(not(test), deny(clippy::unwrap_used))]
+#![cfg_attr(not(test), deny(clippy::disallowed_methods, clippy::disallowed_types))]
+#![deny(
+    warnings,
+    trivial_casts,
+    trivial_numeric_casts,
+    unused_import_braces,
+    unused_qualifications,
+    rust_2018_idioms
+)]
+
+extern crate alloc;
+
+#[cfg(any(test, feature = "std"))]
+extern crate std;
+
+pub mod client_state;
+pub mod consensus_state;
+pub mod context;
+pub mod error;
+pub mod mpt;
+pub mod rlp;
+pub mod ssz;
+pub mod types;
+
+use core::str::FromStr;
+
+use ibc_core_host::types::identifiers::ClientType;
+
+pub const ETHEREUM_CLIENT_TYPE: &str = "ethereum";
+
+/// Returns the Ethereum `ClientType`
+pub fn client_type() -> ClientType {
+    ClientType::from_str(ETHEREUM_CLIENT_TYPE).expect("Never fails because it's valid")
+}
@@ -0,0 +1,303 @@
+//! Verification of Merkle-Patricia trie proofs, as returned by
+//! `eth_getProof`, against execution layer state and storage roots.
+
+use alloc::collections::BTreeMap;
+
+use sha3::{Digest, Keccak256};
+
+use crate::error::Error;
+use crate::rlp::{self, Item};
+use ibc_primitives::prelude::*;
+
+pub type H256 = [u8; 32];
+
+/// The root of an empty trie, i.e. `keccak256(rlp(""))`.
+pub const EMPTY_TRIE_ROOT: H256 = [
+    0x56, 0xe8, 0x1f, 0x17, 0x1b, 0xcc, 0x55, 0xa6, 0xff, 0x83, 0x45, 0xe6, 0x92, 0xc0, 0xf8, 0x6e,
+    0x5b, 0x48, 0xe0, 0x1b, 0x99, 0x6c, 0xad, 0xc0, 0x01, 0x62, 0x2f, 0xb5, 0xe3, 0x63, 0xb4, 0x21,
+];
+
+pub fn keccak256(data: &[u8]) -> H256 {
+    Keccak256::digest(data).into()
+}
+
+/// An account of the execution layer state trie.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Account {
+    pub nonce: u64,
+    /// The balance, as a big-endian integer without leading zeros.
+    pub balance: Vec<u8>,
+    pub storage_root: H256,
+    pub code_hash: H256,
+}
+
+impl Account {
+    pub fn decode(encoded: &[u8]) -> Result<Self, Error> {
+        let item = rlp::decode(encoded)?;
+        let [nonce, balance, storage_root, code_hash] = item.as_list()? else {
+            return Err(invalid_proof("account must have four fields"));
+        };
+
+        let nonce = nonce.as_bytes()?;
+        if nonce.len() > 8 {
+            return Err(invalid_proof("account nonce overflows"));
+        }
+
+        Ok(Self {
+            nonce: nonce.iter().fold(0, |acc, b| (acc << 8) | u64::from(*b)),
+            balance: balance.as_bytes()?.to_vec(),
+            storage_root: hash(storage_root.as_bytes()?)?,
+            code_hash: hash(code_hash.as_bytes()?)?,
+        })
+    }
+}
+
+/// Verifies an account proof against the state root and returns the account
+/// at `address`, or `None` if the proof shows there is none.
+pub fn verify_account(
+    state_root: &H256,
+    address: &[u8; 20],
+    proof: &[Vec<u8>],
+) -> Result<Option<Account>, Error> {
+    read_proof_check(state_root, proof, &keccak256(address))?
+        .map(|encoded| Account::decode(&encoded))
+        .transpose()
+}
+
+/// Verifies a storage proof against an account's storage root and returns
+/// the 32-byte word in the storage slot `slot`, or `None` if the slot is
+/// empty.
+///
+/// Storage values are RLP-encoded without their leading zeros, which are
+/// restored here.
+pub fn verify_storage(
+    storage_root: &H256,
+    slot: &H256,
+    proof: &[Vec<u8>],
+) -> Result<Option<H256>, Error> {
+    let Some(encoded) = read_proof_check(storage_root, proof, &keccak256(slot))? else {
+        return Ok(None);
+    };
+
+    let value = rlp::decode(&encoded)?.as_bytes()?;
+    if value.len() > 32 {
+        return Err(invalid_proof("storage value exceeds 32 bytes"));
+    }
+
+    let mut word = [0u8; 32];
+    word[32 - value.len()..].copy_from_slice(value);
+    Ok(Some(word))
+}
+
+/// Walks the trie with the given `root` along `key` using the nodes of
+/// `proof`, and returns the value stored at `key`, or `None` if the proof
+/// shows that `key` is absent.
+///
+/// Nodes may be given in any order; nodes whose encoding is shorter than 32
+/// bytes are inlined in their parent, as in the trie itself.
+pub fn read_proof_check(
+    root: &H256,
+    proof: &[Vec<u8>],
+    key: &[u8],
+) -> Result<Option<Vec<u8>>, Error> {
+    if *root == EMPTY_TRIE_ROOT {
+        return Ok(None);
+    }
+
+    let nodes: BTreeMap<H256, &[u8]> = proof
+        .iter()
+        .map(|node| (keccak256(node), node.as_slice()))
+        .collect();
+
+    let nibbles: Vec<u8> = key.iter().flat_map(|b| [b >> 4, b & 0x0f]).collect();
+    let mut remaining = nibbles.as_slice();
+    let mut node = lookup(&nodes, root)?;
+
+    loop {
+        let item = rlp::decode(node)?;
+
+        let child = match item.as_list()? {
+            [children @ .., value] if children.len() == 16 => match remaining.split_first() {
+                None => {
+                    let value = value.as_bytes()?;
+                    return Ok((!value.is_empty()).then(|| value.to_vec()));
+                }
+                Some((nibble, rest)) => {
+                    remaining = rest;
+                    &children[usize::from(*nibble)]
+                }
+            },
+            [path, child] => {
+                let (is_leaf, path) = decode_path(path.as_bytes()?)?;
+
+                if is_leaf {
+                    if path != remaining {
+                        return Ok(None);
+                    }
+                    return Ok(Some(child.as_bytes()?.to_vec()));
+                }
+
+                match remaining.strip_prefix(path.as_slice()) {
+                    Some(rest) => {
+                        remaining = rest;
+                        child
+                    }
+                    None => return Ok(None),
+                }
+            }
+            _ => return Err(invalid_proof("trie node must have 2 or 17 items")),
+        };
+
+        node = match child {
+            Item::Bytes(reference) if reference.is_empty() => return Ok(None),
+            Item::Bytes(reference) => lookup(&nodes, &hash(reference)?)?,
+            Item::List(_, encoded) => *encoded,
+        };
+    }
+}
+
+fn lookup<'a>(nodes: &BTreeMap<H256, &'a [u8]>, hash: &H256) -> Result<&'a [u8], Error> {
+    nodes
+        .get(hash)
+        .copied()
+        .ok_or_else(|| invalid_proof("missing trie node"))
+}
+
+/// Decodes the hex-prefix encoded path of a leaf or extension node, and
+/// returns whether the node is a leaf along with its nibbles.
+fn decode_path(encoded: &[u8]) -> Result<(bool, Vec<u8>), Error> {
+    let (&first, rest) = encoded
+        .split_first()
+        .ok_or_else(|| invalid_proof("empty node path"))?;
+
+    let flag = first >> 4;
+    if flag > 3 {
+        return Err(invalid_proof("invalid node path flag"));
+    }
+
+    let mut nibbles = Vec::with_capacity(rest.len() * 2 + 1);
+    if flag & 1 == 1 {
+        nibbles.push(first & 0x0f);
+    }
+    nibbles.extend(rest.iter().flat_map(|b| [b >> 4, b & 0x0f]));
+
+    Ok((flag & 2 == 2, nibbles))
+}
+
+fn hash(bytes: &[u8]) -> Result<H256, Error> {
+    bytes.try_into().map_err(|_| Error::InvalidLength {
+        field: "hash",
+        expected: 32,
+        actual: bytes.len(),
+    })
+}
+
+fn invalid_proof(reason: &str) -> Error {
+    Error::InvalidTrieProof {
+        reason: reason.to_string(),
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+
+    /// Returns the hex-prefix encoding of a leaf path.
+    fn encode_leaf_path(nibbles: &[u8]) -> Vec<u8> {
+        let mut encoded = if nibbles.len() % 2 == 1 {
+            vec![0x30 | nibbles[0]]
+        } else {
+            vec![0x20]
+        };
+        let even = &nibbles[nibbles.len() % 2..];
+        encoded.extend(even.chunks(2).map(|pair| (pair[0] << 4) | pair[1]));
+        encoded
+    }
+
+    fn leaf(nibbles: &[u8], value: &[u8]) -> Vec<u8> {
+        rlp::encode_list(&[
+            rlp::encode_bytes(&encode_leaf_path(nibbles)),
+            rlp::encode_bytes(value),
+        ])
+    }
+
+    fn nibbles(key: &[u8]) -> Vec<u8> {
+        key.iter().flat_map(|b| [b >> 4, b & 0x0f]).collect()
+    }
+
+    /// Returns the root of a trie holding a single entry, and the proof of
+    /// that entry.
+    pub(crate) fn single_entry_trie(key: &[u8], value: &[u8]) -> (H256, Vec<Vec<u8>>) {
+        let node = leaf(&nibbles(key), value);
+        (keccak256(&node), vec![node])
+    }
+
+    #[test]
+    fn verify_branch_proof() {
+        let first = [0x11; 32];
+        let second = [0x22; 32];
+
+        let first_leaf = leaf(&nibbles(&first)[1..], b"one");
+        let second_leaf = leaf(&nibbles(&second)[1..], b"two");
+
+        let mut children = vec![rlp::encode_bytes(&[]); 17];
+        children[1] = rlp::encode_bytes(&keccak256(&first_leaf));
+        children[2] = rlp::encode_bytes(&keccak256(&second_leaf));
+        let branch = rlp::encode_list(&children);
+        let root = keccak256(&branch);
+
+        let proof = vec![second_leaf, branch.clone(), first_leaf];
+        assert_eq!(
+            read_proof_check(&root, &proof, &first).expect("valid proof"),
+            Some(b"one".to_vec())
+        );
+        assert_eq!(
+            read_proof_check(&root, &proof, &second).expect("valid proof"),
+            Some(b"two".to_vec())
+        );
+
+        // an empty child proves absence
+        assert_eq!(
+            read_proof_check(&root, &[branch.clone()], &[0x33; 32]).expect("valid proof"),
+            None
+        );
+
+        // a missing node cannot be skipped
+        assert!(read_proof_check(&root, &[branch], &first).is_err());
+    }
+
+    #[test]
+    fn verify_account_and_storage() {
+        let slot = [1u8; 32];
+        let (storage_root, storage_proof) =
+            single_entry_trie(&keccak256(&slot), &rlp::encode_bytes(&[0xaa; 32]));
+
+        let address = [2u8; 20];
+        let account = rlp::encode_list(&[
+            rlp::encode_bytes(&[1]),
+            rlp::encode_bytes(&[]),
+            rlp::encode_bytes(&storage_root),
+            rlp::encode_bytes(&keccak256(&[])),
+        ]);
+        let (state_root, account_proof) = single_entry_trie(&keccak256(&address), &account);
+
+        let account = verify_account(&state_root, &address, &account_proof)
+            .expect("valid proof")
+            .expect("account exists");
+        assert_eq!(account.nonce, 1);
+        assert_eq!(account.storage_root, storage_root);
+
+        assert_eq!(
+            verify_storage(&storage_root, &slot, &storage_proof).expect("valid proof"),
+            Some([0xaa; 32])
+        );
+        assert_eq!(
+            verify_storage(&storage_root, &[3u8; 32], &storage_proof).expect("valid proof"),
+            None
+        );
+        assert_eq!(
+            verify_storage(&EMPTY_TRIE_ROOT, &slot, &[]).expect("valid proof"),
+            None
+        );
+    }
+}
@@ -0,0 +1,174 @@
+//! A minimal RLP decoder, sufficient to walk Merkle-Patricia trie nodes and
+//! read accounts.
+
+use crate::error::Error;
+use ibc_primitives::prelude::*;
+
+/// A decoded RLP item, borrowing from the encoded input.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Item<'a> {
+    Bytes(&'a [u8]),
+    /// A list, together with its full encoding.
+    List(Vec<Item<'a>>, &'a [u8]),
+}
+
+impl<'a> Item<'a> {
+    pub fn as_bytes(&self) -> Result<&'a [u8], Error> {
+        match self {
+            Self::Bytes(bytes) => Ok(bytes),
+            Self::List(..) => Err(invalid("expected bytes, found a list")),
+        }
+    }
+
+    pub fn as_list(&self) -> Result<&[Item<'a>], Error> {
+        match self {
+            Self::List(items, _) => Ok(items),
+            Self::Bytes(_) => Err(invalid("expected a list, found bytes")),
+        }
+    }
+}
+
+/// Decodes a single RLP item spanning the whole input.
+pub fn decode(input: &[u8]) -> Result<Item<'_>, Error> {
+    let (item, rest) = decode_item(input)?;
+    if !rest.is_empty() {
+        return Err(invalid("trailing bytes"));
+    }
+    Ok(item)
+}
+
+fn decode_item(input: &[u8]) -> Result<(Item<'_>, &[u8]), Error> {
+    let (&prefix, _) = input
+        .split_first()
+        .ok_or_else(|| invalid("unexpected end of input"))?;
+
+    match prefix {
+        0x00..=0x7f => Ok((Item::Bytes(&input[..1]), &input[1..])),
+        0x80..=0xb7 => {
+            let len = usize::from(prefix - 0x80);
+            let (payload, rest) = split(&input[1..], len)?;
+            if len == 1 && payload[0] < 0x80 {
+                return Err(invalid("non-canonical single byte"));
+            }
+            Ok((Item::Bytes(payload), rest))
+        }
+        0xb8..=0xbf => {
+            let (len, rest) = long_length(&input[1..], usize::from(prefix - 0xb7))?;
+            let (payload, rest) = split(rest, len)?;
+            Ok((Item::Bytes(payload), rest))
+        }
+        0xc0..=0xf7 => {
+            let len = usize::from(prefix - 0xc0);
+            let (payload, rest) = split(&input[1..], len)?;
+            let encoded = &input[..1 + len];
+            Ok((Item::List(decode_list(payload)?, encoded), rest))
+        }
+        0xf8..=0xff => {
+            let len_of_len = usize::from(prefix - 0xf7);
+            let (len, after_len) = long_length(&input[1..], len_of_len)?;
+            let (payload, rest) = split(after_len, len)?;
+            let encoded = &input[..1 + len_of_len + len];
+            Ok((Item::List(decode_list(payload)?, encoded), rest))
+        }
+    }
+}
+
+fn decode_list(mut payload: &[u8]) -> Result<Vec<Item<'_>>, Error> {
+    let mut items = Vec::new();
+    while !payload.is_empty() {
+        let (item, rest) = decode_item(payload)?;
+        items.push(item);
+        payload = rest;
+    }
+    Ok(items)
+}
+
+fn long_length(input: &[u8], len_of_len: usize) -> Result<(usize, &[u8]), Error> {
+    let (len_bytes, rest) = split(input, len_of_len)?;
+    if len_bytes.first() == Some(&0) || len_of_len > core::mem::size_of::<usize>() {
+        return Err(invalid("non-canonical length"));
+    }
+    let len = len_bytes
+        .iter()
+        .fold(0usize, |acc, b| (acc << 8) | usize::from(*b));
+    if len < 56 {
+        return Err(invalid("non-canonical length"));
+    }
+    Ok((len, rest))
+}
+
+fn split(input: &[u8], len: usize) -> Result<(&[u8], &[u8]), Error> {
+    if input.len() < len {
+        return Err(invalid("unexpected end of input"));
+    }
+    Ok(input.split_at(len))
+}
+
+fn invalid(reason: &str) -> Error {
+    Error::InvalidRlp {
+        reason: reason.to_string(),
+    }
+}
+
+/// Encodes a byte string, as needed to build trie nodes in tests and to
+/// compare storage values.
+pub fn encode_bytes(bytes: &[u8]) -> Vec<u8> {
+    if bytes.len() == 1 && bytes[0] < 0x80 {
+        return bytes.to_vec();
+    }
+    let mut out = encode_length(bytes.len(), 0x80);
+    out.extend_from_slice(bytes);
+    out
+}
+
+/// Encodes a list from the encodings of its items.
+pub fn encode_list(items: &[Vec<u8>]) -> Vec<u8> {
+    let payload: Vec<u8> = items.concat();
+    let mut out = encode_length(payload.len(), 0xc0);
+    out.extend(payload);
+    out
+}
+
+fn encode_length(len: usize, offset: u8) -> Vec<u8> {
+    if len < 56 {
+        return vec![offset + len as u8];
+    }
+    let len_bytes: Vec<u8> = len
+        .to_be_bytes()
+        .into_iter()
+        .skip_while(|b| *b == 0)
+        .collect();
+    let mut out = vec![offset + 55 + len_bytes.len() as u8];
+    out.extend(len_bytes);
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn roundtrip() {
+        let long = [7u8; 60];
+        let encoded = encode_list(&[
+            encode_bytes(b"dog"),
+            encode_bytes(&[0x01]),
+            encode_bytes(&long),
+            encode_list(&[]),
+        ]);
+
+        let item = decode(&encoded).expect("valid rlp");
+        let items = item.as_list().expect("list");
+        assert_eq!(items[0].as_bytes().expect("bytes"), b"dog");
+        assert_eq!(items[1].as_bytes().expect("bytes"), &[0x01]);
+        assert_eq!(items[2].as_bytes().expect("bytes"), &long[..]);
+        assert!(items[3].as_list().expect("list").is_empty());
+    }
+
+    #[test]
+    fn rejects_malformed_input() {
+        assert!(decode(&[0x83, b'd', b'o']).is_err());
+        assert!(decode(&[0x81, 0x01]).is_err());
+        assert!(decode(&[0x01, 0x02]).is_err());
+    }
+}
@@ -0,0 +1,153 @@
+//! SSZ merkleization, as needed to compute the hash tree roots of beacon
+//! chain containers and to verify merkle branches into them.
+
+use sha2::{Digest, Sha256};
+
+use ibc_primitives::prelude::*;
+
+pub type Root = [u8; 32];
+
+pub fn hash_pair(left: &Root, right: &Root) -> Root {
+    let mut hasher = Sha256::new();
+    hasher.update(left);
+    hasher.update(right);
+    hasher.finalize().into()
+}
+
+/// Returns the root of the merkle tree over `chunks`, padded with zero
+/// chunks up to the next power of two of `limit`.
+pub fn merkleize(chunks: &[Root], limit: usize) -> Root {
+    let width = limit.max(chunks.len()).max(1).next_power_of_two();
+    let depth = width.trailing_zeros() as usize;
+
+    let mut zero = [0u8; 32];
+    let mut layer = chunks.to_vec();
+
+    for _ in 0..depth {
+        if layer.len() % 2 == 1 {
+            layer.push(zero);
+        }
+        layer = layer
+            .chunks(2)
+            .map(|pair| hash_pair(&pair[0], &pair[1]))
+            .collect();
+        zero = hash_pair(&zero, &zero);
+    }
+
+    layer.first().copied().unwrap_or(zero)
+}
+
+/// Mixes the length of a list into the root of its contents.
+pub fn mix_in_length(root: &Root, length: usize) -> Root {
+    let mut length_chunk = [0u8; 32];
+    length_chunk[..8].copy_from_slice(&(length as u64).to_le_bytes());
+    hash_pair(root, &length_chunk)
+}
+
+/// Returns the chunk of a `uint64`.
+pub fn u64_root(value: u64) -> Root {
+    let mut chunk = [0u8; 32];
+    chunk[..8].copy_from_slice(&value.to_le_bytes());
+    chunk
+}
+
+/// Returns the hash tree root of a fixed-size byte vector.
+pub fn bytes_root(bytes: &[u8]) -> Root {
+    merkleize(&pack(bytes), 0)
+}
+
+/// Returns the hash tree root of a byte list with the given maximum length.
+pub fn byte_list_root(bytes: &[u8], max_length: usize) -> Root {
+    mix_in_length(
+        &merkleize(&pack(bytes), (max_length + 31) / 32),
+        bytes.len(),
+    )
+}
+
+/// Packs bytes into right-padded chunks.
+fn pack(bytes: &[u8]) -> Vec<Root> {
+    bytes
+        .chunks(32)
+        .map(|chunk| {
+            let mut root = [0u8; 32];
+            root[..chunk.len()].copy_from_slice(chunk);
+            root
+        })
+        .collect()
+}
+
+/// Verifies that `leaf` is at position `index` of the subtree of the given
+/// `depth` whose root is `root`, as in `is_valid_merkle_branch` of the
+/// consensus specs.
+pub fn is_valid_merkle_branch(
+    leaf: &Root,
+    branch: &[Root],
+    depth: usize,
+    index: u64,
+    root: &Root,
+) -> bool {
+    if branch.len() != depth {
+        return false;
+    }
+
+    let computed = branch.iter().enumerate().fold(*leaf, |node, (i, sibling)| {
+        if (index >> i) & 1 == 1 {
+            hash_pair(sibling, &node)
+        } else {
+            hash_pair(&node, sibling)
+        }
+    });
+
+    computed == *root
+}
+
+/// Returns the depth and subtree index of a generalized index.
+pub const fn gindex_depth_and_index(gindex: u64) -> (usize, u64) {
+    let depth = 63 - gindex.leading_zeros() as usize;
+    (depth, gindex % (1 << depth))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn merkleize_pads_with_zero_hashes() {
+        let a = [1u8; 32];
+        let b = [2u8; 32];
+        let zero = [0u8; 32];
+
+        assert_eq!(merkleize(&[a], 1), a);
+        assert_eq!(merkleize(&[a, b], 2), hash_pair(&a, &b));
+        assert_eq!(
+            merkleize(&[a, b, a], 4),
+            hash_pair(&hash_pair(&a, &b), &hash_pair(&a, &zero))
+        );
+        assert_eq!(merkleize(&[], 2), hash_pair(&zero, &zero));
+    }
+
+    #[test]
+    fn merkle_branch() {
+        let leaves: Vec<Root> = (0u8..4).map(|i| [i; 32]).collect();
+        let root = merkleize(&leaves, 4);
+        let branch = [leaves[3], hash_pair(&leaves[0], &leaves[1])];
+
+        assert!(is_valid_merkle_branch(&leaves[2], &branch, 2, 2, &root));
+        assert!(!is_valid_merkle_branch(&leaves[2], &branch, 2, 3, &root));
+        assert!(!is_valid_merkle_branch(
+            &leaves[2],
+            &branch[..1],
+            2,
+            2,
+            &root
+        ));
+    }
+
+    #[test]
+    fn generalized_indices() {
+        // finalized root, next sync committee, execution payload
+        assert_eq!(gindex_depth_and_index(105), (6, 41));
+        assert_eq!(gindex_depth_and_index(55), (5, 23));
+        assert_eq!(gindex_depth_and_index(25), (4, 9));
+    }
+}
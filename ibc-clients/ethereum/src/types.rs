@@ -0,0 +1,17 @@
+//! Data structures and domain types of the Ethereum light client.
+
+mod beacon;
+mod client_state;
+mod consensus_state;
+mod fork;
+mod header;
+mod misbehaviour;
+
+pub use beacon::*;
+pub use client_state::*;
+pub use consensus_state::*;
+pub use fork::*;
+pub use header::*;
+pub use misbehaviour::*;
+
+pub mod proto;
@@ -0,0 +1,510 @@
+//! Beacon chain containers used by the light client sync protocol, along
+//! with their hash tree roots.
+
+use ibc_primitives::prelude::*;
+
+use super::proto::{
+    BeaconBlockHeader as RawBeaconBlockHeader, ExecutionPayloadHeader as RawExecutionPayloadHeader,
+    LightClientHeader as RawLightClientHeader, LightClientUpdate as RawLightClientUpdate,
+    SyncAggregate as RawSyncAggregate, SyncCommittee as RawSyncCommittee,
+};
+use crate::error::Error;
+use crate::ssz::{
+    byte_list_root, bytes_root, gindex_depth_and_index, hash_pair, is_valid_merkle_branch,
+    merkleize, u64_root, Root,
+};
+
+pub type BlsPublicKey = [u8; 48];
+pub type BlsSignature = [u8; 96];
+
+/// Generalized index of `finalized_checkpoint.root` in a beacon state.
+pub const FINALIZED_ROOT_GINDEX: u64 = 105;
+/// Generalized index of `next_sync_committee` in a beacon state.
+pub const NEXT_SYNC_COMMITTEE_GINDEX: u64 = 55;
+/// Generalized index of `execution_payload` in a beacon block body.
+pub const EXECUTION_PAYLOAD_GINDEX: u64 = 25;
+
+/// The maximum length of the `extra_data` of an execution payload.
+pub const MAX_EXTRA_DATA_BYTES: usize = 32;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BeaconBlockHeader {
+    pub slot: u64,
+    pub proposer_index: u64,
+    pub parent_root: Root,
+    pub state_root: Root,
+    pub body_root: Root,
+}
+
+impl BeaconBlockHeader {
+    pub fn hash_tree_root(&self) -> Root {
+        merkleize(
+            &[
+                u64_root(self.slot),
+                u64_root(self.proposer_index),
+                self.parent_root,
+                self.state_root,
+                self.body_root,
+            ],
+            5,
+        )
+    }
+}
+
+/// The header of an execution payload, as of Deneb. The blob gas fields are
+/// ignored for Capella payloads.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ExecutionPayloadHeader {
+    pub parent_hash: Root,
+    pub fee_recipient: [u8; 20],
+    pub state_root: Root,
+    pub receipts_root: Root,
+    pub logs_bloom: [u8; 256],
+    pub prev_randao: Root,
+    pub block_number: u64,
+    pub gas_limit: u64,
+    pub gas_used: u64,
+    /// Unix timestamp in seconds.
+    pub timestamp: u64,
+    pub extra_data: Vec<u8>,
+    /// Little-endian `uint256`.
+    pub base_fee_per_gas: [u8; 32],
+    pub block_hash: Root,
+    pub transactions_root: Root,
+    pub withdrawals_root: Root,
+    pub blob_gas_used: u64,
+    pub excess_blob_gas: u64,
+}
+
+impl ExecutionPayloadHeader {
+    pub fn hash_tree_root(&self, is_deneb: bool) -> Root {
+        let mut fields = vec![
+            self.parent_hash,
+            bytes_root(&self.fee_recipient),
+            self.state_root,
+            self.receipts_root,
+            bytes_root(&self.logs_bloom),
+            self.prev_randao,
+            u64_root(self.block_number),
+            u64_root(self.gas_limit),
+            u64_root(self.gas_used),
+            u64_root(self.timestamp),
+            byte_list_root(&self.extra_data, MAX_EXTRA_DATA_BYTES),
+            self.base_fee_per_gas,
+            self.block_hash,
+            self.transactions_root,
+            self.withdrawals_root,
+        ];
+
+        if is_deneb {
+            fields.push(u64_root(self.blob_gas_used));
+            fields.push(u64_root(self.excess_blob_gas));
+        }
+
+        merkleize(&fields, fields.len())
+    }
+}
+
+/// A beacon block header along with the execution payload header of its
+/// block and the proof of the latter against the block body.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LightClientHeader {
+    pub beacon: BeaconBlockHeader,
+    pub execution: ExecutionPayloadHeader,
+    pub execution_branch: Vec<Root>,
+}
+
+impl LightClientHeader {
+    /// Verifies the execution payload header against the beacon block body
+    /// root, as in `is_valid_light_client_header` of the consensus specs.
+    pub fn verify_execution_branch(&self, is_deneb: bool) -> Result<(), Error> {
+        let (depth, index) = gindex_depth_and_index(EXECUTION_PAYLOAD_GINDEX);
+
+        if !is_valid_merkle_branch(
+            &self.execution.hash_tree_root(is_deneb),
+            &self.execution_branch,
+            depth,
+            index,
+            &self.beacon.body_root,
+        ) {
+            return Err(Error::InvalidMerkleBranch {
+                field: "execution payload",
+            });
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SyncCommittee {
+    pub pubkeys: Vec<BlsPublicKey>,
+    pub aggregate_pubkey: BlsPublicKey,
+}
+
+impl SyncCommittee {
+    pub fn hash_tree_root(&self) -> Root {
+        let pubkeys: Vec<Root> = self.pubkeys.iter().map(|key| bytes_root(key)).collect();
+        hash_pair(
+            &merkleize(&pubkeys, pubkeys.len()),
+            &bytes_root(&self.aggregate_pubkey),
+        )
+    }
+}
+
+/// The participation bits and aggregate signature of a sync committee.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SyncAggregate {
+    /// A bitvector with one bit per committee member, in little-endian bit
+    /// order.
+    pub sync_committee_bits: Vec<u8>,
+    pub sync_committee_signature: BlsSignature,
+}
+
+impl SyncAggregate {
+    pub fn has_participated(&self, index: usize) -> bool {
+        self.sync_committee_bits
+            .get(index / 8)
+            .is_some_and(|byte| (byte >> (index % 8)) & 1 == 1)
+    }
+
+    /// Returns the public keys of the members of `committee` who took part
+    /// in the aggregate signature.
+    pub fn participants<'a>(&self, committee: &'a SyncCommittee) -> Vec<&'a BlsPublicKey> {
+        committee
+            .pubkeys
+            .iter()
+            .enumerate()
+            .filter(|(index, _)| self.has_participated(*index))
+            .map(|(_, key)| key)
+            .collect()
+    }
+
+    pub fn num_participants(&self) -> usize {
+        self.sync_committee_bits
+            .iter()
+            .map(|byte| byte.count_ones() as usize)
+            .sum()
+    }
+}
+
+/// A light client update finalizing a beacon block, as in the sync
+/// protocol's `LightClientUpdate`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LightClientUpdate {
+    /// The header signed by the sync committee.
+    pub attested_header: LightClientHeader,
+    /// The next sync committee of the attested state, present when the
+    /// update carries it.
+    pub next_sync_committee: Option<SyncCommittee>,
+    pub next_sync_committee_branch: Vec<Root>,
+    /// The header finalized by the attested state.
+    pub finalized_header: LightClientHeader,
+    pub finality_branch: Vec<Root>,
+    pub sync_aggregate: SyncAggregate,
+    pub signature_slot: u64,
+}
+
+impl LightClientUpdate {
+    /// Verifies that the finalized header is the finalized checkpoint of the
+    /// attested state.
+    pub fn verify_finality_branch(&self) -> Result<(), Error> {
+        let (depth, index) = gindex_depth_and_index(FINALIZED_ROOT_GINDEX);
+
+        if !is_valid_merkle_branch(
+            &self.finalized_header.beacon.hash_tree_root(),
+            &self.finality_branch,
+            depth,
+            index,
+            &self.attested_header.beacon.state_root,
+        ) {
+            return Err(Error::InvalidMerkleBranch {
+                field: "finalized header",
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Verifies that the next sync committee, if any, is the one of the
+    /// attested state.
+    pub fn verify_next_sync_committee_branch(&self) -> Result<(), Error> {
+        let Some(next_sync_committee) = &self.next_sync_committee else {
+            return Ok(());
+        };
+        let (depth, index) = gindex_depth_and_index(NEXT_SYNC_COMMITTEE_GINDEX);
+
+        if !is_valid_merkle_branch(
+            &next_sync_committee.hash_tree_root(),
+            &self.next_sync_committee_branch,
+            depth,
+            index,
+            &self.attested_header.beacon.state_root,
+        ) {
+            return Err(Error::InvalidMerkleBranch {
+                field: "next sync committee",
+            });
+        }
+
+        Ok(())
+    }
+}
+
+pub(crate) fn fixed_bytes<const N: usize>(
+    raw: Vec<u8>,
+    field: &'static str,
+) -> Result<[u8; N], Error> {
+    raw.try_into().map_err(|raw: Vec<u8>| Error::InvalidLength {
+        field,
+        expected: N,
+        actual: raw.len(),
+    })
+}
+
+fn roots(raw: Vec<Vec<u8>>, field: &'static str) -> Result<Vec<Root>, Error> {
+    raw.into_iter()
+        .map(|root| fixed_bytes(root, field))
+        .collect()
+}
+
+fn missing(field: &str) -> Error {
+    Error::InvalidRawHeader {
+        reason: format!("missing {field}"),
+    }
+}
+
+impl TryFrom<RawBeaconBlockHeader> for BeaconBlockHeader {
+    type Error = Error;
+
+    fn try_from(raw: RawBeaconBlockHeader) -> Result<Self, Self::Error> {
+        Ok(Self {
+            slot: raw.slot,
+            proposer_index: raw.proposer_index,
+            parent_root: fixed_bytes(raw.parent_root, "parent root")?,
+            state_root: fixed_bytes(raw.state_root, "state root")?,
+            body_root: fixed_bytes(raw.body_root, "body root")?,
+        })
+    }
+}
+
+impl From<BeaconBlockHeader> for RawBeaconBlockHeader {
+    fn from(value: BeaconBlockHeader) -> Self {
+        Self {
+            slot: value.slot,
+            proposer_index: value.proposer_index,
+            parent_root: value.parent_root.to_vec(),
+            state_root: value.state_root.to_vec(),
+            body_root: value.body_root.to_vec(),
+        }
+    }
+}
+
+impl TryFrom<RawExecutionPayloadHeader> for ExecutionPayloadHeader {
+    type Error = Error;
+
+    fn try_from(raw: RawExecutionPayloadHeader) -> Result<Self, Self::Error> {
+        if raw.extra_data.len() > MAX_EXTRA_DATA_BYTES {
+            return Err(Error::InvalidRawHeader {
+                reason: format!("extra data exceeds {MAX_EXTRA_DATA_BYTES} bytes"),
+            });
+        }
+
+        Ok(Self {
+            parent_hash: fixed_bytes(raw.parent_hash, "parent hash")?,
+            fee_recipient: fixed_bytes(raw.fee_recipient, "fee recipient")?,
+            state_root: fixed_bytes(raw.state_root, "execution state root")?,
+            receipts_root: fixed_bytes(raw.receipts_root, "receipts root")?,
+            logs_bloom: fixed_bytes(raw.logs_bloom, "logs bloom")?,
+            prev_randao: fixed_bytes(raw.prev_randao, "prev randao")?,
+            block_number: raw.block_number,
+            gas_limit: raw.gas_limit,
+            gas_used: raw.gas_used,
+            timestamp: raw.timestamp,
+            extra_data: raw.extra_data,
+            base_fee_per_gas: fixed_bytes(raw.base_fee_per_gas, "base fee per gas")?,
+            block_hash: fixed_bytes(raw.block_hash, "block hash")?,
+            transactions_root: fixed_bytes(raw.transactions_root, "transactions root")?,
+            withdrawals_root: fixed_bytes(raw.withdrawals_root, "withdrawals root")?,
+            blob_gas_used: raw.blob_gas_used,
+            excess_blob_gas: raw.excess_blob_gas,
+        })
+    }
+}
+
+impl From<ExecutionPayloadHeader> for RawExecutionPayloadHeader {
+    fn from(value: ExecutionPayloadHeader) -> Self {
+        Self {
+            parent_hash: value.parent_hash.to_vec(),
+            fee_recipient: value.fee_recipient.to_vec(),
+            state_root: value.state_root.to_vec(),
+            receipts_root: value.receipts_root.to_vec(),
+            logs_bloom: value.logs_bloom.to_vec(),
+            prev_randao: value.prev_randao.to_vec(),
+            block_number: value.block_number,
+            gas_limit: value.gas_limit,
+            gas_used: value.gas_used,
+            timestamp: value.timestamp,
+            extra_data: value.extra_data,
+            base_fee_per_gas: value.base_fee_per_gas.to_vec(),
+            block_hash: value.block_hash.to_vec(),
+            transactions_root: value.transactions_root.to_vec(),
+            withdrawals_root: value.withdrawals_root.to_vec(),
+            blob_gas_used: value.blob_gas_used,
+            excess_blob_gas: value.excess_blob_gas,
+        }
+    }
+}
+
+impl TryFrom<RawLightClientHeader> for LightClientHeader {
+    type Error = Error;
+
+    fn try_from(raw: RawLightClientHeader) -> Result<Self, Self::Error> {
+        Ok(Self {
+            beacon: raw
+                .beacon
+                .ok_or_else(|| missing("beacon header"))?
+                .try_into()?,
+            execution: raw
+                .execution
+                .ok_or_else(|| missing("execution payload header"))?
+                .try_into()?,
+            execution_branch: roots(raw.execution_branch, "execution branch")?,
+        })
+    }
+}
+
+impl From<LightClientHeader> for RawLightClientHeader {
+    fn from(value: LightClientHeader) -> Self {
+        Self {
+            beacon: Some(value.beacon.into()),
+            execution: Some(value.execution.into()),
+            execution_branch: value.execution_branch.iter().map(|r| r.to_vec()).collect(),
+        }
+    }
+}
+
+impl TryFrom<RawSyncCommittee> for SyncCommittee {
+    type Error = Error;
+
+    fn try_from(raw: RawSyncCommittee) -> Result<Self, Self::Error> {
+        let pubkeys = raw
+            .pubkeys
+            .into_iter()
+            .map(|key| fixed_bytes(key, "sync committee public key"))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        if pubkeys.is_empty() || !pubkeys.len().is_power_of_two() {
+            return Err(Error::InvalidSyncCommitteeSize {
+                size: pubkeys.len(),
+            });
+        }
+
+        Ok(Self {
+            pubkeys,
+            aggregate_pubkey: fixed_bytes(raw.aggregate_pubkey, "aggregate public key")?,
+        })
+    }
+}
+
+impl From<SyncCommittee> for RawSyncCommittee {
+    fn from(value: SyncCommittee) -> Self {
+        Self {
+            pubkeys: value.pubkeys.iter().map(|key| key.to_vec()).collect(),
+            aggregate_pubkey: value.aggregate_pubkey.to_vec(),
+        }
+    }
+}
+
+impl TryFrom<RawSyncAggregate> for SyncAggregate {
+    type Error = Error;
+
+    fn try_from(raw: RawSyncAggregate) -> Result<Self, Self::Error> {
+        Ok(Self {
+            sync_committee_bits: raw.sync_committee_bits,
+            sync_committee_signature: fixed_bytes(
+                raw.sync_committee_signature,
+                "sync committee signature",
+            )?,
+        })
+    }
+}
+
+impl From<SyncAggregate> for RawSyncAggregate {
+    fn from(value: SyncAggregate) -> Self {
+        Self {
+            sync_committee_bits: value.sync_committee_bits,
+            sync_committee_signature: value.sync_committee_signature.to_vec(),
+        }
+    }
+}
+
+impl TryFrom<RawLightClientUpdate> for LightClientUpdate {
+    type Error = Error;
+
+    fn try_from(raw: RawLightClientUpdate) -> Result<Self, Self::Error> {
+        Ok(Self {
+            attested_header: raw
+                .attested_header
+                .ok_or_else(|| missing("attested header"))?
+                .try_into()?,
+            next_sync_committee: raw.next_sync_committee.map(TryInto::try_into).transpose()?,
+            next_sync_committee_branch: roots(
+                raw.next_sync_committee_branch,
+                "next sync committee branch",
+            )?,
+            finalized_header: raw
+                .finalized_header
+                .ok_or_else(|| missing("finalized header"))?
+                .try_into()?,
+            finality_branch: roots(raw.finality_branch, "finality branch")?,
+            sync_aggregate: raw
+                .sync_aggregate
+                .ok_or_else(|| missing("sync aggregate"))?
+                .try_into()?,
+            signature_slot: raw.signature_slot,
+        })
+    }
+}
+
+impl From<LightClientUpdate> for RawLightClientUpdate {
+    fn from(value: LightClientUpdate) -> Self {
+        Self {
+            attested_header: Some(value.attested_header.into()),
+            next_sync_committee: value.next_sync_committee.map(Into::into),
+            next_sync_committee_branch: value
+                .next_sync_committee_branch
+                .iter()
+                .map(|r| r.to_vec())
+                .collect(),
+            finalized_header: Some(value.finalized_header.into()),
+            finality_branch: value.finality_branch.iter().map(|r| r.to_vec()).collect(),
+            sync_aggregate: Some(value.sync_aggregate.into()),
+            signature_slot: value.signature_slot,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sync_aggregate_participants() {
+        let committee = SyncCommittee {
+            pubkeys: (0u8..16).map(|i| [i; 48]).collect(),
+            aggregate_pubkey: [0xff; 48],
+        };
+        let aggregate = SyncAggregate {
+            sync_committee_bits: vec![0b0000_0101, 0b1000_0000],
+            sync_committee_signature: [0; 96],
+        };
+
+        assert_eq!(aggregate.num_participants(), 3);
+        let participants: Vec<u8> = aggregate
+            .participants(&committee)
+            .into_iter()
+            .map(|key| key[0])
+            .collect();
+        assert_eq!(participants, vec![0, 2, 15]);
+    }
+}
@@ -0,0 +1,213 @@
+//! Defines the client state type for the Ethereum light client.
+
+use ibc_core_client::types::error::ClientError;
+use ibc_core_client::types::Height;
+use ibc_primitives::prelude::*;
+use ibc_primitives::proto::{Any, Protobuf};
+use ibc_primitives::Timestamp;
+
+use super::beacon::{fixed_bytes, SyncCommittee};
+use super::fork::ForkParameters;
+use super::proto::ClientState as RawEthereumClientState;
+use crate::error::Error;
+use crate::ssz::Root;
+
+pub const ETHEREUM_CLIENT_STATE_TYPE_URL: &str = "/ibc.lightclients.ethereum.v1.ClientState";
+
+/// Contains the state of an Ethereum client.
+///
+/// The client tracks the latest finalized beacon block and the sync
+/// committees of its period and of the next one. Consensus states are
+/// indexed by execution block number, with a revision number of 0, as
+/// Ethereum has no notion of revisions.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ClientState {
+    /// The EVM chain id of the execution layer.
+    pub chain_id: u64,
+    pub genesis_validators_root: Root,
+    /// Unix timestamp of the beacon chain genesis, in seconds.
+    pub genesis_time: u64,
+    pub fork_parameters: ForkParameters,
+    pub seconds_per_slot: u64,
+    pub slots_per_epoch: u64,
+    pub epochs_per_sync_committee_period: u64,
+    /// The slot of the latest finalized beacon block.
+    pub latest_slot: u64,
+    /// The execution block number of the latest finalized beacon block.
+    pub latest_execution_block_number: u64,
+    /// The address of the IBC contract whose storage holds the IBC
+    /// commitments.
+    pub ibc_address: [u8; 20],
+    /// The storage slot of the IBC contract's commitments mapping.
+    pub ibc_commitment_slot: Root,
+    pub current_sync_committee: SyncCommittee,
+    pub next_sync_committee: Option<SyncCommittee>,
+    pub frozen_height: Option<Height>,
+}
+
+impl ClientState {
+    pub fn validate(&self) -> Result<(), Error> {
+        if self.seconds_per_slot == 0
+            || self.slots_per_epoch == 0
+            || self.epochs_per_sync_committee_period == 0
+        {
+            return Err(Error::InvalidRawClientState {
+                reason: "slot, epoch and period durations cannot be zero".to_string(),
+            });
+        }
+
+        if self.latest_execution_block_number == 0 {
+            return Err(Error::InvalidRawClientState {
+                reason: "latest execution block number cannot be zero".to_string(),
+            });
+        }
+
+        if let Some(next) = &self.next_sync_committee {
+            if next.pubkeys.len() != self.current_sync_committee.pubkeys.len() {
+                return Err(Error::InvalidSyncCommitteeSize {
+                    size: next.pubkeys.len(),
+                });
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn latest_height(&self) -> Height {
+        Height::new(0, self.latest_execution_block_number)
+            .expect("latest execution block number is validated to be non-zero")
+    }
+
+    pub fn compute_epoch(&self, slot: u64) -> u64 {
+        slot / self.slots_per_epoch
+    }
+
+    pub fn sync_committee_period(&self, slot: u64) -> u64 {
+        self.compute_epoch(slot) / self.epochs_per_sync_committee_period
+    }
+
+    /// Returns the slot at the given time, or 0 before genesis.
+    pub fn slot_at(&self, timestamp: Timestamp) -> u64 {
+        timestamp.seconds().saturating_sub(self.genesis_time) / self.seconds_per_slot
+    }
+
+    /// Returns the sync committee of the given period, if known.
+    pub fn sync_committee(&self, period: u64) -> Option<&SyncCommittee> {
+        let store_period = self.sync_committee_period(self.latest_slot);
+
+        if period == store_period {
+            Some(&self.current_sync_committee)
+        } else if Some(period) == store_period.checked_add(1) {
+            self.next_sync_committee.as_ref()
+        } else {
+            None
+        }
+    }
+
+    pub fn is_frozen(&self) -> bool {
+        self.frozen_height.is_some()
+    }
+
+    pub fn with_frozen_height(self, h: Height) -> Self {
+        Self {
+            frozen_height: Some(h),
+            ..self
+        }
+    }
+}
+
+impl Protobuf<RawEthereumClientState> for ClientState {}
+
+impl TryFrom<RawEthereumClientState> for ClientState {
+    type Error = Error;
+
+    fn try_from(raw: RawEthereumClientState) -> Result<Self, Self::Error> {
+        let missing = |field: &str| Error::InvalidRawClientState {
+            reason: format!("missing {field}"),
+        };
+
+        let client_state = Self {
+            chain_id: raw.chain_id,
+            genesis_validators_root: fixed_bytes(
+                raw.genesis_validators_root,
+                "genesis validators root",
+            )?,
+            genesis_time: raw.genesis_time,
+            fork_parameters: raw
+                .fork_parameters
+                .ok_or_else(|| missing("fork parameters"))?
+                .try_into()?,
+            seconds_per_slot: raw.seconds_per_slot,
+            slots_per_epoch: raw.slots_per_epoch,
+            epochs_per_sync_committee_period: raw.epochs_per_sync_committee_period,
+            latest_slot: raw.latest_slot,
+            latest_execution_block_number: raw.latest_execution_block_number,
+            ibc_address: fixed_bytes(raw.ibc_address, "IBC address")?,
+            ibc_commitment_slot: fixed_bytes(raw.ibc_commitment_slot, "IBC commitment slot")?,
+            current_sync_committee: raw
+                .current_sync_committee
+                .ok_or_else(|| missing("current sync committee"))?
+                .try_into()?,
+            next_sync_committee: raw.next_sync_committee.map(TryInto::try_into).transpose()?,
+            frozen_height: raw
+                .frozen_height
+                .and_then(|raw_height| raw_height.try_into().ok()),
+        };
+        client_state.validate()?;
+        Ok(client_state)
+    }
+}
+
+impl From<ClientState> for RawEthereumClientState {
+    fn from(value: ClientState) -> Self {
+        Self {
+            chain_id: value.chain_id,
+            genesis_validators_root: value.genesis_validators_root.to_vec(),
+            genesis_time: value.genesis_time,
+            fork_parameters: Some(value.fork_parameters.into()),
+            seconds_per_slot: value.seconds_per_slot,
+            slots_per_epoch: value.slots_per_epoch,
+            epochs_per_sync_committee_period: value.epochs_per_sync_committee_period,
+            latest_slot: value.latest_slot,
+            latest_execution_block_number: value.latest_execution_block_number,
+            ibc_address: value.ibc_address.to_vec(),
+            ibc_commitment_slot: value.ibc_commitment_slot.to_vec(),
+            current_sync_committee: Some(value.current_sync_committee.into()),
+            next_sync_committee: value.next_sync_committee.map(Into::into),
+            frozen_height: value.frozen_height.map(|height| height.into()),
+        }
+    }
+}
+
+impl Protobuf<Any> for ClientState {}
+
+impl TryFrom<Any> for ClientState {
+    type Error = ClientError;
+
+    fn try_from(raw: Any) -> Result<Self, Self::Error> {
+        fn decode_client_state(value: &[u8]) -> Result<ClientState, ClientError> {
+            let client_state = Protobuf::<RawEthereumClientState>::decode(value).map_err(|e| {
+                ClientError::Other {
+                    description: e.to_string(),
+                }
+            })?;
+            Ok(client_state)
+        }
+
+        match raw.type_url.as_str() {
+            ETHEREUM_CLIENT_STATE_TYPE_URL => decode_client_state(&raw.value),
+            _ => Err(ClientError::UnknownClientStateType {
+                client_state_type: raw.type_url,
+            }),
+        }
+    }
+}
+
+impl From<ClientState> for Any {
+    fn from(client_state: ClientState) -> Self {
+        Any {
+            type_url: ETHEREUM_CLIENT_STATE_TYPE_URL.to_string(),
+            value: Protobuf::<RawEthereumClientState>::encode_vec(client_state),
+        }
+    }
+}
@@ -0,0 +1,124 @@
+//! Defines the consensus state type for the Ethereum light client.
+
+use ibc_core_client::types::error::ClientError;
+use ibc_core_commitment_types::commitment::CommitmentRoot;
+use ibc_primitives::prelude::*;
+use ibc_primitives::proto::{Any, Protobuf};
+use ibc_primitives::Timestamp;
+
+use super::beacon::fixed_bytes;
+use super::proto::ConsensusState as RawEthereumConsensusState;
+use crate::error::Error;
+use crate::ssz::Root;
+
+pub const ETHEREUM_CONSENSUS_STATE_TYPE_URL: &str = "/ibc.lightclients.ethereum.v1.ConsensusState";
+
+/// Defines the Ethereum consensus state of a finalized execution block.
+///
+/// Its commitment root is the storage root of the IBC contract, against
+/// which commitment proofs are verified; the execution state root is kept
+/// to check for misbehaviour.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ConsensusState {
+    /// The slot of the beacon block including the execution block.
+    pub slot: u64,
+    pub state_root: Root,
+    pub storage_root: CommitmentRoot,
+    pub timestamp: Timestamp,
+}
+
+impl ConsensusState {
+    pub fn new(slot: u64, state_root: Root, storage_root: Root, timestamp: Timestamp) -> Self {
+        Self {
+            slot,
+            state_root,
+            storage_root: CommitmentRoot::from_bytes(&storage_root),
+            timestamp,
+        }
+    }
+
+    pub fn root(&self) -> &CommitmentRoot {
+        &self.storage_root
+    }
+
+    pub fn timestamp(&self) -> Timestamp {
+        self.timestamp
+    }
+}
+
+/// Converts a commitment root into the 32-byte root of a storage trie.
+pub fn storage_root(root: &CommitmentRoot) -> Result<Root, Error> {
+    root.as_bytes()
+        .try_into()
+        .map_err(|_| Error::InvalidLength {
+            field: "storage root",
+            expected: 32,
+            actual: root.as_bytes().len(),
+        })
+}
+
+impl Protobuf<RawEthereumConsensusState> for ConsensusState {}
+
+impl TryFrom<RawEthereumConsensusState> for ConsensusState {
+    type Error = Error;
+
+    fn try_from(raw: RawEthereumConsensusState) -> Result<Self, Self::Error> {
+        let timestamp = Timestamp::from_nanoseconds(raw.timestamp).map_err(|e| {
+            Error::InvalidRawConsensusState {
+                reason: e.to_string(),
+            }
+        })?;
+
+        Ok(Self::new(
+            raw.slot,
+            fixed_bytes(raw.state_root, "state root")?,
+            fixed_bytes(raw.storage_root, "storage root")?,
+            timestamp,
+        ))
+    }
+}
+
+impl From<ConsensusState> for RawEthereumConsensusState {
+    fn from(value: ConsensusState) -> Self {
+        Self {
+            slot: value.slot,
+            state_root: value.state_root.to_vec(),
+            storage_root: value.storage_root.into_vec(),
+            timestamp: value.timestamp.nanoseconds(),
+        }
+    }
+}
+
+impl Protobuf<Any> for ConsensusState {}
+
+impl TryFrom<Any> for ConsensusState {
+    type Error = ClientError;
+
+    fn try_from(raw: Any) -> Result<Self, Self::Error> {
+        fn decode_consensus_state(value: &[u8]) -> Result<ConsensusState, ClientError> {
+            let consensus_state =
+                Protobuf::<RawEthereumConsensusState>::decode(value).map_err(|e| {
+                    ClientError::Other {
+                        description: e.to_string(),
+                    }
+                })?;
+            Ok(consensus_state)
+        }
+
+        match raw.type_url.as_str() {
+            ETHEREUM_CONSENSUS_STATE_TYPE_URL => decode_consensus_state(&raw.value),
+            _ => Err(ClientError::UnknownConsensusStateType {
+                consensus_state_type: raw.type_url,
+            }),
+        }
+    }
+}
+
+impl From<ConsensusState> for Any {
+    fn from(consensus_state: ConsensusState) -> Self {
+        Any {
+            type_url: ETHEREUM_CONSENSUS_STATE_TYPE_URL.to_string(),
+            value: Protobuf::<RawEthereumConsensusState>::encode_vec(consensus_state),
+        }
+    }
+}
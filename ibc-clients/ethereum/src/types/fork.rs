@@ -0,0 +1,196 @@
+//! Fork schedule of the beacon chain and signing domains.
+
+use ibc_primitives::prelude::*;
+
+use super::beacon::fixed_bytes;
+use super::proto::{Fork as RawFork, ForkParameters as RawForkParameters};
+use crate::error::Error;
+use crate::ssz::{hash_pair, Root};
+
+pub type Version = [u8; 4];
+
+/// The domain type of sync committee signatures.
+pub const DOMAIN_SYNC_COMMITTEE: [u8; 4] = [7, 0, 0, 0];
+
+/// A fork of the beacon chain and the epoch at which it activates.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Fork {
+    pub version: Version,
+    pub epoch: u64,
+}
+
+/// The forks a light client must know about to compute signing domains.
+///
+/// Light client updates carry execution payload headers, which are only
+/// supported from Capella onwards.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ForkParameters {
+    pub genesis_fork_version: Version,
+    pub altair: Fork,
+    pub bellatrix: Fork,
+    pub capella: Fork,
+    pub deneb: Fork,
+}
+
+impl ForkParameters {
+    /// Returns the fork version active at `epoch`, as in
+    /// `compute_fork_version` of the consensus specs.
+    pub fn fork_version(&self, epoch: u64) -> Version {
+        [&self.deneb, &self.capella, &self.bellatrix, &self.altair]
+            .into_iter()
+            .find(|fork| epoch >= fork.epoch)
+            .map_or(self.genesis_fork_version, |fork| fork.version)
+    }
+
+    /// Returns whether Deneb is active at `epoch`.
+    pub fn is_deneb(&self, epoch: u64) -> bool {
+        epoch >= self.deneb.epoch
+    }
+
+    /// Returns whether Capella is active at `epoch`.
+    pub fn is_capella(&self, epoch: u64) -> bool {
+        epoch >= self.capella.epoch
+    }
+
+    fn validate(&self) -> Result<(), Error> {
+        let epochs = [
+            self.altair.epoch,
+            self.bellatrix.epoch,
+            self.capella.epoch,
+            self.deneb.epoch,
+        ];
+
+        if epochs.windows(2).any(|pair| pair[0] > pair[1]) {
+            return Err(Error::InvalidRawClientState {
+                reason: "forks must be scheduled in order".to_string(),
+            });
+        }
+
+        Ok(())
+    }
+}
+
+/// Returns the signing domain of `domain_type` for the given fork, as in
+/// `compute_domain` of the consensus specs.
+pub fn compute_domain(
+    domain_type: [u8; 4],
+    fork_version: Version,
+    genesis_validators_root: &Root,
+) -> Root {
+    let mut version_chunk = [0u8; 32];
+    version_chunk[..4].copy_from_slice(&fork_version);
+    let fork_data_root = hash_pair(&version_chunk, genesis_validators_root);
+
+    let mut domain = [0u8; 32];
+    domain[..4].copy_from_slice(&domain_type);
+    domain[4..].copy_from_slice(&fork_data_root[..28]);
+    domain
+}
+
+/// Returns the root signed over `object_root` in `domain`, as in
+/// `compute_signing_root` of the consensus specs.
+pub fn compute_signing_root(object_root: &Root, domain: &Root) -> Root {
+    hash_pair(object_root, domain)
+}
+
+impl TryFrom<RawFork> for Fork {
+    type Error = Error;
+
+    fn try_from(raw: RawFork) -> Result<Self, Self::Error> {
+        Ok(Self {
+            version: fixed_bytes(raw.version, "fork version")?,
+            epoch: raw.epoch,
+        })
+    }
+}
+
+impl From<Fork> for RawFork {
+    fn from(value: Fork) -> Self {
+        Self {
+            version: value.version.to_vec(),
+            epoch: value.epoch,
+        }
+    }
+}
+
+impl TryFrom<RawForkParameters> for ForkParameters {
+    type Error = Error;
+
+    fn try_from(raw: RawForkParameters) -> Result<Self, Self::Error> {
+        fn fork(raw: Option<RawFork>, name: &str) -> Result<Fork, Error> {
+            raw.ok_or_else(|| Error::InvalidRawClientState {
+                reason: format!("missing {name} fork"),
+            })?
+            .try_into()
+        }
+
+        let fork_parameters = Self {
+            genesis_fork_version: fixed_bytes(raw.genesis_fork_version, "fork version")?,
+            altair: fork(raw.altair, "altair")?,
+            bellatrix: fork(raw.bellatrix, "bellatrix")?,
+            capella: fork(raw.capella, "capella")?,
+            deneb: fork(raw.deneb, "deneb")?,
+        };
+        fork_parameters.validate()?;
+        Ok(fork_parameters)
+    }
+}
+
+impl From<ForkParameters> for RawForkParameters {
+    fn from(value: ForkParameters) -> Self {
+        Self {
+            genesis_fork_version: value.genesis_fork_version.to_vec(),
+            altair: Some(value.altair.into()),
+            bellatrix: Some(value.bellatrix.into()),
+            capella: Some(value.capella.into()),
+            deneb: Some(value.deneb.into()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn mainnet() -> ForkParameters {
+        ForkParameters {
+            genesis_fork_version: [0, 0, 0, 0],
+            altair: Fork {
+                version: [1, 0, 0, 0],
+                epoch: 74240,
+            },
+            bellatrix: Fork {
+                version: [2, 0, 0, 0],
+                epoch: 144896,
+            },
+            capella: Fork {
+                version: [3, 0, 0, 0],
+                epoch: 194048,
+            },
+            deneb: Fork {
+                version: [4, 0, 0, 0],
+                epoch: 269568,
+            },
+        }
+    }
+
+    #[test]
+    fn fork_version_at_epoch() {
+        let forks = mainnet();
+        assert_eq!(forks.fork_version(0), [0, 0, 0, 0]);
+        assert_eq!(forks.fork_version(74240), [1, 0, 0, 0]);
+        assert_eq!(forks.fork_version(200000), [3, 0, 0, 0]);
+        assert_eq!(forks.fork_version(u64::MAX), [4, 0, 0, 0]);
+        assert!(!forks.is_deneb(269567));
+        assert!(forks.is_deneb(269568));
+    }
+
+    #[test]
+    fn domain_layout() {
+        let domain = compute_domain(DOMAIN_SYNC_COMMITTEE, [4, 0, 0, 0], &[1; 32]);
+        assert_eq!(domain[..4], DOMAIN_SYNC_COMMITTEE);
+
+        let other = compute_domain(DOMAIN_SYNC_COMMITTEE, [3, 0, 0, 0], &[1; 32]);
+        assert_ne!(domain, other);
+    }
+}
@@ -0,0 +1,137 @@
+//! Defines the header type for the Ethereum light client.
+
+use ibc_core_client::types::error::ClientError;
+use ibc_core_client::types::Height;
+use ibc_primitives::prelude::*;
+use ibc_primitives::proto::{Any, Protobuf};
+
+use super::beacon::{fixed_bytes, LightClientUpdate};
+use super::proto::{AccountUpdate as RawAccountUpdate, Header as RawEthereumHeader};
+use crate::error::Error;
+use crate::ssz::Root;
+
+pub const ETHEREUM_HEADER_TYPE_URL: &str = "/ibc.lightclients.ethereum.v1.Header";
+
+/// The storage root of the IBC contract in the state of a finalized
+/// execution block, along with its account proof.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AccountUpdate {
+    pub account_proof: Vec<Vec<u8>>,
+    pub storage_root: Root,
+}
+
+impl TryFrom<RawAccountUpdate> for AccountUpdate {
+    type Error = Error;
+
+    fn try_from(raw: RawAccountUpdate) -> Result<Self, Self::Error> {
+        if raw.account_proof.is_empty() {
+            return Err(Error::InvalidRawHeader {
+                reason: "account proof cannot be empty".to_string(),
+            });
+        }
+
+        Ok(Self {
+            account_proof: raw.account_proof,
+            storage_root: fixed_bytes(raw.storage_root, "storage root")?,
+        })
+    }
+}
+
+impl From<AccountUpdate> for RawAccountUpdate {
+    fn from(value: AccountUpdate) -> Self {
+        Self {
+            account_proof: value.account_proof,
+            storage_root: value.storage_root.to_vec(),
+        }
+    }
+}
+
+/// Defines the Ethereum client header: a light client update finalizing a
+/// beacon block, and the storage root of the IBC contract at its execution
+/// block.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Header {
+    pub consensus_update: LightClientUpdate,
+    pub account_update: AccountUpdate,
+}
+
+impl Header {
+    /// Returns the height of the consensus state implied by the header, i.e.
+    /// the number of the finalized execution block.
+    pub fn height(&self) -> Result<Height, Error> {
+        let block_number = self
+            .consensus_update
+            .finalized_header
+            .execution
+            .block_number;
+        Height::new(0, block_number).map_err(|e| Error::InvalidRawHeader {
+            reason: e.to_string(),
+        })
+    }
+}
+
+impl Protobuf<RawEthereumHeader> for Header {}
+
+impl TryFrom<RawEthereumHeader> for Header {
+    type Error = Error;
+
+    fn try_from(raw: RawEthereumHeader) -> Result<Self, Self::Error> {
+        let header = Self {
+            consensus_update: raw
+                .consensus_update
+                .ok_or_else(|| Error::InvalidRawHeader {
+                    reason: "missing consensus update".to_string(),
+                })?
+                .try_into()?,
+            account_update: raw
+                .account_update
+                .ok_or_else(|| Error::InvalidRawHeader {
+                    reason: "missing account update".to_string(),
+                })?
+                .try_into()?,
+        };
+        header.height()?;
+        Ok(header)
+    }
+}
+
+impl From<Header> for RawEthereumHeader {
+    fn from(value: Header) -> Self {
+        Self {
+            consensus_update: Some(value.consensus_update.into()),
+            account_update: Some(value.account_update.into()),
+        }
+    }
+}
+
+impl Protobuf<Any> for Header {}
+
+impl TryFrom<Any> for Header {
+    type Error = ClientError;
+
+    fn try_from(raw: Any) -> Result<Self, Self::Error> {
+        fn decode_header(value: &[u8]) -> Result<Header, ClientError> {
+            let header =
+                Protobuf::<RawEthereumHeader>::decode(value).map_err(|e| ClientError::Other {
+                    description: e.to_string(),
+                })?;
+            Ok(header)
+        }
+
+        match raw.type_url.as_str() {
+            ETHEREUM_HEADER_TYPE_URL => decode_header(&raw.value),
+            _ => Err(ClientError::UnknownHeaderType {
+                header_type: raw.type_url,
+            }),
+        }
+    }
+}
+
+impl From<Header> for Any {
+    fn from(header: Header) -> Self {
+        Any {
+            type_url: ETHEREUM_HEADER_TYPE_URL.to_string(),
+            value: Protobuf::<RawEthereumHeader>::encode_vec(header),
+        }
+    }
+}
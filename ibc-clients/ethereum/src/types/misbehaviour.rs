@@ -0,0 +1,104 @@
+//! Defines the misbehaviour type for the Ethereum light client.
+
+use ibc_core_client::types::error::ClientError;
+use ibc_primitives::prelude::*;
+use ibc_primitives::proto::{Any, Protobuf};
+
+use super::beacon::LightClientUpdate;
+use super::proto::Misbehaviour as RawEthereumMisbehaviour;
+use crate::error::Error;
+
+pub const ETHEREUM_MISBEHAVIOUR_TYPE_URL: &str = "/ibc.lightclients.ethereum.v1.Misbehaviour";
+
+/// Evidence of the sync committee finalizing two different beacon blocks at
+/// the same slot.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Misbehaviour {
+    pub update_1: LightClientUpdate,
+    pub update_2: LightClientUpdate,
+}
+
+impl Misbehaviour {
+    /// Checks that both updates finalize distinct beacon blocks at the same
+    /// slot, without verifying their signatures.
+    pub fn validate_basic(&self) -> Result<(), Error> {
+        let first = &self.update_1.finalized_header.beacon;
+        let second = &self.update_2.finalized_header.beacon;
+
+        if first.slot != second.slot {
+            return Err(Error::InvalidSlots {
+                reason: format!(
+                    "updates finalize different slots {} and {}",
+                    first.slot, second.slot
+                ),
+            });
+        }
+
+        if first.hash_tree_root() == second.hash_tree_root() {
+            return Err(Error::NotMisbehaviour { slot: first.slot });
+        }
+
+        Ok(())
+    }
+}
+
+impl Protobuf<RawEthereumMisbehaviour> for Misbehaviour {}
+
+impl TryFrom<RawEthereumMisbehaviour> for Misbehaviour {
+    type Error = Error;
+
+    fn try_from(raw: RawEthereumMisbehaviour) -> Result<Self, Self::Error> {
+        let missing = || Error::InvalidRawMisbehaviour {
+            reason: "missing light client update".to_string(),
+        };
+
+        let misbehaviour = Self {
+            update_1: raw.update_1.ok_or_else(missing)?.try_into()?,
+            update_2: raw.update_2.ok_or_else(missing)?.try_into()?,
+        };
+        misbehaviour.validate_basic()?;
+        Ok(misbehaviour)
+    }
+}
+
+impl From<Misbehaviour> for RawEthereumMisbehaviour {
+    fn from(value: Misbehaviour) -> Self {
+        Self {
+            update_1: Some(value.update_1.into()),
+            update_2: Some(value.update_2.into()),
+        }
+    }
+}
+
+impl Protobuf<Any> for Misbehaviour {}
+
+impl TryFrom<Any> for Misbehaviour {
+    type Error = ClientError;
+
+    fn try_from(raw: Any) -> Result<Self, Self::Error> {
+        fn decode_misbehaviour(value: &[u8]) -> Result<Misbehaviour, ClientError> {
+            let misbehaviour = Protobuf::<RawEthereumMisbehaviour>::decode(value).map_err(|e| {
+                ClientError::Other {
+                    description: e.to_string(),
+                }
+            })?;
+            Ok(misbehaviour)
+        }
+
+        match raw.type_url.as_str() {
+            ETHEREUM_MISBEHAVIOUR_TYPE_URL => decode_misbehaviour(&raw.value),
+            _ => Err(ClientError::UnknownMisbehaviourType {
+                misbehaviour_type: raw.type_url,
+            }),
+        }
+    }
+}
+
+impl From<Misbehaviour> for Any {
+    fn from(misbehaviour: Misbehaviour) -> Self {
+        Any {
+            type_url: ETHEREUM_MISBEHAVIOUR_TYPE_URL.to_string(),
+            value: Protobuf::<RawEthereumMisbehaviour>::encode_vec(misbehaviour),
+        }
+    }
+}
@@ -0,0 +1,368 @@
+//! Raw (protobuf) representations of the Ethereum client types.
+//!
+//! There is no upstream `ibc-proto` definition for these messages yet, so
+//! they are defined here under the `ibc.lightclients.ethereum.v1` package.
+//! Beacon chain containers mirror their definitions in the consensus specs,
+//! with fixed-size byte vectors carried as `bytes`.
+
+use ibc_primitives::prelude::*;
+use ibc_proto::ibc::core::client::v1::Height as RawHeight;
+
+macro_rules! impl_name {
+    ($ty:ty, $name:literal) => {
+        impl prost::Name for $ty {
+            const NAME: &'static str = $name;
+            const PACKAGE: &'static str = "ibc.lightclients.ethereum.v1";
+
+            fn full_name() -> String {
+                format!("{}.{}", Self::PACKAGE, Self::NAME)
+            }
+        }
+    };
+}
+
+/// ```protobuf
+/// message Fork {
+///   bytes version = 1;
+///   uint64 epoch = 2;
+/// }
+/// ```
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct Fork {
+    #[prost(bytes = "vec", tag = "1")]
+    pub version: Vec<u8>,
+    #[prost(uint64, tag = "2")]
+    pub epoch: u64,
+}
+
+impl_name!(Fork, "Fork");
+
+/// ```protobuf
+/// message ForkParameters {
+///   bytes genesis_fork_version = 1;
+///   Fork altair = 2;
+///   Fork bellatrix = 3;
+///   Fork capella = 4;
+///   Fork deneb = 5;
+/// }
+/// ```
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct ForkParameters {
+    #[prost(bytes = "vec", tag = "1")]
+    pub genesis_fork_version: Vec<u8>,
+    #[prost(message, optional, tag = "2")]
+    pub altair: Option<Fork>,
+    #[prost(message, optional, tag = "3")]
+    pub bellatrix: Option<Fork>,
+    #[prost(message, optional, tag = "4")]
+    pub capella: Option<Fork>,
+    #[prost(message, optional, tag = "5")]
+    pub deneb: Option<Fork>,
+}
+
+impl_name!(ForkParameters, "ForkParameters");
+
+/// ```protobuf
+/// message SyncCommittee {
+///   repeated bytes pubkeys = 1;
+///   bytes aggregate_pubkey = 2;
+/// }
+/// ```
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct SyncCommittee {
+    #[prost(bytes = "vec", repeated, tag = "1")]
+    pub pubkeys: Vec<Vec<u8>>,
+    #[prost(bytes = "vec", tag = "2")]
+    pub aggregate_pubkey: Vec<u8>,
+}
+
+impl_name!(SyncCommittee, "SyncCommittee");
+
+/// ```protobuf
+/// message ClientState {
+///   uint64 chain_id = 1;
+///   bytes genesis_validators_root = 2;
+///   uint64 genesis_time = 3;
+///   ForkParameters fork_parameters = 4;
+///   uint64 seconds_per_slot = 5;
+///   uint64 slots_per_epoch = 6;
+///   uint64 epochs_per_sync_committee_period = 7;
+///   uint64 latest_slot = 8;
+///   uint64 latest_execution_block_number = 9;
+///   bytes ibc_address = 10;
+///   bytes ibc_commitment_slot = 11;
+///   SyncCommittee current_sync_committee = 12;
+///   SyncCommittee next_sync_committee = 13;
+///   ibc.core.client.v1.Height frozen_height = 14;
+/// }
+/// ```
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct ClientState {
+    #[prost(uint64, tag = "1")]
+    pub chain_id: u64,
+    #[prost(bytes = "vec", tag = "2")]
+    pub genesis_validators_root: Vec<u8>,
+    #[prost(uint64, tag = "3")]
+    pub genesis_time: u64,
+    #[prost(message, optional, tag = "4")]
+    pub fork_parameters: Option<ForkParameters>,
+    #[prost(uint64, tag = "5")]
+    pub seconds_per_slot: u64,
+    #[prost(uint64, tag = "6")]
+    pub slots_per_epoch: u64,
+    #[prost(uint64, tag = "7")]
+    pub epochs_per_sync_committee_period: u64,
+    #[prost(uint64, tag = "8")]
+    pub latest_slot: u64,
+    #[prost(uint64, tag = "9")]
+    pub latest_execution_block_number: u64,
+    #[prost(bytes = "vec", tag = "10")]
+    pub ibc_address: Vec<u8>,
+    #[prost(bytes = "vec", tag = "11")]
+    pub ibc_commitment_slot: Vec<u8>,
+    #[prost(message, optional, tag = "12")]
+    pub current_sync_committee: Option<SyncCommittee>,
+    #[prost(message, optional, tag = "13")]
+    pub next_sync_committee: Option<SyncCommittee>,
+    #[prost(message, optional, tag = "14")]
+    pub frozen_height: Option<RawHeight>,
+}
+
+impl_name!(ClientState, "ClientState");
+
+/// ```protobuf
+/// message ConsensusState {
+///   uint64 slot = 1;
+///   bytes state_root = 2;
+///   bytes storage_root = 3;
+///   uint64 timestamp = 4;
+/// }
+/// ```
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct ConsensusState {
+    #[prost(uint64, tag = "1")]
+    pub slot: u64,
+    #[prost(bytes = "vec", tag = "2")]
+    pub state_root: Vec<u8>,
+    #[prost(bytes = "vec", tag = "3")]
+    pub storage_root: Vec<u8>,
+    /// Unix timestamp in nanoseconds.
+    #[prost(uint64, tag = "4")]
+    pub timestamp: u64,
+}
+
+impl_name!(ConsensusState, "ConsensusState");
+
+/// ```protobuf
+/// message BeaconBlockHeader {
+///   uint64 slot = 1;
+///   uint64 proposer_index = 2;
+///   bytes parent_root = 3;
+///   bytes state_root = 4;
+///   bytes body_root = 5;
+/// }
+/// ```
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct BeaconBlockHeader {
+    #[prost(uint64, tag = "1")]
+    pub slot: u64,
+    #[prost(uint64, tag = "2")]
+    pub proposer_index: u64,
+    #[prost(bytes = "vec", tag = "3")]
+    pub parent_root: Vec<u8>,
+    #[prost(bytes = "vec", tag = "4")]
+    pub state_root: Vec<u8>,
+    #[prost(bytes = "vec", tag = "5")]
+    pub body_root: Vec<u8>,
+}
+
+impl_name!(BeaconBlockHeader, "BeaconBlockHeader");
+
+/// ```protobuf
+/// message ExecutionPayloadHeader {
+///   bytes parent_hash = 1;
+///   bytes fee_recipient = 2;
+///   bytes state_root = 3;
+///   bytes receipts_root = 4;
+///   bytes logs_bloom = 5;
+///   bytes prev_randao = 6;
+///   uint64 block_number = 7;
+///   uint64 gas_limit = 8;
+///   uint64 gas_used = 9;
+///   uint64 timestamp = 10;
+///   bytes extra_data = 11;
+///   bytes base_fee_per_gas = 12;
+///   bytes block_hash = 13;
+///   bytes transactions_root = 14;
+///   bytes withdrawals_root = 15;
+///   uint64 blob_gas_used = 16;
+///   uint64 excess_blob_gas = 17;
+/// }
+/// ```
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct ExecutionPayloadHeader {
+    #[prost(bytes = "vec", tag = "1")]
+    pub parent_hash: Vec<u8>,
+    #[prost(bytes = "vec", tag = "2")]
+    pub fee_recipient: Vec<u8>,
+    #[prost(bytes = "vec", tag = "3")]
+    pub state_root: Vec<u8>,
+    #[prost(bytes = "vec", tag = "4")]
+    pub receipts_root: Vec<u8>,
+    #[prost(bytes = "vec", tag = "5")]
+    pub logs_bloom: Vec<u8>,
+    #[prost(bytes = "vec", tag = "6")]
+    pub prev_randao: Vec<u8>,
+    #[prost(uint64, tag = "7")]
+    pub block_number: u64,
+    #[prost(uint64, tag = "8")]
+    pub gas_limit: u64,
+    #[prost(uint64, tag = "9")]
+    pub gas_used: u64,
+    #[prost(uint64, tag = "10")]
+    pub timestamp: u64,
+    #[prost(bytes = "vec", tag = "11")]
+    pub extra_data: Vec<u8>,
+    /// Little-endian `uint256`, as in SSZ.
+    #[prost(bytes = "vec", tag = "12")]
+    pub base_fee_per_gas: Vec<u8>,
+    #[prost(bytes = "vec", tag = "13")]
+    pub block_hash: Vec<u8>,
+    #[prost(bytes = "vec", tag = "14")]
+    pub transactions_root: Vec<u8>,
+    #[prost(bytes = "vec", tag = "15")]
+    pub withdrawals_root: Vec<u8>,
+    #[prost(uint64, tag = "16")]
+    pub blob_gas_used: u64,
+    #[prost(uint64, tag = "17")]
+    pub excess_blob_gas: u64,
+}
+
+impl_name!(ExecutionPayloadHeader, "ExecutionPayloadHeader");
+
+/// ```protobuf
+/// message LightClientHeader {
+///   BeaconBlockHeader beacon = 1;
+///   ExecutionPayloadHeader execution = 2;
+///   repeated bytes execution_branch = 3;
+/// }
+/// ```
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct LightClientHeader {
+    #[prost(message, optional, tag = "1")]
+    pub beacon: Option<BeaconBlockHeader>,
+    #[prost(message, optional, tag = "2")]
+    pub execution: Option<ExecutionPayloadHeader>,
+    #[prost(bytes = "vec", repeated, tag = "3")]
+    pub execution_branch: Vec<Vec<u8>>,
+}
+
+impl_name!(LightClientHeader, "LightClientHeader");
+
+/// ```protobuf
+/// message SyncAggregate {
+///   bytes sync_committee_bits = 1;
+///   bytes sync_committee_signature = 2;
+/// }
+/// ```
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct SyncAggregate {
+    #[prost(bytes = "vec", tag = "1")]
+    pub sync_committee_bits: Vec<u8>,
+    #[prost(bytes = "vec", tag = "2")]
+    pub sync_committee_signature: Vec<u8>,
+}
+
+impl_name!(SyncAggregate, "SyncAggregate");
+
+/// ```protobuf
+/// message LightClientUpdate {
+///   LightClientHeader attested_header = 1;
+///   SyncCommittee next_sync_committee = 2;
+///   repeated bytes next_sync_committee_branch = 3;
+///   LightClientHeader finalized_header = 4;
+///   repeated bytes finality_branch = 5;
+///   SyncAggregate sync_aggregate = 6;
+///   uint64 signature_slot = 7;
+/// }
+/// ```
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct LightClientUpdate {
+    #[prost(message, optional, tag = "1")]
+    pub attested_header: Option<LightClientHeader>,
+    #[prost(message, optional, tag = "2")]
+    pub next_sync_committee: Option<SyncCommittee>,
+    #[prost(bytes = "vec", repeated, tag = "3")]
+    pub next_sync_committee_branch: Vec<Vec<u8>>,
+    #[prost(message, optional, tag = "4")]
+    pub finalized_header: Option<LightClientHeader>,
+    #[prost(bytes = "vec", repeated, tag = "5")]
+    pub finality_branch: Vec<Vec<u8>>,
+    #[prost(message, optional, tag = "6")]
+    pub sync_aggregate: Option<SyncAggregate>,
+    #[prost(uint64, tag = "7")]
+    pub signature_slot: u64,
+}
+
+impl_name!(LightClientUpdate, "LightClientUpdate");
+
+/// ```protobuf
+/// message AccountUpdate {
+///   repeated bytes account_proof = 1;
+///   bytes storage_root = 2;
+/// }
+/// ```
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct AccountUpdate {
+    #[prost(bytes = "vec", repeated, tag = "1")]
+    pub account_proof: Vec<Vec<u8>>,
+    #[prost(bytes = "vec", tag = "2")]
+    pub storage_root: Vec<u8>,
+}
+
+impl_name!(AccountUpdate, "AccountUpdate");
+
+/// ```protobuf
+/// message Header {
+///   LightClientUpdate consensus_update = 1;
+///   AccountUpdate account_update = 2;
+/// }
+/// ```
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct Header {
+    #[prost(message, optional, tag = "1")]
+    pub consensus_update: Option<LightClientUpdate>,
+    #[prost(message, optional, tag = "2")]
+    pub account_update: Option<AccountUpdate>,
+}
+
+impl_name!(Header, "Header");
+
+/// ```protobuf
+/// message Misbehaviour {
+///   LightClientUpdate update_1 = 1;
+///   LightClientUpdate update_2 = 2;
+/// }
+/// ```
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct Misbehaviour {
+    #[prost(message, optional, tag = "1")]
+    pub update_1: Option<LightClientUpdate>,
+    #[prost(message, optional, tag = "2")]
+    pub update_2: Option<LightClientUpdate>,
+}
+
+impl_name!(Misbehaviour, "Misbehaviour");
+
+/// ```protobuf
+/// message StorageProof {
+///   repeated bytes proof = 1;
+/// }
+/// ```
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct StorageProof {
+    #[prost(bytes = "vec", repeated, tag = "1")]
+    pub proof: Vec<Vec<u8>>,
+}
+
+impl_name!(StorageProof, "StorageProof");
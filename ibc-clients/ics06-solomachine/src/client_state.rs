@@ -0,0 +1,218 @@
+//! This module includes the implementations of the `ClientStateCommon`,
+//! `ClientStateValidation`, and `ClientStateExecution` traits for the solo
+//! machine client state.
+//!
+//! As with the Tendermint client, the logic behind each trait method is also
+//! exposed as a standalone function, so that hosts wrapping the solo machine
+//! client can reuse it.
+
+mod common;
+mod execution;
+mod validation;
+
+pub use common::*;
+pub use execution::*;
+pub use validation::*;
+
+#[cfg(test)]
+mod tests {
+    use ed25519_consensus::SigningKey;
+    use ibc_core_client::context::client_state::ClientStateCommon;
+    use ibc_core_commitment_types::commitment::{
+        CommitmentPrefix, CommitmentProofBytes, CommitmentRoot,
+    };
+    use ibc_core_host::types::identifiers::ClientId;
+    use ibc_core_host::types::path::{ClientStatePath, Path};
+    use ibc_primitives::prelude::*;
+    use ibc_primitives::proto::Any;
+
+    use super::*;
+    use crate::types::{
+        encode_single_signature, header_sign_bytes, ClientState, ConsensusState, Header,
+        Misbehaviour, PublicKey, SignBytes, SignatureAndData, TimestampedSignatureData,
+    };
+
+    const DIVERSIFIER: &str = "diversifier";
+    const TIMESTAMP: u64 = 1_700_000_000_000_000_000;
+
+    fn signing_key(seed: u8) -> SigningKey {
+        SigningKey::from([seed; 32])
+    }
+
+    fn sign(key: &SigningKey, sign_bytes: SignBytes) -> Vec<u8> {
+        encode_single_signature(key.sign(&sign_bytes.encode_vec()).to_bytes().to_vec())
+    }
+
+    fn dummy_client_state(key: &SigningKey) -> ClientState {
+        let consensus_state = ConsensusState::new(
+            PublicKey::Ed25519(key.verification_key()),
+            DIVERSIFIER.to_string(),
+            TIMESTAMP,
+        );
+        ClientState::new(1, consensus_state).expect("never fails")
+    }
+
+    fn dummy_header(
+        client_state: &ClientState,
+        signer: &SigningKey,
+        new_key: &SigningKey,
+    ) -> Header {
+        let mut header = Header {
+            timestamp: TIMESTAMP + 1,
+            signature: vec![],
+            new_public_key: PublicKey::Ed25519(new_key.verification_key()),
+            new_diversifier: "new-diversifier".to_string(),
+        };
+        let sign_bytes = header_sign_bytes(
+            client_state.sequence,
+            &client_state.consensus_state,
+            &header,
+        );
+        header.signature = sign(signer, sign_bytes);
+        header
+    }
+
+    fn dummy_signature_and_data(key: &SigningKey, sequence: u64, data: &[u8]) -> SignatureAndData {
+        let path = b"ibc/connections/connection-0".to_vec();
+        let signature = sign(
+            key,
+            SignBytes {
+                sequence,
+                timestamp: TIMESTAMP,
+                diversifier: DIVERSIFIER.to_string(),
+                path: path.clone(),
+                data: data.to_vec(),
+            },
+        );
+        SignatureAndData {
+            signature,
+            path,
+            data: data.to_vec(),
+            timestamp: TIMESTAMP,
+        }
+    }
+
+    #[test]
+    fn client_state_any_roundtrip() {
+        let client_state = dummy_client_state(&signing_key(1));
+        let any: Any = client_state.clone().into();
+        assert_eq!(
+            ClientState::try_from(any).expect("never fails"),
+            client_state
+        );
+    }
+
+    #[test]
+    fn verify_header_signed_by_current_key() {
+        let key = signing_key(1);
+        let new_key = signing_key(2);
+        let client_state = dummy_client_state(&key);
+
+        let header = dummy_header(&client_state, &key, &new_key);
+        assert!(verify_client_message(&client_state, header.into()).is_ok());
+
+        let header = dummy_header(&client_state, &new_key, &new_key);
+        assert!(verify_client_message(&client_state, header.into()).is_err());
+    }
+
+    #[test]
+    fn verify_header_rejects_stale_timestamp() {
+        let key = signing_key(1);
+        let client_state = dummy_client_state(&key);
+
+        let mut header = dummy_header(&client_state, &key, &key);
+        header.timestamp = TIMESTAMP - 1;
+        let sign_bytes = header_sign_bytes(
+            client_state.sequence,
+            &client_state.consensus_state,
+            &header,
+        );
+        header.signature = sign(&key, sign_bytes);
+
+        assert!(verify_header(&client_state, &header).is_err());
+    }
+
+    #[test]
+    fn verify_misbehaviour_with_conflicting_signatures() {
+        let key = signing_key(1);
+        let client_state = dummy_client_state(&key);
+
+        let misbehaviour = Misbehaviour {
+            sequence: client_state.sequence,
+            signature_one: dummy_signature_and_data(&key, client_state.sequence, b"data-one"),
+            signature_two: dummy_signature_and_data(&key, client_state.sequence, b"data-two"),
+        };
+        let any: Any = misbehaviour.clone().into();
+        assert!(verify_client_message(&client_state, any.clone()).is_ok());
+        assert!(check_for_misbehaviour(any).expect("never fails"));
+
+        let forged = Misbehaviour {
+            signature_two: dummy_signature_and_data(
+                &signing_key(2),
+                client_state.sequence,
+                b"data-two",
+            ),
+            ..misbehaviour
+        };
+        assert!(verify_misbehaviour(&client_state, &forged).is_err());
+    }
+
+    #[test]
+    fn verify_membership_via_signature() {
+        let key = signing_key(1);
+        let client_state = dummy_client_state(&key);
+        let prefix = CommitmentPrefix::try_from(b"ibc".to_vec()).expect("never fails");
+        let path = Path::ClientState(ClientStatePath::new(
+            ClientId::new(crate::SOLOMACHINE_CLIENT_TYPE, 0).expect("never fails"),
+        ));
+        let value = b"value".to_vec();
+
+        let signature_data = sign(
+            &key,
+            SignBytes {
+                sequence: client_state.sequence,
+                timestamp: TIMESTAMP,
+                diversifier: DIVERSIFIER.to_string(),
+                path: crate::types::commitment_path_bytes(&prefix, &path),
+                data: value.clone(),
+            },
+        );
+        let proof: CommitmentProofBytes = TimestampedSignatureData {
+            signature_data,
+            timestamp: TIMESTAMP,
+        }
+        .try_into()
+        .expect("never fails");
+
+        assert!(verify_signature_and_data(
+            &client_state,
+            &prefix,
+            &proof,
+            path.clone(),
+            value.clone()
+        )
+        .is_ok());
+        assert!(
+            verify_signature_and_data(&client_state, &prefix, &proof, path.clone(), vec![])
+                .is_err()
+        );
+        assert!(verify_signature_and_data(
+            &client_state.with_frozen(),
+            &prefix,
+            &proof,
+            path.clone(),
+            value.clone()
+        )
+        .is_err());
+
+        // The membership methods cannot advance the sequence, and reject the
+        // proofs
+        let root = CommitmentRoot::from_bytes(&[]);
+        assert!(client_state
+            .verify_membership(&prefix, &proof, &root, path.clone(), value)
+            .is_err());
+        assert!(client_state
+            .verify_non_membership(&prefix, &proof, &root, path)
+            .is_err());
+    }
+}
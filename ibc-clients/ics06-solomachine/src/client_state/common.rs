@@ -0,0 +1,151 @@
+use ibc_core_client::context::client_state::ClientStateCommon;
+use ibc_core_client::types::error::ClientError;
+use ibc_core_client::types::Height;
+use ibc_core_commitment_types::commitment::{
+    CommitmentPrefix, CommitmentProofBytes, CommitmentRoot,
+};
+use ibc_core_host::types::identifiers::ClientType;
+use ibc_core_host::types::path::Path;
+use ibc_primitives::prelude::*;
+use ibc_primitives::proto::Any;
+
+use crate::client_type as sm_client_type;
+use crate::error::Error;
+use crate::types::{
+    commitment_path_bytes, ClientState as ClientStateType, ConsensusState as ConsensusStateType,
+    SignBytes, TimestampedSignatureData,
+};
+
+impl ClientStateCommon for ClientStateType {
+    fn verify_consensus_state(&self, consensus_state: Any) -> Result<(), ClientError> {
+        verify_consensus_state(consensus_state)
+    }
+
+    fn client_type(&self) -> ClientType {
+        sm_client_type()
+    }
+
+    fn latest_height(&self) -> Height {
+        ClientStateType::latest_height(self)
+    }
+
+    fn validate_proof_height(&self, proof_height: Height) -> Result<(), ClientError> {
+        validate_proof_height(self, proof_height)
+    }
+
+    fn verify_upgrade_client(
+        &self,
+        _upgraded_client_state: Any,
+        _upgraded_consensus_state: Any,
+        _proof_upgrade_client: CommitmentProofBytes,
+        _proof_upgrade_consensus_state: CommitmentProofBytes,
+        _root: &CommitmentRoot,
+    ) -> Result<(), ClientError> {
+        Err(Error::UpgradeNotSupported.into())
+    }
+
+    // The core handlers verify the proofs with read access only, and cannot
+    // advance the sequence a verified signature was made under, which would
+    // let any signed proof be replayed, e.g. a non-membership proof of a
+    // packet receipt to time the packet out once received. Until they can,
+    // the solo machine clients reject all the proofs.
+    fn verify_membership(
+        &self,
+        _prefix: &CommitmentPrefix,
+        _proof: &CommitmentProofBytes,
+        _root: &CommitmentRoot,
+        _path: Path,
+        _value: Vec<u8>,
+    ) -> Result<(), ClientError> {
+        Err(Error::ProofVerificationNotSupported.into())
+    }
+
+    fn verify_non_membership(
+        &self,
+        _prefix: &CommitmentPrefix,
+        _proof: &CommitmentProofBytes,
+        _root: &CommitmentRoot,
+        _path: Path,
+    ) -> Result<(), ClientError> {
+        Err(Error::ProofVerificationNotSupported.into())
+    }
+}
+
+/// Verify an `Any` consensus state by attempting to convert it to a solo
+/// machine `ConsensusState`.
+///
+/// Note that this function is typically implemented as part of the
+/// [`ClientStateCommon`] trait, but has been made a standalone function
+/// in order to make the ClientState APIs more flexible.
+pub fn verify_consensus_state(consensus_state: Any) -> Result<(), ClientError> {
+    ConsensusStateType::try_from(consensus_state)?;
+
+    Ok(())
+}
+
+/// Validate the given proof height against the client state's latest height,
+/// i.e. its current sequence, returning an error if the proof height is
+/// greater than the latest height of the client state.
+pub fn validate_proof_height(
+    client_state: &ClientStateType,
+    proof_height: Height,
+) -> Result<(), ClientError> {
+    let latest_height = client_state.latest_height();
+
+    if latest_height < proof_height {
+        return Err(ClientError::InvalidProofHeight {
+            latest_height,
+            proof_height,
+        });
+    }
+
+    Ok(())
+}
+
+/// Verifies that the solo machine signed `value` at the given `path` under
+/// its current sequence and diversifier. Non-membership proofs are
+/// signatures over an empty value.
+///
+/// The solo machine commitment root is empty, so the `root` argument of
+/// the membership methods is ignored.
+///
+/// The caller must increment the client sequence once the signature is
+/// verified, as ibc-go does, since the same signature verifies again under
+/// the same sequence. The [`ClientStateCommon`] membership methods cannot,
+/// and reject all the proofs instead.
+pub fn verify_signature_and_data(
+    client_state: &ClientStateType,
+    prefix: &CommitmentPrefix,
+    proof: &CommitmentProofBytes,
+    path: Path,
+    value: Vec<u8>,
+) -> Result<(), ClientError> {
+    if client_state.is_frozen {
+        return Err(Error::FrozenClient.into());
+    }
+
+    let proof = TimestampedSignatureData::try_from(proof)?;
+    let consensus_state = &client_state.consensus_state;
+
+    if proof.timestamp < consensus_state.timestamp {
+        return Err(Error::TimestampTooLow {
+            given: proof.timestamp,
+            min: consensus_state.timestamp,
+        }
+        .into());
+    }
+
+    let sign_bytes = SignBytes {
+        sequence: client_state.sequence,
+        timestamp: proof.timestamp,
+        diversifier: consensus_state.diversifier.clone(),
+        path: commitment_path_bytes(prefix, &path),
+        data: value,
+    };
+
+    consensus_state
+        .public_key
+        .verify_signature(&proof.signature_data, &sign_bytes.encode_vec())?;
+
+    Ok(())
+}
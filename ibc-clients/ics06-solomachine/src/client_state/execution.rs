@@ -0,0 +1,168 @@
+use ibc_core_client::context::prelude::*;
+use ibc_core_client::types::error::ClientError;
+use ibc_core_client::types::Height;
+use ibc_core_host::types::identifiers::ClientId;
+use ibc_core_host::types::path::{ClientConsensusStatePath, ClientStatePath};
+use ibc_primitives::prelude::*;
+use ibc_primitives::proto::Any;
+
+use crate::error::Error;
+use crate::types::{
+    ClientState as ClientStateType, ConsensusState as ConsensusStateType, Header as SmHeader,
+};
+
+impl<E> ClientStateExecution<E> for ClientStateType
+where
+    E: ClientExecutionContext,
+    E::ClientStateRef: From<ClientStateType>,
+    E::ConsensusStateRef: From<ConsensusStateType>,
+{
+    fn initialise(
+        &self,
+        ctx: &mut E,
+        client_id: &ClientId,
+        consensus_state: Any,
+    ) -> Result<(), ClientError> {
+        initialise(self, ctx, client_id, consensus_state)
+    }
+
+    fn update_state(
+        &self,
+        ctx: &mut E,
+        client_id: &ClientId,
+        header: Any,
+    ) -> Result<Vec<Height>, ClientError> {
+        update_state(self, ctx, client_id, header)
+    }
+
+    fn update_state_on_misbehaviour(
+        &self,
+        ctx: &mut E,
+        client_id: &ClientId,
+        _client_message: Any,
+    ) -> Result<(), ClientError> {
+        update_on_misbehaviour(self, ctx, client_id)
+    }
+
+    fn update_state_on_upgrade(
+        &self,
+        _ctx: &mut E,
+        _client_id: &ClientId,
+        _upgraded_client_state: Any,
+        _upgraded_consensus_state: Any,
+    ) -> Result<Height, ClientError> {
+        Err(Error::UpgradeNotSupported.into())
+    }
+}
+
+/// Seed the host store with initial client and consensus states.
+///
+/// The given consensus state must match the one embedded in the client
+/// state.
+pub fn initialise<E>(
+    client_state: &ClientStateType,
+    ctx: &mut E,
+    client_id: &ClientId,
+    consensus_state: Any,
+) -> Result<(), ClientError>
+where
+    E: ClientExecutionContext,
+    E::ClientStateRef: From<ClientStateType>,
+    E::ConsensusStateRef: From<ConsensusStateType>,
+{
+    let sm_consensus_state = ConsensusStateType::try_from(consensus_state)?;
+
+    if sm_consensus_state != client_state.consensus_state {
+        return Err(ClientError::ClientSpecific {
+            description: "consensus state does not match the client state's consensus state"
+                .to_string(),
+        });
+    }
+
+    let latest_height = client_state.latest_height();
+
+    ctx.store_client_state(
+        ClientStatePath::new(client_id.clone()),
+        client_state.clone().into(),
+    )?;
+    ctx.store_consensus_state(
+        ClientConsensusStatePath::new(
+            client_id.clone(),
+            latest_height.revision_number(),
+            latest_height.revision_height(),
+        ),
+        sm_consensus_state.into(),
+    )?;
+
+    Ok(())
+}
+
+/// Applies a verified header: installs the new public key and diversifier,
+/// and increments the client sequence.
+///
+/// Returns the new height of the client, i.e. its incremented sequence.
+pub fn update_state<E>(
+    client_state: &ClientStateType,
+    ctx: &mut E,
+    client_id: &ClientId,
+    header: Any,
+) -> Result<Vec<Height>, ClientError>
+where
+    E: ClientExecutionContext,
+    E::ClientStateRef: From<ClientStateType>,
+    E::ConsensusStateRef: From<ConsensusStateType>,
+{
+    let header = SmHeader::try_from(header)?;
+
+    let new_consensus_state = ConsensusStateType::new(
+        header.new_public_key,
+        header.new_diversifier,
+        header.timestamp,
+    );
+    let new_client_state = ClientStateType {
+        sequence: client_state
+            .sequence
+            .checked_add(1)
+            .ok_or(ClientError::ClientSpecific {
+                description: "solo machine sequence overflow".to_string(),
+            })?,
+        is_frozen: false,
+        consensus_state: new_consensus_state.clone(),
+    };
+    let new_height = new_client_state.latest_height();
+
+    ctx.store_consensus_state(
+        ClientConsensusStatePath::new(
+            client_id.clone(),
+            new_height.revision_number(),
+            new_height.revision_height(),
+        ),
+        new_consensus_state.into(),
+    )?;
+    ctx.store_client_state(
+        ClientStatePath::new(client_id.clone()),
+        new_client_state.into(),
+    )?;
+
+    Ok(vec![new_height])
+}
+
+/// Freezes the client after misbehaviour has been detected and verified.
+pub fn update_on_misbehaviour<E>(
+    client_state: &ClientStateType,
+    ctx: &mut E,
+    client_id: &ClientId,
+) -> Result<(), ClientError>
+where
+    E: ClientExecutionContext,
+    E::ClientStateRef: From<ClientStateType>,
+{
+    let frozen_client_state = client_state.clone().with_frozen();
+
+    ctx.store_client_state(
+        ClientStatePath::new(client_id.clone()),
+        frozen_client_state.into(),
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,160 @@
+use ibc_core_client::context::client_state::ClientStateValidation;
+use ibc_core_client::context::ClientValidationContext;
+use ibc_core_client::types::error::ClientError;
+use ibc_core_client::types::Status;
+use ibc_core_host::types::identifiers::ClientId;
+use ibc_primitives::prelude::*;
+use ibc_primitives::proto::Any;
+
+use crate::error::Error;
+use crate::types::{
+    header_sign_bytes, ClientState as ClientStateType, Header as SmHeader,
+    Misbehaviour as SmMisbehaviour, SignBytes, SignatureAndData, SOLOMACHINE_HEADER_TYPE_URL,
+    SOLOMACHINE_MISBEHAVIOUR_TYPE_URL,
+};
+
+impl<V> ClientStateValidation<V> for ClientStateType
+where
+    V: ClientValidationContext,
+{
+    fn verify_client_message(
+        &self,
+        _ctx: &V,
+        _client_id: &ClientId,
+        client_message: Any,
+    ) -> Result<(), ClientError> {
+        verify_client_message(self, client_message)
+    }
+
+    fn check_for_misbehaviour(
+        &self,
+        _ctx: &V,
+        _client_id: &ClientId,
+        client_message: Any,
+    ) -> Result<bool, ClientError> {
+        check_for_misbehaviour(client_message)
+    }
+
+    fn status(&self, _ctx: &V, _client_id: &ClientId) -> Result<Status, ClientError> {
+        Ok(status(self))
+    }
+}
+
+/// Verify the client message as part of the client state validation process.
+///
+/// Note that this function is typically implemented as part of the
+/// [`ClientStateValidation`] trait, but has been made a standalone function
+/// in order to make the ClientState APIs more flexible.
+pub fn verify_client_message(
+    client_state: &ClientStateType,
+    client_message: Any,
+) -> Result<(), ClientError> {
+    match client_message.type_url.as_str() {
+        SOLOMACHINE_HEADER_TYPE_URL => {
+            let header = SmHeader::try_from(client_message)?;
+            verify_header(client_state, &header)
+        }
+        SOLOMACHINE_MISBEHAVIOUR_TYPE_URL => {
+            let misbehaviour = SmMisbehaviour::try_from(client_message)?;
+            verify_misbehaviour(client_state, &misbehaviour)
+        }
+        _ => Err(ClientError::InvalidUpdateClientMessage),
+    }
+}
+
+/// Verifies that `header` was signed by the current public key of the solo
+/// machine, over its current sequence and diversifier.
+pub fn verify_header(client_state: &ClientStateType, header: &SmHeader) -> Result<(), ClientError> {
+    header.validate_basic()?;
+
+    let consensus_state = &client_state.consensus_state;
+
+    if header.timestamp < consensus_state.timestamp {
+        return Err(Error::TimestampTooLow {
+            given: header.timestamp,
+            min: consensus_state.timestamp,
+        }
+        .into());
+    }
+
+    let sign_bytes = header_sign_bytes(client_state.sequence, consensus_state, header);
+
+    consensus_state
+        .public_key
+        .verify_signature(&header.signature, &sign_bytes.encode_vec())?;
+
+    Ok(())
+}
+
+/// Verifies that both signatures of `misbehaviour` were produced by the
+/// current public key of the solo machine at the misbehaviour's sequence.
+pub fn verify_misbehaviour(
+    client_state: &ClientStateType,
+    misbehaviour: &SmMisbehaviour,
+) -> Result<(), ClientError> {
+    misbehaviour.validate_basic()?;
+
+    verify_signature_and_data_at(
+        client_state,
+        misbehaviour.sequence,
+        &misbehaviour.signature_one,
+    )?;
+    verify_signature_and_data_at(
+        client_state,
+        misbehaviour.sequence,
+        &misbehaviour.signature_two,
+    )?;
+
+    Ok(())
+}
+
+fn verify_signature_and_data_at(
+    client_state: &ClientStateType,
+    sequence: u64,
+    signature_and_data: &SignatureAndData,
+) -> Result<(), ClientError> {
+    let consensus_state = &client_state.consensus_state;
+
+    if signature_and_data.timestamp < consensus_state.timestamp {
+        return Err(Error::TimestampTooLow {
+            given: signature_and_data.timestamp,
+            min: consensus_state.timestamp,
+        }
+        .into());
+    }
+
+    let sign_bytes = SignBytes {
+        sequence,
+        timestamp: signature_and_data.timestamp,
+        diversifier: consensus_state.diversifier.clone(),
+        path: signature_and_data.path.clone(),
+        data: signature_and_data.data.clone(),
+    };
+
+    consensus_state
+        .public_key
+        .verify_signature(&signature_and_data.signature, &sign_bytes.encode_vec())?;
+
+    Ok(())
+}
+
+/// Checks for misbehaviour in the given client message. A verified solo
+/// machine `Misbehaviour` is always evidence of misbehaviour, while a verified
+/// `Header` never is.
+pub fn check_for_misbehaviour(client_message: Any) -> Result<bool, ClientError> {
+    match client_message.type_url.as_str() {
+        SOLOMACHINE_HEADER_TYPE_URL => Ok(false),
+        SOLOMACHINE_MISBEHAVIOUR_TYPE_URL => Ok(true),
+        _ => Err(ClientError::InvalidUpdateClientMessage),
+    }
+}
+
+/// Returns the status of the solo machine client: it is active unless it has
+/// been frozen, as its consensus state never expires.
+pub fn status(client_state: &ClientStateType) -> Status {
+    if client_state.is_frozen {
+        Status::Frozen
+    } else {
+        Status::Active
+    }
+}
@@ -0,0 +1,74 @@
+//! Defines the solo machine light client's error type
+
+use displaydoc::Display;
+use ibc_core_client::types::error::ClientError;
+use ibc_core_commitment_types::error::CommitmentError;
+use ibc_primitives::prelude::*;
+use prost::DecodeError;
+
+/// The main error type
+#[derive(Debug, Display)]
+pub enum Error {
+    /// invalid raw client state: `{reason}`
+    InvalidRawClientState { reason: String },
+    /// invalid raw consensus state: `{reason}`
+    InvalidRawConsensusState { reason: String },
+    /// invalid raw header: `{reason}`
+    InvalidRawHeader { reason: String },
+    /// invalid raw misbehaviour: `{reason}`
+    InvalidRawMisbehaviour { reason: String },
+    /// missing consensus state
+    MissingConsensusState,
+    /// missing public key
+    MissingPublicKey,
+    /// unsupported public key type `{type_url}`
+    UnsupportedPublicKey { type_url: String },
+    /// invalid public key: `{reason}`
+    InvalidPublicKey { reason: String },
+    /// invalid signature data: `{reason}`
+    InvalidSignatureData { reason: String },
+    /// signature verification failed
+    SignatureVerificationFailed,
+    /// invalid proof: `{reason}`
+    InvalidProof { reason: String },
+    /// invalid commitment path: `{0}`
+    InvalidCommitmentPath(CommitmentError),
+    /// client is frozen
+    FrozenClient,
+    /// the sequence `{given}` does not match the client sequence `{expected}`
+    SequenceMismatch { given: u64, expected: u64 },
+    /// timestamp `{given}` is lower than the consensus state timestamp `{min}`
+    TimestampTooLow { given: u64, min: u64 },
+    /// solo machine clients cannot be upgraded
+    UpgradeNotSupported,
+    /// solo machine clients cannot verify proofs, as their sequence is not advanced once verified
+    ProofVerificationNotSupported,
+    /// decoding error: `{0}`
+    Decode(DecodeError),
+}
+
+impl core::error::Error for Error {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match &self {
+            Self::InvalidCommitmentPath(e) => Some(e),
+            // `prost` implements the trait only with `std`
+            #[cfg(feature = "std")]
+            Self::Decode(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<Error> for ClientError {
+    fn from(e: Error) -> Self {
+        Self::ClientSpecific {
+            description: e.to_string(),
+        }
+    }
+}
+
+impl From<DecodeError> for Error {
+    fn from(e: DecodeError) -> Self {
+        Self::Decode(e)
+    }
+}
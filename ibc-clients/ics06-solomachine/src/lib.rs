@@ -0,0 +1,42 @@
+//! ICS 06: Solo machine light client implementation.
+//!
+//! A solo machine is a standalone process, such as a mobile phone, a browser
+//! or an off-chain bridge, controlled by a single public key. Rather than
+//! proving state against a commitment root, a solo machine signs every value
+//! it asserts together with its current sequence and diversifier, and the
+//! client verifies those signatures.
+//!
+//! The client does not verify the proofs of the core handlers yet, as they
+//! cannot advance its sequence once a proof is verified, which would let the
+//! signed proofs be replayed.
+#![no_std]
+#![forbid(unsafe_code)]
+#![cfg_attr(not(test), deny(clippy::unwrap_used))]
+#![cfg_attr(not(test), deny(clippy::disallowed_methods, clippy::disallowed_types))]
+#![deny(
+    warnings,
+    trivial_casts,
+    trivial_numeric_casts,
+    unused_import_braces,
+    unused_qualifications,
+    rust_2018_idioms
+)]
+
+#[cfg(any(test, feature = "std"))]
+extern crate std;
+
+pub mod client_state;
+pub mod consensus_state;
+pub mod error;
+pub mod types;
+
+use core::str::FromStr;
+
+use ibc_core_host::types::identifiers::ClientType;
+
+pub const SOLOMACHINE_CLIENT_TYPE: &str = "06-solomachine";
+
+/// Returns the solo machine `ClientType`
+pub fn client_type() -> ClientType {
+    ClientType::from_str(SOLOMACHINE_CLIENT_TYPE).expect("Never fails because it's valid")
+}
@@ -0,0 +1,22 @@
+//! Data structures and domain types of the solo machine light client.
+
+mod client_state;
+mod consensus_state;
+mod header;
+mod misbehaviour;
+mod proof;
+mod public_key;
+mod sign_bytes;
+
+pub use client_state::*;
+pub use consensus_state::*;
+pub use header::*;
+pub use misbehaviour::*;
+pub use proof::*;
+pub use public_key::*;
+pub use sign_bytes::*;
+
+/// Re-exports the raw solo machine protobuf types.
+pub mod proto {
+    pub use ibc_proto::ibc::lightclients::solomachine::v3;
+}
@@ -0,0 +1,119 @@
+//! Defines the client state type for the solo machine light client.
+
+use ibc_core_client::types::error::ClientError;
+use ibc_core_client::types::Height;
+use ibc_primitives::prelude::*;
+use ibc_primitives::proto::{Any, Protobuf};
+use ibc_proto::ibc::lightclients::solomachine::v3::ClientState as RawSmClientState;
+
+use super::consensus_state::ConsensusState;
+use crate::error::Error;
+
+pub const SOLOMACHINE_CLIENT_STATE_TYPE_URL: &str = "/ibc.lightclients.solomachine.v3.ClientState";
+
+/// Contains the state of a solo machine client.
+///
+/// Solo machines do not have a notion of height: every signature they produce
+/// is bound to a monotonically increasing `sequence`, which is exposed as the
+/// revision height of the client (with a revision number of 0).
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ClientState {
+    pub sequence: u64,
+    pub is_frozen: bool,
+    pub consensus_state: ConsensusState,
+}
+
+impl ClientState {
+    pub fn new(sequence: u64, consensus_state: ConsensusState) -> Result<Self, Error> {
+        let client_state = Self {
+            sequence,
+            is_frozen: false,
+            consensus_state,
+        };
+        client_state.validate()?;
+        Ok(client_state)
+    }
+
+    pub fn validate(&self) -> Result<(), Error> {
+        if self.sequence == 0 {
+            return Err(Error::InvalidRawClientState {
+                reason: "sequence cannot be zero".to_string(),
+            });
+        }
+        Ok(())
+    }
+
+    /// The height of a solo machine client is its current sequence.
+    pub fn latest_height(&self) -> Height {
+        Height::new(0, self.sequence).expect("sequence is validated to be non-zero")
+    }
+
+    pub fn with_frozen(self) -> Self {
+        Self {
+            is_frozen: true,
+            ..self
+        }
+    }
+}
+
+impl Protobuf<RawSmClientState> for ClientState {}
+
+impl TryFrom<RawSmClientState> for ClientState {
+    type Error = Error;
+
+    fn try_from(raw: RawSmClientState) -> Result<Self, Self::Error> {
+        let client_state = Self {
+            sequence: raw.sequence,
+            is_frozen: raw.is_frozen,
+            consensus_state: raw
+                .consensus_state
+                .ok_or(Error::MissingConsensusState)?
+                .try_into()?,
+        };
+        client_state.validate()?;
+        Ok(client_state)
+    }
+}
+
+impl From<ClientState> for RawSmClientState {
+    fn from(value: ClientState) -> Self {
+        Self {
+            sequence: value.sequence,
+            is_frozen: value.is_frozen,
+            consensus_state: Some(value.consensus_state.into()),
+        }
+    }
+}
+
+impl Protobuf<Any> for ClientState {}
+
+impl TryFrom<Any> for ClientState {
+    type Error = ClientError;
+
+    fn try_from(raw: Any) -> Result<Self, Self::Error> {
+        fn decode_client_state(value: &[u8]) -> Result<ClientState, ClientError> {
+            let client_state =
+                Protobuf::<RawSmClientState>::decode(value).map_err(|e| ClientError::Other {
+                    description: e.to_string(),
+                })?;
+            Ok(client_state)
+        }
+
+        match raw.type_url.as_str() {
+            SOLOMACHINE_CLIENT_STATE_TYPE_URL => decode_client_state(&raw.value),
+            _ => Err(ClientError::UnknownClientStateType {
+                client_state_type: raw.type_url,
+            }),
+        }
+    }
+}
+
+impl From<ClientState> for Any {
+    fn from(client_state: ClientState) -> Self {
+        Any {
+            type_url: SOLOMACHINE_CLIENT_STATE_TYPE_URL.to_string(),
+            value: Protobuf::<RawSmClientState>::encode_vec(client_state),
+        }
+    }
+}
@@ -0,0 +1,129 @@
+//! Defines the consensus state type for the solo machine light client.
+
+use ibc_core_client::types::error::ClientError;
+use ibc_core_commitment_types::commitment::CommitmentRoot;
+use ibc_primitives::prelude::*;
+use ibc_primitives::proto::{Any, Protobuf};
+use ibc_primitives::Timestamp;
+use ibc_proto::ibc::lightclients::solomachine::v3::ConsensusState as RawSmConsensusState;
+
+use super::public_key::PublicKey;
+use crate::error::Error;
+
+pub const SOLOMACHINE_CONSENSUS_STATE_TYPE_URL: &str =
+    "/ibc.lightclients.solomachine.v3.ConsensusState";
+
+/// Defines the solo machine consensus state, i.e. the public key currently
+/// controlling the solo machine, its diversifier and the latest timestamp it
+/// signed over.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ConsensusState {
+    pub public_key: PublicKey,
+    /// Arbitrary string chosen by the solo machine operator, included in all
+    /// sign bytes to prevent signatures from being reused across clients
+    /// sharing the same public key.
+    pub diversifier: String,
+    /// Unix timestamp in nanoseconds.
+    pub timestamp: u64,
+    /// Solo machines have no commitment root; this is always empty and only
+    /// exists to satisfy the `ConsensusState` interface.
+    #[cfg_attr(feature = "serde", serde(skip, default = "empty_root"))]
+    root: CommitmentRoot,
+}
+
+impl ConsensusState {
+    pub fn new(public_key: PublicKey, diversifier: String, timestamp: u64) -> Self {
+        Self {
+            public_key,
+            diversifier,
+            timestamp,
+            root: empty_root(),
+        }
+    }
+
+    pub fn root(&self) -> &CommitmentRoot {
+        &self.root
+    }
+
+    pub fn timestamp(&self) -> Timestamp {
+        Timestamp::from_nanoseconds(self.timestamp)
+            .expect("timestamp is validated on construction from its raw type")
+    }
+}
+
+fn empty_root() -> CommitmentRoot {
+    CommitmentRoot::from_bytes(&[])
+}
+
+impl Protobuf<RawSmConsensusState> for ConsensusState {}
+
+impl TryFrom<RawSmConsensusState> for ConsensusState {
+    type Error = Error;
+
+    fn try_from(raw: RawSmConsensusState) -> Result<Self, Self::Error> {
+        let public_key = raw.public_key.ok_or(Error::MissingPublicKey)?.try_into()?;
+
+        if raw.diversifier.trim().is_empty() {
+            return Err(Error::InvalidRawConsensusState {
+                reason: "diversifier cannot be empty".to_string(),
+            });
+        }
+
+        if raw.timestamp == 0 {
+            return Err(Error::InvalidRawConsensusState {
+                reason: "timestamp cannot be zero".to_string(),
+            });
+        }
+
+        Timestamp::from_nanoseconds(raw.timestamp).map_err(|e| {
+            Error::InvalidRawConsensusState {
+                reason: e.to_string(),
+            }
+        })?;
+
+        Ok(Self::new(public_key, raw.diversifier, raw.timestamp))
+    }
+}
+
+impl From<ConsensusState> for RawSmConsensusState {
+    fn from(value: ConsensusState) -> Self {
+        Self {
+            public_key: Some(value.public_key.into()),
+            diversifier: value.diversifier,
+            timestamp: value.timestamp,
+        }
+    }
+}
+
+impl Protobuf<Any> for ConsensusState {}
+
+impl TryFrom<Any> for ConsensusState {
+    type Error = ClientError;
+
+    fn try_from(raw: Any) -> Result<Self, Self::Error> {
+        fn decode_consensus_state(value: &[u8]) -> Result<ConsensusState, ClientError> {
+            let consensus_state =
+                Protobuf::<RawSmConsensusState>::decode(value).map_err(|e| ClientError::Other {
+                    description: e.to_string(),
+                })?;
+            Ok(consensus_state)
+        }
+
+        match raw.type_url.as_str() {
+            SOLOMACHINE_CONSENSUS_STATE_TYPE_URL => decode_consensus_state(&raw.value),
+            _ => Err(ClientError::UnknownConsensusStateType {
+                consensus_state_type: raw.type_url,
+            }),
+        }
+    }
+}
+
+impl From<ConsensusState> for Any {
+    fn from(consensus_state: ConsensusState) -> Self {
+        Any {
+            type_url: SOLOMACHINE_CONSENSUS_STATE_TYPE_URL.to_string(),
+            value: Protobuf::<RawSmConsensusState>::encode_vec(consensus_state),
+        }
+    }
+}
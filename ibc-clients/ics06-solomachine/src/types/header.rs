@@ -0,0 +1,111 @@
+//! Defines the header type for the solo machine light client.
+
+use ibc_core_client::types::error::ClientError;
+use ibc_primitives::prelude::*;
+use ibc_primitives::proto::{Any, Protobuf};
+use ibc_proto::ibc::lightclients::solomachine::v3::Header as RawSmHeader;
+
+use super::public_key::PublicKey;
+use crate::error::Error;
+
+pub const SOLOMACHINE_HEADER_TYPE_URL: &str = "/ibc.lightclients.solomachine.v3.Header";
+
+/// Defines a solo machine header, used to rotate the public key and/or the
+/// diversifier of the solo machine. It must be signed by the current public
+/// key.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Header {
+    /// Unix timestamp in nanoseconds.
+    pub timestamp: u64,
+    pub signature: Vec<u8>,
+    pub new_public_key: PublicKey,
+    pub new_diversifier: String,
+}
+
+impl Header {
+    pub fn validate_basic(&self) -> Result<(), Error> {
+        if self.timestamp == 0 {
+            return Err(Error::InvalidRawHeader {
+                reason: "timestamp cannot be zero".to_string(),
+            });
+        }
+
+        if self.signature.is_empty() {
+            return Err(Error::InvalidRawHeader {
+                reason: "signature cannot be empty".to_string(),
+            });
+        }
+
+        if self.new_diversifier.trim().is_empty() {
+            return Err(Error::InvalidRawHeader {
+                reason: "new diversifier cannot be empty".to_string(),
+            });
+        }
+
+        Ok(())
+    }
+}
+
+impl Protobuf<RawSmHeader> for Header {}
+
+impl TryFrom<RawSmHeader> for Header {
+    type Error = Error;
+
+    fn try_from(raw: RawSmHeader) -> Result<Self, Self::Error> {
+        let header = Self {
+            timestamp: raw.timestamp,
+            signature: raw.signature,
+            new_public_key: raw
+                .new_public_key
+                .ok_or(Error::MissingPublicKey)?
+                .try_into()?,
+            new_diversifier: raw.new_diversifier,
+        };
+        header.validate_basic()?;
+        Ok(header)
+    }
+}
+
+impl From<Header> for RawSmHeader {
+    fn from(value: Header) -> Self {
+        Self {
+            timestamp: value.timestamp,
+            signature: value.signature,
+            new_public_key: Some(value.new_public_key.into()),
+            new_diversifier: value.new_diversifier,
+        }
+    }
+}
+
+impl Protobuf<Any> for Header {}
+
+impl TryFrom<Any> for Header {
+    type Error = ClientError;
+
+    fn try_from(raw: Any) -> Result<Self, Self::Error> {
+        fn decode_header(value: &[u8]) -> Result<Header, ClientError> {
+            let header =
+                Protobuf::<RawSmHeader>::decode(value).map_err(|e| ClientError::Other {
+                    description: e.to_string(),
+                })?;
+            Ok(header)
+        }
+
+        match raw.type_url.as_str() {
+            SOLOMACHINE_HEADER_TYPE_URL => decode_header(&raw.value),
+            _ => Err(ClientError::UnknownHeaderType {
+                header_type: raw.type_url,
+            }),
+        }
+    }
+}
+
+impl From<Header> for Any {
+    fn from(header: Header) -> Self {
+        Any {
+            type_url: SOLOMACHINE_HEADER_TYPE_URL.to_string(),
+            value: Protobuf::<RawSmHeader>::encode_vec(header),
+        }
+    }
+}
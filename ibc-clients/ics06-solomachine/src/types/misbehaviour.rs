@@ -0,0 +1,181 @@
+//! Defines the misbehaviour type for the solo machine light client.
+
+use ibc_core_client::types::error::ClientError;
+use ibc_primitives::prelude::*;
+use ibc_primitives::proto::{Any, Protobuf};
+use ibc_proto::ibc::lightclients::solomachine::v3::{
+    Misbehaviour as RawSmMisbehaviour, SignatureAndData as RawSignatureAndData,
+};
+
+use crate::error::Error;
+
+pub const SOLOMACHINE_MISBEHAVIOUR_TYPE_URL: &str = "/ibc.lightclients.solomachine.v3.Misbehaviour";
+
+/// A signature over some `data` stored at `path`, produced by a solo machine
+/// at a given sequence and timestamp.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SignatureAndData {
+    pub signature: Vec<u8>,
+    pub path: Vec<u8>,
+    pub data: Vec<u8>,
+    /// Unix timestamp in nanoseconds.
+    pub timestamp: u64,
+}
+
+impl SignatureAndData {
+    pub fn validate_basic(&self) -> Result<(), Error> {
+        if self.signature.is_empty() {
+            return Err(Error::InvalidRawMisbehaviour {
+                reason: "signature cannot be empty".to_string(),
+            });
+        }
+        if self.path.is_empty() {
+            return Err(Error::InvalidRawMisbehaviour {
+                reason: "path cannot be empty".to_string(),
+            });
+        }
+        if self.data.is_empty() {
+            return Err(Error::InvalidRawMisbehaviour {
+                reason: "data cannot be empty".to_string(),
+            });
+        }
+        if self.timestamp == 0 {
+            return Err(Error::InvalidRawMisbehaviour {
+                reason: "timestamp cannot be zero".to_string(),
+            });
+        }
+        Ok(())
+    }
+}
+
+impl TryFrom<RawSignatureAndData> for SignatureAndData {
+    type Error = Error;
+
+    fn try_from(raw: RawSignatureAndData) -> Result<Self, Self::Error> {
+        let signature_and_data = Self {
+            signature: raw.signature,
+            path: raw.path,
+            data: raw.data,
+            timestamp: raw.timestamp,
+        };
+        signature_and_data.validate_basic()?;
+        Ok(signature_and_data)
+    }
+}
+
+impl From<SignatureAndData> for RawSignatureAndData {
+    fn from(value: SignatureAndData) -> Self {
+        Self {
+            signature: value.signature,
+            path: value.path,
+            data: value.data,
+            timestamp: value.timestamp,
+        }
+    }
+}
+
+/// Evidence of a solo machine signing two different messages at the same
+/// sequence.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Misbehaviour {
+    pub sequence: u64,
+    pub signature_one: SignatureAndData,
+    pub signature_two: SignatureAndData,
+}
+
+impl Misbehaviour {
+    pub fn validate_basic(&self) -> Result<(), Error> {
+        if self.sequence == 0 {
+            return Err(Error::InvalidRawMisbehaviour {
+                reason: "sequence cannot be zero".to_string(),
+            });
+        }
+
+        self.signature_one.validate_basic()?;
+        self.signature_two.validate_basic()?;
+
+        if self.signature_one.signature == self.signature_two.signature {
+            return Err(Error::InvalidRawMisbehaviour {
+                reason: "signatures cannot be equal".to_string(),
+            });
+        }
+
+        if self.signature_one.data == self.signature_two.data {
+            return Err(Error::InvalidRawMisbehaviour {
+                reason: "data signed cannot be equal".to_string(),
+            });
+        }
+
+        Ok(())
+    }
+}
+
+impl Protobuf<RawSmMisbehaviour> for Misbehaviour {}
+
+impl TryFrom<RawSmMisbehaviour> for Misbehaviour {
+    type Error = Error;
+
+    fn try_from(raw: RawSmMisbehaviour) -> Result<Self, Self::Error> {
+        let misbehaviour = Self {
+            sequence: raw.sequence,
+            signature_one: raw
+                .signature_one
+                .ok_or(Error::InvalidRawMisbehaviour {
+                    reason: "missing signature one".to_string(),
+                })?
+                .try_into()?,
+            signature_two: raw
+                .signature_two
+                .ok_or(Error::InvalidRawMisbehaviour {
+                    reason: "missing signature two".to_string(),
+                })?
+                .try_into()?,
+        };
+        misbehaviour.validate_basic()?;
+        Ok(misbehaviour)
+    }
+}
+
+impl From<Misbehaviour> for RawSmMisbehaviour {
+    fn from(value: Misbehaviour) -> Self {
+        Self {
+            sequence: value.sequence,
+            signature_one: Some(value.signature_one.into()),
+            signature_two: Some(value.signature_two.into()),
+        }
+    }
+}
+
+impl Protobuf<Any> for Misbehaviour {}
+
+impl TryFrom<Any> for Misbehaviour {
+    type Error = ClientError;
+
+    fn try_from(raw: Any) -> Result<Self, Self::Error> {
+        fn decode_misbehaviour(value: &[u8]) -> Result<Misbehaviour, ClientError> {
+            let misbehaviour =
+                Protobuf::<RawSmMisbehaviour>::decode(value).map_err(|e| ClientError::Other {
+                    description: e.to_string(),
+                })?;
+            Ok(misbehaviour)
+        }
+
+        match raw.type_url.as_str() {
+            SOLOMACHINE_MISBEHAVIOUR_TYPE_URL => decode_misbehaviour(&raw.value),
+            _ => Err(ClientError::UnknownMisbehaviourType {
+                misbehaviour_type: raw.type_url,
+            }),
+        }
+    }
+}
+
+impl From<Misbehaviour> for Any {
+    fn from(misbehaviour: Misbehaviour) -> Self {
+        Any {
+            type_url: SOLOMACHINE_MISBEHAVIOUR_TYPE_URL.to_string(),
+            value: Protobuf::<RawSmMisbehaviour>::encode_vec(misbehaviour),
+        }
+    }
+}
@@ -0,0 +1,71 @@
+//! Defines the proof type carried by solo machine membership proofs.
+
+use ibc_core_commitment_types::commitment::CommitmentProofBytes;
+use ibc_primitives::prelude::*;
+use ibc_proto::ibc::lightclients::solomachine::v3::TimestampedSignatureData as RawTimestampedSignatureData;
+use prost::Message;
+
+use crate::error::Error;
+
+/// The proof a solo machine provides for (non-)membership verification: a
+/// signature over the asserted value together with the timestamp it was
+/// produced at.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TimestampedSignatureData {
+    pub signature_data: Vec<u8>,
+    /// Unix timestamp in nanoseconds.
+    pub timestamp: u64,
+}
+
+impl TryFrom<RawTimestampedSignatureData> for TimestampedSignatureData {
+    type Error = Error;
+
+    fn try_from(raw: RawTimestampedSignatureData) -> Result<Self, Self::Error> {
+        if raw.signature_data.is_empty() {
+            return Err(Error::InvalidProof {
+                reason: "signature data cannot be empty".to_string(),
+            });
+        }
+
+        if raw.timestamp == 0 {
+            return Err(Error::InvalidProof {
+                reason: "timestamp cannot be zero".to_string(),
+            });
+        }
+
+        Ok(Self {
+            signature_data: raw.signature_data,
+            timestamp: raw.timestamp,
+        })
+    }
+}
+
+impl From<TimestampedSignatureData> for RawTimestampedSignatureData {
+    fn from(value: TimestampedSignatureData) -> Self {
+        Self {
+            signature_data: value.signature_data,
+            timestamp: value.timestamp,
+        }
+    }
+}
+
+impl TryFrom<&CommitmentProofBytes> for TimestampedSignatureData {
+    type Error = Error;
+
+    fn try_from(proof: &CommitmentProofBytes) -> Result<Self, Self::Error> {
+        RawTimestampedSignatureData::decode(proof.as_ref())?.try_into()
+    }
+}
+
+impl TryFrom<TimestampedSignatureData> for CommitmentProofBytes {
+    type Error = Error;
+
+    fn try_from(value: TimestampedSignatureData) -> Result<Self, Self::Error> {
+        RawTimestampedSignatureData::from(value)
+            .encode_to_vec()
+            .try_into()
+            .map_err(|_| Error::InvalidProof {
+                reason: "proof cannot be empty".to_string(),
+            })
+    }
+}
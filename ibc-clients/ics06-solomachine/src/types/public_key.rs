@@ -0,0 +1,107 @@
+//! Defines the public key type of solo machines along with the verification of
+//! the signatures they produce.
+
+use ed25519_consensus::{Signature, VerificationKey};
+use ibc_primitives::prelude::*;
+use ibc_primitives::proto::Any;
+use ibc_proto::cosmos::crypto::ed25519::PubKey as RawEd25519PubKey;
+use ibc_proto::cosmos::tx::signing::v1beta1::signature_descriptor::data::{
+    Single as RawSingleSignatureData, Sum as RawSignatureDataSum,
+};
+use ibc_proto::cosmos::tx::signing::v1beta1::signature_descriptor::Data as RawSignatureData;
+use ibc_proto::cosmos::tx::signing::v1beta1::SignMode;
+use prost::Message;
+
+use crate::error::Error;
+
+pub const ED25519_PUBLIC_KEY_TYPE_URL: &str = "/cosmos.crypto.ed25519.PubKey";
+
+/// Public key controlling a solo machine.
+///
+/// Public keys are carried over the wire as `Any`-encoded Cosmos SDK keys.
+/// Only Ed25519 keys are currently supported.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum PublicKey {
+    Ed25519(VerificationKey),
+}
+
+impl PublicKey {
+    /// Verifies that `signature_data`, a protobuf-encoded
+    /// `cosmos.tx.signing.v1beta1.SignatureDescriptor.Data` as produced by
+    /// solo machines, holds a valid signature of `sign_bytes` under this key.
+    pub fn verify_signature(&self, signature_data: &[u8], sign_bytes: &[u8]) -> Result<(), Error> {
+        let signature = decode_single_signature(signature_data)?;
+
+        match self {
+            Self::Ed25519(key) => {
+                let signature = Signature::try_from(signature.as_slice()).map_err(|e| {
+                    Error::InvalidSignatureData {
+                        reason: format!("{e:?}"),
+                    }
+                })?;
+                key.verify(&signature, sign_bytes)
+                    .map_err(|_| Error::SignatureVerificationFailed)
+            }
+        }
+    }
+}
+
+/// Encodes a raw signature the way solo machines are expected to submit it,
+/// i.e. as a single-signer `SignatureDescriptor.Data` in direct sign mode.
+pub fn encode_single_signature(signature: Vec<u8>) -> Vec<u8> {
+    RawSignatureData {
+        sum: Some(RawSignatureDataSum::Single(RawSingleSignatureData {
+            mode: SignMode::Direct as i32,
+            signature,
+        })),
+    }
+    .encode_to_vec()
+}
+
+fn decode_single_signature(signature_data: &[u8]) -> Result<Vec<u8>, Error> {
+    match RawSignatureData::decode(signature_data)?.sum {
+        Some(RawSignatureDataSum::Single(single)) => Ok(single.signature),
+        Some(RawSignatureDataSum::Multi(_)) => Err(Error::InvalidSignatureData {
+            reason: "multi-signatures are not supported".to_string(),
+        }),
+        None => Err(Error::InvalidSignatureData {
+            reason: "empty signature data".to_string(),
+        }),
+    }
+}
+
+impl TryFrom<Any> for PublicKey {
+    type Error = Error;
+
+    fn try_from(raw: Any) -> Result<Self, Self::Error> {
+        match raw.type_url.as_str() {
+            ED25519_PUBLIC_KEY_TYPE_URL => {
+                let raw_key = RawEd25519PubKey::decode(raw.value.as_slice())?;
+                let key = VerificationKey::try_from(raw_key.key.as_slice()).map_err(|e| {
+                    Error::InvalidPublicKey {
+                        reason: format!("{e:?}"),
+                    }
+                })?;
+                Ok(Self::Ed25519(key))
+            }
+            _ => Err(Error::UnsupportedPublicKey {
+                type_url: raw.type_url,
+            }),
+        }
+    }
+}
+
+impl From<PublicKey> for Any {
+    fn from(public_key: PublicKey) -> Self {
+        match public_key {
+            PublicKey::Ed25519(key) => Any {
+                type_url: ED25519_PUBLIC_KEY_TYPE_URL.to_string(),
+                value: RawEd25519PubKey {
+                    key: key.as_bytes().to_vec(),
+                }
+                .encode_to_vec(),
+            },
+        }
+    }
+}
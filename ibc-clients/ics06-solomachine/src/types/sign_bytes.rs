@@ -0,0 +1,71 @@
+//! Construction of the bytes a solo machine signs over.
+//!
+//! Every solo machine signature commits to the client sequence, a timestamp,
+//! the diversifier, and a `(path, data)` pair, bundled together into a
+//! protobuf-encoded `SignBytes` message.
+
+use ibc_core_commitment_types::commitment::CommitmentPrefix;
+use ibc_core_commitment_types::merkle::apply_prefix;
+use ibc_core_host::types::path::Path;
+use ibc_primitives::encode_canonical;
+use ibc_primitives::prelude::*;
+use ibc_proto::ibc::lightclients::solomachine::v3::{
+    HeaderData as RawHeaderData, SignBytes as RawSignBytes,
+};
+
+use super::header::Header;
+use super::ConsensusState;
+
+/// Path signed over by headers rotating the solo machine's public key.
+pub const SENTINEL_HEADER_PATH: &str = "solomachine:header";
+
+/// The data a solo machine signs over.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SignBytes {
+    pub sequence: u64,
+    pub timestamp: u64,
+    pub diversifier: String,
+    pub path: Vec<u8>,
+    pub data: Vec<u8>,
+}
+
+impl SignBytes {
+    /// Returns the canonical protobuf encoding of these sign bytes, i.e. the
+    /// message passed to the signature scheme.
+    pub fn encode_vec(self) -> Vec<u8> {
+        encode_canonical(&RawSignBytes {
+            sequence: self.sequence,
+            timestamp: self.timestamp,
+            diversifier: self.diversifier,
+            path: self.path,
+            data: self.data,
+        })
+    }
+}
+
+/// Returns the sign bytes a header updating a client at `sequence` with
+/// `consensus_state` must be signed over.
+pub fn header_sign_bytes(
+    sequence: u64,
+    consensus_state: &ConsensusState,
+    header: &Header,
+) -> SignBytes {
+    let data = encode_canonical(&RawHeaderData {
+        new_pub_key: Some(header.new_public_key.clone().into()),
+        new_diversifier: header.new_diversifier.clone(),
+    });
+
+    SignBytes {
+        sequence,
+        timestamp: header.timestamp,
+        diversifier: consensus_state.diversifier.clone(),
+        path: SENTINEL_HEADER_PATH.as_bytes().to_vec(),
+        data,
+    }
+}
+
+/// Returns the encoding of `path`, prefixed by the counterparty's commitment
+/// `prefix`, as included in the sign bytes of membership proofs.
+pub fn commitment_path_bytes(prefix: &CommitmentPrefix, path: &Path) -> Vec<u8> {
+    encode_canonical(&apply_prefix(prefix, vec![path.to_string()]))
+}
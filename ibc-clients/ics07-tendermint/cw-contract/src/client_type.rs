@@ -0,0 +1,13 @@
+//! Selects the Tendermint light client as the client run by the contract.
+
+use ibc_client_cw::api::ClientType;
+use ibc_client_tendermint::client_state::ClientState;
+use ibc_client_tendermint::consensus_state::ConsensusState;
+
+/// The Tendermint light client, run by the contract.
+pub struct TendermintClient;
+
+impl<'a> ClientType<'a> for TendermintClient {
+    type ClientState = ClientState;
+    type ConsensusState = ConsensusState;
+}
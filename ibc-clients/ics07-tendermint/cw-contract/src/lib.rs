@@ -0,0 +1,19 @@
+//! CosmWasm contract running the ICS-07 Tendermint light client as an ICS-08
+//! Wasm light client, built on the `ibc-client-cw` adapter.
+//!
+//! Enable the `library` feature to depend on the contract without exporting
+//! its entry points.
+#![forbid(unsafe_code)]
+#![cfg_attr(not(test), deny(clippy::unwrap_used))]
+#![cfg_attr(not(test), deny(clippy::disallowed_methods, clippy::disallowed_types))]
+#![deny(
+    warnings,
+    trivial_casts,
+    trivial_numeric_casts,
+    unused_import_braces,
+    unused_qualifications,
+    rust_2018_idioms
+)]
+
+pub mod client_type;
+pub mod entrypoint;
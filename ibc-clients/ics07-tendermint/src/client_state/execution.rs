@@ -1,3 +1,5 @@
+#[cfg(feature = "zk")]
+use ibc_client_tendermint_types::TENDERMINT_ZK_HEADER_TYPE_URL;
 use ibc_client_tendermint_types::{
     ClientState as ClientStateType, ConsensusState as ConsensusStateType, Header as TmHeader,
 };
@@ -9,6 +11,8 @@ use ibc_core_host::types::path::{ClientConsensusStatePath, ClientStatePath};
 use ibc_primitives::prelude::*;
 use ibc_primitives::proto::Any;
 
+#[cfg(feature = "zk")]
+use super::update_state_with_zk;
 use super::ClientState;
 use crate::context::{
     ConsensusStateConverter, ExecutionContext as TmExecutionContext,
@@ -36,6 +40,11 @@ where
         client_id: &ClientId,
         header: Any,
     ) -> Result<Vec<Height>, ClientError> {
+        #[cfg(feature = "zk")]
+        if header.type_url == TENDERMINT_ZK_HEADER_TYPE_URL {
+            return update_state_with_zk(self.inner(), ctx, client_id, header);
+        }
+
         update_state(self.inner(), ctx, client_id, header)
     }
 
@@ -63,6 +72,22 @@ where
             upgraded_consensus_state,
         )
     }
+
+    fn update_on_recovery(
+        &self,
+        ctx: &mut E,
+        subject_client_id: &ClientId,
+        substitute_client_id: &ClientId,
+        substitute_client_state: Any,
+    ) -> Result<(), ClientError> {
+        update_on_recovery(
+            self.inner(),
+            ctx,
+            subject_client_id,
+            substitute_client_id,
+            substitute_client_state,
+        )
+    }
 }
 
 /// Seed the host store with initial client and consensus states.
@@ -81,8 +106,8 @@ where
     E::ClientStateRef: From<ClientStateType>,
     E::ConsensusStateRef: ConsensusStateConverter,
 {
-    let host_timestamp = TmValidationContext::host_timestamp(ctx)?;
-    let host_height = TmValidationContext::host_height(ctx)?;
+    let host_timestamp = HostClock::host_timestamp(ctx)?;
+    let host_height = HostClock::host_height(ctx)?;
 
     let tm_consensus_state = ConsensusStateType::try_from(consensus_state)?;
 
@@ -147,8 +172,8 @@ where
         //
         // Do nothing.
     } else {
-        let host_timestamp = TmValidationContext::host_timestamp(ctx)?;
-        let host_height = TmValidationContext::host_height(ctx)?;
+        let host_timestamp = HostClock::host_timestamp(ctx)?;
+        let host_height = HostClock::host_height(ctx)?;
 
         let new_consensus_state = ConsensusStateType::from(header.clone());
         let new_client_state = client_state.clone().with_header(header)?;
@@ -267,8 +292,8 @@ where
     );
 
     let latest_height = new_client_state.latest_height;
-    let host_timestamp = TmValidationContext::host_timestamp(ctx)?;
-    let host_height = TmValidationContext::host_height(ctx)?;
+    let host_timestamp = HostClock::host_timestamp(ctx)?;
+    let host_height = HostClock::host_height(ctx)?;
 
     ctx.store_client_state(
         ClientStatePath::new(client_id.clone()),
@@ -292,6 +317,69 @@ where
     Ok(latest_height)
 }
 
+/// Commit the recovered client state, which takes the latest height, the
+/// trusting period and the chain ID of the substitute client, along with the
+/// latest consensus state of the substitute client, to the store.
+///
+/// Note that this function is typically implemented as part of the
+/// [`ClientStateExecution`] trait, but has been made a standalone function
+/// in order to make the ClientState APIs more flexible.
+pub fn update_on_recovery<E>(
+    subject_client_state: &ClientStateType,
+    ctx: &mut E,
+    subject_client_id: &ClientId,
+    substitute_client_id: &ClientId,
+    substitute_client_state: Any,
+) -> Result<(), ClientError>
+where
+    E: TmExecutionContext,
+    E::ClientStateRef: From<ClientStateType>,
+    E::ConsensusStateRef: ConsensusStateConverter,
+{
+    let substitute_client_state = ClientState::try_from(substitute_client_state)?.0;
+    let latest_height = substitute_client_state.latest_height;
+
+    let substitute_consensus_state: ConsensusStateType = ctx
+        .consensus_state(&ClientConsensusStatePath::new(
+            substitute_client_id.clone(),
+            latest_height.revision_number(),
+            latest_height.revision_height(),
+        ))?
+        .try_into()?;
+
+    let new_client_state = ClientStateType {
+        chain_id: substitute_client_state.chain_id,
+        trusting_period: substitute_client_state.trusting_period,
+        latest_height,
+        frozen_height: None,
+        ..subject_client_state.clone()
+    };
+
+    let host_timestamp = HostClock::host_timestamp(ctx)?;
+    let host_height = HostClock::host_height(ctx)?;
+
+    ctx.store_client_state(
+        ClientStatePath::new(subject_client_id.clone()),
+        new_client_state.into(),
+    )?;
+    ctx.store_consensus_state(
+        ClientConsensusStatePath::new(
+            subject_client_id.clone(),
+            latest_height.revision_number(),
+            latest_height.revision_height(),
+        ),
+        substitute_consensus_state.into(),
+    )?;
+    ctx.store_update_meta(
+        subject_client_id.clone(),
+        latest_height,
+        host_timestamp,
+        host_height,
+    )?;
+
+    Ok(())
+}
+
 /// Removes consensus states from the client store whose timestamps
 /// are less than or equal to the host timestamp. This ensures that
 /// the client store does not amass a buildup of stale consensus states.
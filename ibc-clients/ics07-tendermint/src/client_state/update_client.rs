@@ -6,6 +6,7 @@ use ibc_core_client::types::error::ClientError;
 use ibc_core_host::types::identifiers::ClientId;
 use ibc_core_host::types::path::ClientConsensusStatePath;
 use ibc_primitives::prelude::*;
+use ibc_primitives::Timestamp;
 use tendermint_light_client_verifier::types::{TrustedBlockState, UntrustedBlockState};
 use tendermint_light_client_verifier::Verifier;
 
@@ -24,6 +25,54 @@ where
     V: TmValidationContext,
     V::ConsensusStateRef: ConsensusStateConverter,
 {
+    verify_header_fields(client_state, header)?;
+
+    let trusted_client_cons_state_path = ClientConsensusStatePath::new(
+        client_id.clone(),
+        header.trusted_height.revision_number(),
+        header.trusted_height.revision_height(),
+    );
+    let trusted_consensus_state = ctx
+        .consensus_state(&trusted_client_cons_state_path)?
+        .try_into()?;
+
+    verify_header_against_trusted_state(
+        client_state,
+        &trusted_consensus_state,
+        header,
+        ctx.host_timestamp()?,
+        verifier,
+    )
+}
+
+/// Verifies the header against the consensus state of the client at its
+/// trusted height, as of the time `now`.
+///
+/// This is the verification [`verify_header`] performs, for the verifiers
+/// holding the trusted consensus state rather than a client context, e.g. the
+/// ones of the browser wallets and explorers.
+pub fn verify_header_with_trusted_state(
+    client_state: &ClientStateType,
+    trusted_consensus_state: &ConsensusStateType,
+    header: &TmHeader,
+    now: Timestamp,
+    verifier: &impl TmVerifier,
+) -> Result<(), ClientError> {
+    verify_header_fields(client_state, header)?;
+
+    verify_header_against_trusted_state(
+        client_state,
+        trusted_consensus_state,
+        header,
+        now,
+        verifier,
+    )
+}
+
+fn verify_header_fields(
+    client_state: &ClientStateType,
+    header: &TmHeader,
+) -> Result<(), ClientError> {
     // Checks that the header fields are valid.
     header.validate_basic()?;
 
@@ -31,66 +80,62 @@ where
     // to have the same revision number. We ensure this here.
     header.verify_chain_id_version_matches_height(&client_state.chain_id())?;
 
+    Ok(())
+}
+
+fn verify_header_against_trusted_state(
+    client_state: &ClientStateType,
+    trusted_consensus_state: &ConsensusStateType,
+    header: &TmHeader,
+    now: Timestamp,
+    verifier: &impl TmVerifier,
+) -> Result<(), ClientError> {
     // Delegate to tendermint-light-client, which contains the required checks
     // of the new header against the trusted consensus state.
-    {
-        let trusted_state = {
-            let trusted_client_cons_state_path = ClientConsensusStatePath::new(
-                client_id.clone(),
-                header.trusted_height.revision_number(),
-                header.trusted_height.revision_height(),
-            );
-            let trusted_consensus_state = ctx
-                .consensus_state(&trusted_client_cons_state_path)?
-                .try_into()?;
-
-            header.check_trusted_next_validator_set(&trusted_consensus_state)?;
-
-            TrustedBlockState {
-                chain_id: &client_state.chain_id.to_string().try_into().map_err(|e| {
-                    ClientError::Other {
-                        description: format!("failed to parse chain id: {}", e),
-                    }
-                })?,
-                header_time: trusted_consensus_state.timestamp(),
-                height: header
-                    .trusted_height
-                    .revision_height()
-                    .try_into()
-                    .map_err(|_| ClientError::ClientSpecific {
-                        description: Error::InvalidHeaderHeight {
-                            height: header.trusted_height.revision_height(),
-                        }
-                        .to_string(),
-                    })?,
-                next_validators: &header.trusted_next_validator_set,
-                next_validators_hash: trusted_consensus_state.next_validators_hash,
+    header.check_trusted_next_validator_set(trusted_consensus_state)?;
+
+    let trusted_state = TrustedBlockState {
+        chain_id: &client_state.chain_id.to_string().try_into().map_err(|e| {
+            ClientError::Other {
+                description: format!("failed to parse chain id: {}", e),
             }
-        };
-
-        let untrusted_state = UntrustedBlockState {
-            signed_header: &header.signed_header,
-            validators: &header.validator_set,
-            // NB: This will skip the
-            // VerificationPredicates::next_validators_match check for the
-            // untrusted state.
-            next_validators: None,
-        };
-
-        let options = client_state.as_light_client_options()?;
-        let now =
-            ctx.host_timestamp()?
-                .into_tm_time()
-                .ok_or_else(|| ClientError::ClientSpecific {
-                    description: "host timestamp is not a valid TM timestamp".to_string(),
-                })?;
-
-        // main header verification, delegated to the tendermint-light-client crate.
-        verifier
-            .verifier()
-            .verify_update_header(untrusted_state, trusted_state, &options, now)
-            .into_result()?;
-    }
+        })?,
+        header_time: trusted_consensus_state.timestamp(),
+        height: header
+            .trusted_height
+            .revision_height()
+            .try_into()
+            .map_err(|_| ClientError::ClientSpecific {
+                description: Error::InvalidHeaderHeight {
+                    height: header.trusted_height.revision_height(),
+                }
+                .to_string(),
+            })?,
+        next_validators: &header.trusted_next_validator_set,
+        next_validators_hash: trusted_consensus_state.next_validators_hash,
+    };
+
+    let untrusted_state = UntrustedBlockState {
+        signed_header: &header.signed_header,
+        validators: &header.validator_set,
+        // NB: This will skip the
+        // VerificationPredicates::next_validators_match check for the
+        // untrusted state.
+        next_validators: None,
+    };
+
+    let options = client_state.as_light_client_options()?;
+    let now = now
+        .into_tm_time()
+        .ok_or_else(|| ClientError::ClientSpecific {
+            description: "host timestamp is not a valid TM timestamp".to_string(),
+        })?;
+
+    // main header verification, delegated to the tendermint-light-client crate.
+    verifier
+        .verifier()
+        .verify_update_header(untrusted_state, trusted_state, &options, now)
+        .into_result()?;
 
     Ok(())
 }
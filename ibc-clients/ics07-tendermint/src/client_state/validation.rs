@@ -1,8 +1,11 @@
+#[cfg(feature = "zk")]
+use ibc_client_tendermint_types::TENDERMINT_ZK_HEADER_TYPE_URL;
 use ibc_client_tendermint_types::{
     ClientState as ClientStateType, Header as TmHeader, Misbehaviour as TmMisbehaviour,
     TENDERMINT_HEADER_TYPE_URL, TENDERMINT_MISBEHAVIOUR_TYPE_URL,
 };
 use ibc_core_client::context::client_state::ClientStateValidation;
+use ibc_core_client::context::signature_batch::SignatureBatch;
 use ibc_core_client::types::error::ClientError;
 use ibc_core_client::types::Status;
 use ibc_core_host::types::identifiers::ClientId;
@@ -13,10 +16,13 @@ use ibc_primitives::proto::Any;
 use super::{
     check_for_misbehaviour_misbehavior, check_for_misbehaviour_update_client, ClientState,
 };
+#[cfg(feature = "zk")]
+use crate::client_state::{check_for_misbehaviour_with_zk, verify_client_message_with_zk};
 use crate::client_state::{verify_header, verify_misbehaviour};
 use crate::context::{
     ConsensusStateConverter, DefaultVerifier, TmVerifier, ValidationContext as TmValidationContext,
 };
+use crate::signature_batch::{collect_header_signature_checks, BatchVerifier};
 
 impl<V> ClientStateValidation<V> for ClientState
 where
@@ -33,13 +39,40 @@ where
         client_id: &ClientId,
         client_message: Any,
     ) -> Result<(), ClientError> {
-        verify_client_message(
-            self.inner(),
-            ctx,
-            client_id,
-            client_message,
-            &DefaultVerifier,
-        )
+        // The ZK headers are only accepted by the hosts which verify their
+        // commit proofs.
+        #[cfg(feature = "zk")]
+        if client_message.type_url == TENDERMINT_ZK_HEADER_TYPE_URL {
+            let zk_verifier = ctx
+                .zk_commit_verifier()
+                .ok_or(ClientError::InvalidUpdateClientMessage)?;
+
+            return verify_client_message_with_zk(
+                self.inner(),
+                ctx,
+                client_id,
+                client_message,
+                &DefaultVerifier,
+                zk_verifier,
+            );
+        }
+
+        match ctx.verified_signatures() {
+            Some(verified_signatures) => verify_client_message(
+                self.inner(),
+                ctx,
+                client_id,
+                client_message,
+                &BatchVerifier::new(verified_signatures),
+            ),
+            None => verify_client_message(
+                self.inner(),
+                ctx,
+                client_id,
+                client_message,
+                &DefaultVerifier,
+            ),
+        }
     }
 
     fn check_for_misbehaviour(
@@ -48,12 +81,31 @@ where
         client_id: &ClientId,
         client_message: Any,
     ) -> Result<bool, ClientError> {
+        #[cfg(feature = "zk")]
+        if client_message.type_url == TENDERMINT_ZK_HEADER_TYPE_URL {
+            return check_for_misbehaviour_with_zk(self.inner(), ctx, client_id, client_message);
+        }
+
         check_for_misbehaviour(self.inner(), ctx, client_id, client_message)
     }
 
     fn status(&self, ctx: &V, client_id: &ClientId) -> Result<Status, ClientError> {
         status(self.inner(), ctx, client_id)
     }
+
+    fn check_substitute(&self, _ctx: &V, substitute_client_state: Any) -> Result<(), ClientError> {
+        check_substitute(self.inner(), substitute_client_state)
+    }
+
+    fn collect_signature_checks(
+        &self,
+        _ctx: &V,
+        _client_id: &ClientId,
+        client_message: &Any,
+        batch: &mut SignatureBatch,
+    ) -> Result<(), ClientError> {
+        collect_signature_checks(client_message, batch)
+    }
 }
 
 /// Verify the client message as part of the client state validation process.
@@ -89,6 +141,33 @@ where
     }
 }
 
+/// Collects the signature checks of the client message, i.e. of the header
+/// or of the two headers of the misbehaviour, for them to be batch-verified.
+///
+/// Only the checks of the headers verified by the
+/// [`BatchVerifier`](crate::signature_batch::BatchVerifier) are skipped, so
+/// that the clients verifying the headers with a custom [`TmVerifier`] should
+/// not collect them.
+pub fn collect_signature_checks(
+    client_message: &Any,
+    batch: &mut SignatureBatch,
+) -> Result<(), ClientError> {
+    match client_message.type_url.as_str() {
+        TENDERMINT_HEADER_TYPE_URL => {
+            let header = TmHeader::try_from(client_message)?;
+            collect_header_signature_checks(&header, batch);
+        }
+        TENDERMINT_MISBEHAVIOUR_TYPE_URL => {
+            let misbehaviour = TmMisbehaviour::try_from(client_message)?;
+            collect_header_signature_checks(misbehaviour.header1(), batch);
+            collect_header_signature_checks(misbehaviour.header2(), batch);
+        }
+        _ => {}
+    }
+
+    Ok(())
+}
+
 /// Check for misbehaviour on the client state as part of the client state
 /// validation process.
 ///
@@ -190,3 +269,34 @@ where
 
     Ok(Status::Active)
 }
+
+/// Checks that the substitute client state matches the subject client state,
+/// i.e. that they only differ by their latest height, frozen height, trusting
+/// period and chain ID, as in ibc-go.
+///
+/// Note that this function is typically implemented as part of the
+/// [`ClientStateValidation`] trait, but has been made a standalone function
+/// in order to make the ClientState APIs more flexible.
+pub fn check_substitute(
+    subject_client_state: &ClientStateType,
+    substitute_client_state: Any,
+) -> Result<(), ClientError> {
+    let substitute_client_state = ClientState::try_from(substitute_client_state)?.0;
+
+    let substitute_client_state = ClientStateType {
+        chain_id: subject_client_state.chain_id.clone(),
+        trusting_period: subject_client_state.trusting_period,
+        latest_height: subject_client_state.latest_height,
+        frozen_height: subject_client_state.frozen_height,
+        ..substitute_client_state
+    };
+
+    if &substitute_client_state != subject_client_state {
+        return Err(ClientError::InvalidRecovery {
+            reason: "the substitute client state does not match the subject client state"
+                .to_string(),
+        });
+    }
+
+    Ok(())
+}
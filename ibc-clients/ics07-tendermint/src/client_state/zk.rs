@@ -0,0 +1,316 @@
+//! Alternative update path for the Tendermint client in which the validator
+//! signatures over a header are not checked on-chain. Instead, the relayer
+//! submits a [`ZkHeader`] carrying a succinct proof that the commit is valid,
+//! and the host plugs in its proof system through [`ZkCommitVerifier`].
+//!
+//! All the non-cryptographic checks performed by the regular update path
+//! (chain id and revision matching, trusting period, clock drift and header
+//! monotonicity) are still performed here.
+
+use ibc_client_tendermint_types::error::Error;
+use ibc_client_tendermint_types::{
+    ClientState as ClientStateType, ConsensusState as ConsensusStateType, Header as TmHeader,
+    Misbehaviour as TmMisbehaviour, TrustThreshold, ZkHeader, TENDERMINT_HEADER_TYPE_URL,
+    TENDERMINT_MISBEHAVIOUR_TYPE_URL, TENDERMINT_ZK_HEADER_TYPE_URL,
+};
+use ibc_core_client::context::HostClock;
+use ibc_core_client::types::error::ClientError;
+use ibc_core_client::types::Height;
+use ibc_core_host::types::identifiers::{ChainId, ClientId};
+use ibc_core_host::types::path::{ClientConsensusStatePath, ClientStatePath};
+use ibc_primitives::prelude::*;
+use ibc_primitives::proto::Any;
+use tendermint::Hash;
+
+use super::{
+    check_for_misbehaviour_misbehavior, check_for_misbehaviour_update_client,
+    prune_oldest_consensus_state, verify_header, verify_misbehaviour,
+};
+use crate::context::{
+    ConsensusStateConverter, ExecutionContext as TmExecutionContext, TmVerifier,
+    ValidationContext as TmValidationContext,
+};
+
+/// The statement a [`ZkHeader`] proof attests to.
+///
+/// A valid proof must show that the commit for the block with hash
+/// `header_hash` at height `height` was signed by more than 2/3 of the voting
+/// power of the validator set hashing to `validators_hash`, and by at least
+/// `trust_threshold` of the voting power of the trusted validator set hashing
+/// to `trusted_next_validators_hash`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ZkPublicInputs {
+    pub chain_id: ChainId,
+    pub trusted_height: Height,
+    pub trusted_next_validators_hash: Hash,
+    pub trust_threshold: TrustThreshold,
+    pub height: Height,
+    pub header_hash: Hash,
+    pub validators_hash: Hash,
+}
+
+/// Specifies the interface hosts must implement to verify the succinct commit
+/// proofs carried by [`ZkHeader`]s.
+///
+/// Implementations wrap the host's proof system (e.g. a Groth16 or PLONK
+/// verifier with a fixed verifying key) and must return an error if the proof
+/// does not attest to the given public inputs.
+pub trait ZkCommitVerifier {
+    fn verify_commit_proof(
+        &self,
+        public_inputs: &ZkPublicInputs,
+        proof: &[u8],
+    ) -> Result<(), ClientError>;
+}
+
+/// Verifies a [`ZkHeader`] against the trusted consensus state it builds on.
+pub fn verify_zk_header<V>(
+    client_state: &ClientStateType,
+    ctx: &V,
+    client_id: &ClientId,
+    header: &ZkHeader,
+    zk_verifier: &(impl ZkCommitVerifier + ?Sized),
+) -> Result<(), ClientError>
+where
+    V: TmValidationContext,
+    V::ConsensusStateRef: ConsensusStateConverter,
+{
+    header.validate_basic()?;
+
+    header.verify_chain_id_version_matches_height(&client_state.chain_id())?;
+
+    if header.header.chain_id.as_str() != client_state.chain_id.as_str() {
+        return Err(Error::MismatchHeaderChainId {
+            given: header.header.chain_id.to_string(),
+            expected: client_state.chain_id.to_string(),
+        }
+        .into());
+    }
+
+    let trusted_consensus_state: ConsensusStateType = ctx
+        .consensus_state(&ClientConsensusStatePath::new(
+            client_id.clone(),
+            header.trusted_height.revision_number(),
+            header.trusted_height.revision_height(),
+        ))?
+        .try_into()?;
+
+    let now = ctx
+        .host_timestamp()?
+        .into_tm_time()
+        .ok_or_else(|| ClientError::ClientSpecific {
+            description: "host timestamp is not a valid TM timestamp".to_string(),
+        })?;
+
+    // The trusted consensus state must still be within the trusting period.
+    let trusted_state_expiry = (trusted_consensus_state.timestamp() + client_state.trusting_period)
+        .map_err(|_| ClientError::ClientSpecific {
+            description: "trusted consensus state expiry overflows".to_string(),
+        })?;
+    if trusted_state_expiry <= now {
+        return Err(ClientError::HeaderVerificationFailure {
+            reason: "trusted consensus state is outside of the trusting period".to_string(),
+        });
+    }
+
+    // Header times must be monotonic with respect to the trusted state.
+    if header.header.time <= trusted_consensus_state.timestamp() {
+        return Err(Error::HeaderTimestampTooLow {
+            actual: header.header.time.to_string(),
+            min: trusted_consensus_state.timestamp().to_string(),
+        }
+        .into());
+    }
+
+    // The header must not be too far in the future.
+    let max_header_time =
+        (now + client_state.max_clock_drift).map_err(|_| ClientError::ClientSpecific {
+            description: "host timestamp plus max clock drift overflows".to_string(),
+        })?;
+    if header.header.time > max_header_time {
+        return Err(Error::HeaderTimestampTooHigh {
+            actual: header.header.time.to_string(),
+            max: max_header_time.to_string(),
+        }
+        .into());
+    }
+
+    let public_inputs = ZkPublicInputs {
+        chain_id: client_state.chain_id.clone(),
+        trusted_height: header.trusted_height,
+        trusted_next_validators_hash: trusted_consensus_state.next_validators_hash,
+        trust_threshold: client_state.trust_level,
+        height: header.height(),
+        header_hash: header.header_hash(),
+        validators_hash: header.header.validators_hash,
+    };
+
+    zk_verifier.verify_commit_proof(&public_inputs, &header.proof)
+}
+
+/// Same as [`verify_client_message`](super::verify_client_message), but
+/// additionally accepts [`ZkHeader`]s, which are checked by `zk_verifier`.
+pub fn verify_client_message_with_zk<V>(
+    client_state: &ClientStateType,
+    ctx: &V,
+    client_id: &ClientId,
+    client_message: Any,
+    verifier: &impl TmVerifier,
+    zk_verifier: &(impl ZkCommitVerifier + ?Sized),
+) -> Result<(), ClientError>
+where
+    V: TmValidationContext,
+    V::ConsensusStateRef: ConsensusStateConverter,
+{
+    match client_message.type_url.as_str() {
+        TENDERMINT_HEADER_TYPE_URL => {
+            let header = TmHeader::try_from(client_message)?;
+            verify_header(client_state, ctx, client_id, &header, verifier)
+        }
+        TENDERMINT_ZK_HEADER_TYPE_URL => {
+            let header = ZkHeader::try_from(client_message)?;
+            verify_zk_header(client_state, ctx, client_id, &header, zk_verifier)
+        }
+        TENDERMINT_MISBEHAVIOUR_TYPE_URL => {
+            let misbehaviour = TmMisbehaviour::try_from(client_message)?;
+            verify_misbehaviour(client_state, ctx, client_id, &misbehaviour, verifier)
+        }
+        _ => Err(ClientError::InvalidUpdateClientMessage),
+    }
+}
+
+/// Same as [`check_for_misbehaviour`](super::check_for_misbehaviour), but
+/// additionally accepts [`ZkHeader`]s.
+///
+/// A `ZkHeader` is treated exactly like a regular header: it constitutes
+/// misbehaviour if it conflicts with an already installed consensus state or
+/// breaks the monotonicity of consensus state timestamps.
+pub fn check_for_misbehaviour_with_zk<V>(
+    client_state: &ClientStateType,
+    ctx: &V,
+    client_id: &ClientId,
+    client_message: Any,
+) -> Result<bool, ClientError>
+where
+    V: TmValidationContext,
+    V::ConsensusStateRef: ConsensusStateConverter,
+{
+    match client_message.type_url.as_str() {
+        TENDERMINT_HEADER_TYPE_URL => {
+            let header = TmHeader::try_from(client_message)?;
+            check_for_misbehaviour_update_client(client_state, ctx, client_id, header)
+        }
+        TENDERMINT_ZK_HEADER_TYPE_URL => {
+            let header = ZkHeader::try_from(client_message)?;
+            check_for_misbehaviour_zk_header(client_state, ctx, client_id, header)
+        }
+        TENDERMINT_MISBEHAVIOUR_TYPE_URL => {
+            let misbehaviour = TmMisbehaviour::try_from(client_message)?;
+            check_for_misbehaviour_misbehavior(&misbehaviour)
+        }
+        _ => Err(ClientError::InvalidUpdateClientMessage),
+    }
+}
+
+fn check_for_misbehaviour_zk_header<V>(
+    client_state: &ClientStateType,
+    ctx: &V,
+    client_id: &ClientId,
+    header: ZkHeader,
+) -> Result<bool, ClientError>
+where
+    V: TmValidationContext,
+    V::ConsensusStateRef: ConsensusStateConverter,
+{
+    let header_height = header.height();
+
+    let maybe_existing_consensus_state = ctx
+        .consensus_state(&ClientConsensusStatePath::new(
+            client_id.clone(),
+            header_height.revision_number(),
+            header_height.revision_height(),
+        ))
+        .ok();
+
+    if let Some(existing_consensus_state) = maybe_existing_consensus_state {
+        let existing_consensus_state: ConsensusStateType = existing_consensus_state.try_into()?;
+
+        return Ok(existing_consensus_state != ConsensusStateType::from(header));
+    }
+
+    if let Some(prev_cs) = ctx.prev_consensus_state(client_id, &header_height)? {
+        let prev_cs: ConsensusStateType = prev_cs.try_into()?;
+
+        if header.header.time <= prev_cs.timestamp() {
+            return Ok(true);
+        }
+    }
+
+    if header_height < client_state.latest_height {
+        if let Some(next_cs) = ctx.next_consensus_state(client_id, &header_height)? {
+            let next_cs: ConsensusStateType = next_cs.try_into()?;
+
+            if header.header.time >= next_cs.timestamp() {
+                return Ok(true);
+            }
+        }
+    }
+
+    Ok(false)
+}
+
+/// Same as [`update_state`](super::update_state), but additionally accepts
+/// [`ZkHeader`]s.
+pub fn update_state_with_zk<E>(
+    client_state: &ClientStateType,
+    ctx: &mut E,
+    client_id: &ClientId,
+    header: Any,
+) -> Result<Vec<Height>, ClientError>
+where
+    E: TmExecutionContext,
+    E::ClientStateRef: From<ClientStateType>,
+    E::ConsensusStateRef: ConsensusStateConverter,
+{
+    if header.type_url != TENDERMINT_ZK_HEADER_TYPE_URL {
+        return super::update_state(client_state, ctx, client_id, header);
+    }
+
+    let header = ZkHeader::try_from(header)?;
+    let header_height = header.height();
+
+    prune_oldest_consensus_state(client_state, ctx, client_id)?;
+
+    let path_at_header_height = ClientConsensusStatePath::new(
+        client_id.clone(),
+        header_height.revision_number(),
+        header_height.revision_height(),
+    );
+
+    // If we already had the header installed by a previous relayer then this
+    // is a no-op.
+    if ctx.consensus_state(&path_at_header_height).is_err() {
+        let host_timestamp = HostClock::host_timestamp(ctx)?;
+        let host_height = HostClock::host_height(ctx)?;
+
+        let new_consensus_state = ConsensusStateType::from(header);
+        let new_client_state = ClientStateType {
+            latest_height: core::cmp::max(header_height, client_state.latest_height),
+            ..client_state.clone()
+        };
+
+        ctx.store_consensus_state(path_at_header_height, new_consensus_state.into())?;
+        ctx.store_client_state(
+            ClientStatePath::new(client_id.clone()),
+            new_client_state.into(),
+        )?;
+        ctx.store_update_meta(
+            client_id.clone(),
+            header_height,
+            host_timestamp,
+            host_height,
+        )?;
+    }
+
+    Ok(vec![header_height])
+}
@@ -14,13 +14,25 @@ use ibc_core_client::types::error::ClientError;
 use ibc_core_commitment_types::commitment::CommitmentRoot;
 use ibc_primitives::prelude::*;
 use ibc_primitives::proto::{Any, Protobuf};
-use ibc_primitives::Timestamp;
+use ibc_primitives::{CanonicalProtobuf, Timestamp};
 use tendermint::{Hash, Time};
 
 /// Newtype wrapper around the `ConsensusState` type imported from the
 /// `ibc-client-tendermint-types` crate. This wrapper exists so that we can
 /// bypass Rust's orphan rules and implement traits from
 /// `ibc::core::client::context` on the `ConsensusState` type.
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Clone, Debug, PartialEq, Eq, derive_more::From)]
 pub struct ConsensusState(ConsensusStateType);
@@ -71,6 +83,14 @@ impl From<ConsensusState> for Any {
     }
 }
 
+impl TryFrom<ConsensusState> for ConsensusStateType {
+    type Error = ClientError;
+
+    fn try_from(consensus_state: ConsensusState) -> Result<Self, Self::Error> {
+        Ok(consensus_state.0)
+    }
+}
+
 impl From<tendermint::block::Header> for ConsensusState {
     fn from(header: tendermint::block::Header) -> Self {
         Self(ConsensusStateType::from(header))
@@ -87,6 +107,6 @@ impl ConsensusStateTrait for ConsensusState {
     }
 
     fn encode_vec(self) -> Vec<u8> {
-        <Self as Protobuf<Any>>::encode_vec(self)
+        <Self as CanonicalProtobuf<Any>>::encode_vec_canonical(self)
     }
 }
@@ -5,9 +5,11 @@ use ibc_core_client::types::Height;
 use ibc_core_handler_types::error::ContextError;
 use ibc_core_host::types::identifiers::ClientId;
 use ibc_primitives::prelude::*;
-use ibc_primitives::Timestamp;
 use tendermint_light_client_verifier::ProdVerifier;
 
+#[cfg(feature = "zk")]
+use crate::client_state::ZkCommitVerifier;
+
 /// Enables conversion (`TryInto` and `From`) between the consensus state type
 /// used by the host and the one specific to the Tendermint light client, which
 /// is `ConsensusStateType`.
@@ -22,16 +24,13 @@ impl<C> ConsensusStateConverter for C where
 }
 
 /// Client's context required during validation
-pub trait ValidationContext: ClientValidationContext
+///
+/// The current height and timestamp of the host are read from its
+/// [`HostClock`].
+pub trait ValidationContext: ClientValidationContext + HostClock
 where
     Self::ConsensusStateRef: ConsensusStateConverter,
 {
-    /// Returns the current timestamp of the local chain.
-    fn host_timestamp(&self) -> Result<Timestamp, ContextError>;
-
-    /// Returns the current height of the local chain.
-    fn host_height(&self) -> Result<Height, ContextError>;
-
     /// Returns all the heights at which a consensus state is stored
     fn consensus_state_heights(&self, client_id: &ClientId) -> Result<Vec<Height>, ContextError>;
 
@@ -48,6 +47,14 @@ where
         client_id: &ClientId,
         height: &Height,
     ) -> Result<Option<Self::ConsensusStateRef>, ContextError>;
+
+    /// Returns the verifier of the succinct commit proofs of the
+    /// [`ZkHeader`](ibc_client_tendermint_types::ZkHeader)s, which are
+    /// rejected by the hosts without one.
+    #[cfg(feature = "zk")]
+    fn zk_commit_verifier(&self) -> Option<&dyn ZkCommitVerifier> {
+        None
+    }
 }
 
 /// Client's context required during execution.
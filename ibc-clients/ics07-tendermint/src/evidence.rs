@@ -0,0 +1,159 @@
+//! Utilities for relayers to construct Tendermint misbehaviour evidence out of
+//! two conflicting headers.
+//!
+//! Given two headers that a relayer observed for the same chain (e.g. one from
+//! the full node it trusts and one installed on the counterparty), the
+//! [`build_misbehaviour`] function picks, for each header, a trusted consensus
+//! state stored by the client from which that header can be verified, fills in
+//! the matching trusted height and validator set, and checks that the result
+//! is indeed evidence of misbehaviour, using the same verification logic as
+//! the on-chain handler.
+
+use ibc_client_tendermint_types::error::Error;
+use ibc_client_tendermint_types::{
+    ClientState as ClientStateType, ConsensusState as ConsensusStateType, Header as TmHeader,
+    Misbehaviour as TmMisbehaviour,
+};
+use ibc_core_client::types::error::ClientError;
+use ibc_core_client::types::Height;
+use ibc_core_host::types::identifiers::ClientId;
+use ibc_primitives::prelude::*;
+use ibc_primitives::Timestamp;
+use tendermint::validator::Set as ValidatorSet;
+
+use crate::client_state::{check_for_misbehaviour_misbehavior, verify_misbehaviour_header};
+use crate::context::TmVerifier;
+
+/// Source of the validator sets a relayer needs to fill in the
+/// `trusted_next_validator_set` field of a header, typically backed by RPC
+/// queries against a full node of the chain the client tracks.
+pub trait TrustedValidatorsSource {
+    /// Returns the next validator set committed to by the block at `height`,
+    /// i.e. the set whose hash is stored in the consensus state at `height`.
+    fn next_validator_set(&self, height: Height) -> Result<ValidatorSet, ClientError>;
+}
+
+impl<F> TrustedValidatorsSource for F
+where
+    F: Fn(Height) -> Result<ValidatorSet, ClientError>,
+{
+    fn next_validator_set(&self, height: Height) -> Result<ValidatorSet, ClientError> {
+        self(height)
+    }
+}
+
+/// Picks the trusted consensus state from which `header` can be verified, and
+/// returns `header` with its trusted height and trusted next validator set
+/// set accordingly.
+///
+/// `trusted_states` should contain the consensus states stored by the client
+/// on the counterparty chain. Candidates are tried from the highest height
+/// below the header's height downwards, which minimises the validator set
+/// change the header has to be verified across.
+pub fn with_trusted_fields<S>(
+    client_state: &ClientStateType,
+    mut header: TmHeader,
+    trusted_states: &[(Height, ConsensusStateType)],
+    validators_source: &S,
+    now: Timestamp,
+    verifier: &impl TmVerifier,
+) -> Result<TmHeader, ClientError>
+where
+    S: TrustedValidatorsSource,
+{
+    let mut candidates: Vec<_> = trusted_states
+        .iter()
+        .filter(|(height, _)| *height < header.height())
+        .collect();
+    candidates.sort_by(|(a, _), (b, _)| b.cmp(a));
+
+    let mut last_error = None;
+
+    for (trusted_height, trusted_consensus_state) in candidates {
+        let trusted_next_validator_set = validators_source.next_validator_set(*trusted_height)?;
+
+        header.trusted_height = *trusted_height;
+        header.trusted_next_validator_set = trusted_next_validator_set;
+
+        match verify_misbehaviour_header(
+            client_state,
+            &header,
+            trusted_consensus_state,
+            now,
+            verifier,
+        ) {
+            Ok(()) => return Ok(header),
+            Err(e) => last_error = Some(e),
+        }
+    }
+
+    Err(
+        last_error.unwrap_or_else(|| ClientError::HeaderVerificationFailure {
+            reason: format!(
+                "no trusted consensus state below header height {} to verify it against",
+                header.height()
+            ),
+        }),
+    )
+}
+
+/// Constructs a well-formed `Misbehaviour` out of two conflicting headers.
+///
+/// The headers may be passed in any order and with arbitrary trusted fields:
+/// the trusted heights and validator sets are chosen by
+/// [`with_trusted_fields`], and the headers are ordered such that `header1`
+/// is at the greater (or equal) height, as required by the handler.
+///
+/// Returns an error if either header cannot be verified from the trusted
+/// states, or if the two headers do not constitute evidence of misbehaviour
+/// (i.e. neither a fork at the same height nor a BFT time violation).
+#[allow(clippy::too_many_arguments)]
+pub fn build_misbehaviour<S>(
+    client_state: &ClientStateType,
+    client_id: ClientId,
+    header_a: TmHeader,
+    header_b: TmHeader,
+    trusted_states: &[(Height, ConsensusStateType)],
+    validators_source: &S,
+    now: Timestamp,
+    verifier: &impl TmVerifier,
+) -> Result<TmMisbehaviour, ClientError>
+where
+    S: TrustedValidatorsSource,
+{
+    let (header1, header2) = if header_a.height() >= header_b.height() {
+        (header_a, header_b)
+    } else {
+        (header_b, header_a)
+    };
+
+    let header1 = with_trusted_fields(
+        client_state,
+        header1,
+        trusted_states,
+        validators_source,
+        now,
+        verifier,
+    )?;
+    let header2 = with_trusted_fields(
+        client_state,
+        header2,
+        trusted_states,
+        validators_source,
+        now,
+        verifier,
+    )?;
+
+    let misbehaviour = TmMisbehaviour::new(client_id, header1, header2);
+
+    misbehaviour.validate_basic()?;
+
+    if !check_for_misbehaviour_misbehavior(&misbehaviour)? {
+        return Err(Error::InvalidRawMisbehaviour {
+            reason: "the given headers do not constitute evidence of misbehaviour".to_string(),
+        }
+        .into());
+    }
+
+    Ok(misbehaviour)
+}
@@ -13,12 +13,16 @@
     rust_2018_idioms
 )]
 
+extern crate alloc;
 #[cfg(any(test, feature = "std"))]
 extern crate std;
 
 pub mod client_state;
 pub mod consensus_state;
 pub mod context;
+#[cfg(feature = "std")]
+pub mod evidence;
+pub mod signature_batch;
 
 pub const TENDERMINT_CLIENT_TYPE: &str = "07-tendermint";
 
@@ -0,0 +1,280 @@
+//! Implements the batch verification of the signatures of the Tendermint
+//! client updates.
+//!
+//! The signatures of the commits of the headers are collected by
+//! [`collect_header_signature_checks`], and the ones verified as a batch are
+//! skipped by the [`BatchVotingPowerCalculator`] of the [`BatchVerifier`],
+//! which the client verifies the headers with whenever its context holds
+//! [`VerifiedSignatures`].
+
+use alloc::collections::BTreeSet;
+
+use ibc_client_tendermint_types::Header as TmHeader;
+use ibc_core_client::context::signature_batch::{
+    SignatureBatch, SignatureCheck, VerifiedSignatures,
+};
+use ibc_primitives::prelude::*;
+use tendermint::block::{Commit, CommitSig, SignedHeader};
+use tendermint::crypto::default::signature::Verifier as DefaultSignatureVerifier;
+use tendermint::validator::Set as ValidatorSet;
+use tendermint::vote::{SignedVote, Type as VoteType, ValidatorIndex, Vote};
+use tendermint_light_client_verifier::errors::VerificationError;
+use tendermint_light_client_verifier::operations::{
+    ProdCommitValidator, ProdVotingPowerCalculator, VotingPowerCalculator, VotingPowerTally,
+};
+use tendermint_light_client_verifier::predicates::ProdPredicates;
+use tendermint_light_client_verifier::types::TrustThreshold;
+use tendermint_light_client_verifier::PredicateVerifier;
+
+use crate::context::TmVerifier;
+
+/// Pushes onto the `batch` the ed25519 signatures of the commit of the
+/// header, as checked against both the validator set of the header and the
+/// trusted next validator set.
+pub fn collect_header_signature_checks(header: &TmHeader, batch: &mut SignatureBatch) {
+    let signed_header = &header.signed_header;
+
+    for signed_vote in signed_votes(signed_header) {
+        let sign_bytes = signed_vote.sign_bytes();
+
+        for validator_set in [&header.validator_set, &header.trusted_next_validator_set] {
+            let Some(validator) = validator_set.validator(signed_vote.validator_id()) else {
+                continue;
+            };
+
+            if validator.pub_key.ed25519().is_some() {
+                batch.push(SignatureCheck {
+                    public_key: validator.pub_key.to_bytes(),
+                    message: sign_bytes.clone(),
+                    signature: signed_vote.signature().as_bytes().to_vec(),
+                });
+            }
+        }
+    }
+}
+
+/// Returns the votes of the commit of the header which are not absent.
+fn signed_votes(signed_header: &SignedHeader) -> impl Iterator<Item = SignedVote> + '_ {
+    let commit = &signed_header.commit;
+
+    commit
+        .signatures
+        .iter()
+        .enumerate()
+        .filter_map(move |(index, commit_sig)| {
+            let vote = non_absent_vote(commit_sig, ValidatorIndex::try_from(index).ok()?, commit)?;
+
+            SignedVote::from_vote(vote, signed_header.header.chain_id.clone())
+        })
+}
+
+/// Returns the vote the commit signature stands for, unless it is absent.
+fn non_absent_vote(
+    commit_sig: &CommitSig,
+    validator_index: ValidatorIndex,
+    commit: &Commit,
+) -> Option<Vote> {
+    let (validator_address, timestamp, signature, block_id) = match commit_sig {
+        CommitSig::BlockIdFlagAbsent => return None,
+        CommitSig::BlockIdFlagCommit {
+            validator_address,
+            timestamp,
+            signature,
+        } => (
+            *validator_address,
+            *timestamp,
+            signature,
+            Some(commit.block_id),
+        ),
+        CommitSig::BlockIdFlagNil {
+            validator_address,
+            timestamp,
+            signature,
+        } => (*validator_address, *timestamp, signature, None),
+    };
+
+    Some(Vote {
+        vote_type: VoteType::Precommit,
+        height: commit.height,
+        round: commit.round,
+        block_id,
+        timestamp: Some(timestamp),
+        validator_address,
+        validator_index,
+        signature: signature.clone(),
+        extension: Default::default(),
+        extension_signature: None,
+    })
+}
+
+/// The voting power calculator of the light client verifier, which skips
+/// verifying the signatures verified as part of a batch.
+///
+/// It tallies the votes exactly as the `ProdVotingPowerCalculator`, and
+/// verifies the signatures missing from the batch on their own, so that the
+/// outcome of the verification is the same.
+#[derive(Clone, Copy, Debug)]
+pub struct BatchVotingPowerCalculator<'a> {
+    verified_signatures: &'a VerifiedSignatures,
+}
+
+impl<'a> BatchVotingPowerCalculator<'a> {
+    pub fn new(verified_signatures: &'a VerifiedSignatures) -> Self {
+        Self {
+            verified_signatures,
+        }
+    }
+}
+
+impl VotingPowerCalculator for BatchVotingPowerCalculator<'_> {
+    fn total_power_of(&self, validator_set: &ValidatorSet) -> u64 {
+        ProdVotingPowerCalculator::default().total_power_of(validator_set)
+    }
+
+    fn voting_power_in(
+        &self,
+        signed_header: &SignedHeader,
+        validator_set: &ValidatorSet,
+        trust_threshold: TrustThreshold,
+    ) -> Result<VotingPowerTally, VerificationError> {
+        let mut tally = VotingPowerTally {
+            total: self.total_power_of(validator_set),
+            tallied: 0,
+            trust_threshold,
+        };
+        let mut seen_validators = BTreeSet::new();
+
+        let commit = &signed_header.commit;
+        for (index, commit_sig) in commit.signatures.iter().enumerate() {
+            let Some(vote) = ValidatorIndex::try_from(index)
+                .ok()
+                .and_then(|index| non_absent_vote(commit_sig, index, commit))
+            else {
+                continue;
+            };
+
+            // Counts the power of each validator only once
+            if !seen_validators.insert(vote.validator_address) {
+                return Err(VerificationError::duplicate_validator(
+                    vote.validator_address,
+                ));
+            }
+
+            let Some(validator) = validator_set.validator(vote.validator_address) else {
+                continue;
+            };
+
+            let signed_vote = SignedVote::from_vote(vote, signed_header.header.chain_id.clone())
+                .ok_or_else(VerificationError::missing_signature)?;
+            let sign_bytes = signed_vote.sign_bytes();
+
+            let check = SignatureCheck {
+                public_key: validator.pub_key.to_bytes(),
+                message: sign_bytes,
+                signature: signed_vote.signature().as_bytes().to_vec(),
+            };
+            if !self.verified_signatures.contains(&check)
+                && validator
+                    .verify_signature::<DefaultSignatureVerifier>(
+                        &check.message,
+                        signed_vote.signature(),
+                    )
+                    .is_err()
+            {
+                return Err(VerificationError::invalid_signature(
+                    check.signature,
+                    Box::new(validator),
+                    check.message,
+                ));
+            }
+
+            // The votes for nil are verified but not tallied
+            if commit_sig.is_commit() {
+                tally.tallied += validator.power();
+            }
+        }
+
+        Ok(tally)
+    }
+}
+
+/// The verifier of the Tendermint client updates whose signatures were
+/// verified as part of a batch, which otherwise behaves as the
+/// [`DefaultVerifier`](crate::context::DefaultVerifier).
+pub struct BatchVerifier<'a> {
+    verified_signatures: &'a VerifiedSignatures,
+}
+
+impl<'a> BatchVerifier<'a> {
+    pub fn new(verified_signatures: &'a VerifiedSignatures) -> Self {
+        Self {
+            verified_signatures,
+        }
+    }
+}
+
+impl<'a> TmVerifier for BatchVerifier<'a> {
+    type Verifier =
+        PredicateVerifier<ProdPredicates, BatchVotingPowerCalculator<'a>, ProdCommitValidator>;
+
+    fn verifier(&self) -> Self::Verifier {
+        PredicateVerifier::new(
+            ProdPredicates,
+            BatchVotingPowerCalculator::new(self.verified_signatures),
+            ProdCommitValidator::default(),
+        )
+    }
+}
+
+#[cfg(feature = "batch-verification")]
+pub use ed25519::Ed25519BatchVerifier;
+
+#[cfg(feature = "batch-verification")]
+mod ed25519 {
+    use ed25519_consensus::{batch, Signature, VerificationKeyBytes};
+    use ibc_core_client::context::signature_batch::{BatchSignatureVerifier, SignatureCheck};
+    use rand_chacha::rand_core::SeedableRng;
+    use rand_chacha::ChaCha20Rng;
+    use sha2::{Digest, Sha256};
+
+    /// Verifies batches of ed25519 signatures with the same ZIP-215 rules as
+    /// the single signature verification of Tendermint.
+    ///
+    /// The random coefficients of the batch are drawn from a generator seeded
+    /// with the hash of the whole batch, so that the verification is
+    /// deterministic, as required across the validators of the host, while
+    /// remaining unpredictable to the submitters of the signatures.
+    #[derive(Clone, Copy, Debug, Default)]
+    pub struct Ed25519BatchVerifier;
+
+    impl BatchSignatureVerifier for Ed25519BatchVerifier {
+        fn verify_batch(&self, checks: &[SignatureCheck]) -> bool {
+            let mut verifier = batch::Verifier::new();
+            let mut seed = Sha256::new();
+
+            for check in checks {
+                let (Ok(public_key), Ok(signature)) = (
+                    <[u8; 32]>::try_from(check.public_key.as_slice()),
+                    <[u8; 64]>::try_from(check.signature.as_slice()),
+                ) else {
+                    return false;
+                };
+
+                seed.update(public_key);
+                seed.update(signature);
+                seed.update((check.message.len() as u64).to_be_bytes());
+                seed.update(&check.message);
+
+                verifier.queue((
+                    VerificationKeyBytes::from(public_key),
+                    Signature::from(signature),
+                    check.message.as_slice(),
+                ));
+            }
+
+            verifier
+                .verify(ChaCha20Rng::from_seed(seed.finalize().into()))
+                .is_ok()
+        }
+    }
+}
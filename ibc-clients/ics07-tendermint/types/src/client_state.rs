@@ -10,6 +10,7 @@ use ibc_core_client_types::Height;
 use ibc_core_commitment_types::specs::ProofSpecs;
 use ibc_core_host_types::identifiers::ChainId;
 use ibc_primitives::prelude::*;
+use ibc_primitives::StrictProtobuf;
 use ibc_primitives::ZERO_DURATION;
 use ibc_proto::google::protobuf::Any;
 use ibc_proto::ibc::lightclients::tendermint::v1::ClientState as RawTmClientState;
@@ -24,6 +25,18 @@ use crate::trust_threshold::TrustThreshold;
 
 pub const TENDERMINT_CLIENT_STATE_TYPE_URL: &str = "/ibc.lightclients.tendermint.v1.ClientState";
 
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub struct AllowUpdate {
@@ -33,6 +46,10 @@ pub struct AllowUpdate {
 
 /// Defines data structure for Tendermint client state.
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "proto-json",
+    serde(try_from = "RawTmClientState", into = "RawTmClientState")
+)]
 #[derive(Clone, Debug, PartialEq)]
 pub struct ClientState {
     pub chain_id: ChainId,
@@ -233,6 +250,63 @@ impl ClientState {
 
 impl Protobuf<RawTmClientState> for ClientState {}
 
+/// The client state is borsh- and SCALE-encoded as its protobuf encoding, for
+/// the durations and the proof specs it holds to be encoded as on the wire.
+#[cfg(feature = "borsh")]
+impl borsh::BorshSerialize for ClientState {
+    fn serialize<W: borsh::maybestd::io::Write>(
+        &self,
+        writer: &mut W,
+    ) -> borsh::maybestd::io::Result<()> {
+        let bytes = Protobuf::<RawTmClientState>::encode_vec(self.clone());
+        borsh::BorshSerialize::serialize(&bytes, writer)
+    }
+}
+
+#[cfg(feature = "borsh")]
+impl borsh::BorshDeserialize for ClientState {
+    fn deserialize_reader<R: borsh::maybestd::io::Read>(
+        reader: &mut R,
+    ) -> borsh::maybestd::io::Result<Self> {
+        let bytes: Vec<u8> = borsh::BorshDeserialize::deserialize_reader(reader)?;
+        Ok(Protobuf::<RawTmClientState>::decode_vec(&bytes)
+            .map_err(|_| borsh::maybestd::io::ErrorKind::InvalidData)?)
+    }
+}
+
+#[cfg(feature = "parity-scale-codec")]
+impl parity_scale_codec::Encode for ClientState {
+    fn encode_to<T: parity_scale_codec::Output + ?Sized>(&self, writer: &mut T) {
+        let bytes = Protobuf::<RawTmClientState>::encode_vec(self.clone());
+        parity_scale_codec::Encode::encode_to(&bytes, writer);
+    }
+}
+
+#[cfg(feature = "parity-scale-codec")]
+impl parity_scale_codec::Decode for ClientState {
+    fn decode<I: parity_scale_codec::Input>(
+        input: &mut I,
+    ) -> Result<Self, parity_scale_codec::Error> {
+        let bytes: Vec<u8> = parity_scale_codec::Decode::decode(input)?;
+        Protobuf::<RawTmClientState>::decode_vec(&bytes)
+            .map_err(|_| parity_scale_codec::Error::from("invalid Tendermint client state"))
+    }
+}
+
+#[cfg(feature = "parity-scale-codec")]
+impl scale_info::TypeInfo for ClientState {
+    type Identity = Self;
+
+    fn type_info() -> scale_info::Type {
+        scale_info::Type::builder()
+            .path(scale_info::Path::new("ClientState", module_path!()))
+            .composite(
+                scale_info::build::Fields::named()
+                    .field(|f| f.ty::<Vec<u8>>().name("bytes").type_name("Vec<u8>")),
+            )
+    }
+}
+
 impl TryFrom<RawTmClientState> for ClientState {
     type Error = Error;
 
@@ -340,8 +414,8 @@ impl TryFrom<Any> for ClientState {
 
     fn try_from(raw: Any) -> Result<Self, Self::Error> {
         fn decode_client_state(value: &[u8]) -> Result<ClientState, ClientError> {
-            let client_state =
-                Protobuf::<RawTmClientState>::decode(value).map_err(|e| ClientError::Other {
+            let client_state = StrictProtobuf::<RawTmClientState>::decode_vec_critical(value)
+                .map_err(|e| ClientError::Other {
                     description: e.to_string(),
                 })?;
             Ok(client_state)
@@ -607,4 +681,49 @@ mod tests {
             );
         }
     }
+
+    fn dummy_client_state() -> ClientState {
+        ClientState::new(
+            ChainId::new("ibc-0").unwrap(),
+            TrustThreshold::ONE_THIRD,
+            Duration::new(64000, 0),
+            Duration::new(128_000, 0),
+            Duration::new(3, 0),
+            Height::new(0, 10).expect("Never fails"),
+            ProofSpecs::cosmos(),
+            Vec::new(),
+            AllowUpdate {
+                after_expiry: false,
+                after_misbehaviour: false,
+            },
+        )
+        .unwrap()
+        .with_frozen_height(Height::new(0, 5).expect("Never fails"))
+    }
+
+    #[cfg(feature = "borsh")]
+    #[test]
+    fn borsh_client_state() {
+        use borsh::BorshDeserialize;
+
+        let client_state = dummy_client_state();
+
+        let serialized = borsh::to_vec(&client_state).unwrap();
+        let deserialized = ClientState::try_from_slice(&serialized).unwrap();
+
+        assert_eq!(client_state, deserialized);
+    }
+
+    #[cfg(feature = "parity-scale-codec")]
+    #[test]
+    fn scale_client_state() {
+        use parity_scale_codec::{Decode, Encode};
+
+        let client_state = dummy_client_state();
+
+        let encoded = client_state.encode();
+        let decoded = ClientState::decode(&mut encoded.as_slice()).unwrap();
+
+        assert_eq!(client_state, decoded);
+    }
 }
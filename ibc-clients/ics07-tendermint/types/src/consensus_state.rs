@@ -3,6 +3,7 @@
 use ibc_core_client_types::error::ClientError;
 use ibc_core_commitment_types::commitment::CommitmentRoot;
 use ibc_primitives::prelude::*;
+use ibc_primitives::StrictProtobuf;
 use ibc_proto::google::protobuf::Any;
 use ibc_proto::ibc::lightclients::tendermint::v1::ConsensusState as RawConsensusState;
 use ibc_proto::Protobuf;
@@ -19,6 +20,10 @@ pub const TENDERMINT_CONSENSUS_STATE_TYPE_URL: &str =
 
 /// Defines the Tendermint light client's consensus state
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "proto-json",
+    serde(try_from = "RawConsensusState", into = "RawConsensusState")
+)]
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct ConsensusState {
     pub timestamp: Time,
@@ -46,6 +51,63 @@ impl ConsensusState {
 
 impl Protobuf<RawConsensusState> for ConsensusState {}
 
+/// The consensus state is borsh- and SCALE-encoded as its protobuf encoding,
+/// for the timestamp and the hash it holds to be encoded as on the wire.
+#[cfg(feature = "borsh")]
+impl borsh::BorshSerialize for ConsensusState {
+    fn serialize<W: borsh::maybestd::io::Write>(
+        &self,
+        writer: &mut W,
+    ) -> borsh::maybestd::io::Result<()> {
+        let bytes = Protobuf::<RawConsensusState>::encode_vec(self.clone());
+        borsh::BorshSerialize::serialize(&bytes, writer)
+    }
+}
+
+#[cfg(feature = "borsh")]
+impl borsh::BorshDeserialize for ConsensusState {
+    fn deserialize_reader<R: borsh::maybestd::io::Read>(
+        reader: &mut R,
+    ) -> borsh::maybestd::io::Result<Self> {
+        let bytes: Vec<u8> = borsh::BorshDeserialize::deserialize_reader(reader)?;
+        Ok(Protobuf::<RawConsensusState>::decode_vec(&bytes)
+            .map_err(|_| borsh::maybestd::io::ErrorKind::InvalidData)?)
+    }
+}
+
+#[cfg(feature = "parity-scale-codec")]
+impl parity_scale_codec::Encode for ConsensusState {
+    fn encode_to<T: parity_scale_codec::Output + ?Sized>(&self, writer: &mut T) {
+        let bytes = Protobuf::<RawConsensusState>::encode_vec(self.clone());
+        parity_scale_codec::Encode::encode_to(&bytes, writer);
+    }
+}
+
+#[cfg(feature = "parity-scale-codec")]
+impl parity_scale_codec::Decode for ConsensusState {
+    fn decode<I: parity_scale_codec::Input>(
+        input: &mut I,
+    ) -> Result<Self, parity_scale_codec::Error> {
+        let bytes: Vec<u8> = parity_scale_codec::Decode::decode(input)?;
+        Protobuf::<RawConsensusState>::decode_vec(&bytes)
+            .map_err(|_| parity_scale_codec::Error::from("invalid Tendermint consensus state"))
+    }
+}
+
+#[cfg(feature = "parity-scale-codec")]
+impl scale_info::TypeInfo for ConsensusState {
+    type Identity = Self;
+
+    fn type_info() -> scale_info::Type {
+        scale_info::Type::builder()
+            .path(scale_info::Path::new("ConsensusState", module_path!()))
+            .composite(
+                scale_info::build::Fields::named()
+                    .field(|f| f.ty::<Vec<u8>>().name("bytes").type_name("Vec<u8>")),
+            )
+    }
+}
+
 impl TryFrom<RawConsensusState> for ConsensusState {
     type Error = Error;
 
@@ -107,8 +169,8 @@ impl TryFrom<Any> for ConsensusState {
 
     fn try_from(raw: Any) -> Result<Self, Self::Error> {
         fn decode_consensus_state(value: &[u8]) -> Result<ConsensusState, ClientError> {
-            let client_state =
-                Protobuf::<RawConsensusState>::decode(value).map_err(|e| ClientError::Other {
+            let client_state = StrictProtobuf::<RawConsensusState>::decode_vec_critical(value)
+                .map_err(|e| ClientError::Other {
                     description: e.to_string(),
                 })?;
             Ok(client_state)
@@ -172,3 +234,24 @@ mod tests {
         test_serialization_roundtrip::<AbciQuery>(json_data);
     }
 }
+
+#[cfg(all(test, feature = "borsh"))]
+mod borsh_tests {
+    use borsh::BorshDeserialize;
+
+    use super::*;
+
+    #[test]
+    fn borsh_consensus_state() {
+        let consensus_state = ConsensusState::new(
+            CommitmentRoot::from_bytes(&[1; 32]),
+            Time::from_unix_timestamp(1_700_000_000, 0).unwrap(),
+            Hash::Sha256([2; 32]),
+        );
+
+        let serialized = borsh::to_vec(&consensus_state).unwrap();
+        let deserialized = ConsensusState::try_from_slice(&serialized).unwrap();
+
+        assert_eq!(consensus_state, deserialized);
+    }
+}
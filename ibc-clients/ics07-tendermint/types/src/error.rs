@@ -95,11 +95,12 @@ pub enum Error {
     MisbehaviourHeadersNotAtSameHeight,
 }
 
-#[cfg(feature = "std")]
-impl std::error::Error for Error {
-    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+impl core::error::Error for Error {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
         match &self {
             Self::InvalidIdentifier(e) => Some(e),
+            // `tendermint` implements the trait only with `std`
+            #[cfg(feature = "std")]
             Self::InvalidHeader { error: e, .. }
             | Self::InvalidTendermintTrustThreshold(e)
             | Self::InvalidRawHeader(e) => Some(e),
@@ -7,6 +7,7 @@ use ibc_core_client_types::error::ClientError;
 use ibc_core_client_types::Height;
 use ibc_core_host_types::identifiers::ChainId;
 use ibc_primitives::prelude::*;
+use ibc_primitives::StrictProtobuf;
 use ibc_primitives::Timestamp;
 use ibc_proto::google::protobuf::Any;
 use ibc_proto::ibc::lightclients::tendermint::v1::Header as RawHeader;
@@ -23,6 +24,10 @@ use crate::error::Error;
 pub const TENDERMINT_HEADER_TYPE_URL: &str = "/ibc.lightclients.tendermint.v1.Header";
 
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "proto-json",
+    serde(try_from = "RawHeader", into = "RawHeader")
+)]
 /// Tendermint consensus header
 #[derive(Clone, PartialEq, Eq)]
 pub struct Header {
@@ -185,17 +190,29 @@ impl Protobuf<Any> for Header {}
 impl TryFrom<Any> for Header {
     type Error = ClientError;
 
-    fn try_from(raw: Any) -> Result<Self, Self::Error> {
+    fn try_from(raw: Any) -> Result<Self, ClientError> {
+        Self::try_from(&raw)
+    }
+}
+
+/// Decodes the header without taking the `Any`, e.g. to inspect a client
+/// message without copying it.
+impl TryFrom<&Any> for Header {
+    type Error = ClientError;
+
+    fn try_from(raw: &Any) -> Result<Self, Self::Error> {
         fn decode_header(value: &[u8]) -> Result<Header, ClientError> {
-            let header = Protobuf::<RawHeader>::decode(value).map_err(|e| ClientError::Other {
-                description: e.to_string(),
+            let header = StrictProtobuf::<RawHeader>::decode_vec_critical(value).map_err(|e| {
+                ClientError::Other {
+                    description: e.to_string(),
+                }
             })?;
             Ok(header)
         }
         match raw.type_url.as_str() {
             TENDERMINT_HEADER_TYPE_URL => decode_header(&raw.value),
             _ => Err(ClientError::UnknownHeaderType {
-                header_type: raw.type_url,
+                header_type: raw.type_url.clone(),
             }),
         }
     }
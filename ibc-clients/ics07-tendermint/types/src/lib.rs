@@ -25,12 +25,16 @@ mod consensus_state;
 mod header;
 mod misbehaviour;
 mod trust_threshold;
+#[cfg(feature = "zk")]
+mod zk_header;
 
 pub use client_state::*;
 pub use consensus_state::*;
 pub use header::*;
 pub use misbehaviour::*;
 pub use trust_threshold::*;
+#[cfg(feature = "zk")]
+pub use zk_header::*;
 
 pub mod error;
 
@@ -3,6 +3,7 @@
 use ibc_core_client_types::error::ClientError;
 use ibc_core_host_types::identifiers::ClientId;
 use ibc_primitives::prelude::*;
+use ibc_primitives::StrictProtobuf;
 use ibc_proto::google::protobuf::Any;
 use ibc_proto::ibc::lightclients::tendermint::v1::Misbehaviour as RawMisbehaviour;
 use ibc_proto::Protobuf;
@@ -14,6 +15,10 @@ pub const TENDERMINT_MISBEHAVIOUR_TYPE_URL: &str = "/ibc.lightclients.tendermint
 
 /// Tendermint light client's misbehaviour type
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "proto-json",
+    serde(try_from = "RawMisbehaviour", into = "RawMisbehaviour")
+)]
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct Misbehaviour {
     client_id: ClientId,
@@ -110,9 +115,19 @@ impl TryFrom<Any> for Misbehaviour {
     type Error = ClientError;
 
     fn try_from(raw: Any) -> Result<Self, ClientError> {
+        Self::try_from(&raw)
+    }
+}
+
+/// Decodes the misbehaviour without taking the `Any`, e.g. to inspect a client
+/// message without copying it.
+impl TryFrom<&Any> for Misbehaviour {
+    type Error = ClientError;
+
+    fn try_from(raw: &Any) -> Result<Self, ClientError> {
         fn decode_misbehaviour(value: &[u8]) -> Result<Misbehaviour, ClientError> {
-            let misbehaviour =
-                Protobuf::<RawMisbehaviour>::decode(value).map_err(|e| ClientError::Other {
+            let misbehaviour = StrictProtobuf::<RawMisbehaviour>::decode_vec_critical(value)
+                .map_err(|e| ClientError::Other {
                     description: e.to_string(),
                 })?;
             Ok(misbehaviour)
@@ -120,7 +135,7 @@ impl TryFrom<Any> for Misbehaviour {
         match raw.type_url.as_str() {
             TENDERMINT_MISBEHAVIOUR_TYPE_URL => decode_misbehaviour(&raw.value),
             _ => Err(ClientError::UnknownMisbehaviourType {
-                misbehaviour_type: raw.type_url,
+                misbehaviour_type: raw.type_url.clone(),
             }),
         }
     }
@@ -0,0 +1,246 @@
+//! Defines the domain type for Tendermint headers whose commit is attested by
+//! a succinct (zero-knowledge) proof instead of the raw validator signatures.
+
+use core::fmt::{Display, Error as FmtError, Formatter};
+use core::str::FromStr;
+
+use ibc_core_client_types::error::ClientError;
+use ibc_core_client_types::Height;
+use ibc_core_host_types::identifiers::ChainId;
+use ibc_primitives::prelude::*;
+use ibc_primitives::Timestamp;
+use ibc_proto::google::protobuf::Any;
+use ibc_proto::Protobuf;
+use tendermint::block::Header as TmBlockHeader;
+use tendermint::Hash;
+
+use crate::consensus_state::ConsensusState as TmConsensusState;
+use crate::error::Error;
+
+pub const TENDERMINT_ZK_HEADER_TYPE_URL: &str = "/ibc.lightclients.tendermint.v1.ZkHeader";
+
+/// Raw (protobuf) representation of [`ZkHeader`].
+///
+/// There is no upstream `ibc-proto` definition for this message yet, so it is
+/// defined here as:
+///
+/// ```protobuf
+/// message ZkHeader {
+///   tendermint.types.Header header = 1;
+///   ibc.core.client.v1.Height trusted_height = 2;
+///   bytes proof = 3;
+/// }
+/// ```
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct RawZkHeader {
+    #[prost(message, optional, tag = "1")]
+    pub header: Option<tendermint_proto::types::Header>,
+    #[prost(message, optional, tag = "2")]
+    pub trusted_height: Option<ibc_proto::ibc::core::client::v1::Height>,
+    #[prost(bytes = "vec", tag = "3")]
+    pub proof: Vec<u8>,
+}
+
+impl prost::Name for RawZkHeader {
+    const NAME: &'static str = "ZkHeader";
+    const PACKAGE: &'static str = "ibc.lightclients.tendermint.v1";
+
+    fn full_name() -> String {
+        format!("{}.{}", Self::PACKAGE, Self::NAME)
+    }
+}
+
+/// Tendermint header whose commit is attested by a succinct proof.
+///
+/// Rather than carrying the commit signatures and both validator sets, the
+/// relayer submits only the block header together with a proof (e.g. Groth16
+/// or PLONK) that the commit for this header was signed by enough voting power
+/// of the trusted validator set. The proof itself is opaque to ibc-rs and is
+/// checked by a host-provided verifier.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, PartialEq, Eq)]
+pub struct ZkHeader {
+    pub header: TmBlockHeader,
+    pub trusted_height: Height,
+    pub proof: Vec<u8>,
+}
+
+impl core::fmt::Debug for ZkHeader {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), FmtError> {
+        write!(f, " ZkHeader {{...}}")
+    }
+}
+
+impl Display for ZkHeader {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), FmtError> {
+        write!(
+            f,
+            "ZkHeader {{ header: {{ chain_id: {}, height: {} }}, trusted_height: {}, proof_len: {} }}",
+            self.header.chain_id,
+            self.header.height,
+            self.trusted_height,
+            self.proof.len()
+        )
+    }
+}
+
+impl ZkHeader {
+    pub fn timestamp(&self) -> Timestamp {
+        self.header.time.into()
+    }
+
+    pub fn height(&self) -> Height {
+        Height::new(
+            ChainId::from_str(self.header.chain_id.as_str())
+                .expect("chain id")
+                .revision_number(),
+            u64::from(self.header.height),
+        )
+        .expect("malformed tendermint header domain type has an illegal height of 0")
+    }
+
+    /// Hash of the attested block header, i.e. the block id hash signed by
+    /// the validators.
+    pub fn header_hash(&self) -> Hash {
+        self.header.hash()
+    }
+
+    pub fn verify_chain_id_version_matches_height(&self, chain_id: &ChainId) -> Result<(), Error> {
+        if self.height().revision_number() != chain_id.revision_number() {
+            return Err(Error::MismatchHeaderChainId {
+                given: self.header.chain_id.to_string(),
+                expected: chain_id.to_string(),
+            });
+        }
+        Ok(())
+    }
+
+    /// Checks if the fields of a given header are consistent with the trusted
+    /// fields of this header.
+    pub fn validate_basic(&self) -> Result<(), Error> {
+        if self.height().revision_number() != self.trusted_height.revision_number() {
+            return Err(Error::MismatchHeightRevisions {
+                trusted_revision: self.trusted_height.revision_number(),
+                header_revision: self.height().revision_number(),
+            });
+        }
+
+        if self.trusted_height >= self.height() {
+            return Err(Error::InvalidHeaderHeight {
+                height: self.height().revision_height(),
+            });
+        }
+
+        if self.proof.is_empty() {
+            return Err(Error::Validation {
+                reason: "zk header proof cannot be empty".to_string(),
+            });
+        }
+
+        Ok(())
+    }
+}
+
+impl Protobuf<RawZkHeader> for ZkHeader {}
+
+impl TryFrom<RawZkHeader> for ZkHeader {
+    type Error = Error;
+
+    fn try_from(raw: RawZkHeader) -> Result<Self, Self::Error> {
+        Ok(Self {
+            header: raw
+                .header
+                .ok_or(Error::MissingSignedHeader)?
+                .try_into()
+                .map_err(Error::InvalidRawHeader)?,
+            trusted_height: raw
+                .trusted_height
+                .and_then(|raw_height| raw_height.try_into().ok())
+                .ok_or(Error::MissingTrustedHeight)?,
+            proof: raw.proof,
+        })
+    }
+}
+
+impl From<ZkHeader> for RawZkHeader {
+    fn from(value: ZkHeader) -> Self {
+        RawZkHeader {
+            header: Some(value.header.into()),
+            trusted_height: Some(value.trusted_height.into()),
+            proof: value.proof,
+        }
+    }
+}
+
+impl Protobuf<Any> for ZkHeader {}
+
+impl TryFrom<Any> for ZkHeader {
+    type Error = ClientError;
+
+    fn try_from(raw: Any) -> Result<Self, Self::Error> {
+        fn decode_header(value: &[u8]) -> Result<ZkHeader, ClientError> {
+            let header =
+                Protobuf::<RawZkHeader>::decode(value).map_err(|e| ClientError::Other {
+                    description: e.to_string(),
+                })?;
+            Ok(header)
+        }
+        match raw.type_url.as_str() {
+            TENDERMINT_ZK_HEADER_TYPE_URL => decode_header(&raw.value),
+            _ => Err(ClientError::UnknownHeaderType {
+                header_type: raw.type_url,
+            }),
+        }
+    }
+}
+
+impl From<ZkHeader> for Any {
+    fn from(header: ZkHeader) -> Self {
+        Any {
+            type_url: TENDERMINT_ZK_HEADER_TYPE_URL.to_string(),
+            value: Protobuf::<RawZkHeader>::encode_vec(header),
+        }
+    }
+}
+
+impl From<ZkHeader> for TmConsensusState {
+    fn from(header: ZkHeader) -> Self {
+        Self::from(header.header)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tendermint_testgen::{Generator, Header as TestgenHeader, Validator};
+
+    use super::*;
+
+    fn dummy_zk_header(height: u64, trusted_height: u64, proof: Vec<u8>) -> ZkHeader {
+        let header = TestgenHeader::new(&[Validator::new("val")])
+            .chain_id("test-1")
+            .height(height)
+            .generate()
+            .expect("never fails");
+
+        ZkHeader {
+            header,
+            trusted_height: Height::new(1, trusted_height).expect("never fails"),
+            proof,
+        }
+    }
+
+    #[test]
+    fn zk_header_any_roundtrip() {
+        let header = dummy_zk_header(10, 5, vec![1, 2, 3]);
+        let any: Any = header.clone().into();
+        assert_eq!(any.type_url, TENDERMINT_ZK_HEADER_TYPE_URL);
+        assert_eq!(ZkHeader::try_from(any).expect("never fails"), header);
+    }
+
+    #[test]
+    fn zk_header_validate_basic() {
+        assert!(dummy_zk_header(10, 5, vec![1]).validate_basic().is_ok());
+        assert!(dummy_zk_header(10, 10, vec![1]).validate_basic().is_err());
+        assert!(dummy_zk_header(10, 5, vec![]).validate_basic().is_err());
+    }
+}
@@ -0,0 +1,66 @@
+//! This module includes trait implementations for the
+//! `ibc_client_wasm_types::client_state::ClientState` type. Implemented
+//! traits include `ClientStateCommon`, `ClientStateValidation`, and
+//! `ClientStateExecution`, which dispatch into the contract backing the
+//! client.
+//!
+//! Note that this crate defines a newtype wrapper around the Wasm
+//! `ClientState` type in order to enable implementing a foreign trait on a
+//! foreign type (i.e. the orphan rule in Rust).
+
+use ibc_client_wasm_types::client_state::ClientState as ClientStateType;
+use ibc_core_client::types::error::ClientError;
+use ibc_primitives::prelude::*;
+use ibc_primitives::proto::{Any, Protobuf};
+use ibc_proto::ibc::lightclients::wasm::v1::ClientState as RawWasmClientState;
+
+mod common;
+mod execution;
+mod validation;
+
+pub use common::*;
+pub use execution::*;
+pub use validation::*;
+
+/// Newtype wrapper around the `ClientState` type imported from the
+/// `ibc-client-wasm-types` crate.
+#[derive(Clone, Debug, PartialEq, Eq, derive_more::From)]
+pub struct ClientState(ClientStateType);
+
+impl ClientState {
+    pub fn inner(&self) -> &ClientStateType {
+        &self.0
+    }
+}
+
+impl Protobuf<RawWasmClientState> for ClientState {}
+
+impl TryFrom<RawWasmClientState> for ClientState {
+    type Error = ClientError;
+
+    fn try_from(raw: RawWasmClientState) -> Result<Self, Self::Error> {
+        Ok(Self(ClientStateType::try_from(raw)?))
+    }
+}
+
+impl From<ClientState> for RawWasmClientState {
+    fn from(client_state: ClientState) -> Self {
+        client_state.0.into()
+    }
+}
+
+impl Protobuf<Any> for ClientState {}
+
+impl TryFrom<Any> for ClientState {
+    type Error = ClientError;
+
+    fn try_from(raw: Any) -> Result<Self, Self::Error> {
+        Ok(Self(ClientStateType::try_from(raw)?))
+    }
+}
+
+impl From<ClientState> for Any {
+    fn from(client_state: ClientState) -> Self {
+        client_state.0.into()
+    }
+}
@@ -0,0 +1,173 @@
+use ibc_client_wasm_types::client_state::ClientState as ClientStateType;
+use ibc_client_wasm_types::client_type as wasm_client_type;
+use ibc_client_wasm_types::consensus_state::ConsensusState as WasmConsensusState;
+use ibc_core_client::context::client_state::ClientStateCommon;
+use ibc_core_client::types::error::ClientError;
+use ibc_core_client::types::Height;
+use ibc_core_commitment_types::commitment::{
+    CommitmentPrefix, CommitmentProofBytes, CommitmentRoot,
+};
+use ibc_core_commitment_types::merkle::apply_prefix;
+use ibc_core_host::types::identifiers::{ClientId, ClientType};
+use ibc_core_host::types::path::Path;
+use ibc_primitives::prelude::*;
+use ibc_primitives::proto::Any;
+
+use super::ClientState;
+use crate::context::ExecutionContext as WasmExecutionContext;
+use crate::contract::{encode_msg, MerklePath, SudoMsg};
+use crate::error::Error;
+
+/// Only the context-free methods are served here. Proof verification needs to
+/// call into the contract backing the client, which `ClientStateCommon` gives
+/// no access to: the membership and upgrade methods therefore return an error,
+/// and hosts should use [`verify_membership`], [`verify_non_membership`] and
+/// [`verify_upgrade_and_update_state`](super::verify_upgrade_and_update_state)
+/// instead.
+impl ClientStateCommon for ClientState {
+    fn verify_consensus_state(&self, consensus_state: Any) -> Result<(), ClientError> {
+        verify_consensus_state(consensus_state)
+    }
+
+    fn client_type(&self) -> ClientType {
+        wasm_client_type()
+    }
+
+    fn latest_height(&self) -> Height {
+        self.0.latest_height
+    }
+
+    fn validate_proof_height(&self, proof_height: Height) -> Result<(), ClientError> {
+        validate_proof_height(self.inner(), proof_height)
+    }
+
+    fn verify_upgrade_client(
+        &self,
+        _upgraded_client_state: Any,
+        _upgraded_consensus_state: Any,
+        _proof_upgrade_client: CommitmentProofBytes,
+        _proof_upgrade_consensus_state: CommitmentProofBytes,
+        _root: &CommitmentRoot,
+    ) -> Result<(), ClientError> {
+        Err(Error::ContextRequired {
+            method: "verify_upgrade_client",
+        }
+        .into())
+    }
+
+    fn verify_membership(
+        &self,
+        _prefix: &CommitmentPrefix,
+        _proof: &CommitmentProofBytes,
+        _root: &CommitmentRoot,
+        _path: Path,
+        _value: Vec<u8>,
+    ) -> Result<(), ClientError> {
+        Err(Error::ContextRequired {
+            method: "verify_membership",
+        }
+        .into())
+    }
+
+    fn verify_non_membership(
+        &self,
+        _prefix: &CommitmentPrefix,
+        _proof: &CommitmentProofBytes,
+        _root: &CommitmentRoot,
+        _path: Path,
+    ) -> Result<(), ClientError> {
+        Err(Error::ContextRequired {
+            method: "verify_non_membership",
+        }
+        .into())
+    }
+}
+
+/// Verify an `Any` consensus state by attempting to convert it to a Wasm
+/// `ConsensusState`. The wrapped data is opaque and checked by the contract.
+pub fn verify_consensus_state(consensus_state: Any) -> Result<(), ClientError> {
+    WasmConsensusState::try_from(consensus_state)?;
+
+    Ok(())
+}
+
+/// Validate the given proof height against the client state's latest height, returning
+/// an error if the proof height is greater than the latest height of the client state.
+pub fn validate_proof_height(
+    client_state: &ClientStateType,
+    proof_height: Height,
+) -> Result<(), ClientError> {
+    let latest_height = client_state.latest_height;
+
+    if latest_height < proof_height {
+        return Err(ClientError::InvalidProofHeight {
+            latest_height,
+            proof_height,
+        });
+    }
+
+    Ok(())
+}
+
+/// Verifies, through the contract backing `client_id`, that `value` is
+/// stored at `path` on the counterparty at `height`.
+#[allow(clippy::too_many_arguments)]
+pub fn verify_membership<E>(
+    client_state: &ClientStateType,
+    ctx: &mut E,
+    client_id: &ClientId,
+    height: Height,
+    prefix: &CommitmentPrefix,
+    proof: &CommitmentProofBytes,
+    path: Path,
+    value: Vec<u8>,
+) -> Result<(), ClientError>
+where
+    E: WasmExecutionContext,
+{
+    let msg = SudoMsg::VerifyMembership {
+        height,
+        delay_time_period: 0,
+        delay_block_period: 0,
+        proof: proof.clone().into(),
+        merkle_path: merkle_path(prefix, path),
+        value,
+    };
+
+    ctx.sudo_contract(client_id, &client_state.checksum, encode_msg(&msg)?)?;
+
+    Ok(())
+}
+
+/// Verifies, through the contract backing `client_id`, that nothing is
+/// stored at `path` on the counterparty at `height`.
+pub fn verify_non_membership<E>(
+    client_state: &ClientStateType,
+    ctx: &mut E,
+    client_id: &ClientId,
+    height: Height,
+    prefix: &CommitmentPrefix,
+    proof: &CommitmentProofBytes,
+    path: Path,
+) -> Result<(), ClientError>
+where
+    E: WasmExecutionContext,
+{
+    let msg = SudoMsg::VerifyNonMembership {
+        height,
+        delay_time_period: 0,
+        delay_block_period: 0,
+        proof: proof.clone().into(),
+        merkle_path: merkle_path(prefix, path),
+    };
+
+    ctx.sudo_contract(client_id, &client_state.checksum, encode_msg(&msg)?)?;
+
+    Ok(())
+}
+
+fn merkle_path(prefix: &CommitmentPrefix, path: Path) -> MerklePath {
+    MerklePath {
+        key_path: apply_prefix(prefix, vec![path.to_string()]).key_path,
+    }
+}
@@ -0,0 +1,182 @@
+use ibc_client_wasm_types::client_message::ClientMessage as WasmClientMessage;
+use ibc_client_wasm_types::client_state::ClientState as ClientStateType;
+use ibc_client_wasm_types::consensus_state::ConsensusState as WasmConsensusState;
+use ibc_core_client::context::prelude::*;
+use ibc_core_client::types::error::ClientError;
+use ibc_core_client::types::Height;
+use ibc_core_commitment_types::commitment::CommitmentProofBytes;
+use ibc_core_host::types::identifiers::ClientId;
+use ibc_core_host::types::path::ClientStatePath;
+use ibc_primitives::prelude::*;
+use ibc_primitives::proto::Any;
+
+use super::ClientState;
+use crate::context::ExecutionContext as WasmExecutionContext;
+use crate::contract::{decode_response, encode_msg, InstantiateMsg, SudoMsg, UpdateStateResult};
+use crate::error::{checksum_hex, Error};
+
+impl<E> ClientStateExecution<E> for ClientState
+where
+    E: WasmExecutionContext,
+    E::ClientStateRef: From<ClientState>,
+{
+    fn initialise(
+        &self,
+        ctx: &mut E,
+        client_id: &ClientId,
+        consensus_state: Any,
+    ) -> Result<(), ClientError> {
+        initialise(self, ctx, client_id, consensus_state)
+    }
+
+    fn update_state(
+        &self,
+        ctx: &mut E,
+        client_id: &ClientId,
+        header: Any,
+    ) -> Result<Vec<Height>, ClientError> {
+        update_state(self.inner(), ctx, client_id, header)
+    }
+
+    fn update_state_on_misbehaviour(
+        &self,
+        ctx: &mut E,
+        client_id: &ClientId,
+        client_message: Any,
+    ) -> Result<(), ClientError> {
+        update_on_misbehaviour(self.inner(), ctx, client_id, client_message)
+    }
+
+    /// Upgrades are verified and applied by the contract in a single call,
+    /// which needs the upgrade proofs; as this method does not receive them,
+    /// hosts should use [`verify_upgrade_and_update_state`] instead.
+    fn update_state_on_upgrade(
+        &self,
+        _ctx: &mut E,
+        _client_id: &ClientId,
+        _upgraded_client_state: Any,
+        _upgraded_consensus_state: Any,
+    ) -> Result<Height, ClientError> {
+        Err(Error::ContextRequired {
+            method: "update_state_on_upgrade",
+        }
+        .into())
+    }
+}
+
+/// Stores the initial client state and instantiates the contract, which is
+/// responsible for storing the initial consensus state.
+pub fn initialise<E>(
+    client_state: &ClientState,
+    ctx: &mut E,
+    client_id: &ClientId,
+    consensus_state: Any,
+) -> Result<(), ClientError>
+where
+    E: WasmExecutionContext,
+    E::ClientStateRef: From<ClientState>,
+{
+    let checksum = client_state.inner().checksum.clone();
+
+    if !ctx.checksum_exists(&checksum)? {
+        return Err(Error::ChecksumNotFound {
+            checksum: checksum_hex(&checksum),
+        }
+        .into());
+    }
+
+    let wasm_consensus_state = WasmConsensusState::try_from(consensus_state)?;
+
+    ctx.store_client_state(
+        ClientStatePath::new(client_id.clone()),
+        client_state.clone().into(),
+    )?;
+
+    let msg = InstantiateMsg {
+        client_state: client_state.inner().data.clone(),
+        consensus_state: wasm_consensus_state.data,
+        checksum: checksum.clone(),
+    };
+
+    ctx.instantiate_contract(client_id, &checksum, encode_msg(&msg)?)
+}
+
+/// Has the contract apply a verified client message, and returns the heights
+/// of the consensus states it installed.
+pub fn update_state<E>(
+    client_state: &ClientStateType,
+    ctx: &mut E,
+    client_id: &ClientId,
+    header: Any,
+) -> Result<Vec<Height>, ClientError>
+where
+    E: WasmExecutionContext,
+{
+    let client_message = WasmClientMessage::try_from(header)?;
+
+    let msg = SudoMsg::UpdateState {
+        client_message: client_message.data,
+    };
+
+    let response = ctx.sudo_contract(client_id, &client_state.checksum, encode_msg(&msg)?)?;
+    let result: UpdateStateResult = decode_response(&response)?;
+
+    if result.heights.is_empty() {
+        return Err(Error::ContractCall {
+            reason: "update_state returned no consensus heights".to_string(),
+        }
+        .into());
+    }
+
+    Ok(result.heights)
+}
+
+/// Has the contract freeze the client after misbehaviour was detected.
+pub fn update_on_misbehaviour<E>(
+    client_state: &ClientStateType,
+    ctx: &mut E,
+    client_id: &ClientId,
+    client_message: Any,
+) -> Result<(), ClientError>
+where
+    E: WasmExecutionContext,
+{
+    let client_message = WasmClientMessage::try_from(client_message)?;
+
+    let msg = SudoMsg::UpdateStateOnMisbehaviour {
+        client_message: client_message.data,
+    };
+
+    ctx.sudo_contract(client_id, &client_state.checksum, encode_msg(&msg)?)?;
+
+    Ok(())
+}
+
+/// Has the contract verify the upgrade proofs and, if valid, install the
+/// upgraded client and consensus states.
+pub fn verify_upgrade_and_update_state<E>(
+    client_state: &ClientStateType,
+    ctx: &mut E,
+    client_id: &ClientId,
+    upgraded_client_state: Any,
+    upgraded_consensus_state: Any,
+    proof_upgrade_client: CommitmentProofBytes,
+    proof_upgrade_consensus_state: CommitmentProofBytes,
+) -> Result<(), ClientError>
+where
+    E: WasmExecutionContext,
+{
+    let upgraded_client_state = ClientStateType::try_from(upgraded_client_state)?;
+    let upgraded_consensus_state = WasmConsensusState::try_from(upgraded_consensus_state)?;
+
+    let msg = SudoMsg::VerifyUpgradeAndUpdateState {
+        upgrade_client_state: upgraded_client_state.data,
+        upgrade_consensus_state: upgraded_consensus_state.data,
+        proof_upgrade_client: proof_upgrade_client.into(),
+        proof_upgrade_consensus_state: proof_upgrade_consensus_state.into(),
+    };
+
+    ctx.sudo_contract(client_id, &client_state.checksum, encode_msg(&msg)?)?;
+
+    Ok(())
+}
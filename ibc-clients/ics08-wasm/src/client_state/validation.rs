@@ -0,0 +1,138 @@
+use core::str::FromStr;
+
+use ibc_client_wasm_types::client_message::ClientMessage as WasmClientMessage;
+use ibc_client_wasm_types::client_state::ClientState as ClientStateType;
+use ibc_core_client::context::client_state::ClientStateValidation;
+use ibc_core_client::types::error::ClientError;
+use ibc_core_client::types::{Height, Status};
+use ibc_core_host::types::identifiers::ClientId;
+use ibc_primitives::prelude::*;
+use ibc_primitives::proto::Any;
+use ibc_primitives::Timestamp;
+
+use super::ClientState;
+use crate::context::ValidationContext as WasmValidationContext;
+use crate::contract::{
+    decode_response, encode_msg, CheckForMisbehaviourResult, QueryMsg, StatusResult,
+    TimestampAtHeightResult,
+};
+
+impl<V> ClientStateValidation<V> for ClientState
+where
+    V: WasmValidationContext,
+{
+    fn verify_client_message(
+        &self,
+        ctx: &V,
+        client_id: &ClientId,
+        client_message: Any,
+    ) -> Result<(), ClientError> {
+        verify_client_message(self.inner(), ctx, client_id, client_message)
+    }
+
+    fn check_for_misbehaviour(
+        &self,
+        ctx: &V,
+        client_id: &ClientId,
+        client_message: Any,
+    ) -> Result<bool, ClientError> {
+        check_for_misbehaviour(self.inner(), ctx, client_id, client_message)
+    }
+
+    fn status(&self, ctx: &V, client_id: &ClientId) -> Result<Status, ClientError> {
+        status(self.inner(), ctx, client_id)
+    }
+}
+
+/// Has the contract verify the client message wrapped in a Wasm
+/// `ClientMessage`.
+///
+/// Note that this function is typically implemented as part of the
+/// [`ClientStateValidation`] trait, but has been made a standalone function
+/// in order to make the ClientState APIs more flexible.
+pub fn verify_client_message<V>(
+    client_state: &ClientStateType,
+    ctx: &V,
+    client_id: &ClientId,
+    client_message: Any,
+) -> Result<(), ClientError>
+where
+    V: WasmValidationContext,
+{
+    let client_message = WasmClientMessage::try_from(client_message)?;
+
+    let msg = QueryMsg::VerifyClientMessage {
+        client_message: client_message.data,
+    };
+
+    ctx.query_contract(client_id, &client_state.checksum, encode_msg(&msg)?)?;
+
+    Ok(())
+}
+
+/// Asks the contract whether the client message constitutes misbehaviour.
+pub fn check_for_misbehaviour<V>(
+    client_state: &ClientStateType,
+    ctx: &V,
+    client_id: &ClientId,
+    client_message: Any,
+) -> Result<bool, ClientError>
+where
+    V: WasmValidationContext,
+{
+    let client_message = WasmClientMessage::try_from(client_message)?;
+
+    let msg = QueryMsg::CheckForMisbehaviour {
+        client_message: client_message.data,
+    };
+
+    let response = ctx.query_contract(client_id, &client_state.checksum, encode_msg(&msg)?)?;
+    let result: CheckForMisbehaviourResult = decode_response(&response)?;
+
+    Ok(result.found_misbehaviour)
+}
+
+/// Returns the status reported by the contract. A client whose byte code is
+/// no longer stored on the host is reported as `Unauthorized`.
+pub fn status<V>(
+    client_state: &ClientStateType,
+    ctx: &V,
+    client_id: &ClientId,
+) -> Result<Status, ClientError>
+where
+    V: WasmValidationContext,
+{
+    if !ctx.checksum_exists(&client_state.checksum)? {
+        return Ok(Status::Unauthorized);
+    }
+
+    let msg = QueryMsg::Status {};
+
+    let response = ctx.query_contract(client_id, &client_state.checksum, encode_msg(&msg)?)?;
+    let result: StatusResult = decode_response(&response)?;
+
+    Status::from_str(&result.status)
+}
+
+/// Asks the contract for the timestamp of its consensus state at `height`.
+///
+/// Hosts use this to attach timestamps to the Wasm consensus states they
+/// hand to core handlers (see [`ConsensusState`](crate::consensus_state::ConsensusState)).
+pub fn timestamp_at_height<V>(
+    client_state: &ClientStateType,
+    ctx: &V,
+    client_id: &ClientId,
+    height: Height,
+) -> Result<Timestamp, ClientError>
+where
+    V: WasmValidationContext,
+{
+    let msg = QueryMsg::TimestampAtHeight { height };
+
+    let response = ctx.query_contract(client_id, &client_state.checksum, encode_msg(&msg)?)?;
+    let result: TimestampAtHeightResult = decode_response(&response)?;
+
+    Timestamp::from_nanoseconds(result.timestamp).map_err(|e| ClientError::Other {
+        description: e.to_string(),
+    })
+}
@@ -0,0 +1,65 @@
+//! Implements the core [`ConsensusState`](ConsensusStateTrait) trait for Wasm
+//! consensus states.
+
+use ibc_client_wasm_types::consensus_state::ConsensusState as ConsensusStateType;
+use ibc_core_client::context::consensus_state::ConsensusState as ConsensusStateTrait;
+use ibc_core_commitment_types::commitment::CommitmentRoot;
+use ibc_primitives::prelude::*;
+use ibc_primitives::proto::{Any, Protobuf};
+use ibc_primitives::Timestamp;
+
+/// Wasm consensus state along with its timestamp.
+///
+/// The consensus state of a Wasm client is opaque to ibc-rs; only the
+/// contract can interpret it. Since core handlers need to know the timestamp
+/// of consensus states (e.g. to check packet timeouts), hosts are expected to
+/// resolve it when loading the consensus state, via
+/// [`timestamp_at_height`](crate::client_state::timestamp_at_height).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ConsensusState {
+    inner: ConsensusStateType,
+    timestamp: Timestamp,
+    root: CommitmentRoot,
+}
+
+impl ConsensusState {
+    pub fn new(inner: ConsensusStateType, timestamp: Timestamp) -> Self {
+        Self {
+            inner,
+            timestamp,
+            root: CommitmentRoot::from_bytes(&[]),
+        }
+    }
+
+    pub fn inner(&self) -> &ConsensusStateType {
+        &self.inner
+    }
+}
+
+impl From<ConsensusState> for ConsensusStateType {
+    fn from(consensus_state: ConsensusState) -> Self {
+        consensus_state.inner
+    }
+}
+
+impl From<ConsensusState> for Any {
+    fn from(consensus_state: ConsensusState) -> Self {
+        consensus_state.inner.into()
+    }
+}
+
+impl ConsensusStateTrait for ConsensusState {
+    /// The commitment root is only known to the contract, hence this is
+    /// always empty.
+    fn root(&self) -> &CommitmentRoot {
+        &self.root
+    }
+
+    fn timestamp(&self) -> Timestamp {
+        self.timestamp
+    }
+
+    fn encode_vec(self) -> Vec<u8> {
+        <ConsensusStateType as Protobuf<Any>>::encode_vec(self.inner)
+    }
+}
@@ -0,0 +1,72 @@
+use ibc_client_wasm_types::Bytes;
+use ibc_core_client::context::prelude::*;
+use ibc_core_client::types::error::ClientError;
+use ibc_core_host::types::identifiers::ClientId;
+use ibc_primitives::prelude::*;
+use ibc_primitives::Signer;
+
+/// Checksum of a wasm byte code, i.e. its SHA-256 hash.
+pub type Checksum = Bytes;
+
+/// Gives access to the wasm byte code stored on the host, and to the
+/// contract runtime instantiated from it.
+///
+/// The host is expected to key each contract instance by the client
+/// identifier, and to give the contract access to the client's store (i.e.
+/// the client and consensus states stored under the client's prefix), so that
+/// the contract can read and write its own states.
+pub trait WasmCodeContext {
+    /// Returns the signer allowed to store and remove byte code, and to
+    /// migrate contracts (typically the governance module account).
+    fn authority(&self) -> Signer;
+
+    /// Returns whether byte code with the given checksum has been stored.
+    fn checksum_exists(&self, checksum: &Checksum) -> Result<bool, ClientError>;
+
+    /// Stores (and typically compiles) the given byte code under `checksum`.
+    fn store_code(&mut self, checksum: Checksum, wasm_byte_code: Bytes) -> Result<(), ClientError>;
+
+    /// Removes the byte code stored under `checksum`.
+    fn remove_checksum(&mut self, checksum: &Checksum) -> Result<(), ClientError>;
+}
+
+/// Client's context required during validation.
+pub trait ValidationContext: ClientValidationContext + WasmCodeContext {
+    /// Runs a read-only query against the contract backing `client_id`, and
+    /// returns its JSON response.
+    fn query_contract(
+        &self,
+        client_id: &ClientId,
+        checksum: &Checksum,
+        msg: Vec<u8>,
+    ) -> Result<Vec<u8>, ClientError>;
+}
+
+/// Client's context required during execution.
+pub trait ExecutionContext: ValidationContext + ClientExecutionContext {
+    /// Instantiates the contract with the given checksum for `client_id`.
+    fn instantiate_contract(
+        &mut self,
+        client_id: &ClientId,
+        checksum: &Checksum,
+        msg: Vec<u8>,
+    ) -> Result<(), ClientError>;
+
+    /// Runs a state-changing call against the contract backing `client_id`,
+    /// and returns its JSON response.
+    fn sudo_contract(
+        &mut self,
+        client_id: &ClientId,
+        checksum: &Checksum,
+        msg: Vec<u8>,
+    ) -> Result<Vec<u8>, ClientError>;
+
+    /// Migrates the contract backing `client_id` to the byte code with the
+    /// given checksum, passing `msg` to its migrate entry point.
+    fn migrate_contract(
+        &mut self,
+        client_id: &ClientId,
+        checksum: &Checksum,
+        msg: Vec<u8>,
+    ) -> Result<(), ClientError>;
+}
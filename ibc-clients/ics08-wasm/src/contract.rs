@@ -0,0 +1,164 @@
+//! Messages exchanged with light client contracts.
+//!
+//! These mirror the JSON API of ibc-go's `08-wasm` module, so that light
+//! client contracts written for ibc-go can be run by ibc-rs hosts unchanged.
+//! Byte fields are base64-encoded.
+
+use ibc_client_wasm_types::serializer::Base64;
+use ibc_client_wasm_types::Bytes;
+use ibc_core_client::types::error::ClientError;
+use ibc_core_client::types::Height;
+use ibc_primitives::prelude::*;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+
+use crate::error::Error;
+
+/// Message passed to the contract when a client is created.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct InstantiateMsg {
+    #[serde(with = "Base64")]
+    pub client_state: Bytes,
+    #[serde(with = "Base64")]
+    pub consensus_state: Bytes,
+    #[serde(with = "Base64")]
+    pub checksum: Bytes,
+}
+
+/// Read-only queries served by the contract.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum QueryMsg {
+    Status {},
+    TimestampAtHeight {
+        height: Height,
+    },
+    VerifyClientMessage {
+        #[serde(with = "Base64")]
+        client_message: Bytes,
+    },
+    CheckForMisbehaviour {
+        #[serde(with = "Base64")]
+        client_message: Bytes,
+    },
+}
+
+/// Merkle path passed to the contract for (non-)membership verification.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MerklePath {
+    pub key_path: Vec<String>,
+}
+
+/// State-changing calls served by the contract.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SudoMsg {
+    UpdateState {
+        #[serde(with = "Base64")]
+        client_message: Bytes,
+    },
+    UpdateStateOnMisbehaviour {
+        #[serde(with = "Base64")]
+        client_message: Bytes,
+    },
+    VerifyUpgradeAndUpdateState {
+        #[serde(with = "Base64")]
+        upgrade_client_state: Bytes,
+        #[serde(with = "Base64")]
+        upgrade_consensus_state: Bytes,
+        #[serde(with = "Base64")]
+        proof_upgrade_client: Bytes,
+        #[serde(with = "Base64")]
+        proof_upgrade_consensus_state: Bytes,
+    },
+    VerifyMembership {
+        height: Height,
+        delay_time_period: u64,
+        delay_block_period: u64,
+        #[serde(with = "Base64")]
+        proof: Bytes,
+        merkle_path: MerklePath,
+        #[serde(with = "Base64")]
+        value: Bytes,
+    },
+    VerifyNonMembership {
+        height: Height,
+        delay_time_period: u64,
+        delay_block_period: u64,
+        #[serde(with = "Base64")]
+        proof: Bytes,
+        merkle_path: MerklePath,
+    },
+}
+
+/// Response to [`QueryMsg::Status`].
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StatusResult {
+    pub status: String,
+}
+
+/// Response to [`QueryMsg::TimestampAtHeight`].
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TimestampAtHeightResult {
+    pub timestamp: u64,
+}
+
+/// Response to [`QueryMsg::CheckForMisbehaviour`].
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CheckForMisbehaviourResult {
+    pub found_misbehaviour: bool,
+}
+
+/// Response to [`SudoMsg::UpdateState`].
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct UpdateStateResult {
+    pub heights: Vec<Height>,
+}
+
+/// JSON-encodes a message to be passed to a contract.
+pub fn encode_msg<T: Serialize>(msg: &T) -> Result<Vec<u8>, ClientError> {
+    serde_json::to_vec(msg).map_err(|e| {
+        Error::ContractMessage {
+            reason: e.to_string(),
+        }
+        .into()
+    })
+}
+
+/// Decodes the JSON response of a contract.
+pub fn decode_response<T: DeserializeOwned>(response: &[u8]) -> Result<T, ClientError> {
+    serde_json::from_slice(response).map_err(|e| {
+        Error::ContractMessage {
+            reason: e.to_string(),
+        }
+        .into()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn query_msg_json_matches_contract_api() {
+        let msg = QueryMsg::Status {};
+        assert_eq!(encode_msg(&msg).unwrap(), br#"{"status":{}}"#.to_vec());
+
+        let msg = QueryMsg::VerifyClientMessage {
+            client_message: b"hello world".to_vec(),
+        };
+        assert_eq!(
+            encode_msg(&msg).unwrap(),
+            br#"{"verify_client_message":{"client_message":"aGVsbG8gd29ybGQ="}}"#.to_vec()
+        );
+    }
+
+    #[test]
+    fn update_state_result_roundtrip() {
+        let result = UpdateStateResult {
+            heights: vec![Height::new(1, 10).unwrap()],
+        };
+        let json = encode_msg(&result).unwrap();
+        assert_eq!(decode_response::<UpdateStateResult>(&json).unwrap(), result);
+    }
+}
@@ -0,0 +1,49 @@
+//! Defines the errors raised by the Wasm proxy light client.
+
+use core::fmt::Write;
+
+use displaydoc::Display;
+use ibc_core_client::types::error::ClientError;
+use ibc_primitives::prelude::*;
+use ibc_primitives::Signer;
+
+/// The main error type
+#[derive(Debug, Display)]
+pub enum Error {
+    /// signer `{signer}` is not the authority allowed to manage wasm code
+    Unauthorized { signer: Signer },
+    /// wasm byte code cannot be empty
+    EmptyCode,
+    /// wasm byte code size `{size}` exceeds the maximum of `{max}` bytes
+    CodeTooLarge { size: usize, max: usize },
+    /// checksum `{checksum}` already exists
+    ChecksumAlreadyExists { checksum: String },
+    /// checksum `{checksum}` not found
+    ChecksumNotFound { checksum: String },
+    /// the new checksum `{checksum}` is the same as the current one
+    SameChecksum { checksum: String },
+    /// failed to encode/decode contract message: `{reason}`
+    ContractMessage { reason: String },
+    /// contract call failed: `{reason}`
+    ContractCall { reason: String },
+    /// `{method}` requires access to the contract and cannot be served through `ClientStateCommon`
+    ContextRequired { method: &'static str },
+}
+
+impl core::error::Error for Error {}
+
+impl From<Error> for ClientError {
+    fn from(e: Error) -> Self {
+        Self::ClientSpecific {
+            description: e.to_string(),
+        }
+    }
+}
+
+/// Hex encoding of a checksum, used in error messages.
+pub(crate) fn checksum_hex(checksum: &[u8]) -> String {
+    checksum.iter().fold(String::new(), |mut hex, b| {
+        let _ = write!(hex, "{b:02x}");
+        hex
+    })
+}
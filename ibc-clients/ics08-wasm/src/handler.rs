@@ -0,0 +1,202 @@
+//! Handlers for the messages of the Wasm light client module: storing and
+//! removing byte code, and migrating the contract backing a client.
+//!
+//! These messages are not routed through the core IBC handlers. Hosts are
+//! expected to dispatch them from the module owning the Wasm light client,
+//! typically on behalf of governance.
+
+use ibc_client_wasm_types::client_state::ClientState as ClientStateType;
+use ibc_client_wasm_types::msgs::migrate_contract::MsgMigrateContract;
+use ibc_client_wasm_types::msgs::remove_checksum::MsgRemoveChecksum;
+use ibc_client_wasm_types::msgs::store_code::MsgStoreCode;
+use ibc_core_client::types::error::ClientError;
+use ibc_core_host::types::path::ClientStatePath;
+use ibc_primitives::prelude::*;
+use ibc_primitives::Signer;
+use sha2::{Digest, Sha256};
+
+use crate::client_state::ClientState;
+use crate::context::{Checksum, ExecutionContext as WasmExecutionContext, WasmCodeContext};
+use crate::error::{checksum_hex, Error};
+
+/// Maximum size of the wasm byte code that can be stored, matching ibc-go.
+pub const MAX_WASM_BYTE_CODE_SIZE: usize = 3 * 1024 * 1024;
+
+/// Computes the checksum under which the given byte code is stored.
+pub fn checksum(wasm_byte_code: &[u8]) -> Checksum {
+    Sha256::digest(wasm_byte_code).to_vec()
+}
+
+fn verify_authority<C: WasmCodeContext>(ctx: &C, signer: &Signer) -> Result<(), ClientError> {
+    if ctx.authority() != *signer {
+        return Err(Error::Unauthorized {
+            signer: signer.clone(),
+        }
+        .into());
+    }
+    Ok(())
+}
+
+/// Stores the given byte code, returning its checksum.
+pub fn store_code<C>(ctx: &mut C, msg: MsgStoreCode) -> Result<Checksum, ClientError>
+where
+    C: WasmCodeContext,
+{
+    verify_authority(ctx, &msg.signer)?;
+
+    if msg.wasm_byte_code.is_empty() {
+        return Err(Error::EmptyCode.into());
+    }
+
+    if msg.wasm_byte_code.len() > MAX_WASM_BYTE_CODE_SIZE {
+        return Err(Error::CodeTooLarge {
+            size: msg.wasm_byte_code.len(),
+            max: MAX_WASM_BYTE_CODE_SIZE,
+        }
+        .into());
+    }
+
+    let checksum = checksum(&msg.wasm_byte_code);
+
+    if ctx.checksum_exists(&checksum)? {
+        return Err(Error::ChecksumAlreadyExists {
+            checksum: checksum_hex(&checksum),
+        }
+        .into());
+    }
+
+    ctx.store_code(checksum.clone(), msg.wasm_byte_code)?;
+
+    Ok(checksum)
+}
+
+/// Removes the byte code stored under the given checksum. Clients backed by
+/// it become `Unauthorized` until migrated to another checksum.
+pub fn remove_checksum<C>(ctx: &mut C, msg: MsgRemoveChecksum) -> Result<(), ClientError>
+where
+    C: WasmCodeContext,
+{
+    verify_authority(ctx, &msg.signer)?;
+
+    if !ctx.checksum_exists(&msg.checksum)? {
+        return Err(Error::ChecksumNotFound {
+            checksum: checksum_hex(&msg.checksum),
+        }
+        .into());
+    }
+
+    ctx.remove_checksum(&msg.checksum)
+}
+
+/// Migrates the contract backing a client to another stored byte code, and
+/// updates the checksum recorded in its client state.
+pub fn migrate_contract<E>(ctx: &mut E, msg: MsgMigrateContract) -> Result<(), ClientError>
+where
+    E: WasmExecutionContext,
+    E::ClientStateRef: From<ClientState> + TryInto<ClientState, Error = ClientError>,
+{
+    verify_authority(ctx, &msg.signer)?;
+
+    let client_state: ClientState = ctx.client_state(&msg.client_id)?.try_into()?;
+    let client_state: ClientStateType = client_state.inner().clone();
+
+    if client_state.checksum == msg.checksum {
+        return Err(Error::SameChecksum {
+            checksum: checksum_hex(&msg.checksum),
+        }
+        .into());
+    }
+
+    if !ctx.checksum_exists(&msg.checksum)? {
+        return Err(Error::ChecksumNotFound {
+            checksum: checksum_hex(&msg.checksum),
+        }
+        .into());
+    }
+
+    ctx.migrate_contract(&msg.client_id, &msg.checksum, msg.msg)?;
+
+    let migrated_client_state = ClientStateType {
+        checksum: msg.checksum,
+        ..client_state
+    };
+
+    ctx.store_client_state(
+        ClientStatePath::new(msg.client_id),
+        ClientState::from(migrated_client_state).into(),
+    )?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Default)]
+    struct MockCodeStore {
+        codes: BTreeMap<Checksum, Vec<u8>>,
+    }
+
+    impl WasmCodeContext for MockCodeStore {
+        fn authority(&self) -> Signer {
+            Signer::from("authority".to_string())
+        }
+
+        fn checksum_exists(&self, checksum: &Checksum) -> Result<bool, ClientError> {
+            Ok(self.codes.contains_key(checksum))
+        }
+
+        fn store_code(&mut self, checksum: Checksum, code: Vec<u8>) -> Result<(), ClientError> {
+            self.codes.insert(checksum, code);
+            Ok(())
+        }
+
+        fn remove_checksum(&mut self, checksum: &Checksum) -> Result<(), ClientError> {
+            self.codes.remove(checksum);
+            Ok(())
+        }
+    }
+
+    fn store_code_msg(signer: &str, code: &[u8]) -> MsgStoreCode {
+        MsgStoreCode {
+            signer: Signer::from(signer.to_string()),
+            wasm_byte_code: code.to_vec(),
+        }
+    }
+
+    #[test]
+    fn store_and_remove_code() {
+        let mut ctx = MockCodeStore::default();
+
+        let checksum = store_code(&mut ctx, store_code_msg("authority", b"code")).unwrap();
+        assert_eq!(checksum, super::checksum(b"code"));
+        assert!(ctx.checksum_exists(&checksum).unwrap());
+
+        // storing the same code twice fails
+        assert!(store_code(&mut ctx, store_code_msg("authority", b"code")).is_err());
+
+        remove_checksum(
+            &mut ctx,
+            MsgRemoveChecksum {
+                signer: Signer::from("authority".to_string()),
+                checksum: checksum.clone(),
+            },
+        )
+        .unwrap();
+        assert!(!ctx.checksum_exists(&checksum).unwrap());
+    }
+
+    #[test]
+    fn store_code_rejects_invalid_msgs() {
+        let mut ctx = MockCodeStore::default();
+
+        assert!(store_code(&mut ctx, store_code_msg("intruder", b"code")).is_err());
+        assert!(store_code(&mut ctx, store_code_msg("authority", b"")).is_err());
+        assert!(store_code(
+            &mut ctx,
+            store_code_msg("authority", &vec![0; MAX_WASM_BYTE_CODE_SIZE + 1])
+        )
+        .is_err());
+    }
+}
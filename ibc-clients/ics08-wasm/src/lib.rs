@@ -0,0 +1,35 @@
+//! ICS 08: Host-side implementation of the Wasm proxy light client.
+//!
+//! The Wasm client does not implement any light client logic itself. Instead,
+//! each client instance is backed by a contract, identified by the checksum
+//! of its byte code, to which verification and state transitions are
+//! dispatched, in the same way as ibc-go's `08-wasm` module. Hosts provide the
+//! contract runtime through the [`context`] traits.
+#![no_std]
+#![forbid(unsafe_code)]
+#![cfg_attr(not(test), deny(clippy::unwrap_used))]
+#![cfg_attr(not(test), deny(clippy::disallowed_methods, clippy::disallowed_types))]
+#![deny(
+    warnings,
+    trivial_casts,
+    trivial_numeric_casts,
+    unused_import_braces,
+    unused_qualifications,
+    rust_2018_idioms
+)]
+
+#[cfg(any(test, feature = "std"))]
+extern crate std;
+
+pub mod client_state;
+pub mod consensus_state;
+pub mod context;
+pub mod contract;
+pub mod error;
+pub mod handler;
+
+/// Re-export of ICS-08 Wasm light client data structures from `ibc-client-wasm-types` crate.
+pub mod types {
+    #[doc(inline)]
+    pub use ibc_client_wasm_types::*;
+}
@@ -1,8 +1,10 @@
 //! Defines the client message type for the ICS-08 Wasm light client.
 
-use ibc_primitives::proto::Protobuf;
+use ibc_primitives::prelude::*;
+use ibc_primitives::proto::{Any, Protobuf};
 use ibc_proto::ibc::lightclients::wasm::v1::ClientMessage as RawClientMessage;
 
+use crate::error::Error;
 use crate::Bytes;
 
 pub const WASM_CLIENT_MESSAGE_TYPE_URL: &str = "/ibc.lightclients.wasm.v1.ClientMessage";
@@ -27,6 +29,39 @@ impl From<ClientMessage> for RawClientMessage {
     }
 }
 
+impl Protobuf<Any> for ClientMessage {}
+
+impl From<ClientMessage> for Any {
+    fn from(value: ClientMessage) -> Self {
+        Self {
+            type_url: WASM_CLIENT_MESSAGE_TYPE_URL.to_string(),
+            value: Protobuf::<RawClientMessage>::encode_vec(value),
+        }
+    }
+}
+
+impl TryFrom<Any> for ClientMessage {
+    type Error = Error;
+
+    fn try_from(any: Any) -> Result<Self, Self::Error> {
+        fn decode_client_message(value: &[u8]) -> Result<ClientMessage, Error> {
+            let client_message =
+                Protobuf::<RawClientMessage>::decode(value).map_err(|e| Error::DecodeError {
+                    reason: e.to_string(),
+                })?;
+
+            Ok(client_message)
+        }
+
+        match any.type_url.as_str() {
+            WASM_CLIENT_MESSAGE_TYPE_URL => decode_client_message(&any.value),
+            _ => Err(Error::DecodeError {
+                reason: "type_url does not match".into(),
+            }),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use rstest::rstest;
@@ -16,6 +16,18 @@ pub const WASM_CLIENT_STATE_TYPE_URL: &str = "/ibc.lightclients.wasm.v1.ClientSt
 
 #[cfg_attr(feature = "cosmwasm", cw_serde)]
 #[cfg_attr(not(feature = "cosmwasm"), derive(Clone, Debug, PartialEq))]
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
 #[derive(Eq)]
 pub struct ClientState {
     #[cfg_attr(feature = "cosmwasm", schemars(with = "String"))]
@@ -118,4 +130,42 @@ mod tests {
             client_state
         );
     }
+
+    #[cfg(feature = "borsh")]
+    #[test]
+    fn test_borsh_roundtrip() {
+        use borsh::BorshDeserialize;
+
+        let client_state = ClientState {
+            data: b"data".to_vec(),
+            checksum: b"checksum".to_vec(),
+            latest_height: Height::new(0, 1).unwrap(),
+        };
+
+        let serialized = borsh::to_vec(&client_state).unwrap();
+
+        assert_eq!(
+            ClientState::try_from_slice(&serialized).unwrap(),
+            client_state
+        );
+    }
+
+    #[cfg(feature = "parity-scale-codec")]
+    #[test]
+    fn test_scale_roundtrip() {
+        use parity_scale_codec::{Decode, Encode};
+
+        let client_state = ClientState {
+            data: b"data".to_vec(),
+            checksum: b"checksum".to_vec(),
+            latest_height: Height::new(0, 1).unwrap(),
+        };
+
+        let encoded = client_state.encode();
+
+        assert_eq!(
+            ClientState::decode(&mut encoded.as_slice()).unwrap(),
+            client_state
+        );
+    }
 }
@@ -1,6 +1,7 @@
 //! Defines the error type for the ICS-08 Wasm light client.
 
 use displaydoc::Display;
+use ibc_core_client::types::error::ClientError;
 use ibc_core_host_types::error::IdentifierError;
 use ibc_primitives::prelude::*;
 
@@ -15,9 +16,8 @@ pub enum Error {
     InvalidLatestHeight { reason: String },
 }
 
-#[cfg(feature = "std")]
-impl std::error::Error for Error {
-    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+impl core::error::Error for Error {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
         match self {
             Error::InvalidIdentifier(err) => Some(err),
             _ => None,
@@ -30,3 +30,11 @@ impl From<IdentifierError> for Error {
         Self::InvalidIdentifier(e)
     }
 }
+
+impl From<Error> for ClientError {
+    fn from(e: Error) -> Self {
+        Self::ClientSpecific {
+            description: e.to_string(),
+        }
+    }
+}
@@ -12,6 +12,11 @@ use crate::Bytes;
 pub const MIGRATE_CONTRACT_TYPE_URL: &str = "/ibc.lightclients.wasm.v1.MsgMigrateContract";
 
 /// Defines the message type for migrating a Wasm contract on the chain.
+#[cfg_attr(
+    feature = "proto-json",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "RawMsgMigrateContract", into = "RawMsgMigrateContract")
+)]
 #[derive(Clone, PartialEq, Debug, Eq)]
 pub struct MsgMigrateContract {
     pub signer: Signer,
@@ -8,6 +8,11 @@ use crate::Bytes;
 pub const REMOVE_CHECKSUM_TYPE_URL: &str = "/ibc.lightclients.wasm.v1.MsgRemoveChecksum";
 
 /// Defines the message type for removing a checksum from the chain.
+#[cfg_attr(
+    feature = "proto-json",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "RawMsgRemoveChecksum", into = "RawMsgRemoveChecksum")
+)]
 #[derive(Clone, PartialEq, Debug, Eq)]
 pub struct MsgRemoveChecksum {
     pub signer: Signer,
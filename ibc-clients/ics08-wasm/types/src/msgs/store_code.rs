@@ -8,6 +8,11 @@ use crate::Bytes;
 pub const STORE_CODE_TYPE_URL: &str = "/ibc.lightclients.wasm.v1.MsgStoreCode";
 
 /// Defines the message type for storing the Wasm byte code on the chain.
+#[cfg_attr(
+    feature = "proto-json",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "RawMsgStoreCode", into = "RawMsgStoreCode")
+)]
 #[derive(Clone, PartialEq, Debug, Eq)]
 pub struct MsgStoreCode {
     pub signer: Signer,
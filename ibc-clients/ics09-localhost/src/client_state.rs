@@ -0,0 +1,219 @@
+//! This module includes the implementations of the `ClientStateCommon`,
+//! `ClientStateValidation`, and `ClientStateExecution` traits for the
+//! localhost client state.
+//!
+//! As with the other clients, the logic behind each trait method is also
+//! exposed as a standalone function, so that hosts wrapping the localhost
+//! client can reuse it.
+
+mod common;
+mod execution;
+mod validation;
+
+pub use common::*;
+pub use execution::*;
+pub use validation::*;
+
+#[cfg(test)]
+mod tests {
+    use ibc_core_client::context::prelude::*;
+    use ibc_core_client::types::error::ClientError;
+    use ibc_core_client::types::Height;
+    use ibc_core_commitment_types::commitment::{
+        CommitmentPrefix, CommitmentProofBytes, CommitmentRoot,
+    };
+    use ibc_core_handler_types::error::ContextError;
+    use ibc_core_host::types::identifiers::{ChannelId, ClientId, PortId, Sequence};
+    use ibc_core_host::types::path::{ClientConsensusStatePath, CommitmentPath, Path, ReceiptPath};
+    use ibc_primitives::prelude::*;
+    use ibc_primitives::proto::Any;
+    use ibc_primitives::Timestamp;
+
+    use super::*;
+    use crate::context::LocalhostValidationContext;
+    use crate::types::{ClientState, ConsensusState};
+    use crate::SENTINEL_PROOF;
+
+    /// A host whose IBC store is a map from paths to values.
+    struct MockHost {
+        store: BTreeMap<String, Vec<u8>>,
+    }
+
+    impl ClientValidationContext for MockHost {
+        type ClientStateRef = ClientState;
+        type ConsensusStateRef = ConsensusState;
+
+        fn client_state(&self, _client_id: &ClientId) -> Result<ClientState, ContextError> {
+            Err(ClientError::Other {
+                description: "not stored".to_string(),
+            }
+            .into())
+        }
+
+        fn consensus_state(
+            &self,
+            _client_cons_state_path: &ClientConsensusStatePath,
+        ) -> Result<ConsensusState, ContextError> {
+            Err(ClientError::Other {
+                description: "not stored".to_string(),
+            }
+            .into())
+        }
+
+        fn client_update_meta(
+            &self,
+            _client_id: &ClientId,
+            _height: &Height,
+        ) -> Result<(Timestamp, Height), ContextError> {
+            Err(ClientError::Other {
+                description: "not stored".to_string(),
+            }
+            .into())
+        }
+    }
+
+    impl HostClock for MockHost {
+        fn host_height(&self) -> Result<Height, ContextError> {
+            Ok(Height::new(0, 10).expect("never fails"))
+        }
+
+        fn host_timestamp(&self) -> Result<Timestamp, ContextError> {
+            Ok(Timestamp::from_nanoseconds(1_700_000_000_000_000_000).expect("never fails"))
+        }
+    }
+
+    impl LocalhostValidationContext for MockHost {
+        fn commitment_prefix(&self) -> CommitmentPrefix {
+            host_prefix()
+        }
+
+        fn stored_value(&self, path: &Path) -> Option<Vec<u8>> {
+            self.store.get(&path.to_string()).cloned()
+        }
+    }
+
+    fn host_prefix() -> CommitmentPrefix {
+        CommitmentPrefix::try_from(b"ibc".to_vec()).expect("never fails")
+    }
+
+    fn sentinel_proof() -> CommitmentProofBytes {
+        SENTINEL_PROOF.to_vec().try_into().expect("never fails")
+    }
+
+    fn commitment_path(sequence: u64) -> CommitmentPath {
+        CommitmentPath::new(
+            &PortId::transfer(),
+            &ChannelId::new(0),
+            Sequence::from(sequence),
+        )
+    }
+
+    fn dummy_host() -> MockHost {
+        let mut store = BTreeMap::new();
+        store.insert(
+            Path::Commitment(commitment_path(1)).to_string(),
+            vec![1, 2, 3],
+        );
+        MockHost { store }
+    }
+
+    fn dummy_client_state() -> ClientState {
+        ClientState::new(Height::new(0, 10).expect("never fails"))
+    }
+
+    #[test]
+    fn client_state_any_roundtrip() {
+        let client_state = dummy_client_state();
+        let any: Any = client_state.clone().into();
+        assert_eq!(
+            ClientState::try_from(any).expect("never fails"),
+            client_state
+        );
+    }
+
+    #[test]
+    fn verify_membership_against_host_store() {
+        let host = dummy_host();
+        let client_state = dummy_client_state();
+        let root = CommitmentRoot::from_bytes(&[]);
+        let verify = |prefix: &CommitmentPrefix, proof: &CommitmentProofBytes, sequence, value| {
+            client_state.verify_membership_with_context(
+                &host,
+                prefix,
+                proof,
+                &root,
+                Path::Commitment(commitment_path(sequence)),
+                value,
+            )
+        };
+
+        assert!(verify(&host_prefix(), &sentinel_proof(), 1, vec![1, 2, 3]).is_ok());
+        assert!(verify(&host_prefix(), &sentinel_proof(), 1, vec![3, 2, 1]).is_err());
+        assert!(verify(&host_prefix(), &sentinel_proof(), 2, vec![1, 2, 3]).is_err());
+
+        let other_proof = vec![0x02].try_into().expect("never fails");
+        assert!(verify(&host_prefix(), &other_proof, 1, vec![1, 2, 3]).is_err());
+
+        let other_prefix = CommitmentPrefix::try_from(b"other".to_vec()).expect("never fails");
+        assert!(verify(&other_prefix, &sentinel_proof(), 1, vec![1, 2, 3]).is_err());
+
+        // proofs cannot be verified without access to the host store
+        assert!(client_state
+            .verify_membership(
+                &host_prefix(),
+                &sentinel_proof(),
+                &root,
+                Path::Commitment(commitment_path(1)),
+                vec![1, 2, 3],
+            )
+            .is_err());
+    }
+
+    #[test]
+    fn verify_non_membership_against_host_store() {
+        let host = dummy_host();
+        let root = CommitmentRoot::from_bytes(&[]);
+        let receipt_path = |sequence| {
+            Path::Receipt(ReceiptPath::new(
+                &PortId::transfer(),
+                &ChannelId::new(0),
+                Sequence::from(sequence),
+            ))
+        };
+
+        assert!(
+            ClientStateValidation::<MockHost>::verify_non_membership_with_context(
+                &dummy_client_state(),
+                &host,
+                &host_prefix(),
+                &sentinel_proof(),
+                &root,
+                receipt_path(1),
+            )
+            .is_ok()
+        );
+
+        assert!(verify_non_membership(
+            &host,
+            &host_prefix(),
+            &sentinel_proof(),
+            Path::Commitment(commitment_path(1)),
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn client_messages_are_rejected() {
+        let host = dummy_host();
+        let client_id = ClientId::localhost();
+        let client_state = dummy_client_state();
+
+        assert!(client_state
+            .verify_client_message(&host, &client_id, client_state.clone().into())
+            .is_err());
+        assert!(client_state
+            .status(&host, &client_id)
+            .expect("never fails")
+            .is_active());
+    }
+}
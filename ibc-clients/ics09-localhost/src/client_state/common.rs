@@ -0,0 +1,107 @@
+use ibc_core_client::context::client_state::ClientStateCommon;
+use ibc_core_client::types::error::ClientError;
+use ibc_core_client::types::Height;
+use ibc_core_commitment_types::commitment::{
+    CommitmentPrefix, CommitmentProofBytes, CommitmentRoot,
+};
+use ibc_core_host::types::identifiers::ClientType;
+use ibc_core_host::types::path::Path;
+use ibc_primitives::prelude::*;
+use ibc_primitives::proto::Any;
+
+use crate::client_type as localhost_client_type;
+use crate::error::Error;
+use crate::types::{ClientState as ClientStateType, ConsensusState as ConsensusStateType};
+
+impl ClientStateCommon for ClientStateType {
+    fn verify_consensus_state(&self, consensus_state: Any) -> Result<(), ClientError> {
+        verify_consensus_state(consensus_state)
+    }
+
+    fn client_type(&self) -> ClientType {
+        localhost_client_type()
+    }
+
+    fn latest_height(&self) -> Height {
+        ClientStateType::latest_height(self)
+    }
+
+    fn validate_proof_height(&self, proof_height: Height) -> Result<(), ClientError> {
+        validate_proof_height(self, proof_height)
+    }
+
+    fn verify_upgrade_client(
+        &self,
+        _upgraded_client_state: Any,
+        _upgraded_consensus_state: Any,
+        _proof_upgrade_client: CommitmentProofBytes,
+        _proof_upgrade_consensus_state: CommitmentProofBytes,
+        _root: &CommitmentRoot,
+    ) -> Result<(), ClientError> {
+        Err(Error::UpgradeNotSupported.into())
+    }
+
+    /// The localhost client verifies values against the host's store, which
+    /// is only available through
+    /// [`verify_membership_with_context`](ibc_core_client::context::client_state::ClientStateValidation::verify_membership_with_context).
+    fn verify_membership(
+        &self,
+        _prefix: &CommitmentPrefix,
+        _proof: &CommitmentProofBytes,
+        _root: &CommitmentRoot,
+        _path: Path,
+        _value: Vec<u8>,
+    ) -> Result<(), ClientError> {
+        Err(Error::ContextRequired {
+            method: "verify_membership",
+        }
+        .into())
+    }
+
+    /// See [`verify_membership`](Self::verify_membership).
+    fn verify_non_membership(
+        &self,
+        _prefix: &CommitmentPrefix,
+        _proof: &CommitmentProofBytes,
+        _root: &CommitmentRoot,
+        _path: Path,
+    ) -> Result<(), ClientError> {
+        Err(Error::ContextRequired {
+            method: "verify_non_membership",
+        }
+        .into())
+    }
+}
+
+/// Verify an `Any` consensus state by attempting to convert it to a
+/// localhost `ConsensusState`.
+///
+/// Note that this function is typically implemented as part of the
+/// [`ClientStateCommon`] trait, but has been made a standalone function
+/// in order to make the ClientState APIs more flexible.
+pub fn verify_consensus_state(consensus_state: Any) -> Result<(), ClientError> {
+    ConsensusStateType::try_from(consensus_state)?;
+
+    Ok(())
+}
+
+/// Validate the given proof height against the client state's latest height,
+/// returning an error if the proof height is greater than the latest height
+/// of the client state.
+pub fn validate_proof_height(
+    client_state: &ClientStateType,
+    proof_height: Height,
+) -> Result<(), ClientError> {
+    let latest_height = client_state.latest_height();
+
+    if latest_height.revision_number() != proof_height.revision_number()
+        || latest_height < proof_height
+    {
+        return Err(ClientError::InvalidProofHeight {
+            latest_height,
+            proof_height,
+        });
+    }
+
+    Ok(())
+}
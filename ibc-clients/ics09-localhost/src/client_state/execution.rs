@@ -0,0 +1,150 @@
+use ibc_core_client::context::prelude::*;
+use ibc_core_client::types::error::ClientError;
+use ibc_core_client::types::Height;
+use ibc_core_host::types::identifiers::ClientId;
+use ibc_core_host::types::path::{ClientConsensusStatePath, ClientStatePath};
+use ibc_primitives::prelude::*;
+use ibc_primitives::proto::Any;
+
+use crate::context::LocalhostValidationContext;
+use crate::error::Error;
+use crate::types::{ClientState as ClientStateType, ConsensusState as ConsensusStateType};
+
+impl<E> ClientStateExecution<E> for ClientStateType
+where
+    E: ClientExecutionContext + LocalhostValidationContext,
+    <E as ClientValidationContext>::ClientStateRef: From<ClientStateType>,
+    <E as ClientValidationContext>::ConsensusStateRef: From<ConsensusStateType>,
+{
+    fn initialise(
+        &self,
+        ctx: &mut E,
+        client_id: &ClientId,
+        consensus_state: Any,
+    ) -> Result<(), ClientError> {
+        initialise(self, ctx, client_id, consensus_state)
+    }
+
+    fn update_state(
+        &self,
+        ctx: &mut E,
+        client_id: &ClientId,
+        _header: Any,
+    ) -> Result<Vec<Height>, ClientError> {
+        update_state(self, ctx, client_id)
+    }
+
+    fn update_state_on_misbehaviour(
+        &self,
+        _ctx: &mut E,
+        _client_id: &ClientId,
+        _client_message: Any,
+    ) -> Result<(), ClientError> {
+        Err(Error::UpdateNotSupported.into())
+    }
+
+    fn update_state_on_upgrade(
+        &self,
+        _ctx: &mut E,
+        _client_id: &ClientId,
+        _upgraded_client_state: Any,
+        _upgraded_consensus_state: Any,
+    ) -> Result<Height, ClientError> {
+        Err(Error::UpgradeNotSupported.into())
+    }
+}
+
+/// Seed the host store with initial client and consensus states.
+///
+/// The localhost client can only be created under its sentinel identifier,
+/// which [`create_localhost_client`](crate::host::create_localhost_client)
+/// takes care of; creating it through `MsgCreateClient` fails.
+pub fn initialise<E>(
+    client_state: &ClientStateType,
+    ctx: &mut E,
+    client_id: &ClientId,
+    consensus_state: Any,
+) -> Result<(), ClientError>
+where
+    E: ClientExecutionContext + LocalhostValidationContext,
+    <E as ClientValidationContext>::ClientStateRef: From<ClientStateType>,
+    <E as ClientValidationContext>::ConsensusStateRef: From<ConsensusStateType>,
+{
+    if !client_id.is_localhost() {
+        return Err(Error::InvalidClientId {
+            client_id: client_id.clone(),
+        }
+        .into());
+    }
+
+    let localhost_consensus_state = ConsensusStateType::try_from(consensus_state)?;
+    let latest_height = client_state.latest_height();
+
+    ctx.store_client_state(
+        ClientStatePath::new(client_id.clone()),
+        client_state.clone().into(),
+    )?;
+    ctx.store_consensus_state(
+        ClientConsensusStatePath::new(
+            client_id.clone(),
+            latest_height.revision_number(),
+            latest_height.revision_height(),
+        ),
+        localhost_consensus_state.into(),
+    )?;
+    ctx.store_update_meta(
+        client_id.clone(),
+        latest_height,
+        HostClock::host_timestamp(ctx)?,
+        HostClock::host_height(ctx)?,
+    )?;
+
+    Ok(())
+}
+
+/// Advances the client to the current height of the host.
+///
+/// The consensus state at the previous latest height is pruned, as values
+/// are always verified against the current state of the host. Does nothing
+/// if the client is already at the current height of the host.
+pub fn update_state<E>(
+    client_state: &ClientStateType,
+    ctx: &mut E,
+    client_id: &ClientId,
+) -> Result<Vec<Height>, ClientError>
+where
+    E: ClientExecutionContext + LocalhostValidationContext,
+    <E as ClientValidationContext>::ClientStateRef: From<ClientStateType>,
+    <E as ClientValidationContext>::ConsensusStateRef: From<ConsensusStateType>,
+{
+    let host_timestamp = HostClock::host_timestamp(ctx)?;
+    let host_height = HostClock::host_height(ctx)?;
+    let latest_height = client_state.latest_height();
+
+    if host_height <= latest_height {
+        return Ok(vec![latest_height]);
+    }
+
+    ctx.delete_consensus_state(ClientConsensusStatePath::new(
+        client_id.clone(),
+        latest_height.revision_number(),
+        latest_height.revision_height(),
+    ))?;
+    ctx.delete_update_meta(client_id.clone(), latest_height)?;
+
+    ctx.store_client_state(
+        ClientStatePath::new(client_id.clone()),
+        ClientStateType::new(host_height).into(),
+    )?;
+    ctx.store_consensus_state(
+        ClientConsensusStatePath::new(
+            client_id.clone(),
+            host_height.revision_number(),
+            host_height.revision_height(),
+        ),
+        ConsensusStateType::new(host_timestamp).into(),
+    )?;
+    ctx.store_update_meta(client_id.clone(), host_height, host_timestamp, host_height)?;
+
+    Ok(vec![host_height])
+}
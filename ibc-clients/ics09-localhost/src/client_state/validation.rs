@@ -0,0 +1,125 @@
+use ibc_core_client::context::client_state::ClientStateValidation;
+use ibc_core_client::types::error::ClientError;
+use ibc_core_client::types::Status;
+use ibc_core_commitment_types::commitment::{
+    CommitmentPrefix, CommitmentProofBytes, CommitmentRoot,
+};
+use ibc_core_host::types::identifiers::ClientId;
+use ibc_core_host::types::path::Path;
+use ibc_primitives::prelude::*;
+use ibc_primitives::proto::Any;
+
+use crate::context::LocalhostValidationContext;
+use crate::error::Error;
+use crate::types::ClientState as ClientStateType;
+use crate::SENTINEL_PROOF;
+
+impl<V> ClientStateValidation<V> for ClientStateType
+where
+    V: LocalhostValidationContext,
+{
+    fn verify_client_message(
+        &self,
+        _ctx: &V,
+        _client_id: &ClientId,
+        _client_message: Any,
+    ) -> Result<(), ClientError> {
+        Err(Error::UpdateNotSupported.into())
+    }
+
+    fn check_for_misbehaviour(
+        &self,
+        _ctx: &V,
+        _client_id: &ClientId,
+        _client_message: Any,
+    ) -> Result<bool, ClientError> {
+        Ok(false)
+    }
+
+    fn status(&self, _ctx: &V, _client_id: &ClientId) -> Result<Status, ClientError> {
+        Ok(Status::Active)
+    }
+
+    fn verify_membership_with_context(
+        &self,
+        ctx: &V,
+        prefix: &CommitmentPrefix,
+        proof: &CommitmentProofBytes,
+        _root: &CommitmentRoot,
+        path: Path,
+        value: Vec<u8>,
+    ) -> Result<(), ClientError> {
+        verify_membership(ctx, prefix, proof, path, value)
+    }
+
+    fn verify_non_membership_with_context(
+        &self,
+        ctx: &V,
+        prefix: &CommitmentPrefix,
+        proof: &CommitmentProofBytes,
+        _root: &CommitmentRoot,
+        path: Path,
+    ) -> Result<(), ClientError> {
+        verify_non_membership(ctx, prefix, proof, path)
+    }
+}
+
+/// Verifies that the host's store holds `value` at the given `path`.
+///
+/// The proof must be the [`SENTINEL_PROOF`] and the prefix that of the host
+/// store, as the counterparty of the localhost client is the host itself.
+pub fn verify_membership<V>(
+    ctx: &V,
+    prefix: &CommitmentPrefix,
+    proof: &CommitmentProofBytes,
+    path: Path,
+    value: Vec<u8>,
+) -> Result<(), ClientError>
+where
+    V: LocalhostValidationContext,
+{
+    verify_sentinel_proof_and_prefix(ctx, prefix, proof)?;
+
+    match ctx.stored_value(&path) {
+        Some(stored) if stored == value => Ok(()),
+        Some(_) => Err(Error::MismatchedValue { path }.into()),
+        None => Err(Error::ValueNotFound { path }.into()),
+    }
+}
+
+/// Verifies that the host's store holds no value at the given `path`.
+pub fn verify_non_membership<V>(
+    ctx: &V,
+    prefix: &CommitmentPrefix,
+    proof: &CommitmentProofBytes,
+    path: Path,
+) -> Result<(), ClientError>
+where
+    V: LocalhostValidationContext,
+{
+    verify_sentinel_proof_and_prefix(ctx, prefix, proof)?;
+
+    match ctx.stored_value(&path) {
+        Some(_) => Err(Error::ValueFound { path }.into()),
+        None => Ok(()),
+    }
+}
+
+fn verify_sentinel_proof_and_prefix<V>(
+    ctx: &V,
+    prefix: &CommitmentPrefix,
+    proof: &CommitmentProofBytes,
+) -> Result<(), Error>
+where
+    V: LocalhostValidationContext,
+{
+    if proof.as_ref().as_slice() != SENTINEL_PROOF {
+        return Err(Error::InvalidProof);
+    }
+
+    if *prefix != ctx.commitment_prefix() {
+        return Err(Error::MismatchedPrefix);
+    }
+
+    Ok(())
+}
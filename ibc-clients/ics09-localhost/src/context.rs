@@ -0,0 +1,73 @@
+//! Defines the context the host provides to the localhost client.
+
+use ibc_core_client::context::prelude::*;
+use ibc_core_commitment_types::commitment::CommitmentPrefix;
+use ibc_core_host::types::path::Path;
+use ibc_core_host::ValidationContext;
+use ibc_primitives::prelude::*;
+use ibc_primitives::proto::{Any, Protobuf};
+use ibc_primitives::ToVec;
+
+/// Gives the localhost client read access to the host's state.
+///
+/// The localhost client tracks the current height and timestamp of the host,
+/// as read from its [`HostClock`].
+pub trait LocalhostValidationContext: ClientValidationContext + HostClock {
+    /// Returns the prefix of the host's IBC store.
+    fn commitment_prefix(&self) -> CommitmentPrefix;
+
+    /// Returns the value stored at `path` in the host's IBC store, encoded
+    /// as the core handlers expect it to be proven, or `None` if there is
+    /// none.
+    ///
+    /// Hosts can implement this with [`stored_value`].
+    fn stored_value(&self, path: &Path) -> Option<Vec<u8>>;
+}
+
+/// Reads the value stored at `path` from the host's `ValidationContext`,
+/// encoded as the core handlers expect it to be proven.
+///
+/// Paths which are never proven to a counterparty, such as those of the
+/// client update metadata or of the next send and acknowledgement
+/// sequences, hold no value.
+pub fn stored_value<Ctx>(ctx: &Ctx, path: &Path) -> Option<Vec<u8>>
+where
+    Ctx: ValidationContext,
+{
+    match path {
+        Path::ClientState(path) => ctx
+            .get_client_validation_context()
+            .client_state(&path.0)
+            .ok()
+            .map(|client_state| Into::<Any>::into(client_state).to_vec()),
+        Path::ClientConsensusState(path) => ctx
+            .get_client_validation_context()
+            .consensus_state(path)
+            .ok()
+            .map(ConsensusState::encode_vec),
+        Path::Connection(path) => ctx.connection_end(&path.0).ok().map(|c| c.encode_vec()),
+        Path::ChannelEnd(path) => ctx.channel_end(path).ok().map(|c| c.encode_vec()),
+        Path::ChannelUpgrade(path) => ctx.channel_upgrade(path).ok().map(|u| u.encode_vec()),
+        Path::ChannelUpgradeError(path) => ctx
+            .channel_upgrade_error_receipt(path)
+            .ok()
+            .map(|receipt| receipt.encode_vec()),
+        Path::SeqRecv(path) => ctx
+            .get_next_sequence_recv(path)
+            .ok()
+            .map(|seq| seq.to_vec()),
+        Path::Commitment(path) => ctx
+            .get_packet_commitment(path)
+            .ok()
+            .map(|commitment| commitment.into_vec()),
+        Path::Ack(path) => ctx
+            .get_packet_acknowledgement(path)
+            .ok()
+            .map(|ack| ack.into_vec()),
+        Path::Receipt(path) => ctx
+            .get_packet_receipt(path)
+            .ok()
+            .map(|receipt| receipt.to_vec()),
+        _ => None,
+    }
+}
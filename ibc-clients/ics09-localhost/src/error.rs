@@ -0,0 +1,62 @@
+//! Defines the localhost client's error type
+
+use displaydoc::Display;
+use ibc_core_client::types::error::ClientError;
+use ibc_core_host::types::identifiers::ClientId;
+use ibc_core_host::types::path::Path;
+use ibc_primitives::prelude::*;
+use prost::DecodeError;
+
+/// The main error type
+#[derive(Debug, Display)]
+pub enum Error {
+    /// invalid raw client state: `{reason}`
+    InvalidRawClientState { reason: String },
+    /// invalid raw consensus state: `{reason}`
+    InvalidRawConsensusState { reason: String },
+    /// protobuf decoding error: `{0}`
+    Decode(DecodeError),
+    /// the localhost client must use the sentinel identifier, got `{client_id}`
+    InvalidClientId { client_id: ClientId },
+    /// `{method}` requires access to the host's store, use the `_with_context` variant
+    ContextRequired { method: &'static str },
+    /// proofs for the localhost client must be the sentinel proof
+    InvalidProof,
+    /// commitment prefix does not match the prefix of the host store
+    MismatchedPrefix,
+    /// no value is stored at `{path}`
+    ValueNotFound { path: Path },
+    /// value stored at `{path}` does not match the expected value
+    MismatchedValue { path: Path },
+    /// a value is stored at `{path}`
+    ValueFound { path: Path },
+    /// the localhost client is updated by the host, not by client messages
+    UpdateNotSupported,
+    /// the localhost client cannot be upgraded
+    UpgradeNotSupported,
+}
+
+impl core::error::Error for Error {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match &self {
+            // `prost` implements the trait only with `std`
+            #[cfg(feature = "std")]
+            Self::Decode(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<Error> for ClientError {
+    fn from(e: Error) -> Self {
+        Self::ClientSpecific {
+            description: e.to_string(),
+        }
+    }
+}
+
+impl From<DecodeError> for Error {
+    fn from(e: DecodeError) -> Self {
+        Self::Decode(e)
+    }
+}
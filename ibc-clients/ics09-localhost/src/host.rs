@@ -0,0 +1,88 @@
+//! Entry points for hosts to create the localhost client and keep it up to
+//! date.
+
+use core::time::Duration;
+
+use ibc_core_client::context::prelude::*;
+use ibc_core_connection_types::{ConnectionEnd, Counterparty, State};
+use ibc_core_handler_types::error::ContextError;
+use ibc_core_host::types::identifiers::{ClientId, ConnectionId};
+use ibc_core_host::types::path::{ClientConnectionPath, ConnectionPath};
+use ibc_core_host::ExecutionContext;
+use ibc_primitives::prelude::*;
+use ibc_primitives::proto::Any;
+
+use crate::client_state::{initialise, update_state};
+use crate::context::LocalhostValidationContext;
+use crate::types::{ClientState as ClientStateType, ConsensusState as ConsensusStateType};
+
+/// Creates the localhost client at the current height of the host, as well
+/// as the open localhost connection using it, typically at genesis.
+///
+/// Both are stored under their sentinel identifiers, so the client and
+/// connection counters of the host are left untouched.
+pub fn create_localhost_client<Ctx>(ctx: &mut Ctx) -> Result<(), ContextError>
+where
+    Ctx: ExecutionContext,
+    Ctx::E: LocalhostValidationContext,
+    <Ctx::E as ClientValidationContext>::ClientStateRef: From<ClientStateType>,
+    <Ctx::E as ClientValidationContext>::ConsensusStateRef: From<ConsensusStateType>,
+{
+    let client_id = ClientId::localhost();
+    let connection_id = ConnectionId::localhost();
+
+    let client_state = ClientStateType::new(ctx.host_height()?);
+    let consensus_state = ConsensusStateType::new(ctx.host_timestamp()?);
+
+    initialise(
+        &client_state,
+        ctx.get_client_execution_context(),
+        &client_id,
+        consensus_state.into(),
+    )?;
+
+    // The localhost connection is its own counterparty, and the only
+    // version it supports is the one it settled on.
+    let connection_end = ConnectionEnd::new(
+        State::Open,
+        client_id.clone(),
+        Counterparty::new(
+            client_id.clone(),
+            Some(connection_id.clone()),
+            ctx.commitment_prefix(),
+        ),
+        ctx.connection_params()
+            .compatible_versions
+            .into_iter()
+            .take(1)
+            .collect(),
+        Duration::ZERO,
+    )?;
+
+    ctx.store_connection(&ConnectionPath::new(&connection_id), connection_end)?;
+    ctx.store_connection_to_client(&ClientConnectionPath::new(client_id), connection_id)?;
+
+    Ok(())
+}
+
+/// Advances the localhost client to the current height of the host.
+///
+/// Hosts call this at the beginning of each block, before processing any
+/// message relying on the localhost client.
+pub fn update_localhost_client<Ctx>(ctx: &mut Ctx) -> Result<(), ContextError>
+where
+    Ctx: ExecutionContext,
+    Ctx::E: LocalhostValidationContext,
+    <Ctx::E as ClientValidationContext>::ClientStateRef: From<ClientStateType>,
+    <Ctx::E as ClientValidationContext>::ConsensusStateRef: From<ConsensusStateType>,
+{
+    let client_id = ClientId::localhost();
+    let client_ctx = ctx.get_client_execution_context();
+
+    let client_state: Any = client_ctx.client_state(&client_id)?.into();
+    let client_state = ClientStateType::try_from(client_state)?;
+
+    update_state(&client_state, client_ctx, &client_id)?;
+
+    Ok(())
+}
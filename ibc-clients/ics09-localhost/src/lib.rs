@@ -0,0 +1,62 @@
+//! ICS-09 localhost light client implementation.
+//!
+//! The localhost client lets a chain open IBC channels to itself, e.g. to
+//! test applications or to have modules of the same chain communicate over
+//! IBC. Following ibc-go, there is a single localhost client, with the
+//! sentinel identifier `09-localhost`, and a single connection using it,
+//! `connection-localhost`, which is opened when the client is created rather
+//! than through a handshake.
+//!
+//! Rather than checking proofs against a root, the client verifies that the
+//! host's own store holds the expected values. Proofs submitted for the
+//! localhost client must therefore be the [`SENTINEL_PROOF`], and the host
+//! must implement [`LocalhostValidationContext`](context::LocalhostValidationContext)
+//! to give the client access to its store.
+//!
+//! The host creates the client and its connection with
+//! [`create_localhost_client`](host::create_localhost_client), and advances
+//! the client to its current height at the beginning of each block with
+//! [`update_localhost_client`](host::update_localhost_client). Only the
+//! consensus state at the latest height is kept, so proofs must be submitted
+//! at the latest height of the client.
+#![no_std]
+#![forbid(unsafe_code)]
+#![cfg_attr(not(test), deny(clippy::unwrap_used))]
+#![cfg_attr(not(test), deny(clippy::disallowed_methods, clippy::disallowed_types))]
+#![deny(
+    warnings,
+    trivial_casts,
+    trivial_numeric_casts,
+    unused_import_braces,
+    unused_qualifications,
+    rust_2018_idioms
+)]
+
+extern crate alloc;
+
+#[cfg(any(test, feature = "std"))]
+extern crate std;
+
+pub mod client_state;
+pub mod consensus_state;
+pub mod context;
+pub mod error;
+pub mod host;
+pub mod types;
+
+use core::str::FromStr;
+
+use ibc_core_host::types::identifiers::ClientType;
+
+pub const LOCALHOST_CLIENT_TYPE: &str = "09-localhost";
+
+/// The proof to submit for any value verified by the localhost client.
+///
+/// Empty proofs are rejected by the core message types, so relayers use this
+/// placeholder instead.
+pub const SENTINEL_PROOF: &[u8] = &[0x01];
+
+/// Returns the localhost `ClientType`
+pub fn client_type() -> ClientType {
+    ClientType::from_str(LOCALHOST_CLIENT_TYPE).expect("Never fails because it's valid")
+}
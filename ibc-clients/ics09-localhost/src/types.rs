@@ -0,0 +1,9 @@
+//! Data structures and domain types of the localhost client.
+
+mod client_state;
+mod consensus_state;
+
+pub use client_state::*;
+pub use consensus_state::*;
+
+pub mod proto;
@@ -0,0 +1,91 @@
+//! Defines the client state type for the localhost client.
+
+use ibc_core_client::types::error::ClientError;
+use ibc_core_client::types::Height;
+use ibc_primitives::prelude::*;
+use ibc_primitives::proto::{Any, Protobuf};
+
+use super::proto::ClientState as RawLocalhostClientState;
+use crate::error::Error;
+
+pub const LOCALHOST_CLIENT_STATE_TYPE_URL: &str = "/ibc.lightclients.localhost.v2.ClientState";
+
+/// Contains the state of the localhost client, which is only the height of
+/// the host it was last updated to.
+///
+/// The localhost client cannot be frozen: it trusts the host it runs on.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ClientState {
+    pub latest_height: Height,
+}
+
+impl ClientState {
+    pub fn new(latest_height: Height) -> Self {
+        Self { latest_height }
+    }
+
+    pub fn latest_height(&self) -> Height {
+        self.latest_height
+    }
+}
+
+impl Protobuf<RawLocalhostClientState> for ClientState {}
+
+impl TryFrom<RawLocalhostClientState> for ClientState {
+    type Error = Error;
+
+    fn try_from(raw: RawLocalhostClientState) -> Result<Self, Self::Error> {
+        let latest_height = raw
+            .latest_height
+            .ok_or(Error::InvalidRawClientState {
+                reason: "missing latest height".to_string(),
+            })?
+            .try_into()
+            .map_err(|e: ClientError| Error::InvalidRawClientState {
+                reason: e.to_string(),
+            })?;
+
+        Ok(Self::new(latest_height))
+    }
+}
+
+impl From<ClientState> for RawLocalhostClientState {
+    fn from(value: ClientState) -> Self {
+        Self {
+            latest_height: Some(value.latest_height.into()),
+        }
+    }
+}
+
+impl Protobuf<Any> for ClientState {}
+
+impl TryFrom<Any> for ClientState {
+    type Error = ClientError;
+
+    fn try_from(raw: Any) -> Result<Self, Self::Error> {
+        fn decode_client_state(value: &[u8]) -> Result<ClientState, ClientError> {
+            let client_state = Protobuf::<RawLocalhostClientState>::decode(value).map_err(|e| {
+                ClientError::Other {
+                    description: e.to_string(),
+                }
+            })?;
+            Ok(client_state)
+        }
+
+        match raw.type_url.as_str() {
+            LOCALHOST_CLIENT_STATE_TYPE_URL => decode_client_state(&raw.value),
+            _ => Err(ClientError::UnknownClientStateType {
+                client_state_type: raw.type_url,
+            }),
+        }
+    }
+}
+
+impl From<ClientState> for Any {
+    fn from(client_state: ClientState) -> Self {
+        Any {
+            type_url: LOCALHOST_CLIENT_STATE_TYPE_URL.to_string(),
+            value: Protobuf::<RawLocalhostClientState>::encode_vec(client_state),
+        }
+    }
+}
@@ -0,0 +1,99 @@
+//! Defines the consensus state type for the localhost client.
+
+use ibc_core_client::types::error::ClientError;
+use ibc_core_commitment_types::commitment::CommitmentRoot;
+use ibc_primitives::prelude::*;
+use ibc_primitives::proto::{Any, Protobuf};
+use ibc_primitives::Timestamp;
+
+use super::proto::ConsensusState as RawLocalhostConsensusState;
+use crate::error::Error;
+
+pub const LOCALHOST_CONSENSUS_STATE_TYPE_URL: &str =
+    "/ibc.lightclients.localhost.v2.ConsensusState";
+
+/// Defines the localhost consensus state, which only records the timestamp
+/// of the host block the client was updated at.
+///
+/// Its root is always empty: the localhost client verifies values against
+/// the host's store, not against a root.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ConsensusState {
+    pub root: CommitmentRoot,
+    pub timestamp: Timestamp,
+}
+
+impl ConsensusState {
+    pub fn new(timestamp: Timestamp) -> Self {
+        Self {
+            root: CommitmentRoot::from_bytes(&[]),
+            timestamp,
+        }
+    }
+
+    pub fn root(&self) -> &CommitmentRoot {
+        &self.root
+    }
+
+    pub fn timestamp(&self) -> Timestamp {
+        self.timestamp
+    }
+}
+
+impl Protobuf<RawLocalhostConsensusState> for ConsensusState {}
+
+impl TryFrom<RawLocalhostConsensusState> for ConsensusState {
+    type Error = Error;
+
+    fn try_from(raw: RawLocalhostConsensusState) -> Result<Self, Self::Error> {
+        let timestamp = Timestamp::from_nanoseconds(raw.timestamp).map_err(|e| {
+            Error::InvalidRawConsensusState {
+                reason: e.to_string(),
+            }
+        })?;
+
+        Ok(Self::new(timestamp))
+    }
+}
+
+impl From<ConsensusState> for RawLocalhostConsensusState {
+    fn from(value: ConsensusState) -> Self {
+        Self {
+            timestamp: value.timestamp.nanoseconds(),
+        }
+    }
+}
+
+impl Protobuf<Any> for ConsensusState {}
+
+impl TryFrom<Any> for ConsensusState {
+    type Error = ClientError;
+
+    fn try_from(raw: Any) -> Result<Self, Self::Error> {
+        fn decode_consensus_state(value: &[u8]) -> Result<ConsensusState, ClientError> {
+            let consensus_state =
+                Protobuf::<RawLocalhostConsensusState>::decode(value).map_err(|e| {
+                    ClientError::Other {
+                        description: e.to_string(),
+                    }
+                })?;
+            Ok(consensus_state)
+        }
+
+        match raw.type_url.as_str() {
+            LOCALHOST_CONSENSUS_STATE_TYPE_URL => decode_consensus_state(&raw.value),
+            _ => Err(ClientError::UnknownConsensusStateType {
+                consensus_state_type: raw.type_url,
+            }),
+        }
+    }
+}
+
+impl From<ConsensusState> for Any {
+    fn from(consensus_state: ConsensusState) -> Self {
+        Any {
+            type_url: LOCALHOST_CONSENSUS_STATE_TYPE_URL.to_string(),
+            value: Protobuf::<RawLocalhostConsensusState>::encode_vec(consensus_state),
+        }
+    }
+}
@@ -0,0 +1,49 @@
+//! Raw (protobuf) representations of the localhost client types.
+//!
+//! The client state matches the `ibc.lightclients.localhost.v2` definition
+//! of ibc-go. ibc-go keeps no consensus states for the localhost client, but
+//! the core handlers of ibc-rs look one up at the proof height, so the
+//! consensus state is defined here under the same package.
+
+use ibc_primitives::prelude::*;
+use ibc_proto::ibc::core::client::v1::Height as RawHeight;
+
+macro_rules! impl_name {
+    ($ty:ty, $name:literal) => {
+        impl prost::Name for $ty {
+            const NAME: &'static str = $name;
+            const PACKAGE: &'static str = "ibc.lightclients.localhost.v2";
+
+            fn full_name() -> String {
+                format!("{}.{}", Self::PACKAGE, Self::NAME)
+            }
+        }
+    };
+}
+
+/// ```protobuf
+/// message ClientState {
+///   ibc.core.client.v1.Height latest_height = 1;
+/// }
+/// ```
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct ClientState {
+    #[prost(message, optional, tag = "1")]
+    pub latest_height: Option<RawHeight>,
+}
+
+impl_name!(ClientState, "ClientState");
+
+/// ```protobuf
+/// message ConsensusState {
+///   uint64 timestamp = 1;
+/// }
+/// ```
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct ConsensusState {
+    /// Unix timestamp in nanoseconds of the host block.
+    #[prost(uint64, tag = "1")]
+    pub timestamp: u64,
+}
+
+impl_name!(ConsensusState, "ConsensusState");
@@ -0,0 +1,251 @@
+//! This module includes the implementations of the `ClientStateCommon`,
+//! `ClientStateValidation`, and `ClientStateExecution` traits for the GRANDPA
+//! client state.
+//!
+//! As with the other clients, the logic behind each trait method is also
+//! exposed as a standalone function, so that hosts wrapping the GRANDPA
+//! client can reuse it.
+
+mod common;
+mod execution;
+mod validation;
+
+pub use common::*;
+pub use execution::*;
+pub use validation::*;
+
+#[cfg(test)]
+mod tests {
+    use core::str::FromStr;
+
+    use ed25519_consensus::SigningKey;
+    use ibc_core_host::types::identifiers::ChainId;
+    use ibc_primitives::prelude::*;
+    use ibc_primitives::proto::Any;
+    use parity_scale_codec::Encode;
+
+    use super::*;
+    use crate::hashing::Hash;
+    use crate::trie::tests::single_entry_trie;
+    use crate::types::{
+        parachain_head_storage_key, precommit_signing_payload, timestamp_storage_key,
+        AuthorityList, ClientState, Commit, ConsensusLog, Digest, DigestItem, FinalityProof,
+        GrandpaJustification, Header, Misbehaviour, ParachainHeaderProof, Precommit,
+        ScheduledChange, SignedPrecommit, SubstrateHeader, GRANDPA_ENGINE_ID,
+    };
+
+    const PARA_ID: u32 = 2000;
+    const TIMESTAMP_MILLIS: u64 = 1_700_000_000_000;
+
+    fn signing_keys() -> Vec<SigningKey> {
+        (1..=4).map(|seed| SigningKey::from([seed; 32])).collect()
+    }
+
+    fn authorities(keys: &[SigningKey]) -> AuthorityList {
+        keys.iter()
+            .map(|key| (key.verification_key().to_bytes(), 1))
+            .collect()
+    }
+
+    fn dummy_client_state(keys: &[SigningKey]) -> ClientState {
+        ClientState::new(
+            ChainId::from_str("polkadot-0").expect("never fails"),
+            PARA_ID,
+            10,
+            [10; 32],
+            0,
+            authorities(keys),
+            100,
+        )
+        .expect("never fails")
+    }
+
+    fn finality_proof(
+        keys: &[SigningKey],
+        set_id: u64,
+        unknown_headers: Vec<SubstrateHeader>,
+    ) -> FinalityProof {
+        let target = unknown_headers.last().expect("never fails");
+        let precommit = Precommit {
+            target_hash: target.hash(),
+            target_number: target.number,
+        };
+        let precommits = keys
+            .iter()
+            .map(|key| SignedPrecommit {
+                precommit: precommit.clone(),
+                signature: key
+                    .sign(&precommit_signing_payload(&precommit, 1, set_id))
+                    .to_bytes(),
+                id: key.verification_key().to_bytes(),
+            })
+            .collect();
+        let justification = GrandpaJustification {
+            round: 1,
+            commit: Commit {
+                target_hash: target.hash(),
+                target_number: target.number,
+                precommits,
+            },
+            votes_ancestries: vec![],
+        };
+
+        FinalityProof {
+            block: target.hash(),
+            justification: justification.encode(),
+            unknown_headers,
+        }
+    }
+
+    fn relay_header(parent_hash: Hash, number: u32, state_root: Hash) -> SubstrateHeader {
+        SubstrateHeader {
+            parent_hash,
+            number,
+            state_root,
+            extrinsics_root: [0; 32],
+            digest: Digest::default(),
+        }
+    }
+
+    /// Returns a header finalizing relay block 11 which includes parachain
+    /// block 101.
+    fn dummy_header(keys: &[SigningKey], client_state: &ClientState) -> Header {
+        let (para_root, timestamp_proof) =
+            single_entry_trie(&timestamp_storage_key(), &TIMESTAMP_MILLIS.encode());
+        let parachain_header = relay_header([1; 32], 101, para_root);
+
+        let (relay_root, state_proof) = single_entry_trie(
+            &parachain_head_storage_key(PARA_ID),
+            &parachain_header.encode().encode(),
+        );
+        let relay = relay_header(client_state.latest_relay_hash, 11, relay_root);
+
+        Header {
+            parachain_headers: vec![ParachainHeaderProof {
+                relay_hash: relay.hash(),
+                parachain_header,
+                state_proof,
+                timestamp_proof,
+            }],
+            finality_proof: finality_proof(&keys[..3], 0, vec![relay]),
+        }
+    }
+
+    #[test]
+    fn client_state_any_roundtrip() {
+        let client_state = dummy_client_state(&signing_keys());
+        let any: Any = client_state.clone().into();
+        assert_eq!(
+            ClientState::try_from(any).expect("never fails"),
+            client_state
+        );
+    }
+
+    #[test]
+    fn header_any_roundtrip() {
+        let keys = signing_keys();
+        let header = dummy_header(&keys, &dummy_client_state(&keys));
+        let any: Any = header.clone().into();
+        assert_eq!(Header::try_from(any).expect("never fails"), header);
+    }
+
+    #[test]
+    fn verify_valid_header() {
+        let keys = signing_keys();
+        let client_state = dummy_client_state(&keys);
+        let header = dummy_header(&keys, &client_state);
+
+        assert!(verify_client_message(&client_state, header.clone().into()).is_ok());
+
+        let relay_headers =
+            verify_finality_proof(&client_state, &header.finality_proof).expect("never fails");
+        let consensus_state =
+            verify_parachain_header(PARA_ID, &relay_headers, &header.parachain_headers[0])
+                .expect("never fails");
+        assert_eq!(
+            consensus_state.timestamp.nanoseconds(),
+            TIMESTAMP_MILLIS * 1_000_000
+        );
+    }
+
+    #[test]
+    fn verify_header_rejects_broken_ancestry() {
+        let keys = signing_keys();
+        let client_state = dummy_client_state(&keys);
+        let mut header = dummy_header(&keys, &client_state);
+
+        let relay = relay_header([9; 32], 11, [0; 32]);
+        header.finality_proof = finality_proof(&keys[..3], 0, vec![relay]);
+
+        assert!(verify_header(&client_state, &header).is_err());
+    }
+
+    #[test]
+    fn verify_header_rejects_wrong_parachain() {
+        let keys = signing_keys();
+        let mut client_state = dummy_client_state(&keys);
+        let header = dummy_header(&keys, &client_state);
+
+        client_state.para_id = PARA_ID + 1;
+        assert!(verify_header(&client_state, &header).is_err());
+    }
+
+    #[test]
+    fn verify_finality_proof_with_authority_set_change() {
+        let keys = signing_keys();
+        let client_state = dummy_client_state(&keys);
+        let change = ScheduledChange {
+            next_authorities: authorities(&keys[..1]),
+            delay: 0,
+        };
+
+        let mut relay = relay_header(client_state.latest_relay_hash, 11, [0; 32]);
+        relay.digest.logs.push(DigestItem::Consensus(
+            GRANDPA_ENGINE_ID,
+            ConsensusLog::ScheduledChange(change.clone()).encode(),
+        ));
+        let proof = finality_proof(&keys[..3], 0, vec![relay.clone()]);
+
+        assert!(verify_finality_proof(&client_state, &proof).is_ok());
+        assert_eq!(enacted_authority_set_change(&proof), Some(change.clone()));
+
+        // the change is enacted later than the finalized block
+        relay.digest.logs = vec![DigestItem::Consensus(
+            GRANDPA_ENGINE_ID,
+            ConsensusLog::ScheduledChange(ScheduledChange { delay: 5, ..change })
+                .encode(),
+        )];
+        let proof = finality_proof(&keys[..3], 0, vec![relay]);
+        assert!(verify_finality_proof(&client_state, &proof).is_err());
+    }
+
+    #[test]
+    fn verify_misbehaviour_with_conflicting_finality() {
+        let keys = signing_keys();
+        let client_state = dummy_client_state(&keys);
+
+        let first = relay_header(client_state.latest_relay_hash, 11, [1; 32]);
+        let second = relay_header(client_state.latest_relay_hash, 11, [2; 32]);
+        let misbehaviour = Misbehaviour {
+            first_finality_proof: finality_proof(&keys[..3], 0, vec![first.clone()]),
+            second_finality_proof: finality_proof(&keys[..3], 0, vec![second]),
+        };
+        assert!(verify_client_message(&client_state, misbehaviour.clone().into()).is_ok());
+
+        let same = Misbehaviour {
+            first_finality_proof: misbehaviour.first_finality_proof.clone(),
+            second_finality_proof: finality_proof(&keys[..3], 0, vec![first]),
+        };
+        assert!(verify_misbehaviour(&client_state, &same).is_err());
+
+        let forged = Misbehaviour {
+            second_finality_proof: finality_proof(
+                &keys[..3],
+                1,
+                misbehaviour.second_finality_proof.unknown_headers.clone(),
+            ),
+            ..misbehaviour
+        };
+        assert!(verify_misbehaviour(&client_state, &forged).is_err());
+    }
+}
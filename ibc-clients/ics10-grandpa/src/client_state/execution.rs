@@ -0,0 +1,174 @@
+use ibc_core_client::context::prelude::*;
+use ibc_core_client::types::error::ClientError;
+use ibc_core_client::types::Height;
+use ibc_core_host::types::identifiers::ClientId;
+use ibc_core_host::types::path::{ClientConsensusStatePath, ClientStatePath};
+use ibc_primitives::prelude::*;
+use ibc_primitives::proto::Any;
+
+use super::{enacted_authority_set_change, verify_finality_proof, verify_parachain_header};
+use crate::error::Error;
+use crate::types::{
+    ClientState as ClientStateType, ConsensusState as ConsensusStateType, Header as GrandpaHeader,
+};
+
+impl<E> ClientStateExecution<E> for ClientStateType
+where
+    E: ClientExecutionContext,
+    E::ClientStateRef: From<ClientStateType>,
+    E::ConsensusStateRef: From<ConsensusStateType>,
+{
+    fn initialise(
+        &self,
+        ctx: &mut E,
+        client_id: &ClientId,
+        consensus_state: Any,
+    ) -> Result<(), ClientError> {
+        initialise(self, ctx, client_id, consensus_state)
+    }
+
+    fn update_state(
+        &self,
+        ctx: &mut E,
+        client_id: &ClientId,
+        header: Any,
+    ) -> Result<Vec<Height>, ClientError> {
+        update_state(self, ctx, client_id, header)
+    }
+
+    fn update_state_on_misbehaviour(
+        &self,
+        ctx: &mut E,
+        client_id: &ClientId,
+        _client_message: Any,
+    ) -> Result<(), ClientError> {
+        update_on_misbehaviour(self, ctx, client_id)
+    }
+
+    fn update_state_on_upgrade(
+        &self,
+        _ctx: &mut E,
+        _client_id: &ClientId,
+        _upgraded_client_state: Any,
+        _upgraded_consensus_state: Any,
+    ) -> Result<Height, ClientError> {
+        Err(Error::UpgradeNotSupported.into())
+    }
+}
+
+/// Seed the host store with initial client and consensus states.
+pub fn initialise<E>(
+    client_state: &ClientStateType,
+    ctx: &mut E,
+    client_id: &ClientId,
+    consensus_state: Any,
+) -> Result<(), ClientError>
+where
+    E: ClientExecutionContext,
+    E::ClientStateRef: From<ClientStateType>,
+    E::ConsensusStateRef: From<ConsensusStateType>,
+{
+    let grandpa_consensus_state = ConsensusStateType::try_from(consensus_state)?;
+    let latest_height = client_state.latest_height();
+
+    ctx.store_client_state(
+        ClientStatePath::new(client_id.clone()),
+        client_state.clone().into(),
+    )?;
+    ctx.store_consensus_state(
+        ClientConsensusStatePath::new(
+            client_id.clone(),
+            latest_height.revision_number(),
+            latest_height.revision_height(),
+        ),
+        grandpa_consensus_state.into(),
+    )?;
+
+    Ok(())
+}
+
+/// Applies a verified header: advances the latest known relay chain block,
+/// enacts the authority set change it carries if any, and stores a consensus
+/// state for each of its parachain headers.
+///
+/// Returns the heights of the newly stored consensus states.
+pub fn update_state<E>(
+    client_state: &ClientStateType,
+    ctx: &mut E,
+    client_id: &ClientId,
+    header: Any,
+) -> Result<Vec<Height>, ClientError>
+where
+    E: ClientExecutionContext,
+    E::ClientStateRef: From<ClientStateType>,
+    E::ConsensusStateRef: From<ConsensusStateType>,
+{
+    let header = GrandpaHeader::try_from(header)?;
+    let relay_headers = verify_finality_proof(client_state, &header.finality_proof)?;
+
+    let mut new_client_state = client_state.clone();
+    let mut heights = Vec::with_capacity(header.parachain_headers.len());
+
+    for proof in &header.parachain_headers {
+        let consensus_state =
+            verify_parachain_header(client_state.para_id, &relay_headers, proof)?;
+        let number = proof.parachain_header.number;
+        let height = client_state.para_height(number)?;
+
+        ctx.store_consensus_state(
+            ClientConsensusStatePath::new(
+                client_id.clone(),
+                height.revision_number(),
+                height.revision_height(),
+            ),
+            consensus_state.into(),
+        )?;
+
+        new_client_state.latest_para_height = new_client_state.latest_para_height.max(number);
+        heights.push(height);
+    }
+
+    let finalized = header
+        .finality_proof
+        .unknown_headers
+        .last()
+        .ok_or_else(|| Error::InvalidAncestry {
+            reason: "finality proof contains no unknown headers".to_string(),
+        })?;
+    new_client_state.latest_relay_height = finalized.number;
+    new_client_state.latest_relay_hash = header.finality_proof.block;
+
+    if let Some(change) = enacted_authority_set_change(&header.finality_proof) {
+        new_client_state.current_set_id = new_client_state.current_set_id.saturating_add(1);
+        new_client_state.current_authorities = change.next_authorities;
+    }
+
+    ctx.store_client_state(
+        ClientStatePath::new(client_id.clone()),
+        new_client_state.into(),
+    )?;
+
+    Ok(heights)
+}
+
+/// Freezes the client after misbehaviour has been detected and verified.
+pub fn update_on_misbehaviour<E>(
+    client_state: &ClientStateType,
+    ctx: &mut E,
+    client_id: &ClientId,
+) -> Result<(), ClientError>
+where
+    E: ClientExecutionContext,
+    E::ClientStateRef: From<ClientStateType>,
+{
+    let frozen_client_state = client_state
+        .clone()
+        .with_frozen_height(client_state.latest_height());
+
+    ctx.store_client_state(
+        ClientStatePath::new(client_id.clone()),
+        frozen_client_state.into(),
+    )?;
+
+    Ok(())
+}
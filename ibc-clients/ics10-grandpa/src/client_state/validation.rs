@@ -0,0 +1,324 @@
+use alloc::collections::BTreeMap;
+
+use ibc_core_client::context::client_state::ClientStateValidation;
+use ibc_core_client::context::consensus_state::ConsensusState as _;
+use ibc_core_client::context::ClientValidationContext;
+use ibc_core_client::types::error::ClientError;
+use ibc_core_client::types::Status;
+use ibc_core_commitment_types::commitment::CommitmentRoot;
+use ibc_core_host::types::identifiers::ClientId;
+use ibc_core_host::types::path::ClientConsensusStatePath;
+use ibc_primitives::prelude::*;
+use ibc_primitives::proto::Any;
+use ibc_primitives::Timestamp;
+use parity_scale_codec::{Decode, Encode};
+
+use crate::error::Error;
+use crate::hashing::Hash;
+use crate::trie::{self, hex};
+use crate::types::{
+    parachain_head_storage_key, timestamp_storage_key, ClientState as ClientStateType,
+    ConsensusState as ConsensusStateType, FinalityProof, Header as GrandpaHeader,
+    Misbehaviour as GrandpaMisbehaviour, ParachainHeaderProof, ScheduledChange, SubstrateHeader,
+    GRANDPA_HEADER_TYPE_URL, GRANDPA_MISBEHAVIOUR_TYPE_URL,
+};
+
+impl<V> ClientStateValidation<V> for ClientStateType
+where
+    V: ClientValidationContext,
+{
+    fn verify_client_message(
+        &self,
+        _ctx: &V,
+        _client_id: &ClientId,
+        client_message: Any,
+    ) -> Result<(), ClientError> {
+        verify_client_message(self, client_message)
+    }
+
+    fn check_for_misbehaviour(
+        &self,
+        ctx: &V,
+        client_id: &ClientId,
+        client_message: Any,
+    ) -> Result<bool, ClientError> {
+        check_for_misbehaviour(self, ctx, client_id, client_message)
+    }
+
+    fn status(&self, _ctx: &V, _client_id: &ClientId) -> Result<Status, ClientError> {
+        Ok(status(self))
+    }
+}
+
+/// Verify the client message as part of the client state validation process.
+///
+/// Note that this function is typically implemented as part of the
+/// [`ClientStateValidation`] trait, but has been made a standalone function
+/// in order to make the ClientState APIs more flexible.
+pub fn verify_client_message(
+    client_state: &ClientStateType,
+    client_message: Any,
+) -> Result<(), ClientError> {
+    match client_message.type_url.as_str() {
+        GRANDPA_HEADER_TYPE_URL => {
+            let header = GrandpaHeader::try_from(client_message)?;
+            verify_header(client_state, &header)
+        }
+        GRANDPA_MISBEHAVIOUR_TYPE_URL => {
+            let misbehaviour = GrandpaMisbehaviour::try_from(client_message)?;
+            verify_misbehaviour(client_state, &misbehaviour)
+        }
+        _ => Err(ClientError::InvalidUpdateClientMessage),
+    }
+}
+
+/// Verifies that `header` finalizes a relay chain block descending from the
+/// latest known one under the current authority set, and that each of its
+/// parachain headers is included in one of the newly finalized relay chain
+/// blocks.
+pub fn verify_header(
+    client_state: &ClientStateType,
+    header: &GrandpaHeader,
+) -> Result<(), ClientError> {
+    if client_state.is_frozen() {
+        return Err(Error::FrozenClient.into());
+    }
+
+    let relay_headers = verify_finality_proof(client_state, &header.finality_proof)?;
+
+    for parachain_header in &header.parachain_headers {
+        verify_parachain_header(client_state.para_id, &relay_headers, parachain_header)?;
+    }
+
+    Ok(())
+}
+
+/// Verifies a finality proof against the client state and returns the newly
+/// finalized relay chain headers, indexed by their hash.
+///
+/// The justification must be signed by the current authority set, and the
+/// unknown headers of the proof must form a chain from the latest known
+/// relay chain block (exclusive) up to the finalized block (inclusive). The
+/// finalized segment may enact at most one authority set change, and only at
+/// the finalized block itself, as later blocks would be finalized by the next
+/// authority set.
+pub fn verify_finality_proof<'a>(
+    client_state: &ClientStateType,
+    finality_proof: &'a FinalityProof,
+) -> Result<BTreeMap<Hash, &'a SubstrateHeader>, Error> {
+    let justification = finality_proof.justification()?;
+
+    if justification.commit.target_hash != finality_proof.block {
+        return Err(Error::MismatchedJustificationTarget {
+            target: hex(&justification.commit.target_hash),
+            block: hex(&finality_proof.block),
+        });
+    }
+
+    if justification.commit.target_number <= client_state.latest_relay_height {
+        return Err(Error::StaleRelayBlock {
+            number: justification.commit.target_number,
+            latest: client_state.latest_relay_height,
+        });
+    }
+
+    justification.verify(
+        client_state.current_set_id,
+        &client_state.current_authorities,
+    )?;
+
+    let mut parent_hash = client_state.latest_relay_hash;
+    let mut relay_headers = BTreeMap::new();
+
+    for header in &finality_proof.unknown_headers {
+        if header.parent_hash != parent_hash {
+            return Err(Error::InvalidAncestry {
+                reason: format!("block {} does not extend its predecessor", header.number),
+            });
+        }
+
+        if header.forced_change().is_some() {
+            return Err(Error::InvalidAuthoritySet {
+                reason: "forced authority set changes are not supported".to_string(),
+            });
+        }
+
+        parent_hash = header.hash();
+        relay_headers.insert(parent_hash, header);
+    }
+
+    if parent_hash != finality_proof.block {
+        return Err(Error::InvalidAncestry {
+            reason: "unknown headers do not end at the finalized block".to_string(),
+        });
+    }
+
+    let changes = finality_proof
+        .unknown_headers
+        .iter()
+        .filter_map(|header| {
+            header
+                .scheduled_change()
+                .map(|change| (header.number, change))
+        })
+        .collect::<Vec<_>>();
+
+    match changes.as_slice() {
+        [] => {}
+        [(number, change)]
+            if number.checked_add(change.delay) == Some(justification.commit.target_number) => {}
+        _ => {
+            return Err(Error::InvalidAuthoritySet {
+                reason: "authority set changes must be enacted at the finalized block"
+                    .to_string(),
+            })
+        }
+    }
+
+    Ok(relay_headers)
+}
+
+/// Returns the authority set change enacted by a verified finality proof, if
+/// any.
+pub fn enacted_authority_set_change(finality_proof: &FinalityProof) -> Option<ScheduledChange> {
+    finality_proof
+        .unknown_headers
+        .iter()
+        .find_map(SubstrateHeader::scheduled_change)
+}
+
+/// Verifies that `proof.parachain_header` is the head of the parachain
+/// `para_id` in the state of the relay chain block `proof.relay_hash`, and
+/// returns the consensus state it implies.
+///
+/// The timestamp of the consensus state is read from the `Timestamp::Now`
+/// storage item of the parachain, which holds milliseconds.
+pub fn verify_parachain_header(
+    para_id: u32,
+    relay_headers: &BTreeMap<Hash, &SubstrateHeader>,
+    proof: &ParachainHeaderProof,
+) -> Result<ConsensusStateType, Error> {
+    let relay_header = relay_headers
+        .get(&proof.relay_hash)
+        .ok_or_else(|| Error::UnknownRelayBlock {
+            hash: hex(&proof.relay_hash),
+        })?;
+
+    // `Paras::Heads` stores the head data, i.e. the encoded header, as a
+    // length-prefixed byte string.
+    let head_data = proof.parachain_header.encode().encode();
+
+    trie::verify_membership(
+        &relay_header.state_root,
+        &proof.state_proof,
+        &parachain_head_storage_key(para_id),
+        &head_data,
+    )
+    .map_err(|e| Error::InvalidParachainHeader {
+        reason: e.to_string(),
+    })?;
+
+    let state_root = proof.parachain_header.state_root;
+
+    let timestamp = trie::read_proof_check(
+        &state_root,
+        &proof.timestamp_proof,
+        &timestamp_storage_key(),
+    )?
+    .ok_or_else(|| Error::InvalidTimestampProof {
+        reason: "timestamp is absent from the parachain state".to_string(),
+    })?;
+
+    let millis = u64::decode(&mut timestamp.as_slice())?;
+    let timestamp = millis
+        .checked_mul(1_000_000)
+        .and_then(|nanos| Timestamp::from_nanoseconds(nanos).ok())
+        .ok_or_else(|| Error::InvalidTimestampProof {
+            reason: format!("invalid timestamp {millis}"),
+        })?;
+
+    Ok(ConsensusStateType::new(
+        CommitmentRoot::from_bytes(&state_root),
+        timestamp,
+    ))
+}
+
+/// Verifies that both finality proofs of `misbehaviour` are justified by the
+/// current authority set.
+pub fn verify_misbehaviour(
+    client_state: &ClientStateType,
+    misbehaviour: &GrandpaMisbehaviour,
+) -> Result<(), ClientError> {
+    misbehaviour.validate_basic()?;
+
+    for finality_proof in [
+        &misbehaviour.first_finality_proof,
+        &misbehaviour.second_finality_proof,
+    ] {
+        let justification = finality_proof.justification()?;
+
+        if justification.commit.target_hash != finality_proof.block {
+            return Err(Error::MismatchedJustificationTarget {
+                target: hex(&justification.commit.target_hash),
+                block: hex(&finality_proof.block),
+            }
+            .into());
+        }
+
+        justification.verify(
+            client_state.current_set_id,
+            &client_state.current_authorities,
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Checks for misbehaviour in the given client message. A verified
+/// `Misbehaviour` is always evidence of misbehaviour, while a verified
+/// `Header` is if it implies a consensus state conflicting with one already
+/// stored at the same parachain height.
+pub fn check_for_misbehaviour<V>(
+    client_state: &ClientStateType,
+    ctx: &V,
+    client_id: &ClientId,
+    client_message: Any,
+) -> Result<bool, ClientError>
+where
+    V: ClientValidationContext,
+{
+    match client_message.type_url.as_str() {
+        GRANDPA_HEADER_TYPE_URL => {
+            let header = GrandpaHeader::try_from(client_message)?;
+
+            for proof in &header.parachain_headers {
+                let height = client_state.para_height(proof.parachain_header.number)?;
+                let path = ClientConsensusStatePath::new(
+                    client_id.clone(),
+                    height.revision_number(),
+                    height.revision_height(),
+                );
+
+                if let Ok(existing) = ctx.consensus_state(&path) {
+                    if existing.root().as_bytes() != proof.parachain_header.state_root {
+                        return Ok(true);
+                    }
+                }
+            }
+
+            Ok(false)
+        }
+        GRANDPA_MISBEHAVIOUR_TYPE_URL => Ok(true),
+        _ => Err(ClientError::InvalidUpdateClientMessage),
+    }
+}
+
+/// Returns the status of the GRANDPA client: it is active unless it has been
+/// frozen, as GRANDPA finality does not expire.
+pub fn status(client_state: &ClientStateType) -> Status {
+    if client_state.is_frozen() {
+        Status::Frozen
+    } else {
+        Status::Active
+    }
+}
@@ -0,0 +1,91 @@
+//! Defines the GRANDPA light client's error type
+
+use displaydoc::Display;
+use ibc_core_client::types::error::ClientError;
+use ibc_primitives::prelude::*;
+use prost::DecodeError;
+
+/// The main error type
+#[derive(Debug, Display)]
+pub enum Error {
+    /// invalid raw client state: `{reason}`
+    InvalidRawClientState { reason: String },
+    /// invalid raw consensus state: `{reason}`
+    InvalidRawConsensusState { reason: String },
+    /// invalid raw header: `{reason}`
+    InvalidRawHeader { reason: String },
+    /// invalid raw misbehaviour: `{reason}`
+    InvalidRawMisbehaviour { reason: String },
+    /// SCALE decoding error: `{reason}`
+    ScaleDecode { reason: String },
+    /// protobuf decoding error: `{0}`
+    Decode(DecodeError),
+    /// justification targets block `{target}` but the finality proof is for block `{block}`
+    MismatchedJustificationTarget { target: String, block: String },
+    /// invalid justification: `{reason}`
+    InvalidJustification { reason: String },
+    /// insufficient precommit weight: got `{got}`, need at least `{needed}`
+    InsufficientPrecommitWeight { got: u64, needed: u64 },
+    /// invalid authority set: `{reason}`
+    InvalidAuthoritySet { reason: String },
+    /// relay chain block `{number}` is not higher than the latest known relay chain block `{latest}`
+    StaleRelayBlock { number: u32, latest: u32 },
+    /// unknown headers do not connect the latest known relay chain block to the finalized block: `{reason}`
+    InvalidAncestry { reason: String },
+    /// relay chain block `{hash}` is not part of the finality proof
+    UnknownRelayBlock { hash: String },
+    /// parachain header is not included in the relay chain state: `{reason}`
+    InvalidParachainHeader { reason: String },
+    /// invalid timestamp proof: `{reason}`
+    InvalidTimestampProof { reason: String },
+    /// invalid storage proof: `{reason}`
+    InvalidStorageProof { reason: String },
+    /// incomplete storage proof: missing node `{hash}`
+    IncompleteStorageProof { hash: String },
+    /// storage value mismatch: expected `{expected:?}`, got `{actual:?}`
+    StorageValueMismatch {
+        expected: Vec<u8>,
+        actual: Option<Vec<u8>>,
+    },
+    /// both finality proofs finalize the same block `{hash}`
+    NotMisbehaviour { hash: String },
+    /// finality proofs target different relay chain heights `{first}` and `{second}`
+    MismatchedRelayHeights { first: u32, second: u32 },
+    /// client is frozen
+    FrozenClient,
+    /// GRANDPA clients cannot be upgraded
+    UpgradeNotSupported,
+}
+
+impl core::error::Error for Error {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match &self {
+            // `prost` implements the trait only with `std`
+            #[cfg(feature = "std")]
+            Self::Decode(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<Error> for ClientError {
+    fn from(e: Error) -> Self {
+        Self::ClientSpecific {
+            description: e.to_string(),
+        }
+    }
+}
+
+impl From<DecodeError> for Error {
+    fn from(e: DecodeError) -> Self {
+        Self::Decode(e)
+    }
+}
+
+impl From<parity_scale_codec::Error> for Error {
+    fn from(e: parity_scale_codec::Error) -> Self {
+        Self::ScaleDecode {
+            reason: e.to_string(),
+        }
+    }
+}
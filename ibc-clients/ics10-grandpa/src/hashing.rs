@@ -0,0 +1,145 @@
+//! Hash functions used by Substrate chains: `blake2_256` for block hashes and
+//! trie nodes, and the `twox` family for storage keys.
+
+use blake2::digest::consts::U32;
+use blake2::{Blake2b, Digest};
+
+pub type Hash = [u8; 32];
+
+/// Blake2b hash with a 256-bit output.
+pub fn blake2_256(data: &[u8]) -> Hash {
+    Blake2b::<U32>::digest(data).into()
+}
+
+/// The 64-bit `twox` hash, i.e. the little-endian xxHash64 with seed 0.
+pub fn twox_64(data: &[u8]) -> [u8; 8] {
+    xxh64(data, 0).to_le_bytes()
+}
+
+/// The 128-bit `twox` hash, i.e. the concatenation of the little-endian
+/// xxHash64 with seeds 0 and 1.
+pub fn twox_128(data: &[u8]) -> [u8; 16] {
+    let mut out = [0; 16];
+    out[..8].copy_from_slice(&xxh64(data, 0).to_le_bytes());
+    out[8..].copy_from_slice(&xxh64(data, 1).to_le_bytes());
+    out
+}
+
+const PRIME64_1: u64 = 0x9E37_79B1_85EB_CA87;
+const PRIME64_2: u64 = 0xC2B2_AE3D_27D4_EB4F;
+const PRIME64_3: u64 = 0x1656_67B1_9E37_79F9;
+const PRIME64_4: u64 = 0x85EB_CA77_C2B2_AE63;
+const PRIME64_5: u64 = 0x27D4_EB2F_1656_67C5;
+
+fn read_u64(data: &[u8], offset: usize) -> u64 {
+    let mut bytes = [0; 8];
+    bytes.copy_from_slice(&data[offset..offset + 8]);
+    u64::from_le_bytes(bytes)
+}
+
+fn read_u32(data: &[u8], offset: usize) -> u32 {
+    let mut bytes = [0; 4];
+    bytes.copy_from_slice(&data[offset..offset + 4]);
+    u32::from_le_bytes(bytes)
+}
+
+fn round(acc: u64, input: u64) -> u64 {
+    acc.wrapping_add(input.wrapping_mul(PRIME64_2))
+        .rotate_left(31)
+        .wrapping_mul(PRIME64_1)
+}
+
+fn merge_round(acc: u64, val: u64) -> u64 {
+    (acc ^ round(0, val))
+        .wrapping_mul(PRIME64_1)
+        .wrapping_add(PRIME64_4)
+}
+
+fn xxh64(data: &[u8], seed: u64) -> u64 {
+    let len = data.len();
+    let mut offset = 0;
+
+    let mut hash = if len >= 32 {
+        let mut v1 = seed.wrapping_add(PRIME64_1).wrapping_add(PRIME64_2);
+        let mut v2 = seed.wrapping_add(PRIME64_2);
+        let mut v3 = seed;
+        let mut v4 = seed.wrapping_sub(PRIME64_1);
+
+        while offset + 32 <= len {
+            v1 = round(v1, read_u64(data, offset));
+            v2 = round(v2, read_u64(data, offset + 8));
+            v3 = round(v3, read_u64(data, offset + 16));
+            v4 = round(v4, read_u64(data, offset + 24));
+            offset += 32;
+        }
+
+        let mut hash = v1
+            .rotate_left(1)
+            .wrapping_add(v2.rotate_left(7))
+            .wrapping_add(v3.rotate_left(12))
+            .wrapping_add(v4.rotate_left(18));
+        hash = merge_round(hash, v1);
+        hash = merge_round(hash, v2);
+        hash = merge_round(hash, v3);
+        merge_round(hash, v4)
+    } else {
+        seed.wrapping_add(PRIME64_5)
+    };
+
+    hash = hash.wrapping_add(len as u64);
+
+    while offset + 8 <= len {
+        hash ^= round(0, read_u64(data, offset));
+        hash = hash
+            .rotate_left(27)
+            .wrapping_mul(PRIME64_1)
+            .wrapping_add(PRIME64_4);
+        offset += 8;
+    }
+
+    if offset + 4 <= len {
+        hash ^= u64::from(read_u32(data, offset)).wrapping_mul(PRIME64_1);
+        hash = hash
+            .rotate_left(23)
+            .wrapping_mul(PRIME64_2)
+            .wrapping_add(PRIME64_3);
+        offset += 4;
+    }
+
+    while offset < len {
+        hash ^= u64::from(data[offset]).wrapping_mul(PRIME64_5);
+        hash = hash.rotate_left(11).wrapping_mul(PRIME64_1);
+        offset += 1;
+    }
+
+    hash ^= hash >> 33;
+    hash = hash.wrapping_mul(PRIME64_2);
+    hash ^= hash >> 29;
+    hash = hash.wrapping_mul(PRIME64_3);
+    hash ^= hash >> 32;
+    hash
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hex(bytes: &[u8]) -> String {
+        bytes.iter().map(|b| format!("{b:02x}")).collect()
+    }
+
+    #[test]
+    fn twox_128_matches_substrate_storage_prefixes() {
+        assert_eq!(hex(&twox_128(b"System")), "26aa394eea5630e07c48ae0c9558cef7");
+        assert_eq!(hex(&twox_128(b"Timestamp")), "f0c365c3cf59d671eb72da0e7a4113c4");
+        assert_eq!(hex(&twox_128(b"Now")), "9f1f0515f462cdcf84e0f1d6045dfcbb");
+    }
+
+    #[test]
+    fn xxh64_long_input() {
+        // exercises the 32-byte stripe loop
+        let data = [0xab; 100];
+        assert_ne!(xxh64(&data, 0), xxh64(&data, 1));
+        assert_eq!(xxh64(&data, 0), xxh64(&data, 0));
+    }
+}
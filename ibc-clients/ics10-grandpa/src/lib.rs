@@ -0,0 +1,43 @@
+//! ICS 10: GRANDPA light client implementation.
+//!
+//! The GRANDPA client tracks a Substrate parachain through the finality of
+//! its relay chain (e.g. Polkadot or Kusama). Relay chain blocks are
+//! finalized by GRANDPA justifications signed by the current authority set,
+//! parachain headers are proven against the state root of finalized relay
+//! chain blocks, and IBC state is proven against the state root of the
+//! parachain headers.
+#![no_std]
+#![forbid(unsafe_code)]
+#![cfg_attr(not(test), deny(clippy::unwrap_used))]
+#![cfg_attr(not(test), deny(clippy::disallowed_methods, clippy::disallowed_types))]
+#![deny(
+    warnings,
+    trivial_casts,
+    trivial_numeric_casts,
+    unused_import_braces,
+    unused_qualifications,
+    rust_2018_idioms
+)]
+
+extern crate alloc;
+
+#[cfg(any(test, feature = "std"))]
+extern crate std;
+
+pub mod client_state;
+pub mod consensus_state;
+pub mod error;
+pub mod hashing;
+pub mod trie;
+pub mod types;
+
+use core::str::FromStr;
+
+use ibc_core_host::types::identifiers::ClientType;
+
+pub const GRANDPA_CLIENT_TYPE: &str = "10-grandpa";
+
+/// Returns the GRANDPA `ClientType`
+pub fn client_type() -> ClientType {
+    ClientType::from_str(GRANDPA_CLIENT_TYPE).expect("Never fails because it's valid")
+}
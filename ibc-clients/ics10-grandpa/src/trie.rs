@@ -0,0 +1,444 @@
+//! Verification of Substrate storage proofs.
+//!
+//! A storage proof is the set of encoded trie nodes visited while looking up
+//! a key in the Base-16 Modified Merkle Patricia trie of a Substrate chain.
+//! This module implements the node codec of `sp-trie` (covering both the
+//! `V0` and `V1` state versions) and looks up keys against a trusted state
+//! root using only the nodes in the proof.
+
+use alloc::collections::BTreeMap;
+
+use parity_scale_codec::{Compact, Decode};
+
+use crate::error::Error;
+use crate::hashing::{blake2_256, Hash};
+use ibc_primitives::prelude::*;
+
+const EMPTY_TRIE: u8 = 0x00;
+const ESCAPE_COMPACT_HEADER: u8 = 0x01;
+
+const LEAF_PREFIX_MASK: u8 = 0b_01 << 6;
+const BRANCH_WITHOUT_VALUE_MASK: u8 = 0b_10 << 6;
+const BRANCH_WITH_VALUE_MASK: u8 = 0b_11 << 6;
+const ALT_HASHING_LEAF_PREFIX_MASK: u8 = 0b_001 << 5;
+const ALT_HASHING_BRANCH_WITH_MASK: u8 = 0b_0001 << 4;
+
+const NIBBLE_PER_BYTE: usize = 2;
+const CHILDREN: usize = 16;
+
+/// A value stored in a trie node: either inline, or (`V1` only) as the hash
+/// of a separate proof entry.
+enum NodeValue<'a> {
+    Inline(&'a [u8]),
+    Hashed(Hash),
+}
+
+/// A reference to a child node: either the hash of a separate proof entry,
+/// or the encoded node itself when it is shorter than a hash.
+enum NodeHandle<'a> {
+    Hash(Hash),
+    Inline(&'a [u8]),
+}
+
+enum Node<'a> {
+    Empty,
+    Leaf {
+        partial: Vec<u8>,
+        value: NodeValue<'a>,
+    },
+    Branch {
+        partial: Vec<u8>,
+        value: Option<NodeValue<'a>>,
+        children: [Option<NodeHandle<'a>>; CHILDREN],
+    },
+}
+
+struct Input<'a> {
+    data: &'a [u8],
+    offset: usize,
+}
+
+impl<'a> Input<'a> {
+    fn take(&mut self, count: usize) -> Result<&'a [u8], Error> {
+        let end = self
+            .offset
+            .checked_add(count)
+            .filter(|end| *end <= self.data.len())
+            .ok_or_else(|| invalid_proof("unexpected end of node"))?;
+        let bytes = &self.data[self.offset..end];
+        self.offset = end;
+        Ok(bytes)
+    }
+
+    fn take_byte(&mut self) -> Result<u8, Error> {
+        Ok(self.take(1)?[0])
+    }
+
+    fn take_hash(&mut self) -> Result<Hash, Error> {
+        let mut hash = [0; 32];
+        hash.copy_from_slice(self.take(32)?);
+        Ok(hash)
+    }
+
+    fn take_length_prefixed(&mut self) -> Result<&'a [u8], Error> {
+        let mut remaining = &self.data[self.offset..];
+        let before = remaining.len();
+        let Compact(len) = Compact::<u32>::decode(&mut remaining)?;
+        self.offset += before - remaining.len();
+        self.take(len as usize)
+    }
+
+    fn is_empty(&self) -> bool {
+        self.offset == self.data.len()
+    }
+}
+
+fn invalid_proof(reason: &str) -> Error {
+    Error::InvalidStorageProof {
+        reason: reason.to_string(),
+    }
+}
+
+/// Decodes the nibble count packed in a node header whose type occupies the
+/// `prefix_bits` most significant bits of the first byte.
+fn decode_size(first: u8, input: &mut Input<'_>, prefix_bits: u8) -> Result<usize, Error> {
+    let max_value = 255u8 >> prefix_bits;
+    let mut result = usize::from(first & max_value);
+    if result < usize::from(max_value) {
+        return Ok(result);
+    }
+    result -= 1;
+    loop {
+        let n = usize::from(input.take_byte()?);
+        if n < 255 {
+            return Ok(result + n + 1);
+        }
+        result += 255;
+    }
+}
+
+/// Decodes a partial key of `nibble_count` nibbles, left-padded with a zero
+/// nibble when the count is odd.
+fn decode_partial(input: &mut Input<'_>, nibble_count: usize) -> Result<Vec<u8>, Error> {
+    let bytes = input.take((nibble_count + 1) / NIBBLE_PER_BYTE)?;
+    let mut nibbles = Vec::with_capacity(nibble_count);
+    for (i, byte) in bytes.iter().enumerate() {
+        if i == 0 && nibble_count % NIBBLE_PER_BYTE == 1 {
+            if byte & 0xf0 != 0 {
+                return Err(invalid_proof("bad partial key padding"));
+            }
+        } else {
+            nibbles.push(byte >> 4);
+        }
+        nibbles.push(byte & 0x0f);
+    }
+    Ok(nibbles)
+}
+
+fn decode_value<'a>(input: &mut Input<'a>, hashed: bool) -> Result<NodeValue<'a>, Error> {
+    if hashed {
+        Ok(NodeValue::Hashed(input.take_hash()?))
+    } else {
+        Ok(NodeValue::Inline(input.take_length_prefixed()?))
+    }
+}
+
+fn decode_node(data: &[u8]) -> Result<Node<'_>, Error> {
+    let mut input = Input { data, offset: 0 };
+    let first = input.take_byte()?;
+
+    if first == EMPTY_TRIE {
+        return Ok(Node::Empty);
+    }
+    if first == ESCAPE_COMPACT_HEADER {
+        return Err(invalid_proof("compact proofs are not supported"));
+    }
+
+    let (is_leaf, has_value, hashed_value, prefix_bits) = match first & (0b11 << 6) {
+        LEAF_PREFIX_MASK => (true, true, false, 2),
+        BRANCH_WITHOUT_VALUE_MASK => (false, false, false, 2),
+        BRANCH_WITH_VALUE_MASK => (false, true, false, 2),
+        _ => match first & (0b111 << 5) {
+            ALT_HASHING_LEAF_PREFIX_MASK => (true, true, true, 3),
+            _ if first & (0b1111 << 4) == ALT_HASHING_BRANCH_WITH_MASK => (false, true, true, 4),
+            _ => return Err(invalid_proof("unknown node header")),
+        },
+    };
+
+    let nibble_count = decode_size(first, &mut input, prefix_bits)?;
+    let partial = decode_partial(&mut input, nibble_count)?;
+
+    let node = if is_leaf {
+        Node::Leaf {
+            partial,
+            value: decode_value(&mut input, hashed_value)?,
+        }
+    } else {
+        let bitmap_bytes = input.take(2)?;
+        let bitmap = u16::from_le_bytes([bitmap_bytes[0], bitmap_bytes[1]]);
+        let value = if has_value {
+            Some(decode_value(&mut input, hashed_value)?)
+        } else {
+            None
+        };
+        let mut children: [Option<NodeHandle<'_>>; CHILDREN] = Default::default();
+        for (i, child) in children.iter_mut().enumerate() {
+            if bitmap & (1 << i) != 0 {
+                let encoded = input.take_length_prefixed()?;
+                *child = Some(if encoded.len() == 32 {
+                    let mut hash = [0; 32];
+                    hash.copy_from_slice(encoded);
+                    NodeHandle::Hash(hash)
+                } else {
+                    NodeHandle::Inline(encoded)
+                });
+            }
+        }
+        Node::Branch {
+            partial,
+            value,
+            children,
+        }
+    };
+
+    if !input.is_empty() {
+        return Err(invalid_proof("trailing bytes after node"));
+    }
+
+    Ok(node)
+}
+
+fn to_nibbles(key: &[u8]) -> Vec<u8> {
+    key.iter().flat_map(|b| [b >> 4, b & 0x0f]).collect()
+}
+
+pub(crate) fn hex(bytes: &[u8]) -> String {
+    use core::fmt::Write;
+
+    bytes.iter().fold(String::new(), |mut out, b| {
+        let _ = write!(out, "{b:02x}");
+        out
+    })
+}
+
+/// Looks up `key` in the trie with the given `root`, using only the nodes in
+/// `proof`.
+///
+/// Returns `Ok(None)` if the proof shows that the key is absent, and an error
+/// if the proof is malformed or lacks a node needed to complete the lookup.
+pub fn read_proof_check(
+    root: &Hash,
+    proof: &[Vec<u8>],
+    key: &[u8],
+) -> Result<Option<Vec<u8>>, Error> {
+    let nodes: BTreeMap<Hash, &[u8]> = proof
+        .iter()
+        .map(|node| (blake2_256(node), node.as_slice()))
+        .collect();
+
+    let get = |hash: &Hash| -> Result<&[u8], Error> {
+        nodes
+            .get(hash)
+            .copied()
+            .ok_or_else(|| Error::IncompleteStorageProof { hash: hex(hash) })
+    };
+
+    let resolve_value = |value: NodeValue<'_>| -> Result<Vec<u8>, Error> {
+        match value {
+            NodeValue::Inline(bytes) => Ok(bytes.to_vec()),
+            NodeValue::Hashed(hash) => Ok(get(&hash)?.to_vec()),
+        }
+    };
+
+    let nibbles = to_nibbles(key);
+    let mut remaining = nibbles.as_slice();
+    let mut encoded = get(root)?;
+
+    loop {
+        match decode_node(encoded)? {
+            Node::Empty => return Ok(None),
+            Node::Leaf { partial, value } => {
+                if remaining == partial.as_slice() {
+                    return resolve_value(value).map(Some);
+                }
+                return Ok(None);
+            }
+            Node::Branch {
+                partial,
+                value,
+                children,
+            } => {
+                if !remaining.starts_with(&partial) {
+                    return Ok(None);
+                }
+                remaining = &remaining[partial.len()..];
+
+                let Some((&index, rest)) = remaining.split_first() else {
+                    return value.map(resolve_value).transpose();
+                };
+
+                encoded = match &children[usize::from(index)] {
+                    None => return Ok(None),
+                    Some(NodeHandle::Hash(hash)) => get(hash)?,
+                    Some(NodeHandle::Inline(node)) => node,
+                };
+                remaining = rest;
+            }
+        }
+    }
+}
+
+/// Verifies that `key` maps to `expected` in the trie with the given `root`.
+pub fn verify_membership(
+    root: &Hash,
+    proof: &[Vec<u8>],
+    key: &[u8],
+    expected: &[u8],
+) -> Result<(), Error> {
+    match read_proof_check(root, proof, key)? {
+        Some(value) if value == expected => Ok(()),
+        actual => Err(Error::StorageValueMismatch {
+            expected: expected.to_vec(),
+            actual,
+        }),
+    }
+}
+
+/// Verifies that `key` is absent from the trie with the given `root`.
+pub fn verify_non_membership(root: &Hash, proof: &[Vec<u8>], key: &[u8]) -> Result<(), Error> {
+    match read_proof_check(root, proof, key)? {
+        None => Ok(()),
+        actual => Err(Error::StorageValueMismatch {
+            expected: Vec::new(),
+            actual,
+        }),
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use parity_scale_codec::Encode;
+
+    use super::*;
+
+    /// Returns the root and proof of a trie holding a single entry.
+    pub(crate) fn single_entry_trie(key: &[u8], value: &[u8]) -> (Hash, Vec<Vec<u8>>) {
+        let node = leaf(&to_nibbles(key), value);
+        (blake2_256(&node), vec![node])
+    }
+
+    /// Encodes a leaf node with an inline value.
+    fn leaf(nibbles: &[u8], value: &[u8]) -> Vec<u8> {
+        let mut node = header(LEAF_PREFIX_MASK, 2, nibbles.len());
+        node.extend(partial(nibbles));
+        node.extend(value.to_vec().encode());
+        node
+    }
+
+    /// Encodes a branch node without a value.
+    fn branch(nibbles: &[u8], children: &[(u8, Vec<u8>)]) -> Vec<u8> {
+        let mut node = header(BRANCH_WITHOUT_VALUE_MASK, 2, nibbles.len());
+        node.extend(partial(nibbles));
+        let bitmap = children.iter().fold(0u16, |acc, (i, _)| acc | (1 << i));
+        node.extend(bitmap.to_le_bytes());
+        for (_, child) in children {
+            node.extend(child.encode());
+        }
+        node
+    }
+
+    fn header(mask: u8, prefix_bits: u8, nibble_count: usize) -> Vec<u8> {
+        let max = usize::from(255u8 >> prefix_bits);
+        if nibble_count < max {
+            return vec![mask | nibble_count as u8];
+        }
+        let mut out = vec![mask | max as u8];
+        let mut rest = nibble_count - max;
+        while rest >= 255 {
+            out.push(255);
+            rest -= 255;
+        }
+        out.push(rest as u8);
+        out
+    }
+
+    fn partial(nibbles: &[u8]) -> Vec<u8> {
+        let mut padded = Vec::new();
+        if nibbles.len() % 2 == 1 {
+            padded.push(0);
+        }
+        padded.extend_from_slice(nibbles);
+        padded.chunks(2).map(|c| (c[0] << 4) | c[1]).collect()
+    }
+
+    #[test]
+    fn single_leaf_proof() {
+        let key = b"key";
+        let node = leaf(&to_nibbles(key), b"value");
+        let root = blake2_256(&node);
+        let proof = vec![node];
+
+        assert_eq!(
+            read_proof_check(&root, &proof, key).expect("valid proof"),
+            Some(b"value".to_vec())
+        );
+        assert_eq!(
+            read_proof_check(&root, &proof, b"kez").expect("valid proof"),
+            None
+        );
+        assert!(verify_membership(&root, &proof, key, b"value").is_ok());
+        assert!(verify_membership(&root, &proof, key, b"other").is_err());
+        assert!(verify_non_membership(&root, &proof, b"nope").is_ok());
+    }
+
+    #[test]
+    fn branch_proof_with_hashed_and_inline_children() {
+        // Keys 0xab.. and 0xac.. share the nibble `a`; the branch at `a` has
+        // children `b` (a hashed leaf) and `c` (an inline leaf).
+        let long_value = [7u8; 40];
+        let hashed_leaf = leaf(&[0x1, 0x2], &long_value);
+        let inline_leaf = leaf(&[0x3, 0x4], b"v");
+        assert!(inline_leaf.len() < 32);
+
+        let node = branch(
+            &[0xa],
+            &[
+                (0xb, blake2_256(&hashed_leaf).to_vec()),
+                (0xc, inline_leaf),
+            ],
+        );
+        let root = blake2_256(&node);
+        let proof = vec![node.clone(), hashed_leaf];
+
+        assert_eq!(
+            read_proof_check(&root, &proof, &[0xab, 0x12]).expect("valid proof"),
+            Some(long_value.to_vec())
+        );
+        assert_eq!(
+            read_proof_check(&root, &proof, &[0xac, 0x34]).expect("valid proof"),
+            Some(b"v".to_vec())
+        );
+        assert_eq!(
+            read_proof_check(&root, &proof, &[0xad, 0x00]).expect("valid proof"),
+            None
+        );
+
+        // without the hashed leaf the lookup cannot complete
+        assert!(matches!(
+            read_proof_check(&root, &[node], &[0xab, 0x12]),
+            Err(Error::IncompleteStorageProof { .. })
+        ));
+    }
+
+    #[test]
+    fn long_partial_key_header() {
+        let key = [0x55u8; 40];
+        let node = leaf(&to_nibbles(&key), b"value");
+        let root = blake2_256(&node);
+
+        assert_eq!(
+            read_proof_check(&root, &[node], &key).expect("valid proof"),
+            Some(b"value".to_vec())
+        );
+    }
+}
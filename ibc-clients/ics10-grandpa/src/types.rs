@@ -0,0 +1,17 @@
+//! Data structures and domain types of the GRANDPA light client.
+
+mod client_state;
+mod consensus_state;
+mod header;
+mod justification;
+mod misbehaviour;
+mod substrate;
+
+pub use client_state::*;
+pub use consensus_state::*;
+pub use header::*;
+pub use justification::*;
+pub use misbehaviour::*;
+pub use substrate::*;
+
+pub mod proto;
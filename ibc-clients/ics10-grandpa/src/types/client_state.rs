@@ -0,0 +1,202 @@
+//! Defines the client state type for the GRANDPA light client.
+
+use core::str::FromStr;
+
+use ibc_core_client::types::error::ClientError;
+use ibc_core_client::types::Height;
+use ibc_core_host::types::identifiers::ChainId;
+use ibc_primitives::prelude::*;
+use ibc_primitives::proto::{Any, Protobuf};
+
+use super::proto::{Authority as RawAuthority, ClientState as RawGrandpaClientState};
+use super::substrate::AuthorityList;
+use crate::error::Error;
+use crate::hashing::Hash;
+
+pub const GRANDPA_CLIENT_STATE_TYPE_URL: &str = "/ibc.lightclients.grandpa.v1.ClientState";
+
+/// Contains the state of a GRANDPA client.
+///
+/// The client follows the relay chain through its latest finalized block and
+/// GRANDPA authority set, and exposes the heights of the tracked parachain:
+/// a client height has the parachain id as its revision number and the
+/// parachain block number as its revision height.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ClientState {
+    pub chain_id: ChainId,
+    pub para_id: u32,
+    pub latest_relay_height: u32,
+    pub latest_relay_hash: Hash,
+    pub current_set_id: u64,
+    pub current_authorities: AuthorityList,
+    pub latest_para_height: u32,
+    pub frozen_height: Option<Height>,
+}
+
+impl ClientState {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        chain_id: ChainId,
+        para_id: u32,
+        latest_relay_height: u32,
+        latest_relay_hash: Hash,
+        current_set_id: u64,
+        current_authorities: AuthorityList,
+        latest_para_height: u32,
+    ) -> Result<Self, Error> {
+        let client_state = Self {
+            chain_id,
+            para_id,
+            latest_relay_height,
+            latest_relay_hash,
+            current_set_id,
+            current_authorities,
+            latest_para_height,
+            frozen_height: None,
+        };
+        client_state.validate()?;
+        Ok(client_state)
+    }
+
+    pub fn validate(&self) -> Result<(), Error> {
+        if self.current_authorities.is_empty() {
+            return Err(Error::InvalidRawClientState {
+                reason: "authority set cannot be empty".to_string(),
+            });
+        }
+
+        if self.latest_para_height == 0 {
+            return Err(Error::InvalidRawClientState {
+                reason: "latest parachain height cannot be zero".to_string(),
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Returns the client height corresponding to the parachain block
+    /// `para_height`.
+    pub fn para_height(&self, para_height: u32) -> Result<Height, ClientError> {
+        Height::new(u64::from(self.para_id), u64::from(para_height))
+    }
+
+    pub fn latest_height(&self) -> Height {
+        self.para_height(self.latest_para_height)
+            .expect("latest parachain height is validated to be non-zero")
+    }
+
+    pub fn is_frozen(&self) -> bool {
+        self.frozen_height.is_some()
+    }
+
+    pub fn with_frozen_height(self, h: Height) -> Self {
+        Self {
+            frozen_height: Some(h),
+            ..self
+        }
+    }
+}
+
+impl Protobuf<RawGrandpaClientState> for ClientState {}
+
+impl TryFrom<RawGrandpaClientState> for ClientState {
+    type Error = Error;
+
+    fn try_from(raw: RawGrandpaClientState) -> Result<Self, Self::Error> {
+        let chain_id = ChainId::from_str(&raw.chain_id).map_err(|e| Error::InvalidRawClientState {
+            reason: e.to_string(),
+        })?;
+
+        let latest_relay_hash =
+            raw.latest_relay_hash
+                .try_into()
+                .map_err(|_| Error::InvalidRawClientState {
+                    reason: "latest relay hash must be 32 bytes".to_string(),
+                })?;
+
+        let current_authorities = raw
+            .current_authorities
+            .into_iter()
+            .map(|authority| {
+                let id = authority.public_key.try_into().map_err(|_| {
+                    Error::InvalidRawClientState {
+                        reason: "authority public key must be 32 bytes".to_string(),
+                    }
+                })?;
+                Ok((id, authority.weight))
+            })
+            .collect::<Result<_, Error>>()?;
+
+        let frozen_height = raw
+            .frozen_height
+            .and_then(|raw_height| raw_height.try_into().ok());
+
+        let client_state = Self {
+            chain_id,
+            para_id: raw.para_id,
+            latest_relay_height: raw.latest_relay_height,
+            latest_relay_hash,
+            current_set_id: raw.current_set_id,
+            current_authorities,
+            latest_para_height: raw.latest_para_height,
+            frozen_height,
+        };
+        client_state.validate()?;
+        Ok(client_state)
+    }
+}
+
+impl From<ClientState> for RawGrandpaClientState {
+    fn from(value: ClientState) -> Self {
+        Self {
+            chain_id: value.chain_id.to_string(),
+            para_id: value.para_id,
+            latest_relay_height: value.latest_relay_height,
+            latest_relay_hash: value.latest_relay_hash.to_vec(),
+            current_set_id: value.current_set_id,
+            current_authorities: value
+                .current_authorities
+                .into_iter()
+                .map(|(id, weight)| RawAuthority {
+                    public_key: id.to_vec(),
+                    weight,
+                })
+                .collect(),
+            latest_para_height: value.latest_para_height,
+            frozen_height: value.frozen_height.map(|height| height.into()),
+        }
+    }
+}
+
+impl Protobuf<Any> for ClientState {}
+
+impl TryFrom<Any> for ClientState {
+    type Error = ClientError;
+
+    fn try_from(raw: Any) -> Result<Self, Self::Error> {
+        fn decode_client_state(value: &[u8]) -> Result<ClientState, ClientError> {
+            let client_state = Protobuf::<RawGrandpaClientState>::decode(value).map_err(|e| {
+                ClientError::Other {
+                    description: e.to_string(),
+                }
+            })?;
+            Ok(client_state)
+        }
+
+        match raw.type_url.as_str() {
+            GRANDPA_CLIENT_STATE_TYPE_URL => decode_client_state(&raw.value),
+            _ => Err(ClientError::UnknownClientStateType {
+                client_state_type: raw.type_url,
+            }),
+        }
+    }
+}
+
+impl From<ClientState> for Any {
+    fn from(client_state: ClientState) -> Self {
+        Any {
+            type_url: GRANDPA_CLIENT_STATE_TYPE_URL.to_string(),
+            value: Protobuf::<RawGrandpaClientState>::encode_vec(client_state),
+        }
+    }
+}
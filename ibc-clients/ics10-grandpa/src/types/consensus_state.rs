@@ -0,0 +1,114 @@
+//! Defines the consensus state type for the GRANDPA light client.
+
+use ibc_core_client::types::error::ClientError;
+use ibc_core_commitment_types::commitment::CommitmentRoot;
+use ibc_primitives::prelude::*;
+use ibc_primitives::proto::{Any, Protobuf};
+use ibc_primitives::Timestamp;
+
+use super::proto::ConsensusState as RawGrandpaConsensusState;
+use crate::error::Error;
+use crate::hashing::Hash;
+
+pub const GRANDPA_CONSENSUS_STATE_TYPE_URL: &str = "/ibc.lightclients.grandpa.v1.ConsensusState";
+
+/// Defines the GRANDPA consensus state, i.e. the state root and timestamp of
+/// a finalized parachain block.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ConsensusState {
+    pub timestamp: Timestamp,
+    pub root: CommitmentRoot,
+}
+
+impl ConsensusState {
+    pub fn new(root: CommitmentRoot, timestamp: Timestamp) -> Self {
+        Self { timestamp, root }
+    }
+
+    pub fn root(&self) -> &CommitmentRoot {
+        &self.root
+    }
+
+    pub fn timestamp(&self) -> Timestamp {
+        self.timestamp
+    }
+
+    /// Returns the parachain state root as a trie root hash.
+    pub fn state_root(&self) -> Result<Hash, Error> {
+        state_root(&self.root)
+    }
+}
+
+/// Converts a commitment root into the 32-byte state root of a Substrate
+/// trie.
+pub fn state_root(root: &CommitmentRoot) -> Result<Hash, Error> {
+    root.as_bytes()
+        .try_into()
+        .map_err(|_| Error::InvalidStorageProof {
+            reason: "state root must be 32 bytes".to_string(),
+        })
+}
+
+impl Protobuf<RawGrandpaConsensusState> for ConsensusState {}
+
+impl TryFrom<RawGrandpaConsensusState> for ConsensusState {
+    type Error = Error;
+
+    fn try_from(raw: RawGrandpaConsensusState) -> Result<Self, Self::Error> {
+        if raw.root.len() != 32 {
+            return Err(Error::InvalidRawConsensusState {
+                reason: "root must be 32 bytes".to_string(),
+            });
+        }
+
+        let timestamp = Timestamp::from_nanoseconds(raw.timestamp).map_err(|e| {
+            Error::InvalidRawConsensusState {
+                reason: e.to_string(),
+            }
+        })?;
+
+        Ok(Self::new(CommitmentRoot::from_bytes(&raw.root), timestamp))
+    }
+}
+
+impl From<ConsensusState> for RawGrandpaConsensusState {
+    fn from(value: ConsensusState) -> Self {
+        Self {
+            timestamp: value.timestamp.nanoseconds(),
+            root: value.root.into_vec(),
+        }
+    }
+}
+
+impl Protobuf<Any> for ConsensusState {}
+
+impl TryFrom<Any> for ConsensusState {
+    type Error = ClientError;
+
+    fn try_from(raw: Any) -> Result<Self, Self::Error> {
+        fn decode_consensus_state(value: &[u8]) -> Result<ConsensusState, ClientError> {
+            let consensus_state = Protobuf::<RawGrandpaConsensusState>::decode(value).map_err(
+                |e| ClientError::Other {
+                    description: e.to_string(),
+                },
+            )?;
+            Ok(consensus_state)
+        }
+
+        match raw.type_url.as_str() {
+            GRANDPA_CONSENSUS_STATE_TYPE_URL => decode_consensus_state(&raw.value),
+            _ => Err(ClientError::UnknownConsensusStateType {
+                consensus_state_type: raw.type_url,
+            }),
+        }
+    }
+}
+
+impl From<ConsensusState> for Any {
+    fn from(consensus_state: ConsensusState) -> Self {
+        Any {
+            type_url: GRANDPA_CONSENSUS_STATE_TYPE_URL.to_string(),
+            value: Protobuf::<RawGrandpaConsensusState>::encode_vec(consensus_state),
+        }
+    }
+}
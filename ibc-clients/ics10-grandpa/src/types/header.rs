@@ -0,0 +1,142 @@
+//! Defines the header type for the GRANDPA light client.
+
+use ibc_core_client::types::error::ClientError;
+use ibc_primitives::prelude::*;
+use ibc_primitives::proto::{Any, Protobuf};
+use parity_scale_codec::{Decode, Encode};
+
+use super::justification::FinalityProof;
+use super::proto::{Header as RawGrandpaHeader, ParachainHeaderProof as RawParachainHeaderProof};
+use super::substrate::SubstrateHeader;
+use crate::error::Error;
+use crate::hashing::Hash;
+
+pub const GRANDPA_HEADER_TYPE_URL: &str = "/ibc.lightclients.grandpa.v1.Header";
+
+/// A parachain header together with the proofs tying it to a finalized relay
+/// chain block.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParachainHeaderProof {
+    /// Hash of the relay chain block whose state includes the parachain
+    /// header. It must be one of the `unknown_headers` of the finality proof.
+    pub relay_hash: Hash,
+    pub parachain_header: SubstrateHeader,
+    /// Proof of `Paras::Heads(para_id)` against the relay chain state root.
+    pub state_proof: Vec<Vec<u8>>,
+    /// Proof of `Timestamp::Now` against the parachain state root.
+    pub timestamp_proof: Vec<Vec<u8>>,
+}
+
+impl TryFrom<RawParachainHeaderProof> for ParachainHeaderProof {
+    type Error = Error;
+
+    fn try_from(raw: RawParachainHeaderProof) -> Result<Self, Self::Error> {
+        let relay_hash = raw
+            .relay_hash
+            .try_into()
+            .map_err(|_| Error::InvalidRawHeader {
+                reason: "relay hash must be 32 bytes".to_string(),
+            })?;
+
+        if raw.state_proof.is_empty() || raw.timestamp_proof.is_empty() {
+            return Err(Error::InvalidRawHeader {
+                reason: "parachain header proofs cannot be empty".to_string(),
+            });
+        }
+
+        Ok(Self {
+            relay_hash,
+            parachain_header: SubstrateHeader::decode(&mut raw.parachain_header.as_slice())?,
+            state_proof: raw.state_proof,
+            timestamp_proof: raw.timestamp_proof,
+        })
+    }
+}
+
+impl From<ParachainHeaderProof> for RawParachainHeaderProof {
+    fn from(value: ParachainHeaderProof) -> Self {
+        Self {
+            relay_hash: value.relay_hash.to_vec(),
+            parachain_header: value.parachain_header.encode(),
+            state_proof: value.state_proof,
+            timestamp_proof: value.timestamp_proof,
+        }
+    }
+}
+
+/// Defines the GRANDPA client header: a proof of finality of a relay chain
+/// block, along with the parachain headers included in the newly finalized
+/// relay chain blocks.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Header {
+    pub finality_proof: FinalityProof,
+    pub parachain_headers: Vec<ParachainHeaderProof>,
+}
+
+impl Protobuf<RawGrandpaHeader> for Header {}
+
+impl TryFrom<RawGrandpaHeader> for Header {
+    type Error = Error;
+
+    fn try_from(raw: RawGrandpaHeader) -> Result<Self, Self::Error> {
+        if raw.parachain_headers.is_empty() {
+            return Err(Error::InvalidRawHeader {
+                reason: "header must contain at least one parachain header".to_string(),
+            });
+        }
+
+        Ok(Self {
+            finality_proof: FinalityProof::decode(&mut raw.finality_proof.as_slice())?,
+            parachain_headers: raw
+                .parachain_headers
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+impl From<Header> for RawGrandpaHeader {
+    fn from(value: Header) -> Self {
+        Self {
+            finality_proof: value.finality_proof.encode(),
+            parachain_headers: value
+                .parachain_headers
+                .into_iter()
+                .map(Into::into)
+                .collect(),
+        }
+    }
+}
+
+impl Protobuf<Any> for Header {}
+
+impl TryFrom<Any> for Header {
+    type Error = ClientError;
+
+    fn try_from(raw: Any) -> Result<Self, Self::Error> {
+        fn decode_header(value: &[u8]) -> Result<Header, ClientError> {
+            let header =
+                Protobuf::<RawGrandpaHeader>::decode(value).map_err(|e| ClientError::Other {
+                    description: e.to_string(),
+                })?;
+            Ok(header)
+        }
+
+        match raw.type_url.as_str() {
+            GRANDPA_HEADER_TYPE_URL => decode_header(&raw.value),
+            _ => Err(ClientError::UnknownHeaderType {
+                header_type: raw.type_url,
+            }),
+        }
+    }
+}
+
+impl From<Header> for Any {
+    fn from(header: Header) -> Self {
+        Any {
+            type_url: GRANDPA_HEADER_TYPE_URL.to_string(),
+            value: Protobuf::<RawGrandpaHeader>::encode_vec(header),
+        }
+    }
+}
@@ -0,0 +1,318 @@
+//! GRANDPA justifications and finality proofs, and their verification
+//! against a known authority set.
+
+use alloc::collections::{BTreeMap, BTreeSet};
+
+use ed25519_consensus::{Signature, VerificationKey};
+use parity_scale_codec::{Decode, Encode};
+
+use super::substrate::{AuthorityId, AuthorityList, SubstrateHeader};
+use crate::error::Error;
+use crate::hashing::Hash;
+use ibc_primitives::prelude::*;
+
+/// A GRANDPA precommit vote for a block.
+#[derive(Clone, Debug, PartialEq, Eq, Encode, Decode)]
+pub struct Precommit {
+    pub target_hash: Hash,
+    pub target_number: u32,
+}
+
+/// A precommit together with the signature and id of its voter.
+#[derive(Clone, Debug, PartialEq, Eq, Encode, Decode)]
+pub struct SignedPrecommit {
+    pub precommit: Precommit,
+    pub signature: [u8; 64],
+    pub id: AuthorityId,
+}
+
+/// A commit message: the finalized block and the precommits justifying it.
+#[derive(Clone, Debug, PartialEq, Eq, Encode, Decode)]
+pub struct Commit {
+    pub target_hash: Hash,
+    pub target_number: u32,
+    pub precommits: Vec<SignedPrecommit>,
+}
+
+/// A GRANDPA justification for block finality, as produced by
+/// `sc-finality-grandpa`.
+///
+/// `votes_ancestries` contains the headers between the commit target and the
+/// (possibly higher) blocks individual precommits voted for.
+#[derive(Clone, Debug, PartialEq, Eq, Encode, Decode)]
+pub struct GrandpaJustification {
+    pub round: u64,
+    pub commit: Commit,
+    pub votes_ancestries: Vec<SubstrateHeader>,
+}
+
+/// A proof of finality of a relay chain block, as returned by the
+/// `grandpa_proveFinality` RPC.
+///
+/// `unknown_headers` must contain the headers from the latest relay chain
+/// block known to the client (exclusive) up to the finalized `block`
+/// (inclusive).
+#[derive(Clone, Debug, PartialEq, Eq, Encode, Decode)]
+pub struct FinalityProof {
+    pub block: Hash,
+    /// The SCALE-encoded [`GrandpaJustification`].
+    pub justification: Vec<u8>,
+    pub unknown_headers: Vec<SubstrateHeader>,
+}
+
+impl FinalityProof {
+    pub fn justification(&self) -> Result<GrandpaJustification, Error> {
+        Ok(GrandpaJustification::decode(
+            &mut self.justification.as_slice(),
+        )?)
+    }
+}
+
+/// The message signed by GRANDPA voters, tagged with its variant index.
+#[derive(Encode)]
+enum Message<'a> {
+    #[codec(index = 1)]
+    Precommit(&'a Precommit),
+}
+
+/// Returns the payload signed by a voter casting `precommit` in `round` of
+/// the authority set `set_id`.
+pub fn precommit_signing_payload(precommit: &Precommit, round: u64, set_id: u64) -> Vec<u8> {
+    (Message::Precommit(precommit), round, set_id).encode()
+}
+
+/// Returns the minimal voting weight required to finalize a block, i.e. the
+/// weight of the authority set minus the maximal tolerated faulty weight.
+pub fn threshold(total_weight: u64) -> u64 {
+    let faulty = total_weight.saturating_sub(1) / 3;
+    total_weight - faulty
+}
+
+impl GrandpaJustification {
+    /// Verifies that this justification finalizes its commit target under
+    /// the given authority set: every precommit must be signed by a distinct
+    /// member of the set, vote for a descendant of the target (proven by
+    /// `votes_ancestries`), and the voters must reach the supermajority
+    /// threshold.
+    pub fn verify(&self, set_id: u64, authorities: &AuthorityList) -> Result<(), Error> {
+        let weights: BTreeMap<&AuthorityId, u64> =
+            authorities.iter().map(|(id, weight)| (id, *weight)).collect();
+
+        if weights.len() != authorities.len() {
+            return Err(Error::InvalidAuthoritySet {
+                reason: "duplicate authority".to_string(),
+            });
+        }
+
+        let total_weight = authorities
+            .iter()
+            .try_fold(0u64, |acc, (_, weight)| acc.checked_add(*weight))
+            .ok_or_else(|| Error::InvalidAuthoritySet {
+                reason: "total weight overflows".to_string(),
+            })?;
+
+        if total_weight == 0 {
+            return Err(Error::InvalidAuthoritySet {
+                reason: "authority set is empty".to_string(),
+            });
+        }
+
+        let ancestry: BTreeMap<Hash, &SubstrateHeader> = self
+            .votes_ancestries
+            .iter()
+            .map(|header| (header.hash(), header))
+            .collect();
+        let mut visited = BTreeSet::new();
+        let mut voters = BTreeSet::new();
+        let mut weight = 0u64;
+
+        for signed in &self.commit.precommits {
+            let Some(voter_weight) = weights.get(&signed.id) else {
+                return Err(invalid_justification("precommit from unknown authority"));
+            };
+
+            if !voters.insert(signed.id) {
+                return Err(invalid_justification("duplicate precommit"));
+            }
+
+            let payload = precommit_signing_payload(&signed.precommit, self.round, set_id);
+            VerificationKey::try_from(signed.id)
+                .and_then(|key| key.verify(&Signature::from(signed.signature), &payload))
+                .map_err(|_| invalid_justification("invalid precommit signature"))?;
+
+            self.check_descends_from_target(&signed.precommit, &ancestry, &mut visited)?;
+
+            weight = weight.saturating_add(*voter_weight);
+        }
+
+        if visited.len() != ancestry.len() {
+            return Err(invalid_justification("redundant votes ancestries"));
+        }
+
+        let needed = threshold(total_weight);
+        if weight < needed {
+            return Err(Error::InsufficientPrecommitWeight {
+                got: weight,
+                needed,
+            });
+        }
+
+        Ok(())
+    }
+
+    fn check_descends_from_target(
+        &self,
+        precommit: &Precommit,
+        ancestry: &BTreeMap<Hash, &SubstrateHeader>,
+        visited: &mut BTreeSet<Hash>,
+    ) -> Result<(), Error> {
+        let mut current = precommit.target_hash;
+
+        while current != self.commit.target_hash {
+            let header = ancestry
+                .get(&current)
+                .ok_or_else(|| invalid_justification("precommit target is not a descendant"))?;
+
+            if header.number <= self.commit.target_number {
+                return Err(invalid_justification("precommit target is not a descendant"));
+            }
+
+            visited.insert(current);
+            current = header.parent_hash;
+        }
+
+        Ok(())
+    }
+}
+
+fn invalid_justification(reason: &str) -> Error {
+    Error::InvalidJustification {
+        reason: reason.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ed25519_consensus::SigningKey;
+
+    use super::*;
+    use crate::types::substrate::Digest;
+
+    fn authorities(keys: &[SigningKey]) -> AuthorityList {
+        keys.iter()
+            .map(|key| (key.verification_key().to_bytes(), 1))
+            .collect()
+    }
+
+    fn justification(
+        keys: &[SigningKey],
+        target: &SubstrateHeader,
+        round: u64,
+        set_id: u64,
+    ) -> GrandpaJustification {
+        let precommit = Precommit {
+            target_hash: target.hash(),
+            target_number: target.number,
+        };
+        let precommits = keys
+            .iter()
+            .map(|key| SignedPrecommit {
+                precommit: precommit.clone(),
+                signature: key
+                    .sign(&precommit_signing_payload(&precommit, round, set_id))
+                    .to_bytes(),
+                id: key.verification_key().to_bytes(),
+            })
+            .collect();
+
+        GrandpaJustification {
+            round,
+            commit: Commit {
+                target_hash: target.hash(),
+                target_number: target.number,
+                precommits,
+            },
+            votes_ancestries: vec![],
+        }
+    }
+
+    fn header(number: u32) -> SubstrateHeader {
+        SubstrateHeader {
+            parent_hash: [number as u8; 32],
+            number,
+            state_root: [1; 32],
+            extrinsics_root: [2; 32],
+            digest: Digest::default(),
+        }
+    }
+
+    #[test]
+    fn threshold_is_a_supermajority() {
+        assert_eq!(threshold(1), 1);
+        assert_eq!(threshold(3), 3);
+        assert_eq!(threshold(4), 3);
+        assert_eq!(threshold(10), 7);
+    }
+
+    #[test]
+    fn verify_justification() {
+        let keys: Vec<_> = (1..=4).map(|seed| SigningKey::from([seed; 32])).collect();
+        let set = authorities(&keys);
+        let target = header(10);
+
+        let valid = justification(&keys[..3], &target, 1, 0);
+        assert!(valid.verify(0, &set).is_ok());
+
+        // signed for another set id
+        assert!(valid.verify(1, &set).is_err());
+
+        // not enough voters
+        let too_few = justification(&keys[..2], &target, 1, 0);
+        assert!(matches!(
+            too_few.verify(0, &set),
+            Err(Error::InsufficientPrecommitWeight { got: 2, needed: 3 })
+        ));
+
+        // unknown voter
+        let outsider = SigningKey::from([9; 32]);
+        let mut unknown = valid.clone();
+        unknown.commit.precommits[0].id = outsider.verification_key().to_bytes();
+        assert!(unknown.verify(0, &set).is_err());
+
+        // duplicate voter
+        let mut duplicate = valid.clone();
+        let first = duplicate.commit.precommits[0].clone();
+        duplicate.commit.precommits[2] = first;
+        assert!(duplicate.verify(0, &set).is_err());
+    }
+
+    #[test]
+    fn verify_justification_with_ancestry() {
+        let keys: Vec<_> = (1..=3).map(|seed| SigningKey::from([seed; 32])).collect();
+        let set = authorities(&keys);
+        let target = header(10);
+        let child = SubstrateHeader {
+            parent_hash: target.hash(),
+            ..header(11)
+        };
+
+        let mut with_ancestry = justification(&keys, &target, 1, 0);
+        let precommit = Precommit {
+            target_hash: child.hash(),
+            target_number: child.number,
+        };
+        with_ancestry.commit.precommits[0] = SignedPrecommit {
+            precommit: precommit.clone(),
+            signature: keys[0]
+                .sign(&precommit_signing_payload(&precommit, 1, 0))
+                .to_bytes(),
+            id: keys[0].verification_key().to_bytes(),
+        };
+
+        // the ancestry from the voted block to the target is missing
+        assert!(with_ancestry.verify(0, &set).is_err());
+
+        with_ancestry.votes_ancestries = vec![child];
+        assert!(with_ancestry.verify(0, &set).is_ok());
+    }
+}
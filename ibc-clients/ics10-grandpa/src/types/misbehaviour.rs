@@ -0,0 +1,106 @@
+//! Defines the misbehaviour type for the GRANDPA light client.
+
+use ibc_core_client::types::error::ClientError;
+use ibc_primitives::prelude::*;
+use ibc_primitives::proto::{Any, Protobuf};
+use parity_scale_codec::{Decode, Encode};
+
+use super::justification::FinalityProof;
+use super::proto::Misbehaviour as RawGrandpaMisbehaviour;
+use crate::error::Error;
+use crate::trie::hex;
+
+pub const GRANDPA_MISBEHAVIOUR_TYPE_URL: &str = "/ibc.lightclients.grandpa.v1.Misbehaviour";
+
+/// Evidence of the relay chain authority set finalizing two different blocks
+/// at the same height.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Misbehaviour {
+    pub first_finality_proof: FinalityProof,
+    pub second_finality_proof: FinalityProof,
+}
+
+impl Misbehaviour {
+    /// Checks that both finality proofs target distinct relay chain blocks
+    /// at the same height, without verifying their justifications.
+    pub fn validate_basic(&self) -> Result<(), Error> {
+        if self.first_finality_proof.block == self.second_finality_proof.block {
+            return Err(Error::NotMisbehaviour {
+                hash: hex(&self.first_finality_proof.block),
+            });
+        }
+
+        let first = self.first_finality_proof.justification()?.commit;
+        let second = self.second_finality_proof.justification()?.commit;
+
+        if first.target_number != second.target_number {
+            return Err(Error::MismatchedRelayHeights {
+                first: first.target_number,
+                second: second.target_number,
+            });
+        }
+
+        Ok(())
+    }
+}
+
+impl Protobuf<RawGrandpaMisbehaviour> for Misbehaviour {}
+
+impl TryFrom<RawGrandpaMisbehaviour> for Misbehaviour {
+    type Error = Error;
+
+    fn try_from(raw: RawGrandpaMisbehaviour) -> Result<Self, Self::Error> {
+        let misbehaviour = Self {
+            first_finality_proof: FinalityProof::decode(
+                &mut raw.first_finality_proof.as_slice(),
+            )?,
+            second_finality_proof: FinalityProof::decode(
+                &mut raw.second_finality_proof.as_slice(),
+            )?,
+        };
+        misbehaviour.validate_basic()?;
+        Ok(misbehaviour)
+    }
+}
+
+impl From<Misbehaviour> for RawGrandpaMisbehaviour {
+    fn from(value: Misbehaviour) -> Self {
+        Self {
+            first_finality_proof: value.first_finality_proof.encode(),
+            second_finality_proof: value.second_finality_proof.encode(),
+        }
+    }
+}
+
+impl Protobuf<Any> for Misbehaviour {}
+
+impl TryFrom<Any> for Misbehaviour {
+    type Error = ClientError;
+
+    fn try_from(raw: Any) -> Result<Self, Self::Error> {
+        fn decode_misbehaviour(value: &[u8]) -> Result<Misbehaviour, ClientError> {
+            let misbehaviour = Protobuf::<RawGrandpaMisbehaviour>::decode(value).map_err(|e| {
+                ClientError::Other {
+                    description: e.to_string(),
+                }
+            })?;
+            Ok(misbehaviour)
+        }
+
+        match raw.type_url.as_str() {
+            GRANDPA_MISBEHAVIOUR_TYPE_URL => decode_misbehaviour(&raw.value),
+            _ => Err(ClientError::UnknownMisbehaviourType {
+                misbehaviour_type: raw.type_url,
+            }),
+        }
+    }
+}
+
+impl From<Misbehaviour> for Any {
+    fn from(misbehaviour: Misbehaviour) -> Self {
+        Any {
+            type_url: GRANDPA_MISBEHAVIOUR_TYPE_URL.to_string(),
+            value: Protobuf::<RawGrandpaMisbehaviour>::encode_vec(misbehaviour),
+        }
+    }
+}
@@ -0,0 +1,143 @@
+//! Raw (protobuf) representations of the GRANDPA client types.
+//!
+//! There is no upstream `ibc-proto` definition for these messages yet, so
+//! they are defined here under the `ibc.lightclients.grandpa.v1` package.
+//! Substrate data structures (headers, finality proofs) are carried as their
+//! SCALE encoding.
+
+use ibc_primitives::prelude::*;
+use ibc_proto::ibc::core::client::v1::Height as RawHeight;
+
+macro_rules! impl_name {
+    ($ty:ty, $name:literal) => {
+        impl prost::Name for $ty {
+            const NAME: &'static str = $name;
+            const PACKAGE: &'static str = "ibc.lightclients.grandpa.v1";
+
+            fn full_name() -> String {
+                format!("{}.{}", Self::PACKAGE, Self::NAME)
+            }
+        }
+    };
+}
+
+/// ```protobuf
+/// message Authority {
+///   bytes public_key = 1;
+///   uint64 weight = 2;
+/// }
+/// ```
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct Authority {
+    #[prost(bytes = "vec", tag = "1")]
+    pub public_key: Vec<u8>,
+    #[prost(uint64, tag = "2")]
+    pub weight: u64,
+}
+
+impl_name!(Authority, "Authority");
+
+/// ```protobuf
+/// message ClientState {
+///   string chain_id = 1;
+///   uint32 para_id = 2;
+///   uint32 latest_relay_height = 3;
+///   bytes latest_relay_hash = 4;
+///   uint64 current_set_id = 5;
+///   repeated Authority current_authorities = 6;
+///   uint32 latest_para_height = 7;
+///   ibc.core.client.v1.Height frozen_height = 8;
+/// }
+/// ```
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct ClientState {
+    #[prost(string, tag = "1")]
+    pub chain_id: String,
+    #[prost(uint32, tag = "2")]
+    pub para_id: u32,
+    #[prost(uint32, tag = "3")]
+    pub latest_relay_height: u32,
+    #[prost(bytes = "vec", tag = "4")]
+    pub latest_relay_hash: Vec<u8>,
+    #[prost(uint64, tag = "5")]
+    pub current_set_id: u64,
+    #[prost(message, repeated, tag = "6")]
+    pub current_authorities: Vec<Authority>,
+    #[prost(uint32, tag = "7")]
+    pub latest_para_height: u32,
+    #[prost(message, optional, tag = "8")]
+    pub frozen_height: Option<RawHeight>,
+}
+
+impl_name!(ClientState, "ClientState");
+
+/// ```protobuf
+/// message ConsensusState {
+///   uint64 timestamp = 1;
+///   bytes root = 2;
+/// }
+/// ```
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct ConsensusState {
+    /// Unix timestamp in nanoseconds.
+    #[prost(uint64, tag = "1")]
+    pub timestamp: u64,
+    #[prost(bytes = "vec", tag = "2")]
+    pub root: Vec<u8>,
+}
+
+impl_name!(ConsensusState, "ConsensusState");
+
+/// ```protobuf
+/// message ParachainHeaderProof {
+///   bytes relay_hash = 1;
+///   bytes parachain_header = 2;
+///   repeated bytes state_proof = 3;
+///   repeated bytes timestamp_proof = 4;
+/// }
+/// ```
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct ParachainHeaderProof {
+    #[prost(bytes = "vec", tag = "1")]
+    pub relay_hash: Vec<u8>,
+    #[prost(bytes = "vec", tag = "2")]
+    pub parachain_header: Vec<u8>,
+    #[prost(bytes = "vec", repeated, tag = "3")]
+    pub state_proof: Vec<Vec<u8>>,
+    #[prost(bytes = "vec", repeated, tag = "4")]
+    pub timestamp_proof: Vec<Vec<u8>>,
+}
+
+impl_name!(ParachainHeaderProof, "ParachainHeaderProof");
+
+/// ```protobuf
+/// message Header {
+///   bytes finality_proof = 1;
+///   repeated ParachainHeaderProof parachain_headers = 2;
+/// }
+/// ```
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct Header {
+    #[prost(bytes = "vec", tag = "1")]
+    pub finality_proof: Vec<u8>,
+    #[prost(message, repeated, tag = "2")]
+    pub parachain_headers: Vec<ParachainHeaderProof>,
+}
+
+impl_name!(Header, "Header");
+
+/// ```protobuf
+/// message Misbehaviour {
+///   bytes first_finality_proof = 1;
+///   bytes second_finality_proof = 2;
+/// }
+/// ```
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct Misbehaviour {
+    #[prost(bytes = "vec", tag = "1")]
+    pub first_finality_proof: Vec<u8>,
+    #[prost(bytes = "vec", tag = "2")]
+    pub second_finality_proof: Vec<u8>,
+}
+
+impl_name!(Misbehaviour, "Misbehaviour");
@@ -0,0 +1,133 @@
+//! SCALE-encoded Substrate primitives needed to follow a relay chain and its
+//! parachains: block headers, their digests, and the GRANDPA consensus logs
+//! announcing authority set changes.
+
+use parity_scale_codec::{Decode, Encode};
+
+use crate::hashing::{blake2_256, twox_128, twox_64, Hash};
+use ibc_primitives::prelude::*;
+
+/// Engine id of the GRANDPA consensus digests.
+pub const GRANDPA_ENGINE_ID: [u8; 4] = *b"FRNK";
+
+/// The public key of a GRANDPA authority, i.e. an ed25519 public key.
+pub type AuthorityId = [u8; 32];
+
+/// The voting weight of a GRANDPA authority.
+pub type AuthorityWeight = u64;
+
+/// A GRANDPA authority set, as stored in the `Grandpa::Authorities` storage
+/// item and announced in `ScheduledChange` logs.
+pub type AuthorityList = Vec<(AuthorityId, AuthorityWeight)>;
+
+/// The header of a Substrate block, with a `u32` block number and blake2
+/// hashing, as used by Polkadot, Kusama and their parachains.
+#[derive(Clone, Debug, PartialEq, Eq, Encode, Decode)]
+pub struct SubstrateHeader {
+    pub parent_hash: Hash,
+    #[codec(compact)]
+    pub number: u32,
+    pub state_root: Hash,
+    pub extrinsics_root: Hash,
+    pub digest: Digest,
+}
+
+impl SubstrateHeader {
+    /// Returns the block hash, i.e. the blake2 hash of the encoded header.
+    pub fn hash(&self) -> Hash {
+        blake2_256(&self.encode())
+    }
+
+    /// Returns the authority set change scheduled by this block, if any.
+    pub fn scheduled_change(&self) -> Option<ScheduledChange> {
+        self.grandpa_logs().find_map(|log| match log {
+            ConsensusLog::ScheduledChange(change) => Some(change),
+            _ => None,
+        })
+    }
+
+    /// Returns the forced authority set change announced by this block, if
+    /// any.
+    pub fn forced_change(&self) -> Option<(u32, ScheduledChange)> {
+        self.grandpa_logs().find_map(|log| match log {
+            ConsensusLog::ForcedChange(median, change) => Some((median, change)),
+            _ => None,
+        })
+    }
+
+    fn grandpa_logs(&self) -> impl Iterator<Item = ConsensusLog> + '_ {
+        self.digest.logs.iter().filter_map(|item| match item {
+            DigestItem::Consensus(engine_id, data) if *engine_id == GRANDPA_ENGINE_ID => {
+                ConsensusLog::decode(&mut data.as_slice()).ok()
+            }
+            _ => None,
+        })
+    }
+}
+
+/// The digest of a Substrate block header.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Encode, Decode)]
+pub struct Digest {
+    pub logs: Vec<DigestItem>,
+}
+
+/// An item of a Substrate header digest.
+#[derive(Clone, Debug, PartialEq, Eq, Encode, Decode)]
+pub enum DigestItem {
+    #[codec(index = 6)]
+    PreRuntime([u8; 4], Vec<u8>),
+    #[codec(index = 4)]
+    Consensus([u8; 4], Vec<u8>),
+    #[codec(index = 5)]
+    Seal([u8; 4], Vec<u8>),
+    #[codec(index = 0)]
+    Other(Vec<u8>),
+    #[codec(index = 8)]
+    RuntimeEnvironmentUpdated,
+}
+
+/// A scheduled change of the GRANDPA authority set.
+#[derive(Clone, Debug, PartialEq, Eq, Encode, Decode)]
+pub struct ScheduledChange {
+    pub next_authorities: AuthorityList,
+    /// The number of blocks after the announcing block at which the change
+    /// is enacted.
+    pub delay: u32,
+}
+
+/// A consensus log item emitted by the GRANDPA pallet.
+#[derive(Clone, Debug, PartialEq, Eq, Encode, Decode)]
+pub enum ConsensusLog {
+    #[codec(index = 1)]
+    ScheduledChange(ScheduledChange),
+    #[codec(index = 2)]
+    ForcedChange(u32, ScheduledChange),
+    #[codec(index = 3)]
+    OnDisabled(u64),
+    #[codec(index = 4)]
+    Pause(u32),
+    #[codec(index = 5)]
+    Resume(u32),
+}
+
+/// Returns the relay chain storage key holding the head data (i.e. the
+/// encoded latest header) of the parachain `para_id`, i.e. the key of
+/// `Paras::Heads(para_id)`.
+pub fn parachain_head_storage_key(para_id: u32) -> Vec<u8> {
+    let encoded_para_id = para_id.encode();
+    let mut key = Vec::with_capacity(16 + 16 + 8 + 4);
+    key.extend_from_slice(&twox_128(b"Paras"));
+    key.extend_from_slice(&twox_128(b"Heads"));
+    key.extend_from_slice(&twox_64(&encoded_para_id));
+    key.extend_from_slice(&encoded_para_id);
+    key
+}
+
+/// Returns the storage key of `Timestamp::Now`, which holds the timestamp of
+/// the current block in milliseconds.
+pub fn timestamp_storage_key() -> Vec<u8> {
+    let mut key = Vec::with_capacity(32);
+    key.extend_from_slice(&twox_128(b"Timestamp"));
+    key.extend_from_slice(&twox_128(b"Now"));
+    key
+}
@@ -0,0 +1,247 @@
+//! This module includes the implementations of the `ClientStateCommon`,
+//! `ClientStateValidation`, and `ClientStateExecution` traits for the NEAR
+//! client state.
+//!
+//! As with the other clients, the logic behind each trait method is also
+//! exposed as a standalone function, so that hosts wrapping the NEAR client
+//! can reuse it.
+
+mod common;
+mod execution;
+mod validation;
+
+pub use common::*;
+pub use execution::*;
+pub use validation::*;
+
+#[cfg(test)]
+mod tests {
+    use core::str::FromStr;
+
+    use borsh::BorshSerialize;
+    use ed25519_consensus::SigningKey;
+    use ibc_core_commitment_types::commitment::{
+        CommitmentPrefix, CommitmentProofBytes, CommitmentRoot,
+    };
+    use ibc_core_host::types::identifiers::{ChainId, ClientId};
+    use ibc_core_host::types::path::{ClientStatePath, Path};
+    use ibc_primitives::prelude::*;
+    use ibc_primitives::proto::Any;
+
+    use super::*;
+    use crate::hashing::{sha256, CryptoHash, Direction, MerklePathItem};
+    use crate::trie::tests::single_entry_trie;
+    use crate::types::{
+        BlockHeaderInnerLite, ClientState, Header, LightClientBlock, Misbehaviour, PublicKey,
+        Signature, StateProof, ValidatorStake, ValidatorStakeV1,
+    };
+
+    const EPOCH: CryptoHash = [1; 32];
+    const NEXT_EPOCH: CryptoHash = [2; 32];
+
+    fn signing_keys() -> Vec<SigningKey> {
+        (1..=4).map(|seed| SigningKey::from([seed; 32])).collect()
+    }
+
+    fn producers(keys: &[SigningKey]) -> Vec<ValidatorStake> {
+        keys.iter()
+            .enumerate()
+            .map(|(i, key)| {
+                ValidatorStake::V1(ValidatorStakeV1 {
+                    account_id: format!("validator-{i}.near"),
+                    public_key: PublicKey::Ed25519(key.verification_key().to_bytes()),
+                    stake: 100,
+                })
+            })
+            .collect()
+    }
+
+    fn inner_lite(
+        height: u64,
+        epoch_id: CryptoHash,
+        next_epoch_id: CryptoHash,
+    ) -> BlockHeaderInnerLite {
+        BlockHeaderInnerLite {
+            height,
+            epoch_id,
+            next_epoch_id,
+            prev_state_root: [height as u8; 32],
+            outcome_root: [0; 32],
+            timestamp: 1_700_000_000_000_000_000 + height,
+            next_bp_hash: [0; 32],
+            block_merkle_root: [0; 32],
+        }
+    }
+
+    fn dummy_client_state(keys: &[SigningKey]) -> ClientState {
+        ClientState::new(
+            ChainId::from_str("near-0").expect("never fails"),
+            "ibc.near".to_string(),
+            inner_lite(10, EPOCH, NEXT_EPOCH),
+            [10; 32],
+            producers(keys),
+            producers(&keys[1..]),
+        )
+        .expect("never fails")
+    }
+
+    fn dummy_block(
+        height: u64,
+        epoch_id: CryptoHash,
+        next_epoch_id: CryptoHash,
+        signers: &[SigningKey],
+    ) -> LightClientBlock {
+        let mut block = LightClientBlock {
+            prev_block_hash: [9; 32],
+            next_block_inner_hash: [8; 32],
+            inner_lite: inner_lite(height, epoch_id, next_epoch_id),
+            inner_rest_hash: [7; 32],
+            next_bps: None,
+            approvals_after_next: vec![],
+        };
+        approve(&mut block, signers);
+        block
+    }
+
+    fn approve(block: &mut LightClientBlock, signers: &[SigningKey]) {
+        let message = block.approval_message();
+        block.approvals_after_next = signers
+            .iter()
+            .map(|key| Some(Signature::Ed25519(key.sign(&message).to_bytes())))
+            .collect();
+    }
+
+    #[test]
+    fn client_state_any_roundtrip() {
+        let client_state = dummy_client_state(&signing_keys());
+        let any: Any = client_state.clone().into();
+        assert_eq!(
+            ClientState::try_from(any).expect("never fails"),
+            client_state
+        );
+    }
+
+    #[test]
+    fn verify_header_in_current_epoch() {
+        let keys = signing_keys();
+        let client_state = dummy_client_state(&keys);
+
+        let block = dummy_block(11, EPOCH, NEXT_EPOCH, &keys[..3]);
+        let header = Header {
+            light_client_block: block,
+        };
+        assert!(verify_client_message(&client_state, header.clone().into()).is_ok());
+
+        let stale = Header {
+            light_client_block: dummy_block(10, EPOCH, NEXT_EPOCH, &keys[..3]),
+        };
+        assert!(verify_header(&client_state, &stale).is_err());
+
+        let unapproved = Header {
+            light_client_block: dummy_block(11, EPOCH, NEXT_EPOCH, &keys[..2]),
+        };
+        assert!(verify_header(&client_state, &unapproved).is_err());
+
+        let unknown_epoch = Header {
+            light_client_block: dummy_block(11, [3; 32], [4; 32], &keys[..3]),
+        };
+        assert!(verify_header(&client_state, &unknown_epoch).is_err());
+    }
+
+    #[test]
+    fn verify_header_in_next_epoch() {
+        let keys = signing_keys();
+        let client_state = dummy_client_state(&keys);
+
+        // approved by the next epoch's block producers, but does not announce
+        // the block producers of the epoch after
+        let mut block = dummy_block(20, NEXT_EPOCH, [3; 32], &keys[1..]);
+        assert!(verify_header(
+            &client_state,
+            &Header {
+                light_client_block: block.clone()
+            }
+        )
+        .is_err());
+
+        let next_bps = producers(&keys[..2]);
+        block.inner_lite.next_bp_hash = sha256(&next_bps.try_to_vec().expect("never fails"));
+        block.next_bps = Some(next_bps);
+        approve(&mut block, &keys[1..]);
+        assert!(verify_header(
+            &client_state,
+            &Header {
+                light_client_block: block
+            }
+        )
+        .is_ok());
+    }
+
+    #[test]
+    fn verify_misbehaviour_with_conflicting_blocks() {
+        let keys = signing_keys();
+        let client_state = dummy_client_state(&keys);
+
+        let block_one = dummy_block(11, EPOCH, NEXT_EPOCH, &keys[..3]);
+        let mut block_two = block_one.clone();
+        block_two.inner_rest_hash = [6; 32];
+        approve(&mut block_two, &keys[..3]);
+
+        let misbehaviour = Misbehaviour {
+            block_one: block_one.clone(),
+            block_two,
+        };
+        assert!(verify_client_message(&client_state, misbehaviour.into()).is_ok());
+
+        let same = Misbehaviour {
+            block_one: block_one.clone(),
+            block_two: block_one,
+        };
+        assert!(verify_misbehaviour(&client_state, &same).is_err());
+    }
+
+    #[test]
+    fn verify_membership_with_state_proof() {
+        let client_state = dummy_client_state(&signing_keys());
+        let prefix = CommitmentPrefix::try_from(b"ibc".to_vec()).expect("never fails");
+        let path = Path::ClientState(ClientStatePath::new(
+            ClientId::new(crate::NEAR_CLIENT_TYPE, 0).expect("never fails"),
+        ));
+        let key = commitment_storage_key(&client_state, &prefix, &path);
+
+        let (shard_state_root, nodes) = single_entry_trie(&key, b"value");
+        let sibling = [5; 32];
+        let proof = StateProof {
+            shard_state_root,
+            shard_state_root_proof: vec![MerklePathItem {
+                hash: sibling,
+                direction: Direction::Right,
+            }],
+            nodes,
+        };
+        let root = CommitmentRoot::from_bytes(&crate::hashing::combine_hash(
+            &sha256(&shard_state_root),
+            &sibling,
+        ));
+        let proof_bytes = CommitmentProofBytes::try_from(proof).expect("never fails");
+
+        assert!(verify_membership(
+            &client_state,
+            &prefix,
+            &proof_bytes,
+            &root,
+            path.clone(),
+            b"value".to_vec()
+        )
+        .is_ok());
+        assert!(verify_membership(
+            &client_state,
+            &prefix,
+            &proof_bytes,
+            &CommitmentRoot::from_bytes(&[0; 32]),
+            path,
+            b"value".to_vec()
+        )
+        .is_err());
+    }
+}
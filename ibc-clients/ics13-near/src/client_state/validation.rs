@@ -0,0 +1,174 @@
+use ibc_core_client::context::client_state::ClientStateValidation;
+use ibc_core_client::context::consensus_state::ConsensusState as _;
+use ibc_core_client::context::ClientValidationContext;
+use ibc_core_client::types::error::ClientError;
+use ibc_core_client::types::Status;
+use ibc_core_host::types::identifiers::ClientId;
+use ibc_core_host::types::path::ClientConsensusStatePath;
+use ibc_primitives::prelude::*;
+use ibc_primitives::proto::Any;
+
+use crate::error::Error;
+use crate::hashing::hex;
+use crate::types::{
+    ClientState as ClientStateType, Header as NearHeader, LightClientBlock,
+    Misbehaviour as NearMisbehaviour, NEAR_HEADER_TYPE_URL, NEAR_MISBEHAVIOUR_TYPE_URL,
+};
+
+impl<V> ClientStateValidation<V> for ClientStateType
+where
+    V: ClientValidationContext,
+{
+    fn verify_client_message(
+        &self,
+        _ctx: &V,
+        _client_id: &ClientId,
+        client_message: Any,
+    ) -> Result<(), ClientError> {
+        verify_client_message(self, client_message)
+    }
+
+    fn check_for_misbehaviour(
+        &self,
+        ctx: &V,
+        client_id: &ClientId,
+        client_message: Any,
+    ) -> Result<bool, ClientError> {
+        check_for_misbehaviour(ctx, client_id, client_message)
+    }
+
+    fn status(&self, _ctx: &V, _client_id: &ClientId) -> Result<Status, ClientError> {
+        Ok(status(self))
+    }
+}
+
+/// Verify the client message as part of the client state validation process.
+///
+/// Note that this function is typically implemented as part of the
+/// [`ClientStateValidation`] trait, but has been made a standalone function
+/// in order to make the ClientState APIs more flexible.
+pub fn verify_client_message(
+    client_state: &ClientStateType,
+    client_message: Any,
+) -> Result<(), ClientError> {
+    match client_message.type_url.as_str() {
+        NEAR_HEADER_TYPE_URL => {
+            let header = NearHeader::try_from(client_message)?;
+            verify_header(client_state, &header)
+        }
+        NEAR_MISBEHAVIOUR_TYPE_URL => {
+            let misbehaviour = NearMisbehaviour::try_from(client_message)?;
+            verify_misbehaviour(client_state, &misbehaviour)
+        }
+        _ => Err(ClientError::InvalidUpdateClientMessage),
+    }
+}
+
+/// Verifies a light client block against the client head, following the
+/// validation rules of the NEAR light client specification:
+///
+/// - the block must be higher than the head;
+/// - it must belong to the epoch of the head or to the next one, and in
+///   the latter case announce the block producers of the epoch after;
+/// - it must be approved by more than two thirds of the stake of the block
+///   producers of its epoch;
+/// - the announced next block producers must match `next_bp_hash`.
+pub fn verify_header(
+    client_state: &ClientStateType,
+    header: &NearHeader,
+) -> Result<(), ClientError> {
+    if client_state.is_frozen() {
+        return Err(Error::FrozenClient.into());
+    }
+
+    let block = &header.light_client_block;
+
+    if block.height() <= client_state.head.height {
+        return Err(Error::StaleBlock {
+            height: block.height(),
+            latest: client_state.head.height,
+        }
+        .into());
+    }
+
+    if block.inner_lite.epoch_id == client_state.head.next_epoch_id && block.next_bps.is_none() {
+        return Err(Error::MissingNextBlockProducers.into());
+    }
+
+    verify_block_approvals(client_state, block)?;
+
+    Ok(())
+}
+
+fn verify_block_approvals(
+    client_state: &ClientStateType,
+    block: &LightClientBlock,
+) -> Result<(), Error> {
+    let epoch_id = &block.inner_lite.epoch_id;
+    let block_producers = client_state
+        .epoch_block_producers(epoch_id)
+        .ok_or_else(|| Error::UnknownEpoch {
+            epoch: hex(epoch_id),
+        })?;
+
+    block.verify_approvals(block_producers)
+}
+
+/// Verifies that both blocks of `misbehaviour` are approved by the block
+/// producers of their epoch.
+pub fn verify_misbehaviour(
+    client_state: &ClientStateType,
+    misbehaviour: &NearMisbehaviour,
+) -> Result<(), ClientError> {
+    misbehaviour.validate_basic()?;
+
+    verify_block_approvals(client_state, &misbehaviour.block_one)?;
+    verify_block_approvals(client_state, &misbehaviour.block_two)?;
+
+    Ok(())
+}
+
+/// Checks for misbehaviour in the given client message. A verified
+/// `Misbehaviour` is always evidence of misbehaviour, while a verified
+/// `Header` is if a consensus state with a different root is already stored
+/// at its height.
+pub fn check_for_misbehaviour<V>(
+    ctx: &V,
+    client_id: &ClientId,
+    client_message: Any,
+) -> Result<bool, ClientError>
+where
+    V: ClientValidationContext,
+{
+    match client_message.type_url.as_str() {
+        NEAR_HEADER_TYPE_URL => {
+            let header = NearHeader::try_from(client_message)?;
+            let height = header.height()?;
+            let path = ClientConsensusStatePath::new(
+                client_id.clone(),
+                height.revision_number(),
+                height.revision_height(),
+            );
+
+            match ctx.consensus_state(&path) {
+                Ok(existing) => Ok(existing.root().as_bytes()
+                    != header.light_client_block.inner_lite.prev_state_root),
+                Err(_) => Ok(false),
+            }
+        }
+        NEAR_MISBEHAVIOUR_TYPE_URL => Ok(true),
+        _ => Err(ClientError::InvalidUpdateClientMessage),
+    }
+}
+
+/// Returns the status of the NEAR client: it is active unless it has been
+/// frozen. Note that a client that falls more than an epoch behind can no
+/// longer be updated, as it does not know the block producers of later
+/// epochs.
+pub fn status(client_state: &ClientStateType) -> Status {
+    if client_state.is_frozen() {
+        Status::Frozen
+    } else {
+        Status::Active
+    }
+}
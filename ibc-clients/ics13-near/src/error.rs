@@ -0,0 +1,89 @@
+//! Defines the NEAR light client's error type
+
+use displaydoc::Display;
+use ibc_core_client::types::error::ClientError;
+use ibc_primitives::prelude::*;
+use prost::DecodeError;
+
+/// The main error type
+#[derive(Debug, Display)]
+pub enum Error {
+    /// invalid raw client state: `{reason}`
+    InvalidRawClientState { reason: String },
+    /// invalid raw consensus state: `{reason}`
+    InvalidRawConsensusState { reason: String },
+    /// invalid raw header: `{reason}`
+    InvalidRawHeader { reason: String },
+    /// invalid raw misbehaviour: `{reason}`
+    InvalidRawMisbehaviour { reason: String },
+    /// borsh decoding error: `{reason}`
+    BorshDecode { reason: String },
+    /// protobuf decoding error: `{0}`
+    Decode(DecodeError),
+    /// block height `{height}` is not higher than the latest known height `{latest}`
+    StaleBlock { height: u64, latest: u64 },
+    /// block epoch `{epoch}` is neither the current nor the next epoch of the client
+    UnknownEpoch { epoch: String },
+    /// block starts a new epoch but does not announce the next block producers
+    MissingNextBlockProducers,
+    /// next block producers do not match the announced `next_bp_hash`
+    MismatchedNextBlockProducers,
+    /// more approvals than block producers: `{approvals}` approvals for `{producers}` block producers
+    TooManyApprovals { approvals: usize, producers: usize },
+    /// invalid approval signature from block producer `{account_id}`
+    InvalidApprovalSignature { account_id: String },
+    /// insufficient approved stake: approved `{approved}` out of `{total}`
+    InsufficientApprovedStake { approved: u128, total: u128 },
+    /// stake overflow when summing block producer stakes
+    StakeOverflow,
+    /// invalid state proof: `{reason}`
+    InvalidStateProof { reason: String },
+    /// incomplete state proof: missing node `{hash}`
+    IncompleteStateProof { hash: String },
+    /// state value mismatch: expected `{expected:?}`, got `{actual:?}`
+    StateValueMismatch {
+        expected: Vec<u8>,
+        actual: Option<Vec<u8>>,
+    },
+    /// both blocks have the same hash `{hash}`
+    NotMisbehaviour { hash: String },
+    /// blocks have different heights `{first}` and `{second}`
+    MismatchedHeights { first: u64, second: u64 },
+    /// client is frozen
+    FrozenClient,
+    /// NEAR clients cannot be upgraded
+    UpgradeNotSupported,
+}
+
+impl core::error::Error for Error {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match &self {
+            // `prost` implements the trait only with `std`
+            #[cfg(feature = "std")]
+            Self::Decode(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<Error> for ClientError {
+    fn from(e: Error) -> Self {
+        Self::ClientSpecific {
+            description: e.to_string(),
+        }
+    }
+}
+
+impl From<DecodeError> for Error {
+    fn from(e: DecodeError) -> Self {
+        Self::Decode(e)
+    }
+}
+
+impl From<borsh::maybestd::io::Error> for Error {
+    fn from(e: borsh::maybestd::io::Error) -> Self {
+        Self::BorshDecode {
+            reason: e.to_string(),
+        }
+    }
+}
@@ -0,0 +1,83 @@
+//! Hash functions and merkle proofs used by NEAR: block hashes, trie nodes
+//! and merkle trees are all built from `sha256`.
+
+use borsh::{BorshDeserialize, BorshSerialize};
+use sha2::{Digest, Sha256};
+
+use ibc_primitives::prelude::*;
+
+pub type CryptoHash = [u8; 32];
+
+pub fn sha256(data: &[u8]) -> CryptoHash {
+    Sha256::digest(data).into()
+}
+
+/// Hashes the concatenation of two hashes, as done for the inner nodes of
+/// NEAR merkle trees and when deriving block hashes.
+pub fn combine_hash(left: &CryptoHash, right: &CryptoHash) -> CryptoHash {
+    let mut hasher = Sha256::new();
+    hasher.update(left);
+    hasher.update(right);
+    hasher.finalize().into()
+}
+
+/// Which side of the path a sibling hash is on.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub enum Direction {
+    Left,
+    Right,
+}
+
+/// A sibling hash of a merkle path.
+#[derive(Clone, Debug, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct MerklePathItem {
+    pub hash: CryptoHash,
+    pub direction: Direction,
+}
+
+pub type MerklePath = Vec<MerklePathItem>;
+
+/// Computes the root of the merkle tree containing `leaf` from its merkle
+/// path.
+pub fn compute_root_from_path(path: &MerklePath, leaf: CryptoHash) -> CryptoHash {
+    path.iter().fold(leaf, |hash, item| match item.direction {
+        Direction::Left => combine_hash(&item.hash, &hash),
+        Direction::Right => combine_hash(&hash, &item.hash),
+    })
+}
+
+pub(crate) fn hex(bytes: &[u8]) -> String {
+    use core::fmt::Write;
+
+    bytes.iter().fold(String::new(), |mut out, b| {
+        let _ = write!(out, "{b:02x}");
+        out
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn root_from_path() {
+        let leaves: Vec<CryptoHash> = (0u8..4).map(|i| sha256(&[i])).collect();
+        let left = combine_hash(&leaves[0], &leaves[1]);
+        let right = combine_hash(&leaves[2], &leaves[3]);
+        let root = combine_hash(&left, &right);
+
+        let path = vec![
+            MerklePathItem {
+                hash: leaves[3],
+                direction: Direction::Right,
+            },
+            MerklePathItem {
+                hash: left,
+                direction: Direction::Left,
+            },
+        ];
+        assert_eq!(compute_root_from_path(&path, leaves[2]), root);
+        assert_ne!(compute_root_from_path(&path, leaves[3]), root);
+        assert_eq!(compute_root_from_path(&vec![], leaves[0]), leaves[0]);
+    }
+}
@@ -0,0 +1,44 @@
+//! NEAR light client implementation.
+//!
+//! The NEAR client follows the NEAR chain through light client blocks, as
+//! described in the [NEAR light client specification][spec]: each block is
+//! approved by the block producers of its epoch, and announces the block
+//! producers of the next epoch. IBC state is proven against the state roots
+//! of the verified blocks.
+//!
+//! [spec]: https://nomicon.io/ChainSpec/LightClient
+#![no_std]
+#![forbid(unsafe_code)]
+#![cfg_attr(not(test), deny(clippy::unwrap_used))]
+#![cfg_attr(not(test), deny(clippy::disallowed_methods, clippy::disallowed_types))]
+#![deny(
+    warnings,
+    trivial_casts,
+    trivial_numeric_casts,
+    unused_import_braces,
+    unused_qualifications,
+    rust_2018_idioms
+)]
+
+extern crate alloc;
+
+#[cfg(any(test, feature = "std"))]
+extern crate std;
+
+pub mod client_state;
+pub mod consensus_state;
+pub mod error;
+pub mod hashing;
+pub mod trie;
+pub mod types;
+
+use core::str::FromStr;
+
+use ibc_core_host::types::identifiers::ClientType;
+
+pub const NEAR_CLIENT_TYPE: &str = "13-near";
+
+/// Returns the NEAR `ClientType`
+pub fn client_type() -> ClientType {
+    ClientType::from_str(NEAR_CLIENT_TYPE).expect("Never fails because it's valid")
+}
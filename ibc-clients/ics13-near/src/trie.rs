@@ -0,0 +1,383 @@
+//! Verification of NEAR state proofs.
+//!
+//! A state proof is the set of borsh-encoded trie nodes visited while
+//! looking up a key in the state trie of a shard. Nodes are identified by
+//! the `sha256` hash of their encoding, and reference values by their length
+//! and hash rather than embedding them.
+
+use alloc::collections::BTreeMap;
+
+use crate::error::Error;
+use crate::hashing::{hex, sha256, CryptoHash};
+use ibc_primitives::prelude::*;
+
+/// The separator between the account id and the key of a contract storage
+/// entry in a state trie key.
+pub const ACCOUNT_DATA_SEPARATOR: u8 = b',';
+
+/// The column of contract storage entries in the state trie.
+pub const CONTRACT_DATA_COLUMN: u8 = 9;
+
+const CHILDREN: usize = 16;
+
+/// A reference to a value stored in the trie.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ValueRef {
+    pub length: u32,
+    pub hash: CryptoHash,
+}
+
+impl ValueRef {
+    pub fn of(value: &[u8]) -> Result<Self, Error> {
+        Ok(Self {
+            length: u32::try_from(value.len()).map_err(|_| Error::InvalidStateProof {
+                reason: "value is too long".to_string(),
+            })?,
+            hash: sha256(value),
+        })
+    }
+}
+
+enum Node {
+    Leaf {
+        key: Vec<u8>,
+        value: ValueRef,
+    },
+    Branch {
+        value: Option<ValueRef>,
+        children: [Option<CryptoHash>; CHILDREN],
+    },
+    Extension {
+        key: Vec<u8>,
+        child: CryptoHash,
+    },
+}
+
+struct Input<'a>(&'a [u8]);
+
+impl<'a> Input<'a> {
+    fn take(&mut self, len: usize) -> Result<&'a [u8], Error> {
+        if self.0.len() < len {
+            return Err(malformed("unexpected end of node"));
+        }
+        let (head, rest) = self.0.split_at(len);
+        self.0 = rest;
+        Ok(head)
+    }
+
+    fn u8(&mut self) -> Result<u8, Error> {
+        Ok(self.take(1)?[0])
+    }
+
+    fn u16(&mut self) -> Result<u16, Error> {
+        let mut bytes = [0; 2];
+        bytes.copy_from_slice(self.take(2)?);
+        Ok(u16::from_le_bytes(bytes))
+    }
+
+    fn u32(&mut self) -> Result<u32, Error> {
+        let mut bytes = [0; 4];
+        bytes.copy_from_slice(self.take(4)?);
+        Ok(u32::from_le_bytes(bytes))
+    }
+
+    fn hash(&mut self) -> Result<CryptoHash, Error> {
+        let mut hash = [0; 32];
+        hash.copy_from_slice(self.take(32)?);
+        Ok(hash)
+    }
+
+    fn bytes(&mut self) -> Result<&'a [u8], Error> {
+        let len = usize::try_from(self.u32()?).map_err(|_| malformed("invalid length"))?;
+        self.take(len)
+    }
+
+    fn value_ref(&mut self) -> Result<ValueRef, Error> {
+        Ok(ValueRef {
+            length: self.u32()?,
+            hash: self.hash()?,
+        })
+    }
+
+    fn children(&mut self) -> Result<[Option<CryptoHash>; CHILDREN], Error> {
+        let bitmap = self.u16()?;
+        let mut children = [None; CHILDREN];
+        for (index, child) in children.iter_mut().enumerate() {
+            if bitmap & (1 << index) != 0 {
+                *child = Some(self.hash()?);
+            }
+        }
+        Ok(children)
+    }
+}
+
+/// Decodes a `RawTrieNodeWithSize`, ignoring its trailing memory usage.
+fn decode_node(encoded: &[u8]) -> Result<Node, Error> {
+    let mut input = Input(encoded);
+
+    let node = match input.u8()? {
+        0 => {
+            let key = decode_nibbles(input.bytes()?, true)?;
+            Node::Leaf {
+                key,
+                value: input.value_ref()?,
+            }
+        }
+        1 => Node::Branch {
+            value: None,
+            children: input.children()?,
+        },
+        2 => {
+            let value = Some(input.value_ref()?);
+            Node::Branch {
+                value,
+                children: input.children()?,
+            }
+        }
+        3 => Node::Extension {
+            key: decode_nibbles(input.bytes()?, false)?,
+            child: input.hash()?,
+        },
+        _ => return Err(malformed("unknown node type")),
+    };
+
+    // memory usage
+    input.take(8)?;
+
+    if !input.0.is_empty() {
+        return Err(malformed("trailing bytes"));
+    }
+
+    Ok(node)
+}
+
+/// Decodes the nibbles of a leaf or extension key. The first byte carries a
+/// leaf flag (`0x20`), an odd-length flag (`0x10`) and, for odd lengths, the
+/// first nibble.
+fn decode_nibbles(encoded: &[u8], is_leaf: bool) -> Result<Vec<u8>, Error> {
+    let (&first, rest) = encoded
+        .split_first()
+        .ok_or_else(|| malformed("empty node key"))?;
+
+    if (first & 0x20 != 0) != is_leaf {
+        return Err(malformed("inconsistent node key flags"));
+    }
+
+    let mut nibbles = Vec::with_capacity(rest.len() * 2 + 1);
+    if first & 0x10 != 0 {
+        nibbles.push(first & 0x0f);
+    }
+    nibbles.extend(to_nibbles(rest));
+    Ok(nibbles)
+}
+
+fn to_nibbles(key: &[u8]) -> Vec<u8> {
+    key.iter().flat_map(|b| [b >> 4, b & 0x0f]).collect()
+}
+
+fn malformed(reason: &str) -> Error {
+    Error::InvalidStateProof {
+        reason: reason.to_string(),
+    }
+}
+
+/// Returns the state trie key of the storage entry `key` of the contract
+/// deployed at `account_id`.
+pub fn contract_data_key(account_id: &str, key: &[u8]) -> Vec<u8> {
+    let mut trie_key = Vec::with_capacity(1 + account_id.len() + 1 + key.len());
+    trie_key.push(CONTRACT_DATA_COLUMN);
+    trie_key.extend_from_slice(account_id.as_bytes());
+    trie_key.push(ACCOUNT_DATA_SEPARATOR);
+    trie_key.extend_from_slice(key);
+    trie_key
+}
+
+/// Looks up `key` in the trie with the given `root`, using only the nodes in
+/// `proof`.
+///
+/// Returns `Ok(None)` if the proof shows that the key is absent, and an error
+/// if the proof is malformed or lacks a node needed to complete the lookup.
+pub fn read_proof_check(
+    root: &CryptoHash,
+    proof: &[Vec<u8>],
+    key: &[u8],
+) -> Result<Option<ValueRef>, Error> {
+    let nodes: BTreeMap<CryptoHash, &[u8]> = proof
+        .iter()
+        .map(|node| (sha256(node), node.as_slice()))
+        .collect();
+
+    let get = |hash: &CryptoHash| -> Result<&[u8], Error> {
+        nodes
+            .get(hash)
+            .copied()
+            .ok_or_else(|| Error::IncompleteStateProof { hash: hex(hash) })
+    };
+
+    let nibbles = to_nibbles(key);
+    let mut remaining = nibbles.as_slice();
+    let mut encoded = get(root)?;
+
+    loop {
+        match decode_node(encoded)? {
+            Node::Leaf { key, value } => {
+                return Ok((remaining == key.as_slice()).then_some(value));
+            }
+            Node::Extension { key, child } => {
+                if !remaining.starts_with(&key) {
+                    return Ok(None);
+                }
+                remaining = &remaining[key.len()..];
+                encoded = get(&child)?;
+            }
+            Node::Branch { value, children } => {
+                let Some((&index, rest)) = remaining.split_first() else {
+                    return Ok(value);
+                };
+
+                match &children[usize::from(index)] {
+                    None => return Ok(None),
+                    Some(child) => encoded = get(child)?,
+                }
+                remaining = rest;
+            }
+        }
+    }
+}
+
+/// Verifies that `key` maps to `expected` in the trie with the given `root`.
+pub fn verify_membership(
+    root: &CryptoHash,
+    proof: &[Vec<u8>],
+    key: &[u8],
+    expected: &[u8],
+) -> Result<(), Error> {
+    let expected_ref = ValueRef::of(expected)?;
+
+    match read_proof_check(root, proof, key)? {
+        Some(value) if value == expected_ref => Ok(()),
+        actual => Err(Error::StateValueMismatch {
+            expected: expected.to_vec(),
+            actual: actual.map(|value| value.hash.to_vec()),
+        }),
+    }
+}
+
+/// Verifies that `key` is absent from the trie with the given `root`.
+pub fn verify_non_membership(
+    root: &CryptoHash,
+    proof: &[Vec<u8>],
+    key: &[u8],
+) -> Result<(), Error> {
+    match read_proof_check(root, proof, key)? {
+        None => Ok(()),
+        Some(value) => Err(Error::StateValueMismatch {
+            expected: Vec::new(),
+            actual: Some(value.hash.to_vec()),
+        }),
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+
+    fn encode_nibbles(nibbles: &[u8], is_leaf: bool) -> Vec<u8> {
+        let flag = if is_leaf { 0x20 } else { 0x00 };
+        let (first, rest) = if nibbles.len() % 2 == 1 {
+            (flag | 0x10 | nibbles[0], &nibbles[1..])
+        } else {
+            (flag, nibbles)
+        };
+        let mut out = vec![first];
+        out.extend(rest.chunks(2).map(|c| (c[0] << 4) | c[1]));
+        out
+    }
+
+    fn push_bytes(node: &mut Vec<u8>, bytes: &[u8]) {
+        node.extend((bytes.len() as u32).to_le_bytes());
+        node.extend_from_slice(bytes);
+    }
+
+    fn push_value(node: &mut Vec<u8>, value: &[u8]) {
+        node.extend((value.len() as u32).to_le_bytes());
+        node.extend(sha256(value));
+    }
+
+    fn leaf(nibbles: &[u8], value: &[u8]) -> Vec<u8> {
+        let mut node = vec![0];
+        push_bytes(&mut node, &encode_nibbles(nibbles, true));
+        push_value(&mut node, value);
+        node.extend(0u64.to_le_bytes());
+        node
+    }
+
+    fn branch(children: &[(u8, CryptoHash)]) -> Vec<u8> {
+        let mut node = vec![1];
+        let bitmap = children.iter().fold(0u16, |acc, (i, _)| acc | (1 << i));
+        node.extend(bitmap.to_le_bytes());
+        for (_, child) in children {
+            node.extend(child);
+        }
+        node.extend(0u64.to_le_bytes());
+        node
+    }
+
+    fn extension(nibbles: &[u8], child: CryptoHash) -> Vec<u8> {
+        let mut node = vec![3];
+        push_bytes(&mut node, &encode_nibbles(nibbles, false));
+        node.extend(child);
+        node.extend(0u64.to_le_bytes());
+        node
+    }
+
+    /// Returns the root and proof of a trie holding a single entry.
+    pub(crate) fn single_entry_trie(key: &[u8], value: &[u8]) -> (CryptoHash, Vec<Vec<u8>>) {
+        let node = leaf(&to_nibbles(key), value);
+        (sha256(&node), vec![node])
+    }
+
+    #[test]
+    fn single_leaf_proof() {
+        let (root, proof) = single_entry_trie(b"key", b"value");
+
+        assert_eq!(
+            read_proof_check(&root, &proof, b"key").expect("valid proof"),
+            Some(ValueRef::of(b"value").expect("never fails"))
+        );
+        assert!(verify_membership(&root, &proof, b"key", b"value").is_ok());
+        assert!(verify_membership(&root, &proof, b"key", b"other").is_err());
+        assert!(verify_non_membership(&root, &proof, b"kez").is_ok());
+    }
+
+    #[test]
+    fn extension_and_branch_proof() {
+        // Keys 0xab and 0xac share the nibble `a`, held by an extension
+        // pointing to a branch with children `b` and `c`.
+        let leaf_b = leaf(&[], b"b-value");
+        let leaf_c = leaf(&[], b"c-value");
+        let branch_node = branch(&[(0xb, sha256(&leaf_b)), (0xc, sha256(&leaf_c))]);
+        let root_node = extension(&[0xa], sha256(&branch_node));
+        let root = sha256(&root_node);
+        let proof = vec![root_node.clone(), branch_node.clone(), leaf_b.clone()];
+
+        assert!(verify_membership(&root, &proof, &[0xab], b"b-value").is_ok());
+        assert!(verify_non_membership(&root, &proof, &[0xad]).is_ok());
+        assert!(verify_non_membership(&root, &proof, &[0xbb]).is_ok());
+
+        // the leaf of 0xac is not part of the proof
+        assert!(matches!(
+            read_proof_check(&root, &proof, &[0xac]),
+            Err(Error::IncompleteStateProof { .. })
+        ));
+    }
+
+    #[test]
+    fn odd_nibble_keys() {
+        assert_eq!(
+            decode_nibbles(&encode_nibbles(&[1, 2, 3], true), true).expect("never fails"),
+            vec![1, 2, 3]
+        );
+        assert!(decode_nibbles(&encode_nibbles(&[1, 2], true), false).is_err());
+    }
+}
@@ -0,0 +1,17 @@
+//! Data structures and domain types of the NEAR light client.
+
+mod block;
+mod client_state;
+mod consensus_state;
+mod header;
+mod misbehaviour;
+mod proof;
+
+pub use block::*;
+pub use client_state::*;
+pub use consensus_state::*;
+pub use header::*;
+pub use misbehaviour::*;
+pub use proof::*;
+
+pub mod proto;
@@ -0,0 +1,286 @@
+//! Borsh-encoded NEAR primitives needed to follow the chain: light client
+//! blocks, block producers, and their keys and signatures.
+
+use borsh::{BorshDeserialize, BorshSerialize};
+use ed25519_consensus::{Signature as Ed25519Signature, VerificationKey};
+
+use crate::error::Error;
+use crate::hashing::{combine_hash, sha256, CryptoHash};
+use ibc_primitives::prelude::*;
+
+/// A NEAR public key.
+///
+/// Block producers must use ed25519 keys, so this is the only key type
+/// supported by the light client.
+#[derive(Clone, Debug, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub enum PublicKey {
+    Ed25519([u8; 32]),
+}
+
+/// A NEAR signature.
+#[derive(Clone, Debug, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub enum Signature {
+    Ed25519([u8; 64]),
+}
+
+impl PublicKey {
+    pub fn verify(&self, message: &[u8], signature: &Signature) -> bool {
+        match (self, signature) {
+            (Self::Ed25519(key), Signature::Ed25519(signature)) => VerificationKey::try_from(*key)
+                .and_then(|key| key.verify(&Ed25519Signature::from(*signature), message))
+                .is_ok(),
+        }
+    }
+}
+
+/// A block producer of an epoch and its stake.
+#[derive(Clone, Debug, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct ValidatorStakeV1 {
+    pub account_id: String,
+    pub public_key: PublicKey,
+    pub stake: u128,
+}
+
+/// A versioned block producer entry, as found in `next_bps`.
+#[derive(Clone, Debug, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub enum ValidatorStake {
+    V1(ValidatorStakeV1),
+}
+
+impl ValidatorStake {
+    pub fn account_id(&self) -> &str {
+        match self {
+            Self::V1(v1) => &v1.account_id,
+        }
+    }
+
+    pub fn public_key(&self) -> &PublicKey {
+        match self {
+            Self::V1(v1) => &v1.public_key,
+        }
+    }
+
+    pub fn stake(&self) -> u128 {
+        match self {
+            Self::V1(v1) => v1.stake,
+        }
+    }
+}
+
+/// The part of a block header a light client needs to know about.
+#[derive(Clone, Debug, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct BlockHeaderInnerLite {
+    pub height: u64,
+    pub epoch_id: CryptoHash,
+    pub next_epoch_id: CryptoHash,
+    /// Merkle root of the state roots of all shards after applying the
+    /// chunks of the previous block.
+    pub prev_state_root: CryptoHash,
+    pub outcome_root: CryptoHash,
+    /// Unix timestamp in nanoseconds.
+    pub timestamp: u64,
+    /// Hash of the block producers of the next epoch.
+    pub next_bp_hash: CryptoHash,
+    pub block_merkle_root: CryptoHash,
+}
+
+/// A light client block, as returned by the `next_light_client_block` RPC.
+#[derive(Clone, Debug, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct LightClientBlock {
+    pub prev_block_hash: CryptoHash,
+    pub next_block_inner_hash: CryptoHash,
+    pub inner_lite: BlockHeaderInnerLite,
+    pub inner_rest_hash: CryptoHash,
+    /// The block producers of the next epoch, present in the last block of
+    /// an epoch.
+    pub next_bps: Option<Vec<ValidatorStake>>,
+    /// Approvals by the block producers of the block's epoch, in order, for
+    /// the block two heights above this one.
+    pub approvals_after_next: Vec<Option<Signature>>,
+}
+
+/// The approval message signed by block producers, tagged with its variant
+/// index.
+#[derive(BorshSerialize)]
+enum ApprovalInner {
+    Endorsement(CryptoHash),
+}
+
+impl LightClientBlock {
+    /// Returns the hash of this block.
+    pub fn current_block_hash(&self) -> CryptoHash {
+        let inner_lite = borsh_encode(&self.inner_lite);
+        combine_hash(
+            &combine_hash(&sha256(&inner_lite), &self.inner_rest_hash),
+            &self.prev_block_hash,
+        )
+    }
+
+    /// Returns the hash of the block following this one.
+    pub fn next_block_hash(&self) -> CryptoHash {
+        combine_hash(&self.next_block_inner_hash, &self.current_block_hash())
+    }
+
+    /// Returns the message signed by block producers approving this block,
+    /// i.e. an endorsement of the next block at two heights above.
+    pub fn approval_message(&self) -> Vec<u8> {
+        let mut message = borsh_encode(&ApprovalInner::Endorsement(self.next_block_hash()));
+        message.extend_from_slice(&(self.inner_lite.height + 2).to_le_bytes());
+        message
+    }
+
+    pub fn height(&self) -> u64 {
+        self.inner_lite.height
+    }
+
+    /// Verifies that the block is approved by more than two thirds of the
+    /// stake of `block_producers`, the block producers of its epoch, and
+    /// that the announced next block producers match `next_bp_hash`.
+    pub fn verify_approvals(&self, block_producers: &[ValidatorStake]) -> Result<(), Error> {
+        if self.approvals_after_next.len() > block_producers.len() {
+            return Err(Error::TooManyApprovals {
+                approvals: self.approvals_after_next.len(),
+                producers: block_producers.len(),
+            });
+        }
+
+        let message = self.approval_message();
+        let mut total_stake = 0u128;
+        let mut approved_stake = 0u128;
+
+        for (index, producer) in block_producers.iter().enumerate() {
+            total_stake = total_stake
+                .checked_add(producer.stake())
+                .ok_or(Error::StakeOverflow)?;
+
+            let Some(Some(signature)) = self.approvals_after_next.get(index) else {
+                continue;
+            };
+
+            if !producer.public_key().verify(&message, signature) {
+                return Err(Error::InvalidApprovalSignature {
+                    account_id: producer.account_id().to_string(),
+                });
+            }
+
+            approved_stake = approved_stake
+                .checked_add(producer.stake())
+                .ok_or(Error::StakeOverflow)?;
+        }
+
+        let approved = approved_stake.checked_mul(3).ok_or(Error::StakeOverflow)?;
+        let needed = total_stake.checked_mul(2).ok_or(Error::StakeOverflow)?;
+        if approved <= needed {
+            return Err(Error::InsufficientApprovedStake {
+                approved: approved_stake,
+                total: total_stake,
+            });
+        }
+
+        if let Some(next_bps) = &self.next_bps {
+            if sha256(&borsh_encode(next_bps)) != self.inner_lite.next_bp_hash {
+                return Err(Error::MismatchedNextBlockProducers);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+pub(crate) fn borsh_encode<T: BorshSerialize>(value: &T) -> Vec<u8> {
+    value
+        .try_to_vec()
+        .expect("never fails: serializing to a vector is infallible")
+}
+
+#[cfg(test)]
+mod tests {
+    use ed25519_consensus::SigningKey;
+
+    use super::*;
+
+    fn producers(keys: &[SigningKey]) -> Vec<ValidatorStake> {
+        keys.iter()
+            .enumerate()
+            .map(|(i, key)| {
+                ValidatorStake::V1(ValidatorStakeV1 {
+                    account_id: format!("validator-{i}.near"),
+                    public_key: PublicKey::Ed25519(key.verification_key().to_bytes()),
+                    stake: 100,
+                })
+            })
+            .collect()
+    }
+
+    fn approve(block: &mut LightClientBlock, keys: &[SigningKey]) {
+        let message = block.approval_message();
+        block.approvals_after_next = keys
+            .iter()
+            .map(|key| Some(Signature::Ed25519(key.sign(&message).to_bytes())))
+            .collect();
+    }
+
+    fn dummy_block(height: u64, epoch_id: CryptoHash) -> LightClientBlock {
+        LightClientBlock {
+            prev_block_hash: [1; 32],
+            next_block_inner_hash: [2; 32],
+            inner_lite: BlockHeaderInnerLite {
+                height,
+                epoch_id,
+                next_epoch_id: [height as u8; 32],
+                prev_state_root: [3; 32],
+                outcome_root: [4; 32],
+                timestamp: 1_700_000_000_000_000_000 + height,
+                next_bp_hash: [5; 32],
+                block_merkle_root: [6; 32],
+            },
+            inner_rest_hash: [7; 32],
+            next_bps: None,
+            approvals_after_next: vec![],
+        }
+    }
+
+    #[test]
+    fn verify_approvals() {
+        let keys: Vec<_> = (1..=4).map(|seed| SigningKey::from([seed; 32])).collect();
+        let bps = producers(&keys);
+        let mut block = dummy_block(10, [0; 32]);
+
+        approve(&mut block, &keys[..3]);
+        assert!(block.verify_approvals(&bps).is_ok());
+
+        // exactly two thirds is not enough
+        let bps_of_three = producers(&keys[..3]);
+        approve(&mut block, &keys[..2]);
+        assert!(matches!(
+            block.verify_approvals(&bps_of_three),
+            Err(Error::InsufficientApprovedStake { .. })
+        ));
+
+        // signature by the wrong key
+        approve(&mut block, &[keys[1].clone(), keys[0].clone(), keys[2].clone()]);
+        assert!(matches!(
+            block.verify_approvals(&bps),
+            Err(Error::InvalidApprovalSignature { .. })
+        ));
+    }
+
+    #[test]
+    fn verify_next_block_producers_hash() {
+        let keys: Vec<_> = (1..=3).map(|seed| SigningKey::from([seed; 32])).collect();
+        let bps = producers(&keys);
+        let mut block = dummy_block(10, [0; 32]);
+
+        block.next_bps = Some(bps.clone());
+        block.inner_lite.next_bp_hash = sha256(&borsh_encode(&bps));
+        approve(&mut block, &keys);
+        assert!(block.verify_approvals(&bps).is_ok());
+
+        block.next_bps = Some(bps[..2].to_vec());
+        approve(&mut block, &keys);
+        assert!(matches!(
+            block.verify_approvals(&bps),
+            Err(Error::MismatchedNextBlockProducers)
+        ));
+    }
+}
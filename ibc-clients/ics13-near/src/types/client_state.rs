@@ -0,0 +1,186 @@
+//! Defines the client state type for the NEAR light client.
+
+use core::str::FromStr;
+
+use borsh::BorshDeserialize;
+use ibc_core_client::types::error::ClientError;
+use ibc_core_client::types::Height;
+use ibc_core_host::types::identifiers::ChainId;
+use ibc_primitives::prelude::*;
+use ibc_primitives::proto::{Any, Protobuf};
+
+use super::block::{borsh_encode, BlockHeaderInnerLite, ValidatorStake};
+use super::proto::ClientState as RawNearClientState;
+use crate::error::Error;
+use crate::hashing::CryptoHash;
+
+pub const NEAR_CLIENT_STATE_TYPE_URL: &str = "/ibc.lightclients.near.v1.ClientState";
+
+/// Contains the state of a NEAR client.
+///
+/// The client tracks the latest verified block (its `head`) and the block
+/// producers of the head's epoch and of the next epoch. NEAR has no notion
+/// of revisions, so client heights have a revision number of 0 and the
+/// block height as their revision height.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ClientState {
+    pub chain_id: ChainId,
+    /// The account of the IBC contract whose storage holds the IBC
+    /// commitments.
+    pub ibc_account_id: String,
+    pub head: BlockHeaderInnerLite,
+    pub head_hash: CryptoHash,
+    pub current_bps: Vec<ValidatorStake>,
+    pub next_bps: Vec<ValidatorStake>,
+    pub frozen_height: Option<Height>,
+}
+
+impl ClientState {
+    pub fn new(
+        chain_id: ChainId,
+        ibc_account_id: String,
+        head: BlockHeaderInnerLite,
+        head_hash: CryptoHash,
+        current_bps: Vec<ValidatorStake>,
+        next_bps: Vec<ValidatorStake>,
+    ) -> Result<Self, Error> {
+        let client_state = Self {
+            chain_id,
+            ibc_account_id,
+            head,
+            head_hash,
+            current_bps,
+            next_bps,
+            frozen_height: None,
+        };
+        client_state.validate()?;
+        Ok(client_state)
+    }
+
+    pub fn validate(&self) -> Result<(), Error> {
+        if self.ibc_account_id.is_empty() {
+            return Err(Error::InvalidRawClientState {
+                reason: "IBC account id cannot be empty".to_string(),
+            });
+        }
+
+        if self.head.height == 0 {
+            return Err(Error::InvalidRawClientState {
+                reason: "head height cannot be zero".to_string(),
+            });
+        }
+
+        if self.current_bps.is_empty() {
+            return Err(Error::InvalidRawClientState {
+                reason: "current block producers cannot be empty".to_string(),
+            });
+        }
+
+        Ok(())
+    }
+
+    pub fn latest_height(&self) -> Height {
+        Height::new(0, self.head.height).expect("head height is validated to be non-zero")
+    }
+
+    /// Returns the block producers of the given epoch, if known.
+    pub fn epoch_block_producers(&self, epoch_id: &CryptoHash) -> Option<&[ValidatorStake]> {
+        if *epoch_id == self.head.epoch_id {
+            Some(&self.current_bps)
+        } else if *epoch_id == self.head.next_epoch_id {
+            Some(&self.next_bps)
+        } else {
+            None
+        }
+    }
+
+    pub fn is_frozen(&self) -> bool {
+        self.frozen_height.is_some()
+    }
+
+    pub fn with_frozen_height(self, h: Height) -> Self {
+        Self {
+            frozen_height: Some(h),
+            ..self
+        }
+    }
+}
+
+impl Protobuf<RawNearClientState> for ClientState {}
+
+impl TryFrom<RawNearClientState> for ClientState {
+    type Error = Error;
+
+    fn try_from(raw: RawNearClientState) -> Result<Self, Self::Error> {
+        let chain_id = ChainId::from_str(&raw.chain_id).map_err(|e| Error::InvalidRawClientState {
+            reason: e.to_string(),
+        })?;
+
+        let head_hash = raw
+            .head_hash
+            .try_into()
+            .map_err(|_| Error::InvalidRawClientState {
+                reason: "head hash must be 32 bytes".to_string(),
+            })?;
+
+        let client_state = Self {
+            chain_id,
+            ibc_account_id: raw.ibc_account_id,
+            head: BlockHeaderInnerLite::try_from_slice(&raw.head)?,
+            head_hash,
+            current_bps: Vec::<ValidatorStake>::try_from_slice(&raw.current_bps)?,
+            next_bps: Vec::<ValidatorStake>::try_from_slice(&raw.next_bps)?,
+            frozen_height: raw
+                .frozen_height
+                .and_then(|raw_height| raw_height.try_into().ok()),
+        };
+        client_state.validate()?;
+        Ok(client_state)
+    }
+}
+
+impl From<ClientState> for RawNearClientState {
+    fn from(value: ClientState) -> Self {
+        Self {
+            chain_id: value.chain_id.to_string(),
+            ibc_account_id: value.ibc_account_id,
+            head: borsh_encode(&value.head),
+            head_hash: value.head_hash.to_vec(),
+            current_bps: borsh_encode(&value.current_bps),
+            next_bps: borsh_encode(&value.next_bps),
+            frozen_height: value.frozen_height.map(|height| height.into()),
+        }
+    }
+}
+
+impl Protobuf<Any> for ClientState {}
+
+impl TryFrom<Any> for ClientState {
+    type Error = ClientError;
+
+    fn try_from(raw: Any) -> Result<Self, Self::Error> {
+        fn decode_client_state(value: &[u8]) -> Result<ClientState, ClientError> {
+            let client_state =
+                Protobuf::<RawNearClientState>::decode(value).map_err(|e| ClientError::Other {
+                    description: e.to_string(),
+                })?;
+            Ok(client_state)
+        }
+
+        match raw.type_url.as_str() {
+            NEAR_CLIENT_STATE_TYPE_URL => decode_client_state(&raw.value),
+            _ => Err(ClientError::UnknownClientStateType {
+                client_state_type: raw.type_url,
+            }),
+        }
+    }
+}
+
+impl From<ClientState> for Any {
+    fn from(client_state: ClientState) -> Self {
+        Any {
+            type_url: NEAR_CLIENT_STATE_TYPE_URL.to_string(),
+            value: Protobuf::<RawNearClientState>::encode_vec(client_state),
+        }
+    }
+}
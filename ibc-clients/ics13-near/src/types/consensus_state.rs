@@ -0,0 +1,117 @@
+//! Defines the consensus state type for the NEAR light client.
+
+use ibc_core_client::types::error::ClientError;
+use ibc_core_commitment_types::commitment::CommitmentRoot;
+use ibc_primitives::prelude::*;
+use ibc_primitives::proto::{Any, Protobuf};
+use ibc_primitives::Timestamp;
+
+use super::proto::ConsensusState as RawNearConsensusState;
+use crate::error::Error;
+use crate::hashing::CryptoHash;
+
+pub const NEAR_CONSENSUS_STATE_TYPE_URL: &str = "/ibc.lightclients.near.v1.ConsensusState";
+
+/// Defines the NEAR consensus state, i.e. the `prev_state_root` and timestamp
+/// of a verified block.
+///
+/// The root commits to the state roots of all shards, which state proofs
+/// open through a merkle path.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ConsensusState {
+    pub timestamp: Timestamp,
+    pub root: CommitmentRoot,
+}
+
+impl ConsensusState {
+    pub fn new(root: CommitmentRoot, timestamp: Timestamp) -> Self {
+        Self { timestamp, root }
+    }
+
+    pub fn root(&self) -> &CommitmentRoot {
+        &self.root
+    }
+
+    pub fn timestamp(&self) -> Timestamp {
+        self.timestamp
+    }
+
+    /// Returns the root as a merkle root hash.
+    pub fn state_root(&self) -> Result<CryptoHash, Error> {
+        state_root(&self.root)
+    }
+}
+
+/// Converts a commitment root into the 32-byte merkle root of the shard
+/// state roots.
+pub fn state_root(root: &CommitmentRoot) -> Result<CryptoHash, Error> {
+    root.as_bytes()
+        .try_into()
+        .map_err(|_| Error::InvalidStateProof {
+            reason: "state root must be 32 bytes".to_string(),
+        })
+}
+
+impl Protobuf<RawNearConsensusState> for ConsensusState {}
+
+impl TryFrom<RawNearConsensusState> for ConsensusState {
+    type Error = Error;
+
+    fn try_from(raw: RawNearConsensusState) -> Result<Self, Self::Error> {
+        if raw.root.len() != 32 {
+            return Err(Error::InvalidRawConsensusState {
+                reason: "root must be 32 bytes".to_string(),
+            });
+        }
+
+        let timestamp = Timestamp::from_nanoseconds(raw.timestamp).map_err(|e| {
+            Error::InvalidRawConsensusState {
+                reason: e.to_string(),
+            }
+        })?;
+
+        Ok(Self::new(CommitmentRoot::from_bytes(&raw.root), timestamp))
+    }
+}
+
+impl From<ConsensusState> for RawNearConsensusState {
+    fn from(value: ConsensusState) -> Self {
+        Self {
+            timestamp: value.timestamp.nanoseconds(),
+            root: value.root.into_vec(),
+        }
+    }
+}
+
+impl Protobuf<Any> for ConsensusState {}
+
+impl TryFrom<Any> for ConsensusState {
+    type Error = ClientError;
+
+    fn try_from(raw: Any) -> Result<Self, Self::Error> {
+        fn decode_consensus_state(value: &[u8]) -> Result<ConsensusState, ClientError> {
+            let consensus_state = Protobuf::<RawNearConsensusState>::decode(value).map_err(
+                |e| ClientError::Other {
+                    description: e.to_string(),
+                },
+            )?;
+            Ok(consensus_state)
+        }
+
+        match raw.type_url.as_str() {
+            NEAR_CONSENSUS_STATE_TYPE_URL => decode_consensus_state(&raw.value),
+            _ => Err(ClientError::UnknownConsensusStateType {
+                consensus_state_type: raw.type_url,
+            }),
+        }
+    }
+}
+
+impl From<ConsensusState> for Any {
+    fn from(consensus_state: ConsensusState) -> Self {
+        Any {
+            type_url: NEAR_CONSENSUS_STATE_TYPE_URL.to_string(),
+            value: Protobuf::<RawNearConsensusState>::encode_vec(consensus_state),
+        }
+    }
+}
@@ -0,0 +1,81 @@
+//! Defines the header type for the NEAR light client.
+
+use borsh::BorshDeserialize;
+use ibc_core_client::types::error::ClientError;
+use ibc_core_client::types::Height;
+use ibc_primitives::prelude::*;
+use ibc_primitives::proto::{Any, Protobuf};
+
+use super::block::{borsh_encode, LightClientBlock};
+use super::proto::Header as RawNearHeader;
+use crate::error::Error;
+
+pub const NEAR_HEADER_TYPE_URL: &str = "/ibc.lightclients.near.v1.Header";
+
+/// Defines the NEAR client header, i.e. a light client block.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Header {
+    pub light_client_block: LightClientBlock,
+}
+
+impl Header {
+    pub fn height(&self) -> Result<Height, Error> {
+        Height::new(0, self.light_client_block.height()).map_err(|e| Error::InvalidRawHeader {
+            reason: e.to_string(),
+        })
+    }
+}
+
+impl Protobuf<RawNearHeader> for Header {}
+
+impl TryFrom<RawNearHeader> for Header {
+    type Error = Error;
+
+    fn try_from(raw: RawNearHeader) -> Result<Self, Self::Error> {
+        let header = Self {
+            light_client_block: LightClientBlock::try_from_slice(&raw.light_client_block)?,
+        };
+        header.height()?;
+        Ok(header)
+    }
+}
+
+impl From<Header> for RawNearHeader {
+    fn from(value: Header) -> Self {
+        Self {
+            light_client_block: borsh_encode(&value.light_client_block),
+        }
+    }
+}
+
+impl Protobuf<Any> for Header {}
+
+impl TryFrom<Any> for Header {
+    type Error = ClientError;
+
+    fn try_from(raw: Any) -> Result<Self, Self::Error> {
+        fn decode_header(value: &[u8]) -> Result<Header, ClientError> {
+            let header =
+                Protobuf::<RawNearHeader>::decode(value).map_err(|e| ClientError::Other {
+                    description: e.to_string(),
+                })?;
+            Ok(header)
+        }
+
+        match raw.type_url.as_str() {
+            NEAR_HEADER_TYPE_URL => decode_header(&raw.value),
+            _ => Err(ClientError::UnknownHeaderType {
+                header_type: raw.type_url,
+            }),
+        }
+    }
+}
+
+impl From<Header> for Any {
+    fn from(header: Header) -> Self {
+        Any {
+            type_url: NEAR_HEADER_TYPE_URL.to_string(),
+            value: Protobuf::<RawNearHeader>::encode_vec(header),
+        }
+    }
+}
@@ -0,0 +1,98 @@
+//! Defines the misbehaviour type for the NEAR light client.
+
+use borsh::BorshDeserialize;
+use ibc_core_client::types::error::ClientError;
+use ibc_primitives::prelude::*;
+use ibc_primitives::proto::{Any, Protobuf};
+
+use super::block::{borsh_encode, LightClientBlock};
+use super::proto::Misbehaviour as RawNearMisbehaviour;
+use crate::error::Error;
+use crate::hashing::hex;
+
+pub const NEAR_MISBEHAVIOUR_TYPE_URL: &str = "/ibc.lightclients.near.v1.Misbehaviour";
+
+/// Evidence of the block producers of an epoch approving two different
+/// blocks at the same height.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Misbehaviour {
+    pub block_one: LightClientBlock,
+    pub block_two: LightClientBlock,
+}
+
+impl Misbehaviour {
+    /// Checks that both blocks are distinct and at the same height, without
+    /// verifying their approvals.
+    pub fn validate_basic(&self) -> Result<(), Error> {
+        let hash = self.block_one.current_block_hash();
+        if hash == self.block_two.current_block_hash() {
+            return Err(Error::NotMisbehaviour { hash: hex(&hash) });
+        }
+
+        if self.block_one.height() != self.block_two.height() {
+            return Err(Error::MismatchedHeights {
+                first: self.block_one.height(),
+                second: self.block_two.height(),
+            });
+        }
+
+        Ok(())
+    }
+}
+
+impl Protobuf<RawNearMisbehaviour> for Misbehaviour {}
+
+impl TryFrom<RawNearMisbehaviour> for Misbehaviour {
+    type Error = Error;
+
+    fn try_from(raw: RawNearMisbehaviour) -> Result<Self, Self::Error> {
+        let misbehaviour = Self {
+            block_one: LightClientBlock::try_from_slice(&raw.block_one)?,
+            block_two: LightClientBlock::try_from_slice(&raw.block_two)?,
+        };
+        misbehaviour.validate_basic()?;
+        Ok(misbehaviour)
+    }
+}
+
+impl From<Misbehaviour> for RawNearMisbehaviour {
+    fn from(value: Misbehaviour) -> Self {
+        Self {
+            block_one: borsh_encode(&value.block_one),
+            block_two: borsh_encode(&value.block_two),
+        }
+    }
+}
+
+impl Protobuf<Any> for Misbehaviour {}
+
+impl TryFrom<Any> for Misbehaviour {
+    type Error = ClientError;
+
+    fn try_from(raw: Any) -> Result<Self, Self::Error> {
+        fn decode_misbehaviour(value: &[u8]) -> Result<Misbehaviour, ClientError> {
+            let misbehaviour = Protobuf::<RawNearMisbehaviour>::decode(value).map_err(|e| {
+                ClientError::Other {
+                    description: e.to_string(),
+                }
+            })?;
+            Ok(misbehaviour)
+        }
+
+        match raw.type_url.as_str() {
+            NEAR_MISBEHAVIOUR_TYPE_URL => decode_misbehaviour(&raw.value),
+            _ => Err(ClientError::UnknownMisbehaviourType {
+                misbehaviour_type: raw.type_url,
+            }),
+        }
+    }
+}
+
+impl From<Misbehaviour> for Any {
+    fn from(misbehaviour: Misbehaviour) -> Self {
+        Any {
+            type_url: NEAR_MISBEHAVIOUR_TYPE_URL.to_string(),
+            value: Protobuf::<RawNearMisbehaviour>::encode_vec(misbehaviour),
+        }
+    }
+}
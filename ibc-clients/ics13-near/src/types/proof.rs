@@ -0,0 +1,73 @@
+//! Defines the state proof carried by NEAR membership proofs, and its
+//! conversion from and to the opaque `CommitmentProofBytes`.
+
+use borsh::{BorshDeserialize, BorshSerialize};
+use ibc_core_commitment_types::commitment::CommitmentProofBytes;
+use ibc_primitives::prelude::*;
+
+use super::block::borsh_encode;
+use crate::error::Error;
+use crate::hashing::{compute_root_from_path, sha256, CryptoHash, MerklePath};
+use crate::trie;
+
+/// A proof of (non-)membership of a key in the state of a NEAR block.
+///
+/// The state root of a block header commits to the state roots of all
+/// shards; the proof opens the state root of the shard holding the key with
+/// a merkle path, then walks the shard trie with `nodes`, as returned by the
+/// `view_state` RPC with `include_proof`.
+#[derive(Clone, Debug, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct StateProof {
+    pub shard_state_root: CryptoHash,
+    pub shard_state_root_proof: MerklePath,
+    pub nodes: Vec<Vec<u8>>,
+}
+
+impl StateProof {
+    /// Verifies that the shard state root is committed to by `state_root`,
+    /// the `prev_state_root` of a verified block.
+    pub fn verify_shard_state_root(&self, state_root: &CryptoHash) -> Result<(), Error> {
+        let leaf = sha256(&self.shard_state_root);
+        if compute_root_from_path(&self.shard_state_root_proof, leaf) != *state_root {
+            return Err(Error::InvalidStateProof {
+                reason: "shard state root is not included in the block state root".to_string(),
+            });
+        }
+        Ok(())
+    }
+
+    pub fn verify_membership(
+        &self,
+        state_root: &CryptoHash,
+        key: &[u8],
+        value: &[u8],
+    ) -> Result<(), Error> {
+        self.verify_shard_state_root(state_root)?;
+        trie::verify_membership(&self.shard_state_root, &self.nodes, key, value)
+    }
+
+    pub fn verify_non_membership(&self, state_root: &CryptoHash, key: &[u8]) -> Result<(), Error> {
+        self.verify_shard_state_root(state_root)?;
+        trie::verify_non_membership(&self.shard_state_root, &self.nodes, key)
+    }
+}
+
+impl TryFrom<&CommitmentProofBytes> for StateProof {
+    type Error = Error;
+
+    fn try_from(proof: &CommitmentProofBytes) -> Result<Self, Self::Error> {
+        Ok(Self::try_from_slice(proof.as_ref())?)
+    }
+}
+
+impl TryFrom<StateProof> for CommitmentProofBytes {
+    type Error = Error;
+
+    fn try_from(proof: StateProof) -> Result<Self, Self::Error> {
+        CommitmentProofBytes::try_from(borsh_encode(&proof)).map_err(|e| {
+            Error::InvalidStateProof {
+                reason: e.to_string(),
+            }
+        })
+    }
+}
@@ -0,0 +1,103 @@
+//! Raw (protobuf) representations of the NEAR client types.
+//!
+//! There is no upstream `ibc-proto` definition for these messages yet, so
+//! they are defined here under the `ibc.lightclients.near.v1` package. NEAR
+//! data structures (blocks, block producers) are carried as their borsh
+//! encoding.
+
+use ibc_primitives::prelude::*;
+use ibc_proto::ibc::core::client::v1::Height as RawHeight;
+
+macro_rules! impl_name {
+    ($ty:ty, $name:literal) => {
+        impl prost::Name for $ty {
+            const NAME: &'static str = $name;
+            const PACKAGE: &'static str = "ibc.lightclients.near.v1";
+
+            fn full_name() -> String {
+                format!("{}.{}", Self::PACKAGE, Self::NAME)
+            }
+        }
+    };
+}
+
+/// ```protobuf
+/// message ClientState {
+///   string chain_id = 1;
+///   string ibc_account_id = 2;
+///   bytes head = 3;
+///   bytes head_hash = 4;
+///   bytes current_bps = 5;
+///   bytes next_bps = 6;
+///   ibc.core.client.v1.Height frozen_height = 7;
+/// }
+/// ```
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct ClientState {
+    #[prost(string, tag = "1")]
+    pub chain_id: String,
+    #[prost(string, tag = "2")]
+    pub ibc_account_id: String,
+    /// The borsh-encoded `BlockHeaderInnerLite` of the latest block.
+    #[prost(bytes = "vec", tag = "3")]
+    pub head: Vec<u8>,
+    #[prost(bytes = "vec", tag = "4")]
+    pub head_hash: Vec<u8>,
+    /// The borsh-encoded block producers of the current epoch.
+    #[prost(bytes = "vec", tag = "5")]
+    pub current_bps: Vec<u8>,
+    /// The borsh-encoded block producers of the next epoch.
+    #[prost(bytes = "vec", tag = "6")]
+    pub next_bps: Vec<u8>,
+    #[prost(message, optional, tag = "7")]
+    pub frozen_height: Option<RawHeight>,
+}
+
+impl_name!(ClientState, "ClientState");
+
+/// ```protobuf
+/// message ConsensusState {
+///   uint64 timestamp = 1;
+///   bytes root = 2;
+/// }
+/// ```
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct ConsensusState {
+    /// Unix timestamp in nanoseconds.
+    #[prost(uint64, tag = "1")]
+    pub timestamp: u64,
+    #[prost(bytes = "vec", tag = "2")]
+    pub root: Vec<u8>,
+}
+
+impl_name!(ConsensusState, "ConsensusState");
+
+/// ```protobuf
+/// message Header {
+///   bytes light_client_block = 1;
+/// }
+/// ```
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct Header {
+    /// The borsh-encoded `LightClientBlock`.
+    #[prost(bytes = "vec", tag = "1")]
+    pub light_client_block: Vec<u8>,
+}
+
+impl_name!(Header, "Header");
+
+/// ```protobuf
+/// message Misbehaviour {
+///   bytes block_one = 1;
+///   bytes block_two = 2;
+/// }
+/// ```
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct Misbehaviour {
+    #[prost(bytes = "vec", tag = "1")]
+    pub block_one: Vec<u8>,
+    #[prost(bytes = "vec", tag = "2")]
+    pub block_two: Vec<u8>,
+}
+
+impl_name!(Misbehaviour, "Misbehaviour");
@@ -0,0 +1,257 @@
+//! This module includes the implementations of the `ClientStateCommon`,
+//! `ClientStateValidation`, and `ClientStateExecution` traits for the Solana
+//! client state.
+//!
+//! As with the other clients, the logic behind each trait method is also
+//! exposed as a standalone function, so that hosts wrapping the Solana
+//! client can reuse it.
+
+mod common;
+mod execution;
+mod validation;
+
+pub use common::*;
+pub use execution::*;
+pub use validation::*;
+
+#[cfg(test)]
+mod tests {
+    use core::str::FromStr;
+
+    use ed25519_consensus::SigningKey;
+    use ibc_core_commitment_types::specs::ProofSpecs;
+    use ibc_core_host::types::identifiers::ChainId;
+    use ibc_primitives::prelude::*;
+    use ibc_primitives::proto::Any;
+
+    use super::*;
+    use crate::error::Error;
+    use crate::types::{
+        clock_account, sign, signing_keys, validators, Account, AccountProof, BankHashComponents,
+        ClientState, Header, Misbehaviour, ProofLevel,
+    };
+
+    const UNIX_TIMESTAMP: i64 = 1_700_000_000;
+    const STORAGE_ACCOUNT: [u8; 32] = [20; 32];
+    const PROGRAM_ID: [u8; 32] = [21; 32];
+
+    fn dummy_client_state(keys: &[SigningKey]) -> ClientState {
+        let client_state = ClientState {
+            chain_id: ChainId::from_str("solana-0").expect("never fails"),
+            latest_slot: 150,
+            slots_per_epoch: 100,
+            current_validators: validators(keys),
+            next_validators: None,
+            ibc_storage_account: STORAGE_ACCOUNT,
+            ibc_program_id: PROGRAM_ID,
+            proof_specs: ProofSpecs::cosmos(),
+            frozen_height: None,
+        };
+        client_state.validate().expect("never fails");
+        client_state
+    }
+
+    fn storage_account(root: [u8; 32]) -> Account {
+        let mut data = root.to_vec();
+        data.extend_from_slice(&[0; 32]);
+
+        Account {
+            pubkey: STORAGE_ACCOUNT,
+            lamports: 1_000_000,
+            owner: PROGRAM_ID,
+            executable: false,
+            rent_epoch: 0,
+            data,
+        }
+    }
+
+    /// Returns a header for `slot` signed by `keys`, where `storage` and
+    /// `clock` are the only accounts modified in the slot.
+    fn dummy_header(keys: &[SigningKey], slot: u64, storage: Account, clock: Account) -> Header {
+        let storage_proof = AccountProof {
+            path: vec![ProofLevel {
+                index: 0,
+                siblings: vec![clock.hash()],
+            }],
+            account: storage.clone(),
+        };
+        let clock_proof = AccountProof {
+            path: vec![ProofLevel {
+                index: 1,
+                siblings: vec![storage.hash()],
+            }],
+            account: clock,
+        };
+
+        let mut header = Header {
+            slot,
+            bank: BankHashComponents {
+                parent_bank_hash: [1; 32],
+                accounts_delta_hash: storage_proof.compute_root().expect("never fails"),
+                signature_count: 3,
+                last_blockhash: [2; 32],
+            },
+            next_validators: None,
+            votes: vec![],
+            storage_proof,
+            clock_proof,
+        };
+        header.votes = sign(keys, &header.vote_message());
+        header
+    }
+
+    fn valid_header(keys: &[SigningKey], slot: u64) -> Header {
+        dummy_header(
+            keys,
+            slot,
+            storage_account([9; 32]),
+            clock_account(slot, UNIX_TIMESTAMP),
+        )
+    }
+
+    #[test]
+    fn client_state_any_roundtrip() {
+        let client_state = dummy_client_state(&signing_keys());
+        let any: Any = client_state.clone().into();
+        assert_eq!(
+            ClientState::try_from(any).expect("never fails"),
+            client_state
+        );
+    }
+
+    #[test]
+    fn header_any_roundtrip() {
+        let keys = signing_keys();
+        let header = valid_header(&keys[..3], 160);
+        let any: Any = header.clone().into();
+        assert_eq!(Header::try_from(any).expect("never fails"), header);
+    }
+
+    #[test]
+    fn verify_valid_header() {
+        let keys = signing_keys();
+        let client_state = dummy_client_state(&keys);
+        let header = valid_header(&keys[..3], 160);
+
+        assert!(verify_client_message(&client_state, header.clone().into()).is_ok());
+
+        let consensus_state = verify_header(&client_state, &header).expect("never fails");
+        assert_eq!(consensus_state.root.as_bytes(), [9; 32]);
+        assert_eq!(
+            consensus_state.timestamp.nanoseconds(),
+            UNIX_TIMESTAMP as u64 * 1_000_000_000
+        );
+        assert_eq!(consensus_state.bank_hash, header.bank_hash());
+    }
+
+    #[test]
+    fn verify_header_rejects_insufficient_votes() {
+        let keys = signing_keys();
+        let client_state = dummy_client_state(&keys);
+
+        let header = valid_header(&keys[..2], 160);
+        assert!(matches!(
+            verify_header(&client_state, &header),
+            Err(Error::InsufficientVotedStake { .. })
+        ));
+
+        let stale = valid_header(&keys[..3], 150);
+        assert!(matches!(
+            verify_header(&client_state, &stale),
+            Err(Error::StaleSlot { .. })
+        ));
+    }
+
+    #[test]
+    fn verify_header_rejects_unexpected_accounts() {
+        let keys = signing_keys();
+        let client_state = dummy_client_state(&keys);
+
+        let header = dummy_header(
+            &keys,
+            160,
+            storage_account([9; 32]),
+            clock_account(159, UNIX_TIMESTAMP),
+        );
+        assert!(matches!(
+            verify_header(&client_state, &header),
+            Err(Error::InvalidClock { .. })
+        ));
+
+        let mut storage = storage_account([9; 32]);
+        storage.owner = [22; 32];
+        let header = dummy_header(&keys, 160, storage, clock_account(160, UNIX_TIMESTAMP));
+        assert!(matches!(
+            verify_header(&client_state, &header),
+            Err(Error::UnexpectedAccount { .. })
+        ));
+
+        let mut header = valid_header(&keys, 160);
+        header.storage_proof.account.data[0] = 0;
+        assert!(matches!(
+            verify_header(&client_state, &header),
+            Err(Error::MismatchedAccountsDeltaHash)
+        ));
+    }
+
+    #[test]
+    fn verify_header_in_next_epoch() {
+        let keys = signing_keys();
+        let mut client_state = dummy_client_state(&keys[..3]);
+
+        let header = valid_header(&keys[1..], 210);
+        assert!(matches!(
+            verify_header(&client_state, &header),
+            Err(Error::UnknownNextValidators)
+        ));
+
+        client_state.next_validators = Some(validators(&keys[1..]));
+        assert!(verify_header(&client_state, &header).is_ok());
+
+        // the next validators a header attests to must match known ones
+        let mut header = valid_header(&keys[..3], 160);
+        header.next_validators = Some(validators(&keys));
+        header.votes = sign(&keys[..3], &header.vote_message());
+        assert!(matches!(
+            verify_header(&client_state, &header),
+            Err(Error::MismatchedNextValidators)
+        ));
+
+        let header = valid_header(&keys[1..], 310);
+        assert!(matches!(
+            verify_header(&client_state, &header),
+            Err(Error::UnknownEpoch { .. })
+        ));
+    }
+
+    #[test]
+    fn verify_misbehaviour_with_conflicting_bank_hashes() {
+        let keys = signing_keys();
+        let client_state = dummy_client_state(&keys);
+
+        let header_1 = valid_header(&keys[..3], 160);
+        let mut header_2 = header_1.clone();
+        header_2.bank.signature_count = 4;
+        header_2.votes = sign(&keys[..3], &header_2.vote_message());
+
+        let misbehaviour = Misbehaviour {
+            header_1: header_1.clone(),
+            header_2: header_2.clone(),
+        };
+        assert!(verify_client_message(&client_state, misbehaviour.into()).is_ok());
+
+        let same = Misbehaviour {
+            header_1: header_1.clone(),
+            header_2: header_1.clone(),
+        };
+        assert!(verify_misbehaviour(&client_state, &same).is_err());
+
+        let mut forged = header_2;
+        forged.votes = header_1.votes.clone();
+        let forged = Misbehaviour {
+            header_1,
+            header_2: forged,
+        };
+        assert!(verify_misbehaviour(&client_state, &forged).is_err());
+    }
+}
@@ -0,0 +1,219 @@
+use ibc_core_client::context::client_state::ClientStateValidation;
+use ibc_core_client::context::consensus_state::ConsensusState as _;
+use ibc_core_client::context::ClientValidationContext;
+use ibc_core_client::types::error::ClientError;
+use ibc_core_client::types::Status;
+use ibc_core_commitment_types::commitment::CommitmentRoot;
+use ibc_core_host::types::identifiers::ClientId;
+use ibc_core_host::types::path::ClientConsensusStatePath;
+use ibc_primitives::prelude::*;
+use ibc_primitives::proto::Any;
+use ibc_primitives::Timestamp;
+
+use crate::error::Error;
+use crate::types::{
+    verify_votes, ClientState as ClientStateType, Clock, ConsensusState as ConsensusStateType,
+    Header as SolanaHeader, Misbehaviour as SolanaMisbehaviour, SOLANA_HEADER_TYPE_URL,
+    SOLANA_MISBEHAVIOUR_TYPE_URL,
+};
+
+impl<V> ClientStateValidation<V> for ClientStateType
+where
+    V: ClientValidationContext,
+{
+    fn verify_client_message(
+        &self,
+        _ctx: &V,
+        _client_id: &ClientId,
+        client_message: Any,
+    ) -> Result<(), ClientError> {
+        verify_client_message(self, client_message)
+    }
+
+    fn check_for_misbehaviour(
+        &self,
+        ctx: &V,
+        client_id: &ClientId,
+        client_message: Any,
+    ) -> Result<bool, ClientError> {
+        check_for_misbehaviour(self, ctx, client_id, client_message)
+    }
+
+    fn status(&self, _ctx: &V, _client_id: &ClientId) -> Result<Status, ClientError> {
+        Ok(status(self))
+    }
+}
+
+/// Verify the client message as part of the client state validation process.
+///
+/// Note that this function is typically implemented as part of the
+/// [`ClientStateValidation`] trait, but has been made a standalone function
+/// in order to make the ClientState APIs more flexible.
+pub fn verify_client_message(
+    client_state: &ClientStateType,
+    client_message: Any,
+) -> Result<(), ClientError> {
+    match client_message.type_url.as_str() {
+        SOLANA_HEADER_TYPE_URL => {
+            let header = SolanaHeader::try_from(client_message)?;
+            verify_header(client_state, &header)?;
+            Ok(())
+        }
+        SOLANA_MISBEHAVIOUR_TYPE_URL => {
+            let misbehaviour = SolanaMisbehaviour::try_from(client_message)?;
+            verify_misbehaviour(client_state, &misbehaviour)
+        }
+        _ => Err(ClientError::InvalidUpdateClientMessage),
+    }
+}
+
+/// Verifies that `header` is a slot above the latest known one whose bank
+/// hash is voted for by the validators of its epoch, and returns the
+/// consensus state it implies.
+///
+/// The root of the consensus state is read from the IBC storage account and
+/// its timestamp from the `Clock` sysvar, both proven against the accounts
+/// delta hash of the slot.
+pub fn verify_header(
+    client_state: &ClientStateType,
+    header: &SolanaHeader,
+) -> Result<ConsensusStateType, Error> {
+    if client_state.is_frozen() {
+        return Err(Error::FrozenClient);
+    }
+
+    if header.slot <= client_state.latest_slot {
+        return Err(Error::StaleSlot {
+            slot: header.slot,
+            latest: client_state.latest_slot,
+        });
+    }
+
+    verify_header_votes(client_state, header)?;
+
+    let accounts_delta_hash = &header.bank.accounts_delta_hash;
+    header.storage_proof.verify(accounts_delta_hash)?;
+    header.clock_proof.verify(accounts_delta_hash)?;
+
+    let storage = &header.storage_proof.account;
+    if storage.pubkey != client_state.ibc_storage_account {
+        return Err(Error::UnexpectedAccount {
+            reason: "the storage proof is not of the IBC storage account".to_string(),
+        });
+    }
+    if storage.owner != client_state.ibc_program_id {
+        return Err(Error::UnexpectedAccount {
+            reason: "the IBC storage account is not owned by the IBC program".to_string(),
+        });
+    }
+    let root = storage
+        .data
+        .get(..32)
+        .ok_or_else(|| Error::UnexpectedAccount {
+            reason: "the IBC storage account holds no commitment root".to_string(),
+        })?;
+
+    let clock = Clock::from_account(&header.clock_proof.account)?;
+    if clock.slot != header.slot {
+        return Err(Error::InvalidClock {
+            reason: format!("clock is at slot {} instead of {}", clock.slot, header.slot),
+        });
+    }
+    let timestamp = u64::try_from(clock.unix_timestamp)
+        .ok()
+        .and_then(|secs| Timestamp::from_seconds(secs).ok())
+        .ok_or_else(|| Error::InvalidClock {
+            reason: format!("invalid unix timestamp {}", clock.unix_timestamp),
+        })?;
+
+    Ok(ConsensusStateType::new(
+        CommitmentRoot::from_bytes(root),
+        timestamp,
+        header.bank_hash(),
+    ))
+}
+
+/// Verifies the votes of `header` against the validators of its slot's
+/// epoch, and that the next validators it attests to, if any, agree with
+/// those already known to the client.
+pub fn verify_header_votes(
+    client_state: &ClientStateType,
+    header: &SolanaHeader,
+) -> Result<(), Error> {
+    let epoch = client_state.epoch(header.slot);
+    let validators = client_state.epoch_validators(epoch)?;
+
+    if let (Some(attested), Some(known)) = (
+        &header.next_validators,
+        client_state.epoch_validators(epoch.saturating_add(1)).ok(),
+    ) {
+        if attested.as_slice() != known {
+            return Err(Error::MismatchedNextValidators);
+        }
+    }
+
+    verify_votes(validators, &header.votes, &header.vote_message())
+}
+
+/// Verifies that both headers of `misbehaviour` are voted for by the
+/// validators of their epoch.
+pub fn verify_misbehaviour(
+    client_state: &ClientStateType,
+    misbehaviour: &SolanaMisbehaviour,
+) -> Result<(), ClientError> {
+    misbehaviour.validate_basic()?;
+
+    for header in [&misbehaviour.header_1, &misbehaviour.header_2] {
+        let validators = client_state.epoch_validators(client_state.epoch(header.slot))?;
+        verify_votes(validators, &header.votes, &header.vote_message())?;
+    }
+
+    Ok(())
+}
+
+/// Checks for misbehaviour in the given client message. A verified
+/// `Misbehaviour` is always evidence of misbehaviour, while a verified
+/// `Header` is if a consensus state with a different root is already stored
+/// at its slot.
+pub fn check_for_misbehaviour<V>(
+    client_state: &ClientStateType,
+    ctx: &V,
+    client_id: &ClientId,
+    client_message: Any,
+) -> Result<bool, ClientError>
+where
+    V: ClientValidationContext,
+{
+    match client_message.type_url.as_str() {
+        SOLANA_HEADER_TYPE_URL => {
+            let header = SolanaHeader::try_from(client_message)?;
+            let height = header.height()?;
+            let path = ClientConsensusStatePath::new(
+                client_id.clone(),
+                height.revision_number(),
+                height.revision_height(),
+            );
+
+            if let Ok(existing) = ctx.consensus_state(&path) {
+                let root = header.storage_proof.account.data.get(..32);
+                if Some(existing.root().as_bytes()) != root {
+                    return Ok(true);
+                }
+            }
+
+            Ok(false)
+        }
+        SOLANA_MISBEHAVIOUR_TYPE_URL => Ok(true),
+        _ => Err(ClientError::InvalidUpdateClientMessage),
+    }
+}
+
+/// Returns the status of the Solana client: it is active unless it has been
+/// frozen.
+pub fn status(client_state: &ClientStateType) -> Status {
+    if client_state.is_frozen() {
+        Status::Frozen
+    } else {
+        Status::Active
+    }
+}
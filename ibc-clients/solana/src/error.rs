@@ -0,0 +1,86 @@
+//! Defines the Solana light client's error type
+
+use displaydoc::Display;
+use ibc_core_client::types::error::ClientError;
+use ibc_primitives::prelude::*;
+use prost::DecodeError;
+
+/// The main error type
+#[derive(Debug, Display)]
+pub enum Error {
+    /// invalid raw client state: `{reason}`
+    InvalidRawClientState { reason: String },
+    /// invalid raw consensus state: `{reason}`
+    InvalidRawConsensusState { reason: String },
+    /// invalid raw header: `{reason}`
+    InvalidRawHeader { reason: String },
+    /// invalid raw misbehaviour: `{reason}`
+    InvalidRawMisbehaviour { reason: String },
+    /// protobuf decoding error: `{0}`
+    Decode(DecodeError),
+    /// invalid length for `{field}`: expected `{expected}` bytes, got `{actual}`
+    InvalidLength {
+        field: &'static str,
+        expected: usize,
+        actual: usize,
+    },
+    /// slot `{slot}` is not higher than the latest known slot `{latest}`
+    StaleSlot { slot: u64, latest: u64 },
+    /// epoch `{epoch}` is neither the current epoch `{current}` nor the next one
+    UnknownEpoch { epoch: u64, current: u64 },
+    /// the validators of the next epoch are unknown
+    UnknownNextValidators,
+    /// next validators do not match the known next validators
+    MismatchedNextValidators,
+    /// `{pubkey}` is not a validator of the slot's epoch
+    UnknownValidator { pubkey: String },
+    /// validator `{pubkey}` voted more than once
+    DuplicateVote { pubkey: String },
+    /// invalid vote signature by `{pubkey}`
+    InvalidVoteSignature { pubkey: String },
+    /// insufficient voted stake: `{voted}` out of `{total}`
+    InsufficientVotedStake { voted: u64, total: u64 },
+    /// stake overflow
+    StakeOverflow,
+    /// invalid account proof: `{reason}`
+    InvalidAccountProof { reason: String },
+    /// account proof does not match the accounts delta hash of the slot
+    MismatchedAccountsDeltaHash,
+    /// unexpected account: `{reason}`
+    UnexpectedAccount { reason: String },
+    /// invalid clock: `{reason}`
+    InvalidClock { reason: String },
+    /// both headers commit to the same bank hash at slot `{slot}`
+    NotMisbehaviour { slot: u64 },
+    /// headers are at different slots `{first}` and `{second}`
+    MismatchedSlots { first: u64, second: u64 },
+    /// client is frozen
+    FrozenClient,
+    /// Solana clients cannot be upgraded
+    UpgradeNotSupported,
+}
+
+impl core::error::Error for Error {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match &self {
+            // `prost` implements the trait only with `std`
+            #[cfg(feature = "std")]
+            Self::Decode(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<Error> for ClientError {
+    fn from(e: Error) -> Self {
+        Self::ClientSpecific {
+            description: e.to_string(),
+        }
+    }
+}
+
+impl From<DecodeError> for Error {
+    fn from(e: DecodeError) -> Self {
+        Self::Decode(e)
+    }
+}
@@ -0,0 +1,64 @@
+//! Hash functions used by Solana: bank hashes and the merkle tree of
+//! account hashes are built from `sha256`, and pubkeys are displayed in
+//! base58.
+
+use sha2::{Digest, Sha256};
+
+use ibc_primitives::prelude::*;
+
+pub type Hash = [u8; 32];
+pub type Pubkey = [u8; 32];
+
+/// Hashes the concatenation of `parts`, as `solana_program::hash::hashv`.
+pub fn hashv(parts: &[&[u8]]) -> Hash {
+    let mut hasher = Sha256::new();
+    for part in parts {
+        hasher.update(part);
+    }
+    hasher.finalize().into()
+}
+
+const BASE58_ALPHABET: &[u8; 58] = b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+
+/// Encodes `bytes` in base58, as Solana displays pubkeys and hashes.
+pub fn base58(bytes: &[u8]) -> String {
+    let zeros = bytes.iter().take_while(|b| **b == 0).count();
+
+    // little-endian base58 digits of the big-endian number `bytes`
+    let mut digits: Vec<u8> = Vec::with_capacity(bytes.len() * 138 / 100 + 1);
+    for byte in &bytes[zeros..] {
+        let mut carry = u32::from(*byte);
+        for digit in digits.iter_mut() {
+            carry += u32::from(*digit) << 8;
+            *digit = (carry % 58) as u8;
+            carry /= 58;
+        }
+        while carry > 0 {
+            digits.push((carry % 58) as u8);
+            carry /= 58;
+        }
+    }
+
+    let mut out = String::with_capacity(zeros + digits.len());
+    out.extend(core::iter::repeat('1').take(zeros));
+    out.extend(
+        digits
+            .iter()
+            .rev()
+            .map(|digit| char::from(BASE58_ALPHABET[usize::from(*digit)])),
+    );
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn base58_encoding() {
+        assert_eq!(base58(&[]), "");
+        assert_eq!(base58(&[0, 0, 1]), "112");
+        assert_eq!(base58(b"hello world"), "StV1DL6CwTryKyV");
+        assert_eq!(base58(&[0; 32]), "11111111111111111111111111111111");
+    }
+}
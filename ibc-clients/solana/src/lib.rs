@@ -0,0 +1,48 @@
+//! Solana light client implementation.
+//!
+//! Solana does not expose a light client protocol of its own, so the client
+//! follows the chain through validator signatures: each header commits to
+//! the bank hash of a slot, and is signed by validators holding more than two
+//! thirds of the stake of the slot's epoch. Those signatures also attest to
+//! the validator set of the next epoch, so that the client can follow epoch
+//! changes.
+//!
+//! The bank hash commits to the hashes of the accounts modified in its slot.
+//! Headers prove the state of the IBC storage account, which holds the root
+//! of the IBC commitment trie, and of the `Clock` sysvar, which holds the
+//! slot's timestamp. IBC commitments are then verified against the trie root
+//! with ICS-23 proofs.
+#![no_std]
+#![forbid(unsafe_code)]
+#![cfg_attr(not(test), deny(clippy::unwrap_used))]
+#![cfg_attr(not(test), deny(clippy::disallowed_methods, clippy::disallowed_types))]
+#![deny(
+    warnings,
+    trivial_casts,
+    trivial_numeric_casts,
+    unused_import_braces,
+    unused_qualifications,
+    rust_2018_idioms
+)]
+
+extern crate alloc;
+
+#[cfg(any(test, feature = "std"))]
+extern crate std;
+
+pub mod client_state;
+pub mod consensus_state;
+pub mod error;
+pub mod hashing;
+pub mod types;
+
+use core::str::FromStr;
+
+use ibc_core_host::types::identifiers::ClientType;
+
+pub const SOLANA_CLIENT_TYPE: &str = "solana-sigverify";
+
+/// Returns the Solana `ClientType`
+pub fn client_type() -> ClientType {
+    ClientType::from_str(SOLANA_CLIENT_TYPE).expect("Never fails because it's valid")
+}
@@ -0,0 +1,22 @@
+//! Data structures and domain types of the Solana light client.
+
+mod account;
+mod bank;
+mod client_state;
+mod consensus_state;
+mod header;
+mod misbehaviour;
+
+pub use account::*;
+pub use bank::*;
+pub use client_state::*;
+pub use consensus_state::*;
+pub use header::*;
+pub use misbehaviour::*;
+
+#[cfg(test)]
+pub(crate) use account::tests::clock_account;
+#[cfg(test)]
+pub(crate) use bank::tests::{sign, signing_keys, validators};
+
+pub mod proto;
@@ -0,0 +1,296 @@
+//! Solana accounts and proofs of their inclusion in the accounts delta hash
+//! of a slot.
+
+use ibc_primitives::prelude::*;
+
+use super::proto::{
+    Account as RawAccount, AccountProof as RawAccountProof, ProofLevel as RawProofLevel,
+};
+use crate::error::Error;
+use crate::hashing::{hashv, Hash, Pubkey};
+
+/// The fanout of the merkle tree of account hashes.
+pub const MERKLE_FANOUT: usize = 16;
+
+/// The address of the `Clock` sysvar, `SysvarC1ock11111111111111111111111111111111`.
+pub const CLOCK_SYSVAR_ID: Pubkey = [
+    6, 167, 213, 23, 24, 199, 116, 201, 40, 86, 99, 152, 105, 29, 94, 182, 139, 94, 184, 163, 155,
+    75, 109, 92, 115, 85, 91, 0, 0, 0, 0, 0,
+];
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Account {
+    pub pubkey: Pubkey,
+    pub lamports: u64,
+    pub owner: Pubkey,
+    pub executable: bool,
+    pub rent_epoch: u64,
+    pub data: Vec<u8>,
+}
+
+impl Account {
+    /// Returns the hash of the account as it enters the accounts delta hash.
+    /// Accounts without lamports are deleted, and hash to zero.
+    pub fn hash(&self) -> Hash {
+        if self.lamports == 0 {
+            return Hash::default();
+        }
+
+        let mut hasher = blake3::Hasher::new();
+        hasher.update(&self.lamports.to_le_bytes());
+        hasher.update(&self.rent_epoch.to_le_bytes());
+        hasher.update(&self.data);
+        hasher.update(&[u8::from(self.executable)]);
+        hasher.update(&self.owner);
+        hasher.update(&self.pubkey);
+        hasher.finalize().into()
+    }
+}
+
+/// The position of a node among its siblings at one level of the merkle
+/// tree, along with the hashes of its siblings in order.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ProofLevel {
+    pub index: u32,
+    pub siblings: Vec<Hash>,
+}
+
+/// An account along with its path in the merkle tree of the hashes of the
+/// accounts modified in a slot, sorted by pubkey.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AccountProof {
+    pub account: Account,
+    pub path: Vec<ProofLevel>,
+}
+
+impl AccountProof {
+    /// Computes the accounts delta hash the account is included in.
+    pub fn compute_root(&self) -> Result<Hash, Error> {
+        if self.path.is_empty() {
+            return Err(Error::InvalidAccountProof {
+                reason: "empty proof path".to_string(),
+            });
+        }
+
+        self.path
+            .iter()
+            .try_fold(self.account.hash(), |hash, level| {
+                let index = level.index as usize;
+
+                if level.siblings.len() >= MERKLE_FANOUT || index > level.siblings.len() {
+                    return Err(Error::InvalidAccountProof {
+                        reason: format!(
+                            "invalid position {index} among {} siblings",
+                            level.siblings.len()
+                        ),
+                    });
+                }
+
+                let mut children: Vec<&[u8]> = level.siblings.iter().map(|h| &h[..]).collect();
+                children.insert(index, &hash);
+                Ok(hashv(&children))
+            })
+    }
+
+    /// Verifies that the account is included in `accounts_delta_hash`.
+    pub fn verify(&self, accounts_delta_hash: &Hash) -> Result<(), Error> {
+        if self.compute_root()? != *accounts_delta_hash {
+            return Err(Error::MismatchedAccountsDeltaHash);
+        }
+
+        Ok(())
+    }
+}
+
+/// The data of the `Clock` sysvar.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Clock {
+    pub slot: u64,
+    pub epoch_start_timestamp: i64,
+    pub epoch: u64,
+    pub leader_schedule_epoch: u64,
+    /// Unix timestamp in seconds.
+    pub unix_timestamp: i64,
+}
+
+impl Clock {
+    /// Decodes the bincode encoding of the `Clock` sysvar held by `account`.
+    pub fn from_account(account: &Account) -> Result<Self, Error> {
+        if account.pubkey != CLOCK_SYSVAR_ID {
+            return Err(Error::UnexpectedAccount {
+                reason: "expected the clock sysvar".to_string(),
+            });
+        }
+
+        let data: &[u8; 40] = account
+            .data
+            .get(..40)
+            .and_then(|data| data.try_into().ok())
+            .ok_or_else(|| Error::InvalidClock {
+                reason: format!("clock data is {} bytes long", account.data.len()),
+            })?;
+
+        let word = |i: usize| {
+            let mut bytes = [0u8; 8];
+            bytes.copy_from_slice(&data[i * 8..(i + 1) * 8]);
+            bytes
+        };
+
+        Ok(Self {
+            slot: u64::from_le_bytes(word(0)),
+            epoch_start_timestamp: i64::from_le_bytes(word(1)),
+            epoch: u64::from_le_bytes(word(2)),
+            leader_schedule_epoch: u64::from_le_bytes(word(3)),
+            unix_timestamp: i64::from_le_bytes(word(4)),
+        })
+    }
+}
+
+pub(crate) fn fixed_bytes<const N: usize>(
+    raw: Vec<u8>,
+    field: &'static str,
+) -> Result<[u8; N], Error> {
+    raw.try_into().map_err(|raw: Vec<u8>| Error::InvalidLength {
+        field,
+        expected: N,
+        actual: raw.len(),
+    })
+}
+
+impl TryFrom<RawAccount> for Account {
+    type Error = Error;
+
+    fn try_from(raw: RawAccount) -> Result<Self, Self::Error> {
+        Ok(Self {
+            pubkey: fixed_bytes(raw.pubkey, "account pubkey")?,
+            lamports: raw.lamports,
+            owner: fixed_bytes(raw.owner, "account owner")?,
+            executable: raw.executable,
+            rent_epoch: raw.rent_epoch,
+            data: raw.data,
+        })
+    }
+}
+
+impl From<Account> for RawAccount {
+    fn from(value: Account) -> Self {
+        Self {
+            pubkey: value.pubkey.to_vec(),
+            lamports: value.lamports,
+            owner: value.owner.to_vec(),
+            executable: value.executable,
+            rent_epoch: value.rent_epoch,
+            data: value.data,
+        }
+    }
+}
+
+impl TryFrom<RawAccountProof> for AccountProof {
+    type Error = Error;
+
+    fn try_from(raw: RawAccountProof) -> Result<Self, Self::Error> {
+        let account = raw
+            .account
+            .ok_or_else(|| Error::InvalidRawHeader {
+                reason: "missing account".to_string(),
+            })?
+            .try_into()?;
+
+        let path = raw
+            .path
+            .into_iter()
+            .map(|level| {
+                Ok(ProofLevel {
+                    index: level.index,
+                    siblings: level
+                        .siblings
+                        .into_iter()
+                        .map(|sibling| fixed_bytes(sibling, "proof sibling"))
+                        .collect::<Result<_, Error>>()?,
+                })
+            })
+            .collect::<Result<_, Error>>()?;
+
+        Ok(Self { account, path })
+    }
+}
+
+impl From<AccountProof> for RawAccountProof {
+    fn from(value: AccountProof) -> Self {
+        Self {
+            account: Some(value.account.into()),
+            path: value
+                .path
+                .into_iter()
+                .map(|level| RawProofLevel {
+                    index: level.index,
+                    siblings: level.siblings.iter().map(|h| h.to_vec()).collect(),
+                })
+                .collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+
+    pub(crate) fn clock_account(slot: u64, unix_timestamp: i64) -> Account {
+        let mut data = Vec::with_capacity(40);
+        data.extend_from_slice(&slot.to_le_bytes());
+        data.extend_from_slice(&(unix_timestamp - 3600).to_le_bytes());
+        data.extend_from_slice(&(slot / 432_000).to_le_bytes());
+        data.extend_from_slice(&(slot / 432_000 + 1).to_le_bytes());
+        data.extend_from_slice(&unix_timestamp.to_le_bytes());
+
+        Account {
+            pubkey: CLOCK_SYSVAR_ID,
+            lamports: 1_169_280,
+            owner: [6; 32],
+            executable: false,
+            rent_epoch: 0,
+            data,
+        }
+    }
+
+    #[test]
+    fn account_proof_root() {
+        let account = clock_account(10, 1_700_000_000);
+        let other = [7u8; 32];
+
+        let proof = AccountProof {
+            account: account.clone(),
+            path: vec![
+                ProofLevel {
+                    index: 1,
+                    siblings: vec![other],
+                },
+                ProofLevel {
+                    index: 0,
+                    siblings: vec![],
+                },
+            ],
+        };
+
+        let leaf_level = hashv(&[&other, &account.hash()]);
+        assert_eq!(
+            proof.compute_root().expect("valid proof"),
+            hashv(&[&leaf_level])
+        );
+
+        let mut invalid = proof;
+        invalid.path[0].index = 2;
+        assert!(invalid.compute_root().is_err());
+    }
+
+    #[test]
+    fn decode_clock() {
+        let clock = Clock::from_account(&clock_account(10, 1_700_000_000)).expect("valid clock");
+        assert_eq!(clock.slot, 10);
+        assert_eq!(clock.unix_timestamp, 1_700_000_000);
+
+        let mut account = clock_account(10, 1_700_000_000);
+        account.pubkey = [1; 32];
+        assert!(Clock::from_account(&account).is_err());
+    }
+}
@@ -0,0 +1,255 @@
+//! Bank hashes, validators and their votes.
+
+use alloc::collections::BTreeSet;
+
+use ed25519_consensus::{Signature, VerificationKey};
+use ibc_primitives::prelude::*;
+
+use super::account::fixed_bytes;
+use super::proto::{
+    BankHashComponents as RawBankHashComponents, Validator as RawValidator, Vote as RawVote,
+};
+use crate::error::Error;
+use crate::hashing::{base58, hashv, Hash, Pubkey};
+
+/// Domain separator of the messages signed by validators attesting to a
+/// bank hash for IBC.
+pub const VOTE_DOMAIN: &[u8] = b"solana-ibc-vote";
+
+/// The components hashed into the bank hash of a slot.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BankHashComponents {
+    pub parent_bank_hash: Hash,
+    /// The root of the merkle tree of the hashes of the accounts modified in
+    /// the slot.
+    pub accounts_delta_hash: Hash,
+    pub signature_count: u64,
+    pub last_blockhash: Hash,
+}
+
+impl BankHashComponents {
+    /// Returns the bank hash, as computed by `Bank::hash_internal_state`.
+    pub fn bank_hash(&self) -> Hash {
+        hashv(&[
+            &self.parent_bank_hash,
+            &self.accounts_delta_hash,
+            &self.signature_count.to_le_bytes(),
+            &self.last_blockhash,
+        ])
+    }
+}
+
+/// A validator and its activated stake for an epoch.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Validator {
+    /// The validator identity, which signs votes.
+    pub pubkey: Pubkey,
+    pub stake: u64,
+}
+
+/// Returns the hash committing to a validator set.
+pub fn validators_hash(validators: &[Validator]) -> Hash {
+    let entries: Vec<[u8; 40]> = validators
+        .iter()
+        .map(|validator| {
+            let mut entry = [0u8; 40];
+            entry[..32].copy_from_slice(&validator.pubkey);
+            entry[32..].copy_from_slice(&validator.stake.to_le_bytes());
+            entry
+        })
+        .collect();
+    let parts: Vec<&[u8]> = entries.iter().map(|entry| &entry[..]).collect();
+
+    hashv(&parts)
+}
+
+/// Returns the message validators sign to attest to `bank_hash` at `slot`,
+/// and to the validators of the next epoch if given.
+pub fn vote_message(slot: u64, bank_hash: &Hash, next_validators: Option<&[Validator]>) -> Vec<u8> {
+    let next_validators_hash = next_validators.map(validators_hash).unwrap_or_default();
+
+    let mut message = VOTE_DOMAIN.to_vec();
+    message.extend_from_slice(&slot.to_le_bytes());
+    message.extend_from_slice(bank_hash);
+    message.extend_from_slice(&next_validators_hash);
+    message
+}
+
+/// A validator's signature of a vote message.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Vote {
+    pub pubkey: Pubkey,
+    pub signature: [u8; 64],
+}
+
+/// Verifies that `votes` are valid signatures of `message` by validators
+/// holding more than two thirds of the stake of `validators`.
+pub fn verify_votes(validators: &[Validator], votes: &[Vote], message: &[u8]) -> Result<(), Error> {
+    let total_stake = validators
+        .iter()
+        .try_fold(0u64, |total, validator| total.checked_add(validator.stake))
+        .ok_or(Error::StakeOverflow)?;
+
+    let mut voters = BTreeSet::new();
+    let mut voted_stake = 0u64;
+
+    for vote in votes {
+        let pubkey = base58(&vote.pubkey);
+
+        let validator = validators
+            .iter()
+            .find(|validator| validator.pubkey == vote.pubkey)
+            .ok_or_else(|| Error::UnknownValidator {
+                pubkey: pubkey.clone(),
+            })?;
+
+        if !voters.insert(vote.pubkey) {
+            return Err(Error::DuplicateVote { pubkey });
+        }
+
+        VerificationKey::try_from(vote.pubkey)
+            .and_then(|key| key.verify(&Signature::from(vote.signature), message))
+            .map_err(|_| Error::InvalidVoteSignature { pubkey })?;
+
+        voted_stake = voted_stake
+            .checked_add(validator.stake)
+            .ok_or(Error::StakeOverflow)?;
+    }
+
+    let voted = u128::from(voted_stake) * 3;
+    let needed = u128::from(total_stake) * 2;
+    if voted <= needed {
+        return Err(Error::InsufficientVotedStake {
+            voted: voted_stake,
+            total: total_stake,
+        });
+    }
+
+    Ok(())
+}
+
+impl TryFrom<RawBankHashComponents> for BankHashComponents {
+    type Error = Error;
+
+    fn try_from(raw: RawBankHashComponents) -> Result<Self, Self::Error> {
+        Ok(Self {
+            parent_bank_hash: fixed_bytes(raw.parent_bank_hash, "parent bank hash")?,
+            accounts_delta_hash: fixed_bytes(raw.accounts_delta_hash, "accounts delta hash")?,
+            signature_count: raw.signature_count,
+            last_blockhash: fixed_bytes(raw.last_blockhash, "last blockhash")?,
+        })
+    }
+}
+
+impl From<BankHashComponents> for RawBankHashComponents {
+    fn from(value: BankHashComponents) -> Self {
+        Self {
+            parent_bank_hash: value.parent_bank_hash.to_vec(),
+            accounts_delta_hash: value.accounts_delta_hash.to_vec(),
+            signature_count: value.signature_count,
+            last_blockhash: value.last_blockhash.to_vec(),
+        }
+    }
+}
+
+impl TryFrom<RawValidator> for Validator {
+    type Error = Error;
+
+    fn try_from(raw: RawValidator) -> Result<Self, Self::Error> {
+        Ok(Self {
+            pubkey: fixed_bytes(raw.pubkey, "validator pubkey")?,
+            stake: raw.stake,
+        })
+    }
+}
+
+impl From<Validator> for RawValidator {
+    fn from(value: Validator) -> Self {
+        Self {
+            pubkey: value.pubkey.to_vec(),
+            stake: value.stake,
+        }
+    }
+}
+
+impl TryFrom<RawVote> for Vote {
+    type Error = Error;
+
+    fn try_from(raw: RawVote) -> Result<Self, Self::Error> {
+        Ok(Self {
+            pubkey: fixed_bytes(raw.pubkey, "vote pubkey")?,
+            signature: fixed_bytes(raw.signature, "vote signature")?,
+        })
+    }
+}
+
+impl From<Vote> for RawVote {
+    fn from(value: Vote) -> Self {
+        Self {
+            pubkey: value.pubkey.to_vec(),
+            signature: value.signature.to_vec(),
+        }
+    }
+}
+
+/// Converts a list of raw validators.
+pub(crate) fn validators_from_raw(raw: Vec<RawValidator>) -> Result<Vec<Validator>, Error> {
+    raw.into_iter().map(TryInto::try_into).collect()
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use ed25519_consensus::SigningKey;
+
+    use super::*;
+
+    pub(crate) fn signing_keys() -> Vec<SigningKey> {
+        (1..=4).map(|seed| SigningKey::from([seed; 32])).collect()
+    }
+
+    pub(crate) fn validators(keys: &[SigningKey]) -> Vec<Validator> {
+        keys.iter()
+            .map(|key| Validator {
+                pubkey: key.verification_key().to_bytes(),
+                stake: 100,
+            })
+            .collect()
+    }
+
+    pub(crate) fn sign(keys: &[SigningKey], message: &[u8]) -> Vec<Vote> {
+        keys.iter()
+            .map(|key| Vote {
+                pubkey: key.verification_key().to_bytes(),
+                signature: key.sign(message).to_bytes(),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn verify_votes_thresholds() {
+        let keys = signing_keys();
+        let validators = validators(&keys);
+        let message = vote_message(10, &[1; 32], None);
+
+        assert!(verify_votes(&validators, &sign(&keys[..3], &message), &message).is_ok());
+
+        // exactly two thirds is not enough
+        assert!(matches!(
+            verify_votes(&validators[..3], &sign(&keys[..2], &message), &message),
+            Err(Error::InsufficientVotedStake { .. })
+        ));
+
+        let mut duplicated = sign(&keys[..3], &message);
+        duplicated.push(duplicated[0].clone());
+        assert!(matches!(
+            verify_votes(&validators, &duplicated, &message),
+            Err(Error::DuplicateVote { .. })
+        ));
+
+        let other = vote_message(10, &[2; 32], None);
+        assert!(matches!(
+            verify_votes(&validators, &sign(&keys[..3], &other), &message),
+            Err(Error::InvalidVoteSignature { .. })
+        ));
+    }
+}
@@ -0,0 +1,188 @@
+//! Defines the client state type for the Solana light client.
+
+use core::str::FromStr;
+
+use ibc_core_client::types::error::ClientError;
+use ibc_core_client::types::Height;
+use ibc_core_commitment_types::specs::ProofSpecs;
+use ibc_core_host::types::identifiers::ChainId;
+use ibc_primitives::prelude::*;
+use ibc_primitives::proto::{Any, Protobuf};
+
+use super::account::fixed_bytes;
+use super::bank::{validators_from_raw, Validator};
+use super::proto::ClientState as RawSolanaClientState;
+use crate::error::Error;
+use crate::hashing::Pubkey;
+
+pub const SOLANA_CLIENT_STATE_TYPE_URL: &str = "/ibc.lightclients.solana.v1.ClientState";
+
+/// Contains the state of a Solana client.
+///
+/// The client tracks the latest verified slot and the validators of its
+/// epoch and, once attested to, of the next epoch. Solana has no notion of
+/// revisions, so client heights have a revision number of 0 and the slot as
+/// their revision height.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ClientState {
+    pub chain_id: ChainId,
+    pub latest_slot: u64,
+    pub slots_per_epoch: u64,
+    pub current_validators: Vec<Validator>,
+    pub next_validators: Option<Vec<Validator>>,
+    /// The account of the IBC program holding the root of the IBC commitment
+    /// trie in its first 32 bytes.
+    pub ibc_storage_account: Pubkey,
+    /// The IBC program, which must own the storage account.
+    pub ibc_program_id: Pubkey,
+    /// The ICS-23 specs of the IBC commitment trie.
+    pub proof_specs: ProofSpecs,
+    pub frozen_height: Option<Height>,
+}
+
+impl ClientState {
+    pub fn validate(&self) -> Result<(), Error> {
+        if self.latest_slot == 0 || self.slots_per_epoch == 0 {
+            return Err(Error::InvalidRawClientState {
+                reason: "latest slot and slots per epoch cannot be zero".to_string(),
+            });
+        }
+
+        if self.current_validators.is_empty() {
+            return Err(Error::InvalidRawClientState {
+                reason: "current validators cannot be empty".to_string(),
+            });
+        }
+
+        self.proof_specs
+            .validate()
+            .map_err(|e| Error::InvalidRawClientState {
+                reason: e.to_string(),
+            })?;
+
+        Ok(())
+    }
+
+    pub fn latest_height(&self) -> Height {
+        Height::new(0, self.latest_slot).expect("latest slot is validated to be non-zero")
+    }
+
+    pub fn epoch(&self, slot: u64) -> u64 {
+        slot / self.slots_per_epoch
+    }
+
+    /// Returns the validators of the given epoch, if known.
+    pub fn epoch_validators(&self, epoch: u64) -> Result<&[Validator], Error> {
+        let current = self.epoch(self.latest_slot);
+
+        if epoch == current {
+            Ok(&self.current_validators)
+        } else if Some(epoch) == current.checked_add(1) {
+            self.next_validators
+                .as_deref()
+                .ok_or(Error::UnknownNextValidators)
+        } else {
+            Err(Error::UnknownEpoch { epoch, current })
+        }
+    }
+
+    pub fn is_frozen(&self) -> bool {
+        self.frozen_height.is_some()
+    }
+
+    pub fn with_frozen_height(self, h: Height) -> Self {
+        Self {
+            frozen_height: Some(h),
+            ..self
+        }
+    }
+}
+
+impl Protobuf<RawSolanaClientState> for ClientState {}
+
+impl TryFrom<RawSolanaClientState> for ClientState {
+    type Error = Error;
+
+    fn try_from(raw: RawSolanaClientState) -> Result<Self, Self::Error> {
+        let chain_id =
+            ChainId::from_str(&raw.chain_id).map_err(|e| Error::InvalidRawClientState {
+                reason: e.to_string(),
+            })?;
+
+        let next_validators = validators_from_raw(raw.next_validators)?;
+
+        let client_state = Self {
+            chain_id,
+            latest_slot: raw.latest_slot,
+            slots_per_epoch: raw.slots_per_epoch,
+            current_validators: validators_from_raw(raw.current_validators)?,
+            next_validators: (!next_validators.is_empty()).then_some(next_validators),
+            ibc_storage_account: fixed_bytes(raw.ibc_storage_account, "IBC storage account")?,
+            ibc_program_id: fixed_bytes(raw.ibc_program_id, "IBC program id")?,
+            proof_specs: raw.proof_specs.into(),
+            frozen_height: raw
+                .frozen_height
+                .and_then(|raw_height| raw_height.try_into().ok()),
+        };
+        client_state.validate()?;
+        Ok(client_state)
+    }
+}
+
+impl From<ClientState> for RawSolanaClientState {
+    fn from(value: ClientState) -> Self {
+        Self {
+            chain_id: value.chain_id.to_string(),
+            latest_slot: value.latest_slot,
+            slots_per_epoch: value.slots_per_epoch,
+            current_validators: value
+                .current_validators
+                .into_iter()
+                .map(Into::into)
+                .collect(),
+            next_validators: value
+                .next_validators
+                .unwrap_or_default()
+                .into_iter()
+                .map(Into::into)
+                .collect(),
+            ibc_storage_account: value.ibc_storage_account.to_vec(),
+            ibc_program_id: value.ibc_program_id.to_vec(),
+            proof_specs: value.proof_specs.into(),
+            frozen_height: value.frozen_height.map(|height| height.into()),
+        }
+    }
+}
+
+impl Protobuf<Any> for ClientState {}
+
+impl TryFrom<Any> for ClientState {
+    type Error = ClientError;
+
+    fn try_from(raw: Any) -> Result<Self, Self::Error> {
+        fn decode_client_state(value: &[u8]) -> Result<ClientState, ClientError> {
+            let client_state = Protobuf::<RawSolanaClientState>::decode(value).map_err(|e| {
+                ClientError::Other {
+                    description: e.to_string(),
+                }
+            })?;
+            Ok(client_state)
+        }
+
+        match raw.type_url.as_str() {
+            SOLANA_CLIENT_STATE_TYPE_URL => decode_client_state(&raw.value),
+            _ => Err(ClientError::UnknownClientStateType {
+                client_state_type: raw.type_url,
+            }),
+        }
+    }
+}
+
+impl From<ClientState> for Any {
+    fn from(client_state: ClientState) -> Self {
+        Any {
+            type_url: SOLANA_CLIENT_STATE_TYPE_URL.to_string(),
+            value: Protobuf::<RawSolanaClientState>::encode_vec(client_state),
+        }
+    }
+}
@@ -0,0 +1,111 @@
+//! Defines the consensus state type for the Solana light client.
+
+use ibc_core_client::types::error::ClientError;
+use ibc_core_commitment_types::commitment::CommitmentRoot;
+use ibc_primitives::prelude::*;
+use ibc_primitives::proto::{Any, Protobuf};
+use ibc_primitives::Timestamp;
+
+use super::account::fixed_bytes;
+use super::proto::ConsensusState as RawSolanaConsensusState;
+use crate::error::Error;
+use crate::hashing::Hash;
+
+pub const SOLANA_CONSENSUS_STATE_TYPE_URL: &str = "/ibc.lightclients.solana.v1.ConsensusState";
+
+/// Defines the Solana consensus state of a verified slot: the root of the
+/// IBC commitment trie, the slot's timestamp and its bank hash.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ConsensusState {
+    pub root: CommitmentRoot,
+    pub timestamp: Timestamp,
+    pub bank_hash: Hash,
+}
+
+impl ConsensusState {
+    pub fn new(root: CommitmentRoot, timestamp: Timestamp, bank_hash: Hash) -> Self {
+        Self {
+            root,
+            timestamp,
+            bank_hash,
+        }
+    }
+
+    pub fn root(&self) -> &CommitmentRoot {
+        &self.root
+    }
+
+    pub fn timestamp(&self) -> Timestamp {
+        self.timestamp
+    }
+}
+
+impl Protobuf<RawSolanaConsensusState> for ConsensusState {}
+
+impl TryFrom<RawSolanaConsensusState> for ConsensusState {
+    type Error = Error;
+
+    fn try_from(raw: RawSolanaConsensusState) -> Result<Self, Self::Error> {
+        if raw.root.is_empty() {
+            return Err(Error::InvalidRawConsensusState {
+                reason: "root cannot be empty".to_string(),
+            });
+        }
+
+        let timestamp = Timestamp::from_nanoseconds(raw.timestamp).map_err(|e| {
+            Error::InvalidRawConsensusState {
+                reason: e.to_string(),
+            }
+        })?;
+
+        Ok(Self::new(
+            CommitmentRoot::from_bytes(&raw.root),
+            timestamp,
+            fixed_bytes(raw.bank_hash, "bank hash")?,
+        ))
+    }
+}
+
+impl From<ConsensusState> for RawSolanaConsensusState {
+    fn from(value: ConsensusState) -> Self {
+        Self {
+            root: value.root.into_vec(),
+            timestamp: value.timestamp.nanoseconds(),
+            bank_hash: value.bank_hash.to_vec(),
+        }
+    }
+}
+
+impl Protobuf<Any> for ConsensusState {}
+
+impl TryFrom<Any> for ConsensusState {
+    type Error = ClientError;
+
+    fn try_from(raw: Any) -> Result<Self, Self::Error> {
+        fn decode_consensus_state(value: &[u8]) -> Result<ConsensusState, ClientError> {
+            let consensus_state =
+                Protobuf::<RawSolanaConsensusState>::decode(value).map_err(|e| {
+                    ClientError::Other {
+                        description: e.to_string(),
+                    }
+                })?;
+            Ok(consensus_state)
+        }
+
+        match raw.type_url.as_str() {
+            SOLANA_CONSENSUS_STATE_TYPE_URL => decode_consensus_state(&raw.value),
+            _ => Err(ClientError::UnknownConsensusStateType {
+                consensus_state_type: raw.type_url,
+            }),
+        }
+    }
+}
+
+impl From<ConsensusState> for Any {
+    fn from(consensus_state: ConsensusState) -> Self {
+        Any {
+            type_url: SOLANA_CONSENSUS_STATE_TYPE_URL.to_string(),
+            value: Protobuf::<RawSolanaConsensusState>::encode_vec(consensus_state),
+        }
+    }
+}
@@ -0,0 +1,143 @@
+//! Defines the header type for the Solana light client.
+
+use ibc_core_client::types::error::ClientError;
+use ibc_core_client::types::Height;
+use ibc_primitives::prelude::*;
+use ibc_primitives::proto::{Any, Protobuf};
+
+use super::account::AccountProof;
+use super::bank::{validators_from_raw, vote_message, BankHashComponents, Validator, Vote};
+use super::proto::Header as RawSolanaHeader;
+use crate::error::Error;
+use crate::hashing::Hash;
+
+pub const SOLANA_HEADER_TYPE_URL: &str = "/ibc.lightclients.solana.v1.Header";
+
+/// Defines the Solana client header: the bank hash of a slot, the votes of
+/// the slot's validators attesting to it, and proofs of the IBC storage
+/// account and of the `Clock` sysvar against it.
+///
+/// Both accounts must have been modified in the slot for their hashes to be
+/// in its accounts delta hash. The `Clock` sysvar is updated by every slot,
+/// and the IBC program is expected to write its storage account whenever
+/// the commitment trie changes.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Header {
+    pub slot: u64,
+    pub bank: BankHashComponents,
+    /// The validators of the epoch following the slot's epoch, if the
+    /// header attests to them.
+    pub next_validators: Option<Vec<Validator>>,
+    pub votes: Vec<Vote>,
+    pub storage_proof: AccountProof,
+    pub clock_proof: AccountProof,
+}
+
+impl Header {
+    pub fn height(&self) -> Result<Height, Error> {
+        Height::new(0, self.slot).map_err(|e| Error::InvalidRawHeader {
+            reason: e.to_string(),
+        })
+    }
+
+    pub fn bank_hash(&self) -> Hash {
+        self.bank.bank_hash()
+    }
+
+    /// Returns the message signed by the votes of this header.
+    pub fn vote_message(&self) -> Vec<u8> {
+        vote_message(
+            self.slot,
+            &self.bank_hash(),
+            self.next_validators.as_deref(),
+        )
+    }
+}
+
+impl Protobuf<RawSolanaHeader> for Header {}
+
+impl TryFrom<RawSolanaHeader> for Header {
+    type Error = Error;
+
+    fn try_from(raw: RawSolanaHeader) -> Result<Self, Self::Error> {
+        let missing = |field: &str| Error::InvalidRawHeader {
+            reason: format!("missing {field}"),
+        };
+
+        let next_validators = validators_from_raw(raw.next_validators)?;
+
+        let header = Self {
+            slot: raw.slot,
+            bank: raw
+                .bank
+                .ok_or_else(|| missing("bank hash components"))?
+                .try_into()?,
+            next_validators: (!next_validators.is_empty()).then_some(next_validators),
+            votes: raw
+                .votes
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<Result<_, Error>>()?,
+            storage_proof: raw
+                .storage_proof
+                .ok_or_else(|| missing("storage account proof"))?
+                .try_into()?,
+            clock_proof: raw
+                .clock_proof
+                .ok_or_else(|| missing("clock proof"))?
+                .try_into()?,
+        };
+        header.height()?;
+        Ok(header)
+    }
+}
+
+impl From<Header> for RawSolanaHeader {
+    fn from(value: Header) -> Self {
+        Self {
+            slot: value.slot,
+            bank: Some(value.bank.into()),
+            next_validators: value
+                .next_validators
+                .unwrap_or_default()
+                .into_iter()
+                .map(Into::into)
+                .collect(),
+            votes: value.votes.into_iter().map(Into::into).collect(),
+            storage_proof: Some(value.storage_proof.into()),
+            clock_proof: Some(value.clock_proof.into()),
+        }
+    }
+}
+
+impl Protobuf<Any> for Header {}
+
+impl TryFrom<Any> for Header {
+    type Error = ClientError;
+
+    fn try_from(raw: Any) -> Result<Self, Self::Error> {
+        fn decode_header(value: &[u8]) -> Result<Header, ClientError> {
+            let header =
+                Protobuf::<RawSolanaHeader>::decode(value).map_err(|e| ClientError::Other {
+                    description: e.to_string(),
+                })?;
+            Ok(header)
+        }
+
+        match raw.type_url.as_str() {
+            SOLANA_HEADER_TYPE_URL => decode_header(&raw.value),
+            _ => Err(ClientError::UnknownHeaderType {
+                header_type: raw.type_url,
+            }),
+        }
+    }
+}
+
+impl From<Header> for Any {
+    fn from(header: Header) -> Self {
+        Any {
+            type_url: SOLANA_HEADER_TYPE_URL.to_string(),
+            value: Protobuf::<RawSolanaHeader>::encode_vec(header),
+        }
+    }
+}
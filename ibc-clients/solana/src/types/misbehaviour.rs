@@ -0,0 +1,101 @@
+//! Defines the misbehaviour type for the Solana light client.
+
+use ibc_core_client::types::error::ClientError;
+use ibc_primitives::prelude::*;
+use ibc_primitives::proto::{Any, Protobuf};
+
+use super::header::Header;
+use super::proto::Misbehaviour as RawSolanaMisbehaviour;
+use crate::error::Error;
+
+pub const SOLANA_MISBEHAVIOUR_TYPE_URL: &str = "/ibc.lightclients.solana.v1.Misbehaviour";
+
+/// Evidence of the validators attesting to two different bank hashes for
+/// the same slot.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Misbehaviour {
+    pub header_1: Header,
+    pub header_2: Header,
+}
+
+impl Misbehaviour {
+    /// Checks that both headers are at the same slot with distinct bank
+    /// hashes, without verifying their votes.
+    pub fn validate_basic(&self) -> Result<(), Error> {
+        if self.header_1.slot != self.header_2.slot {
+            return Err(Error::MismatchedSlots {
+                first: self.header_1.slot,
+                second: self.header_2.slot,
+            });
+        }
+
+        if self.header_1.bank_hash() == self.header_2.bank_hash() {
+            return Err(Error::NotMisbehaviour {
+                slot: self.header_1.slot,
+            });
+        }
+
+        Ok(())
+    }
+}
+
+impl Protobuf<RawSolanaMisbehaviour> for Misbehaviour {}
+
+impl TryFrom<RawSolanaMisbehaviour> for Misbehaviour {
+    type Error = Error;
+
+    fn try_from(raw: RawSolanaMisbehaviour) -> Result<Self, Self::Error> {
+        let missing = || Error::InvalidRawMisbehaviour {
+            reason: "missing header".to_string(),
+        };
+
+        let misbehaviour = Self {
+            header_1: raw.header_1.ok_or_else(missing)?.try_into()?,
+            header_2: raw.header_2.ok_or_else(missing)?.try_into()?,
+        };
+        misbehaviour.validate_basic()?;
+        Ok(misbehaviour)
+    }
+}
+
+impl From<Misbehaviour> for RawSolanaMisbehaviour {
+    fn from(value: Misbehaviour) -> Self {
+        Self {
+            header_1: Some(value.header_1.into()),
+            header_2: Some(value.header_2.into()),
+        }
+    }
+}
+
+impl Protobuf<Any> for Misbehaviour {}
+
+impl TryFrom<Any> for Misbehaviour {
+    type Error = ClientError;
+
+    fn try_from(raw: Any) -> Result<Self, Self::Error> {
+        fn decode_misbehaviour(value: &[u8]) -> Result<Misbehaviour, ClientError> {
+            let misbehaviour = Protobuf::<RawSolanaMisbehaviour>::decode(value).map_err(|e| {
+                ClientError::Other {
+                    description: e.to_string(),
+                }
+            })?;
+            Ok(misbehaviour)
+        }
+
+        match raw.type_url.as_str() {
+            SOLANA_MISBEHAVIOUR_TYPE_URL => decode_misbehaviour(&raw.value),
+            _ => Err(ClientError::UnknownMisbehaviourType {
+                misbehaviour_type: raw.type_url,
+            }),
+        }
+    }
+}
+
+impl From<Misbehaviour> for Any {
+    fn from(misbehaviour: Misbehaviour) -> Self {
+        Any {
+            type_url: SOLANA_MISBEHAVIOUR_TYPE_URL.to_string(),
+            value: Protobuf::<RawSolanaMisbehaviour>::encode_vec(misbehaviour),
+        }
+    }
+}
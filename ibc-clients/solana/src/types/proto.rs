@@ -0,0 +1,238 @@
+//! Raw (protobuf) representations of the Solana client types.
+//!
+//! There is no upstream `ibc-proto` definition for these messages yet, so
+//! they are defined here under the `ibc.lightclients.solana.v1` package.
+
+use ibc_primitives::prelude::*;
+use ibc_proto::ibc::core::client::v1::Height as RawHeight;
+use ibc_proto::ics23::ProofSpec as RawProofSpec;
+
+macro_rules! impl_name {
+    ($ty:ty, $name:literal) => {
+        impl prost::Name for $ty {
+            const NAME: &'static str = $name;
+            const PACKAGE: &'static str = "ibc.lightclients.solana.v1";
+
+            fn full_name() -> String {
+                format!("{}.{}", Self::PACKAGE, Self::NAME)
+            }
+        }
+    };
+}
+
+/// ```protobuf
+/// message Validator {
+///   bytes pubkey = 1;
+///   uint64 stake = 2;
+/// }
+/// ```
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct Validator {
+    #[prost(bytes = "vec", tag = "1")]
+    pub pubkey: Vec<u8>,
+    #[prost(uint64, tag = "2")]
+    pub stake: u64,
+}
+
+impl_name!(Validator, "Validator");
+
+/// ```protobuf
+/// message ClientState {
+///   string chain_id = 1;
+///   uint64 latest_slot = 2;
+///   uint64 slots_per_epoch = 3;
+///   repeated Validator current_validators = 4;
+///   repeated Validator next_validators = 5;
+///   bytes ibc_storage_account = 6;
+///   bytes ibc_program_id = 7;
+///   repeated ics23.ProofSpec proof_specs = 8;
+///   ibc.core.client.v1.Height frozen_height = 9;
+/// }
+/// ```
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct ClientState {
+    #[prost(string, tag = "1")]
+    pub chain_id: String,
+    #[prost(uint64, tag = "2")]
+    pub latest_slot: u64,
+    #[prost(uint64, tag = "3")]
+    pub slots_per_epoch: u64,
+    #[prost(message, repeated, tag = "4")]
+    pub current_validators: Vec<Validator>,
+    /// Empty if the validators of the next epoch are unknown.
+    #[prost(message, repeated, tag = "5")]
+    pub next_validators: Vec<Validator>,
+    #[prost(bytes = "vec", tag = "6")]
+    pub ibc_storage_account: Vec<u8>,
+    #[prost(bytes = "vec", tag = "7")]
+    pub ibc_program_id: Vec<u8>,
+    #[prost(message, repeated, tag = "8")]
+    pub proof_specs: Vec<RawProofSpec>,
+    #[prost(message, optional, tag = "9")]
+    pub frozen_height: Option<RawHeight>,
+}
+
+impl_name!(ClientState, "ClientState");
+
+/// ```protobuf
+/// message ConsensusState {
+///   bytes root = 1;
+///   uint64 timestamp = 2;
+///   bytes bank_hash = 3;
+/// }
+/// ```
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct ConsensusState {
+    #[prost(bytes = "vec", tag = "1")]
+    pub root: Vec<u8>,
+    /// Unix timestamp in nanoseconds.
+    #[prost(uint64, tag = "2")]
+    pub timestamp: u64,
+    #[prost(bytes = "vec", tag = "3")]
+    pub bank_hash: Vec<u8>,
+}
+
+impl_name!(ConsensusState, "ConsensusState");
+
+/// ```protobuf
+/// message BankHashComponents {
+///   bytes parent_bank_hash = 1;
+///   bytes accounts_delta_hash = 2;
+///   uint64 signature_count = 3;
+///   bytes last_blockhash = 4;
+/// }
+/// ```
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct BankHashComponents {
+    #[prost(bytes = "vec", tag = "1")]
+    pub parent_bank_hash: Vec<u8>,
+    #[prost(bytes = "vec", tag = "2")]
+    pub accounts_delta_hash: Vec<u8>,
+    #[prost(uint64, tag = "3")]
+    pub signature_count: u64,
+    #[prost(bytes = "vec", tag = "4")]
+    pub last_blockhash: Vec<u8>,
+}
+
+impl_name!(BankHashComponents, "BankHashComponents");
+
+/// ```protobuf
+/// message Account {
+///   bytes pubkey = 1;
+///   uint64 lamports = 2;
+///   bytes owner = 3;
+///   bool executable = 4;
+///   uint64 rent_epoch = 5;
+///   bytes data = 6;
+/// }
+/// ```
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct Account {
+    #[prost(bytes = "vec", tag = "1")]
+    pub pubkey: Vec<u8>,
+    #[prost(uint64, tag = "2")]
+    pub lamports: u64,
+    #[prost(bytes = "vec", tag = "3")]
+    pub owner: Vec<u8>,
+    #[prost(bool, tag = "4")]
+    pub executable: bool,
+    #[prost(uint64, tag = "5")]
+    pub rent_epoch: u64,
+    #[prost(bytes = "vec", tag = "6")]
+    pub data: Vec<u8>,
+}
+
+impl_name!(Account, "Account");
+
+/// ```protobuf
+/// message ProofLevel {
+///   uint32 index = 1;
+///   repeated bytes siblings = 2;
+/// }
+/// ```
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct ProofLevel {
+    #[prost(uint32, tag = "1")]
+    pub index: u32,
+    #[prost(bytes = "vec", repeated, tag = "2")]
+    pub siblings: Vec<Vec<u8>>,
+}
+
+impl_name!(ProofLevel, "ProofLevel");
+
+/// ```protobuf
+/// message AccountProof {
+///   Account account = 1;
+///   repeated ProofLevel path = 2;
+/// }
+/// ```
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct AccountProof {
+    #[prost(message, optional, tag = "1")]
+    pub account: Option<Account>,
+    #[prost(message, repeated, tag = "2")]
+    pub path: Vec<ProofLevel>,
+}
+
+impl_name!(AccountProof, "AccountProof");
+
+/// ```protobuf
+/// message Vote {
+///   bytes pubkey = 1;
+///   bytes signature = 2;
+/// }
+/// ```
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct Vote {
+    #[prost(bytes = "vec", tag = "1")]
+    pub pubkey: Vec<u8>,
+    #[prost(bytes = "vec", tag = "2")]
+    pub signature: Vec<u8>,
+}
+
+impl_name!(Vote, "Vote");
+
+/// ```protobuf
+/// message Header {
+///   uint64 slot = 1;
+///   BankHashComponents bank = 2;
+///   repeated Validator next_validators = 3;
+///   repeated Vote votes = 4;
+///   AccountProof storage_proof = 5;
+///   AccountProof clock_proof = 6;
+/// }
+/// ```
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct Header {
+    #[prost(uint64, tag = "1")]
+    pub slot: u64,
+    #[prost(message, optional, tag = "2")]
+    pub bank: Option<BankHashComponents>,
+    /// Empty if the header does not attest to the next validators.
+    #[prost(message, repeated, tag = "3")]
+    pub next_validators: Vec<Validator>,
+    #[prost(message, repeated, tag = "4")]
+    pub votes: Vec<Vote>,
+    #[prost(message, optional, tag = "5")]
+    pub storage_proof: Option<AccountProof>,
+    #[prost(message, optional, tag = "6")]
+    pub clock_proof: Option<AccountProof>,
+}
+
+impl_name!(Header, "Header");
+
+/// ```protobuf
+/// message Misbehaviour {
+///   Header header_1 = 1;
+///   Header header_2 = 2;
+/// }
+/// ```
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct Misbehaviour {
+    #[prost(message, optional, tag = "1")]
+    pub header_1: Option<Header>,
+    #[prost(message, optional, tag = "2")]
+    pub header_2: Option<Header>,
+}
+
+impl_name!(Misbehaviour, "Misbehaviour");
@@ -11,14 +11,79 @@
     rust_2018_idioms
 )]
 
+/// Re-exports implementations of ICS-06 Solo Machine light client.
+#[cfg(feature = "solomachine")]
+pub mod solomachine {
+    #[doc(inline)]
+    pub use ibc_client_solomachine::*;
+}
+
 /// Re-exports implementations of ICS-07 Tendermint light client.
+#[cfg(feature = "tendermint")]
 pub mod tendermint {
     #[doc(inline)]
     pub use ibc_client_tendermint::*;
 }
 
+/// Re-exports the host-side implementation of the ICS-08 Wasm light client.
+#[cfg(feature = "wasm")]
+pub mod wasm {
+    #[doc(inline)]
+    pub use ibc_client_wasm::*;
+}
+
 /// Re-exports implementations of ICS-08 Wasm light client types.
+#[cfg(feature = "wasm")]
 pub mod wasm_types {
     #[doc(inline)]
     pub use ibc_client_wasm_types::*;
 }
+
+/// Re-exports implementations of ICS-09 localhost light client.
+#[cfg(feature = "localhost")]
+pub mod localhost {
+    #[doc(inline)]
+    pub use ibc_client_localhost::*;
+}
+
+/// Re-exports implementations of ICS-10 GRANDPA light client.
+#[cfg(feature = "grandpa")]
+pub mod grandpa {
+    #[doc(inline)]
+    pub use ibc_client_grandpa::*;
+}
+
+/// Re-exports implementations of the NEAR light client.
+#[cfg(feature = "near")]
+pub mod near {
+    #[doc(inline)]
+    pub use ibc_client_near::*;
+}
+
+/// Re-exports implementations of the Ethereum light client.
+#[cfg(feature = "ethereum")]
+pub mod ethereum {
+    #[doc(inline)]
+    pub use ibc_client_ethereum::*;
+}
+
+/// Re-exports implementations of the Solana light client.
+#[cfg(feature = "solana")]
+pub mod solana {
+    #[doc(inline)]
+    pub use ibc_client_solana::*;
+}
+
+/// Re-exports implementations of the Bitcoin SPV light client.
+#[cfg(feature = "bitcoin")]
+pub mod bitcoin {
+    #[doc(inline)]
+    pub use ibc_client_bitcoin::*;
+}
+
+/// Re-exports implementations of the attestor light client.
+#[cfg(feature = "attestor")]
+pub mod attestor {
+    #[doc(inline)]
+    pub use ibc_client_attestor::*;
+}
@@ -0,0 +1,118 @@
+//! Defines the cache of the client and consensus states decoded by the client
+//! context of a host.
+
+use core::cell::RefCell;
+
+use ibc_core_handler_types::error::ContextError;
+use ibc_core_host_types::identifiers::ClientId;
+use ibc_core_host_types::path::ClientConsensusStatePath;
+use ibc_primitives::prelude::*;
+
+/// Caches the client states `CS` and consensus states `CONS` decoded by a
+/// [`ClientValidationContext`](crate::ClientValidationContext), for the
+/// duration of a block.
+///
+/// The light clients read the same states many times over the messages of a
+/// block, e.g. the client state of each `MsgRecvPacket` of a relayer. The
+/// client context reads the states through the cache, and invalidates them
+/// whenever it writes them:
+///
+/// ```ignore
+/// fn client_state(&self, client_id: &ClientId) -> Result<Self::ClientStateRef, ContextError> {
+///     self.client_cache
+///         .client_state(client_id, || Ok(AnyClientState::try_from(self.get_any(client_id)?)?))
+/// }
+///
+/// fn store_client_state(
+///     &mut self,
+///     client_state_path: ClientStatePath,
+///     client_state: Self::ClientStateRef,
+/// ) -> Result<(), ContextError> {
+///     self.client_cache.invalidate_client_state(&client_state_path.0);
+///     ...
+/// }
+/// ```
+///
+/// The cache is meant to be [cleared](Self::clear) at the end of each block,
+/// as well as whenever the host discards writes of the client context, e.g.
+/// on rolling back a failed transaction.
+#[derive(Debug)]
+pub struct ClientStateCache<CS, CONS> {
+    client_states: RefCell<BTreeMap<ClientId, CS>>,
+    consensus_states: RefCell<BTreeMap<ClientConsensusStatePath, CONS>>,
+}
+
+impl<CS, CONS> ClientStateCache<CS, CONS> {
+    pub fn new() -> Self {
+        Self {
+            client_states: RefCell::new(BTreeMap::new()),
+            consensus_states: RefCell::new(BTreeMap::new()),
+        }
+    }
+
+    /// Drops the client state of the client, if cached.
+    pub fn invalidate_client_state(&self, client_id: &ClientId) {
+        self.client_states.borrow_mut().remove(client_id);
+    }
+
+    /// Drops the consensus state at the path, if cached.
+    pub fn invalidate_consensus_state(&self, consensus_state_path: &ClientConsensusStatePath) {
+        self.consensus_states
+            .borrow_mut()
+            .remove(consensus_state_path);
+    }
+
+    /// Drops all the cached states.
+    pub fn clear(&self) {
+        self.client_states.borrow_mut().clear();
+        self.consensus_states.borrow_mut().clear();
+    }
+}
+
+impl<CS: Clone, CONS: Clone> ClientStateCache<CS, CONS> {
+    /// Returns the cached client state of the client, or the one returned by
+    /// `load`, which is then cached unless it failed.
+    pub fn client_state(
+        &self,
+        client_id: &ClientId,
+        load: impl FnOnce() -> Result<CS, ContextError>,
+    ) -> Result<CS, ContextError> {
+        if let Some(client_state) = self.client_states.borrow().get(client_id) {
+            return Ok(client_state.clone());
+        }
+
+        let client_state = load()?;
+
+        self.client_states
+            .borrow_mut()
+            .insert(client_id.clone(), client_state.clone());
+
+        Ok(client_state)
+    }
+
+    /// Returns the cached consensus state at the path, or the one returned by
+    /// `load`, which is then cached unless it failed.
+    pub fn consensus_state(
+        &self,
+        consensus_state_path: &ClientConsensusStatePath,
+        load: impl FnOnce() -> Result<CONS, ContextError>,
+    ) -> Result<CONS, ContextError> {
+        if let Some(consensus_state) = self.consensus_states.borrow().get(consensus_state_path) {
+            return Ok(consensus_state.clone());
+        }
+
+        let consensus_state = load()?;
+
+        self.consensus_states
+            .borrow_mut()
+            .insert(consensus_state_path.clone(), consensus_state.clone());
+
+        Ok(consensus_state)
+    }
+}
+
+impl<CS, CONS> Default for ClientStateCache<CS, CONS> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -10,6 +10,8 @@ use ibc_core_host_types::path::Path;
 use ibc_primitives::prelude::*;
 use ibc_primitives::proto::Any;
 
+use crate::signature_batch::SignatureBatch;
+
 /// Convenient trait to decode a client state from an `Any` type and obtain a
 /// handle to the local instance of `ClientState`.
 pub trait ClientStateDecoder: Into<Any> + TryFrom<Any, Error = ClientError> {}
@@ -124,6 +126,72 @@ where
 
     /// Returns the status of the client. Only Active clients are allowed to process packets.
     fn status(&self, ctx: &V, client_id: &ClientId) -> Result<Status, ClientError>;
+
+    /// Verifies a proof of the existence of a value at a given `Path`, with
+    /// access to the client's validation context.
+    ///
+    /// This is what the core handlers call. It defaults to
+    /// [`ClientStateCommon::verify_membership`]; clients which verify against
+    /// the host's own state rather than a root, such as the localhost client,
+    /// override it.
+    fn verify_membership_with_context(
+        &self,
+        _ctx: &V,
+        prefix: &CommitmentPrefix,
+        proof: &CommitmentProofBytes,
+        root: &CommitmentRoot,
+        path: Path,
+        value: Vec<u8>,
+    ) -> Result<(), ClientError> {
+        self.verify_membership(prefix, proof, root, path, value)
+    }
+
+    /// Verifies the absence of a given commitment, with access to the
+    /// client's validation context.
+    ///
+    /// Defaults to [`ClientStateCommon::verify_non_membership`].
+    fn verify_non_membership_with_context(
+        &self,
+        _ctx: &V,
+        prefix: &CommitmentPrefix,
+        proof: &CommitmentProofBytes,
+        root: &CommitmentRoot,
+        path: Path,
+    ) -> Result<(), ClientError> {
+        self.verify_non_membership(prefix, proof, root, path)
+    }
+
+    /// Checks that the given substitute client state can replace the state
+    /// of this client, as requested by the governance of the host to recover
+    /// the client after it froze or expired.
+    ///
+    /// Clients which support recovery override it, along with
+    /// [`ClientStateExecution::update_on_recovery`].
+    fn check_substitute(&self, _ctx: &V, _substitute_client_state: Any) -> Result<(), ClientError> {
+        Err(ClientError::RecoveryUnsupported {
+            client_type: self.client_type(),
+        })
+    }
+
+    /// Pushes onto the `batch` the signature checks which verifying the
+    /// `client_message` would perform, for the batch dispatch entrypoint to
+    /// verify them together with the ones of the other client updates.
+    ///
+    /// Clients which look up the [`VerifiedSignatures`] of their context in
+    /// [`verify_client_message`](Self::verify_client_message) override it.
+    /// Any signature left out is verified by the client on its own, so that
+    /// it is fine to collect only some of them.
+    ///
+    /// [`VerifiedSignatures`]: crate::signature_batch::VerifiedSignatures
+    fn collect_signature_checks(
+        &self,
+        _ctx: &V,
+        _client_id: &ClientId,
+        _client_message: &Any,
+        _batch: &mut SignatureBatch,
+    ) -> Result<(), ClientError> {
+        Ok(())
+    }
 }
 
 /// `ClientState` methods which require access to the client's
@@ -180,6 +248,21 @@ where
         upgraded_client_state: Any,
         upgraded_consensus_state: Any,
     ) -> Result<Height, ClientError>;
+
+    /// Updates the state of the subject client with the one of the substitute
+    /// client, whose latest consensus state is read from the context, once
+    /// [`ClientStateValidation::check_substitute`] succeeded.
+    fn update_on_recovery(
+        &self,
+        _ctx: &mut E,
+        _subject_client_id: &ClientId,
+        _substitute_client_id: &ClientId,
+        _substitute_client_state: Any,
+    ) -> Result<(), ClientError> {
+        Err(ClientError::RecoveryUnsupported {
+            client_type: self.client_type(),
+        })
+    }
 }
 
 use crate::context::{ClientExecutionContext, ClientValidationContext};
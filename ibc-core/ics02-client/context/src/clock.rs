@@ -0,0 +1,18 @@
+use ibc_core_client_types::Height;
+use ibc_core_handler_types::error::ContextError;
+use ibc_primitives::Timestamp;
+
+/// Source of the current height and timestamp of the host chain.
+///
+/// The host contexts of the core handlers and of the light clients all build
+/// on this trait, so that a host implements its clock once. Hosts whose time
+/// does not come from their own blocks, e.g. rollups following the clock of
+/// their data availability layer, or simulations advancing time on demand,
+/// substitute the clock without re-implementing the rest of their contexts.
+pub trait HostClock {
+    /// Returns the current height of the local chain.
+    fn host_height(&self) -> Result<Height, ContextError>;
+
+    /// Returns the current timestamp of the local chain.
+    fn host_timestamp(&self) -> Result<Timestamp, ContextError>;
+}
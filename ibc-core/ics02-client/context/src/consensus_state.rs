@@ -17,6 +17,9 @@ pub trait ConsensusState: Send + Sync {
 
     /// Serializes the `ConsensusState`. This is expected to be implemented as
     /// first converting to the raw type (i.e. the protobuf definition), and then
-    /// serializing that.
+    /// serializing that canonically (see [`encode_canonical`]), as the
+    /// counterparty proves the consensus state against these very bytes.
+    ///
+    /// [`encode_canonical`]: ibc_primitives::encode_canonical
     fn encode_vec(self) -> Vec<u8>;
 }
@@ -6,6 +6,7 @@ use ibc_primitives::Timestamp;
 
 use crate::client_state::{ClientStateExecution, ClientStateValidation};
 use crate::consensus_state::ConsensusState;
+use crate::signature_batch::VerifiedSignatures;
 
 /// Defines the methods available to clients for validating client state
 /// transitions. The generic `V` parameter in
@@ -38,6 +39,15 @@ pub trait ClientValidationContext: Sized {
         client_id: &ClientId,
         height: &Height,
     ) -> Result<(Timestamp, Height), ContextError>;
+
+    /// Returns the signatures which the batch dispatch entrypoint verified
+    /// ahead of the client updates of the batch, if any.
+    ///
+    /// Hosts opting into the batch verification return the signatures last
+    /// set by [`ClientExecutionContext::set_verified_signatures`].
+    fn verified_signatures(&self) -> Option<&VerifiedSignatures> {
+        None
+    }
 }
 
 /// Defines the methods that all client `ExecutionContext`s (precisely the
@@ -101,4 +111,11 @@ pub trait ClientExecutionContext:
         client_id: ClientId,
         height: Height,
     ) -> Result<(), ContextError>;
+
+    /// Sets the signatures verified by the batch dispatch entrypoint, which
+    /// are cleared once the batch is dispatched.
+    ///
+    /// Ignored by default, in which case the light clients verify each
+    /// signature on their own.
+    fn set_verified_signatures(&mut self, _verified_signatures: Option<VerifiedSignatures>) {}
 }
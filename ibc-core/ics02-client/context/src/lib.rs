@@ -15,11 +15,17 @@
     rust_2018_idioms
 )]
 
+extern crate alloc;
 #[cfg(feature = "std")]
 extern crate std;
 
+pub mod cache;
 pub mod client_state;
 pub mod consensus_state;
+pub mod signature_batch;
+
+mod clock;
+pub use clock::*;
 
 mod context;
 pub use context::*;
@@ -27,8 +33,10 @@ pub use context::*;
 /// Trait preludes for the ICS-02 client implementation.
 pub mod prelude {
     pub use crate::client_state::*;
+    pub use crate::clock::*;
     pub use crate::consensus_state::*;
     pub use crate::context::*;
+    pub use crate::signature_batch::*;
 }
 
 pub mod types {
@@ -0,0 +1,99 @@
+//! Defines the batch verification of the signatures checked by the client
+//! updates of a block.
+//!
+//! Verifying the signatures of many client updates together, e.g. with
+//! ed25519 batch verification, is substantially cheaper than verifying them
+//! one by one. The batch dispatch entrypoint collects the [`SignatureCheck`]s
+//! of the `MsgUpdateClient`s of a batch through
+//! [`ClientStateValidation::collect_signature_checks`], verifies them with a
+//! [`BatchSignatureVerifier`] and hands the [`VerifiedSignatures`] to the
+//! client context, for the light clients to skip the checks already made.
+//!
+//! [`ClientStateValidation::collect_signature_checks`]:
+//! crate::client_state::ClientStateValidation::collect_signature_checks
+
+use alloc::collections::BTreeSet;
+
+use ibc_primitives::prelude::*;
+
+/// A signature over a message, to be verified against a public key.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct SignatureCheck {
+    pub public_key: Vec<u8>,
+    pub message: Vec<u8>,
+    pub signature: Vec<u8>,
+}
+
+/// Verifies a batch of signatures together.
+///
+/// The verifiers must only accept a batch if each of its signatures would be
+/// accepted on its own by the light clients, so that the outcome of the
+/// messages does not depend on whether they were batch-verified.
+pub trait BatchSignatureVerifier {
+    /// Returns whether all the signatures of the batch are valid.
+    fn verify_batch(&self, checks: &[SignatureCheck]) -> bool;
+}
+
+/// The signature checks collected from the client updates of a batch.
+#[derive(Clone, Debug, Default)]
+pub struct SignatureBatch {
+    checks: Vec<SignatureCheck>,
+}
+
+impl SignatureBatch {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn push(&mut self, check: SignatureCheck) {
+        self.checks.push(check);
+    }
+
+    pub fn len(&self) -> usize {
+        self.checks.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.checks.is_empty()
+    }
+
+    pub fn checks(&self) -> &[SignatureCheck] {
+        &self.checks
+    }
+
+    /// Verifies the batch with the `verifier`, returning the verified
+    /// signatures, or `None` if the batch is empty or failed to verify, in
+    /// which case the signatures are left for the light clients to verify one
+    /// by one.
+    pub fn verify(self, verifier: &impl BatchSignatureVerifier) -> Option<VerifiedSignatures> {
+        if self.is_empty() || !verifier.verify_batch(&self.checks) {
+            return None;
+        }
+
+        Some(VerifiedSignatures {
+            checks: self.checks.into_iter().collect(),
+        })
+    }
+}
+
+/// The signatures of a batch which were verified together, and which the
+/// light clients do not need to verify again.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct VerifiedSignatures {
+    checks: BTreeSet<SignatureCheck>,
+}
+
+impl VerifiedSignatures {
+    /// Returns whether the signature was verified as part of the batch.
+    pub fn contains(&self, check: &SignatureCheck) -> bool {
+        self.checks.contains(check)
+    }
+
+    pub fn len(&self) -> usize {
+        self.checks.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.checks.is_empty()
+    }
+}
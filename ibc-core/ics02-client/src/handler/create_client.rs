@@ -6,7 +6,9 @@ use ibc_core_client_types::events::CreateClient;
 use ibc_core_client_types::msgs::MsgCreateClient;
 use ibc_core_handler_types::error::ContextError;
 use ibc_core_handler_types::events::{IbcEvent, MessageEvent};
-use ibc_core_host::{ClientStateMut, ClientStateRef, ExecutionContext, ValidationContext};
+use ibc_core_host::{
+    ClientStateMut, ClientStateRef, ExecutionContext, GasOperation, ValidationContext,
+};
 use ibc_primitives::prelude::*;
 
 pub fn validate<Ctx>(ctx: &Ctx, msg: MsgCreateClient) -> Result<(), ContextError>
@@ -28,7 +30,13 @@ where
 
     let client_state = ClientStateRef::<Ctx>::try_from(client_state)?;
 
-    let client_id = client_state.client_type().build_client_id(id_counter);
+    let client_type = client_state.client_type();
+
+    if !ctx.client_params().is_allowed(&client_type) {
+        return Err(ClientError::ClientTypeNotAllowed { client_type }.into());
+    }
+
+    let client_id = client_type.build_client_id(id_counter);
 
     let status = client_state.status(client_val_ctx, &client_id)?;
 
@@ -70,6 +78,7 @@ where
 
     client_state.initialise(client_exec_ctx, &client_id, consensus_state)?;
 
+    ctx.gas_meter().charge(GasOperation::StoreWrite)?;
     ctx.increase_client_counter()?;
 
     let event = IbcEvent::CreateClient(CreateClient::new(
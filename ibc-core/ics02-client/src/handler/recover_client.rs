@@ -0,0 +1,98 @@
+//! Protocol logic specific to processing ICS2 messages of type `MsgRecoverClient`,
+//! submitted by the governance of the host.
+
+use ibc_core_client_context::prelude::*;
+use ibc_core_client_types::error::ClientError;
+use ibc_core_client_types::msgs::MsgRecoverClient;
+use ibc_core_client_types::Status;
+use ibc_core_handler_types::error::ContextError;
+use ibc_core_host::{Authority, ExecutionContext, ValidationContext};
+use ibc_primitives::prelude::*;
+
+pub fn validate<Ctx>(ctx: &Ctx, msg: MsgRecoverClient) -> Result<(), ContextError>
+where
+    Ctx: ValidationContext + Authority,
+{
+    let MsgRecoverClient {
+        subject_client_id,
+        substitute_client_id,
+        signer,
+    } = msg;
+
+    if !ctx.is_authorized(&signer) {
+        return Err(ClientError::Unauthorized { signer }.into());
+    }
+
+    if subject_client_id == substitute_client_id {
+        return Err(ClientError::InvalidRecovery {
+            reason: "the subject and substitute clients must differ".to_string(),
+        }
+        .into());
+    }
+
+    let client_val_ctx = ctx.get_client_validation_context();
+
+    let subject_client_state = client_val_ctx.client_state(&subject_client_id)?;
+    let substitute_client_state = client_val_ctx.client_state(&substitute_client_id)?;
+
+    // Only the clients which can no longer be updated need to be recovered.
+    let subject_status = subject_client_state.status(client_val_ctx, &subject_client_id)?;
+
+    if !matches!(subject_status, Status::Frozen | Status::Expired) {
+        return Err(ClientError::InvalidRecovery {
+            reason: format!("the subject client is {subject_status}, not frozen nor expired"),
+        }
+        .into());
+    }
+
+    substitute_client_state
+        .status(client_val_ctx, &substitute_client_id)?
+        .verify_is_active()?;
+
+    if subject_client_state.client_type() != substitute_client_state.client_type() {
+        return Err(ClientError::InvalidRecovery {
+            reason: "the subject and substitute clients must be of the same type".to_string(),
+        }
+        .into());
+    }
+
+    if substitute_client_state.latest_height() <= subject_client_state.latest_height() {
+        return Err(ClientError::InvalidRecovery {
+            reason: "the substitute client must be ahead of the subject client".to_string(),
+        }
+        .into());
+    }
+
+    subject_client_state.check_substitute(client_val_ctx, substitute_client_state.into())?;
+
+    Ok(())
+}
+
+pub fn execute<Ctx>(ctx: &mut Ctx, msg: MsgRecoverClient) -> Result<(), ContextError>
+where
+    Ctx: ExecutionContext,
+{
+    let MsgRecoverClient {
+        subject_client_id,
+        substitute_client_id,
+        ..
+    } = msg;
+
+    let client_exec_ctx = ctx.get_client_execution_context();
+
+    let subject_client_state = client_exec_ctx.client_state_mut(&subject_client_id)?;
+    let substitute_client_state = client_exec_ctx.client_state(&substitute_client_id)?;
+
+    subject_client_state.update_on_recovery(
+        client_exec_ctx,
+        &subject_client_id,
+        &substitute_client_id,
+        substitute_client_state.into(),
+    )?;
+
+    ctx.log_message(format!(
+        "success: recovered client {subject_client_id} with client {substitute_client_id}"
+    ))?;
+
+    Ok(())
+}
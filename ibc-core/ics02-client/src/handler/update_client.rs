@@ -7,10 +7,18 @@ use ibc_core_client_types::msgs::MsgUpdateOrMisbehaviour;
 use ibc_core_client_types::UpdateKind;
 use ibc_core_handler_types::error::ContextError;
 use ibc_core_handler_types::events::{IbcEvent, MessageEvent};
-use ibc_core_host::{ExecutionContext, ValidationContext};
+use ibc_core_host::{ExecutionContext, GasOperation, ValidationContext};
 use ibc_primitives::prelude::*;
 use ibc_primitives::ToVec;
 
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(
+        level = "debug",
+        skip_all,
+        fields(client_id = %msg.client_id()),
+    )
+)]
 pub fn validate<Ctx>(ctx: &Ctx, msg: MsgUpdateOrMisbehaviour) -> Result<(), ContextError>
 where
     Ctx: ValidationContext,
@@ -30,11 +38,25 @@ where
 
     let client_message = msg.client_message();
 
+    ctx.gas_meter()
+        .charge(GasOperation::ClientMessageVerification {
+            client_id: &client_id,
+            client_message: &client_message,
+        })?;
+
     client_state.verify_client_message(client_val_ctx, &client_id, client_message)?;
 
     Ok(())
 }
 
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(
+        level = "debug",
+        skip_all,
+        fields(client_id = %msg.client_id()),
+    )
+)]
 pub fn execute<Ctx>(ctx: &mut Ctx, msg: MsgUpdateOrMisbehaviour) -> Result<(), ContextError>
 where
     Ctx: ExecutionContext,
@@ -81,6 +103,8 @@ where
                     description: "client update state returned no updated height".to_string(),
                 })?;
 
+                ctx.metrics().client_updated(&client_id, *consensus_height);
+
                 IbcEvent::UpdateClient(UpdateClient::new(
                     client_id,
                     client_state.client_type(),
@@ -0,0 +1,31 @@
+//! Protocol logic specific to processing ICS2 messages of type
+//! `MsgUpdateClientParams`, submitted by the governance of the host.
+
+use ibc_core_client_types::error::ClientError;
+use ibc_core_client_types::msgs::MsgUpdateClientParams;
+use ibc_core_handler_types::error::ContextError;
+use ibc_core_host::{Authority, ExecutionContext, GasOperation, ValidationContext};
+
+pub fn validate<Ctx>(ctx: &Ctx, msg: &MsgUpdateClientParams) -> Result<(), ContextError>
+where
+    Ctx: ValidationContext + Authority,
+{
+    if !ctx.is_authorized(&msg.signer) {
+        return Err(ClientError::Unauthorized {
+            signer: msg.signer.clone(),
+        }
+        .into());
+    }
+
+    Ok(())
+}
+
+/// Replaces the parameters of the client module, applying to the clients
+/// created from now on.
+pub fn execute<Ctx>(ctx: &mut Ctx, msg: MsgUpdateClientParams) -> Result<(), ContextError>
+where
+    Ctx: ExecutionContext,
+{
+    ctx.gas_meter().charge(GasOperation::StoreWrite)?;
+    ctx.store_client_params(msg.params)
+}
@@ -6,7 +6,7 @@ use ibc_core_commitment_types::error::CommitmentError;
 use ibc_core_host_types::error::IdentifierError;
 use ibc_core_host_types::identifiers::{ClientId, ClientType};
 use ibc_primitives::prelude::*;
-use ibc_primitives::Timestamp;
+use ibc_primitives::{Signer, Timestamp};
 
 use super::status::Status;
 use crate::height::Height;
@@ -93,6 +93,20 @@ pub enum ClientError {
     InvalidUpdateClientMessage,
     /// other error: `{description}`
     Other { description: String },
+    /// client type `{client_type}` is not allowed by the client parameters
+    ClientTypeNotAllowed { client_type: ClientType },
+    /// signer `{signer}` is not authorized
+    Unauthorized { signer: Signer },
+    /// invalid client recovery: `{reason}`
+    InvalidRecovery { reason: String },
+    /// client type `{client_type}` does not support client recovery
+    RecoveryUnsupported { client_type: ClientType },
+    /// the client parameters are not stored by the host
+    ParamsUnsupported,
+    /// no decoder is registered for the type URL `{type_url}`
+    UnregisteredTypeUrl { type_url: String },
+    /// a decoder is already registered for the type URL `{type_url}`
+    DuplicateTypeUrl { type_url: String },
 }
 
 impl From<&'static str> for ClientError {
@@ -103,10 +117,71 @@ impl From<&'static str> for ClientError {
     }
 }
 
-#[cfg(feature = "std")]
-impl std::error::Error for ClientError {
-    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+/// The codespace of the [`ClientError`]s in ABCI responses.
+pub const CLIENT_CODESPACE: &str = "client";
+
+impl ClientError {
+    /// Returns the code identifying the variant of the error within the
+    /// [`CLIENT_CODESPACE`], which is kept across releases.
+    ///
+    /// The codes start at 2, as 0 stands for success and 1 for internal errors
+    /// in ABCI responses. The variants of the [`UpgradeClientError`] are given
+    /// the codes from 100 on.
+    pub fn code(&self) -> u32 {
+        match self {
+            Self::Upgrade(e) => e.code(),
+            Self::ClientFrozen { .. } => 2,
+            Self::ClientNotActive { .. } => 3,
+            Self::ClientStateNotFound { .. } => 4,
+            Self::ClientStateAlreadyExists { .. } => 5,
+            Self::ConsensusStateNotFound { .. } => 6,
+            Self::UpdateMetaDataNotFound { .. } => 7,
+            Self::HeaderVerificationFailure { .. } => 8,
+            Self::InvalidTrustThreshold { .. } => 9,
+            Self::FailedTrustThresholdConversion { .. } => 10,
+            Self::UnknownClientStateType { .. } => 11,
+            Self::EmptyPrefix => 12,
+            Self::UnknownConsensusStateType { .. } => 13,
+            Self::UnknownHeaderType { .. } => 14,
+            Self::UnknownMisbehaviourType { .. } => 15,
+            Self::MissingRawClientState => 16,
+            Self::MissingRawConsensusState => 17,
+            Self::InvalidMsgUpdateClientId(_) => 18,
+            Self::InvalidClientIdentifier(_) => 19,
+            Self::InvalidRawHeader { .. } => 20,
+            Self::MissingClientMessage => 21,
+            Self::InvalidRawMisbehaviour(_) => 22,
+            Self::MissingRawMisbehaviour => 23,
+            Self::InvalidHeight => 24,
+            Self::InvalidHeightResult => 25,
+            Self::InvalidProofHeight { .. } => 26,
+            Self::InvalidCommitmentProof(_) => 27,
+            Self::InvalidPacketTimestamp(_) => 28,
+            Self::ClientArgsTypeMismatch { .. } => 29,
+            Self::InvalidConsensusStateTimestamp { .. } => 30,
+            Self::MissingLocalConsensusState { .. } => 31,
+            Self::InvalidSigner { .. } => 32,
+            Self::Ics23Verification(_) => 33,
+            Self::MisbehaviourHandlingFailure { .. } => 34,
+            Self::ClientSpecific { .. } => 35,
+            Self::CounterOverflow => 36,
+            Self::InvalidUpdateClientMessage => 37,
+            Self::Other { .. } => 38,
+            Self::ClientTypeNotAllowed { .. } => 39,
+            Self::Unauthorized { .. } => 40,
+            Self::InvalidRecovery { .. } => 41,
+            Self::RecoveryUnsupported { .. } => 42,
+            Self::ParamsUnsupported => 43,
+            Self::UnregisteredTypeUrl { .. } => 44,
+            Self::DuplicateTypeUrl { .. } => 45,
+        }
+    }
+}
+
+impl core::error::Error for ClientError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
         match &self {
+            Self::Upgrade(e) => Some(e),
             Self::InvalidMsgUpdateClientId(e)
             | Self::InvalidClientIdentifier(e)
             | Self::InvalidRawMisbehaviour(e) => Some(e),
@@ -135,6 +210,8 @@ pub enum UpgradeClientError {
     InvalidUpgradePlan { reason: String },
     /// other upgrade client error: `{reason}`
     Other { reason: String },
+    /// signer `{signer}` is not authorized to schedule upgrades
+    Unauthorized { signer: Signer },
 }
 
 impl From<UpgradeClientError> for ClientError {
@@ -143,9 +220,24 @@ impl From<UpgradeClientError> for ClientError {
     }
 }
 
-#[cfg(feature = "std")]
-impl std::error::Error for UpgradeClientError {
-    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+impl UpgradeClientError {
+    /// Returns the code identifying the variant of the error within the
+    /// [`CLIENT_CODESPACE`], which is kept across releases.
+    pub fn code(&self) -> u32 {
+        match self {
+            Self::InvalidUpgradeClientProof(_) => 100,
+            Self::InvalidUpgradeConsensusStateProof(_) => 101,
+            Self::LowUpgradeHeight { .. } => 102,
+            Self::InvalidUpgradeProposal { .. } => 103,
+            Self::InvalidUpgradePlan { .. } => 104,
+            Self::Other { .. } => 105,
+            Self::Unauthorized { .. } => 106,
+        }
+    }
+}
+
+impl core::error::Error for UpgradeClientError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
         match &self {
             Self::InvalidUpgradeClientProof(e) | Self::InvalidUpgradeConsensusStateProof(e) => {
                 Some(e)
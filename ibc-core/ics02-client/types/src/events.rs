@@ -41,6 +41,7 @@ pub const HEADER_ATTRIBUTE_KEY: &str = "header";
     derive(borsh::BorshSerialize, borsh::BorshDeserialize)
 )]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[derive(Clone, Debug, From, PartialEq, Eq)]
 struct ClientIdAttribute {
     client_id: ClientId,
@@ -65,6 +66,7 @@ impl From<ClientIdAttribute> for abci::EventAttribute {
     derive(borsh::BorshSerialize, borsh::BorshDeserialize)
 )]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[derive(Clone, Debug, From, PartialEq, Eq)]
 struct ClientTypeAttribute {
     client_type: ClientType,
@@ -89,6 +91,7 @@ impl From<ClientTypeAttribute> for abci::EventAttribute {
     derive(borsh::BorshSerialize, borsh::BorshDeserialize)
 )]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[derive(Clone, Debug, From, PartialEq, Eq)]
 struct ConsensusHeightAttribute {
     consensus_height: Height,
@@ -113,6 +116,7 @@ impl From<ConsensusHeightAttribute> for abci::EventAttribute {
     derive(borsh::BorshSerialize, borsh::BorshDeserialize)
 )]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[derive(Clone, Debug, From, PartialEq, Eq)]
 struct ConsensusHeightsAttribute {
     consensus_heights: Vec<Height>,
@@ -142,6 +146,7 @@ impl From<ConsensusHeightsAttribute> for abci::EventAttribute {
     derive(borsh::BorshSerialize, borsh::BorshDeserialize)
 )]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[derive(Clone, Debug, From, PartialEq, Eq)]
 struct HeaderAttribute {
     /// NOTE: The header is encoded as bytes of the
@@ -174,6 +179,7 @@ impl From<HeaderAttribute> for abci::EventAttribute {
     derive(borsh::BorshSerialize, borsh::BorshDeserialize)
 )]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct CreateClient {
     client_id: ClientIdAttribute,
@@ -234,6 +240,7 @@ impl From<CreateClient> for abci::Event {
     derive(borsh::BorshSerialize, borsh::BorshDeserialize)
 )]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct UpdateClient {
     client_id: ClientIdAttribute,
@@ -321,6 +328,7 @@ impl From<UpdateClient> for abci::Event {
     derive(borsh::BorshSerialize, borsh::BorshDeserialize)
 )]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct ClientMisbehaviour {
     client_id: ClientIdAttribute,
@@ -371,6 +379,7 @@ impl From<ClientMisbehaviour> for abci::Event {
     derive(borsh::BorshSerialize, borsh::BorshDeserialize)
 )]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct UpgradeClient {
     client_id: ClientIdAttribute,
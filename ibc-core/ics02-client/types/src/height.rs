@@ -162,9 +162,8 @@ pub enum HeightError {
     InvalidFormat { raw_height: String },
 }
 
-#[cfg(feature = "std")]
-impl std::error::Error for HeightError {
-    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+impl core::error::Error for HeightError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
         match &self {
             HeightError::HeightConversion { error: e, .. } => Some(e),
             HeightError::ZeroHeight | HeightError::InvalidFormat { .. } => None,
@@ -18,6 +18,8 @@ pub mod error;
 pub mod events;
 mod height;
 pub mod msgs;
+pub mod params;
+pub mod registry;
 mod status;
 
 pub use height::*;
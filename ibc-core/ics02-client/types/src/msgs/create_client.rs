@@ -15,6 +15,11 @@ pub const CREATE_CLIENT_TYPE_URL: &str = "/ibc.core.client.v1.MsgCreateClient";
     feature = "borsh",
     derive(borsh::BorshSerialize, borsh::BorshDeserialize)
 )]
+#[cfg_attr(
+    feature = "proto-json",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "RawMsgCreateClient", into = "RawMsgCreateClient")
+)]
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct MsgCreateClient {
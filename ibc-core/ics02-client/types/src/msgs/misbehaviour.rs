@@ -23,6 +23,14 @@ pub const SUBMIT_MISBEHAVIOUR_TYPE_URL: &str = "/ibc.core.client.v1.MsgSubmitMis
     feature = "borsh",
     derive(borsh::BorshSerialize, borsh::BorshDeserialize)
 )]
+#[cfg_attr(
+    feature = "proto-json",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(
+        try_from = "RawMsgSubmitMisbehaviour",
+        into = "RawMsgSubmitMisbehaviour"
+    )
+)]
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct MsgSubmitMisbehaviour {
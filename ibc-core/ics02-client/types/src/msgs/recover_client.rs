@@ -0,0 +1,63 @@
+//! Definition of domain type message `MsgRecoverClient`.
+
+use ibc_core_host_types::identifiers::ClientId;
+use ibc_primitives::prelude::*;
+use ibc_primitives::Signer;
+use ibc_proto::ibc::core::client::v1::MsgRecoverClient as RawMsgRecoverClient;
+use ibc_proto::Protobuf;
+
+use crate::error::ClientError;
+
+pub const RECOVER_CLIENT_TYPE_URL: &str = "/ibc.core.client.v1.MsgRecoverClient";
+
+/// A type of message, submitted by the governance of the host, that recovers
+/// a frozen or expired client with the state of an active substitute client.
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(
+    feature = "proto-json",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "RawMsgRecoverClient", into = "RawMsgRecoverClient")
+)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MsgRecoverClient {
+    /// The client to be recovered
+    pub subject_client_id: ClientId,
+    /// The client whose state replaces the one of the subject client
+    pub substitute_client_id: ClientId,
+    /// The authority of the host
+    pub signer: Signer,
+}
+
+impl Protobuf<RawMsgRecoverClient> for MsgRecoverClient {}
+
+impl TryFrom<RawMsgRecoverClient> for MsgRecoverClient {
+    type Error = ClientError;
+
+    fn try_from(raw: RawMsgRecoverClient) -> Result<Self, Self::Error> {
+        Ok(MsgRecoverClient {
+            subject_client_id: raw
+                .subject_client_id
+                .parse()
+                .map_err(ClientError::InvalidClientIdentifier)?,
+            substitute_client_id: raw
+                .substitute_client_id
+                .parse()
+                .map_err(ClientError::InvalidClientIdentifier)?,
+            signer: raw.signer.into(),
+        })
+    }
+}
+
+impl From<MsgRecoverClient> for RawMsgRecoverClient {
+    fn from(ics_msg: MsgRecoverClient) -> Self {
+        RawMsgRecoverClient {
+            subject_client_id: ics_msg.subject_client_id.to_string(),
+            substitute_client_id: ics_msg.substitute_client_id.to_string(),
+            signer: ics_msg.signer.to_string(),
+        }
+    }
+}
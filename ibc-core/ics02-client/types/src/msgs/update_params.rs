@@ -0,0 +1,56 @@
+//! Definition of domain type message `MsgUpdateClientParams`.
+
+use ibc_primitives::prelude::*;
+use ibc_primitives::Signer;
+use ibc_proto::ibc::core::client::v1::MsgUpdateParams as RawMsgUpdateParams;
+use ibc_proto::Protobuf;
+
+use crate::error::ClientError;
+use crate::params::ClientParams;
+
+pub const UPDATE_CLIENT_PARAMS_TYPE_URL: &str = "/ibc.core.client.v1.MsgUpdateParams";
+
+/// A type of message, submitted by the governance of the host, that replaces
+/// the parameters of the client module.
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(
+    feature = "proto-json",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "RawMsgUpdateParams", into = "RawMsgUpdateParams")
+)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MsgUpdateClientParams {
+    pub params: ClientParams,
+    /// The authority of the host
+    pub signer: Signer,
+}
+
+impl Protobuf<RawMsgUpdateParams> for MsgUpdateClientParams {}
+
+impl TryFrom<RawMsgUpdateParams> for MsgUpdateClientParams {
+    type Error = ClientError;
+
+    fn try_from(raw: RawMsgUpdateParams) -> Result<Self, Self::Error> {
+        let params = raw.params.ok_or(ClientError::Other {
+            description: "missing client parameters".to_string(),
+        })?;
+
+        Ok(MsgUpdateClientParams {
+            params: params.into(),
+            signer: raw.signer.into(),
+        })
+    }
+}
+
+impl From<MsgUpdateClientParams> for RawMsgUpdateParams {
+    fn from(ics_msg: MsgUpdateClientParams) -> Self {
+        RawMsgUpdateParams {
+            signer: ics_msg.signer.to_string(),
+            params: Some(ics_msg.params.into()),
+        }
+    }
+}
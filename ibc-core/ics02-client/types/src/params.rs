@@ -0,0 +1,74 @@
+//! Defines the parameters of the client module, which the governance of the
+//! host updates.
+
+use ibc_core_host_types::identifiers::ClientType;
+use ibc_primitives::prelude::*;
+use ibc_proto::ibc::core::client::v1::Params as RawClientParams;
+
+/// The wildcard allowing all the client types, as in ibc-go.
+pub const ALLOW_ALL_CLIENTS: &str = "*";
+
+/// The configurable parameters of the client module.
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ClientParams {
+    /// The types of the clients which can be created, or
+    /// [`ALLOW_ALL_CLIENTS`] to allow all of them.
+    pub allowed_clients: Vec<String>,
+}
+
+impl ClientParams {
+    pub fn new(allowed_clients: Vec<String>) -> Self {
+        Self { allowed_clients }
+    }
+
+    /// Returns `true` if the clients of the given type can be created.
+    pub fn is_allowed(&self, client_type: &ClientType) -> bool {
+        self.allowed_clients
+            .iter()
+            .any(|allowed| allowed == ALLOW_ALL_CLIENTS || allowed == client_type.as_str())
+    }
+}
+
+impl Default for ClientParams {
+    fn default() -> Self {
+        Self::new(vec![ALLOW_ALL_CLIENTS.to_string()])
+    }
+}
+
+impl From<RawClientParams> for ClientParams {
+    fn from(raw: RawClientParams) -> Self {
+        Self::new(raw.allowed_clients)
+    }
+}
+
+impl From<ClientParams> for RawClientParams {
+    fn from(params: ClientParams) -> Self {
+        Self {
+            allowed_clients: params.allowed_clients,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_allowed_clients() {
+        let tendermint = ClientType::new("07-tendermint").unwrap();
+        let wasm = ClientType::new("08-wasm").unwrap();
+
+        assert!(ClientParams::default().is_allowed(&wasm));
+
+        let params = ClientParams::new(vec!["07-tendermint".to_string()]);
+
+        assert!(params.is_allowed(&tendermint));
+        assert!(!params.is_allowed(&wasm));
+        assert!(!ClientParams::new(vec![]).is_allowed(&tendermint));
+    }
+}
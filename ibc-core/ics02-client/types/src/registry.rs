@@ -0,0 +1,109 @@
+//! Defines the registry of the decoders of the `Any`-wrapped types, for
+//! decoding the client states, consensus states and client messages of the
+//! client types known at runtime only.
+
+use core::fmt;
+
+use ibc_primitives::prelude::*;
+use ibc_proto::google::protobuf::Any;
+use prost::Message;
+
+use crate::error::ClientError;
+
+/// Decodes an `Any` into the type `T` of a registry.
+pub type AnyDecoder<T> = fn(Any) -> Result<T, ClientError>;
+
+/// The registry of the decoders of the `Any`-wrapped types into `T`, e.g. an
+/// enum over the client states a host supports, keyed by their type URLs.
+///
+/// Hosts, relayers and the Wasm client may then decode the client states or
+/// client messages of the client types they are configured with, rather than
+/// only of those they are compiled with.
+#[derive(Clone)]
+pub struct AnyRegistry<T> {
+    decoders: BTreeMap<String, AnyDecoder<T>>,
+}
+
+impl<T> Default for AnyRegistry<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> fmt::Debug for AnyRegistry<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AnyRegistry")
+            .field("type_urls", &self.decoders.keys().collect::<Vec<_>>())
+            .finish()
+    }
+}
+
+impl<T> AnyRegistry<T> {
+    /// Creates an empty registry.
+    pub fn new() -> Self {
+        Self {
+            decoders: BTreeMap::new(),
+        }
+    }
+
+    /// Registers the decoder of the given type URL.
+    pub fn register(
+        &mut self,
+        type_url: impl Into<String>,
+        decoder: AnyDecoder<T>,
+    ) -> Result<(), ClientError> {
+        let type_url = type_url.into();
+
+        if self.decoders.contains_key(&type_url) {
+            return Err(ClientError::DuplicateTypeUrl { type_url });
+        }
+
+        self.decoders.insert(type_url, decoder);
+
+        Ok(())
+    }
+
+    /// Registers the domain type `D` under the given type URL, decoded
+    /// through its conversion from `Any` and converted into `T`.
+    pub fn register_type<D>(&mut self, type_url: impl Into<String>) -> Result<(), ClientError>
+    where
+        D: TryFrom<Any> + Into<T>,
+        <D as TryFrom<Any>>::Error: Into<ClientError>,
+    {
+        self.register(type_url, |any| {
+            D::try_from(any).map(Into::into).map_err(Into::into)
+        })
+    }
+
+    /// Returns whether a decoder is registered for the given type URL.
+    pub fn contains(&self, type_url: &str) -> bool {
+        self.decoders.contains_key(type_url)
+    }
+
+    /// Returns the registered type URLs, in lexicographic order.
+    pub fn type_urls(&self) -> impl Iterator<Item = &str> {
+        self.decoders.keys().map(String::as_str)
+    }
+
+    /// Decodes the given `Any` with the decoder of its type URL.
+    pub fn decode(&self, any: Any) -> Result<T, ClientError> {
+        let decoder =
+            self.decoders
+                .get(&any.type_url)
+                .ok_or_else(|| ClientError::UnregisteredTypeUrl {
+                    type_url: any.type_url.clone(),
+                })?;
+
+        decoder(any)
+    }
+
+    /// Decodes the given protobuf encoding of an `Any`, e.g. the data of a
+    /// Wasm client state, with the decoder of its type URL.
+    pub fn decode_bytes(&self, bytes: &[u8]) -> Result<T, ClientError> {
+        let any = Any::decode(bytes).map_err(|e| ClientError::Other {
+            description: e.to_string(),
+        })?;
+
+        self.decode(any)
+    }
+}
@@ -1,4 +1,7 @@
 use core::fmt::{Debug, Display, Formatter};
+use core::str::FromStr;
+
+use ibc_primitives::prelude::*;
 
 use crate::error::ClientError;
 
@@ -55,3 +58,19 @@ impl Display for Status {
         write!(f, "{self:?}")
     }
 }
+
+impl FromStr for Status {
+    type Err = ClientError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "Active" => Ok(Status::Active),
+            "Frozen" => Ok(Status::Frozen),
+            "Expired" => Ok(Status::Expired),
+            "Unauthorized" => Ok(Status::Unauthorized),
+            _ => Err(ClientError::Other {
+                description: format!("invalid client status: {s}"),
+            }),
+        }
+    }
+}
@@ -1,3 +1,5 @@
+use core::time::Duration;
+
 use ibc_core_client::context::ClientValidationContext;
 use ibc_core_client::types::Height;
 use ibc_core_connection_types::error::ConnectionError;
@@ -5,6 +7,25 @@ use ibc_core_connection_types::ConnectionEnd;
 use ibc_core_handler_types::error::ContextError;
 use ibc_core_host::ValidationContext;
 
+/// Returns the delay periods of `connection_end`: its time delay period, and
+/// the block delay period the host derives from it with
+/// [`ValidationContext::block_delay`].
+pub fn conn_delay_periods<Ctx>(ctx: &Ctx, connection_end: &ConnectionEnd) -> (Duration, u64)
+where
+    Ctx: ValidationContext,
+{
+    let conn_delay_time_period = connection_end.delay_period();
+
+    (
+        conn_delay_time_period,
+        ctx.block_delay(&conn_delay_time_period),
+    )
+}
+
+/// Verifies that both delay periods of `connection_end` have passed since the
+/// consensus state at `packet_proof_height` was stored: the time delay period
+/// according to the host timestamp, and the block delay period according to
+/// the host height, as ibc-go does.
 pub fn verify_conn_delay_passed<Ctx>(
     ctx: &Ctx,
     packet_proof_height: Height,
@@ -24,8 +45,8 @@ where
         .client_update_meta(client_id, &packet_proof_height)?;
 
     // Fetch the connection delay time and height periods.
-    let conn_delay_time_period = connection_end.delay_period();
-    let conn_delay_height_period = ctx.block_delay(&conn_delay_time_period);
+    let (conn_delay_time_period, conn_delay_height_period) =
+        conn_delay_periods(ctx, connection_end);
 
     // Verify that the current host chain time is later than the last client update time
     let earliest_valid_time = (last_client_update.0 + conn_delay_time_period)
@@ -9,11 +9,19 @@ use ibc_core_handler_types::error::ContextError;
 use ibc_core_handler_types::events::{IbcEvent, MessageEvent};
 use ibc_core_host::types::identifiers::ClientId;
 use ibc_core_host::types::path::{ClientConsensusStatePath, ClientStatePath, ConnectionPath, Path};
-use ibc_core_host::{ExecutionContext, ValidationContext};
+use ibc_core_host::{ExecutionContext, GasOperation, HandshakeStep, ProofKind, ValidationContext};
 use ibc_primitives::prelude::*;
-use ibc_primitives::proto::Protobuf;
+use ibc_primitives::CanonicalProtobuf;
 use ibc_primitives::ToVec;
 
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(
+        level = "debug",
+        skip_all,
+        fields(conn_id = %msg.conn_id_on_a),
+    )
+)]
 pub fn validate<Ctx>(ctx_a: &Ctx, msg: MsgConnectionOpenAck) -> Result<(), ContextError>
 where
     Ctx: ValidationContext,
@@ -89,25 +97,45 @@ where
                 vars.conn_end_on_a.delay_period(),
             )?;
 
-            client_state_of_b_on_a
-                .verify_membership(
+            ctx_a.gas_meter().charge(GasOperation::ProofVerification {
+                kind: ProofKind::Connection,
+                proof: &msg.proof_conn_end_on_b,
+            })?;
+            let path = Path::Connection(ConnectionPath::new(&msg.conn_id_on_b));
+            ctx_a
+                .metrics()
+                .observe_proof_verification(ProofKind::Connection, || {
+                    client_state_of_b_on_a.verify_membership_with_context(
+                        client_val_ctx_a,
+                        prefix_on_b,
+                        &msg.proof_conn_end_on_b,
+                        consensus_state_of_b_on_a.root(),
+                        path.clone(),
+                        expected_conn_end_on_b.encode_vec_canonical(),
+                    )
+                })
+                .map_err(|client_error| ConnectionError::VerifyConnectionState {
+                    path,
+                    client_error,
+                })?;
+        }
+
+        ctx_a.gas_meter().charge(GasOperation::ProofVerification {
+            kind: ProofKind::ClientState,
+            proof: &msg.proof_client_state_of_a_on_b,
+        })?;
+        ctx_a
+            .metrics()
+            .observe_proof_verification(ProofKind::ClientState, || {
+                client_state_of_b_on_a.verify_membership_with_context(
+                    client_val_ctx_a,
                     prefix_on_b,
-                    &msg.proof_conn_end_on_b,
+                    &msg.proof_client_state_of_a_on_b,
                     consensus_state_of_b_on_a.root(),
-                    Path::Connection(ConnectionPath::new(&msg.conn_id_on_b)),
-                    expected_conn_end_on_b.encode_vec(),
+                    Path::ClientState(ClientStatePath::new(vars.client_id_on_b().clone())),
+                    msg.client_state_of_a_on_b.to_vec(),
                 )
-                .map_err(ConnectionError::VerifyConnectionState)?;
-        }
-
-        client_state_of_b_on_a
-            .verify_membership(
-                prefix_on_b,
-                &msg.proof_client_state_of_a_on_b,
-                consensus_state_of_b_on_a.root(),
-                Path::ClientState(ClientStatePath::new(vars.client_id_on_b().clone())),
-                msg.client_state_of_a_on_b.to_vec(),
-            )
+            })
             .map_err(|e| ConnectionError::ClientStateVerificationFailure {
                 client_id: vars.client_id_on_b().clone(),
                 client_error: e,
@@ -122,14 +150,22 @@ where
             msg.consensus_height_of_a_on_b.revision_height(),
         );
 
-        client_state_of_b_on_a
-            .verify_membership(
-                prefix_on_b,
-                &msg.proof_consensus_state_of_a_on_b,
-                consensus_state_of_b_on_a.root(),
-                Path::ClientConsensusState(client_cons_state_path_on_b),
-                expected_consensus_state_of_a_on_b.encode_vec(),
-            )
+        ctx_a.gas_meter().charge(GasOperation::ProofVerification {
+            kind: ProofKind::ConsensusState,
+            proof: &msg.proof_consensus_state_of_a_on_b,
+        })?;
+        ctx_a
+            .metrics()
+            .observe_proof_verification(ProofKind::ConsensusState, || {
+                client_state_of_b_on_a.verify_membership_with_context(
+                    client_val_ctx_a,
+                    prefix_on_b,
+                    &msg.proof_consensus_state_of_a_on_b,
+                    consensus_state_of_b_on_a.root(),
+                    Path::ClientConsensusState(client_cons_state_path_on_b),
+                    expected_consensus_state_of_a_on_b.encode_vec(),
+                )
+            })
             .map_err(|e| ConnectionError::ConsensusStateVerificationFailure {
                 height: msg.proofs_height_on_b,
                 client_error: e,
@@ -139,6 +175,14 @@ where
     Ok(())
 }
 
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(
+        level = "debug",
+        skip_all,
+        fields(conn_id = %msg.conn_id_on_a),
+    )
+)]
 pub fn execute<Ctx>(ctx_a: &mut Ctx, msg: MsgConnectionOpenAck) -> Result<(), ContextError>
 where
     Ctx: ExecutionContext,
@@ -160,10 +204,15 @@ where
         vars.client_id_on_a().clone(),
         msg.conn_id_on_b.clone(),
         vars.client_id_on_b().clone(),
+        msg.version.clone(),
+        vars.conn_end_on_a.delay_period(),
     ));
     ctx_a.emit_ibc_event(IbcEvent::Message(MessageEvent::Connection))?;
     ctx_a.emit_ibc_event(event)?;
 
+    ctx_a
+        .metrics()
+        .connection_handshake_step(HandshakeStep::ConnectionOpenAck, &msg.conn_id_on_a);
     ctx_a.log_message("success: conn_open_ack verification passed".to_string())?;
 
     {
@@ -178,6 +227,7 @@ where
             new_conn_end_on_a
         };
 
+        ctx_a.gas_meter().charge(GasOperation::StoreWrite)?;
         ctx_a.store_connection(&ConnectionPath::new(&msg.conn_id_on_a), new_conn_end_on_a)?;
     }
 
@@ -9,10 +9,18 @@ use ibc_core_handler_types::error::ContextError;
 use ibc_core_handler_types::events::{IbcEvent, MessageEvent};
 use ibc_core_host::types::identifiers::{ClientId, ConnectionId};
 use ibc_core_host::types::path::{ClientConsensusStatePath, ConnectionPath, Path};
-use ibc_core_host::{ExecutionContext, ValidationContext};
+use ibc_core_host::{ExecutionContext, GasOperation, HandshakeStep, ProofKind, ValidationContext};
 use ibc_primitives::prelude::*;
-use ibc_primitives::proto::Protobuf;
-
+use ibc_primitives::CanonicalProtobuf;
+
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(
+        level = "debug",
+        skip_all,
+        fields(conn_id = %msg.conn_id_on_b),
+    )
+)]
 pub fn validate<Ctx>(ctx_b: &Ctx, msg: &MsgConnectionOpenConfirm) -> Result<(), ContextError>
 where
     Ctx: ValidationContext,
@@ -73,20 +81,40 @@ where
             conn_end_on_b.delay_period(),
         )?;
 
-        client_state_of_a_on_b
-            .verify_membership(
-                prefix_on_a,
-                &msg.proof_conn_end_on_a,
-                consensus_state_of_a_on_b.root(),
-                Path::Connection(ConnectionPath::new(conn_id_on_a)),
-                expected_conn_end_on_a.encode_vec(),
-            )
-            .map_err(ConnectionError::VerifyConnectionState)?;
+        ctx_b.gas_meter().charge(GasOperation::ProofVerification {
+            kind: ProofKind::Connection,
+            proof: &msg.proof_conn_end_on_a,
+        })?;
+        let path = Path::Connection(ConnectionPath::new(conn_id_on_a));
+        ctx_b
+            .metrics()
+            .observe_proof_verification(ProofKind::Connection, || {
+                client_state_of_a_on_b.verify_membership_with_context(
+                    client_val_ctx_b,
+                    prefix_on_a,
+                    &msg.proof_conn_end_on_a,
+                    consensus_state_of_a_on_b.root(),
+                    path.clone(),
+                    expected_conn_end_on_a.encode_vec_canonical(),
+                )
+            })
+            .map_err(|client_error| ConnectionError::VerifyConnectionState {
+                path,
+                client_error,
+            })?;
     }
 
     Ok(())
 }
 
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(
+        level = "debug",
+        skip_all,
+        fields(conn_id = %msg.conn_id_on_b),
+    )
+)]
 pub fn execute<Ctx>(ctx_b: &mut Ctx, msg: &MsgConnectionOpenConfirm) -> Result<(), ContextError>
 where
     Ctx: ExecutionContext,
@@ -112,9 +140,14 @@ where
         client_id_on_b.clone(),
         conn_id_on_a.clone(),
         client_id_on_a.clone(),
+        vars.conn_end_on_b.versions()[0].clone(),
+        vars.conn_end_on_b.delay_period(),
     ));
     ctx_b.emit_ibc_event(IbcEvent::Message(MessageEvent::Connection))?;
     ctx_b.emit_ibc_event(event)?;
+    ctx_b
+        .metrics()
+        .connection_handshake_step(HandshakeStep::ConnectionOpenConfirm, &msg.conn_id_on_b);
     ctx_b.log_message("success: conn_open_confirm verification passed".to_string())?;
 
     {
@@ -125,6 +158,7 @@ where
             new_conn_end_on_b
         };
 
+        ctx_b.gas_meter().charge(GasOperation::StoreWrite)?;
         ctx_b.store_connection(&ConnectionPath(msg.conn_id_on_b.clone()), new_conn_end_on_b)?;
     }
 
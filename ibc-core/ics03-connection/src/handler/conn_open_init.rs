@@ -1,5 +1,6 @@
 //! Protocol logic specific to ICS3 messages of type `MsgConnectionOpenInit`.
 use ibc_core_client::context::prelude::*;
+use ibc_core_connection_types::error::ConnectionError;
 use ibc_core_connection_types::events::OpenInit;
 use ibc_core_connection_types::msgs::MsgConnectionOpenInit;
 use ibc_core_connection_types::{ConnectionEnd, Counterparty, State};
@@ -7,14 +8,38 @@ use ibc_core_handler_types::error::ContextError;
 use ibc_core_handler_types::events::{IbcEvent, MessageEvent};
 use ibc_core_host::types::identifiers::ConnectionId;
 use ibc_core_host::types::path::{ClientConnectionPath, ConnectionPath};
-use ibc_core_host::{ExecutionContext, ValidationContext};
+use ibc_core_host::{ExecutionContext, GasOperation, HandshakeStep, ValidationContext};
 use ibc_primitives::prelude::*;
 
+use super::validate_counterparty_identifiers;
+
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(
+        level = "debug",
+        skip_all,
+        fields(client_id = %msg.client_id_on_a),
+    )
+)]
 pub fn validate<Ctx>(ctx_a: &Ctx, msg: MsgConnectionOpenInit) -> Result<(), ContextError>
 where
     Ctx: ValidationContext,
 {
     ctx_a.validate_message_signer(&msg.signer)?;
+    ctx_a.validate_connection_creator(&msg.signer, &msg.client_id_on_a)?;
+
+    // The localhost client is connected to the host through the sentinel
+    // localhost connection, which is never opened by a handshake.
+    if msg.client_id_on_a.is_localhost() {
+        return Err(ConnectionError::LocalhostHandshakeDisallowed {
+            connection_id: ConnectionId::localhost(),
+        }
+        .into());
+    }
+
+    // The identifiers of the counterparty should satisfy the identifier rules
+    // of the host.
+    validate_counterparty_identifiers(&ctx_a.identifier_rules(), &msg.counterparty)?;
 
     let client_val_ctx_a = ctx_a.get_client_validation_context();
 
@@ -26,21 +51,29 @@ where
         .verify_is_active()?;
 
     if let Some(version) = msg.version {
-        version.verify_is_supported(&ctx_a.get_compatible_versions())?;
+        version.verify_is_supported(&ctx_a.connection_params().compatible_versions)?;
     }
 
     Ok(())
 }
 
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(
+        level = "debug",
+        skip_all,
+        fields(client_id = %msg.client_id_on_a),
+    )
+)]
 pub fn execute<Ctx>(ctx_a: &mut Ctx, msg: MsgConnectionOpenInit) -> Result<(), ContextError>
 where
     Ctx: ExecutionContext,
 {
     let versions = if let Some(version) = msg.version {
-        version.verify_is_supported(&ctx_a.get_compatible_versions())?;
+        version.verify_is_supported(&ctx_a.connection_params().compatible_versions)?;
         vec![version]
     } else {
-        ctx_a.get_compatible_versions()
+        ctx_a.connection_params().compatible_versions
     };
 
     let conn_end_on_a = ConnectionEnd::new(
@@ -74,12 +107,19 @@ where
         ctx_a.emit_ibc_event(event)?;
     }
 
+    ctx_a.gas_meter().charge(GasOperation::StoreWrite)?;
     ctx_a.increase_connection_counter()?;
+    ctx_a.gas_meter().charge(GasOperation::StoreWrite)?;
     ctx_a.store_connection_to_client(
         &ClientConnectionPath::new(msg.client_id_on_a),
         conn_id_on_a.clone(),
     )?;
+    ctx_a.gas_meter().charge(GasOperation::StoreWrite)?;
     ctx_a.store_connection(&ConnectionPath::new(&conn_id_on_a), conn_end_on_a)?;
 
+    ctx_a
+        .metrics()
+        .connection_handshake_step(HandshakeStep::ConnectionOpenInit, &conn_id_on_a);
+
     Ok(())
 }
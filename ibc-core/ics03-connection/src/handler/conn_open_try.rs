@@ -10,11 +10,21 @@ use ibc_core_host::types::identifiers::{ClientId, ConnectionId};
 use ibc_core_host::types::path::{
     ClientConnectionPath, ClientConsensusStatePath, ClientStatePath, ConnectionPath, Path,
 };
-use ibc_core_host::{ExecutionContext, ValidationContext};
+use ibc_core_host::{ExecutionContext, GasOperation, HandshakeStep, ProofKind, ValidationContext};
 use ibc_primitives::prelude::*;
-use ibc_primitives::proto::Protobuf;
+use ibc_primitives::CanonicalProtobuf;
 use ibc_primitives::ToVec;
 
+use super::validate_counterparty_identifiers;
+
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(
+        level = "debug",
+        skip_all,
+        fields(client_id = %msg.client_id_on_b),
+    )
+)]
 pub fn validate<Ctx>(ctx_b: &Ctx, msg: MsgConnectionOpenTry) -> Result<(), ContextError>
 where
     Ctx: ValidationContext,
@@ -32,6 +42,14 @@ where
     Ctx: ValidationContext,
 {
     ctx_b.validate_message_signer(&msg.signer)?;
+    ctx_b.validate_connection_creator(&msg.signer, &msg.client_id_on_b)?;
+
+    if msg.client_id_on_b.is_localhost() {
+        return Err(ConnectionError::LocalhostHandshakeDisallowed {
+            connection_id: ConnectionId::localhost(),
+        }
+        .into());
+    }
 
     let client_val_ctx_b = ctx_b.get_client_validation_context();
 
@@ -52,6 +70,10 @@ where
         .into());
     }
 
+    // The identifiers of the counterparty should satisfy the identifier rules
+    // of the host.
+    validate_counterparty_identifiers(&ctx_b.identifier_rules(), &msg.counterparty)?;
+
     let client_id_on_a = msg.counterparty.client_id();
 
     // Verify proofs
@@ -85,25 +107,45 @@ where
                 msg.delay_period,
             )?;
 
-            client_state_of_a_on_b
-                .verify_membership(
+            ctx_b.gas_meter().charge(GasOperation::ProofVerification {
+                kind: ProofKind::Connection,
+                proof: &msg.proof_conn_end_on_a,
+            })?;
+            let path = Path::Connection(ConnectionPath::new(&vars.conn_id_on_a));
+            ctx_b
+                .metrics()
+                .observe_proof_verification(ProofKind::Connection, || {
+                    client_state_of_a_on_b.verify_membership_with_context(
+                        client_val_ctx_b,
+                        prefix_on_a,
+                        &msg.proof_conn_end_on_a,
+                        consensus_state_of_a_on_b.root(),
+                        path.clone(),
+                        expected_conn_end_on_a.encode_vec_canonical(),
+                    )
+                })
+                .map_err(|client_error| ConnectionError::VerifyConnectionState {
+                    path,
+                    client_error,
+                })?;
+        }
+
+        ctx_b.gas_meter().charge(GasOperation::ProofVerification {
+            kind: ProofKind::ClientState,
+            proof: &msg.proof_client_state_of_b_on_a,
+        })?;
+        ctx_b
+            .metrics()
+            .observe_proof_verification(ProofKind::ClientState, || {
+                client_state_of_a_on_b.verify_membership_with_context(
+                    client_val_ctx_b,
                     prefix_on_a,
-                    &msg.proof_conn_end_on_a,
+                    &msg.proof_client_state_of_b_on_a,
                     consensus_state_of_a_on_b.root(),
-                    Path::Connection(ConnectionPath::new(&vars.conn_id_on_a)),
-                    expected_conn_end_on_a.encode_vec(),
+                    Path::ClientState(ClientStatePath::new(client_id_on_a.clone())),
+                    msg.client_state_of_b_on_a.to_vec(),
                 )
-                .map_err(ConnectionError::VerifyConnectionState)?;
-        }
-
-        client_state_of_a_on_b
-            .verify_membership(
-                prefix_on_a,
-                &msg.proof_client_state_of_b_on_a,
-                consensus_state_of_a_on_b.root(),
-                Path::ClientState(ClientStatePath::new(client_id_on_a.clone())),
-                msg.client_state_of_b_on_a.to_vec(),
-            )
+            })
             .map_err(|e| ConnectionError::ClientStateVerificationFailure {
                 client_id: msg.client_id_on_b.clone(),
                 client_error: e,
@@ -118,14 +160,22 @@ where
             msg.consensus_height_of_b_on_a.revision_height(),
         );
 
-        client_state_of_a_on_b
-            .verify_membership(
-                prefix_on_a,
-                &msg.proof_consensus_state_of_b_on_a,
-                consensus_state_of_a_on_b.root(),
-                Path::ClientConsensusState(client_cons_state_path_on_a),
-                expected_consensus_state_of_b_on_a.encode_vec(),
-            )
+        ctx_b.gas_meter().charge(GasOperation::ProofVerification {
+            kind: ProofKind::ConsensusState,
+            proof: &msg.proof_consensus_state_of_b_on_a,
+        })?;
+        ctx_b
+            .metrics()
+            .observe_proof_verification(ProofKind::ConsensusState, || {
+                client_state_of_a_on_b.verify_membership_with_context(
+                    client_val_ctx_b,
+                    prefix_on_a,
+                    &msg.proof_consensus_state_of_b_on_a,
+                    consensus_state_of_a_on_b.root(),
+                    Path::ClientConsensusState(client_cons_state_path_on_a),
+                    expected_consensus_state_of_b_on_a.encode_vec(),
+                )
+            })
             .map_err(|e| ConnectionError::ConsensusStateVerificationFailure {
                 height: msg.proofs_height_on_a,
                 client_error: e,
@@ -135,6 +185,14 @@ where
     Ok(())
 }
 
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(
+        level = "debug",
+        skip_all,
+        fields(client_id = %msg.client_id_on_b),
+    )
+)]
 pub fn execute<Ctx>(ctx_b: &mut Ctx, msg: MsgConnectionOpenTry) -> Result<(), ContextError>
 where
     Ctx: ExecutionContext,
@@ -161,16 +219,24 @@ where
         msg.client_id_on_b.clone(),
         conn_id_on_a.clone(),
         vars.client_id_on_a.clone(),
+        vars.conn_end_on_b.versions()[0].clone(),
+        vars.conn_end_on_b.delay_period(),
     ));
     ctx_b.emit_ibc_event(IbcEvent::Message(MessageEvent::Connection))?;
     ctx_b.emit_ibc_event(event)?;
+    ctx_b
+        .metrics()
+        .connection_handshake_step(HandshakeStep::ConnectionOpenTry, &vars.conn_id_on_b);
     ctx_b.log_message("success: conn_open_try verification passed".to_string())?;
 
+    ctx_b.gas_meter().charge(GasOperation::StoreWrite)?;
     ctx_b.increase_connection_counter()?;
+    ctx_b.gas_meter().charge(GasOperation::StoreWrite)?;
     ctx_b.store_connection_to_client(
         &ClientConnectionPath::new(msg.client_id_on_b),
         vars.conn_id_on_b.clone(),
     )?;
+    ctx_b.gas_meter().charge(GasOperation::StoreWrite)?;
     ctx_b.store_connection(&ConnectionPath::new(&vars.conn_id_on_b), vars.conn_end_on_b)?;
 
     Ok(())
@@ -189,6 +255,7 @@ impl LocalVars {
         Ctx: ValidationContext,
     {
         let version_on_b = ctx_b.pick_version(&msg.versions_on_a)?;
+        version_on_b.verify_is_supported(&ctx_b.connection_params().compatible_versions)?;
 
         Ok(Self {
             conn_id_on_b: ConnectionId::new(ctx_b.connection_counter()?),
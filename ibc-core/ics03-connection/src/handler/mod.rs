@@ -1,4 +1,27 @@
+use ibc_core_connection_types::error::ConnectionError;
+use ibc_core_connection_types::Counterparty;
+use ibc_core_host::types::validate::IdentifierRules;
+
 pub mod conn_open_ack;
 pub mod conn_open_confirm;
 pub mod conn_open_init;
 pub mod conn_open_try;
+
+/// Validates the identifiers of the counterparty of a connection against the
+/// identifier rules of the host.
+pub(crate) fn validate_counterparty_identifiers(
+    identifier_rules: &IdentifierRules,
+    counterparty: &Counterparty,
+) -> Result<(), ConnectionError> {
+    identifier_rules
+        .validate_client_identifier(counterparty.client_id().as_str())
+        .map_err(ConnectionError::InvalidIdentifier)?;
+
+    if let Some(connection_id) = counterparty.connection_id() {
+        identifier_rules
+            .validate_connection_identifier(connection_id.as_str())
+            .map_err(ConnectionError::InvalidIdentifier)?;
+    }
+
+    Ok(())
+}
@@ -4,8 +4,9 @@ use displaydoc::Display;
 use ibc_core_client_types::{error as client_error, Height};
 use ibc_core_host_types::error::IdentifierError;
 use ibc_core_host_types::identifiers::{ClientId, ConnectionId};
+use ibc_core_host_types::path::Path;
 use ibc_primitives::prelude::*;
-use ibc_primitives::{Timestamp, TimestampOverflowError};
+use ibc_primitives::{Signer, Timestamp, TimestampOverflowError};
 
 use crate::version::Version;
 
@@ -44,8 +45,11 @@ pub enum ConnectionError {
     MissingConsensusHeight,
     /// invalid connection proof error
     InvalidProof,
-    /// verifying connection state error: `{0}`
-    VerifyConnectionState(client_error::ClientError),
+    /// failed to verify the proof of `{path}`: `{client_error}`
+    VerifyConnectionState {
+        path: Path,
+        client_error: client_error::ClientError,
+    },
     /// invalid signer error: `{reason}`
     InvalidSigner { reason: String },
     /// no connection was found for the previous connection id provided `{connection_id}`
@@ -83,16 +87,67 @@ pub enum ConnectionError {
     TimestampOverflow(TimestampOverflowError),
     /// connection counter overflow error
     CounterOverflow,
+    /// localhost connection handshakes are disallowed, the localhost connection `{connection_id}` already exists on the host
+    LocalhostHandshakeDisallowed { connection_id: ConnectionId },
+    /// `{signer}` is not allowed to create connections on client `{client_id}`
+    UnauthorizedConnectionCreation { signer: Signer, client_id: ClientId },
     /// other error: `{description}`
     Other { description: String },
+    /// the maximum expected time per block must not be zero
+    ZeroMaxExpectedTimePerBlock,
 }
 
-#[cfg(feature = "std")]
-impl std::error::Error for ConnectionError {
-    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+/// The codespace of the [`ConnectionError`]s in ABCI responses.
+pub const CONNECTION_CODESPACE: &str = "connection";
+
+impl ConnectionError {
+    /// Returns the code identifying the variant of the error within the
+    /// [`CONNECTION_CODESPACE`], which is kept across releases.
+    pub fn code(&self) -> u32 {
+        match self {
+            Self::Client(_) => 2,
+            Self::InvalidState { .. } => 3,
+            Self::InvalidConsensusHeight { .. } => 4,
+            Self::InvalidIdentifier(_) => 5,
+            Self::EmptyProtoConnectionEnd => 6,
+            Self::EmptyVersions => 7,
+            Self::InvalidVersionLength => 8,
+            Self::VersionNotSupported { .. } => 9,
+            Self::NoCommonVersion => 10,
+            Self::EmptyFeatures => 11,
+            Self::FeatureNotSupported { .. } => 12,
+            Self::NoCommonFeatures => 13,
+            Self::MissingProofHeight => 14,
+            Self::MissingConsensusHeight => 15,
+            Self::InvalidProof => 16,
+            Self::VerifyConnectionState { .. } => 17,
+            Self::InvalidSigner { .. } => 18,
+            Self::ConnectionNotFound { .. } => 19,
+            Self::InvalidCounterparty => 20,
+            Self::MissingCounterparty => 21,
+            Self::MissingClientState => 22,
+            Self::ConsensusStateVerificationFailure { .. } => 23,
+            Self::ClientStateVerificationFailure { .. } => 24,
+            Self::InvalidClientState { .. } => 25,
+            Self::NotEnoughBlocksElapsed { .. } => 26,
+            Self::NotEnoughTimeElapsed { .. } => 27,
+            Self::TimestampOverflow(_) => 28,
+            Self::CounterOverflow => 29,
+            Self::LocalhostHandshakeDisallowed { .. } => 30,
+            Self::UnauthorizedConnectionCreation { .. } => 31,
+            Self::Other { .. } => 32,
+            Self::ZeroMaxExpectedTimePerBlock => 33,
+        }
+    }
+}
+
+impl core::error::Error for ConnectionError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
         match &self {
             Self::Client(e)
-            | Self::VerifyConnectionState(e)
+            | Self::VerifyConnectionState {
+                client_error: e, ..
+            }
             | Self::ConsensusStateVerificationFailure {
                 client_error: e, ..
             }
@@ -1,9 +1,13 @@
 //! Types for the IBC events emitted from Tendermint Websocket by the connection module.
 
+use core::time::Duration;
+
 use ibc_core_host_types::identifiers::{ClientId, ConnectionId};
 use ibc_primitives::prelude::*;
 use tendermint::abci;
 
+use crate::version::Version;
+
 /// Connection event types
 const CONNECTION_OPEN_INIT_EVENT: &str = "connection_open_init";
 const CONNECTION_OPEN_TRY_EVENT: &str = "connection_open_try";
@@ -15,6 +19,9 @@ pub const CONN_ID_ATTRIBUTE_KEY: &str = "connection_id";
 pub const CLIENT_ID_ATTRIBUTE_KEY: &str = "client_id";
 pub const COUNTERPARTY_CONN_ID_ATTRIBUTE_KEY: &str = "counterparty_connection_id";
 pub const COUNTERPARTY_CLIENT_ID_ATTRIBUTE_KEY: &str = "counterparty_client_id";
+pub const VERSION_IDENTIFIER_ATTRIBUTE_KEY: &str = "version_identifier";
+pub const VERSION_FEATURES_ATTRIBUTE_KEY: &str = "version_features";
+pub const DELAY_PERIOD_ATTRIBUTE_KEY: &str = "delay_period";
 
 #[cfg_attr(
     feature = "parity-scale-codec",
@@ -29,6 +36,7 @@ pub const COUNTERPARTY_CLIENT_ID_ATTRIBUTE_KEY: &str = "counterparty_client_id";
     derive(borsh::BorshSerialize, borsh::BorshDeserialize)
 )]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
 struct Attributes {
     pub connection_id: ConnectionId,
@@ -67,6 +75,76 @@ impl From<Attributes> for Vec<abci::EventAttribute> {
     }
 }
 
+/// The outcome of the handshake negotiation so far, included in the events
+/// emitted from `ConnOpenTry` onwards so that the state of a connection can
+/// be reconstructed from its events alone.
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+struct HandshakeAttributes {
+    pub version: Version,
+    pub delay_period_nanos: u64,
+}
+
+impl HandshakeAttributes {
+    /// Creates the attributes, with the delay periods beyond the `u64`
+    /// nanoseconds saturating at `u64::MAX`.
+    fn new(version: Version, delay_period: Duration) -> Self {
+        Self {
+            version,
+            delay_period_nanos: u64::try_from(delay_period.as_nanos()).unwrap_or(u64::MAX),
+        }
+    }
+
+    /// The delay period, from its nanoseconds.
+    fn delay_period(&self) -> Duration {
+        Duration::from_nanos(self.delay_period_nanos)
+    }
+}
+
+/// Convert handshake attributes to Tendermint ABCI tags, with the version
+/// features comma-separated and the delay period in nanoseconds.
+impl From<HandshakeAttributes> for Vec<abci::EventAttribute> {
+    fn from(a: HandshakeAttributes) -> Self {
+        let version_identifier = (
+            VERSION_IDENTIFIER_ATTRIBUTE_KEY,
+            a.version.identifier().to_string(),
+        )
+            .into();
+        let version_features = (
+            VERSION_FEATURES_ATTRIBUTE_KEY,
+            a.version.features().join(","),
+        )
+            .into();
+        let delay_period = (DELAY_PERIOD_ATTRIBUTE_KEY, a.delay_period_nanos.to_string()).into();
+
+        vec![version_identifier, version_features, delay_period]
+    }
+}
+
+/// Returns the ABCI attributes of an event from its shared and handshake
+/// attributes.
+fn handshake_event_attributes(
+    attributes: Attributes,
+    handshake: HandshakeAttributes,
+) -> Vec<abci::EventAttribute> {
+    let mut event_attributes: Vec<abci::EventAttribute> = attributes.into();
+    event_attributes.extend(Vec::<abci::EventAttribute>::from(handshake));
+    event_attributes
+}
+
 #[cfg_attr(
     feature = "parity-scale-codec",
     derive(
@@ -80,6 +158,7 @@ impl From<Attributes> for Vec<abci::EventAttribute> {
     derive(borsh::BorshSerialize, borsh::BorshDeserialize)
 )]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct OpenInit(Attributes);
 
@@ -138,8 +217,9 @@ impl From<OpenInit> for abci::Event {
     derive(borsh::BorshSerialize, borsh::BorshDeserialize)
 )]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[derive(Clone, Debug, PartialEq, Eq)]
-pub struct OpenTry(Attributes);
+pub struct OpenTry(Attributes, HandshakeAttributes);
 
 impl OpenTry {
     /// Per our convention, this event is generated on chain B.
@@ -148,13 +228,18 @@ impl OpenTry {
         client_id_on_b: ClientId,
         conn_id_on_a: ConnectionId,
         client_id_on_a: ClientId,
+        version: Version,
+        delay_period: Duration,
     ) -> Self {
-        Self(Attributes {
-            connection_id: conn_id_on_b,
-            client_id: client_id_on_b,
-            counterparty_connection_id: Some(conn_id_on_a),
-            counterparty_client_id: client_id_on_a,
-        })
+        Self(
+            Attributes {
+                connection_id: conn_id_on_b,
+                client_id: client_id_on_b,
+                counterparty_connection_id: Some(conn_id_on_a),
+                counterparty_client_id: client_id_on_a,
+            },
+            HandshakeAttributes::new(version, delay_period),
+        )
     }
 
     pub fn conn_id_on_b(&self) -> &ConnectionId {
@@ -170,6 +255,16 @@ impl OpenTry {
         &self.0.counterparty_client_id
     }
 
+    /// The version negotiated so far.
+    pub fn version(&self) -> &Version {
+        &self.1.version
+    }
+
+    /// The delay period of the connection.
+    pub fn delay_period(&self) -> Duration {
+        self.1.delay_period()
+    }
+
     pub fn event_type(&self) -> &str {
         CONNECTION_OPEN_TRY_EVENT
     }
@@ -179,7 +274,7 @@ impl From<OpenTry> for abci::Event {
     fn from(v: OpenTry) -> Self {
         abci::Event {
             kind: CONNECTION_OPEN_TRY_EVENT.to_string(),
-            attributes: v.0.into(),
+            attributes: handshake_event_attributes(v.0, v.1),
         }
     }
 }
@@ -197,8 +292,9 @@ impl From<OpenTry> for abci::Event {
     derive(borsh::BorshSerialize, borsh::BorshDeserialize)
 )]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[derive(Clone, Debug, PartialEq, Eq)]
-pub struct OpenAck(Attributes);
+pub struct OpenAck(Attributes, HandshakeAttributes);
 
 impl OpenAck {
     /// Per our convention, this event is generated on chain A.
@@ -207,13 +303,18 @@ impl OpenAck {
         client_id_on_a: ClientId,
         conn_id_on_b: ConnectionId,
         client_id_on_b: ClientId,
+        version: Version,
+        delay_period: Duration,
     ) -> Self {
-        Self(Attributes {
-            connection_id: conn_id_on_a,
-            client_id: client_id_on_a,
-            counterparty_connection_id: Some(conn_id_on_b),
-            counterparty_client_id: client_id_on_b,
-        })
+        Self(
+            Attributes {
+                connection_id: conn_id_on_a,
+                client_id: client_id_on_a,
+                counterparty_connection_id: Some(conn_id_on_b),
+                counterparty_client_id: client_id_on_b,
+            },
+            HandshakeAttributes::new(version, delay_period),
+        )
     }
 
     pub fn conn_id_on_a(&self) -> &ConnectionId {
@@ -229,6 +330,16 @@ impl OpenAck {
         &self.0.counterparty_client_id
     }
 
+    /// The version negotiated so far.
+    pub fn version(&self) -> &Version {
+        &self.1.version
+    }
+
+    /// The delay period of the connection.
+    pub fn delay_period(&self) -> Duration {
+        self.1.delay_period()
+    }
+
     pub fn event_type(&self) -> &str {
         CONNECTION_OPEN_ACK_EVENT
     }
@@ -238,7 +349,7 @@ impl From<OpenAck> for abci::Event {
     fn from(v: OpenAck) -> Self {
         abci::Event {
             kind: CONNECTION_OPEN_ACK_EVENT.to_string(),
-            attributes: v.0.into(),
+            attributes: handshake_event_attributes(v.0, v.1),
         }
     }
 }
@@ -256,8 +367,9 @@ impl From<OpenAck> for abci::Event {
     derive(borsh::BorshSerialize, borsh::BorshDeserialize)
 )]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[derive(Clone, Debug, PartialEq, Eq)]
-pub struct OpenConfirm(Attributes);
+pub struct OpenConfirm(Attributes, HandshakeAttributes);
 
 impl OpenConfirm {
     /// Per our convention, this event is generated on chain B.
@@ -266,13 +378,18 @@ impl OpenConfirm {
         client_id_on_b: ClientId,
         conn_id_on_a: ConnectionId,
         client_id_on_a: ClientId,
+        version: Version,
+        delay_period: Duration,
     ) -> Self {
-        Self(Attributes {
-            connection_id: conn_id_on_b,
-            client_id: client_id_on_b,
-            counterparty_connection_id: Some(conn_id_on_a),
-            counterparty_client_id: client_id_on_a,
-        })
+        Self(
+            Attributes {
+                connection_id: conn_id_on_b,
+                client_id: client_id_on_b,
+                counterparty_connection_id: Some(conn_id_on_a),
+                counterparty_client_id: client_id_on_a,
+            },
+            HandshakeAttributes::new(version, delay_period),
+        )
     }
 
     pub fn conn_id_on_b(&self) -> &ConnectionId {
@@ -288,6 +405,16 @@ impl OpenConfirm {
         &self.0.counterparty_client_id
     }
 
+    /// The version negotiated so far.
+    pub fn version(&self) -> &Version {
+        &self.1.version
+    }
+
+    /// The delay period of the connection.
+    pub fn delay_period(&self) -> Duration {
+        self.1.delay_period()
+    }
+
     pub fn event_type(&self) -> &str {
         CONNECTION_OPEN_CONFIRM_EVENT
     }
@@ -297,7 +424,7 @@ impl From<OpenConfirm> for abci::Event {
     fn from(v: OpenConfirm) -> Self {
         abci::Event {
             kind: CONNECTION_OPEN_CONFIRM_EVENT.to_string(),
-            attributes: v.0.into(),
+            attributes: handshake_event_attributes(v.0, v.1),
         }
     }
 }
@@ -339,6 +466,14 @@ mod tests {
             "07-tendermint-1",
             "connection-1",
         ];
+        let version = Version::compatibles().remove(0);
+        let delay_period = Duration::from_secs(10);
+        let handshake_keys = ["version_identifier", "version_features", "delay_period"];
+        let handshake_values = ["1", "ORDER_ORDERED,ORDER_UNORDERED", "10000000000"];
+        let with_handshake = |mut entries: Vec<&'static str>, extra: [&'static str; 3]| {
+            entries.extend(extra);
+            entries
+        };
 
         let tests: Vec<Test> = vec![
             Test {
@@ -363,10 +498,15 @@ mod tests {
                     client_id_on_b.clone(),
                     conn_id_on_a.clone(),
                     client_id_on_a.clone(),
+                    version.clone(),
+                    delay_period,
                 )
                 .into(),
-                expected_keys: expected_keys.clone(),
-                expected_values: expected_values.iter().rev().copied().collect(),
+                expected_keys: with_handshake(expected_keys.clone(), handshake_keys),
+                expected_values: with_handshake(
+                    expected_values.iter().rev().copied().collect(),
+                    handshake_values,
+                ),
             },
             Test {
                 kind: CONNECTION_OPEN_ACK_EVENT,
@@ -375,17 +515,29 @@ mod tests {
                     client_id_on_a.clone(),
                     conn_id_on_b.clone(),
                     client_id_on_b.clone(),
+                    version.clone(),
+                    delay_period,
                 )
                 .into(),
-                expected_keys: expected_keys.clone(),
-                expected_values: expected_values.clone(),
+                expected_keys: with_handshake(expected_keys.clone(), handshake_keys),
+                expected_values: with_handshake(expected_values.clone(), handshake_values),
             },
             Test {
                 kind: CONNECTION_OPEN_CONFIRM_EVENT,
-                event: OpenConfirm::new(conn_id_on_b, client_id_on_b, conn_id_on_a, client_id_on_a)
-                    .into(),
-                expected_keys: expected_keys.clone(),
-                expected_values: expected_values.iter().rev().copied().collect(),
+                event: OpenConfirm::new(
+                    conn_id_on_b,
+                    client_id_on_b,
+                    conn_id_on_a,
+                    client_id_on_a,
+                    version,
+                    delay_period,
+                )
+                .into(),
+                expected_keys: with_handshake(expected_keys.clone(), handshake_keys),
+                expected_values: with_handshake(
+                    expected_values.iter().rev().copied().collect(),
+                    handshake_values,
+                ),
             },
         ];
 
@@ -404,4 +556,11 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn delay_period_beyond_u64_nanos_saturates() {
+        let handshake = HandshakeAttributes::new(Version::compatibles().remove(0), Duration::MAX);
+
+        assert_eq!(handshake.delay_period_nanos, u64::MAX);
+    }
 }
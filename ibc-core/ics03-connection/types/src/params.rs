@@ -0,0 +1,126 @@
+//! Defines the parameters of the connection module
+
+use core::time::Duration;
+
+use ibc_primitives::prelude::*;
+
+use crate::error::ConnectionError;
+use crate::version::Version;
+
+/// The default maximum expected time per block, as in ibc-go.
+pub const DEFAULT_MAX_EXPECTED_TIME_PER_BLOCK: Duration = Duration::from_secs(30);
+
+/// The configurable parameters of the connection module.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ConnectionParams {
+    /// The maximum expected time per block of the host, from which the
+    /// block delay of connections is derived.
+    pub max_expected_time_per_block: Duration,
+    /// The versions the host supports in connection handshakes, in order of
+    /// preference.
+    pub compatible_versions: Vec<Version>,
+}
+
+impl ConnectionParams {
+    pub fn new(max_expected_time_per_block: Duration, compatible_versions: Vec<Version>) -> Self {
+        Self {
+            max_expected_time_per_block,
+            compatible_versions,
+        }
+    }
+
+    /// Checks that the parameters are valid, as ibc-go does before storing
+    /// them. Hosts keeping the parameters in their store validate them
+    /// before they are updated.
+    pub fn validate(&self) -> Result<(), ConnectionError> {
+        if self.max_expected_time_per_block.is_zero() {
+            return Err(ConnectionError::ZeroMaxExpectedTimePerBlock);
+        }
+
+        if self.compatible_versions.is_empty() {
+            return Err(ConnectionError::EmptyVersions);
+        }
+
+        Ok(())
+    }
+}
+
+impl Default for ConnectionParams {
+    fn default() -> Self {
+        Self::new(DEFAULT_MAX_EXPECTED_TIME_PER_BLOCK, Version::compatibles())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::time::Duration;
+
+    use ibc_primitives::prelude::*;
+
+    use crate::error::ConnectionError;
+    use crate::params::{ConnectionParams, DEFAULT_MAX_EXPECTED_TIME_PER_BLOCK};
+    use crate::version::Version;
+
+    #[test]
+    fn default_params() {
+        let params = ConnectionParams::default();
+
+        assert_eq!(params.max_expected_time_per_block, Duration::from_secs(30));
+        assert_eq!(params.compatible_versions, Version::compatibles());
+        assert!(params.validate().is_ok());
+    }
+
+    #[test]
+    fn validate_params() {
+        struct Test {
+            name: String,
+            params: ConnectionParams,
+            want_pass: bool,
+        }
+
+        let tests: Vec<Test> = vec![
+            Test {
+                name: "Custom maximum expected time per block".to_string(),
+                params: ConnectionParams::new(Duration::from_secs(5), Version::compatibles()),
+                want_pass: true,
+            },
+            Test {
+                name: "Zero maximum expected time per block".to_string(),
+                params: ConnectionParams::new(Duration::ZERO, Version::compatibles()),
+                want_pass: false,
+            },
+            Test {
+                name: "No compatible versions".to_string(),
+                params: ConnectionParams::new(DEFAULT_MAX_EXPECTED_TIME_PER_BLOCK, vec![]),
+                want_pass: false,
+            },
+        ];
+
+        for test in tests {
+            let res = test.params.validate();
+
+            assert_eq!(
+                test.want_pass,
+                res.is_ok(),
+                "ConnectionParams::validate() failed for test {}, with result {:?}",
+                test.name,
+                res
+            );
+        }
+    }
+
+    #[test]
+    fn validate_params_errors() {
+        let res = ConnectionParams::new(Duration::ZERO, vec![]).validate();
+
+        // The maximum expected time per block is checked first.
+        assert!(matches!(
+            res,
+            Err(ConnectionError::ZeroMaxExpectedTimePerBlock)
+        ));
+
+        let res = ConnectionParams::new(DEFAULT_MAX_EXPECTED_TIME_PER_BLOCK, vec![]).validate();
+
+        assert!(matches!(res, Err(ConnectionError::EmptyVersions)));
+    }
+}
@@ -33,6 +33,30 @@ pub struct Version {
 }
 
 impl Version {
+    /// Creates a new version from its identifier and the features it
+    /// supports, which must all be non-empty.
+    ///
+    /// Hosts negotiating custom connection features construct their
+    /// supported versions with this, and return them from
+    /// `ValidationContext::get_compatible_versions`.
+    pub fn new(identifier: String, features: Vec<String>) -> Result<Self, ConnectionError> {
+        RawVersion {
+            identifier,
+            features,
+        }
+        .try_into()
+    }
+
+    /// Returns the identifier of the version.
+    pub fn identifier(&self) -> &str {
+        &self.identifier
+    }
+
+    /// Returns the features supported by the version.
+    pub fn features(&self) -> &[String] {
+        &self.features
+    }
+
     /// Checks whether the version has a matching version identifier and its
     /// feature set is a subset of the supported features
     pub fn verify_is_supported(
@@ -121,17 +145,7 @@ pub fn pick_version(
     supported_versions: &[Version],
     counterparty_versions: &[Version],
 ) -> Result<Version, ConnectionError> {
-    let mut intersection: Vec<Version> = Vec::new();
-    for sv in supported_versions.iter() {
-        if let Ok(cv) = find_supported_version(sv, counterparty_versions) {
-            if let Ok(feature_set) = get_feature_set_intersection(&sv.features, &cv.features) {
-                intersection.push(Version {
-                    identifier: cv.identifier,
-                    features: feature_set,
-                })
-            }
-        }
-    }
+    let mut intersection = common_versions(supported_versions, counterparty_versions);
 
     if intersection.is_empty() {
         return Err(ConnectionError::NoCommonVersion);
@@ -141,6 +155,31 @@ pub fn pick_version(
     Ok(intersection[0].clone())
 }
 
+/// Returns the versions supported by both the host and the counterparty, in
+/// the order of `supported_versions`, each with the intersection of the
+/// features supported by both sides. Versions without any common feature are
+/// left out.
+///
+/// This is the set of candidates [`pick_version`] chooses from, exposed for
+/// hosts implementing their own selection strategy by overriding
+/// `ValidationContext::pick_version`.
+pub fn common_versions(
+    supported_versions: &[Version],
+    counterparty_versions: &[Version],
+) -> Vec<Version> {
+    supported_versions
+        .iter()
+        .filter_map(|sv| {
+            let cv = find_supported_version(sv, counterparty_versions).ok()?;
+            let features = get_feature_set_intersection(&sv.features, &cv.features).ok()?;
+            Some(Version {
+                identifier: cv.identifier,
+                features,
+            })
+        })
+        .collect()
+}
+
 /// Returns the version from the list of supported versions that matches the
 /// given reference version.
 fn find_supported_version(
@@ -183,7 +222,7 @@ mod tests {
     use ibc_proto::ibc::core::connection::v1::Version as RawVersion;
 
     use crate::error::ConnectionError;
-    use crate::version::{pick_version, Version};
+    use crate::version::{common_versions, pick_version, Version};
 
     fn get_dummy_features() -> Vec<String> {
         vec!["ORDER_RANDOM".to_string(), "ORDER_UNORDERED".to_string()]
@@ -388,6 +427,53 @@ mod tests {
             }
         }
     }
+    #[test]
+    fn new_version() {
+        let version = Version::new(
+            "2".to_string(),
+            vec!["ORDER_UNORDERED".to_string(), "CUSTOM_FEATURE".to_string()],
+        )
+        .unwrap();
+        assert_eq!(version.identifier(), "2");
+        assert_eq!(version.features().len(), 2);
+
+        assert!(Version::new("".to_string(), get_dummy_features()).is_err());
+        assert!(Version::new("2".to_string(), vec!["".to_string()]).is_err());
+    }
+
+    #[test]
+    fn common() {
+        let (supported, counterparty, _) = overlapping();
+        let common = common_versions(&supported, &counterparty);
+
+        // in the host's order, with the common features only
+        assert_eq!(
+            common
+                .iter()
+                .map(|version| version.identifier())
+                .collect::<Vec<_>>(),
+            vec!["3", "4"]
+        );
+
+        let supported = vec![Version::new(
+            "1".to_string(),
+            vec!["ORDER_ORDERED".to_string(), "ORDER_UNORDERED".to_string()],
+        )
+        .unwrap()];
+        let counterparty = vec![Version::new(
+            "1".to_string(),
+            vec!["ORDER_UNORDERED".to_string(), "CUSTOM_FEATURE".to_string()],
+        )
+        .unwrap()];
+        assert_eq!(
+            common_versions(&supported, &counterparty)[0].features(),
+            ["ORDER_UNORDERED".to_string()]
+        );
+
+        let (supported, counterparty) = disjoint();
+        assert!(common_versions(&supported, &counterparty).is_empty());
+    }
+
     #[test]
     fn serialize() {
         let def = Version {
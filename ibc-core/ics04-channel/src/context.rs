@@ -1,14 +1,21 @@
 //! ICS4 (channel) context.
 
-use ibc_core_channel_types::channel::ChannelEnd;
-use ibc_core_channel_types::commitment::PacketCommitment;
+use ibc_core_channel_types::channel::{ChannelEnd, Order};
+use ibc_core_channel_types::commitment::{AcknowledgementCommitment, PacketCommitment};
+use ibc_core_channel_types::error::ChannelError;
+use ibc_core_channel_types::events::PacketDataEventMode;
+use ibc_core_channel_types::packet::{Receipt, SequenceRange};
 use ibc_core_client::context::prelude::*;
 use ibc_core_connection::types::ConnectionEnd;
 use ibc_core_handler_types::error::ContextError;
 use ibc_core_handler_types::events::IbcEvent;
 use ibc_core_host::types::identifiers::{ConnectionId, Sequence};
-use ibc_core_host::types::path::{ChannelEndPath, CommitmentPath, SeqSendPath};
-use ibc_core_host::{ExecutionContext, ValidationContext};
+use ibc_core_host::types::path::{
+    AckPath, ChannelEndPath, CommitmentPath, ReceiptPath, SeqRecvPath, SeqSendPath,
+};
+use ibc_core_host::{
+    ExecutionContext, GasMeter, Metrics, NoopGasMeter, NoopMetrics, ValidationContext,
+};
 use ibc_primitives::prelude::*;
 
 /// Methods required in send packet validation, to be implemented by the host
@@ -26,6 +33,27 @@ pub trait SendPacketValidationContext {
 
     fn get_next_sequence_send(&self, seq_send_path: &SeqSendPath)
         -> Result<Sequence, ContextError>;
+
+    /// Returns the maximum size, in bytes, of the data of the packets sent,
+    /// if any.
+    fn max_packet_data_size(&self) -> Option<usize> {
+        None
+    }
+
+    /// Returns how the packet data is carried by the `SendPacket` events.
+    fn packet_data_event_mode(&self) -> PacketDataEventMode {
+        PacketDataEventMode::Full
+    }
+
+    /// Returns the [`Metrics`] hooks called when the packet is sent.
+    fn metrics(&self) -> &dyn Metrics {
+        &NoopMetrics
+    }
+
+    /// Returns the [`GasMeter`] charged when the packet is sent.
+    fn gas_meter(&self) -> &dyn GasMeter {
+        &NoopGasMeter
+    }
 }
 
 impl<T> SendPacketValidationContext for T
@@ -52,6 +80,22 @@ where
     ) -> Result<Sequence, ContextError> {
         self.get_next_sequence_send(seq_send_path)
     }
+
+    fn max_packet_data_size(&self) -> Option<usize> {
+        self.max_packet_data_size()
+    }
+
+    fn packet_data_event_mode(&self) -> PacketDataEventMode {
+        self.packet_data_event_mode()
+    }
+
+    fn metrics(&self) -> &dyn Metrics {
+        self.metrics()
+    }
+
+    fn gas_meter(&self) -> &dyn GasMeter {
+        self.gas_meter()
+    }
 }
 
 /// Methods required in send packet execution, to be implemented by the host
@@ -103,3 +147,382 @@ where
         self.log_message(message)
     }
 }
+
+/// Methods required in write acknowledgement validation, to be implemented by
+/// the host
+pub trait WriteAcknowledgementValidationContext {
+    /// Returns the ChannelEnd for the given `port_id` and `chan_id`.
+    fn channel_end(&self, channel_end_path: &ChannelEndPath) -> Result<ChannelEnd, ContextError>;
+
+    fn get_next_sequence_recv(&self, seq_recv_path: &SeqRecvPath)
+        -> Result<Sequence, ContextError>;
+
+    fn get_packet_receipt(&self, receipt_path: &ReceiptPath) -> Result<Receipt, ContextError>;
+
+    fn get_packet_acknowledgement(
+        &self,
+        ack_path: &AckPath,
+    ) -> Result<AcknowledgementCommitment, ContextError>;
+
+    /// Returns the [`GasMeter`] charged when the acknowledgement is written.
+    fn gas_meter(&self) -> &dyn GasMeter {
+        &NoopGasMeter
+    }
+}
+
+impl<T> WriteAcknowledgementValidationContext for T
+where
+    T: ValidationContext,
+{
+    fn channel_end(&self, channel_end_path: &ChannelEndPath) -> Result<ChannelEnd, ContextError> {
+        self.channel_end(channel_end_path)
+    }
+
+    fn get_next_sequence_recv(
+        &self,
+        seq_recv_path: &SeqRecvPath,
+    ) -> Result<Sequence, ContextError> {
+        self.get_next_sequence_recv(seq_recv_path)
+    }
+
+    fn get_packet_receipt(&self, receipt_path: &ReceiptPath) -> Result<Receipt, ContextError> {
+        self.get_packet_receipt(receipt_path)
+    }
+
+    fn get_packet_acknowledgement(
+        &self,
+        ack_path: &AckPath,
+    ) -> Result<AcknowledgementCommitment, ContextError> {
+        self.get_packet_acknowledgement(ack_path)
+    }
+
+    fn gas_meter(&self) -> &dyn GasMeter {
+        self.gas_meter()
+    }
+}
+
+/// Methods required in write acknowledgement execution, to be implemented by
+/// the host
+pub trait WriteAcknowledgementExecutionContext: WriteAcknowledgementValidationContext {
+    fn store_packet_acknowledgement(
+        &mut self,
+        ack_path: &AckPath,
+        ack_commitment: AcknowledgementCommitment,
+    ) -> Result<(), ContextError>;
+
+    /// Ibc events
+    fn emit_ibc_event(&mut self, event: IbcEvent) -> Result<(), ContextError>;
+
+    /// Logging facility
+    fn log_message(&mut self, message: String) -> Result<(), ContextError>;
+}
+
+impl<T> WriteAcknowledgementExecutionContext for T
+where
+    T: ExecutionContext,
+{
+    fn store_packet_acknowledgement(
+        &mut self,
+        ack_path: &AckPath,
+        ack_commitment: AcknowledgementCommitment,
+    ) -> Result<(), ContextError> {
+        self.store_packet_acknowledgement(ack_path, ack_commitment)
+    }
+
+    fn emit_ibc_event(&mut self, event: IbcEvent) -> Result<(), ContextError> {
+        self.emit_ibc_event(event)
+    }
+
+    fn log_message(&mut self, message: String) -> Result<(), ContextError> {
+        self.log_message(message)
+    }
+}
+
+/// Methods to iterate over the packet state stored for a channel end, to be
+/// implemented by the host.
+///
+/// They serve the queries of the packets which are not received or
+/// acknowledged yet, and the pruning of the packet state of the channel ends
+/// which are closed or upgraded.
+pub trait PacketStateIterationContext {
+    /// Returns the sequences, in ascending order and within the given range,
+    /// of the packet commitments stored for the given channel end.
+    fn packet_commitment_sequences(
+        &self,
+        channel_end_path: &ChannelEndPath,
+        range: SequenceRange,
+    ) -> Result<Vec<Sequence>, ContextError>;
+
+    /// Returns the sequences, in ascending order and within the given range,
+    /// of the packet receipts stored for the given channel end.
+    fn packet_receipt_sequences(
+        &self,
+        channel_end_path: &ChannelEndPath,
+        range: SequenceRange,
+    ) -> Result<Vec<Sequence>, ContextError>;
+
+    /// Returns the sequences, in ascending order and within the given range,
+    /// of the packet acknowledgements stored for the given channel end.
+    fn packet_acknowledgement_sequences(
+        &self,
+        channel_end_path: &ChannelEndPath,
+        range: SequenceRange,
+    ) -> Result<Vec<Sequence>, ContextError>;
+
+    /// Filters the given packet sequences down to the ones which are neither
+    /// received nor timed out by the receiving channel end, as
+    /// [`PacketStatusContext::unreceived_packet_sequences`] does, iterating
+    /// over the receipts of the channel end in batches instead of looking up
+    /// the receipt of each sequence.
+    fn unreceived_packet_sequences_batched(
+        &self,
+        channel_end_path: &ChannelEndPath,
+        sequences: impl IntoIterator<Item = Sequence>,
+    ) -> Result<Vec<Sequence>, ContextError>
+    where
+        Self: ValidationContext,
+    {
+        let sequences: Vec<_> = sequences.into_iter().collect();
+        let chan_end = self.channel_end(channel_end_path)?;
+
+        match chan_end.ordering {
+            Order::Unordered => {
+                let received = stored_sequences(&sequences, |range| {
+                    self.packet_receipt_sequences(channel_end_path, range)
+                })?;
+
+                Ok(sequences
+                    .into_iter()
+                    .filter(|sequence| received.binary_search(sequence).is_err())
+                    .collect())
+            }
+            // The packets below the next sequence to receive are either
+            // received or timed out.
+            Order::Ordered | Order::OrderedAllowTimeout => {
+                let seq_recv_path = SeqRecvPath::new(&channel_end_path.0, &channel_end_path.1);
+                let next_seq_recv = self.get_next_sequence_recv(&seq_recv_path)?;
+
+                Ok(sequences
+                    .into_iter()
+                    .filter(|sequence| *sequence >= next_seq_recv)
+                    .collect())
+            }
+            Order::None => Err(ChannelError::InvalidOrderType {
+                expected: "Channel ordering cannot be None".to_string(),
+                actual: chan_end.ordering.to_string(),
+            }
+            .into()),
+        }
+    }
+
+    /// Filters the given packet sequences down to the ones whose
+    /// acknowledgement is not received by the sending channel end, as
+    /// [`PacketStatusContext::unreceived_ack_sequences`] does, iterating over
+    /// the commitments of the channel end in batches instead of looking up the
+    /// commitment of each sequence.
+    fn unreceived_ack_sequences_batched(
+        &self,
+        channel_end_path: &ChannelEndPath,
+        sequences: impl IntoIterator<Item = Sequence>,
+    ) -> Result<Vec<Sequence>, ContextError> {
+        let sequences: Vec<_> = sequences.into_iter().collect();
+
+        let pending = stored_sequences(&sequences, |range| {
+            self.packet_commitment_sequences(channel_end_path, range)
+        })?;
+
+        Ok(sequences
+            .into_iter()
+            .filter(|sequence| pending.binary_search(sequence).is_ok())
+            .collect())
+    }
+}
+
+/// The number of sequences fetched at once by the batched lookups of the
+/// [`PacketStateIterationContext`].
+pub const PACKET_SEQUENCES_BATCH_SIZE: usize = 1000;
+
+/// Returns the stored sequences, in ascending order, within the range spanning
+/// the given sequences, fetched by `fetch` in batches of
+/// [`PACKET_SEQUENCES_BATCH_SIZE`].
+fn stored_sequences(
+    sequences: &[Sequence],
+    mut fetch: impl FnMut(SequenceRange) -> Result<Vec<Sequence>, ContextError>,
+) -> Result<Vec<Sequence>, ContextError> {
+    let mut stored = Vec::new();
+
+    let (Some(first), Some(last)) = (sequences.iter().min(), sequences.iter().max()) else {
+        return Ok(stored);
+    };
+
+    let mut range = SequenceRange {
+        start: Some(*first),
+        end: last.value().checked_add(1).map(Sequence::from),
+        limit: Some(PACKET_SEQUENCES_BATCH_SIZE),
+    };
+
+    loop {
+        let batch = fetch(range)?;
+        let batch_size = batch.len();
+
+        let Some(last_fetched) = batch.last().copied() else {
+            break;
+        };
+
+        stored.extend(batch);
+
+        match last_fetched.value().checked_add(1) {
+            Some(next) if batch_size == PACKET_SEQUENCES_BATCH_SIZE => {
+                range.start = Some(Sequence::from(next));
+            }
+            _ => break,
+        }
+    }
+
+    Ok(stored)
+}
+
+/// Convenience methods to look up whether the packets of a channel end were
+/// received, acknowledged or timed out, combining the receipt,
+/// acknowledgement and commitment lookups of the host.
+///
+/// They are implemented for every [`ValidationContext`], and back the queries
+/// of the packets which are not received or acknowledged yet.
+pub trait PacketStatusContext: ValidationContext {
+    /// Returns the receipt of the packet with the given sequence, if it was
+    /// already received, or timed out, by the receiving channel end.
+    ///
+    /// Over ordered channels, the packets below the next sequence to receive
+    /// are received, unless a timeout receipt was written for them.
+    fn packet_receipt_status(
+        &self,
+        channel_end_path: &ChannelEndPath,
+        sequence: Sequence,
+    ) -> Result<Option<Receipt>, ContextError> {
+        let chan_end = self.channel_end(channel_end_path)?;
+        let receipt_path = ReceiptPath::new(&channel_end_path.0, &channel_end_path.1, sequence);
+
+        match chan_end.ordering {
+            Order::Unordered => Ok(self.get_packet_receipt(&receipt_path).ok()),
+            Order::Ordered => {
+                let seq_recv_path = SeqRecvPath::new(&channel_end_path.0, &channel_end_path.1);
+                let next_seq_recv = self.get_next_sequence_recv(&seq_recv_path)?;
+
+                Ok((sequence < next_seq_recv).then_some(Receipt::Ok))
+            }
+            Order::OrderedAllowTimeout => {
+                let seq_recv_path = SeqRecvPath::new(&channel_end_path.0, &channel_end_path.1);
+                let next_seq_recv = self.get_next_sequence_recv(&seq_recv_path)?;
+
+                if sequence >= next_seq_recv {
+                    return Ok(None);
+                }
+
+                match self.get_packet_receipt(&receipt_path) {
+                    Ok(Receipt::Timeout) => Ok(Some(Receipt::Timeout)),
+                    _ => Ok(Some(Receipt::Ok)),
+                }
+            }
+            Order::None => Err(ChannelError::InvalidOrderType {
+                expected: "Channel ordering cannot be None".to_string(),
+                actual: chan_end.ordering.to_string(),
+            }
+            .into()),
+        }
+    }
+
+    /// Returns `true` if the packet with the given sequence was received, and
+    /// not timed out, by the receiving channel end.
+    fn is_packet_received(
+        &self,
+        channel_end_path: &ChannelEndPath,
+        sequence: Sequence,
+    ) -> Result<bool, ContextError> {
+        Ok(matches!(
+            self.packet_receipt_status(channel_end_path, sequence)?,
+            Some(Receipt::Ok)
+        ))
+    }
+
+    /// Returns `true` if the acknowledgement of the packet with the given
+    /// sequence was written by the receiving channel end.
+    fn is_packet_acknowledgement_written(
+        &self,
+        channel_end_path: &ChannelEndPath,
+        sequence: Sequence,
+    ) -> Result<bool, ContextError> {
+        let ack_path = AckPath::new(&channel_end_path.0, &channel_end_path.1, sequence);
+
+        Ok(self.get_packet_acknowledgement(&ack_path).is_ok())
+    }
+
+    /// Returns `true` if the packet with the given sequence was sent by the
+    /// sending channel end, and is neither acknowledged nor timed out yet.
+    fn is_packet_commitment_pending(
+        &self,
+        channel_end_path: &ChannelEndPath,
+        sequence: Sequence,
+    ) -> Result<bool, ContextError> {
+        let commitment_path =
+            CommitmentPath::new(&channel_end_path.0, &channel_end_path.1, sequence);
+
+        Ok(self.get_packet_commitment(&commitment_path).is_ok())
+    }
+
+    /// Returns `true` if the packet with the given sequence was sent by the
+    /// sending channel end, and was since acknowledged or timed out, which
+    /// deletes its commitment.
+    fn is_packet_settled(
+        &self,
+        channel_end_path: &ChannelEndPath,
+        sequence: Sequence,
+    ) -> Result<bool, ContextError> {
+        let seq_send_path = SeqSendPath::new(&channel_end_path.0, &channel_end_path.1);
+        let next_seq_send = self.get_next_sequence_send(&seq_send_path)?;
+
+        Ok(sequence < next_seq_send
+            && !self.is_packet_commitment_pending(channel_end_path, sequence)?)
+    }
+
+    /// Filters the given packet sequences down to the ones which are neither
+    /// received nor timed out by the receiving channel end.
+    fn unreceived_packet_sequences(
+        &self,
+        channel_end_path: &ChannelEndPath,
+        sequences: impl IntoIterator<Item = Sequence>,
+    ) -> Result<Vec<Sequence>, ContextError> {
+        let mut unreceived = Vec::new();
+
+        for sequence in sequences {
+            if self
+                .packet_receipt_status(channel_end_path, sequence)?
+                .is_none()
+            {
+                unreceived.push(sequence);
+            }
+        }
+
+        Ok(unreceived)
+    }
+
+    /// Filters the given packet sequences down to the ones whose
+    /// acknowledgement is not received by the sending channel end, that is
+    /// whose commitment is still stored.
+    fn unreceived_ack_sequences(
+        &self,
+        channel_end_path: &ChannelEndPath,
+        sequences: impl IntoIterator<Item = Sequence>,
+    ) -> Result<Vec<Sequence>, ContextError> {
+        let mut unreceived = Vec::new();
+
+        for sequence in sequences {
+            if self.is_packet_commitment_pending(channel_end_path, sequence)? {
+                unreceived.push(sequence);
+            }
+        }
+
+        Ok(unreceived)
+    }
+}
+
+impl<T> PacketStatusContext for T where T: ValidationContext {}
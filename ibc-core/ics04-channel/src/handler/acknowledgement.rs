@@ -9,12 +9,27 @@ use ibc_core_connection::types::State as ConnectionState;
 use ibc_core_handler_types::error::ContextError;
 use ibc_core_handler_types::events::{IbcEvent, MessageEvent};
 use ibc_core_host::types::path::{
-    AckPath, ChannelEndPath, ClientConsensusStatePath, CommitmentPath, Path, SeqAckPath,
+    AckPath, ChannelEndPath, ChannelUpgradePath, ClientConsensusStatePath, CommitmentPath, Path,
+    SeqAckPath,
 };
-use ibc_core_host::{ExecutionContext, ValidationContext};
+use ibc_core_host::{ExecutionContext, GasOperation, ProofKind, ValidationContext};
 use ibc_core_router::module::Module;
 use ibc_primitives::prelude::*;
 
+use super::packet_proofs::verify_packet_proof;
+
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(
+        level = "debug",
+        skip_all,
+        fields(
+            port_id = %msg.packet.port_id_on_a,
+            channel_id = %msg.packet.chan_id_on_a,
+            sequence = %msg.packet.seq_on_a,
+        ),
+    )
+)]
 pub fn acknowledgement_packet_validate<ValCtx>(
     ctx_a: &ValCtx,
     module: &dyn Module,
@@ -30,6 +45,18 @@ where
         .map_err(ContextError::PacketError)
 }
 
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(
+        level = "debug",
+        skip_all,
+        fields(
+            port_id = %msg.packet.port_id_on_a,
+            channel_id = %msg.packet.chan_id_on_a,
+            sequence = %msg.packet.seq_on_a,
+        ),
+    )
+)]
 pub fn acknowledgement_packet_execute<ExecCtx>(
     ctx_a: &mut ExecCtx,
     module: &mut dyn Module,
@@ -74,19 +101,45 @@ where
 
     // apply state changes
     {
+        ctx_a.gas_meter().charge(GasOperation::StoreWrite)?;
         ctx_a.delete_packet_commitment(&commitment_path_on_a)?;
 
-        if let Order::Ordered = chan_end_on_a.ordering {
+        if let Order::Ordered | Order::OrderedAllowTimeout = chan_end_on_a.ordering {
             // Note: in validation, we verified that `msg.packet.sequence == nextSeqRecv`
             // (where `nextSeqRecv` is the value in the store)
             let seq_ack_path_on_a =
                 SeqAckPath::new(&msg.packet.port_id_on_a, &msg.packet.chan_id_on_a);
+            ctx_a.gas_meter().charge(GasOperation::StoreWrite)?;
             ctx_a.store_next_sequence_ack(&seq_ack_path_on_a, msg.packet.seq_on_a.increment())?;
         }
+
+        // An upgrading channel end is done flushing once its last in-flight
+        // packet is acknowledged.
+        if chan_end_on_a.state == ChannelState::Flushing {
+            let upgrade_path_on_a =
+                ChannelUpgradePath::new(&msg.packet.port_id_on_a, &msg.packet.chan_id_on_a);
+
+            if ctx_a
+                .counterparty_channel_upgrade(&upgrade_path_on_a)
+                .is_ok()
+                && !ctx_a.has_inflight_packets(&chan_end_path_on_a)?
+            {
+                let mut chan_end_on_a = chan_end_on_a.clone();
+                chan_end_on_a.set_state(ChannelState::FlushComplete);
+                ctx_a.gas_meter().charge(GasOperation::StoreWrite)?;
+                ctx_a.store_channel(&chan_end_path_on_a, chan_end_on_a)?;
+            }
+        }
     }
 
     // emit events and logs
     {
+        ctx_a.metrics().packet_acknowledged(
+            &msg.packet.port_id_on_a,
+            &msg.packet.chan_id_on_a,
+            msg.packet.seq_on_a,
+        );
+
         ctx_a.log_message("success: packet acknowledgement".to_string())?;
 
         // Note: Acknowledgement event was emitted at the beginning
@@ -109,11 +162,32 @@ where
 {
     ctx_a.validate_message_signer(&msg.signer)?;
 
+    if let Some(max_size) = ctx_a.max_packet_data_size() {
+        if msg.acknowledgement.as_bytes().len() > max_size {
+            return Err(PacketError::AcknowledgementTooLarge {
+                size: msg.acknowledgement.as_bytes().len(),
+                max_size,
+            }
+            .into());
+        }
+    }
+
     let packet = &msg.packet;
     let chan_end_path_on_a = ChannelEndPath::new(&packet.port_id_on_a, &packet.chan_id_on_a);
     let chan_end_on_a = ctx_a.channel_end(&chan_end_path_on_a)?;
 
-    chan_end_on_a.verify_state_matches(&ChannelState::Open)?;
+    // Packets sent before an upgrade keep being acknowledged while the
+    // channel end flushes them.
+    if !matches!(
+        chan_end_on_a.state,
+        ChannelState::Open | ChannelState::Flushing
+    ) {
+        return Err(ChannelError::InvalidState {
+            expected: "Channel end must be OPEN or FLUSHING".to_string(),
+            actual: chan_end_on_a.state().to_string(),
+        }
+        .into());
+    }
 
     let counterparty = Counterparty::new(
         packet.port_id_on_b.clone(),
@@ -152,7 +226,7 @@ where
         .into());
     }
 
-    if let Order::Ordered = chan_end_on_a.ordering {
+    if let Order::Ordered | Order::OrderedAllowTimeout = chan_end_on_a.ordering {
         let seq_ack_path_on_a = SeqAckPath::new(&packet.port_id_on_a, &packet.chan_id_on_a);
         let next_seq_ack = ctx_a.get_next_sequence_ack(&seq_ack_path_on_a)?;
         if packet.seq_on_a != next_seq_ack {
@@ -191,19 +265,26 @@ where
         verify_conn_delay_passed(ctx_a, msg.proof_height_on_b, &conn_end_on_a)?;
 
         // Verify the proof for the packet against the chain store.
-        client_state_of_b_on_a
-            .verify_membership(
-                conn_end_on_a.counterparty().prefix(),
-                &msg.proof_acked_on_b,
-                consensus_state_of_b_on_a.root(),
-                Path::Ack(ack_path_on_b),
-                ack_commitment.into_vec(),
-            )
-            .map_err(|e| ChannelError::PacketVerificationFailed {
-                sequence: packet.seq_on_a,
-                client_error: e,
-            })
-            .map_err(PacketError::Channel)?;
+        ctx_a.gas_meter().charge(GasOperation::ProofVerification {
+            kind: ProofKind::PacketAcknowledgement,
+            proof: &msg.proof_acked_on_b,
+        })?;
+        verify_packet_proof(
+            ctx_a,
+            &client_state_of_b_on_a,
+            client_id_on_a,
+            ProofKind::PacketAcknowledgement,
+            conn_end_on_a.counterparty().prefix(),
+            &msg.proof_acked_on_b,
+            consensus_state_of_b_on_a.root(),
+            Path::Ack(ack_path_on_b),
+            Some(ack_commitment.into_vec()),
+        )
+        .map_err(|e| ChannelError::PacketVerificationFailed {
+            sequence: packet.seq_on_a,
+            client_error: e,
+        })
+        .map_err(PacketError::Channel)?;
     }
 
     Ok(())
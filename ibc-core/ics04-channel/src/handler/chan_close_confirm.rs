@@ -9,11 +9,19 @@ use ibc_core_connection::types::State as ConnectionState;
 use ibc_core_handler_types::error::ContextError;
 use ibc_core_handler_types::events::{IbcEvent, MessageEvent};
 use ibc_core_host::types::path::{ChannelEndPath, ClientConsensusStatePath, Path};
-use ibc_core_host::{ExecutionContext, ValidationContext};
+use ibc_core_host::{ExecutionContext, GasOperation, HandshakeStep, ProofKind, ValidationContext};
 use ibc_core_router::module::Module;
 use ibc_primitives::prelude::*;
-use ibc_primitives::proto::Protobuf;
-
+use ibc_primitives::CanonicalProtobuf;
+
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(
+        level = "debug",
+        skip_all,
+        fields(port_id = %msg.port_id_on_b, channel_id = %msg.chan_id_on_b),
+    )
+)]
 pub fn chan_close_confirm_validate<ValCtx>(
     ctx_b: &ValCtx,
     module: &dyn Module,
@@ -29,6 +37,14 @@ where
     Ok(())
 }
 
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(
+        level = "debug",
+        skip_all,
+        fields(port_id = %msg.port_id_on_b, channel_id = %msg.chan_id_on_b),
+    )
+)]
 pub fn chan_close_confirm_execute<ExecCtx>(
     ctx_b: &mut ExecCtx,
     module: &mut dyn Module,
@@ -48,11 +64,18 @@ where
             chan_end_on_b.set_state(State::Closed);
             chan_end_on_b
         };
+        ctx_b.gas_meter().charge(GasOperation::StoreWrite)?;
         ctx_b.store_channel(&chan_end_path_on_b, chan_end_on_b)?;
     }
 
     // emit events and logs
     {
+        ctx_b.metrics().channel_handshake_step(
+            HandshakeStep::ChannelCloseConfirm,
+            &msg.port_id_on_b,
+            &msg.chan_id_on_b,
+        );
+
         ctx_b.log_message("success: channel close confirm".to_string())?;
 
         let core_event = {
@@ -146,20 +169,30 @@ where
             Counterparty::new(msg.port_id_on_b.clone(), Some(msg.chan_id_on_b.clone())),
             vec![conn_id_on_a.clone()],
             chan_end_on_b.version().clone(),
-        )?;
+        )?
+        .with_upgrade_sequence(msg.upgrade_sequence_on_a);
         let chan_end_path_on_a = ChannelEndPath::new(port_id_on_a, chan_id_on_a);
 
         // Verify the proof for the channel state against the expected channel end.
         // A counterparty channel id of None in not possible, and is checked by validate_basic in msg.
-        client_state_of_a_on_b
-            .verify_membership(
-                prefix_on_a,
-                &msg.proof_chan_end_on_a,
-                consensus_state_of_a_on_b.root(),
-                Path::ChannelEnd(chan_end_path_on_a),
-                expected_chan_end_on_a.encode_vec(),
-            )
-            .map_err(ChannelError::VerifyChannelFailed)?;
+        ctx_b.gas_meter().charge(GasOperation::ProofVerification {
+            kind: ProofKind::Channel,
+            proof: &msg.proof_chan_end_on_a,
+        })?;
+        let path = Path::ChannelEnd(chan_end_path_on_a);
+        ctx_b
+            .metrics()
+            .observe_proof_verification(ProofKind::Channel, || {
+                client_state_of_a_on_b.verify_membership_with_context(
+                    client_val_ctx_b,
+                    prefix_on_a,
+                    &msg.proof_chan_end_on_a,
+                    consensus_state_of_a_on_b.root(),
+                    path.clone(),
+                    expected_chan_end_on_a.encode_vec_canonical(),
+                )
+            })
+            .map_err(|client_error| ChannelError::VerifyChannelFailed { path, client_error })?;
     }
 
     Ok(())
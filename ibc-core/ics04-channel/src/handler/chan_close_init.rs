@@ -8,10 +8,18 @@ use ibc_core_connection::types::State as ConnectionState;
 use ibc_core_handler_types::error::ContextError;
 use ibc_core_handler_types::events::{IbcEvent, MessageEvent};
 use ibc_core_host::types::path::ChannelEndPath;
-use ibc_core_host::{ExecutionContext, ValidationContext};
+use ibc_core_host::{ExecutionContext, GasOperation, HandshakeStep, ValidationContext};
 use ibc_core_router::module::Module;
 use ibc_primitives::prelude::*;
 
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(
+        level = "debug",
+        skip_all,
+        fields(port_id = %msg.port_id_on_a, channel_id = %msg.chan_id_on_a),
+    )
+)]
 pub fn chan_close_init_validate<ValCtx>(
     ctx_a: &ValCtx,
     module: &dyn Module,
@@ -27,6 +35,14 @@ where
     Ok(())
 }
 
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(
+        level = "debug",
+        skip_all,
+        fields(port_id = %msg.port_id_on_a, channel_id = %msg.chan_id_on_a),
+    )
+)]
 pub fn chan_close_init_execute<ExecCtx>(
     ctx_a: &mut ExecCtx,
     module: &mut dyn Module,
@@ -47,11 +63,18 @@ where
             chan_end_on_a
         };
 
+        ctx_a.gas_meter().charge(GasOperation::StoreWrite)?;
         ctx_a.store_channel(&chan_end_path_on_a, chan_end_on_a)?;
     }
 
     // emit events and logs
     {
+        ctx_a.metrics().channel_handshake_step(
+            HandshakeStep::ChannelCloseInit,
+            &msg.port_id_on_a,
+            &msg.chan_id_on_a,
+        );
+
         ctx_a.log_message("success: channel close init".to_string())?;
 
         let core_event = {
@@ -95,6 +118,7 @@ where
     Ctx: ValidationContext,
 {
     ctx_a.validate_message_signer(&msg.signer)?;
+    ctx_a.validate_channel_close(&msg.port_id_on_a, &msg.chan_id_on_a)?;
 
     let chan_end_path_on_a = ChannelEndPath::new(&msg.port_id_on_a, &msg.chan_id_on_a);
     let chan_end_on_a = ctx_a.channel_end(&chan_end_path_on_a)?;
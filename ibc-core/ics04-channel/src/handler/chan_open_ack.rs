@@ -8,11 +8,19 @@ use ibc_core_connection::types::State as ConnectionState;
 use ibc_core_handler_types::error::ContextError;
 use ibc_core_handler_types::events::{IbcEvent, MessageEvent};
 use ibc_core_host::types::path::{ChannelEndPath, ClientConsensusStatePath, Path};
-use ibc_core_host::{ExecutionContext, ValidationContext};
+use ibc_core_host::{ExecutionContext, GasOperation, HandshakeStep, ProofKind, ValidationContext};
 use ibc_core_router::module::Module;
 use ibc_primitives::prelude::*;
-use ibc_primitives::proto::Protobuf;
-
+use ibc_primitives::CanonicalProtobuf;
+
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(
+        level = "debug",
+        skip_all,
+        fields(port_id = %msg.port_id_on_a, channel_id = %msg.chan_id_on_a),
+    )
+)]
 pub fn chan_open_ack_validate<ValCtx>(
     ctx_a: &ValCtx,
     module: &dyn Module,
@@ -28,6 +36,14 @@ where
     Ok(())
 }
 
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(
+        level = "debug",
+        skip_all,
+        fields(port_id = %msg.port_id_on_a, channel_id = %msg.chan_id_on_a),
+    )
+)]
 pub fn chan_open_ack_execute<ExecCtx>(
     ctx_a: &mut ExecCtx,
     module: &mut dyn Module,
@@ -52,11 +68,18 @@ where
 
             chan_end_on_a
         };
+        ctx_a.gas_meter().charge(GasOperation::StoreWrite)?;
         ctx_a.store_channel(&chan_end_path_on_a, chan_end_on_a)?;
     }
 
     // emit events and logs
     {
+        ctx_a.metrics().channel_handshake_step(
+            HandshakeStep::ChannelOpenAck,
+            &msg.port_id_on_a,
+            &msg.chan_id_on_a,
+        );
+
         ctx_a.log_message("success: channel open ack".to_string())?;
 
         let core_event = {
@@ -144,15 +167,24 @@ where
 
         // Verify the proof for the channel state against the expected channel end.
         // A counterparty channel id of None in not possible, and is checked by validate_basic in msg.
-        client_state_of_b_on_a
-            .verify_membership(
-                prefix_on_b,
-                &msg.proof_chan_end_on_b,
-                consensus_state_of_b_on_a.root(),
-                Path::ChannelEnd(chan_end_path_on_b),
-                expected_chan_end_on_b.encode_vec(),
-            )
-            .map_err(ChannelError::VerifyChannelFailed)?;
+        ctx_a.gas_meter().charge(GasOperation::ProofVerification {
+            kind: ProofKind::Channel,
+            proof: &msg.proof_chan_end_on_b,
+        })?;
+        let path = Path::ChannelEnd(chan_end_path_on_b);
+        ctx_a
+            .metrics()
+            .observe_proof_verification(ProofKind::Channel, || {
+                client_state_of_b_on_a.verify_membership_with_context(
+                    client_val_ctx_a,
+                    prefix_on_b,
+                    &msg.proof_chan_end_on_b,
+                    consensus_state_of_b_on_a.root(),
+                    path.clone(),
+                    expected_chan_end_on_b.encode_vec_canonical(),
+                )
+            })
+            .map_err(|client_error| ChannelError::VerifyChannelFailed { path, client_error })?;
     }
 
     Ok(())
@@ -1,6 +1,7 @@
 //! Protocol logic specific to ICS4 messages of type `MsgChannelOpenInit`.
 
 use ibc_core_channel_types::channel::{ChannelEnd, Counterparty, State};
+use ibc_core_channel_types::error::ChannelError;
 use ibc_core_channel_types::events::OpenInit;
 use ibc_core_channel_types::msgs::MsgChannelOpenInit;
 use ibc_core_client::context::prelude::*;
@@ -8,10 +9,18 @@ use ibc_core_handler_types::error::ContextError;
 use ibc_core_handler_types::events::{IbcEvent, MessageEvent};
 use ibc_core_host::types::identifiers::ChannelId;
 use ibc_core_host::types::path::{ChannelEndPath, SeqAckPath, SeqRecvPath, SeqSendPath};
-use ibc_core_host::{ExecutionContext, ValidationContext};
+use ibc_core_host::{ExecutionContext, GasOperation, HandshakeStep, ValidationContext};
 use ibc_core_router::module::Module;
 use ibc_primitives::prelude::*;
 
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(
+        level = "debug",
+        skip_all,
+        fields(port_id = %msg.port_id_on_a),
+    )
+)]
 pub fn chan_open_init_validate<ValCtx>(
     ctx_a: &ValCtx,
     module: &dyn Module,
@@ -35,6 +44,14 @@ where
     Ok(())
 }
 
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(
+        level = "debug",
+        skip_all,
+        fields(port_id = %msg.port_id_on_a),
+    )
+)]
 pub fn chan_open_init_execute<ExecCtx>(
     ctx_a: &mut ExecCtx,
     module: &mut dyn Module,
@@ -65,23 +82,34 @@ where
             msg.version_proposal.clone(),
         )?;
         let chan_end_path_on_a = ChannelEndPath::new(&msg.port_id_on_a, &chan_id_on_a);
+        ctx_a.gas_meter().charge(GasOperation::StoreWrite)?;
         ctx_a.store_channel(&chan_end_path_on_a, chan_end_on_a)?;
 
+        ctx_a.gas_meter().charge(GasOperation::StoreWrite)?;
         ctx_a.increase_channel_counter()?;
 
         // Initialize send, recv, and ack sequence numbers.
         let seq_send_path = SeqSendPath::new(&msg.port_id_on_a, &chan_id_on_a);
+        ctx_a.gas_meter().charge(GasOperation::StoreWrite)?;
         ctx_a.store_next_sequence_send(&seq_send_path, 1.into())?;
 
         let seq_recv_path = SeqRecvPath::new(&msg.port_id_on_a, &chan_id_on_a);
+        ctx_a.gas_meter().charge(GasOperation::StoreWrite)?;
         ctx_a.store_next_sequence_recv(&seq_recv_path, 1.into())?;
 
         let seq_ack_path = SeqAckPath::new(&msg.port_id_on_a, &chan_id_on_a);
+        ctx_a.gas_meter().charge(GasOperation::StoreWrite)?;
         ctx_a.store_next_sequence_ack(&seq_ack_path, 1.into())?;
     }
 
     // emit events and logs
     {
+        ctx_a.metrics().channel_handshake_step(
+            HandshakeStep::ChannelOpenInit,
+            &msg.port_id_on_a,
+            &chan_id_on_a,
+        );
+
         ctx_a.log_message(format!(
             "success: channel open init with channel identifier: {chan_id_on_a}"
         ))?;
@@ -114,6 +142,15 @@ where
     ctx_a.validate_message_signer(&msg.signer)?;
 
     msg.verify_connection_hops_length()?;
+
+    // The ports of both ends should satisfy the identifier rules of the host.
+    let identifier_rules = ctx_a.identifier_rules();
+    identifier_rules
+        .validate_port_identifier(msg.port_id_on_a.as_str())
+        .map_err(ChannelError::from)?;
+    identifier_rules
+        .validate_port_identifier(msg.port_id_on_b.as_str())
+        .map_err(ChannelError::from)?;
     // An IBC connection running on the local (host) chain should exist.
     let conn_end_on_a = ctx_a.connection_end(&msg.connection_hops_on_a[0])?;
 
@@ -12,11 +12,19 @@ use ibc_core_host::types::identifiers::ChannelId;
 use ibc_core_host::types::path::{
     ChannelEndPath, ClientConsensusStatePath, Path, SeqAckPath, SeqRecvPath, SeqSendPath,
 };
-use ibc_core_host::{ExecutionContext, ValidationContext};
+use ibc_core_host::{ExecutionContext, GasOperation, HandshakeStep, ProofKind, ValidationContext};
 use ibc_core_router::module::Module;
 use ibc_primitives::prelude::*;
-use ibc_primitives::proto::Protobuf;
-
+use ibc_primitives::CanonicalProtobuf;
+
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(
+        level = "debug",
+        skip_all,
+        fields(port_id = %msg.port_id_on_b),
+    )
+)]
 pub fn chan_open_try_validate<ValCtx>(
     ctx_b: &ValCtx,
     module: &dyn Module,
@@ -41,6 +49,14 @@ where
     Ok(())
 }
 
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(
+        level = "debug",
+        skip_all,
+        fields(port_id = %msg.port_id_on_b),
+    )
+)]
 pub fn chan_open_try_execute<ExecCtx>(
     ctx_b: &mut ExecCtx,
     module: &mut dyn Module,
@@ -72,22 +88,33 @@ where
         )?;
 
         let chan_end_path_on_b = ChannelEndPath::new(&msg.port_id_on_b, &chan_id_on_b);
+        ctx_b.gas_meter().charge(GasOperation::StoreWrite)?;
         ctx_b.store_channel(&chan_end_path_on_b, chan_end_on_b)?;
+        ctx_b.gas_meter().charge(GasOperation::StoreWrite)?;
         ctx_b.increase_channel_counter()?;
 
         // Initialize send, recv, and ack sequence numbers.
         let seq_send_path = SeqSendPath::new(&msg.port_id_on_b, &chan_id_on_b);
+        ctx_b.gas_meter().charge(GasOperation::StoreWrite)?;
         ctx_b.store_next_sequence_send(&seq_send_path, 1.into())?;
 
         let seq_recv_path = SeqRecvPath::new(&msg.port_id_on_b, &chan_id_on_b);
+        ctx_b.gas_meter().charge(GasOperation::StoreWrite)?;
         ctx_b.store_next_sequence_recv(&seq_recv_path, 1.into())?;
 
         let seq_ack_path = SeqAckPath::new(&msg.port_id_on_b, &chan_id_on_b);
+        ctx_b.gas_meter().charge(GasOperation::StoreWrite)?;
         ctx_b.store_next_sequence_ack(&seq_ack_path, 1.into())?;
     }
 
     // emit events and logs
     {
+        ctx_b.metrics().channel_handshake_step(
+            HandshakeStep::ChannelOpenTry,
+            &msg.port_id_on_b,
+            &chan_id_on_b,
+        );
+
         ctx_b.log_message(format!(
             "success: channel open try with channel identifier: {chan_id_on_b}"
         ))?;
@@ -123,6 +150,19 @@ where
 
     msg.verify_connection_hops_length()?;
 
+    // The ports of both ends, and the channel of the counterparty, should
+    // satisfy the identifier rules of the host.
+    let identifier_rules = ctx_b.identifier_rules();
+    identifier_rules
+        .validate_port_identifier(msg.port_id_on_b.as_str())
+        .map_err(ChannelError::from)?;
+    identifier_rules
+        .validate_port_identifier(msg.port_id_on_a.as_str())
+        .map_err(ChannelError::from)?;
+    identifier_rules
+        .validate_channel_identifier(msg.chan_id_on_a.as_str())
+        .map_err(ChannelError::from)?;
+
     let conn_end_on_b = ctx_b.connection_end(&msg.connection_hops_on_b[0])?;
 
     conn_end_on_b.verify_state_matches(&ConnectionState::Open)?;
@@ -170,15 +210,24 @@ where
 
         // Verify the proof for the channel state against the expected channel end.
         // A counterparty channel id of None in not possible, and is checked by validate_basic in msg.
-        client_state_of_a_on_b
-            .verify_membership(
-                prefix_on_a,
-                &msg.proof_chan_end_on_a,
-                consensus_state_of_a_on_b.root(),
-                Path::ChannelEnd(chan_end_path_on_a),
-                expected_chan_end_on_a.encode_vec(),
-            )
-            .map_err(ChannelError::VerifyChannelFailed)?;
+        ctx_b.gas_meter().charge(GasOperation::ProofVerification {
+            kind: ProofKind::Channel,
+            proof: &msg.proof_chan_end_on_a,
+        })?;
+        let path = Path::ChannelEnd(chan_end_path_on_a);
+        ctx_b
+            .metrics()
+            .observe_proof_verification(ProofKind::Channel, || {
+                client_state_of_a_on_b.verify_membership_with_context(
+                    client_val_ctx_b,
+                    prefix_on_a,
+                    &msg.proof_chan_end_on_a,
+                    consensus_state_of_a_on_b.root(),
+                    path.clone(),
+                    expected_chan_end_on_a.encode_vec_canonical(),
+                )
+            })
+            .map_err(|client_error| ChannelError::VerifyChannelFailed { path, client_error })?;
     }
 
     Ok(())
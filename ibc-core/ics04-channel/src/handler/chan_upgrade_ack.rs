@@ -0,0 +1,282 @@
+//! Protocol logic specific to ICS4 messages of type `MsgChannelUpgradeAck`.
+use ibc_core_channel_types::channel::{ChannelEnd, Counterparty, State as ChannelState};
+use ibc_core_channel_types::error::ChannelError;
+use ibc_core_channel_types::events::UpgradeAck;
+use ibc_core_channel_types::msgs::MsgChannelUpgradeAck;
+use ibc_core_channel_types::timeout::TimeoutHeight;
+use ibc_core_channel_types::upgrade::UpgradeTimeout;
+use ibc_core_client::context::prelude::*;
+use ibc_core_connection::types::State as ConnectionState;
+use ibc_core_handler_types::error::ContextError;
+use ibc_core_handler_types::events::{IbcEvent, MessageEvent};
+use ibc_core_host::types::path::{
+    ChannelEndPath, ChannelUpgradePath, ClientConsensusStatePath, Path, SeqSendPath,
+};
+use ibc_core_host::{ExecutionContext, GasOperation, ProofKind, ValidationContext};
+use ibc_core_router::module::Module;
+use ibc_primitives::prelude::*;
+use ibc_primitives::CanonicalProtobuf;
+
+pub fn chan_upgrade_ack_validate<ValCtx>(
+    ctx_a: &ValCtx,
+    module: &dyn Module,
+    msg: MsgChannelUpgradeAck,
+) -> Result<(), ContextError>
+where
+    ValCtx: ValidationContext,
+{
+    validate(ctx_a, &msg)?;
+
+    module.on_chan_upgrade_ack_validate(
+        &msg.port_id_on_a,
+        &msg.chan_id_on_a,
+        &msg.upgrade_on_b.fields.version,
+    )?;
+
+    Ok(())
+}
+
+pub fn chan_upgrade_ack_execute<ExecCtx>(
+    ctx_a: &mut ExecCtx,
+    module: &mut dyn Module,
+    msg: MsgChannelUpgradeAck,
+) -> Result<(), ContextError>
+where
+    ExecCtx: ExecutionContext,
+{
+    let extras = module.on_chan_upgrade_ack_execute(
+        &msg.port_id_on_a,
+        &msg.chan_id_on_a,
+        &msg.upgrade_on_b.fields.version,
+    )?;
+    let chan_end_path_on_a = ChannelEndPath::new(&msg.port_id_on_a, &msg.chan_id_on_a);
+    let chan_end_on_a = ctx_a.channel_end(&chan_end_path_on_a)?;
+    let upgrade_path_on_a = ChannelUpgradePath::new(&msg.port_id_on_a, &msg.chan_id_on_a);
+    let mut upgrade_on_a = ctx_a.channel_upgrade(&upgrade_path_on_a)?;
+
+    // Both ends upgrade to the version chain B agreed to.
+    upgrade_on_a.fields.version = msg.upgrade_on_b.fields.version.clone();
+
+    // state changes
+    {
+        let mut chan_end_on_a = chan_end_on_a.clone();
+
+        if chan_end_on_a.state == ChannelState::Open {
+            let timeout_timestamp =
+                (ctx_a.host_timestamp()? + ctx_a.upgrade_timeout()).map_err(|e| {
+                    ChannelError::InvalidUpgradeTimeout {
+                        reason: e.to_string(),
+                    }
+                })?;
+            let seq_send_path_on_a = SeqSendPath::new(&msg.port_id_on_a, &msg.chan_id_on_a);
+
+            upgrade_on_a.timeout = UpgradeTimeout {
+                height: TimeoutHeight::Never,
+                timestamp: timeout_timestamp,
+            };
+            upgrade_on_a.next_sequence_send = ctx_a.get_next_sequence_send(&seq_send_path_on_a)?;
+            chan_end_on_a.set_state(ChannelState::Flushing);
+        }
+
+        if !ctx_a.has_inflight_packets(&chan_end_path_on_a)? {
+            chan_end_on_a.set_state(ChannelState::FlushComplete);
+        }
+
+        ctx_a.gas_meter().charge(GasOperation::StoreWrite)?;
+        ctx_a.store_channel(&chan_end_path_on_a, chan_end_on_a)?;
+        ctx_a.gas_meter().charge(GasOperation::StoreWrite)?;
+        ctx_a.store_channel_upgrade(&upgrade_path_on_a, upgrade_on_a.clone())?;
+        ctx_a.gas_meter().charge(GasOperation::StoreWrite)?;
+        ctx_a.store_counterparty_channel_upgrade(&upgrade_path_on_a, msg.upgrade_on_b.clone())?;
+    }
+
+    // emit events and logs
+    {
+        ctx_a.log_message("success: channel upgrade ack".to_string())?;
+
+        let core_event = {
+            let port_id_on_b = chan_end_on_a.counterparty().port_id.clone();
+            let chan_id_on_b = chan_end_on_a.counterparty().channel_id.clone().ok_or(
+                ContextError::ChannelError(ChannelError::MissingCounterparty),
+            )?;
+
+            IbcEvent::UpgradeAckChannel(UpgradeAck::new(
+                msg.port_id_on_a.clone(),
+                msg.chan_id_on_a.clone(),
+                port_id_on_b,
+                chan_id_on_b,
+                chan_end_on_a.upgrade_sequence(),
+                upgrade_on_a.fields,
+            ))
+        };
+        ctx_a.emit_ibc_event(IbcEvent::Message(MessageEvent::Channel))?;
+        ctx_a.emit_ibc_event(core_event)?;
+
+        for module_event in extras.events {
+            ctx_a.emit_ibc_event(IbcEvent::Module(module_event))?;
+        }
+
+        for log_message in extras.log {
+            ctx_a.log_message(log_message)?;
+        }
+    }
+
+    Ok(())
+}
+
+fn validate<Ctx>(ctx_a: &Ctx, msg: &MsgChannelUpgradeAck) -> Result<(), ContextError>
+where
+    Ctx: ValidationContext,
+{
+    ctx_a.validate_message_signer(&msg.signer)?;
+
+    let chan_end_path_on_a = ChannelEndPath::new(&msg.port_id_on_a, &msg.chan_id_on_a);
+    let chan_end_on_a = ctx_a.channel_end(&chan_end_path_on_a)?;
+
+    if !matches!(
+        chan_end_on_a.state,
+        ChannelState::Open | ChannelState::Flushing
+    ) {
+        return Err(ChannelError::InvalidState {
+            expected: "Channel end must be OPEN or FLUSHING".to_string(),
+            actual: chan_end_on_a.state().to_string(),
+        }
+        .into());
+    }
+
+    let upgrade_path_on_a = ChannelUpgradePath::new(&msg.port_id_on_a, &msg.chan_id_on_a);
+    let upgrade_on_a = ctx_a.channel_upgrade(&upgrade_path_on_a)?;
+
+    // In the crossing hellos, both ends agreed to a version in their
+    // `MsgChannelUpgradeTry`, which must be the same.
+    if chan_end_on_a.state == ChannelState::Flushing
+        && upgrade_on_a.fields.version != msg.upgrade_on_b.fields.version
+    {
+        return Err(ChannelError::IncompatibleUpgrade {
+            reason: "both channel ends must agree on the same version".to_string(),
+        }
+        .into());
+    }
+
+    // The upgrade chain B agreed to must be compatible with the proposed one.
+    {
+        if upgrade_on_a.fields.ordering != msg.upgrade_on_b.fields.ordering {
+            return Err(ChannelError::IncompatibleUpgrade {
+                reason: "the counterparty upgrade ordering does not match".to_string(),
+            }
+            .into());
+        }
+
+        let proposed_conn_end_on_a =
+            ctx_a.connection_end(&upgrade_on_a.fields.connection_hops[0])?;
+
+        proposed_conn_end_on_a.verify_state_matches(&ConnectionState::Open)?;
+
+        if proposed_conn_end_on_a.counterparty().connection_id()
+            != Some(&msg.upgrade_on_b.fields.connection_hops[0])
+        {
+            return Err(ChannelError::IncompatibleUpgrade {
+                reason: "the counterparty upgrade connection hops do not match".to_string(),
+            }
+            .into());
+        }
+    }
+
+    if msg
+        .upgrade_on_b
+        .timeout
+        .has_expired(ctx_a.host_height()?, &ctx_a.host_timestamp()?)
+    {
+        return Err(ChannelError::UpgradeTimedOut.into());
+    }
+
+    chan_end_on_a.verify_connection_hops_length()?;
+
+    let conn_end_on_a = ctx_a.connection_end(&chan_end_on_a.connection_hops()[0])?;
+
+    conn_end_on_a.verify_state_matches(&ConnectionState::Open)?;
+
+    // Verify proofs
+    {
+        let client_id_on_a = conn_end_on_a.client_id();
+        let client_val_ctx_a = ctx_a.get_client_validation_context();
+        let client_state_of_b_on_a = client_val_ctx_a.client_state(client_id_on_a)?;
+
+        client_state_of_b_on_a
+            .status(ctx_a.get_client_validation_context(), client_id_on_a)?
+            .verify_is_active()?;
+        client_state_of_b_on_a.validate_proof_height(msg.proof_height_on_b)?;
+
+        let client_cons_state_path_on_a = ClientConsensusStatePath::new(
+            client_id_on_a.clone(),
+            msg.proof_height_on_b.revision_number(),
+            msg.proof_height_on_b.revision_height(),
+        );
+        let consensus_state_of_b_on_a =
+            client_val_ctx_a.consensus_state(&client_cons_state_path_on_a)?;
+        let prefix_on_b = conn_end_on_a.counterparty().prefix();
+        let port_id_on_b = &chan_end_on_a.counterparty().port_id;
+        let chan_id_on_b = chan_end_on_a
+            .counterparty()
+            .channel_id()
+            .ok_or(ChannelError::MissingCounterparty)?;
+        let conn_id_on_b = conn_end_on_a.counterparty().connection_id().ok_or(
+            ChannelError::UndefinedConnectionCounterparty {
+                connection_id: chan_end_on_a.connection_hops()[0].clone(),
+            },
+        )?;
+
+        // Chain B's channel end is flushing with the current parameters.
+        let expected_chan_end_on_b = ChannelEnd::new(
+            ChannelState::Flushing,
+            *chan_end_on_a.ordering(),
+            Counterparty::new(msg.port_id_on_a.clone(), Some(msg.chan_id_on_a.clone())),
+            vec![conn_id_on_b.clone()],
+            chan_end_on_a.version().clone(),
+        )?
+        .with_upgrade_sequence(chan_end_on_a.upgrade_sequence());
+        let chan_end_path_on_b = ChannelEndPath::new(port_id_on_b, chan_id_on_b);
+
+        ctx_a.gas_meter().charge(GasOperation::ProofVerification {
+            kind: ProofKind::Channel,
+            proof: &msg.proof_chan_end_on_b,
+        })?;
+        let path = Path::ChannelEnd(chan_end_path_on_b);
+        ctx_a
+            .metrics()
+            .observe_proof_verification(ProofKind::Channel, || {
+                client_state_of_b_on_a.verify_membership_with_context(
+                    client_val_ctx_a,
+                    prefix_on_b,
+                    &msg.proof_chan_end_on_b,
+                    consensus_state_of_b_on_a.root(),
+                    path.clone(),
+                    expected_chan_end_on_b.encode_vec_canonical(),
+                )
+            })
+            .map_err(|client_error| ChannelError::VerifyChannelFailed { path, client_error })?;
+
+        let upgrade_path_on_b = ChannelUpgradePath::new(port_id_on_b, chan_id_on_b);
+
+        ctx_a.gas_meter().charge(GasOperation::ProofVerification {
+            kind: ProofKind::ChannelUpgrade,
+            proof: &msg.proof_upgrade_on_b,
+        })?;
+        let path = Path::ChannelUpgrade(upgrade_path_on_b);
+        ctx_a
+            .metrics()
+            .observe_proof_verification(ProofKind::ChannelUpgrade, || {
+                client_state_of_b_on_a.verify_membership_with_context(
+                    client_val_ctx_a,
+                    prefix_on_b,
+                    &msg.proof_upgrade_on_b,
+                    consensus_state_of_b_on_a.root(),
+                    path.clone(),
+                    msg.upgrade_on_b.clone().encode_vec_canonical(),
+                )
+            })
+            .map_err(|client_error| ChannelError::VerifyChannelFailed { path, client_error })?;
+    }
+
+    Ok(())
+}
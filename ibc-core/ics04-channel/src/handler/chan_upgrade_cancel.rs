@@ -0,0 +1,176 @@
+//! Protocol logic specific to ICS4 messages of type `MsgChannelUpgradeCancel`.
+use ibc_core_channel_types::channel::State as ChannelState;
+use ibc_core_channel_types::error::ChannelError;
+use ibc_core_channel_types::events::{UpgradeCancel, UpgradeError};
+use ibc_core_channel_types::msgs::MsgChannelUpgradeCancel;
+use ibc_core_client::context::prelude::*;
+use ibc_core_connection::types::State as ConnectionState;
+use ibc_core_handler_types::error::ContextError;
+use ibc_core_handler_types::events::{IbcEvent, MessageEvent};
+use ibc_core_host::types::path::{
+    ChannelEndPath, ChannelUpgradeErrorPath, ChannelUpgradePath, ClientConsensusStatePath, Path,
+};
+use ibc_core_host::{ExecutionContext, GasOperation, ProofKind, ValidationContext};
+use ibc_primitives::prelude::*;
+use ibc_primitives::CanonicalProtobuf;
+
+use super::abort_upgrade;
+
+const UPGRADE_CANCELLED_MESSAGE: &str = "upgrade cancelled by the counterparty";
+
+pub fn chan_upgrade_cancel_validate<ValCtx>(
+    ctx_a: &ValCtx,
+    msg: MsgChannelUpgradeCancel,
+) -> Result<(), ContextError>
+where
+    ValCtx: ValidationContext,
+{
+    validate(ctx_a, &msg)
+}
+
+pub fn chan_upgrade_cancel_execute<ExecCtx>(
+    ctx_a: &mut ExecCtx,
+    msg: MsgChannelUpgradeCancel,
+) -> Result<(), ContextError>
+where
+    ExecCtx: ExecutionContext,
+{
+    let chan_end_path_on_a = ChannelEndPath::new(&msg.port_id_on_a, &msg.chan_id_on_a);
+    let chan_end_on_a = ctx_a.channel_end(&chan_end_path_on_a)?;
+
+    // The channel end catches up with the upgrade sequence of chain B, so
+    // that the next upgrade attempt starts from the same one on both ends.
+    let upgrade_sequence = chan_end_on_a
+        .upgrade_sequence()
+        .max(msg.error_receipt_on_b.sequence);
+
+    // state changes
+    let chan_end_on_a = abort_upgrade(
+        ctx_a,
+        &msg.port_id_on_a,
+        &msg.chan_id_on_a,
+        upgrade_sequence,
+        UPGRADE_CANCELLED_MESSAGE.to_string(),
+    )?;
+
+    // emit events and logs
+    {
+        ctx_a.log_message("success: channel upgrade cancel".to_string())?;
+
+        let port_id_on_b = chan_end_on_a.counterparty().port_id.clone();
+        let chan_id_on_b = chan_end_on_a
+            .counterparty()
+            .channel_id
+            .clone()
+            .ok_or(ChannelError::MissingCounterparty)?;
+
+        let core_event = IbcEvent::UpgradeCancelChannel(UpgradeCancel::new(
+            msg.port_id_on_a.clone(),
+            msg.chan_id_on_a.clone(),
+            port_id_on_b.clone(),
+            chan_id_on_b.clone(),
+            upgrade_sequence,
+        ));
+        let error_event = IbcEvent::UpgradeErrorChannel(UpgradeError::new(
+            msg.port_id_on_a.clone(),
+            msg.chan_id_on_a.clone(),
+            port_id_on_b,
+            chan_id_on_b,
+            upgrade_sequence,
+            UPGRADE_CANCELLED_MESSAGE.to_string(),
+        ));
+        ctx_a.emit_ibc_event(IbcEvent::Message(MessageEvent::Channel))?;
+        ctx_a.emit_ibc_event(core_event)?;
+        ctx_a.emit_ibc_event(error_event)?;
+    }
+
+    Ok(())
+}
+
+fn validate<Ctx>(ctx_a: &Ctx, msg: &MsgChannelUpgradeCancel) -> Result<(), ContextError>
+where
+    Ctx: ValidationContext,
+{
+    ctx_a.validate_message_signer(&msg.signer)?;
+
+    let chan_end_path_on_a = ChannelEndPath::new(&msg.port_id_on_a, &msg.chan_id_on_a);
+    let chan_end_on_a = ctx_a.channel_end(&chan_end_path_on_a)?;
+
+    // There must be an upgrade to cancel.
+    let upgrade_path_on_a = ChannelUpgradePath::new(&msg.port_id_on_a, &msg.chan_id_on_a);
+    ctx_a.channel_upgrade(&upgrade_path_on_a)?;
+
+    // Once flushed, the channel end waits for chain B to open, which is only
+    // aborted by the upgrade timing out.
+    if chan_end_on_a.state == ChannelState::FlushComplete {
+        return Err(ChannelError::InvalidState {
+            expected: "Channel state must not be FLUSHCOMPLETE".to_string(),
+            actual: chan_end_on_a.state.to_string(),
+        }
+        .into());
+    }
+
+    // The error receipt must be for the current upgrade attempt, or a newer
+    // one, and not for an earlier attempt chain B already aborted.
+    if msg.error_receipt_on_b.sequence < chan_end_on_a.upgrade_sequence() {
+        return Err(ChannelError::StaleUpgradeSequence {
+            upgrade_sequence: chan_end_on_a.upgrade_sequence(),
+            counterparty_upgrade_sequence: msg.error_receipt_on_b.sequence,
+        }
+        .into());
+    }
+
+    chan_end_on_a.verify_connection_hops_length()?;
+
+    let conn_end_on_a = ctx_a.connection_end(&chan_end_on_a.connection_hops()[0])?;
+
+    conn_end_on_a.verify_state_matches(&ConnectionState::Open)?;
+
+    // Verify proofs
+    {
+        let client_id_on_a = conn_end_on_a.client_id();
+        let client_val_ctx_a = ctx_a.get_client_validation_context();
+        let client_state_of_b_on_a = client_val_ctx_a.client_state(client_id_on_a)?;
+
+        client_state_of_b_on_a
+            .status(ctx_a.get_client_validation_context(), client_id_on_a)?
+            .verify_is_active()?;
+        client_state_of_b_on_a.validate_proof_height(msg.proof_height_on_b)?;
+
+        let client_cons_state_path_on_a = ClientConsensusStatePath::new(
+            client_id_on_a.clone(),
+            msg.proof_height_on_b.revision_number(),
+            msg.proof_height_on_b.revision_height(),
+        );
+        let consensus_state_of_b_on_a =
+            client_val_ctx_a.consensus_state(&client_cons_state_path_on_a)?;
+        let prefix_on_b = conn_end_on_a.counterparty().prefix();
+        let port_id_on_b = &chan_end_on_a.counterparty().port_id;
+        let chan_id_on_b = chan_end_on_a
+            .counterparty()
+            .channel_id()
+            .ok_or(ChannelError::MissingCounterparty)?;
+        let upgrade_error_path_on_b = ChannelUpgradeErrorPath::new(port_id_on_b, chan_id_on_b);
+
+        ctx_a.gas_meter().charge(GasOperation::ProofVerification {
+            kind: ProofKind::ChannelUpgradeError,
+            proof: &msg.proof_error_receipt_on_b,
+        })?;
+        let path = Path::ChannelUpgradeError(upgrade_error_path_on_b);
+        ctx_a
+            .metrics()
+            .observe_proof_verification(ProofKind::ChannelUpgradeError, || {
+                client_state_of_b_on_a.verify_membership_with_context(
+                    client_val_ctx_a,
+                    prefix_on_b,
+                    &msg.proof_error_receipt_on_b,
+                    consensus_state_of_b_on_a.root(),
+                    path.clone(),
+                    msg.error_receipt_on_b.encode_vec_canonical(),
+                )
+            })
+            .map_err(|client_error| ChannelError::VerifyChannelFailed { path, client_error })?;
+    }
+
+    Ok(())
+}
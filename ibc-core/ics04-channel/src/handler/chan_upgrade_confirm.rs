@@ -0,0 +1,198 @@
+//! Protocol logic specific to ICS4 messages of type `MsgChannelUpgradeConfirm`.
+use ibc_core_channel_types::channel::{ChannelEnd, Counterparty, State as ChannelState};
+use ibc_core_channel_types::error::ChannelError;
+use ibc_core_channel_types::events::UpgradeConfirm;
+use ibc_core_channel_types::msgs::MsgChannelUpgradeConfirm;
+use ibc_core_client::context::prelude::*;
+use ibc_core_connection::types::State as ConnectionState;
+use ibc_core_handler_types::error::ContextError;
+use ibc_core_handler_types::events::{IbcEvent, MessageEvent};
+use ibc_core_host::types::path::{
+    ChannelEndPath, ChannelUpgradePath, ClientConsensusStatePath, Path,
+};
+use ibc_core_host::{ExecutionContext, GasOperation, ProofKind, ValidationContext};
+use ibc_primitives::prelude::*;
+use ibc_primitives::CanonicalProtobuf;
+
+use super::verify_upgrade_compatibility;
+
+pub fn chan_upgrade_confirm_validate<ValCtx>(
+    ctx_b: &ValCtx,
+    msg: MsgChannelUpgradeConfirm,
+) -> Result<(), ContextError>
+where
+    ValCtx: ValidationContext,
+{
+    validate(ctx_b, &msg)
+}
+
+pub fn chan_upgrade_confirm_execute<ExecCtx>(
+    ctx_b: &mut ExecCtx,
+    msg: MsgChannelUpgradeConfirm,
+) -> Result<(), ContextError>
+where
+    ExecCtx: ExecutionContext,
+{
+    let chan_end_path_on_b = ChannelEndPath::new(&msg.port_id_on_b, &msg.chan_id_on_b);
+    let chan_end_on_b = ctx_b.channel_end(&chan_end_path_on_b)?;
+
+    // state changes
+    let chan_end_on_b = {
+        let mut chan_end_on_b = chan_end_on_b;
+
+        if !ctx_b.has_inflight_packets(&chan_end_path_on_b)? {
+            chan_end_on_b.set_state(ChannelState::FlushComplete);
+        }
+        ctx_b.gas_meter().charge(GasOperation::StoreWrite)?;
+        ctx_b.store_channel(&chan_end_path_on_b, chan_end_on_b.clone())?;
+
+        let upgrade_path_on_b = ChannelUpgradePath::new(&msg.port_id_on_b, &msg.chan_id_on_b);
+        ctx_b.gas_meter().charge(GasOperation::StoreWrite)?;
+        ctx_b.store_counterparty_channel_upgrade(&upgrade_path_on_b, msg.upgrade_on_a)?;
+
+        chan_end_on_b
+    };
+
+    // emit events and logs
+    {
+        ctx_b.log_message("success: channel upgrade confirm".to_string())?;
+
+        let core_event = {
+            let port_id_on_a = chan_end_on_b.counterparty().port_id.clone();
+            let chan_id_on_a = chan_end_on_b.counterparty().channel_id.clone().ok_or(
+                ContextError::ChannelError(ChannelError::MissingCounterparty),
+            )?;
+
+            IbcEvent::UpgradeConfirmChannel(UpgradeConfirm::new(
+                msg.port_id_on_b.clone(),
+                msg.chan_id_on_b.clone(),
+                port_id_on_a,
+                chan_id_on_a,
+                chan_end_on_b.upgrade_sequence(),
+                chan_end_on_b.state,
+            ))
+        };
+        ctx_b.emit_ibc_event(IbcEvent::Message(MessageEvent::Channel))?;
+        ctx_b.emit_ibc_event(core_event)?;
+    }
+
+    Ok(())
+}
+
+fn validate<Ctx>(ctx_b: &Ctx, msg: &MsgChannelUpgradeConfirm) -> Result<(), ContextError>
+where
+    Ctx: ValidationContext,
+{
+    ctx_b.validate_message_signer(&msg.signer)?;
+
+    let chan_end_path_on_b = ChannelEndPath::new(&msg.port_id_on_b, &msg.chan_id_on_b);
+    let chan_end_on_b = ctx_b.channel_end(&chan_end_path_on_b)?;
+
+    chan_end_on_b.verify_state_matches(&ChannelState::Flushing)?;
+
+    // Chain B must have agreed to the upgrade with `MsgChannelUpgradeTry`,
+    // to the one chain A upgrades to.
+    let upgrade_path_on_b = ChannelUpgradePath::new(&msg.port_id_on_b, &msg.chan_id_on_b);
+    let upgrade_on_b = ctx_b.channel_upgrade(&upgrade_path_on_b)?;
+
+    verify_upgrade_compatibility(ctx_b, &upgrade_on_b.fields, &msg.upgrade_on_a.fields)?;
+
+    if msg
+        .upgrade_on_a
+        .timeout
+        .has_expired(ctx_b.host_height()?, &ctx_b.host_timestamp()?)
+    {
+        return Err(ChannelError::UpgradeTimedOut.into());
+    }
+
+    chan_end_on_b.verify_connection_hops_length()?;
+
+    let conn_end_on_b = ctx_b.connection_end(&chan_end_on_b.connection_hops()[0])?;
+
+    conn_end_on_b.verify_state_matches(&ConnectionState::Open)?;
+
+    // Verify proofs
+    {
+        let client_id_on_b = conn_end_on_b.client_id();
+        let client_val_ctx_b = ctx_b.get_client_validation_context();
+        let client_state_of_a_on_b = client_val_ctx_b.client_state(client_id_on_b)?;
+
+        client_state_of_a_on_b
+            .status(ctx_b.get_client_validation_context(), client_id_on_b)?
+            .verify_is_active()?;
+        client_state_of_a_on_b.validate_proof_height(msg.proof_height_on_a)?;
+
+        let client_cons_state_path_on_b = ClientConsensusStatePath::new(
+            client_id_on_b.clone(),
+            msg.proof_height_on_a.revision_number(),
+            msg.proof_height_on_a.revision_height(),
+        );
+        let consensus_state_of_a_on_b =
+            client_val_ctx_b.consensus_state(&client_cons_state_path_on_b)?;
+        let prefix_on_a = conn_end_on_b.counterparty().prefix();
+        let port_id_on_a = &chan_end_on_b.counterparty().port_id;
+        let chan_id_on_a = chan_end_on_b
+            .counterparty()
+            .channel_id()
+            .ok_or(ChannelError::MissingCounterparty)?;
+        let conn_id_on_a = conn_end_on_b.counterparty().connection_id().ok_or(
+            ChannelError::UndefinedConnectionCounterparty {
+                connection_id: chan_end_on_b.connection_hops()[0].clone(),
+            },
+        )?;
+
+        // Chain A's channel end is flushing, or already done with it, with the
+        // current parameters.
+        let expected_chan_end_on_a = ChannelEnd::new(
+            msg.chan_state_on_a,
+            *chan_end_on_b.ordering(),
+            Counterparty::new(msg.port_id_on_b.clone(), Some(msg.chan_id_on_b.clone())),
+            vec![conn_id_on_a.clone()],
+            chan_end_on_b.version().clone(),
+        )?
+        .with_upgrade_sequence(chan_end_on_b.upgrade_sequence());
+        let chan_end_path_on_a = ChannelEndPath::new(port_id_on_a, chan_id_on_a);
+
+        ctx_b.gas_meter().charge(GasOperation::ProofVerification {
+            kind: ProofKind::Channel,
+            proof: &msg.proof_chan_end_on_a,
+        })?;
+        let path = Path::ChannelEnd(chan_end_path_on_a);
+        ctx_b
+            .metrics()
+            .observe_proof_verification(ProofKind::Channel, || {
+                client_state_of_a_on_b.verify_membership_with_context(
+                    client_val_ctx_b,
+                    prefix_on_a,
+                    &msg.proof_chan_end_on_a,
+                    consensus_state_of_a_on_b.root(),
+                    path.clone(),
+                    expected_chan_end_on_a.encode_vec_canonical(),
+                )
+            })
+            .map_err(|client_error| ChannelError::VerifyChannelFailed { path, client_error })?;
+
+        let upgrade_path_on_a = ChannelUpgradePath::new(port_id_on_a, chan_id_on_a);
+
+        ctx_b.gas_meter().charge(GasOperation::ProofVerification {
+            kind: ProofKind::ChannelUpgrade,
+            proof: &msg.proof_upgrade_on_a,
+        })?;
+        let path = Path::ChannelUpgrade(upgrade_path_on_a);
+        ctx_b
+            .metrics()
+            .observe_proof_verification(ProofKind::ChannelUpgrade, || {
+                client_state_of_a_on_b.verify_membership_with_context(
+                    client_val_ctx_b,
+                    prefix_on_a,
+                    &msg.proof_upgrade_on_a,
+                    consensus_state_of_a_on_b.root(),
+                    path.clone(),
+                    msg.upgrade_on_a.clone().encode_vec_canonical(),
+                )
+            })
+            .map_err(|client_error| ChannelError::VerifyChannelFailed { path, client_error })?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,255 @@
+//! Protocol logic specific to ICS4 messages of type `MsgChannelUpgradeOpen`.
+use ibc_core_channel_types::channel::{ChannelEnd, Counterparty, Order, State as ChannelState};
+use ibc_core_channel_types::error::ChannelError;
+use ibc_core_channel_types::events::UpgradeOpen;
+use ibc_core_channel_types::msgs::MsgChannelUpgradeOpen;
+use ibc_core_client::context::prelude::*;
+use ibc_core_connection::types::State as ConnectionState;
+use ibc_core_handler_types::error::ContextError;
+use ibc_core_handler_types::events::{IbcEvent, MessageEvent};
+use ibc_core_host::types::path::{
+    ChannelEndPath, ChannelUpgradePath, ClientConsensusStatePath, Path, SeqAckPath, SeqRecvPath,
+};
+use ibc_core_host::{ExecutionContext, GasOperation, ProofKind, ValidationContext};
+use ibc_core_router::module::Module;
+use ibc_primitives::prelude::*;
+use ibc_primitives::CanonicalProtobuf;
+
+use super::verify_upgrade_compatibility;
+
+pub fn chan_upgrade_open_validate<ValCtx>(
+    ctx_a: &ValCtx,
+    module: &dyn Module,
+    msg: MsgChannelUpgradeOpen,
+) -> Result<(), ContextError>
+where
+    ValCtx: ValidationContext,
+{
+    validate(ctx_a, &msg)?;
+
+    let upgrade_path_on_a = ChannelUpgradePath::new(&msg.port_id_on_a, &msg.chan_id_on_a);
+    let upgrade_on_a = ctx_a.channel_upgrade(&upgrade_path_on_a)?;
+
+    module.on_chan_upgrade_open_validate(
+        &msg.port_id_on_a,
+        &msg.chan_id_on_a,
+        upgrade_on_a.fields.ordering,
+        &upgrade_on_a.fields.connection_hops,
+        &upgrade_on_a.fields.version,
+    )?;
+
+    Ok(())
+}
+
+pub fn chan_upgrade_open_execute<ExecCtx>(
+    ctx_a: &mut ExecCtx,
+    module: &mut dyn Module,
+    msg: MsgChannelUpgradeOpen,
+) -> Result<(), ContextError>
+where
+    ExecCtx: ExecutionContext,
+{
+    let chan_end_path_on_a = ChannelEndPath::new(&msg.port_id_on_a, &msg.chan_id_on_a);
+    let chan_end_on_a = ctx_a.channel_end(&chan_end_path_on_a)?;
+    let upgrade_path_on_a = ChannelUpgradePath::new(&msg.port_id_on_a, &msg.chan_id_on_a);
+    let upgrade_on_a = ctx_a.channel_upgrade(&upgrade_path_on_a)?;
+    let upgrade_on_b = ctx_a.counterparty_channel_upgrade(&upgrade_path_on_a)?;
+
+    // state changes
+    let chan_end_on_a = {
+        let mut upgraded_chan_end_on_a = ChannelEnd::new(
+            ChannelState::Open,
+            upgrade_on_a.fields.ordering,
+            chan_end_on_a.counterparty().clone(),
+            upgrade_on_a.fields.connection_hops.clone(),
+            upgrade_on_a.fields.version.clone(),
+        )?;
+        upgraded_chan_end_on_a.set_upgrade_sequence(chan_end_on_a.upgrade_sequence());
+
+        // Ordered channels track the sequences of the packets which are
+        // received and acknowledged, which resume from where the flush ended.
+        if chan_end_on_a.ordering == Order::Unordered
+            && matches!(
+                upgrade_on_a.fields.ordering,
+                Order::Ordered | Order::OrderedAllowTimeout
+            )
+        {
+            let seq_recv_path_on_a = SeqRecvPath::new(&msg.port_id_on_a, &msg.chan_id_on_a);
+            ctx_a.gas_meter().charge(GasOperation::StoreWrite)?;
+            ctx_a.store_next_sequence_recv(&seq_recv_path_on_a, upgrade_on_b.next_sequence_send)?;
+
+            let seq_ack_path_on_a = SeqAckPath::new(&msg.port_id_on_a, &msg.chan_id_on_a);
+            ctx_a.gas_meter().charge(GasOperation::StoreWrite)?;
+            ctx_a.store_next_sequence_ack(&seq_ack_path_on_a, upgrade_on_a.next_sequence_send)?;
+        }
+
+        ctx_a.gas_meter().charge(GasOperation::StoreWrite)?;
+        ctx_a.store_channel(&chan_end_path_on_a, upgraded_chan_end_on_a.clone())?;
+        ctx_a.gas_meter().charge(GasOperation::StoreWrite)?;
+        ctx_a.delete_channel_upgrade(&upgrade_path_on_a)?;
+
+        upgraded_chan_end_on_a
+    };
+
+    let extras = module.on_chan_upgrade_open_execute(
+        &msg.port_id_on_a,
+        &msg.chan_id_on_a,
+        chan_end_on_a.ordering,
+        &chan_end_on_a.connection_hops,
+        &chan_end_on_a.version,
+    )?;
+
+    // emit events and logs
+    {
+        ctx_a.log_message("success: channel upgrade open".to_string())?;
+
+        let core_event = {
+            let port_id_on_b = chan_end_on_a.counterparty().port_id.clone();
+            let chan_id_on_b = chan_end_on_a.counterparty().channel_id.clone().ok_or(
+                ContextError::ChannelError(ChannelError::MissingCounterparty),
+            )?;
+
+            IbcEvent::UpgradeOpenChannel(UpgradeOpen::new(
+                msg.port_id_on_a.clone(),
+                msg.chan_id_on_a.clone(),
+                port_id_on_b,
+                chan_id_on_b,
+                chan_end_on_a.upgrade_sequence(),
+                upgrade_on_a.fields,
+            ))
+        };
+        ctx_a.emit_ibc_event(IbcEvent::Message(MessageEvent::Channel))?;
+        ctx_a.emit_ibc_event(core_event)?;
+
+        for module_event in extras.events {
+            ctx_a.emit_ibc_event(IbcEvent::Module(module_event))?;
+        }
+
+        for log_message in extras.log {
+            ctx_a.log_message(log_message)?;
+        }
+    }
+
+    Ok(())
+}
+
+fn validate<Ctx>(ctx_a: &Ctx, msg: &MsgChannelUpgradeOpen) -> Result<(), ContextError>
+where
+    Ctx: ValidationContext,
+{
+    ctx_a.validate_message_signer(&msg.signer)?;
+
+    let chan_end_path_on_a = ChannelEndPath::new(&msg.port_id_on_a, &msg.chan_id_on_a);
+    let chan_end_on_a = ctx_a.channel_end(&chan_end_path_on_a)?;
+
+    chan_end_on_a.verify_state_matches(&ChannelState::FlushComplete)?;
+
+    let upgrade_path_on_a = ChannelUpgradePath::new(&msg.port_id_on_a, &msg.chan_id_on_a);
+    let upgrade_on_a = ctx_a.channel_upgrade(&upgrade_path_on_a)?;
+    let upgrade_on_b = ctx_a.counterparty_channel_upgrade(&upgrade_path_on_a)?;
+
+    // Both ends must open with the same upgraded parameters.
+    verify_upgrade_compatibility(ctx_a, &upgrade_on_a.fields, &upgrade_on_b.fields)?;
+
+    chan_end_on_a.verify_connection_hops_length()?;
+
+    let conn_end_on_a = ctx_a.connection_end(&chan_end_on_a.connection_hops()[0])?;
+
+    conn_end_on_a.verify_state_matches(&ConnectionState::Open)?;
+
+    // Verify proofs
+    {
+        let client_id_on_a = conn_end_on_a.client_id();
+        let client_val_ctx_a = ctx_a.get_client_validation_context();
+        let client_state_of_b_on_a = client_val_ctx_a.client_state(client_id_on_a)?;
+
+        client_state_of_b_on_a
+            .status(ctx_a.get_client_validation_context(), client_id_on_a)?
+            .verify_is_active()?;
+        client_state_of_b_on_a.validate_proof_height(msg.proof_height_on_b)?;
+
+        let client_cons_state_path_on_a = ClientConsensusStatePath::new(
+            client_id_on_a.clone(),
+            msg.proof_height_on_b.revision_number(),
+            msg.proof_height_on_b.revision_height(),
+        );
+        let consensus_state_of_b_on_a =
+            client_val_ctx_a.consensus_state(&client_cons_state_path_on_a)?;
+        let prefix_on_b = conn_end_on_a.counterparty().prefix();
+        let port_id_on_b = &chan_end_on_a.counterparty().port_id;
+        let chan_id_on_b = chan_end_on_a
+            .counterparty()
+            .channel_id()
+            .ok_or(ChannelError::MissingCounterparty)?;
+        let counterparty =
+            Counterparty::new(msg.port_id_on_a.clone(), Some(msg.chan_id_on_a.clone()));
+
+        // Chain B's channel end is either done flushing with the current
+        // parameters, or already open with the upgraded ones.
+        let expected_chan_end_on_b = match msg.chan_state_on_b {
+            ChannelState::FlushComplete => {
+                let conn_id_on_b = conn_end_on_a.counterparty().connection_id().ok_or(
+                    ChannelError::UndefinedConnectionCounterparty {
+                        connection_id: chan_end_on_a.connection_hops()[0].clone(),
+                    },
+                )?;
+
+                ChannelEnd::new(
+                    ChannelState::FlushComplete,
+                    *chan_end_on_a.ordering(),
+                    counterparty,
+                    vec![conn_id_on_b.clone()],
+                    chan_end_on_a.version().clone(),
+                )?
+                .with_upgrade_sequence(chan_end_on_a.upgrade_sequence())
+            }
+            ChannelState::Open => {
+                if msg.upgrade_sequence_on_b < chan_end_on_a.upgrade_sequence() {
+                    return Err(ChannelError::StaleUpgradeSequence {
+                        upgrade_sequence: chan_end_on_a.upgrade_sequence(),
+                        counterparty_upgrade_sequence: msg.upgrade_sequence_on_b,
+                    }
+                    .into());
+                }
+
+                ChannelEnd::new(
+                    ChannelState::Open,
+                    upgrade_on_b.fields.ordering,
+                    counterparty,
+                    upgrade_on_b.fields.connection_hops,
+                    upgrade_on_b.fields.version,
+                )?
+                .with_upgrade_sequence(msg.upgrade_sequence_on_b)
+            }
+            state => {
+                return Err(ChannelError::InvalidState {
+                    expected: "Counterparty channel end must be FLUSHCOMPLETE or OPEN".to_string(),
+                    actual: state.to_string(),
+                }
+                .into())
+            }
+        };
+        let chan_end_path_on_b = ChannelEndPath::new(port_id_on_b, chan_id_on_b);
+
+        ctx_a.gas_meter().charge(GasOperation::ProofVerification {
+            kind: ProofKind::Channel,
+            proof: &msg.proof_chan_end_on_b,
+        })?;
+        let path = Path::ChannelEnd(chan_end_path_on_b);
+        ctx_a
+            .metrics()
+            .observe_proof_verification(ProofKind::Channel, || {
+                client_state_of_b_on_a.verify_membership_with_context(
+                    client_val_ctx_a,
+                    prefix_on_b,
+                    &msg.proof_chan_end_on_b,
+                    consensus_state_of_b_on_a.root(),
+                    path.clone(),
+                    expected_chan_end_on_b.encode_vec_canonical(),
+                )
+            })
+            .map_err(|client_error| ChannelError::VerifyChannelFailed { path, client_error })?;
+    }
+
+    Ok(())
+}
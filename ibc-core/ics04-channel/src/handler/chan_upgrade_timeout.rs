@@ -0,0 +1,190 @@
+//! Protocol logic specific to ICS4 messages of type `MsgChannelUpgradeTimeout`.
+use ibc_core_channel_types::channel::{Counterparty, State as ChannelState};
+use ibc_core_channel_types::error::ChannelError;
+use ibc_core_channel_types::events::{UpgradeError, UpgradeTimeout};
+use ibc_core_channel_types::msgs::MsgChannelUpgradeTimeout;
+use ibc_core_client::context::prelude::*;
+use ibc_core_connection::types::State as ConnectionState;
+use ibc_core_handler_types::error::ContextError;
+use ibc_core_handler_types::events::{IbcEvent, MessageEvent};
+use ibc_core_host::types::path::{
+    ChannelEndPath, ChannelUpgradePath, ClientConsensusStatePath, Path,
+};
+use ibc_core_host::{ExecutionContext, GasOperation, ProofKind, ValidationContext};
+use ibc_primitives::prelude::*;
+use ibc_primitives::CanonicalProtobuf;
+
+use super::abort_upgrade;
+
+const UPGRADE_TIMED_OUT_MESSAGE: &str = "upgrade timed out";
+
+pub fn chan_upgrade_timeout_validate<ValCtx>(
+    ctx_a: &ValCtx,
+    msg: MsgChannelUpgradeTimeout,
+) -> Result<(), ContextError>
+where
+    ValCtx: ValidationContext,
+{
+    validate(ctx_a, &msg)
+}
+
+pub fn chan_upgrade_timeout_execute<ExecCtx>(
+    ctx_a: &mut ExecCtx,
+    msg: MsgChannelUpgradeTimeout,
+) -> Result<(), ContextError>
+where
+    ExecCtx: ExecutionContext,
+{
+    let chan_end_path_on_a = ChannelEndPath::new(&msg.port_id_on_a, &msg.chan_id_on_a);
+    let upgrade_sequence = ctx_a.channel_end(&chan_end_path_on_a)?.upgrade_sequence();
+    let upgrade_path_on_a = ChannelUpgradePath::new(&msg.port_id_on_a, &msg.chan_id_on_a);
+    let upgrade_on_a = ctx_a.channel_upgrade(&upgrade_path_on_a)?;
+
+    // state changes
+    let chan_end_on_a = abort_upgrade(
+        ctx_a,
+        &msg.port_id_on_a,
+        &msg.chan_id_on_a,
+        upgrade_sequence,
+        UPGRADE_TIMED_OUT_MESSAGE.to_string(),
+    )?;
+
+    // emit events and logs
+    {
+        ctx_a.log_message("success: channel upgrade timeout".to_string())?;
+
+        let port_id_on_b = chan_end_on_a.counterparty().port_id.clone();
+        let chan_id_on_b = chan_end_on_a
+            .counterparty()
+            .channel_id
+            .clone()
+            .ok_or(ChannelError::MissingCounterparty)?;
+
+        let core_event = IbcEvent::UpgradeTimeoutChannel(UpgradeTimeout::new(
+            msg.port_id_on_a.clone(),
+            msg.chan_id_on_a.clone(),
+            port_id_on_b.clone(),
+            chan_id_on_b.clone(),
+            upgrade_sequence,
+            upgrade_on_a.timeout,
+        ));
+        let error_event = IbcEvent::UpgradeErrorChannel(UpgradeError::new(
+            msg.port_id_on_a.clone(),
+            msg.chan_id_on_a.clone(),
+            port_id_on_b,
+            chan_id_on_b,
+            upgrade_sequence,
+            UPGRADE_TIMED_OUT_MESSAGE.to_string(),
+        ));
+        ctx_a.emit_ibc_event(IbcEvent::Message(MessageEvent::Channel))?;
+        ctx_a.emit_ibc_event(core_event)?;
+        ctx_a.emit_ibc_event(error_event)?;
+    }
+
+    Ok(())
+}
+
+fn validate<Ctx>(ctx_a: &Ctx, msg: &MsgChannelUpgradeTimeout) -> Result<(), ContextError>
+where
+    Ctx: ValidationContext,
+{
+    ctx_a.validate_message_signer(&msg.signer)?;
+
+    let chan_end_path_on_a = ChannelEndPath::new(&msg.port_id_on_a, &msg.chan_id_on_a);
+    let chan_end_on_a = ctx_a.channel_end(&chan_end_path_on_a)?;
+
+    if !matches!(
+        chan_end_on_a.state,
+        ChannelState::Flushing | ChannelState::FlushComplete
+    ) {
+        return Err(ChannelError::InvalidState {
+            expected: "Channel state must be FLUSHING or FLUSHCOMPLETE".to_string(),
+            actual: chan_end_on_a.state.to_string(),
+        }
+        .into());
+    }
+
+    let upgrade_path_on_a = ChannelUpgradePath::new(&msg.port_id_on_a, &msg.chan_id_on_a);
+    let upgrade_on_a = ctx_a.channel_upgrade(&upgrade_path_on_a)?;
+
+    chan_end_on_a.verify_connection_hops_length()?;
+
+    let conn_end_on_a = ctx_a.connection_end(&chan_end_on_a.connection_hops()[0])?;
+
+    conn_end_on_a.verify_state_matches(&ConnectionState::Open)?;
+
+    msg.chan_end_on_b
+        .verify_counterparty_matches(&Counterparty::new(
+            msg.port_id_on_a.clone(),
+            Some(msg.chan_id_on_a.clone()),
+        ))?;
+
+    // Chain B must not have completed the upgrade before it timed out.
+    if msg.chan_end_on_b.state == ChannelState::Open
+        && msg.chan_end_on_b.upgrade_sequence() >= chan_end_on_a.upgrade_sequence()
+    {
+        return Err(ChannelError::IncompatibleUpgrade {
+            reason: "the counterparty channel end has already completed the upgrade".to_string(),
+        }
+        .into());
+    }
+
+    // Verify proofs
+    {
+        let client_id_on_a = conn_end_on_a.client_id();
+        let client_val_ctx_a = ctx_a.get_client_validation_context();
+        let client_state_of_b_on_a = client_val_ctx_a.client_state(client_id_on_a)?;
+
+        client_state_of_b_on_a
+            .status(ctx_a.get_client_validation_context(), client_id_on_a)?
+            .verify_is_active()?;
+        client_state_of_b_on_a.validate_proof_height(msg.proof_height_on_b)?;
+
+        let client_cons_state_path_on_a = ClientConsensusStatePath::new(
+            client_id_on_a.clone(),
+            msg.proof_height_on_b.revision_number(),
+            msg.proof_height_on_b.revision_height(),
+        );
+        let consensus_state_of_b_on_a =
+            client_val_ctx_a.consensus_state(&client_cons_state_path_on_a)?;
+
+        // The proof must be from a height at which the upgrade already timed
+        // out on chain B.
+        let timestamp_of_b = consensus_state_of_b_on_a.timestamp();
+        if !upgrade_on_a
+            .timeout
+            .has_expired(msg.proof_height_on_b, &timestamp_of_b)
+        {
+            return Err(ChannelError::UpgradeTimeoutNotReached.into());
+        }
+
+        let prefix_on_b = conn_end_on_a.counterparty().prefix();
+        let port_id_on_b = &chan_end_on_a.counterparty().port_id;
+        let chan_id_on_b = chan_end_on_a
+            .counterparty()
+            .channel_id()
+            .ok_or(ChannelError::MissingCounterparty)?;
+        let chan_end_path_on_b = ChannelEndPath::new(port_id_on_b, chan_id_on_b);
+
+        ctx_a.gas_meter().charge(GasOperation::ProofVerification {
+            kind: ProofKind::Channel,
+            proof: &msg.proof_chan_end_on_b,
+        })?;
+        let path = Path::ChannelEnd(chan_end_path_on_b);
+        ctx_a
+            .metrics()
+            .observe_proof_verification(ProofKind::Channel, || {
+                client_state_of_b_on_a.verify_membership_with_context(
+                    client_val_ctx_a,
+                    prefix_on_b,
+                    &msg.proof_chan_end_on_b,
+                    consensus_state_of_b_on_a.root(),
+                    path.clone(),
+                    msg.chan_end_on_b.encode_vec_canonical(),
+                )
+            })
+            .map_err(|client_error| ChannelError::VerifyChannelFailed { path, client_error })?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,297 @@
+//! Protocol logic specific to ICS4 messages of type `MsgChannelUpgradeTry`.
+use ibc_core_channel_types::channel::{ChannelEnd, Counterparty, State as ChannelState};
+use ibc_core_channel_types::error::ChannelError;
+use ibc_core_channel_types::events::UpgradeTry;
+use ibc_core_channel_types::msgs::MsgChannelUpgradeTry;
+use ibc_core_channel_types::timeout::TimeoutHeight;
+use ibc_core_channel_types::upgrade::{Upgrade, UpgradeFields, UpgradeTimeout};
+use ibc_core_client::context::prelude::*;
+use ibc_core_connection::types::State as ConnectionState;
+use ibc_core_handler_types::error::ContextError;
+use ibc_core_handler_types::events::{IbcEvent, MessageEvent};
+use ibc_core_host::types::path::{
+    ChannelEndPath, ChannelUpgradePath, ClientConsensusStatePath, Path, SeqSendPath,
+};
+use ibc_core_host::{ExecutionContext, GasOperation, ProofKind, ValidationContext};
+use ibc_core_router::module::Module;
+use ibc_primitives::prelude::*;
+use ibc_primitives::CanonicalProtobuf;
+
+pub fn chan_upgrade_try_validate<ValCtx>(
+    ctx_b: &ValCtx,
+    module: &dyn Module,
+    msg: MsgChannelUpgradeTry,
+) -> Result<(), ContextError>
+where
+    ValCtx: ValidationContext,
+{
+    validate(ctx_b, &msg)?;
+
+    module.on_chan_upgrade_try_validate(
+        &msg.port_id_on_b,
+        &msg.chan_id_on_b,
+        msg.upgrade_fields_on_a.ordering,
+        &msg.proposed_conn_hops_on_b,
+        &msg.upgrade_fields_on_a.version,
+    )?;
+
+    Ok(())
+}
+
+pub fn chan_upgrade_try_execute<ExecCtx>(
+    ctx_b: &mut ExecCtx,
+    module: &mut dyn Module,
+    msg: MsgChannelUpgradeTry,
+) -> Result<(), ContextError>
+where
+    ExecCtx: ExecutionContext,
+{
+    let (extras, version) = module.on_chan_upgrade_try_execute(
+        &msg.port_id_on_b,
+        &msg.chan_id_on_b,
+        msg.upgrade_fields_on_a.ordering,
+        &msg.proposed_conn_hops_on_b,
+        &msg.upgrade_fields_on_a.version,
+    )?;
+    let chan_end_path_on_b = ChannelEndPath::new(&msg.port_id_on_b, &msg.chan_id_on_b);
+    let chan_end_on_b = ctx_b.channel_end(&chan_end_path_on_b)?;
+
+    // In the crossing hellos, chain B keeps the upgrade it initiated, whose
+    // fields were validated to match the ones of chain A.
+    let upgrade_path_on_b = ChannelUpgradePath::new(&msg.port_id_on_b, &msg.chan_id_on_b);
+    let upgrade_fields = match ctx_b.channel_upgrade(&upgrade_path_on_b) {
+        Ok(upgrade_on_b) => upgrade_on_b.fields,
+        Err(_) => UpgradeFields::new(
+            msg.upgrade_fields_on_a.ordering,
+            msg.proposed_conn_hops_on_b.clone(),
+            version,
+        ),
+    };
+
+    // state changes
+    {
+        let chan_end_on_b = {
+            let mut chan_end_on_b = chan_end_on_b.clone();
+
+            chan_end_on_b.set_state(ChannelState::Flushing);
+            chan_end_on_b.set_upgrade_sequence(msg.upgrade_sequence_on_a);
+
+            chan_end_on_b
+        };
+        ctx_b.gas_meter().charge(GasOperation::StoreWrite)?;
+        ctx_b.store_channel(&chan_end_path_on_b, chan_end_on_b)?;
+
+        // Packets sent from now on are sent with the upgraded parameters, so
+        // the timeout and the next sequence to be sent mark the end of the
+        // flush.
+        let timeout_timestamp =
+            (ctx_b.host_timestamp()? + ctx_b.upgrade_timeout()).map_err(|e| {
+                ChannelError::InvalidUpgradeTimeout {
+                    reason: e.to_string(),
+                }
+            })?;
+        let seq_send_path_on_b = SeqSendPath::new(&msg.port_id_on_b, &msg.chan_id_on_b);
+        let upgrade_on_b = Upgrade {
+            fields: upgrade_fields.clone(),
+            timeout: UpgradeTimeout {
+                height: TimeoutHeight::Never,
+                timestamp: timeout_timestamp,
+            },
+            next_sequence_send: ctx_b.get_next_sequence_send(&seq_send_path_on_b)?,
+        };
+        ctx_b.gas_meter().charge(GasOperation::StoreWrite)?;
+        ctx_b.store_channel_upgrade(&upgrade_path_on_b, upgrade_on_b)?;
+    }
+
+    // emit events and logs
+    {
+        ctx_b.log_message("success: channel upgrade try".to_string())?;
+
+        let core_event = {
+            let port_id_on_a = chan_end_on_b.counterparty().port_id.clone();
+            let chan_id_on_a = chan_end_on_b.counterparty().channel_id.clone().ok_or(
+                ContextError::ChannelError(ChannelError::MissingCounterparty),
+            )?;
+
+            IbcEvent::UpgradeTryChannel(UpgradeTry::new(
+                msg.port_id_on_b.clone(),
+                msg.chan_id_on_b.clone(),
+                port_id_on_a,
+                chan_id_on_a,
+                msg.upgrade_sequence_on_a,
+                upgrade_fields,
+            ))
+        };
+        ctx_b.emit_ibc_event(IbcEvent::Message(MessageEvent::Channel))?;
+        ctx_b.emit_ibc_event(core_event)?;
+
+        for module_event in extras.events {
+            ctx_b.emit_ibc_event(IbcEvent::Module(module_event))?;
+        }
+
+        for log_message in extras.log {
+            ctx_b.log_message(log_message)?;
+        }
+    }
+
+    Ok(())
+}
+
+fn validate<Ctx>(ctx_b: &Ctx, msg: &MsgChannelUpgradeTry) -> Result<(), ContextError>
+where
+    Ctx: ValidationContext,
+{
+    ctx_b.validate_message_signer(&msg.signer)?;
+
+    let chan_end_path_on_b = ChannelEndPath::new(&msg.port_id_on_b, &msg.chan_id_on_b);
+    let chan_end_on_b = ctx_b.channel_end(&chan_end_path_on_b)?;
+
+    chan_end_on_b.verify_state_matches(&ChannelState::Open)?;
+
+    // The counterparty must be on a newer upgrade attempt, unless both ends
+    // initiated the same one.
+    let upgrade_path_on_b = ChannelUpgradePath::new(&msg.port_id_on_b, &msg.chan_id_on_b);
+    let upgrade_on_b = ctx_b.channel_upgrade(&upgrade_path_on_b).ok();
+    let is_sequence_valid = if upgrade_on_b.is_some() {
+        msg.upgrade_sequence_on_a >= chan_end_on_b.upgrade_sequence()
+    } else {
+        msg.upgrade_sequence_on_a > chan_end_on_b.upgrade_sequence()
+    };
+    if !is_sequence_valid {
+        return Err(ChannelError::StaleUpgradeSequence {
+            upgrade_sequence: chan_end_on_b.upgrade_sequence(),
+            counterparty_upgrade_sequence: msg.upgrade_sequence_on_a,
+        }
+        .into());
+    }
+
+    // In the crossing hellos, the upgrade chain B initiated must be the one
+    // chain A proposes, as ibc-go requires.
+    if let Some(upgrade_on_b) = upgrade_on_b {
+        let proposed_fields = UpgradeFields::new(
+            msg.upgrade_fields_on_a.ordering,
+            msg.proposed_conn_hops_on_b.clone(),
+            msg.upgrade_fields_on_a.version.clone(),
+        );
+
+        if upgrade_on_b.fields != proposed_fields {
+            return Err(ChannelError::IncompatibleUpgrade {
+                reason: "the upgrade of the channel end does not match the counterparty one"
+                    .to_string(),
+            }
+            .into());
+        }
+    }
+
+    msg.verify_connection_hops_length()?;
+
+    // The connection the channel is upgraded to must be open, and be the
+    // counterparty of the one proposed by chain A.
+    {
+        let proposed_conn_end_on_b = ctx_b.connection_end(&msg.proposed_conn_hops_on_b[0])?;
+
+        proposed_conn_end_on_b.verify_state_matches(&ConnectionState::Open)?;
+
+        if proposed_conn_end_on_b.counterparty().connection_id()
+            != Some(&msg.upgrade_fields_on_a.connection_hops[0])
+        {
+            return Err(ChannelError::IncompatibleUpgrade {
+                reason: "the proposed connection hops do not match the counterparty ones"
+                    .to_string(),
+            }
+            .into());
+        }
+    }
+
+    chan_end_on_b.verify_connection_hops_length()?;
+
+    let conn_end_on_b = ctx_b.connection_end(&chan_end_on_b.connection_hops()[0])?;
+
+    conn_end_on_b.verify_state_matches(&ConnectionState::Open)?;
+
+    // Verify proofs
+    {
+        let client_id_on_b = conn_end_on_b.client_id();
+        let client_val_ctx_b = ctx_b.get_client_validation_context();
+        let client_state_of_a_on_b = client_val_ctx_b.client_state(client_id_on_b)?;
+
+        client_state_of_a_on_b
+            .status(ctx_b.get_client_validation_context(), client_id_on_b)?
+            .verify_is_active()?;
+        client_state_of_a_on_b.validate_proof_height(msg.proof_height_on_a)?;
+
+        let client_cons_state_path_on_b = ClientConsensusStatePath::new(
+            client_id_on_b.clone(),
+            msg.proof_height_on_a.revision_number(),
+            msg.proof_height_on_a.revision_height(),
+        );
+        let consensus_state_of_a_on_b =
+            client_val_ctx_b.consensus_state(&client_cons_state_path_on_b)?;
+        let prefix_on_a = conn_end_on_b.counterparty().prefix();
+        let port_id_on_a = &chan_end_on_b.counterparty().port_id;
+        let chan_id_on_a = chan_end_on_b
+            .counterparty()
+            .channel_id()
+            .ok_or(ChannelError::MissingCounterparty)?;
+        let conn_id_on_a = conn_end_on_b.counterparty().connection_id().ok_or(
+            ChannelError::UndefinedConnectionCounterparty {
+                connection_id: chan_end_on_b.connection_hops()[0].clone(),
+            },
+        )?;
+
+        // Chain A's channel end is still open with the current parameters,
+        // but on the upgrade sequence of the proposed upgrade.
+        let expected_chan_end_on_a = ChannelEnd::new(
+            ChannelState::Open,
+            *chan_end_on_b.ordering(),
+            Counterparty::new(msg.port_id_on_b.clone(), Some(msg.chan_id_on_b.clone())),
+            vec![conn_id_on_a.clone()],
+            chan_end_on_b.version().clone(),
+        )?
+        .with_upgrade_sequence(msg.upgrade_sequence_on_a);
+        let chan_end_path_on_a = ChannelEndPath::new(port_id_on_a, chan_id_on_a);
+
+        ctx_b.gas_meter().charge(GasOperation::ProofVerification {
+            kind: ProofKind::Channel,
+            proof: &msg.proof_chan_end_on_a,
+        })?;
+        let path = Path::ChannelEnd(chan_end_path_on_a);
+        ctx_b
+            .metrics()
+            .observe_proof_verification(ProofKind::Channel, || {
+                client_state_of_a_on_b.verify_membership_with_context(
+                    client_val_ctx_b,
+                    prefix_on_a,
+                    &msg.proof_chan_end_on_a,
+                    consensus_state_of_a_on_b.root(),
+                    path.clone(),
+                    expected_chan_end_on_a.encode_vec_canonical(),
+                )
+            })
+            .map_err(|client_error| ChannelError::VerifyChannelFailed { path, client_error })?;
+
+        let expected_upgrade_on_a = Upgrade::new(msg.upgrade_fields_on_a.clone());
+        let upgrade_path_on_a = ChannelUpgradePath::new(port_id_on_a, chan_id_on_a);
+
+        ctx_b.gas_meter().charge(GasOperation::ProofVerification {
+            kind: ProofKind::ChannelUpgrade,
+            proof: &msg.proof_upgrade_on_a,
+        })?;
+        let path = Path::ChannelUpgrade(upgrade_path_on_a);
+        ctx_b
+            .metrics()
+            .observe_proof_verification(ProofKind::ChannelUpgrade, || {
+                client_state_of_a_on_b.verify_membership_with_context(
+                    client_val_ctx_b,
+                    prefix_on_a,
+                    &msg.proof_upgrade_on_a,
+                    consensus_state_of_a_on_b.root(),
+                    path.clone(),
+                    expected_upgrade_on_a.encode_vec_canonical(),
+                )
+            })
+            .map_err(|client_error| ChannelError::VerifyChannelFailed { path, client_error })?;
+    }
+
+    Ok(())
+}
@@ -1,4 +1,14 @@
 //! This module implements the processing logic for ICS4 (channel) messages.
+use ibc_core_channel_types::channel::{ChannelEnd, State as ChannelState};
+use ibc_core_channel_types::error::ChannelError;
+use ibc_core_channel_types::upgrade::{ErrorReceipt, UpgradeFields};
+use ibc_core_connection::types::State as ConnectionState;
+use ibc_core_handler_types::error::ContextError;
+use ibc_core_host::types::identifiers::{ChannelId, PortId};
+use ibc_core_host::types::path::{ChannelEndPath, ChannelUpgradeErrorPath, ChannelUpgradePath};
+use ibc_core_host::{ExecutionContext, GasOperation, ValidationContext};
+use ibc_primitives::prelude::*;
+
 mod acknowledgement;
 mod chan_close_confirm;
 mod chan_close_init;
@@ -6,10 +16,19 @@ mod chan_open_ack;
 mod chan_open_confirm;
 mod chan_open_init;
 mod chan_open_try;
+mod chan_upgrade_ack;
+mod chan_upgrade_cancel;
+mod chan_upgrade_confirm;
+mod chan_upgrade_init;
+mod chan_upgrade_open;
+mod chan_upgrade_timeout;
+mod chan_upgrade_try;
+mod packet_proofs;
 mod recv_packet;
 mod send_packet;
 mod timeout;
 mod timeout_on_close;
+mod write_acknowledgement;
 
 pub use acknowledgement::*;
 pub use chan_close_confirm::*;
@@ -18,7 +37,94 @@ pub use chan_open_ack::*;
 pub use chan_open_confirm::*;
 pub use chan_open_init::*;
 pub use chan_open_try::*;
+pub use chan_upgrade_ack::*;
+pub use chan_upgrade_cancel::*;
+pub use chan_upgrade_confirm::*;
+pub use chan_upgrade_init::*;
+pub use chan_upgrade_open::*;
+pub use chan_upgrade_timeout::*;
+pub use chan_upgrade_try::*;
+pub use packet_proofs::{packet_proof_checks, verify_proof_check};
 pub use recv_packet::*;
 pub use send_packet::*;
 pub use timeout::*;
 pub use timeout_on_close::*;
+pub use write_acknowledgement::*;
+
+/// Aborts the ongoing upgrade of the channel end: restores it to `OPEN` with
+/// its current parameters, deletes the upgrade and writes the error receipt
+/// which lets the counterparty cancel its own side of the upgrade.
+fn abort_upgrade<ExecCtx>(
+    ctx: &mut ExecCtx,
+    port_id: &PortId,
+    chan_id: &ChannelId,
+    upgrade_sequence: u64,
+    message: String,
+) -> Result<ChannelEnd, ContextError>
+where
+    ExecCtx: ExecutionContext,
+{
+    let chan_end_path = ChannelEndPath::new(port_id, chan_id);
+    let chan_end = {
+        let mut chan_end = ctx.channel_end(&chan_end_path)?;
+
+        chan_end.set_state(ChannelState::Open);
+        chan_end.set_upgrade_sequence(upgrade_sequence);
+
+        chan_end
+    };
+    ctx.gas_meter().charge(GasOperation::StoreWrite)?;
+    ctx.store_channel(&chan_end_path, chan_end.clone())?;
+
+    let upgrade_path = ChannelUpgradePath::new(port_id, chan_id);
+    ctx.gas_meter().charge(GasOperation::StoreWrite)?;
+    ctx.delete_channel_upgrade(&upgrade_path)?;
+
+    let upgrade_error_path = ChannelUpgradeErrorPath::new(port_id, chan_id);
+    ctx.gas_meter().charge(GasOperation::StoreWrite)?;
+    ctx.store_channel_upgrade_error_receipt(
+        &upgrade_error_path,
+        ErrorReceipt::new(upgrade_sequence, message),
+    )?;
+
+    Ok(chan_end)
+}
+
+/// Verifies that the upgrade of the counterparty is the one of the channel
+/// end: that both upgrade to the same ordering and version, over connections
+/// which are the counterparties of each other.
+fn verify_upgrade_compatibility<Ctx>(
+    ctx: &Ctx,
+    fields: &UpgradeFields,
+    counterparty_fields: &UpgradeFields,
+) -> Result<(), ContextError>
+where
+    Ctx: ValidationContext,
+{
+    if fields.ordering != counterparty_fields.ordering {
+        return Err(ChannelError::IncompatibleUpgrade {
+            reason: "the counterparty upgrade ordering does not match".to_string(),
+        }
+        .into());
+    }
+
+    if fields.version != counterparty_fields.version {
+        return Err(ChannelError::IncompatibleUpgrade {
+            reason: "the counterparty upgrade version does not match".to_string(),
+        }
+        .into());
+    }
+
+    let conn_end = ctx.connection_end(&fields.connection_hops[0])?;
+
+    conn_end.verify_state_matches(&ConnectionState::Open)?;
+
+    if conn_end.counterparty().connection_id() != counterparty_fields.connection_hops.first() {
+        return Err(ChannelError::IncompatibleUpgrade {
+            reason: "the counterparty upgrade connection hops do not match".to_string(),
+        }
+        .into());
+    }
+
+    Ok(())
+}
@@ -0,0 +1,371 @@
+//! Implements the verification of the proofs of the packet messages, which the
+//! parallel dispatch entrypoint runs ahead of the messages of a batch.
+
+use ibc_core_channel_types::channel::{ChannelEnd, Order};
+use ibc_core_channel_types::commitment::{compute_ack_commitment, compute_packet_commitment};
+use ibc_core_channel_types::msgs::{
+    MsgAcknowledgement, MsgRecvPacket, MsgTimeout, MsgTimeoutOnClose, PacketMsg,
+};
+use ibc_core_channel_types::packet::{Packet, Receipt};
+use ibc_core_client::context::prelude::*;
+use ibc_core_client::types::error::ClientError;
+use ibc_core_client::types::Height;
+use ibc_core_commitment_types::commitment::{
+    CommitmentPrefix, CommitmentProofBytes, CommitmentRoot,
+};
+use ibc_core_connection::types::ConnectionEnd;
+use ibc_core_handler_types::error::ContextError;
+use ibc_core_host::types::identifiers::{ChannelId, ClientId, PortId, Sequence};
+use ibc_core_host::types::path::{
+    AckPath, ChannelEndPath, ClientConsensusStatePath, CommitmentPath, Path, ReceiptPath,
+    SeqRecvPath,
+};
+use ibc_core_host::{ProofCheck, ProofKind, ValidationContext};
+use ibc_primitives::prelude::*;
+use ibc_primitives::CanonicalProtobuf;
+
+use super::timeout_on_close::expected_chan_end_on_b;
+
+/// Verifies the proof of the value at the path on the counterparty chain, or
+/// of its absence if `value` is `None`, unless the proof is among the
+/// [`ValidationContext::verified_proofs`] of the batch being dispatched.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn verify_packet_proof<Ctx, CS>(
+    ctx: &Ctx,
+    client_state: &CS,
+    client_id: &ClientId,
+    kind: ProofKind,
+    prefix: &CommitmentPrefix,
+    proof: &CommitmentProofBytes,
+    root: &CommitmentRoot,
+    path: Path,
+    value: Option<Vec<u8>>,
+) -> Result<(), ClientError>
+where
+    Ctx: ValidationContext,
+    CS: ClientStateValidation<Ctx::V>,
+{
+    if let Some(verified_proofs) = ctx.verified_proofs() {
+        let check = ProofCheck {
+            kind,
+            client_id: client_id.clone(),
+            prefix: prefix.clone(),
+            root: root.clone(),
+            path: path.clone(),
+            proof: proof.clone(),
+            value: value.clone(),
+        };
+
+        if verified_proofs.contains(&check) {
+            return Ok(());
+        }
+    }
+
+    ctx.metrics().observe_proof_verification(kind, || {
+        verify(
+            client_state,
+            ctx.get_client_validation_context(),
+            prefix,
+            proof,
+            root,
+            path,
+            value,
+        )
+    })
+}
+
+/// Verifies the proof of the check against the client it names.
+///
+/// Only the proof itself is verified: whether the client is active, the proof
+/// height is valid and the delay period of the connection has passed is
+/// left to the handlers of the messages.
+pub fn verify_proof_check<Ctx>(ctx: &Ctx, check: &ProofCheck) -> Result<(), ContextError>
+where
+    Ctx: ValidationContext,
+{
+    let client_val_ctx = ctx.get_client_validation_context();
+    let client_state = client_val_ctx.client_state(&check.client_id)?;
+
+    verify(
+        &client_state,
+        client_val_ctx,
+        &check.prefix,
+        &check.proof,
+        &check.root,
+        check.path.clone(),
+        check.value.clone(),
+    )?;
+
+    Ok(())
+}
+
+/// Returns the proofs the handler of the packet message verifies, as looked up
+/// against the current state of the host.
+///
+/// The messages whose channel, connection or consensus state cannot be found,
+/// and which are thus bound to fail, have no proof to verify.
+pub fn packet_proof_checks<Ctx>(ctx: &Ctx, msg: &PacketMsg) -> Vec<ProofCheck>
+where
+    Ctx: ValidationContext,
+{
+    let checks = match msg {
+        PacketMsg::Recv(msg) => recv_packet_proof_checks(ctx, msg),
+        PacketMsg::Ack(msg) => acknowledgement_proof_checks(ctx, msg),
+        PacketMsg::Timeout(msg) => timeout_proof_checks(ctx, msg),
+        PacketMsg::TimeoutOnClose(msg) => timeout_on_close_proof_checks(ctx, msg),
+    };
+
+    checks.unwrap_or_default()
+}
+
+fn recv_packet_proof_checks<Ctx>(
+    ctx_b: &Ctx,
+    msg: &MsgRecvPacket,
+) -> Result<Vec<ProofCheck>, ContextError>
+where
+    Ctx: ValidationContext,
+{
+    let packet = &msg.packet;
+    let (_, conn_end_on_b) =
+        channel_and_connection(ctx_b, &packet.port_id_on_b, &packet.chan_id_on_b)?;
+    let builder = CheckBuilder::new(ctx_b, &conn_end_on_b, msg.proof_height_on_a)?;
+
+    Ok(vec![builder.check(
+        ProofKind::PacketCommitment,
+        Path::Commitment(CommitmentPath::new(
+            &packet.port_id_on_a,
+            &packet.chan_id_on_a,
+            packet.seq_on_a,
+        )),
+        &msg.proof_commitment_on_a,
+        Some(expected_packet_commitment(packet)),
+    )])
+}
+
+fn acknowledgement_proof_checks<Ctx>(
+    ctx_a: &Ctx,
+    msg: &MsgAcknowledgement,
+) -> Result<Vec<ProofCheck>, ContextError>
+where
+    Ctx: ValidationContext,
+{
+    let packet = &msg.packet;
+    let (_, conn_end_on_a) =
+        channel_and_connection(ctx_a, &packet.port_id_on_a, &packet.chan_id_on_a)?;
+    let builder = CheckBuilder::new(ctx_a, &conn_end_on_a, msg.proof_height_on_b)?;
+
+    Ok(vec![builder.check(
+        ProofKind::PacketAcknowledgement,
+        Path::Ack(AckPath::new(
+            &packet.port_id_on_b,
+            &packet.chan_id_on_b,
+            packet.seq_on_a,
+        )),
+        &msg.proof_acked_on_b,
+        Some(compute_ack_commitment(&msg.acknowledgement).into_vec()),
+    )])
+}
+
+fn timeout_proof_checks<Ctx>(ctx_a: &Ctx, msg: &MsgTimeout) -> Result<Vec<ProofCheck>, ContextError>
+where
+    Ctx: ValidationContext,
+{
+    let packet = &msg.packet;
+    let (chan_end_on_a, conn_end_on_a) =
+        channel_and_connection(ctx_a, &packet.port_id_on_a, &packet.chan_id_on_a)?;
+    let builder = CheckBuilder::new(ctx_a, &conn_end_on_a, msg.proof_height_on_b)?;
+
+    let check = match chan_end_on_a.ordering {
+        Order::Ordered => next_seq_recv_check(
+            &builder,
+            packet,
+            msg.next_seq_recv_on_b,
+            &msg.proof_unreceived_on_b,
+        ),
+        Order::OrderedAllowTimeout => builder.check(
+            ProofKind::PacketReceipt,
+            receipt_path(packet),
+            &msg.proof_unreceived_on_b,
+            Some(Receipt::Timeout.to_vec()),
+        ),
+        Order::Unordered => builder.check(
+            ProofKind::PacketReceipt,
+            receipt_path(packet),
+            &msg.proof_unreceived_on_b,
+            None,
+        ),
+        Order::None => return Ok(Vec::new()),
+    };
+
+    Ok(vec![check])
+}
+
+fn timeout_on_close_proof_checks<Ctx>(
+    ctx_a: &Ctx,
+    msg: &MsgTimeoutOnClose,
+) -> Result<Vec<ProofCheck>, ContextError>
+where
+    Ctx: ValidationContext,
+{
+    let packet = &msg.packet;
+    let (chan_end_on_a, conn_end_on_a) =
+        channel_and_connection(ctx_a, &packet.port_id_on_a, &packet.chan_id_on_a)?;
+    let builder = CheckBuilder::new(ctx_a, &conn_end_on_a, msg.proof_height_on_b)?;
+
+    let (chan_end_path_on_b, expected_chan_end_on_b) =
+        expected_chan_end_on_b(&chan_end_on_a, &conn_end_on_a, msg)?;
+    let mut checks = vec![builder.check(
+        ProofKind::Channel,
+        Path::ChannelEnd(chan_end_path_on_b),
+        &msg.proof_close_on_b,
+        Some(expected_chan_end_on_b.encode_vec_canonical()),
+    )];
+
+    match chan_end_on_a.ordering {
+        Order::Ordered | Order::OrderedAllowTimeout => checks.push(next_seq_recv_check(
+            &builder,
+            packet,
+            msg.next_seq_recv_on_b,
+            &msg.proof_unreceived_on_b,
+        )),
+        Order::Unordered => checks.push(builder.check(
+            ProofKind::PacketReceipt,
+            receipt_path(packet),
+            &msg.proof_unreceived_on_b,
+            None,
+        )),
+        Order::None => {}
+    }
+
+    Ok(checks)
+}
+
+/// Builds the checks of the proofs of a message against the consensus state
+/// of the counterparty at the proof height.
+struct CheckBuilder<'a> {
+    client_id: &'a ClientId,
+    prefix: &'a CommitmentPrefix,
+    root: CommitmentRoot,
+}
+
+impl<'a> CheckBuilder<'a> {
+    fn new<Ctx>(
+        ctx: &Ctx,
+        conn_end: &'a ConnectionEnd,
+        proof_height: Height,
+    ) -> Result<Self, ContextError>
+    where
+        Ctx: ValidationContext,
+    {
+        let client_id = conn_end.client_id();
+        let client_cons_state_path = ClientConsensusStatePath::new(
+            client_id.clone(),
+            proof_height.revision_number(),
+            proof_height.revision_height(),
+        );
+        let consensus_state = ctx
+            .get_client_validation_context()
+            .consensus_state(&client_cons_state_path)?;
+
+        Ok(Self {
+            client_id,
+            prefix: conn_end.counterparty().prefix(),
+            root: consensus_state.root().clone(),
+        })
+    }
+
+    fn check(
+        &self,
+        kind: ProofKind,
+        path: Path,
+        proof: &CommitmentProofBytes,
+        value: Option<Vec<u8>>,
+    ) -> ProofCheck {
+        ProofCheck {
+            kind,
+            client_id: self.client_id.clone(),
+            prefix: self.prefix.clone(),
+            root: self.root.clone(),
+            path,
+            proof: proof.clone(),
+            value,
+        }
+    }
+}
+
+fn channel_and_connection<Ctx>(
+    ctx: &Ctx,
+    port_id: &PortId,
+    chan_id: &ChannelId,
+) -> Result<(ChannelEnd, ConnectionEnd), ContextError>
+where
+    Ctx: ValidationContext,
+{
+    let chan_end = ctx.channel_end(&ChannelEndPath::new(port_id, chan_id))?;
+    let conn_end = ctx.connection_end(&chan_end.connection_hops()[0])?;
+
+    Ok((chan_end, conn_end))
+}
+
+fn next_seq_recv_check(
+    builder: &CheckBuilder<'_>,
+    packet: &Packet,
+    next_seq_recv_on_b: Sequence,
+    proof: &CommitmentProofBytes,
+) -> ProofCheck {
+    builder.check(
+        ProofKind::NextSequenceRecv,
+        Path::SeqRecv(SeqRecvPath::new(&packet.port_id_on_b, &packet.chan_id_on_b)),
+        proof,
+        Some(next_seq_recv_on_b.to_vec()),
+    )
+}
+
+fn receipt_path(packet: &Packet) -> Path {
+    Path::Receipt(ReceiptPath::new(
+        &packet.port_id_on_b,
+        &packet.chan_id_on_b,
+        packet.seq_on_a,
+    ))
+}
+
+fn expected_packet_commitment(packet: &Packet) -> Vec<u8> {
+    compute_packet_commitment(
+        &packet.data,
+        &packet.timeout_height_on_b,
+        &packet.timeout_timestamp_on_b,
+    )
+    .into_vec()
+}
+
+fn verify<V, CS>(
+    client_state: &CS,
+    client_val_ctx: &V,
+    prefix: &CommitmentPrefix,
+    proof: &CommitmentProofBytes,
+    root: &CommitmentRoot,
+    path: Path,
+    value: Option<Vec<u8>>,
+) -> Result<(), ClientError>
+where
+    V: ClientValidationContext,
+    CS: ClientStateValidation<V>,
+{
+    match value {
+        Some(value) => client_state.verify_membership_with_context(
+            client_val_ctx,
+            prefix,
+            proof,
+            root,
+            path,
+            value,
+        ),
+        None => client_state.verify_non_membership_with_context(
+            client_val_ctx,
+            prefix,
+            proof,
+            root,
+            path,
+        ),
+    }
+}
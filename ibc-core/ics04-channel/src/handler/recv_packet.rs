@@ -10,25 +10,76 @@ use ibc_core_connection::types::State as ConnectionState;
 use ibc_core_handler_types::error::ContextError;
 use ibc_core_handler_types::events::{IbcEvent, MessageEvent};
 use ibc_core_host::types::path::{
-    AckPath, ChannelEndPath, ClientConsensusStatePath, CommitmentPath, Path, ReceiptPath,
-    SeqRecvPath,
+    AckPath, ChannelEndPath, ChannelUpgradePath, ClientConsensusStatePath, CommitmentPath, Path,
+    ReceiptPath, SeqRecvPath,
 };
-use ibc_core_host::{ExecutionContext, ValidationContext};
+use ibc_core_host::{ExecutionContext, GasOperation, ProofKind, ValidationContext};
 use ibc_core_router::module::Module;
 use ibc_primitives::prelude::*;
 use ibc_primitives::Expiry;
 
+use super::packet_proofs::verify_packet_proof;
+
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(
+        level = "debug",
+        skip_all,
+        fields(
+            port_id = %msg.packet.port_id_on_b,
+            channel_id = %msg.packet.chan_id_on_b,
+            sequence = %msg.packet.seq_on_a,
+        ),
+    )
+)]
 pub fn recv_packet_validate<ValCtx>(ctx_b: &ValCtx, msg: MsgRecvPacket) -> Result<(), ContextError>
 where
     ValCtx: ValidationContext,
 {
     // Note: this contains the validation for `write_acknowledgement` as well.
-    validate(ctx_b, &msg)
+    validate(ctx_b, core::slice::from_ref(&msg))
 
     // nothing to validate with the module, since `onRecvPacket` cannot fail.
     // If any error occurs, then an "error acknowledgement" must be returned.
 }
 
+/// Validates a batch of `MsgRecvPacket`s, as if each of them were validated
+/// then executed in turn.
+///
+/// The packets of a batch must be received over the same channel, and proven
+/// at the same height, so that the channel, the connection, the client and
+/// the consensus state the proofs are verified against are only looked up and
+/// checked once for the whole batch.
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(
+        level = "debug",
+        skip_all,
+        fields(batch_size = msgs.len()),
+    )
+)]
+pub fn recv_packet_batch_validate<ValCtx>(
+    ctx_b: &ValCtx,
+    msgs: &[MsgRecvPacket],
+) -> Result<(), ContextError>
+where
+    ValCtx: ValidationContext,
+{
+    validate(ctx_b, msgs)
+}
+
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(
+        level = "debug",
+        skip_all,
+        fields(
+            port_id = %msg.packet.port_id_on_b,
+            channel_id = %msg.packet.chan_id_on_b,
+            sequence = %msg.packet.seq_on_a,
+        ),
+    )
+)]
 pub fn recv_packet_execute<ExecCtx>(
     ctx_b: &mut ExecCtx,
     module: &mut dyn Module,
@@ -53,7 +104,7 @@ where
                     ReceiptPath::new(&packet.port_id_on_b, &packet.chan_id_on_b, packet.seq_on_a);
                 ctx_b.get_packet_receipt(&receipt_path_on_b).is_ok()
             }
-            Order::Ordered => {
+            Order::Ordered | Order::OrderedAllowTimeout => {
                 let seq_recv_path_on_b =
                     SeqRecvPath::new(&msg.packet.port_id_on_b, &msg.packet.chan_id_on_b);
                 let next_seq_recv = ctx_b.get_next_sequence_recv(&seq_recv_path_on_b)?;
@@ -69,6 +120,32 @@ where
         }
     }
 
+    // A packet which timed out over a channel allowing timeouts is not
+    // delivered to the module: its sequence is skipped, and a timeout receipt
+    // is written for chain A to prove the timeout against.
+    if chan_end_on_b.ordering == Order::OrderedAllowTimeout
+        && msg
+            .packet
+            .timed_out(&ctx_b.host_timestamp()?, ctx_b.host_height()?)
+    {
+        let seq_recv_path_on_b =
+            SeqRecvPath::new(&msg.packet.port_id_on_b, &msg.packet.chan_id_on_b);
+        ctx_b.gas_meter().charge(GasOperation::StoreWrite)?;
+        ctx_b.store_next_sequence_recv(&seq_recv_path_on_b, msg.packet.seq_on_a.increment())?;
+
+        let receipt_path_on_b = ReceiptPath::new(
+            &msg.packet.port_id_on_b,
+            &msg.packet.chan_id_on_b,
+            msg.packet.seq_on_a,
+        );
+        ctx_b.gas_meter().charge(GasOperation::StoreWrite)?;
+        ctx_b.store_packet_receipt(&receipt_path_on_b, Receipt::Timeout)?;
+
+        ctx_b.log_message("success: packet timeout receipt".to_string())?;
+
+        return Ok(());
+    }
+
     let (extras, acknowledgement) = module.on_recv_packet_execute(&msg.packet, &msg.signer);
 
     // state changes
@@ -82,49 +159,69 @@ where
                     sequence: msg.packet.seq_on_a,
                 };
 
+                ctx_b.gas_meter().charge(GasOperation::StoreWrite)?;
                 ctx_b.store_packet_receipt(&receipt_path_on_b, Receipt::Ok)?;
             }
-            Order::Ordered => {
+            Order::Ordered | Order::OrderedAllowTimeout => {
                 let seq_recv_path_on_b =
                     SeqRecvPath::new(&msg.packet.port_id_on_b, &msg.packet.chan_id_on_b);
                 let next_seq_recv = ctx_b.get_next_sequence_recv(&seq_recv_path_on_b)?;
+                ctx_b.gas_meter().charge(GasOperation::StoreWrite)?;
                 ctx_b.store_next_sequence_recv(&seq_recv_path_on_b, next_seq_recv.increment())?;
             }
             _ => {}
         }
-        let ack_path_on_b = AckPath::new(
+        // Asynchronous acknowledgements are written later on by the module,
+        // with `write_acknowledgement`.
+        if let Some(acknowledgement) = &acknowledgement {
+            let ack_path_on_b = AckPath::new(
+                &msg.packet.port_id_on_b,
+                &msg.packet.chan_id_on_b,
+                msg.packet.seq_on_a,
+            );
+            // `writeAcknowledgement` handler state changes
+            ctx_b.gas_meter().charge(GasOperation::StoreWrite)?;
+            ctx_b.store_packet_acknowledgement(
+                &ack_path_on_b,
+                compute_ack_commitment(acknowledgement),
+            )?;
+        }
+    }
+
+    // emit events and logs
+    {
+        ctx_b.metrics().packet_received(
             &msg.packet.port_id_on_b,
             &msg.packet.chan_id_on_b,
             msg.packet.seq_on_a,
         );
-        // `writeAcknowledgement` handler state changes
-        ctx_b.store_packet_acknowledgement(
-            &ack_path_on_b,
-            compute_ack_commitment(&acknowledgement),
-        )?;
-    }
 
-    // emit events and logs
-    {
         ctx_b.log_message("success: packet receive".to_string())?;
-        ctx_b.log_message("success: packet write acknowledgement".to_string())?;
 
         let conn_id_on_b = &chan_end_on_b.connection_hops()[0];
-        let event = IbcEvent::ReceivePacket(ReceivePacket::new(
-            msg.packet.clone(),
-            chan_end_on_b.ordering,
-            conn_id_on_b.clone(),
-        ));
-        ctx_b.emit_ibc_event(IbcEvent::Message(MessageEvent::Channel))?;
-        ctx_b.emit_ibc_event(event)?;
-        let event = IbcEvent::WriteAcknowledgement(WriteAcknowledgement::new(
-            msg.packet,
-            acknowledgement,
-            conn_id_on_b.clone(),
-        ));
+        let event = IbcEvent::ReceivePacket(
+            ReceivePacket::new(
+                msg.packet.clone(),
+                chan_end_on_b.ordering,
+                conn_id_on_b.clone(),
+            )
+            .with_packet_data_mode(ctx_b.packet_data_event_mode()),
+        );
         ctx_b.emit_ibc_event(IbcEvent::Message(MessageEvent::Channel))?;
         ctx_b.emit_ibc_event(event)?;
 
+        if let Some(acknowledgement) = acknowledgement {
+            ctx_b.log_message("success: packet write acknowledgement".to_string())?;
+
+            let event = IbcEvent::WriteAcknowledgement(WriteAcknowledgement::new(
+                msg.packet,
+                acknowledgement,
+                conn_id_on_b.clone(),
+            ));
+            ctx_b.emit_ibc_event(IbcEvent::Message(MessageEvent::Channel))?;
+            ctx_b.emit_ibc_event(event)?;
+        }
+
         for module_event in extras.events {
             ctx_b.emit_ibc_event(IbcEvent::Module(module_event))?;
         }
@@ -137,24 +234,114 @@ where
     Ok(())
 }
 
-fn validate<Ctx>(ctx_b: &Ctx, msg: &MsgRecvPacket) -> Result<(), ContextError>
+/// Executes a batch of `MsgRecvPacket`s, in order. A prior call to
+/// [`recv_packet_batch_validate`] MUST have succeeded.
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(
+        level = "debug",
+        skip_all,
+        fields(batch_size = msgs.len()),
+    )
+)]
+pub fn recv_packet_batch_execute<ExecCtx>(
+    ctx_b: &mut ExecCtx,
+    module: &mut dyn Module,
+    msgs: Vec<MsgRecvPacket>,
+) -> Result<(), ContextError>
+where
+    ExecCtx: ExecutionContext,
+{
+    for msg in msgs {
+        recv_packet_execute(ctx_b, module, msg)?;
+    }
+
+    Ok(())
+}
+
+fn validate<Ctx>(ctx_b: &Ctx, msgs: &[MsgRecvPacket]) -> Result<(), ContextError>
 where
     Ctx: ValidationContext,
 {
-    ctx_b.validate_message_signer(&msg.signer)?;
+    let first_msg = msgs.first().ok_or(PacketError::InvalidPacketBatch {
+        reason: "the batch is empty".to_string(),
+    })?;
 
-    let chan_end_path_on_b =
-        ChannelEndPath::new(&msg.packet.port_id_on_b, &msg.packet.chan_id_on_b);
+    let max_packet_data_size = ctx_b.max_packet_data_size();
+
+    for msg in msgs {
+        ctx_b.validate_message_signer(&msg.signer)?;
+
+        if let Some(max_size) = max_packet_data_size {
+            if msg.packet.data.len() > max_size {
+                return Err(PacketError::PacketDataTooLarge {
+                    size: msg.packet.data.len(),
+                    max_size,
+                }
+                .into());
+            }
+        }
+
+        if msg.packet.port_id_on_b != first_msg.packet.port_id_on_b
+            || msg.packet.chan_id_on_b != first_msg.packet.chan_id_on_b
+        {
+            return Err(PacketError::InvalidPacketBatch {
+                reason: "all packets must be received over the same channel".to_string(),
+            }
+            .into());
+        }
+
+        if msg.proof_height_on_a != first_msg.proof_height_on_a {
+            return Err(PacketError::InvalidPacketBatch {
+                reason: "all packets must be proven at the same height".to_string(),
+            }
+            .into());
+        }
+    }
+
+    let port_id_on_b = &first_msg.packet.port_id_on_b;
+    let chan_id_on_b = &first_msg.packet.chan_id_on_b;
+    let proof_height_on_a = first_msg.proof_height_on_a;
+
+    let chan_end_path_on_b = ChannelEndPath::new(port_id_on_b, chan_id_on_b);
     let chan_end_on_b = ctx_b.channel_end(&chan_end_path_on_b)?;
 
-    chan_end_on_b.verify_state_matches(&ChannelState::Open)?;
+    // Packets keep being received while the channel end is upgrading, as
+    // long as they were sent before the counterparty started flushing.
+    match chan_end_on_b.state {
+        ChannelState::Open => {}
+        ChannelState::Flushing | ChannelState::FlushComplete => {
+            let upgrade_path_on_b = ChannelUpgradePath::new(port_id_on_b, chan_id_on_b);
+
+            if let Ok(upgrade_on_a) = ctx_b.counterparty_channel_upgrade(&upgrade_path_on_b) {
+                for msg in msgs {
+                    if msg.packet.seq_on_a >= upgrade_on_a.next_sequence_send {
+                        return Err(PacketError::PacketSentDuringUpgrade {
+                            sequence: msg.packet.seq_on_a,
+                            next_sequence_send: upgrade_on_a.next_sequence_send,
+                        }
+                        .into());
+                    }
+                }
+            }
+        }
+        state => {
+            return Err(ChannelError::InvalidState {
+                expected: "Channel end must be OPEN, FLUSHING or FLUSHCOMPLETE".to_string(),
+                actual: state.to_string(),
+            }
+            .into())
+        }
+    }
 
-    let counterparty = Counterparty::new(
-        msg.packet.port_id_on_a.clone(),
-        Some(msg.packet.chan_id_on_a.clone()),
-    );
+    for msg in msgs {
+        let counterparty = Counterparty::new(
+            msg.packet.port_id_on_a.clone(),
+            Some(msg.packet.chan_id_on_a.clone()),
+        );
 
-    chan_end_on_b.verify_counterparty_matches(&counterparty)?;
+        chan_end_on_b.verify_counterparty_matches(&counterparty)?;
+    }
 
     let conn_id_on_b = &chan_end_on_b.connection_hops()[0];
     let conn_end_on_b = ctx_b.connection_end(conn_id_on_b)?;
@@ -162,20 +349,32 @@ where
     conn_end_on_b.verify_state_matches(&ConnectionState::Open)?;
 
     let latest_height = ctx_b.host_height()?;
-    if msg.packet.timeout_height_on_b.has_expired(latest_height) {
-        return Err(PacketError::LowPacketHeight {
-            chain_height: latest_height,
-            timeout_height: msg.packet.timeout_height_on_b,
-        }
-        .into());
-    }
-
     let latest_timestamp = ctx_b.host_timestamp()?;
-    if let Expiry::Expired = latest_timestamp.check_expiry(&msg.packet.timeout_timestamp_on_b) {
-        return Err(PacketError::LowPacketTimestamp.into());
+
+    // Channels allowing timeouts receive timed out packets, to skip their
+    // sequence.
+    if chan_end_on_b.ordering != Order::OrderedAllowTimeout {
+        for msg in msgs {
+            if msg.packet.timeout_height_on_b.has_expired(latest_height) {
+                return Err(PacketError::LowPacketHeight {
+                    chain_height: latest_height,
+                    timeout_height: msg.packet.timeout_height_on_b,
+                }
+                .into());
+            }
+
+            if let Expiry::Expired =
+                latest_timestamp.check_expiry(&msg.packet.timeout_timestamp_on_b)
+            {
+                return Err(PacketError::LowPacketTimestamp.into());
+            }
+        }
     }
 
     // Verify proofs
+    //
+    // The client and the consensus state the proofs are verified against are
+    // looked up and checked once for the whole batch.
     {
         let client_id_on_b = conn_end_on_b.client_id();
         let client_val_ctx_b = ctx_b.get_client_validation_context();
@@ -185,81 +384,101 @@ where
             .status(ctx_b.get_client_validation_context(), client_id_on_b)?
             .verify_is_active()?;
 
-        client_state_of_a_on_b.validate_proof_height(msg.proof_height_on_a)?;
+        client_state_of_a_on_b.validate_proof_height(proof_height_on_a)?;
 
         let client_cons_state_path_on_b = ClientConsensusStatePath::new(
             client_id_on_b.clone(),
-            msg.proof_height_on_a.revision_number(),
-            msg.proof_height_on_a.revision_height(),
+            proof_height_on_a.revision_number(),
+            proof_height_on_a.revision_height(),
         );
 
         let consensus_state_of_a_on_b =
             client_val_ctx_b.consensus_state(&client_cons_state_path_on_b)?;
 
-        let expected_commitment_on_a = compute_packet_commitment(
-            &msg.packet.data,
-            &msg.packet.timeout_height_on_b,
-            &msg.packet.timeout_timestamp_on_b,
-        );
-        let commitment_path_on_a = CommitmentPath::new(
-            &msg.packet.port_id_on_a,
-            &msg.packet.chan_id_on_a,
-            msg.packet.seq_on_a,
-        );
+        verify_conn_delay_passed(ctx_b, proof_height_on_a, &conn_end_on_b)?;
 
-        verify_conn_delay_passed(ctx_b, msg.proof_height_on_a, &conn_end_on_b)?;
+        for msg in msgs {
+            let expected_commitment_on_a = compute_packet_commitment(
+                &msg.packet.data,
+                &msg.packet.timeout_height_on_b,
+                &msg.packet.timeout_timestamp_on_b,
+            );
+            let commitment_path_on_a = CommitmentPath::new(
+                &msg.packet.port_id_on_a,
+                &msg.packet.chan_id_on_a,
+                msg.packet.seq_on_a,
+            );
 
-        // Verify the proof for the packet against the chain store.
-        client_state_of_a_on_b
-            .verify_membership(
+            // Verify the proof for the packet against the chain store.
+            ctx_b.gas_meter().charge(GasOperation::ProofVerification {
+                kind: ProofKind::PacketCommitment,
+                proof: &msg.proof_commitment_on_a,
+            })?;
+            verify_packet_proof(
+                ctx_b,
+                &client_state_of_a_on_b,
+                client_id_on_b,
+                ProofKind::PacketCommitment,
                 conn_end_on_b.counterparty().prefix(),
                 &msg.proof_commitment_on_a,
                 consensus_state_of_a_on_b.root(),
                 Path::Commitment(commitment_path_on_a),
-                expected_commitment_on_a.into_vec(),
+                Some(expected_commitment_on_a.into_vec()),
             )
             .map_err(|e| ChannelError::PacketVerificationFailed {
                 sequence: msg.packet.seq_on_a,
                 client_error: e,
             })
             .map_err(PacketError::Channel)?;
+        }
     }
 
     match chan_end_on_b.ordering {
-        Order::Ordered => {
-            let seq_recv_path_on_b =
-                SeqRecvPath::new(&msg.packet.port_id_on_b, &msg.packet.chan_id_on_b);
-            let next_seq_recv = ctx_b.get_next_sequence_recv(&seq_recv_path_on_b)?;
-            if msg.packet.seq_on_a > next_seq_recv {
-                return Err(PacketError::InvalidPacketSequence {
-                    given_sequence: msg.packet.seq_on_a,
-                    next_sequence: next_seq_recv,
+        Order::Ordered | Order::OrderedAllowTimeout => {
+            let seq_recv_path_on_b = SeqRecvPath::new(port_id_on_b, chan_id_on_b);
+            // The packets of the batch are received in turn, each one
+            // incrementing the sequence the next one is expected at.
+            let mut next_seq_recv = ctx_b.get_next_sequence_recv(&seq_recv_path_on_b)?;
+
+            for msg in msgs {
+                if msg.packet.seq_on_a > next_seq_recv {
+                    return Err(PacketError::InvalidPacketSequence {
+                        given_sequence: msg.packet.seq_on_a,
+                        next_sequence: next_seq_recv,
+                    }
+                    .into());
                 }
-                .into());
-            }
 
-            if msg.packet.seq_on_a == next_seq_recv {
-                // Case where the recvPacket is successful and an
-                // acknowledgement will be written (not a no-op)
-                validate_write_acknowledgement(ctx_b, msg)?;
+                if msg.packet.seq_on_a == next_seq_recv {
+                    if !msg.packet.timed_out(&latest_timestamp, latest_height) {
+                        // Case where the recvPacket is successful and an
+                        // acknowledgement will be written (not a no-op)
+                        validate_write_acknowledgement(ctx_b, msg)?;
+                    }
+
+                    next_seq_recv = next_seq_recv.increment();
+                }
             }
         }
         Order::Unordered => {
-            let receipt_path_on_b = ReceiptPath::new(
-                &msg.packet.port_id_on_a,
-                &msg.packet.chan_id_on_a,
-                msg.packet.seq_on_a,
-            );
-            let packet_rec = ctx_b.get_packet_receipt(&receipt_path_on_b);
-            match packet_rec {
-                Ok(_receipt) => {}
-                Err(ContextError::PacketError(PacketError::PacketReceiptNotFound { sequence }))
-                    if sequence == msg.packet.seq_on_a => {}
-                Err(e) => return Err(e),
+            for msg in msgs {
+                let receipt_path_on_b = ReceiptPath::new(
+                    &msg.packet.port_id_on_b,
+                    &msg.packet.chan_id_on_b,
+                    msg.packet.seq_on_a,
+                );
+                let packet_rec = ctx_b.get_packet_receipt(&receipt_path_on_b);
+                match packet_rec {
+                    Ok(_receipt) => {}
+                    Err(ContextError::PacketError(PacketError::PacketReceiptNotFound {
+                        sequence,
+                    })) if sequence == msg.packet.seq_on_a => {}
+                    Err(e) => return Err(e),
+                }
+                // Case where the recvPacket is successful and an
+                // acknowledgement will be written (not a no-op)
+                validate_write_acknowledgement(ctx_b, msg)?;
             }
-            // Case where the recvPacket is successful and an
-            // acknowledgement will be written (not a no-op)
-            validate_write_acknowledgement(ctx_b, msg)?;
         }
         Order::None => {
             return Err(ContextError::ChannelError(ChannelError::InvalidOrderType {
@@ -1,4 +1,4 @@
-use ibc_core_channel_types::channel::Counterparty;
+use ibc_core_channel_types::channel::{Counterparty, State as ChannelState};
 use ibc_core_channel_types::commitment::compute_packet_commitment;
 use ibc_core_channel_types::error::PacketError;
 use ibc_core_channel_types::events::SendPacket;
@@ -9,6 +9,7 @@ use ibc_core_handler_types::events::{IbcEvent, MessageEvent};
 use ibc_core_host::types::path::{
     ChannelEndPath, ClientConsensusStatePath, CommitmentPath, SeqSendPath,
 };
+use ibc_core_host::GasOperation;
 use ibc_primitives::prelude::*;
 use ibc_primitives::Expiry;
 
@@ -17,6 +18,18 @@ use crate::context::{SendPacketExecutionContext, SendPacketValidationContext};
 /// Send the given packet, including all necessary validation.
 ///
 /// Equivalent to calling [`send_packet_validate`], followed by [`send_packet_execute`]
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(
+        level = "debug",
+        skip_all,
+        fields(
+            port_id = %packet.port_id_on_a,
+            channel_id = %packet.chan_id_on_a,
+            sequence = %packet.seq_on_a,
+        ),
+    )
+)]
 pub fn send_packet(
     ctx_a: &mut impl SendPacketExecutionContext,
     packet: Packet,
@@ -26,10 +39,32 @@ pub fn send_packet(
 }
 
 /// Validate that sending the given packet would succeed.
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(
+        level = "debug",
+        skip_all,
+        fields(
+            port_id = %packet.port_id_on_a,
+            channel_id = %packet.chan_id_on_a,
+            sequence = %packet.seq_on_a,
+        ),
+    )
+)]
 pub fn send_packet_validate(
     ctx_a: &impl SendPacketValidationContext,
     packet: &Packet,
 ) -> Result<(), ContextError> {
+    if let Some(max_size) = ctx_a.max_packet_data_size() {
+        if packet.data.len() > max_size {
+            return Err(PacketError::PacketDataTooLarge {
+                size: packet.data.len(),
+                max_size,
+            }
+            .into());
+        }
+    }
+
     let chan_end_path_on_a = ChannelEndPath::new(&packet.port_id_on_a, &packet.chan_id_on_a);
     let chan_end_on_a = ctx_a.channel_end(&chan_end_path_on_a)?;
 
@@ -37,6 +72,19 @@ pub fn send_packet_validate(
     // This allows for optimistic packet processing before a channel opens
     chan_end_on_a.verify_not_closed()?;
 
+    // No packets are sent while the channel end is flushing the ones sent
+    // before an upgrade.
+    if matches!(
+        chan_end_on_a.state,
+        ChannelState::Flushing | ChannelState::FlushComplete
+    ) {
+        return Err(PacketError::InvalidChannelState {
+            channel_id: packet.chan_id_on_a.clone(),
+            state: chan_end_on_a.state,
+        }
+        .into());
+    }
+
     let counterparty = Counterparty::new(
         packet.port_id_on_b.clone(),
         Some(packet.chan_id_on_b.clone()),
@@ -98,6 +146,18 @@ pub fn send_packet_validate(
 /// Send the packet without any validation.
 ///
 /// A prior call to [`send_packet_validate`] MUST have succeeded.
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(
+        level = "debug",
+        skip_all,
+        fields(
+            port_id = %packet.port_id_on_a,
+            channel_id = %packet.chan_id_on_a,
+            sequence = %packet.seq_on_a,
+        ),
+    )
+)]
 pub fn send_packet_execute(
     ctx_a: &mut impl SendPacketExecutionContext,
     packet: Packet,
@@ -106,9 +166,11 @@ pub fn send_packet_execute(
         let seq_send_path_on_a = SeqSendPath::new(&packet.port_id_on_a, &packet.chan_id_on_a);
         let next_seq_send_on_a = ctx_a.get_next_sequence_send(&seq_send_path_on_a)?;
 
+        ctx_a.gas_meter().charge(GasOperation::StoreWrite)?;
         ctx_a.store_next_sequence_send(&seq_send_path_on_a, next_seq_send_on_a.increment())?;
     }
 
+    ctx_a.gas_meter().charge(GasOperation::StoreWrite)?;
     ctx_a.store_packet_commitment(
         &CommitmentPath::new(&packet.port_id_on_a, &packet.chan_id_on_a, packet.seq_on_a),
         compute_packet_commitment(
@@ -124,12 +186,15 @@ pub fn send_packet_execute(
         let chan_end_on_a = ctx_a.channel_end(&chan_end_path_on_a)?;
         let conn_id_on_a = &chan_end_on_a.connection_hops()[0];
 
+        ctx_a
+            .metrics()
+            .packet_sent(&packet.port_id_on_a, &packet.chan_id_on_a, packet.seq_on_a);
+
         ctx_a.log_message("success: packet send".to_string())?;
-        let event = IbcEvent::SendPacket(SendPacket::new(
-            packet,
-            chan_end_on_a.ordering,
-            conn_id_on_a.clone(),
-        ));
+        let event = IbcEvent::SendPacket(
+            SendPacket::new(packet, chan_end_on_a.ordering, conn_id_on_a.clone())
+                .with_packet_data_mode(ctx_a.packet_data_event_mode()),
+        );
         ctx_a.emit_ibc_event(IbcEvent::Message(MessageEvent::Channel))?;
         ctx_a.emit_ibc_event(event)?;
     }
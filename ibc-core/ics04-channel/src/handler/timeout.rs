@@ -3,17 +3,20 @@ use ibc_core_channel_types::commitment::compute_packet_commitment;
 use ibc_core_channel_types::error::{ChannelError, PacketError};
 use ibc_core_channel_types::events::{ChannelClosed, TimeoutPacket};
 use ibc_core_channel_types::msgs::{MsgTimeout, MsgTimeoutOnClose};
+use ibc_core_channel_types::packet::{Packet, Receipt};
 use ibc_core_client::context::prelude::*;
 use ibc_core_connection::delay::verify_conn_delay_passed;
 use ibc_core_handler_types::error::ContextError;
 use ibc_core_handler_types::events::{IbcEvent, MessageEvent};
 use ibc_core_host::types::path::{
-    ChannelEndPath, ClientConsensusStatePath, CommitmentPath, Path, ReceiptPath, SeqRecvPath,
+    ChannelEndPath, ChannelUpgradePath, ClientConsensusStatePath, CommitmentPath, Path,
+    ReceiptPath, SeqAckPath, SeqRecvPath,
 };
-use ibc_core_host::{ExecutionContext, ValidationContext};
+use ibc_core_host::{ExecutionContext, GasOperation, ProofKind, ValidationContext};
 use ibc_core_router::module::Module;
 use ibc_primitives::prelude::*;
 
+use super::packet_proofs::verify_packet_proof;
 use super::timeout_on_close;
 
 pub enum TimeoutMsgType {
@@ -21,6 +24,28 @@ pub enum TimeoutMsgType {
     TimeoutOnClose(MsgTimeoutOnClose),
 }
 
+impl TimeoutMsgType {
+    /// Returns the packet timing out.
+    pub fn packet(&self) -> &Packet {
+        match self {
+            TimeoutMsgType::Timeout(msg) => &msg.packet,
+            TimeoutMsgType::TimeoutOnClose(msg) => &msg.packet,
+        }
+    }
+}
+
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(
+        level = "debug",
+        skip_all,
+        fields(
+            port_id = %timeout_msg_type.packet().port_id_on_a,
+            channel_id = %timeout_msg_type.packet().chan_id_on_a,
+            sequence = %timeout_msg_type.packet().seq_on_a,
+        ),
+    )
+)]
 pub fn timeout_packet_validate<ValCtx>(
     ctx_a: &ValCtx,
     module: &dyn Module,
@@ -44,6 +69,18 @@ where
         .map_err(ContextError::PacketError)
 }
 
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(
+        level = "debug",
+        skip_all,
+        fields(
+            port_id = %timeout_msg_type.packet().port_id_on_a,
+            channel_id = %timeout_msg_type.packet().chan_id_on_a,
+            sequence = %timeout_msg_type.packet().seq_on_a,
+        ),
+    )
+)]
 pub fn timeout_packet_execute<ExecCtx>(
     ctx_a: &mut ExecCtx,
     module: &mut dyn Module,
@@ -82,11 +119,43 @@ where
 
     // apply state changes
     let chan_end_on_a = {
+        ctx_a.gas_meter().charge(GasOperation::StoreWrite)?;
         ctx_a.delete_packet_commitment(&commitment_path_on_a)?;
 
+        // Over channels allowing timeouts, the next packet to acknowledge is
+        // the one following the timed out packet.
+        if chan_end_on_a.ordering == Order::OrderedAllowTimeout {
+            let seq_ack_path_on_a = SeqAckPath::new(&packet.port_id_on_a, &packet.chan_id_on_a);
+            ctx_a.gas_meter().charge(GasOperation::StoreWrite)?;
+            ctx_a.store_next_sequence_ack(&seq_ack_path_on_a, packet.seq_on_a.increment())?;
+        }
+
+        let upgrade_path_on_a = ChannelUpgradePath::new(&packet.port_id_on_a, &packet.chan_id_on_a);
+
         if let Order::Ordered = chan_end_on_a.ordering {
+            // Closing the channel end also aborts any upgrade in progress.
+            if chan_end_on_a.state == State::Flushing {
+                ctx_a.gas_meter().charge(GasOperation::StoreWrite)?;
+                ctx_a.delete_channel_upgrade(&upgrade_path_on_a)?;
+            }
+
             let mut chan_end_on_a = chan_end_on_a;
             chan_end_on_a.state = State::Closed;
+            ctx_a.gas_meter().charge(GasOperation::StoreWrite)?;
+            ctx_a.store_channel(&chan_end_path_on_a, chan_end_on_a.clone())?;
+
+            chan_end_on_a
+        } else if chan_end_on_a.state == State::Flushing
+            && ctx_a
+                .counterparty_channel_upgrade(&upgrade_path_on_a)
+                .is_ok()
+            && !ctx_a.has_inflight_packets(&chan_end_path_on_a)?
+        {
+            // An upgrading channel end is done flushing once its last
+            // in-flight packet times out.
+            let mut chan_end_on_a = chan_end_on_a;
+            chan_end_on_a.state = State::FlushComplete;
+            ctx_a.gas_meter().charge(GasOperation::StoreWrite)?;
             ctx_a.store_channel(&chan_end_path_on_a, chan_end_on_a.clone())?;
 
             chan_end_on_a
@@ -97,6 +166,12 @@ where
 
     // emit events and logs
     {
+        ctx_a.metrics().packet_timed_out(
+            &packet.port_id_on_a,
+            &packet.chan_id_on_a,
+            packet.seq_on_a,
+        );
+
         ctx_a.log_message("success: packet timeout".to_string())?;
 
         if let Order::Ordered = chan_end_on_a.ordering {
@@ -137,7 +212,13 @@ where
         &msg.packet.chan_id_on_a,
     ))?;
 
-    chan_end_on_a.verify_state_matches(&State::Open)?;
+    if !matches!(chan_end_on_a.state, State::Open | State::Flushing) {
+        return Err(ChannelError::InvalidState {
+            expected: "Channel end must be OPEN or FLUSHING".to_string(),
+            actual: chan_end_on_a.state().to_string(),
+        }
+        .into());
+    }
 
     let counterparty = Counterparty::new(
         msg.packet.port_id_on_b.clone(),
@@ -221,12 +302,57 @@ where
                 let seq_recv_path_on_b =
                     SeqRecvPath::new(&msg.packet.port_id_on_b, &msg.packet.chan_id_on_b);
 
-                client_state_of_b_on_a.verify_membership(
+                ctx_a.gas_meter().charge(GasOperation::ProofVerification {
+                    kind: ProofKind::NextSequenceRecv,
+                    proof: &msg.proof_unreceived_on_b,
+                })?;
+                verify_packet_proof(
+                    ctx_a,
+                    &client_state_of_b_on_a,
+                    client_id_on_a,
+                    ProofKind::NextSequenceRecv,
                     conn_end_on_a.counterparty().prefix(),
                     &msg.proof_unreceived_on_b,
                     consensus_state_of_b_on_a.root(),
                     Path::SeqRecv(seq_recv_path_on_b),
-                    msg.packet.seq_on_a.to_vec(),
+                    Some(msg.next_seq_recv_on_b.to_vec()),
+                )
+            }
+            Order::OrderedAllowTimeout => {
+                // Packets time out in the order they are acknowledged in.
+                let seq_ack_path_on_a =
+                    SeqAckPath::new(&msg.packet.port_id_on_a, &msg.packet.chan_id_on_a);
+                let next_seq_ack = ctx_a.get_next_sequence_ack(&seq_ack_path_on_a)?;
+                if msg.packet.seq_on_a != next_seq_ack {
+                    return Err(PacketError::InvalidPacketSequence {
+                        given_sequence: msg.packet.seq_on_a,
+                        next_sequence: next_seq_ack,
+                    }
+                    .into());
+                }
+
+                // Chain B skipped the sequence of the packet when receiving
+                // it after its timeout, and wrote a timeout receipt instead.
+                let receipt_path_on_b = ReceiptPath::new(
+                    &msg.packet.port_id_on_b,
+                    &msg.packet.chan_id_on_b,
+                    msg.packet.seq_on_a,
+                );
+
+                ctx_a.gas_meter().charge(GasOperation::ProofVerification {
+                    kind: ProofKind::PacketReceipt,
+                    proof: &msg.proof_unreceived_on_b,
+                })?;
+                verify_packet_proof(
+                    ctx_a,
+                    &client_state_of_b_on_a,
+                    client_id_on_a,
+                    ProofKind::PacketReceipt,
+                    conn_end_on_a.counterparty().prefix(),
+                    &msg.proof_unreceived_on_b,
+                    consensus_state_of_b_on_a.root(),
+                    Path::Receipt(receipt_path_on_b),
+                    Some(Receipt::Timeout.to_vec()),
                 )
             }
             Order::Unordered => {
@@ -236,11 +362,20 @@ where
                     msg.packet.seq_on_a,
                 );
 
-                client_state_of_b_on_a.verify_non_membership(
+                ctx_a.gas_meter().charge(GasOperation::ProofVerification {
+                    kind: ProofKind::PacketReceipt,
+                    proof: &msg.proof_unreceived_on_b,
+                })?;
+                verify_packet_proof(
+                    ctx_a,
+                    &client_state_of_b_on_a,
+                    client_id_on_a,
+                    ProofKind::PacketReceipt,
                     conn_end_on_a.counterparty().prefix(),
                     &msg.proof_unreceived_on_b,
                     consensus_state_of_b_on_a.root(),
                     Path::Receipt(receipt_path_on_b),
+                    None,
                 )
             }
             Order::None => {
@@ -4,13 +4,16 @@ use ibc_core_channel_types::error::{ChannelError, PacketError};
 use ibc_core_channel_types::msgs::MsgTimeoutOnClose;
 use ibc_core_client::context::prelude::*;
 use ibc_core_connection::delay::verify_conn_delay_passed;
+use ibc_core_connection::types::ConnectionEnd;
 use ibc_core_handler_types::error::ContextError;
 use ibc_core_host::types::path::{
     ChannelEndPath, ClientConsensusStatePath, CommitmentPath, Path, ReceiptPath, SeqRecvPath,
 };
-use ibc_core_host::ValidationContext;
+use ibc_core_host::{GasOperation, ProofKind, ValidationContext};
 use ibc_primitives::prelude::*;
-use ibc_primitives::proto::Protobuf;
+use ibc_primitives::CanonicalProtobuf;
+
+use super::packet_proofs::verify_packet_proof;
 
 pub fn validate<Ctx>(ctx_a: &Ctx, msg: &MsgTimeoutOnClose) -> Result<(), ContextError>
 where
@@ -79,48 +82,36 @@ where
         let consensus_state_of_b_on_a =
             client_val_ctx_a.consensus_state(&client_cons_state_path_on_a)?;
         let prefix_on_b = conn_end_on_a.counterparty().prefix();
-        let port_id_on_b = chan_end_on_a.counterparty().port_id.clone();
-        let chan_id_on_b = chan_end_on_a
-            .counterparty()
-            .channel_id()
-            .ok_or(PacketError::Channel(ChannelError::MissingCounterparty))?;
-        let conn_id_on_b = conn_end_on_a.counterparty().connection_id().ok_or(
-            PacketError::UndefinedConnectionCounterparty {
-                connection_id: chan_end_on_a.connection_hops()[0].clone(),
-            },
-        )?;
-        let expected_conn_hops_on_b = vec![conn_id_on_b.clone()];
-        let expected_counterparty = Counterparty::new(
-            packet.port_id_on_a.clone(),
-            Some(packet.chan_id_on_a.clone()),
-        );
-        let expected_chan_end_on_b = ChannelEnd::new(
-            State::Closed,
-            *chan_end_on_a.ordering(),
-            expected_counterparty,
-            expected_conn_hops_on_b,
-            chan_end_on_a.version().clone(),
-        )?;
-
-        let chan_end_path_on_b = ChannelEndPath(port_id_on_b, chan_id_on_b.clone());
+        let (chan_end_path_on_b, expected_chan_end_on_b) =
+            expected_chan_end_on_b(&chan_end_on_a, &conn_end_on_a, msg)?;
 
         // Verify the proof for the channel state against the expected channel end.
         // A counterparty channel id of None in not possible, and is checked by validate_basic in msg.
-        client_state_of_b_on_a
-            .verify_membership(
-                prefix_on_b,
-                &msg.proof_unreceived_on_b,
-                consensus_state_of_b_on_a.root(),
-                Path::ChannelEnd(chan_end_path_on_b),
-                expected_chan_end_on_b.encode_vec(),
-            )
-            .map_err(ChannelError::VerifyChannelFailed)
-            .map_err(PacketError::Channel)?;
+        ctx_a.gas_meter().charge(GasOperation::ProofVerification {
+            kind: ProofKind::Channel,
+            proof: &msg.proof_close_on_b,
+        })?;
+        let path = Path::ChannelEnd(chan_end_path_on_b);
+        verify_packet_proof(
+            ctx_a,
+            &client_state_of_b_on_a,
+            client_id_on_a,
+            ProofKind::Channel,
+            prefix_on_b,
+            &msg.proof_close_on_b,
+            consensus_state_of_b_on_a.root(),
+            path.clone(),
+            Some(expected_chan_end_on_b.encode_vec_canonical()),
+        )
+        .map_err(|client_error| ChannelError::VerifyChannelFailed { path, client_error })
+        .map_err(PacketError::Channel)?;
 
         verify_conn_delay_passed(ctx_a, msg.proof_height_on_b, &conn_end_on_a)?;
 
         let next_seq_recv_verification_result = match chan_end_on_a.ordering {
-            Order::Ordered => {
+            // Whether or not chain B allows timeouts, the packet cannot be
+            // received past the closing of its channel end.
+            Order::Ordered | Order::OrderedAllowTimeout => {
                 if packet.seq_on_a < msg.next_seq_recv_on_b {
                     return Err(PacketError::InvalidPacketSequence {
                         given_sequence: packet.seq_on_a,
@@ -131,12 +122,20 @@ where
                 let seq_recv_path_on_b =
                     SeqRecvPath::new(&packet.port_id_on_b, &packet.chan_id_on_b);
 
-                client_state_of_b_on_a.verify_membership(
+                ctx_a.gas_meter().charge(GasOperation::ProofVerification {
+                    kind: ProofKind::NextSequenceRecv,
+                    proof: &msg.proof_unreceived_on_b,
+                })?;
+                verify_packet_proof(
+                    ctx_a,
+                    &client_state_of_b_on_a,
+                    client_id_on_a,
+                    ProofKind::NextSequenceRecv,
                     conn_end_on_a.counterparty().prefix(),
                     &msg.proof_unreceived_on_b,
                     consensus_state_of_b_on_a.root(),
                     Path::SeqRecv(seq_recv_path_on_b),
-                    packet.seq_on_a.to_vec(),
+                    Some(msg.next_seq_recv_on_b.to_vec()),
                 )
             }
             Order::Unordered => {
@@ -146,11 +145,20 @@ where
                     msg.packet.seq_on_a,
                 );
 
-                client_state_of_b_on_a.verify_non_membership(
+                ctx_a.gas_meter().charge(GasOperation::ProofVerification {
+                    kind: ProofKind::PacketReceipt,
+                    proof: &msg.proof_unreceived_on_b,
+                })?;
+                verify_packet_proof(
+                    ctx_a,
+                    &client_state_of_b_on_a,
+                    client_id_on_a,
+                    ProofKind::PacketReceipt,
                     conn_end_on_a.counterparty().prefix(),
                     &msg.proof_unreceived_on_b,
                     consensus_state_of_b_on_a.root(),
                     Path::Receipt(receipt_path_on_b),
+                    None,
                 )
             }
             Order::None => {
@@ -171,3 +179,40 @@ where
 
     Ok(())
 }
+
+/// Returns the path and the expected end of the closed channel end on chain
+/// B, which the proof of the message is checked against.
+pub(crate) fn expected_chan_end_on_b(
+    chan_end_on_a: &ChannelEnd,
+    conn_end_on_a: &ConnectionEnd,
+    msg: &MsgTimeoutOnClose,
+) -> Result<(ChannelEndPath, ChannelEnd), ContextError> {
+    let port_id_on_b = chan_end_on_a.counterparty().port_id.clone();
+    let chan_id_on_b = chan_end_on_a
+        .counterparty()
+        .channel_id()
+        .ok_or(PacketError::Channel(ChannelError::MissingCounterparty))?;
+    let conn_id_on_b = conn_end_on_a.counterparty().connection_id().ok_or(
+        PacketError::UndefinedConnectionCounterparty {
+            connection_id: chan_end_on_a.connection_hops()[0].clone(),
+        },
+    )?;
+    let expected_conn_hops_on_b = vec![conn_id_on_b.clone()];
+    let expected_counterparty = Counterparty::new(
+        msg.packet.port_id_on_a.clone(),
+        Some(msg.packet.chan_id_on_a.clone()),
+    );
+    let expected_chan_end_on_b = ChannelEnd::new(
+        State::Closed,
+        *chan_end_on_a.ordering(),
+        expected_counterparty,
+        expected_conn_hops_on_b,
+        chan_end_on_a.version().clone(),
+    )?
+    .with_upgrade_sequence(msg.upgrade_sequence_on_b);
+
+    Ok((
+        ChannelEndPath(port_id_on_b, chan_id_on_b.clone()),
+        expected_chan_end_on_b,
+    ))
+}
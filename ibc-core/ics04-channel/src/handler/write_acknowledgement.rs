@@ -0,0 +1,148 @@
+use ibc_core_channel_types::acknowledgement::Acknowledgement;
+use ibc_core_channel_types::channel::{Counterparty, Order, State as ChannelState};
+use ibc_core_channel_types::commitment::compute_ack_commitment;
+use ibc_core_channel_types::error::{ChannelError, PacketError};
+use ibc_core_channel_types::events::WriteAcknowledgement;
+use ibc_core_channel_types::packet::{Packet, Receipt};
+use ibc_core_handler_types::error::ContextError;
+use ibc_core_handler_types::events::{IbcEvent, MessageEvent};
+use ibc_core_host::types::path::{AckPath, ChannelEndPath, ReceiptPath, SeqRecvPath};
+use ibc_core_host::GasOperation;
+use ibc_primitives::prelude::*;
+
+use crate::context::{WriteAcknowledgementExecutionContext, WriteAcknowledgementValidationContext};
+
+/// Write the acknowledgement of a received packet, including all necessary
+/// validation.
+///
+/// Modules which don't acknowledge a packet when receiving it, by returning no
+/// acknowledgement from `on_recv_packet_execute`, call it once the
+/// acknowledgement is known.
+///
+/// Equivalent to calling [`write_acknowledgement_validate`], followed by
+/// [`write_acknowledgement_execute`]
+pub fn write_acknowledgement(
+    ctx_b: &mut impl WriteAcknowledgementExecutionContext,
+    packet: Packet,
+    acknowledgement: Acknowledgement,
+) -> Result<(), ContextError> {
+    write_acknowledgement_validate(ctx_b, &packet)?;
+    write_acknowledgement_execute(ctx_b, packet, acknowledgement)
+}
+
+/// Validate that writing the acknowledgement of the given packet would
+/// succeed.
+pub fn write_acknowledgement_validate(
+    ctx_b: &impl WriteAcknowledgementValidationContext,
+    packet: &Packet,
+) -> Result<(), ContextError> {
+    let chan_end_path_on_b = ChannelEndPath::new(&packet.port_id_on_b, &packet.chan_id_on_b);
+    let chan_end_on_b = ctx_b.channel_end(&chan_end_path_on_b)?;
+
+    // Packets received while the channel end is upgrading are still
+    // acknowledged.
+    if !matches!(
+        chan_end_on_b.state,
+        ChannelState::Open | ChannelState::Flushing | ChannelState::FlushComplete
+    ) {
+        return Err(ChannelError::InvalidState {
+            expected: "Channel end must be OPEN, FLUSHING or FLUSHCOMPLETE".to_string(),
+            actual: chan_end_on_b.state.to_string(),
+        }
+        .into());
+    }
+
+    let counterparty = Counterparty::new(
+        packet.port_id_on_a.clone(),
+        Some(packet.chan_id_on_a.clone()),
+    );
+
+    chan_end_on_b.verify_counterparty_matches(&counterparty)?;
+
+    // The packet must have been received, and its acknowledgement deferred.
+    let packet_received = match chan_end_on_b.ordering {
+        Order::Unordered => {
+            let receipt_path_on_b =
+                ReceiptPath::new(&packet.port_id_on_b, &packet.chan_id_on_b, packet.seq_on_a);
+
+            matches!(
+                ctx_b.get_packet_receipt(&receipt_path_on_b),
+                Ok(Receipt::Ok)
+            )
+        }
+        Order::Ordered | Order::OrderedAllowTimeout => {
+            let seq_recv_path_on_b = SeqRecvPath::new(&packet.port_id_on_b, &packet.chan_id_on_b);
+            let next_seq_recv = ctx_b.get_next_sequence_recv(&seq_recv_path_on_b)?;
+
+            // The sequences of packets which timed out over a channel
+            // allowing timeouts are skipped with a timeout receipt, without
+            // the packets being received.
+            let timed_out = chan_end_on_b.ordering == Order::OrderedAllowTimeout && {
+                let receipt_path_on_b =
+                    ReceiptPath::new(&packet.port_id_on_b, &packet.chan_id_on_b, packet.seq_on_a);
+
+                matches!(
+                    ctx_b.get_packet_receipt(&receipt_path_on_b),
+                    Ok(Receipt::Timeout)
+                )
+            };
+
+            packet.seq_on_a < next_seq_recv && !timed_out
+        }
+        Order::None => {
+            return Err(ChannelError::InvalidOrderType {
+                expected: "Channel ordering cannot be None".to_string(),
+                actual: chan_end_on_b.ordering.to_string(),
+            }
+            .into())
+        }
+    };
+    if !packet_received {
+        return Err(PacketError::PacketNotReceived {
+            sequence: packet.seq_on_a,
+        }
+        .into());
+    }
+
+    let ack_path_on_b = AckPath::new(&packet.port_id_on_b, &packet.chan_id_on_b, packet.seq_on_a);
+    if ctx_b.get_packet_acknowledgement(&ack_path_on_b).is_ok() {
+        return Err(PacketError::AcknowledgementExists {
+            sequence: packet.seq_on_a,
+        }
+        .into());
+    }
+
+    Ok(())
+}
+
+/// Write the acknowledgement of the packet without any validation.
+///
+/// A prior call to [`write_acknowledgement_validate`] MUST have succeeded.
+pub fn write_acknowledgement_execute(
+    ctx_b: &mut impl WriteAcknowledgementExecutionContext,
+    packet: Packet,
+    acknowledgement: Acknowledgement,
+) -> Result<(), ContextError> {
+    let ack_path_on_b = AckPath::new(&packet.port_id_on_b, &packet.chan_id_on_b, packet.seq_on_a);
+    ctx_b.gas_meter().charge(GasOperation::StoreWrite)?;
+    ctx_b.store_packet_acknowledgement(&ack_path_on_b, compute_ack_commitment(&acknowledgement))?;
+
+    // emit events and logs
+    {
+        let chan_end_path_on_b = ChannelEndPath::new(&packet.port_id_on_b, &packet.chan_id_on_b);
+        let chan_end_on_b = ctx_b.channel_end(&chan_end_path_on_b)?;
+        let conn_id_on_b = chan_end_on_b.connection_hops()[0].clone();
+
+        ctx_b.log_message("success: packet write acknowledgement".to_string())?;
+
+        let event = IbcEvent::WriteAcknowledgement(WriteAcknowledgement::new(
+            packet,
+            acknowledgement,
+            conn_id_on_b,
+        ));
+        ctx_b.emit_ibc_event(IbcEvent::Message(MessageEvent::Channel))?;
+        ctx_b.emit_ibc_event(event)?;
+    }
+
+    Ok(())
+}
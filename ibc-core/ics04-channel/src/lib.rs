@@ -18,6 +18,8 @@ extern crate std;
 
 pub mod context;
 pub mod handler;
+pub mod timeout;
+pub mod v2;
 
 /// Re-exports ICS-04 data structures from the `ibc-core-channel-types` crate.
 pub mod types {
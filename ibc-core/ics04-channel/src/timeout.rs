@@ -0,0 +1,176 @@
+//! Helpers to build the timeouts of the packets sent over a channel end,
+//! relative to the state of the destination chain known by the host.
+
+use core::time::Duration;
+
+use ibc_core_channel_types::error::PacketError;
+use ibc_core_channel_types::timeout::TimeoutHeight;
+use ibc_core_client::context::prelude::*;
+use ibc_core_client::types::Height;
+use ibc_core_handler_types::error::ContextError;
+use ibc_core_host::types::path::{ChannelEndPath, ClientConsensusStatePath};
+use ibc_core_host::ValidationContext;
+use ibc_primitives::Timestamp;
+
+/// The timeout height and timestamp of a packet, on the destination chain.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PacketTimeout {
+    pub timeout_height_on_b: TimeoutHeight,
+    pub timeout_timestamp_on_b: Timestamp,
+}
+
+impl PacketTimeout {
+    /// Returns the timeout of a packet sent over the given channel end which
+    /// times out once the given duration elapsed.
+    pub fn after<Ctx>(
+        ctx_a: &Ctx,
+        chan_end_path_on_a: &ChannelEndPath,
+        duration: Duration,
+    ) -> Result<Self, ContextError>
+    where
+        Ctx: ValidationContext,
+    {
+        PacketTimeoutBuilder::new(ctx_a, chan_end_path_on_a)?
+            .after(duration)
+            .build()
+    }
+
+    /// Returns the timeout of a packet sent over the given channel end which
+    /// times out once the destination chain produced the given number of
+    /// blocks.
+    pub fn in_blocks<Ctx>(
+        ctx_a: &Ctx,
+        chan_end_path_on_a: &ChannelEndPath,
+        blocks: u64,
+    ) -> Result<Self, ContextError>
+    where
+        Ctx: ValidationContext,
+    {
+        PacketTimeoutBuilder::new(ctx_a, chan_end_path_on_a)?
+            .in_blocks(blocks)
+            .build()
+    }
+}
+
+/// Builds the timeout of a packet sent over a channel end, relative to the
+/// latest state of the destination chain known by the host.
+///
+/// The latest height and timestamp of the destination chain are the ones of
+/// the client of the channel end, which lag behind as long as the client is
+/// not updated. To not build timeouts which already elapsed on the
+/// destination chain, durations are counted from the host clock when it is
+/// ahead of the client, and, given the expected block time of the
+/// destination chain, blocks are counted from an estimate of the height the
+/// destination chain reached since.
+#[derive(Clone, Debug)]
+pub struct PacketTimeoutBuilder {
+    host_timestamp: Timestamp,
+    latest_height_on_b: Height,
+    latest_timestamp_on_b: Timestamp,
+    block_time_on_b: Option<Duration>,
+    duration: Option<Duration>,
+    blocks: Option<u64>,
+}
+
+impl PacketTimeoutBuilder {
+    /// Looks up the host clock, and the latest height and timestamp of the
+    /// destination chain, for the packets sent over the given channel end.
+    pub fn new<Ctx>(ctx_a: &Ctx, chan_end_path_on_a: &ChannelEndPath) -> Result<Self, ContextError>
+    where
+        Ctx: ValidationContext,
+    {
+        let chan_end_on_a = ctx_a.channel_end(chan_end_path_on_a)?;
+
+        chan_end_on_a.verify_connection_hops_length()?;
+
+        let conn_end_on_a = ctx_a.connection_end(&chan_end_on_a.connection_hops()[0])?;
+        let client_id_on_a = conn_end_on_a.client_id();
+        let client_val_ctx_a = ctx_a.get_client_validation_context();
+        let client_state_of_b_on_a = client_val_ctx_a.client_state(client_id_on_a)?;
+
+        let latest_height_on_b = client_state_of_b_on_a.latest_height();
+
+        let client_cons_state_path_on_a = ClientConsensusStatePath::new(
+            client_id_on_a.clone(),
+            latest_height_on_b.revision_number(),
+            latest_height_on_b.revision_height(),
+        );
+        let latest_timestamp_on_b = client_val_ctx_a
+            .consensus_state(&client_cons_state_path_on_a)?
+            .timestamp();
+
+        Ok(Self {
+            host_timestamp: ctx_a.host_timestamp()?,
+            latest_height_on_b,
+            latest_timestamp_on_b,
+            block_time_on_b: None,
+            duration: None,
+            blocks: None,
+        })
+    }
+
+    /// Sets the expected time between two blocks of the destination chain,
+    /// from which the blocks the destination chain produced since the latest
+    /// update of the client are estimated.
+    pub fn with_block_time_on_b(mut self, block_time_on_b: Duration) -> Self {
+        self.block_time_on_b = Some(block_time_on_b);
+        self
+    }
+
+    /// Times the packet out once the given duration elapsed.
+    pub fn after(mut self, duration: Duration) -> Self {
+        self.duration = Some(duration);
+        self
+    }
+
+    /// Times the packet out once the destination chain produced the given
+    /// number of blocks.
+    pub fn in_blocks(mut self, blocks: u64) -> Self {
+        self.blocks = Some(blocks);
+        self
+    }
+
+    /// Returns the timestamp the durations are counted from, which is the
+    /// latest of the host clock and of the client timestamp.
+    pub fn reference_timestamp(&self) -> Timestamp {
+        self.host_timestamp.max(self.latest_timestamp_on_b)
+    }
+
+    /// Returns the height of the destination chain the blocks are counted
+    /// from, estimated from the latest height of the client.
+    pub fn estimated_height_on_b(&self) -> Height {
+        let blocks_since_update = self
+            .block_time_on_b
+            .filter(|block_time| !block_time.is_zero())
+            .and_then(|block_time| {
+                let elapsed = self
+                    .host_timestamp
+                    .duration_since(&self.latest_timestamp_on_b)?;
+
+                u64::try_from(elapsed.as_nanos() / block_time.as_nanos()).ok()
+            })
+            .unwrap_or_default();
+
+        self.latest_height_on_b.add(blocks_since_update)
+    }
+
+    /// Returns the timeout of the packet, which does not time out on the
+    /// height, or on the timestamp, unless set to.
+    pub fn build(self) -> Result<PacketTimeout, ContextError> {
+        let timeout_height_on_b = match self.blocks {
+            Some(blocks) => TimeoutHeight::At(self.estimated_height_on_b().add(blocks)),
+            None => TimeoutHeight::Never,
+        };
+
+        let timeout_timestamp_on_b = match self.duration {
+            Some(duration) => (self.reference_timestamp() + duration)
+                .map_err(PacketError::TimeoutTimestampOverflow)?,
+            None => Timestamp::none(),
+        };
+
+        Ok(PacketTimeout {
+            timeout_height_on_b,
+            timeout_timestamp_on_b,
+        })
+    }
+}
@@ -0,0 +1,81 @@
+//! Defines the contexts the IBC v2 packet handlers require from the host.
+use ibc_core_channel_types::commitment::{AcknowledgementCommitment, PacketCommitment};
+use ibc_core_channel_types::packet::Receipt;
+use ibc_core_channel_types::v2::counterparty::ClientCounterparty;
+use ibc_core_handler_types::error::ContextError;
+use ibc_core_host::types::identifiers::{ClientId, Sequence};
+use ibc_core_host::types::path::{AckPathV2, CommitmentPathV2, ReceiptPathV2, SeqSendPathV2};
+use ibc_core_host::{ExecutionContext, ValidationContext};
+
+/// Methods required in IBC v2 packet validation, to be implemented by the
+/// hosts which support IBC v2 on top of the classic channels.
+pub trait PacketV2ValidationContext: ValidationContext {
+    /// Returns the counterparty registered for the client `client_id`, which
+    /// packets are sent to and received from over that client.
+    ///
+    /// How counterparties are registered is up to the host, e.g. by the
+    /// creator of the client or by governance.
+    fn client_counterparty(&self, client_id: &ClientId)
+        -> Result<ClientCounterparty, ContextError>;
+
+    /// Returns the sequence of the next packet sent over the client, which
+    /// starts at `1`.
+    fn get_next_sequence_send_v2(
+        &self,
+        seq_send_path: &SeqSendPathV2,
+    ) -> Result<Sequence, ContextError>;
+
+    /// Returns the packet commitment for the given path
+    fn get_packet_commitment_v2(
+        &self,
+        commitment_path: &CommitmentPathV2,
+    ) -> Result<PacketCommitment, ContextError>;
+
+    /// Returns the packet receipt for the given path
+    fn get_packet_receipt_v2(&self, receipt_path: &ReceiptPathV2) -> Result<Receipt, ContextError>;
+
+    /// Returns the packet acknowledgement commitment for the given path
+    fn get_packet_acknowledgement_v2(
+        &self,
+        ack_path: &AckPathV2,
+    ) -> Result<AcknowledgementCommitment, ContextError>;
+}
+
+/// Methods required in IBC v2 packet execution, to be implemented by the
+/// hosts which support IBC v2 on top of the classic channels.
+pub trait PacketV2ExecutionContext: PacketV2ValidationContext + ExecutionContext {
+    /// Stores the sequence of the next packet sent over the client
+    fn store_next_sequence_send_v2(
+        &mut self,
+        seq_send_path: &SeqSendPathV2,
+        seq: Sequence,
+    ) -> Result<(), ContextError>;
+
+    /// Stores the given packet commitment at the given store path
+    fn store_packet_commitment_v2(
+        &mut self,
+        commitment_path: &CommitmentPathV2,
+        commitment: PacketCommitment,
+    ) -> Result<(), ContextError>;
+
+    /// Deletes the packet commitment at the given store path
+    fn delete_packet_commitment_v2(
+        &mut self,
+        commitment_path: &CommitmentPathV2,
+    ) -> Result<(), ContextError>;
+
+    /// Stores the given packet receipt at the given store path
+    fn store_packet_receipt_v2(
+        &mut self,
+        receipt_path: &ReceiptPathV2,
+        receipt: Receipt,
+    ) -> Result<(), ContextError>;
+
+    /// Stores the given packet acknowledgement commitment at the given store
+    /// path
+    fn store_packet_acknowledgement_v2(
+        &mut self,
+        ack_path: &AckPathV2,
+        ack_commitment: AcknowledgementCommitment,
+    ) -> Result<(), ContextError>;
+}
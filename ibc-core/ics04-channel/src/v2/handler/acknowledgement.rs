@@ -0,0 +1,213 @@
+//! Protocol logic specific to IBC v2 messages of type `MsgAcknowledgement`.
+use ibc_core_channel_types::error::{ChannelError, PacketError};
+use ibc_core_channel_types::v2::commitment::{
+    compute_ack_commitment_v2, compute_packet_commitment_v2,
+};
+use ibc_core_channel_types::v2::events::AcknowledgePacket;
+use ibc_core_channel_types::v2::msgs::MsgAcknowledgement;
+use ibc_core_client::context::prelude::*;
+use ibc_core_handler_types::error::ContextError;
+use ibc_core_handler_types::events::{IbcEvent, MessageEvent};
+use ibc_core_host::types::path::{AckPathV2, ClientConsensusStatePath, CommitmentPathV2, Path};
+use ibc_core_host::{GasOperation, ProofKind};
+use ibc_core_router::v2::RouterV2;
+use ibc_primitives::prelude::*;
+
+use super::{route, route_mut, verify_client_counterparty};
+use crate::v2::context::{PacketV2ExecutionContext, PacketV2ValidationContext};
+
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(
+        level = "debug",
+        skip_all,
+        fields(client_id = %msg.packet.client_id_on_a, sequence = %msg.packet.seq_on_a),
+    )
+)]
+pub fn acknowledgement_packet_v2_validate<ValCtx>(
+    ctx_a: &ValCtx,
+    router: &impl RouterV2,
+    msg: &MsgAcknowledgement,
+) -> Result<(), ContextError>
+where
+    ValCtx: PacketV2ValidationContext,
+{
+    ctx_a.validate_message_signer(&msg.signer)?;
+
+    let packet = &msg.packet;
+
+    let counterparty =
+        verify_client_counterparty(ctx_a, &packet.client_id_on_a, &packet.client_id_on_b)?;
+
+    let commitment_path_on_a = CommitmentPathV2::new(&packet.client_id_on_a, packet.seq_on_a);
+
+    // Acknowledging a packet which was already acknowledged is a no-op, so
+    // that the transactions of competing relayers do not fail.
+    let Ok(commitment_on_a) = ctx_a.get_packet_commitment_v2(&commitment_path_on_a) else {
+        return Ok(());
+    };
+
+    if commitment_on_a != compute_packet_commitment_v2(packet) {
+        return Err(PacketError::IncorrectPacketCommitment {
+            sequence: packet.seq_on_a,
+        }
+        .into());
+    }
+
+    if !msg.acknowledgement.is_error()
+        && msg.acknowledgement.app_acknowledgements().len() != packet.payloads.len()
+    {
+        return Err(PacketError::MismatchedAppAcknowledgements {
+            expected: packet.payloads.len(),
+            actual: msg.acknowledgement.app_acknowledgements().len(),
+        }
+        .into());
+    }
+
+    // Verify proofs
+    {
+        let client_id_on_a = &packet.client_id_on_a;
+        let client_val_ctx_a = ctx_a.get_client_validation_context();
+        let client_state_of_b_on_a = client_val_ctx_a.client_state(client_id_on_a)?;
+
+        client_state_of_b_on_a
+            .status(client_val_ctx_a, client_id_on_a)?
+            .verify_is_active()?;
+
+        client_state_of_b_on_a.validate_proof_height(msg.proof_height_on_b)?;
+
+        let client_cons_state_path_on_a = ClientConsensusStatePath::new(
+            client_id_on_a.clone(),
+            msg.proof_height_on_b.revision_number(),
+            msg.proof_height_on_b.revision_height(),
+        );
+        let consensus_state_of_b_on_a =
+            client_val_ctx_a.consensus_state(&client_cons_state_path_on_a)?;
+
+        let ack_commitment = compute_ack_commitment_v2(&msg.acknowledgement);
+        let ack_path_on_b = AckPathV2::new(&packet.client_id_on_b, packet.seq_on_a);
+
+        ctx_a.gas_meter().charge(GasOperation::ProofVerification {
+            kind: ProofKind::PacketAcknowledgement,
+            proof: &msg.proof_acked_on_b,
+        })?;
+        ctx_a
+            .metrics()
+            .observe_proof_verification(ProofKind::PacketAcknowledgement, || {
+                client_state_of_b_on_a.verify_membership_with_context(
+                    client_val_ctx_a,
+                    &counterparty.merkle_prefix,
+                    &msg.proof_acked_on_b,
+                    consensus_state_of_b_on_a.root(),
+                    Path::AckV2(ack_path_on_b),
+                    ack_commitment.into_vec(),
+                )
+            })
+            .map_err(|e| ChannelError::PacketVerificationFailed {
+                sequence: packet.seq_on_a,
+                client_error: e,
+            })
+            .map_err(PacketError::Channel)?;
+    }
+
+    for (i, payload) in packet.payloads.iter().enumerate() {
+        route(router, &payload.port_id_on_a)?.on_acknowledgement_packet_validate(
+            &packet.client_id_on_a,
+            &packet.client_id_on_b,
+            packet.seq_on_a,
+            payload,
+            app_acknowledgement(msg, i),
+            &msg.signer,
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Acknowledges the packet without any validation.
+///
+/// A prior call to [`acknowledgement_packet_v2_validate`] MUST have
+/// succeeded.
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(
+        level = "debug",
+        skip_all,
+        fields(client_id = %msg.packet.client_id_on_a, sequence = %msg.packet.seq_on_a),
+    )
+)]
+pub fn acknowledgement_packet_v2_execute<ExecCtx>(
+    ctx_a: &mut ExecCtx,
+    router: &mut impl RouterV2,
+    msg: MsgAcknowledgement,
+) -> Result<(), ContextError>
+where
+    ExecCtx: PacketV2ExecutionContext,
+{
+    let packet = &msg.packet;
+
+    let commitment_path_on_a = CommitmentPathV2::new(&packet.client_id_on_a, packet.seq_on_a);
+
+    // check if we're in the NO-OP case
+    if ctx_a
+        .get_packet_commitment_v2(&commitment_path_on_a)
+        .is_err()
+    {
+        return Ok(());
+    }
+
+    ctx_a.gas_meter().charge(GasOperation::StoreWrite)?;
+    ctx_a.delete_packet_commitment_v2(&commitment_path_on_a)?;
+
+    let mut module_extras = Vec::new();
+
+    for (i, payload) in packet.payloads.iter().enumerate() {
+        let (extras, cb_result) = route_mut(router, &payload.port_id_on_a)?
+            .on_acknowledgement_packet_execute(
+                &packet.client_id_on_a,
+                &packet.client_id_on_b,
+                packet.seq_on_a,
+                payload,
+                app_acknowledgement(&msg, i),
+                &msg.signer,
+            );
+
+        cb_result?;
+
+        module_extras.push(extras);
+    }
+
+    // emit events and logs
+    {
+        ctx_a.log_message("success: packet acknowledgement".to_string())?;
+
+        let event = IbcEvent::AcknowledgePacketV2(AcknowledgePacket::new(msg.packet.clone()));
+        ctx_a.emit_ibc_event(IbcEvent::Message(MessageEvent::Channel))?;
+        ctx_a.emit_ibc_event(event)?;
+
+        for extras in module_extras {
+            for module_event in extras.events {
+                ctx_a.emit_ibc_event(IbcEvent::Module(module_event))?;
+            }
+
+            for log_message in extras.log {
+                ctx_a.log_message(log_message)?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Returns the acknowledgement of the `i`-th payload of the packet, which is
+/// the error acknowledgement for all of them if the packet failed to be
+/// received.
+fn app_acknowledgement(msg: &MsgAcknowledgement, i: usize) -> &[u8] {
+    let app_acknowledgements = msg.acknowledgement.app_acknowledgements();
+
+    if msg.acknowledgement.is_error() {
+        &app_acknowledgements[0]
+    } else {
+        &app_acknowledgements[i]
+    }
+}
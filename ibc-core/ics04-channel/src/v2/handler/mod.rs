@@ -0,0 +1,88 @@
+//! This module implements the processing logic for IBC v2 packet messages.
+mod acknowledgement;
+mod recv_packet;
+mod send_packet;
+mod timeout;
+
+pub use acknowledgement::*;
+use ibc_core_channel_types::error::PacketError;
+use ibc_core_channel_types::v2::counterparty::ClientCounterparty;
+use ibc_core_channel_types::v2::packet::Payload;
+use ibc_core_handler_types::error::ContextError;
+use ibc_core_host::types::identifiers::{ClientId, PortId};
+use ibc_core_router::types::error::RouterError;
+use ibc_core_router::v2::{ModuleV2, RouterV2};
+pub use recv_packet::*;
+pub use send_packet::*;
+pub use timeout::*;
+
+use super::context::PacketV2ValidationContext;
+
+/// Returns the counterparty of the client `client_id`, checking that it is
+/// the client `counterparty_client_id` the packet is exchanged with.
+fn verify_client_counterparty<Ctx>(
+    ctx: &Ctx,
+    client_id: &ClientId,
+    counterparty_client_id: &ClientId,
+) -> Result<ClientCounterparty, ContextError>
+where
+    Ctx: PacketV2ValidationContext,
+{
+    let counterparty = ctx.client_counterparty(client_id)?;
+
+    if &counterparty.client_id != counterparty_client_id {
+        return Err(PacketError::InvalidClientCounterparty {
+            client_id: client_id.clone(),
+            counterparty_client_id: counterparty.client_id,
+            expected: counterparty_client_id.clone(),
+        }
+        .into());
+    }
+
+    Ok(counterparty)
+}
+
+/// Checks that none of the payloads exceeds the maximum packet data size of
+/// the host.
+fn verify_payload_sizes<Ctx>(ctx: &Ctx, payloads: &[Payload]) -> Result<(), ContextError>
+where
+    Ctx: PacketV2ValidationContext,
+{
+    if let Some(max_size) = ctx.max_packet_data_size() {
+        for payload in payloads {
+            if payload.value.len() > max_size {
+                return Err(PacketError::PacketDataTooLarge {
+                    size: payload.value.len(),
+                    max_size,
+                }
+                .into());
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn route<'r>(
+    router: &'r impl RouterV2,
+    port_id: &PortId,
+) -> Result<&'r dyn ModuleV2, ContextError> {
+    router.get_route_v2(port_id).ok_or_else(|| {
+        RouterError::UnknownPort {
+            port_id: port_id.clone(),
+        }
+        .into()
+    })
+}
+
+fn route_mut<'r>(
+    router: &'r mut impl RouterV2,
+    port_id: &PortId,
+) -> Result<&'r mut dyn ModuleV2, ContextError> {
+    router.get_route_v2_mut(port_id).ok_or_else(|| {
+        RouterError::UnknownPort {
+            port_id: port_id.clone(),
+        }
+        .into()
+    })
+}
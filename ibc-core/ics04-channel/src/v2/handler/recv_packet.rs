@@ -0,0 +1,222 @@
+//! Protocol logic specific to IBC v2 messages of type `MsgRecvPacket`.
+use ibc_core_channel_types::error::{ChannelError, PacketError};
+use ibc_core_channel_types::packet::Receipt;
+use ibc_core_channel_types::v2::acknowledgement::{Acknowledgement, PacketStatus};
+use ibc_core_channel_types::v2::commitment::{
+    compute_ack_commitment_v2, compute_packet_commitment_v2,
+};
+use ibc_core_channel_types::v2::events::{ReceivePacket, WriteAcknowledgement};
+use ibc_core_channel_types::v2::msgs::MsgRecvPacket;
+use ibc_core_client::context::prelude::*;
+use ibc_core_handler_types::error::ContextError;
+use ibc_core_handler_types::events::{IbcEvent, MessageEvent};
+use ibc_core_host::types::path::{
+    AckPathV2, ClientConsensusStatePath, CommitmentPathV2, Path, ReceiptPathV2,
+};
+use ibc_core_host::{GasOperation, ProofKind};
+use ibc_core_router::v2::RouterV2;
+use ibc_primitives::prelude::*;
+
+use super::{route, route_mut, verify_client_counterparty, verify_payload_sizes};
+use crate::v2::context::{PacketV2ExecutionContext, PacketV2ValidationContext};
+
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(
+        level = "debug",
+        skip_all,
+        fields(client_id = %msg.packet.client_id_on_b, sequence = %msg.packet.seq_on_a),
+    )
+)]
+pub fn recv_packet_v2_validate<ValCtx>(
+    ctx_b: &ValCtx,
+    router: &impl RouterV2,
+    msg: &MsgRecvPacket,
+) -> Result<(), ContextError>
+where
+    ValCtx: PacketV2ValidationContext,
+{
+    ctx_b.validate_message_signer(&msg.signer)?;
+
+    let packet = &msg.packet;
+
+    verify_payload_sizes(ctx_b, &packet.payloads)?;
+
+    let counterparty =
+        verify_client_counterparty(ctx_b, &packet.client_id_on_b, &packet.client_id_on_a)?;
+
+    if packet.timed_out(&ctx_b.host_timestamp()?) {
+        return Err(PacketError::LowPacketTimestamp.into());
+    }
+
+    // A packet which was already received is a no-op, so that the
+    // transactions of competing relayers do not fail.
+    let receipt_path_on_b = ReceiptPathV2::new(&packet.client_id_on_b, packet.seq_on_a);
+    if ctx_b.get_packet_receipt_v2(&receipt_path_on_b).is_ok() {
+        return Ok(());
+    }
+
+    let ack_path_on_b = AckPathV2::new(&packet.client_id_on_b, packet.seq_on_a);
+    if ctx_b.get_packet_acknowledgement_v2(&ack_path_on_b).is_ok() {
+        return Err(PacketError::AcknowledgementExists {
+            sequence: packet.seq_on_a,
+        }
+        .into());
+    }
+
+    // Verify proofs
+    {
+        let client_id_on_b = &packet.client_id_on_b;
+        let client_val_ctx_b = ctx_b.get_client_validation_context();
+        let client_state_of_a_on_b = client_val_ctx_b.client_state(client_id_on_b)?;
+
+        client_state_of_a_on_b
+            .status(client_val_ctx_b, client_id_on_b)?
+            .verify_is_active()?;
+
+        client_state_of_a_on_b.validate_proof_height(msg.proof_height_on_a)?;
+
+        let client_cons_state_path_on_b = ClientConsensusStatePath::new(
+            client_id_on_b.clone(),
+            msg.proof_height_on_a.revision_number(),
+            msg.proof_height_on_a.revision_height(),
+        );
+        let consensus_state_of_a_on_b =
+            client_val_ctx_b.consensus_state(&client_cons_state_path_on_b)?;
+
+        let expected_commitment_on_a = compute_packet_commitment_v2(packet);
+        let commitment_path_on_a = CommitmentPathV2::new(&packet.client_id_on_a, packet.seq_on_a);
+
+        ctx_b.gas_meter().charge(GasOperation::ProofVerification {
+            kind: ProofKind::PacketCommitment,
+            proof: &msg.proof_commitment_on_a,
+        })?;
+        ctx_b
+            .metrics()
+            .observe_proof_verification(ProofKind::PacketCommitment, || {
+                client_state_of_a_on_b.verify_membership_with_context(
+                    client_val_ctx_b,
+                    &counterparty.merkle_prefix,
+                    &msg.proof_commitment_on_a,
+                    consensus_state_of_a_on_b.root(),
+                    Path::CommitmentV2(commitment_path_on_a),
+                    expected_commitment_on_a.into_vec(),
+                )
+            })
+            .map_err(|e| ChannelError::PacketVerificationFailed {
+                sequence: packet.seq_on_a,
+                client_error: e,
+            })
+            .map_err(PacketError::Channel)?;
+    }
+
+    // nothing to validate with the modules, since `onRecvPacket` cannot
+    // fail: they must be bound to the destination ports though.
+    for payload in &packet.payloads {
+        route(router, &payload.port_id_on_b)?;
+    }
+
+    Ok(())
+}
+
+/// Receives the packet, and writes its acknowledgement.
+///
+/// The payloads are delivered to their applications in turn. If any of them
+/// fails to be received, the packet is acknowledged with the error
+/// acknowledgement, but the state changes of the applications which already
+/// received their payload are not reverted: applications must not alter
+/// their state when they fail, and packets whose payloads depend on one
+/// another are better sent to a single application.
+///
+/// A prior call to [`recv_packet_v2_validate`] MUST have succeeded.
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(
+        level = "debug",
+        skip_all,
+        fields(client_id = %msg.packet.client_id_on_b, sequence = %msg.packet.seq_on_a),
+    )
+)]
+pub fn recv_packet_v2_execute<ExecCtx>(
+    ctx_b: &mut ExecCtx,
+    router: &mut impl RouterV2,
+    msg: MsgRecvPacket,
+) -> Result<(), ContextError>
+where
+    ExecCtx: PacketV2ExecutionContext,
+{
+    let packet = &msg.packet;
+
+    // Check if another relayer already relayed the packet.
+    // We don't want to fail the transaction in this case.
+    let receipt_path_on_b = ReceiptPathV2::new(&packet.client_id_on_b, packet.seq_on_a);
+    if ctx_b.get_packet_receipt_v2(&receipt_path_on_b).is_ok() {
+        return Ok(());
+    }
+
+    ctx_b.gas_meter().charge(GasOperation::StoreWrite)?;
+    ctx_b.store_packet_receipt_v2(&receipt_path_on_b, Receipt::Ok)?;
+
+    let mut module_extras = Vec::new();
+    let mut app_acknowledgements = Vec::new();
+    let mut failed = false;
+
+    for payload in &packet.payloads {
+        let (extras, result) = route_mut(router, &payload.port_id_on_b)?.on_recv_packet_execute(
+            &packet.client_id_on_a,
+            &packet.client_id_on_b,
+            packet.seq_on_a,
+            payload,
+            &msg.signer,
+        );
+        module_extras.push(extras);
+
+        match result.status {
+            PacketStatus::Success => app_acknowledgements.push(result.acknowledgement),
+            PacketStatus::Failure => failed = true,
+        }
+    }
+
+    let acknowledgement = if failed {
+        Acknowledgement::error()
+    } else {
+        Acknowledgement::new(app_acknowledgements)?
+    };
+
+    let ack_path_on_b = AckPathV2::new(&packet.client_id_on_b, packet.seq_on_a);
+    ctx_b.gas_meter().charge(GasOperation::StoreWrite)?;
+    ctx_b.store_packet_acknowledgement_v2(
+        &ack_path_on_b,
+        compute_ack_commitment_v2(&acknowledgement),
+    )?;
+
+    // emit events and logs
+    {
+        ctx_b.log_message("success: packet receive".to_string())?;
+
+        let event = IbcEvent::ReceivePacketV2(ReceivePacket::new(msg.packet.clone()));
+        ctx_b.emit_ibc_event(IbcEvent::Message(MessageEvent::Channel))?;
+        ctx_b.emit_ibc_event(event)?;
+
+        ctx_b.log_message("success: packet write acknowledgement".to_string())?;
+
+        let event = IbcEvent::WriteAcknowledgementV2(WriteAcknowledgement::new(
+            msg.packet,
+            acknowledgement,
+        ));
+        ctx_b.emit_ibc_event(IbcEvent::Message(MessageEvent::Channel))?;
+        ctx_b.emit_ibc_event(event)?;
+
+        for extras in module_extras {
+            for module_event in extras.events {
+                ctx_b.emit_ibc_event(IbcEvent::Module(module_event))?;
+            }
+
+            for log_message in extras.log {
+                ctx_b.log_message(log_message)?;
+            }
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,158 @@
+//! Protocol logic specific to IBC v2 messages of type `MsgSendPacket`.
+use ibc_core_channel_types::error::PacketError;
+use ibc_core_channel_types::v2::commitment::compute_packet_commitment_v2;
+use ibc_core_channel_types::v2::events::SendPacket;
+use ibc_core_channel_types::v2::msgs::MsgSendPacket;
+use ibc_core_channel_types::v2::packet::{timestamp_seconds, Packet, MAX_TIMEOUT_DELTA};
+use ibc_core_client::context::prelude::*;
+use ibc_core_handler_types::error::ContextError;
+use ibc_core_handler_types::events::{IbcEvent, MessageEvent};
+use ibc_core_host::types::path::{CommitmentPathV2, SeqSendPathV2};
+use ibc_core_host::GasOperation;
+use ibc_core_router::v2::RouterV2;
+use ibc_primitives::prelude::*;
+
+use super::{route, route_mut, verify_payload_sizes};
+use crate::v2::context::{PacketV2ExecutionContext, PacketV2ValidationContext};
+
+/// Validates that sending the packet of the message would succeed.
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(
+        level = "debug",
+        skip_all,
+        fields(client_id = %msg.client_id_on_a),
+    )
+)]
+pub fn send_packet_v2_validate<ValCtx>(
+    ctx_a: &ValCtx,
+    router: &impl RouterV2,
+    msg: &MsgSendPacket,
+) -> Result<(), ContextError>
+where
+    ValCtx: PacketV2ValidationContext,
+{
+    ctx_a.validate_message_signer(&msg.signer)?;
+
+    verify_payload_sizes(ctx_a, &msg.payloads)?;
+
+    let counterparty = ctx_a.client_counterparty(&msg.client_id_on_a)?;
+
+    let client_val_ctx_a = ctx_a.get_client_validation_context();
+    let client_state_of_b_on_a = client_val_ctx_a.client_state(&msg.client_id_on_a)?;
+
+    client_state_of_b_on_a
+        .status(client_val_ctx_a, &msg.client_id_on_a)?
+        .verify_is_active()?;
+
+    // The timeout must be in the future of the host, within the maximum
+    // timeout delta.
+    let host_timestamp = timestamp_seconds(&ctx_a.host_timestamp()?);
+
+    if msg.timeout_timestamp_on_b <= host_timestamp {
+        return Err(PacketError::InvalidTimeoutTimestamp {
+            timeout_timestamp: msg.timeout_timestamp_on_b,
+            reason: "the timeout has already passed".to_string(),
+        }
+        .into());
+    }
+
+    if msg.timeout_timestamp_on_b - host_timestamp > MAX_TIMEOUT_DELTA {
+        return Err(PacketError::InvalidTimeoutTimestamp {
+            timeout_timestamp: msg.timeout_timestamp_on_b,
+            reason: format!("the timeout is more than {MAX_TIMEOUT_DELTA} seconds ahead"),
+        }
+        .into());
+    }
+
+    let seq_send_path_on_a = SeqSendPathV2::new(&msg.client_id_on_a);
+    let seq_on_a = ctx_a.get_next_sequence_send_v2(&seq_send_path_on_a)?;
+
+    for payload in &msg.payloads {
+        route(router, &payload.port_id_on_a)?.on_send_packet_validate(
+            &msg.client_id_on_a,
+            &counterparty.client_id,
+            seq_on_a,
+            payload,
+            &msg.signer,
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Sends the packet without any validation.
+///
+/// A prior call to [`send_packet_v2_validate`] MUST have succeeded.
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(
+        level = "debug",
+        skip_all,
+        fields(client_id = %msg.client_id_on_a),
+    )
+)]
+pub fn send_packet_v2_execute<ExecCtx>(
+    ctx_a: &mut ExecCtx,
+    router: &mut impl RouterV2,
+    msg: MsgSendPacket,
+) -> Result<(), ContextError>
+where
+    ExecCtx: PacketV2ExecutionContext,
+{
+    let counterparty = ctx_a.client_counterparty(&msg.client_id_on_a)?;
+
+    let seq_send_path_on_a = SeqSendPathV2::new(&msg.client_id_on_a);
+    let seq_on_a = ctx_a.get_next_sequence_send_v2(&seq_send_path_on_a)?;
+
+    ctx_a.gas_meter().charge(GasOperation::StoreWrite)?;
+    ctx_a.store_next_sequence_send_v2(&seq_send_path_on_a, seq_on_a.increment())?;
+
+    let mut module_extras = Vec::new();
+
+    for payload in &msg.payloads {
+        let extras = route_mut(router, &payload.port_id_on_a)?.on_send_packet_execute(
+            &msg.client_id_on_a,
+            &counterparty.client_id,
+            seq_on_a,
+            payload,
+            &msg.signer,
+        )?;
+        module_extras.push(extras);
+    }
+
+    let packet = Packet {
+        seq_on_a,
+        client_id_on_a: msg.client_id_on_a,
+        client_id_on_b: counterparty.client_id,
+        timeout_timestamp_on_b: msg.timeout_timestamp_on_b,
+        payloads: msg.payloads,
+    };
+
+    ctx_a.gas_meter().charge(GasOperation::StoreWrite)?;
+    ctx_a.store_packet_commitment_v2(
+        &CommitmentPathV2::new(&packet.client_id_on_a, packet.seq_on_a),
+        compute_packet_commitment_v2(&packet),
+    )?;
+
+    // emit events and logs
+    {
+        ctx_a.log_message("success: packet send".to_string())?;
+
+        let event = IbcEvent::SendPacketV2(SendPacket::new(packet));
+        ctx_a.emit_ibc_event(IbcEvent::Message(MessageEvent::Channel))?;
+        ctx_a.emit_ibc_event(event)?;
+
+        for extras in module_extras {
+            for module_event in extras.events {
+                ctx_a.emit_ibc_event(IbcEvent::Module(module_event))?;
+            }
+
+            for log_message in extras.log {
+                ctx_a.log_message(log_message)?;
+            }
+        }
+    }
+
+    Ok(())
+}
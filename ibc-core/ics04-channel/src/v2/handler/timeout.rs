@@ -0,0 +1,194 @@
+//! Protocol logic specific to IBC v2 messages of type `MsgTimeout`.
+use ibc_core_channel_types::error::{ChannelError, PacketError};
+use ibc_core_channel_types::v2::commitment::compute_packet_commitment_v2;
+use ibc_core_channel_types::v2::events::TimeoutPacket;
+use ibc_core_channel_types::v2::msgs::MsgTimeout;
+use ibc_core_channel_types::v2::packet::timestamp_seconds;
+use ibc_core_client::context::prelude::*;
+use ibc_core_handler_types::error::ContextError;
+use ibc_core_handler_types::events::{IbcEvent, MessageEvent};
+use ibc_core_host::types::path::{ClientConsensusStatePath, CommitmentPathV2, Path, ReceiptPathV2};
+use ibc_core_host::{GasOperation, ProofKind};
+use ibc_core_router::v2::RouterV2;
+use ibc_primitives::prelude::*;
+
+use super::{route, route_mut, verify_client_counterparty};
+use crate::v2::context::{PacketV2ExecutionContext, PacketV2ValidationContext};
+
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(
+        level = "debug",
+        skip_all,
+        fields(client_id = %msg.packet.client_id_on_a, sequence = %msg.packet.seq_on_a),
+    )
+)]
+pub fn timeout_packet_v2_validate<ValCtx>(
+    ctx_a: &ValCtx,
+    router: &impl RouterV2,
+    msg: &MsgTimeout,
+) -> Result<(), ContextError>
+where
+    ValCtx: PacketV2ValidationContext,
+{
+    ctx_a.validate_message_signer(&msg.signer)?;
+
+    let packet = &msg.packet;
+
+    let counterparty =
+        verify_client_counterparty(ctx_a, &packet.client_id_on_a, &packet.client_id_on_b)?;
+
+    let commitment_path_on_a = CommitmentPathV2::new(&packet.client_id_on_a, packet.seq_on_a);
+
+    // Timing out a packet which was already acknowledged or timed out is a
+    // no-op, so that the transactions of competing relayers do not fail.
+    let Ok(commitment_on_a) = ctx_a.get_packet_commitment_v2(&commitment_path_on_a) else {
+        return Ok(());
+    };
+
+    if commitment_on_a != compute_packet_commitment_v2(packet) {
+        return Err(PacketError::IncorrectPacketCommitment {
+            sequence: packet.seq_on_a,
+        }
+        .into());
+    }
+
+    // Verify proofs
+    {
+        let client_id_on_a = &packet.client_id_on_a;
+        let client_val_ctx_a = ctx_a.get_client_validation_context();
+        let client_state_of_b_on_a = client_val_ctx_a.client_state(client_id_on_a)?;
+
+        client_state_of_b_on_a
+            .status(client_val_ctx_a, client_id_on_a)?
+            .verify_is_active()?;
+
+        client_state_of_b_on_a.validate_proof_height(msg.proof_height_on_b)?;
+
+        let client_cons_state_path_on_a = ClientConsensusStatePath::new(
+            client_id_on_a.clone(),
+            msg.proof_height_on_b.revision_number(),
+            msg.proof_height_on_b.revision_height(),
+        );
+        let consensus_state_of_b_on_a =
+            client_val_ctx_a.consensus_state(&client_cons_state_path_on_a)?;
+
+        // check that timeout timestamp has passed on the other end
+        let timestamp_of_b = consensus_state_of_b_on_a.timestamp();
+        if !packet.timed_out(&timestamp_of_b) {
+            return Err(PacketError::PacketTimeoutTimestampNotReached {
+                timeout_timestamp: packet.timeout_timestamp_on_b,
+                chain_timestamp: timestamp_seconds(&timestamp_of_b),
+            }
+            .into());
+        }
+
+        let receipt_path_on_b = ReceiptPathV2::new(&packet.client_id_on_b, packet.seq_on_a);
+
+        ctx_a.gas_meter().charge(GasOperation::ProofVerification {
+            kind: ProofKind::PacketReceipt,
+            proof: &msg.proof_unreceived_on_b,
+        })?;
+        ctx_a
+            .metrics()
+            .observe_proof_verification(ProofKind::PacketReceipt, || {
+                client_state_of_b_on_a.verify_non_membership_with_context(
+                    client_val_ctx_a,
+                    &counterparty.merkle_prefix,
+                    &msg.proof_unreceived_on_b,
+                    consensus_state_of_b_on_a.root(),
+                    Path::ReceiptV2(receipt_path_on_b),
+                )
+            })
+            .map_err(|e| ChannelError::PacketVerificationFailed {
+                sequence: packet.seq_on_a,
+                client_error: e,
+            })
+            .map_err(PacketError::Channel)?;
+    }
+
+    for payload in &packet.payloads {
+        route(router, &payload.port_id_on_a)?.on_timeout_packet_validate(
+            &packet.client_id_on_a,
+            &packet.client_id_on_b,
+            packet.seq_on_a,
+            payload,
+            &msg.signer,
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Times out the packet without any validation.
+///
+/// A prior call to [`timeout_packet_v2_validate`] MUST have succeeded.
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(
+        level = "debug",
+        skip_all,
+        fields(client_id = %msg.packet.client_id_on_a, sequence = %msg.packet.seq_on_a),
+    )
+)]
+pub fn timeout_packet_v2_execute<ExecCtx>(
+    ctx_a: &mut ExecCtx,
+    router: &mut impl RouterV2,
+    msg: MsgTimeout,
+) -> Result<(), ContextError>
+where
+    ExecCtx: PacketV2ExecutionContext,
+{
+    let packet = &msg.packet;
+
+    let commitment_path_on_a = CommitmentPathV2::new(&packet.client_id_on_a, packet.seq_on_a);
+
+    // check if we're in the NO-OP case
+    if ctx_a
+        .get_packet_commitment_v2(&commitment_path_on_a)
+        .is_err()
+    {
+        return Ok(());
+    }
+
+    ctx_a.gas_meter().charge(GasOperation::StoreWrite)?;
+    ctx_a.delete_packet_commitment_v2(&commitment_path_on_a)?;
+
+    let mut module_extras = Vec::new();
+
+    for payload in &packet.payloads {
+        let (extras, cb_result) = route_mut(router, &payload.port_id_on_a)?
+            .on_timeout_packet_execute(
+                &packet.client_id_on_a,
+                &packet.client_id_on_b,
+                packet.seq_on_a,
+                payload,
+                &msg.signer,
+            );
+
+        cb_result?;
+
+        module_extras.push(extras);
+    }
+
+    // emit events and logs
+    {
+        ctx_a.log_message("success: packet timeout".to_string())?;
+
+        let event = IbcEvent::TimeoutPacketV2(TimeoutPacket::new(msg.packet.clone()));
+        ctx_a.emit_ibc_event(IbcEvent::Message(MessageEvent::Channel))?;
+        ctx_a.emit_ibc_event(event)?;
+
+        for extras in module_extras {
+            for module_event in extras.events {
+                ctx_a.emit_ibc_event(IbcEvent::Module(module_event))?;
+            }
+
+            for log_message in extras.log {
+                ctx_a.log_message(log_message)?;
+            }
+        }
+    }
+
+    Ok(())
+}
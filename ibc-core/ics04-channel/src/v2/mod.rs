@@ -0,0 +1,4 @@
+//! Implementation of the IBC v2 packet handlers, which process the packets
+//! sent over clients rather than over channel ends.
+pub mod context;
+pub mod handler;
@@ -2,6 +2,8 @@
 
 use core::fmt::{Display, Error as FmtError, Formatter};
 
+use base64::prelude::BASE64_STANDARD;
+use base64::Engine;
 use derive_more::Into;
 use ibc_primitives::prelude::*;
 
@@ -52,10 +54,43 @@ impl TryFrom<Vec<u8>> for Acknowledgement {
     }
 }
 
+/// The message ibc-go writes in the error acknowledgements in place of the
+/// details of the error, which are only emitted in the events, for the
+/// acknowledgements to be deterministic.
+pub const ACK_ERR_DETAILS_STR: &str = "error handling packet: see events for details";
+
+/// Returns the message of the error acknowledgements written by ibc-go for
+/// an application error of the given ABCI code, that is
+/// `ABCI code: <code>: error handling packet: see events for details`.
+pub fn abci_error_message(code: u32) -> String {
+    alloc::format!("ABCI code: {code}: {ACK_ERR_DETAILS_STR}")
+}
+
+/// Parses the ABCI code of the application error out of the message of an
+/// error acknowledgement written by ibc-go, or as ibc-go does.
+pub fn parse_abci_error_code(message: &str) -> Option<u32> {
+    let (code, _) = message.strip_prefix("ABCI code: ")?.split_once(": ")?;
+
+    code.parse().ok()
+}
+
 /// Defines a convenience type for IBC applications to construct an
 /// [`Acknowledgement`] based on the
 /// success or failure of processing a received packet.
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub enum AcknowledgementStatus {
     /// Successful Acknowledgement
@@ -71,7 +106,20 @@ pub enum AcknowledgementStatus {
 /// A wrapper type that guards variants of
 /// [`AcknowledgementStatus`]
 /// against being constructed with an empty value.
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct StatusValue(String);
 
@@ -88,6 +136,42 @@ impl StatusValue {
     }
 }
 
+impl StatusValue {
+    /// Constructs the status value of an error acknowledgement written, as
+    /// ibc-go does, for an application error of the given ABCI code.
+    ///
+    /// See [`abci_error_message`] for the format of the value.
+    pub fn from_abci_error_code(code: u32) -> Self {
+        Self(abci_error_message(code))
+    }
+
+    /// Returns the ABCI code of the application error, if the status value is
+    /// the one of an error acknowledgement written as ibc-go does.
+    pub fn abci_error_code(&self) -> Option<u32> {
+        parse_abci_error_code(&self.0)
+    }
+}
+
+impl StatusValue {
+    /// Constructs the status value of a success acknowledgement carrying the
+    /// given result, base64 encoded as ICS-20 and ICS-27 applications do.
+    ///
+    /// An empty result is rejected, as it is by ibc-go.
+    pub fn from_result(result: impl AsRef<[u8]>) -> Result<Self, PacketError> {
+        Self::new(BASE64_STANDARD.encode(result))
+    }
+
+    /// Returns the result carried by the status value of a success
+    /// acknowledgement, base64 decoded.
+    pub fn decoded_result(&self) -> Result<Vec<u8>, PacketError> {
+        BASE64_STANDARD
+            .decode(&self.0)
+            .map_err(|e| PacketError::InvalidAcknowledgementEnvelope {
+                reason: e.to_string(),
+            })
+    }
+}
+
 impl Display for StatusValue {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), FmtError> {
         write!(f, "{status_value}", status_value = self.0)
@@ -125,12 +209,35 @@ impl From<AcknowledgementStatus> for Vec<u8> {
         // WARNING: Make sure all branches always return a non-empty vector.
         // Otherwise, the conversion to `Acknowledgement` will panic.
         match ack {
-            AcknowledgementStatus::Success(v) => alloc::format!(r#"{{"result":"{v}"}}"#).into(),
-            AcknowledgementStatus::Error(v) => alloc::format!(r#"{{"error":"{v}"}}"#).into(),
+            AcknowledgementStatus::Success(v) => {
+                alloc::format!(r#"{{"result":"{}"}}"#, escape_json(&v.0)).into()
+            }
+            AcknowledgementStatus::Error(v) => {
+                alloc::format!(r#"{{"error":"{}"}}"#, escape_json(&v.0)).into()
+            }
         }
     }
 }
 
+/// Escapes the given value to be written in a JSON string.
+fn escape_json(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+
+    for c in value.chars() {
+        match c {
+            '"' => escaped.push_str(r#"\""#),
+            '\\' => escaped.push_str(r"\\"),
+            '\n' => escaped.push_str(r"\n"),
+            '\r' => escaped.push_str(r"\r"),
+            '\t' => escaped.push_str(r"\t"),
+            c if c.is_control() => escaped.push_str(&alloc::format!("\\u{:04x}", u32::from(c))),
+            c => escaped.push(c),
+        }
+    }
+
+    escaped
+}
+
 impl From<AcknowledgementStatus> for Acknowledgement {
     fn from(ack_status: AcknowledgementStatus) -> Self {
         let v: Vec<u8> = ack_status.into();
@@ -139,3 +246,98 @@ impl From<AcknowledgementStatus> for Acknowledgement {
             .expect("token transfer internal error: ack is never supposed to be empty")
     }
 }
+
+/// Parses the acknowledgement status out of the standard JSON envelope of
+/// the acknowledgements, i.e. `{"result":"<base64 encoded result>"}` or
+/// `{"error":"<error message>"}`, as written by ICS-20 and ICS-27
+/// applications.
+#[cfg(feature = "serde")]
+impl TryFrom<&Acknowledgement> for AcknowledgementStatus {
+    type Error = PacketError;
+
+    fn try_from(ack: &Acknowledgement) -> Result<Self, PacketError> {
+        let ack_status: Self = serde_json::from_slice(ack.as_bytes()).map_err(|e| {
+            PacketError::InvalidAcknowledgementEnvelope {
+                reason: e.to_string(),
+            }
+        })?;
+
+        match &ack_status {
+            AcknowledgementStatus::Success(v) | AcknowledgementStatus::Error(v)
+                if v.0.is_empty() =>
+            {
+                Err(PacketError::EmptyAcknowledgementStatus)
+            }
+            _ => Ok(ack_status),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_acknowledgement_status_encoding() {
+        let ack_status = AcknowledgementStatus::success(StatusValue::from_result([1]).unwrap());
+        let ack: Acknowledgement = ack_status.clone().into();
+        assert_eq!(ack.as_bytes(), br#"{"result":"AQ=="}"#);
+        #[cfg(feature = "serde")]
+        assert_eq!(AcknowledgementStatus::try_from(&ack).unwrap(), ack_status);
+
+        let ack_status =
+            AcknowledgementStatus::error(StatusValue::new(r#"invalid "denom""#).unwrap());
+        let ack: Acknowledgement = ack_status.clone().into();
+        assert_eq!(ack.as_bytes(), br#"{"error":"invalid \"denom\""}"#);
+        #[cfg(feature = "serde")]
+        assert_eq!(AcknowledgementStatus::try_from(&ack).unwrap(), ack_status);
+    }
+
+    #[test]
+    fn test_status_value_result() {
+        let value = StatusValue::from_result([1, 2, 3]).unwrap();
+        assert_eq!(value.decoded_result().unwrap(), vec![1, 2, 3]);
+
+        assert!(StatusValue::from_result([]).is_err());
+        assert!(StatusValue::new("not base64!")
+            .unwrap()
+            .decoded_result()
+            .is_err());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_acknowledgement_status_invalid() {
+        for ack in [
+            br#"{"success":"AQ=="}"#.to_vec(),
+            br#"{"error":""}"#.to_vec(),
+            vec![1],
+        ] {
+            let ack = Acknowledgement::try_from(ack).unwrap();
+            assert!(AcknowledgementStatus::try_from(&ack).is_err());
+        }
+
+        assert!(StatusValue::new("").is_err());
+    }
+
+    #[test]
+    fn test_abci_error_code() {
+        let value = StatusValue::from_abci_error_code(5);
+        let ack: Acknowledgement = AcknowledgementStatus::error(value.clone()).into();
+
+        // Check that it's the same output as ibc-go
+        assert_eq!(
+            ack.as_bytes(),
+            br#"{"error":"ABCI code: 5: error handling packet: see events for details"}"#
+        );
+        assert_eq!(value.abci_error_code(), Some(5));
+
+        for message in [
+            "invalid token amount",
+            "ABCI code: five: error handling packet",
+            "ABCI code: 5",
+        ] {
+            assert_eq!(parse_abci_error_code(message), None);
+        }
+    }
+}
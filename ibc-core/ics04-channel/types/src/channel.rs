@@ -52,10 +52,6 @@ impl TryFrom<RawIdentifiedChannel> for IdentifiedChannelEnd {
     type Error = ChannelError;
 
     fn try_from(value: RawIdentifiedChannel) -> Result<Self, Self::Error> {
-        if value.upgrade_sequence != 0 {
-            return Err(ChannelError::UnsupportedChannelUpgradeSequence);
-        }
-
         let raw_channel_end = RawChannel {
             state: value.state,
             ordering: value.ordering,
@@ -88,7 +84,7 @@ impl From<IdentifiedChannelEnd> for RawIdentifiedChannel {
             version: value.channel_end.version.to_string(),
             port_id: value.port_id.to_string(),
             channel_id: value.channel_id.to_string(),
-            upgrade_sequence: 0,
+            upgrade_sequence: value.channel_end.upgrade_sequence,
         }
     }
 }
@@ -107,6 +103,7 @@ impl From<IdentifiedChannelEnd> for RawIdentifiedChannel {
     derive(borsh::BorshSerialize, borsh::BorshDeserialize)
 )]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct ChannelEnd {
     pub state: State,
@@ -114,14 +111,17 @@ pub struct ChannelEnd {
     pub remote: Counterparty,
     pub connection_hops: Vec<ConnectionId>,
     pub version: Version,
+    /// The sequence of the latest upgrade attempted on this channel, which is
+    /// zero until the channel goes through an upgrade handshake.
+    pub upgrade_sequence: u64,
 }
 
 impl Display for ChannelEnd {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), FmtError> {
         write!(
             f,
-            "ChannelEnd {{ state: {}, ordering: {}, remote: {}, connection_hops: {}, version: {} }}",
-            self.state, self.ordering, self.remote, PrettySlice(&self.connection_hops), self.version
+            "ChannelEnd {{ state: {}, ordering: {}, remote: {}, connection_hops: {}, version: {}, upgrade_sequence: {} }}",
+            self.state, self.ordering, self.remote, PrettySlice(&self.connection_hops), self.version, self.upgrade_sequence
         )
     }
 }
@@ -151,7 +151,10 @@ impl TryFrom<RawChannel> for ChannelEnd {
 
         let version = value.version.into();
 
-        ChannelEnd::new(chan_state, chan_ordering, remote, connection_hops, version)
+        Ok(
+            ChannelEnd::new(chan_state, chan_ordering, remote, connection_hops, version)?
+                .with_upgrade_sequence(value.upgrade_sequence),
+        )
     }
 }
 
@@ -167,7 +170,7 @@ impl From<ChannelEnd> for RawChannel {
                 .map(|v| v.as_str().to_string())
                 .collect(),
             version: value.version.to_string(),
-            upgrade_sequence: 0,
+            upgrade_sequence: value.upgrade_sequence,
         }
     }
 }
@@ -191,6 +194,7 @@ impl ChannelEnd {
             remote,
             connection_hops,
             version,
+            upgrade_sequence: 0,
         }
     }
 
@@ -221,6 +225,19 @@ impl ChannelEnd {
         self.remote.channel_id = Some(c);
     }
 
+    pub fn set_upgrade_sequence(&mut self, upgrade_sequence: u64) {
+        self.upgrade_sequence = upgrade_sequence;
+    }
+
+    /// Returns this `ChannelEnd` with its upgrade sequence set to
+    /// `upgrade_sequence`.
+    pub fn with_upgrade_sequence(self, upgrade_sequence: u64) -> Self {
+        Self {
+            upgrade_sequence,
+            ..self
+        }
+    }
+
     /// Returns `true` if this `ChannelEnd` is in state [`State::Open`].
     pub fn is_open(&self) -> bool {
         self.state == State::Open
@@ -246,6 +263,10 @@ impl ChannelEnd {
         &self.version
     }
 
+    pub fn upgrade_sequence(&self) -> u64 {
+        self.upgrade_sequence
+    }
+
     pub fn validate_basic(&self) -> Result<(), ChannelError> {
         if self.state == State::Uninitialized {
             return Err(ChannelError::InvalidState {
@@ -349,6 +370,7 @@ pub(crate) fn verify_connection_hops_length(
     derive(borsh::BorshSerialize, borsh::BorshDeserialize)
 )]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct Counterparty {
     pub port_id: PortId,
@@ -451,6 +473,12 @@ pub enum Order {
     None = 0isize,
     Unordered = 1isize,
     Ordered = 2isize,
+    /// Packets are received in order, but a packet which times out skips its
+    /// sequence instead of closing the channel. The connection version must
+    /// list the `ORDER_ORDERED_ALLOW_TIMEOUT` feature, which
+    /// [`Version::compatibles`](ibc_core_connection_types::version::Version::compatibles)
+    /// doesn't.
+    OrderedAllowTimeout = 3isize,
 }
 
 impl Display for Order {
@@ -467,6 +495,7 @@ impl Order {
             Self::None => "ORDER_NONE_UNSPECIFIED",
             Self::Unordered => "ORDER_UNORDERED",
             Self::Ordered => "ORDER_ORDERED",
+            Self::OrderedAllowTimeout => "ORDER_ORDERED_ALLOW_TIMEOUT",
         }
     }
 
@@ -476,8 +505,9 @@ impl Order {
             0 => Ok(Self::None),
             1 => Ok(Self::Unordered),
             2 => Ok(Self::Ordered),
+            3 => Ok(Self::OrderedAllowTimeout),
             _ => Err(ChannelError::InvalidOrderType {
-                expected: "Must be one of 0, 1, 2".to_string(),
+                expected: "Must be one of 0, 1, 2, 3".to_string(),
                 actual: nr.to_string(),
             }),
         }
@@ -492,8 +522,10 @@ impl FromStr for Order {
             "uninitialized" => Ok(Self::None),
             "unordered" => Ok(Self::Unordered),
             "ordered" => Ok(Self::Ordered),
+            "ordered_allow_timeout" => Ok(Self::OrderedAllowTimeout),
             _ => Err(ChannelError::InvalidOrderType {
-                expected: "Must be one of 'uninitialized', 'unordered', 'ordered'".to_string(),
+                expected: "Must be one of 'uninitialized', 'unordered', 'ordered', 'ordered_allow_timeout'"
+                    .to_string(),
                 actual: s.to_string(),
             }),
         }
@@ -514,6 +546,7 @@ impl FromStr for Order {
     derive(borsh::BorshSerialize, borsh::BorshDeserialize)
 )]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub enum State {
     Uninitialized = 0isize,
@@ -521,6 +554,12 @@ pub enum State {
     TryOpen = 2isize,
     Open = 3isize,
     Closed = 4isize,
+    /// The channel is being upgraded and is waiting for its in-flight packets
+    /// to be flushed before the upgrade can be opened.
+    Flushing = 5isize,
+    /// All the in-flight packets of a channel being upgraded have been
+    /// flushed.
+    FlushComplete = 6isize,
 }
 
 impl State {
@@ -532,6 +571,8 @@ impl State {
             Self::TryOpen => "TRYOPEN",
             Self::Open => "OPEN",
             Self::Closed => "CLOSED",
+            Self::Flushing => "FLUSHING",
+            Self::FlushComplete => "FLUSHCOMPLETE",
         }
     }
 
@@ -543,8 +584,10 @@ impl State {
             2 => Ok(Self::TryOpen),
             3 => Ok(Self::Open),
             4 => Ok(Self::Closed),
+            5 => Ok(Self::Flushing),
+            6 => Ok(Self::FlushComplete),
             _ => Err(ChannelError::InvalidState {
-                expected: "Must be one of: 0, 1, 2, 3, 4".to_string(),
+                expected: "Must be one of: 0, 1, 2, 3, 4, 5, 6".to_string(),
                 actual: s.to_string(),
             }),
         }
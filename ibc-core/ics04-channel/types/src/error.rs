@@ -4,9 +4,10 @@ use displaydoc::Display;
 use ibc_core_client_types::{error as client_error, Height};
 use ibc_core_connection_types::error as connection_error;
 use ibc_core_host_types::error::IdentifierError;
-use ibc_core_host_types::identifiers::{ChannelId, ConnectionId, PortId, Sequence};
+use ibc_core_host_types::identifiers::{ChannelId, ClientId, ConnectionId, PortId, Sequence};
+use ibc_core_host_types::path::Path;
 use ibc_primitives::prelude::*;
-use ibc_primitives::{ParseTimestampError, Timestamp};
+use ibc_primitives::{ParseTimestampError, Timestamp, TimestampOverflowError};
 
 use super::channel::Counterparty;
 use super::timeout::TimeoutHeight;
@@ -33,8 +34,6 @@ pub enum ChannelError {
     NonUtf8PacketData,
     /// missing counterparty
     MissingCounterparty,
-    /// unsupported channel upgrade sequence
-    UnsupportedChannelUpgradeSequence,
     /// version not supported: expected `{expected}`, actual `{actual}`
     VersionNotSupported { expected: Version, actual: Version },
     /// missing channel end
@@ -49,8 +48,11 @@ pub enum ChannelError {
         sequence: Sequence,
         client_error: client_error::ClientError,
     },
-    /// Error verifying channel state error: `{0}`
-    VerifyChannelFailed(client_error::ClientError),
+    /// failed to verify the proof of `{path}`: `{client_error}`
+    VerifyChannelFailed {
+        path: Path,
+        client_error: client_error::ClientError,
+    },
     /// String `{value}` cannot be converted to packet sequence, error: `{error}`
     InvalidStringAsSequence {
         value: String,
@@ -71,6 +73,46 @@ pub enum ChannelError {
     InvalidIdentifier(IdentifierError),
     /// channel counter overflow error
     CounterOverflow,
+    /// missing upgrade fields
+    MissingUpgradeFields,
+    /// missing upgrade timeout
+    MissingUpgradeTimeout,
+    /// missing counterparty upgrade
+    MissingUpgrade,
+    /// invalid upgrade timeout: `{reason}`
+    InvalidUpgradeTimeout { reason: String },
+    /// no upgrade in progress for the channel end (`{port_id}`, `{channel_id}`)
+    UpgradeNotFound {
+        port_id: PortId,
+        channel_id: ChannelId,
+    },
+    /// the proposed upgrade fields are identical to the ones of the channel end
+    IdenticalUpgradeFields,
+    /// counterparty upgrade sequence `{counterparty_upgrade_sequence}` is behind the channel end upgrade sequence `{upgrade_sequence}`
+    StaleUpgradeSequence {
+        upgrade_sequence: u64,
+        counterparty_upgrade_sequence: u64,
+    },
+    /// incompatible counterparty upgrade: `{reason}`
+    IncompatibleUpgrade { reason: String },
+    /// the counterparty upgrade has timed out
+    UpgradeTimedOut,
+    /// the upgrade has not timed out on the counterparty yet
+    UpgradeTimeoutNotReached,
+    /// missing error receipt
+    MissingErrorReceipt,
+    /// no error receipt for the channel end (`{port_id}`, `{channel_id}`)
+    ErrorReceiptNotFound {
+        port_id: PortId,
+        channel_id: ChannelId,
+    },
+    /// the application bound to port `{port_id}` does not support channel upgrades
+    UpgradeNotSupported { port_id: PortId },
+    /// the channel end (`{port_id}`, `{channel_id}`) is not allowed to be closed
+    UnauthorizedChannelClose {
+        port_id: PortId,
+        channel_id: ChannelId,
+    },
     /// other error: `{description}`
     Other { description: String },
 }
@@ -88,6 +130,8 @@ pub enum PacketError {
     },
     /// Receiving chain block timestamp >= packet timeout timestamp
     LowPacketTimestamp,
+    /// packet timeout timestamp overflow error: `{0}`
+    TimeoutTimestampOverflow(TimestampOverflowError),
     /// Invalid packet sequence `{given_sequence}` ≠ next send sequence `{next_sequence}`
     InvalidPacketSequence {
         given_sequence: Sequence,
@@ -120,8 +164,12 @@ pub enum PacketError {
     InvalidAcknowledgement,
     /// Acknowledgment status cannot be empty
     EmptyAcknowledgementStatus,
+    /// invalid acknowledgement envelope: `{reason}`
+    InvalidAcknowledgementEnvelope { reason: String },
     /// Acknowledgment for the packet `{sequence}` not found
     PacketAcknowledgementNotFound { sequence: Sequence },
+    /// Packet `{sequence}` cannot be acknowledged before it is received
+    PacketNotReceived { sequence: Sequence },
     /// invalid proof: missing height
     MissingHeight,
     /// there is no packet in this message
@@ -166,6 +214,43 @@ pub enum PacketError {
         port_id: PortId,
         channel_id: ChannelId,
     },
+    /// packet `{sequence}` was sent after the counterparty started upgrading the channel, from sequence `{next_sequence_send}`
+    PacketSentDuringUpgrade {
+        sequence: Sequence,
+        next_sequence_send: Sequence,
+    },
+    /// packet data of `{size}` bytes exceeds the maximum size of `{max_size}` bytes
+    PacketDataTooLarge { size: usize, max_size: usize },
+    /// acknowledgement of `{size}` bytes exceeds the maximum size of `{max_size}` bytes
+    AcknowledgementTooLarge { size: usize, max_size: usize },
+    /// invalid batch of packets: `{reason}`
+    InvalidPacketBatch { reason: String },
+    /// the packet carries no payload
+    MissingPayloads,
+    /// invalid packet payload: `{reason}`
+    InvalidPayload { reason: String },
+    /// invalid packet timeout timestamp `{timeout_timestamp}`: `{reason}`
+    InvalidTimeoutTimestamp {
+        timeout_timestamp: u64,
+        reason: String,
+    },
+    /// packet timeout timestamp `{timeout_timestamp}` > chain timestamp `{chain_timestamp}`, in seconds
+    PacketTimeoutTimestampNotReached {
+        timeout_timestamp: u64,
+        chain_timestamp: u64,
+    },
+    /// the acknowledgement carries `{actual}` application acknowledgements, expected `{expected}`
+    MismatchedAppAcknowledgements { expected: usize, actual: usize },
+    /// no counterparty is registered for the client `{client_id}`
+    MissingClientCounterparty { client_id: ClientId },
+    /// the counterparty of the client `{client_id}` is `{counterparty_client_id}`, not `{expected}`
+    InvalidClientCounterparty {
+        client_id: ClientId,
+        counterparty_client_id: ClientId,
+        expected: ClientId,
+    },
+    /// invalid protobuf encoding of the packet: `{reason}`
+    InvalidPacketEncoding { reason: String },
     /// other error: `{description}`
     Other { description: String },
 }
@@ -182,25 +267,136 @@ impl From<IdentifierError> for PacketError {
     }
 }
 
-#[cfg(feature = "std")]
-impl std::error::Error for PacketError {
-    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+/// The codespace of the [`ChannelError`]s in ABCI responses.
+pub const CHANNEL_CODESPACE: &str = "channel";
+
+impl ChannelError {
+    /// Returns the code identifying the variant of the error within the
+    /// [`CHANNEL_CODESPACE`], which is kept across releases.
+    pub fn code(&self) -> u32 {
+        match self {
+            Self::InvalidChannelEnd { .. } => 2,
+            Self::InvalidChannelId { .. } => 3,
+            Self::InvalidState { .. } => 4,
+            Self::InvalidOrderType { .. } => 5,
+            Self::InvalidConnectionHopsLength { .. } => 6,
+            Self::InvalidSigner { .. } => 7,
+            Self::MissingHeight => 8,
+            Self::NonUtf8PacketData => 9,
+            Self::MissingCounterparty => 10,
+            Self::VersionNotSupported { .. } => 11,
+            Self::MissingChannel => 12,
+            Self::ChannelNotFound { .. } => 13,
+            Self::PacketVerificationFailed { .. } => 14,
+            Self::VerifyChannelFailed { .. } => 15,
+            Self::InvalidStringAsSequence { .. } => 16,
+            Self::InvalidCounterparty { .. } => 17,
+            Self::AppModule { .. } => 18,
+            Self::UndefinedConnectionCounterparty { .. } => 19,
+            Self::InvalidProof => 20,
+            Self::InvalidIdentifier(_) => 21,
+            Self::CounterOverflow => 22,
+            Self::MissingUpgradeFields => 23,
+            Self::MissingUpgradeTimeout => 24,
+            Self::MissingUpgrade => 25,
+            Self::InvalidUpgradeTimeout { .. } => 26,
+            Self::UpgradeNotFound { .. } => 27,
+            Self::IdenticalUpgradeFields => 28,
+            Self::StaleUpgradeSequence { .. } => 29,
+            Self::IncompatibleUpgrade { .. } => 30,
+            Self::UpgradeTimedOut => 31,
+            Self::UpgradeTimeoutNotReached => 32,
+            Self::MissingErrorReceipt => 33,
+            Self::ErrorReceiptNotFound { .. } => 34,
+            Self::UpgradeNotSupported { .. } => 35,
+            Self::UnauthorizedChannelClose { .. } => 36,
+            Self::Other { .. } => 37,
+        }
+    }
+}
+
+/// The codespace of the [`PacketError`]s in ABCI responses.
+pub const PACKET_CODESPACE: &str = "packet";
+
+impl PacketError {
+    /// Returns the code identifying the variant of the error within the
+    /// [`PACKET_CODESPACE`], which is kept across releases.
+    pub fn code(&self) -> u32 {
+        match self {
+            Self::Connection(_) => 2,
+            Self::Channel(_) => 3,
+            Self::LowPacketHeight { .. } => 4,
+            Self::LowPacketTimestamp => 5,
+            Self::TimeoutTimestampOverflow(_) => 6,
+            Self::InvalidPacketSequence { .. } => 7,
+            Self::InvalidChannelState { .. } => 8,
+            Self::ConnectionNotOpen { .. } => 9,
+            Self::PacketReceiptNotFound { .. } => 10,
+            Self::IncorrectPacketCommitment { .. } => 11,
+            Self::ImplementationSpecific => 12,
+            Self::UndefinedConnectionCounterparty { .. } => 13,
+            Self::InvalidProof => 14,
+            Self::PacketTimeoutNotReached { .. } => 15,
+            Self::AcknowledgementExists { .. } => 16,
+            Self::InvalidAcknowledgement => 17,
+            Self::EmptyAcknowledgementStatus => 18,
+            Self::InvalidAcknowledgementEnvelope { .. } => 19,
+            Self::PacketAcknowledgementNotFound { .. } => 20,
+            Self::PacketNotReceived { .. } => 21,
+            Self::MissingHeight => 22,
+            Self::MissingPacket => 23,
+            Self::InvalidSigner { .. } => 24,
+            Self::AppModule { .. } => 25,
+            Self::RouteNotFound => 26,
+            Self::ZeroPacketSequence => 27,
+            Self::ZeroPacketData => 28,
+            Self::InvalidTimeoutHeight => 29,
+            Self::InvalidPacketTimestamp(_) => 30,
+            Self::MissingTimeout => 31,
+            Self::InvalidIdentifier(_) => 32,
+            Self::MissingNextSendSeq { .. } => 33,
+            Self::ChannelNotFound { .. } => 34,
+            Self::PacketCommitmentNotFound { .. } => 35,
+            Self::MissingNextRecvSeq { .. } => 36,
+            Self::MissingNextAckSeq { .. } => 37,
+            Self::PacketSentDuringUpgrade { .. } => 38,
+            Self::PacketDataTooLarge { .. } => 39,
+            Self::AcknowledgementTooLarge { .. } => 40,
+            Self::InvalidPacketBatch { .. } => 41,
+            Self::MissingPayloads => 42,
+            Self::InvalidPayload { .. } => 43,
+            Self::InvalidTimeoutTimestamp { .. } => 44,
+            Self::PacketTimeoutTimestampNotReached { .. } => 45,
+            Self::MismatchedAppAcknowledgements { .. } => 46,
+            Self::MissingClientCounterparty { .. } => 47,
+            Self::InvalidClientCounterparty { .. } => 48,
+            Self::Other { .. } => 49,
+            Self::InvalidPacketEncoding { .. } => 50,
+        }
+    }
+}
+
+impl core::error::Error for PacketError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
         match &self {
             Self::Connection(e) => Some(e),
             Self::Channel(e) => Some(e),
             Self::InvalidIdentifier(e) => Some(e),
+            Self::TimeoutTimestampOverflow(e) => Some(e),
             _ => None,
         }
     }
 }
 
-#[cfg(feature = "std")]
-impl std::error::Error for ChannelError {
-    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+impl core::error::Error for ChannelError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
         match &self {
             Self::InvalidIdentifier(e) => Some(e),
             Self::PacketVerificationFailed {
                 client_error: e, ..
+            }
+            | Self::VerifyChannelFailed {
+                client_error: e, ..
             } => Some(e),
             Self::InvalidStringAsSequence { error: e, .. } => Some(e),
             _ => None,
@@ -2,8 +2,12 @@
 //! during the channel handshake.
 use derive_more::From;
 use ibc_core_host_types::identifiers::{ChannelId, ConnectionId, PortId};
+use ibc_primitives::prelude::*;
 use tendermint::abci;
 
+use crate::channel::State;
+use crate::timeout::TimeoutHeight;
+use crate::upgrade::{UpgradeFields, UpgradeTimeout};
 use crate::Version;
 
 const CONNECTION_ID_ATTRIBUTE_KEY: &str = "connection_id";
@@ -14,6 +18,14 @@ const PORT_ID_ATTRIBUTE_KEY: &str = "port_id";
 pub(super) const COUNTERPARTY_CHANNEL_ID_ATTRIBUTE_KEY: &str = "counterparty_channel_id";
 const COUNTERPARTY_PORT_ID_ATTRIBUTE_KEY: &str = "counterparty_port_id";
 const VERSION_ATTRIBUTE_KEY: &str = "version";
+const CHANNEL_STATE_ATTRIBUTE_KEY: &str = "channel_state";
+const UPGRADE_SEQUENCE_ATTRIBUTE_KEY: &str = "upgrade_sequence";
+const UPGRADE_CONNECTION_HOPS_ATTRIBUTE_KEY: &str = "upgrade_connection_hops";
+const UPGRADE_VERSION_ATTRIBUTE_KEY: &str = "upgrade_version";
+const UPGRADE_ORDERING_ATTRIBUTE_KEY: &str = "upgrade_ordering";
+const UPGRADE_TIMEOUT_HEIGHT_ATTRIBUTE_KEY: &str = "upgrade_timeout_height";
+const UPGRADE_TIMEOUT_TIMESTAMP_ATTRIBUTE_KEY: &str = "upgrade_timeout_timestamp";
+const ERROR_RECEIPT_ATTRIBUTE_KEY: &str = "error_receipt";
 
 #[cfg_attr(
     feature = "parity-scale-codec",
@@ -28,6 +40,7 @@ const VERSION_ATTRIBUTE_KEY: &str = "version";
     derive(borsh::BorshSerialize, borsh::BorshDeserialize)
 )]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[derive(Clone, Debug, From, PartialEq, Eq)]
 pub struct PortIdAttribute {
     pub port_id: PortId,
@@ -52,6 +65,7 @@ impl From<PortIdAttribute> for abci::EventAttribute {
     derive(borsh::BorshSerialize, borsh::BorshDeserialize)
 )]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[derive(Clone, Debug, From, PartialEq, Eq)]
 pub struct ChannelIdAttribute {
     pub channel_id: ChannelId,
@@ -75,6 +89,7 @@ impl From<ChannelIdAttribute> for abci::EventAttribute {
     derive(borsh::BorshSerialize, borsh::BorshDeserialize)
 )]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[derive(Clone, Debug, From, PartialEq, Eq)]
 pub struct CounterpartyPortIdAttribute {
     pub counterparty_port_id: PortId,
@@ -102,6 +117,7 @@ impl From<CounterpartyPortIdAttribute> for abci::EventAttribute {
     derive(borsh::BorshSerialize, borsh::BorshDeserialize)
 )]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[derive(Clone, Debug, From, PartialEq, Eq)]
 pub struct CounterpartyChannelIdAttribute {
     pub counterparty_channel_id: ChannelId,
@@ -136,6 +152,7 @@ impl AsRef<ChannelId> for CounterpartyChannelIdAttribute {
     derive(borsh::BorshSerialize, borsh::BorshDeserialize)
 )]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[derive(Clone, Debug, From, PartialEq, Eq)]
 pub struct ConnectionIdAttribute {
     pub connection_id: ConnectionId,
@@ -160,6 +177,7 @@ impl From<ConnectionIdAttribute> for abci::EventAttribute {
     derive(borsh::BorshSerialize, borsh::BorshDeserialize)
 )]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[derive(Clone, Debug, From, PartialEq, Eq)]
 pub struct VersionAttribute {
     pub version: Version,
@@ -170,3 +188,164 @@ impl From<VersionAttribute> for abci::EventAttribute {
         (VERSION_ATTRIBUTE_KEY, attr.version.as_str()).into()
     }
 }
+
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Debug, From, PartialEq, Eq)]
+pub struct ChannelStateAttribute {
+    pub state: State,
+}
+
+impl From<ChannelStateAttribute> for abci::EventAttribute {
+    fn from(attr: ChannelStateAttribute) -> Self {
+        (CHANNEL_STATE_ATTRIBUTE_KEY, attr.state.as_string()).into()
+    }
+}
+
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Debug, From, PartialEq, Eq)]
+pub struct UpgradeSequenceAttribute {
+    pub upgrade_sequence: u64,
+}
+
+impl From<UpgradeSequenceAttribute> for abci::EventAttribute {
+    fn from(attr: UpgradeSequenceAttribute) -> Self {
+        (
+            UPGRADE_SEQUENCE_ATTRIBUTE_KEY,
+            attr.upgrade_sequence.to_string(),
+        )
+            .into()
+    }
+}
+
+/// Holds the `upgrade_connection_hops`, `upgrade_version` and
+/// `upgrade_ordering` attributes of the channel upgrade events.
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Debug, From, PartialEq, Eq)]
+pub struct UpgradeFieldsAttributes {
+    pub fields: UpgradeFields,
+}
+
+impl From<UpgradeFieldsAttributes> for Vec<abci::EventAttribute> {
+    fn from(attrs: UpgradeFieldsAttributes) -> Self {
+        let connection_hops = attrs
+            .fields
+            .connection_hops
+            .iter()
+            .map(|conn_id| conn_id.as_str())
+            .collect::<Vec<_>>()
+            .join(",");
+
+        vec![
+            (UPGRADE_CONNECTION_HOPS_ATTRIBUTE_KEY, connection_hops).into(),
+            (UPGRADE_VERSION_ATTRIBUTE_KEY, attrs.fields.version.as_str()).into(),
+            (
+                UPGRADE_ORDERING_ATTRIBUTE_KEY,
+                attrs.fields.ordering.as_str(),
+            )
+                .into(),
+        ]
+    }
+}
+
+/// Holds the `upgrade_timeout_height` and `upgrade_timeout_timestamp`
+/// attributes of the channel upgrade events.
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Debug, From, PartialEq, Eq)]
+pub struct UpgradeTimeoutAttributes {
+    pub timeout: UpgradeTimeout,
+}
+
+impl From<UpgradeTimeoutAttributes> for Vec<abci::EventAttribute> {
+    fn from(attrs: UpgradeTimeoutAttributes) -> Self {
+        let timeout_height = match attrs.timeout.height {
+            TimeoutHeight::Never => "0-0".to_string(),
+            TimeoutHeight::At(height) => height.to_string(),
+        };
+
+        vec![
+            (UPGRADE_TIMEOUT_HEIGHT_ATTRIBUTE_KEY, timeout_height).into(),
+            (
+                UPGRADE_TIMEOUT_TIMESTAMP_ATTRIBUTE_KEY,
+                attrs.timeout.timestamp.nanoseconds().to_string(),
+            )
+                .into(),
+        ]
+    }
+}
+
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Debug, From, PartialEq, Eq)]
+pub struct ErrorReceiptAttribute {
+    pub message: String,
+}
+
+impl From<ErrorReceiptAttribute> for abci::EventAttribute {
+    fn from(attr: ErrorReceiptAttribute) -> Self {
+        (ERROR_RECEIPT_ATTRIBUTE_KEY, attr.message).into()
+    }
+}
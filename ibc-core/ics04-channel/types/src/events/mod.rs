@@ -9,9 +9,10 @@ use ibc_primitives::Timestamp;
 use tendermint::abci;
 
 use self::channel_attributes::{
-    ChannelIdAttribute, ConnectionIdAttribute, CounterpartyChannelIdAttribute,
-    CounterpartyPortIdAttribute, PortIdAttribute, VersionAttribute,
-    COUNTERPARTY_CHANNEL_ID_ATTRIBUTE_KEY,
+    ChannelIdAttribute, ChannelStateAttribute, ConnectionIdAttribute,
+    CounterpartyChannelIdAttribute, CounterpartyPortIdAttribute, ErrorReceiptAttribute,
+    PortIdAttribute, UpgradeFieldsAttributes, UpgradeSequenceAttribute, UpgradeTimeoutAttributes,
+    VersionAttribute, COUNTERPARTY_CHANNEL_ID_ATTRIBUTE_KEY,
 };
 use self::packet_attributes::{
     AcknowledgementAttribute, ChannelOrderingAttribute, DstChannelIdAttribute, DstPortIdAttribute,
@@ -19,11 +20,12 @@ use self::packet_attributes::{
     SrcPortIdAttribute, TimeoutHeightAttribute, TimeoutTimestampAttribute,
 };
 use super::acknowledgement::Acknowledgement;
-use super::channel::Order;
+use super::channel::{Order, State};
 use super::timeout::TimeoutHeight;
 use super::Version;
 use crate::error::ChannelError;
 use crate::packet::Packet;
+use crate::upgrade::{UpgradeFields, UpgradeTimeout as ChannelUpgradeTimeout};
 
 /// Channel event types
 const CHANNEL_OPEN_INIT_EVENT: &str = "channel_open_init";
@@ -32,6 +34,14 @@ const CHANNEL_OPEN_ACK_EVENT: &str = "channel_open_ack";
 const CHANNEL_OPEN_CONFIRM_EVENT: &str = "channel_open_confirm";
 const CHANNEL_CLOSE_INIT_EVENT: &str = "channel_close_init";
 const CHANNEL_CLOSE_CONFIRM_EVENT: &str = "channel_close_confirm";
+const CHANNEL_UPGRADE_INIT_EVENT: &str = "channel_upgrade_init";
+const CHANNEL_UPGRADE_TRY_EVENT: &str = "channel_upgrade_try";
+const CHANNEL_UPGRADE_ACK_EVENT: &str = "channel_upgrade_ack";
+const CHANNEL_UPGRADE_CONFIRM_EVENT: &str = "channel_upgrade_confirm";
+const CHANNEL_UPGRADE_OPEN_EVENT: &str = "channel_upgrade_open";
+const CHANNEL_UPGRADE_TIMEOUT_EVENT: &str = "channel_upgrade_timeout";
+const CHANNEL_UPGRADE_CANCEL_EVENT: &str = "channel_upgrade_cancelled";
+const CHANNEL_UPGRADE_ERROR_EVENT: &str = "channel_upgrade_error";
 /// Packet event types
 const SEND_PACKET_EVENT: &str = "send_packet";
 const RECEIVE_PACKET_EVENT: &str = "receive_packet";
@@ -40,6 +50,38 @@ const ACK_PACKET_EVENT: &str = "acknowledge_packet";
 const TIMEOUT_EVENT: &str = "timeout_packet";
 const CHANNEL_CLOSED_EVENT: &str = "channel_close";
 
+/// The number of leading bytes of the packet data which are hex encoded in
+/// the events emitted with [`PacketDataEventMode::Hashed`].
+pub const PACKET_DATA_PREVIEW_LEN: usize = 32;
+
+/// Determines how the packet data is carried by the `SendPacket` and
+/// `ReceivePacket` events.
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum PacketDataEventMode {
+    /// The events carry the full packet data, both as is and hex encoded.
+    /// The packet data must then be valid UTF-8.
+    #[default]
+    Full,
+    /// The events carry the SHA-256 hash of the packet data, along with the
+    /// hex encoding of its first [`PACKET_DATA_PREVIEW_LEN`] bytes, which
+    /// keeps them small regardless of the size of the packet data.
+    Hashed,
+}
+
 #[cfg_attr(
     feature = "parity-scale-codec",
     derive(
@@ -53,6 +95,7 @@ const CHANNEL_CLOSED_EVENT: &str = "channel_close";
     derive(borsh::BorshSerialize, borsh::BorshDeserialize)
 )]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct OpenInit {
     port_id_attr_on_a: PortIdAttribute,
@@ -128,6 +171,7 @@ impl From<OpenInit> for abci::Event {
     derive(borsh::BorshSerialize, borsh::BorshDeserialize)
 )]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct OpenTry {
     port_id_attr_on_b: PortIdAttribute,
@@ -209,6 +253,7 @@ impl From<OpenTry> for abci::Event {
     derive(borsh::BorshSerialize, borsh::BorshDeserialize)
 )]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct OpenAck {
     port_id_attr_on_a: PortIdAttribute,
@@ -283,6 +328,7 @@ impl From<OpenAck> for abci::Event {
     derive(borsh::BorshSerialize, borsh::BorshDeserialize)
 )]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct OpenConfirm {
     port_id_attr_on_b: PortIdAttribute,
@@ -357,6 +403,7 @@ impl From<OpenConfirm> for abci::Event {
     derive(borsh::BorshSerialize, borsh::BorshDeserialize)
 )]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct CloseInit {
     port_id_attr_on_a: PortIdAttribute,
@@ -431,6 +478,7 @@ impl From<CloseInit> for abci::Event {
     derive(borsh::BorshSerialize, borsh::BorshDeserialize)
 )]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct CloseConfirm {
     port_id_attr_on_b: PortIdAttribute,
@@ -492,6 +540,716 @@ impl From<CloseConfirm> for abci::Event {
     }
 }
 
+/// Attributes shared by all the channel upgrade events, which are emitted by
+/// the chain processing the upgrade message.
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct UpgradeAttributes {
+    port_id_attr: PortIdAttribute,
+    chan_id_attr: ChannelIdAttribute,
+    counterparty_port_id_attr: CounterpartyPortIdAttribute,
+    counterparty_chan_id_attr: CounterpartyChannelIdAttribute,
+    upgrade_sequence_attr: UpgradeSequenceAttribute,
+}
+
+impl UpgradeAttributes {
+    fn new(
+        port_id: PortId,
+        channel_id: ChannelId,
+        counterparty_port_id: PortId,
+        counterparty_channel_id: ChannelId,
+        upgrade_sequence: u64,
+    ) -> Self {
+        Self {
+            port_id_attr: port_id.into(),
+            chan_id_attr: channel_id.into(),
+            counterparty_port_id_attr: counterparty_port_id.into(),
+            counterparty_chan_id_attr: counterparty_channel_id.into(),
+            upgrade_sequence_attr: upgrade_sequence.into(),
+        }
+    }
+
+    fn into_abci_attributes(self) -> Vec<abci::EventAttribute> {
+        vec![
+            self.port_id_attr.into(),
+            self.chan_id_attr.into(),
+            self.counterparty_port_id_attr.into(),
+            self.counterparty_chan_id_attr.into(),
+            self.upgrade_sequence_attr.into(),
+        ]
+    }
+}
+
+/// Emitted when a channel upgrade is proposed with `MsgChannelUpgradeInit`.
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UpgradeInit {
+    attributes: UpgradeAttributes,
+    upgrade_fields_attrs: UpgradeFieldsAttributes,
+}
+
+impl UpgradeInit {
+    pub fn new(
+        port_id: PortId,
+        channel_id: ChannelId,
+        counterparty_port_id: PortId,
+        counterparty_channel_id: ChannelId,
+        upgrade_sequence: u64,
+        upgrade_fields: UpgradeFields,
+    ) -> Self {
+        Self {
+            attributes: UpgradeAttributes::new(
+                port_id,
+                channel_id,
+                counterparty_port_id,
+                counterparty_channel_id,
+                upgrade_sequence,
+            ),
+            upgrade_fields_attrs: upgrade_fields.into(),
+        }
+    }
+    pub fn port_id(&self) -> &PortId {
+        &self.attributes.port_id_attr.port_id
+    }
+    pub fn channel_id(&self) -> &ChannelId {
+        &self.attributes.chan_id_attr.channel_id
+    }
+    pub fn counterparty_port_id(&self) -> &PortId {
+        &self
+            .attributes
+            .counterparty_port_id_attr
+            .counterparty_port_id
+    }
+    pub fn counterparty_channel_id(&self) -> &ChannelId {
+        &self
+            .attributes
+            .counterparty_chan_id_attr
+            .counterparty_channel_id
+    }
+    pub fn upgrade_sequence(&self) -> u64 {
+        self.attributes.upgrade_sequence_attr.upgrade_sequence
+    }
+    pub fn upgrade_fields(&self) -> &UpgradeFields {
+        &self.upgrade_fields_attrs.fields
+    }
+
+    pub fn event_type(&self) -> &str {
+        CHANNEL_UPGRADE_INIT_EVENT
+    }
+}
+
+impl From<UpgradeInit> for abci::Event {
+    fn from(e: UpgradeInit) -> Self {
+        let mut attributes = e.attributes.into_abci_attributes();
+        attributes.extend(Vec::<abci::EventAttribute>::from(e.upgrade_fields_attrs));
+
+        abci::Event {
+            kind: CHANNEL_UPGRADE_INIT_EVENT.to_string(),
+            attributes,
+        }
+    }
+}
+
+/// Emitted when a channel end accepts a proposed upgrade and starts flushing.
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UpgradeTry {
+    attributes: UpgradeAttributes,
+    upgrade_fields_attrs: UpgradeFieldsAttributes,
+}
+
+impl UpgradeTry {
+    pub fn new(
+        port_id: PortId,
+        channel_id: ChannelId,
+        counterparty_port_id: PortId,
+        counterparty_channel_id: ChannelId,
+        upgrade_sequence: u64,
+        upgrade_fields: UpgradeFields,
+    ) -> Self {
+        Self {
+            attributes: UpgradeAttributes::new(
+                port_id,
+                channel_id,
+                counterparty_port_id,
+                counterparty_channel_id,
+                upgrade_sequence,
+            ),
+            upgrade_fields_attrs: upgrade_fields.into(),
+        }
+    }
+    pub fn port_id(&self) -> &PortId {
+        &self.attributes.port_id_attr.port_id
+    }
+    pub fn channel_id(&self) -> &ChannelId {
+        &self.attributes.chan_id_attr.channel_id
+    }
+    pub fn counterparty_port_id(&self) -> &PortId {
+        &self
+            .attributes
+            .counterparty_port_id_attr
+            .counterparty_port_id
+    }
+    pub fn counterparty_channel_id(&self) -> &ChannelId {
+        &self
+            .attributes
+            .counterparty_chan_id_attr
+            .counterparty_channel_id
+    }
+    pub fn upgrade_sequence(&self) -> u64 {
+        self.attributes.upgrade_sequence_attr.upgrade_sequence
+    }
+    pub fn upgrade_fields(&self) -> &UpgradeFields {
+        &self.upgrade_fields_attrs.fields
+    }
+
+    pub fn event_type(&self) -> &str {
+        CHANNEL_UPGRADE_TRY_EVENT
+    }
+}
+
+impl From<UpgradeTry> for abci::Event {
+    fn from(e: UpgradeTry) -> Self {
+        let mut attributes = e.attributes.into_abci_attributes();
+        attributes.extend(Vec::<abci::EventAttribute>::from(e.upgrade_fields_attrs));
+
+        abci::Event {
+            kind: CHANNEL_UPGRADE_TRY_EVENT.to_string(),
+            attributes,
+        }
+    }
+}
+
+/// Emitted when the proposing channel end acknowledges the counterparty upgrade.
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UpgradeAck {
+    attributes: UpgradeAttributes,
+    upgrade_fields_attrs: UpgradeFieldsAttributes,
+}
+
+impl UpgradeAck {
+    pub fn new(
+        port_id: PortId,
+        channel_id: ChannelId,
+        counterparty_port_id: PortId,
+        counterparty_channel_id: ChannelId,
+        upgrade_sequence: u64,
+        upgrade_fields: UpgradeFields,
+    ) -> Self {
+        Self {
+            attributes: UpgradeAttributes::new(
+                port_id,
+                channel_id,
+                counterparty_port_id,
+                counterparty_channel_id,
+                upgrade_sequence,
+            ),
+            upgrade_fields_attrs: upgrade_fields.into(),
+        }
+    }
+    pub fn port_id(&self) -> &PortId {
+        &self.attributes.port_id_attr.port_id
+    }
+    pub fn channel_id(&self) -> &ChannelId {
+        &self.attributes.chan_id_attr.channel_id
+    }
+    pub fn counterparty_port_id(&self) -> &PortId {
+        &self
+            .attributes
+            .counterparty_port_id_attr
+            .counterparty_port_id
+    }
+    pub fn counterparty_channel_id(&self) -> &ChannelId {
+        &self
+            .attributes
+            .counterparty_chan_id_attr
+            .counterparty_channel_id
+    }
+    pub fn upgrade_sequence(&self) -> u64 {
+        self.attributes.upgrade_sequence_attr.upgrade_sequence
+    }
+    pub fn upgrade_fields(&self) -> &UpgradeFields {
+        &self.upgrade_fields_attrs.fields
+    }
+
+    pub fn event_type(&self) -> &str {
+        CHANNEL_UPGRADE_ACK_EVENT
+    }
+}
+
+impl From<UpgradeAck> for abci::Event {
+    fn from(e: UpgradeAck) -> Self {
+        let mut attributes = e.attributes.into_abci_attributes();
+        attributes.extend(Vec::<abci::EventAttribute>::from(e.upgrade_fields_attrs));
+
+        abci::Event {
+            kind: CHANNEL_UPGRADE_ACK_EVENT.to_string(),
+            attributes,
+        }
+    }
+}
+
+/// Emitted when a flushing channel end confirms the counterparty upgrade.
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UpgradeConfirm {
+    attributes: UpgradeAttributes,
+    channel_state_attr: ChannelStateAttribute,
+}
+
+impl UpgradeConfirm {
+    pub fn new(
+        port_id: PortId,
+        channel_id: ChannelId,
+        counterparty_port_id: PortId,
+        counterparty_channel_id: ChannelId,
+        upgrade_sequence: u64,
+        channel_state: State,
+    ) -> Self {
+        Self {
+            attributes: UpgradeAttributes::new(
+                port_id,
+                channel_id,
+                counterparty_port_id,
+                counterparty_channel_id,
+                upgrade_sequence,
+            ),
+            channel_state_attr: channel_state.into(),
+        }
+    }
+    pub fn port_id(&self) -> &PortId {
+        &self.attributes.port_id_attr.port_id
+    }
+    pub fn channel_id(&self) -> &ChannelId {
+        &self.attributes.chan_id_attr.channel_id
+    }
+    pub fn counterparty_port_id(&self) -> &PortId {
+        &self
+            .attributes
+            .counterparty_port_id_attr
+            .counterparty_port_id
+    }
+    pub fn counterparty_channel_id(&self) -> &ChannelId {
+        &self
+            .attributes
+            .counterparty_chan_id_attr
+            .counterparty_channel_id
+    }
+    pub fn upgrade_sequence(&self) -> u64 {
+        self.attributes.upgrade_sequence_attr.upgrade_sequence
+    }
+    pub fn channel_state(&self) -> &State {
+        &self.channel_state_attr.state
+    }
+
+    pub fn event_type(&self) -> &str {
+        CHANNEL_UPGRADE_CONFIRM_EVENT
+    }
+}
+
+impl From<UpgradeConfirm> for abci::Event {
+    fn from(e: UpgradeConfirm) -> Self {
+        let mut attributes = e.attributes.into_abci_attributes();
+        attributes.push(e.channel_state_attr.into());
+
+        abci::Event {
+            kind: CHANNEL_UPGRADE_CONFIRM_EVENT.to_string(),
+            attributes,
+        }
+    }
+}
+
+/// Emitted when a channel end has been upgraded and is open again.
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UpgradeOpen {
+    attributes: UpgradeAttributes,
+    upgrade_fields_attrs: UpgradeFieldsAttributes,
+}
+
+impl UpgradeOpen {
+    pub fn new(
+        port_id: PortId,
+        channel_id: ChannelId,
+        counterparty_port_id: PortId,
+        counterparty_channel_id: ChannelId,
+        upgrade_sequence: u64,
+        upgrade_fields: UpgradeFields,
+    ) -> Self {
+        Self {
+            attributes: UpgradeAttributes::new(
+                port_id,
+                channel_id,
+                counterparty_port_id,
+                counterparty_channel_id,
+                upgrade_sequence,
+            ),
+            upgrade_fields_attrs: upgrade_fields.into(),
+        }
+    }
+    pub fn port_id(&self) -> &PortId {
+        &self.attributes.port_id_attr.port_id
+    }
+    pub fn channel_id(&self) -> &ChannelId {
+        &self.attributes.chan_id_attr.channel_id
+    }
+    pub fn counterparty_port_id(&self) -> &PortId {
+        &self
+            .attributes
+            .counterparty_port_id_attr
+            .counterparty_port_id
+    }
+    pub fn counterparty_channel_id(&self) -> &ChannelId {
+        &self
+            .attributes
+            .counterparty_chan_id_attr
+            .counterparty_channel_id
+    }
+    pub fn upgrade_sequence(&self) -> u64 {
+        self.attributes.upgrade_sequence_attr.upgrade_sequence
+    }
+    pub fn upgrade_fields(&self) -> &UpgradeFields {
+        &self.upgrade_fields_attrs.fields
+    }
+
+    pub fn event_type(&self) -> &str {
+        CHANNEL_UPGRADE_OPEN_EVENT
+    }
+}
+
+impl From<UpgradeOpen> for abci::Event {
+    fn from(e: UpgradeOpen) -> Self {
+        let mut attributes = e.attributes.into_abci_attributes();
+        attributes.extend(Vec::<abci::EventAttribute>::from(e.upgrade_fields_attrs));
+
+        abci::Event {
+            kind: CHANNEL_UPGRADE_OPEN_EVENT.to_string(),
+            attributes,
+        }
+    }
+}
+
+/// Emitted when a timed out channel upgrade is aborted.
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UpgradeTimeout {
+    attributes: UpgradeAttributes,
+    upgrade_timeout_attrs: UpgradeTimeoutAttributes,
+}
+
+impl UpgradeTimeout {
+    pub fn new(
+        port_id: PortId,
+        channel_id: ChannelId,
+        counterparty_port_id: PortId,
+        counterparty_channel_id: ChannelId,
+        upgrade_sequence: u64,
+        upgrade_timeout: ChannelUpgradeTimeout,
+    ) -> Self {
+        Self {
+            attributes: UpgradeAttributes::new(
+                port_id,
+                channel_id,
+                counterparty_port_id,
+                counterparty_channel_id,
+                upgrade_sequence,
+            ),
+            upgrade_timeout_attrs: upgrade_timeout.into(),
+        }
+    }
+    pub fn port_id(&self) -> &PortId {
+        &self.attributes.port_id_attr.port_id
+    }
+    pub fn channel_id(&self) -> &ChannelId {
+        &self.attributes.chan_id_attr.channel_id
+    }
+    pub fn counterparty_port_id(&self) -> &PortId {
+        &self
+            .attributes
+            .counterparty_port_id_attr
+            .counterparty_port_id
+    }
+    pub fn counterparty_channel_id(&self) -> &ChannelId {
+        &self
+            .attributes
+            .counterparty_chan_id_attr
+            .counterparty_channel_id
+    }
+    pub fn upgrade_sequence(&self) -> u64 {
+        self.attributes.upgrade_sequence_attr.upgrade_sequence
+    }
+    pub fn upgrade_timeout(&self) -> &ChannelUpgradeTimeout {
+        &self.upgrade_timeout_attrs.timeout
+    }
+
+    pub fn event_type(&self) -> &str {
+        CHANNEL_UPGRADE_TIMEOUT_EVENT
+    }
+}
+
+impl From<UpgradeTimeout> for abci::Event {
+    fn from(e: UpgradeTimeout) -> Self {
+        let mut attributes = e.attributes.into_abci_attributes();
+        attributes.extend(Vec::<abci::EventAttribute>::from(e.upgrade_timeout_attrs));
+
+        abci::Event {
+            kind: CHANNEL_UPGRADE_TIMEOUT_EVENT.to_string(),
+            attributes,
+        }
+    }
+}
+
+/// Emitted when a channel upgrade is cancelled after the counterparty aborted
+/// it.
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UpgradeCancel {
+    attributes: UpgradeAttributes,
+}
+
+impl UpgradeCancel {
+    pub fn new(
+        port_id: PortId,
+        channel_id: ChannelId,
+        counterparty_port_id: PortId,
+        counterparty_channel_id: ChannelId,
+        upgrade_sequence: u64,
+    ) -> Self {
+        Self {
+            attributes: UpgradeAttributes::new(
+                port_id,
+                channel_id,
+                counterparty_port_id,
+                counterparty_channel_id,
+                upgrade_sequence,
+            ),
+        }
+    }
+    pub fn port_id(&self) -> &PortId {
+        &self.attributes.port_id_attr.port_id
+    }
+    pub fn channel_id(&self) -> &ChannelId {
+        &self.attributes.chan_id_attr.channel_id
+    }
+    pub fn counterparty_port_id(&self) -> &PortId {
+        &self
+            .attributes
+            .counterparty_port_id_attr
+            .counterparty_port_id
+    }
+    pub fn counterparty_channel_id(&self) -> &ChannelId {
+        &self
+            .attributes
+            .counterparty_chan_id_attr
+            .counterparty_channel_id
+    }
+    pub fn upgrade_sequence(&self) -> u64 {
+        self.attributes.upgrade_sequence_attr.upgrade_sequence
+    }
+
+    pub fn event_type(&self) -> &str {
+        CHANNEL_UPGRADE_CANCEL_EVENT
+    }
+}
+
+impl From<UpgradeCancel> for abci::Event {
+    fn from(e: UpgradeCancel) -> Self {
+        abci::Event {
+            kind: CHANNEL_UPGRADE_CANCEL_EVENT.to_string(),
+            attributes: e.attributes.into_abci_attributes(),
+        }
+    }
+}
+
+/// Emitted along with the error receipt written when a channel upgrade is
+/// aborted.
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UpgradeError {
+    attributes: UpgradeAttributes,
+    error_receipt_attr: ErrorReceiptAttribute,
+}
+
+impl UpgradeError {
+    pub fn new(
+        port_id: PortId,
+        channel_id: ChannelId,
+        counterparty_port_id: PortId,
+        counterparty_channel_id: ChannelId,
+        upgrade_sequence: u64,
+        error_receipt: String,
+    ) -> Self {
+        Self {
+            attributes: UpgradeAttributes::new(
+                port_id,
+                channel_id,
+                counterparty_port_id,
+                counterparty_channel_id,
+                upgrade_sequence,
+            ),
+            error_receipt_attr: error_receipt.into(),
+        }
+    }
+    pub fn port_id(&self) -> &PortId {
+        &self.attributes.port_id_attr.port_id
+    }
+    pub fn channel_id(&self) -> &ChannelId {
+        &self.attributes.chan_id_attr.channel_id
+    }
+    pub fn counterparty_port_id(&self) -> &PortId {
+        &self
+            .attributes
+            .counterparty_port_id_attr
+            .counterparty_port_id
+    }
+    pub fn counterparty_channel_id(&self) -> &ChannelId {
+        &self
+            .attributes
+            .counterparty_chan_id_attr
+            .counterparty_channel_id
+    }
+    pub fn upgrade_sequence(&self) -> u64 {
+        self.attributes.upgrade_sequence_attr.upgrade_sequence
+    }
+    pub fn error_receipt(&self) -> &str {
+        &self.error_receipt_attr.message
+    }
+
+    pub fn event_type(&self) -> &str {
+        CHANNEL_UPGRADE_ERROR_EVENT
+    }
+}
+
+impl From<UpgradeError> for abci::Event {
+    fn from(e: UpgradeError) -> Self {
+        let mut attributes = e.attributes.into_abci_attributes();
+        attributes.push(e.error_receipt_attr.into());
+
+        abci::Event {
+            kind: CHANNEL_UPGRADE_ERROR_EVENT.to_string(),
+            attributes,
+        }
+    }
+}
+
 /// A `ChannelClosed` event is emitted when a channel is closed as a result of a packet timing out. Note that
 /// since optimistic packet sends (i.e. send a packet before channel handshake is complete) are supported,
 /// we might not have a counterparty channel id value yet. This would happen if a packet is sent right
@@ -509,6 +1267,7 @@ impl From<CloseConfirm> for abci::Event {
     derive(borsh::BorshSerialize, borsh::BorshDeserialize)
 )]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct ChannelClosed {
     port_id_attr_on_a: PortIdAttribute,
@@ -593,9 +1352,11 @@ impl From<ChannelClosed> for abci::Event {
     derive(borsh::BorshSerialize, borsh::BorshDeserialize)
 )]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct SendPacket {
     packet_data_attr: PacketDataAttribute,
+    packet_data_mode: PacketDataEventMode,
     timeout_height_attr_on_b: TimeoutHeightAttribute,
     timeout_timestamp_attr_on_b: TimeoutTimestampAttribute,
     seq_attr_on_a: SequenceAttribute,
@@ -611,6 +1372,7 @@ impl SendPacket {
     pub fn new(packet: Packet, channel_ordering: Order, src_connection_id: ConnectionId) -> Self {
         Self {
             packet_data_attr: packet.data.into(),
+            packet_data_mode: PacketDataEventMode::default(),
             timeout_height_attr_on_b: packet.timeout_height_on_b.into(),
             timeout_timestamp_attr_on_b: packet.timeout_timestamp_on_b.into(),
             seq_attr_on_a: packet.seq_on_a.into(),
@@ -623,10 +1385,23 @@ impl SendPacket {
         }
     }
 
+    /// Sets how the packet data is carried by the event once converted to
+    /// an ABCI event.
+    pub fn with_packet_data_mode(self, packet_data_mode: PacketDataEventMode) -> Self {
+        Self {
+            packet_data_mode,
+            ..self
+        }
+    }
+
     pub fn packet_data(&self) -> &[u8] {
         &self.packet_data_attr.packet_data
     }
 
+    pub fn packet_data_mode(&self) -> PacketDataEventMode {
+        self.packet_data_mode
+    }
+
     pub fn timeout_height_on_b(&self) -> &TimeoutHeight {
         &self.timeout_height_attr_on_b.timeout_height
     }
@@ -673,7 +1448,12 @@ impl TryFrom<SendPacket> for abci::Event {
 
     fn try_from(v: SendPacket) -> Result<Self, Self::Error> {
         let mut attributes = Vec::with_capacity(11);
-        attributes.append(&mut v.packet_data_attr.try_into()?);
+        match v.packet_data_mode {
+            PacketDataEventMode::Full => attributes.append(&mut v.packet_data_attr.try_into()?),
+            PacketDataEventMode::Hashed => {
+                attributes.append(&mut v.packet_data_attr.into_hashed_abci_attributes())
+            }
+        }
         attributes.push(v.timeout_height_attr_on_b.into());
         attributes.push(v.timeout_timestamp_attr_on_b.into());
         attributes.push(v.seq_attr_on_a.into());
@@ -704,9 +1484,11 @@ impl TryFrom<SendPacket> for abci::Event {
     derive(borsh::BorshSerialize, borsh::BorshDeserialize)
 )]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct ReceivePacket {
     packet_data_attr: PacketDataAttribute,
+    packet_data_mode: PacketDataEventMode,
     timeout_height_attr_on_b: TimeoutHeightAttribute,
     timeout_timestamp_attr_on_b: TimeoutTimestampAttribute,
     seq_attr_on_a: SequenceAttribute,
@@ -722,6 +1504,7 @@ impl ReceivePacket {
     pub fn new(packet: Packet, channel_ordering: Order, dst_connection_id: ConnectionId) -> Self {
         Self {
             packet_data_attr: packet.data.into(),
+            packet_data_mode: PacketDataEventMode::default(),
             timeout_height_attr_on_b: packet.timeout_height_on_b.into(),
             timeout_timestamp_attr_on_b: packet.timeout_timestamp_on_b.into(),
             seq_attr_on_a: packet.seq_on_a.into(),
@@ -734,10 +1517,23 @@ impl ReceivePacket {
         }
     }
 
+    /// Sets how the packet data is carried by the event once converted to
+    /// an ABCI event.
+    pub fn with_packet_data_mode(self, packet_data_mode: PacketDataEventMode) -> Self {
+        Self {
+            packet_data_mode,
+            ..self
+        }
+    }
+
     pub fn packet_data(&self) -> &[u8] {
         &self.packet_data_attr.packet_data
     }
 
+    pub fn packet_data_mode(&self) -> PacketDataEventMode {
+        self.packet_data_mode
+    }
+
     pub fn timeout_height_on_b(&self) -> &TimeoutHeight {
         &self.timeout_height_attr_on_b.timeout_height
     }
@@ -784,7 +1580,12 @@ impl TryFrom<ReceivePacket> for abci::Event {
 
     fn try_from(v: ReceivePacket) -> Result<Self, Self::Error> {
         let mut attributes = Vec::with_capacity(11);
-        attributes.append(&mut v.packet_data_attr.try_into()?);
+        match v.packet_data_mode {
+            PacketDataEventMode::Full => attributes.append(&mut v.packet_data_attr.try_into()?),
+            PacketDataEventMode::Hashed => {
+                attributes.append(&mut v.packet_data_attr.into_hashed_abci_attributes())
+            }
+        }
         attributes.push(v.timeout_height_attr_on_b.into());
         attributes.push(v.timeout_timestamp_attr_on_b.into());
         attributes.push(v.seq_attr_on_a.into());
@@ -815,6 +1616,7 @@ impl TryFrom<ReceivePacket> for abci::Event {
     derive(borsh::BorshSerialize, borsh::BorshDeserialize)
 )]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct WriteAcknowledgement {
     packet_data: PacketDataAttribute,
@@ -930,6 +1732,7 @@ impl TryFrom<WriteAcknowledgement> for abci::Event {
     derive(borsh::BorshSerialize, borsh::BorshDeserialize)
 )]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct AcknowledgePacket {
     timeout_height_attr_on_b: TimeoutHeightAttribute,
@@ -1033,6 +1836,7 @@ impl TryFrom<AcknowledgePacket> for abci::Event {
     derive(borsh::BorshSerialize, borsh::BorshDeserialize)
 )]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct TimeoutPacket {
     timeout_height_attr_on_b: TimeoutHeightAttribute,
@@ -1256,4 +2060,127 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn ibc_to_abci_channel_upgrade_events() {
+        let fields = UpgradeFields::new(
+            Order::Ordered,
+            vec![ConnectionId::new(1)],
+            Version::new("ics20-2".to_string()),
+        );
+        let expected_keys = vec![
+            "port_id",
+            "channel_id",
+            "counterparty_port_id",
+            "counterparty_channel_id",
+            "upgrade_sequence",
+        ];
+        let expected_values = vec!["transfer", "channel-0", "transfer", "channel-1", "1"];
+
+        let upgrade_init: AbciEvent = UpgradeInit::new(
+            PortId::transfer(),
+            ChannelId::zero(),
+            PortId::transfer(),
+            ChannelId::new(1),
+            1,
+            fields,
+        )
+        .into();
+
+        assert_eq!(upgrade_init.kind, CHANNEL_UPGRADE_INIT_EVENT);
+        assert_eq!(
+            upgrade_init
+                .attributes
+                .iter()
+                .map(|e| (e.key.as_str(), e.value.as_str()))
+                .collect::<Vec<_>>(),
+            expected_keys
+                .iter()
+                .copied()
+                .chain([
+                    "upgrade_connection_hops",
+                    "upgrade_version",
+                    "upgrade_ordering"
+                ])
+                .zip(expected_values.iter().copied().chain([
+                    "connection-1",
+                    "ics20-2",
+                    "ORDER_ORDERED"
+                ]))
+                .collect::<Vec<_>>()
+        );
+
+        let upgrade_confirm: AbciEvent = UpgradeConfirm::new(
+            PortId::transfer(),
+            ChannelId::zero(),
+            PortId::transfer(),
+            ChannelId::new(1),
+            1,
+            State::FlushComplete,
+        )
+        .into();
+
+        assert_eq!(upgrade_confirm.kind, CHANNEL_UPGRADE_CONFIRM_EVENT);
+        assert_eq!(
+            upgrade_confirm
+                .attributes
+                .iter()
+                .map(|e| (e.key.as_str(), e.value.as_str()))
+                .collect::<Vec<_>>(),
+            expected_keys
+                .iter()
+                .copied()
+                .chain(["channel_state"])
+                .zip(expected_values.iter().copied().chain(["FLUSHCOMPLETE"]))
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn ibc_to_abci_hashed_packet_events() {
+        let packet = Packet {
+            seq_on_a: Sequence::from(1),
+            port_id_on_a: PortId::transfer(),
+            chan_id_on_a: ChannelId::zero(),
+            port_id_on_b: PortId::transfer(),
+            chan_id_on_b: ChannelId::new(1),
+            data: vec![0xff; 64],
+            timeout_height_on_b: TimeoutHeight::Never,
+            timeout_timestamp_on_b: Timestamp::none(),
+        };
+
+        let send_packet = SendPacket::new(packet.clone(), Order::Unordered, ConnectionId::zero());
+
+        assert!(
+            AbciEvent::try_from(send_packet.clone()).is_err(),
+            "the full packet data must be valid UTF-8"
+        );
+
+        let events = [
+            AbciEvent::try_from(send_packet.with_packet_data_mode(PacketDataEventMode::Hashed))
+                .unwrap(),
+            AbciEvent::try_from(
+                ReceivePacket::new(packet, Order::Unordered, ConnectionId::zero())
+                    .with_packet_data_mode(PacketDataEventMode::Hashed),
+            )
+            .unwrap(),
+        ];
+
+        for event in events {
+            assert_eq!(event.attributes[0].key, "packet_data_hash");
+            assert_eq!(
+                event.attributes[0].value,
+                "8667e718294e9e0df1d30600ba3eeb201f764aad2dad72748643e4a285e1d1f7"
+            );
+            assert_eq!(event.attributes[1].key, "packet_data_hex_preview");
+            assert_eq!(
+                event.attributes[1].value,
+                "ff".repeat(PACKET_DATA_PREVIEW_LEN)
+            );
+            assert!(event
+                .attributes
+                .iter()
+                .all(|attr| attr.key != "packet_data" && attr.key != "packet_data_hex"));
+        }
+    }
 }
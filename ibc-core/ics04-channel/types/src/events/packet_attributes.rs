@@ -10,6 +10,7 @@ use ibc_primitives::Timestamp;
 use subtle_encoding::hex;
 use tendermint::abci;
 
+use super::PACKET_DATA_PREVIEW_LEN;
 use crate::acknowledgement::Acknowledgement;
 use crate::channel::Order;
 use crate::error::ChannelError;
@@ -18,6 +19,8 @@ use crate::timeout::TimeoutHeight;
 const PKT_SEQ_ATTRIBUTE_KEY: &str = "packet_sequence";
 const PKT_DATA_ATTRIBUTE_KEY: &str = "packet_data";
 const PKT_DATA_HEX_ATTRIBUTE_KEY: &str = "packet_data_hex";
+const PKT_DATA_HASH_ATTRIBUTE_KEY: &str = "packet_data_hash";
+const PKT_DATA_HEX_PREVIEW_ATTRIBUTE_KEY: &str = "packet_data_hex_preview";
 const PKT_SRC_PORT_ATTRIBUTE_KEY: &str = "packet_src_port";
 const PKT_SRC_CHANNEL_ATTRIBUTE_KEY: &str = "packet_src_channel";
 const PKT_DST_PORT_ATTRIBUTE_KEY: &str = "packet_dst_port";
@@ -42,6 +45,7 @@ const PKT_CONNECTION_ID_ATTRIBUTE_KEY: &str = "packet_connection";
     derive(borsh::BorshSerialize, borsh::BorshDeserialize)
 )]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[derive(Clone, Debug, From, PartialEq, Eq)]
 pub struct PacketDataAttribute {
     pub packet_data: Vec<u8>,
@@ -69,6 +73,33 @@ impl TryFrom<PacketDataAttribute> for Vec<abci::EventAttribute> {
     }
 }
 
+impl PacketDataAttribute {
+    /// Returns the attributes which carry the hex encoded SHA-256 hash of the
+    /// packet data, along with the hex encoding of its first
+    /// [`PACKET_DATA_PREVIEW_LEN`] bytes, in place of the full packet data.
+    pub fn into_hashed_abci_attributes(self) -> Vec<abci::EventAttribute> {
+        use sha2::Digest;
+
+        let hash = sha2::Sha256::digest(&self.packet_data);
+        let preview_len = self.packet_data.len().min(PACKET_DATA_PREVIEW_LEN);
+
+        vec![
+            (
+                PKT_DATA_HASH_ATTRIBUTE_KEY,
+                str::from_utf8(&hex::encode(hash))
+                    .expect("Never fails because hexadecimal is valid UTF8"),
+            )
+                .into(),
+            (
+                PKT_DATA_HEX_PREVIEW_ATTRIBUTE_KEY,
+                str::from_utf8(&hex::encode(&self.packet_data[..preview_len]))
+                    .expect("Never fails because hexadecimal is valid UTF8"),
+            )
+                .into(),
+        ]
+    }
+}
+
 #[cfg_attr(
     feature = "parity-scale-codec",
     derive(
@@ -82,6 +113,7 @@ impl TryFrom<PacketDataAttribute> for Vec<abci::EventAttribute> {
     derive(borsh::BorshSerialize, borsh::BorshDeserialize)
 )]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[derive(Clone, Debug, From, PartialEq, Eq)]
 pub struct TimeoutHeightAttribute {
     pub timeout_height: TimeoutHeight,
@@ -111,6 +143,7 @@ impl From<TimeoutHeightAttribute> for abci::EventAttribute {
     derive(borsh::BorshSerialize, borsh::BorshDeserialize)
 )]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[derive(Clone, Debug, From, PartialEq, Eq)]
 pub struct TimeoutTimestampAttribute {
     pub timeout_timestamp: Timestamp,
@@ -139,6 +172,7 @@ impl From<TimeoutTimestampAttribute> for abci::EventAttribute {
     derive(borsh::BorshSerialize, borsh::BorshDeserialize)
 )]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[derive(Clone, Debug, From, PartialEq, Eq)]
 pub struct SequenceAttribute {
     pub sequence: Sequence,
@@ -163,6 +197,7 @@ impl From<SequenceAttribute> for abci::EventAttribute {
     derive(borsh::BorshSerialize, borsh::BorshDeserialize)
 )]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[derive(Clone, Debug, From, PartialEq, Eq)]
 pub struct SrcPortIdAttribute {
     pub src_port_id: PortId,
@@ -187,6 +222,7 @@ impl From<SrcPortIdAttribute> for abci::EventAttribute {
     derive(borsh::BorshSerialize, borsh::BorshDeserialize)
 )]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[derive(Clone, Debug, From, PartialEq, Eq)]
 pub struct SrcChannelIdAttribute {
     pub src_channel_id: ChannelId,
@@ -211,6 +247,7 @@ impl From<SrcChannelIdAttribute> for abci::EventAttribute {
     derive(borsh::BorshSerialize, borsh::BorshDeserialize)
 )]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[derive(Clone, Debug, From, PartialEq, Eq)]
 pub struct DstPortIdAttribute {
     pub dst_port_id: PortId,
@@ -235,6 +272,7 @@ impl From<DstPortIdAttribute> for abci::EventAttribute {
     derive(borsh::BorshSerialize, borsh::BorshDeserialize)
 )]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[derive(Clone, Debug, From, PartialEq, Eq)]
 pub struct DstChannelIdAttribute {
     pub dst_channel_id: ChannelId,
@@ -259,6 +297,7 @@ impl From<DstChannelIdAttribute> for abci::EventAttribute {
     derive(borsh::BorshSerialize, borsh::BorshDeserialize)
 )]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[derive(Clone, Debug, From, PartialEq, Eq)]
 pub struct ChannelOrderingAttribute {
     pub order: Order,
@@ -283,6 +322,7 @@ impl From<ChannelOrderingAttribute> for abci::EventAttribute {
     derive(borsh::BorshSerialize, borsh::BorshDeserialize)
 )]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[derive(Clone, Debug, From, PartialEq, Eq)]
 pub struct PacketConnectionIdAttribute {
     pub connection_id: ConnectionId,
@@ -307,6 +347,7 @@ impl From<PacketConnectionIdAttribute> for abci::EventAttribute {
     derive(borsh::BorshSerialize, borsh::BorshDeserialize)
 )]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[derive(Clone, Debug, From, PartialEq, Eq)]
 pub struct AcknowledgementAttribute {
     pub acknowledgement: Acknowledgement,
@@ -22,7 +22,10 @@ pub mod events;
 
 pub mod msgs;
 pub mod packet;
+pub mod packet_view;
 pub mod timeout;
+pub mod upgrade;
+pub mod v2;
 
 pub mod acknowledgement;
 pub mod commitment;
@@ -19,6 +19,14 @@ pub const CHAN_CLOSE_CONFIRM_TYPE_URL: &str = "/ibc.core.channel.v1.MsgChannelCl
     feature = "borsh",
     derive(borsh::BorshSerialize, borsh::BorshDeserialize)
 )]
+#[cfg_attr(
+    feature = "proto-json",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(
+        try_from = "RawMsgChannelCloseConfirm",
+        into = "RawMsgChannelCloseConfirm"
+    )
+)]
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct MsgChannelCloseConfirm {
@@ -26,6 +34,8 @@ pub struct MsgChannelCloseConfirm {
     pub chan_id_on_b: ChannelId,
     pub proof_chan_end_on_a: CommitmentProofBytes,
     pub proof_height_on_a: Height,
+    /// The upgrade sequence of the closed channel end on chain A.
+    pub upgrade_sequence_on_a: u64,
     pub signer: Signer,
 }
 
@@ -35,10 +45,6 @@ impl TryFrom<RawMsgChannelCloseConfirm> for MsgChannelCloseConfirm {
     type Error = ChannelError;
 
     fn try_from(raw_msg: RawMsgChannelCloseConfirm) -> Result<Self, Self::Error> {
-        if raw_msg.counterparty_upgrade_sequence != 0 {
-            return Err(ChannelError::UnsupportedChannelUpgradeSequence);
-        }
-
         Ok(MsgChannelCloseConfirm {
             port_id_on_b: raw_msg.port_id.parse()?,
             chan_id_on_b: raw_msg.channel_id.parse()?,
@@ -50,6 +56,7 @@ impl TryFrom<RawMsgChannelCloseConfirm> for MsgChannelCloseConfirm {
                 .proof_height
                 .and_then(|raw_height| raw_height.try_into().ok())
                 .ok_or(ChannelError::MissingHeight)?,
+            upgrade_sequence_on_a: raw_msg.counterparty_upgrade_sequence,
             signer: raw_msg.signer.into(),
         })
     }
@@ -63,7 +70,7 @@ impl From<MsgChannelCloseConfirm> for RawMsgChannelCloseConfirm {
             proof_init: domain_msg.proof_chan_end_on_a.clone().into(),
             proof_height: Some(domain_msg.proof_height_on_a.into()),
             signer: domain_msg.signer.to_string(),
-            counterparty_upgrade_sequence: 0,
+            counterparty_upgrade_sequence: domain_msg.upgrade_sequence_on_a,
         }
     }
 }
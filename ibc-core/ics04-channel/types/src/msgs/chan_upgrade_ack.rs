@@ -0,0 +1,83 @@
+use ibc_core_client_types::Height;
+use ibc_core_commitment_types::commitment::CommitmentProofBytes;
+use ibc_core_host_types::identifiers::{ChannelId, PortId};
+use ibc_primitives::prelude::*;
+use ibc_primitives::Signer;
+use ibc_proto::ibc::core::channel::v1::MsgChannelUpgradeAck as RawMsgChannelUpgradeAck;
+use ibc_proto::Protobuf;
+
+use crate::error::ChannelError;
+use crate::upgrade::Upgrade;
+
+pub const CHAN_UPGRADE_ACK_TYPE_URL: &str = "/ibc.core.channel.v1.MsgChannelUpgradeAck";
+
+///
+/// Message definition for the third step in the channel upgrade handshake (`ChanUpgradeAck`
+/// datagram).
+/// Per our convention, this message is sent to chain A.
+///
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(
+    feature = "proto-json",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "RawMsgChannelUpgradeAck", into = "RawMsgChannelUpgradeAck")
+)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MsgChannelUpgradeAck {
+    pub port_id_on_a: PortId,
+    pub chan_id_on_a: ChannelId,
+    /// The upgrade stored on chain B, which is flushing.
+    pub upgrade_on_b: Upgrade,
+    pub proof_chan_end_on_b: CommitmentProofBytes,
+    pub proof_upgrade_on_b: CommitmentProofBytes,
+    pub proof_height_on_b: Height,
+    pub signer: Signer,
+}
+
+impl Protobuf<RawMsgChannelUpgradeAck> for MsgChannelUpgradeAck {}
+
+impl TryFrom<RawMsgChannelUpgradeAck> for MsgChannelUpgradeAck {
+    type Error = ChannelError;
+
+    fn try_from(raw_msg: RawMsgChannelUpgradeAck) -> Result<Self, Self::Error> {
+        Ok(MsgChannelUpgradeAck {
+            port_id_on_a: raw_msg.port_id.parse()?,
+            chan_id_on_a: raw_msg.channel_id.parse()?,
+            upgrade_on_b: raw_msg
+                .counterparty_upgrade
+                .ok_or(ChannelError::MissingUpgrade)?
+                .try_into()?,
+            proof_chan_end_on_b: raw_msg
+                .proof_channel
+                .try_into()
+                .map_err(|_| ChannelError::InvalidProof)?,
+            proof_upgrade_on_b: raw_msg
+                .proof_upgrade
+                .try_into()
+                .map_err(|_| ChannelError::InvalidProof)?,
+            proof_height_on_b: raw_msg
+                .proof_height
+                .and_then(|raw_height| raw_height.try_into().ok())
+                .ok_or(ChannelError::MissingHeight)?,
+            signer: raw_msg.signer.into(),
+        })
+    }
+}
+
+impl From<MsgChannelUpgradeAck> for RawMsgChannelUpgradeAck {
+    fn from(domain_msg: MsgChannelUpgradeAck) -> Self {
+        RawMsgChannelUpgradeAck {
+            port_id: domain_msg.port_id_on_a.to_string(),
+            channel_id: domain_msg.chan_id_on_a.to_string(),
+            counterparty_upgrade: Some(domain_msg.upgrade_on_b.into()),
+            proof_channel: domain_msg.proof_chan_end_on_b.into(),
+            proof_upgrade: domain_msg.proof_upgrade_on_b.into(),
+            proof_height: Some(domain_msg.proof_height_on_b.into()),
+            signer: domain_msg.signer.to_string(),
+        }
+    }
+}
@@ -0,0 +1,91 @@
+use ibc_core_client_types::Height;
+use ibc_core_commitment_types::commitment::CommitmentProofBytes;
+use ibc_core_host_types::identifiers::{ChannelId, PortId};
+use ibc_primitives::prelude::*;
+use ibc_primitives::Signer;
+use ibc_proto::ibc::core::channel::v1::MsgChannelUpgradeOpen as RawMsgChannelUpgradeOpen;
+use ibc_proto::Protobuf;
+
+use crate::channel::State;
+use crate::error::ChannelError;
+
+pub const CHAN_UPGRADE_OPEN_TYPE_URL: &str = "/ibc.core.channel.v1.MsgChannelUpgradeOpen";
+
+///
+/// Message definition for the last step in the channel upgrade handshake (`ChanUpgradeOpen`
+/// datagram).
+/// It is sent to each chain whose channel end has flushed all its in-flight packets. Per our
+/// convention, the receiving chain is called chain A.
+///
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(
+    feature = "proto-json",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(
+        try_from = "RawMsgChannelUpgradeOpen",
+        into = "RawMsgChannelUpgradeOpen"
+    )
+)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MsgChannelUpgradeOpen {
+    pub port_id_on_a: PortId,
+    pub chan_id_on_a: ChannelId,
+    /// The state of the channel end on chain B, either `FLUSHCOMPLETE` or
+    /// `OPEN` if it has already completed the upgrade.
+    pub chan_state_on_b: State,
+    pub upgrade_sequence_on_b: u64,
+    pub proof_chan_end_on_b: CommitmentProofBytes,
+    pub proof_height_on_b: Height,
+    pub signer: Signer,
+}
+
+impl Protobuf<RawMsgChannelUpgradeOpen> for MsgChannelUpgradeOpen {}
+
+impl TryFrom<RawMsgChannelUpgradeOpen> for MsgChannelUpgradeOpen {
+    type Error = ChannelError;
+
+    fn try_from(raw_msg: RawMsgChannelUpgradeOpen) -> Result<Self, Self::Error> {
+        let chan_state_on_b = State::from_i32(raw_msg.counterparty_channel_state)?;
+
+        if !matches!(chan_state_on_b, State::FlushComplete | State::Open) {
+            return Err(ChannelError::InvalidState {
+                expected: "Counterparty channel state must be FLUSHCOMPLETE or OPEN".to_string(),
+                actual: chan_state_on_b.to_string(),
+            });
+        }
+
+        Ok(MsgChannelUpgradeOpen {
+            port_id_on_a: raw_msg.port_id.parse()?,
+            chan_id_on_a: raw_msg.channel_id.parse()?,
+            chan_state_on_b,
+            upgrade_sequence_on_b: raw_msg.counterparty_upgrade_sequence,
+            proof_chan_end_on_b: raw_msg
+                .proof_channel
+                .try_into()
+                .map_err(|_| ChannelError::InvalidProof)?,
+            proof_height_on_b: raw_msg
+                .proof_height
+                .and_then(|raw_height| raw_height.try_into().ok())
+                .ok_or(ChannelError::MissingHeight)?,
+            signer: raw_msg.signer.into(),
+        })
+    }
+}
+
+impl From<MsgChannelUpgradeOpen> for RawMsgChannelUpgradeOpen {
+    fn from(domain_msg: MsgChannelUpgradeOpen) -> Self {
+        RawMsgChannelUpgradeOpen {
+            port_id: domain_msg.port_id_on_a.to_string(),
+            channel_id: domain_msg.chan_id_on_a.to_string(),
+            counterparty_channel_state: domain_msg.chan_state_on_b as i32,
+            counterparty_upgrade_sequence: domain_msg.upgrade_sequence_on_b,
+            proof_channel: domain_msg.proof_chan_end_on_b.into(),
+            proof_height: Some(domain_msg.proof_height_on_b.into()),
+            signer: domain_msg.signer.to_string(),
+        }
+    }
+}
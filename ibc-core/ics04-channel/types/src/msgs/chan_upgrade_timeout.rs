@@ -0,0 +1,91 @@
+use ibc_core_client_types::Height;
+use ibc_core_commitment_types::commitment::CommitmentProofBytes;
+use ibc_core_host_types::identifiers::{ChannelId, PortId};
+use ibc_primitives::prelude::*;
+use ibc_primitives::Signer;
+use ibc_proto::ibc::core::channel::v1::MsgChannelUpgradeTimeout as RawMsgChannelUpgradeTimeout;
+use ibc_proto::Protobuf;
+
+use crate::channel::{ChannelEnd, State};
+use crate::error::ChannelError;
+
+pub const CHAN_UPGRADE_TIMEOUT_TYPE_URL: &str = "/ibc.core.channel.v1.MsgChannelUpgradeTimeout";
+
+///
+/// Message definition for aborting a channel upgrade which timed out on the counterparty
+/// (`ChanUpgradeTimeout` datagram).
+/// It is sent to the chain whose channel end is flushing. Per our convention, the receiving chain
+/// is called chain A.
+///
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(
+    feature = "proto-json",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(
+        try_from = "RawMsgChannelUpgradeTimeout",
+        into = "RawMsgChannelUpgradeTimeout"
+    )
+)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MsgChannelUpgradeTimeout {
+    pub port_id_on_a: PortId,
+    pub chan_id_on_a: ChannelId,
+    /// The channel end on chain B, either `OPEN` or `FLUSHING`, at a height
+    /// after the upgrade timed out.
+    pub chan_end_on_b: ChannelEnd,
+    pub proof_chan_end_on_b: CommitmentProofBytes,
+    pub proof_height_on_b: Height,
+    pub signer: Signer,
+}
+
+impl Protobuf<RawMsgChannelUpgradeTimeout> for MsgChannelUpgradeTimeout {}
+
+impl TryFrom<RawMsgChannelUpgradeTimeout> for MsgChannelUpgradeTimeout {
+    type Error = ChannelError;
+
+    fn try_from(raw_msg: RawMsgChannelUpgradeTimeout) -> Result<Self, Self::Error> {
+        let chan_end_on_b: ChannelEnd = raw_msg
+            .counterparty_channel
+            .ok_or(ChannelError::MissingChannel)?
+            .try_into()?;
+
+        if !matches!(chan_end_on_b.state, State::Open | State::Flushing) {
+            return Err(ChannelError::InvalidState {
+                expected: "Counterparty channel state must be OPEN or FLUSHING".to_string(),
+                actual: chan_end_on_b.state.to_string(),
+            });
+        }
+
+        Ok(MsgChannelUpgradeTimeout {
+            port_id_on_a: raw_msg.port_id.parse()?,
+            chan_id_on_a: raw_msg.channel_id.parse()?,
+            chan_end_on_b,
+            proof_chan_end_on_b: raw_msg
+                .proof_channel
+                .try_into()
+                .map_err(|_| ChannelError::InvalidProof)?,
+            proof_height_on_b: raw_msg
+                .proof_height
+                .and_then(|raw_height| raw_height.try_into().ok())
+                .ok_or(ChannelError::MissingHeight)?,
+            signer: raw_msg.signer.into(),
+        })
+    }
+}
+
+impl From<MsgChannelUpgradeTimeout> for RawMsgChannelUpgradeTimeout {
+    fn from(domain_msg: MsgChannelUpgradeTimeout) -> Self {
+        RawMsgChannelUpgradeTimeout {
+            port_id: domain_msg.port_id_on_a.to_string(),
+            channel_id: domain_msg.chan_id_on_a.to_string(),
+            counterparty_channel: Some(domain_msg.chan_end_on_b.into()),
+            proof_channel: domain_msg.proof_chan_end_on_b.into(),
+            proof_height: Some(domain_msg.proof_height_on_b.into()),
+            signer: domain_msg.signer.to_string(),
+        }
+    }
+}
@@ -0,0 +1,116 @@
+use core::str::FromStr;
+
+use ibc_core_client_types::Height;
+use ibc_core_commitment_types::commitment::CommitmentProofBytes;
+use ibc_core_host_types::identifiers::{ChannelId, ConnectionId, PortId};
+use ibc_primitives::prelude::*;
+use ibc_primitives::Signer;
+use ibc_proto::ibc::core::channel::v1::MsgChannelUpgradeTry as RawMsgChannelUpgradeTry;
+use ibc_proto::Protobuf;
+
+use crate::channel::verify_connection_hops_length;
+use crate::error::ChannelError;
+use crate::upgrade::UpgradeFields;
+
+pub const CHAN_UPGRADE_TRY_TYPE_URL: &str = "/ibc.core.channel.v1.MsgChannelUpgradeTry";
+
+///
+/// Message definition for the second step in the channel upgrade handshake (`ChanUpgradeTry`
+/// datagram).
+/// Per our convention, this message is sent to chain B.
+///
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(
+    feature = "proto-json",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "RawMsgChannelUpgradeTry", into = "RawMsgChannelUpgradeTry")
+)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MsgChannelUpgradeTry {
+    pub port_id_on_b: PortId,
+    pub chan_id_on_b: ChannelId,
+    /// The connection hops the channel end on chain B should use after the
+    /// upgrade.
+    pub proposed_conn_hops_on_b: Vec<ConnectionId>,
+    /// The upgrade fields proposed by chain A.
+    pub upgrade_fields_on_a: UpgradeFields,
+    pub upgrade_sequence_on_a: u64,
+    pub proof_chan_end_on_a: CommitmentProofBytes,
+    pub proof_upgrade_on_a: CommitmentProofBytes,
+    pub proof_height_on_a: Height,
+    pub signer: Signer,
+}
+
+impl MsgChannelUpgradeTry {
+    /// Checks that both the proposed connection hops and the ones of the
+    /// upgrade of chain A have a length of one.
+    ///
+    /// Note: Current IBC version only supports one connection hop.
+    pub fn verify_connection_hops_length(&self) -> Result<(), ChannelError> {
+        verify_connection_hops_length(&self.proposed_conn_hops_on_b, 1)?;
+        verify_connection_hops_length(&self.upgrade_fields_on_a.connection_hops, 1)
+    }
+}
+
+impl Protobuf<RawMsgChannelUpgradeTry> for MsgChannelUpgradeTry {}
+
+impl TryFrom<RawMsgChannelUpgradeTry> for MsgChannelUpgradeTry {
+    type Error = ChannelError;
+
+    fn try_from(raw_msg: RawMsgChannelUpgradeTry) -> Result<Self, Self::Error> {
+        let proposed_conn_hops_on_b = raw_msg
+            .proposed_upgrade_connection_hops
+            .into_iter()
+            .map(|conn_id| ConnectionId::from_str(conn_id.as_str()))
+            .collect::<Result<Vec<_>, _>>()?;
+        verify_connection_hops_length(&proposed_conn_hops_on_b, 1)?;
+
+        Ok(MsgChannelUpgradeTry {
+            port_id_on_b: raw_msg.port_id.parse()?,
+            chan_id_on_b: raw_msg.channel_id.parse()?,
+            proposed_conn_hops_on_b,
+            upgrade_fields_on_a: raw_msg
+                .counterparty_upgrade_fields
+                .ok_or(ChannelError::MissingUpgradeFields)?
+                .try_into()?,
+            upgrade_sequence_on_a: raw_msg.counterparty_upgrade_sequence,
+            proof_chan_end_on_a: raw_msg
+                .proof_channel
+                .try_into()
+                .map_err(|_| ChannelError::InvalidProof)?,
+            proof_upgrade_on_a: raw_msg
+                .proof_upgrade
+                .try_into()
+                .map_err(|_| ChannelError::InvalidProof)?,
+            proof_height_on_a: raw_msg
+                .proof_height
+                .and_then(|raw_height| raw_height.try_into().ok())
+                .ok_or(ChannelError::MissingHeight)?,
+            signer: raw_msg.signer.into(),
+        })
+    }
+}
+
+impl From<MsgChannelUpgradeTry> for RawMsgChannelUpgradeTry {
+    fn from(domain_msg: MsgChannelUpgradeTry) -> Self {
+        RawMsgChannelUpgradeTry {
+            port_id: domain_msg.port_id_on_b.to_string(),
+            channel_id: domain_msg.chan_id_on_b.to_string(),
+            proposed_upgrade_connection_hops: domain_msg
+                .proposed_conn_hops_on_b
+                .iter()
+                .map(|v| v.as_str().to_string())
+                .collect(),
+            counterparty_upgrade_fields: Some(domain_msg.upgrade_fields_on_a.into()),
+            counterparty_upgrade_sequence: domain_msg.upgrade_sequence_on_a,
+            proof_channel: domain_msg.proof_chan_end_on_a.into(),
+            proof_upgrade: domain_msg.proof_upgrade_on_a.into(),
+            proof_height: Some(domain_msg.proof_height_on_a.into()),
+            signer: domain_msg.signer.to_string(),
+        }
+    }
+}
@@ -6,7 +6,7 @@ use ibc_primitives::Signer;
 use ibc_proto::ibc::core::channel::v1::MsgTimeoutOnClose as RawMsgTimeoutOnClose;
 use ibc_proto::Protobuf;
 
-use crate::error::{ChannelError, PacketError};
+use crate::error::PacketError;
 use crate::packet::Packet;
 
 pub const TIMEOUT_ON_CLOSE_TYPE_URL: &str = "/ibc.core.channel.v1.MsgTimeoutOnClose";
@@ -18,6 +18,11 @@ pub const TIMEOUT_ON_CLOSE_TYPE_URL: &str = "/ibc.core.channel.v1.MsgTimeoutOnCl
     feature = "borsh",
     derive(borsh::BorshSerialize, borsh::BorshDeserialize)
 )]
+#[cfg_attr(
+    feature = "proto-json",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "RawMsgTimeoutOnClose", into = "RawMsgTimeoutOnClose")
+)]
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct MsgTimeoutOnClose {
@@ -26,6 +31,8 @@ pub struct MsgTimeoutOnClose {
     pub proof_unreceived_on_b: CommitmentProofBytes,
     pub proof_close_on_b: CommitmentProofBytes,
     pub proof_height_on_b: Height,
+    /// The upgrade sequence of the closed channel end on chain B.
+    pub upgrade_sequence_on_b: u64,
     pub signer: Signer,
 }
 
@@ -39,12 +46,6 @@ impl TryFrom<RawMsgTimeoutOnClose> for MsgTimeoutOnClose {
             return Err(PacketError::ZeroPacketSequence);
         }
 
-        if raw_msg.counterparty_upgrade_sequence != 0 {
-            return Err(PacketError::Channel(
-                ChannelError::UnsupportedChannelUpgradeSequence,
-            ));
-        }
-
         Ok(MsgTimeoutOnClose {
             packet: raw_msg
                 .packet
@@ -63,6 +64,7 @@ impl TryFrom<RawMsgTimeoutOnClose> for MsgTimeoutOnClose {
                 .proof_height
                 .and_then(|raw_height| raw_height.try_into().ok())
                 .ok_or(PacketError::MissingHeight)?,
+            upgrade_sequence_on_b: raw_msg.counterparty_upgrade_sequence,
             signer: raw_msg.signer.into(),
         })
     }
@@ -77,7 +79,7 @@ impl From<MsgTimeoutOnClose> for RawMsgTimeoutOnClose {
             proof_height: Some(domain_msg.proof_height_on_b.into()),
             next_sequence_recv: domain_msg.next_seq_recv_on_b.into(),
             signer: domain_msg.signer.to_string(),
-            counterparty_upgrade_sequence: 0,
+            counterparty_upgrade_sequence: domain_msg.upgrade_sequence_on_b,
         }
     }
 }
@@ -5,6 +5,7 @@ use ibc_primitives::prelude::*;
 use ibc_primitives::Expiry::Expired;
 use ibc_primitives::Timestamp;
 use ibc_proto::ibc::core::channel::v1::{Packet as RawPacket, PacketState as RawPacketState};
+use ibc_proto::Protobuf;
 
 use super::timeout::TimeoutHeight;
 use crate::error::PacketError;
@@ -19,7 +20,8 @@ pub enum PacketMsgType {
     TimeoutOnClose,
 }
 
-/// Packet receipt, used over unordered channels.
+/// Packet receipt, used over unordered channels, and over ordered channels
+/// allowing timeouts to record the packets which timed out.
 #[cfg_attr(
     feature = "parity-scale-codec",
     derive(
@@ -33,9 +35,23 @@ pub enum PacketMsgType {
     derive(borsh::BorshSerialize, borsh::BorshDeserialize)
 )]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub enum Receipt {
     Ok,
+    /// The packet timed out before it was received, over a channel ordered
+    /// with [`Order::OrderedAllowTimeout`](crate::channel::Order::OrderedAllowTimeout).
+    Timeout,
+}
+
+impl Receipt {
+    /// Returns the value committed to the store for the receipt. Like ibc-go,
+    /// a single `1` byte is stored for received packets.
+    pub fn to_vec(&self) -> Vec<u8> {
+        match self {
+            Self::Ok => vec![1],
+            Self::Timeout => vec![2],
+        }
+    }
 }
 
 impl core::fmt::Display for PacketMsgType {
@@ -164,6 +180,8 @@ impl core::fmt::Display for Packet {
     }
 }
 
+impl Protobuf<RawPacket> for Packet {}
+
 impl TryFrom<RawPacket> for Packet {
     type Error = PacketError;
 
@@ -309,3 +327,91 @@ impl From<PacketState> for RawPacketState {
         }
     }
 }
+
+/// A range of packet sequences, which paginates the iteration over the packet
+/// state stored for a channel end.
+///
+/// The sequences of the next page start right after the last sequence of the
+/// current one.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct SequenceRange {
+    /// The first sequence of the range, if bounded.
+    pub start: Option<Sequence>,
+    /// The sequence the range ends before, if bounded.
+    pub end: Option<Sequence>,
+    /// The maximum number of sequences in the range, if any.
+    pub limit: Option<usize>,
+}
+
+impl SequenceRange {
+    /// The range of all the sequences.
+    pub fn all() -> Self {
+        Self::default()
+    }
+
+    /// The range of the sequences from `start`, inclusive, to `end`,
+    /// exclusive.
+    pub fn new(start: Sequence, end: Sequence) -> Self {
+        Self {
+            start: Some(start),
+            end: Some(end),
+            limit: None,
+        }
+    }
+
+    /// Limits the number of sequences in the range.
+    pub fn with_limit(self, limit: usize) -> Self {
+        Self {
+            limit: Some(limit),
+            ..self
+        }
+    }
+
+    /// Checks whether the given sequence is within the bounds of the range.
+    pub fn contains(&self, sequence: Sequence) -> bool {
+        self.start.map_or(true, |start| sequence >= start)
+            && self.end.map_or(true, |end| sequence < end)
+    }
+
+    /// Collects the given sequences, in ascending order, which are within the
+    /// bounds of the range, up to its limit.
+    pub fn collect(&self, sequences: impl IntoIterator<Item = Sequence>) -> Vec<Sequence> {
+        let mut sequences: Vec<_> = sequences
+            .into_iter()
+            .filter(|sequence| self.contains(*sequence))
+            .collect();
+        sequences.sort();
+
+        if let Some(limit) = self.limit {
+            sequences.truncate(limit);
+        }
+
+        sequences
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sequence_range() {
+        let sequences = [5u64, 1, 3, 2, 4].map(Sequence::from);
+
+        assert_eq!(
+            SequenceRange::all().collect(sequences),
+            [1u64, 2, 3, 4, 5].map(Sequence::from)
+        );
+        assert_eq!(
+            SequenceRange::new(2.into(), 5.into()).collect(sequences),
+            [2u64, 3, 4].map(Sequence::from)
+        );
+        assert_eq!(
+            SequenceRange::all().with_limit(2).collect(sequences),
+            [1u64, 2].map(Sequence::from)
+        );
+        assert!(SequenceRange::new(6.into(), 10.into())
+            .collect(sequences)
+            .is_empty());
+    }
+}
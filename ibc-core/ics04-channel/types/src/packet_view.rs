@@ -0,0 +1,387 @@
+//! Defines a borrowed view of the protobuf encoding of a packet, decoded
+//! without copying its identifiers and data.
+//!
+//! Decoding a [`Packet`] through its raw type allocates its data and each of
+//! its identifiers, which on the hot paths of the hosts is often only to
+//! compute the packet commitment or to check the packet timeout. A
+//! [`PacketView`] borrows these from the encoded bytes instead, and is only
+//! turned into a [`Packet`] when one is needed.
+
+use ibc_core_client_types::Height;
+use ibc_core_host_types::identifiers::{ChannelId, PortId, Sequence};
+use ibc_core_host_types::validate::{validate_channel_identifier, validate_port_identifier};
+use ibc_primitives::prelude::*;
+use ibc_primitives::Expiry::Expired;
+use ibc_primitives::Timestamp;
+use ibc_proto::ibc::core::client::v1::Height as RawHeight;
+
+use crate::commitment::{compute_packet_commitment, PacketCommitment};
+use crate::error::PacketError;
+use crate::packet::Packet;
+use crate::timeout::TimeoutHeight;
+
+/// A packet borrowing its identifiers and data from its protobuf encoding.
+///
+/// The view is validated as a [`Packet`] is when decoded, so that turning it
+/// into one never fails.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PacketView<'a> {
+    seq_on_a: Sequence,
+    port_id_on_a: &'a str,
+    chan_id_on_a: &'a str,
+    port_id_on_b: &'a str,
+    chan_id_on_b: &'a str,
+    data: &'a [u8],
+    timeout_height_on_b: TimeoutHeight,
+    timeout_timestamp_on_b: Timestamp,
+}
+
+impl<'a> PacketView<'a> {
+    /// Decodes the view from the protobuf encoding of a `Packet`.
+    pub fn decode(bytes: &'a [u8]) -> Result<Self, PacketError> {
+        let mut reader = WireReader::new(bytes);
+
+        let mut sequence = 0;
+        let mut source_port = "";
+        let mut source_channel = "";
+        let mut destination_port = "";
+        let mut destination_channel = "";
+        let mut data: &[u8] = &[];
+        let mut timeout_height: Option<RawHeight> = None;
+        let mut timeout_timestamp = 0;
+
+        while !reader.is_empty() {
+            let (field, wire_type) = reader.key()?;
+
+            match (field, wire_type) {
+                (1, WIRE_VARINT) => sequence = reader.varint()?,
+                (2, WIRE_LEN) => source_port = reader.str()?,
+                (3, WIRE_LEN) => source_channel = reader.str()?,
+                (4, WIRE_LEN) => destination_port = reader.str()?,
+                (5, WIRE_LEN) => destination_channel = reader.str()?,
+                (6, WIRE_LEN) => data = reader.len_delimited()?,
+                (7, WIRE_LEN) => {
+                    // Like the other message fields, the occurrences of the
+                    // height merge into each other.
+                    let height = timeout_height.get_or_insert(RawHeight {
+                        revision_number: 0,
+                        revision_height: 0,
+                    });
+
+                    decode_height(reader.len_delimited()?, height)?;
+                }
+                (8, WIRE_VARINT) => timeout_timestamp = reader.varint()?,
+                (1..=8, _) => {
+                    return Err(PacketError::InvalidPacketEncoding {
+                        reason: format!("unexpected wire type {wire_type} of the field {field}"),
+                    })
+                }
+                _ => reader.skip(wire_type)?,
+            }
+        }
+
+        let seq_on_a = Sequence::from(sequence);
+
+        if seq_on_a.is_zero() {
+            return Err(PacketError::ZeroPacketSequence);
+        }
+
+        if data.is_empty() {
+            return Err(PacketError::ZeroPacketData);
+        }
+
+        validate_port_identifier(source_port)?;
+        validate_channel_identifier(source_channel)?;
+        validate_port_identifier(destination_port)?;
+        validate_channel_identifier(destination_channel)?;
+
+        let timeout_height_on_b = TimeoutHeight::try_from(timeout_height)
+            .map_err(|_| PacketError::InvalidTimeoutHeight)?;
+
+        let timeout_timestamp_on_b = Timestamp::from_nanoseconds(timeout_timestamp)
+            .map_err(PacketError::InvalidPacketTimestamp)?;
+
+        if !timeout_height_on_b.is_set() && !timeout_timestamp_on_b.is_set() {
+            return Err(PacketError::MissingTimeout);
+        }
+
+        Ok(Self {
+            seq_on_a,
+            port_id_on_a: source_port,
+            chan_id_on_a: source_channel,
+            port_id_on_b: destination_port,
+            chan_id_on_b: destination_channel,
+            data,
+            timeout_height_on_b,
+            timeout_timestamp_on_b,
+        })
+    }
+
+    pub fn seq_on_a(&self) -> Sequence {
+        self.seq_on_a
+    }
+
+    pub fn port_id_on_a(&self) -> &'a str {
+        self.port_id_on_a
+    }
+
+    pub fn chan_id_on_a(&self) -> &'a str {
+        self.chan_id_on_a
+    }
+
+    pub fn port_id_on_b(&self) -> &'a str {
+        self.port_id_on_b
+    }
+
+    pub fn chan_id_on_b(&self) -> &'a str {
+        self.chan_id_on_b
+    }
+
+    pub fn data(&self) -> &'a [u8] {
+        self.data
+    }
+
+    pub fn timeout_height_on_b(&self) -> TimeoutHeight {
+        self.timeout_height_on_b
+    }
+
+    pub fn timeout_timestamp_on_b(&self) -> Timestamp {
+        self.timeout_timestamp_on_b
+    }
+
+    /// Computes the commitment of the packet, over its borrowed data.
+    pub fn commitment(&self) -> PacketCommitment {
+        compute_packet_commitment(
+            self.data,
+            &self.timeout_height_on_b,
+            &self.timeout_timestamp_on_b,
+        )
+    }
+
+    /// Checks whether the packet is timed out on the destination chain, as
+    /// [`Packet::timed_out`] does.
+    pub fn timed_out(&self, dst_chain_ts: &Timestamp, dst_chain_height: Height) -> bool {
+        let height_timed_out = self.timeout_height_on_b.has_expired(dst_chain_height);
+
+        let timestamp_timed_out = self.timeout_timestamp_on_b.is_set()
+            && dst_chain_ts.check_expiry(&self.timeout_timestamp_on_b) == Expired;
+
+        height_timed_out || timestamp_timed_out
+    }
+
+    /// Copies the identifiers and the data of the view into a [`Packet`].
+    pub fn to_packet(&self) -> Packet {
+        Packet {
+            seq_on_a: self.seq_on_a,
+            port_id_on_a: PortId::new(self.port_id_on_a.to_string()).expect("Never fails"),
+            chan_id_on_a: self.chan_id_on_a.parse::<ChannelId>().expect("Never fails"),
+            port_id_on_b: PortId::new(self.port_id_on_b.to_string()).expect("Never fails"),
+            chan_id_on_b: self.chan_id_on_b.parse::<ChannelId>().expect("Never fails"),
+            data: self.data.to_vec(),
+            timeout_height_on_b: self.timeout_height_on_b,
+            timeout_timestamp_on_b: self.timeout_timestamp_on_b,
+        }
+    }
+}
+
+impl<'a> From<&'a Packet> for PacketView<'a> {
+    fn from(packet: &'a Packet) -> Self {
+        Self {
+            seq_on_a: packet.seq_on_a,
+            port_id_on_a: packet.port_id_on_a.as_str(),
+            chan_id_on_a: packet.chan_id_on_a.as_str(),
+            port_id_on_b: packet.port_id_on_b.as_str(),
+            chan_id_on_b: packet.chan_id_on_b.as_str(),
+            data: &packet.data,
+            timeout_height_on_b: packet.timeout_height_on_b,
+            timeout_timestamp_on_b: packet.timeout_timestamp_on_b,
+        }
+    }
+}
+
+impl From<PacketView<'_>> for Packet {
+    fn from(view: PacketView<'_>) -> Self {
+        view.to_packet()
+    }
+}
+
+const WIRE_VARINT: u8 = 0;
+const WIRE_FIXED64: u8 = 1;
+const WIRE_LEN: u8 = 2;
+const WIRE_FIXED32: u8 = 5;
+
+/// Decodes the fields of a `Height` into the given one.
+fn decode_height(bytes: &[u8], height: &mut RawHeight) -> Result<(), PacketError> {
+    let mut reader = WireReader::new(bytes);
+
+    while !reader.is_empty() {
+        match reader.key()? {
+            (1, WIRE_VARINT) => height.revision_number = reader.varint()?,
+            (2, WIRE_VARINT) => height.revision_height = reader.varint()?,
+            (1..=2, wire_type) => {
+                return Err(PacketError::InvalidPacketEncoding {
+                    reason: format!("unexpected wire type {wire_type} of the timeout height"),
+                })
+            }
+            (_, wire_type) => reader.skip(wire_type)?,
+        }
+    }
+
+    Ok(())
+}
+
+/// Reads the protobuf wire format, borrowing the length-delimited fields.
+struct WireReader<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> WireReader<'a> {
+    fn new(bytes: &'a [u8]) -> Self {
+        Self { bytes }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.bytes.is_empty()
+    }
+
+    fn varint(&mut self) -> Result<u64, PacketError> {
+        let mut value = 0u64;
+
+        for (i, byte) in self.bytes.iter().take(10).enumerate() {
+            value |= u64::from(byte & 0x7f) << (7 * i);
+
+            if byte & 0x80 == 0 {
+                self.bytes = &self.bytes[i + 1..];
+                return Ok(value);
+            }
+        }
+
+        Err(PacketError::InvalidPacketEncoding {
+            reason: "invalid varint".to_string(),
+        })
+    }
+
+    fn key(&mut self) -> Result<(u64, u8), PacketError> {
+        let key = self.varint()?;
+
+        Ok((key >> 3, (key & 0x07) as u8))
+    }
+
+    fn take(&mut self, len: usize) -> Result<&'a [u8], PacketError> {
+        if self.bytes.len() < len {
+            return Err(PacketError::InvalidPacketEncoding {
+                reason: "unexpected end of the buffer".to_string(),
+            });
+        }
+
+        let (taken, rest) = self.bytes.split_at(len);
+        self.bytes = rest;
+
+        Ok(taken)
+    }
+
+    fn len_delimited(&mut self) -> Result<&'a [u8], PacketError> {
+        let len =
+            usize::try_from(self.varint()?).map_err(|_| PacketError::InvalidPacketEncoding {
+                reason: "length overflow".to_string(),
+            })?;
+
+        self.take(len)
+    }
+
+    fn str(&mut self) -> Result<&'a str, PacketError> {
+        core::str::from_utf8(self.len_delimited()?).map_err(|e| {
+            PacketError::InvalidPacketEncoding {
+                reason: e.to_string(),
+            }
+        })
+    }
+
+    fn skip(&mut self, wire_type: u8) -> Result<(), PacketError> {
+        match wire_type {
+            WIRE_VARINT => self.varint().map(|_| ()),
+            WIRE_FIXED64 => self.take(8).map(|_| ()),
+            WIRE_LEN => self.len_delimited().map(|_| ()),
+            WIRE_FIXED32 => self.take(4).map(|_| ()),
+            _ => Err(PacketError::InvalidPacketEncoding {
+                reason: format!("unsupported wire type {wire_type}"),
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ibc_proto::ibc::core::channel::v1::Packet as RawPacket;
+    use prost::Message;
+
+    use super::*;
+
+    fn raw_packet() -> RawPacket {
+        RawPacket {
+            sequence: 7,
+            source_port: "transfer".to_string(),
+            source_channel: "channel-0".to_string(),
+            destination_port: "transfer".to_string(),
+            destination_channel: "channel-1".to_string(),
+            data: vec![0xab; 1024],
+            timeout_height: Some(RawHeight {
+                revision_number: 1,
+                revision_height: 100,
+            }),
+            timeout_timestamp: 1_700_000_000_000_000_000,
+        }
+    }
+
+    #[test]
+    fn test_packet_view_matches_packet() {
+        let raw = raw_packet();
+        let bytes = raw.encode_to_vec();
+
+        let view = PacketView::decode(&bytes).unwrap();
+        let packet = Packet::try_from(raw).unwrap();
+
+        assert_eq!(view.to_packet(), packet);
+        assert_eq!(view, PacketView::from(&packet));
+        assert_eq!(
+            view.commitment(),
+            compute_packet_commitment(
+                &packet.data,
+                &packet.timeout_height_on_b,
+                &packet.timeout_timestamp_on_b
+            )
+        );
+    }
+
+    #[test]
+    fn test_packet_view_skips_unknown_fields() {
+        let mut bytes = raw_packet().encode_to_vec();
+        // An unknown field 15 of the varint wire type.
+        bytes.extend_from_slice(&[15 << 3, 1]);
+
+        assert!(PacketView::decode(&bytes).is_ok());
+    }
+
+    #[test]
+    fn test_packet_view_validates_as_packet() {
+        let mut raw = raw_packet();
+        raw.sequence = 0;
+        assert!(matches!(
+            PacketView::decode(&raw.encode_to_vec()),
+            Err(PacketError::ZeroPacketSequence)
+        ));
+
+        let mut raw = raw_packet();
+        raw.source_channel = "channel/0".to_string();
+        assert!(matches!(
+            PacketView::decode(&raw.encode_to_vec()),
+            Err(PacketError::InvalidIdentifier(_))
+        ));
+
+        let bytes = raw_packet().encode_to_vec();
+        assert!(matches!(
+            PacketView::decode(&bytes[..bytes.len() - 1]),
+            Err(PacketError::InvalidPacketEncoding { .. })
+        ));
+    }
+}
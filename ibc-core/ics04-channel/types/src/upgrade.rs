@@ -0,0 +1,305 @@
+//! Types of the channel upgrade handshake, as described in ICS-04.
+
+use core::str::FromStr;
+use core::time::Duration;
+
+use ibc_core_client_types::Height;
+use ibc_core_host_types::identifiers::{ConnectionId, Sequence};
+use ibc_primitives::prelude::*;
+use ibc_primitives::Expiry::Expired;
+use ibc_primitives::Timestamp;
+use ibc_proto::ibc::core::channel::v1::{
+    ErrorReceipt as RawErrorReceipt, Timeout as RawUpgradeTimeout, Upgrade as RawUpgrade,
+    UpgradeFields as RawUpgradeFields,
+};
+use ibc_proto::Protobuf;
+
+use crate::channel::{verify_connection_hops_length, ChannelEnd, Order};
+use crate::error::ChannelError;
+use crate::timeout::TimeoutHeight;
+use crate::Version;
+
+/// The default period after which a counterparty stops accepting to
+/// move on with an upgrade, as in ibc-go.
+pub const DEFAULT_UPGRADE_TIMEOUT: Duration = Duration::from_secs(600);
+
+/// The parameters of a channel end that an upgrade proposes to change.
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UpgradeFields {
+    pub ordering: Order,
+    pub connection_hops: Vec<ConnectionId>,
+    pub version: Version,
+}
+
+impl UpgradeFields {
+    pub fn new(ordering: Order, connection_hops: Vec<ConnectionId>, version: Version) -> Self {
+        Self {
+            ordering,
+            connection_hops,
+            version,
+        }
+    }
+
+    pub fn validate_basic(&self) -> Result<(), ChannelError> {
+        if self.ordering == Order::None {
+            return Err(ChannelError::InvalidOrderType {
+                expected: "Channel ordering cannot be None".to_string(),
+                actual: self.ordering.to_string(),
+            });
+        }
+
+        verify_connection_hops_length(&self.connection_hops, 1)
+    }
+
+    /// Returns `true` if upgrading `chan_end` to these fields would leave
+    /// it unchanged.
+    pub fn matches_channel_end(&self, chan_end: &ChannelEnd) -> bool {
+        self.ordering == chan_end.ordering
+            && self.connection_hops == chan_end.connection_hops
+            && self.version == chan_end.version
+    }
+}
+
+impl Protobuf<RawUpgradeFields> for UpgradeFields {}
+
+impl TryFrom<RawUpgradeFields> for UpgradeFields {
+    type Error = ChannelError;
+
+    fn try_from(raw_fields: RawUpgradeFields) -> Result<Self, Self::Error> {
+        let connection_hops = raw_fields
+            .connection_hops
+            .into_iter()
+            .map(|conn_id| ConnectionId::from_str(conn_id.as_str()))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let fields = UpgradeFields {
+            ordering: Order::from_i32(raw_fields.ordering)?,
+            connection_hops,
+            version: raw_fields.version.into(),
+        };
+        fields.validate_basic()?;
+
+        Ok(fields)
+    }
+}
+
+impl From<UpgradeFields> for RawUpgradeFields {
+    fn from(fields: UpgradeFields) -> Self {
+        RawUpgradeFields {
+            ordering: fields.ordering as i32,
+            connection_hops: fields
+                .connection_hops
+                .iter()
+                .map(|v| v.as_str().to_string())
+                .collect(),
+            version: fields.version.to_string(),
+        }
+    }
+}
+
+/// The absolute height and/or timestamp on the counterparty chain after which
+/// an upgrade can no longer proceed.
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct UpgradeTimeout {
+    pub height: TimeoutHeight,
+    pub timestamp: Timestamp,
+}
+
+impl UpgradeTimeout {
+    /// The timeout of an upgrade which has not started flushing yet.
+    pub fn unset() -> Self {
+        Self {
+            height: TimeoutHeight::Never,
+            timestamp: Timestamp::none(),
+        }
+    }
+
+    pub fn is_set(&self) -> bool {
+        self.height.is_set() || self.timestamp.is_set()
+    }
+
+    /// Checks whether the timeout has elapsed at the given height and
+    /// timestamp of the chain it refers to.
+    pub fn has_expired(&self, height: Height, timestamp: &Timestamp) -> bool {
+        let timestamp_expired =
+            self.timestamp.is_set() && timestamp.check_expiry(&self.timestamp) == Expired;
+
+        self.height.has_expired(height) || timestamp_expired
+    }
+}
+
+impl Protobuf<RawUpgradeTimeout> for UpgradeTimeout {}
+
+impl TryFrom<RawUpgradeTimeout> for UpgradeTimeout {
+    type Error = ChannelError;
+
+    fn try_from(raw_timeout: RawUpgradeTimeout) -> Result<Self, Self::Error> {
+        let height = TimeoutHeight::try_from(raw_timeout.height).map_err(|e| {
+            ChannelError::InvalidUpgradeTimeout {
+                reason: e.to_string(),
+            }
+        })?;
+        let timestamp = Timestamp::from_nanoseconds(raw_timeout.timestamp).map_err(|e| {
+            ChannelError::InvalidUpgradeTimeout {
+                reason: e.to_string(),
+            }
+        })?;
+
+        Ok(UpgradeTimeout { height, timestamp })
+    }
+}
+
+impl From<UpgradeTimeout> for RawUpgradeTimeout {
+    fn from(timeout: UpgradeTimeout) -> Self {
+        RawUpgradeTimeout {
+            height: timeout.height.into(),
+            timestamp: timeout.timestamp.nanoseconds(),
+        }
+    }
+}
+
+/// An upgrade proposed for a channel end, as stored under the
+/// `ChannelUpgradePath` during the upgrade handshake.
+///
+/// The timeout and the next sequence to be sent are only set once the channel
+/// end starts flushing its in-flight packets.
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Upgrade {
+    pub fields: UpgradeFields,
+    pub timeout: UpgradeTimeout,
+    pub next_sequence_send: Sequence,
+}
+
+impl Upgrade {
+    /// Creates the upgrade proposed by `MsgChannelUpgradeInit`, which isn't
+    /// flushing yet.
+    pub fn new(fields: UpgradeFields) -> Self {
+        Self {
+            fields,
+            timeout: UpgradeTimeout::unset(),
+            next_sequence_send: Sequence::from(0),
+        }
+    }
+}
+
+impl Protobuf<RawUpgrade> for Upgrade {}
+
+impl TryFrom<RawUpgrade> for Upgrade {
+    type Error = ChannelError;
+
+    fn try_from(raw_upgrade: RawUpgrade) -> Result<Self, Self::Error> {
+        Ok(Upgrade {
+            fields: raw_upgrade
+                .fields
+                .ok_or(ChannelError::MissingUpgradeFields)?
+                .try_into()?,
+            timeout: raw_upgrade
+                .timeout
+                .ok_or(ChannelError::MissingUpgradeTimeout)?
+                .try_into()?,
+            next_sequence_send: raw_upgrade.next_sequence_send.into(),
+        })
+    }
+}
+
+impl From<Upgrade> for RawUpgrade {
+    fn from(upgrade: Upgrade) -> Self {
+        RawUpgrade {
+            fields: Some(upgrade.fields.into()),
+            timeout: Some(upgrade.timeout.into()),
+            next_sequence_send: upgrade.next_sequence_send.into(),
+        }
+    }
+}
+
+/// The receipt a channel end writes when it aborts an upgrade, under the
+/// `ChannelUpgradeErrorPath`, so that its counterparty can prove the upgrade
+/// was aborted and cancel it in turn.
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ErrorReceipt {
+    /// The upgrade sequence of the aborted upgrade.
+    pub sequence: u64,
+    pub message: String,
+}
+
+impl ErrorReceipt {
+    pub fn new(sequence: u64, message: String) -> Self {
+        Self { sequence, message }
+    }
+}
+
+impl Protobuf<RawErrorReceipt> for ErrorReceipt {}
+
+impl From<RawErrorReceipt> for ErrorReceipt {
+    fn from(raw_receipt: RawErrorReceipt) -> Self {
+        ErrorReceipt {
+            sequence: raw_receipt.sequence,
+            message: raw_receipt.message,
+        }
+    }
+}
+
+impl From<ErrorReceipt> for RawErrorReceipt {
+    fn from(receipt: ErrorReceipt) -> Self {
+        RawErrorReceipt {
+            sequence: receipt.sequence,
+            message: receipt.message,
+        }
+    }
+}
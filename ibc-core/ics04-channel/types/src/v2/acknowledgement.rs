@@ -0,0 +1,121 @@
+//! Defines the acknowledgement of IBC v2 packets, which carries one
+//! acknowledgement per payload of the packet.
+use ibc_primitives::prelude::*;
+use ibc_proto::Protobuf;
+
+use super::proto::Acknowledgement as RawAcknowledgement;
+use crate::error::PacketError;
+
+/// The acknowledgement written in place of the application ones when the
+/// packet fails to be received by any of its applications, which is the
+/// SHA-256 hash of `"UNIVERSAL_ERROR_ACKNOWLEDGEMENT"`, as in ibc-go.
+pub const ERROR_ACKNOWLEDGEMENT: [u8; 32] = [
+    0x47, 0x74, 0xd4, 0xa5, 0x75, 0x99, 0x3f, 0x96, 0x3b, 0x1c, 0x06, 0x57, 0x37, 0x36, 0x61, 0x7a,
+    0x45, 0x7a, 0xbe, 0xf8, 0x58, 0x91, 0x78, 0xdb, 0x8d, 0x10, 0xc9, 0x4b, 0x4a, 0xb5, 0x11, 0xab,
+];
+
+/// The acknowledgement of an IBC v2 packet.
+///
+/// It either carries the acknowledgements of the applications, in the order
+/// of the payloads of the packet, or the single [`ERROR_ACKNOWLEDGEMENT`] if
+/// any of them failed to receive its payload.
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Acknowledgement {
+    app_acknowledgements: Vec<Vec<u8>>,
+}
+
+impl Acknowledgement {
+    /// Builds the acknowledgement from the acknowledgements of the
+    /// applications, none of which can be empty.
+    pub fn new(app_acknowledgements: Vec<Vec<u8>>) -> Result<Self, PacketError> {
+        if app_acknowledgements.is_empty() || app_acknowledgements.iter().any(Vec::is_empty) {
+            return Err(PacketError::InvalidAcknowledgement);
+        }
+
+        Ok(Self {
+            app_acknowledgements,
+        })
+    }
+
+    /// Returns the acknowledgement written when the packet fails to be
+    /// received.
+    pub fn error() -> Self {
+        Self {
+            app_acknowledgements: vec![ERROR_ACKNOWLEDGEMENT.to_vec()],
+        }
+    }
+
+    /// Returns whether the packet failed to be received.
+    pub fn is_error(&self) -> bool {
+        self.app_acknowledgements
+            .first()
+            .is_some_and(|ack| ack.as_slice() == ERROR_ACKNOWLEDGEMENT)
+    }
+
+    pub fn app_acknowledgements(&self) -> &[Vec<u8>] {
+        &self.app_acknowledgements
+    }
+}
+
+impl Protobuf<RawAcknowledgement> for Acknowledgement {}
+
+impl TryFrom<RawAcknowledgement> for Acknowledgement {
+    type Error = PacketError;
+
+    fn try_from(raw_ack: RawAcknowledgement) -> Result<Self, Self::Error> {
+        Self::new(raw_ack.app_acknowledgements)
+    }
+}
+
+impl From<Acknowledgement> for RawAcknowledgement {
+    fn from(ack: Acknowledgement) -> Self {
+        RawAcknowledgement {
+            app_acknowledgements: ack.app_acknowledgements,
+        }
+    }
+}
+
+/// The outcome of the reception of a payload by its application.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PacketStatus {
+    Success,
+    Failure,
+}
+
+/// The result of the reception of a payload by its application, carrying the
+/// acknowledgement of the application.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RecvPacketResult {
+    pub status: PacketStatus,
+    pub acknowledgement: Vec<u8>,
+}
+
+impl RecvPacketResult {
+    pub fn success(acknowledgement: Vec<u8>) -> Self {
+        Self {
+            status: PacketStatus::Success,
+            acknowledgement,
+        }
+    }
+
+    pub fn failure(acknowledgement: Vec<u8>) -> Self {
+        Self {
+            status: PacketStatus::Failure,
+            acknowledgement,
+        }
+    }
+}
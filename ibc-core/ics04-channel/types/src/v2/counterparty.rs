@@ -0,0 +1,38 @@
+//! Defines the counterparty of a client, which IBC v2 packets are exchanged
+//! with.
+use ibc_core_commitment_types::commitment::CommitmentPrefix;
+use ibc_core_host_types::identifiers::ClientId;
+
+/// The counterparty of a client: the client tracking the host on the chain
+/// the client tracks, and the prefix the IBC store of that chain is proven
+/// under.
+///
+/// IBC v2 packets are sent over a client once its counterparty is
+/// registered, which replaces the connection and the channel handshakes.
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ClientCounterparty {
+    pub client_id: ClientId,
+    pub merkle_prefix: CommitmentPrefix,
+}
+
+impl ClientCounterparty {
+    pub fn new(client_id: ClientId, merkle_prefix: CommitmentPrefix) -> Self {
+        Self {
+            client_id,
+            merkle_prefix,
+        }
+    }
+}
@@ -0,0 +1,172 @@
+//! Types for the IBC events emitted by the IBC v2 packet handlers, with the
+//! kinds and the attributes of the ones of ibc-go.
+use ibc_primitives::prelude::*;
+use ibc_proto::Protobuf;
+use subtle_encoding::hex;
+use tendermint::abci;
+
+use super::acknowledgement::Acknowledgement;
+use super::packet::Packet;
+
+const SEND_PACKET_EVENT: &str = "send_packet";
+const RECV_PACKET_EVENT: &str = "recv_packet";
+const WRITE_ACK_EVENT: &str = "write_acknowledgement";
+const ACK_PACKET_EVENT: &str = "acknowledge_packet";
+const TIMEOUT_EVENT: &str = "timeout_packet";
+
+const PKT_SRC_CLIENT_ATTRIBUTE_KEY: &str = "packet_source_client";
+const PKT_DST_CLIENT_ATTRIBUTE_KEY: &str = "packet_dest_client";
+const PKT_SEQ_ATTRIBUTE_KEY: &str = "packet_sequence";
+const PKT_TIMEOUT_TIMESTAMP_ATTRIBUTE_KEY: &str = "packet_timeout_timestamp";
+const PKT_ENCODED_HEX_ATTRIBUTE_KEY: &str = "encoded_packet_hex";
+const ACK_ENCODED_HEX_ATTRIBUTE_KEY: &str = "encoded_acknowledgement_hex";
+
+fn hex_string(bytes: Vec<u8>) -> String {
+    String::from_utf8(hex::encode(bytes)).expect("Never fails because hexadecimal is valid UTF8")
+}
+
+fn packet_attributes(packet: Packet) -> Vec<abci::EventAttribute> {
+    vec![
+        (PKT_SRC_CLIENT_ATTRIBUTE_KEY, packet.client_id_on_a.as_str()).into(),
+        (PKT_DST_CLIENT_ATTRIBUTE_KEY, packet.client_id_on_b.as_str()).into(),
+        (PKT_SEQ_ATTRIBUTE_KEY, packet.seq_on_a.to_string()).into(),
+        (
+            PKT_TIMEOUT_TIMESTAMP_ATTRIBUTE_KEY,
+            packet.timeout_timestamp_on_b.to_string(),
+        )
+            .into(),
+        (
+            PKT_ENCODED_HEX_ATTRIBUTE_KEY,
+            hex_string(packet.encode_vec()),
+        )
+            .into(),
+    ]
+}
+
+macro_rules! packet_event {
+    ($(#[$doc:meta])* $name:ident, $kind:ident) => {
+        $(#[$doc])*
+        #[cfg_attr(
+            feature = "parity-scale-codec",
+            derive(
+                parity_scale_codec::Encode,
+                parity_scale_codec::Decode,
+                scale_info::TypeInfo
+            )
+        )]
+        #[cfg_attr(
+            feature = "borsh",
+            derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+        )]
+        #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+        #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+        #[derive(Clone, Debug, PartialEq, Eq)]
+        pub struct $name {
+            packet: Packet,
+        }
+
+        impl $name {
+            pub fn new(packet: Packet) -> Self {
+                Self { packet }
+            }
+
+            pub fn packet(&self) -> &Packet {
+                &self.packet
+            }
+
+            pub fn event_type(&self) -> &str {
+                $kind
+            }
+        }
+
+        impl From<$name> for abci::Event {
+            fn from(e: $name) -> Self {
+                abci::Event {
+                    kind: $kind.to_string(),
+                    attributes: packet_attributes(e.packet),
+                }
+            }
+        }
+    };
+}
+
+packet_event!(
+    /// Emitted when an IBC v2 packet is sent.
+    SendPacket,
+    SEND_PACKET_EVENT
+);
+packet_event!(
+    /// Emitted when an IBC v2 packet is received.
+    ReceivePacket,
+    RECV_PACKET_EVENT
+);
+packet_event!(
+    /// Emitted when an IBC v2 packet is acknowledged.
+    AcknowledgePacket,
+    ACK_PACKET_EVENT
+);
+packet_event!(
+    /// Emitted when an IBC v2 packet times out.
+    TimeoutPacket,
+    TIMEOUT_EVENT
+);
+
+/// Emitted when the acknowledgement of a received IBC v2 packet is written.
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct WriteAcknowledgement {
+    packet: Packet,
+    acknowledgement: Acknowledgement,
+}
+
+impl WriteAcknowledgement {
+    pub fn new(packet: Packet, acknowledgement: Acknowledgement) -> Self {
+        Self {
+            packet,
+            acknowledgement,
+        }
+    }
+
+    pub fn packet(&self) -> &Packet {
+        &self.packet
+    }
+
+    pub fn acknowledgement(&self) -> &Acknowledgement {
+        &self.acknowledgement
+    }
+
+    pub fn event_type(&self) -> &str {
+        WRITE_ACK_EVENT
+    }
+}
+
+impl From<WriteAcknowledgement> for abci::Event {
+    fn from(e: WriteAcknowledgement) -> Self {
+        let mut attributes = packet_attributes(e.packet);
+        attributes.push(
+            (
+                ACK_ENCODED_HEX_ATTRIBUTE_KEY,
+                hex_string(e.acknowledgement.encode_vec()),
+            )
+                .into(),
+        );
+
+        abci::Event {
+            kind: WRITE_ACK_EVENT.to_string(),
+            attributes,
+        }
+    }
+}
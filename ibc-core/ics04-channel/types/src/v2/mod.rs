@@ -0,0 +1,14 @@
+//! Implementation of the IBC v2 packet data structures.
+//!
+//! IBC v2 packets are addressed by the clients of either chain rather than
+//! by channel ends, and carry one or more payloads, each routed to the
+//! application bound to its own port. They live alongside the classic
+//! packets, with their own commitment scheme and store paths.
+
+pub mod acknowledgement;
+pub mod commitment;
+pub mod counterparty;
+pub mod events;
+pub mod msgs;
+pub mod packet;
+pub mod proto;
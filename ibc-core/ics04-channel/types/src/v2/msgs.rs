@@ -0,0 +1,265 @@
+//! Message definitions of the IBC v2 packet datagrams.
+use core::str::FromStr;
+
+use ibc_core_client_types::Height;
+use ibc_core_commitment_types::commitment::CommitmentProofBytes;
+use ibc_core_host_types::identifiers::ClientId;
+use ibc_primitives::prelude::*;
+use ibc_primitives::Signer;
+use ibc_proto::Protobuf;
+
+use super::acknowledgement::Acknowledgement;
+use super::packet::{Packet, Payload};
+use super::proto::{
+    MsgAcknowledgement as RawMsgAcknowledgement, MsgRecvPacket as RawMsgRecvPacket,
+    MsgSendPacket as RawMsgSendPacket, MsgTimeout as RawMsgTimeout,
+};
+use crate::error::PacketError;
+
+pub const SEND_PACKET_V2_TYPE_URL: &str = "/ibc.core.channel.v2.MsgSendPacket";
+pub const RECV_PACKET_V2_TYPE_URL: &str = "/ibc.core.channel.v2.MsgRecvPacket";
+pub const ACKNOWLEDGEMENT_V2_TYPE_URL: &str = "/ibc.core.channel.v2.MsgAcknowledgement";
+pub const TIMEOUT_V2_TYPE_URL: &str = "/ibc.core.channel.v2.MsgTimeout";
+
+/// All IBC v2 packet messages
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Debug, PartialEq, Eq, derive_more::From)]
+pub enum PacketMsgV2 {
+    Send(MsgSendPacket),
+    Recv(MsgRecvPacket),
+    Ack(MsgAcknowledgement),
+    Timeout(MsgTimeout),
+}
+
+///
+/// Message definition for the "packet sending" datagram of IBC v2, by which
+/// the signer sends the payloads to the applications on the chain tracked by
+/// the given client.
+/// Per our convention, this message is sent to chain A.
+///
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MsgSendPacket {
+    /// The client on chain A which tracks chain B
+    pub client_id_on_a: ClientId,
+    /// The timeout timestamp of the packet, in seconds since the Unix epoch
+    pub timeout_timestamp_on_b: u64,
+    /// The payloads, each delivered to the application bound to its port
+    pub payloads: Vec<Payload>,
+    /// The signer of the message
+    pub signer: Signer,
+}
+
+impl Protobuf<RawMsgSendPacket> for MsgSendPacket {}
+
+impl TryFrom<RawMsgSendPacket> for MsgSendPacket {
+    type Error = PacketError;
+
+    fn try_from(raw_msg: RawMsgSendPacket) -> Result<Self, Self::Error> {
+        if raw_msg.timeout_timestamp == 0 {
+            return Err(PacketError::MissingTimeout);
+        }
+
+        if raw_msg.payloads.is_empty() {
+            return Err(PacketError::MissingPayloads);
+        }
+
+        Ok(MsgSendPacket {
+            client_id_on_a: ClientId::from_str(&raw_msg.source_client)?,
+            timeout_timestamp_on_b: raw_msg.timeout_timestamp,
+            payloads: raw_msg
+                .payloads
+                .into_iter()
+                .map(Payload::try_from)
+                .collect::<Result<_, _>>()?,
+            signer: raw_msg.signer.into(),
+        })
+    }
+}
+
+impl From<MsgSendPacket> for RawMsgSendPacket {
+    fn from(domain_msg: MsgSendPacket) -> Self {
+        RawMsgSendPacket {
+            source_client: domain_msg.client_id_on_a.to_string(),
+            timeout_timestamp: domain_msg.timeout_timestamp_on_b,
+            payloads: domain_msg.payloads.into_iter().map(Into::into).collect(),
+            signer: domain_msg.signer.to_string(),
+        }
+    }
+}
+
+///
+/// Message definition for the "packet receiving" datagram of IBC v2.
+///
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MsgRecvPacket {
+    /// The packet to be received
+    pub packet: Packet,
+    /// Proof of packet commitment on the sending chain
+    pub proof_commitment_on_a: CommitmentProofBytes,
+    /// Height at which the commitment proof in this message were taken
+    pub proof_height_on_a: Height,
+    /// The signer of the message
+    pub signer: Signer,
+}
+
+impl Protobuf<RawMsgRecvPacket> for MsgRecvPacket {}
+
+impl TryFrom<RawMsgRecvPacket> for MsgRecvPacket {
+    type Error = PacketError;
+
+    fn try_from(raw_msg: RawMsgRecvPacket) -> Result<Self, Self::Error> {
+        Ok(MsgRecvPacket {
+            packet: raw_msg
+                .packet
+                .ok_or(PacketError::MissingPacket)?
+                .try_into()?,
+            proof_commitment_on_a: raw_msg
+                .proof_commitment
+                .try_into()
+                .map_err(|_| PacketError::InvalidProof)?,
+            proof_height_on_a: raw_msg
+                .proof_height
+                .and_then(|raw_height| raw_height.try_into().ok())
+                .ok_or(PacketError::MissingHeight)?,
+            signer: raw_msg.signer.into(),
+        })
+    }
+}
+
+impl From<MsgRecvPacket> for RawMsgRecvPacket {
+    fn from(domain_msg: MsgRecvPacket) -> Self {
+        RawMsgRecvPacket {
+            packet: Some(domain_msg.packet.into()),
+            proof_commitment: domain_msg.proof_commitment_on_a.into(),
+            proof_height: Some(domain_msg.proof_height_on_a.into()),
+            signer: domain_msg.signer.to_string(),
+        }
+    }
+}
+
+///
+/// Message definition for the "packet acknowledgement" datagram of IBC v2.
+///
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MsgAcknowledgement {
+    pub packet: Packet,
+    pub acknowledgement: Acknowledgement,
+    /// Proof of the acknowledgement on the receiving chain
+    pub proof_acked_on_b: CommitmentProofBytes,
+    /// Height at which the acknowledgement proof in this message were taken
+    pub proof_height_on_b: Height,
+    pub signer: Signer,
+}
+
+impl Protobuf<RawMsgAcknowledgement> for MsgAcknowledgement {}
+
+impl TryFrom<RawMsgAcknowledgement> for MsgAcknowledgement {
+    type Error = PacketError;
+
+    fn try_from(raw_msg: RawMsgAcknowledgement) -> Result<Self, Self::Error> {
+        Ok(MsgAcknowledgement {
+            packet: raw_msg
+                .packet
+                .ok_or(PacketError::MissingPacket)?
+                .try_into()?,
+            acknowledgement: raw_msg
+                .acknowledgement
+                .ok_or(PacketError::InvalidAcknowledgement)?
+                .try_into()?,
+            proof_acked_on_b: raw_msg
+                .proof_acked
+                .try_into()
+                .map_err(|_| PacketError::InvalidProof)?,
+            proof_height_on_b: raw_msg
+                .proof_height
+                .and_then(|raw_height| raw_height.try_into().ok())
+                .ok_or(PacketError::MissingHeight)?,
+            signer: raw_msg.signer.into(),
+        })
+    }
+}
+
+impl From<MsgAcknowledgement> for RawMsgAcknowledgement {
+    fn from(domain_msg: MsgAcknowledgement) -> Self {
+        RawMsgAcknowledgement {
+            packet: Some(domain_msg.packet.into()),
+            acknowledgement: Some(domain_msg.acknowledgement.into()),
+            proof_acked: domain_msg.proof_acked_on_b.into(),
+            proof_height: Some(domain_msg.proof_height_on_b.into()),
+            signer: domain_msg.signer.to_string(),
+        }
+    }
+}
+
+///
+/// Message definition for the "packet timeout" datagram of IBC v2.
+///
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MsgTimeout {
+    pub packet: Packet,
+    /// Proof of the absence of the receipt of the packet on the receiving
+    /// chain
+    pub proof_unreceived_on_b: CommitmentProofBytes,
+    /// Height at which the absence proof in this message were taken
+    pub proof_height_on_b: Height,
+    pub signer: Signer,
+}
+
+impl Protobuf<RawMsgTimeout> for MsgTimeout {}
+
+impl TryFrom<RawMsgTimeout> for MsgTimeout {
+    type Error = PacketError;
+
+    fn try_from(raw_msg: RawMsgTimeout) -> Result<Self, Self::Error> {
+        Ok(MsgTimeout {
+            packet: raw_msg
+                .packet
+                .ok_or(PacketError::MissingPacket)?
+                .try_into()?,
+            proof_unreceived_on_b: raw_msg
+                .proof_unreceived
+                .try_into()
+                .map_err(|_| PacketError::InvalidProof)?,
+            proof_height_on_b: raw_msg
+                .proof_height
+                .and_then(|raw_height| raw_height.try_into().ok())
+                .ok_or(PacketError::MissingHeight)?,
+            signer: raw_msg.signer.into(),
+        })
+    }
+}
+
+impl From<MsgTimeout> for RawMsgTimeout {
+    fn from(domain_msg: MsgTimeout) -> Self {
+        RawMsgTimeout {
+            packet: Some(domain_msg.packet.into()),
+            proof_unreceived: domain_msg.proof_unreceived_on_b.into(),
+            proof_height: Some(domain_msg.proof_height_on_b.into()),
+            signer: domain_msg.signer.to_string(),
+        }
+    }
+}
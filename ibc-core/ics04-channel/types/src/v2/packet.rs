@@ -0,0 +1,197 @@
+//! Defines the IBC v2 packet type, which is addressed by client rather than
+//! by channel, and carries one or more application payloads.
+use core::str::FromStr;
+
+use ibc_core_host_types::identifiers::{ClientId, PortId, Sequence};
+use ibc_primitives::prelude::*;
+use ibc_primitives::Timestamp;
+use ibc_proto::Protobuf;
+
+use super::proto::{Packet as RawPacket, Payload as RawPayload};
+use crate::error::PacketError;
+
+/// The maximum time, in seconds, the timeout of an IBC v2 packet may be set
+/// ahead of the timestamp of the host it is sent from, as in ibc-go.
+pub const MAX_TIMEOUT_DELTA: u64 = 24 * 60 * 60;
+
+/// The data an application sends to its counterparty application within an
+/// IBC v2 packet.
+///
+/// The ports identify the applications on either end, and are used to route
+/// the payload to them.
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+pub struct Payload {
+    pub port_id_on_a: PortId,
+    pub port_id_on_b: PortId,
+    /// The version of the application the payload is encoded for.
+    pub version: String,
+    /// The encoding of the value, such as `application/json`.
+    pub encoding: String,
+    #[cfg_attr(
+        feature = "serde",
+        serde(serialize_with = "ibc_core_commitment_types::serializer::ser_hex_upper")
+    )]
+    pub value: Vec<u8>,
+}
+
+impl TryFrom<RawPayload> for Payload {
+    type Error = PacketError;
+
+    fn try_from(raw_payload: RawPayload) -> Result<Self, Self::Error> {
+        if raw_payload.version.trim().is_empty() {
+            return Err(PacketError::InvalidPayload {
+                reason: "the version cannot be empty".to_string(),
+            });
+        }
+
+        if raw_payload.encoding.trim().is_empty() {
+            return Err(PacketError::InvalidPayload {
+                reason: "the encoding cannot be empty".to_string(),
+            });
+        }
+
+        if raw_payload.value.is_empty() {
+            return Err(PacketError::InvalidPayload {
+                reason: "the value cannot be empty".to_string(),
+            });
+        }
+
+        Ok(Payload {
+            port_id_on_a: PortId::from_str(&raw_payload.source_port)?,
+            port_id_on_b: PortId::from_str(&raw_payload.destination_port)?,
+            version: raw_payload.version,
+            encoding: raw_payload.encoding,
+            value: raw_payload.value,
+        })
+    }
+}
+
+impl From<Payload> for RawPayload {
+    fn from(payload: Payload) -> Self {
+        RawPayload {
+            source_port: payload.port_id_on_a.to_string(),
+            destination_port: payload.port_id_on_b.to_string(),
+            version: payload.version,
+            encoding: payload.encoding,
+            value: payload.value,
+        }
+    }
+}
+
+/// The IBC v2 packet type.
+///
+/// Packets are sent from the chain A tracked by the client `client_id_on_b`
+/// on chain B, to the chain B tracked by the client `client_id_on_a` on
+/// chain A. Each payload is delivered to the application bound to its own
+/// port.
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+pub struct Packet {
+    pub seq_on_a: Sequence,
+    /// The client on chain A which tracks chain B.
+    pub client_id_on_a: ClientId,
+    /// The client on chain B which tracks chain A.
+    pub client_id_on_b: ClientId,
+    /// The timeout timestamp of the packet, in seconds since the Unix epoch.
+    pub timeout_timestamp_on_b: u64,
+    pub payloads: Vec<Payload>,
+}
+
+impl Packet {
+    /// Checks whether the packet is timed out relative to the timestamp
+    /// `dst_chain_ts` of the destination chain.
+    pub fn timed_out(&self, dst_chain_ts: &Timestamp) -> bool {
+        timestamp_seconds(dst_chain_ts) >= self.timeout_timestamp_on_b
+    }
+}
+
+/// Returns the given timestamp in seconds since the Unix epoch, the precision
+/// the timeouts of IBC v2 packets are set with.
+pub fn timestamp_seconds(timestamp: &Timestamp) -> u64 {
+    timestamp.seconds()
+}
+
+impl core::fmt::Display for Packet {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> Result<(), core::fmt::Error> {
+        write!(
+            f,
+            "seq:{}, path:{}->{}, payloads:{}, tos:{}",
+            self.seq_on_a,
+            self.client_id_on_a,
+            self.client_id_on_b,
+            self.payloads.len(),
+            self.timeout_timestamp_on_b
+        )
+    }
+}
+
+impl Protobuf<RawPacket> for Packet {}
+
+impl TryFrom<RawPacket> for Packet {
+    type Error = PacketError;
+
+    fn try_from(raw_pkt: RawPacket) -> Result<Self, Self::Error> {
+        if Sequence::from(raw_pkt.sequence).is_zero() {
+            return Err(PacketError::ZeroPacketSequence);
+        }
+
+        if raw_pkt.timeout_timestamp == 0 {
+            return Err(PacketError::MissingTimeout);
+        }
+
+        if raw_pkt.payloads.is_empty() {
+            return Err(PacketError::MissingPayloads);
+        }
+
+        Ok(Packet {
+            seq_on_a: Sequence::from(raw_pkt.sequence),
+            client_id_on_a: ClientId::from_str(&raw_pkt.source_client)?,
+            client_id_on_b: ClientId::from_str(&raw_pkt.destination_client)?,
+            timeout_timestamp_on_b: raw_pkt.timeout_timestamp,
+            payloads: raw_pkt
+                .payloads
+                .into_iter()
+                .map(Payload::try_from)
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+impl From<Packet> for RawPacket {
+    fn from(packet: Packet) -> Self {
+        RawPacket {
+            sequence: packet.seq_on_a.value(),
+            source_client: packet.client_id_on_a.to_string(),
+            destination_client: packet.client_id_on_b.to_string(),
+            timeout_timestamp: packet.timeout_timestamp_on_b,
+            payloads: packet.payloads.into_iter().map(Into::into).collect(),
+        }
+    }
+}
@@ -0,0 +1,176 @@
+//! Raw (protobuf) representations of the IBC v2 packet types and messages.
+//!
+//! There is no upstream `ibc-proto` definition for these messages yet, so
+//! they are defined here under the `ibc.core.channel.v2` package, following
+//! the definitions of `ibc-go`.
+
+use ibc_primitives::prelude::*;
+use ibc_proto::ibc::core::client::v1::Height as RawHeight;
+
+macro_rules! impl_name {
+    ($ty:ty, $name:literal) => {
+        impl prost::Name for $ty {
+            const NAME: &'static str = $name;
+            const PACKAGE: &'static str = "ibc.core.channel.v2";
+
+            fn full_name() -> String {
+                format!("{}.{}", Self::PACKAGE, Self::NAME)
+            }
+        }
+    };
+}
+
+/// ```protobuf
+/// message Payload {
+///   string source_port = 1;
+///   string destination_port = 2;
+///   string version = 3;
+///   string encoding = 4;
+///   bytes value = 5;
+/// }
+/// ```
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct Payload {
+    #[prost(string, tag = "1")]
+    pub source_port: String,
+    #[prost(string, tag = "2")]
+    pub destination_port: String,
+    #[prost(string, tag = "3")]
+    pub version: String,
+    #[prost(string, tag = "4")]
+    pub encoding: String,
+    #[prost(bytes = "vec", tag = "5")]
+    pub value: Vec<u8>,
+}
+
+impl_name!(Payload, "Payload");
+
+/// ```protobuf
+/// message Packet {
+///   uint64 sequence = 1;
+///   string source_client = 2;
+///   string destination_client = 3;
+///   uint64 timeout_timestamp = 4;
+///   repeated Payload payloads = 5;
+/// }
+/// ```
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct Packet {
+    #[prost(uint64, tag = "1")]
+    pub sequence: u64,
+    #[prost(string, tag = "2")]
+    pub source_client: String,
+    #[prost(string, tag = "3")]
+    pub destination_client: String,
+    /// The timeout timestamp of the packet, in seconds since the Unix epoch.
+    #[prost(uint64, tag = "4")]
+    pub timeout_timestamp: u64,
+    #[prost(message, repeated, tag = "5")]
+    pub payloads: Vec<Payload>,
+}
+
+impl_name!(Packet, "Packet");
+
+/// ```protobuf
+/// message Acknowledgement {
+///   repeated bytes app_acknowledgements = 1;
+/// }
+/// ```
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct Acknowledgement {
+    #[prost(bytes = "vec", repeated, tag = "1")]
+    pub app_acknowledgements: Vec<Vec<u8>>,
+}
+
+impl_name!(Acknowledgement, "Acknowledgement");
+
+/// ```protobuf
+/// message MsgSendPacket {
+///   string source_client = 1;
+///   uint64 timeout_timestamp = 2;
+///   repeated Payload payloads = 3;
+///   string signer = 4;
+/// }
+/// ```
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct MsgSendPacket {
+    #[prost(string, tag = "1")]
+    pub source_client: String,
+    #[prost(uint64, tag = "2")]
+    pub timeout_timestamp: u64,
+    #[prost(message, repeated, tag = "3")]
+    pub payloads: Vec<Payload>,
+    #[prost(string, tag = "4")]
+    pub signer: String,
+}
+
+impl_name!(MsgSendPacket, "MsgSendPacket");
+
+/// ```protobuf
+/// message MsgRecvPacket {
+///   Packet packet = 1;
+///   bytes proof_commitment = 2;
+///   ibc.core.client.v1.Height proof_height = 3;
+///   string signer = 4;
+/// }
+/// ```
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct MsgRecvPacket {
+    #[prost(message, optional, tag = "1")]
+    pub packet: Option<Packet>,
+    #[prost(bytes = "vec", tag = "2")]
+    pub proof_commitment: Vec<u8>,
+    #[prost(message, optional, tag = "3")]
+    pub proof_height: Option<RawHeight>,
+    #[prost(string, tag = "4")]
+    pub signer: String,
+}
+
+impl_name!(MsgRecvPacket, "MsgRecvPacket");
+
+/// ```protobuf
+/// message MsgAcknowledgement {
+///   Packet packet = 1;
+///   Acknowledgement acknowledgement = 2;
+///   bytes proof_acked = 3;
+///   ibc.core.client.v1.Height proof_height = 4;
+///   string signer = 5;
+/// }
+/// ```
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct MsgAcknowledgement {
+    #[prost(message, optional, tag = "1")]
+    pub packet: Option<Packet>,
+    #[prost(message, optional, tag = "2")]
+    pub acknowledgement: Option<Acknowledgement>,
+    #[prost(bytes = "vec", tag = "3")]
+    pub proof_acked: Vec<u8>,
+    #[prost(message, optional, tag = "4")]
+    pub proof_height: Option<RawHeight>,
+    #[prost(string, tag = "5")]
+    pub signer: String,
+}
+
+impl_name!(MsgAcknowledgement, "MsgAcknowledgement");
+
+/// ```protobuf
+/// message MsgTimeout {
+///   Packet packet = 1;
+///   bytes proof_unreceived = 2;
+///   ibc.core.client.v1.Height proof_height = 3;
+///   string signer = 5;
+/// }
+/// ```
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct MsgTimeout {
+    #[prost(message, optional, tag = "1")]
+    pub packet: Option<Packet>,
+    #[prost(bytes = "vec", tag = "2")]
+    pub proof_unreceived: Vec<u8>,
+    #[prost(message, optional, tag = "3")]
+    pub proof_height: Option<RawHeight>,
+    #[prost(string, tag = "5")]
+    pub signer: String,
+}
+
+impl_name!(MsgTimeout, "MsgTimeout");
@@ -12,9 +12,21 @@ use super::merkle::MerkleProof;
 use crate::error::CommitmentError;
 
 /// Encodes a commitment root; most often a Merkle tree root hash.
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "serde", serde(transparent))]
-#[derive(Clone, PartialEq, Eq)]
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord)]
 pub struct CommitmentRoot {
     #[cfg_attr(
         feature = "serde",
@@ -63,6 +75,14 @@ impl From<Vec<u8>> for CommitmentRoot {
 ///
 /// For example, in the case of a proof of membership in a Merkle tree,
 /// this encodes a Merkle proof.
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
 #[cfg_attr(
     feature = "borsh",
     derive(borsh::BorshSerialize, borsh::BorshDeserialize)
@@ -70,7 +90,7 @@ impl From<Vec<u8>> for CommitmentRoot {
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "serde", serde(transparent))]
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
-#[derive(Clone, PartialEq, Eq, derive_more::AsRef, derive_more::Into)]
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, derive_more::AsRef, derive_more::Into)]
 #[as_ref(forward)]
 pub struct CommitmentProofBytes {
     #[cfg_attr(
@@ -143,7 +163,7 @@ impl<'a> TryFrom<&'a CommitmentProofBytes> for MerkleProof {
 )]
 #[cfg_attr(feature = "serde", derive(serde::Deserialize))]
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
-#[derive(Clone, PartialEq, Eq, Hash)]
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct CommitmentPrefix {
     bytes: Vec<u8>,
 }
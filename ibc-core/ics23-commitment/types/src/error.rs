@@ -31,5 +31,4 @@ pub enum CommitmentError {
     DecodingFailure(String),
 }
 
-#[cfg(feature = "std")]
-impl std::error::Error for CommitmentError {}
+impl core::error::Error for CommitmentError {}
@@ -52,6 +52,39 @@ impl From<MerkleProof> for RawMerkleProof {
     }
 }
 
+/// The length of the longest hashes of ics23, hence of the roots computed
+/// while verifying a proof.
+const MAX_ROOT_LEN: usize = 64;
+
+/// Lets the hosts whose allocator is costly, e.g. a bump allocator which
+/// never frees, release the temporary buffers allocated while verifying a
+/// proof, by running each step of the verification in an arena.
+///
+/// The roots computed by a step, which the next one proves, are copied into
+/// buffers allocated before the steps run, hence may outlive the arena.
+pub trait VerificationArena {
+    /// Runs the given step of the verification, after which the buffers it
+    /// allocated may all be released, as it returns none of them.
+    fn scoped(
+        &mut self,
+        step: impl FnOnce() -> Result<(), CommitmentError>,
+    ) -> Result<(), CommitmentError>;
+}
+
+/// Runs the steps of the verification on the global allocator, which frees
+/// their buffers as they are dropped.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct NoArena;
+
+impl VerificationArena for NoArena {
+    fn scoped(
+        &mut self,
+        step: impl FnOnce() -> Result<(), CommitmentError>,
+    ) -> Result<(), CommitmentError> {
+        step()
+    }
+}
+
 impl MerkleProof {
     pub fn verify_membership(
         &self,
@@ -60,6 +93,20 @@ impl MerkleProof {
         keys: MerklePath,
         value: Vec<u8>,
         start_index: u64,
+    ) -> Result<(), CommitmentError> {
+        self.verify_membership_in(&mut NoArena, specs, root, keys, value, start_index)
+    }
+
+    /// Verifies the membership of the given value, running the verification
+    /// of each proof in the given arena.
+    pub fn verify_membership_in(
+        &self,
+        arena: &mut impl VerificationArena,
+        specs: &ProofSpecs,
+        root: MerkleRoot,
+        keys: MerklePath,
+        value: Vec<u8>,
+        start_index: u64,
     ) -> Result<(), CommitmentError> {
         // validate arguments
         if self.proofs.is_empty() {
@@ -69,7 +116,7 @@ impl MerkleProof {
             return Err(CommitmentError::EmptyMerkleRoot);
         }
         let num = self.proofs.len();
-        let ics23_specs = Vec::<ics23::ProofSpec>::from(specs.clone());
+        let ics23_specs = specs.raw_specs();
         if ics23_specs.len() != num {
             return Err(CommitmentError::NumberOfSpecsMismatch);
         }
@@ -80,13 +127,15 @@ impl MerkleProof {
             return Err(CommitmentError::EmptyVerifiedValue);
         }
 
-        let mut subroot = value.clone();
+        // The root computed at each level is the value proven at the next
+        // one, hence the two buffers are swapped rather than copied.
         let mut value = value;
+        let mut subroot = Vec::with_capacity(MAX_ROOT_LEN);
         // keys are represented from root-to-leaf
         for ((proof, spec), key) in self
             .proofs
             .iter()
-            .zip(ics23_specs.iter())
+            .zip(ics23_specs)
             .zip(keys.key_path.iter().rev())
             .skip(
                 start_index
@@ -96,26 +145,36 @@ impl MerkleProof {
         {
             match &proof.proof {
                 Some(Proof::Exist(existence_proof)) => {
-                    subroot =
-                        calculate_existence_root::<ics23::HostFunctionsManager>(existence_proof)
+                    arena.scoped(|| {
+                        let computed_root =
+                            calculate_existence_root::<ics23::HostFunctionsManager>(
+                                existence_proof,
+                            )
                             .map_err(|_| CommitmentError::InvalidMerkleProof)?;
 
-                    if !verify_membership::<ics23::HostFunctionsManager>(
-                        proof,
-                        spec,
-                        &subroot,
-                        key.as_bytes(),
-                        &value,
-                    ) {
-                        return Err(CommitmentError::VerificationFailure);
-                    }
-                    value.clone_from(&subroot);
+                        if !verify_membership::<ics23::HostFunctionsManager>(
+                            proof,
+                            spec,
+                            &computed_root,
+                            key.as_bytes(),
+                            &value,
+                        ) {
+                            return Err(CommitmentError::VerificationFailure);
+                        }
+
+                        copy_root(&computed_root, &mut subroot)
+                    })?;
+
+                    core::mem::swap(&mut value, &mut subroot);
+                    // The buffer of the value proven at the first level may
+                    // not fit the root of the next one.
+                    subroot.reserve(MAX_ROOT_LEN);
                 }
                 _ => return Err(CommitmentError::InvalidMerkleProof),
             }
         }
 
-        if root.hash != subroot {
+        if root.hash != value {
             return Err(CommitmentError::VerificationFailure);
         }
 
@@ -127,6 +186,18 @@ impl MerkleProof {
         specs: &ProofSpecs,
         root: MerkleRoot,
         keys: MerklePath,
+    ) -> Result<(), CommitmentError> {
+        self.verify_non_membership_in(&mut NoArena, specs, root, keys)
+    }
+
+    /// Verifies the absence of the given key, running the verification of
+    /// each proof in the given arena.
+    pub fn verify_non_membership_in(
+        &self,
+        arena: &mut impl VerificationArena,
+        specs: &ProofSpecs,
+        root: MerkleRoot,
+        keys: MerklePath,
     ) -> Result<(), CommitmentError> {
         // validate arguments
         if self.proofs.is_empty() {
@@ -136,7 +207,7 @@ impl MerkleProof {
             return Err(CommitmentError::EmptyMerkleRoot);
         }
         let num = self.proofs.len();
-        let ics23_specs = Vec::<ics23::ProofSpec>::from(specs.clone());
+        let mut ics23_specs = specs.raw_specs();
         if ics23_specs.len() != num {
             return Err(CommitmentError::NumberOfSpecsMismatch);
         }
@@ -150,7 +221,7 @@ impl MerkleProof {
             .first()
             .ok_or(CommitmentError::InvalidMerkleProof)?;
         let spec = ics23_specs
-            .first()
+            .next()
             .ok_or(CommitmentError::InvalidMerkleProof)?;
         // keys are represented from root-to-leaf
         let key = keys
@@ -159,25 +230,45 @@ impl MerkleProof {
             .ok_or(CommitmentError::InvalidMerkleProof)?;
         match &proof.proof {
             Some(Proof::Nonexist(non_existence_proof)) => {
-                let subroot = calculate_non_existence_root(non_existence_proof)?;
-
-                if !verify_non_membership::<ics23::HostFunctionsManager>(
-                    proof,
-                    spec,
-                    &subroot,
-                    key.as_bytes(),
-                ) {
-                    return Err(CommitmentError::VerificationFailure);
-                }
+                let mut subroot = Vec::with_capacity(MAX_ROOT_LEN);
+
+                arena.scoped(|| {
+                    let computed_root = calculate_non_existence_root(non_existence_proof)?;
+
+                    if !verify_non_membership::<ics23::HostFunctionsManager>(
+                        proof,
+                        spec,
+                        &computed_root,
+                        key.as_bytes(),
+                    ) {
+                        return Err(CommitmentError::VerificationFailure);
+                    }
+
+                    copy_root(&computed_root, &mut subroot)
+                })?;
 
                 // verify membership proofs starting from index 1 with value = subroot
-                self.verify_membership(specs, root, keys, subroot, 1)
+                self.verify_membership_in(arena, specs, root, keys, subroot, 1)
             }
             _ => Err(CommitmentError::InvalidMerkleProof),
         }
     }
 }
 
+/// Copies the root computed by a step of the verification into the given
+/// buffer, without growing it, as the step may run in an arena which does not
+/// outlive it.
+fn copy_root(computed_root: &[u8], root: &mut Vec<u8>) -> Result<(), CommitmentError> {
+    if computed_root.len() > root.capacity() {
+        return Err(CommitmentError::InvalidMerkleProof);
+    }
+
+    root.clear();
+    root.extend_from_slice(computed_root);
+
+    Ok(())
+}
+
 // TODO move to ics23
 fn calculate_non_existence_root(proof: &NonExistenceProof) -> Result<Vec<u8>, CommitmentError> {
     if let Some(left) = &proof.left {
@@ -26,6 +26,12 @@ impl ProofSpecs {
         self.0.is_empty()
     }
 
+    /// Returns the ics23 specifications, borrowed rather than converted, so
+    /// that verifying a proof does not copy them.
+    pub(crate) fn raw_specs(&self) -> impl ExactSizeIterator<Item = &RawProofSpec> {
+        self.0.iter().map(|spec| &spec.0)
+    }
+
     pub fn validate(&self) -> Result<(), CommitmentError> {
         if self.is_empty() {
             return Err(CommitmentError::EmptyProofSpecs);
@@ -15,7 +15,7 @@ pub mod upgrade_proposal;
 pub mod utils;
 
 mod validate_self_client;
-pub use validate_self_client::ValidateSelfClientContext;
+pub use validate_self_client::{TendermintSelfClientValidator, ValidateSelfClientContext};
 
 /// Re-exports necessary proto types for implementing the tendermint client
 /// upgradeability feature.
@@ -24,6 +24,7 @@ const UPGRADE_PLAN_TITLE_ATTRIBUTE_KEY: &str = "title";
     derive(borsh::BorshSerialize, borsh::BorshDeserialize)
 )]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[derive(Clone, Debug, From, PartialEq, Eq)]
 struct UpgradeStoreAttribute {
     upgrade_store: String,
@@ -48,6 +49,7 @@ impl From<UpgradeStoreAttribute> for abci::EventAttribute {
     derive(borsh::BorshSerialize, borsh::BorshDeserialize)
 )]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[derive(Clone, Debug, From, PartialEq, Eq)]
 struct UpgradePlanHeightAttribute {
     plan_height: u64,
@@ -76,6 +78,7 @@ impl From<UpgradePlanHeightAttribute> for abci::EventAttribute {
     derive(borsh::BorshSerialize, borsh::BorshDeserialize)
 )]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[derive(Clone, Debug, From, PartialEq, Eq)]
 struct UpgradePlanTitleAttribute {
     title: String,
@@ -101,6 +104,7 @@ impl From<UpgradePlanTitleAttribute> for abci::EventAttribute {
     derive(borsh::BorshSerialize, borsh::BorshDeserialize)
 )]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct UpgradeChain {
     // The height at which the upgrade performed.
@@ -144,6 +148,7 @@ impl From<UpgradeChain> for abci::Event {
     derive(borsh::BorshSerialize, borsh::BorshDeserialize)
 )]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct UpgradeClientProposal {
     // The title of the upgrade plan
@@ -1,11 +1,15 @@
 use ibc_client_tendermint::types::ClientState as TmClientState;
 use ibc_core_client_types::error::UpgradeClientError;
+use ibc_core_host::Authority;
 use ibc_core_host_types::path::UpgradeClientPath;
 use ibc_primitives::prelude::*;
+use ibc_proto::google::protobuf::Any;
 use tendermint::abci::Event as TmEvent;
 
 use super::UpgradedClientStateRef;
-use crate::upgrade_proposal::{UpgradeClientProposal, UpgradeExecutionContext, UpgradeProposal};
+use crate::upgrade_proposal::{
+    MsgIbcSoftwareUpgrade, Plan, UpgradeClientProposal, UpgradeExecutionContext, UpgradeProposal,
+};
 
 /// Handles an upgrade client proposal
 ///
@@ -20,18 +24,60 @@ where
     Ctx: UpgradeExecutionContext,
     UpgradedClientStateRef<Ctx>: From<TmClientState>,
 {
-    let plan = proposal.plan;
+    let plan_height = proposal.plan.height;
 
+    schedule_upgrade(ctx, proposal.plan, proposal.upgraded_client_state)?;
+
+    let event = TmEvent::from(UpgradeClientProposal::new(proposal.title, plan_height));
+
+    Ok(event)
+}
+
+/// Handles an IBC software upgrade message, submitted by the governance of the
+/// host, which must be signed by its authority.
+///
+/// It schedules the upgrade as [`upgrade_client_proposal_handler`] does, with
+/// the name of the plan standing for the title of the proposal in the event.
+pub fn ibc_software_upgrade_handler<Ctx>(
+    ctx: &mut Ctx,
+    msg: MsgIbcSoftwareUpgrade,
+) -> Result<TmEvent, UpgradeClientError>
+where
+    Ctx: UpgradeExecutionContext + Authority,
+    UpgradedClientStateRef<Ctx>: From<TmClientState>,
+{
+    if !ctx.is_authorized(&msg.signer) {
+        return Err(UpgradeClientError::Unauthorized { signer: msg.signer });
+    }
+
+    let event = TmEvent::from(UpgradeClientProposal::new(
+        msg.plan.name.clone(),
+        msg.plan.height,
+    ));
+
+    schedule_upgrade(ctx, msg.plan, msg.upgraded_client_state)?;
+
+    Ok(event)
+}
+
+fn schedule_upgrade<Ctx>(
+    ctx: &mut Ctx,
+    plan: Plan,
+    upgraded_client_state: Any,
+) -> Result<(), UpgradeClientError>
+where
+    Ctx: UpgradeExecutionContext,
+    UpgradedClientStateRef<Ctx>: From<TmClientState>,
+{
     if ctx.upgrade_plan().is_ok() {
         ctx.clear_upgrade_plan(plan.height)?;
     }
 
-    let mut client_state =
-        TmClientState::try_from(proposal.upgraded_client_state).map_err(|e| {
-            UpgradeClientError::InvalidUpgradeProposal {
-                reason: e.to_string(),
-            }
-        })?;
+    let mut client_state = TmClientState::try_from(upgraded_client_state).map_err(|e| {
+        UpgradeClientError::InvalidUpgradeProposal {
+            reason: e.to_string(),
+        }
+    })?;
 
     client_state.zero_custom_fields();
 
@@ -41,7 +87,5 @@ where
 
     ctx.store_upgraded_client_state(upgraded_client_state_path, client_state.into())?;
 
-    let event = TmEvent::from(UpgradeClientProposal::new(proposal.title, plan.height));
-
-    Ok(event)
+    Ok(())
 }
@@ -3,11 +3,13 @@
 mod context;
 mod events;
 mod handler;
+mod msg;
 mod plan;
 mod proposal;
 
 pub use context::*;
 pub use events::{UpgradeChain, UpgradeClientProposal};
-pub use handler::upgrade_client_proposal_handler;
+pub use handler::{ibc_software_upgrade_handler, upgrade_client_proposal_handler};
+pub use msg::*;
 pub use plan::Plan;
 pub use proposal::UpgradeProposal;
@@ -0,0 +1,69 @@
+//! Definition of domain type message `MsgIbcSoftwareUpgrade`.
+
+use ibc_core_client_types::error::UpgradeClientError;
+use ibc_primitives::prelude::*;
+use ibc_primitives::Signer;
+use ibc_proto::google::protobuf::Any;
+use ibc_proto::ibc::core::client::v1::MsgIbcSoftwareUpgrade as RawMsgIbcSoftwareUpgrade;
+use ibc_proto::Protobuf;
+
+use super::Plan;
+
+pub const IBC_SOFTWARE_UPGRADE_TYPE_URL: &str = "/ibc.core.client.v1.MsgIBCSoftwareUpgrade";
+
+/// A type of message, submitted by the governance of the host, that schedules
+/// an IBC breaking upgrade along with the client state to be used after it.
+#[cfg_attr(
+    feature = "proto-json",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(
+        try_from = "RawMsgIbcSoftwareUpgrade",
+        into = "RawMsgIbcSoftwareUpgrade"
+    )
+)]
+#[derive(Clone, Debug)]
+pub struct MsgIbcSoftwareUpgrade {
+    // The upgrade plan
+    pub plan: Plan,
+    // The upgraded client state
+    pub upgraded_client_state: Any,
+    // The authority of the host
+    pub signer: Signer,
+}
+
+impl Protobuf<RawMsgIbcSoftwareUpgrade> for MsgIbcSoftwareUpgrade {}
+
+impl TryFrom<RawMsgIbcSoftwareUpgrade> for MsgIbcSoftwareUpgrade {
+    type Error = UpgradeClientError;
+
+    fn try_from(raw: RawMsgIbcSoftwareUpgrade) -> Result<Self, Self::Error> {
+        let plan = raw
+            .plan
+            .ok_or_else(|| UpgradeClientError::InvalidUpgradeProposal {
+                reason: "plan field cannot be empty".to_string(),
+            })?
+            .try_into()?;
+
+        let upgraded_client_state = raw.upgraded_client_state.ok_or_else(|| {
+            UpgradeClientError::InvalidUpgradeProposal {
+                reason: "upgraded client state cannot be empty".to_string(),
+            }
+        })?;
+
+        Ok(Self {
+            plan,
+            upgraded_client_state,
+            signer: raw.signer.into(),
+        })
+    }
+}
+
+impl From<MsgIbcSoftwareUpgrade> for RawMsgIbcSoftwareUpgrade {
+    fn from(value: MsgIbcSoftwareUpgrade) -> Self {
+        Self {
+            plan: Some(value.plan.into()),
+            upgraded_client_state: Some(value.upgraded_client_state),
+            signer: value.signer.to_string(),
+        }
+    }
+}
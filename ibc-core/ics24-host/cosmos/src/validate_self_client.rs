@@ -6,6 +6,7 @@ use ibc_core_client_types::Height;
 use ibc_core_commitment_types::specs::ProofSpecs;
 use ibc_core_connection_types::error::ConnectionError;
 use ibc_core_handler_types::error::ContextError;
+use ibc_core_host::SelfClientValidation;
 use ibc_core_host_types::identifiers::ChainId;
 use ibc_primitives::prelude::*;
 use tendermint::trust_threshold::TrustThresholdFraction as TendermintTrustThresholdFraction;
@@ -147,3 +148,46 @@ pub trait ValidateSelfClientContext {
     /// Returns the host upgrade path. May be empty.
     fn upgrade_path(&self) -> &[String];
 }
+
+/// The Tendermint [`SelfClientValidation`] policy, performing the checks of
+/// [`ValidateSelfClientContext`] against a snapshot of the host parameters.
+#[derive(Clone, Debug)]
+pub struct TendermintSelfClientValidator {
+    pub chain_id: ChainId,
+    pub host_current_height: Height,
+    pub proof_specs: ProofSpecs,
+    pub unbonding_period: Duration,
+    /// May be empty.
+    pub upgrade_path: Vec<String>,
+}
+
+impl ValidateSelfClientContext for TendermintSelfClientValidator {
+    fn chain_id(&self) -> &ChainId {
+        &self.chain_id
+    }
+
+    fn host_current_height(&self) -> Height {
+        self.host_current_height
+    }
+
+    fn proof_specs(&self) -> &ProofSpecs {
+        &self.proof_specs
+    }
+
+    fn unbonding_period(&self) -> Duration {
+        self.unbonding_period
+    }
+
+    fn upgrade_path(&self) -> &[String] {
+        &self.upgrade_path
+    }
+}
+
+impl SelfClientValidation<TmClientState> for TendermintSelfClientValidator {
+    fn validate_self_client(
+        &self,
+        client_state_of_host_on_counterparty: TmClientState,
+    ) -> Result<(), ContextError> {
+        self.validate_self_tendermint_client(client_state_of_host_on_counterparty)
+    }
+}
@@ -0,0 +1,69 @@
+//! Defines the trait selecting the light clients and the host specifics of a
+//! program.
+
+use core::time::Duration;
+
+use ibc_core::client::context::client_state::{ClientStateExecution, ClientStateValidation};
+use ibc_core::client::context::consensus_state::ConsensusState as ConsensusStateTrait;
+use ibc_core::client::types::error::ClientError;
+use ibc_core::client::types::Height;
+use ibc_core::commitment_types::commitment::CommitmentPrefix;
+use ibc_core::handler::types::error::ContextError;
+use ibc_core::primitives::prelude::*;
+use ibc_core::primitives::proto::Any;
+
+use crate::context::SolanaContext;
+
+/// Selects the light clients a program hosts, and the states the
+/// counterparties track the program's chain with, for the [`SolanaContext`]
+/// to store and validate them.
+///
+/// Implemented by a unit struct of the program, e.g.:
+///
+/// ```ignore
+/// pub struct IbcProgram;
+///
+/// impl<'info> SolanaHost<'info> for IbcProgram {
+///     type ClientState = AnyClientState;
+///     type ConsensusState = AnyConsensusState;
+///     type HostClientState = SolanaClientState;
+///     type HostConsensusState = SolanaConsensusState;
+///     ...
+/// }
+/// ```
+pub trait SolanaHost<'info>: Sized {
+    /// The client states of the light clients hosted by the program.
+    type ClientState: ClientStateExecution<SolanaContext<'info, Self>> + Clone;
+    /// The consensus states of the light clients hosted by the program.
+    type ConsensusState: ConsensusStateTrait + Clone + Into<Any> + TryFrom<Any, Error = ClientError>;
+    /// The client state of the program's chain on the counterparties.
+    type HostClientState: ClientStateValidation<SolanaContext<'info, Self>>;
+    /// The consensus state of the program's chain on the counterparties.
+    type HostConsensusState: ConsensusStateTrait;
+
+    /// Returns the consensus state of the program's chain at `height`, which
+    /// the program has to record itself, as the runtime does not expose the
+    /// bank hashes of past slots to programs.
+    fn host_consensus_state(
+        ctx: &SolanaContext<'info, Self>,
+        height: &Height,
+    ) -> Result<Self::HostConsensusState, ContextError>;
+
+    /// Validates the client state of the program's chain on a counterparty.
+    fn validate_self_client(
+        ctx: &SolanaContext<'info, Self>,
+        client_state_of_host_on_counterparty: Self::HostClientState,
+    ) -> Result<(), ContextError>;
+
+    /// Returns the prefix the counterparties prove the IBC store of the
+    /// program under.
+    fn commitment_prefix() -> CommitmentPrefix {
+        CommitmentPrefix::try_from(b"ibc".to_vec()).expect("Never fails")
+    }
+
+    /// Returns the maximum time expected between two slots, which defaults
+    /// to twice the 400ms targeted by Solana, as slots are regularly skipped.
+    fn max_expected_time_per_block() -> Duration {
+        Duration::from_millis(800)
+    }
+}
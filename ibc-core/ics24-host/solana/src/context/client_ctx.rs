@@ -0,0 +1,204 @@
+use ibc_core::client::context::prelude::*;
+use ibc_core::client::types::error::ClientError;
+use ibc_core::client::types::Height;
+use ibc_core::handler::types::error::ContextError;
+use ibc_core::host::types::identifiers::ClientId;
+use ibc_core::host::types::path::{
+    ClientConsensusStatePath, ClientStatePath, ClientUpdateHeightPath, ClientUpdateTimePath,
+};
+use ibc_core::primitives::prelude::*;
+use ibc_core::primitives::proto::Any;
+use ibc_core::primitives::Timestamp;
+use prost::Message;
+
+use super::{consensus_heights_key, SolanaContext};
+use crate::api::SolanaHost;
+
+impl<'info, H: SolanaHost<'info>> ClientValidationContext for SolanaContext<'info, H> {
+    type ClientStateRef = H::ClientState;
+    type ConsensusStateRef = H::ConsensusState;
+
+    fn client_state(&self, client_id: &ClientId) -> Result<Self::ClientStateRef, ContextError> {
+        let any = self
+            .get_any(&ClientStatePath::new(client_id.clone()).to_string())?
+            .ok_or(ClientError::ClientStateNotFound {
+                client_id: client_id.clone(),
+            })?;
+
+        Ok(H::ClientState::try_from(any)?)
+    }
+
+    fn consensus_state(
+        &self,
+        client_cons_state_path: &ClientConsensusStatePath,
+    ) -> Result<Self::ConsensusStateRef, ContextError> {
+        let height = Height::new(
+            client_cons_state_path.revision_number,
+            client_cons_state_path.revision_height,
+        )?;
+
+        let any = self.get_any(&client_cons_state_path.to_string())?.ok_or(
+            ClientError::ConsensusStateNotFound {
+                client_id: client_cons_state_path.client_id.clone(),
+                height,
+            },
+        )?;
+
+        Ok(H::ConsensusState::try_from(any)?)
+    }
+
+    fn client_update_meta(
+        &self,
+        client_id: &ClientId,
+        height: &Height,
+    ) -> Result<(Timestamp, Height), ContextError> {
+        let time_path = ClientUpdateTimePath::new(
+            client_id.clone(),
+            height.revision_number(),
+            height.revision_height(),
+        );
+        let height_path = ClientUpdateHeightPath::new(
+            client_id.clone(),
+            height.revision_number(),
+            height.revision_height(),
+        );
+
+        let missing = || ClientError::UpdateMetaDataNotFound {
+            client_id: client_id.clone(),
+            height: *height,
+        };
+
+        let timestamp = self
+            .store
+            .get(&time_path.to_string())?
+            .ok_or_else(missing)?;
+        let host_height = self
+            .store
+            .get(&height_path.to_string())?
+            .ok_or_else(missing)?;
+
+        Ok((timestamp, host_height))
+    }
+}
+
+impl<'info, H: SolanaHost<'info>> ClientExecutionContext for SolanaContext<'info, H> {
+    type ClientStateMut = H::ClientState;
+
+    fn store_client_state(
+        &mut self,
+        client_state_path: ClientStatePath,
+        client_state: Self::ClientStateRef,
+    ) -> Result<(), ContextError> {
+        self.set_any(&client_state_path.to_string(), client_state.into())
+    }
+
+    fn store_consensus_state(
+        &mut self,
+        consensus_state_path: ClientConsensusStatePath,
+        consensus_state: Self::ConsensusStateRef,
+    ) -> Result<(), ContextError> {
+        let height = Height::new(
+            consensus_state_path.revision_number,
+            consensus_state_path.revision_height,
+        )?;
+
+        let mut heights = self.consensus_state_heights(&consensus_state_path.client_id)?;
+        if let Err(index) = heights.binary_search(&height) {
+            heights.insert(index, height);
+            self.store.set(
+                &consensus_heights_key(&consensus_state_path.client_id),
+                &heights,
+            )?;
+        }
+
+        self.set_any(&consensus_state_path.to_string(), consensus_state.into())
+    }
+
+    fn delete_consensus_state(
+        &mut self,
+        consensus_state_path: ClientConsensusStatePath,
+    ) -> Result<(), ContextError> {
+        let height = Height::new(
+            consensus_state_path.revision_number,
+            consensus_state_path.revision_height,
+        )?;
+
+        let mut heights = self.consensus_state_heights(&consensus_state_path.client_id)?;
+        if let Ok(index) = heights.binary_search(&height) {
+            heights.remove(index);
+            self.store.set(
+                &consensus_heights_key(&consensus_state_path.client_id),
+                &heights,
+            )?;
+        }
+
+        Ok(self.store.delete(&consensus_state_path.to_string())?)
+    }
+
+    fn store_update_meta(
+        &mut self,
+        client_id: ClientId,
+        height: Height,
+        host_timestamp: Timestamp,
+        host_height: Height,
+    ) -> Result<(), ContextError> {
+        let time_path = ClientUpdateTimePath::new(
+            client_id.clone(),
+            height.revision_number(),
+            height.revision_height(),
+        );
+        let height_path = ClientUpdateHeightPath::new(
+            client_id,
+            height.revision_number(),
+            height.revision_height(),
+        );
+
+        self.store.set(&time_path.to_string(), &host_timestamp)?;
+        self.store.set(&height_path.to_string(), &host_height)?;
+
+        Ok(())
+    }
+
+    fn delete_update_meta(
+        &mut self,
+        client_id: ClientId,
+        height: Height,
+    ) -> Result<(), ContextError> {
+        let time_path = ClientUpdateTimePath::new(
+            client_id.clone(),
+            height.revision_number(),
+            height.revision_height(),
+        );
+        let height_path = ClientUpdateHeightPath::new(
+            client_id,
+            height.revision_number(),
+            height.revision_height(),
+        );
+
+        self.store.delete(&time_path.to_string())?;
+        self.store.delete(&height_path.to_string())?;
+
+        Ok(())
+    }
+}
+
+impl<'info, H> SolanaContext<'info, H> {
+    /// Reads the protobuf-encoded `Any` stored at `key`, if any.
+    fn get_any(&self, key: &str) -> Result<Option<Any>, ContextError> {
+        self.store
+            .get_bytes(key)?
+            .map(|bytes| {
+                Any::decode(bytes.as_slice()).map_err(|e| {
+                    ClientError::Other {
+                        description: e.to_string(),
+                    }
+                    .into()
+                })
+            })
+            .transpose()
+    }
+
+    fn set_any(&mut self, key: &str, any: Any) -> Result<(), ContextError> {
+        Ok(self.store.set_bytes(key, &any.encode_to_vec())?)
+    }
+}
@@ -0,0 +1,431 @@
+use core::time::Duration;
+
+use borsh::BorshSerialize;
+use ibc_core::channel::types::channel::ChannelEnd;
+use ibc_core::channel::types::commitment::{AcknowledgementCommitment, PacketCommitment};
+use ibc_core::channel::types::error::{ChannelError, PacketError};
+use ibc_core::channel::types::packet::Receipt;
+use ibc_core::channel::types::upgrade::{ErrorReceipt, Upgrade};
+use ibc_core::client::types::error::ClientError;
+use ibc_core::client::types::Height;
+use ibc_core::commitment_types::commitment::CommitmentPrefix;
+use ibc_core::connection::types::error::ConnectionError;
+use ibc_core::connection::types::ConnectionEnd;
+use ibc_core::handler::types::error::ContextError;
+use ibc_core::handler::types::events::IbcEvent;
+use ibc_core::host::types::identifiers::{ConnectionId, Sequence};
+use ibc_core::host::types::path::{
+    AckPath, ChannelEndPath, ChannelUpgradeErrorPath, ChannelUpgradePath, ClientConnectionPath,
+    CommitmentPath, ConnectionPath, ReceiptPath, SeqAckPath, SeqRecvPath, SeqSendPath,
+    NEXT_CHANNEL_SEQUENCE, NEXT_CLIENT_SEQUENCE, NEXT_CONNECTION_SEQUENCE,
+};
+use ibc_core::host::{ExecutionContext, ValidationContext};
+use ibc_core::primitives::prelude::*;
+use ibc_core::primitives::{Signer, SignerFormat};
+use solana_program::log::sol_log_data;
+use solana_program::msg;
+use solana_program::pubkey::Pubkey;
+
+use super::{counterparty_upgrade_key, inflight_packets_key, SolanaContext};
+use crate::api::SolanaHost;
+
+impl<'info, H: SolanaHost<'info>> ValidationContext for SolanaContext<'info, H> {
+    type V = Self;
+    type HostClientState = H::HostClientState;
+    type HostConsensusState = H::HostConsensusState;
+
+    fn get_client_validation_context(&self) -> &Self::V {
+        self
+    }
+
+    fn host_consensus_state(
+        &self,
+        height: &Height,
+    ) -> Result<Self::HostConsensusState, ContextError> {
+        H::host_consensus_state(self, height)
+    }
+
+    fn client_counter(&self) -> Result<u64, ContextError> {
+        self.get_counter(NEXT_CLIENT_SEQUENCE)
+    }
+
+    fn connection_end(&self, conn_id: &ConnectionId) -> Result<ConnectionEnd, ContextError> {
+        self.store
+            .get(&ConnectionPath::new(conn_id).to_string())?
+            .ok_or_else(|| {
+                ConnectionError::ConnectionNotFound {
+                    connection_id: conn_id.clone(),
+                }
+                .into()
+            })
+    }
+
+    fn validate_self_client(
+        &self,
+        client_state_of_host_on_counterparty: Self::HostClientState,
+    ) -> Result<(), ContextError> {
+        H::validate_self_client(self, client_state_of_host_on_counterparty)
+    }
+
+    fn commitment_prefix(&self) -> CommitmentPrefix {
+        H::commitment_prefix()
+    }
+
+    fn connection_counter(&self) -> Result<u64, ContextError> {
+        self.get_counter(NEXT_CONNECTION_SEQUENCE)
+    }
+
+    fn channel_end(&self, channel_end_path: &ChannelEndPath) -> Result<ChannelEnd, ContextError> {
+        self.store
+            .get(&channel_end_path.to_string())?
+            .ok_or_else(|| {
+                ChannelError::ChannelNotFound {
+                    port_id: channel_end_path.0.clone(),
+                    channel_id: channel_end_path.1.clone(),
+                }
+                .into()
+            })
+    }
+
+    fn get_next_sequence_send(
+        &self,
+        seq_send_path: &SeqSendPath,
+    ) -> Result<Sequence, ContextError> {
+        self.store.get(&seq_send_path.to_string())?.ok_or_else(|| {
+            PacketError::MissingNextSendSeq {
+                port_id: seq_send_path.0.clone(),
+                channel_id: seq_send_path.1.clone(),
+            }
+            .into()
+        })
+    }
+
+    fn get_next_sequence_recv(
+        &self,
+        seq_recv_path: &SeqRecvPath,
+    ) -> Result<Sequence, ContextError> {
+        self.store.get(&seq_recv_path.to_string())?.ok_or_else(|| {
+            PacketError::MissingNextRecvSeq {
+                port_id: seq_recv_path.0.clone(),
+                channel_id: seq_recv_path.1.clone(),
+            }
+            .into()
+        })
+    }
+
+    fn get_next_sequence_ack(&self, seq_ack_path: &SeqAckPath) -> Result<Sequence, ContextError> {
+        self.store.get(&seq_ack_path.to_string())?.ok_or_else(|| {
+            PacketError::MissingNextAckSeq {
+                port_id: seq_ack_path.0.clone(),
+                channel_id: seq_ack_path.1.clone(),
+            }
+            .into()
+        })
+    }
+
+    fn get_packet_commitment(
+        &self,
+        commitment_path: &CommitmentPath,
+    ) -> Result<PacketCommitment, ContextError> {
+        self.store
+            .get(&commitment_path.to_string())?
+            .ok_or_else(|| {
+                PacketError::PacketCommitmentNotFound {
+                    sequence: commitment_path.sequence,
+                }
+                .into()
+            })
+    }
+
+    fn get_packet_receipt(&self, receipt_path: &ReceiptPath) -> Result<Receipt, ContextError> {
+        self.store.get(&receipt_path.to_string())?.ok_or_else(|| {
+            PacketError::PacketReceiptNotFound {
+                sequence: receipt_path.sequence,
+            }
+            .into()
+        })
+    }
+
+    fn get_packet_acknowledgement(
+        &self,
+        ack_path: &AckPath,
+    ) -> Result<AcknowledgementCommitment, ContextError> {
+        self.store.get(&ack_path.to_string())?.ok_or_else(|| {
+            PacketError::PacketAcknowledgementNotFound {
+                sequence: ack_path.sequence,
+            }
+            .into()
+        })
+    }
+
+    fn channel_upgrade(&self, upgrade_path: &ChannelUpgradePath) -> Result<Upgrade, ContextError> {
+        self.store.get(&upgrade_path.to_string())?.ok_or_else(|| {
+            ChannelError::UpgradeNotFound {
+                port_id: upgrade_path.0.clone(),
+                channel_id: upgrade_path.1.clone(),
+            }
+            .into()
+        })
+    }
+
+    fn counterparty_channel_upgrade(
+        &self,
+        upgrade_path: &ChannelUpgradePath,
+    ) -> Result<Upgrade, ContextError> {
+        self.store
+            .get(&counterparty_upgrade_key(upgrade_path))?
+            .ok_or_else(|| {
+                ChannelError::UpgradeNotFound {
+                    port_id: upgrade_path.0.clone(),
+                    channel_id: upgrade_path.1.clone(),
+                }
+                .into()
+            })
+    }
+
+    fn channel_upgrade_error_receipt(
+        &self,
+        upgrade_error_path: &ChannelUpgradeErrorPath,
+    ) -> Result<ErrorReceipt, ContextError> {
+        self.store
+            .get(&upgrade_error_path.to_string())?
+            .ok_or_else(|| {
+                ChannelError::ErrorReceiptNotFound {
+                    port_id: upgrade_error_path.0.clone(),
+                    channel_id: upgrade_error_path.1.clone(),
+                }
+                .into()
+            })
+    }
+
+    fn has_inflight_packets(
+        &self,
+        channel_end_path: &ChannelEndPath,
+    ) -> Result<bool, ContextError> {
+        Ok(self.get_counter(&inflight_packets_key(channel_end_path))? > 0)
+    }
+
+    fn channel_counter(&self) -> Result<u64, ContextError> {
+        self.get_counter(NEXT_CHANNEL_SEQUENCE)
+    }
+
+    fn max_expected_time_per_block(&self) -> Duration {
+        H::max_expected_time_per_block()
+    }
+
+    /// Signers are the base58 addresses of accounts, which must sign the
+    /// instruction, either in its transaction or through the cross-program
+    /// invocation of another program.
+    fn validate_message_signer(&self, signer: &Signer) -> Result<(), ContextError> {
+        let invalid = |reason: String| ClientError::InvalidSigner { reason };
+
+        signer
+            .validate(&SignerFormat::SolanaBase58)
+            .map_err(|e| invalid(e.to_string()))?;
+
+        let address: Pubkey = signer
+            .as_ref()
+            .parse()
+            .map_err(|_| invalid(format!("invalid address `{signer}`")))?;
+
+        let signed = self
+            .store
+            .accounts()
+            .iter()
+            .chain([self.store.payer()])
+            .any(|account| account.key == &address && account.is_signer);
+
+        if !signed {
+            return Err(invalid(format!("`{signer}` did not sign the instruction")).into());
+        }
+
+        Ok(())
+    }
+}
+
+impl<'info, H: SolanaHost<'info>> ExecutionContext for SolanaContext<'info, H> {
+    type E = Self;
+
+    fn get_client_execution_context(&mut self) -> &mut Self::E {
+        self
+    }
+
+    fn increase_client_counter(&mut self) -> Result<(), ContextError> {
+        self.increase_counter(NEXT_CLIENT_SEQUENCE, ClientError::CounterOverflow)
+    }
+
+    fn store_connection(
+        &mut self,
+        connection_path: &ConnectionPath,
+        connection_end: ConnectionEnd,
+    ) -> Result<(), ContextError> {
+        Ok(self
+            .store
+            .set(&connection_path.to_string(), &connection_end)?)
+    }
+
+    fn store_connection_to_client(
+        &mut self,
+        client_connection_path: &ClientConnectionPath,
+        conn_id: ConnectionId,
+    ) -> Result<(), ContextError> {
+        let key = client_connection_path.to_string();
+
+        let mut conn_ids: Vec<ConnectionId> = self.store.get(&key)?.unwrap_or_default();
+        conn_ids.push(conn_id);
+
+        Ok(self.store.set(&key, &conn_ids)?)
+    }
+
+    fn increase_connection_counter(&mut self) -> Result<(), ContextError> {
+        self.increase_counter(NEXT_CONNECTION_SEQUENCE, ConnectionError::CounterOverflow)
+    }
+
+    fn store_packet_commitment(
+        &mut self,
+        commitment_path: &CommitmentPath,
+        commitment: PacketCommitment,
+    ) -> Result<(), ContextError> {
+        let key = commitment_path.to_string();
+
+        if self.store.get_bytes(&key)?.is_none() {
+            let channel_end_path =
+                ChannelEndPath::new(&commitment_path.port_id, &commitment_path.channel_id);
+            self.increase_counter(
+                &inflight_packets_key(&channel_end_path),
+                ChannelError::CounterOverflow,
+            )?;
+        }
+
+        Ok(self.store.set(&key, &commitment)?)
+    }
+
+    fn delete_packet_commitment(
+        &mut self,
+        commitment_path: &CommitmentPath,
+    ) -> Result<(), ContextError> {
+        let key = commitment_path.to_string();
+
+        if self.store.get_bytes(&key)?.is_some() {
+            let channel_end_path =
+                ChannelEndPath::new(&commitment_path.port_id, &commitment_path.channel_id);
+            let inflight_key = inflight_packets_key(&channel_end_path);
+            let inflight = self.get_counter(&inflight_key)?.saturating_sub(1);
+            self.store.set(&inflight_key, &inflight)?;
+        }
+
+        Ok(self.store.delete(&key)?)
+    }
+
+    fn store_packet_receipt(
+        &mut self,
+        receipt_path: &ReceiptPath,
+        receipt: Receipt,
+    ) -> Result<(), ContextError> {
+        Ok(self.store.set(&receipt_path.to_string(), &receipt)?)
+    }
+
+    fn store_packet_acknowledgement(
+        &mut self,
+        ack_path: &AckPath,
+        ack_commitment: AcknowledgementCommitment,
+    ) -> Result<(), ContextError> {
+        Ok(self.store.set(&ack_path.to_string(), &ack_commitment)?)
+    }
+
+    fn delete_packet_acknowledgement(&mut self, ack_path: &AckPath) -> Result<(), ContextError> {
+        Ok(self.store.delete(&ack_path.to_string())?)
+    }
+
+    fn store_channel(
+        &mut self,
+        channel_end_path: &ChannelEndPath,
+        channel_end: ChannelEnd,
+    ) -> Result<(), ContextError> {
+        Ok(self
+            .store
+            .set(&channel_end_path.to_string(), &channel_end)?)
+    }
+
+    fn store_channel_upgrade(
+        &mut self,
+        upgrade_path: &ChannelUpgradePath,
+        upgrade: Upgrade,
+    ) -> Result<(), ContextError> {
+        Ok(self.store.set(&upgrade_path.to_string(), &upgrade)?)
+    }
+
+    fn store_counterparty_channel_upgrade(
+        &mut self,
+        upgrade_path: &ChannelUpgradePath,
+        upgrade: Upgrade,
+    ) -> Result<(), ContextError> {
+        Ok(self
+            .store
+            .set(&counterparty_upgrade_key(upgrade_path), &upgrade)?)
+    }
+
+    fn delete_channel_upgrade(
+        &mut self,
+        upgrade_path: &ChannelUpgradePath,
+    ) -> Result<(), ContextError> {
+        self.store.delete(&upgrade_path.to_string())?;
+        self.store.delete(&counterparty_upgrade_key(upgrade_path))?;
+
+        Ok(())
+    }
+
+    fn store_channel_upgrade_error_receipt(
+        &mut self,
+        upgrade_error_path: &ChannelUpgradeErrorPath,
+        error_receipt: ErrorReceipt,
+    ) -> Result<(), ContextError> {
+        Ok(self
+            .store
+            .set(&upgrade_error_path.to_string(), &error_receipt)?)
+    }
+
+    fn store_next_sequence_send(
+        &mut self,
+        seq_send_path: &SeqSendPath,
+        seq: Sequence,
+    ) -> Result<(), ContextError> {
+        Ok(self.store.set(&seq_send_path.to_string(), &seq)?)
+    }
+
+    fn store_next_sequence_recv(
+        &mut self,
+        seq_recv_path: &SeqRecvPath,
+        seq: Sequence,
+    ) -> Result<(), ContextError> {
+        Ok(self.store.set(&seq_recv_path.to_string(), &seq)?)
+    }
+
+    fn store_next_sequence_ack(
+        &mut self,
+        seq_ack_path: &SeqAckPath,
+        seq: Sequence,
+    ) -> Result<(), ContextError> {
+        Ok(self.store.set(&seq_ack_path.to_string(), &seq)?)
+    }
+
+    fn increase_channel_counter(&mut self) -> Result<(), ContextError> {
+        self.increase_counter(NEXT_CHANNEL_SEQUENCE, ChannelError::CounterOverflow)
+    }
+
+    /// Events are logged as the borsh encoding of the [`IbcEvent`], for
+    /// relayers to decode from the logs of the transaction.
+    fn emit_ibc_event(&mut self, event: IbcEvent) -> Result<(), ContextError> {
+        let data = event.try_to_vec().expect("Never fails");
+
+        sol_log_data(&[&data]);
+
+        Ok(())
+    }
+
+    fn log_message(&mut self, message: String) -> Result<(), ContextError> {
+        msg!("{}", message);
+
+        Ok(())
+    }
+}
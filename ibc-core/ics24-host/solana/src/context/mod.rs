@@ -0,0 +1,121 @@
+//! Implements the IBC contexts over the [`AccountStore`] of a program.
+//!
+//! The values are stored at the keys of their IBC paths, along with:
+//!
+//! - `clients/{client_id}/consensusStates/heights`: the heights of the
+//!   consensus states of a client, in ascending order, as the accounts of
+//!   the program cannot be iterated over,
+//! - `channelEnds/ports/{port_id}/channels/{channel_id}/inflightPackets`: the
+//!   number of the packet commitments of a channel,
+//! - `counterparty/channelUpgrades/...`: the upgrades of the counterparties
+//!   of the channels, under the path of the upgrade of the channel.
+
+mod client_ctx;
+mod core_ctx;
+#[cfg(feature = "tendermint")]
+mod tendermint_ctx;
+
+use core::marker::PhantomData;
+
+use ibc_core::client::context::HostClock;
+use ibc_core::client::types::error::ClientError;
+use ibc_core::client::types::Height;
+use ibc_core::handler::types::error::ContextError;
+use ibc_core::host::types::identifiers::ClientId;
+use ibc_core::host::types::path::{full_consensus_state_path, ChannelEndPath, ChannelUpgradePath};
+use ibc_core::primitives::prelude::*;
+use ibc_core::primitives::Timestamp;
+use solana_program::account_info::AccountInfo;
+use solana_program::clock::Clock;
+use solana_program::pubkey::Pubkey;
+use solana_program::sysvar::Sysvar;
+
+use crate::error::StoreError;
+use crate::store::AccountStore;
+
+/// The context of a program hosting IBC, as selected by `H`.
+pub struct SolanaContext<'info, H> {
+    store: AccountStore<'info>,
+    host: PhantomData<H>,
+}
+
+impl<'info, H> SolanaContext<'info, H> {
+    /// Creates the context over the accounts of an instruction, laid out as
+    /// the [`AccountStore`] expects.
+    pub fn new(program_id: &Pubkey, accounts: &[AccountInfo<'info>]) -> Result<Self, StoreError> {
+        Ok(Self {
+            store: AccountStore::new(program_id, accounts)?,
+            host: PhantomData,
+        })
+    }
+
+    pub fn store(&self) -> &AccountStore<'info> {
+        &self.store
+    }
+
+    pub fn store_mut(&mut self) -> &mut AccountStore<'info> {
+        &mut self.store
+    }
+
+    /// Returns the heights of the consensus states stored for the client, in
+    /// ascending order.
+    pub fn consensus_state_heights(&self, client_id: &ClientId) -> Result<Vec<Height>, StoreError> {
+        Ok(self
+            .store
+            .get(&consensus_heights_key(client_id))?
+            .unwrap_or_default())
+    }
+
+    fn get_counter(&self, key: &str) -> Result<u64, ContextError> {
+        Ok(self.store.get(key)?.unwrap_or_default())
+    }
+
+    fn increase_counter(
+        &mut self,
+        key: &str,
+        overflow: impl Into<ContextError>,
+    ) -> Result<(), ContextError> {
+        let counter = self
+            .get_counter(key)?
+            .checked_add(1)
+            .ok_or_else(|| overflow.into())?;
+
+        Ok(self.store.set(key, &counter)?)
+    }
+}
+
+impl<'info, H> HostClock for SolanaContext<'info, H> {
+    /// The height of the host is its slot, as Solana has no revisions.
+    fn host_height(&self) -> Result<Height, ContextError> {
+        let clock = Clock::get().map_err(StoreError::from)?;
+
+        Ok(Height::new(0, clock.slot)?)
+    }
+
+    fn host_timestamp(&self) -> Result<Timestamp, ContextError> {
+        let clock = Clock::get().map_err(StoreError::from)?;
+
+        u64::try_from(clock.unix_timestamp)
+            .ok()
+            .and_then(|seconds| seconds.checked_mul(1_000_000_000))
+            .and_then(|nanoseconds| Timestamp::from_nanoseconds(nanoseconds).ok())
+            .ok_or_else(|| {
+                ClientError::Other {
+                    description: format!("invalid host timestamp: `{}`", clock.unix_timestamp),
+                }
+                .into()
+            })
+    }
+}
+
+pub(crate) fn consensus_heights_key(client_id: &ClientId) -> String {
+    format!("{}/heights", full_consensus_state_path(client_id))
+}
+
+pub(crate) fn inflight_packets_key(channel_end_path: &ChannelEndPath) -> String {
+    format!("{channel_end_path}/inflightPackets")
+}
+
+pub(crate) fn counterparty_upgrade_key(upgrade_path: &ChannelUpgradePath) -> String {
+    format!("counterparty/{upgrade_path}")
+}
@@ -0,0 +1,118 @@
+//! Defines the instruction of a program hosting IBC, and its processing by
+//! the core handlers.
+
+use borsh::{BorshDeserialize, BorshSerialize};
+use ibc_core::entrypoint::dispatch;
+use ibc_core::handler::types::error::ContextError;
+use ibc_core::handler::types::msgs::MsgEnvelope;
+use ibc_core::primitives::prelude::*;
+use ibc_core::primitives::proto::Any;
+use ibc_core::router::router::Router;
+use prost::Message;
+use solana_program::account_info::AccountInfo;
+use solana_program::entrypoint::ProgramResult;
+use solana_program::instruction::{AccountMeta, Instruction};
+use solana_program::msg;
+use solana_program::program_error::ProgramError;
+use solana_program::pubkey::Pubkey;
+use solana_program::system_program;
+
+use crate::api::SolanaHost;
+use crate::context::SolanaContext;
+use crate::error::StoreError;
+
+/// The instruction of a program hosting IBC.
+#[derive(Clone, Debug, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub enum IbcInstruction {
+    /// Delivers the IBC messages, each a protobuf-encoded `Any`, in order.
+    Deliver { msgs: Vec<Vec<u8>> },
+}
+
+/// Processes an [`IbcInstruction`], dispatching its messages to the core
+/// handlers and to the applications of the `router`.
+///
+/// Programs call it from their entry point, e.g.:
+///
+/// ```ignore
+/// entrypoint!(process);
+///
+/// fn process(program_id: &Pubkey, accounts: &[AccountInfo<'_>], data: &[u8]) -> ProgramResult {
+///     process_instruction::<IbcProgram>(program_id, accounts, data, &mut IbcRouter::new())
+/// }
+/// ```
+///
+/// Failures are logged, and returned as the [`ProgramError::Custom`] holding
+/// the [`code`](ContextError::code) of the error.
+pub fn process_instruction<'info, H>(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo<'info>],
+    instruction_data: &[u8],
+    router: &mut impl Router,
+) -> ProgramResult
+where
+    H: SolanaHost<'info>,
+{
+    let instruction = IbcInstruction::try_from_slice(instruction_data)
+        .map_err(|_| ProgramError::InvalidInstructionData)?;
+
+    let mut ctx = SolanaContext::<H>::new(program_id, accounts).map_err(store_error)?;
+
+    match instruction {
+        IbcInstruction::Deliver { msgs } => {
+            for msg in msgs {
+                let any = Any::decode(msg.as_slice())
+                    .map_err(|_| ProgramError::InvalidInstructionData)?;
+                let envelope = MsgEnvelope::try_from(any)
+                    .map_err(|e| context_error(ContextError::RouterError(e)))?;
+
+                dispatch(&mut ctx, router, envelope).map_err(context_error)?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Builds the instruction delivering `msgs` to the program `program_id`, be
+/// it sent in a transaction or invoked by another program.
+///
+/// The `accounts` are the accounts of the paths the messages read or write,
+/// as found with [`find_path_address`](crate::store::find_path_address), and
+/// the signers of the messages.
+pub fn deliver(
+    program_id: &Pubkey,
+    payer: &Pubkey,
+    accounts: Vec<AccountMeta>,
+    msgs: Vec<Any>,
+) -> Instruction {
+    let instruction = IbcInstruction::Deliver {
+        msgs: msgs.iter().map(Message::encode_to_vec).collect(),
+    };
+
+    let mut metas = vec![
+        AccountMeta::new(*payer, true),
+        AccountMeta::new_readonly(system_program::ID, false),
+    ];
+    metas.extend(accounts);
+
+    Instruction::new_with_bytes(
+        *program_id,
+        &instruction.try_to_vec().expect("Never fails"),
+        metas,
+    )
+}
+
+fn store_error(e: StoreError) -> ProgramError {
+    msg!("{}", e);
+
+    match e {
+        StoreError::Program(e) => e,
+        _ => ProgramError::InvalidAccountData,
+    }
+}
+
+fn context_error(e: ContextError) -> ProgramError {
+    msg!("{}", e);
+
+    ProgramError::Custom(e.code())
+}
@@ -0,0 +1,56 @@
+//! Defines the errors raised by the storage of the IBC store in accounts.
+
+use displaydoc::Display;
+use ibc_core::client::types::error::ClientError;
+use ibc_core::handler::types::error::ContextError;
+use ibc_core::primitives::prelude::*;
+use solana_program::program_error::ProgramError;
+use solana_program::pubkey::Pubkey;
+
+/// Errors of the [`AccountStore`](crate::store::AccountStore).
+#[derive(Debug, Display)]
+pub enum StoreError {
+    /// missing the payer and system program accounts of the instruction
+    MissingProgramAccounts,
+    /// payer `{address}` must sign the instruction and be writable
+    InvalidPayer { address: Pubkey },
+    /// account `{address}` is not the system program
+    InvalidSystemProgram { address: Pubkey },
+    /// missing the account `{address}` storing `{key}`
+    MissingAccount { key: String, address: Pubkey },
+    /// account `{address}` storing `{key}` is not owned by the program
+    InvalidAccountOwner { key: String, address: Pubkey },
+    /// failed to decode the value of `{key}`: `{reason}`
+    InvalidValue { key: String, reason: String },
+    /// program error: `{0}`
+    Program(ProgramError),
+}
+
+impl core::error::Error for StoreError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            Self::Program(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<ProgramError> for StoreError {
+    fn from(e: ProgramError) -> Self {
+        Self::Program(e)
+    }
+}
+
+impl From<StoreError> for ClientError {
+    fn from(e: StoreError) -> Self {
+        ClientError::Other {
+            description: e.to_string(),
+        }
+    }
+}
+
+impl From<StoreError> for ContextError {
+    fn from(e: StoreError) -> Self {
+        ContextError::ClientError(e.into())
+    }
+}
@@ -0,0 +1,38 @@
+//! Provides the adapter implementing the IBC contexts over the accounts of a
+//! Solana program, so that programs can embed the IBC core handlers without
+//! mapping the IBC store onto accounts themselves.
+//!
+//! Each IBC path is stored in its own account, at the program-derived address
+//! found with [`find_path_address`](store::find_path_address), which the
+//! [`AccountStore`](store::AccountStore) creates, resizes and closes as values
+//! are written and deleted, the payer of the instruction funding their rent
+//! exemption. Values are borsh-encoded, save for the client and consensus
+//! states, which are stored as the protobuf-encoded `Any`s the light clients
+//! define them as.
+//!
+//! The [`SolanaContext`](context::SolanaContext) implements the validation and
+//! execution contexts of the core handlers over this store, for the light
+//! clients and the host specifics selected by a [`SolanaHost`](api::SolanaHost),
+//! and [`process_instruction`](entrypoint::process_instruction) dispatches the
+//! messages of an [`IbcInstruction`](entrypoint::IbcInstruction) with it, be it
+//! sent in a transaction or by another program through a cross-program
+//! invocation.
+//!
+//! As the writes of a failed instruction are reverted by the runtime, the
+//! context does not stage them.
+#![forbid(unsafe_code)]
+#![cfg_attr(not(test), deny(clippy::unwrap_used))]
+#![cfg_attr(not(test), deny(clippy::disallowed_methods, clippy::disallowed_types))]
+#![deny(
+    warnings,
+    trivial_numeric_casts,
+    unused_import_braces,
+    unused_qualifications,
+    rust_2018_idioms
+)]
+
+pub mod api;
+pub mod context;
+pub mod entrypoint;
+pub mod error;
+pub mod store;
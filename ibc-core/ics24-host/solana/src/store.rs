@@ -0,0 +1,213 @@
+//! Stores the values of the IBC paths in program-derived accounts.
+
+use borsh::{BorshDeserialize, BorshSerialize};
+use ibc_core::primitives::prelude::*;
+use solana_program::account_info::AccountInfo;
+use solana_program::hash::hash;
+use solana_program::program::{invoke, invoke_signed};
+use solana_program::pubkey::Pubkey;
+use solana_program::rent::Rent;
+use solana_program::system_instruction::{create_account, transfer};
+use solana_program::system_program;
+use solana_program::sysvar::Sysvar;
+
+use crate::error::StoreError;
+
+/// The first seed of the addresses of the accounts storing the IBC paths.
+pub const STORE_SEED: &[u8] = b"ibc";
+
+/// Returns the seed of the account storing `key`, which is the SHA-256 hash
+/// of the key, as the keys of the IBC paths are longer than the 32 bytes a
+/// seed is limited to.
+pub fn path_seed(key: &str) -> [u8; 32] {
+    hash(key.as_bytes()).to_bytes()
+}
+
+/// Returns the program-derived address of the account storing `key`, along
+/// with its bump seed.
+///
+/// Callers use it to find the accounts the instructions of the program read
+/// and write, e.g. `find_path_address(&program_id, &path.to_string())`.
+pub fn find_path_address(program_id: &Pubkey, key: &str) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[STORE_SEED, &path_seed(key)], program_id)
+}
+
+/// The IBC store of a program, over the accounts passed to its instruction.
+///
+/// The accounts are expected in the order:
+///
+/// 0. the payer of the rent of the created accounts, signer and writable,
+/// 1. the system program,
+/// 2. the accounts of the paths the instruction reads or writes, writable if
+///    written, along with any signer of the messages of the instruction.
+pub struct AccountStore<'info> {
+    program_id: Pubkey,
+    payer: AccountInfo<'info>,
+    system_program: AccountInfo<'info>,
+    accounts: Vec<AccountInfo<'info>>,
+}
+
+impl<'info> AccountStore<'info> {
+    pub fn new(program_id: &Pubkey, accounts: &[AccountInfo<'info>]) -> Result<Self, StoreError> {
+        let (payer, system_program, accounts) = match accounts {
+            [payer, system_program, accounts @ ..] => (payer, system_program, accounts),
+            _ => return Err(StoreError::MissingProgramAccounts),
+        };
+
+        if !payer.is_signer || !payer.is_writable {
+            return Err(StoreError::InvalidPayer {
+                address: *payer.key,
+            });
+        }
+
+        if !system_program::check_id(system_program.key) {
+            return Err(StoreError::InvalidSystemProgram {
+                address: *system_program.key,
+            });
+        }
+
+        Ok(Self {
+            program_id: *program_id,
+            payer: payer.clone(),
+            system_program: system_program.clone(),
+            accounts: accounts.to_vec(),
+        })
+    }
+
+    pub fn program_id(&self) -> &Pubkey {
+        &self.program_id
+    }
+
+    pub fn payer(&self) -> &AccountInfo<'info> {
+        &self.payer
+    }
+
+    /// Returns the accounts of the paths and the signers of the instruction.
+    pub fn accounts(&self) -> &[AccountInfo<'info>] {
+        &self.accounts
+    }
+
+    /// Reads and decodes the value stored at `key`, if any.
+    pub fn get<T: BorshDeserialize>(&self, key: &str) -> Result<Option<T>, StoreError> {
+        self.get_bytes(key)?
+            .map(|bytes| {
+                T::try_from_slice(&bytes).map_err(|e| StoreError::InvalidValue {
+                    key: key.to_string(),
+                    reason: e.to_string(),
+                })
+            })
+            .transpose()
+    }
+
+    /// Encodes and stores `value` at `key`.
+    pub fn set<T: BorshSerialize>(&mut self, key: &str, value: &T) -> Result<(), StoreError> {
+        let bytes = value.try_to_vec().expect("Never fails");
+
+        self.set_bytes(key, &bytes)
+    }
+
+    /// Reads the bytes stored at `key`, if any.
+    pub fn get_bytes(&self, key: &str) -> Result<Option<Vec<u8>>, StoreError> {
+        let (address, _) = find_path_address(&self.program_id, key);
+        let account = self.account(key, &address)?;
+
+        if account.data_is_empty() {
+            return Ok(None);
+        }
+
+        if account.owner != &self.program_id {
+            return Err(StoreError::InvalidAccountOwner {
+                key: key.to_string(),
+                address,
+            });
+        }
+
+        let data = account.try_borrow_data()?;
+
+        Ok(Some(data.to_vec()))
+    }
+
+    /// Stores `bytes` at `key`, creating the account of the key or resizing
+    /// it to their length, and topping up its rent exemption from the payer.
+    pub fn set_bytes(&mut self, key: &str, bytes: &[u8]) -> Result<(), StoreError> {
+        let seed = path_seed(key);
+        let (address, bump) = find_path_address(&self.program_id, key);
+        let account = self.account(key, &address)?;
+
+        let rent_exemption = Rent::get()?.minimum_balance(bytes.len());
+
+        if account.owner != &self.program_id {
+            invoke_signed(
+                &create_account(
+                    self.payer.key,
+                    &address,
+                    rent_exemption,
+                    bytes.len() as u64,
+                    &self.program_id,
+                ),
+                &[
+                    self.payer.clone(),
+                    account.clone(),
+                    self.system_program.clone(),
+                ],
+                &[&[STORE_SEED, &seed, &[bump]]],
+            )?;
+        } else {
+            if account.lamports() < rent_exemption {
+                invoke(
+                    &transfer(
+                        self.payer.key,
+                        &address,
+                        rent_exemption - account.lamports(),
+                    ),
+                    &[
+                        self.payer.clone(),
+                        account.clone(),
+                        self.system_program.clone(),
+                    ],
+                )?;
+            }
+
+            account.realloc(bytes.len(), false)?;
+        }
+
+        account.try_borrow_mut_data()?.copy_from_slice(bytes);
+
+        Ok(())
+    }
+
+    /// Deletes the value stored at `key`, closing its account and refunding
+    /// its rent exemption to the payer.
+    pub fn delete(&mut self, key: &str) -> Result<(), StoreError> {
+        let (address, _) = find_path_address(&self.program_id, key);
+        let account = self.account(key, &address)?;
+
+        if account.owner != &self.program_id {
+            return Ok(());
+        }
+
+        let refund = self
+            .payer
+            .lamports()
+            .checked_add(account.lamports())
+            .expect("Never fails, as the lamports in circulation fit in a u64");
+
+        **self.payer.try_borrow_mut_lamports()? = refund;
+        **account.try_borrow_mut_lamports()? = 0;
+
+        account.realloc(0, false)?;
+        account.assign(&system_program::ID);
+
+        Ok(())
+    }
+
+    fn account(&self, key: &str, address: &Pubkey) -> Result<&AccountInfo<'info>, StoreError> {
+        self.accounts
+            .iter()
+            .find(|account| account.key == address)
+            .ok_or_else(|| StoreError::MissingAccount {
+                key: key.to_string(),
+                address: *address,
+            })
+    }
+}
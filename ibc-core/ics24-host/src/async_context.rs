@@ -0,0 +1,676 @@
+//! Defines the asynchronous variants of the top-level contexts, for hosts
+//! whose storage or crypto can only be accessed asynchronously, e.g. state
+//! served over RPC, remote signers or asynchronous wasm runtimes.
+//!
+//! The store of the IBC core modules is read and written asynchronously,
+//! while the contexts of the light clients, their parameters and the
+//! validation of the host's own client state stay synchronous. Every
+//! [`ValidationContext`] and [`ExecutionContext`] is also an asynchronous
+//! context, so that the same host may be driven by both kinds of entrypoints.
+//!
+//! These traits use `async fn`, which requires Rust 1.75. Their futures are
+//! not required to be `Send`.
+use core::time::Duration;
+
+use ibc_core_channel_types::channel::ChannelEnd;
+use ibc_core_channel_types::commitment::{AcknowledgementCommitment, PacketCommitment};
+use ibc_core_channel_types::events::PacketDataEventMode;
+use ibc_core_channel_types::packet::Receipt;
+use ibc_core_channel_types::upgrade::{ErrorReceipt, Upgrade, DEFAULT_UPGRADE_TIMEOUT};
+use ibc_core_client_context::prelude::*;
+use ibc_core_client_types::error::ClientError;
+use ibc_core_client_types::params::ClientParams;
+use ibc_core_client_types::Height;
+use ibc_core_commitment_types::commitment::CommitmentPrefix;
+use ibc_core_connection_types::params::ConnectionParams;
+use ibc_core_connection_types::version::{pick_version, Version as ConnectionVersion};
+use ibc_core_connection_types::ConnectionEnd;
+use ibc_core_handler_types::error::ContextError;
+use ibc_core_handler_types::events::IbcEvent;
+use ibc_core_host_types::identifiers::{ChannelId, ClientId, ConnectionId, PortId, Sequence};
+use ibc_core_host_types::path::{
+    AckPath, ChannelEndPath, ChannelUpgradeErrorPath, ChannelUpgradePath, ClientConnectionPath,
+    CommitmentPath, ConnectionPath, ReceiptPath, SeqAckPath, SeqRecvPath, SeqSendPath,
+};
+use ibc_core_host_types::validate::IdentifierRules;
+use ibc_primitives::prelude::*;
+use ibc_primitives::{Signer, Timestamp};
+
+use crate::{ExecutionContext, GasMeter, Metrics, NoopGasMeter, NoopMetrics, ValidationContext};
+
+/// Asynchronous variant of [`ValidationContext`], whose methods are
+/// documented there.
+///
+/// Trait used for the top-level asynchronous `validate` entrypoint in the
+/// `ibc-core` crate.
+#[allow(async_fn_in_trait)]
+pub trait AsyncValidationContext {
+    type V: ClientValidationContext;
+    /// The client state type for the host chain.
+    type HostClientState: ClientStateValidation<Self::V>;
+    /// The consensus state type for the host chain.
+    type HostConsensusState: ConsensusState + Clone;
+
+    /// Retrieve the context that implements all clients' `ValidationContext`.
+    fn get_client_validation_context(&self) -> &Self::V;
+
+    async fn host_height(&self) -> Result<Height, ContextError>;
+
+    async fn host_timestamp(&self) -> Result<Timestamp, ContextError>;
+
+    async fn host_consensus_state(
+        &self,
+        height: &Height,
+    ) -> Result<Self::HostConsensusState, ContextError>;
+
+    async fn client_counter(&self) -> Result<u64, ContextError>;
+
+    fn client_params(&self) -> ClientParams {
+        ClientParams::default()
+    }
+
+    async fn connection_end(&self, conn_id: &ConnectionId) -> Result<ConnectionEnd, ContextError>;
+
+    fn validate_self_client(
+        &self,
+        client_state_of_host_on_counterparty: Self::HostClientState,
+    ) -> Result<(), ContextError>;
+
+    fn commitment_prefix(&self) -> CommitmentPrefix;
+
+    async fn connection_counter(&self) -> Result<u64, ContextError>;
+
+    fn connection_params(&self) -> ConnectionParams {
+        ConnectionParams::new(
+            self.max_expected_time_per_block(),
+            self.get_compatible_versions(),
+        )
+    }
+
+    fn get_compatible_versions(&self) -> Vec<ConnectionVersion> {
+        ConnectionVersion::compatibles()
+    }
+
+    fn pick_version(
+        &self,
+        counterparty_candidate_versions: &[ConnectionVersion],
+    ) -> Result<ConnectionVersion, ContextError> {
+        let version = pick_version(
+            &self.connection_params().compatible_versions,
+            counterparty_candidate_versions,
+        )?;
+        Ok(version)
+    }
+
+    async fn channel_end(
+        &self,
+        channel_end_path: &ChannelEndPath,
+    ) -> Result<ChannelEnd, ContextError>;
+
+    async fn get_next_sequence_send(
+        &self,
+        seq_send_path: &SeqSendPath,
+    ) -> Result<Sequence, ContextError>;
+
+    async fn get_next_sequence_recv(
+        &self,
+        seq_recv_path: &SeqRecvPath,
+    ) -> Result<Sequence, ContextError>;
+
+    async fn get_next_sequence_ack(
+        &self,
+        seq_ack_path: &SeqAckPath,
+    ) -> Result<Sequence, ContextError>;
+
+    async fn get_packet_commitment(
+        &self,
+        commitment_path: &CommitmentPath,
+    ) -> Result<PacketCommitment, ContextError>;
+
+    async fn get_packet_receipt(&self, receipt_path: &ReceiptPath)
+        -> Result<Receipt, ContextError>;
+
+    async fn get_packet_acknowledgement(
+        &self,
+        ack_path: &AckPath,
+    ) -> Result<AcknowledgementCommitment, ContextError>;
+
+    async fn channel_upgrade(
+        &self,
+        upgrade_path: &ChannelUpgradePath,
+    ) -> Result<Upgrade, ContextError>;
+
+    async fn counterparty_channel_upgrade(
+        &self,
+        upgrade_path: &ChannelUpgradePath,
+    ) -> Result<Upgrade, ContextError>;
+
+    async fn channel_upgrade_error_receipt(
+        &self,
+        upgrade_error_path: &ChannelUpgradeErrorPath,
+    ) -> Result<ErrorReceipt, ContextError>;
+
+    async fn has_inflight_packets(
+        &self,
+        channel_end_path: &ChannelEndPath,
+    ) -> Result<bool, ContextError>;
+
+    fn upgrade_timeout(&self) -> Duration {
+        DEFAULT_UPGRADE_TIMEOUT
+    }
+
+    fn max_packet_data_size(&self) -> Option<usize> {
+        None
+    }
+
+    fn packet_data_event_mode(&self) -> PacketDataEventMode {
+        PacketDataEventMode::Full
+    }
+
+    fn metrics(&self) -> &dyn Metrics {
+        &NoopMetrics
+    }
+
+    fn gas_meter(&self) -> &dyn GasMeter {
+        &NoopGasMeter
+    }
+
+    async fn channel_counter(&self) -> Result<u64, ContextError>;
+
+    fn max_expected_time_per_block(&self) -> Duration;
+
+    fn validate_message_signer(&self, signer: &Signer) -> Result<(), ContextError>;
+
+    fn validate_connection_creator(
+        &self,
+        _signer: &Signer,
+        _client_id: &ClientId,
+    ) -> Result<(), ContextError> {
+        Ok(())
+    }
+
+    fn identifier_rules(&self) -> IdentifierRules {
+        IdentifierRules::ICS24
+    }
+
+    fn validate_channel_close(
+        &self,
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+    ) -> Result<(), ContextError> {
+        Ok(())
+    }
+}
+
+/// Asynchronous variant of [`ExecutionContext`], whose methods are documented
+/// there.
+///
+/// Trait used for the top-level asynchronous `execute` and `dispatch`
+/// entrypoints in the `ibc-core` crate.
+#[allow(async_fn_in_trait)]
+pub trait AsyncExecutionContext: AsyncValidationContext {
+    type E: ClientExecutionContext;
+
+    /// Retrieve the context that implements all clients' `ExecutionContext`.
+    fn get_client_execution_context(&mut self) -> &mut Self::E;
+
+    async fn increase_client_counter(&mut self) -> Result<(), ContextError>;
+
+    /// Stores the parameters of the client module, as updated by the
+    /// governance of the host. Implement only if the host stores them.
+    async fn store_client_params(&mut self, _params: ClientParams) -> Result<(), ContextError> {
+        Err(ClientError::ParamsUnsupported.into())
+    }
+
+    async fn store_connection(
+        &mut self,
+        connection_path: &ConnectionPath,
+        connection_end: ConnectionEnd,
+    ) -> Result<(), ContextError>;
+
+    async fn store_connection_to_client(
+        &mut self,
+        client_connection_path: &ClientConnectionPath,
+        conn_id: ConnectionId,
+    ) -> Result<(), ContextError>;
+
+    async fn increase_connection_counter(&mut self) -> Result<(), ContextError>;
+
+    async fn store_packet_commitment(
+        &mut self,
+        commitment_path: &CommitmentPath,
+        commitment: PacketCommitment,
+    ) -> Result<(), ContextError>;
+
+    async fn delete_packet_commitment(
+        &mut self,
+        commitment_path: &CommitmentPath,
+    ) -> Result<(), ContextError>;
+
+    async fn store_packet_receipt(
+        &mut self,
+        receipt_path: &ReceiptPath,
+        receipt: Receipt,
+    ) -> Result<(), ContextError>;
+
+    async fn store_packet_acknowledgement(
+        &mut self,
+        ack_path: &AckPath,
+        ack_commitment: AcknowledgementCommitment,
+    ) -> Result<(), ContextError>;
+
+    async fn delete_packet_acknowledgement(
+        &mut self,
+        ack_path: &AckPath,
+    ) -> Result<(), ContextError>;
+
+    async fn store_channel(
+        &mut self,
+        channel_end_path: &ChannelEndPath,
+        channel_end: ChannelEnd,
+    ) -> Result<(), ContextError>;
+
+    async fn store_channel_upgrade(
+        &mut self,
+        upgrade_path: &ChannelUpgradePath,
+        upgrade: Upgrade,
+    ) -> Result<(), ContextError>;
+
+    async fn store_counterparty_channel_upgrade(
+        &mut self,
+        upgrade_path: &ChannelUpgradePath,
+        upgrade: Upgrade,
+    ) -> Result<(), ContextError>;
+
+    async fn delete_channel_upgrade(
+        &mut self,
+        upgrade_path: &ChannelUpgradePath,
+    ) -> Result<(), ContextError>;
+
+    async fn store_channel_upgrade_error_receipt(
+        &mut self,
+        upgrade_error_path: &ChannelUpgradeErrorPath,
+        error_receipt: ErrorReceipt,
+    ) -> Result<(), ContextError>;
+
+    async fn store_next_sequence_send(
+        &mut self,
+        seq_send_path: &SeqSendPath,
+        seq: Sequence,
+    ) -> Result<(), ContextError>;
+
+    async fn store_next_sequence_recv(
+        &mut self,
+        seq_recv_path: &SeqRecvPath,
+        seq: Sequence,
+    ) -> Result<(), ContextError>;
+
+    async fn store_next_sequence_ack(
+        &mut self,
+        seq_ack_path: &SeqAckPath,
+        seq: Sequence,
+    ) -> Result<(), ContextError>;
+
+    async fn increase_channel_counter(&mut self) -> Result<(), ContextError>;
+
+    async fn emit_ibc_event(&mut self, event: IbcEvent) -> Result<(), ContextError>;
+
+    async fn log_message(&mut self, message: String) -> Result<(), ContextError>;
+}
+
+impl<T> AsyncValidationContext for T
+where
+    T: ValidationContext,
+    T::HostConsensusState: Clone,
+{
+    type V = T::V;
+    type HostClientState = T::HostClientState;
+    type HostConsensusState = T::HostConsensusState;
+
+    fn get_client_validation_context(&self) -> &Self::V {
+        ValidationContext::get_client_validation_context(self)
+    }
+
+    async fn host_height(&self) -> Result<Height, ContextError> {
+        HostClock::host_height(self)
+    }
+
+    async fn host_timestamp(&self) -> Result<Timestamp, ContextError> {
+        HostClock::host_timestamp(self)
+    }
+
+    async fn host_consensus_state(
+        &self,
+        height: &Height,
+    ) -> Result<Self::HostConsensusState, ContextError> {
+        ValidationContext::host_consensus_state(self, height)
+    }
+
+    async fn client_counter(&self) -> Result<u64, ContextError> {
+        ValidationContext::client_counter(self)
+    }
+
+    async fn connection_end(&self, conn_id: &ConnectionId) -> Result<ConnectionEnd, ContextError> {
+        ValidationContext::connection_end(self, conn_id)
+    }
+
+    fn validate_self_client(
+        &self,
+        client_state_of_host_on_counterparty: Self::HostClientState,
+    ) -> Result<(), ContextError> {
+        ValidationContext::validate_self_client(self, client_state_of_host_on_counterparty)
+    }
+
+    fn commitment_prefix(&self) -> CommitmentPrefix {
+        ValidationContext::commitment_prefix(self)
+    }
+
+    async fn connection_counter(&self) -> Result<u64, ContextError> {
+        ValidationContext::connection_counter(self)
+    }
+
+    fn client_params(&self) -> ClientParams {
+        ValidationContext::client_params(self)
+    }
+
+    fn connection_params(&self) -> ConnectionParams {
+        ValidationContext::connection_params(self)
+    }
+
+    fn get_compatible_versions(&self) -> Vec<ConnectionVersion> {
+        ValidationContext::get_compatible_versions(self)
+    }
+
+    fn pick_version(
+        &self,
+        counterparty_candidate_versions: &[ConnectionVersion],
+    ) -> Result<ConnectionVersion, ContextError> {
+        ValidationContext::pick_version(self, counterparty_candidate_versions)
+    }
+
+    async fn channel_end(
+        &self,
+        channel_end_path: &ChannelEndPath,
+    ) -> Result<ChannelEnd, ContextError> {
+        ValidationContext::channel_end(self, channel_end_path)
+    }
+
+    async fn get_next_sequence_send(
+        &self,
+        seq_send_path: &SeqSendPath,
+    ) -> Result<Sequence, ContextError> {
+        ValidationContext::get_next_sequence_send(self, seq_send_path)
+    }
+
+    async fn get_next_sequence_recv(
+        &self,
+        seq_recv_path: &SeqRecvPath,
+    ) -> Result<Sequence, ContextError> {
+        ValidationContext::get_next_sequence_recv(self, seq_recv_path)
+    }
+
+    async fn get_next_sequence_ack(
+        &self,
+        seq_ack_path: &SeqAckPath,
+    ) -> Result<Sequence, ContextError> {
+        ValidationContext::get_next_sequence_ack(self, seq_ack_path)
+    }
+
+    async fn get_packet_commitment(
+        &self,
+        commitment_path: &CommitmentPath,
+    ) -> Result<PacketCommitment, ContextError> {
+        ValidationContext::get_packet_commitment(self, commitment_path)
+    }
+
+    async fn get_packet_receipt(
+        &self,
+        receipt_path: &ReceiptPath,
+    ) -> Result<Receipt, ContextError> {
+        ValidationContext::get_packet_receipt(self, receipt_path)
+    }
+
+    async fn get_packet_acknowledgement(
+        &self,
+        ack_path: &AckPath,
+    ) -> Result<AcknowledgementCommitment, ContextError> {
+        ValidationContext::get_packet_acknowledgement(self, ack_path)
+    }
+
+    async fn channel_upgrade(
+        &self,
+        upgrade_path: &ChannelUpgradePath,
+    ) -> Result<Upgrade, ContextError> {
+        ValidationContext::channel_upgrade(self, upgrade_path)
+    }
+
+    async fn counterparty_channel_upgrade(
+        &self,
+        upgrade_path: &ChannelUpgradePath,
+    ) -> Result<Upgrade, ContextError> {
+        ValidationContext::counterparty_channel_upgrade(self, upgrade_path)
+    }
+
+    async fn channel_upgrade_error_receipt(
+        &self,
+        upgrade_error_path: &ChannelUpgradeErrorPath,
+    ) -> Result<ErrorReceipt, ContextError> {
+        ValidationContext::channel_upgrade_error_receipt(self, upgrade_error_path)
+    }
+
+    async fn has_inflight_packets(
+        &self,
+        channel_end_path: &ChannelEndPath,
+    ) -> Result<bool, ContextError> {
+        ValidationContext::has_inflight_packets(self, channel_end_path)
+    }
+
+    fn upgrade_timeout(&self) -> Duration {
+        ValidationContext::upgrade_timeout(self)
+    }
+
+    fn max_packet_data_size(&self) -> Option<usize> {
+        ValidationContext::max_packet_data_size(self)
+    }
+
+    fn packet_data_event_mode(&self) -> PacketDataEventMode {
+        ValidationContext::packet_data_event_mode(self)
+    }
+
+    fn metrics(&self) -> &dyn Metrics {
+        ValidationContext::metrics(self)
+    }
+
+    fn gas_meter(&self) -> &dyn GasMeter {
+        ValidationContext::gas_meter(self)
+    }
+
+    async fn channel_counter(&self) -> Result<u64, ContextError> {
+        ValidationContext::channel_counter(self)
+    }
+
+    fn max_expected_time_per_block(&self) -> Duration {
+        ValidationContext::max_expected_time_per_block(self)
+    }
+
+    fn validate_message_signer(&self, signer: &Signer) -> Result<(), ContextError> {
+        ValidationContext::validate_message_signer(self, signer)
+    }
+
+    fn validate_connection_creator(
+        &self,
+        signer: &Signer,
+        client_id: &ClientId,
+    ) -> Result<(), ContextError> {
+        ValidationContext::validate_connection_creator(self, signer, client_id)
+    }
+
+    fn identifier_rules(&self) -> IdentifierRules {
+        ValidationContext::identifier_rules(self)
+    }
+
+    fn validate_channel_close(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<(), ContextError> {
+        ValidationContext::validate_channel_close(self, port_id, channel_id)
+    }
+}
+
+impl<T> AsyncExecutionContext for T
+where
+    T: ExecutionContext,
+    T::HostConsensusState: Clone,
+{
+    type E = T::E;
+
+    fn get_client_execution_context(&mut self) -> &mut Self::E {
+        ExecutionContext::get_client_execution_context(self)
+    }
+
+    async fn increase_client_counter(&mut self) -> Result<(), ContextError> {
+        ExecutionContext::increase_client_counter(self)
+    }
+
+    async fn store_client_params(&mut self, params: ClientParams) -> Result<(), ContextError> {
+        ExecutionContext::store_client_params(self, params)
+    }
+
+    async fn store_connection(
+        &mut self,
+        connection_path: &ConnectionPath,
+        connection_end: ConnectionEnd,
+    ) -> Result<(), ContextError> {
+        ExecutionContext::store_connection(self, connection_path, connection_end)
+    }
+
+    async fn store_connection_to_client(
+        &mut self,
+        client_connection_path: &ClientConnectionPath,
+        conn_id: ConnectionId,
+    ) -> Result<(), ContextError> {
+        ExecutionContext::store_connection_to_client(self, client_connection_path, conn_id)
+    }
+
+    async fn increase_connection_counter(&mut self) -> Result<(), ContextError> {
+        ExecutionContext::increase_connection_counter(self)
+    }
+
+    async fn store_packet_commitment(
+        &mut self,
+        commitment_path: &CommitmentPath,
+        commitment: PacketCommitment,
+    ) -> Result<(), ContextError> {
+        ExecutionContext::store_packet_commitment(self, commitment_path, commitment)
+    }
+
+    async fn delete_packet_commitment(
+        &mut self,
+        commitment_path: &CommitmentPath,
+    ) -> Result<(), ContextError> {
+        ExecutionContext::delete_packet_commitment(self, commitment_path)
+    }
+
+    async fn store_packet_receipt(
+        &mut self,
+        receipt_path: &ReceiptPath,
+        receipt: Receipt,
+    ) -> Result<(), ContextError> {
+        ExecutionContext::store_packet_receipt(self, receipt_path, receipt)
+    }
+
+    async fn store_packet_acknowledgement(
+        &mut self,
+        ack_path: &AckPath,
+        ack_commitment: AcknowledgementCommitment,
+    ) -> Result<(), ContextError> {
+        ExecutionContext::store_packet_acknowledgement(self, ack_path, ack_commitment)
+    }
+
+    async fn delete_packet_acknowledgement(
+        &mut self,
+        ack_path: &AckPath,
+    ) -> Result<(), ContextError> {
+        ExecutionContext::delete_packet_acknowledgement(self, ack_path)
+    }
+
+    async fn store_channel(
+        &mut self,
+        channel_end_path: &ChannelEndPath,
+        channel_end: ChannelEnd,
+    ) -> Result<(), ContextError> {
+        ExecutionContext::store_channel(self, channel_end_path, channel_end)
+    }
+
+    async fn store_channel_upgrade(
+        &mut self,
+        upgrade_path: &ChannelUpgradePath,
+        upgrade: Upgrade,
+    ) -> Result<(), ContextError> {
+        ExecutionContext::store_channel_upgrade(self, upgrade_path, upgrade)
+    }
+
+    async fn store_counterparty_channel_upgrade(
+        &mut self,
+        upgrade_path: &ChannelUpgradePath,
+        upgrade: Upgrade,
+    ) -> Result<(), ContextError> {
+        ExecutionContext::store_counterparty_channel_upgrade(self, upgrade_path, upgrade)
+    }
+
+    async fn delete_channel_upgrade(
+        &mut self,
+        upgrade_path: &ChannelUpgradePath,
+    ) -> Result<(), ContextError> {
+        ExecutionContext::delete_channel_upgrade(self, upgrade_path)
+    }
+
+    async fn store_channel_upgrade_error_receipt(
+        &mut self,
+        upgrade_error_path: &ChannelUpgradeErrorPath,
+        error_receipt: ErrorReceipt,
+    ) -> Result<(), ContextError> {
+        ExecutionContext::store_channel_upgrade_error_receipt(
+            self,
+            upgrade_error_path,
+            error_receipt,
+        )
+    }
+
+    async fn store_next_sequence_send(
+        &mut self,
+        seq_send_path: &SeqSendPath,
+        seq: Sequence,
+    ) -> Result<(), ContextError> {
+        ExecutionContext::store_next_sequence_send(self, seq_send_path, seq)
+    }
+
+    async fn store_next_sequence_recv(
+        &mut self,
+        seq_recv_path: &SeqRecvPath,
+        seq: Sequence,
+    ) -> Result<(), ContextError> {
+        ExecutionContext::store_next_sequence_recv(self, seq_recv_path, seq)
+    }
+
+    async fn store_next_sequence_ack(
+        &mut self,
+        seq_ack_path: &SeqAckPath,
+        seq: Sequence,
+    ) -> Result<(), ContextError> {
+        ExecutionContext::store_next_sequence_ack(self, seq_ack_path, seq)
+    }
+
+    async fn increase_channel_counter(&mut self) -> Result<(), ContextError> {
+        ExecutionContext::increase_channel_counter(self)
+    }
+
+    async fn emit_ibc_event(&mut self, event: IbcEvent) -> Result<(), ContextError> {
+        ExecutionContext::emit_ibc_event(self, event)
+    }
+
+    async fn log_message(&mut self, message: String) -> Result<(), ContextError> {
+        ExecutionContext::log_message(self, message)
+    }
+}
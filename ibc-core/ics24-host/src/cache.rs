@@ -0,0 +1,484 @@
+use core::cell::RefCell;
+use core::time::Duration;
+
+use ibc_core_channel_types::channel::ChannelEnd;
+use ibc_core_channel_types::commitment::{AcknowledgementCommitment, PacketCommitment};
+use ibc_core_channel_types::events::PacketDataEventMode;
+use ibc_core_channel_types::packet::Receipt;
+use ibc_core_channel_types::upgrade::{ErrorReceipt, Upgrade};
+use ibc_core_client_types::params::ClientParams;
+use ibc_core_client_types::Height;
+use ibc_core_commitment_types::commitment::CommitmentPrefix;
+use ibc_core_connection_types::params::ConnectionParams;
+use ibc_core_connection_types::version::Version as ConnectionVersion;
+use ibc_core_connection_types::ConnectionEnd;
+use ibc_core_handler_types::error::ContextError;
+use ibc_core_handler_types::events::IbcEvent;
+use ibc_core_host_types::identifiers::{ChannelId, ClientId, ConnectionId, PortId, Sequence};
+use ibc_core_host_types::path::{
+    AckPath, ChannelEndPath, ChannelUpgradeErrorPath, ChannelUpgradePath, ClientConnectionPath,
+    CommitmentPath, ConnectionPath, ReceiptPath, SeqAckPath, SeqRecvPath, SeqSendPath,
+};
+use ibc_core_host_types::validate::IdentifierRules;
+use ibc_primitives::prelude::*;
+use ibc_primitives::{Signer, Timestamp};
+
+use crate::{
+    ExecutionContext, GasMeter, HostClock, Metrics, TransactionalExecutionContext,
+    ValidationContext, VerifiedProofs,
+};
+
+/// A context which caches the connection ends and channel ends decoded by
+/// the host context it wraps, for the duration of a block.
+///
+/// The handlers read the same connection and channel ends many times over
+/// the messages of a block, e.g. on each packet relayed over a channel,
+/// which the cache decodes only once. The cached ends are dropped whenever
+/// they are written, as well as whenever the writes of the host context are
+/// discarded or rolled back. The host creates the cache at the beginning of
+/// each block, or [clears](Self::clear) it at the end of each block.
+///
+/// The client and consensus states are read by the light clients through the
+/// client context of the host, which the cache passes through as is. Hosts
+/// cache them in their client context with a
+/// [`ClientStateCache`](ibc_core_client_context::cache::ClientStateCache).
+#[derive(Debug)]
+pub struct BlockCache<Ctx> {
+    ctx: Ctx,
+    connections: RefCell<BTreeMap<ConnectionId, ConnectionEnd>>,
+    channels: RefCell<BTreeMap<ChannelEndPath, ChannelEnd>>,
+}
+
+impl<Ctx> BlockCache<Ctx> {
+    pub fn new(ctx: Ctx) -> Self {
+        Self {
+            ctx,
+            connections: RefCell::new(BTreeMap::new()),
+            channels: RefCell::new(BTreeMap::new()),
+        }
+    }
+
+    pub fn inner(&self) -> &Ctx {
+        &self.ctx
+    }
+
+    /// Returns the host context, which the cache does not write to on its
+    /// own, so that none of its writes are lost.
+    pub fn into_inner(self) -> Ctx {
+        self.ctx
+    }
+
+    /// Drops all the cached ends.
+    pub fn clear(&mut self) {
+        self.connections.get_mut().clear();
+        self.channels.get_mut().clear();
+    }
+}
+
+impl<Ctx: TransactionalExecutionContext> TransactionalExecutionContext for BlockCache<Ctx> {
+    fn begin_transaction(&mut self) {
+        self.ctx.begin_transaction();
+    }
+
+    fn commit_transaction(&mut self) -> Result<(), ContextError> {
+        self.ctx.commit_transaction()
+    }
+
+    /// Rolls back the transaction of the host context, along with the ends
+    /// cached since, which may have been read from its writes.
+    fn rollback_transaction(&mut self) {
+        self.ctx.rollback_transaction();
+        self.clear();
+    }
+
+    fn transaction_events(&self) -> Vec<IbcEvent> {
+        self.ctx.transaction_events()
+    }
+}
+
+impl<Ctx: ValidationContext> HostClock for BlockCache<Ctx> {
+    fn host_height(&self) -> Result<Height, ContextError> {
+        self.ctx.host_height()
+    }
+
+    fn host_timestamp(&self) -> Result<Timestamp, ContextError> {
+        self.ctx.host_timestamp()
+    }
+}
+
+impl<Ctx: ValidationContext> ValidationContext for BlockCache<Ctx> {
+    type V = Ctx::V;
+    type HostClientState = Ctx::HostClientState;
+    type HostConsensusState = Ctx::HostConsensusState;
+
+    fn get_client_validation_context(&self) -> &Self::V {
+        self.ctx.get_client_validation_context()
+    }
+
+    fn host_consensus_state(
+        &self,
+        height: &Height,
+    ) -> Result<Self::HostConsensusState, ContextError> {
+        self.ctx.host_consensus_state(height)
+    }
+
+    fn client_counter(&self) -> Result<u64, ContextError> {
+        self.ctx.client_counter()
+    }
+
+    fn client_params(&self) -> ClientParams {
+        self.ctx.client_params()
+    }
+
+    fn connection_end(&self, conn_id: &ConnectionId) -> Result<ConnectionEnd, ContextError> {
+        if let Some(conn_end) = self.connections.borrow().get(conn_id) {
+            return Ok(conn_end.clone());
+        }
+
+        let conn_end = self.ctx.connection_end(conn_id)?;
+
+        self.connections
+            .borrow_mut()
+            .insert(conn_id.clone(), conn_end.clone());
+
+        Ok(conn_end)
+    }
+
+    fn validate_self_client(
+        &self,
+        client_state_of_host_on_counterparty: Self::HostClientState,
+    ) -> Result<(), ContextError> {
+        self.ctx
+            .validate_self_client(client_state_of_host_on_counterparty)
+    }
+
+    fn commitment_prefix(&self) -> CommitmentPrefix {
+        self.ctx.commitment_prefix()
+    }
+
+    fn connection_counter(&self) -> Result<u64, ContextError> {
+        self.ctx.connection_counter()
+    }
+
+    fn connection_params(&self) -> ConnectionParams {
+        self.ctx.connection_params()
+    }
+
+    fn get_compatible_versions(&self) -> Vec<ConnectionVersion> {
+        self.ctx.get_compatible_versions()
+    }
+
+    fn pick_version(
+        &self,
+        counterparty_candidate_versions: &[ConnectionVersion],
+    ) -> Result<ConnectionVersion, ContextError> {
+        self.ctx.pick_version(counterparty_candidate_versions)
+    }
+
+    fn channel_end(&self, channel_end_path: &ChannelEndPath) -> Result<ChannelEnd, ContextError> {
+        if let Some(chan_end) = self.channels.borrow().get(channel_end_path) {
+            return Ok(chan_end.clone());
+        }
+
+        let chan_end = self.ctx.channel_end(channel_end_path)?;
+
+        self.channels
+            .borrow_mut()
+            .insert(channel_end_path.clone(), chan_end.clone());
+
+        Ok(chan_end)
+    }
+
+    fn get_next_sequence_send(
+        &self,
+        seq_send_path: &SeqSendPath,
+    ) -> Result<Sequence, ContextError> {
+        self.ctx.get_next_sequence_send(seq_send_path)
+    }
+
+    fn get_next_sequence_recv(
+        &self,
+        seq_recv_path: &SeqRecvPath,
+    ) -> Result<Sequence, ContextError> {
+        self.ctx.get_next_sequence_recv(seq_recv_path)
+    }
+
+    fn get_next_sequence_ack(&self, seq_ack_path: &SeqAckPath) -> Result<Sequence, ContextError> {
+        self.ctx.get_next_sequence_ack(seq_ack_path)
+    }
+
+    fn get_packet_commitment(
+        &self,
+        commitment_path: &CommitmentPath,
+    ) -> Result<PacketCommitment, ContextError> {
+        self.ctx.get_packet_commitment(commitment_path)
+    }
+
+    fn get_packet_receipt(&self, receipt_path: &ReceiptPath) -> Result<Receipt, ContextError> {
+        self.ctx.get_packet_receipt(receipt_path)
+    }
+
+    fn get_packet_acknowledgement(
+        &self,
+        ack_path: &AckPath,
+    ) -> Result<AcknowledgementCommitment, ContextError> {
+        self.ctx.get_packet_acknowledgement(ack_path)
+    }
+
+    fn channel_upgrade(&self, upgrade_path: &ChannelUpgradePath) -> Result<Upgrade, ContextError> {
+        self.ctx.channel_upgrade(upgrade_path)
+    }
+
+    fn counterparty_channel_upgrade(
+        &self,
+        upgrade_path: &ChannelUpgradePath,
+    ) -> Result<Upgrade, ContextError> {
+        self.ctx.counterparty_channel_upgrade(upgrade_path)
+    }
+
+    fn channel_upgrade_error_receipt(
+        &self,
+        upgrade_error_path: &ChannelUpgradeErrorPath,
+    ) -> Result<ErrorReceipt, ContextError> {
+        self.ctx.channel_upgrade_error_receipt(upgrade_error_path)
+    }
+
+    fn has_inflight_packets(
+        &self,
+        channel_end_path: &ChannelEndPath,
+    ) -> Result<bool, ContextError> {
+        self.ctx.has_inflight_packets(channel_end_path)
+    }
+
+    fn upgrade_timeout(&self) -> Duration {
+        self.ctx.upgrade_timeout()
+    }
+
+    fn max_packet_data_size(&self) -> Option<usize> {
+        self.ctx.max_packet_data_size()
+    }
+
+    fn packet_data_event_mode(&self) -> PacketDataEventMode {
+        self.ctx.packet_data_event_mode()
+    }
+
+    fn metrics(&self) -> &dyn Metrics {
+        self.ctx.metrics()
+    }
+
+    fn gas_meter(&self) -> &dyn GasMeter {
+        self.ctx.gas_meter()
+    }
+
+    fn verified_proofs(&self) -> Option<&VerifiedProofs> {
+        self.ctx.verified_proofs()
+    }
+
+    fn channel_counter(&self) -> Result<u64, ContextError> {
+        self.ctx.channel_counter()
+    }
+
+    fn max_expected_time_per_block(&self) -> Duration {
+        self.ctx.max_expected_time_per_block()
+    }
+
+    fn block_delay(&self, delay_period_time: &Duration) -> u64 {
+        self.ctx.block_delay(delay_period_time)
+    }
+
+    fn validate_message_signer(&self, signer: &Signer) -> Result<(), ContextError> {
+        self.ctx.validate_message_signer(signer)
+    }
+
+    fn validate_connection_creator(
+        &self,
+        signer: &Signer,
+        client_id: &ClientId,
+    ) -> Result<(), ContextError> {
+        self.ctx.validate_connection_creator(signer, client_id)
+    }
+
+    fn identifier_rules(&self) -> IdentifierRules {
+        self.ctx.identifier_rules()
+    }
+
+    fn validate_channel_close(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<(), ContextError> {
+        self.ctx.validate_channel_close(port_id, channel_id)
+    }
+}
+
+impl<Ctx: ExecutionContext> ExecutionContext for BlockCache<Ctx> {
+    type E = Ctx::E;
+
+    fn get_client_execution_context(&mut self) -> &mut Self::E {
+        self.ctx.get_client_execution_context()
+    }
+
+    fn increase_client_counter(&mut self) -> Result<(), ContextError> {
+        self.ctx.increase_client_counter()
+    }
+
+    fn store_client_params(&mut self, params: ClientParams) -> Result<(), ContextError> {
+        self.ctx.store_client_params(params)
+    }
+
+    fn store_connection(
+        &mut self,
+        connection_path: &ConnectionPath,
+        connection_end: ConnectionEnd,
+    ) -> Result<(), ContextError> {
+        self.connections.get_mut().remove(&connection_path.0);
+        self.ctx.store_connection(connection_path, connection_end)
+    }
+
+    fn store_connection_to_client(
+        &mut self,
+        client_connection_path: &ClientConnectionPath,
+        conn_id: ConnectionId,
+    ) -> Result<(), ContextError> {
+        self.ctx
+            .store_connection_to_client(client_connection_path, conn_id)
+    }
+
+    fn increase_connection_counter(&mut self) -> Result<(), ContextError> {
+        self.ctx.increase_connection_counter()
+    }
+
+    fn store_packet_commitment(
+        &mut self,
+        commitment_path: &CommitmentPath,
+        commitment: PacketCommitment,
+    ) -> Result<(), ContextError> {
+        self.ctx
+            .store_packet_commitment(commitment_path, commitment)
+    }
+
+    fn delete_packet_commitment(
+        &mut self,
+        commitment_path: &CommitmentPath,
+    ) -> Result<(), ContextError> {
+        self.ctx.delete_packet_commitment(commitment_path)
+    }
+
+    fn store_packet_receipt(
+        &mut self,
+        receipt_path: &ReceiptPath,
+        receipt: Receipt,
+    ) -> Result<(), ContextError> {
+        self.ctx.store_packet_receipt(receipt_path, receipt)
+    }
+
+    fn store_packet_acknowledgement(
+        &mut self,
+        ack_path: &AckPath,
+        ack_commitment: AcknowledgementCommitment,
+    ) -> Result<(), ContextError> {
+        self.ctx
+            .store_packet_acknowledgement(ack_path, ack_commitment)
+    }
+
+    fn delete_packet_acknowledgement(&mut self, ack_path: &AckPath) -> Result<(), ContextError> {
+        self.ctx.delete_packet_acknowledgement(ack_path)
+    }
+
+    fn store_channel(
+        &mut self,
+        channel_end_path: &ChannelEndPath,
+        channel_end: ChannelEnd,
+    ) -> Result<(), ContextError> {
+        self.channels.get_mut().remove(channel_end_path);
+        self.ctx.store_channel(channel_end_path, channel_end)
+    }
+
+    fn store_channel_upgrade(
+        &mut self,
+        upgrade_path: &ChannelUpgradePath,
+        upgrade: Upgrade,
+    ) -> Result<(), ContextError> {
+        self.ctx.store_channel_upgrade(upgrade_path, upgrade)
+    }
+
+    fn store_counterparty_channel_upgrade(
+        &mut self,
+        upgrade_path: &ChannelUpgradePath,
+        upgrade: Upgrade,
+    ) -> Result<(), ContextError> {
+        self.ctx
+            .store_counterparty_channel_upgrade(upgrade_path, upgrade)
+    }
+
+    fn delete_channel_upgrade(
+        &mut self,
+        upgrade_path: &ChannelUpgradePath,
+    ) -> Result<(), ContextError> {
+        self.ctx.delete_channel_upgrade(upgrade_path)
+    }
+
+    fn store_channel_upgrade_error_receipt(
+        &mut self,
+        upgrade_error_path: &ChannelUpgradeErrorPath,
+        error_receipt: ErrorReceipt,
+    ) -> Result<(), ContextError> {
+        self.ctx
+            .store_channel_upgrade_error_receipt(upgrade_error_path, error_receipt)
+    }
+
+    fn store_next_sequence_send(
+        &mut self,
+        seq_send_path: &SeqSendPath,
+        seq: Sequence,
+    ) -> Result<(), ContextError> {
+        self.ctx.store_next_sequence_send(seq_send_path, seq)
+    }
+
+    fn store_next_sequence_recv(
+        &mut self,
+        seq_recv_path: &SeqRecvPath,
+        seq: Sequence,
+    ) -> Result<(), ContextError> {
+        self.ctx.store_next_sequence_recv(seq_recv_path, seq)
+    }
+
+    fn store_next_sequence_ack(
+        &mut self,
+        seq_ack_path: &SeqAckPath,
+        seq: Sequence,
+    ) -> Result<(), ContextError> {
+        self.ctx.store_next_sequence_ack(seq_ack_path, seq)
+    }
+
+    fn increase_channel_counter(&mut self) -> Result<(), ContextError> {
+        self.ctx.increase_channel_counter()
+    }
+
+    fn emit_ibc_event(&mut self, event: IbcEvent) -> Result<(), ContextError> {
+        self.ctx.emit_ibc_event(event)
+    }
+
+    fn log_message(&mut self, message: String) -> Result<(), ContextError> {
+        self.ctx.log_message(message)
+    }
+
+    fn stage_writes(&mut self) -> Result<(), ContextError> {
+        self.ctx.stage_writes()
+    }
+
+    fn commit_writes(&mut self) -> Result<(), ContextError> {
+        self.ctx.commit_writes()
+    }
+
+    /// Discards the staged writes of the host context, along with the ends
+    /// cached since, which may have been read from them.
+    fn discard_writes(&mut self) {
+        self.ctx.discard_writes();
+        self.clear();
+    }
+
+    fn set_verified_proofs(&mut self, verified_proofs: Option<VerifiedProofs>) {
+        self.ctx.set_verified_proofs(verified_proofs);
+    }
+}
@@ -2,28 +2,38 @@ use core::time::Duration;
 
 use ibc_core_channel_types::channel::ChannelEnd;
 use ibc_core_channel_types::commitment::{AcknowledgementCommitment, PacketCommitment};
+use ibc_core_channel_types::events::PacketDataEventMode;
 use ibc_core_channel_types::packet::Receipt;
+use ibc_core_channel_types::upgrade::{ErrorReceipt, Upgrade, DEFAULT_UPGRADE_TIMEOUT};
 use ibc_core_client_context::prelude::*;
+use ibc_core_client_types::error::ClientError;
+use ibc_core_client_types::params::ClientParams;
 use ibc_core_client_types::Height;
 use ibc_core_commitment_types::commitment::CommitmentPrefix;
+use ibc_core_connection_types::params::ConnectionParams;
 use ibc_core_connection_types::version::{pick_version, Version as ConnectionVersion};
 use ibc_core_connection_types::ConnectionEnd;
 use ibc_core_handler_types::error::ContextError;
 use ibc_core_handler_types::events::IbcEvent;
-use ibc_core_host_types::identifiers::{ConnectionId, Sequence};
+use ibc_core_host_types::identifiers::{ChannelId, ClientId, ConnectionId, PortId, Sequence};
 use ibc_core_host_types::path::{
-    AckPath, ChannelEndPath, ClientConnectionPath, CommitmentPath, ConnectionPath, ReceiptPath,
-    SeqAckPath, SeqRecvPath, SeqSendPath,
+    AckPath, ChannelEndPath, ChannelUpgradeErrorPath, ChannelUpgradePath, ClientConnectionPath,
+    CommitmentPath, ConnectionPath, ReceiptPath, SeqAckPath, SeqRecvPath, SeqSendPath,
 };
+use ibc_core_host_types::validate::IdentifierRules;
 use ibc_primitives::prelude::*;
-use ibc_primitives::{Signer, Timestamp};
+use ibc_primitives::Signer;
 
 use crate::utils::calculate_block_delay;
+use crate::{GasMeter, Metrics, NoopGasMeter, NoopMetrics, VerifiedProofs};
 
 /// Context to be implemented by the host that provides all "read-only" methods.
 ///
 /// Trait used for the top-level `validate` entrypoint in the `ibc-core` crate.
-pub trait ValidationContext {
+///
+/// The current height and timestamp of the host are read from its
+/// [`HostClock`], which the light client contexts of the host share.
+pub trait ValidationContext: HostClock {
     type V: ClientValidationContext;
     /// The client state type for the host chain.
     type HostClientState: ClientStateValidation<Self::V>;
@@ -33,12 +43,6 @@ pub trait ValidationContext {
     /// Retrieve the context that implements all clients' `ValidationContext`.
     fn get_client_validation_context(&self) -> &Self::V;
 
-    /// Returns the current height of the local chain.
-    fn host_height(&self) -> Result<Height, ContextError>;
-
-    /// Returns the current timestamp of the local chain.
-    fn host_timestamp(&self) -> Result<Timestamp, ContextError>;
-
     /// Returns the `ConsensusState` of the host (local) chain at a specific height.
     fn host_consensus_state(
         &self,
@@ -50,6 +54,15 @@ pub trait ValidationContext {
     /// `ExecutionContext::increase_client_counter`.
     fn client_counter(&self) -> Result<u64, ContextError>;
 
+    /// Returns the parameters of the client module, e.g. as set by the
+    /// governance of the host with
+    /// [`store_client_params`](ExecutionContext::store_client_params).
+    ///
+    /// Defaults to allowing all the client types.
+    fn client_params(&self) -> ClientParams {
+        ClientParams::default()
+    }
+
     /// Returns the ConnectionEnd for the given identifier `conn_id`.
     fn connection_end(&self, conn_id: &ConnectionId) -> Result<ConnectionEnd, ContextError>;
 
@@ -61,7 +74,9 @@ pub trait ValidationContext {
     /// requirements](https://github.com/cosmos/ibc/tree/main/spec/core/ics-024-host-requirements#client-state-validation)
     ///
     /// Additionally, implementations specific to individual chains can be found
-    /// in the `ibc-core/ics24-host` module.
+    /// in the `ibc-core/ics24-host` module. Hosts usually implement this by
+    /// delegating to a [`SelfClientValidation`](crate::SelfClientValidation)
+    /// policy matching their consensus.
     fn validate_self_client(
         &self,
         client_state_of_host_on_counterparty: Self::HostClientState,
@@ -73,20 +88,47 @@ pub trait ValidationContext {
     /// Returns a counter on how many connections have been created thus far.
     fn connection_counter(&self) -> Result<u64, ContextError>;
 
+    /// Returns the parameters of the connection module, which the connection
+    /// and packet handlers read the host's compatible versions and maximum
+    /// expected time per block from.
+    ///
+    /// Defaults to the values of
+    /// [`max_expected_time_per_block`](Self::max_expected_time_per_block) and
+    /// [`get_compatible_versions`](Self::get_compatible_versions). Hosts
+    /// keeping these parameters in their store, e.g. to have them set by
+    /// governance, override this instead.
+    fn connection_params(&self) -> ConnectionParams {
+        ConnectionParams::new(
+            self.max_expected_time_per_block(),
+            self.get_compatible_versions(),
+        )
+    }
+
     /// Function required by ICS-03. Returns the list of all possible versions that the connection
     /// handshake protocol supports.
+    ///
+    /// Defaults to [`ConnectionVersion::compatibles`]. Hosts negotiating
+    /// custom connection features override it to advertise their own
+    /// versions, built with [`ConnectionVersion::new`].
     fn get_compatible_versions(&self) -> Vec<ConnectionVersion> {
         ConnectionVersion::compatibles()
     }
 
     /// Function required by ICS-03. Returns one version out of the supplied list of versions, which the
     /// connection handshake protocol prefers.
+    ///
+    /// Defaults to [`pick_version`], which selects the common version with
+    /// the lowest identifier. Hosts may override it with their own strategy,
+    /// e.g. choosing among the candidates returned by
+    /// [`common_versions`](ibc_core_connection_types::version::common_versions).
+    /// The `ConnOpenTry` handler rejects any picked version that is not among
+    /// the compatible versions of [`connection_params`](Self::connection_params).
     fn pick_version(
         &self,
         counterparty_candidate_versions: &[ConnectionVersion],
     ) -> Result<ConnectionVersion, ContextError> {
         let version = pick_version(
-            &self.get_compatible_versions(),
+            &self.connection_params().compatible_versions,
             counterparty_candidate_versions,
         )?;
         Ok(version)
@@ -121,23 +163,158 @@ pub trait ValidationContext {
         ack_path: &AckPath,
     ) -> Result<AcknowledgementCommitment, ContextError>;
 
+    /// Returns the upgrade proposed for the channel end under `upgrade_path`,
+    /// as stored during the channel upgrade handshake.
+    fn channel_upgrade(&self, upgrade_path: &ChannelUpgradePath) -> Result<Upgrade, ContextError>;
+
+    /// Returns the upgrade of the counterparty of the channel end under
+    /// `upgrade_path`, as recorded once both ends of the channel started
+    /// flushing.
+    fn counterparty_channel_upgrade(
+        &self,
+        upgrade_path: &ChannelUpgradePath,
+    ) -> Result<Upgrade, ContextError>;
+
+    /// Returns the error receipt written when the last upgrade attempt of the
+    /// channel end under `upgrade_error_path` was aborted.
+    fn channel_upgrade_error_receipt(
+        &self,
+        upgrade_error_path: &ChannelUpgradeErrorPath,
+    ) -> Result<ErrorReceipt, ContextError>;
+
+    /// Returns `true` if the channel end has packet commitments which have
+    /// neither been acknowledged nor timed out yet.
+    ///
+    /// An upgrading channel end only completes its flush once this returns
+    /// `false`.
+    fn has_inflight_packets(&self, channel_end_path: &ChannelEndPath)
+        -> Result<bool, ContextError>;
+
+    /// Returns the period, relative to the host timestamp, after which the
+    /// counterparty stops accepting to move on with a channel upgrade this
+    /// host agreed to.
+    ///
+    /// Defaults to [`DEFAULT_UPGRADE_TIMEOUT`].
+    fn upgrade_timeout(&self) -> Duration {
+        DEFAULT_UPGRADE_TIMEOUT
+    }
+
+    /// Returns the maximum size, in bytes, of the data of the packets this
+    /// host sends and receives, and of the acknowledgements of the packets it
+    /// sent, if any.
+    ///
+    /// This bounds the growth of the state and of the commitments of the host.
+    /// Defaults to `None`, so that packets of any size are accepted.
+    fn max_packet_data_size(&self) -> Option<usize> {
+        None
+    }
+
+    /// Returns how the packet data is carried by the `SendPacket` and
+    /// `ReceivePacket` events this host emits.
+    ///
+    /// Hosts which send or receive packets with large or sensitive data may
+    /// emit the hash of the packet data instead. Defaults to
+    /// [`PacketDataEventMode::Full`], which emits the full packet data.
+    fn packet_data_event_mode(&self) -> PacketDataEventMode {
+        PacketDataEventMode::Full
+    }
+
+    /// Returns the [`Metrics`] hooks the core handlers call.
+    ///
+    /// Defaults to [`NoopMetrics`], which records nothing.
+    fn metrics(&self) -> &dyn Metrics {
+        &NoopMetrics
+    }
+
+    /// Returns the [`GasMeter`] the core handlers charge the gas of the IBC
+    /// operations to.
+    ///
+    /// Defaults to [`NoopGasMeter`], which charges nothing.
+    fn gas_meter(&self) -> &dyn GasMeter {
+        &NoopGasMeter
+    }
+
+    /// Returns the packet proofs which the parallel dispatch entrypoint
+    /// verified ahead of the messages of the batch, if any.
+    ///
+    /// Hosts opting into the parallel verification return the proofs last
+    /// set by [`ExecutionContext::set_verified_proofs`].
+    fn verified_proofs(&self) -> Option<&VerifiedProofs> {
+        None
+    }
+
     /// Returns a counter on the number of channel ids have been created thus far.
     /// The value of this counter should increase only via method
     /// `ExecutionContext::increase_channel_counter`.
     fn channel_counter(&self) -> Result<u64, ContextError>;
 
     /// Returns the maximum expected time per block
+    ///
+    /// This is what the block-based component of connection delays is
+    /// derived from (see [`block_delay`](Self::block_delay)), so hosts should
+    /// return an upper bound of their block time, as the
+    /// `max_expected_time_per_block` parameter of ibc-go.
     fn max_expected_time_per_block(&self) -> Duration;
 
     /// Calculates the block delay period using the connection's delay period and the maximum
-    /// expected time per block.
+    /// expected time per block, rounding up.
     fn block_delay(&self, delay_period_time: &Duration) -> u64 {
-        calculate_block_delay(delay_period_time, &self.max_expected_time_per_block())
+        calculate_block_delay(
+            delay_period_time,
+            &self.connection_params().max_expected_time_per_block,
+        )
     }
 
     /// Validates the `signer` field of IBC messages, which represents the address
     /// of the user/relayer that signed the given message.
+    ///
+    /// Hosts may check it is a well-formed address of their chain with
+    /// [`Signer::validate`], e.g. against their Bech32 prefix, to reject
+    /// malformed addresses before the message processing.
     fn validate_message_signer(&self, signer: &Signer) -> Result<(), ContextError>;
+
+    /// Validates that `signer` may create a connection on top of the client
+    /// `client_id`, by submitting a `ConnectionOpenInit` or a
+    /// `ConnectionOpenTry` message.
+    ///
+    /// Anyone may create connections by default. Permissioned hosts override
+    /// this to restrict connection creation, e.g. to governance or to an
+    /// allowlisted set of relayers, typically by returning
+    /// [`ConnectionError::UnauthorizedConnectionCreation`](ibc_core_connection_types::error::ConnectionError::UnauthorizedConnectionCreation).
+    fn validate_connection_creator(
+        &self,
+        _signer: &Signer,
+        _client_id: &ClientId,
+    ) -> Result<(), ContextError> {
+        Ok(())
+    }
+
+    /// Returns the rules the identifiers chosen by the handshake messages,
+    /// i.e. the identifiers of the counterparty and the ports of the channel,
+    /// are validated against when opening connections and channels.
+    ///
+    /// Defaults to [`IdentifierRules::ICS24`], which the identifiers satisfy
+    /// once parsed. Hosts with stricter identifier conventions override this
+    /// to enforce them during the validation of the handshakes.
+    fn identifier_rules(&self) -> IdentifierRules {
+        IdentifierRules::ICS24
+    }
+
+    /// Validates that the channel end (`port_id`, `channel_id`) may be closed
+    /// by submitting a `ChannelCloseInit` message.
+    ///
+    /// This is checked on top of the application callbacks, and lets hosts
+    /// set a closing policy per port or per channel, e.g. allowing the closure
+    /// of interchain accounts channels while rejecting the one of transfer
+    /// channels, typically by returning
+    /// [`ChannelError::UnauthorizedChannelClose`](ibc_core_channel_types::error::ChannelError::UnauthorizedChannelClose).
+    fn validate_channel_close(
+        &self,
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+    ) -> Result<(), ContextError> {
+        Ok(())
+    }
 }
 
 /// Context to be implemented by the host that provides all "write-only" methods.
@@ -154,6 +331,12 @@ pub trait ExecutionContext: ValidationContext {
     /// Should never fail.
     fn increase_client_counter(&mut self) -> Result<(), ContextError>;
 
+    /// Stores the parameters of the client module, as updated by the
+    /// governance of the host. Implement only if the host stores them.
+    fn store_client_params(&mut self, _params: ClientParams) -> Result<(), ContextError> {
+        Err(ClientError::ParamsUnsupported.into())
+    }
+
     /// Stores the given connection_end at path
     fn store_connection(
         &mut self,
@@ -210,6 +393,36 @@ pub trait ExecutionContext: ValidationContext {
         channel_end: ChannelEnd,
     ) -> Result<(), ContextError>;
 
+    /// Stores the upgrade proposed for the channel end under `upgrade_path`
+    fn store_channel_upgrade(
+        &mut self,
+        upgrade_path: &ChannelUpgradePath,
+        upgrade: Upgrade,
+    ) -> Result<(), ContextError>;
+
+    /// Stores the upgrade of the counterparty of the channel end under
+    /// `upgrade_path`
+    fn store_counterparty_channel_upgrade(
+        &mut self,
+        upgrade_path: &ChannelUpgradePath,
+        upgrade: Upgrade,
+    ) -> Result<(), ContextError>;
+
+    /// Deletes both the upgrade and the counterparty upgrade of the channel
+    /// end under `upgrade_path`, once the upgrade handshake is over
+    fn delete_channel_upgrade(
+        &mut self,
+        upgrade_path: &ChannelUpgradePath,
+    ) -> Result<(), ContextError>;
+
+    /// Stores the error receipt of an aborted upgrade of the channel end
+    /// under `upgrade_error_path`, overwriting the previous one
+    fn store_channel_upgrade_error_receipt(
+        &mut self,
+        upgrade_error_path: &ChannelUpgradeErrorPath,
+        error_receipt: ErrorReceipt,
+    ) -> Result<(), ContextError>;
+
     /// Stores the given `nextSequenceSend` number at the given store path
     fn store_next_sequence_send(
         &mut self,
@@ -241,6 +454,58 @@ pub trait ExecutionContext: ValidationContext {
 
     /// Log the given message.
     fn log_message(&mut self, message: String) -> Result<(), ContextError>;
+
+    /// Begins staging the writes of a message, which the host may buffer,
+    /// e.g. in a write batch of its merkleized database, until they are
+    /// committed together by [`commit_writes`](Self::commit_writes).
+    ///
+    /// The entrypoints stage the writes of each message they execute, which
+    /// lets hosts with a high cost per write amortize it, and order the
+    /// writes of the batch as they see fit, e.g. by key. Does nothing by
+    /// default, the writes being then made one by one.
+    fn stage_writes(&mut self) -> Result<(), ContextError> {
+        Ok(())
+    }
+
+    /// Commits the writes staged since [`stage_writes`](Self::stage_writes)
+    /// in one batch, once the message is executed.
+    fn commit_writes(&mut self) -> Result<(), ContextError> {
+        Ok(())
+    }
+
+    /// Discards the writes staged since [`stage_writes`](Self::stage_writes),
+    /// as the message failed to execute.
+    fn discard_writes(&mut self) {}
+
+    /// Sets the proofs verified by the parallel dispatch entrypoint, which
+    /// are cleared once the batch is dispatched.
+    ///
+    /// Ignored by default, in which case the core handlers verify each proof
+    /// on their own.
+    fn set_verified_proofs(&mut self, _verified_proofs: Option<VerifiedProofs>) {}
+
+    /// Runs `f` with its writes staged, which are committed in one batch if
+    /// `f` succeeds and discarded otherwise.
+    fn with_staged_writes<T>(
+        &mut self,
+        f: impl FnOnce(&mut Self) -> Result<T, ContextError>,
+    ) -> Result<T, ContextError>
+    where
+        Self: Sized,
+    {
+        self.stage_writes()?;
+
+        match f(self) {
+            Ok(value) => {
+                self.commit_writes()?;
+                Ok(value)
+            }
+            Err(e) => {
+                self.discard_writes();
+                Err(e)
+            }
+        }
+    }
 }
 
 /// Convenient type alias for `ClientStateRef`, providing access to client
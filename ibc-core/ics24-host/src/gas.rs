@@ -0,0 +1,74 @@
+use ibc_core_commitment_types::commitment::CommitmentProofBytes;
+use ibc_core_handler_types::error::GasError;
+use ibc_core_host_types::identifiers::ClientId;
+use ibc_primitives::proto::Any;
+
+use crate::ProofKind;
+
+/// The operations the core handlers charge gas for.
+#[derive(Clone, Copy, Debug)]
+pub enum GasOperation<'a> {
+    /// Verifying a proof of the given kind against the state of the
+    /// counterparty chain
+    ProofVerification {
+        kind: ProofKind,
+        proof: &'a CommitmentProofBytes,
+    },
+    /// Verifying a header or a misbehaviour submitted to a client, which
+    /// typically involves checking the signatures of the counterparty
+    /// validators
+    ClientMessageVerification {
+        client_id: &'a ClientId,
+        client_message: &'a Any,
+    },
+    /// Writing a state to, or deleting one from, the IBC store
+    StoreWrite,
+}
+
+/// Meter charging the gas of the IBC operations, for the hosts with their own
+/// gas model, e.g. CosmWasm gas, Substrate weights or Solana compute units.
+///
+/// The core handlers charge the operations right before performing them, in
+/// the same order on every node, so that the gas consumed by a message is
+/// deterministic. Both validation and execution charge gas, hence the meter
+/// takes `&self` and must track the consumed gas with interior mutability.
+/// The states the light clients write through their own contexts are not
+/// charged as store writes.
+pub trait GasMeter {
+    /// Charges the gas of the given operation, failing if it exceeds the gas
+    /// limit of the host, in which case the message fails to be processed.
+    fn charge(&self, operation: GasOperation<'_>) -> Result<(), GasError>;
+}
+
+/// The [`GasMeter`] of the hosts which don't meter gas.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct NoopGasMeter;
+
+impl GasMeter for NoopGasMeter {
+    fn charge(&self, _operation: GasOperation<'_>) -> Result<(), GasError> {
+        Ok(())
+    }
+}
+
+/// The number of operations of each kind charged to a [`GasMeter`], as
+/// recorded by an [`OverlayContext`](crate::OverlayContext), e.g. for the
+/// hosts to estimate the gas of a simulated message with their own gas model.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct GasUsage {
+    pub proof_verifications: u64,
+    pub client_message_verifications: u64,
+    pub store_writes: u64,
+}
+
+impl GasUsage {
+    /// Records the charge of the given operation.
+    pub fn record(&mut self, operation: &GasOperation<'_>) {
+        match operation {
+            GasOperation::ProofVerification { .. } => self.proof_verifications += 1,
+            GasOperation::ClientMessageVerification { .. } => {
+                self.client_message_verifications += 1
+            }
+            GasOperation::StoreWrite => self.store_writes += 1,
+        }
+    }
+}
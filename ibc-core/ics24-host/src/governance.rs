@@ -0,0 +1,14 @@
+use ibc_primitives::Signer;
+
+/// Authority of the host over the governance operations of IBC, e.g. the
+/// updates of the parameters of the IBC modules, the recovery of the clients
+/// and the scheduling of the upgrades.
+///
+/// Hosts bridge their own governance to IBC by authorizing the signers of
+/// the governance messages, typically the account of their governance
+/// module, or the admin of a contract.
+pub trait Authority {
+    /// Returns `true` if the given signer is authorized to perform the
+    /// governance operations.
+    fn is_authorized(&self, signer: &Signer) -> bool;
+}
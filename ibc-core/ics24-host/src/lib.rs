@@ -14,13 +14,47 @@
     rust_2018_idioms
 )]
 
+extern crate alloc;
+
 #[cfg(any(test, feature = "std"))]
 extern crate std;
 
 pub(crate) mod utils;
 
+mod cache;
+pub use cache::*;
+
 mod context;
 pub use context::*;
+#[doc(inline)]
+pub use ibc_core_client_context::HostClock;
+
+mod gas;
+pub use gas::*;
+
+mod governance;
+pub use governance::*;
+
+mod metrics;
+pub use metrics::*;
+
+mod migration;
+pub use migration::*;
+
+mod profiling;
+pub use profiling::*;
+
+mod self_client;
+pub use self_client::*;
+
+mod transaction;
+pub use transaction::*;
+
+mod verified_proofs;
+pub use verified_proofs::*;
+
+#[cfg(feature = "async")]
+pub mod async_context;
 
 /// Re-exports ICS-24 data structures from `ibc-core-host-types` crate.
 pub mod types {
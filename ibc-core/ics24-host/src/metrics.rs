@@ -0,0 +1,103 @@
+use ibc_core_client_types::Height;
+use ibc_core_host_types::identifiers::{ChannelId, ClientId, ConnectionId, PortId, Sequence};
+
+/// The steps of the connection and channel handshakes.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum HandshakeStep {
+    ConnectionOpenInit,
+    ConnectionOpenTry,
+    ConnectionOpenAck,
+    ConnectionOpenConfirm,
+    ChannelOpenInit,
+    ChannelOpenTry,
+    ChannelOpenAck,
+    ChannelOpenConfirm,
+    ChannelCloseInit,
+    ChannelCloseConfirm,
+}
+
+/// The kinds of the states whose proofs the core handlers verify.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ProofKind {
+    ClientState,
+    ConsensusState,
+    Connection,
+    Channel,
+    ChannelUpgrade,
+    ChannelUpgradeError,
+    PacketCommitment,
+    PacketAcknowledgement,
+    /// A packet receipt, or its absence
+    PacketReceipt,
+    NextSequenceRecv,
+}
+
+/// Hooks called by the core handlers for the host to record metrics, e.g.
+/// as Prometheus counters and histograms or as chain-native telemetry.
+///
+/// All of them default to doing nothing. The packet, client and handshake
+/// hooks are called once the corresponding message is executed, while the
+/// proof verification hooks are called during validation, hence they all
+/// take `&self`. The packet hooks are only called for the packets sent over
+/// channels.
+pub trait Metrics {
+    /// Called when a packet is sent.
+    fn packet_sent(&self, _port_id: &PortId, _channel_id: &ChannelId, _sequence: Sequence) {}
+
+    /// Called when a packet is received, but not when a packet which was
+    /// already received is relayed again.
+    fn packet_received(&self, _port_id: &PortId, _channel_id: &ChannelId, _sequence: Sequence) {}
+
+    /// Called when a packet sent is acknowledged.
+    fn packet_acknowledged(&self, _port_id: &PortId, _channel_id: &ChannelId, _sequence: Sequence) {
+    }
+
+    /// Called when a packet sent times out.
+    fn packet_timed_out(&self, _port_id: &PortId, _channel_id: &ChannelId, _sequence: Sequence) {}
+
+    /// Called when a client is updated to the given height.
+    fn client_updated(&self, _client_id: &ClientId, _height: Height) {}
+
+    /// Called when a step of a connection handshake is executed.
+    fn connection_handshake_step(&self, _step: HandshakeStep, _conn_id: &ConnectionId) {}
+
+    /// Called when a step of a channel handshake is executed.
+    fn channel_handshake_step(
+        &self,
+        _step: HandshakeStep,
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+    ) {
+    }
+
+    /// Called right before a proof is verified, e.g. to start a timer.
+    fn proof_verification_started(&self, _kind: ProofKind) {}
+
+    /// Called right after a proof is verified, e.g. to record the
+    /// verification time since [`Metrics::proof_verification_started`].
+    fn proof_verification_finished(&self, _kind: ProofKind, _success: bool) {}
+}
+
+impl dyn Metrics + '_ {
+    /// Verifies a proof with `verify`, calling the proof verification hooks
+    /// around it.
+    pub fn observe_proof_verification<T, E>(
+        &self,
+        kind: ProofKind,
+        verify: impl FnOnce() -> Result<T, E>,
+    ) -> Result<T, E> {
+        self.proof_verification_started(kind);
+
+        let result = verify();
+
+        self.proof_verification_finished(kind, result.is_ok());
+
+        result
+    }
+}
+
+/// The [`Metrics`] of the hosts which don't record any.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct NoopMetrics;
+
+impl Metrics for NoopMetrics {}
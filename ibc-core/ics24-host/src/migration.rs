@@ -0,0 +1,134 @@
+use ibc_core_handler_types::error::{ContextError, MigrationError};
+use ibc_primitives::prelude::*;
+
+use crate::ExecutionContext;
+
+/// The version of the IBC stores which were never migrated, including the
+/// stores created before their hosts tracked the version of their layout.
+pub const INITIAL_STORE_VERSION: u64 = 1;
+
+/// Context tracking the version of the layout of the IBC store, which is
+/// bumped by each of the migrations run by a [`Migrator`].
+pub trait MigrationContext: ExecutionContext {
+    /// Returns the version of the layout of the IBC store, or `None` if it was
+    /// never set, in which case the store is at the [`INITIAL_STORE_VERSION`].
+    fn store_version(&self) -> Result<Option<u64>, ContextError>;
+
+    /// Sets the version of the layout of the IBC store.
+    fn set_store_version(&mut self, version: u64) -> Result<(), ContextError>;
+}
+
+/// Migration of the IBC store from a version of its layout to the next one,
+/// e.g. re-keying the states under new paths or storing new parameters.
+///
+/// A migration failing halfway through runs again on the next attempt, unless
+/// the host reverts its writes, hence should tolerate the states it already
+/// migrated.
+pub trait Migration<Ctx> {
+    fn migrate(&self, ctx: &mut Ctx) -> Result<(), ContextError>;
+}
+
+impl<Ctx, F> Migration<Ctx> for F
+where
+    F: Fn(&mut Ctx) -> Result<(), ContextError>,
+{
+    fn migrate(&self, ctx: &mut Ctx) -> Result<(), ContextError> {
+        self(ctx)
+    }
+}
+
+/// Runs the registered migrations of the IBC store, from its current version
+/// up to the latest one.
+///
+/// Hosts register the migrations shipped with each release of ibc-rs along
+/// with their own ones, and run the migrator on upgrade. Running it again is a
+/// no-op, since each migration bumps the store version once it succeeds.
+pub struct Migrator<Ctx> {
+    /// The migrations, indexed by the store version they migrate from.
+    migrations: BTreeMap<u64, Box<dyn Migration<Ctx>>>,
+}
+
+impl<Ctx> Default for Migrator<Ctx> {
+    fn default() -> Self {
+        Self {
+            migrations: BTreeMap::new(),
+        }
+    }
+}
+
+impl<Ctx: MigrationContext> Migrator<Ctx> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers the migration of the store from the given version to the
+    /// next one.
+    pub fn register(
+        &mut self,
+        from_version: u64,
+        migration: impl Migration<Ctx> + 'static,
+    ) -> Result<(), MigrationError> {
+        if from_version < INITIAL_STORE_VERSION {
+            return Err(MigrationError::InvalidStoreVersion {
+                version: from_version,
+            });
+        }
+
+        if self.migrations.contains_key(&from_version) {
+            return Err(MigrationError::DuplicateMigration { from_version });
+        }
+
+        self.migrations.insert(from_version, Box::new(migration));
+
+        Ok(())
+    }
+
+    /// Returns the store version reached once all the registered migrations
+    /// have run.
+    pub fn latest_version(&self) -> u64 {
+        self.migrations
+            .keys()
+            .next_back()
+            .map_or(INITIAL_STORE_VERSION, |from_version| from_version + 1)
+    }
+
+    /// Sets the store version of a new store, e.g. at genesis, to the latest
+    /// version, so that none of the migrations run on it.
+    pub fn init(&self, ctx: &mut Ctx) -> Result<(), ContextError> {
+        ctx.set_store_version(self.latest_version())
+    }
+
+    /// Runs the pending migrations in order, and returns the store version
+    /// reached.
+    ///
+    /// Fails if the store is newer than the latest version, e.g. when a host
+    /// downgrades ibc-rs, or if a migration is missing along the way.
+    pub fn migrate(&self, ctx: &mut Ctx) -> Result<u64, ContextError> {
+        let latest_version = self.latest_version();
+        let mut version = ctx.store_version()?.unwrap_or(INITIAL_STORE_VERSION);
+
+        if version > latest_version {
+            return Err(MigrationError::UnsupportedStoreVersion {
+                version,
+                latest_version,
+            }
+            .into());
+        }
+
+        while version < latest_version {
+            let migration =
+                self.migrations
+                    .get(&version)
+                    .ok_or(MigrationError::MissingMigration {
+                        from_version: version,
+                    })?;
+
+            migration.migrate(ctx)?;
+
+            version += 1;
+            ctx.set_store_version(version)?;
+        }
+
+        Ok(version)
+    }
+}
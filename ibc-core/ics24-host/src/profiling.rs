@@ -0,0 +1,723 @@
+use core::cell::Cell;
+use core::time::Duration;
+
+use ibc_core_channel_types::channel::ChannelEnd;
+use ibc_core_channel_types::commitment::{AcknowledgementCommitment, PacketCommitment};
+use ibc_core_channel_types::events::PacketDataEventMode;
+use ibc_core_channel_types::packet::Receipt;
+use ibc_core_channel_types::upgrade::{ErrorReceipt, Upgrade};
+use ibc_core_client_types::params::ClientParams;
+use ibc_core_client_types::Height;
+use ibc_core_commitment_types::commitment::CommitmentPrefix;
+use ibc_core_connection_types::params::ConnectionParams;
+use ibc_core_connection_types::version::Version as ConnectionVersion;
+use ibc_core_connection_types::ConnectionEnd;
+use ibc_core_handler_types::error::{ContextError, GasError};
+use ibc_core_handler_types::events::IbcEvent;
+use ibc_core_host_types::identifiers::{ChannelId, ClientId, ConnectionId, PortId, Sequence};
+use ibc_core_host_types::path::{
+    AckPath, ChannelEndPath, ChannelUpgradeErrorPath, ChannelUpgradePath, ClientConnectionPath,
+    CommitmentPath, ConnectionPath, ReceiptPath, SeqAckPath, SeqRecvPath, SeqSendPath,
+};
+use ibc_core_host_types::validate::IdentifierRules;
+use ibc_primitives::prelude::*;
+use ibc_primitives::{Signer, Timestamp};
+
+use crate::{
+    ExecutionContext, GasMeter, GasOperation, HandshakeStep, HostClock, Metrics, ProofKind,
+    TransactionalExecutionContext, ValidationContext, VerifiedProofs,
+};
+
+/// The clock a [`ProfiledContext`] times the phases of the messages with.
+///
+/// The handlers are deterministic and `no_std`, hence the time is read from
+/// the host, e.g. from [`StdProfileClock`] or from the cycle counter of the
+/// runtime. The profiles are only meant to be reported off-chain, as the
+/// timings differ from one node to the other.
+pub trait ProfileClock {
+    /// Returns the time elapsed since an arbitrary, but fixed, instant.
+    fn now(&self) -> Duration;
+}
+
+/// The [`ProfileClock`] of the hosts running with the standard library,
+/// measuring the time elapsed since its creation.
+#[cfg(feature = "std")]
+#[derive(Clone, Copy, Debug)]
+pub struct StdProfileClock {
+    started_at: std::time::Instant,
+}
+
+#[cfg(feature = "std")]
+impl StdProfileClock {
+    pub fn new() -> Self {
+        Self {
+            started_at: std::time::Instant::now(),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl Default for StdProfileClock {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(feature = "std")]
+impl ProfileClock for StdProfileClock {
+    fn now(&self) -> Duration {
+        self.started_at.elapsed()
+    }
+}
+
+/// The phases of the processing of a message timed by a [`ProfiledContext`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ProfilePhase {
+    /// Decoding the message from its protobuf `Any`
+    Decode,
+    /// Validating the message, including the verification of its proofs
+    Validation,
+    /// Executing the message
+    Execution,
+    /// Verifying a proof against the state of the counterparty chain
+    ProofVerification,
+}
+
+/// The number of times a phase was entered, and the total time spent in it.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct PhaseProfile {
+    pub count: u64,
+    pub duration: Duration,
+}
+
+impl PhaseProfile {
+    fn record(&mut self, duration: Duration) {
+        self.count += 1;
+        self.duration += duration;
+    }
+}
+
+/// The timings and counts recorded by a [`ProfiledContext`].
+///
+/// The state reads and writes are the ones of the IBC store made by the core
+/// handlers, excluding the states the light clients read and write through
+/// the client contexts of the host.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Profile {
+    pub decode: PhaseProfile,
+    pub validation: PhaseProfile,
+    pub execution: PhaseProfile,
+    /// Included in the validation phase
+    pub proof_verification: PhaseProfile,
+    /// The headers and misbehaviours verified by the light clients, as
+    /// charged to the gas meter of the host
+    pub client_message_verifications: u64,
+    pub state_reads: u64,
+    pub state_writes: u64,
+}
+
+impl Profile {
+    /// Returns the profile of the given phase.
+    pub fn phase(&self, phase: ProfilePhase) -> PhaseProfile {
+        match phase {
+            ProfilePhase::Decode => self.decode,
+            ProfilePhase::Validation => self.validation,
+            ProfilePhase::Execution => self.execution,
+            ProfilePhase::ProofVerification => self.proof_verification,
+        }
+    }
+
+    fn phase_mut(&mut self, phase: ProfilePhase) -> &mut PhaseProfile {
+        match phase {
+            ProfilePhase::Decode => &mut self.decode,
+            ProfilePhase::Validation => &mut self.validation,
+            ProfilePhase::Execution => &mut self.execution,
+            ProfilePhase::ProofVerification => &mut self.proof_verification,
+        }
+    }
+}
+
+/// A phase being timed by a [`ProfiledContext`], as started by
+/// [`ProfiledContext::start_phase`].
+#[derive(Clone, Copy, Debug)]
+#[must_use]
+pub struct PhaseTimer {
+    phase: ProfilePhase,
+    started_at: Duration,
+}
+
+/// A context which profiles the messages processed over the host context it
+/// wraps, for integrators to find out where the time goes in production.
+///
+/// The context counts the reads and writes of the IBC store, and the client
+/// messages charged to the gas meter of the host, and times the proof
+/// verifications through the [`Metrics`] hooks, which are forwarded to the
+/// metrics of the host. The decoding, validation and execution of the
+/// messages are timed by the `dispatch_profiled` entrypoint of
+/// `ibc-core-handler`, or by the host around its own calls to the entrypoints
+/// with [`start_phase`](Self::start_phase) and
+/// [`finish_phase`](Self::finish_phase).
+///
+/// Profiling is opt-in: hosts which don't wrap their context in a
+/// `ProfiledContext` pay nothing for it.
+#[derive(Debug)]
+pub struct ProfiledContext<Ctx, C> {
+    ctx: Ctx,
+    clock: C,
+    profile: Cell<Profile>,
+    proof_verification_started_at: Cell<Option<Duration>>,
+}
+
+impl<Ctx, C: ProfileClock> ProfiledContext<Ctx, C> {
+    pub fn new(ctx: Ctx, clock: C) -> Self {
+        Self {
+            ctx,
+            clock,
+            profile: Cell::new(Profile::default()),
+            proof_verification_started_at: Cell::new(None),
+        }
+    }
+
+    /// Returns the profile recorded since the context was created, or since
+    /// the last [`reset_profile`](Self::reset_profile).
+    pub fn profile(&self) -> Profile {
+        self.profile.get()
+    }
+
+    pub fn reset_profile(&mut self) {
+        self.profile.set(Profile::default());
+    }
+
+    pub fn inner(&self) -> &Ctx {
+        &self.ctx
+    }
+
+    pub fn into_inner(self) -> Ctx {
+        self.ctx
+    }
+
+    /// Starts timing the given phase.
+    pub fn start_phase(&self, phase: ProfilePhase) -> PhaseTimer {
+        PhaseTimer {
+            phase,
+            started_at: self.clock.now(),
+        }
+    }
+
+    /// Records the time elapsed since the phase was started.
+    pub fn finish_phase(&self, timer: PhaseTimer) {
+        let elapsed = self.clock.now().saturating_sub(timer.started_at);
+
+        self.update_profile(|profile| profile.phase_mut(timer.phase).record(elapsed));
+    }
+
+    fn update_profile(&self, f: impl FnOnce(&mut Profile)) {
+        let mut profile = self.profile.get();
+        f(&mut profile);
+        self.profile.set(profile);
+    }
+
+    fn count_state_read(&self) {
+        self.update_profile(|profile| profile.state_reads += 1);
+    }
+
+    fn count_state_write(&self) {
+        self.update_profile(|profile| profile.state_writes += 1);
+    }
+}
+
+impl<Ctx, C> TransactionalExecutionContext for ProfiledContext<Ctx, C>
+where
+    Ctx: TransactionalExecutionContext,
+    C: ProfileClock,
+{
+    fn begin_transaction(&mut self) {
+        self.ctx.begin_transaction();
+    }
+
+    fn commit_transaction(&mut self) -> Result<(), ContextError> {
+        self.ctx.commit_transaction()
+    }
+
+    fn rollback_transaction(&mut self) {
+        self.ctx.rollback_transaction();
+    }
+
+    fn transaction_events(&self) -> Vec<IbcEvent> {
+        self.ctx.transaction_events()
+    }
+}
+
+impl<Ctx: ValidationContext, C: ProfileClock> GasMeter for ProfiledContext<Ctx, C> {
+    fn charge(&self, operation: GasOperation<'_>) -> Result<(), GasError> {
+        self.ctx.gas_meter().charge(operation)?;
+
+        if let GasOperation::ClientMessageVerification { .. } = operation {
+            self.update_profile(|profile| profile.client_message_verifications += 1);
+        }
+
+        Ok(())
+    }
+}
+
+impl<Ctx: ValidationContext, C: ProfileClock> Metrics for ProfiledContext<Ctx, C> {
+    fn packet_sent(&self, port_id: &PortId, channel_id: &ChannelId, sequence: Sequence) {
+        self.ctx
+            .metrics()
+            .packet_sent(port_id, channel_id, sequence)
+    }
+
+    fn packet_received(&self, port_id: &PortId, channel_id: &ChannelId, sequence: Sequence) {
+        self.ctx
+            .metrics()
+            .packet_received(port_id, channel_id, sequence)
+    }
+
+    fn packet_acknowledged(&self, port_id: &PortId, channel_id: &ChannelId, sequence: Sequence) {
+        self.ctx
+            .metrics()
+            .packet_acknowledged(port_id, channel_id, sequence)
+    }
+
+    fn packet_timed_out(&self, port_id: &PortId, channel_id: &ChannelId, sequence: Sequence) {
+        self.ctx
+            .metrics()
+            .packet_timed_out(port_id, channel_id, sequence)
+    }
+
+    fn client_updated(&self, client_id: &ClientId, height: Height) {
+        self.ctx.metrics().client_updated(client_id, height)
+    }
+
+    fn connection_handshake_step(&self, step: HandshakeStep, conn_id: &ConnectionId) {
+        self.ctx.metrics().connection_handshake_step(step, conn_id)
+    }
+
+    fn channel_handshake_step(
+        &self,
+        step: HandshakeStep,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) {
+        self.ctx
+            .metrics()
+            .channel_handshake_step(step, port_id, channel_id)
+    }
+
+    fn proof_verification_started(&self, kind: ProofKind) {
+        self.ctx.metrics().proof_verification_started(kind);
+
+        self.proof_verification_started_at
+            .set(Some(self.clock.now()));
+    }
+
+    fn proof_verification_finished(&self, kind: ProofKind, success: bool) {
+        if let Some(started_at) = self.proof_verification_started_at.take() {
+            self.finish_phase(PhaseTimer {
+                phase: ProfilePhase::ProofVerification,
+                started_at,
+            });
+        }
+
+        self.ctx
+            .metrics()
+            .proof_verification_finished(kind, success)
+    }
+}
+
+impl<Ctx: ValidationContext, C: ProfileClock> HostClock for ProfiledContext<Ctx, C> {
+    fn host_height(&self) -> Result<Height, ContextError> {
+        self.ctx.host_height()
+    }
+
+    fn host_timestamp(&self) -> Result<Timestamp, ContextError> {
+        self.ctx.host_timestamp()
+    }
+}
+
+impl<Ctx: ValidationContext, C: ProfileClock> ValidationContext for ProfiledContext<Ctx, C> {
+    type V = Ctx::V;
+    type HostClientState = Ctx::HostClientState;
+    type HostConsensusState = Ctx::HostConsensusState;
+
+    fn get_client_validation_context(&self) -> &Self::V {
+        self.ctx.get_client_validation_context()
+    }
+
+    fn host_consensus_state(
+        &self,
+        height: &Height,
+    ) -> Result<Self::HostConsensusState, ContextError> {
+        self.count_state_read();
+        self.ctx.host_consensus_state(height)
+    }
+
+    fn client_counter(&self) -> Result<u64, ContextError> {
+        self.count_state_read();
+        self.ctx.client_counter()
+    }
+
+    fn client_params(&self) -> ClientParams {
+        self.ctx.client_params()
+    }
+
+    fn connection_end(&self, conn_id: &ConnectionId) -> Result<ConnectionEnd, ContextError> {
+        self.count_state_read();
+        self.ctx.connection_end(conn_id)
+    }
+
+    fn validate_self_client(
+        &self,
+        client_state_of_host_on_counterparty: Self::HostClientState,
+    ) -> Result<(), ContextError> {
+        self.ctx
+            .validate_self_client(client_state_of_host_on_counterparty)
+    }
+
+    fn commitment_prefix(&self) -> CommitmentPrefix {
+        self.ctx.commitment_prefix()
+    }
+
+    fn connection_counter(&self) -> Result<u64, ContextError> {
+        self.count_state_read();
+        self.ctx.connection_counter()
+    }
+
+    fn connection_params(&self) -> ConnectionParams {
+        self.ctx.connection_params()
+    }
+
+    fn get_compatible_versions(&self) -> Vec<ConnectionVersion> {
+        self.ctx.get_compatible_versions()
+    }
+
+    fn pick_version(
+        &self,
+        counterparty_candidate_versions: &[ConnectionVersion],
+    ) -> Result<ConnectionVersion, ContextError> {
+        self.ctx.pick_version(counterparty_candidate_versions)
+    }
+
+    fn channel_end(&self, channel_end_path: &ChannelEndPath) -> Result<ChannelEnd, ContextError> {
+        self.count_state_read();
+        self.ctx.channel_end(channel_end_path)
+    }
+
+    fn get_next_sequence_send(
+        &self,
+        seq_send_path: &SeqSendPath,
+    ) -> Result<Sequence, ContextError> {
+        self.count_state_read();
+        self.ctx.get_next_sequence_send(seq_send_path)
+    }
+
+    fn get_next_sequence_recv(
+        &self,
+        seq_recv_path: &SeqRecvPath,
+    ) -> Result<Sequence, ContextError> {
+        self.count_state_read();
+        self.ctx.get_next_sequence_recv(seq_recv_path)
+    }
+
+    fn get_next_sequence_ack(&self, seq_ack_path: &SeqAckPath) -> Result<Sequence, ContextError> {
+        self.count_state_read();
+        self.ctx.get_next_sequence_ack(seq_ack_path)
+    }
+
+    fn get_packet_commitment(
+        &self,
+        commitment_path: &CommitmentPath,
+    ) -> Result<PacketCommitment, ContextError> {
+        self.count_state_read();
+        self.ctx.get_packet_commitment(commitment_path)
+    }
+
+    fn get_packet_receipt(&self, receipt_path: &ReceiptPath) -> Result<Receipt, ContextError> {
+        self.count_state_read();
+        self.ctx.get_packet_receipt(receipt_path)
+    }
+
+    fn get_packet_acknowledgement(
+        &self,
+        ack_path: &AckPath,
+    ) -> Result<AcknowledgementCommitment, ContextError> {
+        self.count_state_read();
+        self.ctx.get_packet_acknowledgement(ack_path)
+    }
+
+    fn channel_upgrade(&self, upgrade_path: &ChannelUpgradePath) -> Result<Upgrade, ContextError> {
+        self.count_state_read();
+        self.ctx.channel_upgrade(upgrade_path)
+    }
+
+    fn counterparty_channel_upgrade(
+        &self,
+        upgrade_path: &ChannelUpgradePath,
+    ) -> Result<Upgrade, ContextError> {
+        self.count_state_read();
+        self.ctx.counterparty_channel_upgrade(upgrade_path)
+    }
+
+    fn channel_upgrade_error_receipt(
+        &self,
+        upgrade_error_path: &ChannelUpgradeErrorPath,
+    ) -> Result<ErrorReceipt, ContextError> {
+        self.count_state_read();
+        self.ctx.channel_upgrade_error_receipt(upgrade_error_path)
+    }
+
+    fn has_inflight_packets(
+        &self,
+        channel_end_path: &ChannelEndPath,
+    ) -> Result<bool, ContextError> {
+        self.count_state_read();
+        self.ctx.has_inflight_packets(channel_end_path)
+    }
+
+    fn upgrade_timeout(&self) -> Duration {
+        self.ctx.upgrade_timeout()
+    }
+
+    fn max_packet_data_size(&self) -> Option<usize> {
+        self.ctx.max_packet_data_size()
+    }
+
+    fn packet_data_event_mode(&self) -> PacketDataEventMode {
+        self.ctx.packet_data_event_mode()
+    }
+
+    fn metrics(&self) -> &dyn Metrics {
+        self
+    }
+
+    fn gas_meter(&self) -> &dyn GasMeter {
+        self
+    }
+
+    fn verified_proofs(&self) -> Option<&VerifiedProofs> {
+        self.ctx.verified_proofs()
+    }
+
+    fn channel_counter(&self) -> Result<u64, ContextError> {
+        self.count_state_read();
+        self.ctx.channel_counter()
+    }
+
+    fn max_expected_time_per_block(&self) -> Duration {
+        self.ctx.max_expected_time_per_block()
+    }
+
+    fn block_delay(&self, delay_period_time: &Duration) -> u64 {
+        self.ctx.block_delay(delay_period_time)
+    }
+
+    fn validate_message_signer(&self, signer: &Signer) -> Result<(), ContextError> {
+        self.ctx.validate_message_signer(signer)
+    }
+
+    fn validate_connection_creator(
+        &self,
+        signer: &Signer,
+        client_id: &ClientId,
+    ) -> Result<(), ContextError> {
+        self.ctx.validate_connection_creator(signer, client_id)
+    }
+
+    fn identifier_rules(&self) -> IdentifierRules {
+        self.ctx.identifier_rules()
+    }
+
+    fn validate_channel_close(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<(), ContextError> {
+        self.ctx.validate_channel_close(port_id, channel_id)
+    }
+}
+
+impl<Ctx: ExecutionContext, C: ProfileClock> ExecutionContext for ProfiledContext<Ctx, C> {
+    type E = Ctx::E;
+
+    fn get_client_execution_context(&mut self) -> &mut Self::E {
+        self.ctx.get_client_execution_context()
+    }
+
+    fn increase_client_counter(&mut self) -> Result<(), ContextError> {
+        self.count_state_write();
+        self.ctx.increase_client_counter()
+    }
+
+    fn store_client_params(&mut self, params: ClientParams) -> Result<(), ContextError> {
+        self.count_state_write();
+        self.ctx.store_client_params(params)
+    }
+
+    fn store_connection(
+        &mut self,
+        connection_path: &ConnectionPath,
+        connection_end: ConnectionEnd,
+    ) -> Result<(), ContextError> {
+        self.count_state_write();
+        self.ctx.store_connection(connection_path, connection_end)
+    }
+
+    fn store_connection_to_client(
+        &mut self,
+        client_connection_path: &ClientConnectionPath,
+        conn_id: ConnectionId,
+    ) -> Result<(), ContextError> {
+        self.count_state_write();
+        self.ctx
+            .store_connection_to_client(client_connection_path, conn_id)
+    }
+
+    fn increase_connection_counter(&mut self) -> Result<(), ContextError> {
+        self.count_state_write();
+        self.ctx.increase_connection_counter()
+    }
+
+    fn store_packet_commitment(
+        &mut self,
+        commitment_path: &CommitmentPath,
+        commitment: PacketCommitment,
+    ) -> Result<(), ContextError> {
+        self.count_state_write();
+        self.ctx
+            .store_packet_commitment(commitment_path, commitment)
+    }
+
+    fn delete_packet_commitment(
+        &mut self,
+        commitment_path: &CommitmentPath,
+    ) -> Result<(), ContextError> {
+        self.count_state_write();
+        self.ctx.delete_packet_commitment(commitment_path)
+    }
+
+    fn store_packet_receipt(
+        &mut self,
+        receipt_path: &ReceiptPath,
+        receipt: Receipt,
+    ) -> Result<(), ContextError> {
+        self.count_state_write();
+        self.ctx.store_packet_receipt(receipt_path, receipt)
+    }
+
+    fn store_packet_acknowledgement(
+        &mut self,
+        ack_path: &AckPath,
+        ack_commitment: AcknowledgementCommitment,
+    ) -> Result<(), ContextError> {
+        self.count_state_write();
+        self.ctx
+            .store_packet_acknowledgement(ack_path, ack_commitment)
+    }
+
+    fn delete_packet_acknowledgement(&mut self, ack_path: &AckPath) -> Result<(), ContextError> {
+        self.count_state_write();
+        self.ctx.delete_packet_acknowledgement(ack_path)
+    }
+
+    fn store_channel(
+        &mut self,
+        channel_end_path: &ChannelEndPath,
+        channel_end: ChannelEnd,
+    ) -> Result<(), ContextError> {
+        self.count_state_write();
+        self.ctx.store_channel(channel_end_path, channel_end)
+    }
+
+    fn store_channel_upgrade(
+        &mut self,
+        upgrade_path: &ChannelUpgradePath,
+        upgrade: Upgrade,
+    ) -> Result<(), ContextError> {
+        self.count_state_write();
+        self.ctx.store_channel_upgrade(upgrade_path, upgrade)
+    }
+
+    fn store_counterparty_channel_upgrade(
+        &mut self,
+        upgrade_path: &ChannelUpgradePath,
+        upgrade: Upgrade,
+    ) -> Result<(), ContextError> {
+        self.count_state_write();
+        self.ctx
+            .store_counterparty_channel_upgrade(upgrade_path, upgrade)
+    }
+
+    fn delete_channel_upgrade(
+        &mut self,
+        upgrade_path: &ChannelUpgradePath,
+    ) -> Result<(), ContextError> {
+        self.count_state_write();
+        self.ctx.delete_channel_upgrade(upgrade_path)
+    }
+
+    fn store_channel_upgrade_error_receipt(
+        &mut self,
+        upgrade_error_path: &ChannelUpgradeErrorPath,
+        error_receipt: ErrorReceipt,
+    ) -> Result<(), ContextError> {
+        self.count_state_write();
+        self.ctx
+            .store_channel_upgrade_error_receipt(upgrade_error_path, error_receipt)
+    }
+
+    fn store_next_sequence_send(
+        &mut self,
+        seq_send_path: &SeqSendPath,
+        seq: Sequence,
+    ) -> Result<(), ContextError> {
+        self.count_state_write();
+        self.ctx.store_next_sequence_send(seq_send_path, seq)
+    }
+
+    fn store_next_sequence_recv(
+        &mut self,
+        seq_recv_path: &SeqRecvPath,
+        seq: Sequence,
+    ) -> Result<(), ContextError> {
+        self.count_state_write();
+        self.ctx.store_next_sequence_recv(seq_recv_path, seq)
+    }
+
+    fn store_next_sequence_ack(
+        &mut self,
+        seq_ack_path: &SeqAckPath,
+        seq: Sequence,
+    ) -> Result<(), ContextError> {
+        self.count_state_write();
+        self.ctx.store_next_sequence_ack(seq_ack_path, seq)
+    }
+
+    fn increase_channel_counter(&mut self) -> Result<(), ContextError> {
+        self.count_state_write();
+        self.ctx.increase_channel_counter()
+    }
+
+    fn emit_ibc_event(&mut self, event: IbcEvent) -> Result<(), ContextError> {
+        self.ctx.emit_ibc_event(event)
+    }
+
+    fn log_message(&mut self, message: String) -> Result<(), ContextError> {
+        self.ctx.log_message(message)
+    }
+
+    fn stage_writes(&mut self) -> Result<(), ContextError> {
+        self.ctx.stage_writes()
+    }
+
+    fn commit_writes(&mut self) -> Result<(), ContextError> {
+        self.ctx.commit_writes()
+    }
+
+    fn discard_writes(&mut self) {
+        self.ctx.discard_writes();
+    }
+
+    fn set_verified_proofs(&mut self, verified_proofs: Option<VerifiedProofs>) {
+        self.ctx.set_verified_proofs(verified_proofs);
+    }
+}
@@ -0,0 +1,116 @@
+//! Policies for validating the client state of the host chain stored on
+//! counterparty chains, as required by the connection handshake.
+//!
+//! What a correct client of the host looks like depends on the consensus of
+//! the host, so implementations of [`ValidationContext::validate_self_client`]
+//! typically delegate to one of these policies: the
+//! `TendermintSelfClientValidator` of `ibc-core-host-cosmos` for Tendermint
+//! hosts, [`BasicSelfClientValidator`] for hosts with no more specific
+//! checks, such as rollups, Solana or Substrate chains, or
+//! [`NoSelfClientValidation`] for hosts which cannot validate their clients
+//! at all.
+//!
+//! [`ValidationContext::validate_self_client`]: crate::ValidationContext::validate_self_client
+
+use ibc_core_client_context::prelude::*;
+use ibc_core_client_types::Height;
+use ibc_core_connection_types::error::ConnectionError;
+use ibc_core_handler_types::error::ContextError;
+use ibc_core_host_types::identifiers::ClientType;
+use ibc_primitives::prelude::*;
+
+/// A policy validating client states of the host stored on counterparty
+/// chains, of type `ClientState`.
+pub trait SelfClientValidation<ClientState> {
+    /// Validates the client state of the host stored on the counterparty
+    /// chain.
+    fn validate_self_client(
+        &self,
+        client_state_of_host_on_counterparty: ClientState,
+    ) -> Result<(), ContextError>;
+}
+
+/// Accepts any client state of the host.
+///
+/// Suited to hosts whose clients cannot be checked against their local
+/// state. The correctness of the clients of such hosts is then left to
+/// relayers and to the governance of the counterparty chains.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct NoSelfClientValidation;
+
+impl<ClientState> SelfClientValidation<ClientState> for NoSelfClientValidation {
+    fn validate_self_client(
+        &self,
+        _client_state_of_host_on_counterparty: ClientState,
+    ) -> Result<(), ContextError> {
+        Ok(())
+    }
+}
+
+/// Performs the checks shared by all clients: that the client is of the type
+/// tracking the host, and that its latest height is in the revision of the
+/// host and below its current height.
+#[derive(Clone, Debug)]
+pub struct BasicSelfClientValidator {
+    /// The type of the clients tracking the host.
+    pub client_type: ClientType,
+    /// The current height of the host.
+    pub host_height: Height,
+}
+
+impl BasicSelfClientValidator {
+    pub fn new(client_type: ClientType, host_height: Height) -> Self {
+        Self {
+            client_type,
+            host_height,
+        }
+    }
+}
+
+impl<ClientState> SelfClientValidation<ClientState> for BasicSelfClientValidator
+where
+    ClientState: ClientStateCommon,
+{
+    fn validate_self_client(
+        &self,
+        client_state_of_host_on_counterparty: ClientState,
+    ) -> Result<(), ContextError> {
+        let client_type = client_state_of_host_on_counterparty.client_type();
+        if client_type != self.client_type {
+            return Err(ContextError::ConnectionError(
+                ConnectionError::InvalidClientState {
+                    reason: format!(
+                        "invalid client type. expected: {}, got: {}",
+                        self.client_type, client_type
+                    ),
+                },
+            ));
+        }
+
+        let latest_height = client_state_of_host_on_counterparty.latest_height();
+        if latest_height.revision_number() != self.host_height.revision_number() {
+            return Err(ContextError::ConnectionError(
+                ConnectionError::InvalidClientState {
+                    reason: format!(
+                        "client is not in the same revision as the chain. expected: {}, got: {}",
+                        self.host_height.revision_number(),
+                        latest_height.revision_number()
+                    ),
+                },
+            ));
+        }
+
+        if latest_height >= self.host_height {
+            return Err(ContextError::ConnectionError(
+                ConnectionError::InvalidClientState {
+                    reason: format!(
+                        "client has latest height {} greater than or equal to chain height {}",
+                        latest_height, self.host_height
+                    ),
+                },
+            ));
+        }
+
+        Ok(())
+    }
+}
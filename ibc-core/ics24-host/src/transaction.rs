@@ -0,0 +1,987 @@
+use core::cell::{Cell, RefCell};
+use core::time::Duration;
+
+use ibc_core_channel_types::channel::{ChannelEnd, State as ChannelState};
+use ibc_core_channel_types::commitment::{AcknowledgementCommitment, PacketCommitment};
+use ibc_core_channel_types::error::{ChannelError, PacketError};
+use ibc_core_channel_types::events::PacketDataEventMode;
+use ibc_core_channel_types::packet::Receipt;
+use ibc_core_channel_types::upgrade::{ErrorReceipt, Upgrade};
+use ibc_core_client_types::params::ClientParams;
+use ibc_core_client_types::Height;
+use ibc_core_commitment_types::commitment::CommitmentPrefix;
+use ibc_core_connection_types::params::ConnectionParams;
+use ibc_core_connection_types::version::Version as ConnectionVersion;
+use ibc_core_connection_types::ConnectionEnd;
+use ibc_core_handler_types::error::{ContextError, GasError};
+use ibc_core_handler_types::events::IbcEvent;
+use ibc_core_host_types::identifiers::{ChannelId, ClientId, ConnectionId, PortId, Sequence};
+use ibc_core_host_types::path::{
+    AckPath, ChannelEndPath, ChannelUpgradeErrorPath, ChannelUpgradePath, ClientConnectionPath,
+    CommitmentPath, ConnectionPath, ReceiptPath, SeqAckPath, SeqRecvPath, SeqSendPath,
+};
+use ibc_core_host_types::validate::IdentifierRules;
+use ibc_primitives::prelude::*;
+use ibc_primitives::{Signer, Timestamp};
+
+use crate::{
+    Authority, ExecutionContext, GasMeter, GasOperation, GasUsage, HostClock, Metrics,
+    ValidationContext, VerifiedProofs,
+};
+
+/// Context to be implemented by the host whose writes can be grouped into
+/// transactions, which are either committed or rolled back as a whole.
+///
+/// Transactions let hosts roll back the state changes of a message which
+/// failed to execute, e.g. after an application callback failed, or of a
+/// whole batch of messages when one of them failed:
+///
+/// ```ignore
+/// ctx.transact(|ctx| msgs.into_iter().try_for_each(|msg| dispatch(ctx, router, msg)))?;
+/// ```
+///
+/// Hosts without transactional storage may wrap their context in an
+/// [`OverlayContext`].
+pub trait TransactionalExecutionContext: ExecutionContext {
+    /// Begins a transaction, nested in the current one if any.
+    fn begin_transaction(&mut self);
+
+    /// Commits the current transaction, whose writes are then part of the
+    /// enclosing transaction if any, or written to the host otherwise.
+    ///
+    /// Does nothing if no transaction is in progress.
+    fn commit_transaction(&mut self) -> Result<(), ContextError>;
+
+    /// Rolls back the current transaction, discarding its writes.
+    ///
+    /// Does nothing if no transaction is in progress.
+    fn rollback_transaction(&mut self);
+
+    /// Returns the events emitted since the current transaction began, along
+    /// with the ones of the nested transactions it committed, or none if no
+    /// transaction is in progress.
+    fn transaction_events(&self) -> Vec<IbcEvent>;
+
+    /// Runs `f` in a transaction, which is committed if `f` succeeds and
+    /// rolled back otherwise.
+    fn transact<T, E>(&mut self, f: impl FnOnce(&mut Self) -> Result<T, E>) -> Result<T, E>
+    where
+        Self: Sized,
+        E: From<ContextError>,
+    {
+        self.begin_transaction();
+
+        match f(self) {
+            Ok(value) => {
+                self.commit_transaction()?;
+                Ok(value)
+            }
+            Err(e) => {
+                self.rollback_transaction();
+                Err(e)
+            }
+        }
+    }
+}
+
+/// The writes of a transaction of an [`OverlayContext`].
+///
+/// Deleted entries are kept as `None`.
+#[derive(Debug, Default)]
+struct Transaction {
+    client_counter_increments: u64,
+    client_params: Option<ClientParams>,
+    connections: BTreeMap<ConnectionId, ConnectionEnd>,
+    client_connections: Vec<(ClientConnectionPath, ConnectionId)>,
+    connection_counter_increments: u64,
+    packet_commitments: BTreeMap<CommitmentPath, Option<PacketCommitment>>,
+    packet_receipts: BTreeMap<ReceiptPath, Receipt>,
+    packet_acks: BTreeMap<AckPath, Option<AcknowledgementCommitment>>,
+    channels: BTreeMap<ChannelEndPath, ChannelEnd>,
+    channel_upgrades: BTreeMap<ChannelUpgradePath, Option<Upgrade>>,
+    counterparty_channel_upgrades: BTreeMap<ChannelUpgradePath, Option<Upgrade>>,
+    channel_upgrade_errors: BTreeMap<ChannelUpgradeErrorPath, ErrorReceipt>,
+    next_sequence_send: BTreeMap<SeqSendPath, Sequence>,
+    next_sequence_recv: BTreeMap<SeqRecvPath, Sequence>,
+    next_sequence_ack: BTreeMap<SeqAckPath, Sequence>,
+    channel_counter_increments: u64,
+    events: Vec<IbcEvent>,
+    logs: Vec<String>,
+    /// The channel ends reported to have in-flight packets while some of
+    /// their packet commitments were deleted, which is checked again once
+    /// the deletions are written.
+    flush_checks: RefCell<Vec<ChannelEndPath>>,
+}
+
+impl Transaction {
+    /// Merges the writes of the given nested transaction into this one.
+    fn merge(&mut self, nested: Transaction) {
+        self.client_counter_increments += nested.client_counter_increments;
+        if nested.client_params.is_some() {
+            self.client_params = nested.client_params;
+        }
+        self.connections.extend(nested.connections);
+        self.client_connections.extend(nested.client_connections);
+        self.connection_counter_increments += nested.connection_counter_increments;
+        self.packet_commitments.extend(nested.packet_commitments);
+        self.packet_receipts.extend(nested.packet_receipts);
+        self.packet_acks.extend(nested.packet_acks);
+        self.channels.extend(nested.channels);
+        self.channel_upgrades.extend(nested.channel_upgrades);
+        self.counterparty_channel_upgrades
+            .extend(nested.counterparty_channel_upgrades);
+        self.channel_upgrade_errors
+            .extend(nested.channel_upgrade_errors);
+        self.next_sequence_send.extend(nested.next_sequence_send);
+        self.next_sequence_recv.extend(nested.next_sequence_recv);
+        self.next_sequence_ack.extend(nested.next_sequence_ack);
+        self.channel_counter_increments += nested.channel_counter_increments;
+        self.events.extend(nested.events);
+        self.logs.extend(nested.logs);
+        self.flush_checks
+            .get_mut()
+            .extend(nested.flush_checks.into_inner());
+    }
+
+    /// Writes this transaction to the given context.
+    fn write<Ctx: ExecutionContext>(self, ctx: &mut Ctx) -> Result<(), ContextError> {
+        for _ in 0..self.client_counter_increments {
+            ctx.increase_client_counter()?;
+        }
+        if let Some(params) = self.client_params {
+            ctx.store_client_params(params)?;
+        }
+        for (conn_id, conn_end) in self.connections {
+            ctx.store_connection(&ConnectionPath::new(&conn_id), conn_end)?;
+        }
+        for (client_connection_path, conn_id) in self.client_connections {
+            ctx.store_connection_to_client(&client_connection_path, conn_id)?;
+        }
+        for _ in 0..self.connection_counter_increments {
+            ctx.increase_connection_counter()?;
+        }
+        for _ in 0..self.channel_counter_increments {
+            ctx.increase_channel_counter()?;
+        }
+        for (chan_end_path, chan_end) in self.channels {
+            ctx.store_channel(&chan_end_path, chan_end)?;
+        }
+
+        // Deleting an upgrade deletes the counterparty upgrade as well, so
+        // the upgrades still stored are written after the deletions.
+        for (upgrade_path, upgrade) in self
+            .channel_upgrades
+            .iter()
+            .chain(self.counterparty_channel_upgrades.iter())
+        {
+            if upgrade.is_none() {
+                ctx.delete_channel_upgrade(upgrade_path)?;
+            }
+        }
+        for (upgrade_path, upgrade) in self.channel_upgrades {
+            if let Some(upgrade) = upgrade {
+                ctx.store_channel_upgrade(&upgrade_path, upgrade)?;
+            }
+        }
+        for (upgrade_path, upgrade) in self.counterparty_channel_upgrades {
+            if let Some(upgrade) = upgrade {
+                ctx.store_counterparty_channel_upgrade(&upgrade_path, upgrade)?;
+            }
+        }
+        for (upgrade_error_path, error_receipt) in self.channel_upgrade_errors {
+            ctx.store_channel_upgrade_error_receipt(&upgrade_error_path, error_receipt)?;
+        }
+
+        for (seq_send_path, seq) in self.next_sequence_send {
+            ctx.store_next_sequence_send(&seq_send_path, seq)?;
+        }
+        for (seq_recv_path, seq) in self.next_sequence_recv {
+            ctx.store_next_sequence_recv(&seq_recv_path, seq)?;
+        }
+        for (seq_ack_path, seq) in self.next_sequence_ack {
+            ctx.store_next_sequence_ack(&seq_ack_path, seq)?;
+        }
+        for (commitment_path, commitment) in self.packet_commitments {
+            match commitment {
+                Some(commitment) => ctx.store_packet_commitment(&commitment_path, commitment)?,
+                None => ctx.delete_packet_commitment(&commitment_path)?,
+            }
+        }
+        for (receipt_path, receipt) in self.packet_receipts {
+            ctx.store_packet_receipt(&receipt_path, receipt)?;
+        }
+        for (ack_path, ack_commitment) in self.packet_acks {
+            match ack_commitment {
+                Some(ack_commitment) => {
+                    ctx.store_packet_acknowledgement(&ack_path, ack_commitment)?
+                }
+                None => ctx.delete_packet_acknowledgement(&ack_path)?,
+            }
+        }
+
+        for event in self.events {
+            ctx.emit_ibc_event(event)?;
+        }
+        for message in self.logs {
+            ctx.log_message(message)?;
+        }
+
+        // The handlers complete the flush of an upgrading channel end once
+        // they acknowledged or timed out its last in-flight packet, which is
+        // only known once the packet commitments are deleted from the host.
+        for chan_end_path in self.flush_checks.into_inner() {
+            if ctx.has_inflight_packets(&chan_end_path)? {
+                continue;
+            }
+
+            let mut chan_end = ctx.channel_end(&chan_end_path)?;
+            if chan_end.state == ChannelState::Flushing {
+                chan_end.set_state(ChannelState::FlushComplete);
+                ctx.store_channel(&chan_end_path, chan_end)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// A [`TransactionalExecutionContext`] which keeps the writes of its
+/// transactions in memory, on top of any host context, until they are
+/// committed.
+///
+/// Outside of a transaction, the writes go to the host context right away.
+/// The writes of a committed transaction are written to the host context in
+/// a deterministic order, as one batch of
+/// [staged writes](ExecutionContext::stage_writes).
+/// While a transaction is in progress, the reads return the state as
+/// modified by its writes. Only the IBC store is covered by the transactions,
+/// the states written through the client execution context of the host and
+/// by the applications are not.
+///
+/// The overlay also records the operations charged to the gas meter of the
+/// host, as a [`GasUsage`].
+#[derive(Debug)]
+pub struct OverlayContext<Ctx> {
+    ctx: Ctx,
+    transactions: Vec<Transaction>,
+    gas_usage: Cell<GasUsage>,
+}
+
+impl<Ctx> OverlayContext<Ctx> {
+    pub fn new(ctx: Ctx) -> Self {
+        Self {
+            ctx,
+            transactions: Vec::new(),
+            gas_usage: Cell::new(GasUsage::default()),
+        }
+    }
+
+    /// Returns the operations charged to the gas meter of the host since the
+    /// overlay was created, or since the last [`reset_gas_usage`](Self::reset_gas_usage).
+    pub fn gas_usage(&self) -> GasUsage {
+        self.gas_usage.get()
+    }
+
+    pub fn reset_gas_usage(&mut self) {
+        self.gas_usage.set(GasUsage::default());
+    }
+
+    pub fn inner(&self) -> &Ctx {
+        &self.ctx
+    }
+
+    /// Returns the host context, discarding the writes of the transactions
+    /// in progress.
+    pub fn into_inner(self) -> Ctx {
+        self.ctx
+    }
+
+    /// Returns whether a transaction is in progress.
+    pub fn in_transaction(&self) -> bool {
+        !self.transactions.is_empty()
+    }
+
+    /// Returns the most recent write of the transactions in progress to the
+    /// entry read by `f`, if any.
+    fn pending<T: Clone>(&self, f: impl Fn(&Transaction) -> Option<&T>) -> Option<T> {
+        self.transactions.iter().rev().find_map(f).cloned()
+    }
+
+    /// Returns the transaction in progress, if any.
+    fn current(&mut self) -> Option<&mut Transaction> {
+        self.transactions.last_mut()
+    }
+}
+
+impl<Ctx: ExecutionContext> TransactionalExecutionContext for OverlayContext<Ctx> {
+    fn begin_transaction(&mut self) {
+        self.transactions.push(Transaction::default());
+    }
+
+    fn commit_transaction(&mut self) -> Result<(), ContextError> {
+        let Some(transaction) = self.transactions.pop() else {
+            return Ok(());
+        };
+
+        match self.transactions.last_mut() {
+            Some(enclosing) => {
+                enclosing.merge(transaction);
+                Ok(())
+            }
+            None => self.ctx.with_staged_writes(|ctx| transaction.write(ctx)),
+        }
+    }
+
+    fn rollback_transaction(&mut self) {
+        self.transactions.pop();
+    }
+
+    fn transaction_events(&self) -> Vec<IbcEvent> {
+        self.transactions
+            .last()
+            .map(|transaction| transaction.events.clone())
+            .unwrap_or_default()
+    }
+}
+
+impl<Ctx: Authority> Authority for OverlayContext<Ctx> {
+    fn is_authorized(&self, signer: &Signer) -> bool {
+        self.ctx.is_authorized(signer)
+    }
+}
+
+impl<Ctx: ExecutionContext> GasMeter for OverlayContext<Ctx> {
+    fn charge(&self, operation: GasOperation<'_>) -> Result<(), GasError> {
+        self.ctx.gas_meter().charge(operation)?;
+
+        let mut gas_usage = self.gas_usage.get();
+        gas_usage.record(&operation);
+        self.gas_usage.set(gas_usage);
+
+        Ok(())
+    }
+}
+
+impl<Ctx: ExecutionContext> HostClock for OverlayContext<Ctx> {
+    fn host_height(&self) -> Result<Height, ContextError> {
+        self.ctx.host_height()
+    }
+
+    fn host_timestamp(&self) -> Result<Timestamp, ContextError> {
+        self.ctx.host_timestamp()
+    }
+}
+
+impl<Ctx: ExecutionContext> ValidationContext for OverlayContext<Ctx> {
+    type V = Ctx::V;
+    type HostClientState = Ctx::HostClientState;
+    type HostConsensusState = Ctx::HostConsensusState;
+
+    fn get_client_validation_context(&self) -> &Self::V {
+        self.ctx.get_client_validation_context()
+    }
+
+    fn host_consensus_state(
+        &self,
+        height: &Height,
+    ) -> Result<Self::HostConsensusState, ContextError> {
+        self.ctx.host_consensus_state(height)
+    }
+
+    fn client_counter(&self) -> Result<u64, ContextError> {
+        let increments: u64 = self
+            .transactions
+            .iter()
+            .map(|transaction| transaction.client_counter_increments)
+            .sum();
+
+        Ok(self.ctx.client_counter()? + increments)
+    }
+
+    fn connection_end(&self, conn_id: &ConnectionId) -> Result<ConnectionEnd, ContextError> {
+        match self.pending(|transaction| transaction.connections.get(conn_id)) {
+            Some(conn_end) => Ok(conn_end),
+            None => self.ctx.connection_end(conn_id),
+        }
+    }
+
+    fn validate_self_client(
+        &self,
+        client_state_of_host_on_counterparty: Self::HostClientState,
+    ) -> Result<(), ContextError> {
+        self.ctx
+            .validate_self_client(client_state_of_host_on_counterparty)
+    }
+
+    fn commitment_prefix(&self) -> CommitmentPrefix {
+        self.ctx.commitment_prefix()
+    }
+
+    fn connection_counter(&self) -> Result<u64, ContextError> {
+        let increments: u64 = self
+            .transactions
+            .iter()
+            .map(|transaction| transaction.connection_counter_increments)
+            .sum();
+
+        Ok(self.ctx.connection_counter()? + increments)
+    }
+
+    fn client_params(&self) -> ClientParams {
+        self.pending(|transaction| transaction.client_params.as_ref())
+            .unwrap_or_else(|| self.ctx.client_params())
+    }
+
+    fn connection_params(&self) -> ConnectionParams {
+        self.ctx.connection_params()
+    }
+
+    fn get_compatible_versions(&self) -> Vec<ConnectionVersion> {
+        self.ctx.get_compatible_versions()
+    }
+
+    fn pick_version(
+        &self,
+        counterparty_candidate_versions: &[ConnectionVersion],
+    ) -> Result<ConnectionVersion, ContextError> {
+        self.ctx.pick_version(counterparty_candidate_versions)
+    }
+
+    fn channel_end(&self, channel_end_path: &ChannelEndPath) -> Result<ChannelEnd, ContextError> {
+        match self.pending(|transaction| transaction.channels.get(channel_end_path)) {
+            Some(chan_end) => Ok(chan_end),
+            None => self.ctx.channel_end(channel_end_path),
+        }
+    }
+
+    fn get_next_sequence_send(
+        &self,
+        seq_send_path: &SeqSendPath,
+    ) -> Result<Sequence, ContextError> {
+        match self.pending(|transaction| transaction.next_sequence_send.get(seq_send_path)) {
+            Some(seq) => Ok(seq),
+            None => self.ctx.get_next_sequence_send(seq_send_path),
+        }
+    }
+
+    fn get_next_sequence_recv(
+        &self,
+        seq_recv_path: &SeqRecvPath,
+    ) -> Result<Sequence, ContextError> {
+        match self.pending(|transaction| transaction.next_sequence_recv.get(seq_recv_path)) {
+            Some(seq) => Ok(seq),
+            None => self.ctx.get_next_sequence_recv(seq_recv_path),
+        }
+    }
+
+    fn get_next_sequence_ack(&self, seq_ack_path: &SeqAckPath) -> Result<Sequence, ContextError> {
+        match self.pending(|transaction| transaction.next_sequence_ack.get(seq_ack_path)) {
+            Some(seq) => Ok(seq),
+            None => self.ctx.get_next_sequence_ack(seq_ack_path),
+        }
+    }
+
+    fn get_packet_commitment(
+        &self,
+        commitment_path: &CommitmentPath,
+    ) -> Result<PacketCommitment, ContextError> {
+        match self.pending(|transaction| transaction.packet_commitments.get(commitment_path)) {
+            Some(Some(commitment)) => Ok(commitment),
+            Some(None) => Err(PacketError::PacketCommitmentNotFound {
+                sequence: commitment_path.sequence,
+            }
+            .into()),
+            None => self.ctx.get_packet_commitment(commitment_path),
+        }
+    }
+
+    fn get_packet_receipt(&self, receipt_path: &ReceiptPath) -> Result<Receipt, ContextError> {
+        match self.pending(|transaction| transaction.packet_receipts.get(receipt_path)) {
+            Some(receipt) => Ok(receipt),
+            None => self.ctx.get_packet_receipt(receipt_path),
+        }
+    }
+
+    fn get_packet_acknowledgement(
+        &self,
+        ack_path: &AckPath,
+    ) -> Result<AcknowledgementCommitment, ContextError> {
+        match self.pending(|transaction| transaction.packet_acks.get(ack_path)) {
+            Some(Some(ack_commitment)) => Ok(ack_commitment),
+            Some(None) => Err(PacketError::PacketAcknowledgementNotFound {
+                sequence: ack_path.sequence,
+            }
+            .into()),
+            None => self.ctx.get_packet_acknowledgement(ack_path),
+        }
+    }
+
+    fn channel_upgrade(&self, upgrade_path: &ChannelUpgradePath) -> Result<Upgrade, ContextError> {
+        match self.pending(|transaction| transaction.channel_upgrades.get(upgrade_path)) {
+            Some(Some(upgrade)) => Ok(upgrade),
+            Some(None) => Err(upgrade_not_found(upgrade_path)),
+            None => self.ctx.channel_upgrade(upgrade_path),
+        }
+    }
+
+    fn counterparty_channel_upgrade(
+        &self,
+        upgrade_path: &ChannelUpgradePath,
+    ) -> Result<Upgrade, ContextError> {
+        match self
+            .pending(|transaction| transaction.counterparty_channel_upgrades.get(upgrade_path))
+        {
+            Some(Some(upgrade)) => Ok(upgrade),
+            Some(None) => Err(upgrade_not_found(upgrade_path)),
+            None => self.ctx.counterparty_channel_upgrade(upgrade_path),
+        }
+    }
+
+    fn channel_upgrade_error_receipt(
+        &self,
+        upgrade_error_path: &ChannelUpgradeErrorPath,
+    ) -> Result<ErrorReceipt, ContextError> {
+        match self.pending(|transaction| transaction.channel_upgrade_errors.get(upgrade_error_path))
+        {
+            Some(error_receipt) => Ok(error_receipt),
+            None => self.ctx.channel_upgrade_error_receipt(upgrade_error_path),
+        }
+    }
+
+    fn has_inflight_packets(
+        &self,
+        channel_end_path: &ChannelEndPath,
+    ) -> Result<bool, ContextError> {
+        let mut deleted_commitments = false;
+
+        for transaction in &self.transactions {
+            for (commitment_path, commitment) in &transaction.packet_commitments {
+                if commitment_path.port_id == channel_end_path.0
+                    && commitment_path.channel_id == channel_end_path.1
+                {
+                    if commitment.is_some() {
+                        return Ok(true);
+                    }
+                    deleted_commitments = true;
+                }
+            }
+        }
+
+        let has_inflight_packets = self.ctx.has_inflight_packets(channel_end_path)?;
+
+        // Whether the deleted packet commitments were the last in-flight
+        // ones is only known once they are deleted from the host.
+        if has_inflight_packets && deleted_commitments {
+            if let Some(transaction) = self.transactions.last() {
+                transaction
+                    .flush_checks
+                    .borrow_mut()
+                    .push(channel_end_path.clone());
+            }
+        }
+
+        Ok(has_inflight_packets)
+    }
+
+    fn upgrade_timeout(&self) -> Duration {
+        self.ctx.upgrade_timeout()
+    }
+
+    fn max_packet_data_size(&self) -> Option<usize> {
+        self.ctx.max_packet_data_size()
+    }
+
+    fn packet_data_event_mode(&self) -> PacketDataEventMode {
+        self.ctx.packet_data_event_mode()
+    }
+
+    fn metrics(&self) -> &dyn Metrics {
+        self.ctx.metrics()
+    }
+
+    fn gas_meter(&self) -> &dyn GasMeter {
+        self
+    }
+
+    fn verified_proofs(&self) -> Option<&VerifiedProofs> {
+        self.ctx.verified_proofs()
+    }
+
+    fn channel_counter(&self) -> Result<u64, ContextError> {
+        let increments: u64 = self
+            .transactions
+            .iter()
+            .map(|transaction| transaction.channel_counter_increments)
+            .sum();
+
+        Ok(self.ctx.channel_counter()? + increments)
+    }
+
+    fn max_expected_time_per_block(&self) -> Duration {
+        self.ctx.max_expected_time_per_block()
+    }
+
+    fn validate_message_signer(&self, signer: &Signer) -> Result<(), ContextError> {
+        self.ctx.validate_message_signer(signer)
+    }
+
+    fn validate_connection_creator(
+        &self,
+        signer: &Signer,
+        client_id: &ClientId,
+    ) -> Result<(), ContextError> {
+        self.ctx.validate_connection_creator(signer, client_id)
+    }
+
+    fn identifier_rules(&self) -> IdentifierRules {
+        self.ctx.identifier_rules()
+    }
+
+    fn validate_channel_close(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<(), ContextError> {
+        self.ctx.validate_channel_close(port_id, channel_id)
+    }
+}
+
+impl<Ctx: ExecutionContext> ExecutionContext for OverlayContext<Ctx> {
+    type E = Ctx::E;
+
+    fn get_client_execution_context(&mut self) -> &mut Self::E {
+        self.ctx.get_client_execution_context()
+    }
+
+    fn increase_client_counter(&mut self) -> Result<(), ContextError> {
+        match self.current() {
+            Some(transaction) => {
+                transaction.client_counter_increments += 1;
+                Ok(())
+            }
+            None => self.ctx.increase_client_counter(),
+        }
+    }
+
+    fn store_client_params(&mut self, params: ClientParams) -> Result<(), ContextError> {
+        match self.current() {
+            Some(transaction) => {
+                transaction.client_params = Some(params);
+                Ok(())
+            }
+            None => self.ctx.store_client_params(params),
+        }
+    }
+
+    fn store_connection(
+        &mut self,
+        connection_path: &ConnectionPath,
+        connection_end: ConnectionEnd,
+    ) -> Result<(), ContextError> {
+        match self.current() {
+            Some(transaction) => {
+                transaction
+                    .connections
+                    .insert(connection_path.0.clone(), connection_end);
+                Ok(())
+            }
+            None => self.ctx.store_connection(connection_path, connection_end),
+        }
+    }
+
+    fn store_connection_to_client(
+        &mut self,
+        client_connection_path: &ClientConnectionPath,
+        conn_id: ConnectionId,
+    ) -> Result<(), ContextError> {
+        match self.current() {
+            Some(transaction) => {
+                transaction
+                    .client_connections
+                    .push((client_connection_path.clone(), conn_id));
+                Ok(())
+            }
+            None => self
+                .ctx
+                .store_connection_to_client(client_connection_path, conn_id),
+        }
+    }
+
+    fn increase_connection_counter(&mut self) -> Result<(), ContextError> {
+        match self.current() {
+            Some(transaction) => {
+                transaction.connection_counter_increments += 1;
+                Ok(())
+            }
+            None => self.ctx.increase_connection_counter(),
+        }
+    }
+
+    fn store_packet_commitment(
+        &mut self,
+        commitment_path: &CommitmentPath,
+        commitment: PacketCommitment,
+    ) -> Result<(), ContextError> {
+        match self.current() {
+            Some(transaction) => {
+                transaction
+                    .packet_commitments
+                    .insert(commitment_path.clone(), Some(commitment));
+                Ok(())
+            }
+            None => self
+                .ctx
+                .store_packet_commitment(commitment_path, commitment),
+        }
+    }
+
+    fn delete_packet_commitment(
+        &mut self,
+        commitment_path: &CommitmentPath,
+    ) -> Result<(), ContextError> {
+        match self.current() {
+            Some(transaction) => {
+                transaction
+                    .packet_commitments
+                    .insert(commitment_path.clone(), None);
+                Ok(())
+            }
+            None => self.ctx.delete_packet_commitment(commitment_path),
+        }
+    }
+
+    fn store_packet_receipt(
+        &mut self,
+        receipt_path: &ReceiptPath,
+        receipt: Receipt,
+    ) -> Result<(), ContextError> {
+        match self.current() {
+            Some(transaction) => {
+                transaction
+                    .packet_receipts
+                    .insert(receipt_path.clone(), receipt);
+                Ok(())
+            }
+            None => self.ctx.store_packet_receipt(receipt_path, receipt),
+        }
+    }
+
+    fn store_packet_acknowledgement(
+        &mut self,
+        ack_path: &AckPath,
+        ack_commitment: AcknowledgementCommitment,
+    ) -> Result<(), ContextError> {
+        match self.current() {
+            Some(transaction) => {
+                transaction
+                    .packet_acks
+                    .insert(ack_path.clone(), Some(ack_commitment));
+                Ok(())
+            }
+            None => self
+                .ctx
+                .store_packet_acknowledgement(ack_path, ack_commitment),
+        }
+    }
+
+    fn delete_packet_acknowledgement(&mut self, ack_path: &AckPath) -> Result<(), ContextError> {
+        match self.current() {
+            Some(transaction) => {
+                transaction.packet_acks.insert(ack_path.clone(), None);
+                Ok(())
+            }
+            None => self.ctx.delete_packet_acknowledgement(ack_path),
+        }
+    }
+
+    fn store_channel(
+        &mut self,
+        channel_end_path: &ChannelEndPath,
+        channel_end: ChannelEnd,
+    ) -> Result<(), ContextError> {
+        match self.current() {
+            Some(transaction) => {
+                transaction
+                    .channels
+                    .insert(channel_end_path.clone(), channel_end);
+                Ok(())
+            }
+            None => self.ctx.store_channel(channel_end_path, channel_end),
+        }
+    }
+
+    fn store_channel_upgrade(
+        &mut self,
+        upgrade_path: &ChannelUpgradePath,
+        upgrade: Upgrade,
+    ) -> Result<(), ContextError> {
+        match self.current() {
+            Some(transaction) => {
+                transaction
+                    .channel_upgrades
+                    .insert(upgrade_path.clone(), Some(upgrade));
+                Ok(())
+            }
+            None => self.ctx.store_channel_upgrade(upgrade_path, upgrade),
+        }
+    }
+
+    fn store_counterparty_channel_upgrade(
+        &mut self,
+        upgrade_path: &ChannelUpgradePath,
+        upgrade: Upgrade,
+    ) -> Result<(), ContextError> {
+        match self.current() {
+            Some(transaction) => {
+                transaction
+                    .counterparty_channel_upgrades
+                    .insert(upgrade_path.clone(), Some(upgrade));
+                Ok(())
+            }
+            None => self
+                .ctx
+                .store_counterparty_channel_upgrade(upgrade_path, upgrade),
+        }
+    }
+
+    fn delete_channel_upgrade(
+        &mut self,
+        upgrade_path: &ChannelUpgradePath,
+    ) -> Result<(), ContextError> {
+        match self.current() {
+            Some(transaction) => {
+                transaction
+                    .channel_upgrades
+                    .insert(upgrade_path.clone(), None);
+                transaction
+                    .counterparty_channel_upgrades
+                    .insert(upgrade_path.clone(), None);
+                Ok(())
+            }
+            None => self.ctx.delete_channel_upgrade(upgrade_path),
+        }
+    }
+
+    fn store_channel_upgrade_error_receipt(
+        &mut self,
+        upgrade_error_path: &ChannelUpgradeErrorPath,
+        error_receipt: ErrorReceipt,
+    ) -> Result<(), ContextError> {
+        match self.current() {
+            Some(transaction) => {
+                transaction
+                    .channel_upgrade_errors
+                    .insert(upgrade_error_path.clone(), error_receipt);
+                Ok(())
+            }
+            None => self
+                .ctx
+                .store_channel_upgrade_error_receipt(upgrade_error_path, error_receipt),
+        }
+    }
+
+    fn store_next_sequence_send(
+        &mut self,
+        seq_send_path: &SeqSendPath,
+        seq: Sequence,
+    ) -> Result<(), ContextError> {
+        match self.current() {
+            Some(transaction) => {
+                transaction
+                    .next_sequence_send
+                    .insert(seq_send_path.clone(), seq);
+                Ok(())
+            }
+            None => self.ctx.store_next_sequence_send(seq_send_path, seq),
+        }
+    }
+
+    fn store_next_sequence_recv(
+        &mut self,
+        seq_recv_path: &SeqRecvPath,
+        seq: Sequence,
+    ) -> Result<(), ContextError> {
+        match self.current() {
+            Some(transaction) => {
+                transaction
+                    .next_sequence_recv
+                    .insert(seq_recv_path.clone(), seq);
+                Ok(())
+            }
+            None => self.ctx.store_next_sequence_recv(seq_recv_path, seq),
+        }
+    }
+
+    fn store_next_sequence_ack(
+        &mut self,
+        seq_ack_path: &SeqAckPath,
+        seq: Sequence,
+    ) -> Result<(), ContextError> {
+        match self.current() {
+            Some(transaction) => {
+                transaction
+                    .next_sequence_ack
+                    .insert(seq_ack_path.clone(), seq);
+                Ok(())
+            }
+            None => self.ctx.store_next_sequence_ack(seq_ack_path, seq),
+        }
+    }
+
+    fn increase_channel_counter(&mut self) -> Result<(), ContextError> {
+        match self.current() {
+            Some(transaction) => {
+                transaction.channel_counter_increments += 1;
+                Ok(())
+            }
+            None => self.ctx.increase_channel_counter(),
+        }
+    }
+
+    fn emit_ibc_event(&mut self, event: IbcEvent) -> Result<(), ContextError> {
+        match self.current() {
+            Some(transaction) => {
+                transaction.events.push(event);
+                Ok(())
+            }
+            None => self.ctx.emit_ibc_event(event),
+        }
+    }
+
+    fn log_message(&mut self, message: String) -> Result<(), ContextError> {
+        match self.current() {
+            Some(transaction) => {
+                transaction.logs.push(message);
+                Ok(())
+            }
+            None => self.ctx.log_message(message),
+        }
+    }
+
+    /// Stages the writes in a transaction, which is written to the host
+    /// context in one batch once committed.
+    fn stage_writes(&mut self) -> Result<(), ContextError> {
+        self.begin_transaction();
+        Ok(())
+    }
+
+    fn commit_writes(&mut self) -> Result<(), ContextError> {
+        self.commit_transaction()
+    }
+
+    fn discard_writes(&mut self) {
+        self.rollback_transaction();
+    }
+
+    fn set_verified_proofs(&mut self, verified_proofs: Option<VerifiedProofs>) {
+        self.ctx.set_verified_proofs(verified_proofs);
+    }
+}
+
+fn upgrade_not_found(upgrade_path: &ChannelUpgradePath) -> ContextError {
+    ChannelError::UpgradeNotFound {
+        port_id: upgrade_path.0.clone(),
+        channel_id: upgrade_path.1.clone(),
+    }
+    .into()
+}
@@ -1,20 +1,29 @@
 use core::time::Duration;
 
+/// Returns the number of blocks a `delay_period_time` amounts to, given the
+/// maximum expected time per block: `delay_period_time` divided by
+/// `max_expected_time_per_block`, rounded up.
+///
+/// As in ibc-go, the computation is done with nanosecond precision, so that
+/// sub-second block times are accounted for, and a zero
+/// `max_expected_time_per_block` yields no block delay.
 pub fn calculate_block_delay(
     delay_period_time: &Duration,
     max_expected_time_per_block: &Duration,
 ) -> u64 {
-    let delay_period_time = delay_period_time.as_secs();
-    let max_expected_time_per_block = max_expected_time_per_block.as_secs();
+    let delay_period_time = delay_period_time.as_nanos();
+    let max_expected_time_per_block = max_expected_time_per_block.as_nanos();
     if max_expected_time_per_block == 0 {
         return 0;
     }
-    if delay_period_time % max_expected_time_per_block == 0 {
-        return delay_period_time / max_expected_time_per_block;
-    }
 
-    // TODO: Use `u64::div_ceil` here instead
-    (delay_period_time / max_expected_time_per_block) + 1
+    let block_delay = if delay_period_time % max_expected_time_per_block == 0 {
+        delay_period_time / max_expected_time_per_block
+    } else {
+        delay_period_time / max_expected_time_per_block + 1
+    };
+
+    u64::try_from(block_delay).unwrap_or(u64::MAX)
 }
 
 #[cfg(test)]
@@ -44,4 +53,22 @@ mod tests {
             expected
         );
     }
+
+    #[rstest]
+    #[case::sub_second_blocks(10_000, 500, 20)]
+    #[case::sub_second_remainder(1_000, 300, 4)]
+    #[case::sub_second_delay(500, 2_000, 1)]
+    fn test_calculate_block_delay_millis(
+        #[case] delay_period_time: u64,
+        #[case] max_expected_time_per_block: u64,
+        #[case] expected: u64,
+    ) {
+        assert_eq!(
+            calculate_block_delay(
+                &Duration::from_millis(delay_period_time),
+                &Duration::from_millis(max_expected_time_per_block)
+            ),
+            expected
+        );
+    }
 }
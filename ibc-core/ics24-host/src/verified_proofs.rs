@@ -0,0 +1,73 @@
+//! Defines the proofs of the packet messages of a block which the parallel
+//! dispatch entrypoint verifies ahead of their execution.
+//!
+//! Verifying a proof only depends on the proof, the path and value it proves,
+//! and the commitment root and prefix of the counterparty chain, which makes
+//! it the one step of the packet messages which can run in parallel. The
+//! entrypoint hands the [`VerifiedProofs`] to the context, for the core
+//! handlers to skip the proofs already verified while validating the messages
+//! in turn, which keeps their outcome the same as if they were dispatched one
+//! by one.
+
+use alloc::collections::BTreeSet;
+
+use ibc_core_commitment_types::commitment::{
+    CommitmentPrefix, CommitmentProofBytes, CommitmentRoot,
+};
+use ibc_core_host_types::identifiers::ClientId;
+use ibc_core_host_types::path::Path;
+use ibc_primitives::prelude::*;
+
+use crate::ProofKind;
+
+/// A proof of the state of the counterparty chain, as verified by a client.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct ProofCheck {
+    pub kind: ProofKind,
+    pub client_id: ClientId,
+    pub prefix: CommitmentPrefix,
+    pub root: CommitmentRoot,
+    pub path: Path,
+    pub proof: CommitmentProofBytes,
+    /// The value proven at the path, or `None` for a proof of its absence
+    pub value: Option<Vec<u8>>,
+}
+
+/// The proofs verified ahead of the packet messages of a batch, which the
+/// core handlers do not need to verify again.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct VerifiedProofs {
+    checks: BTreeSet<ProofCheck>,
+}
+
+impl VerifiedProofs {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Records the proof as verified.
+    pub fn insert(&mut self, check: ProofCheck) {
+        self.checks.insert(check);
+    }
+
+    /// Returns whether the proof was verified ahead of the batch.
+    pub fn contains(&self, check: &ProofCheck) -> bool {
+        self.checks.contains(check)
+    }
+
+    pub fn len(&self) -> usize {
+        self.checks.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.checks.is_empty()
+    }
+}
+
+impl FromIterator<ProofCheck> for VerifiedProofs {
+    fn from_iter<I: IntoIterator<Item = ProofCheck>>(iter: I) -> Self {
+        Self {
+            checks: iter.into_iter().collect(),
+        }
+    }
+}
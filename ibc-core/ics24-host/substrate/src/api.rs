@@ -0,0 +1,92 @@
+//! Defines the trait selecting the light clients and the runtime specifics of
+//! a pallet.
+
+use core::time::Duration;
+
+use ibc_core::client::context::client_state::{ClientStateExecution, ClientStateValidation};
+use ibc_core::client::context::consensus_state::ConsensusState as ConsensusStateTrait;
+use ibc_core::client::types::error::ClientError;
+use ibc_core::client::types::Height;
+use ibc_core::commitment_types::commitment::CommitmentPrefix;
+use ibc_core::handler::types::error::ContextError;
+use ibc_core::handler::types::events::IbcEvent;
+use ibc_core::primitives::prelude::*;
+use ibc_core::primitives::proto::Any;
+use ibc_core::primitives::{Signer, Timestamp};
+
+use crate::context::SubstrateContext;
+use crate::store::{ChildTrie, IBC_CHILD_TRIE_KEY};
+
+/// Selects the light clients a pallet hosts, the states the counterparties
+/// track the chain with, and the runtime specifics the [`SubstrateContext`]
+/// reads and writes through the pallet.
+///
+/// Implemented by the pallet, typically for its configuration, e.g.:
+///
+/// ```ignore
+/// impl<T: Config> SubstrateHost for Pallet<T> {
+///     type ClientState = AnyClientState;
+///     type ConsensusState = AnyConsensusState;
+///     type HostClientState = GrandpaClientState;
+///     type HostConsensusState = GrandpaConsensusState;
+///
+///     fn host_height() -> Result<Height, ContextError> {
+///         let number = frame_system::Pallet::<T>::block_number();
+///         Ok(Height::new(T::REVISION_NUMBER, number.saturated_into())?)
+///     }
+///
+///     fn deposit_event(event: IbcEvent) {
+///         Self::deposit_event(Event::Ibc { event });
+///     }
+///     ...
+/// }
+/// ```
+pub trait SubstrateHost: Sized {
+    /// The client states of the light clients hosted by the pallet.
+    type ClientState: ClientStateExecution<SubstrateContext<Self>> + Clone;
+    /// The consensus states of the light clients hosted by the pallet.
+    type ConsensusState: ConsensusStateTrait + Clone + Into<Any> + TryFrom<Any, Error = ClientError>;
+    /// The client state of the chain on the counterparties.
+    type HostClientState: ClientStateValidation<SubstrateContext<Self>>;
+    /// The consensus state of the chain on the counterparties.
+    type HostConsensusState: ConsensusStateTrait;
+
+    /// The child trie of the IBC store.
+    const CHILD_TRIE: ChildTrie = ChildTrie::new(IBC_CHILD_TRIE_KEY);
+
+    /// Returns the height of the current block, e.g. from `frame_system`.
+    fn host_height() -> Result<Height, ContextError>;
+
+    /// Returns the timestamp of the current block, e.g. from
+    /// `pallet_timestamp`.
+    fn host_timestamp() -> Result<Timestamp, ContextError>;
+
+    /// Returns the consensus state of the chain at `height`.
+    fn host_consensus_state(
+        ctx: &SubstrateContext<Self>,
+        height: &Height,
+    ) -> Result<Self::HostConsensusState, ContextError>;
+
+    /// Validates the client state of the chain on a counterparty.
+    fn validate_self_client(
+        ctx: &SubstrateContext<Self>,
+        client_state_of_host_on_counterparty: Self::HostClientState,
+    ) -> Result<(), ContextError>;
+
+    /// Validates the signer of the IBC messages, e.g. as an SS58 address.
+    fn validate_message_signer(signer: &Signer) -> Result<(), ContextError>;
+
+    /// Returns the maximum time expected between two blocks of the chain.
+    fn max_expected_time_per_block() -> Duration;
+
+    /// Deposits the IBC event as an event of the pallet.
+    fn deposit_event(event: IbcEvent);
+
+    /// Logs the message of an IBC application, which is ignored by default.
+    fn log_message(_message: String) {}
+
+    /// Returns the prefix the counterparties prove the IBC store under.
+    fn commitment_prefix() -> CommitmentPrefix {
+        CommitmentPrefix::try_from(b"ibc".to_vec()).expect("Never fails")
+    }
+}
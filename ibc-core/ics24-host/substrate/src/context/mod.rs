@@ -0,0 +1,111 @@
+//! Implements the IBC contexts over the [`ChildTrie`] of a pallet.
+//!
+//! The values are stored at the keys of their ICS-24 paths, save for the
+//! upgrades of the counterparties of the channels, which are stored under
+//! `counterparty/` followed by the path of the upgrade of the channel.
+
+mod client_ctx;
+mod core_ctx;
+#[cfg(feature = "tendermint")]
+mod tendermint_ctx;
+
+use core::marker::PhantomData;
+
+use ibc_core::client::context::HostClock;
+use ibc_core::client::types::error::ClientError;
+use ibc_core::client::types::Height;
+use ibc_core::handler::types::error::ContextError;
+use ibc_core::host::types::identifiers::ClientId;
+use ibc_core::host::types::path::{full_consensus_state_path, ChannelUpgradePath};
+use ibc_core::primitives::prelude::*;
+use ibc_core::primitives::Timestamp;
+
+use crate::api::SubstrateHost;
+use crate::store::ChildTrie;
+
+/// The context of a pallet hosting IBC, as selected by `H`.
+///
+/// The context holds no state of its own, as the runtime storage is global,
+/// and so is created anew by each call of the pallet.
+pub struct SubstrateContext<H> {
+    host: PhantomData<H>,
+}
+
+impl<H> SubstrateContext<H> {
+    pub fn new() -> Self {
+        Self { host: PhantomData }
+    }
+}
+
+impl<H> Default for SubstrateContext<H> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<H: SubstrateHost> SubstrateContext<H> {
+    pub fn store(&self) -> ChildTrie {
+        H::CHILD_TRIE
+    }
+
+    /// Returns the heights of the consensus states stored for the client, in
+    /// ascending order.
+    pub fn consensus_state_heights(
+        &self,
+        client_id: &ClientId,
+    ) -> Result<Vec<Height>, ClientError> {
+        let prefix = format!("{}/", full_consensus_state_path(client_id));
+
+        // The update metadata are stored under the paths of the consensus
+        // states, and so are skipped along with their separator
+        let mut heights = self
+            .store()
+            .keys(&prefix)
+            .filter_map(|key| {
+                key.strip_prefix(&prefix)
+                    .filter(|height| !height.contains('/'))
+                    .map(|height| height.parse::<Height>())
+            })
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| ClientError::Other {
+                description: e.to_string(),
+            })?;
+
+        heights.sort();
+
+        Ok(heights)
+    }
+
+    fn get_counter(&self, key: &str) -> Result<u64, ContextError> {
+        Ok(self.store().get(key)?.unwrap_or_default())
+    }
+
+    fn increase_counter(
+        &mut self,
+        key: &str,
+        overflow: impl Into<ContextError>,
+    ) -> Result<(), ContextError> {
+        let counter = self
+            .get_counter(key)?
+            .checked_add(1)
+            .ok_or_else(|| overflow.into())?;
+
+        self.store().set(key, &counter);
+
+        Ok(())
+    }
+}
+
+impl<H: SubstrateHost> HostClock for SubstrateContext<H> {
+    fn host_height(&self) -> Result<Height, ContextError> {
+        H::host_height()
+    }
+
+    fn host_timestamp(&self) -> Result<Timestamp, ContextError> {
+        H::host_timestamp()
+    }
+}
+
+pub(crate) fn counterparty_upgrade_key(upgrade_path: &ChannelUpgradePath) -> String {
+    format!("counterparty/{upgrade_path}")
+}
@@ -0,0 +1,62 @@
+use ibc_client_tendermint::context::{ConsensusStateConverter, ValidationContext};
+use ibc_core::client::context::ClientValidationContext;
+use ibc_core::client::types::Height;
+use ibc_core::handler::types::error::ContextError;
+use ibc_core::host::types::identifiers::ClientId;
+use ibc_core::host::types::path::ClientConsensusStatePath;
+use ibc_core::primitives::prelude::*;
+
+use super::SubstrateContext;
+use crate::api::SubstrateHost;
+
+impl<H: SubstrateHost> ValidationContext for SubstrateContext<H>
+where
+    H::ConsensusState: ConsensusStateConverter,
+{
+    fn consensus_state_heights(&self, client_id: &ClientId) -> Result<Vec<Height>, ContextError> {
+        Ok(SubstrateContext::consensus_state_heights(self, client_id)?)
+    }
+
+    fn next_consensus_state(
+        &self,
+        client_id: &ClientId,
+        height: &Height,
+    ) -> Result<Option<Self::ConsensusStateRef>, ContextError> {
+        let heights = SubstrateContext::consensus_state_heights(self, client_id)?;
+
+        heights
+            .into_iter()
+            .find(|h| h > height)
+            .map(|next| self.consensus_state_at(client_id, &next))
+            .transpose()
+    }
+
+    fn prev_consensus_state(
+        &self,
+        client_id: &ClientId,
+        height: &Height,
+    ) -> Result<Option<Self::ConsensusStateRef>, ContextError> {
+        let heights = SubstrateContext::consensus_state_heights(self, client_id)?;
+
+        heights
+            .into_iter()
+            .rev()
+            .find(|h| h < height)
+            .map(|prev| self.consensus_state_at(client_id, &prev))
+            .transpose()
+    }
+}
+
+impl<H: SubstrateHost> SubstrateContext<H> {
+    fn consensus_state_at(
+        &self,
+        client_id: &ClientId,
+        height: &Height,
+    ) -> Result<H::ConsensusState, ContextError> {
+        self.consensus_state(&ClientConsensusStatePath::new(
+            client_id.clone(),
+            height.revision_number(),
+            height.revision_height(),
+        ))
+    }
+}
@@ -0,0 +1,36 @@
+//! Provides the adapter implementing the IBC contexts over the storage of a
+//! Substrate runtime, so that pallets can host ibc-rs with minimal glue.
+//!
+//! The values of the ICS-24 paths are SCALE-encoded and stored at the keys of
+//! their paths in a child trie of the runtime, the [`ChildTrie`](store::ChildTrie),
+//! whose root commits to the IBC store for the proofs of the counterparties.
+//! Client and consensus states are stored as the protobuf-encoded `Any`s the
+//! light clients define them as.
+//!
+//! The [`SubstrateContext`](context::SubstrateContext) implements the
+//! validation and execution contexts of the core handlers over the trie, for
+//! the light clients and the runtime specifics selected by a
+//! [`SubstrateHost`](api::SubstrateHost), which the pallet implements with its
+//! configuration, e.g. to read the block number and timestamp of the runtime,
+//! or deposit the IBC events as its own.
+//!
+//! As the storage changes of a failed extrinsic are reverted by the runtime,
+//! the context does not stage them.
+#![no_std]
+#![forbid(unsafe_code)]
+#![cfg_attr(not(test), deny(clippy::unwrap_used))]
+#![cfg_attr(not(test), deny(clippy::disallowed_methods, clippy::disallowed_types))]
+#![deny(
+    warnings,
+    trivial_numeric_casts,
+    unused_import_braces,
+    unused_qualifications,
+    rust_2018_idioms
+)]
+
+#[cfg(any(test, feature = "std"))]
+extern crate std;
+
+pub mod api;
+pub mod context;
+pub mod store;
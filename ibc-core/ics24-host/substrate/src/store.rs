@@ -0,0 +1,82 @@
+//! Stores the values of the ICS-24 paths in a child trie of the runtime.
+
+use ibc_core::client::types::error::ClientError;
+use ibc_core::primitives::prelude::*;
+use parity_scale_codec::{Decode, Encode};
+use sp_io::default_child_storage;
+
+/// The default key of the child trie of the IBC store.
+pub const IBC_CHILD_TRIE_KEY: &[u8] = b"ibc";
+
+/// The child trie of the IBC store, keyed by the ICS-24 paths.
+///
+/// The trie is a default child trie of the runtime, and so its root is
+/// committed to by the state root of the runtime, for the counterparties to
+/// verify the proofs of the IBC store against.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ChildTrie {
+    storage_key: &'static [u8],
+}
+
+impl ChildTrie {
+    pub const fn new(storage_key: &'static [u8]) -> Self {
+        Self { storage_key }
+    }
+
+    pub fn storage_key(&self) -> &'static [u8] {
+        self.storage_key
+    }
+
+    /// Reads and decodes the value stored at `key`, if any.
+    pub fn get<T: Decode>(&self, key: &str) -> Result<Option<T>, ClientError> {
+        self.get_bytes(key)
+            .map(|bytes| {
+                T::decode(&mut bytes.as_slice()).map_err(|e| ClientError::Other {
+                    description: format!("failed to decode the value of `{key}`: {e}"),
+                })
+            })
+            .transpose()
+    }
+
+    /// Encodes and stores `value` at `key`.
+    pub fn set<T: Encode>(&self, key: &str, value: &T) {
+        self.set_bytes(key, &value.encode());
+    }
+
+    pub fn get_bytes(&self, key: &str) -> Option<Vec<u8>> {
+        default_child_storage::get(self.storage_key, key.as_bytes())
+    }
+
+    pub fn set_bytes(&self, key: &str, bytes: &[u8]) {
+        default_child_storage::set(self.storage_key, key.as_bytes(), bytes);
+    }
+
+    pub fn delete(&self, key: &str) {
+        default_child_storage::clear(self.storage_key, key.as_bytes());
+    }
+
+    /// Returns the keys stored under `prefix`, in lexicographic order.
+    pub fn keys(&self, prefix: &str) -> impl Iterator<Item = String> + '_ {
+        let prefix = prefix.as_bytes().to_vec();
+        let mut next = Some(prefix.clone());
+
+        core::iter::from_fn(move || {
+            let key = default_child_storage::next_key(self.storage_key, next.as_deref()?)
+                .filter(|key| key.starts_with(&prefix));
+            next = key.clone();
+
+            key.map(|key| String::from_utf8_lossy(&key).into_owned())
+        })
+    }
+
+    /// Returns whether any key is stored under `prefix`.
+    pub fn has_keys(&self, prefix: &str) -> bool {
+        self.keys(prefix).next().is_some()
+    }
+}
+
+impl Default for ChildTrie {
+    fn default() -> Self {
+        Self::new(IBC_CHILD_TRIE_KEY)
+    }
+}
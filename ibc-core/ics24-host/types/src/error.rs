@@ -18,5 +18,4 @@ pub enum IdentifierError {
     InvalidStringAsSequence { value: String, reason: String },
 }
 
-#[cfg(feature = "std")]
-impl std::error::Error for IdentifierError {}
+impl core::error::Error for IdentifierError {}
@@ -6,6 +6,10 @@ use ibc_primitives::prelude::*;
 use crate::error::IdentifierError;
 use crate::validate::{validate_client_identifier, validate_client_type};
 
+/// The sentinel identifier of the localhost client, which is not suffixed by
+/// a counter as there is only ever one such client.
+const LOCALHOST_CLIENT_ID: &str = "09-localhost";
+
 #[cfg_attr(
     feature = "parity-scale-codec",
     derive(
@@ -57,6 +61,16 @@ impl ClientId {
         Self(client_id)
     }
 
+    /// Returns the sentinel identifier of the localhost client.
+    pub fn localhost() -> Self {
+        Self(LOCALHOST_CLIENT_ID.to_string())
+    }
+
+    /// Returns `true` if this is the identifier of the localhost client.
+    pub fn is_localhost(&self) -> bool {
+        self.0 == LOCALHOST_CLIENT_ID
+    }
+
     /// Get this identifier as a borrowed `&str`
     pub fn as_str(&self) -> &str {
         &self.0
@@ -9,6 +9,10 @@ use crate::validate::validate_connection_identifier;
 
 const CONNECTION_ID_PREFIX: &str = "connection";
 
+/// The sentinel identifier of the connection of the localhost client to the
+/// host itself.
+const LOCALHOST_CONNECTION_ID: &str = "connection-localhost";
+
 #[cfg_attr(
     feature = "parity-scale-codec",
     derive(
@@ -61,6 +65,16 @@ impl ConnectionId {
     pub fn zero() -> Self {
         Self::new(0)
     }
+
+    /// Returns the sentinel identifier of the localhost connection.
+    pub fn localhost() -> Self {
+        Self(LOCALHOST_CONNECTION_ID.to_string())
+    }
+
+    /// Returns `true` if this is the identifier of the localhost connection.
+    pub fn is_localhost(&self) -> bool {
+        self.0 == LOCALHOST_CONNECTION_ID
+    }
 }
 
 /// This implementation provides a `to_string` method.
@@ -0,0 +1,229 @@
+//! Defines the codec between the store paths and the keys of the IBC store,
+//! along with the key prefixes of the groups of paths which storage backends
+//! query by range or prune, e.g. all the packet commitments of a channel end.
+use core::str::FromStr;
+
+use ibc_primitives::prelude::*;
+
+use crate::identifiers::{ChannelId, ClientId, PortId};
+use crate::path::{
+    Path, PathError, CHANNEL_END_PREFIX, CHANNEL_PREFIX, CLIENT_PREFIX, CONNECTION_PREFIX,
+    CONSENSUS_STATE_PREFIX, PACKET_ACK_PREFIX, PACKET_COMMITMENT_PREFIX, PACKET_RECEIPT_PREFIX,
+    PORT_PREFIX, SEQUENCE_PREFIX,
+};
+
+impl Path {
+    /// Returns the canonical key of the path in the IBC store, which is the
+    /// string representation of the path.
+    pub fn to_key(&self) -> Vec<u8> {
+        self.to_string().into_bytes()
+    }
+
+    /// Decodes the path from its key in the IBC store, as returned by
+    /// [`Path::to_key`].
+    pub fn from_key(key: &[u8]) -> Result<Self, PathError> {
+        let path = core::str::from_utf8(key).map_err(|_| PathError::ParseFailure {
+            path: String::from_utf8_lossy(key).into_owned(),
+        })?;
+
+        Path::from_str(path)
+    }
+}
+
+/// The prefix of the keys of a group of paths in the IBC store.
+///
+/// A prefix always ends with a `/`, so that the prefix of the client
+/// `07-tendermint-1`, say, does not match the keys of the client
+/// `07-tendermint-10`. The keys matching a prefix are sorted
+/// lexicographically, and not e.g. by sequence, by the storage backends;
+/// they should be decoded with [`Path::from_key`] to be ordered otherwise.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct KeyPrefix(Vec<u8>);
+
+impl KeyPrefix {
+    fn new(prefix: String) -> Self {
+        Self(format!("{prefix}/").into_bytes())
+    }
+
+    /// Returns the prefix of all the states of a client, i.e. its client
+    /// state, its consensus states and the connections it backs.
+    pub fn client(client_id: &ClientId) -> Self {
+        Self::new(format!("{CLIENT_PREFIX}/{client_id}"))
+    }
+
+    /// Returns the prefix of all the consensus states of a client.
+    ///
+    /// Note that the processed time and height of each consensus state are
+    /// stored under the same prefix.
+    pub fn consensus_states(client_id: &ClientId) -> Self {
+        Self::new(format!(
+            "{CLIENT_PREFIX}/{client_id}/{CONSENSUS_STATE_PREFIX}"
+        ))
+    }
+
+    /// Returns the prefix of all the connection ends.
+    pub fn connections() -> Self {
+        Self::new(CONNECTION_PREFIX.to_string())
+    }
+
+    /// Returns the prefix of all the channel ends.
+    pub fn channel_ends() -> Self {
+        Self::new(CHANNEL_END_PREFIX.to_string())
+    }
+
+    /// Returns the prefix of all the packet commitments of a channel end.
+    pub fn commitments(port_id: &PortId, channel_id: &ChannelId) -> Self {
+        Self::channel_sequences(PACKET_COMMITMENT_PREFIX, port_id, channel_id)
+    }
+
+    /// Returns the prefix of all the packet acknowledgements of a channel end.
+    pub fn acks(port_id: &PortId, channel_id: &ChannelId) -> Self {
+        Self::channel_sequences(PACKET_ACK_PREFIX, port_id, channel_id)
+    }
+
+    /// Returns the prefix of all the packet receipts of a channel end.
+    pub fn receipts(port_id: &PortId, channel_id: &ChannelId) -> Self {
+        Self::channel_sequences(PACKET_RECEIPT_PREFIX, port_id, channel_id)
+    }
+
+    /// Returns the prefix of all the commitments of the IBC v2 packets sent
+    /// from a client.
+    pub fn commitments_v2(client_id: &ClientId) -> Self {
+        Self::client_sequences(PACKET_COMMITMENT_PREFIX, client_id)
+    }
+
+    /// Returns the prefix of all the acknowledgements of the IBC v2 packets
+    /// received by a client.
+    pub fn acks_v2(client_id: &ClientId) -> Self {
+        Self::client_sequences(PACKET_ACK_PREFIX, client_id)
+    }
+
+    /// Returns the prefix of all the receipts of the IBC v2 packets received
+    /// by a client.
+    pub fn receipts_v2(client_id: &ClientId) -> Self {
+        Self::client_sequences(PACKET_RECEIPT_PREFIX, client_id)
+    }
+
+    fn channel_sequences(prefix: &str, port_id: &PortId, channel_id: &ChannelId) -> Self {
+        Self::new(format!(
+            "{prefix}/{PORT_PREFIX}/{port_id}/{CHANNEL_PREFIX}/{channel_id}/{SEQUENCE_PREFIX}"
+        ))
+    }
+
+    fn client_sequences(prefix: &str, client_id: &ClientId) -> Self {
+        Self::new(format!(
+            "{prefix}/{CLIENT_PREFIX}/{client_id}/{SEQUENCE_PREFIX}"
+        ))
+    }
+
+    /// Returns whether the key starts with the prefix.
+    pub fn matches(&self, key: &[u8]) -> bool {
+        key.starts_with(&self.0)
+    }
+
+    /// Returns the exclusive upper bound of the keys starting with the
+    /// prefix, for the range queries over `[prefix, range_end)`.
+    pub fn range_end(&self) -> Vec<u8> {
+        let mut end = self.0.clone();
+
+        // The prefix ends with a `/`, which is incremented without overflow.
+        if let Some(last) = end.last_mut() {
+            *last += 1;
+        }
+
+        end
+    }
+
+    /// Returns the bytes of the prefix.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl AsRef<[u8]> for KeyPrefix {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::identifiers::Sequence;
+    use crate::path::{
+        ClientConsensusStatePath, ClientStatePath, CommitmentPath, CommitmentPathV2, ReceiptPathV2,
+    };
+
+    #[rstest::rstest]
+    #[case(Path::ClientState(ClientStatePath::new(ClientId::new("07-tendermint", 1).unwrap())))]
+    #[case(Path::Commitment(CommitmentPath::new(
+        &PortId::transfer(),
+        &ChannelId::new(1),
+        Sequence::from(10)
+    )))]
+    #[case(Path::ReceiptV2(ReceiptPathV2::new(
+        &ClientId::new("07-tendermint", 1).unwrap(),
+        Sequence::from(10)
+    )))]
+    fn test_key_roundtrip(#[case] path: Path) {
+        assert_eq!(Path::from_key(&path.to_key()).unwrap(), path);
+    }
+
+    #[test]
+    fn test_invalid_key() {
+        assert!(Path::from_key(&[0xff, 0xfe]).is_err());
+        assert!(Path::from_key(b"clients").is_err());
+    }
+
+    #[test]
+    fn test_prefix_matches() {
+        let client_id = ClientId::new("07-tendermint", 1).unwrap();
+        let other_client_id = ClientId::new("07-tendermint", 10).unwrap();
+
+        let prefix = KeyPrefix::consensus_states(&client_id);
+
+        let key = Path::from(ClientConsensusStatePath::new(client_id.clone(), 0, 5)).to_key();
+        let other_key = Path::from(ClientConsensusStatePath::new(other_client_id, 0, 5)).to_key();
+
+        assert!(prefix.matches(&key));
+        assert!(!prefix.matches(&other_key));
+        assert!(!KeyPrefix::client(&client_id).matches(&other_key));
+
+        let prefix = KeyPrefix::commitments(&PortId::transfer(), &ChannelId::new(1));
+
+        let key = Path::from(CommitmentPath::new(
+            &PortId::transfer(),
+            &ChannelId::new(1),
+            Sequence::from(2),
+        ))
+        .to_key();
+        let other_key = Path::from(CommitmentPath::new(
+            &PortId::transfer(),
+            &ChannelId::new(10),
+            Sequence::from(2),
+        ))
+        .to_key();
+
+        assert!(prefix.matches(&key));
+        assert!(!prefix.matches(&other_key));
+
+        let key_v2 = Path::from(CommitmentPathV2::new(&client_id, Sequence::from(2))).to_key();
+
+        assert!(KeyPrefix::commitments_v2(&client_id).matches(&key_v2));
+    }
+
+    #[test]
+    fn test_prefix_range_end() {
+        let prefix = KeyPrefix::commitments(&PortId::transfer(), &ChannelId::new(1));
+        let key = Path::from(CommitmentPath::new(
+            &PortId::transfer(),
+            &ChannelId::new(1),
+            Sequence::from(u64::MAX),
+        ))
+        .to_key();
+
+        assert!(prefix.as_bytes() <= key.as_slice());
+        assert!(key < prefix.range_end());
+        assert!(!prefix.matches(&prefix.range_end()));
+    }
+}
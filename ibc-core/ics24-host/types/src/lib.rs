@@ -17,5 +17,6 @@ extern crate std;
 
 pub mod error;
 pub mod identifiers;
+pub mod key;
 pub mod path;
-pub(crate) mod validate;
+pub mod validate;
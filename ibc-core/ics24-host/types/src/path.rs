@@ -29,6 +29,9 @@ pub const NEXT_SEQ_ACK_PREFIX: &str = "nextSequenceAck";
 pub const PACKET_COMMITMENT_PREFIX: &str = "commitments";
 pub const PACKET_ACK_PREFIX: &str = "acks";
 pub const PACKET_RECEIPT_PREFIX: &str = "receipts";
+pub const CHANNEL_UPGRADE_PREFIX: &str = "channelUpgrades";
+pub const UPGRADES_PREFIX: &str = "upgrades";
+pub const UPGRADE_ERROR_PREFIX: &str = "upgradeError";
 
 pub const ITERATE_CONSENSUS_STATE_PREFIX: &str = "iterateConsensusStates";
 pub const PROCESSED_TIME: &str = "processedTime";
@@ -63,6 +66,12 @@ pub enum Path {
     Ack(AckPath),
     Receipt(ReceiptPath),
     UpgradeClient(UpgradeClientPath),
+    ChannelUpgrade(ChannelUpgradePath),
+    ChannelUpgradeError(ChannelUpgradeErrorPath),
+    SeqSendV2(SeqSendPathV2),
+    CommitmentV2(CommitmentPathV2),
+    AckV2(AckPathV2),
+    ReceiptV2(ReceiptPathV2),
 }
 
 #[cfg_attr(
@@ -665,6 +674,180 @@ pub enum UpgradeClientPath {
     UpgradedClientConsensusState(u64),
 }
 
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// The path under which the upgrade proposed for a channel is stored, while
+/// the channel upgrade handshake is in progress.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Display)]
+#[display(
+    fmt = "{CHANNEL_UPGRADE_PREFIX}/{UPGRADES_PREFIX}/{PORT_PREFIX}/{_0}/{CHANNEL_PREFIX}/{_1}"
+)]
+pub struct ChannelUpgradePath(pub PortId, pub ChannelId);
+
+impl ChannelUpgradePath {
+    pub fn new(port_id: &PortId, channel_id: &ChannelId) -> ChannelUpgradePath {
+        ChannelUpgradePath(port_id.clone(), channel_id.clone())
+    }
+}
+
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// The path under which the error receipt of the last aborted upgrade of a
+/// channel is stored.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Display)]
+#[display(
+    fmt = "{CHANNEL_UPGRADE_PREFIX}/{UPGRADE_ERROR_PREFIX}/{PORT_PREFIX}/{_0}/{CHANNEL_PREFIX}/{_1}"
+)]
+pub struct ChannelUpgradeErrorPath(pub PortId, pub ChannelId);
+
+impl ChannelUpgradeErrorPath {
+    pub fn new(port_id: &PortId, channel_id: &ChannelId) -> ChannelUpgradeErrorPath {
+        ChannelUpgradeErrorPath(port_id.clone(), channel_id.clone())
+    }
+}
+
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Display)]
+#[display(fmt = "{NEXT_SEQ_SEND_PREFIX}/{CLIENT_PREFIX}/{_0}")]
+/// The path of the next sequence to send IBC v2 packets from, over the given
+/// client.
+pub struct SeqSendPathV2(pub ClientId);
+
+impl SeqSendPathV2 {
+    pub fn new(client_id: &ClientId) -> SeqSendPathV2 {
+        SeqSendPathV2(client_id.clone())
+    }
+}
+
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Display)]
+#[display(
+    fmt = "{PACKET_COMMITMENT_PREFIX}/{CLIENT_PREFIX}/{client_id}/{SEQUENCE_PREFIX}/{sequence}"
+)]
+/// The path of the commitment of an IBC v2 packet, keyed by the source client
+/// of the packet.
+pub struct CommitmentPathV2 {
+    pub client_id: ClientId,
+    pub sequence: Sequence,
+}
+
+impl CommitmentPathV2 {
+    pub fn new(client_id: &ClientId, sequence: Sequence) -> CommitmentPathV2 {
+        CommitmentPathV2 {
+            client_id: client_id.clone(),
+            sequence,
+        }
+    }
+}
+
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Display)]
+#[display(fmt = "{PACKET_ACK_PREFIX}/{CLIENT_PREFIX}/{client_id}/{SEQUENCE_PREFIX}/{sequence}")]
+/// The path of the acknowledgement commitment of an IBC v2 packet, keyed by
+/// the destination client of the packet.
+pub struct AckPathV2 {
+    pub client_id: ClientId,
+    pub sequence: Sequence,
+}
+
+impl AckPathV2 {
+    pub fn new(client_id: &ClientId, sequence: Sequence) -> AckPathV2 {
+        AckPathV2 {
+            client_id: client_id.clone(),
+            sequence,
+        }
+    }
+}
+
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Display)]
+#[display(fmt = "{PACKET_RECEIPT_PREFIX}/{CLIENT_PREFIX}/{client_id}/{SEQUENCE_PREFIX}/{sequence}")]
+/// The path of the receipt of an IBC v2 packet, keyed by the destination
+/// client of the packet.
+pub struct ReceiptPathV2 {
+    pub client_id: ClientId,
+    pub sequence: Sequence,
+}
+
+impl ReceiptPathV2 {
+    pub fn new(client_id: &ClientId, sequence: Sequence) -> ReceiptPathV2 {
+        ReceiptPathV2 {
+            client_id: client_id.clone(),
+            sequence,
+        }
+    }
+}
+
 #[cfg_attr(
     feature = "parity-scale-codec",
     derive(
@@ -704,8 +887,7 @@ pub enum PathError {
     ParseFailure { path: String },
 }
 
-#[cfg(feature = "std")]
-impl std::error::Error for PathError {}
+impl core::error::Error for PathError {}
 
 /// The FromStr trait allows paths encoded as strings to be parsed into Paths.
 impl FromStr for Path {
@@ -724,6 +906,8 @@ impl FromStr for Path {
             .or_else(|| parse_acks(&components))
             .or_else(|| parse_receipts(&components))
             .or_else(|| parse_upgrades(&components))
+            .or_else(|| parse_channel_upgrades(&components))
+            .or_else(|| parse_packet_paths_v2(&components))
             .ok_or(PathError::ParseFailure {
                 path: s.to_string(),
             })
@@ -750,7 +934,7 @@ fn parse_client_paths(components: &[&str]) -> Option<Path> {
         return None;
     }
 
-    let client_id = ClientId::from_str(components[1]).ok()?;
+    let client_id = ClientId::from_str(components.get(1)?).ok()?;
 
     if components.len() == 3 {
         match components[2] {
@@ -1071,6 +1255,87 @@ fn parse_upgrades(components: &[&str]) -> Option<Path> {
     }
 }
 
+fn parse_channel_upgrades(components: &[&str]) -> Option<Path> {
+    if components.len() != 6 {
+        return None;
+    }
+
+    if components[0] != CHANNEL_UPGRADE_PREFIX
+        || !matches!(components[1], UPGRADES_PREFIX | UPGRADE_ERROR_PREFIX)
+    {
+        return None;
+    }
+
+    let port = parse_ports(&components[2..=3]);
+    let channel = parse_channels(&components[4..=5]);
+
+    let Some(Path::Ports(PortPath(port_id))) = port else {
+        return None;
+    };
+
+    let Some(SubPath::Channels(channel_id)) = channel else {
+        return None;
+    };
+
+    if components[1] == UPGRADE_ERROR_PREFIX {
+        Some(ChannelUpgradeErrorPath(port_id, channel_id).into())
+    } else {
+        Some(ChannelUpgradePath(port_id, channel_id).into())
+    }
+}
+
+/// Parses the paths of the IBC v2 packet state, which are keyed by client
+/// rather than by port and channel.
+fn parse_packet_paths_v2(components: &[&str]) -> Option<Path> {
+    if components.len() != 3 && components.len() != 5 {
+        return None;
+    }
+
+    let first = *components.first()?;
+
+    if components[1] != CLIENT_PREFIX {
+        return None;
+    }
+
+    let client_id = ClientId::from_str(components[2]).ok()?;
+
+    if components.len() == 3 {
+        return match first {
+            NEXT_SEQ_SEND_PREFIX => Some(SeqSendPathV2(client_id).into()),
+            _ => None,
+        };
+    }
+
+    let Some(SubPath::Sequences(sequence)) = parse_sequences(&components[3..]) else {
+        return None;
+    };
+
+    match first {
+        PACKET_COMMITMENT_PREFIX => Some(
+            CommitmentPathV2 {
+                client_id,
+                sequence,
+            }
+            .into(),
+        ),
+        PACKET_ACK_PREFIX => Some(
+            AckPathV2 {
+                client_id,
+                sequence,
+            }
+            .into(),
+        ),
+        PACKET_RECEIPT_PREFIX => Some(
+            ReceiptPathV2 {
+                client_id,
+                sequence,
+            }
+            .into(),
+        ),
+        _ => None,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1176,6 +1441,39 @@ mod tests {
         "upgradedIBCState/0/upgradedConsState",
         Path::UpgradeClient(UpgradeClientPath::UpgradedClientConsensusState(0))
     )]
+    #[case(
+        "channelUpgrades/upgrades/ports/transfer/channels/channel-0",
+        Path::ChannelUpgrade(ChannelUpgradePath(PortId::transfer(), ChannelId::zero()))
+    )]
+    #[case(
+        "channelUpgrades/upgradeError/ports/transfer/channels/channel-0",
+        Path::ChannelUpgradeError(ChannelUpgradeErrorPath(PortId::transfer(), ChannelId::zero()))
+    )]
+    #[case(
+        "nextSequenceSend/clients/07-tendermint-0",
+        Path::SeqSendV2(SeqSendPathV2(ClientId::new_dummy()))
+    )]
+    #[case(
+        "commitments/clients/07-tendermint-0/sequences/1",
+        Path::CommitmentV2(CommitmentPathV2 {
+            client_id: ClientId::new_dummy(),
+            sequence: Sequence::from(1),
+        })
+    )]
+    #[case(
+        "acks/clients/07-tendermint-0/sequences/1",
+        Path::AckV2(AckPathV2 {
+            client_id: ClientId::new_dummy(),
+            sequence: Sequence::from(1),
+        })
+    )]
+    #[case(
+        "receipts/clients/07-tendermint-0/sequences/1",
+        Path::ReceiptV2(ReceiptPathV2 {
+            client_id: ClientId::new_dummy(),
+            sequence: Sequence::from(1),
+        })
+    )]
     fn test_successful_parsing(#[case] path_str: &str, #[case] path: Path) {
         // can be parsed into Path
         assert_eq!(Path::from_str(path_str).expect("no error"), path);
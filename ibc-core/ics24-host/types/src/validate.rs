@@ -72,8 +72,7 @@ pub fn validate_client_type(id: &str) -> Result<(), Error> {
 /// A valid client identifier must be between 9-64 characters as specified in
 /// the ICS-24 spec.
 pub fn validate_client_identifier(id: &str) -> Result<(), Error> {
-    validate_identifier_chars(id)?;
-    validate_identifier_length(id, 9, 64)
+    IdentifierRules::ICS24.validate_client_identifier(id)
 }
 
 /// Default validator function for Connection identifiers.
@@ -81,8 +80,7 @@ pub fn validate_client_identifier(id: &str) -> Result<(), Error> {
 /// A valid connection identifier must be between 10-64 characters as specified
 /// in the ICS-24 spec.
 pub fn validate_connection_identifier(id: &str) -> Result<(), Error> {
-    validate_identifier_chars(id)?;
-    validate_identifier_length(id, 10, 64)
+    IdentifierRules::ICS24.validate_connection_identifier(id)
 }
 
 /// Default validator function for Port identifiers.
@@ -90,8 +88,7 @@ pub fn validate_connection_identifier(id: &str) -> Result<(), Error> {
 /// A valid port identifier must be between 2-128 characters as specified in the
 /// ICS-24 spec.
 pub fn validate_port_identifier(id: &str) -> Result<(), Error> {
-    validate_identifier_chars(id)?;
-    validate_identifier_length(id, 2, 128)
+    IdentifierRules::ICS24.validate_port_identifier(id)
 }
 
 /// Default validator function for Channel identifiers.
@@ -99,8 +96,98 @@ pub fn validate_port_identifier(id: &str) -> Result<(), Error> {
 /// A valid channel identifier must be between 8-64 characters as specified in
 /// the ICS-24 spec.
 pub fn validate_channel_identifier(id: &str) -> Result<(), Error> {
-    validate_identifier_chars(id)?;
-    validate_identifier_length(id, 8, 64)
+    IdentifierRules::ICS24.validate_channel_identifier(id)
+}
+
+/// The inclusive bounds of the length of an identifier.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct LengthBounds {
+    pub min: u64,
+    pub max: u64,
+}
+
+impl LengthBounds {
+    pub const fn new(min: u64, max: u64) -> Self {
+        Self { min, max }
+    }
+}
+
+/// The rules the identifiers are validated against, which default to the ones
+/// of the [`ICS-24`](https://github.com/cosmos/ibc/tree/main/spec/core/ics-024-host-requirements#paths-identifiers-separators)
+/// spec.
+///
+/// The identifiers are always checked against the ICS-24 rules when they are
+/// parsed, so hosts with stricter conventions, e.g. shorter port identifiers
+/// or ASCII only characters, narrow them down to enforce their own policy on
+/// the identifiers of the messages.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct IdentifierRules {
+    pub client_id: LengthBounds,
+    pub connection_id: LengthBounds,
+    pub port_id: LengthBounds,
+    pub channel_id: LengthBounds,
+    /// The characters allowed besides the alphanumeric ones.
+    pub special_chars: &'static str,
+    /// Whether the non-ASCII alphanumeric characters are allowed.
+    pub allow_non_ascii: bool,
+}
+
+impl IdentifierRules {
+    /// The rules of the ICS-24 spec.
+    pub const ICS24: Self = Self {
+        client_id: LengthBounds::new(9, 64),
+        connection_id: LengthBounds::new(10, 64),
+        port_id: LengthBounds::new(2, 128),
+        channel_id: LengthBounds::new(8, 64),
+        special_chars: VALID_SPECIAL_CHARS,
+        allow_non_ascii: true,
+    };
+
+    /// Checks if the identifier only contains the allowed characters.
+    pub fn validate_chars(&self, id: &str) -> Result<(), Error> {
+        let is_valid = |c: char| {
+            let is_alphanumeric = if self.allow_non_ascii {
+                c.is_alphanumeric()
+            } else {
+                c.is_ascii_alphanumeric()
+            };
+
+            is_alphanumeric || self.special_chars.contains(c)
+        };
+
+        if !id.chars().all(is_valid) {
+            return Err(Error::InvalidCharacter { id: id.into() });
+        }
+
+        Ok(())
+    }
+
+    pub fn validate_client_identifier(&self, id: &str) -> Result<(), Error> {
+        self.validate(id, self.client_id)
+    }
+
+    pub fn validate_connection_identifier(&self, id: &str) -> Result<(), Error> {
+        self.validate(id, self.connection_id)
+    }
+
+    pub fn validate_port_identifier(&self, id: &str) -> Result<(), Error> {
+        self.validate(id, self.port_id)
+    }
+
+    pub fn validate_channel_identifier(&self, id: &str) -> Result<(), Error> {
+        self.validate(id, self.channel_id)
+    }
+
+    fn validate(&self, id: &str, bounds: LengthBounds) -> Result<(), Error> {
+        self.validate_chars(id)?;
+        validate_identifier_length(id, bounds.min, bounds.max)
+    }
+}
+
+impl Default for IdentifierRules {
+    fn default() -> Self {
+        Self::ICS24
+    }
 }
 
 #[cfg(test)]
@@ -223,6 +310,27 @@ mod tests {
         assert!(id.is_err())
     }
 
+    #[test]
+    fn validate_with_stricter_rules() {
+        let rules = IdentifierRules {
+            port_id: LengthBounds::new(2, 16),
+            special_chars: "-",
+            allow_non_ascii: false,
+            ..IdentifierRules::ICS24
+        };
+
+        assert!(rules.validate_port_identifier("transfer").is_ok());
+        assert!(rules
+            .validate_port_identifier("icacontroller-cosmos1abc")
+            .is_err());
+        assert!(rules.validate_port_identifier("my.port").is_err());
+        assert!(rules.validate_port_identifier("pörtid").is_err());
+        assert!(IdentifierRules::ICS24
+            .validate_port_identifier("pörtid")
+            .is_ok());
+        assert!(rules.validate_channel_identifier("channel-0").is_ok());
+    }
+
     #[rstest]
     #[case::zero_min_length("", 0, 64, false)]
     #[case::empty_prefix("", 1, 64, false)]
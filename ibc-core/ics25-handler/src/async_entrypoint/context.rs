@@ -0,0 +1,734 @@
+//! Defines the synchronous context the handlers run against on behalf of an
+//! asynchronous context.
+use core::cell::RefCell;
+use core::mem;
+use core::ops::{Deref, DerefMut};
+use core::time::Duration;
+
+use ibc_core_channel::types::channel::{ChannelEnd, State as ChannelState};
+use ibc_core_channel::types::commitment::{AcknowledgementCommitment, PacketCommitment};
+use ibc_core_channel::types::error::ChannelError;
+use ibc_core_channel::types::events::PacketDataEventMode;
+use ibc_core_channel::types::packet::Receipt;
+use ibc_core_channel::types::upgrade::{ErrorReceipt, Upgrade};
+use ibc_core_client::types::params::ClientParams;
+use ibc_core_client::types::Height;
+use ibc_core_commitment_types::commitment::CommitmentPrefix;
+use ibc_core_connection::types::params::ConnectionParams;
+use ibc_core_connection::types::version::Version as ConnectionVersion;
+use ibc_core_connection::types::ConnectionEnd;
+use ibc_core_handler_types::error::ContextError;
+use ibc_core_handler_types::events::IbcEvent;
+use ibc_core_host::async_context::{AsyncExecutionContext, AsyncValidationContext};
+use ibc_core_host::types::identifiers::{ChannelId, ClientId, ConnectionId, PortId, Sequence};
+use ibc_core_host::types::path::{
+    AckPath, ChannelEndPath, ChannelUpgradeErrorPath, ChannelUpgradePath, ClientConnectionPath,
+    CommitmentPath, ConnectionPath, ReceiptPath, SeqAckPath, SeqRecvPath, SeqSendPath,
+};
+use ibc_core_host::types::validate::IdentifierRules;
+use ibc_core_host::{ExecutionContext, GasMeter, HostClock, Metrics, ValidationContext};
+use ibc_primitives::prelude::*;
+use ibc_primitives::{Signer, Timestamp};
+
+use super::state::{StateCache, StateKey, StateValue, StateWrite};
+
+type HostConsensusStateOf<R> = <<R as Deref>::Target as AsyncValidationContext>::HostConsensusState;
+
+/// A synchronous context which serves the reads of the handlers from the
+/// state fetched from the asynchronous context `R` refers to, and buffers
+/// their writes until they are applied to it.
+///
+/// The reads of states which were not fetched fail, and are recorded so that
+/// they can be fetched before the handlers are run again.
+pub(crate) struct CachedContext<R>
+where
+    R: Deref,
+    R::Target: AsyncValidationContext,
+{
+    ctx: R,
+    cache: StateCache<HostConsensusStateOf<R>>,
+    writes: Vec<StateWrite>,
+    missing: RefCell<Vec<StateKey>>,
+    /// The parameters of the client module stored by the handlers, if any.
+    client_params: Option<ClientParams>,
+    /// The channel ends whose packet commitments were deleted.
+    deleted_commitments: Vec<ChannelEndPath>,
+    /// The channel ends whose in-flight packets were read after one of their
+    /// packet commitments was deleted.
+    flush_checks: RefCell<Vec<ChannelEndPath>>,
+}
+
+impl<R> CachedContext<R>
+where
+    R: Deref,
+    R::Target: AsyncValidationContext,
+{
+    pub(crate) fn new(ctx: R) -> Self {
+        Self {
+            ctx,
+            cache: StateCache::new(),
+            writes: Vec::new(),
+            missing: RefCell::new(Vec::new()),
+            client_params: None,
+            deleted_commitments: Vec::new(),
+            flush_checks: RefCell::new(Vec::new()),
+        }
+    }
+
+    /// Fetches the given states from the asynchronous context.
+    pub(crate) async fn fetch(&mut self, keys: impl IntoIterator<Item = StateKey>) {
+        self.cache.fetch(&*self.ctx, keys).await;
+    }
+
+    /// Fetches the states the handlers failed to read since the last call,
+    /// and returns whether there were any.
+    pub(crate) async fn fetch_missing(&mut self) -> bool {
+        let missing = mem::take(self.missing.get_mut());
+        let any_missing = !missing.is_empty();
+
+        self.fetch(missing).await;
+
+        any_missing
+    }
+
+    /// Fails if the handlers read a state which was not fetched.
+    pub(crate) fn ensure_fetched(&self) -> Result<(), ContextError> {
+        match self.missing.borrow().first() {
+            Some(key) => Err(ChannelError::Other {
+                description: format!(
+                    "{key} is read by the execution of the message but was not fetched"
+                ),
+            }
+            .into()),
+            None => Ok(()),
+        }
+    }
+
+    fn read<T>(
+        &self,
+        key: StateKey,
+        extract: impl FnOnce(StateValue<HostConsensusStateOf<R>>) -> Option<T>,
+    ) -> Result<T, ContextError> {
+        match self.cache.get(&key) {
+            Some(result) => extract(result?).ok_or_else(|| {
+                ChannelError::Other {
+                    description: format!("unexpected value fetched for {key}"),
+                }
+                .into()
+            }),
+            None => {
+                let err = ChannelError::Other {
+                    description: format!("{key} was not fetched"),
+                }
+                .into();
+
+                let mut missing = self.missing.borrow_mut();
+                if !missing.contains(&key) {
+                    missing.push(key);
+                }
+
+                Err(err)
+            }
+        }
+    }
+
+    fn read_counter(&self, key: StateKey) -> Result<u64, ContextError> {
+        self.read(key, |value| match value {
+            StateValue::Counter(counter) => Some(counter),
+            _ => None,
+        })
+    }
+
+    fn read_sequence(&self, key: StateKey) -> Result<Sequence, ContextError> {
+        self.read(key, |value| match value {
+            StateValue::Sequence(seq) => Some(seq),
+            _ => None,
+        })
+    }
+
+    fn read_upgrade(&self, key: StateKey) -> Result<Upgrade, ContextError> {
+        self.read(key, |value| match value {
+            StateValue::Upgrade(upgrade) => Some(upgrade),
+            _ => None,
+        })
+    }
+}
+
+impl<R> CachedContext<R>
+where
+    R: DerefMut,
+    R::Target: AsyncExecutionContext,
+{
+    /// Applies the buffered writes to the asynchronous context.
+    pub(crate) async fn commit(self) -> Result<(), ContextError> {
+        let Self {
+            mut ctx,
+            cache,
+            writes,
+            flush_checks,
+            ..
+        } = self;
+
+        for write in writes {
+            write.apply(&mut *ctx).await?;
+        }
+
+        // The handlers complete the flush of an upgrading channel end once
+        // they acknowledged or timed out its last in-flight packet, which is
+        // only known once the packet commitment is deleted from the host.
+        // Until then, the channel end was reported to have in-flight packets.
+        for chan_end_path in flush_checks.into_inner() {
+            if ctx.has_inflight_packets(&chan_end_path).await? {
+                continue;
+            }
+
+            if let Some(Ok(StateValue::Channel(mut chan_end))) =
+                cache.get(&StateKey::Channel(chan_end_path.clone()))
+            {
+                if chan_end.state == ChannelState::Flushing {
+                    chan_end.set_state(ChannelState::FlushComplete);
+                    ctx.store_channel(&chan_end_path, chan_end).await?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn increase_counter(&mut self, key: StateKey) {
+        if let Some(Ok(StateValue::Counter(counter))) = self.cache.get(&key) {
+            self.cache.set(key, StateValue::Counter(counter + 1));
+        }
+    }
+}
+
+impl<R> HostClock for CachedContext<R>
+where
+    R: Deref,
+    R::Target: AsyncValidationContext,
+{
+    fn host_height(&self) -> Result<Height, ContextError> {
+        self.read(StateKey::HostHeight, |value| match value {
+            StateValue::Height(height) => Some(height),
+            _ => None,
+        })
+    }
+
+    fn host_timestamp(&self) -> Result<Timestamp, ContextError> {
+        self.read(StateKey::HostTimestamp, |value| match value {
+            StateValue::Timestamp(timestamp) => Some(timestamp),
+            _ => None,
+        })
+    }
+}
+
+impl<R> ValidationContext for CachedContext<R>
+where
+    R: Deref,
+    R::Target: AsyncValidationContext,
+{
+    type V = <R::Target as AsyncValidationContext>::V;
+    type HostClientState = <R::Target as AsyncValidationContext>::HostClientState;
+    type HostConsensusState = HostConsensusStateOf<R>;
+
+    fn get_client_validation_context(&self) -> &Self::V {
+        self.ctx.get_client_validation_context()
+    }
+
+    fn host_consensus_state(
+        &self,
+        height: &Height,
+    ) -> Result<Self::HostConsensusState, ContextError> {
+        self.read(StateKey::HostConsensusState(*height), |value| match value {
+            StateValue::ConsensusState(consensus_state) => Some(consensus_state),
+            _ => None,
+        })
+    }
+
+    fn client_counter(&self) -> Result<u64, ContextError> {
+        self.read_counter(StateKey::ClientCounter)
+    }
+
+    fn connection_end(&self, conn_id: &ConnectionId) -> Result<ConnectionEnd, ContextError> {
+        self.read(StateKey::Connection(conn_id.clone()), |value| match value {
+            StateValue::Connection(conn_end) => Some(conn_end),
+            _ => None,
+        })
+    }
+
+    fn validate_self_client(
+        &self,
+        client_state_of_host_on_counterparty: Self::HostClientState,
+    ) -> Result<(), ContextError> {
+        self.ctx
+            .validate_self_client(client_state_of_host_on_counterparty)
+    }
+
+    fn commitment_prefix(&self) -> CommitmentPrefix {
+        self.ctx.commitment_prefix()
+    }
+
+    fn connection_counter(&self) -> Result<u64, ContextError> {
+        self.read_counter(StateKey::ConnectionCounter)
+    }
+
+    fn client_params(&self) -> ClientParams {
+        self.client_params
+            .clone()
+            .unwrap_or_else(|| self.ctx.client_params())
+    }
+
+    fn connection_params(&self) -> ConnectionParams {
+        self.ctx.connection_params()
+    }
+
+    fn get_compatible_versions(&self) -> Vec<ConnectionVersion> {
+        self.ctx.get_compatible_versions()
+    }
+
+    fn pick_version(
+        &self,
+        counterparty_candidate_versions: &[ConnectionVersion],
+    ) -> Result<ConnectionVersion, ContextError> {
+        self.ctx.pick_version(counterparty_candidate_versions)
+    }
+
+    fn channel_end(&self, channel_end_path: &ChannelEndPath) -> Result<ChannelEnd, ContextError> {
+        self.read(
+            StateKey::Channel(channel_end_path.clone()),
+            |value| match value {
+                StateValue::Channel(chan_end) => Some(chan_end),
+                _ => None,
+            },
+        )
+    }
+
+    fn get_next_sequence_send(
+        &self,
+        seq_send_path: &SeqSendPath,
+    ) -> Result<Sequence, ContextError> {
+        self.read_sequence(StateKey::NextSequenceSend(seq_send_path.clone()))
+    }
+
+    fn get_next_sequence_recv(
+        &self,
+        seq_recv_path: &SeqRecvPath,
+    ) -> Result<Sequence, ContextError> {
+        self.read_sequence(StateKey::NextSequenceRecv(seq_recv_path.clone()))
+    }
+
+    fn get_next_sequence_ack(&self, seq_ack_path: &SeqAckPath) -> Result<Sequence, ContextError> {
+        self.read_sequence(StateKey::NextSequenceAck(seq_ack_path.clone()))
+    }
+
+    fn get_packet_commitment(
+        &self,
+        commitment_path: &CommitmentPath,
+    ) -> Result<PacketCommitment, ContextError> {
+        self.read(
+            StateKey::PacketCommitment(commitment_path.clone()),
+            |value| match value {
+                StateValue::PacketCommitment(commitment) => Some(commitment),
+                _ => None,
+            },
+        )
+    }
+
+    fn get_packet_receipt(&self, receipt_path: &ReceiptPath) -> Result<Receipt, ContextError> {
+        self.read(
+            StateKey::PacketReceipt(receipt_path.clone()),
+            |value| match value {
+                StateValue::Receipt(receipt) => Some(receipt),
+                _ => None,
+            },
+        )
+    }
+
+    fn get_packet_acknowledgement(
+        &self,
+        ack_path: &AckPath,
+    ) -> Result<AcknowledgementCommitment, ContextError> {
+        self.read(
+            StateKey::PacketAcknowledgement(ack_path.clone()),
+            |value| match value {
+                StateValue::Acknowledgement(ack_commitment) => Some(ack_commitment),
+                _ => None,
+            },
+        )
+    }
+
+    fn channel_upgrade(&self, upgrade_path: &ChannelUpgradePath) -> Result<Upgrade, ContextError> {
+        self.read_upgrade(StateKey::ChannelUpgrade(upgrade_path.clone()))
+    }
+
+    fn counterparty_channel_upgrade(
+        &self,
+        upgrade_path: &ChannelUpgradePath,
+    ) -> Result<Upgrade, ContextError> {
+        self.read_upgrade(StateKey::CounterpartyChannelUpgrade(upgrade_path.clone()))
+    }
+
+    fn channel_upgrade_error_receipt(
+        &self,
+        upgrade_error_path: &ChannelUpgradeErrorPath,
+    ) -> Result<ErrorReceipt, ContextError> {
+        self.read(
+            StateKey::ChannelUpgradeErrorReceipt(upgrade_error_path.clone()),
+            |value| match value {
+                StateValue::ErrorReceipt(error_receipt) => Some(error_receipt),
+                _ => None,
+            },
+        )
+    }
+
+    fn has_inflight_packets(
+        &self,
+        channel_end_path: &ChannelEndPath,
+    ) -> Result<bool, ContextError> {
+        // Whether the deleted packet commitments were the last in-flight
+        // ones is checked once they are deleted from the host.
+        if self.deleted_commitments.contains(channel_end_path) {
+            let mut flush_checks = self.flush_checks.borrow_mut();
+            if !flush_checks.contains(channel_end_path) {
+                flush_checks.push(channel_end_path.clone());
+            }
+
+            return Ok(true);
+        }
+
+        self.read(
+            StateKey::InflightPackets(channel_end_path.clone()),
+            |value| match value {
+                StateValue::Flag(has_inflight_packets) => Some(has_inflight_packets),
+                _ => None,
+            },
+        )
+    }
+
+    fn upgrade_timeout(&self) -> Duration {
+        self.ctx.upgrade_timeout()
+    }
+
+    fn max_packet_data_size(&self) -> Option<usize> {
+        self.ctx.max_packet_data_size()
+    }
+
+    fn packet_data_event_mode(&self) -> PacketDataEventMode {
+        self.ctx.packet_data_event_mode()
+    }
+
+    fn metrics(&self) -> &dyn Metrics {
+        self.ctx.metrics()
+    }
+
+    fn gas_meter(&self) -> &dyn GasMeter {
+        self.ctx.gas_meter()
+    }
+
+    fn channel_counter(&self) -> Result<u64, ContextError> {
+        self.read_counter(StateKey::ChannelCounter)
+    }
+
+    fn max_expected_time_per_block(&self) -> Duration {
+        self.ctx.max_expected_time_per_block()
+    }
+
+    fn validate_message_signer(&self, signer: &Signer) -> Result<(), ContextError> {
+        self.ctx.validate_message_signer(signer)
+    }
+
+    fn validate_connection_creator(
+        &self,
+        signer: &Signer,
+        client_id: &ClientId,
+    ) -> Result<(), ContextError> {
+        self.ctx.validate_connection_creator(signer, client_id)
+    }
+
+    fn identifier_rules(&self) -> IdentifierRules {
+        self.ctx.identifier_rules()
+    }
+
+    fn validate_channel_close(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<(), ContextError> {
+        self.ctx.validate_channel_close(port_id, channel_id)
+    }
+}
+
+impl<R> ExecutionContext for CachedContext<R>
+where
+    R: DerefMut,
+    R::Target: AsyncExecutionContext,
+{
+    type E = <R::Target as AsyncExecutionContext>::E;
+
+    fn get_client_execution_context(&mut self) -> &mut Self::E {
+        self.ctx.get_client_execution_context()
+    }
+
+    fn increase_client_counter(&mut self) -> Result<(), ContextError> {
+        self.increase_counter(StateKey::ClientCounter);
+        self.writes.push(StateWrite::IncreaseClientCounter);
+        Ok(())
+    }
+
+    fn store_client_params(&mut self, params: ClientParams) -> Result<(), ContextError> {
+        self.client_params = Some(params.clone());
+        self.writes.push(StateWrite::StoreClientParams(params));
+        Ok(())
+    }
+
+    fn store_connection(
+        &mut self,
+        connection_path: &ConnectionPath,
+        connection_end: ConnectionEnd,
+    ) -> Result<(), ContextError> {
+        self.cache.set(
+            StateKey::Connection(connection_path.0.clone()),
+            StateValue::Connection(connection_end.clone()),
+        );
+        self.writes.push(StateWrite::StoreConnection(
+            connection_path.clone(),
+            connection_end,
+        ));
+        Ok(())
+    }
+
+    fn store_connection_to_client(
+        &mut self,
+        client_connection_path: &ClientConnectionPath,
+        conn_id: ConnectionId,
+    ) -> Result<(), ContextError> {
+        self.writes.push(StateWrite::StoreConnectionToClient(
+            client_connection_path.clone(),
+            conn_id,
+        ));
+        Ok(())
+    }
+
+    fn increase_connection_counter(&mut self) -> Result<(), ContextError> {
+        self.increase_counter(StateKey::ConnectionCounter);
+        self.writes.push(StateWrite::IncreaseConnectionCounter);
+        Ok(())
+    }
+
+    fn store_packet_commitment(
+        &mut self,
+        commitment_path: &CommitmentPath,
+        commitment: PacketCommitment,
+    ) -> Result<(), ContextError> {
+        self.cache.set(
+            StateKey::PacketCommitment(commitment_path.clone()),
+            StateValue::PacketCommitment(commitment.clone()),
+        );
+        self.writes.push(StateWrite::StorePacketCommitment(
+            commitment_path.clone(),
+            commitment,
+        ));
+        Ok(())
+    }
+
+    fn delete_packet_commitment(
+        &mut self,
+        commitment_path: &CommitmentPath,
+    ) -> Result<(), ContextError> {
+        self.cache
+            .delete(StateKey::PacketCommitment(commitment_path.clone()));
+        self.writes
+            .push(StateWrite::DeletePacketCommitment(commitment_path.clone()));
+
+        let chan_end_path = ChannelEndPath(
+            commitment_path.port_id.clone(),
+            commitment_path.channel_id.clone(),
+        );
+        if !self.deleted_commitments.contains(&chan_end_path) {
+            self.deleted_commitments.push(chan_end_path);
+        }
+
+        Ok(())
+    }
+
+    fn store_packet_receipt(
+        &mut self,
+        receipt_path: &ReceiptPath,
+        receipt: Receipt,
+    ) -> Result<(), ContextError> {
+        self.cache.set(
+            StateKey::PacketReceipt(receipt_path.clone()),
+            StateValue::Receipt(receipt.clone()),
+        );
+        self.writes.push(StateWrite::StorePacketReceipt(
+            receipt_path.clone(),
+            receipt,
+        ));
+        Ok(())
+    }
+
+    fn store_packet_acknowledgement(
+        &mut self,
+        ack_path: &AckPath,
+        ack_commitment: AcknowledgementCommitment,
+    ) -> Result<(), ContextError> {
+        self.cache.set(
+            StateKey::PacketAcknowledgement(ack_path.clone()),
+            StateValue::Acknowledgement(ack_commitment.clone()),
+        );
+        self.writes.push(StateWrite::StorePacketAcknowledgement(
+            ack_path.clone(),
+            ack_commitment,
+        ));
+        Ok(())
+    }
+
+    fn delete_packet_acknowledgement(&mut self, ack_path: &AckPath) -> Result<(), ContextError> {
+        self.cache
+            .delete(StateKey::PacketAcknowledgement(ack_path.clone()));
+        self.writes
+            .push(StateWrite::DeletePacketAcknowledgement(ack_path.clone()));
+        Ok(())
+    }
+
+    fn store_channel(
+        &mut self,
+        channel_end_path: &ChannelEndPath,
+        channel_end: ChannelEnd,
+    ) -> Result<(), ContextError> {
+        self.cache.set(
+            StateKey::Channel(channel_end_path.clone()),
+            StateValue::Channel(channel_end.clone()),
+        );
+        self.writes.push(StateWrite::StoreChannel(
+            channel_end_path.clone(),
+            channel_end,
+        ));
+        Ok(())
+    }
+
+    fn store_channel_upgrade(
+        &mut self,
+        upgrade_path: &ChannelUpgradePath,
+        upgrade: Upgrade,
+    ) -> Result<(), ContextError> {
+        self.cache.set(
+            StateKey::ChannelUpgrade(upgrade_path.clone()),
+            StateValue::Upgrade(upgrade.clone()),
+        );
+        self.writes.push(StateWrite::StoreChannelUpgrade(
+            upgrade_path.clone(),
+            upgrade,
+        ));
+        Ok(())
+    }
+
+    fn store_counterparty_channel_upgrade(
+        &mut self,
+        upgrade_path: &ChannelUpgradePath,
+        upgrade: Upgrade,
+    ) -> Result<(), ContextError> {
+        self.cache.set(
+            StateKey::CounterpartyChannelUpgrade(upgrade_path.clone()),
+            StateValue::Upgrade(upgrade.clone()),
+        );
+        self.writes
+            .push(StateWrite::StoreCounterpartyChannelUpgrade(
+                upgrade_path.clone(),
+                upgrade,
+            ));
+        Ok(())
+    }
+
+    fn delete_channel_upgrade(
+        &mut self,
+        upgrade_path: &ChannelUpgradePath,
+    ) -> Result<(), ContextError> {
+        self.cache
+            .delete(StateKey::ChannelUpgrade(upgrade_path.clone()));
+        self.cache
+            .delete(StateKey::CounterpartyChannelUpgrade(upgrade_path.clone()));
+        self.writes
+            .push(StateWrite::DeleteChannelUpgrade(upgrade_path.clone()));
+        Ok(())
+    }
+
+    fn store_channel_upgrade_error_receipt(
+        &mut self,
+        upgrade_error_path: &ChannelUpgradeErrorPath,
+        error_receipt: ErrorReceipt,
+    ) -> Result<(), ContextError> {
+        self.cache.set(
+            StateKey::ChannelUpgradeErrorReceipt(upgrade_error_path.clone()),
+            StateValue::ErrorReceipt(error_receipt.clone()),
+        );
+        self.writes
+            .push(StateWrite::StoreChannelUpgradeErrorReceipt(
+                upgrade_error_path.clone(),
+                error_receipt,
+            ));
+        Ok(())
+    }
+
+    fn store_next_sequence_send(
+        &mut self,
+        seq_send_path: &SeqSendPath,
+        seq: Sequence,
+    ) -> Result<(), ContextError> {
+        self.cache.set(
+            StateKey::NextSequenceSend(seq_send_path.clone()),
+            StateValue::Sequence(seq),
+        );
+        self.writes.push(StateWrite::StoreNextSequenceSend(
+            seq_send_path.clone(),
+            seq,
+        ));
+        Ok(())
+    }
+
+    fn store_next_sequence_recv(
+        &mut self,
+        seq_recv_path: &SeqRecvPath,
+        seq: Sequence,
+    ) -> Result<(), ContextError> {
+        self.cache.set(
+            StateKey::NextSequenceRecv(seq_recv_path.clone()),
+            StateValue::Sequence(seq),
+        );
+        self.writes.push(StateWrite::StoreNextSequenceRecv(
+            seq_recv_path.clone(),
+            seq,
+        ));
+        Ok(())
+    }
+
+    fn store_next_sequence_ack(
+        &mut self,
+        seq_ack_path: &SeqAckPath,
+        seq: Sequence,
+    ) -> Result<(), ContextError> {
+        self.cache.set(
+            StateKey::NextSequenceAck(seq_ack_path.clone()),
+            StateValue::Sequence(seq),
+        );
+        self.writes
+            .push(StateWrite::StoreNextSequenceAck(seq_ack_path.clone(), seq));
+        Ok(())
+    }
+
+    fn increase_channel_counter(&mut self) -> Result<(), ContextError> {
+        self.increase_counter(StateKey::ChannelCounter);
+        self.writes.push(StateWrite::IncreaseChannelCounter);
+        Ok(())
+    }
+
+    fn emit_ibc_event(&mut self, event: IbcEvent) -> Result<(), ContextError> {
+        self.writes.push(StateWrite::EmitIbcEvent(event));
+        Ok(())
+    }
+
+    fn log_message(&mut self, message: String) -> Result<(), ContextError> {
+        self.writes.push(StateWrite::LogMessage(message));
+        Ok(())
+    }
+}
@@ -0,0 +1,186 @@
+//! Exposes the asynchronous variants of the IBC handler entrypoints, for hosts
+//! implementing the [`AsyncValidationContext`] and [`AsyncExecutionContext`].
+//!
+//! The handlers themselves are synchronous: they run against a snapshot of the
+//! state of the IBC store, which is fetched from the asynchronous context
+//! beforehand, and their writes are buffered until the message is executed.
+//! As the state a message reads depends on the state itself, e.g. on the
+//! connection of a channel, the message is validated against the snapshot
+//! until it no longer reads any state which was not fetched, each round
+//! fetching the missing states. The callbacks validating the message in the
+//! applications are therefore called several times, which they must support
+//! as they do not write any state, and the gas the validation charges to the
+//! [`GasMeter`](ibc_core_host::GasMeter) is charged on every round.
+//!
+//! The application modules and the light clients are not driven
+//! asynchronously, and still read and write their state through the router
+//! and the client contexts of the host.
+use core::ops::Deref;
+
+use ibc_core_channel::types::msgs::{ChannelMsg, PacketMsg};
+use ibc_core_client::types::msgs::ClientMsg;
+use ibc_core_connection::types::msgs::ConnectionMsg;
+use ibc_core_handler_types::error::ContextError;
+use ibc_core_handler_types::msgs::MsgEnvelope;
+use ibc_core_host::async_context::{AsyncExecutionContext, AsyncValidationContext};
+use ibc_core_host::types::identifiers::{ChannelId, PortId};
+use ibc_core_host::types::path::{ChannelEndPath, ChannelUpgradePath, SeqSendPath};
+use ibc_core_router::router::Router;
+use ibc_primitives::prelude::*;
+
+use crate::entrypoint;
+
+mod context;
+mod state;
+
+use context::CachedContext;
+use state::StateKey;
+
+/// Asynchronous entrypoint which performs both validation and message
+/// execution
+#[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+pub async fn dispatch<Ctx>(
+    ctx: &mut Ctx,
+    router: &mut impl Router,
+    msg: MsgEnvelope,
+) -> Result<(), ContextError>
+where
+    Ctx: AsyncExecutionContext,
+{
+    execute(ctx, router, msg).await
+}
+
+/// Asynchronous entrypoint which only performs message validation
+///
+/// Messages must be processed as with the synchronous
+/// [`validate`](entrypoint::validate) entrypoint.
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(skip_all, err(level = "debug"))
+)]
+pub async fn validate<Ctx>(
+    ctx: &Ctx,
+    router: &impl Router,
+    msg: MsgEnvelope,
+) -> Result<(), ContextError>
+where
+    Ctx: AsyncValidationContext,
+{
+    let mut cached_ctx = CachedContext::new(ctx);
+
+    validate_cached(&mut cached_ctx, router, &msg).await
+}
+
+/// Asynchronous entrypoint which performs message execution
+///
+/// Unlike the synchronous [`execute`](entrypoint::execute) entrypoint, the
+/// message is validated first, as this is how the state it reads is fetched,
+/// which makes this equivalent to [`dispatch`]. The state changes are only
+/// written to the context once the message is successfully executed.
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(skip_all, err(level = "debug"))
+)]
+pub async fn execute<Ctx>(
+    ctx: &mut Ctx,
+    router: &mut impl Router,
+    msg: MsgEnvelope,
+) -> Result<(), ContextError>
+where
+    Ctx: AsyncExecutionContext,
+{
+    let mut cached_ctx = CachedContext::new(ctx);
+
+    cached_ctx.fetch(execution_reads(&msg)).await;
+
+    validate_cached(&mut cached_ctx, &*router, &msg).await?;
+
+    let result = entrypoint::execute(&mut cached_ctx, router, msg);
+
+    cached_ctx.ensure_fetched()?;
+    result?;
+
+    cached_ctx.commit().await
+}
+
+/// Validates the message against the cached context, fetching the states it
+/// reads until all of them were.
+async fn validate_cached<R>(
+    cached_ctx: &mut CachedContext<R>,
+    router: &impl Router,
+    msg: &MsgEnvelope,
+) -> Result<(), ContextError>
+where
+    R: Deref,
+    R::Target: AsyncValidationContext,
+{
+    loop {
+        let result = entrypoint::validate(&*cached_ctx, router, msg.clone());
+
+        if !cached_ctx.fetch_missing().await {
+            return result;
+        }
+    }
+}
+
+/// Returns the states the execution of the message reads on top of the ones
+/// its validation does.
+fn execution_reads(msg: &MsgEnvelope) -> Vec<StateKey> {
+    let mut reads = vec![StateKey::HostHeight, StateKey::HostTimestamp];
+
+    match msg {
+        MsgEnvelope::Client(ClientMsg::CreateClient(_)) => reads.push(StateKey::ClientCounter),
+        MsgEnvelope::Connection(ConnectionMsg::OpenInit(_) | ConnectionMsg::OpenTry(_)) => {
+            reads.push(StateKey::ConnectionCounter)
+        }
+        MsgEnvelope::Channel(ChannelMsg::OpenInit(_) | ChannelMsg::OpenTry(_)) => {
+            reads.push(StateKey::ChannelCounter)
+        }
+        MsgEnvelope::Channel(msg) => {
+            let channel = match msg {
+                ChannelMsg::UpgradeInit(msg) => Some((&msg.port_id_on_a, &msg.chan_id_on_a)),
+                ChannelMsg::UpgradeTry(msg) => Some((&msg.port_id_on_b, &msg.chan_id_on_b)),
+                ChannelMsg::UpgradeAck(msg) => Some((&msg.port_id_on_a, &msg.chan_id_on_a)),
+                ChannelMsg::UpgradeConfirm(msg) => Some((&msg.port_id_on_b, &msg.chan_id_on_b)),
+                ChannelMsg::UpgradeOpen(msg) => Some((&msg.port_id_on_a, &msg.chan_id_on_a)),
+                ChannelMsg::UpgradeTimeout(msg) => Some((&msg.port_id_on_a, &msg.chan_id_on_a)),
+                ChannelMsg::UpgradeCancel(msg) => Some((&msg.port_id_on_a, &msg.chan_id_on_a)),
+                _ => None,
+            };
+
+            if let Some((port_id, channel_id)) = channel {
+                reads.extend(upgrade_reads(port_id, channel_id));
+                reads.push(StateKey::NextSequenceSend(SeqSendPath::new(
+                    port_id, channel_id,
+                )));
+            }
+        }
+        MsgEnvelope::Packet(msg) => {
+            let packet = match msg {
+                PacketMsg::Recv(_) => None,
+                PacketMsg::Ack(msg) => Some(&msg.packet),
+                PacketMsg::Timeout(msg) => Some(&msg.packet),
+                PacketMsg::TimeoutOnClose(msg) => Some(&msg.packet),
+            };
+
+            // Acknowledging or timing out a packet may complete the flush of
+            // an upgrading channel end.
+            if let Some(packet) = packet {
+                reads.extend(upgrade_reads(&packet.port_id_on_a, &packet.chan_id_on_a));
+            }
+        }
+        _ => {}
+    }
+
+    reads
+}
+
+fn upgrade_reads(port_id: &PortId, channel_id: &ChannelId) -> [StateKey; 3] {
+    let upgrade_path = ChannelUpgradePath::new(port_id, channel_id);
+
+    [
+        StateKey::ChannelUpgrade(upgrade_path.clone()),
+        StateKey::CounterpartyChannelUpgrade(upgrade_path),
+        StateKey::InflightPackets(ChannelEndPath::new(port_id, channel_id)),
+    ]
+}
@@ -0,0 +1,354 @@
+//! Defines the state of the IBC store the handlers read and write, which the
+//! asynchronous entrypoints fetch from and write to the asynchronous context.
+use core::fmt::{Display, Error as FmtError, Formatter};
+
+use ibc_core_channel::types::channel::ChannelEnd;
+use ibc_core_channel::types::commitment::{AcknowledgementCommitment, PacketCommitment};
+use ibc_core_channel::types::error::{ChannelError, PacketError};
+use ibc_core_channel::types::packet::Receipt;
+use ibc_core_channel::types::upgrade::{ErrorReceipt, Upgrade};
+use ibc_core_client::types::error::ClientError;
+use ibc_core_client::types::params::ClientParams;
+use ibc_core_client::types::Height;
+use ibc_core_connection::types::error::ConnectionError;
+use ibc_core_connection::types::ConnectionEnd;
+use ibc_core_handler_types::error::ContextError;
+use ibc_core_handler_types::events::IbcEvent;
+use ibc_core_host::async_context::{AsyncExecutionContext, AsyncValidationContext};
+use ibc_core_host::types::identifiers::{ConnectionId, Sequence};
+use ibc_core_host::types::path::{
+    AckPath, ChannelEndPath, ChannelUpgradeErrorPath, ChannelUpgradePath, ClientConnectionPath,
+    CommitmentPath, ConnectionPath, ReceiptPath, SeqAckPath, SeqRecvPath, SeqSendPath,
+};
+use ibc_core_router::types::error::RouterError;
+use ibc_primitives::prelude::*;
+use ibc_primitives::Timestamp;
+
+/// A state the handlers read, identified by the method of the context it is
+/// read with.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub(crate) enum StateKey {
+    HostHeight,
+    HostTimestamp,
+    HostConsensusState(Height),
+    ClientCounter,
+    Connection(ConnectionId),
+    ConnectionCounter,
+    Channel(ChannelEndPath),
+    NextSequenceSend(SeqSendPath),
+    NextSequenceRecv(SeqRecvPath),
+    NextSequenceAck(SeqAckPath),
+    PacketCommitment(CommitmentPath),
+    PacketReceipt(ReceiptPath),
+    PacketAcknowledgement(AckPath),
+    ChannelUpgrade(ChannelUpgradePath),
+    CounterpartyChannelUpgrade(ChannelUpgradePath),
+    ChannelUpgradeErrorReceipt(ChannelUpgradeErrorPath),
+    InflightPackets(ChannelEndPath),
+    ChannelCounter,
+}
+
+impl StateKey {
+    /// Reads the state from the asynchronous context.
+    async fn fetch<Ctx>(
+        &self,
+        ctx: &Ctx,
+    ) -> Result<StateValue<Ctx::HostConsensusState>, ContextError>
+    where
+        Ctx: AsyncValidationContext + ?Sized,
+    {
+        let value = match self {
+            Self::HostHeight => StateValue::Height(ctx.host_height().await?),
+            Self::HostTimestamp => StateValue::Timestamp(ctx.host_timestamp().await?),
+            Self::HostConsensusState(height) => {
+                StateValue::ConsensusState(ctx.host_consensus_state(height).await?)
+            }
+            Self::ClientCounter => StateValue::Counter(ctx.client_counter().await?),
+            Self::Connection(conn_id) => StateValue::Connection(ctx.connection_end(conn_id).await?),
+            Self::ConnectionCounter => StateValue::Counter(ctx.connection_counter().await?),
+            Self::Channel(path) => StateValue::Channel(ctx.channel_end(path).await?),
+            Self::NextSequenceSend(path) => {
+                StateValue::Sequence(ctx.get_next_sequence_send(path).await?)
+            }
+            Self::NextSequenceRecv(path) => {
+                StateValue::Sequence(ctx.get_next_sequence_recv(path).await?)
+            }
+            Self::NextSequenceAck(path) => {
+                StateValue::Sequence(ctx.get_next_sequence_ack(path).await?)
+            }
+            Self::PacketCommitment(path) => {
+                StateValue::PacketCommitment(ctx.get_packet_commitment(path).await?)
+            }
+            Self::PacketReceipt(path) => StateValue::Receipt(ctx.get_packet_receipt(path).await?),
+            Self::PacketAcknowledgement(path) => {
+                StateValue::Acknowledgement(ctx.get_packet_acknowledgement(path).await?)
+            }
+            Self::ChannelUpgrade(path) => StateValue::Upgrade(ctx.channel_upgrade(path).await?),
+            Self::CounterpartyChannelUpgrade(path) => {
+                StateValue::Upgrade(ctx.counterparty_channel_upgrade(path).await?)
+            }
+            Self::ChannelUpgradeErrorReceipt(path) => {
+                StateValue::ErrorReceipt(ctx.channel_upgrade_error_receipt(path).await?)
+            }
+            Self::InflightPackets(path) => StateValue::Flag(ctx.has_inflight_packets(path).await?),
+            Self::ChannelCounter => StateValue::Counter(ctx.channel_counter().await?),
+        };
+
+        Ok(value)
+    }
+}
+
+impl Display for StateKey {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), FmtError> {
+        match self {
+            Self::HostHeight => write!(f, "host height"),
+            Self::HostTimestamp => write!(f, "host timestamp"),
+            Self::HostConsensusState(height) => write!(f, "host consensus state at {height}"),
+            Self::ClientCounter => write!(f, "client counter"),
+            Self::Connection(conn_id) => write!(f, "connection end {conn_id}"),
+            Self::ConnectionCounter => write!(f, "connection counter"),
+            Self::Channel(path) => write!(f, "channel end {path}"),
+            Self::NextSequenceSend(path) => write!(f, "next sequence send {path}"),
+            Self::NextSequenceRecv(path) => write!(f, "next sequence recv {path}"),
+            Self::NextSequenceAck(path) => write!(f, "next sequence ack {path}"),
+            Self::PacketCommitment(path) => write!(f, "packet commitment {path}"),
+            Self::PacketReceipt(path) => write!(f, "packet receipt {path}"),
+            Self::PacketAcknowledgement(path) => write!(f, "packet acknowledgement {path}"),
+            Self::ChannelUpgrade(path) => write!(f, "channel upgrade {path}"),
+            Self::CounterpartyChannelUpgrade(path) => {
+                write!(f, "counterparty channel upgrade {path}")
+            }
+            Self::ChannelUpgradeErrorReceipt(path) => {
+                write!(f, "channel upgrade error receipt {path}")
+            }
+            Self::InflightPackets(path) => write!(f, "in-flight packets of {path}"),
+            Self::ChannelCounter => write!(f, "channel counter"),
+        }
+    }
+}
+
+/// The value of a [`StateKey`].
+#[derive(Clone, Debug)]
+pub(crate) enum StateValue<CS> {
+    Height(Height),
+    Timestamp(Timestamp),
+    ConsensusState(CS),
+    Counter(u64),
+    Connection(ConnectionEnd),
+    Channel(ChannelEnd),
+    Sequence(Sequence),
+    PacketCommitment(PacketCommitment),
+    Receipt(Receipt),
+    Acknowledgement(AcknowledgementCommitment),
+    Upgrade(Upgrade),
+    ErrorReceipt(ErrorReceipt),
+    Flag(bool),
+}
+
+/// The outcome of the reads of the handlers, as fetched from the asynchronous
+/// context and updated by their writes.
+pub(crate) struct StateCache<CS> {
+    entries: BTreeMap<StateKey, Result<StateValue<CS>, ContextError>>,
+}
+
+impl<CS: Clone> StateCache<CS> {
+    pub(crate) fn new() -> Self {
+        Self {
+            entries: BTreeMap::new(),
+        }
+    }
+
+    /// Fetches the given states from the asynchronous context, unless they
+    /// were already.
+    pub(crate) async fn fetch<Ctx>(&mut self, ctx: &Ctx, keys: impl IntoIterator<Item = StateKey>)
+    where
+        Ctx: AsyncValidationContext<HostConsensusState = CS> + ?Sized,
+    {
+        for key in keys {
+            if !self.entries.contains_key(&key) {
+                let value = key.fetch(ctx).await;
+                self.entries.insert(key, value);
+            }
+        }
+    }
+
+    /// Returns the outcome of reading the given state, or `None` if it was
+    /// not fetched.
+    ///
+    /// As context errors cannot be cloned, a failed read is replayed with an
+    /// error of the same kind carrying the same description.
+    pub(crate) fn get(&self, key: &StateKey) -> Option<Result<StateValue<CS>, ContextError>> {
+        self.entries.get(key).map(|entry| match entry {
+            Ok(value) => Ok(value.clone()),
+            Err(e) => Err(replay_error(e)),
+        })
+    }
+
+    pub(crate) fn set(&mut self, key: StateKey, value: StateValue<CS>) {
+        self.entries.insert(key, Ok(value));
+    }
+
+    /// Records the deletion of the given state, which the handlers then fail
+    /// to read.
+    pub(crate) fn delete(&mut self, key: StateKey) {
+        let description = format!("{key} was deleted");
+        let err = match key {
+            StateKey::PacketCommitment(_) | StateKey::PacketAcknowledgement(_) => {
+                PacketError::Other { description }.into()
+            }
+            _ => ChannelError::Other { description }.into(),
+        };
+        self.entries.insert(key, Err(err));
+    }
+}
+
+fn replay_error(err: &ContextError) -> ContextError {
+    match err {
+        ContextError::ClientError(e) => ClientError::Other {
+            description: e.to_string(),
+        }
+        .into(),
+        ContextError::ConnectionError(e) => ConnectionError::Other {
+            description: e.to_string(),
+        }
+        .into(),
+        ContextError::ChannelError(e) => ChannelError::Other {
+            description: e.to_string(),
+        }
+        .into(),
+        ContextError::PacketError(e) => PacketError::Other {
+            description: e.to_string(),
+        }
+        .into(),
+        ContextError::RouterError(e) => match e {
+            RouterError::UnknownMessageTypeUrl { url } => {
+                RouterError::UnknownMessageTypeUrl { url: url.clone() }
+            }
+            RouterError::MalformedMessageBytes { reason } => RouterError::MalformedMessageBytes {
+                reason: reason.clone(),
+            },
+            RouterError::UnknownPort { port_id } => RouterError::UnknownPort {
+                port_id: port_id.clone(),
+            },
+            RouterError::ModuleNotFound => RouterError::ModuleNotFound,
+            RouterError::PortAlreadyBound { port_id, module_id } => RouterError::PortAlreadyBound {
+                port_id: port_id.clone(),
+                module_id: module_id.clone(),
+            },
+            RouterError::InvalidPortCapability { port_id } => RouterError::InvalidPortCapability {
+                port_id: port_id.clone(),
+            },
+            RouterError::ModuleAlreadyRegistered { module_id } => {
+                RouterError::ModuleAlreadyRegistered {
+                    module_id: module_id.clone(),
+                }
+            }
+            RouterError::ModuleStillBound { module_id, port_id } => RouterError::ModuleStillBound {
+                module_id: module_id.clone(),
+                port_id: port_id.clone(),
+            },
+            RouterError::Unauthorized { description } => RouterError::Unauthorized {
+                description: description.clone(),
+            },
+            RouterError::ChannelAlreadyClaimed {
+                port_id,
+                channel_id,
+                module_id,
+            } => RouterError::ChannelAlreadyClaimed {
+                port_id: port_id.clone(),
+                channel_id: channel_id.clone(),
+                module_id: module_id.clone(),
+            },
+            RouterError::InvalidChannelCapability {
+                port_id,
+                channel_id,
+            } => RouterError::InvalidChannelCapability {
+                port_id: port_id.clone(),
+                channel_id: channel_id.clone(),
+            },
+        }
+        .into(),
+        ContextError::GasError(e) => e.clone().into(),
+        ContextError::MigrationError(e) => e.clone().into(),
+    }
+}
+
+/// A state change or an output of the handlers, to be applied to the
+/// asynchronous context once the message is executed.
+pub(crate) enum StateWrite {
+    IncreaseClientCounter,
+    StoreClientParams(ClientParams),
+    StoreConnection(ConnectionPath, ConnectionEnd),
+    StoreConnectionToClient(ClientConnectionPath, ConnectionId),
+    IncreaseConnectionCounter,
+    StorePacketCommitment(CommitmentPath, PacketCommitment),
+    DeletePacketCommitment(CommitmentPath),
+    StorePacketReceipt(ReceiptPath, Receipt),
+    StorePacketAcknowledgement(AckPath, AcknowledgementCommitment),
+    DeletePacketAcknowledgement(AckPath),
+    StoreChannel(ChannelEndPath, ChannelEnd),
+    StoreChannelUpgrade(ChannelUpgradePath, Upgrade),
+    StoreCounterpartyChannelUpgrade(ChannelUpgradePath, Upgrade),
+    DeleteChannelUpgrade(ChannelUpgradePath),
+    StoreChannelUpgradeErrorReceipt(ChannelUpgradeErrorPath, ErrorReceipt),
+    StoreNextSequenceSend(SeqSendPath, Sequence),
+    StoreNextSequenceRecv(SeqRecvPath, Sequence),
+    StoreNextSequenceAck(SeqAckPath, Sequence),
+    IncreaseChannelCounter,
+    EmitIbcEvent(IbcEvent),
+    LogMessage(String),
+}
+
+impl StateWrite {
+    /// Applies the write to the asynchronous context.
+    pub(crate) async fn apply<Ctx>(self, ctx: &mut Ctx) -> Result<(), ContextError>
+    where
+        Ctx: AsyncExecutionContext + ?Sized,
+    {
+        match self {
+            Self::IncreaseClientCounter => ctx.increase_client_counter().await,
+            Self::StoreClientParams(params) => ctx.store_client_params(params).await,
+            Self::StoreConnection(path, end) => ctx.store_connection(&path, end).await,
+            Self::StoreConnectionToClient(path, conn_id) => {
+                ctx.store_connection_to_client(&path, conn_id).await
+            }
+            Self::IncreaseConnectionCounter => ctx.increase_connection_counter().await,
+            Self::StorePacketCommitment(path, commitment) => {
+                ctx.store_packet_commitment(&path, commitment).await
+            }
+            Self::DeletePacketCommitment(path) => ctx.delete_packet_commitment(&path).await,
+            Self::StorePacketReceipt(path, receipt) => {
+                ctx.store_packet_receipt(&path, receipt).await
+            }
+            Self::StorePacketAcknowledgement(path, ack_commitment) => {
+                ctx.store_packet_acknowledgement(&path, ack_commitment)
+                    .await
+            }
+            Self::DeletePacketAcknowledgement(path) => {
+                ctx.delete_packet_acknowledgement(&path).await
+            }
+            Self::StoreChannel(path, end) => ctx.store_channel(&path, end).await,
+            Self::StoreChannelUpgrade(path, upgrade) => {
+                ctx.store_channel_upgrade(&path, upgrade).await
+            }
+            Self::StoreCounterpartyChannelUpgrade(path, upgrade) => {
+                ctx.store_counterparty_channel_upgrade(&path, upgrade).await
+            }
+            Self::DeleteChannelUpgrade(path) => ctx.delete_channel_upgrade(&path).await,
+            Self::StoreChannelUpgradeErrorReceipt(path, error_receipt) => {
+                ctx.store_channel_upgrade_error_receipt(&path, error_receipt)
+                    .await
+            }
+            Self::StoreNextSequenceSend(path, seq) => {
+                ctx.store_next_sequence_send(&path, seq).await
+            }
+            Self::StoreNextSequenceRecv(path, seq) => {
+                ctx.store_next_sequence_recv(&path, seq).await
+            }
+            Self::StoreNextSequenceAck(path, seq) => ctx.store_next_sequence_ack(&path, seq).await,
+            Self::IncreaseChannelCounter => ctx.increase_channel_counter().await,
+            Self::EmitIbcEvent(event) => ctx.emit_ibc_event(event).await,
+            Self::LogMessage(message) => ctx.log_message(message).await,
+        }
+    }
+}
@@ -3,25 +3,59 @@ use ibc_core_channel::handler::{
     chan_close_confirm_validate, chan_close_init_execute, chan_close_init_validate,
     chan_open_ack_execute, chan_open_ack_validate, chan_open_confirm_execute,
     chan_open_confirm_validate, chan_open_init_execute, chan_open_init_validate,
-    chan_open_try_execute, chan_open_try_validate, recv_packet_execute, recv_packet_validate,
-    timeout_packet_execute, timeout_packet_validate, TimeoutMsgType,
+    chan_open_try_execute, chan_open_try_validate, chan_upgrade_ack_execute,
+    chan_upgrade_ack_validate, chan_upgrade_cancel_execute, chan_upgrade_cancel_validate,
+    chan_upgrade_confirm_execute, chan_upgrade_confirm_validate, chan_upgrade_init_execute,
+    chan_upgrade_init_validate, chan_upgrade_open_execute, chan_upgrade_open_validate,
+    chan_upgrade_timeout_execute, chan_upgrade_timeout_validate, chan_upgrade_try_execute,
+    chan_upgrade_try_validate, recv_packet_batch_execute, recv_packet_batch_validate,
+    recv_packet_execute, recv_packet_validate, timeout_packet_execute, timeout_packet_validate,
+    TimeoutMsgType,
 };
+#[cfg(feature = "parallel")]
+use ibc_core_channel::handler::{packet_proof_checks, verify_proof_check};
+use ibc_core_channel::types::acknowledgement::Acknowledgement;
+use ibc_core_channel::types::error::PacketError;
 use ibc_core_channel::types::msgs::{
-    channel_msg_to_port_id, packet_msg_to_port_id, ChannelMsg, PacketMsg,
+    channel_msg_to_port_id, packet_msg_to_port_id, ChannelMsg, MsgRecvPacket, PacketMsg,
 };
+use ibc_core_channel::types::v2::msgs::PacketMsgV2;
+use ibc_core_channel::v2::context::{PacketV2ExecutionContext, PacketV2ValidationContext};
+use ibc_core_channel::v2::handler::{
+    acknowledgement_packet_v2_execute, acknowledgement_packet_v2_validate, recv_packet_v2_execute,
+    recv_packet_v2_validate, send_packet_v2_execute, send_packet_v2_validate,
+    timeout_packet_v2_execute, timeout_packet_v2_validate,
+};
+use ibc_core_client::context::client_state::ClientStateValidation;
+use ibc_core_client::context::signature_batch::{
+    BatchSignatureVerifier, SignatureBatch, VerifiedSignatures,
+};
+use ibc_core_client::context::{ClientExecutionContext, ClientValidationContext};
 use ibc_core_client::handler::{create_client, update_client, upgrade_client};
 use ibc_core_client::types::msgs::{ClientMsg, MsgUpdateOrMisbehaviour};
 use ibc_core_connection::handler::{
     conn_open_ack, conn_open_confirm, conn_open_init, conn_open_try,
 };
 use ibc_core_connection::types::msgs::ConnectionMsg;
-use ibc_core_handler_types::error::ContextError;
-use ibc_core_handler_types::msgs::MsgEnvelope;
-use ibc_core_host::{ExecutionContext, ValidationContext};
-use ibc_core_router::router::Router;
+use ibc_core_handler_types::error::{BatchError, ContextError};
+use ibc_core_handler_types::events::IbcEvent;
+use ibc_core_handler_types::msgs::{ExtendedMsgEnvelope, MsgEnvelope};
+#[cfg(feature = "parallel")]
+use ibc_core_host::VerifiedProofs;
+use ibc_core_host::{
+    ExecutionContext, GasUsage, OverlayContext, ProfileClock, ProfilePhase, ProfiledContext,
+    TransactionalExecutionContext, ValidationContext,
+};
+use ibc_core_router::router::{Router, TransactionalRouter};
 use ibc_core_router::types::error::RouterError;
+use ibc_core_router::v2::RouterV2;
+use ibc_primitives::prelude::*;
+use ibc_primitives::proto::Any;
+
+use crate::extension::HostMsgHandler;
 
 /// Entrypoint which performs both validation and message execution
+#[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
 pub fn dispatch<Ctx>(
     ctx: &mut Ctx,
     router: &mut impl Router,
@@ -34,6 +68,341 @@ where
     execute(ctx, router, msg)
 }
 
+/// Entrypoint which performs both validation and execution of a batch of
+/// `MsgRecvPacket`s, received over the same channel and proven at the same
+/// height.
+///
+/// This is equivalent to calling `dispatch()` on each message of the batch
+/// successively, except that the channel, the connection, the client and the
+/// consensus state the proofs are verified against are only looked up and
+/// checked once for the whole batch.
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(
+        skip_all,
+        err(level = "debug"),
+        fields(batch_size = msgs.len()),
+    )
+)]
+pub fn dispatch_recv_packet_batch<Ctx>(
+    ctx: &mut Ctx,
+    router: &mut impl Router,
+    msgs: Vec<MsgRecvPacket>,
+) -> Result<(), ContextError>
+where
+    Ctx: ExecutionContext,
+{
+    let port_id = &msgs
+        .first()
+        .ok_or(PacketError::InvalidPacketBatch {
+            reason: "the batch is empty".to_string(),
+        })?
+        .packet
+        .port_id_on_b;
+    let module_id = router
+        .lookup_module(port_id)
+        .ok_or(RouterError::UnknownPort {
+            port_id: port_id.clone(),
+        })?;
+    let module = router
+        .get_route_mut(&module_id)
+        .ok_or(RouterError::ModuleNotFound)?;
+
+    recv_packet_batch_validate(ctx, &msgs)?;
+    ctx.with_staged_writes(|ctx| recv_packet_batch_execute(ctx, module, msgs))
+}
+
+/// Entrypoint which performs both validation and execution of a batch of
+/// messages, as the messages of a transaction on Cosmos SDK chains.
+///
+/// The messages are dispatched in order, each one against the state as
+/// modified by the previous ones, within a transaction of the context and one
+/// of the modules of the router, which are only committed once all of them
+/// succeeded. If one of them fails, the writes of the previous ones to the IBC
+/// store are rolled back by the context, and the ones of the modules by the
+/// router. The states written by the light clients are rolled back only if
+/// the transactions of the context cover its client execution context, which
+/// the [`OverlayContext`] does not. Returns the events emitted by each message,
+/// which are emitted to the host as well on commit.
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(
+        skip_all,
+        err(level = "debug"),
+        fields(batch_size = msgs.len()),
+    )
+)]
+pub fn dispatch_batch<Ctx>(
+    ctx: &mut Ctx,
+    router: &mut impl TransactionalRouter,
+    msgs: Vec<MsgEnvelope>,
+) -> Result<Vec<Vec<IbcEvent>>, BatchError>
+where
+    Ctx: TransactionalExecutionContext,
+{
+    let mut events = Vec::with_capacity(msgs.len());
+
+    ctx.begin_transaction();
+    router.begin_transaction();
+
+    for (index, msg) in msgs.into_iter().enumerate() {
+        let result = ctx.transact(|ctx| {
+            dispatch(ctx, router, msg)?;
+            Ok(ctx.transaction_events())
+        });
+
+        match result {
+            Ok(msg_events) => events.push(msg_events),
+            Err(error) => {
+                ctx.rollback_transaction();
+                router.rollback_transaction();
+                return Err(BatchError::Msg { index, error });
+            }
+        }
+    }
+
+    if let Err(error) = ctx.commit_transaction() {
+        router.rollback_transaction();
+        return Err(BatchError::Commit(error));
+    }
+    router.commit_transaction();
+
+    Ok(events)
+}
+
+/// Entrypoint which dispatches the messages as [`dispatch_batch`] does, after
+/// verifying the signatures of their client updates and misbehaviours
+/// together with the `verifier`.
+///
+/// The signatures verified as a batch are exposed to the light clients by
+/// [`ClientValidationContext::verified_signatures`] for the duration of the
+/// batch, for them to skip verifying these signatures again. If the batch
+/// fails to verify, the light clients verify every signature on their own, so
+/// that the outcome of the messages is the same either way.
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(
+        skip_all,
+        err(level = "debug"),
+        fields(batch_size = msgs.len()),
+    )
+)]
+pub fn dispatch_batch_verifying_signatures<Ctx>(
+    ctx: &mut Ctx,
+    router: &mut impl TransactionalRouter,
+    msgs: Vec<MsgEnvelope>,
+    verifier: &impl BatchSignatureVerifier,
+) -> Result<Vec<Vec<IbcEvent>>, BatchError>
+where
+    Ctx: TransactionalExecutionContext,
+{
+    let verified_signatures = batch_verify_signatures(ctx, &msgs, verifier);
+
+    ctx.get_client_execution_context()
+        .set_verified_signatures(verified_signatures);
+
+    let result = dispatch_batch(ctx, router, msgs);
+
+    ctx.get_client_execution_context()
+        .set_verified_signatures(None);
+
+    result
+}
+
+/// Verifies as one batch the signatures the light clients collect from the
+/// client updates and misbehaviours of the messages.
+///
+/// The messages whose client is missing or whose signatures can not be
+/// collected are left to fail, or succeed, when dispatched.
+fn batch_verify_signatures<Ctx>(
+    ctx: &Ctx,
+    msgs: &[MsgEnvelope],
+    verifier: &impl BatchSignatureVerifier,
+) -> Option<VerifiedSignatures>
+where
+    Ctx: ValidationContext,
+{
+    let client_val_ctx = ctx.get_client_validation_context();
+    let mut batch = SignatureBatch::new();
+
+    for msg in msgs {
+        let (client_id, client_message) = match msg {
+            MsgEnvelope::Client(ClientMsg::UpdateClient(msg)) => {
+                (&msg.client_id, &msg.client_message)
+            }
+            MsgEnvelope::Client(ClientMsg::Misbehaviour(msg)) => {
+                (&msg.client_id, &msg.misbehaviour)
+            }
+            _ => continue,
+        };
+
+        let Ok(client_state) = client_val_ctx.client_state(client_id) else {
+            continue;
+        };
+
+        // Leaves the signatures collected before the error in the batch, as
+        // they are verified again by the light client anyway
+        let _ = client_state.collect_signature_checks(
+            client_val_ctx,
+            client_id,
+            client_message,
+            &mut batch,
+        );
+    }
+
+    batch.verify(verifier)
+}
+
+/// Entrypoint which dispatches independent packet messages one by one, as
+/// `dispatch()` does, after verifying their proofs in parallel.
+///
+/// The proofs are looked up against the state of the host before the first
+/// message, and verified on all the threads of the rayon pool. The ones found
+/// valid are exposed to the core handlers by
+/// [`ValidationContext::verified_proofs`] for the duration of the batch, for
+/// them to skip verifying these proofs again. The messages are then validated
+/// and executed in order, each one against the state as modified by the
+/// previous ones, so that their outcome is the same as if they were
+/// dispatched one by one. Returns the outcome of each message.
+#[cfg(feature = "parallel")]
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(skip_all, fields(batch_size = msgs.len()))
+)]
+pub fn dispatch_packets_parallel<Ctx>(
+    ctx: &mut Ctx,
+    router: &mut impl Router,
+    msgs: Vec<PacketMsg>,
+) -> Vec<Result<(), ContextError>>
+where
+    Ctx: ExecutionContext + Sync,
+{
+    let verified_proofs = parallel_verify_proofs(ctx, &msgs);
+
+    ctx.set_verified_proofs(Some(verified_proofs));
+
+    let results = msgs
+        .into_iter()
+        .map(|msg| dispatch(ctx, router, MsgEnvelope::Packet(msg)))
+        .collect();
+
+    ctx.set_verified_proofs(None);
+
+    results
+}
+
+/// Verifies in parallel the proofs of the packet messages, as looked up
+/// against the current state of the host.
+///
+/// The proofs which fail to verify are left out, for the core handlers to
+/// fail the messages when dispatching them.
+#[cfg(feature = "parallel")]
+fn parallel_verify_proofs<Ctx>(ctx: &Ctx, msgs: &[PacketMsg]) -> VerifiedProofs
+where
+    Ctx: ValidationContext + Sync,
+{
+    use rayon::prelude::*;
+
+    let checks: Vec<_> = msgs
+        .par_iter()
+        .flat_map_iter(|msg| packet_proof_checks(ctx, msg))
+        .filter(|check| verify_proof_check(ctx, check).is_ok())
+        .collect();
+
+    checks.into_iter().collect()
+}
+
+/// Outcome of a message simulated by [`simulate`].
+#[derive(Clone, Debug)]
+pub struct Simulation {
+    /// The events the message emits
+    pub events: Vec<IbcEvent>,
+    /// The acknowledgement written on receiving a packet, if any
+    pub acknowledgement: Option<Acknowledgement>,
+    /// The operations charged to the gas meter of the host, on both the
+    /// validation and the execution of the message
+    pub gas_usage: GasUsage,
+}
+
+/// Entrypoint which performs both validation and execution of a message
+/// without committing its writes, for wallets and relayers to pre-flight
+/// their transactions.
+///
+/// The message is executed in a transaction of the [`OverlayContext`], which
+/// is then rolled back whether the execution succeeded or not. As the overlay
+/// does not cover the states written by the light clients and the
+/// applications, hosts simulate the message over a branch of their state
+/// which they discard afterwards, as e.g. the simulations of Cosmos SDK chains.
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(skip_all, err(level = "debug"))
+)]
+pub fn simulate<Ctx>(
+    ctx: &mut OverlayContext<Ctx>,
+    router: &mut impl Router,
+    msg: MsgEnvelope,
+) -> Result<Simulation, ContextError>
+where
+    Ctx: ExecutionContext,
+{
+    ctx.reset_gas_usage();
+
+    validate(ctx, router, msg.clone())?;
+
+    ctx.begin_transaction();
+    let result = execute(ctx, router, msg).map(|()| ctx.transaction_events());
+    ctx.rollback_transaction();
+
+    let events = result?;
+
+    let acknowledgement = events.iter().find_map(|event| match event {
+        IbcEvent::WriteAcknowledgement(event) => Some(event.acknowledgement().clone()),
+        _ => None,
+    });
+
+    Ok(Simulation {
+        events,
+        acknowledgement,
+        gas_usage: ctx.gas_usage(),
+    })
+}
+
+/// Entrypoint which decodes a message, then performs both its validation and
+/// its execution, timing each of these phases in the profile of the
+/// [`ProfiledContext`].
+///
+/// The profile is recorded whether the message succeeds or not, and is read
+/// from the context with [`ProfiledContext::profile`].
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(skip_all, err(level = "debug"))
+)]
+pub fn dispatch_profiled<Ctx, C>(
+    ctx: &mut ProfiledContext<Ctx, C>,
+    router: &mut impl Router,
+    msg: Any,
+) -> Result<(), ContextError>
+where
+    Ctx: ExecutionContext,
+    C: ProfileClock,
+{
+    let timer = ctx.start_phase(ProfilePhase::Decode);
+    let msg = MsgEnvelope::try_from(msg);
+    ctx.finish_phase(timer);
+    let msg = msg?;
+
+    let timer = ctx.start_phase(ProfilePhase::Validation);
+    let result = validate(ctx, router, msg.clone());
+    ctx.finish_phase(timer);
+    result?;
+
+    let timer = ctx.start_phase(ProfilePhase::Execution);
+    let result = execute(ctx, router, msg);
+    ctx.finish_phase(timer);
+
+    result
+}
+
 /// Entrypoint which only performs message validation
 ///
 /// If a transaction contains `n` messages `m_1` ... `m_n`, then
@@ -42,6 +411,10 @@ where
 /// That is, the state transition of message `i` must be applied before
 /// message `i+1` is validated. This is equivalent to calling
 /// `dispatch()` on each successively.
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(skip_all, err(level = "debug"))
+)]
 pub fn validate<Ctx>(ctx: &Ctx, router: &impl Router, msg: MsgEnvelope) -> Result<(), ContextError>
 where
     Ctx: ValidationContext,
@@ -81,6 +454,13 @@ where
                 ChannelMsg::OpenConfirm(msg) => chan_open_confirm_validate(ctx, module, msg),
                 ChannelMsg::CloseInit(msg) => chan_close_init_validate(ctx, module, msg),
                 ChannelMsg::CloseConfirm(msg) => chan_close_confirm_validate(ctx, module, msg),
+                ChannelMsg::UpgradeInit(msg) => chan_upgrade_init_validate(ctx, module, msg),
+                ChannelMsg::UpgradeTry(msg) => chan_upgrade_try_validate(ctx, module, msg),
+                ChannelMsg::UpgradeAck(msg) => chan_upgrade_ack_validate(ctx, module, msg),
+                ChannelMsg::UpgradeConfirm(msg) => chan_upgrade_confirm_validate(ctx, msg),
+                ChannelMsg::UpgradeOpen(msg) => chan_upgrade_open_validate(ctx, module, msg),
+                ChannelMsg::UpgradeTimeout(msg) => chan_upgrade_timeout_validate(ctx, msg),
+                ChannelMsg::UpgradeCancel(msg) => chan_upgrade_cancel_validate(ctx, msg),
             }
         }
         MsgEnvelope::Packet(msg) => {
@@ -109,11 +489,30 @@ where
 }
 
 /// Entrypoint which only performs message execution
+///
+/// The writes of the message are staged, and committed in one batch once it
+/// is executed, as described in
+/// [`ExecutionContext::stage_writes`](ibc_core_host::ExecutionContext::stage_writes).
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(skip_all, err(level = "debug"))
+)]
 pub fn execute<Ctx>(
     ctx: &mut Ctx,
     router: &mut impl Router,
     msg: MsgEnvelope,
 ) -> Result<(), ContextError>
+where
+    Ctx: ExecutionContext,
+{
+    ctx.with_staged_writes(|ctx| execute_msg(ctx, router, msg))
+}
+
+fn execute_msg<Ctx>(
+    ctx: &mut Ctx,
+    router: &mut impl Router,
+    msg: MsgEnvelope,
+) -> Result<(), ContextError>
 where
     Ctx: ExecutionContext,
 {
@@ -152,6 +551,13 @@ where
                 ChannelMsg::OpenConfirm(msg) => chan_open_confirm_execute(ctx, module, msg),
                 ChannelMsg::CloseInit(msg) => chan_close_init_execute(ctx, module, msg),
                 ChannelMsg::CloseConfirm(msg) => chan_close_confirm_execute(ctx, module, msg),
+                ChannelMsg::UpgradeInit(msg) => chan_upgrade_init_execute(ctx, module, msg),
+                ChannelMsg::UpgradeTry(msg) => chan_upgrade_try_execute(ctx, module, msg),
+                ChannelMsg::UpgradeAck(msg) => chan_upgrade_ack_execute(ctx, module, msg),
+                ChannelMsg::UpgradeConfirm(msg) => chan_upgrade_confirm_execute(ctx, msg),
+                ChannelMsg::UpgradeOpen(msg) => chan_upgrade_open_execute(ctx, module, msg),
+                ChannelMsg::UpgradeTimeout(msg) => chan_upgrade_timeout_execute(ctx, msg),
+                ChannelMsg::UpgradeCancel(msg) => chan_upgrade_cancel_execute(ctx, msg),
             }
         }
         MsgEnvelope::Packet(msg) => {
@@ -178,3 +584,114 @@ where
         }
     }
 }
+
+/// Entrypoint which performs both validation and execution of an IBC message
+/// or of a message of the host, which the given handler processes
+pub fn dispatch_extended<Ctx, H>(
+    ctx: &mut Ctx,
+    router: &mut impl Router,
+    handler: &mut H,
+    msg: ExtendedMsgEnvelope<H::Msg>,
+) -> Result<(), ContextError>
+where
+    Ctx: ExecutionContext,
+    H: HostMsgHandler<Ctx>,
+{
+    match msg {
+        ExtendedMsgEnvelope::Ibc(msg) => dispatch(ctx, router, msg),
+        ExtendedMsgEnvelope::Host(msg) => {
+            handler.validate(ctx, &msg)?;
+            ctx.with_staged_writes(|ctx| handler.execute(ctx, msg))
+        }
+    }
+}
+
+/// Entrypoint which only performs validation of an IBC message or of a
+/// message of the host
+///
+/// The messages of the host MUST be processed alongside the other messages of
+/// the transaction, as described in [`validate`].
+pub fn validate_extended<Ctx, H>(
+    ctx: &Ctx,
+    router: &impl Router,
+    handler: &H,
+    msg: ExtendedMsgEnvelope<H::Msg>,
+) -> Result<(), ContextError>
+where
+    Ctx: ValidationContext,
+    H: HostMsgHandler<Ctx>,
+{
+    match msg {
+        ExtendedMsgEnvelope::Ibc(msg) => validate(ctx, router, msg),
+        ExtendedMsgEnvelope::Host(msg) => handler.validate(ctx, &msg),
+    }
+}
+
+/// Entrypoint which only performs execution of an IBC message or of a message
+/// of the host
+pub fn execute_extended<Ctx, H>(
+    ctx: &mut Ctx,
+    router: &mut impl Router,
+    handler: &mut H,
+    msg: ExtendedMsgEnvelope<H::Msg>,
+) -> Result<(), ContextError>
+where
+    Ctx: ExecutionContext,
+    H: HostMsgHandler<Ctx>,
+{
+    match msg {
+        ExtendedMsgEnvelope::Ibc(msg) => execute(ctx, router, msg),
+        ExtendedMsgEnvelope::Host(msg) => ctx.with_staged_writes(|ctx| handler.execute(ctx, msg)),
+    }
+}
+
+/// Entrypoint which performs both validation and execution of an IBC v2
+/// packet message
+pub fn dispatch_v2<Ctx>(
+    ctx: &mut Ctx,
+    router: &mut impl RouterV2,
+    msg: PacketMsgV2,
+) -> Result<(), ContextError>
+where
+    Ctx: PacketV2ExecutionContext,
+{
+    validate_v2(ctx, router, msg.clone())?;
+    execute_v2(ctx, router, msg)
+}
+
+/// Entrypoint which only performs validation of an IBC v2 packet message
+///
+/// IBC v2 packet messages MUST be processed alongside the other messages of
+/// the transaction, as described in [`validate`].
+pub fn validate_v2<Ctx>(
+    ctx: &Ctx,
+    router: &impl RouterV2,
+    msg: PacketMsgV2,
+) -> Result<(), ContextError>
+where
+    Ctx: PacketV2ValidationContext,
+{
+    match msg {
+        PacketMsgV2::Send(msg) => send_packet_v2_validate(ctx, router, &msg),
+        PacketMsgV2::Recv(msg) => recv_packet_v2_validate(ctx, router, &msg),
+        PacketMsgV2::Ack(msg) => acknowledgement_packet_v2_validate(ctx, router, &msg),
+        PacketMsgV2::Timeout(msg) => timeout_packet_v2_validate(ctx, router, &msg),
+    }
+}
+
+/// Entrypoint which only performs execution of an IBC v2 packet message
+pub fn execute_v2<Ctx>(
+    ctx: &mut Ctx,
+    router: &mut impl RouterV2,
+    msg: PacketMsgV2,
+) -> Result<(), ContextError>
+where
+    Ctx: PacketV2ExecutionContext,
+{
+    ctx.with_staged_writes(|ctx| match msg {
+        PacketMsgV2::Send(msg) => send_packet_v2_execute(ctx, router, msg),
+        PacketMsgV2::Recv(msg) => recv_packet_v2_execute(ctx, router, msg),
+        PacketMsgV2::Ack(msg) => acknowledgement_packet_v2_execute(ctx, router, msg),
+        PacketMsgV2::Timeout(msg) => timeout_packet_v2_execute(ctx, router, msg),
+    })
+}
@@ -0,0 +1,20 @@
+//! Extends the entrypoints with the messages of the host, so that hosts do not
+//! need to fork the [`MsgEnvelope`](ibc_core_handler_types::msgs::MsgEnvelope)
+//! to process their own messages in the same pipeline as the IBC ones.
+use ibc_core_handler_types::error::ContextError;
+use ibc_core_handler_types::msgs::HostMsg;
+
+/// Handler of the messages of the host, called by the
+/// [`dispatch_extended`](crate::entrypoint::dispatch_extended),
+/// [`validate_extended`](crate::entrypoint::validate_extended) and
+/// [`execute_extended`](crate::entrypoint::execute_extended) entrypoints.
+pub trait HostMsgHandler<Ctx> {
+    /// The messages of the host
+    type Msg: HostMsg;
+
+    /// Validates the message, without writing any state.
+    fn validate(&self, ctx: &Ctx, msg: &Self::Msg) -> Result<(), ContextError>;
+
+    /// Executes the message, which was validated beforehand.
+    fn execute(&mut self, ctx: &mut Ctx, msg: Self::Msg) -> Result<(), ContextError>;
+}
@@ -8,7 +8,10 @@
 //! to the context (e.g. [`ExecutionContext`](ibc_core_host::ExecutionContext))
 //! while processing `M`. If the transaction containing `M` consists of multiple
 //! messages, then typically the state modifications from all messages is
-//! expected to be rolled back as well.
+//! expected to be rolled back as well, as
+//! [`dispatch_batch`](entrypoint::dispatch_batch) does over a
+//! [`TransactionalExecutionContext`](ibc_core_host::TransactionalExecutionContext)
+//! and a [`TransactionalRouter`](ibc_core_router::router::TransactionalRouter).
 #![no_std]
 #![forbid(unsafe_code)]
 #![cfg_attr(not(test), deny(clippy::unwrap_used))]
@@ -25,6 +28,10 @@
 extern crate std;
 
 pub mod entrypoint;
+pub mod extension;
+
+#[cfg(feature = "async")]
+pub mod async_entrypoint;
 
 /// Re-export IBC handler types from `ibc-core-handler-types` crate.
 pub mod types {
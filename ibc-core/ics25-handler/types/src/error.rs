@@ -2,10 +2,12 @@
 
 use derive_more::From;
 use displaydoc::Display;
-use ibc_core_channel_types::error::{ChannelError, PacketError};
-use ibc_core_client_types::error::ClientError;
-use ibc_core_connection_types::error::ConnectionError;
-use ibc_core_router_types::error::RouterError;
+use ibc_core_channel_types::error::{
+    ChannelError, PacketError, CHANNEL_CODESPACE, PACKET_CODESPACE,
+};
+use ibc_core_client_types::error::{ClientError, CLIENT_CODESPACE};
+use ibc_core_connection_types::error::{ConnectionError, CONNECTION_CODESPACE};
+use ibc_core_router_types::error::{RouterError, ROUTER_CODESPACE};
 use ibc_primitives::prelude::*;
 
 /// Top-level error
@@ -21,6 +23,54 @@ pub enum ContextError {
     PacketError(PacketError),
     /// ICS26 Routing error: {0}
     RouterError(RouterError),
+    /// Gas error: {0}
+    GasError(GasError),
+    /// Store migration error: {0}
+    MigrationError(MigrationError),
+}
+
+/// Error type for the gas meters of the hosts.
+#[derive(Clone, Debug, Display, PartialEq, Eq)]
+pub enum GasError {
+    /// out of gas: `{consumed}` consumed out of a `{limit}` limit
+    OutOfGas { limit: u64, consumed: u64 },
+    /// other error: `{description}`
+    Other { description: String },
+}
+
+impl ContextError {
+    /// Returns the codespace of the error in ABCI responses, which is the one
+    /// of the error it wraps.
+    pub fn codespace(&self) -> &'static str {
+        match self {
+            Self::ClientError(_) => CLIENT_CODESPACE,
+            Self::ConnectionError(_) => CONNECTION_CODESPACE,
+            Self::ChannelError(_) => CHANNEL_CODESPACE,
+            Self::PacketError(_) => PACKET_CODESPACE,
+            Self::RouterError(_) => ROUTER_CODESPACE,
+            Self::GasError(_) => GAS_CODESPACE,
+            Self::MigrationError(_) => MIGRATION_CODESPACE,
+        }
+    }
+
+    /// Returns the code of the error within its
+    /// [`codespace`](Self::codespace).
+    ///
+    /// Each variant of the errors keeps its code across releases, and the
+    /// codes of the removed variants are never reused, so that hosts and
+    /// clients can match failures on the codespace and the code, e.g. as
+    /// set in the `code` and `codespace` fields of the ABCI responses.
+    pub fn code(&self) -> u32 {
+        match self {
+            Self::ClientError(e) => e.code(),
+            Self::ConnectionError(e) => e.code(),
+            Self::ChannelError(e) => e.code(),
+            Self::PacketError(e) => e.code(),
+            Self::RouterError(e) => e.code(),
+            Self::GasError(e) => e.code(),
+            Self::MigrationError(e) => e.code(),
+        }
+    }
 }
 
 impl From<ContextError> for ClientError {
@@ -34,15 +84,159 @@ impl From<ContextError> for ClientError {
     }
 }
 
-#[cfg(feature = "std")]
-impl std::error::Error for ContextError {
-    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+impl core::error::Error for ContextError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
         match self {
             Self::ClientError(e) => Some(e),
             Self::ConnectionError(e) => Some(e),
             Self::ChannelError(e) => Some(e),
             Self::PacketError(e) => Some(e),
             Self::RouterError(e) => Some(e),
+            Self::GasError(e) => Some(e),
+            Self::MigrationError(e) => Some(e),
+        }
+    }
+}
+
+/// The codespace of the [`GasError`]s in ABCI responses.
+pub const GAS_CODESPACE: &str = "gas";
+
+impl GasError {
+    /// Returns the code identifying the variant of the error within the
+    /// [`GAS_CODESPACE`], which is kept across releases.
+    pub fn code(&self) -> u32 {
+        match self {
+            Self::OutOfGas { .. } => 2,
+            Self::Other { .. } => 3,
+        }
+    }
+}
+
+impl core::error::Error for GasError {}
+
+/// Error type for the migrations of the IBC store.
+#[derive(Clone, Debug, Display, PartialEq, Eq)]
+pub enum MigrationError {
+    /// invalid store version: `{version}`
+    InvalidStoreVersion { version: u64 },
+    /// migration from store version `{from_version}` is already registered
+    DuplicateMigration { from_version: u64 },
+    /// missing migration from store version `{from_version}`
+    MissingMigration { from_version: u64 },
+    /// store version `{version}` is newer than the latest supported version `{latest_version}`
+    UnsupportedStoreVersion { version: u64, latest_version: u64 },
+    /// other error: `{description}`
+    Other { description: String },
+}
+
+/// The codespace of the [`MigrationError`]s in ABCI responses.
+pub const MIGRATION_CODESPACE: &str = "migration";
+
+impl MigrationError {
+    /// Returns the code identifying the variant of the error within the
+    /// [`MIGRATION_CODESPACE`], which is kept across releases.
+    pub fn code(&self) -> u32 {
+        match self {
+            Self::InvalidStoreVersion { .. } => 2,
+            Self::DuplicateMigration { .. } => 3,
+            Self::MissingMigration { .. } => 4,
+            Self::UnsupportedStoreVersion { .. } => 5,
+            Self::Other { .. } => 6,
+        }
+    }
+}
+
+impl core::error::Error for MigrationError {}
+
+/// Error of a batch of messages dispatched atomically, in which case none of
+/// the messages of the batch is applied.
+#[derive(Debug, Display)]
+pub enum BatchError {
+    /// message `{index}` of the batch failed: {error}
+    Msg { index: usize, error: ContextError },
+    /// failed to commit the batch: {0}
+    Commit(ContextError),
+}
+
+impl BatchError {
+    /// Returns the index of the message of the batch which failed, or `None`
+    /// if all of them succeeded but committing their writes failed.
+    pub fn index(&self) -> Option<usize> {
+        match self {
+            Self::Msg { index, .. } => Some(*index),
+            Self::Commit(_) => None,
+        }
+    }
+
+    /// Returns the error the batch failed with, whose codespace and code are
+    /// the ones to report in the ABCI response of the transaction.
+    pub fn context_error(&self) -> &ContextError {
+        match self {
+            Self::Msg { error, .. } | Self::Commit(error) => error,
+        }
+    }
+}
+
+impl core::error::Error for BatchError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        Some(self.context_error())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ibc_core_host_types::identifiers::{ChannelId, PortId, Sequence};
+
+    use super::*;
+
+    // The codes must not change across releases, hence are pinned here.
+    #[test]
+    fn test_error_codes() {
+        let errors: [(ContextError, &str, u32); 5] = [
+            (
+                ClientError::Other {
+                    description: "other".to_string(),
+                }
+                .into(),
+                "client",
+                38,
+            ),
+            (
+                PacketError::PacketCommitmentNotFound {
+                    sequence: Sequence::from(1),
+                }
+                .into(),
+                "packet",
+                35,
+            ),
+            (
+                ChannelError::ChannelNotFound {
+                    port_id: PortId::transfer(),
+                    channel_id: ChannelId::new(0),
+                }
+                .into(),
+                "channel",
+                13,
+            ),
+            (
+                GasError::OutOfGas {
+                    limit: 1,
+                    consumed: 2,
+                }
+                .into(),
+                "gas",
+                2,
+            ),
+            (
+                MigrationError::MissingMigration { from_version: 1 }.into(),
+                "migration",
+                4,
+            ),
+        ];
+
+        for (error, codespace, code) in errors {
+            assert_eq!(error.codespace(), codespace);
+            assert_eq!(error.code(), code);
         }
     }
 }
@@ -0,0 +1,442 @@
+//! Defines the encodings of the IBC events, for hosts to emit them natively.
+//!
+//! Every event is encoded from its ABCI representation, a kind along with
+//! key/value attributes, which is how the relayers index and query them:
+//!
+//! - [`AbciEncoder`] produces the ABCI events emitted by Tendermint hosts.
+//! - [`JsonEncoder`] produces the events as JSON objects, in the format of the
+//!   Tendermint RPC, e.g. for the hosts emitting them as logs.
+//! - [`BinaryEncoder`] produces a compact binary encoding, e.g. for the hosts
+//!   emitting them as EVM logs or Substrate events, in which the kinds known
+//!   to the [`EventRegistry`] are replaced by a tag.
+use ibc_core_router_types::event::ModuleEvent;
+use ibc_primitives::prelude::*;
+use tendermint::abci;
+
+use super::{Error, IbcEvent};
+
+/// The kinds of the events emitted by the IBC core, in the order of their
+/// binary tags.
+///
+/// New kinds must only ever be appended, to keep the existing tags stable.
+pub const CORE_EVENT_KINDS: &[&str] = &[
+    "create_client",
+    "update_client",
+    "client_misbehaviour",
+    "upgrade_client",
+    "connection_open_init",
+    "connection_open_try",
+    "connection_open_ack",
+    "connection_open_confirm",
+    "channel_open_init",
+    "channel_open_try",
+    "channel_open_ack",
+    "channel_open_confirm",
+    "channel_close_init",
+    "channel_close_confirm",
+    "channel_upgrade_init",
+    "channel_upgrade_try",
+    "channel_upgrade_ack",
+    "channel_upgrade_confirm",
+    "channel_upgrade_open",
+    "channel_upgrade_timeout",
+    "channel_upgrade_cancelled",
+    "channel_upgrade_error",
+    "send_packet",
+    "receive_packet",
+    "recv_packet",
+    "write_acknowledgement",
+    "acknowledge_packet",
+    "timeout_packet",
+    "channel_close",
+    "message",
+];
+
+/// An event defined by an application, which it emits as a [`ModuleEvent`].
+pub trait AppEvent: Into<ModuleEvent> {
+    /// The kind of the module events of this type.
+    const KIND: &'static str;
+}
+
+/// Encodes the IBC events for the host to emit them.
+pub trait EventEncoder {
+    type Output;
+
+    fn encode(&self, event: IbcEvent) -> Result<Self::Output, Error>;
+}
+
+/// Encodes the events as ABCI events.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct AbciEncoder;
+
+impl EventEncoder for AbciEncoder {
+    type Output = abci::Event;
+
+    fn encode(&self, event: IbcEvent) -> Result<Self::Output, Error> {
+        event.try_into()
+    }
+}
+
+/// Encodes the events as JSON objects, e.g.
+/// `{"type":"message","attributes":[{"key":"module","value":"ibc_client","index":true}]}`.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct JsonEncoder;
+
+impl JsonEncoder {
+    /// Encodes the given ABCI event as a JSON object.
+    pub fn encode_abci(&self, event: &abci::Event) -> String {
+        let mut json = String::from("{\"type\":");
+        push_json_string(&mut json, &event.kind);
+        json.push_str(",\"attributes\":[");
+
+        for (i, attribute) in event.attributes.iter().enumerate() {
+            if i > 0 {
+                json.push(',');
+            }
+            json.push_str("{\"key\":");
+            push_json_string(&mut json, &attribute.key);
+            json.push_str(",\"value\":");
+            push_json_string(&mut json, &attribute.value);
+            json.push_str(",\"index\":");
+            json.push_str(if attribute.index { "true" } else { "false" });
+            json.push('}');
+        }
+
+        json.push_str("]}");
+        json
+    }
+}
+
+impl EventEncoder for JsonEncoder {
+    type Output = String;
+
+    fn encode(&self, event: IbcEvent) -> Result<Self::Output, Error> {
+        Ok(self.encode_abci(&event.try_into()?))
+    }
+}
+
+fn push_json_string(json: &mut String, s: &str) {
+    json.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => json.push_str("\\\""),
+            '\\' => json.push_str("\\\\"),
+            '\n' => json.push_str("\\n"),
+            '\r' => json.push_str("\\r"),
+            '\t' => json.push_str("\\t"),
+            c if (c as u32) < 0x20 => json.push_str(&format!("\\u{:04x}", c as u32)),
+            c => json.push(c),
+        }
+    }
+    json.push('"');
+}
+
+/// The registry of the event kinds the host emits, which assigns each of
+/// them the tag used in place of the kind by the [`BinaryEncoder`].
+///
+/// The core event kinds are always registered first, with the tags `1` to
+/// `CORE_EVENT_KINDS.len()`, the kinds of the applications are then tagged in
+/// the order they are registered in. The tag `0` is used for the kinds which
+/// are not registered, which are then encoded in full.
+#[derive(Clone, Debug)]
+pub struct EventRegistry {
+    app_kinds: Vec<String>,
+}
+
+impl Default for EventRegistry {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl EventRegistry {
+    /// Creates a registry of the core event kinds only.
+    pub fn new() -> Self {
+        Self {
+            app_kinds: Vec::new(),
+        }
+    }
+
+    /// Registers the given event kind of an application, returning its tag.
+    pub fn register(&mut self, kind: impl Into<String>) -> Result<u64, Error> {
+        let kind = kind.into();
+
+        if self.tag(&kind).is_some() {
+            return Err(Error::DuplicateEventKind { kind });
+        }
+
+        self.app_kinds.push(kind);
+
+        Ok((CORE_EVENT_KINDS.len() + self.app_kinds.len()) as u64)
+    }
+
+    /// Registers the kind of the given application event type, returning
+    /// its tag.
+    pub fn register_app_event<E: AppEvent>(&mut self) -> Result<u64, Error> {
+        self.register(E::KIND)
+    }
+
+    /// Returns the tag of the given event kind, if it is registered.
+    pub fn tag(&self, kind: &str) -> Option<u64> {
+        CORE_EVENT_KINDS
+            .iter()
+            .copied()
+            .chain(self.app_kinds.iter().map(String::as_str))
+            .position(|registered| registered == kind)
+            .map(|index| index as u64 + 1)
+    }
+
+    /// Returns the event kind with the given tag, if any.
+    pub fn kind(&self, tag: u64) -> Option<&str> {
+        let index = usize::try_from(tag.checked_sub(1)?).ok()?;
+
+        match CORE_EVENT_KINDS.get(index) {
+            Some(kind) => Some(kind),
+            None => self
+                .app_kinds
+                .get(index - CORE_EVENT_KINDS.len())
+                .map(String::as_str),
+        }
+    }
+}
+
+/// Encodes the events in a compact binary format.
+///
+/// An event is encoded as the tag of its kind in the [`EventRegistry`],
+/// followed by the kind itself if it is not registered, then by the number
+/// of its attributes and, for each of them, its key and its value. The
+/// numbers are encoded as unsigned LEB128, and the strings as their length
+/// followed by their UTF-8 bytes, except the length of the key of an
+/// attribute which is shifted left to carry whether it is indexed.
+#[derive(Clone, Debug, Default)]
+pub struct BinaryEncoder {
+    registry: EventRegistry,
+}
+
+impl BinaryEncoder {
+    pub fn new(registry: EventRegistry) -> Self {
+        Self { registry }
+    }
+
+    pub fn registry(&self) -> &EventRegistry {
+        &self.registry
+    }
+
+    /// Encodes the given ABCI event.
+    pub fn encode_abci(&self, event: &abci::Event) -> Vec<u8> {
+        let mut bytes = Vec::new();
+
+        match self.registry.tag(&event.kind) {
+            Some(tag) => push_varint(&mut bytes, tag),
+            None => {
+                push_varint(&mut bytes, 0);
+                push_bytes(&mut bytes, event.kind.as_bytes());
+            }
+        }
+
+        push_varint(&mut bytes, event.attributes.len() as u64);
+
+        for attribute in &event.attributes {
+            push_varint(
+                &mut bytes,
+                ((attribute.key.len() as u64) << 1) | u64::from(attribute.index),
+            );
+            bytes.extend_from_slice(attribute.key.as_bytes());
+            push_bytes(&mut bytes, attribute.value.as_bytes());
+        }
+
+        bytes
+    }
+
+    /// Decodes an ABCI event from its binary encoding.
+    pub fn decode(&self, bytes: &[u8]) -> Result<abci::Event, Error> {
+        let mut reader = Reader { bytes };
+
+        let kind = match reader.varint()? {
+            0 => reader.string()?,
+            tag => self
+                .registry
+                .kind(tag)
+                .ok_or(Error::UnknownEventTag { tag })?
+                .to_string(),
+        };
+
+        let count = reader.varint()?;
+        let mut attributes = Vec::new();
+
+        for _ in 0..count {
+            let key_header = reader.varint()?;
+            let key = reader.utf8(reader_len(key_header >> 1)?)?;
+            let value = reader.string()?;
+
+            attributes.push(abci::EventAttribute {
+                key,
+                value,
+                index: key_header & 1 == 1,
+            });
+        }
+
+        if !reader.bytes.is_empty() {
+            return Err(Error::InvalidEncoding {
+                description: "trailing bytes".to_string(),
+            });
+        }
+
+        Ok(abci::Event { kind, attributes })
+    }
+}
+
+impl EventEncoder for BinaryEncoder {
+    type Output = Vec<u8>;
+
+    fn encode(&self, event: IbcEvent) -> Result<Self::Output, Error> {
+        Ok(self.encode_abci(&event.try_into()?))
+    }
+}
+
+fn push_varint(bytes: &mut Vec<u8>, mut n: u64) {
+    while n >= 0x80 {
+        bytes.push((n as u8 & 0x7f) | 0x80);
+        n >>= 7;
+    }
+    bytes.push(n as u8);
+}
+
+fn push_bytes(bytes: &mut Vec<u8>, value: &[u8]) {
+    push_varint(bytes, value.len() as u64);
+    bytes.extend_from_slice(value);
+}
+
+fn reader_len(len: u64) -> Result<usize, Error> {
+    usize::try_from(len).map_err(|_| Error::InvalidEncoding {
+        description: format!("length {len} out of range"),
+    })
+}
+
+struct Reader<'a> {
+    bytes: &'a [u8],
+}
+
+impl Reader<'_> {
+    fn varint(&mut self) -> Result<u64, Error> {
+        let mut n = 0u64;
+
+        for shift in (0..64).step_by(7) {
+            let (&byte, rest) = self.bytes.split_first().ok_or(Error::InvalidEncoding {
+                description: "unexpected end of input".to_string(),
+            })?;
+            self.bytes = rest;
+
+            n |= u64::from(byte & 0x7f) << shift;
+
+            if byte & 0x80 == 0 {
+                return Ok(n);
+            }
+        }
+
+        Err(Error::InvalidEncoding {
+            description: "varint overflow".to_string(),
+        })
+    }
+
+    fn string(&mut self) -> Result<String, Error> {
+        let len = reader_len(self.varint()?)?;
+        self.utf8(len)
+    }
+
+    fn utf8(&mut self, len: usize) -> Result<String, Error> {
+        if self.bytes.len() < len {
+            return Err(Error::InvalidEncoding {
+                description: "unexpected end of input".to_string(),
+            });
+        }
+
+        let (value, rest) = self.bytes.split_at(len);
+        self.bytes = rest;
+
+        str::from_utf8(value)
+            .map(ToString::to_string)
+            .map_err(|e| Error::InvalidEncoding {
+                description: e.to_string(),
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::events::MessageEvent;
+
+    fn module_event() -> abci::Event {
+        abci::Event {
+            kind: "fungible_token_packet".to_string(),
+            attributes: vec![
+                ("sender", "cosmos1\"sender\"", true).into(),
+                ("amount", "100\n", false).into(),
+            ],
+        }
+    }
+
+    #[test]
+    fn test_json_encoding() {
+        let json = JsonEncoder
+            .encode(IbcEvent::Message(MessageEvent::Client))
+            .unwrap();
+
+        assert_eq!(
+            json,
+            r#"{"type":"message","attributes":[{"key":"module","value":"ibc_client","index":true}]}"#
+        );
+
+        assert_eq!(
+            JsonEncoder.encode_abci(&module_event()),
+            r#"{"type":"fungible_token_packet","attributes":[{"key":"sender","value":"cosmos1\"sender\"","index":true},{"key":"amount","value":"100\n","index":false}]}"#
+        );
+    }
+
+    #[test]
+    fn test_binary_encoding_roundtrip() {
+        let encoder = BinaryEncoder::default();
+
+        let bytes = encoder
+            .encode(IbcEvent::Message(MessageEvent::Channel))
+            .unwrap();
+        let event = encoder.decode(&bytes).unwrap();
+
+        assert_eq!(bytes[0] as usize, CORE_EVENT_KINDS.len());
+        assert_eq!(event.kind, "message");
+        assert_eq!(event.attributes[0].value, "ibc_channel");
+
+        // Unregistered kinds are encoded in full.
+        let unregistered = encoder.encode_abci(&module_event());
+        assert_eq!(unregistered[0], 0);
+        assert_eq!(encoder.decode(&unregistered).unwrap(), module_event());
+
+        let mut registry = EventRegistry::new();
+        let tag = registry.register("fungible_token_packet").unwrap();
+        let encoder = BinaryEncoder::new(registry);
+
+        let registered = encoder.encode_abci(&module_event());
+        assert_eq!(registered[0] as u64, tag);
+        assert!(registered.len() < unregistered.len());
+        assert_eq!(encoder.decode(&registered).unwrap(), module_event());
+
+        assert!(encoder.decode(&registered[..registered.len() - 1]).is_err());
+    }
+
+    #[test]
+    fn test_registry_rejects_duplicate_kinds() {
+        let mut registry = EventRegistry::new();
+
+        assert!(registry.register("send_packet").is_err());
+        assert!(registry.register("app_event").is_ok());
+        assert!(registry.register("app_event").is_err());
+        assert_eq!(registry.kind(0), None);
+        assert_eq!(registry.kind(1), Some("create_client"));
+        assert_eq!(
+            registry.kind(CORE_EVENT_KINDS.len() as u64 + 1),
+            Some("app_event")
+        );
+        assert_eq!(registry.kind(CORE_EVENT_KINDS.len() as u64 + 2), None);
+    }
+}
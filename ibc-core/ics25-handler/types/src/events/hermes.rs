@@ -0,0 +1,332 @@
+//! Defines the encoding of the IBC events in the exact layout of the ones of
+//! ibc-go, for the relayers built against it, e.g. Hermes, to relay for the
+//! hosts emitting them without being patched.
+//!
+//! The events of ibc-go differ from the ABCI events of `ibc-rs` in a few
+//! legacy ways, which the [`HermesEncoder`] reproduces:
+//!
+//! - the `ReceivePacket` event is of kind `recv_packet`,
+//! - the packet events carry the full packet data, and the write
+//!   acknowledgement events the full acknowledgement, both as is and hex
+//!   encoded, whatever the [`PacketDataEventMode`] of the host. The raw
+//!   attributes are lossily converted to UTF-8 rather than failing, as ibc-go
+//!   emits the bytes as is,
+//! - the packet events carry the connection both as `packet_connection` and
+//!   as `connection_id`,
+//! - all the attributes are indexed, for the relayers to query the
+//!   transactions by the attributes of their packets.
+//!
+//! [`PacketDataEventMode`]: ibc_core_channel_types::events::PacketDataEventMode
+use ibc_core_channel_types::acknowledgement::Acknowledgement;
+use ibc_core_channel_types::channel::Order;
+use ibc_core_channel_types::events::{
+    AcknowledgePacket, ReceivePacket, SendPacket, TimeoutPacket, WriteAcknowledgement,
+};
+use ibc_core_channel_types::timeout::TimeoutHeight;
+use ibc_core_host_types::identifiers::{ChannelId, ConnectionId, PortId, Sequence};
+use ibc_primitives::prelude::*;
+use ibc_primitives::Timestamp;
+use subtle_encoding::hex;
+use tendermint::abci;
+
+use super::{Error, EventEncoder, IbcEvent};
+
+/// The kind of the `ReceivePacket` event in ibc-go.
+const RECV_PACKET_EVENT: &str = "recv_packet";
+
+/// Encodes the events as the ABCI events ibc-go emits, for the relayers
+/// expecting them verbatim.
+///
+/// The events ibc-go emits no differently than `ibc-rs`, e.g. the client and
+/// connection events, are encoded as by the [`AbciEncoder`](super::AbciEncoder)
+/// with all their attributes indexed.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct HermesEncoder;
+
+impl EventEncoder for HermesEncoder {
+    type Output = abci::Event;
+
+    fn encode(&self, event: IbcEvent) -> Result<Self::Output, Error> {
+        let mut event = match event {
+            IbcEvent::SendPacket(event) => send_packet(&event),
+            IbcEvent::ReceivePacket(event) => receive_packet(&event),
+            IbcEvent::WriteAcknowledgement(event) => write_acknowledgement(&event),
+            IbcEvent::AcknowledgePacket(event) => acknowledge_packet(&event),
+            IbcEvent::TimeoutPacket(event) => timeout_packet(&event),
+            event => event.try_into()?,
+        };
+
+        for attribute in &mut event.attributes {
+            attribute.index = true;
+        }
+
+        Ok(event)
+    }
+}
+
+fn send_packet(event: &SendPacket) -> abci::Event {
+    let mut attributes = PacketAttributes::default();
+    attributes.push_data(event.packet_data());
+    attributes.push_packet(&PacketFields {
+        timeout_height: event.timeout_height_on_b(),
+        timeout_timestamp: event.timeout_timestamp_on_b(),
+        sequence: event.seq_on_a(),
+        src_port_id: event.port_id_on_a(),
+        src_channel_id: event.chan_id_on_a(),
+        dst_port_id: event.port_id_on_b(),
+        dst_channel_id: event.chan_id_on_b(),
+    });
+    attributes.push_ordering(event.channel_ordering());
+    attributes.push_connection(event.conn_id_on_a());
+
+    attributes.into_event(event.event_type())
+}
+
+fn receive_packet(event: &ReceivePacket) -> abci::Event {
+    let mut attributes = PacketAttributes::default();
+    attributes.push_data(event.packet_data());
+    attributes.push_packet(&PacketFields {
+        timeout_height: event.timeout_height_on_b(),
+        timeout_timestamp: event.timeout_timestamp_on_b(),
+        sequence: event.seq_on_b(),
+        src_port_id: event.port_id_on_a(),
+        src_channel_id: event.chan_id_on_a(),
+        dst_port_id: event.port_id_on_b(),
+        dst_channel_id: event.chan_id_on_b(),
+    });
+    attributes.push_ordering(event.channel_ordering());
+    attributes.push_connection(event.conn_id_on_a());
+
+    attributes.into_event(RECV_PACKET_EVENT)
+}
+
+fn write_acknowledgement(event: &WriteAcknowledgement) -> abci::Event {
+    let mut attributes = PacketAttributes::default();
+    attributes.push_data(event.packet_data());
+    attributes.push_packet(&PacketFields {
+        timeout_height: event.timeout_height_on_b(),
+        timeout_timestamp: event.timeout_timestamp_on_b(),
+        sequence: event.seq_on_a(),
+        src_port_id: event.port_id_on_a(),
+        src_channel_id: event.chan_id_on_a(),
+        dst_port_id: event.port_id_on_b(),
+        dst_channel_id: event.chan_id_on_b(),
+    });
+    attributes.push_acknowledgement(event.acknowledgement());
+    attributes.push_connection(event.conn_id_on_b());
+
+    attributes.into_event(event.event_type())
+}
+
+fn acknowledge_packet(event: &AcknowledgePacket) -> abci::Event {
+    let mut attributes = PacketAttributes::default();
+    attributes.push_packet(&PacketFields {
+        timeout_height: event.timeout_height_on_b(),
+        timeout_timestamp: event.timeout_timestamp_on_b(),
+        sequence: event.seq_on_a(),
+        src_port_id: event.port_id_on_a(),
+        src_channel_id: event.chan_id_on_a(),
+        dst_port_id: event.port_id_on_b(),
+        dst_channel_id: event.chan_id_on_b(),
+    });
+    attributes.push_ordering(event.channel_ordering());
+    attributes.push_connection(event.conn_id_on_a());
+
+    attributes.into_event(event.event_type())
+}
+
+/// The `TimeoutPacket` event does not carry the connection of the channel,
+/// which ibc-go emits as `connection_id`, and which is then left out.
+fn timeout_packet(event: &TimeoutPacket) -> abci::Event {
+    let mut attributes = PacketAttributes::default();
+    attributes.push_packet(&PacketFields {
+        timeout_height: event.timeout_height_on_b(),
+        timeout_timestamp: event.timeout_timestamp_on_b(),
+        sequence: event.seq_on_a(),
+        src_port_id: event.port_id_on_a(),
+        src_channel_id: event.chan_id_on_a(),
+        dst_port_id: event.port_id_on_b(),
+        dst_channel_id: event.chan_id_on_b(),
+    });
+    attributes.push_ordering(event.channel_ordering());
+
+    attributes.into_event(event.event_type())
+}
+
+/// The fields of the packet every packet event carries.
+struct PacketFields<'a> {
+    timeout_height: &'a TimeoutHeight,
+    timeout_timestamp: &'a Timestamp,
+    sequence: &'a Sequence,
+    src_port_id: &'a PortId,
+    src_channel_id: &'a ChannelId,
+    dst_port_id: &'a PortId,
+    dst_channel_id: &'a ChannelId,
+}
+
+/// The attributes of a packet event, in the order ibc-go emits them in.
+#[derive(Default)]
+struct PacketAttributes {
+    attributes: Vec<abci::EventAttribute>,
+}
+
+impl PacketAttributes {
+    fn push(&mut self, key: &str, value: impl Into<String>) {
+        self.attributes.push((key, value.into(), true).into());
+    }
+
+    fn push_data(&mut self, data: &[u8]) {
+        self.push("packet_data", String::from_utf8_lossy(data));
+        self.push("packet_data_hex", hex_string(data));
+    }
+
+    fn push_packet(&mut self, packet: &PacketFields<'_>) {
+        let timeout_height = match packet.timeout_height {
+            TimeoutHeight::Never => "0-0".to_string(),
+            TimeoutHeight::At(height) => height.to_string(),
+        };
+
+        self.push("packet_timeout_height", timeout_height);
+        self.push(
+            "packet_timeout_timestamp",
+            packet.timeout_timestamp.nanoseconds().to_string(),
+        );
+        self.push("packet_sequence", packet.sequence.to_string());
+        self.push("packet_src_port", packet.src_port_id.as_str());
+        self.push("packet_src_channel", packet.src_channel_id.as_str());
+        self.push("packet_dst_port", packet.dst_port_id.as_str());
+        self.push("packet_dst_channel", packet.dst_channel_id.as_str());
+    }
+
+    fn push_ordering(&mut self, ordering: &Order) {
+        self.push("packet_channel_ordering", ordering.as_str());
+    }
+
+    fn push_acknowledgement(&mut self, acknowledgement: &Acknowledgement) {
+        self.push(
+            "packet_ack",
+            String::from_utf8_lossy(acknowledgement.as_bytes()),
+        );
+        self.push("packet_ack_hex", hex_string(acknowledgement.as_bytes()));
+    }
+
+    fn push_connection(&mut self, connection_id: &ConnectionId) {
+        self.push("packet_connection", connection_id.as_str());
+        self.push("connection_id", connection_id.as_str());
+    }
+
+    fn into_event(self, kind: &str) -> abci::Event {
+        abci::Event {
+            kind: kind.to_string(),
+            attributes: self.attributes,
+        }
+    }
+}
+
+fn hex_string(bytes: &[u8]) -> String {
+    String::from_utf8(hex::encode(bytes)).expect("Never fails because hexadecimal is valid UTF-8")
+}
+
+#[cfg(test)]
+mod tests {
+    use ibc_core_channel_types::events::PacketDataEventMode;
+    use ibc_core_channel_types::packet::Packet;
+    use ibc_core_client_types::Height;
+
+    use super::*;
+    use crate::events::{AbciEncoder, MessageEvent};
+
+    fn packet(data: Vec<u8>) -> Packet {
+        Packet {
+            seq_on_a: Sequence::from(7),
+            port_id_on_a: PortId::transfer(),
+            chan_id_on_a: ChannelId::zero(),
+            port_id_on_b: PortId::transfer(),
+            chan_id_on_b: ChannelId::new(1),
+            data,
+            timeout_height_on_b: Height::new(1, 100).unwrap().into(),
+            timeout_timestamp_on_b: Timestamp::from_nanoseconds(1_000).unwrap(),
+        }
+    }
+
+    fn attribute<'a>(event: &'a abci::Event, key: &str) -> Option<&'a str> {
+        event
+            .attributes
+            .iter()
+            .find(|attribute| attribute.key == key)
+            .map(|attribute| attribute.value.as_str())
+    }
+
+    #[test]
+    fn test_send_packet_layout() {
+        let event = IbcEvent::SendPacket(
+            SendPacket::new(
+                packet(b"{}".to_vec()),
+                Order::Unordered,
+                ConnectionId::zero(),
+            )
+            .with_packet_data_mode(PacketDataEventMode::Hashed),
+        );
+
+        let event = HermesEncoder.encode(event).unwrap();
+        let keys: Vec<_> = event.attributes.iter().map(|a| a.key.as_str()).collect();
+
+        assert_eq!(event.kind, "send_packet");
+        assert_eq!(
+            keys,
+            [
+                "packet_data",
+                "packet_data_hex",
+                "packet_timeout_height",
+                "packet_timeout_timestamp",
+                "packet_sequence",
+                "packet_src_port",
+                "packet_src_channel",
+                "packet_dst_port",
+                "packet_dst_channel",
+                "packet_channel_ordering",
+                "packet_connection",
+                "connection_id",
+            ]
+        );
+        assert_eq!(attribute(&event, "packet_data"), Some("{}"));
+        assert_eq!(attribute(&event, "packet_data_hex"), Some("7b7d"));
+        assert_eq!(attribute(&event, "packet_timeout_height"), Some("1-100"));
+        assert_eq!(attribute(&event, "packet_timeout_timestamp"), Some("1000"));
+        assert_eq!(
+            attribute(&event, "packet_channel_ordering"),
+            Some("ORDER_UNORDERED")
+        );
+        assert!(event.attributes.iter().all(|a| a.index));
+    }
+
+    #[test]
+    fn test_receive_packet_kind_and_non_utf8_data() {
+        let event = IbcEvent::ReceivePacket(ReceivePacket::new(
+            packet(vec![0xff, 0x01]),
+            Order::Ordered,
+            ConnectionId::zero(),
+        ));
+
+        // The native encoding fails on non UTF-8 packet data.
+        assert!(AbciEncoder.encode(event.clone()).is_err());
+
+        let event = HermesEncoder.encode(event).unwrap();
+
+        assert_eq!(event.kind, "recv_packet");
+        assert_eq!(attribute(&event, "packet_data"), Some("\u{fffd}\u{1}"));
+        assert_eq!(attribute(&event, "packet_data_hex"), Some("ff01"));
+        assert_eq!(attribute(&event, "connection_id"), Some("connection-0"));
+    }
+
+    #[test]
+    fn test_other_events_are_indexed() {
+        let event = HermesEncoder
+            .encode(IbcEvent::Message(MessageEvent::Channel))
+            .unwrap();
+
+        assert_eq!(event.kind, "message");
+        assert_eq!(attribute(&event, "module"), Some("ibc_channel"));
+        assert!(event.attributes.iter().all(|a| a.index));
+    }
+}
@@ -1,6 +1,7 @@
 //! Defines events emitted during handling of IBC messages
 
 use displaydoc::Display;
+use ibc_core_channel_types::v2::events as ChannelEventsV2;
 use ibc_core_channel_types::{error as channel_error, events as ChannelEvents};
 use ibc_core_client_types::error as client_error;
 use ibc_core_client_types::events::{self as ClientEvents};
@@ -11,6 +12,11 @@ use ibc_primitives::prelude::*;
 use ibc_primitives::ParseTimestampError;
 use tendermint::abci;
 
+mod encoding;
+mod hermes;
+pub use encoding::*;
+pub use hermes::*;
+
 /// All error variants related to IBC events
 #[derive(Debug, Display)]
 pub enum Error {
@@ -30,11 +36,16 @@ pub enum Error {
     IncorrectEventType { event: String },
     /// module event cannot use core event types: `{event:?}`
     MalformedModuleEvent { event: ModuleEvent },
+    /// event kind `{kind}` is already registered
+    DuplicateEventKind { kind: String },
+    /// unknown event tag `{tag}`
+    UnknownEventTag { tag: u64 },
+    /// invalid encoded event: `{description}`
+    InvalidEncoding { description: String },
 }
 
-#[cfg(feature = "std")]
-impl std::error::Error for Error {
-    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+impl core::error::Error for Error {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
         match &self {
             Self::Parse(e) => Some(e),
             Self::Client(e) => Some(e),
@@ -62,6 +73,7 @@ const MESSAGE_EVENT: &str = "message";
     derive(borsh::BorshSerialize, borsh::BorshDeserialize)
 )]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub enum IbcEvent {
     CreateClient(ClientEvents::CreateClient),
@@ -80,6 +92,14 @@ pub enum IbcEvent {
     OpenConfirmChannel(ChannelEvents::OpenConfirm),
     CloseInitChannel(ChannelEvents::CloseInit),
     CloseConfirmChannel(ChannelEvents::CloseConfirm),
+    UpgradeInitChannel(ChannelEvents::UpgradeInit),
+    UpgradeTryChannel(ChannelEvents::UpgradeTry),
+    UpgradeAckChannel(ChannelEvents::UpgradeAck),
+    UpgradeConfirmChannel(ChannelEvents::UpgradeConfirm),
+    UpgradeOpenChannel(ChannelEvents::UpgradeOpen),
+    UpgradeTimeoutChannel(ChannelEvents::UpgradeTimeout),
+    UpgradeCancelChannel(ChannelEvents::UpgradeCancel),
+    UpgradeErrorChannel(ChannelEvents::UpgradeError),
 
     SendPacket(ChannelEvents::SendPacket),
     ReceivePacket(ChannelEvents::ReceivePacket),
@@ -88,6 +108,12 @@ pub enum IbcEvent {
     TimeoutPacket(ChannelEvents::TimeoutPacket),
     ChannelClosed(ChannelEvents::ChannelClosed),
 
+    SendPacketV2(ChannelEventsV2::SendPacket),
+    ReceivePacketV2(ChannelEventsV2::ReceivePacket),
+    WriteAcknowledgementV2(ChannelEventsV2::WriteAcknowledgement),
+    AcknowledgePacketV2(ChannelEventsV2::AcknowledgePacket),
+    TimeoutPacketV2(ChannelEventsV2::TimeoutPacket),
+
     Module(ModuleEvent),
     Message(MessageEvent),
 }
@@ -111,12 +137,25 @@ impl TryFrom<IbcEvent> for abci::Event {
             IbcEvent::OpenConfirmChannel(event) => event.into(),
             IbcEvent::CloseInitChannel(event) => event.into(),
             IbcEvent::CloseConfirmChannel(event) => event.into(),
+            IbcEvent::UpgradeInitChannel(event) => event.into(),
+            IbcEvent::UpgradeTryChannel(event) => event.into(),
+            IbcEvent::UpgradeAckChannel(event) => event.into(),
+            IbcEvent::UpgradeConfirmChannel(event) => event.into(),
+            IbcEvent::UpgradeOpenChannel(event) => event.into(),
+            IbcEvent::UpgradeTimeoutChannel(event) => event.into(),
+            IbcEvent::UpgradeCancelChannel(event) => event.into(),
+            IbcEvent::UpgradeErrorChannel(event) => event.into(),
             IbcEvent::SendPacket(event) => event.try_into().map_err(Error::Channel)?,
             IbcEvent::ReceivePacket(event) => event.try_into().map_err(Error::Channel)?,
             IbcEvent::WriteAcknowledgement(event) => event.try_into().map_err(Error::Channel)?,
             IbcEvent::AcknowledgePacket(event) => event.try_into().map_err(Error::Channel)?,
             IbcEvent::TimeoutPacket(event) => event.try_into().map_err(Error::Channel)?,
             IbcEvent::ChannelClosed(event) => event.into(),
+            IbcEvent::SendPacketV2(event) => event.into(),
+            IbcEvent::ReceivePacketV2(event) => event.into(),
+            IbcEvent::WriteAcknowledgementV2(event) => event.into(),
+            IbcEvent::AcknowledgePacketV2(event) => event.into(),
+            IbcEvent::TimeoutPacketV2(event) => event.into(),
             IbcEvent::Module(event) => event.into(),
             IbcEvent::Message(event) => abci::Event {
                 kind: MESSAGE_EVENT.to_string(),
@@ -127,6 +166,11 @@ impl TryFrom<IbcEvent> for abci::Event {
 }
 
 impl IbcEvent {
+    /// Encodes the event with the given encoder.
+    pub fn encode<E: EventEncoder>(self, encoder: &E) -> Result<E::Output, Error> {
+        encoder.encode(self)
+    }
+
     pub fn event_type(&self) -> &str {
         match self {
             IbcEvent::CreateClient(event) => event.event_type(),
@@ -143,12 +187,25 @@ impl IbcEvent {
             IbcEvent::OpenConfirmChannel(event) => event.event_type(),
             IbcEvent::CloseInitChannel(event) => event.event_type(),
             IbcEvent::CloseConfirmChannel(event) => event.event_type(),
+            IbcEvent::UpgradeInitChannel(event) => event.event_type(),
+            IbcEvent::UpgradeTryChannel(event) => event.event_type(),
+            IbcEvent::UpgradeAckChannel(event) => event.event_type(),
+            IbcEvent::UpgradeConfirmChannel(event) => event.event_type(),
+            IbcEvent::UpgradeOpenChannel(event) => event.event_type(),
+            IbcEvent::UpgradeTimeoutChannel(event) => event.event_type(),
+            IbcEvent::UpgradeCancelChannel(event) => event.event_type(),
+            IbcEvent::UpgradeErrorChannel(event) => event.event_type(),
             IbcEvent::SendPacket(event) => event.event_type(),
             IbcEvent::ReceivePacket(event) => event.event_type(),
             IbcEvent::WriteAcknowledgement(event) => event.event_type(),
             IbcEvent::AcknowledgePacket(event) => event.event_type(),
             IbcEvent::TimeoutPacket(event) => event.event_type(),
             IbcEvent::ChannelClosed(event) => event.event_type(),
+            IbcEvent::SendPacketV2(event) => event.event_type(),
+            IbcEvent::ReceivePacketV2(event) => event.event_type(),
+            IbcEvent::WriteAcknowledgementV2(event) => event.event_type(),
+            IbcEvent::AcknowledgePacketV2(event) => event.event_type(),
+            IbcEvent::TimeoutPacketV2(event) => event.event_type(),
             IbcEvent::Module(module_event) => module_event.kind.as_str(),
             IbcEvent::Message(_) => MESSAGE_EVENT,
         }
@@ -173,6 +230,7 @@ impl IbcEvent {
     derive(borsh::BorshSerialize, borsh::BorshDeserialize)
 )]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum MessageEvent {
     Client,
@@ -1,10 +1,18 @@
 use ibc_core_channel_types::msgs::{
     ChannelMsg, MsgAcknowledgement, MsgChannelCloseConfirm, MsgChannelCloseInit, MsgChannelOpenAck,
-    MsgChannelOpenConfirm, MsgChannelOpenInit, MsgChannelOpenTry, MsgRecvPacket, MsgTimeout,
-    MsgTimeoutOnClose, PacketMsg, ACKNOWLEDGEMENT_TYPE_URL, CHAN_CLOSE_CONFIRM_TYPE_URL,
-    CHAN_CLOSE_INIT_TYPE_URL, CHAN_OPEN_ACK_TYPE_URL, CHAN_OPEN_CONFIRM_TYPE_URL,
-    CHAN_OPEN_INIT_TYPE_URL, CHAN_OPEN_TRY_TYPE_URL, RECV_PACKET_TYPE_URL,
-    TIMEOUT_ON_CLOSE_TYPE_URL, TIMEOUT_TYPE_URL,
+    MsgChannelOpenConfirm, MsgChannelOpenInit, MsgChannelOpenTry, MsgChannelUpgradeAck,
+    MsgChannelUpgradeCancel, MsgChannelUpgradeConfirm, MsgChannelUpgradeInit,
+    MsgChannelUpgradeOpen, MsgChannelUpgradeTimeout, MsgChannelUpgradeTry, MsgRecvPacket,
+    MsgTimeout, MsgTimeoutOnClose, PacketMsg, ACKNOWLEDGEMENT_TYPE_URL,
+    CHAN_CLOSE_CONFIRM_TYPE_URL, CHAN_CLOSE_INIT_TYPE_URL, CHAN_OPEN_ACK_TYPE_URL,
+    CHAN_OPEN_CONFIRM_TYPE_URL, CHAN_OPEN_INIT_TYPE_URL, CHAN_OPEN_TRY_TYPE_URL,
+    CHAN_UPGRADE_ACK_TYPE_URL, CHAN_UPGRADE_CANCEL_TYPE_URL, CHAN_UPGRADE_CONFIRM_TYPE_URL,
+    CHAN_UPGRADE_INIT_TYPE_URL, CHAN_UPGRADE_OPEN_TYPE_URL, CHAN_UPGRADE_TIMEOUT_TYPE_URL,
+    CHAN_UPGRADE_TRY_TYPE_URL, RECV_PACKET_TYPE_URL, TIMEOUT_ON_CLOSE_TYPE_URL, TIMEOUT_TYPE_URL,
+};
+use ibc_core_channel_types::v2::msgs::{
+    self as v2, PacketMsgV2, ACKNOWLEDGEMENT_V2_TYPE_URL, RECV_PACKET_V2_TYPE_URL,
+    SEND_PACKET_V2_TYPE_URL, TIMEOUT_V2_TYPE_URL,
 };
 #[allow(deprecated)]
 use ibc_core_client_types::msgs::{
@@ -19,8 +27,8 @@ use ibc_core_connection_types::msgs::{
 };
 use ibc_core_router_types::error::RouterError;
 use ibc_primitives::prelude::*;
+use ibc_primitives::StrictProtobuf;
 use ibc_proto::google::protobuf::Any;
-use ibc_proto::Protobuf;
 
 /// Enumeration of all messages that the local ICS26 module is capable of routing.
 #[cfg_attr(
@@ -45,71 +53,66 @@ impl TryFrom<Any> for MsgEnvelope {
             // ICS2 messages
             CREATE_CLIENT_TYPE_URL => {
                 // Pop out the message and then wrap it in the corresponding type.
-                let domain_msg = MsgCreateClient::decode_vec(&any_msg.value).map_err(|e| {
-                    RouterError::MalformedMessageBytes {
-                        reason: e.to_string(),
-                    }
-                })?;
+                let domain_msg =
+                    MsgCreateClient::decode_vec_critical(&any_msg.value).map_err(|e| {
+                        RouterError::MalformedMessageBytes {
+                            reason: e.to_string(),
+                        }
+                    })?;
                 Ok(MsgEnvelope::Client(ClientMsg::CreateClient(domain_msg)))
             }
             UPDATE_CLIENT_TYPE_URL => {
-                let domain_msg = MsgUpdateClient::decode_vec(&any_msg.value).map_err(|e| {
-                    RouterError::MalformedMessageBytes {
-                        reason: e.to_string(),
-                    }
-                })?;
+                let domain_msg =
+                    MsgUpdateClient::decode_vec_critical(&any_msg.value).map_err(|e| {
+                        RouterError::MalformedMessageBytes {
+                            reason: e.to_string(),
+                        }
+                    })?;
                 Ok(MsgEnvelope::Client(ClientMsg::UpdateClient(domain_msg)))
             }
             UPGRADE_CLIENT_TYPE_URL => {
-                let domain_msg = MsgUpgradeClient::decode_vec(&any_msg.value).map_err(|e| {
-                    RouterError::MalformedMessageBytes {
-                        reason: e.to_string(),
-                    }
-                })?;
-                Ok(MsgEnvelope::Client(ClientMsg::UpgradeClient(domain_msg)))
-            }
-            SUBMIT_MISBEHAVIOUR_TYPE_URL => {
                 let domain_msg =
-                    MsgSubmitMisbehaviour::decode_vec(&any_msg.value).map_err(|e| {
+                    MsgUpgradeClient::decode_vec_critical(&any_msg.value).map_err(|e| {
                         RouterError::MalformedMessageBytes {
                             reason: e.to_string(),
                         }
                     })?;
+                Ok(MsgEnvelope::Client(ClientMsg::UpgradeClient(domain_msg)))
+            }
+            SUBMIT_MISBEHAVIOUR_TYPE_URL => {
+                let domain_msg = MsgSubmitMisbehaviour::decode_vec_critical(&any_msg.value)
+                    .map_err(|e| RouterError::MalformedMessageBytes {
+                        reason: e.to_string(),
+                    })?;
                 Ok(MsgEnvelope::Client(ClientMsg::Misbehaviour(domain_msg)))
             }
 
             // ICS03
             CONN_OPEN_INIT_TYPE_URL => {
-                let domain_msg =
-                    MsgConnectionOpenInit::decode_vec(&any_msg.value).map_err(|e| {
-                        RouterError::MalformedMessageBytes {
-                            reason: e.to_string(),
-                        }
+                let domain_msg = MsgConnectionOpenInit::decode_vec_critical(&any_msg.value)
+                    .map_err(|e| RouterError::MalformedMessageBytes {
+                        reason: e.to_string(),
                     })?;
                 Ok(MsgEnvelope::Connection(ConnectionMsg::OpenInit(domain_msg)))
             }
             CONN_OPEN_TRY_TYPE_URL => {
-                let domain_msg = MsgConnectionOpenTry::decode_vec(&any_msg.value).map_err(|e| {
-                    RouterError::MalformedMessageBytes {
+                let domain_msg = MsgConnectionOpenTry::decode_vec_critical(&any_msg.value)
+                    .map_err(|e| RouterError::MalformedMessageBytes {
                         reason: e.to_string(),
-                    }
-                })?;
+                    })?;
                 Ok(MsgEnvelope::Connection(ConnectionMsg::OpenTry(domain_msg)))
             }
             CONN_OPEN_ACK_TYPE_URL => {
-                let domain_msg = MsgConnectionOpenAck::decode_vec(&any_msg.value).map_err(|e| {
-                    RouterError::MalformedMessageBytes {
+                let domain_msg = MsgConnectionOpenAck::decode_vec_critical(&any_msg.value)
+                    .map_err(|e| RouterError::MalformedMessageBytes {
                         reason: e.to_string(),
-                    }
-                })?;
+                    })?;
                 Ok(MsgEnvelope::Connection(ConnectionMsg::OpenAck(domain_msg)))
             }
             CONN_OPEN_CONFIRM_TYPE_URL => {
-                let domain_msg =
-                    MsgConnectionOpenConfirm::decode_vec(&any_msg.value).map_err(|e| {
-                        RouterError::MalformedMessageBytes {
-                            reason: e.to_string(),
-                        }
+                let domain_msg = MsgConnectionOpenConfirm::decode_vec_critical(&any_msg.value)
+                    .map_err(|e| RouterError::MalformedMessageBytes {
+                        reason: e.to_string(),
                     })?;
                 Ok(MsgEnvelope::Connection(ConnectionMsg::OpenConfirm(
                     domain_msg,
@@ -118,74 +121,125 @@ impl TryFrom<Any> for MsgEnvelope {
 
             // ICS04 channel messages
             CHAN_OPEN_INIT_TYPE_URL => {
-                let domain_msg = MsgChannelOpenInit::decode_vec(&any_msg.value).map_err(|e| {
-                    RouterError::MalformedMessageBytes {
-                        reason: e.to_string(),
-                    }
-                })?;
+                let domain_msg =
+                    MsgChannelOpenInit::decode_vec_critical(&any_msg.value).map_err(|e| {
+                        RouterError::MalformedMessageBytes {
+                            reason: e.to_string(),
+                        }
+                    })?;
                 Ok(MsgEnvelope::Channel(ChannelMsg::OpenInit(domain_msg)))
             }
             CHAN_OPEN_TRY_TYPE_URL => {
-                let domain_msg = MsgChannelOpenTry::decode_vec(&any_msg.value).map_err(|e| {
-                    RouterError::MalformedMessageBytes {
-                        reason: e.to_string(),
-                    }
-                })?;
+                let domain_msg =
+                    MsgChannelOpenTry::decode_vec_critical(&any_msg.value).map_err(|e| {
+                        RouterError::MalformedMessageBytes {
+                            reason: e.to_string(),
+                        }
+                    })?;
                 Ok(MsgEnvelope::Channel(ChannelMsg::OpenTry(domain_msg)))
             }
             CHAN_OPEN_ACK_TYPE_URL => {
-                let domain_msg = MsgChannelOpenAck::decode_vec(&any_msg.value).map_err(|e| {
-                    RouterError::MalformedMessageBytes {
-                        reason: e.to_string(),
-                    }
-                })?;
-                Ok(MsgEnvelope::Channel(ChannelMsg::OpenAck(domain_msg)))
-            }
-            CHAN_OPEN_CONFIRM_TYPE_URL => {
                 let domain_msg =
-                    MsgChannelOpenConfirm::decode_vec(&any_msg.value).map_err(|e| {
+                    MsgChannelOpenAck::decode_vec_critical(&any_msg.value).map_err(|e| {
                         RouterError::MalformedMessageBytes {
                             reason: e.to_string(),
                         }
                     })?;
-                Ok(MsgEnvelope::Channel(ChannelMsg::OpenConfirm(domain_msg)))
+                Ok(MsgEnvelope::Channel(ChannelMsg::OpenAck(domain_msg)))
             }
-            CHAN_CLOSE_INIT_TYPE_URL => {
-                let domain_msg = MsgChannelCloseInit::decode_vec(&any_msg.value).map_err(|e| {
-                    RouterError::MalformedMessageBytes {
+            CHAN_OPEN_CONFIRM_TYPE_URL => {
+                let domain_msg = MsgChannelOpenConfirm::decode_vec_critical(&any_msg.value)
+                    .map_err(|e| RouterError::MalformedMessageBytes {
                         reason: e.to_string(),
-                    }
-                })?;
-                Ok(MsgEnvelope::Channel(ChannelMsg::CloseInit(domain_msg)))
+                    })?;
+                Ok(MsgEnvelope::Channel(ChannelMsg::OpenConfirm(domain_msg)))
             }
-            CHAN_CLOSE_CONFIRM_TYPE_URL => {
+            CHAN_CLOSE_INIT_TYPE_URL => {
                 let domain_msg =
-                    MsgChannelCloseConfirm::decode_vec(&any_msg.value).map_err(|e| {
+                    MsgChannelCloseInit::decode_vec_critical(&any_msg.value).map_err(|e| {
                         RouterError::MalformedMessageBytes {
                             reason: e.to_string(),
                         }
                     })?;
+                Ok(MsgEnvelope::Channel(ChannelMsg::CloseInit(domain_msg)))
+            }
+            CHAN_CLOSE_CONFIRM_TYPE_URL => {
+                let domain_msg = MsgChannelCloseConfirm::decode_vec_critical(&any_msg.value)
+                    .map_err(|e| RouterError::MalformedMessageBytes {
+                        reason: e.to_string(),
+                    })?;
                 Ok(MsgEnvelope::Channel(ChannelMsg::CloseConfirm(domain_msg)))
             }
+            CHAN_UPGRADE_INIT_TYPE_URL => {
+                let domain_msg = MsgChannelUpgradeInit::decode_vec_critical(&any_msg.value)
+                    .map_err(|e| RouterError::MalformedMessageBytes {
+                        reason: e.to_string(),
+                    })?;
+                Ok(MsgEnvelope::Channel(ChannelMsg::UpgradeInit(domain_msg)))
+            }
+            CHAN_UPGRADE_TRY_TYPE_URL => {
+                let domain_msg = MsgChannelUpgradeTry::decode_vec_critical(&any_msg.value)
+                    .map_err(|e| RouterError::MalformedMessageBytes {
+                        reason: e.to_string(),
+                    })?;
+                Ok(MsgEnvelope::Channel(ChannelMsg::UpgradeTry(domain_msg)))
+            }
+            CHAN_UPGRADE_ACK_TYPE_URL => {
+                let domain_msg = MsgChannelUpgradeAck::decode_vec_critical(&any_msg.value)
+                    .map_err(|e| RouterError::MalformedMessageBytes {
+                        reason: e.to_string(),
+                    })?;
+                Ok(MsgEnvelope::Channel(ChannelMsg::UpgradeAck(domain_msg)))
+            }
+            CHAN_UPGRADE_CONFIRM_TYPE_URL => {
+                let domain_msg = MsgChannelUpgradeConfirm::decode_vec_critical(&any_msg.value)
+                    .map_err(|e| RouterError::MalformedMessageBytes {
+                        reason: e.to_string(),
+                    })?;
+                Ok(MsgEnvelope::Channel(ChannelMsg::UpgradeConfirm(domain_msg)))
+            }
+            CHAN_UPGRADE_OPEN_TYPE_URL => {
+                let domain_msg = MsgChannelUpgradeOpen::decode_vec_critical(&any_msg.value)
+                    .map_err(|e| RouterError::MalformedMessageBytes {
+                        reason: e.to_string(),
+                    })?;
+                Ok(MsgEnvelope::Channel(ChannelMsg::UpgradeOpen(domain_msg)))
+            }
+            CHAN_UPGRADE_TIMEOUT_TYPE_URL => {
+                let domain_msg = MsgChannelUpgradeTimeout::decode_vec_critical(&any_msg.value)
+                    .map_err(|e| RouterError::MalformedMessageBytes {
+                        reason: e.to_string(),
+                    })?;
+                Ok(MsgEnvelope::Channel(ChannelMsg::UpgradeTimeout(domain_msg)))
+            }
+            CHAN_UPGRADE_CANCEL_TYPE_URL => {
+                let domain_msg = MsgChannelUpgradeCancel::decode_vec_critical(&any_msg.value)
+                    .map_err(|e| RouterError::MalformedMessageBytes {
+                        reason: e.to_string(),
+                    })?;
+                Ok(MsgEnvelope::Channel(ChannelMsg::UpgradeCancel(domain_msg)))
+            }
             // ICS04 packet messages
             RECV_PACKET_TYPE_URL => {
-                let domain_msg = MsgRecvPacket::decode_vec(&any_msg.value).map_err(|e| {
-                    RouterError::MalformedMessageBytes {
-                        reason: e.to_string(),
-                    }
-                })?;
+                let domain_msg =
+                    MsgRecvPacket::decode_vec_critical(&any_msg.value).map_err(|e| {
+                        RouterError::MalformedMessageBytes {
+                            reason: e.to_string(),
+                        }
+                    })?;
                 Ok(MsgEnvelope::Packet(PacketMsg::Recv(domain_msg)))
             }
             ACKNOWLEDGEMENT_TYPE_URL => {
-                let domain_msg = MsgAcknowledgement::decode_vec(&any_msg.value).map_err(|e| {
-                    RouterError::MalformedMessageBytes {
-                        reason: e.to_string(),
-                    }
-                })?;
+                let domain_msg =
+                    MsgAcknowledgement::decode_vec_critical(&any_msg.value).map_err(|e| {
+                        RouterError::MalformedMessageBytes {
+                            reason: e.to_string(),
+                        }
+                    })?;
                 Ok(MsgEnvelope::Packet(PacketMsg::Ack(domain_msg)))
             }
             TIMEOUT_TYPE_URL => {
-                let domain_msg = MsgTimeout::decode_vec(&any_msg.value).map_err(|e| {
+                let domain_msg = MsgTimeout::decode_vec_critical(&any_msg.value).map_err(|e| {
                     RouterError::MalformedMessageBytes {
                         reason: e.to_string(),
                     }
@@ -193,11 +247,12 @@ impl TryFrom<Any> for MsgEnvelope {
                 Ok(MsgEnvelope::Packet(PacketMsg::Timeout(domain_msg)))
             }
             TIMEOUT_ON_CLOSE_TYPE_URL => {
-                let domain_msg = MsgTimeoutOnClose::decode_vec(&any_msg.value).map_err(|e| {
-                    RouterError::MalformedMessageBytes {
-                        reason: e.to_string(),
-                    }
-                })?;
+                let domain_msg =
+                    MsgTimeoutOnClose::decode_vec_critical(&any_msg.value).map_err(|e| {
+                        RouterError::MalformedMessageBytes {
+                            reason: e.to_string(),
+                        }
+                    })?;
                 Ok(MsgEnvelope::Packet(PacketMsg::TimeoutOnClose(domain_msg)))
             }
             _ => Err(RouterError::UnknownMessageTypeUrl {
@@ -206,3 +261,87 @@ impl TryFrom<Any> for MsgEnvelope {
         }
     }
 }
+
+impl TryFrom<Any> for PacketMsgV2 {
+    type Error = RouterError;
+
+    fn try_from(any_msg: Any) -> Result<Self, Self::Error> {
+        match any_msg.type_url.as_str() {
+            SEND_PACKET_V2_TYPE_URL => {
+                let domain_msg =
+                    v2::MsgSendPacket::decode_vec_critical(&any_msg.value).map_err(|e| {
+                        RouterError::MalformedMessageBytes {
+                            reason: e.to_string(),
+                        }
+                    })?;
+                Ok(PacketMsgV2::Send(domain_msg))
+            }
+            RECV_PACKET_V2_TYPE_URL => {
+                let domain_msg =
+                    v2::MsgRecvPacket::decode_vec_critical(&any_msg.value).map_err(|e| {
+                        RouterError::MalformedMessageBytes {
+                            reason: e.to_string(),
+                        }
+                    })?;
+                Ok(PacketMsgV2::Recv(domain_msg))
+            }
+            ACKNOWLEDGEMENT_V2_TYPE_URL => {
+                let domain_msg = v2::MsgAcknowledgement::decode_vec_critical(&any_msg.value)
+                    .map_err(|e| RouterError::MalformedMessageBytes {
+                        reason: e.to_string(),
+                    })?;
+                Ok(PacketMsgV2::Ack(domain_msg))
+            }
+            TIMEOUT_V2_TYPE_URL => {
+                let domain_msg =
+                    v2::MsgTimeout::decode_vec_critical(&any_msg.value).map_err(|e| {
+                        RouterError::MalformedMessageBytes {
+                            reason: e.to_string(),
+                        }
+                    })?;
+                Ok(PacketMsgV2::Timeout(domain_msg))
+            }
+            _ => Err(RouterError::UnknownMessageTypeUrl {
+                url: any_msg.type_url,
+            }),
+        }
+    }
+}
+
+/// Messages of the host, which the extended entrypoints dispatch alongside the
+/// IBC ones, e.g. governance messages or the messages of a fee middleware.
+pub trait HostMsg: Sized {
+    /// Decodes the given message, or returns `None` if its type URL is not the
+    /// one of a message of the host.
+    ///
+    /// The messages of the host are decoded before the IBC ones, hence their
+    /// type URLs must not be the ones of the [`MsgEnvelope`] messages.
+    fn decode(any_msg: &Any) -> Option<Result<Self, RouterError>>;
+}
+
+/// Either an IBC message or a message of the host.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ExtendedMsgEnvelope<M> {
+    Ibc(MsgEnvelope),
+    Host(M),
+}
+
+impl<M> From<MsgEnvelope> for ExtendedMsgEnvelope<M> {
+    fn from(msg: MsgEnvelope) -> Self {
+        Self::Ibc(msg)
+    }
+}
+
+impl<M> TryFrom<Any> for ExtendedMsgEnvelope<M>
+where
+    M: HostMsg,
+{
+    type Error = RouterError;
+
+    fn try_from(any_msg: Any) -> Result<Self, Self::Error> {
+        match M::decode(&any_msg) {
+            Some(msg) => msg.map(Self::Host),
+            None => MsgEnvelope::try_from(any_msg).map(Self::Ibc),
+        }
+    }
+}
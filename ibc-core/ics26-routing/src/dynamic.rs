@@ -0,0 +1,148 @@
+//! Defines the `ModuleRouter`, a router whose modules and ports are updated at
+//! runtime.
+
+use ibc_core_host_types::identifiers::{ChannelId, PortId};
+use ibc_core_router_types::error::RouterError;
+use ibc_core_router_types::module::ModuleId;
+use ibc_core_router_types::port::{ChannelCapability, PortBindings, PortCapability};
+use ibc_primitives::prelude::*;
+
+use crate::module::Module;
+use crate::router::{ChannelRouter, DynamicRouter, PortRouter, Router, RouterAuthority};
+
+/// [`DynamicRouter`] keeping its modules in memory, whose updates are
+/// authorized by the given [`RouterAuthority`].
+///
+/// Hosts wire the applications available at genesis when building the
+/// router, and register the other ones at runtime, through the same API.
+#[derive(Debug)]
+pub struct ModuleRouter<A> {
+    modules: BTreeMap<ModuleId, Box<dyn Module>>,
+    port_bindings: PortBindings,
+    authority: A,
+}
+
+impl<A: RouterAuthority> ModuleRouter<A> {
+    /// Creates an empty router, whose updates are authorized by the given
+    /// authority.
+    pub fn new(authority: A) -> Self {
+        Self {
+            modules: BTreeMap::new(),
+            port_bindings: PortBindings::default(),
+            authority,
+        }
+    }
+
+    pub fn authority(&self) -> &A {
+        &self.authority
+    }
+
+    /// Returns a mutable reference to the authority, e.g. for the host to
+    /// set the signer of the transaction being processed.
+    pub fn authority_mut(&mut self) -> &mut A {
+        &mut self.authority
+    }
+
+    /// Returns the ports bound to the module registered against the given
+    /// `ModuleId`.
+    pub fn ports_of<'a>(&'a self, module_id: &'a ModuleId) -> impl Iterator<Item = &'a PortId> {
+        self.port_bindings.ports_of(module_id)
+    }
+}
+
+impl<A: RouterAuthority> Router for ModuleRouter<A> {
+    fn get_route(&self, module_id: &ModuleId) -> Option<&dyn Module> {
+        self.modules.get(module_id).map(AsRef::as_ref)
+    }
+
+    fn get_route_mut(&mut self, module_id: &ModuleId) -> Option<&mut dyn Module> {
+        match self.modules.get_mut(module_id) {
+            Some(module) => Some(module.as_mut()),
+            None => None,
+        }
+    }
+
+    fn lookup_module(&self, port_id: &PortId) -> Option<ModuleId> {
+        self.port_bindings.lookup_module(port_id).cloned()
+    }
+}
+
+impl<A: RouterAuthority> PortRouter for ModuleRouter<A> {
+    fn bind_port(
+        &mut self,
+        port_id: PortId,
+        module_id: ModuleId,
+    ) -> Result<PortCapability, RouterError> {
+        if !self.modules.contains_key(&module_id) {
+            return Err(RouterError::ModuleNotFound);
+        }
+
+        self.authority.authorize_port(&port_id, &module_id)?;
+
+        self.port_bindings.bind(port_id, module_id)
+    }
+
+    fn release_port(&mut self, capability: &PortCapability) -> Result<(), RouterError> {
+        self.port_bindings.release(capability)
+    }
+
+    fn authenticate_port(&self, capability: &PortCapability) -> bool {
+        self.port_bindings.authenticate(capability)
+    }
+}
+
+impl<A: RouterAuthority> ChannelRouter for ModuleRouter<A> {
+    fn claim_channel(
+        &mut self,
+        port_capability: &PortCapability,
+        channel_id: ChannelId,
+    ) -> Result<ChannelCapability, RouterError> {
+        self.port_bindings
+            .claim_channel(port_capability, channel_id)
+    }
+
+    fn release_channel(&mut self, capability: &ChannelCapability) -> Result<(), RouterError> {
+        self.port_bindings.release_channel(capability)
+    }
+
+    fn authenticate_channel(&self, capability: &ChannelCapability) -> bool {
+        self.port_bindings.authenticate_channel(capability)
+    }
+}
+
+impl<A: RouterAuthority> DynamicRouter for ModuleRouter<A> {
+    fn add_module(
+        &mut self,
+        module_id: ModuleId,
+        module: Box<dyn Module>,
+    ) -> Result<(), RouterError> {
+        if self.modules.contains_key(&module_id) {
+            return Err(RouterError::ModuleAlreadyRegistered { module_id });
+        }
+
+        self.authority.authorize_module(&module_id)?;
+
+        self.modules.insert(module_id, module);
+
+        Ok(())
+    }
+
+    fn remove_module(&mut self, module_id: &ModuleId) -> Result<Box<dyn Module>, RouterError> {
+        if !self.modules.contains_key(module_id) {
+            return Err(RouterError::ModuleNotFound);
+        }
+
+        if let Some(port_id) = self.port_bindings.ports_of(module_id).next() {
+            return Err(RouterError::ModuleStillBound {
+                module_id: module_id.clone(),
+                port_id: port_id.clone(),
+            });
+        }
+
+        self.authority.authorize_module(module_id)?;
+
+        self.modules
+            .remove(module_id)
+            .ok_or(RouterError::ModuleNotFound)
+    }
+}
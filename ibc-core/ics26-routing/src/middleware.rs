@@ -0,0 +1,779 @@
+//! Defines the middleware abstraction, which composes IBC modules into stacks.
+//!
+//! A middleware wraps the callbacks of the next module of the stack, which is
+//! either another [`ModuleStack`] or the application at its bottom, e.g. to
+//! incentivize relayers, rate limit the packets or forward them. Stacks are
+//! built from the application up:
+//!
+//! ```ignore
+//! let stack = ModuleStack::new(RateLimitMiddleware::new(..), transfer_module);
+//! let stack = ModuleStack::new(FeeMiddleware::new(..), stack);
+//! ```
+//!
+//! The callbacks of the channel handshakes and of the received, acknowledged
+//! and timed out packets are called on the outermost middleware first, which
+//! decides whether and how to call the next module. The hooks on sending
+//! packets are called the other way around, from the application up to the
+//! outermost middleware, since packets are sent down to the core from the
+//! application.
+//!
+//! All callbacks pass through to the next module by default, so that a
+//! middleware only implements the ones it is interested in. Middlewares which
+//! wrap the channel version of the application, such as the fee middleware,
+//! override the handshake callbacks to negotiate their own version and pass
+//! the application version through.
+use core::fmt::Debug;
+
+use ibc_core_channel_types::acknowledgement::Acknowledgement;
+use ibc_core_channel_types::channel::{Counterparty, Order};
+use ibc_core_channel_types::error::{ChannelError, PacketError};
+use ibc_core_channel_types::packet::Packet;
+use ibc_core_channel_types::Version;
+use ibc_core_host_types::identifiers::{ChannelId, ConnectionId, PortId};
+use ibc_core_router_types::module::ModuleExtras;
+use ibc_primitives::Signer;
+
+use crate::module::Module;
+
+/// An IBC middleware, which wraps the callbacks of the `next` module of a
+/// [`ModuleStack`].
+pub trait Middleware: Debug {
+    #[allow(clippy::too_many_arguments)]
+    fn on_chan_open_init_validate(
+        &self,
+        next: &dyn Module,
+        order: Order,
+        connection_hops: &[ConnectionId],
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        counterparty: &Counterparty,
+        version: &Version,
+    ) -> Result<Version, ChannelError> {
+        next.on_chan_open_init_validate(
+            order,
+            connection_hops,
+            port_id,
+            channel_id,
+            counterparty,
+            version,
+        )
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn on_chan_open_init_execute(
+        &mut self,
+        next: &mut dyn Module,
+        order: Order,
+        connection_hops: &[ConnectionId],
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        counterparty: &Counterparty,
+        version: &Version,
+    ) -> Result<(ModuleExtras, Version), ChannelError> {
+        next.on_chan_open_init_execute(
+            order,
+            connection_hops,
+            port_id,
+            channel_id,
+            counterparty,
+            version,
+        )
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn on_chan_open_try_validate(
+        &self,
+        next: &dyn Module,
+        order: Order,
+        connection_hops: &[ConnectionId],
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        counterparty: &Counterparty,
+        counterparty_version: &Version,
+    ) -> Result<Version, ChannelError> {
+        next.on_chan_open_try_validate(
+            order,
+            connection_hops,
+            port_id,
+            channel_id,
+            counterparty,
+            counterparty_version,
+        )
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn on_chan_open_try_execute(
+        &mut self,
+        next: &mut dyn Module,
+        order: Order,
+        connection_hops: &[ConnectionId],
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        counterparty: &Counterparty,
+        counterparty_version: &Version,
+    ) -> Result<(ModuleExtras, Version), ChannelError> {
+        next.on_chan_open_try_execute(
+            order,
+            connection_hops,
+            port_id,
+            channel_id,
+            counterparty,
+            counterparty_version,
+        )
+    }
+
+    fn on_chan_open_ack_validate(
+        &self,
+        next: &dyn Module,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        counterparty_version: &Version,
+    ) -> Result<(), ChannelError> {
+        next.on_chan_open_ack_validate(port_id, channel_id, counterparty_version)
+    }
+
+    fn on_chan_open_ack_execute(
+        &mut self,
+        next: &mut dyn Module,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        counterparty_version: &Version,
+    ) -> Result<ModuleExtras, ChannelError> {
+        next.on_chan_open_ack_execute(port_id, channel_id, counterparty_version)
+    }
+
+    fn on_chan_open_confirm_validate(
+        &self,
+        next: &dyn Module,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<(), ChannelError> {
+        next.on_chan_open_confirm_validate(port_id, channel_id)
+    }
+
+    fn on_chan_open_confirm_execute(
+        &mut self,
+        next: &mut dyn Module,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<ModuleExtras, ChannelError> {
+        next.on_chan_open_confirm_execute(port_id, channel_id)
+    }
+
+    fn on_chan_close_init_validate(
+        &self,
+        next: &dyn Module,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<(), ChannelError> {
+        next.on_chan_close_init_validate(port_id, channel_id)
+    }
+
+    fn on_chan_close_init_execute(
+        &mut self,
+        next: &mut dyn Module,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<ModuleExtras, ChannelError> {
+        next.on_chan_close_init_execute(port_id, channel_id)
+    }
+
+    fn on_chan_close_confirm_validate(
+        &self,
+        next: &dyn Module,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<(), ChannelError> {
+        next.on_chan_close_confirm_validate(port_id, channel_id)
+    }
+
+    fn on_chan_close_confirm_execute(
+        &mut self,
+        next: &mut dyn Module,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<ModuleExtras, ChannelError> {
+        next.on_chan_close_confirm_execute(port_id, channel_id)
+    }
+
+    fn on_chan_upgrade_init_validate(
+        &self,
+        next: &dyn Module,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        proposed_order: Order,
+        proposed_connection_hops: &[ConnectionId],
+        proposed_version: &Version,
+    ) -> Result<Version, ChannelError> {
+        next.on_chan_upgrade_init_validate(
+            port_id,
+            channel_id,
+            proposed_order,
+            proposed_connection_hops,
+            proposed_version,
+        )
+    }
+
+    fn on_chan_upgrade_init_execute(
+        &mut self,
+        next: &mut dyn Module,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        proposed_order: Order,
+        proposed_connection_hops: &[ConnectionId],
+        proposed_version: &Version,
+    ) -> Result<(ModuleExtras, Version), ChannelError> {
+        next.on_chan_upgrade_init_execute(
+            port_id,
+            channel_id,
+            proposed_order,
+            proposed_connection_hops,
+            proposed_version,
+        )
+    }
+
+    fn on_chan_upgrade_try_validate(
+        &self,
+        next: &dyn Module,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        proposed_order: Order,
+        proposed_connection_hops: &[ConnectionId],
+        counterparty_version: &Version,
+    ) -> Result<Version, ChannelError> {
+        next.on_chan_upgrade_try_validate(
+            port_id,
+            channel_id,
+            proposed_order,
+            proposed_connection_hops,
+            counterparty_version,
+        )
+    }
+
+    fn on_chan_upgrade_try_execute(
+        &mut self,
+        next: &mut dyn Module,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        proposed_order: Order,
+        proposed_connection_hops: &[ConnectionId],
+        counterparty_version: &Version,
+    ) -> Result<(ModuleExtras, Version), ChannelError> {
+        next.on_chan_upgrade_try_execute(
+            port_id,
+            channel_id,
+            proposed_order,
+            proposed_connection_hops,
+            counterparty_version,
+        )
+    }
+
+    fn on_chan_upgrade_ack_validate(
+        &self,
+        next: &dyn Module,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        counterparty_version: &Version,
+    ) -> Result<(), ChannelError> {
+        next.on_chan_upgrade_ack_validate(port_id, channel_id, counterparty_version)
+    }
+
+    fn on_chan_upgrade_ack_execute(
+        &mut self,
+        next: &mut dyn Module,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        counterparty_version: &Version,
+    ) -> Result<ModuleExtras, ChannelError> {
+        next.on_chan_upgrade_ack_execute(port_id, channel_id, counterparty_version)
+    }
+
+    fn on_chan_upgrade_open_validate(
+        &self,
+        next: &dyn Module,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        order: Order,
+        connection_hops: &[ConnectionId],
+        version: &Version,
+    ) -> Result<(), ChannelError> {
+        next.on_chan_upgrade_open_validate(port_id, channel_id, order, connection_hops, version)
+    }
+
+    fn on_chan_upgrade_open_execute(
+        &mut self,
+        next: &mut dyn Module,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        order: Order,
+        connection_hops: &[ConnectionId],
+        version: &Version,
+    ) -> Result<ModuleExtras, ChannelError> {
+        next.on_chan_upgrade_open_execute(port_id, channel_id, order, connection_hops, version)
+    }
+
+    /// Called after the `next` module sent the packet, before it is passed on
+    /// to the middleware above, or to the core if this is the outermost one.
+    fn on_send_packet_validate(&self, _packet: &Packet) -> Result<(), PacketError> {
+        Ok(())
+    }
+
+    fn on_send_packet_execute(&mut self, _packet: &Packet) -> Result<ModuleExtras, PacketError> {
+        Ok(ModuleExtras::empty())
+    }
+
+    fn on_recv_packet_execute(
+        &mut self,
+        next: &mut dyn Module,
+        packet: &Packet,
+        relayer: &Signer,
+    ) -> (ModuleExtras, Option<Acknowledgement>) {
+        next.on_recv_packet_execute(packet, relayer)
+    }
+
+    fn on_acknowledgement_packet_validate(
+        &self,
+        next: &dyn Module,
+        packet: &Packet,
+        acknowledgement: &Acknowledgement,
+        relayer: &Signer,
+    ) -> Result<(), PacketError> {
+        next.on_acknowledgement_packet_validate(packet, acknowledgement, relayer)
+    }
+
+    fn on_acknowledgement_packet_execute(
+        &mut self,
+        next: &mut dyn Module,
+        packet: &Packet,
+        acknowledgement: &Acknowledgement,
+        relayer: &Signer,
+    ) -> (ModuleExtras, Result<(), PacketError>) {
+        next.on_acknowledgement_packet_execute(packet, acknowledgement, relayer)
+    }
+
+    fn on_timeout_packet_validate(
+        &self,
+        next: &dyn Module,
+        packet: &Packet,
+        relayer: &Signer,
+    ) -> Result<(), PacketError> {
+        next.on_timeout_packet_validate(packet, relayer)
+    }
+
+    fn on_timeout_packet_execute(
+        &mut self,
+        next: &mut dyn Module,
+        packet: &Packet,
+        relayer: &Signer,
+    ) -> (ModuleExtras, Result<(), PacketError>) {
+        next.on_timeout_packet_execute(packet, relayer)
+    }
+}
+
+/// A middleware stacked on top of the `next` module, which is itself an IBC
+/// module.
+#[derive(Debug)]
+pub struct ModuleStack<W, M> {
+    middleware: W,
+    next: M,
+}
+
+impl<W, M> ModuleStack<W, M> {
+    pub fn new(middleware: W, next: M) -> Self {
+        Self { middleware, next }
+    }
+
+    pub fn middleware(&self) -> &W {
+        &self.middleware
+    }
+
+    pub fn middleware_mut(&mut self) -> &mut W {
+        &mut self.middleware
+    }
+
+    pub fn next(&self) -> &M {
+        &self.next
+    }
+
+    pub fn next_mut(&mut self) -> &mut M {
+        &mut self.next
+    }
+}
+
+/// Merges the extras of the `next` module into the ones of the middleware.
+pub fn merge_extras(mut extras: ModuleExtras, next_extras: ModuleExtras) -> ModuleExtras {
+    extras.events.extend(next_extras.events);
+    extras.log.extend(next_extras.log);
+    extras
+}
+
+impl<W, M> Module for ModuleStack<W, M>
+where
+    W: Middleware,
+    M: Module,
+{
+    fn on_chan_open_init_validate(
+        &self,
+        order: Order,
+        connection_hops: &[ConnectionId],
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        counterparty: &Counterparty,
+        version: &Version,
+    ) -> Result<Version, ChannelError> {
+        self.middleware.on_chan_open_init_validate(
+            &self.next,
+            order,
+            connection_hops,
+            port_id,
+            channel_id,
+            counterparty,
+            version,
+        )
+    }
+
+    fn on_chan_open_init_execute(
+        &mut self,
+        order: Order,
+        connection_hops: &[ConnectionId],
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        counterparty: &Counterparty,
+        version: &Version,
+    ) -> Result<(ModuleExtras, Version), ChannelError> {
+        self.middleware.on_chan_open_init_execute(
+            &mut self.next,
+            order,
+            connection_hops,
+            port_id,
+            channel_id,
+            counterparty,
+            version,
+        )
+    }
+
+    fn on_chan_open_try_validate(
+        &self,
+        order: Order,
+        connection_hops: &[ConnectionId],
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        counterparty: &Counterparty,
+        counterparty_version: &Version,
+    ) -> Result<Version, ChannelError> {
+        self.middleware.on_chan_open_try_validate(
+            &self.next,
+            order,
+            connection_hops,
+            port_id,
+            channel_id,
+            counterparty,
+            counterparty_version,
+        )
+    }
+
+    fn on_chan_open_try_execute(
+        &mut self,
+        order: Order,
+        connection_hops: &[ConnectionId],
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        counterparty: &Counterparty,
+        counterparty_version: &Version,
+    ) -> Result<(ModuleExtras, Version), ChannelError> {
+        self.middleware.on_chan_open_try_execute(
+            &mut self.next,
+            order,
+            connection_hops,
+            port_id,
+            channel_id,
+            counterparty,
+            counterparty_version,
+        )
+    }
+
+    fn on_chan_open_ack_validate(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        counterparty_version: &Version,
+    ) -> Result<(), ChannelError> {
+        self.middleware.on_chan_open_ack_validate(
+            &self.next,
+            port_id,
+            channel_id,
+            counterparty_version,
+        )
+    }
+
+    fn on_chan_open_ack_execute(
+        &mut self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        counterparty_version: &Version,
+    ) -> Result<ModuleExtras, ChannelError> {
+        self.middleware.on_chan_open_ack_execute(
+            &mut self.next,
+            port_id,
+            channel_id,
+            counterparty_version,
+        )
+    }
+
+    fn on_chan_open_confirm_validate(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<(), ChannelError> {
+        self.middleware
+            .on_chan_open_confirm_validate(&self.next, port_id, channel_id)
+    }
+
+    fn on_chan_open_confirm_execute(
+        &mut self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<ModuleExtras, ChannelError> {
+        self.middleware
+            .on_chan_open_confirm_execute(&mut self.next, port_id, channel_id)
+    }
+
+    fn on_chan_close_init_validate(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<(), ChannelError> {
+        self.middleware
+            .on_chan_close_init_validate(&self.next, port_id, channel_id)
+    }
+
+    fn on_chan_close_init_execute(
+        &mut self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<ModuleExtras, ChannelError> {
+        self.middleware
+            .on_chan_close_init_execute(&mut self.next, port_id, channel_id)
+    }
+
+    fn on_chan_close_confirm_validate(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<(), ChannelError> {
+        self.middleware
+            .on_chan_close_confirm_validate(&self.next, port_id, channel_id)
+    }
+
+    fn on_chan_close_confirm_execute(
+        &mut self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<ModuleExtras, ChannelError> {
+        self.middleware
+            .on_chan_close_confirm_execute(&mut self.next, port_id, channel_id)
+    }
+
+    fn on_chan_upgrade_init_validate(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        proposed_order: Order,
+        proposed_connection_hops: &[ConnectionId],
+        proposed_version: &Version,
+    ) -> Result<Version, ChannelError> {
+        self.middleware.on_chan_upgrade_init_validate(
+            &self.next,
+            port_id,
+            channel_id,
+            proposed_order,
+            proposed_connection_hops,
+            proposed_version,
+        )
+    }
+
+    fn on_chan_upgrade_init_execute(
+        &mut self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        proposed_order: Order,
+        proposed_connection_hops: &[ConnectionId],
+        proposed_version: &Version,
+    ) -> Result<(ModuleExtras, Version), ChannelError> {
+        self.middleware.on_chan_upgrade_init_execute(
+            &mut self.next,
+            port_id,
+            channel_id,
+            proposed_order,
+            proposed_connection_hops,
+            proposed_version,
+        )
+    }
+
+    fn on_chan_upgrade_try_validate(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        proposed_order: Order,
+        proposed_connection_hops: &[ConnectionId],
+        counterparty_version: &Version,
+    ) -> Result<Version, ChannelError> {
+        self.middleware.on_chan_upgrade_try_validate(
+            &self.next,
+            port_id,
+            channel_id,
+            proposed_order,
+            proposed_connection_hops,
+            counterparty_version,
+        )
+    }
+
+    fn on_chan_upgrade_try_execute(
+        &mut self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        proposed_order: Order,
+        proposed_connection_hops: &[ConnectionId],
+        counterparty_version: &Version,
+    ) -> Result<(ModuleExtras, Version), ChannelError> {
+        self.middleware.on_chan_upgrade_try_execute(
+            &mut self.next,
+            port_id,
+            channel_id,
+            proposed_order,
+            proposed_connection_hops,
+            counterparty_version,
+        )
+    }
+
+    fn on_chan_upgrade_ack_validate(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        counterparty_version: &Version,
+    ) -> Result<(), ChannelError> {
+        self.middleware.on_chan_upgrade_ack_validate(
+            &self.next,
+            port_id,
+            channel_id,
+            counterparty_version,
+        )
+    }
+
+    fn on_chan_upgrade_ack_execute(
+        &mut self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        counterparty_version: &Version,
+    ) -> Result<ModuleExtras, ChannelError> {
+        self.middleware.on_chan_upgrade_ack_execute(
+            &mut self.next,
+            port_id,
+            channel_id,
+            counterparty_version,
+        )
+    }
+
+    fn on_chan_upgrade_open_validate(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        order: Order,
+        connection_hops: &[ConnectionId],
+        version: &Version,
+    ) -> Result<(), ChannelError> {
+        self.middleware.on_chan_upgrade_open_validate(
+            &self.next,
+            port_id,
+            channel_id,
+            order,
+            connection_hops,
+            version,
+        )
+    }
+
+    fn on_chan_upgrade_open_execute(
+        &mut self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        order: Order,
+        connection_hops: &[ConnectionId],
+        version: &Version,
+    ) -> Result<ModuleExtras, ChannelError> {
+        self.middleware.on_chan_upgrade_open_execute(
+            &mut self.next,
+            port_id,
+            channel_id,
+            order,
+            connection_hops,
+            version,
+        )
+    }
+
+    fn on_send_packet_validate(&self, packet: &Packet) -> Result<(), PacketError> {
+        self.next.on_send_packet_validate(packet)?;
+        self.middleware.on_send_packet_validate(packet)
+    }
+
+    fn on_send_packet_execute(&mut self, packet: &Packet) -> Result<ModuleExtras, PacketError> {
+        let next_extras = self.next.on_send_packet_execute(packet)?;
+        let extras = self.middleware.on_send_packet_execute(packet)?;
+
+        Ok(merge_extras(next_extras, extras))
+    }
+
+    fn on_recv_packet_execute(
+        &mut self,
+        packet: &Packet,
+        relayer: &Signer,
+    ) -> (ModuleExtras, Option<Acknowledgement>) {
+        self.middleware
+            .on_recv_packet_execute(&mut self.next, packet, relayer)
+    }
+
+    fn on_acknowledgement_packet_validate(
+        &self,
+        packet: &Packet,
+        acknowledgement: &Acknowledgement,
+        relayer: &Signer,
+    ) -> Result<(), PacketError> {
+        self.middleware.on_acknowledgement_packet_validate(
+            &self.next,
+            packet,
+            acknowledgement,
+            relayer,
+        )
+    }
+
+    fn on_acknowledgement_packet_execute(
+        &mut self,
+        packet: &Packet,
+        acknowledgement: &Acknowledgement,
+        relayer: &Signer,
+    ) -> (ModuleExtras, Result<(), PacketError>) {
+        self.middleware.on_acknowledgement_packet_execute(
+            &mut self.next,
+            packet,
+            acknowledgement,
+            relayer,
+        )
+    }
+
+    fn on_timeout_packet_validate(
+        &self,
+        packet: &Packet,
+        relayer: &Signer,
+    ) -> Result<(), PacketError> {
+        self.middleware
+            .on_timeout_packet_validate(&self.next, packet, relayer)
+    }
+
+    fn on_timeout_packet_execute(
+        &mut self,
+        packet: &Packet,
+        relayer: &Signer,
+    ) -> (ModuleExtras, Result<(), PacketError>) {
+        self.middleware
+            .on_timeout_packet_execute(&mut self.next, packet, relayer)
+    }
+}
@@ -118,16 +118,134 @@ pub trait Module: Debug {
         Ok(ModuleExtras::empty())
     }
 
+    /// Validates the upgrade of a channel end to the proposed parameters,
+    /// returning the version the application agrees to upgrade to.
+    ///
+    /// Applications which don't support channel upgrades keep the default,
+    /// which rejects them.
+    fn on_chan_upgrade_init_validate(
+        &self,
+        port_id: &PortId,
+        _channel_id: &ChannelId,
+        _proposed_order: Order,
+        _proposed_connection_hops: &[ConnectionId],
+        _proposed_version: &Version,
+    ) -> Result<Version, ChannelError> {
+        Err(ChannelError::UpgradeNotSupported {
+            port_id: port_id.clone(),
+        })
+    }
+
+    fn on_chan_upgrade_init_execute(
+        &mut self,
+        port_id: &PortId,
+        _channel_id: &ChannelId,
+        _proposed_order: Order,
+        _proposed_connection_hops: &[ConnectionId],
+        _proposed_version: &Version,
+    ) -> Result<(ModuleExtras, Version), ChannelError> {
+        Err(ChannelError::UpgradeNotSupported {
+            port_id: port_id.clone(),
+        })
+    }
+
+    /// Validates the upgrade proposed by the counterparty, returning the
+    /// version the application agrees to upgrade to.
+    fn on_chan_upgrade_try_validate(
+        &self,
+        port_id: &PortId,
+        _channel_id: &ChannelId,
+        _proposed_order: Order,
+        _proposed_connection_hops: &[ConnectionId],
+        _counterparty_version: &Version,
+    ) -> Result<Version, ChannelError> {
+        Err(ChannelError::UpgradeNotSupported {
+            port_id: port_id.clone(),
+        })
+    }
+
+    fn on_chan_upgrade_try_execute(
+        &mut self,
+        port_id: &PortId,
+        _channel_id: &ChannelId,
+        _proposed_order: Order,
+        _proposed_connection_hops: &[ConnectionId],
+        _counterparty_version: &Version,
+    ) -> Result<(ModuleExtras, Version), ChannelError> {
+        Err(ChannelError::UpgradeNotSupported {
+            port_id: port_id.clone(),
+        })
+    }
+
+    fn on_chan_upgrade_ack_validate(
+        &self,
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+        _counterparty_version: &Version,
+    ) -> Result<(), ChannelError> {
+        Ok(())
+    }
+
+    fn on_chan_upgrade_ack_execute(
+        &mut self,
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+        _counterparty_version: &Version,
+    ) -> Result<ModuleExtras, ChannelError> {
+        Ok(ModuleExtras::empty())
+    }
+
+    /// Called once the channel end is open again with the upgraded
+    /// parameters, for the application to migrate its state if needed.
+    fn on_chan_upgrade_open_validate(
+        &self,
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+        _order: Order,
+        _connection_hops: &[ConnectionId],
+        _version: &Version,
+    ) -> Result<(), ChannelError> {
+        Ok(())
+    }
+
+    fn on_chan_upgrade_open_execute(
+        &mut self,
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+        _order: Order,
+        _connection_hops: &[ConnectionId],
+        _version: &Version,
+    ) -> Result<ModuleExtras, ChannelError> {
+        Ok(ModuleExtras::empty())
+    }
+
+    /// Validates the packet the application is about to send, before it is
+    /// committed by the core.
+    ///
+    /// Applications call it when sending packets through their module stack,
+    /// so that the middlewares of the stack can hook into it, see
+    /// [`crate::middleware`].
+    fn on_send_packet_validate(&self, _packet: &Packet) -> Result<(), PacketError> {
+        Ok(())
+    }
+
+    fn on_send_packet_execute(&mut self, _packet: &Packet) -> Result<ModuleExtras, PacketError> {
+        Ok(ModuleExtras::empty())
+    }
+
     // Note: no `on_recv_packet_validate()`
     // the `onRecvPacket` callback always succeeds
     // if any error occurs, than an "error acknowledgement"
     // must be returned
 
+    /// Returns the acknowledgement of the packet, or `None` if the module
+    /// acknowledges it asynchronously, by calling the `write_acknowledgement`
+    /// handler of `ibc-core-channel` once the acknowledgement is known.
     fn on_recv_packet_execute(
         &mut self,
         packet: &Packet,
         relayer: &Signer,
-    ) -> (ModuleExtras, Acknowledgement);
+    ) -> (ModuleExtras, Option<Acknowledgement>);
 
     fn on_acknowledgement_packet_validate(
         &self,
@@ -1,7 +1,10 @@
 //! Defines the `Router`, which binds modules to ports
 
-use ibc_core_host_types::identifiers::PortId;
+use ibc_core_host_types::identifiers::{ChannelId, PortId};
+use ibc_core_router_types::error::RouterError;
 use ibc_core_router_types::module::ModuleId;
+use ibc_core_router_types::port::{ChannelCapability, PortCapability};
+use ibc_primitives::prelude::*;
 
 use crate::module::Module;
 
@@ -16,3 +19,153 @@ pub trait Router {
     /// Return the module_id associated with a given port_id
     fn lookup_module(&self, port_id: &PortId) -> Option<ModuleId>;
 }
+
+/// Router whose modules group their writes into transactions, which are
+/// either committed or rolled back as a whole, in lockstep with the ones of a
+/// `TransactionalExecutionContext`.
+///
+/// The batch entrypoints require it for their writes to be atomic: rolling
+/// back the IBC store alone would undo e.g. the receipt of a packet but keep
+/// the vouchers minted on receiving it, which would be minted again once the
+/// packet is relayed again. Hosts whose modules write to the store covered by
+/// the transactions of their context implement it with no-ops.
+pub trait TransactionalRouter: Router {
+    /// Begins a transaction of the modules, nested in the current one if any.
+    fn begin_transaction(&mut self);
+
+    /// Commits the current transaction of the modules, whose writes are then
+    /// part of the enclosing transaction if any, or written to the host
+    /// otherwise.
+    fn commit_transaction(&mut self);
+
+    /// Rolls back the current transaction of the modules, discarding their
+    /// writes.
+    fn rollback_transaction(&mut self);
+}
+
+/// Router whose ports are bound to, and released by, its modules at runtime,
+/// rather than only when the router is built.
+///
+/// Binding a port issues a [`PortCapability`] to the module, with which the
+/// module authenticates as the owner of the port. Hosts keep track of the
+/// bound ports with [`PortBindings`](ibc_core_router_types::port::PortBindings).
+pub trait PortRouter: Router {
+    /// Binds the given port to the module registered against the given
+    /// `ModuleId`, and returns the capability of the module over the port.
+    fn bind_port(
+        &mut self,
+        port_id: PortId,
+        module_id: ModuleId,
+    ) -> Result<PortCapability, RouterError>;
+
+    /// Releases the port of the given capability, after which the port can be
+    /// bound by any module.
+    fn release_port(&mut self, capability: &PortCapability) -> Result<(), RouterError>;
+
+    /// Returns `true` if the given capability authenticates its module as the
+    /// current owner of its port.
+    fn authenticate_port(&self, capability: &PortCapability) -> bool;
+}
+
+/// Router issuing capabilities over the channel ends of the ports bound with
+/// [`PortRouter::bind_port`], with which multi-tenant hosts authenticate the
+/// modules using a channel end, e.g. before letting a contract send a packet
+/// over it.
+///
+/// Modules claim the channel ends opened over their ports, typically once
+/// the channel open init or try handshake step was executed, and release
+/// them once the channel is closed. Releasing a port releases the channel
+/// ends claimed over it.
+pub trait ChannelRouter: PortRouter {
+    /// Claims the given channel end over the port of the given capability,
+    /// and returns the capability of the module over the channel end.
+    fn claim_channel(
+        &mut self,
+        port_capability: &PortCapability,
+        channel_id: ChannelId,
+    ) -> Result<ChannelCapability, RouterError>;
+
+    /// Releases the channel end of the given capability, after which the
+    /// channel end can be claimed again by the owner of the port.
+    fn release_channel(&mut self, capability: &ChannelCapability) -> Result<(), RouterError>;
+
+    /// Returns `true` if the given capability authenticates its module as the
+    /// current owner of its channel end.
+    fn authenticate_channel(&self, capability: &ChannelCapability) -> bool;
+
+    /// Checks that the given capability authenticates its module as the
+    /// current owner of the given channel end.
+    fn verify_channel_capability(
+        &self,
+        capability: &ChannelCapability,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<(), RouterError> {
+        if capability.port_id() != port_id
+            || capability.channel_id() != channel_id
+            || !self.authenticate_channel(capability)
+        {
+            return Err(RouterError::InvalidChannelCapability {
+                port_id: port_id.clone(),
+                channel_id: channel_id.clone(),
+            });
+        }
+
+        Ok(())
+    }
+}
+
+/// Router whose modules are registered, and removed, at runtime, e.g. by the
+/// governance of the host or when a contract implementing an IBC application
+/// is deployed after genesis.
+///
+/// The updates of the router, along with the ports bound by its modules with
+/// [`PortRouter::bind_port`], are authorized by a [`RouterAuthority`].
+pub trait DynamicRouter: PortRouter {
+    /// Registers the given module against the given `ModuleId`, failing if a
+    /// module is already registered against it.
+    fn add_module(
+        &mut self,
+        module_id: ModuleId,
+        module: Box<dyn Module>,
+    ) -> Result<(), RouterError>;
+
+    /// Removes the module registered against the given `ModuleId`, which must
+    /// have released all of its ports beforehand.
+    fn remove_module(&mut self, module_id: &ModuleId) -> Result<Box<dyn Module>, RouterError>;
+
+    /// Returns `true` if a module is registered against the given `ModuleId`.
+    fn has_route(&self, module_id: &ModuleId) -> bool {
+        self.get_route(module_id).is_some()
+    }
+}
+
+/// Authorization hook of a [`DynamicRouter`], consulted before each update of
+/// the router.
+///
+/// Hosts typically check the signer of the current transaction against their
+/// governance account, or the code of a deployed contract against an
+/// allowlist.
+pub trait RouterAuthority {
+    /// Authorizes registering or removing the module of the given `ModuleId`.
+    fn authorize_module(&self, module_id: &ModuleId) -> Result<(), RouterError>;
+
+    /// Authorizes binding the given port to the module of the given
+    /// `ModuleId`.
+    fn authorize_port(&self, port_id: &PortId, module_id: &ModuleId) -> Result<(), RouterError>;
+}
+
+/// The [`RouterAuthority`] of the hosts which let any module be registered
+/// and bind any port.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct PermissionlessAuthority;
+
+impl RouterAuthority for PermissionlessAuthority {
+    fn authorize_module(&self, _module_id: &ModuleId) -> Result<(), RouterError> {
+        Ok(())
+    }
+
+    fn authorize_port(&self, _port_id: &PortId, _module_id: &ModuleId) -> Result<(), RouterError> {
+        Ok(())
+    }
+}
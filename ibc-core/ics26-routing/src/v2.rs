@@ -0,0 +1,106 @@
+//! Defines the application callbacks of IBC v2 packets, and the router which
+//! dispatches their payloads to the applications.
+//!
+//! IBC v2 packets are not sent over channels, so there is no handshake to
+//! hook into: the applications are only called with the payloads routed to
+//! their ports, along with the clients the packet is sent over.
+use core::fmt::Debug;
+
+use ibc_core_channel_types::error::PacketError;
+use ibc_core_channel_types::v2::acknowledgement::RecvPacketResult;
+use ibc_core_channel_types::v2::packet::Payload;
+use ibc_core_host_types::identifiers::{ClientId, PortId, Sequence};
+use ibc_core_router_types::module::ModuleExtras;
+use ibc_primitives::Signer;
+
+/// The trait that defines an IBC v2 application.
+///
+/// Each callback is called once per payload of the packet bound to the port
+/// of the application, in the order of the payloads.
+pub trait ModuleV2: Debug {
+    /// Validates the payload the signer sends, before the packet is committed.
+    fn on_send_packet_validate(
+        &self,
+        client_id_on_a: &ClientId,
+        client_id_on_b: &ClientId,
+        sequence: Sequence,
+        payload: &Payload,
+        signer: &Signer,
+    ) -> Result<(), PacketError>;
+
+    fn on_send_packet_execute(
+        &mut self,
+        client_id_on_a: &ClientId,
+        client_id_on_b: &ClientId,
+        sequence: Sequence,
+        payload: &Payload,
+        signer: &Signer,
+    ) -> Result<ModuleExtras, PacketError>;
+
+    // Note: no `on_recv_packet_validate()`, since the reception of a payload
+    // always completes: failures are reported to the sender with an error
+    // acknowledgement instead.
+
+    /// Returns the acknowledgement of the payload. If any of the payloads of
+    /// the packet fails to be received, the packet is acknowledged with the
+    /// [`ERROR_ACKNOWLEDGEMENT`](ibc_core_channel_types::v2::acknowledgement::ERROR_ACKNOWLEDGEMENT),
+    /// so applications must not alter their state when they fail.
+    fn on_recv_packet_execute(
+        &mut self,
+        client_id_on_a: &ClientId,
+        client_id_on_b: &ClientId,
+        sequence: Sequence,
+        payload: &Payload,
+        relayer: &Signer,
+    ) -> (ModuleExtras, RecvPacketResult);
+
+    /// Validates the acknowledgement of the payload, which is the error
+    /// acknowledgement if the packet failed to be received.
+    fn on_acknowledgement_packet_validate(
+        &self,
+        client_id_on_a: &ClientId,
+        client_id_on_b: &ClientId,
+        sequence: Sequence,
+        payload: &Payload,
+        acknowledgement: &[u8],
+        relayer: &Signer,
+    ) -> Result<(), PacketError>;
+
+    fn on_acknowledgement_packet_execute(
+        &mut self,
+        client_id_on_a: &ClientId,
+        client_id_on_b: &ClientId,
+        sequence: Sequence,
+        payload: &Payload,
+        acknowledgement: &[u8],
+        relayer: &Signer,
+    ) -> (ModuleExtras, Result<(), PacketError>);
+
+    fn on_timeout_packet_validate(
+        &self,
+        client_id_on_a: &ClientId,
+        client_id_on_b: &ClientId,
+        sequence: Sequence,
+        payload: &Payload,
+        relayer: &Signer,
+    ) -> Result<(), PacketError>;
+
+    fn on_timeout_packet_execute(
+        &mut self,
+        client_id_on_a: &ClientId,
+        client_id_on_b: &ClientId,
+        sequence: Sequence,
+        payload: &Payload,
+        relayer: &Signer,
+    ) -> (ModuleExtras, Result<(), PacketError>);
+}
+
+/// Router of the IBC v2 packets, which binds applications to ports.
+pub trait RouterV2 {
+    /// Returns a reference to the application bound to the specified port
+    fn get_route_v2(&self, port_id: &PortId) -> Option<&dyn ModuleV2>;
+
+    /// Returns a mutable reference to the application bound to the specified
+    /// port
+    fn get_route_v2_mut(&mut self, port_id: &PortId) -> Option<&mut dyn ModuleV2>;
+}
@@ -1,7 +1,9 @@
 use displaydoc::Display;
-use ibc_core_host_types::identifiers::PortId;
+use ibc_core_host_types::identifiers::{ChannelId, PortId};
 use ibc_primitives::prelude::*;
 
+use crate::module::ModuleId;
+
 /// Error type for the router module.
 #[derive(Debug, Display)]
 pub enum RouterError {
@@ -13,7 +15,56 @@ pub enum RouterError {
     UnknownPort { port_id: PortId },
     /// module not found
     ModuleNotFound,
+    /// port `{port_id}` is already bound to module `{module_id}`
+    PortAlreadyBound {
+        port_id: PortId,
+        module_id: ModuleId,
+    },
+    /// invalid capability for port `{port_id}`
+    InvalidPortCapability { port_id: PortId },
+    /// module `{module_id}` is already registered
+    ModuleAlreadyRegistered { module_id: ModuleId },
+    /// module `{module_id}` is still bound to port `{port_id}`
+    ModuleStillBound {
+        module_id: ModuleId,
+        port_id: PortId,
+    },
+    /// unauthorized router update: `{description}`
+    Unauthorized { description: String },
+    /// channel end `{channel_id}` of port `{port_id}` is already claimed by module `{module_id}`
+    ChannelAlreadyClaimed {
+        port_id: PortId,
+        channel_id: ChannelId,
+        module_id: ModuleId,
+    },
+    /// invalid capability for channel end `{channel_id}` of port `{port_id}`
+    InvalidChannelCapability {
+        port_id: PortId,
+        channel_id: ChannelId,
+    },
+}
+
+/// The codespace of the [`RouterError`]s in ABCI responses.
+pub const ROUTER_CODESPACE: &str = "router";
+
+impl RouterError {
+    /// Returns the code identifying the variant of the error within the
+    /// [`ROUTER_CODESPACE`], which is kept across releases.
+    pub fn code(&self) -> u32 {
+        match self {
+            Self::UnknownMessageTypeUrl { .. } => 2,
+            Self::MalformedMessageBytes { .. } => 3,
+            Self::UnknownPort { .. } => 4,
+            Self::ModuleNotFound => 5,
+            Self::PortAlreadyBound { .. } => 6,
+            Self::InvalidPortCapability { .. } => 7,
+            Self::ModuleAlreadyRegistered { .. } => 8,
+            Self::ModuleStillBound { .. } => 9,
+            Self::Unauthorized { .. } => 10,
+            Self::ChannelAlreadyClaimed { .. } => 11,
+            Self::InvalidChannelCapability { .. } => 12,
+        }
+    }
 }
 
-#[cfg(feature = "std")]
-impl std::error::Error for RouterError {}
+impl core::error::Error for RouterError {}
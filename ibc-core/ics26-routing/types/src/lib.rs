@@ -18,3 +18,4 @@ extern crate std;
 pub mod error;
 pub mod event;
 pub mod module;
+pub mod port;
@@ -0,0 +1,322 @@
+//! Defines the bindings of the ports to the modules of the router, and the
+//! capabilities authenticating the modules as the owners of their ports and of
+//! the channel ends over them.
+
+use ibc_core_host_types::identifiers::{ChannelId, PortId};
+use ibc_primitives::prelude::*;
+
+use crate::error::RouterError;
+use crate::module::ModuleId;
+
+/// Capability issued to a module when it binds a port, with which the module
+/// authenticates to the router as the owner of the port.
+///
+/// Capabilities are only issued by [`PortBindings`]. Releasing the port
+/// invalidates its capability, even if the port is bound again later on.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PortCapability {
+    port_id: PortId,
+    module_id: ModuleId,
+    index: u64,
+}
+
+impl PortCapability {
+    pub fn port_id(&self) -> &PortId {
+        &self.port_id
+    }
+
+    pub fn module_id(&self) -> &ModuleId {
+        &self.module_id
+    }
+}
+
+/// Capability issued to a module when it claims a channel end over one of its
+/// ports, with which the module authenticates to the router as the owner of
+/// the channel end.
+///
+/// Multi-tenant hosts, e.g. running contracts which bind their own ports,
+/// check the capability of a module before letting it send packets over or
+/// close a channel end, so that modules cannot use each other's channels.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ChannelCapability {
+    port_id: PortId,
+    channel_id: ChannelId,
+    module_id: ModuleId,
+    index: u64,
+}
+
+impl ChannelCapability {
+    pub fn port_id(&self) -> &PortId {
+        &self.port_id
+    }
+
+    pub fn channel_id(&self) -> &ChannelId {
+        &self.channel_id
+    }
+
+    pub fn module_id(&self) -> &ModuleId {
+        &self.module_id
+    }
+}
+
+/// The ports bound to the modules of a router, which can be bound and
+/// released at runtime, along with the channel ends claimed over them.
+#[derive(Clone, Debug, Default)]
+pub struct PortBindings {
+    bindings: BTreeMap<PortId, (ModuleId, u64)>,
+    channels: BTreeMap<(PortId, ChannelId), (ModuleId, u64)>,
+    next_index: u64,
+}
+
+impl PortBindings {
+    /// Binds the given port to the given module, and returns the capability
+    /// of the module over the port.
+    pub fn bind(
+        &mut self,
+        port_id: PortId,
+        module_id: ModuleId,
+    ) -> Result<PortCapability, RouterError> {
+        if let Some((bound_module_id, _)) = self.bindings.get(&port_id) {
+            return Err(RouterError::PortAlreadyBound {
+                port_id,
+                module_id: bound_module_id.clone(),
+            });
+        }
+
+        let index = self.next_index;
+        self.next_index += 1;
+
+        self.bindings
+            .insert(port_id.clone(), (module_id.clone(), index));
+
+        Ok(PortCapability {
+            port_id,
+            module_id,
+            index,
+        })
+    }
+
+    /// Releases the port of the given capability, which must authenticate the
+    /// module owning the port.
+    ///
+    /// The channel ends claimed over the port are released as well, and can
+    /// be claimed by the next owner of the port.
+    pub fn release(&mut self, capability: &PortCapability) -> Result<(), RouterError> {
+        if !self.authenticate(capability) {
+            return Err(RouterError::InvalidPortCapability {
+                port_id: capability.port_id.clone(),
+            });
+        }
+
+        self.bindings.remove(&capability.port_id);
+        self.channels
+            .retain(|(port_id, _), _| *port_id != capability.port_id);
+
+        Ok(())
+    }
+
+    /// Returns `true` if the given capability authenticates its module as the
+    /// current owner of its port.
+    pub fn authenticate(&self, capability: &PortCapability) -> bool {
+        matches!(
+            self.bindings.get(&capability.port_id),
+            Some((module_id, index))
+                if *module_id == capability.module_id && *index == capability.index
+        )
+    }
+
+    /// Returns the module the given port is bound to, if any.
+    pub fn lookup_module(&self, port_id: &PortId) -> Option<&ModuleId> {
+        self.bindings.get(port_id).map(|(module_id, _)| module_id)
+    }
+
+    /// Returns the ports bound to the given module.
+    pub fn ports_of<'a>(&'a self, module_id: &'a ModuleId) -> impl Iterator<Item = &'a PortId> {
+        self.bindings
+            .iter()
+            .filter(move |(_, (bound_module_id, _))| bound_module_id == module_id)
+            .map(|(port_id, _)| port_id)
+    }
+
+    /// Returns `true` if the given port is bound to a module.
+    pub fn is_bound(&self, port_id: &PortId) -> bool {
+        self.bindings.contains_key(port_id)
+    }
+
+    /// Claims the given channel end over the port of the given capability,
+    /// which must authenticate the module owning the port, and returns the
+    /// capability of the module over the channel end.
+    pub fn claim_channel(
+        &mut self,
+        port_capability: &PortCapability,
+        channel_id: ChannelId,
+    ) -> Result<ChannelCapability, RouterError> {
+        if !self.authenticate(port_capability) {
+            return Err(RouterError::InvalidPortCapability {
+                port_id: port_capability.port_id.clone(),
+            });
+        }
+
+        let key = (port_capability.port_id.clone(), channel_id);
+
+        if let Some((module_id, _)) = self.channels.get(&key) {
+            let (port_id, channel_id) = key;
+            return Err(RouterError::ChannelAlreadyClaimed {
+                port_id,
+                channel_id,
+                module_id: module_id.clone(),
+            });
+        }
+
+        let index = self.next_index;
+        self.next_index += 1;
+
+        self.channels
+            .insert(key.clone(), (port_capability.module_id.clone(), index));
+
+        let (port_id, channel_id) = key;
+
+        Ok(ChannelCapability {
+            port_id,
+            channel_id,
+            module_id: port_capability.module_id.clone(),
+            index,
+        })
+    }
+
+    /// Releases the channel end of the given capability, which must
+    /// authenticate the module owning the channel end, e.g. once the channel
+    /// is closed.
+    pub fn release_channel(&mut self, capability: &ChannelCapability) -> Result<(), RouterError> {
+        if !self.authenticate_channel(capability) {
+            return Err(RouterError::InvalidChannelCapability {
+                port_id: capability.port_id.clone(),
+                channel_id: capability.channel_id.clone(),
+            });
+        }
+
+        self.channels
+            .remove(&(capability.port_id.clone(), capability.channel_id.clone()));
+
+        Ok(())
+    }
+
+    /// Returns `true` if the given capability authenticates its module as the
+    /// current owner of its channel end.
+    pub fn authenticate_channel(&self, capability: &ChannelCapability) -> bool {
+        matches!(
+            self.channels.get(&(capability.port_id.clone(), capability.channel_id.clone())),
+            Some((module_id, index))
+                if *module_id == capability.module_id && *index == capability.index
+        )
+    }
+
+    /// Returns the module which claimed the given channel end, if any.
+    pub fn channel_owner(&self, port_id: &PortId, channel_id: &ChannelId) -> Option<&ModuleId> {
+        self.channels
+            .get(&(port_id.clone(), channel_id.clone()))
+            .map(|(module_id, _)| module_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn module_id(name: &str) -> ModuleId {
+        ModuleId::new(name.to_string())
+    }
+
+    #[test]
+    fn bind_and_authenticate_port() {
+        let mut bindings = PortBindings::default();
+
+        let capability = bindings
+            .bind(PortId::transfer(), module_id("transfer"))
+            .unwrap();
+
+        assert!(bindings.authenticate(&capability));
+        assert_eq!(
+            bindings.lookup_module(&PortId::transfer()),
+            Some(&module_id("transfer"))
+        );
+
+        // A port is bound to a single module at a time.
+        assert!(matches!(
+            bindings.bind(PortId::transfer(), module_id("other")),
+            Err(RouterError::PortAlreadyBound { .. })
+        ));
+    }
+
+    #[test]
+    fn released_port_capability_is_invalidated() {
+        let mut bindings = PortBindings::default();
+
+        let capability = bindings
+            .bind(PortId::transfer(), module_id("transfer"))
+            .unwrap();
+        bindings.release(&capability).unwrap();
+
+        assert!(!bindings.is_bound(&PortId::transfer()));
+        assert!(bindings.release(&capability).is_err());
+
+        // Binding the port again issues a new capability, the previous one
+        // not authenticating the module anymore.
+        let new_capability = bindings
+            .bind(PortId::transfer(), module_id("transfer"))
+            .unwrap();
+
+        assert!(bindings.authenticate(&new_capability));
+        assert!(!bindings.authenticate(&capability));
+    }
+
+    #[test]
+    fn claim_and_authenticate_channel() {
+        let mut bindings = PortBindings::default();
+
+        let port_id = PortId::transfer();
+        let other_port_id = PortId::new("other".to_string()).unwrap();
+        let capability = bindings
+            .bind(port_id.clone(), module_id("transfer"))
+            .unwrap();
+        let other_capability = bindings
+            .bind(other_port_id.clone(), module_id("other"))
+            .unwrap();
+
+        let channel_capability = bindings
+            .claim_channel(&capability, ChannelId::new(0))
+            .unwrap();
+
+        assert!(bindings.authenticate_channel(&channel_capability));
+        assert_eq!(
+            bindings.channel_owner(&port_id, &ChannelId::new(0)),
+            Some(&module_id("transfer"))
+        );
+
+        // A channel end is claimed by a single module at a time, and only
+        // over the ports of the module. The channel ends of other ports are
+        // not authenticated by the capability either.
+        assert!(matches!(
+            bindings.claim_channel(&capability, ChannelId::new(0)),
+            Err(RouterError::ChannelAlreadyClaimed { .. })
+        ));
+        let other_channel_capability = bindings
+            .claim_channel(&other_capability, ChannelId::new(0))
+            .unwrap();
+        assert_ne!(other_channel_capability, channel_capability);
+
+        // Releasing the port releases its channel ends.
+        bindings.release(&capability).unwrap();
+
+        assert!(!bindings.authenticate_channel(&channel_capability));
+        assert!(bindings.release_channel(&channel_capability).is_err());
+        assert!(bindings.authenticate_channel(&other_channel_capability));
+
+        bindings.release_channel(&other_channel_capability).unwrap();
+
+        assert_eq!(
+            bindings.channel_owner(&other_port_id, &ChannelId::new(0)),
+            None
+        );
+    }
+}
@@ -18,6 +18,14 @@ pub mod entrypoint {
     pub use ibc_core_handler::entrypoint::*;
 }
 
+/// Re-exports the asynchronous IBC handler entrypoints from the
+/// `ibc-core-handler` crate for added convenience.
+#[cfg(feature = "async")]
+pub mod async_entrypoint {
+    #[doc(inline)]
+    pub use ibc_core_handler::async_entrypoint::*;
+}
+
 /// Re-exports IBC primitive types from the `ibc-primitives` crate
 pub mod primitives {
     #[doc(inline)]
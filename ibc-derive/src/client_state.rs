@@ -2,12 +2,12 @@ mod traits;
 
 use proc_macro2::TokenStream;
 use quote::{quote, ToTokens};
-use syn::{DeriveInput, Error, GenericArgument, Ident, WherePredicate};
+use syn::{DeriveInput, Error, GenericArgument, Generics, Ident, WherePredicate};
 use traits::client_state_common::impl_ClientStateCommon;
 use traits::client_state_execution::impl_ClientStateExecution;
 use traits::client_state_validation::impl_ClientStateValidation;
 
-use crate::utils::Imports;
+use crate::utils::{impl_params, Imports};
 
 const MISSING_ATTR: &str = "must be annotated with #[validation(<your ClientValidationContext>) and #[execution(<your ClientExecutionContext>)]";
 const MISSING_VALIDATION_ATTR: &str = "missing #[validation(<your ClientValidationContext>)]";
@@ -32,19 +32,25 @@ impl ClientCtx {
     }
 
     /// Returns the `impl` quote block for the given context type, used for
-    /// implementing ClientValidation/ExecutionContext on the given enum.
-    fn impl_ts(&self) -> TokenStream {
-        let gens = self.generics.clone();
-
-        quote! { impl<#(#gens),*> }
+    /// implementing ClientValidation/ExecutionContext on the given enum, with
+    /// the generics of the context merged with those of the enum.
+    fn impl_ts(&self, enum_generics: &Generics) -> TokenStream {
+        let (lifetimes, gens): (Vec<_>, Vec<_>) = self
+            .generics
+            .iter()
+            .partition(|gen| matches!(gen, GenericArgument::Lifetime(_)));
+        let (enum_lifetimes, enum_params) = impl_params(enum_generics);
+
+        quote! { impl<#(#lifetimes,)* #(#enum_lifetimes,)* #(#gens,)* #(#enum_params),*> }
     }
 
     /// Returns the `where` clause quote block for the given context type, used
-    /// for implementing ClientValidation/ExecutionContext on the given enum.
-    fn where_clause_ts(&self) -> TokenStream {
+    /// for implementing ClientValidation/ExecutionContext on the given enum,
+    /// followed by the predicates the enum requires.
+    fn where_clause_ts(&self, enum_predicates: &[TokenStream]) -> TokenStream {
         let predicates = self.predicates.clone();
 
-        quote! { where #(#predicates),* }
+        quote! { where #(#predicates,)* #(#enum_predicates),* }
     }
 }
 
@@ -155,11 +161,14 @@ pub fn client_state_derive_impl(ast: DeriveInput, imports: &Imports) -> TokenStr
         _ => panic!("ClientState only supports enums"),
     };
 
-    let ClientStateCommon_impl_block = impl_ClientStateCommon(enum_name, enum_variants, imports);
+    let enum_generics = &ast.generics;
+
+    let ClientStateCommon_impl_block =
+        impl_ClientStateCommon(enum_name, enum_generics, enum_variants, imports);
     let ClientStateValidation_impl_block =
-        impl_ClientStateValidation(enum_name, enum_variants, &opts, imports);
+        impl_ClientStateValidation(enum_name, enum_generics, enum_variants, &opts, imports);
     let ClientStateExecution_impl_block =
-        impl_ClientStateExecution(enum_name, enum_variants, &opts, imports);
+        impl_ClientStateExecution(enum_name, enum_generics, enum_variants, &opts, imports);
 
     quote! {
         #ClientStateCommon_impl_block
@@ -2,12 +2,13 @@ use proc_macro2::{Ident, TokenStream};
 use quote::quote;
 use syn::punctuated::{Iter, Punctuated};
 use syn::token::Comma;
-use syn::Variant;
+use syn::{Generics, Variant};
 
-use crate::utils::{get_enum_variant_type_path, Imports};
+use crate::utils::{get_enum_variant_type_path, impl_params, where_predicates, Imports};
 
 pub(crate) fn impl_ClientStateCommon(
     client_state_enum_name: &Ident,
+    enum_generics: &Generics,
     enum_variants: &Punctuated<Variant, Comma>,
     imports: &Imports,
 ) -> TokenStream {
@@ -66,8 +67,15 @@ pub(crate) fn impl_ClientStateCommon(
     let Height = imports.height();
     let Path = imports.path();
 
+    let (Lifetimes, Params) = impl_params(enum_generics);
+    let (_, TyGenerics, _) = enum_generics.split_for_impl();
+    let Predicates = where_predicates(enum_generics, enum_variants.iter(), &ClientStateCommon);
+
     quote! {
-        impl #ClientStateCommon for #HostClientState {
+        impl<#(#Lifetimes,)* #(#Params),*> #ClientStateCommon for #HostClientState #TyGenerics
+        where
+            #(#Predicates),*
+        {
             fn verify_consensus_state(&self, consensus_state: #Any) -> Result<(), #ClientError> {
                 match self {
                     #(#verify_consensus_state_impl),*
@@ -2,13 +2,14 @@ use proc_macro2::{Ident, TokenStream};
 use quote::{quote, ToTokens};
 use syn::punctuated::{Iter, Punctuated};
 use syn::token::Comma;
-use syn::Variant;
+use syn::{Generics, Variant};
 
 use crate::client_state::Opts;
-use crate::utils::{get_enum_variant_type_path, Imports};
+use crate::utils::{get_enum_variant_type_path, where_predicates, Imports};
 
 pub(crate) fn impl_ClientStateExecution(
     client_state_enum_name: &Ident,
+    enum_generics: &Generics,
     enum_variants: &Punctuated<Variant, Comma>,
     opts: &Opts,
     imports: &Imports,
@@ -43,6 +44,14 @@ pub(crate) fn impl_ClientStateExecution(
         imports,
     );
 
+    let update_on_recovery_impl = delegate_call_in_match(
+        client_state_enum_name,
+        enum_variants.iter(),
+        opts,
+        quote! { update_on_recovery(cs, ctx, subject_client_id, substitute_client_id, substitute_client_state) },
+        imports,
+    );
+
     // The imports we need for the generated code.
     let Any = imports.any();
     let ClientId = imports.client_id();
@@ -54,15 +63,22 @@ pub(crate) fn impl_ClientStateExecution(
     let HostClientState = client_state_enum_name;
     let ClientExecutionContext = &opts.client_execution_context.clone().into_token_stream();
 
+    let (_, TyGenerics, _) = enum_generics.split_for_impl();
+
     // The `impl` block quote based on whether the context includes generics.
-    let Impl = opts.client_execution_context.impl_ts();
+    let Impl = opts.client_execution_context.impl_ts(enum_generics);
 
     // The `Where` clause quote based on whether the generics within the context
-    // include trait bounds
-    let Where = opts.client_execution_context.where_clause_ts();
+    // or the enum include trait bounds
+    let Predicates = where_predicates(
+        enum_generics,
+        enum_variants.iter(),
+        &quote! { #ClientStateExecution<#ClientExecutionContext> },
+    );
+    let Where = opts.client_execution_context.where_clause_ts(&Predicates);
 
     quote! {
-        #Impl #ClientStateExecution<#ClientExecutionContext> for #HostClientState #Where {
+        #Impl #ClientStateExecution<#ClientExecutionContext> for #HostClientState #TyGenerics #Where {
             fn initialise(
                 &self,
                 ctx: &mut #ClientExecutionContext,
@@ -107,6 +123,18 @@ pub(crate) fn impl_ClientStateExecution(
                     #(#update_state_with_upgrade_client_impl),*
                 }
             }
+
+            fn update_on_recovery(
+                &self,
+                ctx: &mut #ClientExecutionContext,
+                subject_client_id: &#ClientId,
+                substitute_client_id: &#ClientId,
+                substitute_client_state: #Any,
+            ) -> core::result::Result<(), #ClientError> {
+                match self {
+                    #(#update_on_recovery_impl),*
+                }
+            }
         }
 
     }
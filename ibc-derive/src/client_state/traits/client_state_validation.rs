@@ -2,13 +2,14 @@ use proc_macro2::{Ident, TokenStream};
 use quote::{quote, ToTokens};
 use syn::punctuated::{Iter, Punctuated};
 use syn::token::Comma;
-use syn::Variant;
+use syn::{Generics, Variant};
 
 use crate::client_state::Opts;
-use crate::utils::{get_enum_variant_type_path, Imports};
+use crate::utils::{get_enum_variant_type_path, where_predicates, Imports};
 
 pub(crate) fn impl_ClientStateValidation(
     client_state_enum_name: &Ident,
+    enum_generics: &Generics,
     enum_variants: &Punctuated<Variant, Comma>,
     opts: &Opts,
     imports: &Imports,
@@ -37,26 +38,70 @@ pub(crate) fn impl_ClientStateValidation(
         imports,
     );
 
+    let verify_membership_with_context_impl = delegate_call_in_match(
+        client_state_enum_name,
+        enum_variants.iter(),
+        opts,
+        quote! { verify_membership_with_context(cs, ctx, prefix, proof, root, path, value) },
+        imports,
+    );
+
+    let verify_non_membership_with_context_impl = delegate_call_in_match(
+        client_state_enum_name,
+        enum_variants.iter(),
+        opts,
+        quote! { verify_non_membership_with_context(cs, ctx, prefix, proof, root, path) },
+        imports,
+    );
+
+    let check_substitute_impl = delegate_call_in_match(
+        client_state_enum_name,
+        enum_variants.iter(),
+        opts,
+        quote! { check_substitute(cs, ctx, substitute_client_state) },
+        imports,
+    );
+
+    let collect_signature_checks_impl = delegate_call_in_match(
+        client_state_enum_name,
+        enum_variants.iter(),
+        opts,
+        quote! { collect_signature_checks(cs, ctx, client_id, client_message, batch) },
+        imports,
+    );
+
     // The imports we need for the generated code.
     let Any = imports.any();
     let ClientId = imports.client_id();
     let ClientError = imports.client_error();
     let ClientStateValidation = imports.client_state_validation();
+    let CommitmentRoot = imports.commitment_root();
+    let CommitmentPrefix = imports.commitment_prefix();
+    let CommitmentProofBytes = imports.commitment_proof_bytes();
+    let Path = imports.path();
+    let SignatureBatch = imports.signature_batch();
     let Status = imports.status();
 
     // The types we need for the generated code.
     let HostClientState = client_state_enum_name;
     let ClientValidationContext = opts.client_validation_context.clone().into_token_stream();
 
+    let (_, TyGenerics, _) = enum_generics.split_for_impl();
+
     // The `impl` block quote based on whether the context includes generics.
-    let Impl = opts.client_validation_context.impl_ts();
+    let Impl = opts.client_validation_context.impl_ts(enum_generics);
 
     // The `Where` clause quote based on whether the generics within the context
-    // include trait bounds
-    let Where = opts.client_validation_context.where_clause_ts();
+    // or the enum include trait bounds
+    let Predicates = where_predicates(
+        enum_generics,
+        enum_variants.iter(),
+        &quote! { #ClientStateValidation<#ClientValidationContext> },
+    );
+    let Where = opts.client_validation_context.where_clause_ts(&Predicates);
 
     quote! {
-        #Impl #ClientStateValidation<#ClientValidationContext> for #HostClientState #Where {
+        #Impl #ClientStateValidation<#ClientValidationContext> for #HostClientState #TyGenerics #Where {
             fn verify_client_message(
                 &self,
                 ctx: &#ClientValidationContext,
@@ -89,6 +134,55 @@ pub(crate) fn impl_ClientStateValidation(
                 }
 
             }
+
+            fn verify_membership_with_context(
+                &self,
+                ctx: &#ClientValidationContext,
+                prefix: &#CommitmentPrefix,
+                proof: &#CommitmentProofBytes,
+                root: &#CommitmentRoot,
+                path: #Path,
+                value: Vec<u8>,
+            ) -> core::result::Result<(), #ClientError> {
+                match self {
+                    #(#verify_membership_with_context_impl),*
+                }
+            }
+
+            fn verify_non_membership_with_context(
+                &self,
+                ctx: &#ClientValidationContext,
+                prefix: &#CommitmentPrefix,
+                proof: &#CommitmentProofBytes,
+                root: &#CommitmentRoot,
+                path: #Path,
+            ) -> core::result::Result<(), #ClientError> {
+                match self {
+                    #(#verify_non_membership_with_context_impl),*
+                }
+            }
+
+            fn check_substitute(
+                &self,
+                ctx: &#ClientValidationContext,
+                substitute_client_state: #Any,
+            ) -> core::result::Result<(), #ClientError> {
+                match self {
+                    #(#check_substitute_impl),*
+                }
+            }
+
+            fn collect_signature_checks(
+                &self,
+                ctx: &#ClientValidationContext,
+                client_id: &#ClientId,
+                client_message: &#Any,
+                batch: &mut #SignatureBatch,
+            ) -> core::result::Result<(), #ClientError> {
+                match self {
+                    #(#collect_signature_checks_impl),*
+                }
+            }
         }
 
     }
@@ -3,7 +3,7 @@ use quote::quote;
 use syn::punctuated::Iter;
 use syn::{DeriveInput, Ident, Variant};
 
-use crate::utils::{get_enum_variant_type_path, Imports};
+use crate::utils::{get_enum_variant_type_path, impl_params, where_predicates, Imports};
 
 pub fn consensus_state_derive_impl(ast: DeriveInput, imports: &Imports) -> TokenStream {
     let enum_name = &ast.ident;
@@ -31,8 +31,15 @@ pub fn consensus_state_derive_impl(ast: DeriveInput, imports: &Imports) -> Token
     let ConsensusState = imports.consensus_state();
     let Timestamp = imports.timestamp();
 
+    let (lifetimes, params) = impl_params(&ast.generics);
+    let (_, ty_generics, _) = ast.generics.split_for_impl();
+    let predicates = where_predicates(&ast.generics, enum_variants.iter(), &ConsensusState);
+
     quote! {
-        impl #ConsensusState for #enum_name {
+        impl<#(#lifetimes,)* #(#params),*> #ConsensusState for #enum_name #ty_generics
+        where
+            #(#predicates),*
+        {
             fn root(&self) -> &#CommitmentRoot {
                 match self {
                     #(#root_impl),*
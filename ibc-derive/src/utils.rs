@@ -1,6 +1,7 @@
-use proc_macro2::TokenStream;
-use quote::quote;
-use syn::{Path, Variant};
+use proc_macro2::{TokenStream, TokenTree};
+use quote::{quote, ToTokens};
+use syn::punctuated::Iter;
+use syn::{GenericParam, Generics, Ident, Path, Variant};
 
 /// The IBC crates that we already support in the derive macro
 pub enum SupportedCrate {
@@ -70,6 +71,11 @@ impl Imports {
         quote! {#Prefix::client::context::client_state::ClientStateExecution}
     }
 
+    pub fn signature_batch(&self) -> TokenStream {
+        let Prefix = self.prefix();
+        quote! {#Prefix::client::context::signature_batch::SignatureBatch}
+    }
+
     pub fn client_id(&self) -> TokenStream {
         let Prefix = self.prefix();
         quote! {#Prefix::host::types::identifiers::ClientId}
@@ -140,3 +146,81 @@ pub fn get_enum_variant_type_path(enum_variant: &Variant) -> &Path {
         }
     }
 }
+
+/// Splits the generic parameters of the user's enum into its lifetimes and its
+/// type and const parameters, stripped of their defaults, for merging them
+/// with the generics of a context into the parameters of an `impl` block.
+pub fn impl_params(generics: &Generics) -> (Vec<GenericParam>, Vec<GenericParam>) {
+    generics
+        .params
+        .iter()
+        .cloned()
+        .map(|param| match param {
+            GenericParam::Type(mut ty) => {
+                ty.eq_token = None;
+                ty.default = None;
+                GenericParam::Type(ty)
+            }
+            GenericParam::Const(mut c) => {
+                c.eq_token = None;
+                c.default = None;
+                GenericParam::Const(c)
+            }
+            lifetime => lifetime,
+        })
+        .partition(|param| matches!(param, GenericParam::Lifetime(_)))
+}
+
+/// Returns the predicates of the `where` clause of the user's enum, followed
+/// by the bounds requiring the variants wrapping its generic parameters to
+/// implement `bound`.
+///
+/// For example, given
+/// ```ignore
+///
+/// #[derive(IbcConsensusState)]
+/// enum AnyConsensusState<C> {
+///     Tendermint(TmConsensusState),
+///     Wasm(WasmConsensusState<C>),
+/// }
+/// ```
+/// this will return `WasmConsensusState<C>: ConsensusState`, so that the calls
+/// on the `Wasm` variant delegate through the implementation of the wrapper
+/// for the wrapped client `C`.
+pub fn where_predicates(
+    generics: &Generics,
+    enum_variants: Iter<'_, Variant>,
+    bound: &TokenStream,
+) -> Vec<TokenStream> {
+    let params: Vec<&Ident> = generics
+        .params
+        .iter()
+        .filter_map(|param| match param {
+            GenericParam::Type(ty) => Some(&ty.ident),
+            GenericParam::Const(c) => Some(&c.ident),
+            GenericParam::Lifetime(_) => None,
+        })
+        .collect();
+
+    let enum_predicates = generics
+        .where_clause
+        .iter()
+        .flat_map(|where_clause| where_clause.predicates.iter())
+        .map(|predicate| predicate.to_token_stream());
+
+    let variant_bounds = enum_variants
+        .map(get_enum_variant_type_path)
+        .filter(|path| mentions_any(path.to_token_stream(), &params))
+        .map(|path| quote! { #path: #bound });
+
+    enum_predicates.chain(variant_bounds).collect()
+}
+
+/// Returns whether any of the `idents` occurs in the `tokens`.
+fn mentions_any(tokens: TokenStream, idents: &[&Ident]) -> bool {
+    tokens.into_iter().any(|tree| match tree {
+        TokenTree::Ident(ident) => idents.contains(&&ident),
+        TokenTree::Group(group) => mentions_any(group.stream(), idents),
+        _ => false,
+    })
+}
@@ -0,0 +1,104 @@
+/*
+ * The C FFI of the proof and Tendermint header verification of ibc-rs.
+ *
+ * The declarations below are stable within an IBC_FFI_ABI_VERSION: any
+ * change to them comes with a bump of the version, which the embedders can
+ * check against ibc_ffi_abi_version() when loading the library.
+ */
+
+#ifndef IBC_FFI_H
+#define IBC_FFI_H
+
+#include <stddef.h>
+#include <stdint.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+#define IBC_FFI_ABI_VERSION 1
+
+/*
+ * The outcome of a call to the library.
+ */
+typedef enum IbcStatus {
+    IBC_STATUS_OK = 0,
+    /* An argument is a null pointer with a non-zero length, or a path is not UTF-8. */
+    IBC_STATUS_INVALID_ARGUMENT = 1,
+    /* An argument failed to decode. */
+    IBC_STATUS_DECODE_FAILED = 2,
+    /* The arguments decoded, but the proof or the header failed to verify. */
+    IBC_STATUS_VERIFICATION_FAILED = 3,
+    /* The library panicked, which is a bug of the library. */
+    IBC_STATUS_PANICKED = 4,
+} IbcStatus;
+
+/*
+ * A borrowed byte slice. The pointer may be null when the length is zero.
+ */
+typedef struct IbcSlice {
+    const uint8_t *ptr;
+    size_t len;
+} IbcSlice;
+
+/*
+ * Returns the IBC_FFI_ABI_VERSION the library was built with.
+ */
+uint32_t ibc_ffi_abi_version(void);
+
+/*
+ * Returns the NUL-terminated description of the error of the last call to
+ * the library on the calling thread, or NULL if it succeeded. The string is
+ * owned by the library, and stays valid until the next call to the library
+ * on the same thread.
+ */
+const char *ibc_last_error_message(void);
+
+/*
+ * Verifies the proof of the value at the path, against the commitment root
+ * and prefix of the chain the Tendermint client state tracks.
+ *
+ * client_state: the protobuf encoding of the Any of the client state
+ * prefix:       the commitment prefix of the store of the chain
+ * proof:        the protobuf encoding of the MerkleProof
+ * root:         the commitment root the proof is verified against
+ * path:         the UTF-8 IBC path, e.g. "clients/07-tendermint-0/clientState"
+ * value:        the value proven at the path
+ */
+IbcStatus ibc_verify_membership(IbcSlice client_state,
+                                IbcSlice prefix,
+                                IbcSlice proof,
+                                IbcSlice root,
+                                IbcSlice path,
+                                IbcSlice value);
+
+/*
+ * Verifies the proof of the absence of a value at the path, against the
+ * commitment root and prefix of the chain the Tendermint client state
+ * tracks. The arguments are the ones of ibc_verify_membership.
+ */
+IbcStatus ibc_verify_non_membership(IbcSlice client_state,
+                                    IbcSlice prefix,
+                                    IbcSlice proof,
+                                    IbcSlice root,
+                                    IbcSlice path);
+
+/*
+ * Verifies the Tendermint header against the trusted client state and
+ * consensus state, at the time now, in nanoseconds since the Unix epoch.
+ *
+ * client_state:            the protobuf encoding of the Any of the client state
+ * trusted_consensus_state: the protobuf encoding of the Any of the consensus
+ *                          state at the trusted height of the header
+ * header:                  the protobuf encoding of the Any of the header
+ */
+IbcStatus ibc_verify_tendermint_header(IbcSlice client_state,
+                                       IbcSlice trusted_consensus_state,
+                                       IbcSlice header,
+                                       uint64_t now);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif /* IBC_FFI_H */
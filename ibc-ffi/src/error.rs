@@ -0,0 +1,88 @@
+use std::cell::RefCell;
+use std::ffi::{c_char, CString};
+
+use displaydoc::Display;
+use ibc::core::client::types::error::ClientError;
+use ibc::core::host::types::path::PathError;
+
+use crate::IbcStatus;
+
+thread_local! {
+    /// The description of the error of the last call on the thread.
+    static LAST_ERROR: RefCell<Option<CString>> = const { RefCell::new(None) };
+}
+
+#[derive(Debug, Display)]
+pub enum FfiError {
+    /// invalid argument `{name}`: {reason}
+    InvalidArgument {
+        name: &'static str,
+        reason: &'static str,
+    },
+    /// failed to decode `{type_name}`: {description}
+    Decode {
+        type_name: &'static str,
+        description: String,
+    },
+    /// invalid path: {0}
+    Path(PathError),
+    /// verification failed: {0}
+    Verification(ClientError),
+    /// panicked: {description}
+    Panicked { description: String },
+}
+
+impl FfiError {
+    pub fn decode(type_name: &'static str, error: impl core::fmt::Display) -> Self {
+        Self::Decode {
+            type_name,
+            description: error.to_string(),
+        }
+    }
+
+    /// Returns the status the error is returned as.
+    pub fn status(&self) -> IbcStatus {
+        match self {
+            Self::InvalidArgument { .. } => IbcStatus::InvalidArgument,
+            Self::Decode { .. } | Self::Path(_) => IbcStatus::DecodeFailed,
+            Self::Verification(_) => IbcStatus::VerificationFailed,
+            Self::Panicked { .. } => IbcStatus::Panicked,
+        }
+    }
+}
+
+impl From<PathError> for FfiError {
+    fn from(e: PathError) -> Self {
+        Self::Path(e)
+    }
+}
+
+impl From<ClientError> for FfiError {
+    fn from(e: ClientError) -> Self {
+        Self::Verification(e)
+    }
+}
+
+impl std::error::Error for FfiError {}
+
+/// Records the error of the current call, or clears the one of the previous
+/// call if `None`.
+pub fn set_last_error(error: Option<&FfiError>) {
+    // A description may only carry a NUL byte from the decoded inputs, which
+    // is dropped rather than failing to record the error.
+    let message = error.map(|e| {
+        CString::new(e.to_string().replace('\0', "")).expect("the NUL bytes have been removed")
+    });
+
+    LAST_ERROR.with(|last_error| *last_error.borrow_mut() = message);
+}
+
+/// Returns the pointer to the error of the last call on the thread, or null.
+pub fn last_error_ptr() -> *const c_char {
+    LAST_ERROR.with(|last_error| {
+        last_error
+            .borrow()
+            .as_ref()
+            .map_or(core::ptr::null(), |message| message.as_ptr())
+    })
+}
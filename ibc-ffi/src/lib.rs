@@ -0,0 +1,188 @@
+//! Provides the C FFI of the verification of the IBC proofs and the
+//! Tendermint headers, for the Go, C++ and mobile stacks to embed the
+//! verification of `ibc-rs` rather than reimplementing it.
+//!
+//! The functions are declared for C in `include/ibc_ffi.h`, which is kept in
+//! sync with this crate by hand, and whose ABI is versioned by
+//! [`IBC_FFI_ABI_VERSION`]. The client and consensus states and the headers
+//! are passed as the protobuf encoding of their `Any`, and the proofs as the
+//! protobuf encoding of their `MerkleProof`.
+#![deny(
+    warnings,
+    trivial_numeric_casts,
+    unused_import_braces,
+    unused_qualifications,
+    unsafe_op_in_unsafe_fn,
+    rust_2018_idioms
+)]
+
+mod error;
+mod slice;
+mod verify;
+
+use std::ffi::c_char;
+use std::panic::{catch_unwind, AssertUnwindSafe};
+
+pub use crate::error::FfiError;
+pub use crate::slice::IbcSlice;
+use crate::verify::ProvenState;
+
+/// The version of the ABI of the functions and types of the C header, which
+/// is bumped along with any change to them.
+pub const IBC_FFI_ABI_VERSION: u32 = 1;
+
+/// The outcome of a call to the library.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum IbcStatus {
+    Ok = 0,
+    /// An argument is a null pointer with a non-zero length, or a path is not
+    /// UTF-8.
+    InvalidArgument = 1,
+    /// An argument failed to decode.
+    DecodeFailed = 2,
+    /// The arguments decoded, but the proof or the header failed to verify.
+    VerificationFailed = 3,
+    /// The library panicked.
+    Panicked = 4,
+}
+
+/// Returns the [`IBC_FFI_ABI_VERSION`] the library was built with.
+#[no_mangle]
+pub extern "C" fn ibc_ffi_abi_version() -> u32 {
+    IBC_FFI_ABI_VERSION
+}
+
+/// Returns the NUL-terminated description of the error of the last call on
+/// the calling thread, or null if it succeeded, which stays valid until the
+/// next call to the library on the same thread.
+#[no_mangle]
+pub extern "C" fn ibc_last_error_message() -> *const c_char {
+    error::last_error_ptr()
+}
+
+/// Verifies the proof of the value at the path, against the commitment root
+/// and prefix of the chain the Tendermint client state tracks.
+///
+/// # Safety
+///
+/// Each slice must point to `len` bytes valid for reads for the duration of
+/// the call, unless its `len` is zero.
+#[no_mangle]
+pub unsafe extern "C" fn ibc_verify_membership(
+    client_state: IbcSlice,
+    prefix: IbcSlice,
+    proof: IbcSlice,
+    root: IbcSlice,
+    path: IbcSlice,
+    value: IbcSlice,
+) -> IbcStatus {
+    ffi_call(|| {
+        // SAFETY: forwarded to the caller.
+        let (state, value) = unsafe {
+            (
+                proven_state(client_state, prefix, proof, root, path)?,
+                value.as_bytes("value")?,
+            )
+        };
+
+        verify::verify_membership(state, value)
+    })
+}
+
+/// Verifies the proof of the absence of a value at the path, against the
+/// commitment root and prefix of the chain the Tendermint client state
+/// tracks.
+///
+/// # Safety
+///
+/// See [`ibc_verify_membership`].
+#[no_mangle]
+pub unsafe extern "C" fn ibc_verify_non_membership(
+    client_state: IbcSlice,
+    prefix: IbcSlice,
+    proof: IbcSlice,
+    root: IbcSlice,
+    path: IbcSlice,
+) -> IbcStatus {
+    ffi_call(|| {
+        // SAFETY: forwarded to the caller.
+        let state = unsafe { proven_state(client_state, prefix, proof, root, path)? };
+
+        verify::verify_non_membership(state)
+    })
+}
+
+/// Verifies the Tendermint header against the trusted client state and
+/// consensus state, at the time `now`, in nanoseconds since the Unix epoch.
+///
+/// # Safety
+///
+/// See [`ibc_verify_membership`].
+#[no_mangle]
+pub unsafe extern "C" fn ibc_verify_tendermint_header(
+    client_state: IbcSlice,
+    trusted_consensus_state: IbcSlice,
+    header: IbcSlice,
+    now: u64,
+) -> IbcStatus {
+    ffi_call(|| {
+        // SAFETY: forwarded to the caller.
+        let (client_state, trusted_consensus_state, header) = unsafe {
+            (
+                client_state.as_bytes("client_state")?,
+                trusted_consensus_state.as_bytes("trusted_consensus_state")?,
+                header.as_bytes("header")?,
+            )
+        };
+
+        verify::verify_tendermint_header(client_state, trusted_consensus_state, header, now)
+    })
+}
+
+/// # Safety
+///
+/// See [`ibc_verify_membership`].
+unsafe fn proven_state<'a>(
+    client_state: IbcSlice,
+    prefix: IbcSlice,
+    proof: IbcSlice,
+    root: IbcSlice,
+    path: IbcSlice,
+) -> Result<ProvenState<'a>, FfiError> {
+    // SAFETY: forwarded to the caller.
+    unsafe {
+        Ok(ProvenState {
+            client_state: client_state.as_bytes("client_state")?,
+            prefix: prefix.as_bytes("prefix")?,
+            proof: proof.as_bytes("proof")?,
+            root: root.as_bytes("root")?,
+            path: path.as_str("path")?,
+        })
+    }
+}
+
+/// Runs the call, recording its error for [`ibc_last_error_message`], and
+/// catching its panics rather than unwinding across the FFI.
+fn ffi_call(call: impl FnOnce() -> Result<(), FfiError>) -> IbcStatus {
+    let result = catch_unwind(AssertUnwindSafe(call)).unwrap_or_else(|panic| {
+        let description = panic
+            .downcast_ref::<&str>()
+            .map(|s| s.to_string())
+            .or_else(|| panic.downcast_ref::<String>().cloned())
+            .unwrap_or_default();
+
+        Err(FfiError::Panicked { description })
+    });
+
+    match result {
+        Ok(()) => {
+            error::set_last_error(None);
+            IbcStatus::Ok
+        }
+        Err(e) => {
+            error::set_last_error(Some(&e));
+            e.status()
+        }
+    }
+}
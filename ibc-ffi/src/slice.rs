@@ -0,0 +1,51 @@
+use core::slice;
+
+use crate::error::FfiError;
+
+/// A byte slice borrowed from the caller.
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct IbcSlice {
+    pub ptr: *const u8,
+    pub len: usize,
+}
+
+impl IbcSlice {
+    /// Returns the bytes the slice points to.
+    ///
+    /// # Safety
+    ///
+    /// Unless `len` is zero, `ptr` must be valid for reads of `len` bytes,
+    /// which must not be mutated for the lifetime `'a`.
+    pub unsafe fn as_bytes<'a>(&self, name: &'static str) -> Result<&'a [u8], FfiError> {
+        if self.len == 0 {
+            return Ok(&[]);
+        }
+
+        if self.ptr.is_null() {
+            return Err(FfiError::InvalidArgument {
+                name,
+                reason: "null pointer with a non-zero length",
+            });
+        }
+
+        // SAFETY: the pointer is non-null, and valid for reads of `len`
+        // bytes as per the contract of the function.
+        Ok(unsafe { slice::from_raw_parts(self.ptr, self.len) })
+    }
+
+    /// Returns the UTF-8 string the slice points to.
+    ///
+    /// # Safety
+    ///
+    /// See [`IbcSlice::as_bytes`].
+    pub unsafe fn as_str<'a>(&self, name: &'static str) -> Result<&'a str, FfiError> {
+        // SAFETY: forwarded to the caller.
+        let bytes = unsafe { self.as_bytes(name) }?;
+
+        core::str::from_utf8(bytes).map_err(|_| FfiError::InvalidArgument {
+            name,
+            reason: "not UTF-8",
+        })
+    }
+}
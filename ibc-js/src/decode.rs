@@ -0,0 +1,126 @@
+//! Decodes the packets and the ABCI events of the IBC handlers into JSON.
+
+use core::str::FromStr;
+
+use ibc::core::channel::types::packet::Packet;
+use ibc::core::channel::types::proto::v1::Packet as RawPacket;
+use ibc::core::channel::types::timeout::TimeoutHeight;
+use ibc::core::client::types::Height;
+use ibc::core::host::types::identifiers::{ChannelId, PortId, Sequence};
+use ibc::primitives::proto::Protobuf;
+use ibc::primitives::Timestamp;
+use serde_json::{json, Map, Value};
+use tendermint::abci;
+
+use crate::error::BindingError;
+
+/// The events whose attributes describe a packet, as emitted by `ibc-rs` and
+/// ibc-go, which names the receive packet event `recv_packet`.
+const PACKET_EVENT_KINDS: &[&str] = &[
+    "send_packet",
+    "receive_packet",
+    "recv_packet",
+    "write_acknowledgement",
+    "acknowledge_packet",
+    "timeout_packet",
+];
+
+/// Decodes the protobuf encoding of a packet into its JSON.
+pub fn decode_packet(bytes: &[u8]) -> Result<String, BindingError> {
+    let packet: Packet =
+        Protobuf::<RawPacket>::decode_vec(bytes).map_err(|e| BindingError::decode("Packet", e))?;
+
+    to_json(&packet)
+}
+
+/// Decodes the JSON of an ABCI event, as returned by the CometBFT RPC, into
+/// the JSON of its kind and attributes, along with the packet it describes,
+/// if any.
+pub fn decode_event(event: &str) -> Result<String, BindingError> {
+    let event: abci::Event =
+        serde_json::from_str(event).map_err(|e| BindingError::decode("Event", e))?;
+
+    let attributes: Map<String, Value> = event
+        .attributes
+        .iter()
+        .map(|attribute| (attribute.key.clone(), attribute.value.clone().into()))
+        .collect();
+
+    let mut decoded = json!({
+        "type": event.kind.clone(),
+        "attributes": attributes,
+    });
+
+    if PACKET_EVENT_KINDS.contains(&event.kind.as_str()) {
+        let packet = packet_of_event(&event)?;
+        decoded["packet"] =
+            serde_json::to_value(packet).map_err(|e| BindingError::decode("Packet", e))?;
+    }
+
+    to_json(&decoded)
+}
+
+/// Returns the packet the attributes of the packet event describe.
+fn packet_of_event(event: &abci::Event) -> Result<Packet, BindingError> {
+    let attribute = |key: &'static str| {
+        event
+            .attributes
+            .iter()
+            .find(|attribute| attribute.key == key)
+            .map(|attribute| attribute.value.as_str())
+            .ok_or_else(|| BindingError::MissingAttribute {
+                kind: event.kind.clone(),
+                key,
+            })
+    };
+
+    let timeout_height_on_b = match attribute("packet_timeout_height")? {
+        "0-0" => TimeoutHeight::Never,
+        height => Height::from_str(height)
+            .map_err(|e| BindingError::decode("Height", e))?
+            .into(),
+    };
+    let timeout_timestamp_on_b = attribute("packet_timeout_timestamp")?
+        .parse()
+        .map_err(|e| BindingError::decode("Timestamp", e))
+        .and_then(|nanos| {
+            Timestamp::from_nanoseconds(nanos).map_err(|e| BindingError::decode("Timestamp", e))
+        })?;
+    // Only the `send_packet`, `recv_packet` and `write_acknowledgement`
+    // events carry the data of the packet.
+    let data = match attribute("packet_data_hex") {
+        Ok(data) => decode_hex(data)?,
+        Err(_) => Vec::new(),
+    };
+
+    Ok(Packet {
+        seq_on_a: Sequence::from_str(attribute("packet_sequence")?)
+            .map_err(|e| BindingError::decode("Sequence", e))?,
+        port_id_on_a: PortId::from_str(attribute("packet_src_port")?)
+            .map_err(|e| BindingError::decode("PortId", e))?,
+        chan_id_on_a: ChannelId::from_str(attribute("packet_src_channel")?)
+            .map_err(|e| BindingError::decode("ChannelId", e))?,
+        port_id_on_b: PortId::from_str(attribute("packet_dst_port")?)
+            .map_err(|e| BindingError::decode("PortId", e))?,
+        chan_id_on_b: ChannelId::from_str(attribute("packet_dst_channel")?)
+            .map_err(|e| BindingError::decode("ChannelId", e))?,
+        data,
+        timeout_height_on_b,
+        timeout_timestamp_on_b,
+    })
+}
+
+fn decode_hex(hex: &str) -> Result<Vec<u8>, BindingError> {
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| {
+            hex.get(i..i + 2)
+                .and_then(|byte| u8::from_str_radix(byte, 16).ok())
+                .ok_or_else(|| BindingError::decode("hex", "invalid hex digit"))
+        })
+        .collect()
+}
+
+fn to_json(value: &impl serde::Serialize) -> Result<String, BindingError> {
+    serde_json::to_string(value).map_err(|e| BindingError::decode("JSON", e))
+}
@@ -0,0 +1,41 @@
+use displaydoc::Display;
+use ibc::core::client::types::error::ClientError;
+use ibc::core::host::types::path::PathError;
+
+#[derive(Debug, Display)]
+pub enum BindingError {
+    /// failed to decode `{type_name}`: {description}
+    Decode {
+        type_name: &'static str,
+        description: String,
+    },
+    /// missing attribute `{key}` of the `{kind}` event
+    MissingAttribute { kind: String, key: &'static str },
+    /// invalid path: {0}
+    Path(PathError),
+    /// verification failed: {0}
+    Verification(ClientError),
+}
+
+impl BindingError {
+    pub fn decode(type_name: &'static str, error: impl core::fmt::Display) -> Self {
+        Self::Decode {
+            type_name,
+            description: error.to_string(),
+        }
+    }
+}
+
+impl From<PathError> for BindingError {
+    fn from(e: PathError) -> Self {
+        Self::Path(e)
+    }
+}
+
+impl From<ClientError> for BindingError {
+    fn from(e: ClientError) -> Self {
+        Self::Verification(e)
+    }
+}
+
+impl std::error::Error for BindingError {}
@@ -0,0 +1,99 @@
+//! Provides the [wasm-bindgen](https://rustwasm.github.io/wasm-bindgen/)
+//! bindings of the verification of the IBC proofs and the Tendermint headers,
+//! for the browser wallets and explorers to verify the states of a chain
+//! client-side with the same code the chains run.
+//!
+//! The client and consensus states and the headers are passed as the protobuf
+//! encoding of their `Any`, the proofs as the protobuf encoding of their
+//! `MerkleProof`, and the decoded packets and events are returned as JSON.
+#![forbid(unsafe_code)]
+#![deny(
+    warnings,
+    trivial_numeric_casts,
+    unused_import_braces,
+    unused_qualifications,
+    rust_2018_idioms
+)]
+
+mod decode;
+mod error;
+mod verify;
+
+use wasm_bindgen::prelude::*;
+
+pub use crate::error::BindingError;
+pub use crate::verify::ProvenState;
+
+/// Verifies the proof of the value at the path, against the commitment root
+/// and prefix of the chain the Tendermint client state tracks.
+#[wasm_bindgen(js_name = verifyMembership)]
+pub fn verify_membership(
+    client_state: &[u8],
+    prefix: &[u8],
+    proof: &[u8],
+    root: &[u8],
+    path: &str,
+    value: &[u8],
+) -> Result<(), JsError> {
+    let state = ProvenState {
+        client_state,
+        prefix,
+        proof,
+        root,
+        path,
+    };
+
+    verify::verify_membership(state, value).map_err(to_js_error)
+}
+
+/// Verifies the proof of the absence of a value at the path, against the
+/// commitment root and prefix of the chain the Tendermint client state
+/// tracks.
+#[wasm_bindgen(js_name = verifyNonMembership)]
+pub fn verify_non_membership(
+    client_state: &[u8],
+    prefix: &[u8],
+    proof: &[u8],
+    root: &[u8],
+    path: &str,
+) -> Result<(), JsError> {
+    let state = ProvenState {
+        client_state,
+        prefix,
+        proof,
+        root,
+        path,
+    };
+
+    verify::verify_non_membership(state).map_err(to_js_error)
+}
+
+/// Verifies the Tendermint header against the trusted consensus state of the
+/// client, at the time `now` given in nanoseconds since the Unix epoch.
+#[wasm_bindgen(js_name = verifyTendermintHeader)]
+pub fn verify_tendermint_header(
+    client_state: &[u8],
+    trusted_consensus_state: &[u8],
+    header: &[u8],
+    now: u64,
+) -> Result<(), JsError> {
+    verify::verify_tendermint_header(client_state, trusted_consensus_state, header, now)
+        .map_err(to_js_error)
+}
+
+/// Decodes the protobuf encoding of a packet into its JSON.
+#[wasm_bindgen(js_name = decodePacket)]
+pub fn decode_packet(packet: &[u8]) -> Result<String, JsError> {
+    decode::decode_packet(packet).map_err(to_js_error)
+}
+
+/// Decodes the JSON of an ABCI event into the JSON of its type, attributes
+/// and, for the packet events, the packet.
+#[wasm_bindgen(js_name = decodeEvent)]
+pub fn decode_event(event: &str) -> Result<String, JsError> {
+    decode::decode_event(event).map_err(to_js_error)
+}
+
+fn to_js_error(e: BindingError) -> JsError {
+    JsError::new(&e.to_string())
+}
@@ -0,0 +1,122 @@
+//! Verifies the proofs of the IBC states and the Tendermint headers, with the
+//! verification functions of the Tendermint light client.
+
+use core::str::FromStr;
+
+use ibc::clients::tendermint::client_state::{
+    verify_header_with_trusted_state, verify_membership as tm_verify_membership,
+    verify_non_membership as tm_verify_non_membership,
+};
+use ibc::clients::tendermint::context::DefaultVerifier;
+use ibc::clients::tendermint::types::{
+    ClientState as TmClientState, ConsensusState as TmConsensusState, Header as TmHeader,
+};
+use ibc::core::commitment_types::commitment::{
+    CommitmentPrefix, CommitmentProofBytes, CommitmentRoot,
+};
+use ibc::core::host::types::path::Path;
+use ibc::primitives::proto::Any;
+use ibc::primitives::Timestamp;
+use prost::Message;
+
+use crate::error::BindingError;
+
+/// The state proven by a proof, and the commitment root and prefix of the
+/// store of the chain it is proven against.
+pub struct ProvenState<'a> {
+    pub client_state: &'a [u8],
+    pub prefix: &'a [u8],
+    pub proof: &'a [u8],
+    pub root: &'a [u8],
+    pub path: &'a str,
+}
+
+/// The decoded [`ProvenState`].
+struct DecodedState {
+    client_state: TmClientState,
+    prefix: CommitmentPrefix,
+    proof: CommitmentProofBytes,
+    root: CommitmentRoot,
+    path: Path,
+}
+
+impl ProvenState<'_> {
+    fn decode(&self) -> Result<DecodedState, BindingError> {
+        Ok(DecodedState {
+            client_state: decode_any("ClientState", self.client_state)?,
+            prefix: CommitmentPrefix::try_from(self.prefix.to_vec())
+                .map_err(|e| BindingError::decode("CommitmentPrefix", e))?,
+            proof: CommitmentProofBytes::try_from(self.proof.to_vec())
+                .map_err(|e| BindingError::decode("CommitmentProofBytes", e))?,
+            root: CommitmentRoot::from_bytes(self.root),
+            path: Path::from_str(self.path)?,
+        })
+    }
+}
+
+/// Verifies the proof of the value at the path of the state.
+pub fn verify_membership(state: ProvenState<'_>, value: &[u8]) -> Result<(), BindingError> {
+    let state = state.decode()?;
+
+    tm_verify_membership(
+        &state.client_state,
+        &state.prefix,
+        &state.proof,
+        &state.root,
+        state.path,
+        value.to_vec(),
+    )?;
+
+    Ok(())
+}
+
+/// Verifies the proof of the absence of a value at the path of the state.
+pub fn verify_non_membership(state: ProvenState<'_>) -> Result<(), BindingError> {
+    let state = state.decode()?;
+
+    tm_verify_non_membership(
+        &state.client_state,
+        &state.prefix,
+        &state.proof,
+        &state.root,
+        state.path,
+    )?;
+
+    Ok(())
+}
+
+/// Verifies the header against the trusted client state and consensus state,
+/// as of `now`, in nanoseconds since the Unix epoch.
+pub fn verify_tendermint_header(
+    client_state: &[u8],
+    trusted_consensus_state: &[u8],
+    header: &[u8],
+    now: u64,
+) -> Result<(), BindingError> {
+    let client_state = decode_any::<TmClientState>("ClientState", client_state)?;
+    let trusted_consensus_state =
+        decode_any::<TmConsensusState>("ConsensusState", trusted_consensus_state)?;
+    let header = decode_any::<TmHeader>("Header", header)?;
+    let now = Timestamp::from_nanoseconds(now).map_err(|e| BindingError::decode("Timestamp", e))?;
+
+    verify_header_with_trusted_state(
+        &client_state,
+        &trusted_consensus_state,
+        &header,
+        now,
+        &DefaultVerifier,
+    )?;
+
+    Ok(())
+}
+
+/// Decodes the protobuf encoding of the `Any` of a `T`.
+fn decode_any<T>(type_name: &'static str, bytes: &[u8]) -> Result<T, BindingError>
+where
+    T: TryFrom<Any>,
+    T::Error: core::fmt::Display,
+{
+    let any = Any::decode(bytes).map_err(|e| BindingError::decode(type_name, e))?;
+
+    T::try_from(any).map_err(|e| BindingError::decode(type_name, e))
+}
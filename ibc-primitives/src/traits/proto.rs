@@ -2,6 +2,7 @@ use core::fmt::Display;
 
 use ibc_proto::google::protobuf::Any;
 use ibc_proto::Protobuf;
+use prost::encoding::{decode_key, decode_varint, encoded_len_varint, WireType};
 
 use crate::prelude::*;
 
@@ -42,3 +43,411 @@ impl<T: prost::Message> ToVec for T {
         self.encode_to_vec()
     }
 }
+
+/// Errors raised when strictly decoding a raw Protobuf message.
+#[derive(Debug, displaydoc::Display)]
+pub enum StrictDecodingError {
+    /// failed to decode the message: `{description}`
+    Decode { description: String },
+    /// message of type `{type_name}` contains unknown fields
+    UnknownFields { type_name: &'static str },
+    /// failed to convert the decoded message: `{description}`
+    Conversion { description: String },
+}
+
+impl core::error::Error for StrictDecodingError {}
+
+/// Decodes the raw message `P`, rejecting the `bytes` if they contain fields
+/// unknown to `P`.
+///
+/// Unknown fields are silently dropped by `prost`, whereas other
+/// implementations preserve them, so that chains accepting such messages in
+/// consensus-critical paths may diverge. The fields are detected by
+/// comparing the `bytes` against the re-encoding of the decoded message.
+/// Known fields explicitly set to their default values are accepted, as they
+/// are merely omitted by the re-encoding, but so are unknown fields holding
+/// default values, which cannot be told apart from them. Non-canonical
+/// encodings, such as unpacked repeated scalars, are rejected as well.
+pub fn decode_known_fields<P>(bytes: &[u8]) -> Result<P, StrictDecodingError>
+where
+    P: prost::Message + Default,
+{
+    let raw = P::decode(bytes).map_err(|e| StrictDecodingError::Decode {
+        description: e.to_string(),
+    })?;
+
+    if !retains_fields(bytes, &raw.encode_to_vec()) {
+        return Err(StrictDecodingError::UnknownFields {
+            type_name: core::any::type_name::<P>(),
+        });
+    }
+
+    Ok(raw)
+}
+
+/// Extends [`Protobuf`] with decoding that rejects unknown fields, meant for
+/// the consensus-critical types, such as the client states, the headers and
+/// the packets.
+pub trait StrictProtobuf<P>: Protobuf<P>
+where
+    P: From<Self> + prost::Message + Default,
+    <Self as TryFrom<P>>::Error: Display,
+{
+    /// Decodes the type from `bytes`, rejecting any unknown fields.
+    fn decode_vec_strict(bytes: &[u8]) -> Result<Self, StrictDecodingError> {
+        let raw = decode_known_fields::<P>(bytes)?;
+
+        Self::try_from(raw).map_err(|e| StrictDecodingError::Conversion {
+            description: e.to_string(),
+        })
+    }
+
+    /// Decodes the type from `bytes`, rejecting any unknown fields only if
+    /// the `strict-decoding` feature is enabled.
+    ///
+    /// Used by the consensus-critical decoding of this library, so that the
+    /// feature, once enabled by any crate of a build, applies to all of it.
+    fn decode_vec_critical(bytes: &[u8]) -> Result<Self, StrictDecodingError> {
+        if cfg!(feature = "strict-decoding") {
+            Self::decode_vec_strict(bytes)
+        } else {
+            Self::decode_vec(bytes).map_err(|e| StrictDecodingError::Decode {
+                description: e.to_string(),
+            })
+        }
+    }
+}
+
+impl<T, P> StrictProtobuf<P> for T
+where
+    T: Protobuf<P>,
+    P: From<Self> + prost::Message + Default,
+    <Self as TryFrom<P>>::Error: Display,
+{
+}
+
+/// Returns the canonical encoding of the raw message `P`, i.e. the encoding
+/// the values hashed or signed over, such as the proven states and the sign
+/// bytes, must reproduce byte for byte across implementations.
+///
+/// Like gogoproto, which ibc-go encodes with, `prost` encodes the fields in
+/// the order of their numbers, packs the repeated scalars, and omits the
+/// scalars holding their default values, while the domain types always set
+/// the nested messages that gogoproto marks as non-nullable. The encoding is
+/// checked against [`is_canonical`] in debug builds.
+pub fn encode_canonical<P>(raw: &P) -> Vec<u8>
+where
+    P: prost::Message,
+{
+    let bytes = raw.encode_to_vec();
+
+    debug_assert!(
+        is_canonical(&bytes),
+        "non-canonical encoding of `{}`",
+        core::any::type_name::<P>()
+    );
+
+    bytes
+}
+
+/// Returns whether the top level of the encoded message is canonical: its
+/// fields are in increasing order of their numbers, with the elements of the
+/// repeated fields contiguous, its varints are minimally encoded, and none of
+/// its scalars holds its default value.
+///
+/// The nested messages cannot be told apart from the bytes and strings
+/// without their schema, and are thus left unchecked. The empty
+/// length-delimited fields are accepted, as gogoproto encodes the empty
+/// non-nullable nested messages.
+pub fn is_canonical(mut bytes: &[u8]) -> bool {
+    let mut last_tag = 0;
+
+    while !bytes.is_empty() {
+        let start = bytes;
+        let Ok((tag, wire_type)) = decode_key(&mut bytes) else {
+            return false;
+        };
+        let key = (u64::from(tag) << 3) | wire_type as u64;
+        if start.len() - bytes.len() != encoded_len_varint(key) || tag < last_tag {
+            return false;
+        }
+        last_tag = tag;
+
+        let is_default = match wire_type {
+            WireType::Varint => match minimal_varint(&mut bytes) {
+                Some(value) => value == 0,
+                None => return false,
+            },
+            WireType::SixtyFourBit | WireType::ThirtyTwoBit => {
+                let len = if wire_type == WireType::SixtyFourBit {
+                    8
+                } else {
+                    4
+                };
+                match take(&mut bytes, len) {
+                    Some(value) => value.iter().all(|b| *b == 0),
+                    None => return false,
+                }
+            }
+            WireType::LengthDelimited => {
+                let len = minimal_varint(&mut bytes).and_then(|len| usize::try_from(len).ok());
+                if len.and_then(|len| take(&mut bytes, len)).is_none() {
+                    return false;
+                }
+                false
+            }
+            WireType::StartGroup | WireType::EndGroup => return false,
+        };
+
+        if is_default {
+            return false;
+        }
+    }
+
+    true
+}
+
+/// Decodes a varint, or returns `None` if it is malformed or not minimally
+/// encoded.
+fn minimal_varint(bytes: &mut &[u8]) -> Option<u64> {
+    let start = *bytes;
+    let value = decode_varint(bytes).ok()?;
+
+    (start.len() - bytes.len() == encoded_len_varint(value)).then_some(value)
+}
+
+fn take<'a>(bytes: &mut &'a [u8], len: usize) -> Option<&'a [u8]> {
+    if bytes.len() < len {
+        return None;
+    }
+
+    let (value, rest) = bytes.split_at(len);
+    *bytes = rest;
+
+    Some(value)
+}
+
+/// Extends [`Protobuf`] with the canonical encoding, meant for the values
+/// hashed or signed over, such as the channel and connection ends proven to
+/// the counterparty and the sign bytes of the solo machines.
+pub trait CanonicalProtobuf<P>: Protobuf<P>
+where
+    P: From<Self> + prost::Message + Default,
+    <Self as TryFrom<P>>::Error: Display,
+{
+    /// Encodes the type as [`encode_canonical`] does.
+    fn encode_vec_canonical(self) -> Vec<u8> {
+        encode_canonical(&P::from(self))
+    }
+}
+
+impl<T, P> CanonicalProtobuf<P> for T
+where
+    T: Protobuf<P>,
+    P: From<Self> + prost::Message + Default,
+    <Self as TryFrom<P>>::Error: Display,
+{
+}
+
+/// A field of an encoded Protobuf message, with its raw value.
+struct WireField<'a> {
+    tag: u32,
+    wire_type: WireType,
+    value: &'a [u8],
+}
+
+impl WireField<'_> {
+    fn is_default(&self) -> bool {
+        match self.wire_type {
+            WireType::Varint => self.value == [0],
+            WireType::SixtyFourBit | WireType::ThirtyTwoBit => self.value.iter().all(|b| *b == 0),
+            WireType::LengthDelimited => self.value.is_empty(),
+            WireType::StartGroup | WireType::EndGroup => false,
+        }
+    }
+}
+
+/// Splits the encoded message into its fields, or returns `None` if the
+/// bytes are malformed or contain the deprecated groups.
+fn wire_fields(mut bytes: &[u8]) -> Option<Vec<WireField<'_>>> {
+    let mut fields = Vec::new();
+
+    while !bytes.is_empty() {
+        let (tag, wire_type) = decode_key(&mut bytes).ok()?;
+
+        let len = match wire_type {
+            WireType::Varint => {
+                let start = bytes;
+                decode_varint(&mut bytes).ok()?;
+                let value = &start[..start.len() - bytes.len()];
+                fields.push(WireField {
+                    tag,
+                    wire_type,
+                    value,
+                });
+                continue;
+            }
+            WireType::SixtyFourBit => 8,
+            WireType::ThirtyTwoBit => 4,
+            WireType::LengthDelimited => usize::try_from(decode_varint(&mut bytes).ok()?).ok()?,
+            WireType::StartGroup | WireType::EndGroup => return None,
+        };
+
+        if bytes.len() < len {
+            return None;
+        }
+        let (value, rest) = bytes.split_at(len);
+        bytes = rest;
+
+        fields.push(WireField {
+            tag,
+            wire_type,
+            value,
+        });
+    }
+
+    Some(fields)
+}
+
+/// Returns whether the `reencoded` message retains all the fields of the
+/// `input`, bar those holding default values.
+///
+/// The length-delimited fields are compared recursively, as they may hold
+/// nested messages with unknown fields of their own.
+fn retains_fields(input: &[u8], reencoded: &[u8]) -> bool {
+    if input == reencoded {
+        return true;
+    }
+
+    let (Some(input), Some(reencoded)) = (wire_fields(input), wire_fields(reencoded)) else {
+        return false;
+    };
+
+    let mut input_by_tag = BTreeMap::<u32, Vec<WireField<'_>>>::new();
+    for field in input {
+        input_by_tag.entry(field.tag).or_default().push(field);
+    }
+    let mut reencoded_by_tag = BTreeMap::<u32, Vec<WireField<'_>>>::new();
+    for field in reencoded {
+        reencoded_by_tag.entry(field.tag).or_default().push(field);
+    }
+
+    if reencoded_by_tag
+        .keys()
+        .any(|tag| !input_by_tag.contains_key(tag))
+    {
+        return false;
+    }
+
+    input_by_tag.iter().all(|(tag, fields)| {
+        let Some(reencoded_fields) = reencoded_by_tag.get(tag) else {
+            return fields.iter().all(WireField::is_default);
+        };
+
+        fields.len() == reencoded_fields.len()
+            && fields
+                .iter()
+                .zip(reencoded_fields)
+                .all(|(field, reencoded)| {
+                    field.wire_type == reencoded.wire_type
+                        && (field.value == reencoded.value
+                            || (field.wire_type == WireType::LengthDelimited
+                                && retains_fields(field.value, reencoded.value)))
+                })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use ibc_proto::ibc::core::client::v1::Height as RawHeight;
+    use prost::Message;
+
+    use super::*;
+
+    #[test]
+    fn decode_known_fields_of_height() {
+        let height = RawHeight {
+            revision_number: 1,
+            revision_height: 10,
+        };
+        let bytes = height.encode_to_vec();
+        assert_eq!(decode_known_fields::<RawHeight>(&bytes).unwrap(), height);
+
+        // Known fields set to their default values are accepted
+        let explicit_default = [0x08, 0x00, 0x10, 0x0a];
+        assert!(decode_known_fields::<RawHeight>(&explicit_default).is_ok());
+
+        // An unknown varint field, tagged with the field number 5
+        let mut unknown_varint = bytes.clone();
+        unknown_varint.extend([0x28, 0x01]);
+        assert!(matches!(
+            decode_known_fields::<RawHeight>(&unknown_varint),
+            Err(StrictDecodingError::UnknownFields { .. })
+        ));
+        assert!(RawHeight::decode(unknown_varint.as_slice()).is_ok());
+
+        // An unknown length-delimited field, tagged with the field number 3
+        let mut unknown_bytes = bytes;
+        unknown_bytes.extend([0x1a, 0x02, 0xde, 0xad]);
+        assert!(decode_known_fields::<RawHeight>(&unknown_bytes).is_err());
+    }
+
+    #[test]
+    fn canonical_encoding_of_height() {
+        let height = RawHeight {
+            revision_number: 1,
+            revision_height: 10,
+        };
+        assert_eq!(encode_canonical(&height), [0x08, 0x01, 0x10, 0x0a]);
+        assert!(is_canonical(&[]));
+
+        // A field explicitly set to its default value
+        assert!(!is_canonical(&[0x08, 0x00, 0x10, 0x0a]));
+        // Fields out of the order of their numbers
+        assert!(!is_canonical(&[0x10, 0x0a, 0x08, 0x01]));
+        // A varint which is not minimally encoded
+        assert!(!is_canonical(&[0x08, 0x81, 0x00]));
+        // A truncated field
+        assert!(!is_canonical(&[0x08]));
+        // The empty nested messages are kept by gogoproto
+        assert!(is_canonical(&[0x0a, 0x00]));
+    }
+
+    #[test]
+    fn decode_known_fields_of_nested_message() {
+        use ibc_proto::ibc::core::client::v1::MsgUpdateClient as RawMsgUpdateClient;
+
+        let msg = RawMsgUpdateClient {
+            client_id: "07-tendermint-0".to_string(),
+            client_message: Some(Any {
+                type_url: "/test".to_string(),
+                value: vec![1, 2, 3],
+            }),
+            signer: "signer".to_string(),
+        };
+        let bytes = msg.encode_to_vec();
+        assert_eq!(
+            decode_known_fields::<RawMsgUpdateClient>(&bytes).unwrap(),
+            msg
+        );
+
+        // An unknown field within the nested `Any`, tagged with the field
+        // number 7
+        let mut any = msg.client_message.clone().unwrap().encode_to_vec();
+        any.extend([0x38, 0x01]);
+        let mut nested = RawMsgUpdateClient {
+            client_message: None,
+            ..msg
+        }
+        .encode_to_vec();
+        nested.push(0x12);
+        nested.push(u8::try_from(any.len()).unwrap());
+        nested.extend(any);
+
+        assert!(RawMsgUpdateClient::decode(nested.as_slice()).is_ok());
+        assert!(matches!(
+            decode_known_fields::<RawMsgUpdateClient>(&nested),
+            Err(StrictDecodingError::UnknownFields { .. })
+        ));
+    }
+}
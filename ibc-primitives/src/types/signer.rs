@@ -20,6 +20,18 @@ use crate::prelude::*;
 #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Display)]
 pub struct Signer(String);
 
+impl Signer {
+    /// Checks that the signer is a well-formed address of the given format.
+    ///
+    /// The signer is not validated on construction, as the address formats
+    /// are host-specific. Hosts may call this from their
+    /// `ValidationContext::validate_message_signer` to reject malformed
+    /// addresses before any message is processed.
+    pub fn validate(&self, format: &SignerFormat) -> Result<(), SignerError> {
+        format.validate(self.as_ref())
+    }
+}
+
 impl From<String> for Signer {
     fn from(s: String) -> Self {
         Self(s)
@@ -31,3 +43,326 @@ impl AsRef<str> for Signer {
         self.0.as_str()
     }
 }
+
+/// The address formats a [`Signer`] may be validated against.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SignerFormat {
+    /// A Bech32 address with the given human-readable part, e.g. `cosmos`,
+    /// as used by the Cosmos SDK chains.
+    Bech32 { hrp: String },
+    /// A `0x`-prefixed, hex-encoded 20-byte address, as used by the EVM
+    /// chains. The mixed-case checksum of EIP-55 is not verified.
+    EvmHex,
+    /// A base58-encoded 32-byte public key, as used by Solana.
+    SolanaBase58,
+}
+
+impl SignerFormat {
+    /// Length in bytes of the EVM addresses.
+    pub const EVM_ADDRESS_LEN: usize = 20;
+
+    /// Length in bytes of the Solana public keys.
+    pub const SOLANA_ADDRESS_LEN: usize = 32;
+
+    /// Returns the Bech32 format with the given human-readable part.
+    pub fn bech32(hrp: impl Into<String>) -> Self {
+        Self::Bech32 { hrp: hrp.into() }
+    }
+
+    /// Checks that the given address is well-formed in this format.
+    pub fn validate(&self, address: &str) -> Result<(), SignerError> {
+        if address.is_empty() {
+            return Err(SignerError::EmptySigner);
+        }
+
+        match self {
+            Self::Bech32 { hrp } => validate_bech32(address, hrp),
+            Self::EvmHex => validate_evm_hex(address),
+            Self::SolanaBase58 => validate_solana_base58(address),
+        }
+    }
+}
+
+#[derive(Debug, Display)]
+pub enum SignerError {
+    /// empty signer
+    EmptySigner,
+    /// invalid bech32 address: `{reason}`
+    InvalidBech32Address { reason: String },
+    /// mismatched bech32 prefix: expected `{expected}`, actual `{actual}`
+    MismatchedBech32Prefix { expected: String, actual: String },
+    /// invalid EVM hex address: `{reason}`
+    InvalidEvmHexAddress { reason: String },
+    /// invalid Solana base58 address: `{reason}`
+    InvalidSolanaBase58Address { reason: String },
+}
+
+impl core::error::Error for SignerError {}
+
+/// The characters of the data part of the Bech32 encoding, by their value.
+const BECH32_CHARSET: &[u8; 32] = b"qpzry9x8gf2tvdw0s3jn54khce6mua7l";
+
+/// The generators of the BCH code of the Bech32 checksums.
+const BECH32_GENERATORS: [u32; 5] = [
+    0x3b6a_57b2,
+    0x2650_8e6d,
+    0x1ea1_19fa,
+    0x3d42_33dd,
+    0x2a14_62b3,
+];
+
+/// Length of the checksum closing the data part of a Bech32 address.
+const BECH32_CHECKSUM_LEN: usize = 6;
+
+/// Validates a Bech32 address, as specified by [BIP-173], except for its
+/// 90-character limit, which the Cosmos SDK does not enforce either.
+///
+/// [BIP-173]: https://github.com/bitcoin/bips/blob/master/bip-0173.mediawiki
+fn validate_bech32(address: &str, expected_hrp: &str) -> Result<(), SignerError> {
+    let invalid = |reason: &str| SignerError::InvalidBech32Address {
+        reason: format!("`{address}`: {reason}"),
+    };
+
+    if !address.bytes().all(|b| (33..=126).contains(&b)) {
+        return Err(invalid("contains characters out of the US-ASCII range"));
+    }
+
+    if address.bytes().any(|b| b.is_ascii_lowercase())
+        && address.bytes().any(|b| b.is_ascii_uppercase())
+    {
+        return Err(invalid("mixes lowercase and uppercase characters"));
+    }
+
+    let address = address.to_ascii_lowercase();
+
+    let (hrp, data) = address
+        .rsplit_once('1')
+        .ok_or_else(|| invalid("missing separator"))?;
+
+    if hrp.is_empty() {
+        return Err(invalid("empty human-readable part"));
+    }
+
+    if hrp != expected_hrp {
+        return Err(SignerError::MismatchedBech32Prefix {
+            expected: expected_hrp.to_string(),
+            actual: hrp.to_string(),
+        });
+    }
+
+    if data.len() < BECH32_CHECKSUM_LEN {
+        return Err(invalid("data part too short"));
+    }
+
+    let data = data
+        .bytes()
+        .map(|c| {
+            BECH32_CHARSET
+                .iter()
+                .position(|&v| v == c)
+                .map(|v| v as u8)
+                .ok_or_else(|| invalid("invalid character in data part"))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let values = hrp
+        .bytes()
+        .map(|c| c >> 5)
+        .chain(core::iter::once(0))
+        .chain(hrp.bytes().map(|c| c & 0x1f))
+        .chain(data);
+
+    if bech32_polymod(values) != 1 {
+        return Err(invalid("invalid checksum"));
+    }
+
+    Ok(())
+}
+
+fn bech32_polymod(values: impl Iterator<Item = u8>) -> u32 {
+    values.fold(1, |chk, value| {
+        let top = chk >> 25;
+
+        BECH32_GENERATORS
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| (top >> i) & 1 == 1)
+            .fold(
+                ((chk & 0x01ff_ffff) << 5) ^ u32::from(value),
+                |chk, (_, g)| chk ^ g,
+            )
+    })
+}
+
+fn validate_evm_hex(address: &str) -> Result<(), SignerError> {
+    let invalid = |reason: &str| SignerError::InvalidEvmHexAddress {
+        reason: format!("`{address}`: {reason}"),
+    };
+
+    let digits = address
+        .strip_prefix("0x")
+        .ok_or_else(|| invalid("missing `0x` prefix"))?;
+
+    if digits.len() != 2 * SignerFormat::EVM_ADDRESS_LEN {
+        return Err(invalid(&format!(
+            "expected {} hex digits, got {}",
+            2 * SignerFormat::EVM_ADDRESS_LEN,
+            digits.len()
+        )));
+    }
+
+    if !digits.bytes().all(|b| b.is_ascii_hexdigit()) {
+        return Err(invalid("invalid hex digit"));
+    }
+
+    Ok(())
+}
+
+/// The characters of the base58 encoding used by Bitcoin and Solana, by
+/// their value.
+const BASE58_ALPHABET: &[u8; 58] = b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+
+fn validate_solana_base58(address: &str) -> Result<(), SignerError> {
+    let invalid = |reason: &str| SignerError::InvalidSolanaBase58Address {
+        reason: format!("`{address}`: {reason}"),
+    };
+
+    // The decoded number, in little-endian order
+    let mut bytes: Vec<u8> = Vec::with_capacity(SignerFormat::SOLANA_ADDRESS_LEN);
+
+    for c in address.bytes() {
+        let mut carry = BASE58_ALPHABET
+            .iter()
+            .position(|&v| v == c)
+            .ok_or_else(|| invalid("invalid base58 character"))? as u32;
+
+        for byte in bytes.iter_mut() {
+            carry += u32::from(*byte) * 58;
+            *byte = carry as u8;
+            carry >>= 8;
+        }
+
+        while carry > 0 {
+            bytes.push(carry as u8);
+            carry >>= 8;
+        }
+
+        if bytes.len() > SignerFormat::SOLANA_ADDRESS_LEN {
+            return Err(invalid("decodes to more than 32 bytes"));
+        }
+    }
+
+    // Each leading `1` encodes a leading zero byte
+    let leading_zeros = address.bytes().take_while(|&c| c == b'1').count();
+    let len = leading_zeros + bytes.len();
+
+    if len != SignerFormat::SOLANA_ADDRESS_LEN {
+        return Err(invalid(&format!(
+            "expected {} bytes, got {len}",
+            SignerFormat::SOLANA_ADDRESS_LEN
+        )));
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn signer(address: &str) -> Signer {
+        Signer::from(address.to_string())
+    }
+
+    #[test]
+    fn test_bech32_signers() {
+        let format = SignerFormat::bech32("cosmos");
+
+        let address = "cosmos10d07y265gmmuvt4z0w9aw880jnsr700j6zn9kn";
+        assert!(signer(address).validate(&format).is_ok());
+        assert!(signer(&address.to_uppercase()).validate(&format).is_ok());
+
+        assert!(matches!(
+            signer("osmo10d07y265gmmuvt4z0w9aw880jnsr700jjeq4qp").validate(&format),
+            Err(SignerError::MismatchedBech32Prefix { expected, actual })
+                if expected == "cosmos" && actual == "osmo"
+        ));
+
+        for address in [
+            // Invalid checksum
+            "cosmos10d07y265gmmuvt4z0w9aw880jnsr700j6zn9km",
+            // Mixed case
+            "cosmos10d07y265gmmuvt4z0w9aw880jnsr700j6zN9kn",
+            // Invalid `b` in the data part
+            "cosmos10d07y265gmmuvt4z0w9aw880jnsr700j6zn9kb",
+            // Missing separator
+            "cosmos0d07y265gmmuvt4z0w9aw880jnsr700j6zn9kn",
+            // Too short data part
+            "cosmos1qpzry",
+        ] {
+            assert!(matches!(
+                signer(address).validate(&format),
+                Err(SignerError::InvalidBech32Address { .. })
+            ));
+        }
+
+        assert!(matches!(
+            signer("").validate(&format),
+            Err(SignerError::EmptySigner)
+        ));
+    }
+
+    #[test]
+    fn test_evm_hex_signers() {
+        let format = SignerFormat::EvmHex;
+
+        assert!(signer("0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed")
+            .validate(&format)
+            .is_ok());
+
+        for address in [
+            // Missing prefix
+            "5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed",
+            // Too short
+            "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeA",
+            // Too long
+            "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed00",
+            // Invalid digit
+            "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAeg",
+        ] {
+            assert!(matches!(
+                signer(address).validate(&format),
+                Err(SignerError::InvalidEvmHexAddress { .. })
+            ));
+        }
+    }
+
+    #[test]
+    fn test_solana_base58_signers() {
+        let format = SignerFormat::SolanaBase58;
+
+        for address in [
+            "11111111111111111111111111111111",
+            "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA",
+            "2gPihUTjt3FJqf1VpidgrY5cZ6PuyMccGVwQHRfjMPZG",
+        ] {
+            assert!(signer(address).validate(&format).is_ok());
+        }
+
+        for address in [
+            // 31 zero bytes
+            "1111111111111111111111111111111",
+            // Invalid `0`
+            "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ50A",
+            // Too long
+            "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DAz",
+            // A Cosmos address
+            "cosmos10d07y265gmmuvt4z0w9aw880jnsr700j6zn9kn",
+        ] {
+            assert!(matches!(
+                signer(address).validate(&format),
+                Err(SignerError::InvalidSolanaBase58Address { .. })
+            ));
+        }
+    }
+}
@@ -126,6 +126,16 @@ impl Timestamp {
         }
     }
 
+    /// Builds a `Timestamp` from the whole seconds since the Unix epoch, as
+    /// the protocol value of 0 again indicates that it is not set.
+    pub fn from_seconds(seconds: u64) -> Result<Self, ParseTimestampError> {
+        let nanoseconds = seconds.checked_mul(1_000_000_000).ok_or_else(|| {
+            ParseTimestampError::DataOutOfRange(format!("{seconds} seconds since the Unix epoch"))
+        })?;
+
+        Self::from_nanoseconds(nanoseconds)
+    }
+
     /// Returns a `Timestamp` representation of the current time.
     #[cfg(feature = "std")]
     pub fn now() -> Self {
@@ -213,6 +223,93 @@ impl Timestamp {
     pub fn is_set(&self) -> bool {
         self.time.is_some()
     }
+
+    /// Convert a `Timestamp` to `u64` value in whole seconds since the Unix
+    /// epoch. If no timestamp is set, the result is 0.
+    pub fn seconds(self) -> u64 {
+        self.nanoseconds() / 1_000_000_000
+    }
+
+    /// Returns the timestamp `duration` after this one, or `None` if it does
+    /// not fit in the `u64` nanoseconds of the protocol. A timestamp not set
+    /// stays so.
+    pub fn checked_add(self, duration: Duration) -> Option<Self> {
+        if !self.is_set() {
+            return Some(self);
+        }
+
+        let nanoseconds = u64::try_from(duration.as_nanos()).ok()?;
+
+        Self::from_nanoseconds(self.nanoseconds().checked_add(nanoseconds)?).ok()
+    }
+
+    /// Returns the timestamp `duration` before this one, or `None` if it is
+    /// not after the Unix epoch, which the protocol value of 0 stands for. A
+    /// timestamp not set stays so.
+    pub fn checked_sub(self, duration: Duration) -> Option<Self> {
+        if !self.is_set() {
+            return Some(self);
+        }
+
+        let nanoseconds = u64::try_from(duration.as_nanos()).ok()?;
+
+        match self.nanoseconds().checked_sub(nanoseconds)? {
+            0 => None,
+            nanoseconds => Self::from_nanoseconds(nanoseconds).ok(),
+        }
+    }
+
+    /// Returns the timestamp `duration` after this one, saturating at the
+    /// greatest timestamp of the protocol. A timestamp not set stays so.
+    pub fn saturating_add(self, duration: Duration) -> Self {
+        self.checked_add(duration)
+            .unwrap_or_else(|| Self::from_nanoseconds(u64::MAX).expect("Never fails"))
+    }
+
+    /// Returns the timestamp `duration` before this one, saturating at the
+    /// nanosecond after the Unix epoch, as the epoch itself stands for a
+    /// timestamp not set. A timestamp not set stays so.
+    pub fn saturating_sub(self, duration: Duration) -> Self {
+        self.checked_sub(duration)
+            .unwrap_or_else(|| Self::from_nanoseconds(1).expect("Never fails"))
+    }
+
+    /// Computes the duration elapsed from the `other` timestamp to this one,
+    /// which is zero if the `other` one is more advanced or if either of the
+    /// timestamps is not set.
+    pub fn saturating_duration_since(&self, other: &Self) -> Duration {
+        self.duration_since(other).unwrap_or(ZERO_DURATION)
+    }
+
+    /// Checks whether both timestamps are set and this one is strictly after
+    /// the `other` one.
+    ///
+    /// Unlike the derived ordering, in which a timestamp not set comes before
+    /// any other, a timestamp not set is neither before nor after any other.
+    pub fn is_after(&self, other: &Self) -> bool {
+        matches!((self.time, other.time), (Some(time1), Some(time2)) if time1 > time2)
+    }
+
+    /// Checks whether both timestamps are set and this one is strictly
+    /// before the `other` one. See [`Timestamp::is_after`].
+    pub fn is_before(&self, other: &Self) -> bool {
+        other.is_after(self)
+    }
+
+    /// Convert a `Timestamp` to an optional [`chrono::DateTime`] in UTC.
+    #[cfg(feature = "chrono")]
+    pub fn into_chrono(self) -> Option<chrono::DateTime<chrono::Utc>> {
+        if !self.is_set() {
+            return None;
+        }
+
+        let nanoseconds = self.nanoseconds();
+
+        chrono::DateTime::from_timestamp(
+            i64::try_from(nanoseconds / 1_000_000_000).ok()?,
+            (nanoseconds % 1_000_000_000) as u32,
+        )
+    }
 }
 
 impl Display for Timestamp {
@@ -232,8 +329,7 @@ pub enum TimestampOverflowError {
     TimestampOverflow,
 }
 
-#[cfg(feature = "std")]
-impl std::error::Error for TimestampOverflowError {}
+impl core::error::Error for TimestampOverflowError {}
 
 impl Add<Duration> for Timestamp {
     type Output = Result<Self, TimestampOverflowError>;
@@ -267,9 +363,8 @@ pub enum ParseTimestampError {
     DataOutOfRange(String),
 }
 
-#[cfg(feature = "std")]
-impl std::error::Error for ParseTimestampError {
-    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+impl core::error::Error for ParseTimestampError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
         match &self {
             Self::ParseInt(e) => Some(e),
             Self::DataOutOfRange(_) => None,
@@ -295,6 +390,38 @@ impl From<Time> for Timestamp {
     }
 }
 
+/// Converts the nanoseconds since the Unix epoch of a date time, which must
+/// be after the epoch, as the protocol value of 0 stands for a timestamp not
+/// set.
+fn from_unix_timestamp_nanos(nanoseconds: i128) -> Result<Timestamp, ParseTimestampError> {
+    match u64::try_from(nanoseconds) {
+        Ok(nanoseconds) if nanoseconds > 0 => Timestamp::from_nanoseconds(nanoseconds),
+        _ => Err(ParseTimestampError::DataOutOfRange(format!(
+            "{nanoseconds} nanoseconds since the Unix epoch"
+        ))),
+    }
+}
+
+impl TryFrom<OffsetDateTime> for Timestamp {
+    type Error = ParseTimestampError;
+
+    fn try_from(date_time: OffsetDateTime) -> Result<Self, Self::Error> {
+        from_unix_timestamp_nanos(date_time.unix_timestamp_nanos())
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl TryFrom<chrono::DateTime<chrono::Utc>> for Timestamp {
+    type Error = ParseTimestampError;
+
+    fn try_from(date_time: chrono::DateTime<chrono::Utc>) -> Result<Self, Self::Error> {
+        let nanoseconds = i128::from(date_time.timestamp()) * 1_000_000_000
+            + i128::from(date_time.timestamp_subsec_nanos());
+
+        from_unix_timestamp_nanos(nanoseconds)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use core::time::Duration;
@@ -356,6 +483,82 @@ mod tests {
         assert_eq!(time0, (time0 - duration).unwrap());
     }
 
+    #[test]
+    fn test_timestamp_checked_arithmetic() {
+        let time0 = Timestamp::none();
+        let time1 = Timestamp::from_nanoseconds(100).unwrap();
+        let max = Timestamp::from_nanoseconds(u64::MAX).unwrap();
+        let duration = Duration::from_nanos(50);
+
+        assert_eq!(time1.checked_add(duration).unwrap().nanoseconds(), 150);
+        assert_eq!(time1.checked_sub(duration).unwrap().nanoseconds(), 50);
+        assert_eq!(time0.checked_add(duration), Some(time0));
+        assert_eq!(time0.checked_sub(duration), Some(time0));
+
+        // The Unix epoch stands for a timestamp not set.
+        assert_eq!(time1.checked_sub(Duration::from_nanos(100)), None);
+        assert_eq!(max.checked_add(Duration::from_nanos(1)), None);
+
+        assert_eq!(max.saturating_add(duration), max);
+        assert_eq!(
+            time1.saturating_sub(Duration::from_secs(1)).nanoseconds(),
+            1
+        );
+        assert_eq!(time0.saturating_add(duration), time0);
+    }
+
+    #[test]
+    fn test_timestamp_comparison_helpers() {
+        let time0 = Timestamp::none();
+        let time1 = Timestamp::from_nanoseconds(100).unwrap();
+        let time2 = Timestamp::from_nanoseconds(2_000_000_100).unwrap();
+
+        assert!(time2.is_after(&time1));
+        assert!(time1.is_before(&time2));
+        assert!(!time1.is_after(&time1));
+
+        // Unlike with the derived ordering, a timestamp not set is not
+        // before the others.
+        assert!(time0 < time1);
+        assert!(!time0.is_before(&time1));
+        assert!(!time1.is_after(&time0));
+
+        assert_eq!(
+            time2.saturating_duration_since(&time1),
+            Duration::from_secs(2)
+        );
+        assert_eq!(time1.saturating_duration_since(&time2), ZERO_DURATION);
+        assert_eq!(time2.saturating_duration_since(&time0), ZERO_DURATION);
+        assert_eq!(time2.seconds(), 2);
+        assert_eq!(
+            Timestamp::from_seconds(2).unwrap().nanoseconds(),
+            2_000_000_000
+        );
+        assert!(Timestamp::from_seconds(u64::MAX).is_err());
+    }
+
+    #[test]
+    fn test_timestamp_conversions() {
+        let timestamp = Timestamp::from_nanoseconds(1_700_000_000_123_456_789).unwrap();
+
+        let date_time = timestamp.into_datetime().unwrap();
+        assert_eq!(Timestamp::try_from(date_time).unwrap(), timestamp);
+        assert_eq!(
+            Timestamp::from(timestamp.into_tm_time().unwrap()),
+            timestamp
+        );
+
+        assert!(Timestamp::try_from(OffsetDateTime::UNIX_EPOCH).is_err());
+
+        #[cfg(feature = "chrono")]
+        {
+            let date_time = timestamp.into_chrono().unwrap();
+            assert_eq!(date_time.timestamp_subsec_nanos(), 123_456_789);
+            assert_eq!(Timestamp::try_from(date_time).unwrap(), timestamp);
+            assert_eq!(Timestamp::none().into_chrono(), None);
+        }
+    }
+
     #[test]
     fn subtract_compare() {
         let sleep_duration = Duration::from_micros(100);
@@ -0,0 +1,66 @@
+//! Error reporting utilities.
+
+use core::error::Error;
+
+/// Iterates over the error and its chain of sources, from the error itself to
+/// its root cause.
+///
+/// Meant for the hosts to log the errors of the handlers, or to look for the
+/// offending identifiers and heights by downcasting the sources to the error
+/// types of the modules, e.g. `ClientError`.
+pub fn error_chain<'a>(
+    error: &'a (dyn Error + 'static),
+) -> impl Iterator<Item = &'a (dyn Error + 'static)> {
+    core::iter::successors(Some(error), |error| error.source())
+}
+
+/// Returns the last error of the chain of sources of the error, which is the
+/// error itself if it has no source.
+pub fn root_cause<'a>(error: &'a (dyn Error + 'static)) -> &'a (dyn Error + 'static) {
+    error_chain(error).last().unwrap_or(error)
+}
+
+#[cfg(test)]
+mod tests {
+    use core::fmt::{Display, Formatter, Result as FmtResult};
+
+    use super::*;
+    use crate::prelude::*;
+
+    #[derive(Debug)]
+    struct Inner;
+
+    impl Display for Inner {
+        fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+            write!(f, "inner")
+        }
+    }
+
+    impl Error for Inner {}
+
+    #[derive(Debug)]
+    struct Outer(Inner);
+
+    impl Display for Outer {
+        fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+            write!(f, "outer: {}", self.0)
+        }
+    }
+
+    impl Error for Outer {
+        fn source(&self) -> Option<&(dyn Error + 'static)> {
+            Some(&self.0)
+        }
+    }
+
+    #[test]
+    fn test_error_chain() {
+        let error = Outer(Inner);
+
+        let chain: Vec<String> = error_chain(&error).map(|e| e.to_string()).collect();
+        assert_eq!(chain, ["outer: inner", "inner"]);
+
+        assert!(root_cause(&error).downcast_ref::<Inner>().is_some());
+        assert!(root_cause(&Inner).downcast_ref::<Inner>().is_some());
+    }
+}
@@ -1,4 +1,6 @@
 //! Contains various internally-used utilities.
+pub mod error;
 pub mod pretty;
 
+pub use error::*;
 pub use pretty::*;
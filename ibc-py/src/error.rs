@@ -0,0 +1,78 @@
+use displaydoc::Display;
+use ibc::core::client::types::error::ClientError;
+use ibc::core::commitment_types::error::CommitmentError;
+use ibc::core::host::types::error::IdentifierError;
+use ibc::core::host::types::path::PathError;
+use pyo3::create_exception;
+use pyo3::exceptions::PyValueError;
+use pyo3::PyErr;
+
+create_exception!(
+    ibc_rs,
+    IbcError,
+    PyValueError,
+    "The base class of the errors of the IBC bindings."
+);
+create_exception!(
+    ibc_rs,
+    DecodeError,
+    IbcError,
+    "Raised when an input fails to parse or decode."
+);
+create_exception!(
+    ibc_rs,
+    VerificationError,
+    IbcError,
+    "Raised when a proof fails to verify."
+);
+
+#[derive(Debug, Display)]
+pub enum BindingError {
+    /// failed to decode `{type_name}`: {description}
+    Decode {
+        type_name: &'static str,
+        description: String,
+    },
+    /// invalid identifier: {0}
+    Identifier(IdentifierError),
+    /// invalid height: {0}
+    Height(ClientError),
+    /// invalid path: {0}
+    Path(PathError),
+    /// unknown proof specs `{specs}`, expected `cosmos`, `iavl` or `tendermint`
+    UnknownSpecs { specs: String },
+    /// verification failed: {0}
+    Verification(CommitmentError),
+}
+
+impl BindingError {
+    pub fn decode(type_name: &'static str, error: impl core::fmt::Display) -> Self {
+        Self::Decode {
+            type_name,
+            description: error.to_string(),
+        }
+    }
+}
+
+impl From<IdentifierError> for BindingError {
+    fn from(e: IdentifierError) -> Self {
+        Self::Identifier(e)
+    }
+}
+
+impl From<PathError> for BindingError {
+    fn from(e: PathError) -> Self {
+        Self::Path(e)
+    }
+}
+
+impl std::error::Error for BindingError {}
+
+impl From<BindingError> for PyErr {
+    fn from(e: BindingError) -> Self {
+        match e {
+            BindingError::Verification(_) => VerificationError::new_err(e.to_string()),
+            _ => DecodeError::new_err(e.to_string()),
+        }
+    }
+}
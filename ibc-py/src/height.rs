@@ -0,0 +1,68 @@
+//! Binds the heights of the chains.
+
+use core::str::FromStr;
+
+use ibc::core::client::types::Height;
+use pyo3::basic::CompareOp;
+use pyo3::prelude::*;
+
+use crate::error::BindingError;
+use crate::identifiers::hash;
+
+/// The height of a chain, as a revision number and a height within the
+/// revision.
+#[pyclass(module = "ibc_rs", name = "Height", frozen)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct PyHeight(pub(crate) Height);
+
+#[pymethods]
+impl PyHeight {
+    #[new]
+    fn new(revision_number: u64, revision_height: u64) -> PyResult<Self> {
+        let height = Height::new(revision_number, revision_height).map_err(BindingError::Height)?;
+
+        Ok(Self(height))
+    }
+
+    /// Parses the height from its `{revision_number}-{revision_height}` form.
+    #[staticmethod]
+    fn parse(height: &str) -> PyResult<Self> {
+        let height = Height::from_str(height).map_err(|e| BindingError::decode("Height", e))?;
+
+        Ok(Self(height))
+    }
+
+    #[getter]
+    fn revision_number(&self) -> u64 {
+        self.0.revision_number()
+    }
+
+    #[getter]
+    fn revision_height(&self) -> u64 {
+        self.0.revision_height()
+    }
+
+    fn increment(&self) -> Self {
+        Self(self.0.increment())
+    }
+
+    fn __str__(&self) -> String {
+        self.0.to_string()
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "Height({}, {})",
+            self.0.revision_number(),
+            self.0.revision_height()
+        )
+    }
+
+    fn __richcmp__(&self, other: &Self, op: CompareOp) -> bool {
+        op.matches(self.0.cmp(&other.0))
+    }
+
+    fn __hash__(&self) -> u64 {
+        hash(self)
+    }
+}
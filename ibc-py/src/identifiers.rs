@@ -0,0 +1,113 @@
+//! Binds the ICS-24 identifiers, which are parsed and validated on creation.
+
+use core::str::FromStr;
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+
+use ibc::core::host::types::identifiers::{
+    ChainId, ChannelId, ClientId, ConnectionId, PortId, Sequence,
+};
+use pyo3::prelude::*;
+
+use crate::error::BindingError;
+
+/// Defines the Python class of a string identifier, which wraps the domain
+/// type of `ibc-rs`.
+macro_rules! string_identifier {
+    ($py_name:ident, $name:literal, $inner:ty) => {
+        #[doc = concat!("The `", $name, "` identifier.")]
+        #[pyclass(module = "ibc_rs", name = $name, frozen)]
+        #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+        pub struct $py_name(pub(crate) $inner);
+
+        #[pymethods]
+        impl $py_name {
+            #[new]
+            fn new(value: &str) -> PyResult<Self> {
+                let identifier = <$inner>::from_str(value).map_err(BindingError::from)?;
+
+                Ok(Self(identifier))
+            }
+
+            fn __str__(&self) -> String {
+                self.0.to_string()
+            }
+
+            fn __repr__(&self) -> String {
+                format!("{}('{}')", $name, self.0)
+            }
+
+            fn __eq__(&self, other: &Self) -> bool {
+                self == other
+            }
+
+            fn __hash__(&self) -> u64 {
+                hash(self)
+            }
+        }
+    };
+}
+
+string_identifier!(PyClientId, "ClientId", ClientId);
+string_identifier!(PyConnectionId, "ConnectionId", ConnectionId);
+string_identifier!(PyChannelId, "ChannelId", ChannelId);
+string_identifier!(PyPortId, "PortId", PortId);
+string_identifier!(PyChainId, "ChainId", ChainId);
+
+#[pymethods]
+impl PyChainId {
+    /// The revision number the chain identifier ends with, if any.
+    #[getter]
+    fn revision_number(&self) -> u64 {
+        self.0.revision_number()
+    }
+}
+
+/// The sequence number of a packet.
+#[pyclass(module = "ibc_rs", name = "Sequence", frozen)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct PySequence(pub(crate) Sequence);
+
+#[pymethods]
+impl PySequence {
+    #[new]
+    fn new(value: u64) -> Self {
+        Self(Sequence::from(value))
+    }
+
+    #[getter]
+    fn value(&self) -> u64 {
+        self.0.value()
+    }
+
+    fn increment(&self) -> Self {
+        Self(self.0.increment())
+    }
+
+    fn __int__(&self) -> u64 {
+        self.0.value()
+    }
+
+    fn __str__(&self) -> String {
+        self.0.to_string()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("Sequence({})", self.0)
+    }
+
+    fn __eq__(&self, other: &Self) -> bool {
+        self == other
+    }
+
+    fn __hash__(&self) -> u64 {
+        hash(self)
+    }
+}
+
+pub(crate) fn hash(value: &impl Hash) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    value.hash(&mut hasher);
+
+    hasher.finish()
+}
@@ -0,0 +1,51 @@
+//! Provides the [pyo3](https://pyo3.rs) bindings of the IBC domain types and
+//! verification of `ibc-rs`, as the `ibc_rs` Python module, for the
+//! monitoring and data teams and the integration-test tooling to parse,
+//! decode and verify the IBC artifacts with the same code as the chains.
+#![forbid(unsafe_code)]
+#![deny(
+    warnings,
+    trivial_numeric_casts,
+    unused_import_braces,
+    unused_qualifications,
+    rust_2018_idioms
+)]
+
+mod error;
+mod height;
+mod identifiers;
+mod msgs;
+mod packet;
+mod proof;
+
+use pyo3::prelude::*;
+
+use crate::error::{DecodeError, IbcError, VerificationError};
+
+#[pymodule]
+fn ibc_rs(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    let py = m.py();
+
+    m.add("IbcError", py.get_type_bound::<IbcError>())?;
+    m.add("DecodeError", py.get_type_bound::<DecodeError>())?;
+    m.add(
+        "VerificationError",
+        py.get_type_bound::<VerificationError>(),
+    )?;
+
+    m.add_class::<identifiers::PyChainId>()?;
+    m.add_class::<identifiers::PyChannelId>()?;
+    m.add_class::<identifiers::PyClientId>()?;
+    m.add_class::<identifiers::PyConnectionId>()?;
+    m.add_class::<identifiers::PyPortId>()?;
+    m.add_class::<identifiers::PySequence>()?;
+    m.add_class::<height::PyHeight>()?;
+    m.add_class::<packet::PyPacket>()?;
+    m.add_class::<msgs::PyMessage>()?;
+
+    m.add_function(wrap_pyfunction!(proof::verify_membership, m)?)?;
+    m.add_function(wrap_pyfunction!(proof::verify_non_membership, m)?)?;
+    m.add_function(wrap_pyfunction!(msgs::decode_message, m)?)?;
+
+    Ok(())
+}
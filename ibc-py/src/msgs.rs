@@ -0,0 +1,75 @@
+//! Binds the decoding of the IBC messages, as routed by the IBC handlers.
+
+use ibc::core::channel::types::msgs::PacketMsg;
+use ibc::core::handler::types::msgs::MsgEnvelope;
+use ibc::primitives::proto::Any;
+use pyo3::prelude::*;
+
+use crate::error::BindingError;
+use crate::packet::PyPacket;
+
+/// A decoded IBC message.
+#[pyclass(module = "ibc_rs", name = "Message", frozen)]
+#[derive(Clone, Debug)]
+pub struct PyMessage {
+    type_url: String,
+    envelope: MsgEnvelope,
+}
+
+#[pymethods]
+impl PyMessage {
+    #[getter]
+    fn type_url(&self) -> &str {
+        &self.type_url
+    }
+
+    /// The kind of the message, among `client`, `connection`, `channel` and
+    /// `packet`.
+    #[getter]
+    fn kind(&self) -> &'static str {
+        match self.envelope {
+            MsgEnvelope::Client(_) => "client",
+            MsgEnvelope::Connection(_) => "connection",
+            MsgEnvelope::Channel(_) => "channel",
+            MsgEnvelope::Packet(_) => "packet",
+        }
+    }
+
+    /// The packet of the packet messages, or `None` for the other messages.
+    #[getter]
+    fn packet(&self) -> Option<PyPacket> {
+        let MsgEnvelope::Packet(msg) = &self.envelope else {
+            return None;
+        };
+
+        let packet = match msg {
+            PacketMsg::Recv(msg) => &msg.packet,
+            PacketMsg::Ack(msg) => &msg.packet,
+            PacketMsg::Timeout(msg) => &msg.packet,
+            PacketMsg::TimeoutOnClose(msg) => &msg.packet,
+        };
+
+        Some(PyPacket(packet.clone()))
+    }
+
+    fn __repr__(&self) -> String {
+        format!("{:#?}", self.envelope)
+    }
+}
+
+/// Decodes the IBC message of the type URL from its protobuf encoding, e.g.
+/// the value of an `Any` of the messages of a transaction.
+#[pyfunction]
+pub fn decode_message(type_url: &str, value: Vec<u8>) -> PyResult<PyMessage> {
+    let any = Any {
+        type_url: type_url.to_string(),
+        value,
+    };
+    let envelope =
+        MsgEnvelope::try_from(any).map_err(|e| BindingError::decode("MsgEnvelope", e))?;
+
+    Ok(PyMessage {
+        type_url: type_url.to_string(),
+        envelope,
+    })
+}
@@ -0,0 +1,141 @@
+//! Binds the packets, along with their encoding and commitment.
+
+use ibc::core::channel::types::commitment::compute_packet_commitment;
+use ibc::core::channel::types::packet::Packet;
+use ibc::core::channel::types::proto::v1::Packet as RawPacket;
+use ibc::core::channel::types::timeout::TimeoutHeight;
+use ibc::primitives::proto::Protobuf;
+use ibc::primitives::Timestamp;
+use pyo3::prelude::*;
+use pyo3::types::PyBytes;
+
+use crate::error::BindingError;
+use crate::height::PyHeight;
+use crate::identifiers::{PyChannelId, PyPortId, PySequence};
+
+/// A packet sent from the source channel to the destination channel.
+#[pyclass(module = "ibc_rs", name = "Packet", frozen)]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PyPacket(pub(crate) Packet);
+
+#[pymethods]
+impl PyPacket {
+    /// Creates the packet, which times out at the height or the timestamp, in
+    /// nanoseconds since the Unix epoch, if any.
+    #[new]
+    #[pyo3(signature = (
+        sequence,
+        source_port,
+        source_channel,
+        destination_port,
+        destination_channel,
+        data,
+        timeout_height = None,
+        timeout_timestamp = 0,
+    ))]
+    #[allow(clippy::too_many_arguments)]
+    fn new(
+        sequence: u64,
+        source_port: PyPortId,
+        source_channel: PyChannelId,
+        destination_port: PyPortId,
+        destination_channel: PyChannelId,
+        data: Vec<u8>,
+        timeout_height: Option<PyHeight>,
+        timeout_timestamp: u64,
+    ) -> PyResult<Self> {
+        let timeout_timestamp_on_b = Timestamp::from_nanoseconds(timeout_timestamp)
+            .map_err(|e| BindingError::decode("Timestamp", e))?;
+
+        Ok(Self(Packet {
+            seq_on_a: sequence.into(),
+            port_id_on_a: source_port.0,
+            chan_id_on_a: source_channel.0,
+            port_id_on_b: destination_port.0,
+            chan_id_on_b: destination_channel.0,
+            data,
+            timeout_height_on_b: timeout_height
+                .map_or(TimeoutHeight::Never, |height| height.0.into()),
+            timeout_timestamp_on_b,
+        }))
+    }
+
+    /// Decodes the packet from its protobuf encoding.
+    #[staticmethod]
+    fn decode(bytes: &[u8]) -> PyResult<Self> {
+        let packet = Protobuf::<RawPacket>::decode_vec(bytes)
+            .map_err(|e| BindingError::decode("Packet", e))?;
+
+        Ok(Self(packet))
+    }
+
+    /// Returns the protobuf encoding of the packet.
+    fn encode<'py>(&self, py: Python<'py>) -> Bound<'py, PyBytes> {
+        PyBytes::new_bound(py, &Protobuf::<RawPacket>::encode_vec(self.0.clone()))
+    }
+
+    /// Returns the commitment to the packet the source chain stores.
+    fn commitment<'py>(&self, py: Python<'py>) -> Bound<'py, PyBytes> {
+        let commitment = compute_packet_commitment(
+            &self.0.data,
+            &self.0.timeout_height_on_b,
+            &self.0.timeout_timestamp_on_b,
+        );
+
+        PyBytes::new_bound(py, commitment.as_ref())
+    }
+
+    #[getter]
+    fn sequence(&self) -> PySequence {
+        PySequence(self.0.seq_on_a)
+    }
+
+    #[getter]
+    fn source_port(&self) -> PyPortId {
+        PyPortId(self.0.port_id_on_a.clone())
+    }
+
+    #[getter]
+    fn source_channel(&self) -> PyChannelId {
+        PyChannelId(self.0.chan_id_on_a.clone())
+    }
+
+    #[getter]
+    fn destination_port(&self) -> PyPortId {
+        PyPortId(self.0.port_id_on_b.clone())
+    }
+
+    #[getter]
+    fn destination_channel(&self) -> PyChannelId {
+        PyChannelId(self.0.chan_id_on_b.clone())
+    }
+
+    #[getter]
+    fn data<'py>(&self, py: Python<'py>) -> Bound<'py, PyBytes> {
+        PyBytes::new_bound(py, &self.0.data)
+    }
+
+    /// The height at which the packet times out, or `None` if it never does.
+    #[getter]
+    fn timeout_height(&self) -> Option<PyHeight> {
+        match self.0.timeout_height_on_b {
+            TimeoutHeight::Never => None,
+            TimeoutHeight::At(height) => Some(PyHeight(height)),
+        }
+    }
+
+    /// The timestamp at which the packet times out, in nanoseconds since the
+    /// Unix epoch, or `0` if it never does.
+    #[getter]
+    fn timeout_timestamp(&self) -> u64 {
+        self.0.timeout_timestamp_on_b.nanoseconds()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("{:?}", self.0)
+    }
+
+    fn __eq__(&self, other: &Self) -> bool {
+        self == other
+    }
+}
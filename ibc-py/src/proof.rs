@@ -0,0 +1,108 @@
+//! Binds the verification of the Merkle proofs of the states committed by the
+//! IBC hosts.
+
+use core::str::FromStr;
+
+use ibc::core::commitment_types::commitment::CommitmentPrefix;
+use ibc::core::commitment_types::merkle::{apply_prefix, MerkleProof};
+use ibc::core::commitment_types::proto::ics23;
+use ibc::core::commitment_types::proto::v1::{
+    MerklePath, MerkleProof as RawMerkleProof, MerkleRoot,
+};
+use ibc::core::commitment_types::specs::ProofSpecs;
+use ibc::core::host::types::path::Path;
+use ibc::primitives::proto::Protobuf;
+use pyo3::prelude::*;
+
+use crate::error::BindingError;
+
+/// Verifies the protobuf-encoded `MerkleProof` of the value at the ICS-24
+/// path under the store prefix, against the commitment root.
+///
+/// The proof specs are the ones of the store the value is committed to, among
+/// `cosmos` (an IAVL store within a Tendermint multistore, as on the Cosmos
+/// SDK chains), `iavl` and `tendermint`.
+#[pyfunction]
+#[pyo3(signature = (proof, root, prefix, path, value, specs = "cosmos"))]
+pub fn verify_membership(
+    proof: &[u8],
+    root: &[u8],
+    prefix: &[u8],
+    path: &str,
+    value: Vec<u8>,
+    specs: &str,
+) -> PyResult<()> {
+    let proven = ProvenPath::decode(proof, prefix, path, specs)?;
+
+    proven
+        .proof
+        .verify_membership(&proven.specs, root_of(root), proven.merkle_path(), value, 0)
+        .map_err(BindingError::Verification)?;
+
+    Ok(())
+}
+
+/// Verifies the protobuf-encoded `MerkleProof` of the absence of a value at
+/// the ICS-24 path under the store prefix, against the commitment root.
+///
+/// The proof specs are the ones of [`verify_membership`].
+#[pyfunction]
+#[pyo3(signature = (proof, root, prefix, path, specs = "cosmos"))]
+pub fn verify_non_membership(
+    proof: &[u8],
+    root: &[u8],
+    prefix: &[u8],
+    path: &str,
+    specs: &str,
+) -> PyResult<()> {
+    let proven = ProvenPath::decode(proof, prefix, path, specs)?;
+
+    proven
+        .proof
+        .verify_non_membership(&proven.specs, root_of(root), proven.merkle_path())
+        .map_err(BindingError::Verification)?;
+
+    Ok(())
+}
+
+/// The decoded proof of a path, and the specs it is verified with.
+struct ProvenPath {
+    proof: MerkleProof,
+    prefix: CommitmentPrefix,
+    path: Path,
+    specs: ProofSpecs,
+}
+
+impl ProvenPath {
+    fn decode(proof: &[u8], prefix: &[u8], path: &str, specs: &str) -> Result<Self, BindingError> {
+        Ok(Self {
+            proof: Protobuf::<RawMerkleProof>::decode_vec(proof)
+                .map_err(|e| BindingError::decode("MerkleProof", e))?,
+            prefix: CommitmentPrefix::try_from(prefix.to_vec())
+                .map_err(|e| BindingError::decode("CommitmentPrefix", e))?,
+            path: Path::from_str(path)?,
+            specs: proof_specs(specs)?,
+        })
+    }
+
+    fn merkle_path(&self) -> MerklePath {
+        apply_prefix(&self.prefix, vec![self.path.to_string()])
+    }
+}
+
+fn proof_specs(specs: &str) -> Result<ProofSpecs, BindingError> {
+    match specs {
+        "cosmos" => Ok(ProofSpecs::cosmos()),
+        "iavl" => Ok(vec![ics23::iavl_spec()].into()),
+        "tendermint" => Ok(vec![ics23::tendermint_spec()].into()),
+        _ => Err(BindingError::UnknownSpecs {
+            specs: specs.to_string(),
+        }),
+    }
+}
+
+fn root_of(root: &[u8]) -> MerkleRoot {
+    MerkleRoot {
+        hash: root.to_vec(),
+    }
+}
@@ -0,0 +1,295 @@
+//! Provides utility functions for querying the fees escrowed for the packets,
+//! the registered payees and the fee-enabled channels of the ICS-29 fee
+//! middleware.
+//!
+//! The historical queries, at a given `query_height`, are not supported: the
+//! queries are served from the current state of the host.
+
+use core::str::FromStr;
+
+use ibc::apps::fee::context::FeeValidationContext;
+use ibc::apps::fee::types::error::FeeError;
+use ibc::apps::fee::types::{Fee, PacketFee, PacketId};
+use ibc::core::host::types::identifiers::{ChannelId, PortId};
+use ibc::core::primitives::prelude::*;
+use ibc::core::primitives::Signer;
+use ibc_proto::ibc::apps::fee::v1::{
+    FeeEnabledChannel, IdentifiedPacketFees, QueryCounterpartyPayeeRequest,
+    QueryCounterpartyPayeeResponse, QueryFeeEnabledChannelRequest, QueryFeeEnabledChannelResponse,
+    QueryFeeEnabledChannelsRequest, QueryFeeEnabledChannelsResponse,
+    QueryIncentivizedPacketRequest, QueryIncentivizedPacketResponse,
+    QueryIncentivizedPacketsForChannelRequest, QueryIncentivizedPacketsForChannelResponse,
+    QueryIncentivizedPacketsRequest, QueryIncentivizedPacketsResponse, QueryPayeeRequest,
+    QueryPayeeResponse, QueryTotalAckFeesRequest, QueryTotalAckFeesResponse,
+    QueryTotalRecvFeesRequest, QueryTotalRecvFeesResponse, QueryTotalTimeoutFeesRequest,
+    QueryTotalTimeoutFeesResponse,
+};
+use ibc_proto::ibc::core::channel::v1::PacketId as RawPacketId;
+
+use crate::error::QueryError;
+use crate::pagination::paginate_by_key;
+
+/// Returns the key of the channel, by which the fee-enabled channels are
+/// paginated.
+fn channel_key(port_id: &PortId, channel_id: &ChannelId) -> Vec<u8> {
+    format!("{port_id}/{channel_id}").into_bytes()
+}
+
+/// Returns the key of the packet, by which the incentivized packets are
+/// paginated, in the order of their sequences within each channel.
+fn packet_key(packet_id: &PacketId) -> Vec<u8> {
+    let mut key = channel_key(&packet_id.port_id, &packet_id.channel_id);
+    key.push(b'/');
+    key.extend(packet_id.sequence.value().to_be_bytes());
+    key
+}
+
+fn identified_packet_fees(
+    (packet_id, packet_fees): (PacketId, Vec<PacketFee>),
+) -> IdentifiedPacketFees {
+    IdentifiedPacketFees {
+        packet_id: Some(packet_id.into()),
+        packet_fees: packet_fees.into_iter().map(Into::into).collect(),
+    }
+}
+
+fn packet_id(raw: Option<&RawPacketId>) -> Result<PacketId, QueryError> {
+    let raw = raw.cloned().ok_or(FeeError::MissingPacketId)?;
+
+    Ok(PacketId::try_from(raw)?)
+}
+
+/// Returns the total of the fees escrowed for the given packet.
+fn total_fees<I>(fee_ctx: &I, raw_packet_id: Option<&RawPacketId>) -> Result<Fee, QueryError>
+where
+    I: FeeValidationContext,
+{
+    let packet_id = packet_id(raw_packet_id)?;
+
+    let packet_fees = fee_ctx.get_fees_in_escrow(&packet_id)?;
+
+    if packet_fees.is_empty() {
+        return Err(FeeError::FeesNotFound { packet_id }.into());
+    }
+
+    Ok(packet_fees
+        .iter()
+        .try_fold(Fee::default(), |total, packet_fee| {
+            total.checked_add(&packet_fee.fee)
+        })?)
+}
+
+/// Queries for the fees escrowed for all the packets.
+pub fn query_incentivized_packets<I>(
+    fee_ctx: &I,
+    request: &QueryIncentivizedPacketsRequest,
+) -> Result<QueryIncentivizedPacketsResponse, QueryError>
+where
+    I: FeeValidationContext,
+{
+    let (incentivized_packets, pagination) = paginate_by_key(
+        fee_ctx.incentivized_packets()?,
+        |(packet_id, _)| packet_key(packet_id),
+        request.pagination.as_ref(),
+    )?;
+
+    Ok(QueryIncentivizedPacketsResponse {
+        incentivized_packets: incentivized_packets
+            .into_iter()
+            .map(identified_packet_fees)
+            .collect(),
+        pagination: Some(pagination),
+    })
+}
+
+/// Queries for the fees escrowed for the given packet.
+pub fn query_incentivized_packet<I>(
+    fee_ctx: &I,
+    request: &QueryIncentivizedPacketRequest,
+) -> Result<QueryIncentivizedPacketResponse, QueryError>
+where
+    I: FeeValidationContext,
+{
+    let packet_id = packet_id(request.packet_id.as_ref())?;
+
+    let packet_fees = fee_ctx.get_fees_in_escrow(&packet_id)?;
+
+    if packet_fees.is_empty() {
+        return Err(FeeError::FeesNotFound { packet_id }.into());
+    }
+
+    Ok(QueryIncentivizedPacketResponse {
+        incentivized_packet: Some(identified_packet_fees((packet_id, packet_fees))),
+    })
+}
+
+/// Queries for the fees escrowed for the packets sent on the given channel.
+pub fn query_incentivized_packets_for_channel<I>(
+    fee_ctx: &I,
+    request: &QueryIncentivizedPacketsForChannelRequest,
+) -> Result<QueryIncentivizedPacketsForChannelResponse, QueryError>
+where
+    I: FeeValidationContext,
+{
+    let port_id = PortId::from_str(request.port_id.as_str())?;
+
+    let channel_id = ChannelId::from_str(request.channel_id.as_str())?;
+
+    let channel_packets = fee_ctx
+        .incentivized_packets()?
+        .into_iter()
+        .filter(|(packet_id, _)| packet_id.port_id == port_id && packet_id.channel_id == channel_id)
+        .collect();
+
+    let (incentivized_packets, pagination) = paginate_by_key(
+        channel_packets,
+        |(packet_id, _)| packet_key(packet_id),
+        request.pagination.as_ref(),
+    )?;
+
+    Ok(QueryIncentivizedPacketsForChannelResponse {
+        incentivized_packets: incentivized_packets
+            .into_iter()
+            .map(identified_packet_fees)
+            .collect(),
+        pagination: Some(pagination),
+    })
+}
+
+/// Queries for the total of the `recv_fee`s escrowed for the given packet.
+pub fn query_total_recv_fees<I>(
+    fee_ctx: &I,
+    request: &QueryTotalRecvFeesRequest,
+) -> Result<QueryTotalRecvFeesResponse, QueryError>
+where
+    I: FeeValidationContext,
+{
+    let total = total_fees(fee_ctx, request.packet_id.as_ref())?;
+
+    Ok(QueryTotalRecvFeesResponse {
+        recv_fees: total.recv_fee.into_iter().map(Into::into).collect(),
+    })
+}
+
+/// Queries for the total of the `ack_fee`s escrowed for the given packet.
+pub fn query_total_ack_fees<I>(
+    fee_ctx: &I,
+    request: &QueryTotalAckFeesRequest,
+) -> Result<QueryTotalAckFeesResponse, QueryError>
+where
+    I: FeeValidationContext,
+{
+    let total = total_fees(fee_ctx, request.packet_id.as_ref())?;
+
+    Ok(QueryTotalAckFeesResponse {
+        ack_fees: total.ack_fee.into_iter().map(Into::into).collect(),
+    })
+}
+
+/// Queries for the total of the `timeout_fee`s escrowed for the given packet.
+pub fn query_total_timeout_fees<I>(
+    fee_ctx: &I,
+    request: &QueryTotalTimeoutFeesRequest,
+) -> Result<QueryTotalTimeoutFeesResponse, QueryError>
+where
+    I: FeeValidationContext,
+{
+    let total = total_fees(fee_ctx, request.packet_id.as_ref())?;
+
+    Ok(QueryTotalTimeoutFeesResponse {
+        timeout_fees: total.timeout_fee.into_iter().map(Into::into).collect(),
+    })
+}
+
+/// Queries for the address the relayer registered to be paid the `ack_fee`s
+/// and `timeout_fee`s at on the given channel.
+pub fn query_payee<I>(
+    fee_ctx: &I,
+    request: &QueryPayeeRequest,
+) -> Result<QueryPayeeResponse, QueryError>
+where
+    I: FeeValidationContext,
+{
+    let channel_id = ChannelId::from_str(request.channel_id.as_str())?;
+
+    let relayer = Signer::from(request.relayer.clone());
+
+    let payee = fee_ctx
+        .get_payee(&relayer, &channel_id)?
+        .ok_or(FeeError::PayeeNotFound {
+            relayer,
+            channel_id,
+        })?;
+
+    Ok(QueryPayeeResponse {
+        payee_address: payee.to_string(),
+    })
+}
+
+/// Queries for the address the relayer registered to be paid the `recv_fee`s
+/// at on the counterparty chain of the given channel.
+pub fn query_counterparty_payee<I>(
+    fee_ctx: &I,
+    request: &QueryCounterpartyPayeeRequest,
+) -> Result<QueryCounterpartyPayeeResponse, QueryError>
+where
+    I: FeeValidationContext,
+{
+    let channel_id = ChannelId::from_str(request.channel_id.as_str())?;
+
+    let relayer = Signer::from(request.relayer.clone());
+
+    let counterparty_payee = fee_ctx
+        .get_counterparty_payee(&relayer, &channel_id)?
+        .ok_or(FeeError::CounterpartyPayeeNotFound {
+            relayer,
+            channel_id,
+        })?;
+
+    Ok(QueryCounterpartyPayeeResponse {
+        counterparty_payee: counterparty_payee.to_string(),
+    })
+}
+
+/// Queries for all the fee-enabled channels.
+pub fn query_fee_enabled_channels<I>(
+    fee_ctx: &I,
+    request: &QueryFeeEnabledChannelsRequest,
+) -> Result<QueryFeeEnabledChannelsResponse, QueryError>
+where
+    I: FeeValidationContext,
+{
+    let (fee_enabled_channels, pagination) = paginate_by_key(
+        fee_ctx.fee_enabled_channels()?,
+        |(port_id, channel_id)| channel_key(port_id, channel_id),
+        request.pagination.as_ref(),
+    )?;
+
+    Ok(QueryFeeEnabledChannelsResponse {
+        fee_enabled_channels: fee_enabled_channels
+            .into_iter()
+            .map(|(port_id, channel_id)| FeeEnabledChannel {
+                port_id: port_id.to_string(),
+                channel_id: channel_id.to_string(),
+            })
+            .collect(),
+        pagination: Some(pagination),
+    })
+}
+
+/// Queries for whether the given channel is fee-enabled.
+pub fn query_fee_enabled_channel<I>(
+    fee_ctx: &I,
+    request: &QueryFeeEnabledChannelRequest,
+) -> Result<QueryFeeEnabledChannelResponse, QueryError>
+where
+    I: FeeValidationContext,
+{
+    let port_id = PortId::from_str(request.port_id.as_str())?;
+
+    let channel_id = ChannelId::from_str(request.channel_id.as_str())?;
+
+    Ok(QueryFeeEnabledChannelResponse {
+        fee_enabled: fee_ctx.is_fee_enabled(&port_id, &channel_id)?,
+    })
+}
@@ -0,0 +1,2 @@
+pub mod fee;
+pub mod transfer;
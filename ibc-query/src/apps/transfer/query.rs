@@ -0,0 +1,109 @@
+//! Provides utility functions for querying the denom traces and the escrowed
+//! tokens of the ICS-20 token transfer application.
+
+use core::str::FromStr;
+
+use ibc::apps::transfer::context::{TokenTransferEscrowContext, TokenTransferValidationContext};
+use ibc::apps::transfer::denom_trace::{denom_trace, resolve_denom};
+use ibc::apps::transfer::types::{DenomTraceHash, PrefixedDenom, IBC_DENOM_PREFIX};
+use ibc::core::host::types::identifiers::{ChannelId, PortId};
+use ibc::core::primitives::prelude::*;
+use ibc_proto::cosmos::base::v1beta1::Coin;
+use ibc_proto::ibc::applications::transfer::v1::{
+    QueryDenomHashRequest, QueryDenomHashResponse, QueryDenomTraceRequest, QueryDenomTraceResponse,
+    QueryDenomTracesRequest, QueryDenomTracesResponse, QueryEscrowAddressRequest,
+    QueryEscrowAddressResponse, QueryTotalEscrowForDenomRequest, QueryTotalEscrowForDenomResponse,
+};
+
+use crate::error::QueryError;
+
+/// Queries for the denom trace of the given hash, either in hexadecimal or as
+/// an `ibc/{hash}` denomination.
+pub fn query_denom_trace<I>(
+    transfer_ctx: &I,
+    request: &QueryDenomTraceRequest,
+) -> Result<QueryDenomTraceResponse, QueryError>
+where
+    I: TokenTransferValidationContext,
+{
+    let hash = request.hash.as_str();
+    let hash = hash
+        .strip_prefix(IBC_DENOM_PREFIX)
+        .and_then(|hash| hash.strip_prefix('/'))
+        .unwrap_or(hash);
+
+    let denom = denom_trace(transfer_ctx, &DenomTraceHash::from_str(hash)?)?;
+
+    Ok(QueryDenomTraceResponse {
+        denom_trace: Some(denom.into()),
+    })
+}
+
+/// Queries for all the denom traces stored by the host.
+pub fn query_denom_traces<I>(
+    transfer_ctx: &I,
+    _request: &QueryDenomTracesRequest,
+) -> Result<QueryDenomTracesResponse, QueryError>
+where
+    I: TokenTransferValidationContext,
+{
+    let denom_traces = transfer_ctx.denom_traces()?;
+
+    Ok(QueryDenomTracesResponse {
+        denom_traces: denom_traces.into_iter().map(Into::into).collect(),
+        // no support for pagination yet
+        pagination: None,
+    })
+}
+
+/// Queries for the hash of the given denom trace, which does not need to be
+/// stored by the host.
+pub fn query_denom_hash(
+    request: &QueryDenomHashRequest,
+) -> Result<QueryDenomHashResponse, QueryError> {
+    let denom = PrefixedDenom::from_str(&request.trace)?;
+
+    Ok(QueryDenomHashResponse {
+        hash: denom.trace_hash().to_string(),
+    })
+}
+
+/// Queries for the address of the account escrowing the tokens sent over the
+/// given channel.
+pub fn query_escrow_address<I>(
+    transfer_ctx: &I,
+    request: &QueryEscrowAddressRequest,
+) -> Result<QueryEscrowAddressResponse, QueryError>
+where
+    I: TokenTransferEscrowContext,
+{
+    let port_id = PortId::from_str(request.port_id.as_str())?;
+    let channel_id = ChannelId::from_str(request.channel_id.as_str())?;
+
+    let escrow_address = transfer_ctx.escrow_address(&port_id, &channel_id)?;
+
+    Ok(QueryEscrowAddressResponse {
+        escrow_address: escrow_address.to_string(),
+    })
+}
+
+/// Queries for the total amount of the given denomination, either an
+/// `ibc/{hash}` or a base denomination, escrowed by all the channels.
+pub fn query_total_escrow_for_denom<I>(
+    transfer_ctx: &I,
+    request: &QueryTotalEscrowForDenomRequest,
+) -> Result<QueryTotalEscrowForDenomResponse, QueryError>
+where
+    I: TokenTransferEscrowContext,
+{
+    let denom = resolve_denom(transfer_ctx, &request.denom)?;
+
+    let amount = transfer_ctx.total_escrowed_amount(&denom)?;
+
+    Ok(QueryTotalEscrowForDenomResponse {
+        amount: Some(Coin {
+            denom: request.denom.clone(),
+            amount: amount.to_string(),
+        }),
+    })
+}
@@ -3,12 +3,13 @@
 use alloc::format;
 use core::str::FromStr;
 
+use ibc::core::channel::types::channel::IdentifiedChannelEnd;
 use ibc::core::client::context::ClientValidationContext;
 use ibc::core::client::types::Height;
 use ibc::core::host::types::identifiers::{ChannelId, ConnectionId, PortId, Sequence};
 use ibc::core::host::types::path::{
-    AckPath, ChannelEndPath, ClientConsensusStatePath, ClientStatePath, CommitmentPath, Path,
-    ReceiptPath, SeqRecvPath, SeqSendPath,
+    AckPath, ChannelEndPath, ChannelUpgradeErrorPath, ChannelUpgradePath, ClientConsensusStatePath,
+    ClientStatePath, CommitmentPath, Path, ReceiptPath, SeqRecvPath, SeqSendPath,
 };
 use ibc::core::host::{ConsensusStateRef, ValidationContext};
 use ibc_proto::google::protobuf::Any;
@@ -24,11 +25,22 @@ use ibc_proto::ibc::core::channel::v1::{
     QueryPacketCommitmentResponse, QueryPacketCommitmentsRequest, QueryPacketCommitmentsResponse,
     QueryPacketReceiptRequest, QueryPacketReceiptResponse, QueryUnreceivedAcksRequest,
     QueryUnreceivedAcksResponse, QueryUnreceivedPacketsRequest, QueryUnreceivedPacketsResponse,
+    QueryUpgradeErrorRequest, QueryUpgradeErrorResponse, QueryUpgradeRequest, QueryUpgradeResponse,
 };
 use ibc_proto::ibc::core::client::v1::IdentifiedClientState;
 
-use crate::core::context::{ProvableContext, QueryContext};
+use crate::core::context::{prove, ProvableContext, QueryContext};
 use crate::error::QueryError;
+use crate::pagination::paginate;
+
+/// Returns the path of the given channel end, by which the channel ends are
+/// paginated.
+fn channel_end_path(channel_end: &IdentifiedChannelEnd) -> Path {
+    Path::from(ChannelEndPath::new(
+        &channel_end.port_id,
+        &channel_end.channel_id,
+    ))
+}
 
 /// Queries for a specific IBC channel by the given channel and port ids and
 /// returns the channel end with the associated proof.
@@ -48,37 +60,34 @@ where
     let channel_end = ibc_ctx.channel_end(&channel_end_path)?;
 
     let current_height = ibc_ctx.host_height()?;
-    let proof = ibc_ctx
-        .get_proof(current_height, &Path::ChannelEnd(channel_end_path.clone()))
-        .ok_or(QueryError::ProofNotFound {
-            description: format!(
-                "Proof not found for channel end path {:?}",
-                channel_end_path
-            ),
-        })?;
+    let (proof, proof_height) =
+        prove(ibc_ctx, current_height, &Path::ChannelEnd(channel_end_path))?;
 
     Ok(QueryChannelResponse {
         channel: Some(channel_end.into()),
         proof,
-        proof_height: Some(current_height.into()),
+        proof_height,
     })
 }
 
 /// Queries for all existing IBC channels and returns the corresponding channel ends
 pub fn query_channels<I>(
     ibc_ctx: &I,
-    _request: &QueryChannelsRequest,
+    request: &QueryChannelsRequest,
 ) -> Result<QueryChannelsResponse, QueryError>
 where
     I: QueryContext,
 {
-    let channel_ends = ibc_ctx.channel_ends()?;
+    let (channel_ends, pagination) = paginate(
+        ibc_ctx.channel_ends()?,
+        channel_end_path,
+        request.pagination.as_ref(),
+    )?;
 
     Ok(QueryChannelsResponse {
         channels: channel_ends.into_iter().map(Into::into).collect(),
         height: Some(ibc_ctx.host_height()?.into()),
-        // no support for pagination yet
-        pagination: None,
+        pagination: Some(pagination),
     })
 }
 
@@ -103,14 +112,21 @@ where
                 .iter()
                 .any(|connection_hop| connection_hop == &connection_id)
         })
-        .map(Into::into)
         .collect();
 
+    let (connection_channel_ends, pagination) = paginate(
+        connection_channel_ends,
+        channel_end_path,
+        request.pagination.as_ref(),
+    )?;
+
     Ok(QueryConnectionChannelsResponse {
-        channels: connection_channel_ends,
+        channels: connection_channel_ends
+            .into_iter()
+            .map(Into::into)
+            .collect(),
         height: Some(ibc_ctx.host_height()?.into()),
-        // no support for pagination yet
-        pagination: None,
+        pagination: Some(pagination),
     })
 }
 
@@ -145,17 +161,11 @@ where
 
     let current_height = ibc_ctx.host_height()?;
 
-    let proof = ibc_ctx
-        .get_proof(
-            current_height,
-            &Path::ClientState(ClientStatePath::new(connection_end.client_id().clone())),
-        )
-        .ok_or(QueryError::ProofNotFound {
-            description: format!(
-                "Proof not found for client state path: {:?}",
-                connection_end.client_id()
-            ),
-        })?;
+    let (proof, proof_height) = prove(
+        ibc_ctx,
+        current_height,
+        &Path::ClientState(ClientStatePath::new(connection_end.client_id().clone())),
+    )?;
 
     Ok(QueryChannelClientStateResponse {
         identified_client_state: Some(IdentifiedClientState {
@@ -163,7 +173,7 @@ where
             client_state: Some(client_state.into()),
         }),
         proof,
-        proof_height: Some(current_height.into()),
+        proof_height,
     })
 }
 
@@ -206,23 +216,17 @@ where
 
     let current_height = ibc_ctx.host_height()?;
 
-    let proof = ibc_ctx
-        .get_proof(
-            current_height,
-            &Path::ClientConsensusState(consensus_path.clone()),
-        )
-        .ok_or(QueryError::ProofNotFound {
-            description: format!(
-                "Proof not found for client consensus state path: {:?}",
-                consensus_path
-            ),
-        })?;
+    let (proof, proof_height) = prove(
+        ibc_ctx,
+        current_height,
+        &Path::ClientConsensusState(consensus_path),
+    )?;
 
     Ok(QueryChannelConsensusStateResponse {
         client_id: connection_end.client_id().as_str().into(),
         consensus_state: Some(consensus_state.into()),
         proof,
-        proof_height: Some(current_height.into()),
+        proof_height,
     })
 }
 
@@ -247,19 +251,12 @@ where
 
     let current_height = ibc_ctx.host_height()?;
 
-    let proof = ibc_ctx
-        .get_proof(current_height, &Path::Commitment(commitment_path.clone()))
-        .ok_or(QueryError::ProofNotFound {
-            description: format!(
-                "Proof not found for packet commitment path: {:?}",
-                commitment_path
-            ),
-        })?;
+    let (proof, proof_height) = prove(ibc_ctx, current_height, &Path::Commitment(commitment_path))?;
 
     Ok(QueryPacketCommitmentResponse {
         commitment: packet_commitment_data.into_vec(),
         proof,
-        proof_height: Some(current_height.into()),
+        proof_height,
     })
 }
 
@@ -277,17 +274,22 @@ where
 
     let channel_end_path = ChannelEndPath::new(&port_id, &channel_id);
 
-    let commitments = ibc_ctx
-        .packet_commitments(&channel_end_path)?
-        .into_iter()
-        .map(Into::into)
-        .collect();
+    let (commitments, pagination) = paginate(
+        ibc_ctx.packet_commitments(&channel_end_path)?,
+        |packet_state| {
+            Path::from(CommitmentPath::new(
+                &packet_state.port_id,
+                &packet_state.chan_id,
+                packet_state.seq,
+            ))
+        },
+        request.pagination.as_ref(),
+    )?;
 
     Ok(QueryPacketCommitmentsResponse {
-        commitments,
+        commitments: commitments.into_iter().map(Into::into).collect(),
         height: Some(ibc_ctx.host_height()?.into()),
-        // no support for pagination yet
-        pagination: None,
+        pagination: Some(pagination),
     })
 }
 
@@ -308,25 +310,18 @@ where
 
     let receipt_path = ReceiptPath::new(&port_id, &channel_id, sequence);
 
-    // Receipt only has one enum
-    // Unreceived packets are not stored
+    // Unreceived packets are not stored, and timeout receipts also consume
+    // the sequence of the packet
     let packet_receipt_data = ibc_ctx.get_packet_receipt(&receipt_path);
 
     let current_height = ibc_ctx.host_height()?;
 
-    let proof = ibc_ctx
-        .get_proof(current_height, &Path::Receipt(receipt_path.clone()))
-        .ok_or(QueryError::ProofNotFound {
-            description: format!(
-                "Proof not found for packet receipt path: {:?}",
-                receipt_path
-            ),
-        })?;
+    let (proof, proof_height) = prove(ibc_ctx, current_height, &Path::Receipt(receipt_path))?;
 
     Ok(QueryPacketReceiptResponse {
         received: packet_receipt_data.is_ok(),
         proof,
-        proof_height: Some(current_height.into()),
+        proof_height,
     })
 }
 
@@ -351,19 +346,12 @@ where
 
     let current_height = ibc_ctx.host_height()?;
 
-    let proof = ibc_ctx
-        .get_proof(current_height, &Path::Ack(acknowledgement_path.clone()))
-        .ok_or(QueryError::ProofNotFound {
-            description: format!(
-                "Proof not found for packet acknowledgement path: {:?}",
-                acknowledgement_path
-            ),
-        })?;
+    let (proof, proof_height) = prove(ibc_ctx, current_height, &Path::Ack(acknowledgement_path))?;
 
     Ok(QueryPacketAcknowledgementResponse {
         acknowledgement: packet_acknowledgement_data.into_vec(),
         proof,
-        proof_height: Some(current_height.into()),
+        proof_height,
     })
 }
 
@@ -387,17 +375,22 @@ where
 
     let channel_end_path = ChannelEndPath::new(&port_id, &channel_id);
 
-    let acknowledgements = ibc_ctx
-        .packet_acknowledgements(&channel_end_path, commitment_sequences)?
-        .into_iter()
-        .map(Into::into)
-        .collect();
+    let (acknowledgements, pagination) = paginate(
+        ibc_ctx.packet_acknowledgements(&channel_end_path, commitment_sequences)?,
+        |packet_state| {
+            Path::from(AckPath::new(
+                &packet_state.port_id,
+                &packet_state.chan_id,
+                packet_state.seq,
+            ))
+        },
+        request.pagination.as_ref(),
+    )?;
 
     Ok(QueryPacketAcknowledgementsResponse {
-        acknowledgements,
+        acknowledgements: acknowledgements.into_iter().map(Into::into).collect(),
         height: Some(ibc_ctx.host_height()?.into()),
-        // no support for pagination yet
-        pagination: None,
+        pagination: Some(pagination),
     })
 }
 
@@ -476,19 +469,12 @@ where
 
     let current_height = ibc_ctx.host_height()?;
 
-    let proof = ibc_ctx
-        .get_proof(current_height, &Path::SeqSend(next_seq_send_path))
-        .ok_or(QueryError::ProofNotFound {
-            description: format!(
-                "Next sequence send proof not found for channel {}",
-                channel_id
-            ),
-        })?;
+    let (proof, proof_height) = prove(ibc_ctx, current_height, &Path::SeqSend(next_seq_send_path))?;
 
     Ok(QueryNextSequenceSendResponse {
         next_sequence_send: next_sequence_send.into(),
         proof,
-        proof_height: Some(current_height.into()),
+        proof_height,
     })
 }
 
@@ -510,18 +496,72 @@ where
 
     let current_height = ibc_ctx.host_height()?;
 
-    let proof = ibc_ctx
-        .get_proof(current_height, &Path::SeqRecv(next_seq_recv_path))
-        .ok_or(QueryError::ProofNotFound {
-            description: format!(
-                "Next sequence receive proof not found for channel {}",
-                channel_id
-            ),
-        })?;
+    let (proof, proof_height) = prove(ibc_ctx, current_height, &Path::SeqRecv(next_seq_recv_path))?;
 
     Ok(QueryNextSequenceReceiveResponse {
         next_sequence_receive: next_sequence_recv.into(),
         proof,
-        proof_height: Some(current_height.into()),
+        proof_height,
+    })
+}
+
+/// Queries for the upgrade proposed for a channel end, during its upgrade
+/// handshake, with the associated proof.
+pub fn query_upgrade<I>(
+    ibc_ctx: &I,
+    request: &QueryUpgradeRequest,
+) -> Result<QueryUpgradeResponse, QueryError>
+where
+    I: ValidationContext + ProvableContext,
+{
+    let channel_id = ChannelId::from_str(request.channel_id.as_str())?;
+
+    let port_id = PortId::from_str(request.port_id.as_str())?;
+
+    let upgrade_path = ChannelUpgradePath::new(&port_id, &channel_id);
+
+    let upgrade = ibc_ctx.channel_upgrade(&upgrade_path)?;
+
+    let current_height = ibc_ctx.host_height()?;
+
+    let (proof, proof_height) =
+        prove(ibc_ctx, current_height, &Path::ChannelUpgrade(upgrade_path))?;
+
+    Ok(QueryUpgradeResponse {
+        upgrade: Some(upgrade.into()),
+        proof,
+        proof_height,
+    })
+}
+
+/// Queries for the error receipt of the last aborted upgrade of a channel end,
+/// with the associated proof.
+pub fn query_upgrade_error<I>(
+    ibc_ctx: &I,
+    request: &QueryUpgradeErrorRequest,
+) -> Result<QueryUpgradeErrorResponse, QueryError>
+where
+    I: ValidationContext + ProvableContext,
+{
+    let channel_id = ChannelId::from_str(request.channel_id.as_str())?;
+
+    let port_id = PortId::from_str(request.port_id.as_str())?;
+
+    let upgrade_error_path = ChannelUpgradeErrorPath::new(&port_id, &channel_id);
+
+    let error_receipt = ibc_ctx.channel_upgrade_error_receipt(&upgrade_error_path)?;
+
+    let current_height = ibc_ctx.host_height()?;
+
+    let (proof, proof_height) = prove(
+        ibc_ctx,
+        current_height,
+        &Path::ChannelUpgradeError(upgrade_error_path),
+    )?;
+
+    Ok(QueryUpgradeErrorResponse {
+        error_receipt: Some(error_receipt.into()),
+        proof,
+        proof_height,
     })
 }
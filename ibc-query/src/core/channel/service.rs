@@ -28,12 +28,10 @@ use super::{
     query_connection_channels, query_next_sequence_receive, query_next_sequence_send,
     query_packet_acknowledgement, query_packet_acknowledgements, query_packet_commitment,
     query_packet_commitments, query_packet_receipt, query_unreceived_acks,
-    query_unreceived_packets,
+    query_unreceived_packets, query_upgrade, query_upgrade_error,
 };
 use crate::core::context::QueryContext;
 
-// TODO(rano): currently the services don't support pagination, so we return all the results.
-
 /// The generic `I` must be a type where writes from one thread are readable from another.
 /// This means using `Arc<Mutex<_>>` or `Arc<RwLock<_>>` in most cases.
 pub struct ChannelQueryService<I>
@@ -193,20 +191,20 @@ where
 
     async fn upgrade_error(
         &self,
-        _request: Request<QueryUpgradeErrorRequest>,
+        request: Request<QueryUpgradeErrorRequest>,
     ) -> Result<Response<QueryUpgradeErrorResponse>, Status> {
-        Err(Status::unimplemented(
-            "Querying UpgradeError is not supported yet",
-        ))
+        let response = query_upgrade_error(&self.ibc_context, request.get_ref())?;
+
+        Ok(Response::new(response))
     }
 
     async fn upgrade(
         &self,
-        _request: Request<QueryUpgradeRequest>,
+        request: Request<QueryUpgradeRequest>,
     ) -> Result<Response<QueryUpgradeResponse>, Status> {
-        Err(Status::unimplemented(
-            "Querying Upgrade is not supported yet",
-        ))
+        let response = query_upgrade(&self.ibc_context, request.get_ref())?;
+
+        Ok(Response::new(response))
     }
 
     async fn channel_params(
@@ -15,17 +15,29 @@ use ibc::core::host::{ConsensusStateRef, ValidationContext};
 use ibc::cosmos_host::upgrade_proposal::{UpgradeValidationContext, UpgradedConsensusStateRef};
 use ibc_proto::google::protobuf::Any;
 use ibc_proto::ibc::core::client::v1::{
-    ConsensusStateWithHeight, IdentifiedClientState, QueryClientStateRequest,
-    QueryClientStateResponse, QueryClientStatesRequest, QueryClientStatesResponse,
-    QueryClientStatusRequest, QueryClientStatusResponse, QueryConsensusStateHeightsRequest,
+    ConsensusStateWithHeight, IdentifiedClientState, QueryClientParamsRequest,
+    QueryClientParamsResponse, QueryClientStateRequest, QueryClientStateResponse,
+    QueryClientStatesRequest, QueryClientStatesResponse, QueryClientStatusRequest,
+    QueryClientStatusResponse, QueryConsensusStateHeightsRequest,
     QueryConsensusStateHeightsResponse, QueryConsensusStateRequest, QueryConsensusStateResponse,
     QueryConsensusStatesRequest, QueryConsensusStatesResponse, QueryUpgradedClientStateRequest,
     QueryUpgradedClientStateResponse, QueryUpgradedConsensusStateRequest,
     QueryUpgradedConsensusStateResponse,
 };
 
-use crate::core::context::QueryContext;
+use crate::core::context::{prove, QueryContext};
 use crate::error::QueryError;
+use crate::pagination::paginate;
+
+/// Returns the path of the consensus state of the client at the given height,
+/// by which the consensus states are paginated.
+fn consensus_state_path(client_id: &ClientId, height: &Height) -> Path {
+    Path::from(ClientConsensusStatePath::new(
+        client_id.clone(),
+        height.revision_number(),
+        height.revision_height(),
+    ))
+}
 
 /// Queries for the client state of a given client id.
 pub fn query_client_state<I>(
@@ -43,31 +55,32 @@ where
 
     let current_height = ibc_ctx.host_height()?;
 
-    let proof = ibc_ctx
-        .get_proof(
-            current_height,
-            &Path::ClientState(ClientStatePath::new(client_id.clone())),
-        )
-        .ok_or(QueryError::ProofNotFound {
-            description: format!("Proof not found for client state path: {client_id:?}"),
-        })?;
+    let (proof, proof_height) = prove(
+        ibc_ctx,
+        current_height,
+        &Path::ClientState(ClientStatePath::new(client_id)),
+    )?;
 
     Ok(QueryClientStateResponse {
         client_state: Some(client_state.into()),
         proof,
-        proof_height: Some(current_height.into()),
+        proof_height,
     })
 }
 
 /// Queries for all the existing client states.
 pub fn query_client_states<I>(
     ibc_ctx: &I,
-    _request: &QueryClientStatesRequest,
+    request: &QueryClientStatesRequest,
 ) -> Result<QueryClientStatesResponse, QueryError>
 where
     I: QueryContext,
 {
-    let client_states = ibc_ctx.client_states()?;
+    let (client_states, pagination) = paginate(
+        ibc_ctx.client_states()?,
+        |(client_id, _)| Path::from(ClientStatePath::new(client_id.clone())),
+        request.pagination.as_ref(),
+    )?;
 
     Ok(QueryClientStatesResponse {
         client_states: client_states
@@ -77,8 +90,7 @@ where
                 client_state: Some(state.into()),
             })
             .collect(),
-        // no support for pagination yet
-        pagination: None,
+        pagination: Some(pagination),
     })
 }
 
@@ -117,23 +129,20 @@ where
 
     let current_height = ibc_ctx.host_height()?;
 
-    let proof = ibc_ctx
-        .get_proof(
-            current_height,
-            &Path::ClientConsensusState(ClientConsensusStatePath::new(
-                client_id.clone(),
-                height.revision_number(),
-                height.revision_height(),
-            )),
-        )
-        .ok_or(QueryError::ProofNotFound {
-            description: format!("Proof not found for consensus state path: {client_id:?}"),
-        })?;
+    let (proof, proof_height) = prove(
+        ibc_ctx,
+        current_height,
+        &Path::ClientConsensusState(ClientConsensusStatePath::new(
+            client_id,
+            height.revision_number(),
+            height.revision_height(),
+        )),
+    )?;
 
     Ok(QueryConsensusStateResponse {
         consensus_state: Some(consensus_state.into()),
         proof,
-        proof_height: Some(current_height.into()),
+        proof_height,
     })
 }
 
@@ -148,7 +157,11 @@ where
 {
     let client_id = ClientId::from_str(request.client_id.as_str())?;
 
-    let consensus_states = ibc_ctx.consensus_states(&client_id)?;
+    let (consensus_states, pagination) = paginate(
+        ibc_ctx.consensus_states(&client_id)?,
+        |(height, _)| consensus_state_path(&client_id, height),
+        request.pagination.as_ref(),
+    )?;
 
     Ok(QueryConsensusStatesResponse {
         consensus_states: consensus_states
@@ -158,8 +171,7 @@ where
                 consensus_state: Some(state.into()),
             })
             .collect(),
-        // no support for pagination yet
-        pagination: None,
+        pagination: Some(pagination),
     })
 }
 
@@ -173,15 +185,18 @@ where
 {
     let client_id = ClientId::from_str(request.client_id.as_str())?;
 
-    let consensus_state_heights = ibc_ctx.consensus_state_heights(&client_id)?;
+    let (consensus_state_heights, pagination) = paginate(
+        ibc_ctx.consensus_state_heights(&client_id)?,
+        |height| consensus_state_path(&client_id, height),
+        request.pagination.as_ref(),
+    )?;
 
     Ok(QueryConsensusStateHeightsResponse {
         consensus_state_heights: consensus_state_heights
             .into_iter()
             .map(Into::into)
             .collect(),
-        // no support for pagination yet
-        pagination: None,
+        pagination: Some(pagination),
     })
 }
 
@@ -204,6 +219,19 @@ where
     })
 }
 
+/// Queries for the parameters of the client module.
+pub fn query_client_params<I>(
+    ibc_ctx: &I,
+    _request: &QueryClientParamsRequest,
+) -> Result<QueryClientParamsResponse, QueryError>
+where
+    I: ValidationContext,
+{
+    Ok(QueryClientParamsResponse {
+        params: Some(ibc_ctx.client_params().into()),
+    })
+}
+
 /// Queries for the upgraded client state.
 pub fn query_upgraded_client_state<U>(
     upgrade_ctx: &U,
@@ -1,5 +1,7 @@
 mod query;
+#[cfg(feature = "grpc")]
 mod service;
 
 pub use query::*;
+#[cfg(feature = "grpc")]
 pub use service::*;
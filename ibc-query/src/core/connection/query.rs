@@ -1,7 +1,5 @@
 //! Provides utility functions for querying IBC connection states.
 
-use alloc::format;
-use alloc::vec::Vec;
 use core::str::FromStr;
 
 use ibc::core::client::context::ClientValidationContext;
@@ -21,8 +19,9 @@ use ibc_proto::ibc::core::connection::v1::{
     QueryConnectionResponse, QueryConnectionsRequest, QueryConnectionsResponse,
 };
 
-use crate::core::context::{ProvableContext, QueryContext};
+use crate::core::context::{prove, ProvableContext, QueryContext};
 use crate::error::QueryError;
+use crate::pagination::paginate;
 
 /// Queries for the connection end of a given connection id.
 pub fn query_connection<I>(
@@ -38,37 +37,37 @@ where
 
     let current_height = ibc_ctx.host_height()?;
 
-    let proof = ibc_ctx
-        .get_proof(
-            current_height,
-            &Path::Connection(ConnectionPath::new(&connection_id)),
-        )
-        .ok_or(QueryError::ProofNotFound {
-            description: format!("Proof not found for connection path: {connection_id:?}"),
-        })?;
+    let (proof, proof_height) = prove(
+        ibc_ctx,
+        current_height,
+        &Path::Connection(ConnectionPath::new(&connection_id)),
+    )?;
 
     Ok(QueryConnectionResponse {
         connection: Some(connection_end.into()),
         proof,
-        proof_height: Some(current_height.into()),
+        proof_height,
     })
 }
 
 /// Queries for all the existing connection ends.
 pub fn query_connections<I>(
     ibc_ctx: &I,
-    _request: &QueryConnectionsRequest,
+    request: &QueryConnectionsRequest,
 ) -> Result<QueryConnectionsResponse, QueryError>
 where
     I: QueryContext,
 {
-    let connections = ibc_ctx.connection_ends()?;
+    let (connections, pagination) = paginate(
+        ibc_ctx.connection_ends()?,
+        |connection| Path::from(ConnectionPath::new(&connection.connection_id)),
+        request.pagination.as_ref(),
+    )?;
 
     Ok(QueryConnectionsResponse {
         connections: connections.into_iter().map(Into::into).collect(),
         height: Some(ibc_ctx.host_height()?.into()),
-        // no support for pagination yet
-        pagination: None,
+        pagination: Some(pagination),
     })
 }
 
@@ -86,19 +85,16 @@ where
 
     let current_height = ibc_ctx.host_height()?;
 
-    let proof: Vec<u8> = ibc_ctx
-        .get_proof(
-            current_height,
-            &Path::ClientConnection(ClientConnectionPath::new(client_id.clone())),
-        )
-        .ok_or(QueryError::ProofNotFound {
-            description: format!("Proof not found for client connection path: {client_id:?}"),
-        })?;
+    let (proof, proof_height) = prove(
+        ibc_ctx,
+        current_height,
+        &Path::ClientConnection(ClientConnectionPath::new(client_id.clone())),
+    )?;
 
     Ok(QueryClientConnectionsResponse {
         connection_paths: connections.into_iter().map(|x| x.as_str().into()).collect(),
         proof,
-        proof_height: Some(current_height.into()),
+        proof_height,
     })
 }
 
@@ -120,17 +116,11 @@ where
 
     let current_height = ibc_ctx.host_height()?;
 
-    let proof = ibc_ctx
-        .get_proof(
-            current_height,
-            &Path::ClientState(ClientStatePath::new(connection_end.client_id().clone())),
-        )
-        .ok_or(QueryError::ProofNotFound {
-            description: format!(
-                "Proof not found for client state path: {:?}",
-                connection_end.client_id()
-            ),
-        })?;
+    let (proof, proof_height) = prove(
+        ibc_ctx,
+        current_height,
+        &Path::ClientState(ClientStatePath::new(connection_end.client_id().clone())),
+    )?;
 
     Ok(QueryConnectionClientStateResponse {
         identified_client_state: Some(IdentifiedClientState {
@@ -138,7 +128,7 @@ where
             client_state: Some(client_state.into()),
         }),
         proof,
-        proof_height: Some(current_height.into()),
+        proof_height,
     })
 }
 
@@ -169,20 +159,17 @@ where
 
     let current_height = ibc_ctx.host_height()?;
 
-    let proof = ibc_ctx
-        .get_proof(current_height, &Path::ClientConsensusState(consensus_path))
-        .ok_or(QueryError::ProofNotFound {
-            description: format!(
-                "Proof not found for consensus state path: {:?}",
-                connection_end.client_id()
-            ),
-        })?;
+    let (proof, proof_height) = prove(
+        ibc_ctx,
+        current_height,
+        &Path::ClientConsensusState(consensus_path),
+    )?;
 
     Ok(QueryConnectionConsensusStateResponse {
         consensus_state: Some(consensus_state.into()),
         client_id: connection_end.client_id().as_str().into(),
         proof,
-        proof_height: Some(current_height.into()),
+        proof_height,
     })
 }
 
@@ -1,5 +1,6 @@
 //! Required traits for blanket implementations of [`gRPC query services`](crate::core).
 
+use ibc::core::channel::context::PacketStatusContext;
 use ibc::core::channel::types::channel::IdentifiedChannelEnd;
 use ibc::core::channel::types::packet::PacketState;
 use ibc::core::client::types::Height;
@@ -9,12 +10,49 @@ use ibc::core::host::types::identifiers::{ClientId, ConnectionId, Sequence};
 use ibc::core::host::types::path::{ChannelEndPath, Path};
 use ibc::core::host::{ClientStateRef, ConsensusStateRef, ValidationContext};
 use ibc::core::primitives::prelude::*;
+use ibc_proto::ibc::core::client::v1::Height as RawHeight;
+
+use crate::error::QueryError;
 
 /// Context to be implemented by the host to provide proofs in query responses
 pub trait ProvableContext {
     /// Returns the proof for the given path at the given height.
     /// As this is in the context of IBC, the path is expected to be an [`IbcPath`](Path).
     fn get_proof(&self, height: Height, path: &Path) -> Option<Vec<u8>>;
+
+    /// Returns whether the store of the host proves its paths, in which case
+    /// the queries of the states return their proofs along with the height
+    /// they were proven at, which relayers need to build their messages.
+    ///
+    /// Hosts whose store is not merkleized, e.g. the light deployments
+    /// mirroring the states of another node, return `false`, and the queries
+    /// then return the states without proofs instead of failing.
+    fn is_provable(&self) -> bool {
+        true
+    }
+}
+
+/// Returns the proof of the path at the given height along with the height, or
+/// neither if the host does not prove its paths.
+pub(crate) fn prove<I>(
+    ibc_ctx: &I,
+    height: Height,
+    path: &Path,
+) -> Result<(Vec<u8>, Option<RawHeight>), QueryError>
+where
+    I: ProvableContext,
+{
+    if !ibc_ctx.is_provable() {
+        return Ok((Vec::new(), None));
+    }
+
+    let proof = ibc_ctx
+        .get_proof(height, path)
+        .ok_or(QueryError::ProofNotFound {
+            description: format!("Proof not found for path {path} at height {height}"),
+        })?;
+
+    Ok((proof, Some(height.into())))
 }
 
 /// Context to be implemented by the host that provides gRPC query services.
@@ -66,17 +104,43 @@ pub trait QueryContext: ProvableContext + ValidationContext {
     ) -> Result<Vec<PacketState>, ContextError>;
 
     /// Filters the packet sequences for the given channel end that are not received.
+    ///
+    /// Defaults to the receipt lookups of [`PacketStatusContext`], one per
+    /// sequence. Hosts iterating over their packet state, as a
+    /// [`PacketStateIterationContext`](ibc::core::channel::context::PacketStateIterationContext),
+    /// rather delegate to
+    /// [`unreceived_packet_sequences_batched`](ibc::core::channel::context::PacketStateIterationContext::unreceived_packet_sequences_batched).
     fn unreceived_packets(
         &self,
         channel_end_path: &ChannelEndPath,
         sequences: impl ExactSizeIterator<Item = Sequence>,
-    ) -> Result<Vec<Sequence>, ContextError>;
+    ) -> Result<Vec<Sequence>, ContextError> {
+        self.unreceived_packet_sequences(channel_end_path, sequences)
+    }
 
     /// Filters the list of packet sequences for the given channel end whose acknowledgement is not received.
     /// Returns all the unreceived acknowledgements if `sequences` is empty.
+    ///
+    /// Defaults to the commitment lookups of [`PacketStatusContext`], one per
+    /// sequence. Hosts iterating over their packet state, as a
+    /// [`PacketStateIterationContext`](ibc::core::channel::context::PacketStateIterationContext),
+    /// rather delegate to
+    /// [`unreceived_ack_sequences_batched`](ibc::core::channel::context::PacketStateIterationContext::unreceived_ack_sequences_batched).
     fn unreceived_acks(
         &self,
         channel_end_path: &ChannelEndPath,
         sequences: impl ExactSizeIterator<Item = Sequence>,
-    ) -> Result<Vec<Sequence>, ContextError>;
+    ) -> Result<Vec<Sequence>, ContextError> {
+        let sequences: Vec<_> = sequences.collect();
+
+        if sequences.is_empty() {
+            return Ok(self
+                .packet_commitments(channel_end_path)?
+                .into_iter()
+                .map(|packet_state| packet_state.seq)
+                .collect());
+        }
+
+        self.unreceived_ack_sequences(channel_end_path, sequences)
+    }
 }
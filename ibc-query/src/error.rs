@@ -1,10 +1,16 @@
-use alloc::string::{String, ToString};
+use alloc::string::String;
+#[cfg(feature = "grpc")]
+use alloc::string::ToString;
 
 use displaydoc::Display;
+use ibc::apps::fee::types::error::FeeError;
+use ibc::apps::transfer::types::error::TokenTransferError;
 use ibc::core::client::types::error::ClientError;
 use ibc::core::handler::types::error::ContextError;
+use ibc::core::handler::types::events::Error as EventError;
 use ibc::core::host::types::error::IdentifierError;
-use tonic::Status;
+#[cfg(feature = "grpc")]
+use tonic::{Code, Status};
 
 #[derive(Debug, Display)]
 pub enum QueryError {
@@ -16,16 +22,66 @@ pub enum QueryError {
     IdentifierError(IdentifierError),
     /// Proof not found: {description}
     ProofNotFound { description: String },
+    /// Token transfer error: {0}
+    TokenTransferError(TokenTransferError),
+    /// Fee error: {0}
+    FeeError(FeeError),
+    /// Invalid pagination: {description}
+    InvalidPagination { description: String },
+    /// Event error: {0}
+    EventError(EventError),
+    /// Subscription lagged behind the published events, skipping `{skipped}` of them
+    SubscriptionLagged { skipped: u64 },
 }
 
+/// The kinds of the query errors, for the transports serving the queries to
+/// map them to their own errors, e.g. to the gRPC status codes.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum QueryErrorKind {
+    /// The request is invalid
+    InvalidArgument,
+    /// The queried state is not found
+    NotFound,
+    /// Some of the queried events were lost
+    DataLoss,
+    /// The host failed to serve the query
+    Internal,
+}
+
+impl QueryError {
+    pub fn kind(&self) -> QueryErrorKind {
+        match self {
+            QueryError::ContextError(_)
+            | QueryError::ClientError(_)
+            | QueryError::IdentifierError(_)
+            | QueryError::EventError(_)
+            | QueryError::FeeError(FeeError::ContextError(_)) => QueryErrorKind::Internal,
+            QueryError::ProofNotFound { .. }
+            | QueryError::TokenTransferError(TokenTransferError::DenomTraceNotFound { .. })
+            | QueryError::FeeError(
+                FeeError::FeesNotFound { .. }
+                | FeeError::PayeeNotFound { .. }
+                | FeeError::CounterpartyPayeeNotFound { .. },
+            ) => QueryErrorKind::NotFound,
+            QueryError::TokenTransferError(_)
+            | QueryError::FeeError(_)
+            | QueryError::InvalidPagination { .. } => QueryErrorKind::InvalidArgument,
+            QueryError::SubscriptionLagged { .. } => QueryErrorKind::DataLoss,
+        }
+    }
+}
+
+#[cfg(feature = "grpc")]
 impl From<QueryError> for Status {
     fn from(e: QueryError) -> Self {
-        match e {
-            QueryError::ContextError(e) => Status::internal(e.to_string()),
-            QueryError::ClientError(e) => Status::internal(e.to_string()),
-            QueryError::IdentifierError(e) => Status::internal(e.to_string()),
-            QueryError::ProofNotFound { description } => Status::not_found(description),
-        }
+        let code = match e.kind() {
+            QueryErrorKind::InvalidArgument => Code::InvalidArgument,
+            QueryErrorKind::NotFound => Code::NotFound,
+            QueryErrorKind::DataLoss => Code::DataLoss,
+            QueryErrorKind::Internal => Code::Internal,
+        };
+
+        Status::new(code, e.to_string())
     }
 }
 
@@ -46,3 +102,21 @@ impl From<IdentifierError> for QueryError {
         QueryError::IdentifierError(e)
     }
 }
+
+impl From<TokenTransferError> for QueryError {
+    fn from(e: TokenTransferError) -> Self {
+        QueryError::TokenTransferError(e)
+    }
+}
+
+impl From<FeeError> for QueryError {
+    fn from(e: FeeError) -> Self {
+        QueryError::FeeError(e)
+    }
+}
+
+impl From<EventError> for QueryError {
+    fn from(e: EventError) -> Self {
+        QueryError::EventError(e)
+    }
+}
@@ -0,0 +1,257 @@
+//! Provides an index of the emitted IBC events, for the light deployments to
+//! query them without an external indexer, e.g. all the `send_packet` events
+//! of `channel-3` after a given height.
+//!
+//! The events are queried by kind and by the values of their ABCI attributes,
+//! as the relayers query them from the Tendermint RPC, and are stored by a
+//! pluggable [`EventStore`].
+
+use core::ops::Bound;
+
+use ibc::core::client::types::Height;
+use ibc::core::handler::types::events::{AbciEncoder, IbcEvent};
+use ibc::core::host::types::identifiers::{ChannelId, ClientId, Sequence};
+use ibc::core::primitives::prelude::*;
+
+use crate::error::QueryError;
+
+/// The attributes holding the channel ids of the IBC events.
+const CHANNEL_ATTRIBUTE_KEYS: &[&str] = &["channel_id", "packet_src_channel", "packet_dst_channel"];
+
+/// The attributes holding the client ids of the IBC events.
+const CLIENT_ATTRIBUTE_KEYS: &[&str] = &["client_id", "packet_source_client", "packet_dest_client"];
+
+/// The attribute holding the sequence of the packet events.
+const SEQUENCE_ATTRIBUTE_KEY: &str = "packet_sequence";
+
+/// An IBC event recorded by an [`EventIndex`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct IndexedEvent {
+    /// The height the event was emitted at
+    pub height: Height,
+    /// The position of the event among the ones emitted at its height
+    pub position: u64,
+    pub event: IbcEvent,
+    /// The ABCI attributes of the event, by which it is queried
+    pub attributes: Vec<(String, String)>,
+}
+
+impl IndexedEvent {
+    /// Returns the kind of the event, e.g. `send_packet`.
+    pub fn kind(&self) -> &str {
+        self.event.event_type()
+    }
+
+    /// Returns the value of the attribute of the event with the given key.
+    pub fn attribute(&self, key: &str) -> Option<&str> {
+        self.attributes
+            .iter()
+            .find(|(attribute_key, _)| attribute_key == key)
+            .map(|(_, value)| value.as_str())
+    }
+}
+
+/// Storage of the events recorded by an [`EventIndex`], e.g. in memory or in
+/// a column of the database of the host.
+pub trait EventStore {
+    /// Stores the event, under its kind.
+    fn insert(&mut self, event: IndexedEvent) -> Result<(), QueryError>;
+
+    /// Returns the events of the given kind emitted after the given height,
+    /// if any, ordered by height and position.
+    fn events<'a>(
+        &'a self,
+        kind: &str,
+        after: Option<Height>,
+    ) -> Result<Box<dyn Iterator<Item = IndexedEvent> + 'a>, QueryError>;
+}
+
+/// An [`EventStore`] keeping the events in memory.
+#[derive(Clone, Debug, Default)]
+pub struct InMemoryEventStore {
+    events: BTreeMap<String, BTreeMap<(Height, u64), IndexedEvent>>,
+}
+
+impl EventStore for InMemoryEventStore {
+    fn insert(&mut self, event: IndexedEvent) -> Result<(), QueryError> {
+        self.events
+            .entry(event.kind().to_string())
+            .or_default()
+            .insert((event.height, event.position), event);
+
+        Ok(())
+    }
+
+    fn events<'a>(
+        &'a self,
+        kind: &str,
+        after: Option<Height>,
+    ) -> Result<Box<dyn Iterator<Item = IndexedEvent> + 'a>, QueryError> {
+        let Some(events) = self.events.get(kind) else {
+            return Ok(Box::new(core::iter::empty()));
+        };
+
+        let start = match after {
+            Some(height) => Bound::Excluded((height, u64::MAX)),
+            None => Bound::Unbounded,
+        };
+
+        Ok(Box::new(
+            events
+                .range((start, Bound::Unbounded))
+                .map(|(_, event)| event.clone()),
+        ))
+    }
+}
+
+/// A query of the events recorded by an [`EventIndex`], matching the events
+/// of the given kind with all the given attributes.
+///
+/// ```rust,ignore
+/// let query = EventQuery::new("send_packet")
+///     .channel(&ChannelId::new(3))
+///     .after(height);
+///
+/// let events = index.query(&query)?;
+/// ```
+#[derive(Clone, Debug)]
+pub struct EventQuery {
+    kind: String,
+    /// The values the events must have for any of the given attribute keys
+    filters: Vec<(Vec<String>, String)>,
+    after: Option<Height>,
+    pub(crate) limit: Option<usize>,
+}
+
+impl EventQuery {
+    pub fn new(kind: impl Into<String>) -> Self {
+        Self {
+            kind: kind.into(),
+            filters: Vec::new(),
+            after: None,
+            limit: None,
+        }
+    }
+
+    /// Matches the events with the given value for the attribute.
+    pub fn attribute(mut self, key: impl Into<String>, value: impl ToString) -> Self {
+        self.filters.push((vec![key.into()], value.to_string()));
+        self
+    }
+
+    /// Matches the events of the given client, e.g. the client updates or the
+    /// IBC v2 packets sent or received by it.
+    pub fn client(self, client_id: &ClientId) -> Self {
+        self.any_attribute(CLIENT_ATTRIBUTE_KEYS, client_id)
+    }
+
+    /// Matches the events of the given channel, either the handshake events
+    /// of the channel or the packets sent or received on it.
+    pub fn channel(self, channel_id: &ChannelId) -> Self {
+        self.any_attribute(CHANNEL_ATTRIBUTE_KEYS, channel_id)
+    }
+
+    /// Matches the packet events of the given sequence.
+    pub fn sequence(self, sequence: Sequence) -> Self {
+        self.attribute(SEQUENCE_ATTRIBUTE_KEY, sequence)
+    }
+
+    /// Matches the events emitted after the given height.
+    pub fn after(mut self, height: Height) -> Self {
+        self.after = Some(height);
+        self
+    }
+
+    /// Returns at most the given number of events, the earliest ones, or in
+    /// the case of a subscription, ends it after this number of events.
+    pub fn limit(mut self, limit: usize) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    fn any_attribute(mut self, keys: &[&str], value: impl ToString) -> Self {
+        self.filters.push((
+            keys.iter().map(ToString::to_string).collect(),
+            value.to_string(),
+        ));
+        self
+    }
+
+    /// Returns whether the event is of the kind of the query, emitted after
+    /// its height, and has all its attributes.
+    pub fn matches(&self, event: &IndexedEvent) -> bool {
+        event.kind() == self.kind
+            && self.after.map_or(true, |after| event.height > after)
+            && self.filters.iter().all(|(keys, value)| {
+                keys.iter()
+                    .any(|key| event.attribute(key) == Some(value.as_str()))
+            })
+    }
+}
+
+/// Index of the IBC events emitted by the host, recording them in its
+/// [`EventStore`] by kind and height.
+///
+/// The host records the events of each block once it is committed, all at
+/// once, since the events are identified by their height and position.
+#[derive(Clone, Debug, Default)]
+pub struct EventIndex<S> {
+    store: S,
+}
+
+impl<S: EventStore> EventIndex<S> {
+    pub fn new(store: S) -> Self {
+        Self { store }
+    }
+
+    pub fn store(&self) -> &S {
+        &self.store
+    }
+
+    /// Records the events emitted at the given height, in the order they
+    /// were emitted, and returns them as recorded, e.g. for the host to
+    /// publish them to the subscribers of the events.
+    pub fn record(
+        &mut self,
+        height: Height,
+        events: impl IntoIterator<Item = IbcEvent>,
+    ) -> Result<Vec<IndexedEvent>, QueryError> {
+        let mut recorded = Vec::new();
+
+        for (position, event) in (0u64..).zip(events) {
+            let attributes = event
+                .clone()
+                .encode(&AbciEncoder)?
+                .attributes
+                .into_iter()
+                .map(|attribute| (attribute.key, attribute.value))
+                .collect();
+
+            let event = IndexedEvent {
+                height,
+                position,
+                event,
+                attributes,
+            };
+
+            self.store.insert(event.clone())?;
+            recorded.push(event);
+        }
+
+        Ok(recorded)
+    }
+
+    /// Returns the recorded events matching the query, ordered by height and
+    /// position.
+    pub fn query(&self, query: &EventQuery) -> Result<Vec<IndexedEvent>, QueryError> {
+        let events = self
+            .store
+            .events(&query.kind, query.after)?
+            .filter(|event| query.matches(event));
+
+        Ok(match query.limit {
+            Some(limit) => events.take(limit).collect(),
+            None => events.collect(),
+        })
+    }
+}
@@ -0,0 +1,52 @@
+//! Provides the gRPC health-check service of the query services, for the load
+//! balancers and the probes of orchestrators, e.g. Kubernetes, to monitor the
+//! query server.
+
+use tonic_health::server::{health_reporter, Health, HealthReporter, HealthServer};
+use tonic_health::ServingStatus;
+
+/// The names of the gRPC services of the [`ClientQueryService`],
+/// [`ConnectionQueryService`] and [`ChannelQueryService`], as reported by the
+/// health-check service.
+///
+/// [`ClientQueryService`]: crate::core::client::ClientQueryService
+/// [`ConnectionQueryService`]: crate::core::connection::ConnectionQueryService
+/// [`ChannelQueryService`]: crate::core::channel::ChannelQueryService
+pub const QUERY_SERVICE_NAMES: &[&str] = &[
+    "ibc.core.client.v1.Query",
+    "ibc.core.connection.v1.Query",
+    "ibc.core.channel.v1.Query",
+];
+
+/// Returns the health-check service, reporting the query services as serving,
+/// along with its reporter.
+///
+/// The host keeps the reporter to update the status of the query services,
+/// e.g. with [`set_query_services_status`] while the node is syncing.
+///
+/// ```rust,ignore
+/// let (reporter, health_service) = query_health_service().await;
+///
+/// let grpc_server = tonic::transport::Server::builder()
+///       .add_service(health_service)
+///       .add_service(client_service)
+///       .serve(addr);
+/// ```
+pub async fn query_health_service() -> (HealthReporter, HealthServer<impl Health>) {
+    let (mut reporter, service) = health_reporter();
+
+    set_query_services_status(&mut reporter, ServingStatus::Serving).await;
+
+    (reporter, service)
+}
+
+/// Sets the status of all the query services, as well as the overall status
+/// of the server.
+pub async fn set_query_services_status(reporter: &mut HealthReporter, status: ServingStatus) {
+    // The empty service name stands for the overall status of the server.
+    reporter.set_service_status("", status).await;
+
+    for name in QUERY_SERVICE_NAMES {
+        reporter.set_service_status(*name, status).await;
+    }
+}
@@ -51,6 +51,22 @@
 //!       .serve(addr);
 //! ```
 //!
+//! The query methods, e.g. [`query_channel`](crate::core::channel::query_channel),
+//! do not depend on the transport serving them, and the gRPC services are
+//! only one of their front-ends, behind the default `grpc` feature. Without
+//! it, the crate compiles to `no_std` targets, e.g. `wasm32`, for the hosts
+//! to serve the queries over their own transports, e.g. the smart queries of
+//! CosmWasm contracts or JSON-RPC, mapping the errors by their
+//! [`QueryErrorKind`](crate::error::QueryErrorKind).
+//!
+//! The `reflection` and `health` features respectively provide the gRPC
+//! reflection and health-check services of the query services, for the
+//! standard tooling, e.g. `grpcurl` or the probes of load balancers, to
+//! discover and monitor the query server. The `rest` feature provides a REST
+//! gateway over the query services, under the `/ibc/core/...` paths of
+//! Cosmos SDK chains, and the `subscriptions` feature the subscriptions to the
+//! IBC events as they are committed, over a WebSocket.
+//!
 
 #![cfg_attr(not(test), deny(clippy::unwrap_used))]
 #![no_std]
@@ -66,5 +82,16 @@
 
 extern crate alloc;
 
+pub mod apps;
 pub mod core;
 pub mod error;
+pub mod events;
+#[cfg(feature = "health")]
+pub mod health;
+pub mod pagination;
+#[cfg(feature = "reflection")]
+pub mod reflection;
+#[cfg(feature = "rest")]
+pub mod rest;
+#[cfg(feature = "subscriptions")]
+pub mod subscriptions;
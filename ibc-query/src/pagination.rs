@@ -0,0 +1,98 @@
+//! Provides the pagination of the query results, following the
+//! [`PageRequest`]s of the Cosmos SDK.
+
+use alloc::vec::Vec;
+
+use ibc::core::host::types::path::Path;
+use ibc_proto::cosmos::base::query::v1beta1::{PageRequest, PageResponse};
+
+use crate::error::QueryError;
+
+/// The number of results returned when the request does not set a limit, as
+/// on Cosmos SDK chains.
+pub const DEFAULT_LIMIT: u64 = 100;
+
+/// Returns the page of the results selected by the request, along with the
+/// page response to return.
+///
+/// The results are ordered by the keys of their paths in the IBC store, as
+/// the results of the queries of Cosmos SDK chains, or in the reverse order
+/// if requested. The page starts either at the given key, which is the
+/// `next_key` of the previous page, or after the given offset, but not both.
+/// The total number of results is only counted for the offset-based requests,
+/// when requested or when the request does not set a limit.
+pub fn paginate<T>(
+    results: Vec<T>,
+    path: impl Fn(&T) -> Path,
+    request: Option<&PageRequest>,
+) -> Result<(Vec<T>, PageResponse), QueryError> {
+    paginate_by_key(results, |result| path(result).to_key(), request)
+}
+
+/// Returns the page of the results selected by the request, as [`paginate`]
+/// does, ordering the results by the given keys, e.g. for the state of the
+/// applications which is not stored under the IBC paths.
+pub fn paginate_by_key<T>(
+    results: Vec<T>,
+    key: impl Fn(&T) -> Vec<u8>,
+    request: Option<&PageRequest>,
+) -> Result<(Vec<T>, PageResponse), QueryError> {
+    let request = request.cloned().unwrap_or_default();
+
+    if !request.key.is_empty() && request.offset > 0 {
+        return Err(QueryError::InvalidPagination {
+            description: "either the key or the offset of the page is expected, got both".into(),
+        });
+    }
+
+    let (limit, count_total) = match request.limit {
+        0 => (DEFAULT_LIMIT, true),
+        limit => (limit, request.count_total),
+    };
+
+    let mut results: Vec<_> = results
+        .into_iter()
+        .map(|result| (key(&result), result))
+        .collect();
+
+    results.sort_by(|(key, _), (other_key, _)| key.cmp(other_key));
+
+    if request.reverse {
+        results.reverse();
+    }
+
+    let total = results.len() as u64;
+
+    let start = if request.key.is_empty() {
+        usize::try_from(request.offset).unwrap_or(usize::MAX)
+    } else {
+        results
+            .iter()
+            .position(|(key, _)| {
+                if request.reverse {
+                    key <= &request.key
+                } else {
+                    key >= &request.key
+                }
+            })
+            .unwrap_or(results.len())
+    };
+
+    let limit = usize::try_from(limit).unwrap_or(usize::MAX);
+
+    let mut page = results.into_iter().skip(start);
+    let page_results: Vec<_> = page
+        .by_ref()
+        .take(limit)
+        .map(|(_, result)| result)
+        .collect();
+    let next_key = page.next().map(|(key, _)| key).unwrap_or_default();
+
+    let total = if request.key.is_empty() && count_total {
+        total
+    } else {
+        0
+    };
+
+    Ok((page_results, PageResponse { next_key, total }))
+}
@@ -0,0 +1,20 @@
+//! Provides the gRPC reflection service of the query services, for the
+//! standard tooling, e.g. `grpcurl`, to discover the services and messages of
+//! the query server without the proto files at hand.
+
+use tonic_reflection::server::{Builder, Error, ServerReflection, ServerReflectionServer};
+
+/// Returns the reflection service describing the query services, and all the
+/// other services and messages defined by `ibc-proto`.
+///
+/// ```rust,ignore
+/// let grpc_server = tonic::transport::Server::builder()
+///       .add_service(query_reflection_service()?)
+///       .add_service(client_service)
+///       .serve(addr);
+/// ```
+pub fn query_reflection_service() -> Result<ServerReflectionServer<impl ServerReflection>, Error> {
+    Builder::configure()
+        .register_encoded_file_descriptor_set(ibc_proto::FILE_DESCRIPTOR_SET)
+        .build()
+}
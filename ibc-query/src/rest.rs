@@ -0,0 +1,858 @@
+//! Provides a REST gateway over the query services, serving them as JSON under
+//! the `/ibc/core/...` paths of the gRPC gateway of Cosmos SDK chains, for the
+//! explorers and the frontends built for these chains to query an `ibc-rs`
+//! enabled chain unchanged.
+//!
+//! The gateway takes the gRPC services, e.g. the [`ClientQueryService`], and
+//! calls them as the gRPC gateway does: the path parameters and the query
+//! string of the HTTP request set the fields of the gRPC request, and the
+//! gRPC errors are returned with their matching HTTP status.
+//!
+//! ```rust,ignore
+//! let rest_server = axum::Server::bind(&addr).serve(
+//!     query_routes(client_service, connection_service, channel_service).into_make_service(),
+//! );
+//! ```
+//!
+//! [`ClientQueryService`]: crate::core::client::ClientQueryService
+
+use alloc::sync::Arc;
+use core::str::FromStr;
+
+use axum::extract::{Path, Query, State};
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+use axum::routing::get;
+use axum::{Json, Router};
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use ibc::core::primitives::prelude::*;
+use ibc_proto::cosmos::base::query::v1beta1::PageRequest;
+use ibc_proto::ibc::core::channel::v1::query_server::Query as ChannelQuery;
+use ibc_proto::ibc::core::channel::v1::{
+    QueryChannelClientStateRequest, QueryChannelClientStateResponse,
+    QueryChannelConsensusStateRequest, QueryChannelConsensusStateResponse,
+    QueryChannelParamsRequest, QueryChannelParamsResponse, QueryChannelRequest,
+    QueryChannelResponse, QueryChannelsRequest, QueryChannelsResponse,
+    QueryConnectionChannelsRequest, QueryConnectionChannelsResponse,
+    QueryNextSequenceReceiveRequest, QueryNextSequenceReceiveResponse,
+    QueryNextSequenceSendRequest, QueryNextSequenceSendResponse, QueryPacketAcknowledgementRequest,
+    QueryPacketAcknowledgementResponse, QueryPacketAcknowledgementsRequest,
+    QueryPacketAcknowledgementsResponse, QueryPacketCommitmentRequest,
+    QueryPacketCommitmentResponse, QueryPacketCommitmentsRequest, QueryPacketCommitmentsResponse,
+    QueryPacketReceiptRequest, QueryPacketReceiptResponse, QueryUnreceivedAcksRequest,
+    QueryUnreceivedAcksResponse, QueryUnreceivedPacketsRequest, QueryUnreceivedPacketsResponse,
+    QueryUpgradeErrorRequest, QueryUpgradeErrorResponse, QueryUpgradeRequest, QueryUpgradeResponse,
+};
+use ibc_proto::ibc::core::client::v1::query_server::Query as ClientQuery;
+use ibc_proto::ibc::core::client::v1::{
+    QueryClientParamsRequest, QueryClientParamsResponse, QueryClientStateRequest,
+    QueryClientStateResponse, QueryClientStatesRequest, QueryClientStatesResponse,
+    QueryClientStatusRequest, QueryClientStatusResponse, QueryConsensusStateHeightsRequest,
+    QueryConsensusStateHeightsResponse, QueryConsensusStateRequest, QueryConsensusStateResponse,
+    QueryConsensusStatesRequest, QueryConsensusStatesResponse, QueryUpgradedClientStateRequest,
+    QueryUpgradedClientStateResponse, QueryUpgradedConsensusStateRequest,
+    QueryUpgradedConsensusStateResponse,
+};
+use ibc_proto::ibc::core::connection::v1::query_server::Query as ConnectionQuery;
+use ibc_proto::ibc::core::connection::v1::{
+    QueryClientConnectionsRequest, QueryClientConnectionsResponse,
+    QueryConnectionClientStateRequest, QueryConnectionClientStateResponse,
+    QueryConnectionConsensusStateRequest, QueryConnectionConsensusStateResponse,
+    QueryConnectionParamsRequest, QueryConnectionParamsResponse, QueryConnectionRequest,
+    QueryConnectionResponse, QueryConnectionsRequest, QueryConnectionsResponse,
+};
+use serde::Serialize;
+use tonic::{Code, Request, Status};
+
+/// The query string of an HTTP request, as its key-value pairs.
+pub(crate) type Params = Query<Vec<(String, String)>>;
+
+/// The result of a REST query, either the JSON of the gRPC response or the
+/// gRPC error.
+type RestResult<T> = Result<Json<T>, RestError>;
+
+/// The body of the response to a failed query, as returned by the gRPC
+/// gateway.
+#[derive(Serialize)]
+struct ErrorBody {
+    code: i32,
+    message: String,
+    details: Vec<String>,
+}
+
+/// A gRPC error returned by the REST gateway, with the HTTP status the gRPC
+/// gateway maps its code to.
+#[derive(Debug)]
+pub struct RestError(pub Status);
+
+impl From<Status> for RestError {
+    fn from(status: Status) -> Self {
+        Self(status)
+    }
+}
+
+impl IntoResponse for RestError {
+    fn into_response(self) -> Response {
+        let status = match self.0.code() {
+            Code::Ok => StatusCode::OK,
+            Code::Cancelled => StatusCode::from_u16(499).unwrap_or(StatusCode::BAD_REQUEST),
+            Code::InvalidArgument | Code::FailedPrecondition | Code::OutOfRange => {
+                StatusCode::BAD_REQUEST
+            }
+            Code::DeadlineExceeded => StatusCode::GATEWAY_TIMEOUT,
+            Code::NotFound => StatusCode::NOT_FOUND,
+            Code::AlreadyExists | Code::Aborted => StatusCode::CONFLICT,
+            Code::PermissionDenied => StatusCode::FORBIDDEN,
+            Code::Unauthenticated => StatusCode::UNAUTHORIZED,
+            Code::ResourceExhausted => StatusCode::TOO_MANY_REQUESTS,
+            Code::Unimplemented => StatusCode::NOT_IMPLEMENTED,
+            Code::Unavailable => StatusCode::SERVICE_UNAVAILABLE,
+            Code::Unknown | Code::Internal | Code::DataLoss => StatusCode::INTERNAL_SERVER_ERROR,
+        };
+
+        let body = ErrorBody {
+            code: self.0.code() as i32,
+            message: self.0.message().to_string(),
+            details: Vec::new(),
+        };
+
+        (status, Json(body)).into_response()
+    }
+}
+
+/// Returns the routes of the client, connection and channel query services.
+pub fn query_routes<C, N, H>(client: C, connection: N, channel: H) -> Router
+where
+    C: ClientQuery,
+    N: ConnectionQuery,
+    H: ChannelQuery,
+{
+    client_routes(client)
+        .merge(connection_routes(connection))
+        .merge(channel_routes(channel))
+}
+
+/// Returns the routes of the given client query service, under
+/// `/ibc/core/client/v1`.
+pub fn client_routes<S: ClientQuery>(service: S) -> Router {
+    Router::new()
+        .route(
+            "/ibc/core/client/v1/client_states",
+            get(client_states::<S>),
+        )
+        .route(
+            "/ibc/core/client/v1/client_states/:client_id",
+            get(client_state::<S>),
+        )
+        .route(
+            "/ibc/core/client/v1/consensus_states/:client_id",
+            get(consensus_states::<S>),
+        )
+        .route(
+            "/ibc/core/client/v1/consensus_states/:client_id/heights",
+            get(consensus_state_heights::<S>),
+        )
+        .route(
+            "/ibc/core/client/v1/consensus_states/:client_id/revision/:revision_number/height/:revision_height",
+            get(consensus_state::<S>),
+        )
+        .route(
+            "/ibc/core/client/v1/client_status/:client_id",
+            get(client_status::<S>),
+        )
+        .route("/ibc/core/client/v1/params", get(client_params::<S>))
+        .route(
+            "/ibc/core/client/v1/upgraded_client_states",
+            get(upgraded_client_state::<S>),
+        )
+        .route(
+            "/ibc/core/client/v1/upgraded_consensus_states",
+            get(upgraded_consensus_state::<S>),
+        )
+        .with_state(Arc::new(service))
+}
+
+/// Returns the routes of the given connection query service, under
+/// `/ibc/core/connection/v1`.
+pub fn connection_routes<S: ConnectionQuery>(service: S) -> Router {
+    Router::new()
+        .route(
+            "/ibc/core/connection/v1/connections",
+            get(connections::<S>),
+        )
+        .route(
+            "/ibc/core/connection/v1/connections/:connection_id",
+            get(connection::<S>),
+        )
+        .route(
+            "/ibc/core/connection/v1/client_connections/:client_id",
+            get(client_connections::<S>),
+        )
+        .route(
+            "/ibc/core/connection/v1/connections/:connection_id/client_state",
+            get(connection_client_state::<S>),
+        )
+        .route(
+            "/ibc/core/connection/v1/connections/:connection_id/consensus_state/revision/:revision_number/height/:revision_height",
+            get(connection_consensus_state::<S>),
+        )
+        .route(
+            "/ibc/core/connection/v1/params",
+            get(connection_params::<S>),
+        )
+        .with_state(Arc::new(service))
+}
+
+/// Returns the routes of the given channel query service, under
+/// `/ibc/core/channel/v1`.
+pub fn channel_routes<S: ChannelQuery>(service: S) -> Router {
+    const CHANNEL: &str = "/ibc/core/channel/v1/channels/:channel_id/ports/:port_id";
+
+    Router::new()
+        .route("/ibc/core/channel/v1/channels", get(channels::<S>))
+        .route(CHANNEL, get(channel::<S>))
+        .route(
+            "/ibc/core/channel/v1/connections/:connection/channels",
+            get(connection_channels::<S>),
+        )
+        .route(
+            &format!("{CHANNEL}/client_state"),
+            get(channel_client_state::<S>),
+        )
+        .route(
+            &format!("{CHANNEL}/consensus_state/revision/:revision_number/height/:revision_height"),
+            get(channel_consensus_state::<S>),
+        )
+        .route(
+            &format!("{CHANNEL}/packet_commitments"),
+            get(packet_commitments::<S>),
+        )
+        .route(
+            &format!("{CHANNEL}/packet_commitments/:sequence"),
+            get(packet_commitment::<S>),
+        )
+        .route(
+            &format!(
+                "{CHANNEL}/packet_commitments/:packet_commitment_sequences/unreceived_packets"
+            ),
+            get(unreceived_packets::<S>),
+        )
+        .route(
+            &format!("{CHANNEL}/packet_commitments/:packet_ack_sequences/unreceived_acks"),
+            get(unreceived_acks::<S>),
+        )
+        .route(
+            &format!("{CHANNEL}/packet_receipts/:sequence"),
+            get(packet_receipt::<S>),
+        )
+        .route(
+            &format!("{CHANNEL}/packet_acks/:sequence"),
+            get(packet_acknowledgement::<S>),
+        )
+        .route(
+            &format!("{CHANNEL}/packet_acknowledgements"),
+            get(packet_acknowledgements::<S>),
+        )
+        .route(
+            &format!("{CHANNEL}/next_sequence"),
+            get(next_sequence_receive::<S>),
+        )
+        .route(
+            &format!("{CHANNEL}/next_sequence_send"),
+            get(next_sequence_send::<S>),
+        )
+        .route(&format!("{CHANNEL}/upgrade_error"), get(upgrade_error::<S>))
+        .route(&format!("{CHANNEL}/upgrade"), get(upgrade::<S>))
+        .route("/ibc/core/channel/v1/params", get(channel_params::<S>))
+        .with_state(Arc::new(service))
+}
+
+/// Returns the value of the given key of the query string, if any.
+pub(crate) fn param<'a>(params: &'a [(String, String)], key: &str) -> Option<&'a str> {
+    params
+        .iter()
+        .find(|(param_key, _)| param_key == key)
+        .map(|(_, value)| value.as_str())
+}
+
+/// Parses the value of the given parameter of the request.
+fn parse<T: FromStr>(name: &str, value: &str) -> Result<T, Status> {
+    value
+        .parse()
+        .map_err(|_| Status::invalid_argument(format!("invalid {name}: {value}")))
+}
+
+/// Parses the value of the given key of the query string, if any.
+fn parse_param<T: FromStr>(params: &[(String, String)], key: &str) -> Result<Option<T>, Status> {
+    param(params, key)
+        .map(|value| parse(key, value))
+        .transpose()
+}
+
+/// Parses the sequences of a path parameter, separated by commas, as the gRPC
+/// gateway does for the repeated fields.
+fn parse_sequences(name: &str, value: &str) -> Result<Vec<u64>, Status> {
+    value
+        .split(',')
+        .filter(|sequence| !sequence.is_empty())
+        .map(|sequence| parse(name, sequence))
+        .collect()
+}
+
+/// Parses the sequences of the given key of the query string, either repeated
+/// or separated by commas.
+fn parse_sequences_param(params: &[(String, String)], key: &str) -> Result<Vec<u64>, Status> {
+    params
+        .iter()
+        .filter(|(param_key, _)| param_key == key)
+        .map(|(_, value)| parse_sequences(key, value))
+        .collect::<Result<Vec<_>, _>>()
+        .map(|sequences| sequences.concat())
+}
+
+/// Parses the `pagination.*` keys of the query string into the page request,
+/// if any of them is given.
+fn pagination(params: &[(String, String)]) -> Result<Option<PageRequest>, Status> {
+    if !params.iter().any(|(key, _)| key.starts_with("pagination.")) {
+        return Ok(None);
+    }
+
+    let key = param(params, "pagination.key")
+        .map(|key| {
+            STANDARD
+                .decode(key)
+                .map_err(|_| Status::invalid_argument(format!("invalid pagination.key: {key}")))
+        })
+        .transpose()?
+        .unwrap_or_default();
+
+    Ok(Some(PageRequest {
+        key,
+        offset: parse_param(params, "pagination.offset")?.unwrap_or_default(),
+        limit: parse_param(params, "pagination.limit")?.unwrap_or_default(),
+        count_total: parse_param(params, "pagination.count_total")?.unwrap_or_default(),
+        reverse: parse_param(params, "pagination.reverse")?.unwrap_or_default(),
+    }))
+}
+
+async fn client_state<S: ClientQuery>(
+    State(service): State<Arc<S>>,
+    Path(client_id): Path<String>,
+) -> RestResult<QueryClientStateResponse> {
+    let request = QueryClientStateRequest { client_id };
+
+    Ok(Json(
+        service
+            .client_state(Request::new(request))
+            .await?
+            .into_inner(),
+    ))
+}
+
+async fn client_states<S: ClientQuery>(
+    State(service): State<Arc<S>>,
+    Query(params): Params,
+) -> RestResult<QueryClientStatesResponse> {
+    let request = QueryClientStatesRequest {
+        pagination: pagination(&params)?,
+    };
+
+    Ok(Json(
+        service
+            .client_states(Request::new(request))
+            .await?
+            .into_inner(),
+    ))
+}
+
+async fn consensus_state<S: ClientQuery>(
+    State(service): State<Arc<S>>,
+    Path((client_id, revision_number, revision_height)): Path<(String, String, String)>,
+    Query(params): Params,
+) -> RestResult<QueryConsensusStateResponse> {
+    let request = QueryConsensusStateRequest {
+        client_id,
+        revision_number: parse("revision_number", &revision_number)?,
+        revision_height: parse("revision_height", &revision_height)?,
+        latest_height: parse_param(&params, "latest_height")?.unwrap_or_default(),
+    };
+
+    Ok(Json(
+        service
+            .consensus_state(Request::new(request))
+            .await?
+            .into_inner(),
+    ))
+}
+
+async fn consensus_states<S: ClientQuery>(
+    State(service): State<Arc<S>>,
+    Path(client_id): Path<String>,
+    Query(params): Params,
+) -> RestResult<QueryConsensusStatesResponse> {
+    let request = QueryConsensusStatesRequest {
+        client_id,
+        pagination: pagination(&params)?,
+    };
+
+    Ok(Json(
+        service
+            .consensus_states(Request::new(request))
+            .await?
+            .into_inner(),
+    ))
+}
+
+async fn consensus_state_heights<S: ClientQuery>(
+    State(service): State<Arc<S>>,
+    Path(client_id): Path<String>,
+    Query(params): Params,
+) -> RestResult<QueryConsensusStateHeightsResponse> {
+    let request = QueryConsensusStateHeightsRequest {
+        client_id,
+        pagination: pagination(&params)?,
+    };
+
+    Ok(Json(
+        service
+            .consensus_state_heights(Request::new(request))
+            .await?
+            .into_inner(),
+    ))
+}
+
+async fn client_status<S: ClientQuery>(
+    State(service): State<Arc<S>>,
+    Path(client_id): Path<String>,
+) -> RestResult<QueryClientStatusResponse> {
+    let request = QueryClientStatusRequest { client_id };
+
+    Ok(Json(
+        service
+            .client_status(Request::new(request))
+            .await?
+            .into_inner(),
+    ))
+}
+
+async fn client_params<S: ClientQuery>(
+    State(service): State<Arc<S>>,
+) -> RestResult<QueryClientParamsResponse> {
+    let request = QueryClientParamsRequest {};
+
+    Ok(Json(
+        service
+            .client_params(Request::new(request))
+            .await?
+            .into_inner(),
+    ))
+}
+
+async fn upgraded_client_state<S: ClientQuery>(
+    State(service): State<Arc<S>>,
+) -> RestResult<QueryUpgradedClientStateResponse> {
+    let request = QueryUpgradedClientStateRequest {};
+
+    Ok(Json(
+        service
+            .upgraded_client_state(Request::new(request))
+            .await?
+            .into_inner(),
+    ))
+}
+
+async fn upgraded_consensus_state<S: ClientQuery>(
+    State(service): State<Arc<S>>,
+) -> RestResult<QueryUpgradedConsensusStateResponse> {
+    let request = QueryUpgradedConsensusStateRequest {};
+
+    Ok(Json(
+        service
+            .upgraded_consensus_state(Request::new(request))
+            .await?
+            .into_inner(),
+    ))
+}
+
+async fn connection<S: ConnectionQuery>(
+    State(service): State<Arc<S>>,
+    Path(connection_id): Path<String>,
+) -> RestResult<QueryConnectionResponse> {
+    let request = QueryConnectionRequest { connection_id };
+
+    Ok(Json(
+        service
+            .connection(Request::new(request))
+            .await?
+            .into_inner(),
+    ))
+}
+
+async fn connections<S: ConnectionQuery>(
+    State(service): State<Arc<S>>,
+    Query(params): Params,
+) -> RestResult<QueryConnectionsResponse> {
+    let request = QueryConnectionsRequest {
+        pagination: pagination(&params)?,
+    };
+
+    Ok(Json(
+        service
+            .connections(Request::new(request))
+            .await?
+            .into_inner(),
+    ))
+}
+
+async fn client_connections<S: ConnectionQuery>(
+    State(service): State<Arc<S>>,
+    Path(client_id): Path<String>,
+) -> RestResult<QueryClientConnectionsResponse> {
+    let request = QueryClientConnectionsRequest { client_id };
+
+    Ok(Json(
+        service
+            .client_connections(Request::new(request))
+            .await?
+            .into_inner(),
+    ))
+}
+
+async fn connection_client_state<S: ConnectionQuery>(
+    State(service): State<Arc<S>>,
+    Path(connection_id): Path<String>,
+) -> RestResult<QueryConnectionClientStateResponse> {
+    let request = QueryConnectionClientStateRequest { connection_id };
+
+    Ok(Json(
+        service
+            .connection_client_state(Request::new(request))
+            .await?
+            .into_inner(),
+    ))
+}
+
+async fn connection_consensus_state<S: ConnectionQuery>(
+    State(service): State<Arc<S>>,
+    Path((connection_id, revision_number, revision_height)): Path<(String, String, String)>,
+) -> RestResult<QueryConnectionConsensusStateResponse> {
+    let request = QueryConnectionConsensusStateRequest {
+        connection_id,
+        revision_number: parse("revision_number", &revision_number)?,
+        revision_height: parse("revision_height", &revision_height)?,
+    };
+
+    Ok(Json(
+        service
+            .connection_consensus_state(Request::new(request))
+            .await?
+            .into_inner(),
+    ))
+}
+
+async fn connection_params<S: ConnectionQuery>(
+    State(service): State<Arc<S>>,
+) -> RestResult<QueryConnectionParamsResponse> {
+    let request = QueryConnectionParamsRequest {};
+
+    Ok(Json(
+        service
+            .connection_params(Request::new(request))
+            .await?
+            .into_inner(),
+    ))
+}
+
+async fn channel<S: ChannelQuery>(
+    State(service): State<Arc<S>>,
+    Path((channel_id, port_id)): Path<(String, String)>,
+) -> RestResult<QueryChannelResponse> {
+    let request = QueryChannelRequest {
+        port_id,
+        channel_id,
+    };
+
+    Ok(Json(
+        service.channel(Request::new(request)).await?.into_inner(),
+    ))
+}
+
+async fn channels<S: ChannelQuery>(
+    State(service): State<Arc<S>>,
+    Query(params): Params,
+) -> RestResult<QueryChannelsResponse> {
+    let request = QueryChannelsRequest {
+        pagination: pagination(&params)?,
+    };
+
+    Ok(Json(
+        service.channels(Request::new(request)).await?.into_inner(),
+    ))
+}
+
+async fn connection_channels<S: ChannelQuery>(
+    State(service): State<Arc<S>>,
+    Path(connection): Path<String>,
+    Query(params): Params,
+) -> RestResult<QueryConnectionChannelsResponse> {
+    let request = QueryConnectionChannelsRequest {
+        connection,
+        pagination: pagination(&params)?,
+    };
+
+    Ok(Json(
+        service
+            .connection_channels(Request::new(request))
+            .await?
+            .into_inner(),
+    ))
+}
+
+async fn channel_client_state<S: ChannelQuery>(
+    State(service): State<Arc<S>>,
+    Path((channel_id, port_id)): Path<(String, String)>,
+) -> RestResult<QueryChannelClientStateResponse> {
+    let request = QueryChannelClientStateRequest {
+        port_id,
+        channel_id,
+    };
+
+    Ok(Json(
+        service
+            .channel_client_state(Request::new(request))
+            .await?
+            .into_inner(),
+    ))
+}
+
+async fn channel_consensus_state<S: ChannelQuery>(
+    State(service): State<Arc<S>>,
+    Path((channel_id, port_id, revision_number, revision_height)): Path<(
+        String,
+        String,
+        String,
+        String,
+    )>,
+) -> RestResult<QueryChannelConsensusStateResponse> {
+    let request = QueryChannelConsensusStateRequest {
+        port_id,
+        channel_id,
+        revision_number: parse("revision_number", &revision_number)?,
+        revision_height: parse("revision_height", &revision_height)?,
+    };
+
+    Ok(Json(
+        service
+            .channel_consensus_state(Request::new(request))
+            .await?
+            .into_inner(),
+    ))
+}
+
+async fn packet_commitment<S: ChannelQuery>(
+    State(service): State<Arc<S>>,
+    Path((channel_id, port_id, sequence)): Path<(String, String, String)>,
+) -> RestResult<QueryPacketCommitmentResponse> {
+    let request = QueryPacketCommitmentRequest {
+        port_id,
+        channel_id,
+        sequence: parse("sequence", &sequence)?,
+    };
+
+    Ok(Json(
+        service
+            .packet_commitment(Request::new(request))
+            .await?
+            .into_inner(),
+    ))
+}
+
+async fn packet_commitments<S: ChannelQuery>(
+    State(service): State<Arc<S>>,
+    Path((channel_id, port_id)): Path<(String, String)>,
+    Query(params): Params,
+) -> RestResult<QueryPacketCommitmentsResponse> {
+    let request = QueryPacketCommitmentsRequest {
+        port_id,
+        channel_id,
+        pagination: pagination(&params)?,
+    };
+
+    Ok(Json(
+        service
+            .packet_commitments(Request::new(request))
+            .await?
+            .into_inner(),
+    ))
+}
+
+async fn packet_receipt<S: ChannelQuery>(
+    State(service): State<Arc<S>>,
+    Path((channel_id, port_id, sequence)): Path<(String, String, String)>,
+) -> RestResult<QueryPacketReceiptResponse> {
+    let request = QueryPacketReceiptRequest {
+        port_id,
+        channel_id,
+        sequence: parse("sequence", &sequence)?,
+    };
+
+    Ok(Json(
+        service
+            .packet_receipt(Request::new(request))
+            .await?
+            .into_inner(),
+    ))
+}
+
+async fn packet_acknowledgement<S: ChannelQuery>(
+    State(service): State<Arc<S>>,
+    Path((channel_id, port_id, sequence)): Path<(String, String, String)>,
+) -> RestResult<QueryPacketAcknowledgementResponse> {
+    let request = QueryPacketAcknowledgementRequest {
+        port_id,
+        channel_id,
+        sequence: parse("sequence", &sequence)?,
+    };
+
+    Ok(Json(
+        service
+            .packet_acknowledgement(Request::new(request))
+            .await?
+            .into_inner(),
+    ))
+}
+
+async fn packet_acknowledgements<S: ChannelQuery>(
+    State(service): State<Arc<S>>,
+    Path((channel_id, port_id)): Path<(String, String)>,
+    Query(params): Params,
+) -> RestResult<QueryPacketAcknowledgementsResponse> {
+    let request = QueryPacketAcknowledgementsRequest {
+        port_id,
+        channel_id,
+        pagination: pagination(&params)?,
+        packet_commitment_sequences: parse_sequences_param(&params, "packet_commitment_sequences")?,
+    };
+
+    Ok(Json(
+        service
+            .packet_acknowledgements(Request::new(request))
+            .await?
+            .into_inner(),
+    ))
+}
+
+async fn unreceived_packets<S: ChannelQuery>(
+    State(service): State<Arc<S>>,
+    Path((channel_id, port_id, sequences)): Path<(String, String, String)>,
+) -> RestResult<QueryUnreceivedPacketsResponse> {
+    let request = QueryUnreceivedPacketsRequest {
+        port_id,
+        channel_id,
+        packet_commitment_sequences: parse_sequences("packet_commitment_sequences", &sequences)?,
+    };
+
+    Ok(Json(
+        service
+            .unreceived_packets(Request::new(request))
+            .await?
+            .into_inner(),
+    ))
+}
+
+async fn unreceived_acks<S: ChannelQuery>(
+    State(service): State<Arc<S>>,
+    Path((channel_id, port_id, sequences)): Path<(String, String, String)>,
+) -> RestResult<QueryUnreceivedAcksResponse> {
+    let request = QueryUnreceivedAcksRequest {
+        port_id,
+        channel_id,
+        packet_ack_sequences: parse_sequences("packet_ack_sequences", &sequences)?,
+    };
+
+    Ok(Json(
+        service
+            .unreceived_acks(Request::new(request))
+            .await?
+            .into_inner(),
+    ))
+}
+
+async fn next_sequence_receive<S: ChannelQuery>(
+    State(service): State<Arc<S>>,
+    Path((channel_id, port_id)): Path<(String, String)>,
+) -> RestResult<QueryNextSequenceReceiveResponse> {
+    let request = QueryNextSequenceReceiveRequest {
+        port_id,
+        channel_id,
+    };
+
+    Ok(Json(
+        service
+            .next_sequence_receive(Request::new(request))
+            .await?
+            .into_inner(),
+    ))
+}
+
+async fn next_sequence_send<S: ChannelQuery>(
+    State(service): State<Arc<S>>,
+    Path((channel_id, port_id)): Path<(String, String)>,
+) -> RestResult<QueryNextSequenceSendResponse> {
+    let request = QueryNextSequenceSendRequest {
+        port_id,
+        channel_id,
+    };
+
+    Ok(Json(
+        service
+            .next_sequence_send(Request::new(request))
+            .await?
+            .into_inner(),
+    ))
+}
+
+async fn upgrade_error<S: ChannelQuery>(
+    State(service): State<Arc<S>>,
+    Path((channel_id, port_id)): Path<(String, String)>,
+) -> RestResult<QueryUpgradeErrorResponse> {
+    let request = QueryUpgradeErrorRequest {
+        port_id,
+        channel_id,
+    };
+
+    Ok(Json(
+        service
+            .upgrade_error(Request::new(request))
+            .await?
+            .into_inner(),
+    ))
+}
+
+async fn upgrade<S: ChannelQuery>(
+    State(service): State<Arc<S>>,
+    Path((channel_id, port_id)): Path<(String, String)>,
+) -> RestResult<QueryUpgradeResponse> {
+    let request = QueryUpgradeRequest {
+        port_id,
+        channel_id,
+    };
+
+    Ok(Json(
+        service.upgrade(Request::new(request)).await?.into_inner(),
+    ))
+}
+
+async fn channel_params<S: ChannelQuery>(
+    State(service): State<Arc<S>>,
+) -> RestResult<QueryChannelParamsResponse> {
+    let request = QueryChannelParamsRequest {};
+
+    Ok(Json(
+        service
+            .channel_params(Request::new(request))
+            .await?
+            .into_inner(),
+    ))
+}
@@ -0,0 +1,230 @@
+//! Provides the subscriptions to the IBC events as they are committed, for the
+//! relayers to be pushed the events they are interested in instead of polling
+//! the blocks of the host.
+//!
+//! The host publishes the events recorded by its [`EventIndex`] to the
+//! [`EventSubscriptions`], which forward them to the subscribers whose
+//! [`EventQuery`] they match, either in process or over a WebSocket.
+//!
+//! ```rust,ignore
+//! let subscriptions = EventSubscriptions::new(1024);
+//!
+//! // On each committed block:
+//! let recorded = index.record(height, events)?;
+//! subscriptions.publish(recorded);
+//!
+//! // The relayers subscribe to `/ibc/events/subscribe?kind=send_packet&channel_id=channel-3`.
+//! let rest_server = axum::Server::bind(&addr)
+//!     .serve(subscription_routes(subscriptions).into_make_service());
+//! ```
+//!
+//! [`EventIndex`]: crate::events::EventIndex
+
+use core::str::FromStr;
+
+use axum::extract::ws::{Message, WebSocket, WebSocketUpgrade};
+use axum::extract::{Query, State};
+use axum::response::{IntoResponse, Response};
+use axum::routing::get;
+use axum::Router;
+use ibc::core::host::types::identifiers::{ChannelId, ClientId, Sequence};
+use ibc::core::primitives::prelude::*;
+use serde::Serialize;
+use tokio::sync::broadcast;
+use tonic::Status;
+
+use crate::error::QueryError;
+use crate::events::{EventQuery, IndexedEvent};
+use crate::rest::{param, Params, RestError};
+
+/// The publisher of the committed IBC events to their subscribers.
+///
+/// The events are buffered for each subscriber up to the given capacity, past
+/// which the subscribers lagging behind skip the oldest of them.
+#[derive(Clone, Debug)]
+pub struct EventSubscriptions {
+    sender: broadcast::Sender<IndexedEvent>,
+}
+
+impl EventSubscriptions {
+    pub fn new(capacity: usize) -> Self {
+        let (sender, _) = broadcast::channel(capacity);
+
+        Self { sender }
+    }
+
+    /// Publishes the committed events, in the order they were emitted, to the
+    /// current subscribers.
+    pub fn publish(&self, events: impl IntoIterator<Item = IndexedEvent>) {
+        for event in events {
+            // Publishing fails only if there is no subscriber.
+            let _ = self.sender.send(event);
+        }
+    }
+
+    /// Subscribes to the events published from now on which match the
+    /// query.
+    pub fn subscribe(&self, query: EventQuery) -> EventSubscription {
+        EventSubscription {
+            remaining: query.limit,
+            query,
+            receiver: self.sender.subscribe(),
+        }
+    }
+
+    /// Returns the number of current subscribers.
+    pub fn subscriber_count(&self) -> usize {
+        self.sender.receiver_count()
+    }
+}
+
+/// A subscription to the events matching an [`EventQuery`], ending once the
+/// limit of the query, if any, is reached.
+#[derive(Debug)]
+pub struct EventSubscription {
+    query: EventQuery,
+    remaining: Option<usize>,
+    receiver: broadcast::Receiver<IndexedEvent>,
+}
+
+impl EventSubscription {
+    /// Returns the next event matching the query, or `None` once the
+    /// subscription ends.
+    ///
+    /// Fails if the subscriber lagged behind the published events, in which
+    /// case it can query the skipped events from the [`EventIndex`] and keep
+    /// receiving the next ones.
+    ///
+    /// [`EventIndex`]: crate::events::EventIndex
+    pub async fn next(&mut self) -> Result<Option<IndexedEvent>, QueryError> {
+        if self.remaining == Some(0) {
+            return Ok(None);
+        }
+
+        loop {
+            match self.receiver.recv().await {
+                Ok(event) if self.query.matches(&event) => {
+                    if let Some(remaining) = self.remaining.as_mut() {
+                        *remaining -= 1;
+                    }
+
+                    return Ok(Some(event));
+                }
+                Ok(_) => {}
+                Err(broadcast::error::RecvError::Closed) => return Ok(None),
+                Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                    return Err(QueryError::SubscriptionLagged { skipped })
+                }
+            }
+        }
+    }
+}
+
+/// An event pushed to the WebSocket subscribers, with its attributes as in
+/// the events of Tendermint.
+#[derive(Serialize)]
+struct EventMessage<'a> {
+    height: String,
+    position: u64,
+    kind: &'a str,
+    attributes: Vec<EventAttribute<'a>>,
+}
+
+#[derive(Serialize)]
+struct EventAttribute<'a> {
+    key: &'a str,
+    value: &'a str,
+}
+
+impl<'a> From<&'a IndexedEvent> for EventMessage<'a> {
+    fn from(event: &'a IndexedEvent) -> Self {
+        Self {
+            height: event.height.to_string(),
+            position: event.position,
+            kind: event.kind(),
+            attributes: event
+                .attributes
+                .iter()
+                .map(|(key, value)| EventAttribute { key, value })
+                .collect(),
+        }
+    }
+}
+
+/// Returns the route of the WebSocket subscriptions to the published events,
+/// `/ibc/events/subscribe`.
+///
+/// The query string of the subscription sets its [`EventQuery`]: the `kind`
+/// of the events, and optionally the `client_id`, `channel_id` and
+/// `packet_sequence` of the events, and the `limit` of the subscription. The
+/// events are pushed as JSON text messages, and the socket is closed once the
+/// subscription ends, or if the subscriber lags behind the published events.
+pub fn subscription_routes(subscriptions: EventSubscriptions) -> Router {
+    Router::new()
+        .route("/ibc/events/subscribe", get(subscribe))
+        .with_state(subscriptions)
+}
+
+/// Parses the query string of a subscription into its event query.
+fn event_query(params: &[(String, String)]) -> Result<EventQuery, Status> {
+    let kind = param(params, "kind").ok_or_else(|| Status::invalid_argument("missing kind"))?;
+
+    let mut query = EventQuery::new(kind);
+
+    if let Some(client_id) = param(params, "client_id") {
+        let client_id =
+            ClientId::from_str(client_id).map_err(|e| Status::invalid_argument(e.to_string()))?;
+        query = query.client(&client_id);
+    }
+
+    if let Some(channel_id) = param(params, "channel_id") {
+        let channel_id =
+            ChannelId::from_str(channel_id).map_err(|e| Status::invalid_argument(e.to_string()))?;
+        query = query.channel(&channel_id);
+    }
+
+    if let Some(sequence) = param(params, "packet_sequence") {
+        let sequence =
+            Sequence::from_str(sequence).map_err(|e| Status::invalid_argument(e.to_string()))?;
+        query = query.sequence(sequence);
+    }
+
+    if let Some(limit) = param(params, "limit") {
+        let limit = limit
+            .parse()
+            .map_err(|_| Status::invalid_argument(format!("invalid limit: {limit}")))?;
+        query = query.limit(limit);
+    }
+
+    Ok(query)
+}
+
+async fn subscribe(
+    State(subscriptions): State<EventSubscriptions>,
+    Query(params): Params,
+    upgrade: WebSocketUpgrade,
+) -> Response {
+    let query = match event_query(&params) {
+        Ok(query) => query,
+        Err(status) => return RestError(status).into_response(),
+    };
+
+    let subscription = subscriptions.subscribe(query);
+
+    upgrade.on_upgrade(move |socket| forward_events(socket, subscription))
+}
+
+/// Pushes the events of the subscription to the socket until either ends.
+async fn forward_events(mut socket: WebSocket, mut subscription: EventSubscription) {
+    while let Ok(Some(event)) = subscription.next().await {
+        let Ok(message) = serde_json::to_string(&EventMessage::from(&event)) else {
+            break;
+        };
+
+        if socket.send(Message::Text(message)).await.is_err() {
+            return;
+        }
+    }
+
+    let _ = socket.close().await;
+}
@@ -0,0 +1,105 @@
+//! Measures the parsing and displaying of multi-hop denominations, and the
+//! removal and addition of their trace prefixes as the transfer handlers do on
+//! every received packet.
+//!
+//! Along with the timings, the number of the allocations made by each
+//! operation is printed, counted by the global allocator of the benchmark.
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::str::FromStr;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use ibc::apps::transfer::types::{PrefixedDenom, TracePrefix};
+use ibc::core::host::types::identifiers::{ChannelId, PortId};
+
+struct CountingAllocator;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+/// Returns a denomination traced over the given number of hops.
+fn multi_hop_denom(hops: u64) -> String {
+    let mut denom = String::new();
+    for hop in 0..hops {
+        denom.push_str(&format!("transfer/channel-{hop}/"));
+    }
+    denom.push_str("uatom");
+
+    denom
+}
+
+/// Receives the denomination back over its latest hop, and then over a new
+/// channel, as the transfer handlers do.
+fn receive(denom: &mut PrefixedDenom, channel_id: &ChannelId) {
+    let port_id = PortId::transfer();
+
+    if !denom.try_remove_trace_prefix(&port_id, &ChannelId::new(0)) {
+        denom.add_trace_prefix(TracePrefix::new(port_id, channel_id.clone()));
+    }
+}
+
+/// Returns the number of the allocations made by the given function.
+fn allocations(f: impl FnOnce()) -> usize {
+    let before = ALLOCATIONS.load(Ordering::Relaxed);
+    f();
+    ALLOCATIONS.load(Ordering::Relaxed) - before
+}
+
+fn denom_parsing(c: &mut Criterion) {
+    let mut group = c.benchmark_group("denom_parsing");
+
+    for hops in [1, 4, 16, 64] {
+        let denom_str = multi_hop_denom(hops);
+        let denom = PrefixedDenom::from_str(&denom_str).unwrap();
+        let channel_id = ChannelId::new(hops);
+
+        println!(
+            "denom_parsing/{hops}: parse {} allocations, display {} allocations, trace hash {} allocations",
+            allocations(|| {
+                black_box(PrefixedDenom::from_str(&denom_str).unwrap());
+            }),
+            allocations(|| {
+                black_box(denom.to_string());
+            }),
+            allocations(|| {
+                black_box(denom.trace_hash());
+            }),
+        );
+
+        group.bench_with_input(BenchmarkId::new("parse", hops), &denom_str, |b, s| {
+            b.iter(|| PrefixedDenom::from_str(black_box(s)).unwrap())
+        });
+        group.bench_with_input(BenchmarkId::new("display", hops), &denom, |b, denom| {
+            b.iter(|| black_box(denom).to_string())
+        });
+        group.bench_with_input(BenchmarkId::new("trace_hash", hops), &denom, |b, denom| {
+            b.iter(|| black_box(denom).trace_hash())
+        });
+        group.bench_with_input(BenchmarkId::new("receive", hops), &denom, |b, denom| {
+            b.iter_batched_ref(
+                || denom.clone(),
+                |denom| receive(denom, &channel_id),
+                criterion::BatchSize::SmallInput,
+            )
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, denom_parsing);
+criterion_main!(benches);
@@ -0,0 +1,106 @@
+//! Compares the decoding of the packets, into the owned `Packet` or into the
+//! borrowed `PacketView`, to compute their commitments as the handlers do.
+//!
+//! Along with the timings, the number of the allocations made by each
+//! decoding is printed, counted by the global allocator of the benchmark.
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use ibc::core::channel::types::commitment::compute_packet_commitment;
+use ibc::core::channel::types::packet::Packet;
+use ibc::core::channel::types::packet_view::PacketView;
+use ibc::core::channel::types::proto::v1::Packet as RawPacket;
+use ibc::core::channel::types::timeout::TimeoutHeight;
+use ibc::core::client::types::Height;
+use ibc::core::host::types::identifiers::{ChannelId, PortId};
+use ibc::core::primitives::Timestamp;
+use ibc::primitives::proto::Protobuf;
+
+struct CountingAllocator;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+fn encoded_packet(data_len: usize) -> Vec<u8> {
+    let packet = Packet {
+        seq_on_a: 1.into(),
+        port_id_on_a: PortId::transfer(),
+        chan_id_on_a: ChannelId::new(0),
+        port_id_on_b: PortId::transfer(),
+        chan_id_on_b: ChannelId::new(1),
+        data: vec![0xab; data_len],
+        timeout_height_on_b: TimeoutHeight::At(Height::new(0, 100).unwrap()),
+        timeout_timestamp_on_b: Timestamp::none(),
+    };
+
+    Protobuf::<RawPacket>::encode_vec(packet)
+}
+
+fn owned_commitment(bytes: &[u8]) -> Vec<u8> {
+    let packet = <Packet as Protobuf<RawPacket>>::decode_vec(bytes).unwrap();
+
+    compute_packet_commitment(
+        &packet.data,
+        &packet.timeout_height_on_b,
+        &packet.timeout_timestamp_on_b,
+    )
+    .into_vec()
+}
+
+fn borrowed_commitment(bytes: &[u8]) -> Vec<u8> {
+    PacketView::decode(bytes).unwrap().commitment().into_vec()
+}
+
+/// Returns the number of the allocations made by the given function.
+fn allocations(f: impl FnOnce()) -> usize {
+    let before = ALLOCATIONS.load(Ordering::Relaxed);
+    f();
+    ALLOCATIONS.load(Ordering::Relaxed) - before
+}
+
+fn packet_decoding(c: &mut Criterion) {
+    let mut group = c.benchmark_group("packet_decoding");
+
+    for data_len in [256, 4_096, 65_536] {
+        let bytes = encoded_packet(data_len);
+
+        println!(
+            "packet_decoding/{data_len}: owned {} allocations, borrowed {} allocations",
+            allocations(|| {
+                black_box(owned_commitment(&bytes));
+            }),
+            allocations(|| {
+                black_box(borrowed_commitment(&bytes));
+            }),
+        );
+
+        group.bench_with_input(BenchmarkId::new("owned", data_len), &bytes, |b, bytes| {
+            b.iter(|| owned_commitment(black_box(bytes)))
+        });
+        group.bench_with_input(
+            BenchmarkId::new("borrowed", data_len),
+            &bytes,
+            |b, bytes| b.iter(|| borrowed_commitment(black_box(bytes))),
+        );
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, packet_decoding);
+criterion_main!(benches);
@@ -0,0 +1,105 @@
+//! Compares the lookups of the unreceived packets and acks of a channel, one
+//! sequence at a time or by batches of the stored packet state.
+
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use ibc::core::channel::context::{PacketStateIterationContext, PacketStatusContext};
+use ibc::core::channel::types::channel::{ChannelEnd, Counterparty, Order, State};
+use ibc::core::channel::types::commitment::PacketCommitment;
+use ibc::core::channel::types::packet::Receipt;
+use ibc::core::channel::types::Version;
+use ibc::core::host::types::identifiers::{ChannelId, ConnectionId, PortId, Sequence};
+use ibc::core::host::types::path::{ChannelEndPath, CommitmentPath, ReceiptPath};
+use ibc::core::host::ExecutionContext;
+use ibc_testkit::testapp::ibc::core::types::MockContext;
+
+fn channel_end_path() -> ChannelEndPath {
+    ChannelEndPath::new(&PortId::transfer(), &ChannelId::zero())
+}
+
+/// Returns a context whose channel received, and still has the commitments
+/// of, every other packet up to the given sequence.
+fn context_with_packets(last: u64) -> MockContext {
+    let chan_end = ChannelEnd::new(
+        State::Open,
+        Order::Unordered,
+        Counterparty::new(PortId::transfer(), Some(ChannelId::new(1))),
+        vec![ConnectionId::zero()],
+        Version::new("ics20-1".to_string()),
+    )
+    .unwrap();
+
+    let mut ctx =
+        MockContext::default().with_channel(PortId::transfer(), ChannelId::zero(), chan_end);
+    let chan_end_path = channel_end_path();
+
+    for seq in (1..=last).step_by(2) {
+        ctx.store_packet_receipt(
+            &ReceiptPath::new(&chan_end_path.0, &chan_end_path.1, seq.into()),
+            Receipt::Ok,
+        )
+        .unwrap();
+        ctx.store_packet_commitment(
+            &CommitmentPath::new(&chan_end_path.0, &chan_end_path.1, seq.into()),
+            PacketCommitment::from(vec![1]),
+        )
+        .unwrap();
+    }
+
+    ctx
+}
+
+fn unreceived_packets(c: &mut Criterion) {
+    let mut group = c.benchmark_group("unreceived_packets");
+    let chan_end_path = channel_end_path();
+
+    for last in [100, 1_000, 10_000] {
+        let ctx = context_with_packets(last);
+        let sequences: Vec<Sequence> = (1..=last).map(Sequence::from).collect();
+
+        group.bench_with_input(BenchmarkId::new("per_sequence", last), &last, |b, _| {
+            b.iter(|| {
+                ctx.unreceived_packet_sequences(&chan_end_path, black_box(sequences.clone()))
+                    .unwrap()
+            })
+        });
+        group.bench_with_input(BenchmarkId::new("batched", last), &last, |b, _| {
+            b.iter(|| {
+                ctx.unreceived_packet_sequences_batched(
+                    &chan_end_path,
+                    black_box(sequences.clone()),
+                )
+                .unwrap()
+            })
+        });
+    }
+
+    group.finish();
+}
+
+fn unreceived_acks(c: &mut Criterion) {
+    let mut group = c.benchmark_group("unreceived_acks");
+    let chan_end_path = channel_end_path();
+
+    for last in [100, 1_000, 10_000] {
+        let ctx = context_with_packets(last);
+        let sequences: Vec<Sequence> = (1..=last).map(Sequence::from).collect();
+
+        group.bench_with_input(BenchmarkId::new("per_sequence", last), &last, |b, _| {
+            b.iter(|| {
+                ctx.unreceived_ack_sequences(&chan_end_path, black_box(sequences.clone()))
+                    .unwrap()
+            })
+        });
+        group.bench_with_input(BenchmarkId::new("batched", last), &last, |b, _| {
+            b.iter(|| {
+                ctx.unreceived_ack_sequences_batched(&chan_end_path, black_box(sequences.clone()))
+                    .unwrap()
+            })
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, unreceived_packets, unreceived_acks);
+criterion_main!(benches);
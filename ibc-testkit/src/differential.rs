@@ -0,0 +1,458 @@
+//! A differential harness submitting the same messages to an embedded
+//! [`MockContext`] and to a local ibc-go node, such as a simapp, over gRPC,
+//! and diffing the outcomes, the events and the state the handlers of both
+//! implementations leave, to validate the parity of their behaviours.
+//!
+//! Each message is submitted in a transaction of its own to the node, for a
+//! failing message not to revert the messages before it, which the embedded
+//! context applied. The transactions are signed by the [`TxSigner`] of the
+//! test, which holds the keys of an account funded on the node.
+//!
+//! ```rust,ignore
+//! let node = IbcGoNode::connect("http://127.0.0.1:9090", signer).await?;
+//!
+//! let mut harness = DifferentialHarness::new(ctx, MockRouter::new_with_transfer(), node);
+//!
+//! let mismatches = harness.submit_all(msgs).await?;
+//! let mismatches = harness.compare_state(&[StateQuery::Connection(conn_id)]).await?;
+//! ```
+//!
+//! The events are compared on the kinds ibc-rs emits, leaving out the events
+//! of the other modules of the node, such as the bank and the fees, and the
+//! `message` events, whose attributes the implementations word differently.
+
+use alloc::collections::BTreeSet;
+use core::time::Duration;
+
+use displaydoc::Display;
+use ibc::core::client::context::ClientValidationContext;
+use ibc::core::handler::types::msgs::MsgEnvelope;
+use ibc::core::host::types::identifiers::{ChannelId, ClientId, ConnectionId, PortId, Sequence};
+use ibc::core::host::types::path::{ChannelEndPath, CommitmentPath};
+use ibc::core::host::ValidationContext;
+use ibc::core::primitives::prelude::*;
+use ibc::primitives::proto::{Any, Protobuf};
+use ibc::primitives::ToVec;
+use ibc_proto::cosmos::tx::v1beta1::service_client::ServiceClient;
+use ibc_proto::cosmos::tx::v1beta1::{BroadcastMode, BroadcastTxRequest, GetTxRequest};
+use ibc_proto::ibc::core::channel::v1::query_client::QueryClient as ChannelQueryClient;
+use ibc_proto::ibc::core::channel::v1::{QueryChannelRequest, QueryPacketCommitmentRequest};
+use ibc_proto::ibc::core::client::v1::query_client::QueryClient as ClientQueryClient;
+use ibc_proto::ibc::core::client::v1::QueryClientStateRequest;
+use ibc_proto::ibc::core::connection::v1::query_client::QueryClient as ConnectionQueryClient;
+use ibc_proto::ibc::core::connection::v1::QueryConnectionRequest;
+use tonic::transport::Channel;
+use tonic::Code;
+
+use crate::fixtures::compat::{AttributeFixture, EventFixture, ExpectedOutcome};
+use crate::testapp::ibc::core::router::MockRouter;
+use crate::testapp::ibc::core::types::MockContext;
+
+/// The number of times the node is polled for a transaction it accepted,
+/// before giving up on its inclusion in a block.
+pub const TX_POLL_ATTEMPTS: u32 = 30;
+
+/// The interval between the polls of the node for a transaction.
+pub const TX_POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// The kind of the events whose attributes the implementations word
+/// differently.
+const MESSAGE_EVENT_KIND: &str = "message";
+
+/// The attribute the Cosmos SDK adds to the events of a transaction, with
+/// the index of the message which emitted them.
+const MSG_INDEX_ATTRIBUTE: &str = "msg_index";
+
+#[derive(Debug, Display)]
+pub enum DifferentialError {
+    /// invalid endpoint `{endpoint}` of the node
+    InvalidEndpoint { endpoint: String },
+    /// connecting to the node failed: `{0}`
+    Transport(tonic::transport::Error),
+    /// the node responded with an error: `{0}`
+    Status(tonic::Status),
+    /// signing the transaction failed: `{description}`
+    Signing { description: String },
+    /// the transaction `{hash}` was not included in a block
+    TxNotIncluded { hash: String },
+}
+
+impl std::error::Error for DifferentialError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match &self {
+            Self::Transport(e) => Some(e),
+            Self::Status(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+/// Signs the transactions submitted to the node, with the keys of an account
+/// funded on the node.
+pub trait TxSigner {
+    /// Returns the encoding of the `TxRaw` carrying the messages, signed
+    /// with the next sequence of the account.
+    fn sign_tx(&mut self, msgs: Vec<Any>) -> Result<Vec<u8>, String>;
+}
+
+/// A piece of the IBC state of the hosts, compared by its protobuf encoding.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum StateQuery {
+    ClientState(ClientId),
+    Connection(ConnectionId),
+    Channel(PortId, ChannelId),
+    PacketCommitment(PortId, ChannelId, Sequence),
+}
+
+/// A divergence of ibc-rs from the ibc-go node.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Mismatch {
+    /// The outcomes of the delivery of the message differ.
+    Outcome {
+        index: usize,
+        embedded: ExpectedOutcome,
+        node: ExpectedOutcome,
+    },
+    /// The encodings of the piece of state differ, `None` if the host holds
+    /// none.
+    State {
+        query: StateQuery,
+        embedded: Option<Vec<u8>>,
+        node: Option<Vec<u8>>,
+    },
+}
+
+/// A local ibc-go node, submitted transactions and queried over gRPC.
+pub struct IbcGoNode<S> {
+    channel: Channel,
+    signer: S,
+}
+
+impl<S: TxSigner> IbcGoNode<S> {
+    /// Connects to the gRPC endpoint of the node, e.g.
+    /// `http://127.0.0.1:9090`.
+    pub async fn connect(
+        endpoint: impl Into<String>,
+        signer: S,
+    ) -> Result<Self, DifferentialError> {
+        let endpoint = endpoint.into();
+
+        let channel = Channel::from_shared(endpoint.clone())
+            .map_err(|_| DifferentialError::InvalidEndpoint { endpoint })?
+            .connect()
+            .await
+            .map_err(DifferentialError::Transport)?;
+
+        Ok(Self { channel, signer })
+    }
+
+    /// Submits the messages in a transaction, waits for its inclusion in a
+    /// block, and returns its outcome.
+    pub async fn submit(&mut self, msgs: Vec<Any>) -> Result<ExpectedOutcome, DifferentialError> {
+        let tx_bytes = self
+            .signer
+            .sign_tx(msgs)
+            .map_err(|description| DifferentialError::Signing { description })?;
+
+        let mut client = ServiceClient::new(self.channel.clone());
+
+        let response = client
+            .broadcast_tx(BroadcastTxRequest {
+                tx_bytes,
+                mode: BroadcastMode::Sync as i32,
+            })
+            .await
+            .map_err(DifferentialError::Status)?
+            .into_inner()
+            .tx_response
+            .unwrap_or_default();
+
+        // The transaction was rejected by `CheckTx`.
+        if response.code != 0 {
+            return Ok(ExpectedOutcome::Failure);
+        }
+
+        for _ in 0..TX_POLL_ATTEMPTS {
+            tokio::time::sleep(TX_POLL_INTERVAL).await;
+
+            let tx_response = match client
+                .get_tx(GetTxRequest {
+                    hash: response.txhash.clone(),
+                })
+                .await
+            {
+                Ok(response) => response.into_inner().tx_response.unwrap_or_default(),
+                Err(status) if status.code() == Code::NotFound => continue,
+                Err(status) => return Err(DifferentialError::Status(status)),
+            };
+
+            if tx_response.code != 0 {
+                return Ok(ExpectedOutcome::Failure);
+            }
+
+            let events = tx_response
+                .events
+                .into_iter()
+                .map(|event| EventFixture {
+                    kind: event.r#type,
+                    attributes: event
+                        .attributes
+                        .into_iter()
+                        .map(|attribute| AttributeFixture {
+                            key: String::from_utf8_lossy(attribute.key.as_ref()).into_owned(),
+                            value: String::from_utf8_lossy(attribute.value.as_ref()).into_owned(),
+                        })
+                        .collect(),
+                })
+                .collect();
+
+            return Ok(ExpectedOutcome::Success { events });
+        }
+
+        Err(DifferentialError::TxNotIncluded {
+            hash: response.txhash,
+        })
+    }
+
+    /// Returns the encoding of the piece of state of the node, or `None` if
+    /// it holds none.
+    pub async fn query(&self, query: &StateQuery) -> Result<Option<Vec<u8>>, DifferentialError> {
+        let result = match query {
+            StateQuery::ClientState(client_id) => ClientQueryClient::new(self.channel.clone())
+                .client_state(QueryClientStateRequest {
+                    client_id: client_id.to_string(),
+                })
+                .await
+                .map(|response| response.into_inner().client_state.map(|any| any.to_vec())),
+            StateQuery::Connection(conn_id) => ConnectionQueryClient::new(self.channel.clone())
+                .connection(QueryConnectionRequest {
+                    connection_id: conn_id.to_string(),
+                })
+                .await
+                .map(|response| response.into_inner().connection.map(|end| end.to_vec())),
+            StateQuery::Channel(port_id, chan_id) => ChannelQueryClient::new(self.channel.clone())
+                .channel(QueryChannelRequest {
+                    port_id: port_id.to_string(),
+                    channel_id: chan_id.to_string(),
+                })
+                .await
+                .map(|response| response.into_inner().channel.map(|end| end.to_vec())),
+            StateQuery::PacketCommitment(port_id, chan_id, seq) => {
+                ChannelQueryClient::new(self.channel.clone())
+                    .packet_commitment(QueryPacketCommitmentRequest {
+                        port_id: port_id.to_string(),
+                        channel_id: chan_id.to_string(),
+                        sequence: u64::from(*seq),
+                    })
+                    .await
+                    .map(|response| Some(response.into_inner().commitment))
+            }
+        };
+
+        match result {
+            Ok(value) => Ok(value.filter(|value| !value.is_empty())),
+            Err(status) if status.code() == Code::NotFound => Ok(None),
+            Err(status) => Err(DifferentialError::Status(status)),
+        }
+    }
+}
+
+/// Submits the same messages to an embedded context and to an ibc-go node,
+/// and diffs their outcomes and state.
+pub struct DifferentialHarness<S> {
+    pub ctx: MockContext,
+    pub router: MockRouter,
+    pub node: IbcGoNode<S>,
+}
+
+impl<S: TxSigner> DifferentialHarness<S> {
+    pub fn new(ctx: MockContext, router: MockRouter, node: IbcGoNode<S>) -> Self {
+        Self { ctx, router, node }
+    }
+
+    /// Submits the messages in turn, and returns the mismatches of their
+    /// outcomes.
+    pub async fn submit_all(
+        &mut self,
+        msgs: impl IntoIterator<Item = Any>,
+    ) -> Result<Vec<Mismatch>, DifferentialError> {
+        let mut mismatches = Vec::new();
+
+        for (index, msg) in msgs.into_iter().enumerate() {
+            let embedded = self.deliver(msg.clone());
+
+            let node = self.node.submit(vec![msg]).await?;
+
+            let (embedded, node) = (normalize(embedded, None), normalize(node, Some(&embedded)));
+
+            if embedded != node {
+                mismatches.push(Mismatch::Outcome {
+                    index,
+                    embedded,
+                    node,
+                });
+            }
+        }
+
+        Ok(mismatches)
+    }
+
+    /// Queries the pieces of state of both hosts, and returns their
+    /// mismatches.
+    pub async fn compare_state(
+        &self,
+        queries: &[StateQuery],
+    ) -> Result<Vec<Mismatch>, DifferentialError> {
+        let mut mismatches = Vec::new();
+
+        for query in queries {
+            let embedded = self.query(query);
+            let node = self.node.query(query).await?;
+
+            if embedded != node {
+                mismatches.push(Mismatch::State {
+                    query: query.clone(),
+                    embedded,
+                    node,
+                });
+            }
+        }
+
+        Ok(mismatches)
+    }
+
+    fn deliver(&mut self, msg: Any) -> ExpectedOutcome {
+        let Ok(envelope) = MsgEnvelope::try_from(msg) else {
+            return ExpectedOutcome::Failure;
+        };
+
+        let emitted = self.ctx.get_events().len();
+
+        if self.ctx.deliver(&mut self.router, envelope).is_err() {
+            return ExpectedOutcome::Failure;
+        }
+
+        let events = self
+            .ctx
+            .get_events()
+            .split_off(emitted)
+            .into_iter()
+            .filter_map(|event| EventFixture::try_from(event).ok())
+            .collect();
+
+        ExpectedOutcome::Success { events }
+    }
+
+    fn query(&self, query: &StateQuery) -> Option<Vec<u8>> {
+        match query {
+            StateQuery::ClientState(client_id) => self
+                .ctx
+                .client_state(client_id)
+                .ok()
+                .map(|client_state| Any::from(client_state).to_vec()),
+            StateQuery::Connection(conn_id) => self
+                .ctx
+                .connection_end(conn_id)
+                .ok()
+                .map(|conn_end| conn_end.encode_vec()),
+            StateQuery::Channel(port_id, chan_id) => self
+                .ctx
+                .channel_end(&ChannelEndPath::new(port_id, chan_id))
+                .ok()
+                .map(|chan_end| chan_end.encode_vec()),
+            StateQuery::PacketCommitment(port_id, chan_id, seq) => self
+                .ctx
+                .get_packet_commitment(&CommitmentPath::new(port_id, chan_id, *seq))
+                .ok()
+                .map(|commitment| commitment.into_vec()),
+        }
+    }
+}
+
+/// Leaves out the `message` events and the `msg_index` attributes of the
+/// outcome, and, if given the outcome of the embedded context, the events of
+/// the kinds it did not emit.
+fn normalize(outcome: ExpectedOutcome, embedded: Option<&ExpectedOutcome>) -> ExpectedOutcome {
+    let ExpectedOutcome::Success { events } = outcome else {
+        return ExpectedOutcome::Failure;
+    };
+
+    let kinds: Option<BTreeSet<&str>> = match embedded {
+        Some(ExpectedOutcome::Success { events }) => {
+            Some(events.iter().map(|event| event.kind.as_str()).collect())
+        }
+        _ => None,
+    };
+
+    let events = events
+        .into_iter()
+        .filter(|event| event.kind != MESSAGE_EVENT_KIND)
+        .filter(|event| {
+            kinds
+                .as_ref()
+                .map_or(true, |kinds| kinds.contains(event.kind.as_str()))
+        })
+        .map(|event| EventFixture {
+            kind: event.kind,
+            attributes: event
+                .attributes
+                .into_iter()
+                .filter(|attribute| attribute.key != MSG_INDEX_ATTRIBUTE)
+                .collect(),
+        })
+        .collect();
+
+    ExpectedOutcome::Success { events }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn event(kind: &str, attributes: &[(&str, &str)]) -> EventFixture {
+        EventFixture {
+            kind: kind.to_string(),
+            attributes: attributes
+                .iter()
+                .map(|(key, value)| AttributeFixture {
+                    key: key.to_string(),
+                    value: value.to_string(),
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn node_events_are_normalized_on_the_embedded_kinds() {
+        let embedded = ExpectedOutcome::Success {
+            events: vec![
+                event("create_client", &[("client_id", "07-tendermint-0")]),
+                event("message", &[("module", "ibc_client")]),
+            ],
+        };
+
+        let node = ExpectedOutcome::Success {
+            events: vec![
+                event("coin_spent", &[("amount", "200stake")]),
+                event(
+                    "create_client",
+                    &[("client_id", "07-tendermint-0"), ("msg_index", "0")],
+                ),
+                event(
+                    "message",
+                    &[("action", "/ibc.core.client.v1.MsgCreateClient")],
+                ),
+            ],
+        };
+
+        let embedded = normalize(embedded, None);
+
+        assert_eq!(
+            embedded,
+            ExpectedOutcome::Success {
+                events: vec![event("create_client", &[("client_id", "07-tendermint-0")])],
+            }
+        );
+        assert_eq!(normalize(node, Some(&embedded)), embedded);
+    }
+}
@@ -0,0 +1,421 @@
+//! Matchers of the events emitted by the handlers, for the tests to assert on
+//! the events they expect by their kinds and a few of their fields, rather
+//! than by comparing the whole events or their string attributes.
+//!
+//! ```rust,ignore
+//! assert_events!(ctx, [
+//!     CreateClient { client: "07-tendermint-0" },
+//!     SendPacket { channel: "channel-0", seq: 1.. },
+//!     ..
+//! ]);
+//! ```
+//!
+//! A matcher names the variant of [`IbcEvent`] it matches, or the kind of the
+//! event for the module events, e.g. `fungible_token_packet`. Its fields are
+//! matched against the attributes of the event:
+//!
+//! - `seq` and `sequence` against the sequence of the packet,
+//! - `port`, `channel` and their `counterparty_` variants against the ports
+//!   and channels of the packet on the chain emitting the event, and on its
+//!   counterparty,
+//! - any other field `f` against the attribute `f`, `f_id` or `packet_f`,
+//!   the first one the event carries.
+//!
+//! The fields are matched against a string, exactly, or against an integer or
+//! a range of integers, e.g. `seq: 1..=3`.
+//!
+//! The `message` events are left out. The matchers must match the events in
+//! order, with no event left over unless the list of matchers ends with `..`.
+
+use core::fmt::{Display, Error as FmtError, Formatter};
+use core::ops::{Bound, Range, RangeBounds, RangeFrom, RangeInclusive, RangeTo, RangeToInclusive};
+
+use ibc::core::handler::types::events::IbcEvent;
+use ibc::core::host::types::identifiers::Sequence;
+use ibc::core::primitives::prelude::*;
+use tendermint::abci;
+
+/// The kind of the events emitted along with each message.
+const MESSAGE_EVENT_KIND: &str = "message";
+
+/// A matcher of the value of an attribute of an event.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum FieldMatcher {
+    /// Matches the values equal to the string.
+    Exact(String),
+    /// Matches the integer values within the bounds.
+    Range {
+        start: Bound<i128>,
+        end: Bound<i128>,
+    },
+}
+
+impl FieldMatcher {
+    pub fn matches(&self, value: &str) -> bool {
+        match self {
+            Self::Exact(expected) => expected == value,
+            Self::Range { start, end } => value
+                .parse::<i128>()
+                .map(|value| (*start, *end).contains(&value))
+                .unwrap_or(false),
+        }
+    }
+}
+
+impl Display for FieldMatcher {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), FmtError> {
+        match self {
+            Self::Exact(expected) => write!(f, "{expected:?}"),
+            Self::Range { start, end } => {
+                if let Bound::Included(start) = start {
+                    write!(f, "{start}")?;
+                }
+                match end {
+                    Bound::Included(end) => write!(f, "..={end}"),
+                    Bound::Excluded(end) => write!(f, "..{end}"),
+                    Bound::Unbounded => write!(f, ".."),
+                }
+            }
+        }
+    }
+}
+
+impl From<&str> for FieldMatcher {
+    fn from(value: &str) -> Self {
+        Self::Exact(value.to_string())
+    }
+}
+
+impl From<String> for FieldMatcher {
+    fn from(value: String) -> Self {
+        Self::Exact(value)
+    }
+}
+
+impl From<Sequence> for FieldMatcher {
+    fn from(value: Sequence) -> Self {
+        Self::Exact(value.to_string())
+    }
+}
+
+/// Implements the conversions of the integers and of their ranges into the
+/// matchers, the integer literals falling back to `i32`.
+macro_rules! impl_integer_matchers {
+    ($($int:ty),*) => {
+        $(
+            impl From<$int> for FieldMatcher {
+                fn from(value: $int) -> Self {
+                    Self::Exact(value.to_string())
+                }
+            }
+
+            impl From<Range<$int>> for FieldMatcher {
+                fn from(range: Range<$int>) -> Self {
+                    Self::Range {
+                        start: Bound::Included(range.start.into()),
+                        end: Bound::Excluded(range.end.into()),
+                    }
+                }
+            }
+
+            impl From<RangeFrom<$int>> for FieldMatcher {
+                fn from(range: RangeFrom<$int>) -> Self {
+                    Self::Range {
+                        start: Bound::Included(range.start.into()),
+                        end: Bound::Unbounded,
+                    }
+                }
+            }
+
+            impl From<RangeInclusive<$int>> for FieldMatcher {
+                fn from(range: RangeInclusive<$int>) -> Self {
+                    let (start, end) = range.into_inner();
+
+                    Self::Range {
+                        start: Bound::Included(start.into()),
+                        end: Bound::Included(end.into()),
+                    }
+                }
+            }
+
+            impl From<RangeTo<$int>> for FieldMatcher {
+                fn from(range: RangeTo<$int>) -> Self {
+                    Self::Range {
+                        start: Bound::Unbounded,
+                        end: Bound::Excluded(range.end.into()),
+                    }
+                }
+            }
+
+            impl From<RangeToInclusive<$int>> for FieldMatcher {
+                fn from(range: RangeToInclusive<$int>) -> Self {
+                    Self::Range {
+                        start: Bound::Unbounded,
+                        end: Bound::Included(range.end.into()),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_integer_matchers!(i32, u64);
+
+/// A matcher of an event, by its kind and some of its fields.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EventMatcher {
+    pub kind: String,
+    pub fields: Vec<(String, FieldMatcher)>,
+}
+
+impl EventMatcher {
+    /// Creates a matcher of the events of the variant of [`IbcEvent`], or of
+    /// the kind of module events, of the given name.
+    pub fn new(kind: impl Into<String>) -> Self {
+        Self {
+            kind: kind.into(),
+            fields: Vec::new(),
+        }
+    }
+
+    /// Matches the field of the events against the matcher.
+    pub fn field(mut self, name: impl Into<String>, matcher: impl Into<FieldMatcher>) -> Self {
+        self.fields.push((name.into(), matcher.into()));
+        self
+    }
+
+    pub fn matches(&self, event: &IbcEvent) -> bool {
+        self.mismatches(event).is_empty()
+    }
+
+    /// Returns the descriptions of the differences between the event and the
+    /// matcher, empty if it matches the event.
+    pub fn mismatches(&self, event: &IbcEvent) -> Vec<String> {
+        if self.kind != variant_name(event) && self.kind != event.event_type() {
+            return vec![format!(
+                "expected a `{}` event, found a `{}` event",
+                self.kind,
+                variant_name(event)
+            )];
+        }
+
+        let attributes = attributes(event);
+
+        self.fields
+            .iter()
+            .filter_map(|(name, matcher)| {
+                let Some((key, value)) = attribute_keys(event, name).into_iter().find_map(|key| {
+                    attributes
+                        .iter()
+                        .find(|(attribute_key, _)| *attribute_key == key)
+                        .map(|(_, value)| (key, value))
+                }) else {
+                    return Some(format!(
+                        "field `{name}`: the event carries no such attribute"
+                    ));
+                };
+
+                (!matcher.matches(value)).then(|| {
+                    format!("field `{name}` (`{key}`): expected {matcher}, found {value:?}")
+                })
+            })
+            .collect()
+    }
+}
+
+impl Display for EventMatcher {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), FmtError> {
+        write!(f, "{}", self.kind)?;
+
+        if self.fields.is_empty() {
+            return Ok(());
+        }
+
+        let fields: Vec<_> = self
+            .fields
+            .iter()
+            .map(|(name, matcher)| format!("{name}: {matcher}"))
+            .collect();
+
+        write!(f, " {{ {} }}", fields.join(", "))
+    }
+}
+
+/// Asserts that the matchers match the events, in order, leaving out the
+/// `message` events, with no event left over unless `allow_more` is set.
+///
+/// The panic message lists the differences with the events, along with all
+/// the events.
+#[track_caller]
+pub fn assert_events(events: &[IbcEvent], matchers: &[EventMatcher], allow_more: bool) {
+    let events: Vec<_> = events
+        .iter()
+        .filter(|event| event.event_type() != MESSAGE_EVENT_KIND)
+        .collect();
+
+    let mut differences = Vec::new();
+
+    for (index, matcher) in matchers.iter().enumerate() {
+        match events.get(index) {
+            Some(event) => {
+                let mismatches = matcher.mismatches(event);
+
+                if !mismatches.is_empty() {
+                    differences.push(format!("[{index}] {matcher}:"));
+                    differences.extend(mismatches.into_iter().map(|m| format!("    {m}")));
+                }
+            }
+            None => differences.push(format!("[{index}] {matcher}: no such event")),
+        }
+    }
+
+    if !allow_more {
+        for (index, event) in events.iter().enumerate().skip(matchers.len()) {
+            differences.push(format!(
+                "[{index}] unexpected `{}` event",
+                variant_name(event)
+            ));
+        }
+    }
+
+    if differences.is_empty() {
+        return;
+    }
+
+    let events: Vec<_> = events
+        .iter()
+        .enumerate()
+        .map(|(index, event)| format!("[{index}] {}", describe(event)))
+        .collect();
+
+    panic!(
+        "the events do not match:\n  {}\nthe events emitted:\n  {}",
+        differences.join("\n  "),
+        events.join("\n  ")
+    );
+}
+
+/// Asserts that the events of the context, as returned by its `get_events`
+/// method, are matched by the list of matchers. See the [`events`](crate::events)
+/// module for the syntax of the matchers.
+#[macro_export]
+macro_rules! assert_events {
+    (@munch $events:expr, [$($acc:expr,)*], ..) => {
+        $crate::events::assert_events(&$events, &[$($acc,)*], true)
+    };
+    (@munch $events:expr, [$($acc:expr,)*], ) => {
+        $crate::events::assert_events(&$events, &[$($acc,)*], false)
+    };
+    (@munch $events:expr, [$($acc:expr,)*], $kind:ident $({ $($field:ident : $value:expr),* $(,)? })? $(, $($rest:tt)*)?) => {
+        $crate::assert_events!(
+            @munch $events,
+            [
+                $($acc,)*
+                $crate::events::EventMatcher::new(stringify!($kind))
+                    $($(.field(stringify!($field), $value))*)?,
+            ],
+            $($($rest)*)?
+        )
+    };
+    ($ctx:expr, [$($matchers:tt)*] $(,)?) => {
+        $crate::assert_events!(@munch $ctx.get_events(), [], $($matchers)*)
+    };
+}
+
+/// Returns the name of the variant of the event.
+pub fn variant_name(event: &IbcEvent) -> &'static str {
+    match event {
+        IbcEvent::CreateClient(_) => "CreateClient",
+        IbcEvent::UpdateClient(_) => "UpdateClient",
+        IbcEvent::UpgradeClient(_) => "UpgradeClient",
+        IbcEvent::ClientMisbehaviour(_) => "ClientMisbehaviour",
+        IbcEvent::OpenInitConnection(_) => "OpenInitConnection",
+        IbcEvent::OpenTryConnection(_) => "OpenTryConnection",
+        IbcEvent::OpenAckConnection(_) => "OpenAckConnection",
+        IbcEvent::OpenConfirmConnection(_) => "OpenConfirmConnection",
+        IbcEvent::OpenInitChannel(_) => "OpenInitChannel",
+        IbcEvent::OpenTryChannel(_) => "OpenTryChannel",
+        IbcEvent::OpenAckChannel(_) => "OpenAckChannel",
+        IbcEvent::OpenConfirmChannel(_) => "OpenConfirmChannel",
+        IbcEvent::CloseInitChannel(_) => "CloseInitChannel",
+        IbcEvent::CloseConfirmChannel(_) => "CloseConfirmChannel",
+        IbcEvent::UpgradeInitChannel(_) => "UpgradeInitChannel",
+        IbcEvent::UpgradeTryChannel(_) => "UpgradeTryChannel",
+        IbcEvent::UpgradeAckChannel(_) => "UpgradeAckChannel",
+        IbcEvent::UpgradeConfirmChannel(_) => "UpgradeConfirmChannel",
+        IbcEvent::UpgradeOpenChannel(_) => "UpgradeOpenChannel",
+        IbcEvent::UpgradeTimeoutChannel(_) => "UpgradeTimeoutChannel",
+        IbcEvent::UpgradeCancelChannel(_) => "UpgradeCancelChannel",
+        IbcEvent::UpgradeErrorChannel(_) => "UpgradeErrorChannel",
+        IbcEvent::SendPacket(_) => "SendPacket",
+        IbcEvent::ReceivePacket(_) => "ReceivePacket",
+        IbcEvent::WriteAcknowledgement(_) => "WriteAcknowledgement",
+        IbcEvent::AcknowledgePacket(_) => "AcknowledgePacket",
+        IbcEvent::TimeoutPacket(_) => "TimeoutPacket",
+        IbcEvent::ChannelClosed(_) => "ChannelClosed",
+        IbcEvent::SendPacketV2(_) => "SendPacketV2",
+        IbcEvent::ReceivePacketV2(_) => "ReceivePacketV2",
+        IbcEvent::WriteAcknowledgementV2(_) => "WriteAcknowledgementV2",
+        IbcEvent::AcknowledgePacketV2(_) => "AcknowledgePacketV2",
+        IbcEvent::TimeoutPacketV2(_) => "TimeoutPacketV2",
+        IbcEvent::Module(_) => "Module",
+        IbcEvent::Message(_) => "Message",
+    }
+}
+
+/// Returns the attributes of the event, as encoded in its ABCI event.
+fn attributes(event: &IbcEvent) -> Vec<(String, String)> {
+    abci::Event::try_from(event.clone())
+        .map(|event| {
+            event
+                .attributes
+                .into_iter()
+                .map(|attribute| (attribute.key, attribute.value))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Returns the keys of the attributes of the event the field may stand for,
+/// in order of preference.
+fn attribute_keys(event: &IbcEvent, field: &str) -> Vec<String> {
+    // The side of the packet on the chain emitting the event, and on its
+    // counterparty.
+    let sides = match event {
+        IbcEvent::SendPacket(_)
+        | IbcEvent::AcknowledgePacket(_)
+        | IbcEvent::TimeoutPacket(_)
+        | IbcEvent::ChannelClosed(_) => Some(("src", "dst")),
+        IbcEvent::ReceivePacket(_) | IbcEvent::WriteAcknowledgement(_) => Some(("dst", "src")),
+        _ => None,
+    };
+
+    let alias = match (field, sides) {
+        ("seq" | "sequence", _) => Some("packet_sequence".to_string()),
+        ("port" | "channel", Some((side, _))) => Some(format!("packet_{side}_{field}")),
+        ("counterparty_port" | "counterparty_channel", Some((_, side))) => Some(format!(
+            "packet_{side}_{}",
+            field.trim_start_matches("counterparty_")
+        )),
+        _ => None,
+    };
+
+    alias
+        .into_iter()
+        .chain([
+            field.to_string(),
+            format!("{field}_id"),
+            format!("packet_{field}"),
+        ])
+        .collect()
+}
+
+/// Describes the event by its variant and its attributes.
+fn describe(event: &IbcEvent) -> String {
+    let attributes: Vec<_> = attributes(event)
+        .into_iter()
+        .map(|(key, value)| format!("{key}: {value:?}"))
+        .collect();
+
+    format!("{} {{ {} }}", variant_name(event), attributes.join(", "))
+}
@@ -0,0 +1,404 @@
+//! Fixtures captured from another implementation of IBC, such as ibc-go, to
+//! replay against the handlers of ibc-rs, and captured from ibc-rs to replay
+//! against the other implementation, catching the divergences of the
+//! encodings and of the semantics between the implementations.
+//!
+//! A [`CompatFixture`] is a JSON document listing the messages delivered to a
+//! host, along with their expected outcome and events, and the proofs of the
+//! store of the host, along with the root they verify against. The bytes are
+//! encoded in lowercase hex.
+//!
+//! ```json
+//! {
+//!   "source": "ibc-go v8.1.0",
+//!   "messages": [
+//!     {
+//!       "type_url": "/ibc.core.client.v1.MsgCreateClient",
+//!       "value": "0a310a2b2f6962632e...",
+//!       "outcome": {
+//!         "result": "success",
+//!         "events": [
+//!           {
+//!             "kind": "create_client",
+//!             "attributes": [{ "key": "client_id", "value": "07-tendermint-0" }]
+//!           }
+//!         ]
+//!       }
+//!     }
+//!   ],
+//!   "proofs": []
+//! }
+//! ```
+//!
+//! The messages are replayed in order, each one in the state of the host left
+//! by the previous ones, on a host whose state the test sets up as the state
+//! of the host the messages were captured on.
+
+use ibc::core::channel::types::msgs::{ChannelMsg, PacketMsg};
+use ibc::core::client::types::msgs::ClientMsg;
+use ibc::core::commitment_types::commitment::{
+    CommitmentPrefix, CommitmentProofBytes, CommitmentRoot,
+};
+use ibc::core::commitment_types::merkle::{apply_prefix, MerkleProof};
+use ibc::core::commitment_types::specs::ProofSpecs;
+use ibc::core::connection::types::msgs::ConnectionMsg;
+use ibc::core::handler::types::events::IbcEvent;
+use ibc::core::handler::types::msgs::MsgEnvelope;
+use ibc::core::primitives::prelude::*;
+use ibc::primitives::proto::{Any, Protobuf};
+use subtle_encoding::hex;
+use tendermint::abci;
+
+use crate::testapp::ibc::core::router::MockRouter;
+use crate::testapp::ibc::core::types::MockContext;
+
+/// The messages and the proofs captured from an implementation of IBC.
+#[derive(Clone, Debug, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct CompatFixture {
+    /// The implementation, along with its version, which captured the
+    /// fixture, e.g. `ibc-go v8.1.0`.
+    pub source: String,
+    /// The messages delivered to the host, in order.
+    #[serde(default)]
+    pub messages: Vec<MsgFixture>,
+    /// The proofs of the store of the host.
+    #[serde(default)]
+    pub proofs: Vec<ProofFixture>,
+}
+
+/// A message delivered to the host, along with its outcome.
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct MsgFixture {
+    pub type_url: String,
+    /// The protobuf encoding of the message, in hex.
+    pub value: String,
+    pub outcome: ExpectedOutcome,
+}
+
+/// The outcome of the delivery of a message.
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(tag = "result", rename_all = "snake_case")]
+pub enum ExpectedOutcome {
+    /// The handlers applied the message, which emitted the events.
+    Success { events: Vec<EventFixture> },
+    /// The handlers rejected the message. The errors are not compared, as
+    /// the implementations word them differently.
+    Failure,
+}
+
+/// An event emitted by the handlers, in its ABCI encoding.
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct EventFixture {
+    pub kind: String,
+    pub attributes: Vec<AttributeFixture>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct AttributeFixture {
+    pub key: String,
+    pub value: String,
+}
+
+/// A proof of the store of the host, of the membership of the value at the
+/// path, or of the non-membership of the path if there is no value.
+///
+/// The proofs are verified against the proof specs of the Cosmos SDK
+/// chains.
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct ProofFixture {
+    /// The commitment prefix of the store, e.g. `ibc`.
+    pub prefix: String,
+    /// The path, e.g. `connections/connection-0`.
+    pub path: String,
+    /// The value stored at the path, in hex.
+    #[serde(default)]
+    pub value: Option<String>,
+    /// The protobuf encoding of the merkle proof, in hex.
+    pub proof: String,
+    /// The root of the store, in hex.
+    pub root: String,
+}
+
+/// A divergence of ibc-rs from the implementation which captured a fixture.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Divergence {
+    /// The message, or the bytes of the fixture, fail to decode.
+    Decoding { index: usize, error: String },
+    /// The message is encoded back into different bytes.
+    Encoding {
+        index: usize,
+        expected: String,
+        actual: String,
+    },
+    /// The outcome of the delivery of the message differs.
+    Outcome {
+        index: usize,
+        expected: ExpectedOutcome,
+        actual: ExpectedOutcome,
+    },
+    /// An event emitted by the handlers has no ABCI encoding.
+    Event { index: usize, error: String },
+    /// The proof fails to verify.
+    Proof { index: usize, error: String },
+}
+
+impl CompatFixture {
+    pub fn from_json(json: &str) -> Result<Self, serde_json::Error> {
+        serde_json::from_str(json)
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).expect("Never fails")
+    }
+
+    /// Delivers the messages to the host, and captures them along with their
+    /// outcome into a fixture, for the other implementations to replay.
+    ///
+    /// The messages failing to decode are left out.
+    pub fn capture(
+        source: impl Into<String>,
+        ctx: &mut MockContext,
+        router: &mut MockRouter,
+        msgs: impl IntoIterator<Item = Any>,
+    ) -> Self {
+        let messages = msgs
+            .into_iter()
+            .filter_map(|any| {
+                let msg = MsgEnvelope::try_from(any.clone()).ok()?;
+
+                let outcome = match deliver(ctx, router, msg) {
+                    Ok(events) => ExpectedOutcome::Success {
+                        events: events.into_iter().filter_map(Result::ok).collect(),
+                    },
+                    Err(()) => ExpectedOutcome::Failure,
+                };
+
+                Some(MsgFixture {
+                    type_url: any.type_url,
+                    value: encode_hex(&any.value),
+                    outcome,
+                })
+            })
+            .collect();
+
+        Self {
+            source: source.into(),
+            messages,
+            proofs: Vec::new(),
+        }
+    }
+
+    /// Replays the messages of the fixture against the host, verifies its
+    /// proofs, and returns the divergences of ibc-rs.
+    ///
+    /// The messages failing to decode are not delivered.
+    pub fn replay(&self, ctx: &mut MockContext, router: &mut MockRouter) -> Vec<Divergence> {
+        let mut divergences = Vec::new();
+
+        for (index, fixture) in self.messages.iter().enumerate() {
+            let value = match decode_hex(&fixture.value) {
+                Ok(value) => value,
+                Err(error) => {
+                    divergences.push(Divergence::Decoding { index, error });
+                    continue;
+                }
+            };
+
+            let any = Any {
+                type_url: fixture.type_url.clone(),
+                value,
+            };
+
+            let msg = match MsgEnvelope::try_from(any.clone()) {
+                Ok(msg) => msg,
+                Err(e) => {
+                    divergences.push(Divergence::Decoding {
+                        index,
+                        error: e.to_string(),
+                    });
+                    continue;
+                }
+            };
+
+            let encoded = encode_msg_envelope(&msg);
+
+            if encoded != any.value {
+                divergences.push(Divergence::Encoding {
+                    index,
+                    expected: fixture.value.clone(),
+                    actual: encode_hex(&encoded),
+                });
+            }
+
+            let actual = match deliver(ctx, router, msg) {
+                Ok(events) => {
+                    let mut abci_events = Vec::new();
+
+                    for event in events {
+                        match event {
+                            Ok(event) => abci_events.push(event),
+                            Err(error) => divergences.push(Divergence::Event { index, error }),
+                        }
+                    }
+
+                    ExpectedOutcome::Success {
+                        events: abci_events,
+                    }
+                }
+                Err(()) => ExpectedOutcome::Failure,
+            };
+
+            if actual != fixture.outcome {
+                divergences.push(Divergence::Outcome {
+                    index,
+                    expected: fixture.outcome.clone(),
+                    actual,
+                });
+            }
+        }
+
+        divergences.extend(self.verify_proofs());
+
+        divergences
+    }
+
+    /// Verifies the proofs of the fixture, and returns the divergences of
+    /// ibc-rs.
+    pub fn verify_proofs(&self) -> Vec<Divergence> {
+        self.proofs
+            .iter()
+            .enumerate()
+            .filter_map(|(index, fixture)| {
+                fixture
+                    .verify()
+                    .err()
+                    .map(|error| Divergence::Proof { index, error })
+            })
+            .collect()
+    }
+
+    /// Asserts that ibc-rs does not diverge from the fixture.
+    pub fn assert_compatible(&self, ctx: &mut MockContext, router: &mut MockRouter) {
+        let divergences = self.replay(ctx, router);
+
+        assert!(
+            divergences.is_empty(),
+            "ibc-rs diverges from {}: {divergences:#?}",
+            self.source
+        );
+    }
+}
+
+impl ProofFixture {
+    fn verify(&self) -> Result<(), String> {
+        let prefix = CommitmentPrefix::try_from(self.prefix.as_bytes().to_vec())
+            .map_err(|e| e.to_string())?;
+
+        let proof =
+            CommitmentProofBytes::try_from(decode_hex(&self.proof)?).map_err(|e| e.to_string())?;
+
+        let proof = MerkleProof::try_from(&proof).map_err(|e| e.to_string())?;
+
+        let root = CommitmentRoot::from_bytes(&decode_hex(&self.root)?);
+
+        let merkle_path = apply_prefix(&prefix, vec![self.path.clone()]);
+
+        match &self.value {
+            Some(value) => proof.verify_membership(
+                &ProofSpecs::cosmos(),
+                root.into(),
+                merkle_path,
+                decode_hex(value)?,
+                0,
+            ),
+            None => proof.verify_non_membership(&ProofSpecs::cosmos(), root.into(), merkle_path),
+        }
+        .map_err(|e| e.to_string())
+    }
+}
+
+impl TryFrom<IbcEvent> for EventFixture {
+    type Error = String;
+
+    fn try_from(event: IbcEvent) -> Result<Self, Self::Error> {
+        let event = abci::Event::try_from(event).map_err(|e| e.to_string())?;
+
+        Ok(Self {
+            kind: event.kind,
+            attributes: event
+                .attributes
+                .into_iter()
+                .map(|attribute| AttributeFixture {
+                    key: attribute.key,
+                    value: attribute.value,
+                })
+                .collect(),
+        })
+    }
+}
+
+/// Returns the protobuf encoding of the message of the envelope.
+pub fn encode_msg_envelope(msg: &MsgEnvelope) -> Vec<u8> {
+    match msg.clone() {
+        MsgEnvelope::Client(msg) => match msg {
+            ClientMsg::CreateClient(msg) => msg.encode_vec(),
+            ClientMsg::UpdateClient(msg) => msg.encode_vec(),
+            ClientMsg::Misbehaviour(msg) => msg.encode_vec(),
+            ClientMsg::UpgradeClient(msg) => msg.encode_vec(),
+        },
+        MsgEnvelope::Connection(msg) => match msg {
+            ConnectionMsg::OpenInit(msg) => msg.encode_vec(),
+            ConnectionMsg::OpenTry(msg) => msg.encode_vec(),
+            ConnectionMsg::OpenAck(msg) => msg.encode_vec(),
+            ConnectionMsg::OpenConfirm(msg) => msg.encode_vec(),
+        },
+        MsgEnvelope::Channel(msg) => match msg {
+            ChannelMsg::OpenInit(msg) => msg.encode_vec(),
+            ChannelMsg::OpenTry(msg) => msg.encode_vec(),
+            ChannelMsg::OpenAck(msg) => msg.encode_vec(),
+            ChannelMsg::OpenConfirm(msg) => msg.encode_vec(),
+            ChannelMsg::CloseInit(msg) => msg.encode_vec(),
+            ChannelMsg::CloseConfirm(msg) => msg.encode_vec(),
+            ChannelMsg::UpgradeInit(msg) => msg.encode_vec(),
+            ChannelMsg::UpgradeTry(msg) => msg.encode_vec(),
+            ChannelMsg::UpgradeAck(msg) => msg.encode_vec(),
+            ChannelMsg::UpgradeConfirm(msg) => msg.encode_vec(),
+            ChannelMsg::UpgradeOpen(msg) => msg.encode_vec(),
+            ChannelMsg::UpgradeTimeout(msg) => msg.encode_vec(),
+            ChannelMsg::UpgradeCancel(msg) => msg.encode_vec(),
+        },
+        MsgEnvelope::Packet(msg) => match msg {
+            PacketMsg::Recv(msg) => msg.encode_vec(),
+            PacketMsg::Ack(msg) => msg.encode_vec(),
+            PacketMsg::Timeout(msg) => msg.encode_vec(),
+            PacketMsg::TimeoutOnClose(msg) => msg.encode_vec(),
+        },
+    }
+}
+
+/// Delivers the message to the host, and returns the events it emitted in
+/// their ABCI encoding, or `Err` if the handlers rejected it.
+fn deliver(
+    ctx: &mut MockContext,
+    router: &mut MockRouter,
+    msg: MsgEnvelope,
+) -> Result<Vec<Result<EventFixture, String>>, ()> {
+    let emitted = ctx.get_events().len();
+
+    ctx.deliver(router, msg).map_err(|_| ())?;
+
+    Ok(ctx
+        .get_events()
+        .split_off(emitted)
+        .into_iter()
+        .map(EventFixture::try_from)
+        .collect())
+}
+
+fn encode_hex(bytes: &[u8]) -> String {
+    String::from_utf8(hex::encode(bytes)).expect("Never fails")
+}
+
+fn decode_hex(encoded: &str) -> Result<Vec<u8>, String> {
+    hex::decode(encoded).map_err(|e| e.to_string())
+}
@@ -173,6 +173,11 @@ mod tests {
                 want_res: Order::Ordered,
                 want_err: false,
             },
+            Test {
+                ordering: "ORDER_ORDERED_ALLOW_TIMEOUT",
+                want_res: Order::OrderedAllowTimeout,
+                want_err: false,
+            },
             Test {
                 ordering: "UNKNOWN_ORDER",
                 want_res: Order::None,
@@ -3,6 +3,7 @@ use core::cmp::min;
 use core::ops::{Add, Sub};
 use core::time::Duration;
 
+use ibc::core::channel::types::events::PacketDataEventMode;
 use ibc::core::client::types::Height;
 use ibc::core::host::types::identifiers::ChainId;
 use ibc::core::primitives::prelude::*;
@@ -121,7 +122,30 @@ impl From<MockContextConfig> for MockContext {
             max_history_size: params.max_history_size,
             history,
             block_time: params.block_time,
+            max_packet_data_size: None,
+            packet_data_event_mode: PacketDataEventMode::default(),
+            signer_format: None,
+            connection_creators: None,
+            zk_commit_verifier: None,
             ibc_store: Arc::new(Mutex::new(MockIbcStore::default())),
         }
     }
 }
+
+/// Returns the context of a mock chain of the given identifier, at the height
+/// 5 of the revision of the identifier.
+pub fn mock_chain(chain_id: &str) -> MockContext {
+    mock_chain_at(chain_id, Timestamp::now())
+}
+
+/// Same as [`mock_chain`], with the latest block of the chain at the given
+/// timestamp.
+pub fn mock_chain_at(chain_id: &str, timestamp: Timestamp) -> MockContext {
+    let host_id = ChainId::new(chain_id).expect("Never fails");
+
+    MockContextConfig::builder()
+        .latest_height(Height::new(host_id.revision_number(), 5).expect("Never fails"))
+        .latest_timestamp(timestamp)
+        .host_id(host_id)
+        .build()
+}
@@ -1,6 +1,10 @@
 pub mod applications;
 pub mod clients;
+#[cfg(feature = "serde")]
+pub mod compat;
 pub mod core;
+#[cfg(feature = "proptest")]
+pub mod strategies;
 use alloc::fmt::Debug;
 
 use ibc::core::handler::types::error::ContextError;
@@ -0,0 +1,383 @@
+//! Provides the [`proptest`] strategies generating the IBC domain types, for
+//! the hosts to write property tests over their IBC integration without
+//! defining the generators themselves.
+//!
+//! The strategies only generate valid values, i.e. values their raw types
+//! decode into, and combine into one another, e.g. the [`msg_envelope`]
+//! strategy generates the messages along with their packets or channel
+//! parameters.
+
+use core::time::Duration;
+
+use ibc::core::channel::types::acknowledgement::Acknowledgement;
+use ibc::core::channel::types::channel::{
+    ChannelEnd, Counterparty as ChannelCounterparty, Order, State as ChannelState,
+};
+use ibc::core::channel::types::msgs::{
+    ChannelMsg, MsgAcknowledgement, MsgChannelCloseInit, MsgChannelOpenInit, MsgRecvPacket,
+    MsgTimeout, PacketMsg,
+};
+use ibc::core::channel::types::packet::Packet;
+use ibc::core::channel::types::timeout::TimeoutHeight;
+use ibc::core::channel::types::Version as ChannelVersion;
+use ibc::core::client::types::Height;
+use ibc::core::commitment_types::commitment::{CommitmentPrefix, CommitmentProofBytes};
+use ibc::core::connection::types::msgs::{ConnectionMsg, MsgConnectionOpenInit};
+use ibc::core::connection::types::version::Version as ConnectionVersion;
+use ibc::core::connection::types::{
+    ConnectionEnd, Counterparty as ConnectionCounterparty, State as ConnectionState,
+};
+use ibc::core::handler::types::msgs::MsgEnvelope;
+use ibc::core::host::types::identifiers::{
+    ChainId, ChannelId, ClientId, ConnectionId, PortId, Sequence,
+};
+use ibc::core::primitives::prelude::*;
+use ibc::core::primitives::{Signer, Timestamp};
+use proptest::collection::vec;
+use proptest::prelude::*;
+
+/// The largest timestamp generated, in nanoseconds, in the year 2200.
+const MAX_TIMESTAMP_NANOS: u64 = 7_258_118_400_000_000_000;
+
+/// Generates the heights of the revisions `0` to `15`.
+pub fn height() -> impl Strategy<Value = Height> {
+    (0..16u64, 1..=u64::from(u32::MAX)).prop_map(|(revision_number, revision_height)| {
+        Height::new(revision_number, revision_height).expect("Never fails")
+    })
+}
+
+/// Generates the timestamps, which are all set.
+pub fn timestamp() -> impl Strategy<Value = Timestamp> {
+    (1..=MAX_TIMESTAMP_NANOS)
+        .prop_map(|nanoseconds| Timestamp::from_nanoseconds(nanoseconds).expect("Never fails"))
+}
+
+/// Generates the chain identifiers, of the revisions `0` to `15`.
+pub fn chain_id() -> impl Strategy<Value = ChainId> {
+    ("[a-z]{1,16}", 0..16u64).prop_map(|(name, revision_number)| {
+        ChainId::new(&format!("{name}-{revision_number}")).expect("Never fails")
+    })
+}
+
+/// Generates the identifiers of the Tendermint and mock clients.
+pub fn client_id() -> impl Strategy<Value = ClientId> {
+    (
+        prop_oneof![Just("07-tendermint"), Just("9999-mock")],
+        any::<u64>(),
+    )
+        .prop_map(|(client_type, counter)| {
+            ClientId::new(client_type, counter).expect("Never fails")
+        })
+}
+
+pub fn connection_id() -> impl Strategy<Value = ConnectionId> {
+    any::<u64>().prop_map(ConnectionId::new)
+}
+
+pub fn channel_id() -> impl Strategy<Value = ChannelId> {
+    any::<u64>().prop_map(ChannelId::new)
+}
+
+pub fn port_id() -> impl Strategy<Value = PortId> {
+    prop_oneof![
+        Just(PortId::transfer()),
+        "[a-z][a-z0-9]{1,31}".prop_map(|port_id| PortId::new(port_id).expect("Never fails")),
+    ]
+}
+
+/// Generates the sequences, which cannot be zero.
+pub fn sequence() -> impl Strategy<Value = Sequence> {
+    (1..=u64::MAX).prop_map(Sequence::from)
+}
+
+pub fn signer() -> impl Strategy<Value = Signer> {
+    "cosmos1[a-z0-9]{38}".prop_map(Signer::from)
+}
+
+/// Generates the non-empty proofs, which are not valid Merkle proofs.
+pub fn proof_bytes() -> impl Strategy<Value = CommitmentProofBytes> {
+    vec(any::<u8>(), 1..128)
+        .prop_map(|bytes| CommitmentProofBytes::try_from(bytes).expect("Never fails"))
+}
+
+/// Generates the timeouts of the packets, setting the timeout height, the
+/// timeout timestamp or both.
+pub fn packet_timeout() -> impl Strategy<Value = (TimeoutHeight, Timestamp)> {
+    prop_oneof![
+        height().prop_map(|height| (TimeoutHeight::At(height), Timestamp::none())),
+        timestamp().prop_map(|timestamp| (TimeoutHeight::Never, timestamp)),
+        (height(), timestamp())
+            .prop_map(|(height, timestamp)| (TimeoutHeight::At(height), timestamp)),
+    ]
+}
+
+pub fn packet() -> impl Strategy<Value = Packet> {
+    (
+        sequence(),
+        port_id(),
+        channel_id(),
+        port_id(),
+        channel_id(),
+        vec(any::<u8>(), 1..256),
+        packet_timeout(),
+    )
+        .prop_map(
+            |(
+                seq_on_a,
+                port_id_on_a,
+                chan_id_on_a,
+                port_id_on_b,
+                chan_id_on_b,
+                data,
+                (timeout_height_on_b, timeout_timestamp_on_b),
+            )| Packet {
+                seq_on_a,
+                port_id_on_a,
+                chan_id_on_a,
+                port_id_on_b,
+                chan_id_on_b,
+                data,
+                timeout_height_on_b,
+                timeout_timestamp_on_b,
+            },
+        )
+}
+
+/// Generates the states of the channels, other than `Uninitialized`.
+pub fn channel_state() -> impl Strategy<Value = ChannelState> {
+    prop_oneof![
+        Just(ChannelState::Init),
+        Just(ChannelState::TryOpen),
+        Just(ChannelState::Open),
+        Just(ChannelState::Closed),
+        Just(ChannelState::Flushing),
+        Just(ChannelState::FlushComplete),
+    ]
+}
+
+/// Generates the orderings of the channels, other than `None`.
+pub fn channel_order() -> impl Strategy<Value = Order> {
+    prop_oneof![
+        Just(Order::Unordered),
+        Just(Order::Ordered),
+        Just(Order::OrderedAllowTimeout),
+    ]
+}
+
+pub fn channel_version() -> impl Strategy<Value = ChannelVersion> {
+    prop_oneof![
+        Just(ChannelVersion::empty()),
+        "[a-z0-9-]{1,16}".prop_map(ChannelVersion::new),
+    ]
+}
+
+/// Generates the channel ends over a single connection.
+pub fn channel_end() -> impl Strategy<Value = ChannelEnd> {
+    (
+        channel_state(),
+        channel_order(),
+        port_id(),
+        proptest::option::of(channel_id()),
+        connection_id(),
+        channel_version(),
+    )
+        .prop_map(
+            |(state, ordering, port_id_on_b, chan_id_on_b, conn_id, version)| {
+                ChannelEnd::new(
+                    state,
+                    ordering,
+                    ChannelCounterparty::new(port_id_on_b, chan_id_on_b),
+                    vec![conn_id],
+                    version,
+                )
+                .expect("Never fails")
+            },
+        )
+}
+
+/// Generates the states of the connections, other than `Uninitialized`.
+pub fn connection_state() -> impl Strategy<Value = ConnectionState> {
+    prop_oneof![
+        Just(ConnectionState::Init),
+        Just(ConnectionState::TryOpen),
+        Just(ConnectionState::Open),
+    ]
+}
+
+/// Generates the connection ends, of the compatible versions, under the
+/// `ibc` commitment prefix.
+pub fn connection_end() -> impl Strategy<Value = ConnectionEnd> {
+    (
+        connection_state(),
+        client_id(),
+        client_id(),
+        proptest::option::of(connection_id()),
+        any::<u64>(),
+    )
+        .prop_map(
+            |(state, client_id, client_id_on_b, conn_id_on_b, delay_period_nanos)| {
+                ConnectionEnd::new(
+                    state,
+                    client_id,
+                    ConnectionCounterparty::new(client_id_on_b, conn_id_on_b, ibc_prefix()),
+                    ConnectionVersion::compatibles(),
+                    Duration::from_nanos(delay_period_nanos),
+                )
+                .expect("Never fails")
+            },
+        )
+}
+
+pub fn msg_recv_packet() -> impl Strategy<Value = MsgRecvPacket> {
+    (packet(), proof_bytes(), height(), signer()).prop_map(
+        |(packet, proof_commitment_on_a, proof_height_on_a, signer)| MsgRecvPacket {
+            packet,
+            proof_commitment_on_a,
+            proof_height_on_a,
+            signer,
+        },
+    )
+}
+
+pub fn msg_acknowledgement() -> impl Strategy<Value = MsgAcknowledgement> {
+    (
+        packet(),
+        vec(any::<u8>(), 1..64),
+        proof_bytes(),
+        height(),
+        signer(),
+    )
+        .prop_map(
+            |(packet, acknowledgement, proof_acked_on_b, proof_height_on_b, signer)| {
+                MsgAcknowledgement {
+                    packet,
+                    acknowledgement: Acknowledgement::try_from(acknowledgement)
+                        .expect("Never fails"),
+                    proof_acked_on_b,
+                    proof_height_on_b,
+                    signer,
+                }
+            },
+        )
+}
+
+pub fn msg_timeout() -> impl Strategy<Value = MsgTimeout> {
+    (packet(), sequence(), proof_bytes(), height(), signer()).prop_map(
+        |(packet, next_seq_recv_on_b, proof_unreceived_on_b, proof_height_on_b, signer)| {
+            MsgTimeout {
+                packet,
+                next_seq_recv_on_b,
+                proof_unreceived_on_b,
+                proof_height_on_b,
+                signer,
+            }
+        },
+    )
+}
+
+pub fn msg_channel_open_init() -> impl Strategy<Value = MsgChannelOpenInit> {
+    (
+        port_id(),
+        connection_id(),
+        port_id(),
+        channel_order(),
+        signer(),
+        channel_version(),
+    )
+        .prop_map(
+            |(port_id_on_a, conn_id_on_a, port_id_on_b, ordering, signer, version_proposal)| {
+                MsgChannelOpenInit {
+                    port_id_on_a,
+                    connection_hops_on_a: vec![conn_id_on_a],
+                    port_id_on_b,
+                    ordering,
+                    signer,
+                    version_proposal,
+                }
+            },
+        )
+}
+
+pub fn msg_channel_close_init() -> impl Strategy<Value = MsgChannelCloseInit> {
+    (port_id(), channel_id(), signer()).prop_map(|(port_id_on_a, chan_id_on_a, signer)| {
+        MsgChannelCloseInit {
+            port_id_on_a,
+            chan_id_on_a,
+            signer,
+        }
+    })
+}
+
+pub fn msg_connection_open_init() -> impl Strategy<Value = MsgConnectionOpenInit> {
+    (
+        client_id(),
+        client_id(),
+        proptest::option::of(Just(ConnectionVersion::compatibles()[0].clone())),
+        any::<u64>(),
+        signer(),
+    )
+        .prop_map(
+            |(client_id_on_a, client_id_on_b, version, delay_period_nanos, signer)| {
+                MsgConnectionOpenInit {
+                    client_id_on_a,
+                    counterparty: ConnectionCounterparty::new(client_id_on_b, None, ibc_prefix()),
+                    version,
+                    delay_period: Duration::from_nanos(delay_period_nanos),
+                    signer,
+                }
+            },
+        )
+}
+
+/// Generates the messages opening the connections and the channels, closing
+/// the channels, and relaying the packets.
+pub fn msg_envelope() -> impl Strategy<Value = MsgEnvelope> {
+    prop_oneof![
+        msg_connection_open_init().prop_map(|msg| MsgEnvelope::from(ConnectionMsg::OpenInit(msg))),
+        msg_channel_open_init().prop_map(|msg| MsgEnvelope::from(ChannelMsg::OpenInit(msg))),
+        msg_channel_close_init().prop_map(|msg| MsgEnvelope::from(ChannelMsg::CloseInit(msg))),
+        msg_recv_packet().prop_map(|msg| MsgEnvelope::from(PacketMsg::Recv(msg))),
+        msg_acknowledgement().prop_map(|msg| MsgEnvelope::from(PacketMsg::Ack(msg))),
+        msg_timeout().prop_map(|msg| MsgEnvelope::from(PacketMsg::Timeout(msg))),
+    ]
+}
+
+fn ibc_prefix() -> CommitmentPrefix {
+    CommitmentPrefix::try_from(b"ibc".to_vec()).expect("Never fails")
+}
+
+#[cfg(test)]
+mod tests {
+    use ibc::core::channel::types::proto::v1::Packet as RawPacket;
+    use ibc::primitives::proto::Protobuf;
+
+    use super::*;
+
+    proptest! {
+        #[test]
+        fn packets_round_trip(packet in packet()) {
+            prop_assert_eq!(Packet::try_from(RawPacket::from(packet.clone())).unwrap(), packet);
+        }
+
+        #[test]
+        fn channel_ends_round_trip(chan_end in channel_end()) {
+            let encoded = chan_end.clone().encode_vec();
+
+            prop_assert_eq!(ChannelEnd::decode_vec(&encoded).unwrap(), chan_end);
+        }
+
+        #[test]
+        fn connection_ends_round_trip(conn_end in connection_end()) {
+            let encoded = conn_end.clone().encode_vec();
+
+            prop_assert_eq!(ConnectionEnd::decode_vec(&encoded).unwrap(), conn_end);
+        }
+
+        #[test]
+        fn packet_msgs_round_trip(msg in msg_recv_packet()) {
+            let encoded = msg.clone().encode_vec();
+
+            prop_assert_eq!(MsgRecvPacket::decode_vec(&encoded).unwrap(), msg);
+        }
+    }
+}
@@ -13,6 +13,7 @@ use ibc::core::primitives::Timestamp;
 use ibc::primitives::proto::{Any, Protobuf};
 use ibc::primitives::ToVec;
 use tendermint::block::Header as TmHeader;
+use tendermint::hash::AppHash;
 use tendermint::validator::Set as ValidatorSet;
 use tendermint_testgen::light_block::TmLightBlock;
 use tendermint_testgen::{
@@ -172,18 +173,43 @@ impl HostBlock {
         chain_id: ChainId,
         height: u64,
         timestamp: Timestamp,
+    ) -> SyntheticTmBlock {
+        Self::build_tm_block(chain_id, height, timestamp, None)
+    }
+
+    /// Generates a new Tendermint block at `height`, whose header commits to
+    /// the given application state hash, e.g. the root of the provable store
+    /// of the host.
+    pub fn generate_tm_block_with_app_hash(
+        chain_id: ChainId,
+        height: u64,
+        timestamp: Timestamp,
+        app_hash: AppHash,
+    ) -> SyntheticTmBlock {
+        Self::build_tm_block(chain_id, height, timestamp, Some(app_hash))
+    }
+
+    fn build_tm_block(
+        chain_id: ChainId,
+        height: u64,
+        timestamp: Timestamp,
+        app_hash: Option<AppHash>,
     ) -> SyntheticTmBlock {
         let validators = [
             TestgenValidator::new("1").voting_power(50),
             TestgenValidator::new("2").voting_power(50),
         ];
 
-        let header = TestgenHeader::new(&validators)
+        let mut header = TestgenHeader::new(&validators)
             .height(height)
             .chain_id(chain_id.as_str())
             .next_validators(&validators)
             .time(timestamp.into_tm_time().expect("Never fails"));
 
+        if let Some(app_hash) = app_hash {
+            header = header.app_hash(app_hash);
+        }
+
         let light_block = TestgenLightBlock::new_default_with_header(header)
             .generate()
             .expect("Never fails");
@@ -15,6 +15,9 @@ extern crate alloc;
 #[cfg(feature = "std")]
 extern crate std;
 
+#[cfg(feature = "differential")]
+pub mod differential;
+pub mod events;
 pub mod fixtures;
 pub mod hosts;
 pub mod relayer;
@@ -0,0 +1,308 @@
+//! Byzantine variants of the messages relayed between the chains, for the
+//! tests of the hosts to check that their handlers reject the messages of a
+//! faulty or malicious relayer.
+//!
+//! A [`Mutation`] rewrites an otherwise valid message: it corrupts its
+//! proofs, proves it at a stale or future height, replays the sequence of a
+//! packet already relayed, or signs it with another signer.
+//! [`assert_rejected`] delivers each variant of a message to a chain, and
+//! panics if the handlers apply any of them.
+//!
+//! ```rust,ignore
+//! let msg = harness.recv_packet_msg(&channel, &packet)?;
+//!
+//! byzantine::assert_rejected(
+//!     harness.chain_mut(channel.connection.chain_b),
+//!     &PacketMsg::Recv(msg.clone()).into(),
+//!     &Mutation::all(wrong_signer),
+//! );
+//! ```
+//!
+//! The mock clients accept any proof and the mock host any signer: against
+//! the chains of the `HostType::Mock` host type, only the mutations of the
+//! heights and of the sequences are rejected.
+
+use ibc::core::channel::types::msgs::{ChannelMsg, PacketMsg};
+use ibc::core::client::types::Height;
+use ibc::core::commitment_types::commitment::CommitmentProofBytes;
+use ibc::core::connection::types::msgs::ConnectionMsg;
+use ibc::core::handler::types::events::IbcEvent;
+use ibc::core::handler::types::msgs::MsgEnvelope;
+use ibc::core::host::types::identifiers::Sequence;
+use ibc::core::primitives::prelude::*;
+use ibc::core::primitives::Signer;
+
+use crate::relayer::error::RelayerError;
+use crate::relayer::harness::RelayedChain;
+
+/// The number of blocks past the proof height of a message at which the
+/// [`Mutation::FutureProofHeight`] proves it.
+pub const FUTURE_PROOF_HEIGHT_BLOCKS: u64 = 1000;
+
+/// A rewrite of a message relayed between the chains, which the handlers of
+/// the receiving chain must reject.
+#[derive(Clone, Debug)]
+pub enum Mutation {
+    /// Flips the bits of the proofs carried by the message.
+    CorruptProof,
+    /// Proves the message at the first height of the revision of its proof
+    /// height, at which the client of the counterparty holds no consensus
+    /// state.
+    StaleProofHeight,
+    /// Proves the message at a height past the latest height of the client
+    /// of the counterparty.
+    FutureProofHeight,
+    /// Reuses the sequence of a packet already relayed for the packet of the
+    /// message.
+    ReplayedSequence(Sequence),
+    /// Signs the message with the given signer, which the host is not
+    /// expected to accept.
+    WrongSigner(Signer),
+}
+
+impl Mutation {
+    /// Returns the mutations of the proofs, the heights and the signer of
+    /// the messages.
+    pub fn all(wrong_signer: Signer) -> Vec<Self> {
+        vec![
+            Self::CorruptProof,
+            Self::StaleProofHeight,
+            Self::FutureProofHeight,
+            Self::WrongSigner(wrong_signer),
+        ]
+    }
+
+    /// Returns the message rewritten by the mutation, or `None` if the
+    /// message carries nothing the mutation rewrites.
+    ///
+    /// The messages relayed between the chains are mutated: those of the
+    /// connection and channel handshakes, except for the channel upgrades,
+    /// and the packet messages.
+    pub fn apply(&self, msg: &MsgEnvelope) -> Option<MsgEnvelope> {
+        let mut msg = msg.clone();
+
+        let fields = RelayedFields::of(&mut msg)?;
+
+        match self {
+            Self::CorruptProof => {
+                for proof in fields.proofs {
+                    let bytes: Vec<u8> = Vec::from(proof.clone())
+                        .into_iter()
+                        .map(|byte| !byte)
+                        .collect();
+
+                    *proof = bytes.try_into().expect("Never fails");
+                }
+            }
+            Self::StaleProofHeight => {
+                *fields.proof_height =
+                    Height::new(fields.proof_height.revision_number(), 1).expect("Never fails");
+            }
+            Self::FutureProofHeight => {
+                *fields.proof_height = fields.proof_height.add(FUTURE_PROOF_HEIGHT_BLOCKS);
+            }
+            Self::ReplayedSequence(sequence) => {
+                *fields.sequence? = *sequence;
+            }
+            Self::WrongSigner(signer) => {
+                *fields.signer = signer.clone();
+            }
+        }
+
+        Some(msg)
+    }
+}
+
+/// How the handlers of a chain treated a message.
+#[derive(Debug)]
+pub enum Verdict {
+    /// The handlers rejected the message.
+    Rejected(RelayerError),
+    /// The handlers accepted the message without applying it, as they do
+    /// for the packets already relayed.
+    Ignored,
+    /// The handlers applied the message, which emitted the events.
+    Accepted(Vec<IbcEvent>),
+}
+
+impl Verdict {
+    pub fn is_accepted(&self) -> bool {
+        matches!(self, Self::Accepted(_))
+    }
+}
+
+/// Delivers the message to the chain, and returns how its handlers treated
+/// it.
+pub fn deliver(chain: &mut RelayedChain, msg: MsgEnvelope) -> Verdict {
+    let RelayedChain { ctx, router } = chain;
+
+    let emitted = ctx.get_events().len();
+
+    if let Err(e) = ctx.deliver(router, msg) {
+        return Verdict::Rejected(e);
+    }
+
+    let events = ctx.get_events().split_off(emitted);
+
+    if events.is_empty() {
+        Verdict::Ignored
+    } else {
+        Verdict::Accepted(events)
+    }
+}
+
+/// Delivers the variants of the message by each of the mutations to the
+/// chain, and returns how its handlers treated them, leaving out the
+/// mutations which do not apply to the message.
+pub fn deliver_mutated(
+    chain: &mut RelayedChain,
+    msg: &MsgEnvelope,
+    mutations: &[Mutation],
+) -> Vec<(Mutation, Verdict)> {
+    mutations
+        .iter()
+        .filter_map(|mutation| {
+            let mutated = mutation.apply(msg)?;
+
+            Some((mutation.clone(), deliver(chain, mutated)))
+        })
+        .collect()
+}
+
+/// Asserts that the handlers of the chain reject the variants of the
+/// message by each of the mutations which apply to it.
+///
+/// The variants are delivered in turn, so that a variant applied by the
+/// handlers may fail the ones delivered after it: the panic message lists
+/// all the variants applied.
+pub fn assert_rejected(chain: &mut RelayedChain, msg: &MsgEnvelope, mutations: &[Mutation]) {
+    let accepted: Vec<_> = deliver_mutated(chain, msg, mutations)
+        .into_iter()
+        .filter(|(_, verdict)| verdict.is_accepted())
+        .map(|(mutation, _)| mutation)
+        .collect();
+
+    assert!(
+        accepted.is_empty(),
+        "the handlers applied the message mutated by {accepted:?}"
+    );
+}
+
+/// Asserts that the handlers of the chain apply the message, then reject or
+/// ignore it when it is replayed.
+pub fn assert_replay_rejected(chain: &mut RelayedChain, msg: MsgEnvelope) {
+    let verdict = deliver(chain, msg.clone());
+
+    assert!(
+        verdict.is_accepted(),
+        "the handlers did not apply the message: {verdict:?}"
+    );
+
+    let verdict = deliver(chain, msg);
+
+    assert!(
+        !verdict.is_accepted(),
+        "the handlers applied the replayed message"
+    );
+}
+
+/// The fields of a message relayed between the chains, which the mutations
+/// rewrite.
+struct RelayedFields<'a> {
+    proofs: Vec<&'a mut CommitmentProofBytes>,
+    proof_height: &'a mut Height,
+    sequence: Option<&'a mut Sequence>,
+    signer: &'a mut Signer,
+}
+
+impl<'a> RelayedFields<'a> {
+    fn of(msg: &'a mut MsgEnvelope) -> Option<Self> {
+        let fields = match msg {
+            MsgEnvelope::Connection(ConnectionMsg::OpenTry(msg)) => Self {
+                proofs: [
+                    Some(&mut msg.proof_conn_end_on_a),
+                    Some(&mut msg.proof_client_state_of_b_on_a),
+                    Some(&mut msg.proof_consensus_state_of_b_on_a),
+                    msg.proof_consensus_state_of_b.as_mut(),
+                ]
+                .into_iter()
+                .flatten()
+                .collect(),
+                proof_height: &mut msg.proofs_height_on_a,
+                sequence: None,
+                signer: &mut msg.signer,
+            },
+            MsgEnvelope::Connection(ConnectionMsg::OpenAck(msg)) => Self {
+                proofs: [
+                    Some(&mut msg.proof_conn_end_on_b),
+                    Some(&mut msg.proof_client_state_of_a_on_b),
+                    Some(&mut msg.proof_consensus_state_of_a_on_b),
+                    msg.proof_consensus_state_of_a.as_mut(),
+                ]
+                .into_iter()
+                .flatten()
+                .collect(),
+                proof_height: &mut msg.proofs_height_on_b,
+                sequence: None,
+                signer: &mut msg.signer,
+            },
+            MsgEnvelope::Connection(ConnectionMsg::OpenConfirm(msg)) => Self {
+                proofs: vec![&mut msg.proof_conn_end_on_a],
+                proof_height: &mut msg.proof_height_on_a,
+                sequence: None,
+                signer: &mut msg.signer,
+            },
+            MsgEnvelope::Channel(ChannelMsg::OpenTry(msg)) => Self {
+                proofs: vec![&mut msg.proof_chan_end_on_a],
+                proof_height: &mut msg.proof_height_on_a,
+                sequence: None,
+                signer: &mut msg.signer,
+            },
+            MsgEnvelope::Channel(ChannelMsg::OpenAck(msg)) => Self {
+                proofs: vec![&mut msg.proof_chan_end_on_b],
+                proof_height: &mut msg.proof_height_on_b,
+                sequence: None,
+                signer: &mut msg.signer,
+            },
+            MsgEnvelope::Channel(ChannelMsg::OpenConfirm(msg)) => Self {
+                proofs: vec![&mut msg.proof_chan_end_on_a],
+                proof_height: &mut msg.proof_height_on_a,
+                sequence: None,
+                signer: &mut msg.signer,
+            },
+            MsgEnvelope::Channel(ChannelMsg::CloseConfirm(msg)) => Self {
+                proofs: vec![&mut msg.proof_chan_end_on_a],
+                proof_height: &mut msg.proof_height_on_a,
+                sequence: None,
+                signer: &mut msg.signer,
+            },
+            MsgEnvelope::Packet(PacketMsg::Recv(msg)) => Self {
+                proofs: vec![&mut msg.proof_commitment_on_a],
+                proof_height: &mut msg.proof_height_on_a,
+                sequence: Some(&mut msg.packet.seq_on_a),
+                signer: &mut msg.signer,
+            },
+            MsgEnvelope::Packet(PacketMsg::Ack(msg)) => Self {
+                proofs: vec![&mut msg.proof_acked_on_b],
+                proof_height: &mut msg.proof_height_on_b,
+                sequence: Some(&mut msg.packet.seq_on_a),
+                signer: &mut msg.signer,
+            },
+            MsgEnvelope::Packet(PacketMsg::Timeout(msg)) => Self {
+                proofs: vec![&mut msg.proof_unreceived_on_b],
+                proof_height: &mut msg.proof_height_on_b,
+                sequence: Some(&mut msg.packet.seq_on_a),
+                signer: &mut msg.signer,
+            },
+            MsgEnvelope::Packet(PacketMsg::TimeoutOnClose(msg)) => Self {
+                proofs: vec![&mut msg.proof_unreceived_on_b, &mut msg.proof_close_on_b],
+                proof_height: &mut msg.proof_height_on_b,
+                sequence: Some(&mut msg.packet.seq_on_a),
+                signer: &mut msg.signer,
+            },
+            _ => return None,
+        };
+
+        Some(fields)
+    }
+}
@@ -2,7 +2,7 @@ use ibc::core::client::context::ClientValidationContext;
 use ibc::core::client::types::Height;
 use ibc::core::handler::types::error::ContextError;
 use ibc::core::host::types::identifiers::ClientId;
-use ibc::core::host::ValidationContext;
+use ibc::core::host::HostClock;
 use ibc::core::primitives::prelude::*;
 use ibc::core::primitives::Signer;
 
@@ -27,7 +27,7 @@ pub trait RelayerContext {
 
 impl RelayerContext for MockContext {
     fn query_latest_height(&self) -> Result<Height, ContextError> {
-        ValidationContext::host_height(self)
+        HostClock::host_height(self)
     }
 
     fn query_client_full_state(&self, client_id: &ClientId) -> Option<AnyClientState> {
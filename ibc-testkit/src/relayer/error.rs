@@ -2,6 +2,7 @@ use displaydoc::Display;
 use ibc::core::client::types::Height;
 use ibc::core::connection::types::error::ConnectionError;
 use ibc::core::handler::types::error::ContextError;
+use ibc::core::host::types::error::IdentifierError;
 use ibc::core::host::types::identifiers::ClientId;
 
 #[derive(Debug, Display)]
@@ -24,6 +25,14 @@ pub enum RelayerError {
     TransactionFailed(ContextError),
     /// connection error: `{0}`
     Connection(ConnectionError),
+    /// identifier error: `{0}`
+    Identifier(IdentifierError),
+    /// querying the state of the chain failed error: `{0}`
+    Query(ContextError),
+    /// the delivered message emitted no `{kind}` event
+    MissingEvent { kind: &'static str },
+    /// the scenario step requires a prior `{step}` step
+    MissingScenarioStep { step: &'static str },
 }
 
 #[cfg(feature = "std")]
@@ -32,6 +41,8 @@ impl std::error::Error for RelayerError {
         match &self {
             Self::TransactionFailed(e) => Some(e),
             Self::Connection(e) => Some(e),
+            Self::Identifier(e) => Some(e),
+            Self::Query(e) => Some(e),
             _ => None,
         }
     }
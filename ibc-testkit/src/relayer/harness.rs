@@ -0,0 +1,1013 @@
+//! A harness relaying the IBC messages between several [`MockContext`]s, for
+//! the integration tests to open the connections and channels between the
+//! chains, and to relay the packets sent on them, in a few lines.
+//!
+//! Before relaying a message, the harness updates the client of the
+//! destination chain with the latest header of the source chain, at which the
+//! proofs of the message are taken. Delivering a message commits a block,
+//! which advances the height and the clock of the chain.
+//!
+//! The messages carry dummy proofs, which the mock clients accept without
+//! verifying them. The clients of the synthetic Tendermint chains can be
+//! created and updated, but the handshakes are relayed only between the
+//! chains of the `HostType::Mock` host type, whose consensus states the
+//! `MockContext` can introspect.
+//!
+//! ```rust,ignore
+//! let mut harness = RelayerHarness::new()
+//!     .with_chain(MockContextConfig::builder().host_id(chain_id_a).build())
+//!     .with_chain(MockContextConfig::builder().host_id(chain_id_b).build());
+//!
+//! let connection = harness.open_connection(0, 1)?;
+//! let channel = harness.open_channel(&connection, PortId::transfer(), version)?;
+//!
+//! let packet = harness.send_packet(&channel, data)?;
+//! let acknowledgement = harness.relay_packet(&channel, &packet)?;
+//!
+//! let packet = harness.send_packet(&channel, data)?;
+//! harness.timeout_packet(&channel, &packet)?;
+//! ```
+//!
+//! Timing out a packet commits blocks on the receiving chain past the timeout
+//! height and the timeout timestamp of the packet first.
+
+use core::time::Duration;
+
+use ibc::clients::tendermint::client_state::ClientState as TmClientState;
+use ibc::core::channel::handler::send_packet;
+use ibc::core::channel::types::acknowledgement::Acknowledgement;
+use ibc::core::channel::types::channel::Order;
+use ibc::core::channel::types::msgs::{
+    ChannelMsg, MsgAcknowledgement, MsgChannelOpenAck, MsgChannelOpenConfirm, MsgChannelOpenInit,
+    MsgChannelOpenTry, MsgRecvPacket, MsgTimeout, MsgTimeoutOnClose, PacketMsg,
+};
+use ibc::core::channel::types::packet::Packet;
+use ibc::core::channel::types::timeout::TimeoutHeight;
+use ibc::core::channel::types::Version as ChannelVersion;
+use ibc::core::client::context::client_state::ClientStateCommon;
+use ibc::core::client::context::ClientValidationContext;
+use ibc::core::client::types::msgs::{ClientMsg, MsgCreateClient, MsgUpdateClient};
+use ibc::core::client::types::Height;
+use ibc::core::commitment_types::commitment::CommitmentProofBytes;
+use ibc::core::connection::types::msgs::{
+    ConnectionMsg, MsgConnectionOpenAck, MsgConnectionOpenConfirm, MsgConnectionOpenInit,
+    MsgConnectionOpenTry,
+};
+use ibc::core::connection::types::Counterparty as ConnectionCounterparty;
+use ibc::core::handler::types::events::IbcEvent;
+use ibc::core::handler::types::msgs::MsgEnvelope;
+use ibc::core::host::types::identifiers::{ChannelId, ClientId, ConnectionId, PortId, Sequence};
+use ibc::core::host::types::path::{ChannelEndPath, SeqRecvPath, SeqSendPath};
+use ibc::core::host::ValidationContext;
+use ibc::core::primitives::prelude::*;
+use ibc::core::primitives::Timestamp;
+use ibc::primitives::proto::Any;
+
+use crate::fixtures::clients::tendermint::ClientStateConfig as TmClientStateConfig;
+use crate::fixtures::core::channel::dummy_proof;
+use crate::hosts::block::HostBlock;
+use crate::relayer::context::RelayerContext;
+use crate::relayer::error::RelayerError;
+use crate::testapp::ibc::clients::mock::client_state::MockClientState;
+use crate::testapp::ibc::clients::mock::consensus_state::MockConsensusState;
+use crate::testapp::ibc::clients::AnyConsensusState;
+use crate::testapp::ibc::core::router::MockRouter;
+use crate::testapp::ibc::core::types::{MockContext, MockContextSnapshot};
+
+/// The number of blocks of the receiving chain, past its height at which a
+/// packet is sent, after which the packets sent by the harness time out.
+pub const PACKET_TIMEOUT_BLOCKS: u64 = 1000;
+
+/// A chain of a [`RelayerHarness`], along with the router of its modules.
+pub struct RelayedChain {
+    pub ctx: MockContext,
+    pub router: MockRouter,
+}
+
+/// A connection opened by a [`RelayerHarness`] between its chains `chain_a`
+/// and `chain_b`, from the side of `chain_a`.
+#[derive(Clone, Debug)]
+pub struct RelayedConnection {
+    pub chain_a: usize,
+    pub chain_b: usize,
+    pub client_id_on_a: ClientId,
+    pub client_id_on_b: ClientId,
+    pub conn_id_on_a: ConnectionId,
+    pub conn_id_on_b: ConnectionId,
+}
+
+impl RelayedConnection {
+    /// Returns the connection from the side of `chain_b`.
+    pub fn flipped(&self) -> Self {
+        Self {
+            chain_a: self.chain_b,
+            chain_b: self.chain_a,
+            client_id_on_a: self.client_id_on_b.clone(),
+            client_id_on_b: self.client_id_on_a.clone(),
+            conn_id_on_a: self.conn_id_on_b.clone(),
+            conn_id_on_b: self.conn_id_on_a.clone(),
+        }
+    }
+}
+
+/// A channel opened by a [`RelayerHarness`] on one of its connections, from
+/// the side of `chain_a` of the connection.
+#[derive(Clone, Debug)]
+pub struct RelayedChannel {
+    pub connection: RelayedConnection,
+    pub port_id_on_a: PortId,
+    pub chan_id_on_a: ChannelId,
+    pub port_id_on_b: PortId,
+    pub chan_id_on_b: ChannelId,
+}
+
+impl RelayedChannel {
+    /// Returns the channel from the side of `chain_b` of its connection.
+    pub fn flipped(&self) -> Self {
+        Self {
+            connection: self.connection.flipped(),
+            port_id_on_a: self.port_id_on_b.clone(),
+            chan_id_on_a: self.chan_id_on_b.clone(),
+            port_id_on_b: self.port_id_on_a.clone(),
+            chan_id_on_b: self.chan_id_on_a.clone(),
+        }
+    }
+}
+
+/// A step of the trace of a [`RelayerHarness`], i.e. a message it delivered
+/// or a packet it sent, along with the events emitted on the chain.
+#[derive(Clone, Debug)]
+pub enum TraceStep {
+    Msg {
+        chain: usize,
+        msg: MsgEnvelope,
+        events: Vec<IbcEvent>,
+    },
+    SendPacket {
+        chain: usize,
+        packet: Packet,
+        events: Vec<IbcEvent>,
+    },
+}
+
+impl TraceStep {
+    pub fn chain(&self) -> usize {
+        match self {
+            Self::Msg { chain, .. } | Self::SendPacket { chain, .. } => *chain,
+        }
+    }
+
+    pub fn events(&self) -> &[IbcEvent] {
+        match self {
+            Self::Msg { events, .. } | Self::SendPacket { events, .. } => events,
+        }
+    }
+}
+
+/// The state of the chains of a [`RelayerHarness`], along with its trace,
+/// taken by [`RelayerHarness::snapshot`].
+///
+/// The state of the modules bound on the routers of the chains is not part of
+/// the snapshot.
+#[derive(Clone, Debug)]
+pub struct HarnessSnapshot {
+    chains: Vec<MockContextSnapshot>,
+    trace: Vec<TraceStep>,
+}
+
+impl HarnessSnapshot {
+    /// Returns a new context in the state of the chain of the snapshot.
+    ///
+    /// Panics if the harness had no such chain when the snapshot was taken.
+    pub fn fork_chain(&self, chain: usize) -> MockContext {
+        self.chains[chain].fork()
+    }
+}
+
+/// Relays the messages between its chains, which are identified by their
+/// index, in the order they were added to the harness.
+///
+/// The methods of the harness panic if given the index of a missing chain.
+#[derive(Default)]
+pub struct RelayerHarness {
+    chains: Vec<RelayedChain>,
+    trace: Vec<TraceStep>,
+}
+
+impl RelayerHarness {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a chain, whose router binds the transfer port to the dummy
+    /// transfer module.
+    pub fn with_chain(self, ctx: MockContext) -> Self {
+        self.with_chain_and_router(ctx, MockRouter::new_with_transfer())
+    }
+
+    /// Adds a chain, along with the router of its modules.
+    pub fn with_chain_and_router(mut self, ctx: MockContext, router: MockRouter) -> Self {
+        self.chains.push(RelayedChain { ctx, router });
+        self
+    }
+
+    pub fn chain(&self, chain: usize) -> &MockContext {
+        &self.chains[chain].ctx
+    }
+
+    pub fn chain_mut(&mut self, chain: usize) -> &mut RelayedChain {
+        &mut self.chains[chain]
+    }
+
+    /// Returns the messages delivered and the packets sent by the harness so
+    /// far, in order.
+    pub fn trace(&self) -> &[TraceStep] {
+        &self.trace
+    }
+
+    /// Takes a snapshot of the state of the chains of the harness, e.g. once
+    /// the connections and channels of a test are opened.
+    pub fn snapshot(&self) -> HarnessSnapshot {
+        HarnessSnapshot {
+            chains: self
+                .chains
+                .iter()
+                .map(|chain| chain.ctx.snapshot())
+                .collect(),
+            trace: self.trace.clone(),
+        }
+    }
+
+    /// Restores the chains of the harness, and its trace, to the state of the
+    /// snapshot.
+    ///
+    /// The chains added to the harness after the snapshot was taken are kept
+    /// as they are.
+    pub fn restore(&mut self, snapshot: &HarnessSnapshot) {
+        for (chain, ctx) in self.chains.iter_mut().zip(&snapshot.chains) {
+            chain.ctx.restore(ctx);
+        }
+
+        self.trace = snapshot.trace.clone();
+    }
+
+    /// Commits the given number of blocks on all the chains.
+    pub fn advance_blocks(&mut self, blocks: u64) {
+        for chain in &mut self.chains {
+            for _ in 0..blocks {
+                chain.ctx.advance_host_chain_height();
+            }
+        }
+    }
+
+    /// Commits a block on all the chains, `duration` after their latest
+    /// block, for their clocks to travel forward in time.
+    pub fn advance_time(&mut self, duration: Duration) {
+        for chain in &mut self.chains {
+            chain.ctx.advance_host_chain_time(duration);
+        }
+    }
+
+    /// Commits a block on the chain `chain`, `drift` after the latest block
+    /// of the chain `reference`, for the clock of `chain` to run ahead of the
+    /// clock of `reference` by `drift`.
+    ///
+    /// Panics if the clock of `chain` is already past the drifted time, as
+    /// the clocks only travel forward.
+    pub fn set_clock_drift(&mut self, chain: usize, reference: usize, drift: Duration) {
+        let latest_timestamp = self
+            .chain(reference)
+            .query_latest_header()
+            .expect("history cannot be empty")
+            .timestamp();
+
+        let timestamp = (latest_timestamp + drift).expect("Never fails");
+
+        self.chains[chain].ctx.advance_host_chain_time_to(timestamp);
+    }
+
+    /// Creates a client of the chain `of` on the chain `on`, at the latest
+    /// height of `of`, and returns its identifier.
+    ///
+    /// The client is a mock client for a mock chain, and a Tendermint client
+    /// for a synthetic Tendermint chain.
+    pub fn create_client(&mut self, on: usize, of: usize) -> Result<ClientId, RelayerError> {
+        let ctx_of = self.chain(of);
+
+        let header = ctx_of
+            .query_latest_header()
+            .expect("history cannot be empty");
+
+        let (client_state, consensus_state): (Any, Any) = match header {
+            HostBlock::Mock(header) => (
+                MockClientState::new(*header).into(),
+                MockConsensusState::new(*header).into(),
+            ),
+            block @ HostBlock::SyntheticTendermint(_) => {
+                let client_state: TmClientState = TmClientStateConfig::builder()
+                    .chain_id(ctx_of.host_chain_id.clone())
+                    .latest_height(block.height())
+                    .build()
+                    .try_into()
+                    .expect("never fails");
+
+                (client_state.into(), AnyConsensusState::from(block).into())
+            }
+        };
+
+        let msg = MsgCreateClient::new(client_state, consensus_state, self.chain(on).signer());
+
+        let events = self.deliver(on, ClientMsg::CreateClient(msg))?;
+
+        events
+            .iter()
+            .find_map(|event| match event {
+                IbcEvent::CreateClient(event) => Some(event.client_id().clone()),
+                _ => None,
+            })
+            .ok_or(RelayerError::MissingEvent {
+                kind: "create_client",
+            })
+    }
+
+    /// Updates the client on the chain `on` to the latest header of the
+    /// chain `of` it tracks, unless the client is already up-to-date.
+    pub fn update_client(
+        &mut self,
+        on: usize,
+        client_id: &ClientId,
+        of: usize,
+    ) -> Result<(), RelayerError> {
+        let client_height = self
+            .chain(on)
+            .query_client_full_state(client_id)
+            .ok_or_else(|| RelayerError::ClientStateNotFound {
+                client_id: client_id.clone(),
+            })?
+            .latest_height();
+
+        let mut header = self
+            .chain(of)
+            .query_latest_header()
+            .expect("history cannot be empty");
+
+        if header.height() <= client_height {
+            return Ok(());
+        }
+
+        header.set_trusted_height(client_height);
+
+        let msg = MsgUpdateClient {
+            client_id: client_id.clone(),
+            client_message: header.into(),
+            signer: self.chain(on).signer(),
+        };
+
+        self.deliver(on, ClientMsg::UpdateClient(msg))?;
+
+        Ok(())
+    }
+
+    /// Creates the clients of the chains `a` and `b` on each other, and opens
+    /// a connection between them, initiated by `a`.
+    pub fn open_connection(
+        &mut self,
+        a: usize,
+        b: usize,
+    ) -> Result<RelayedConnection, RelayerError> {
+        let client_id_on_a = self.create_client(a, b)?;
+        let client_id_on_b = self.create_client(b, a)?;
+
+        self.open_connection_on_clients(a, client_id_on_a, b, client_id_on_b)
+    }
+
+    /// Opens a connection between the chains `a` and `b`, initiated by `a`,
+    /// on their existing clients of each other.
+    pub fn open_connection_on_clients(
+        &mut self,
+        a: usize,
+        client_id_on_a: ClientId,
+        b: usize,
+        client_id_on_b: ClientId,
+    ) -> Result<RelayedConnection, RelayerError> {
+        let msg = MsgConnectionOpenInit {
+            client_id_on_a: client_id_on_a.clone(),
+            counterparty: ConnectionCounterparty::new(
+                client_id_on_b.clone(),
+                None,
+                self.chain(b).commitment_prefix(),
+            ),
+            version: None,
+            delay_period: Duration::ZERO,
+            signer: self.chain(a).signer(),
+        };
+
+        let events = self.deliver(a, ConnectionMsg::OpenInit(msg))?;
+
+        let conn_id_on_a = events
+            .iter()
+            .find_map(|event| match event {
+                IbcEvent::OpenInitConnection(event) => Some(event.conn_id_on_a().clone()),
+                _ => None,
+            })
+            .ok_or(RelayerError::MissingEvent {
+                kind: "connection_open_init",
+            })?;
+
+        let proofs_height_on_a = self.update_clients(a, &client_id_on_a, b, &client_id_on_b)?;
+
+        let ctx_a = self.chain(a);
+
+        let client_state_of_b_on_a = ctx_a
+            .client_state(&client_id_on_a)
+            .map_err(RelayerError::Query)?;
+
+        #[allow(deprecated)]
+        let msg = MsgConnectionOpenTry {
+            client_id_on_b: client_id_on_b.clone(),
+            consensus_height_of_b_on_a: client_state_of_b_on_a.latest_height(),
+            client_state_of_b_on_a: client_state_of_b_on_a.into(),
+            counterparty: ConnectionCounterparty::new(
+                client_id_on_a.clone(),
+                Some(conn_id_on_a.clone()),
+                ctx_a.commitment_prefix(),
+            ),
+            versions_on_a: ctx_a
+                .connection_end(&conn_id_on_a)
+                .map_err(RelayerError::Query)?
+                .versions()
+                .to_vec(),
+            proof_conn_end_on_a: relayed_proof(),
+            proof_client_state_of_b_on_a: relayed_proof(),
+            proof_consensus_state_of_b_on_a: relayed_proof(),
+            proofs_height_on_a,
+            delay_period: Duration::ZERO,
+            signer: self.chain(b).signer(),
+            proof_consensus_state_of_b: None,
+            previous_connection_id: String::new(),
+        };
+
+        let events = self.deliver(b, ConnectionMsg::OpenTry(msg))?;
+
+        let conn_id_on_b = events
+            .iter()
+            .find_map(|event| match event {
+                IbcEvent::OpenTryConnection(event) => Some(event.conn_id_on_b().clone()),
+                _ => None,
+            })
+            .ok_or(RelayerError::MissingEvent {
+                kind: "connection_open_try",
+            })?;
+
+        let proofs_height_on_b = self.update_clients(b, &client_id_on_b, a, &client_id_on_a)?;
+
+        let ctx_b = self.chain(b);
+
+        let client_state_of_a_on_b = ctx_b
+            .client_state(&client_id_on_b)
+            .map_err(RelayerError::Query)?;
+
+        let version = ctx_b
+            .connection_end(&conn_id_on_b)
+            .map_err(RelayerError::Query)?
+            .versions()[0]
+            .clone();
+
+        let msg = MsgConnectionOpenAck {
+            conn_id_on_a: conn_id_on_a.clone(),
+            conn_id_on_b: conn_id_on_b.clone(),
+            consensus_height_of_a_on_b: client_state_of_a_on_b.latest_height(),
+            client_state_of_a_on_b: client_state_of_a_on_b.into(),
+            proof_conn_end_on_b: relayed_proof(),
+            proof_client_state_of_a_on_b: relayed_proof(),
+            proof_consensus_state_of_a_on_b: relayed_proof(),
+            proofs_height_on_b,
+            version,
+            signer: self.chain(a).signer(),
+            proof_consensus_state_of_a: None,
+        };
+
+        self.deliver(a, ConnectionMsg::OpenAck(msg))?;
+
+        let proof_height_on_a = self.update_clients(a, &client_id_on_a, b, &client_id_on_b)?;
+
+        let msg = MsgConnectionOpenConfirm {
+            conn_id_on_b: conn_id_on_b.clone(),
+            proof_conn_end_on_a: relayed_proof(),
+            proof_height_on_a,
+            signer: self.chain(b).signer(),
+        };
+
+        self.deliver(b, ConnectionMsg::OpenConfirm(msg))?;
+
+        Ok(RelayedConnection {
+            chain_a: a,
+            chain_b: b,
+            client_id_on_a,
+            client_id_on_b,
+            conn_id_on_a,
+            conn_id_on_b,
+        })
+    }
+
+    /// Opens an unordered channel on the connection, between the given port
+    /// on both chains, initiated by `chain_a` of the connection.
+    pub fn open_channel(
+        &mut self,
+        connection: &RelayedConnection,
+        port_id: PortId,
+        version: ChannelVersion,
+    ) -> Result<RelayedChannel, RelayerError> {
+        let RelayedConnection {
+            chain_a: a,
+            chain_b: b,
+            client_id_on_a,
+            client_id_on_b,
+            conn_id_on_a,
+            conn_id_on_b,
+        } = connection;
+
+        let (a, b) = (*a, *b);
+
+        let msg = MsgChannelOpenInit {
+            port_id_on_a: port_id.clone(),
+            connection_hops_on_a: vec![conn_id_on_a.clone()],
+            port_id_on_b: port_id.clone(),
+            ordering: Order::Unordered,
+            signer: self.chain(a).signer(),
+            version_proposal: version,
+        };
+
+        let events = self.deliver(a, ChannelMsg::OpenInit(msg))?;
+
+        let chan_id_on_a = events
+            .iter()
+            .find_map(|event| match event {
+                IbcEvent::OpenInitChannel(event) => Some(event.chan_id_on_a().clone()),
+                _ => None,
+            })
+            .ok_or(RelayerError::MissingEvent {
+                kind: "channel_open_init",
+            })?;
+
+        let proof_height_on_a = self.update_clients(a, client_id_on_a, b, client_id_on_b)?;
+
+        let version_supported_on_a = self
+            .chain(a)
+            .channel_end(&ChannelEndPath::new(&port_id, &chan_id_on_a))
+            .map_err(RelayerError::Query)?
+            .version()
+            .clone();
+
+        #[allow(deprecated)]
+        let msg = MsgChannelOpenTry {
+            port_id_on_b: port_id.clone(),
+            connection_hops_on_b: vec![conn_id_on_b.clone()],
+            port_id_on_a: port_id.clone(),
+            chan_id_on_a: chan_id_on_a.clone(),
+            version_proposal: version_supported_on_a.clone(),
+            version_supported_on_a,
+            proof_chan_end_on_a: relayed_proof(),
+            proof_height_on_a,
+            ordering: Order::Unordered,
+            signer: self.chain(b).signer(),
+        };
+
+        let events = self.deliver(b, ChannelMsg::OpenTry(msg))?;
+
+        let chan_id_on_b = events
+            .iter()
+            .find_map(|event| match event {
+                IbcEvent::OpenTryChannel(event) => Some(event.chan_id_on_b().clone()),
+                _ => None,
+            })
+            .ok_or(RelayerError::MissingEvent {
+                kind: "channel_open_try",
+            })?;
+
+        let proof_height_on_b = self.update_clients(b, client_id_on_b, a, client_id_on_a)?;
+
+        let version_on_b = self
+            .chain(b)
+            .channel_end(&ChannelEndPath::new(&port_id, &chan_id_on_b))
+            .map_err(RelayerError::Query)?
+            .version()
+            .clone();
+
+        let msg = MsgChannelOpenAck {
+            port_id_on_a: port_id.clone(),
+            chan_id_on_a: chan_id_on_a.clone(),
+            chan_id_on_b: chan_id_on_b.clone(),
+            version_on_b,
+            proof_chan_end_on_b: relayed_proof(),
+            proof_height_on_b,
+            signer: self.chain(a).signer(),
+        };
+
+        self.deliver(a, ChannelMsg::OpenAck(msg))?;
+
+        let proof_height_on_a = self.update_clients(a, client_id_on_a, b, client_id_on_b)?;
+
+        let msg = MsgChannelOpenConfirm {
+            port_id_on_b: port_id.clone(),
+            chan_id_on_b: chan_id_on_b.clone(),
+            proof_chan_end_on_a: relayed_proof(),
+            proof_height_on_a,
+            signer: self.chain(b).signer(),
+        };
+
+        self.deliver(b, ChannelMsg::OpenConfirm(msg))?;
+
+        Ok(RelayedChannel {
+            connection: connection.clone(),
+            port_id_on_a: port_id.clone(),
+            chan_id_on_a,
+            port_id_on_b: port_id,
+            chan_id_on_b,
+        })
+    }
+
+    /// Sends a packet with the given data on the channel, from `chain_a` of
+    /// its connection, and commits the block it was sent in.
+    ///
+    /// The packet times out [`PACKET_TIMEOUT_BLOCKS`] blocks past the current
+    /// height of the receiving chain.
+    pub fn send_packet(
+        &mut self,
+        channel: &RelayedChannel,
+        data: Vec<u8>,
+    ) -> Result<Packet, RelayerError> {
+        let timeout_height_on_b = TimeoutHeight::At(
+            self.chain(channel.connection.chain_b)
+                .latest_height()
+                .add(PACKET_TIMEOUT_BLOCKS),
+        );
+
+        self.send_packet_with_timeout(channel, data, timeout_height_on_b, Timestamp::none())
+    }
+
+    /// Sends a packet with the given data and timeouts on the channel, from
+    /// `chain_a` of its connection, and commits the block it was sent in.
+    pub fn send_packet_with_timeout(
+        &mut self,
+        channel: &RelayedChannel,
+        data: Vec<u8>,
+        timeout_height_on_b: TimeoutHeight,
+        timeout_timestamp_on_b: Timestamp,
+    ) -> Result<Packet, RelayerError> {
+        let a = channel.connection.chain_a;
+
+        let seq_on_a = self
+            .chain(a)
+            .get_next_sequence_send(&SeqSendPath::new(
+                &channel.port_id_on_a,
+                &channel.chan_id_on_a,
+            ))
+            .map_err(RelayerError::Query)?;
+
+        let packet = Packet {
+            seq_on_a,
+            port_id_on_a: channel.port_id_on_a.clone(),
+            chan_id_on_a: channel.chan_id_on_a.clone(),
+            port_id_on_b: channel.port_id_on_b.clone(),
+            chan_id_on_b: channel.chan_id_on_b.clone(),
+            data,
+            timeout_height_on_b,
+            timeout_timestamp_on_b,
+        };
+
+        let ctx_a = &mut self.chain_mut(a).ctx;
+
+        let emitted = ctx_a.get_events().len();
+
+        send_packet(ctx_a, packet.clone()).map_err(RelayerError::TransactionFailed)?;
+
+        ctx_a.advance_host_chain_height();
+
+        let events = ctx_a.get_events().split_off(emitted);
+
+        self.trace.push(TraceStep::SendPacket {
+            chain: a,
+            packet: packet.clone(),
+            events,
+        });
+
+        Ok(packet)
+    }
+
+    /// Relays the packet sent on the channel to `chain_b` of its connection,
+    /// then relays its acknowledgement back to `chain_a`, and returns the
+    /// acknowledgement.
+    pub fn relay_packet(
+        &mut self,
+        channel: &RelayedChannel,
+        packet: &Packet,
+    ) -> Result<Acknowledgement, RelayerError> {
+        let acknowledgement = self.recv_packet(channel, packet)?;
+
+        self.acknowledge_packet(channel, packet, acknowledgement.clone())?;
+
+        Ok(acknowledgement)
+    }
+
+    /// Relays the packet sent on the channel to `chain_b` of its connection,
+    /// and returns the acknowledgement written by the receiving module.
+    pub fn recv_packet(
+        &mut self,
+        channel: &RelayedChannel,
+        packet: &Packet,
+    ) -> Result<Acknowledgement, RelayerError> {
+        let msg = self.recv_packet_msg(channel, packet)?;
+
+        let events = self.deliver(channel.connection.chain_b, PacketMsg::Recv(msg))?;
+
+        let acknowledgement = events
+            .iter()
+            .find_map(|event| match event {
+                IbcEvent::WriteAcknowledgement(event) => Some(event.acknowledgement().clone()),
+                _ => None,
+            })
+            .ok_or(RelayerError::MissingEvent {
+                kind: "write_acknowledgement",
+            })?;
+
+        Ok(acknowledgement)
+    }
+
+    /// Updates the clients of the chains of the connection of the channel on
+    /// each other, and returns the message relaying the packet sent on the
+    /// channel to `chain_b`, without delivering it.
+    pub fn recv_packet_msg(
+        &mut self,
+        channel: &RelayedChannel,
+        packet: &Packet,
+    ) -> Result<MsgRecvPacket, RelayerError> {
+        let RelayedConnection {
+            chain_a: a,
+            chain_b: b,
+            client_id_on_a,
+            client_id_on_b,
+            ..
+        } = &channel.connection;
+
+        let (a, b) = (*a, *b);
+
+        let proof_height_on_a = self.update_clients(a, client_id_on_a, b, client_id_on_b)?;
+
+        Ok(MsgRecvPacket {
+            packet: packet.clone(),
+            proof_commitment_on_a: relayed_proof(),
+            proof_height_on_a,
+            signer: self.chain(b).signer(),
+        })
+    }
+
+    /// Relays the acknowledgement of the packet received by `chain_b` of the
+    /// connection of the channel back to `chain_a`.
+    pub fn acknowledge_packet(
+        &mut self,
+        channel: &RelayedChannel,
+        packet: &Packet,
+        acknowledgement: Acknowledgement,
+    ) -> Result<(), RelayerError> {
+        let msg = self.acknowledge_packet_msg(channel, packet, acknowledgement)?;
+
+        self.deliver(channel.connection.chain_a, PacketMsg::Ack(msg))?;
+
+        Ok(())
+    }
+
+    /// Updates the clients of the chains of the connection of the channel on
+    /// each other, and returns the message relaying the acknowledgement of the
+    /// packet back to `chain_a`, without delivering it.
+    pub fn acknowledge_packet_msg(
+        &mut self,
+        channel: &RelayedChannel,
+        packet: &Packet,
+        acknowledgement: Acknowledgement,
+    ) -> Result<MsgAcknowledgement, RelayerError> {
+        let RelayedConnection {
+            chain_a: a,
+            chain_b: b,
+            client_id_on_a,
+            client_id_on_b,
+            ..
+        } = &channel.connection;
+
+        let (a, b) = (*a, *b);
+
+        let proof_height_on_b = self.update_clients(b, client_id_on_b, a, client_id_on_a)?;
+
+        Ok(MsgAcknowledgement {
+            packet: packet.clone(),
+            acknowledgement,
+            proof_acked_on_b: relayed_proof(),
+            proof_height_on_b,
+            signer: self.chain(a).signer(),
+        })
+    }
+
+    /// Times out the packet sent on the channel, which `chain_b` of its
+    /// connection has not received, on `chain_a`.
+    pub fn timeout_packet(
+        &mut self,
+        channel: &RelayedChannel,
+        packet: &Packet,
+    ) -> Result<(), RelayerError> {
+        let msg = self.timeout_packet_msg(channel, packet)?;
+
+        self.deliver(channel.connection.chain_a, PacketMsg::Timeout(msg))?;
+
+        Ok(())
+    }
+
+    /// Commits blocks on `chain_b` of the connection of the channel past the
+    /// timeout height and the timeout timestamp of the packet, updates the
+    /// clients of the chains on each other, and returns the message timing
+    /// out the packet on `chain_a`, without delivering it.
+    ///
+    /// A timeout height in a revision other than the current one of
+    /// `chain_b` is not waited for.
+    pub fn timeout_packet_msg(
+        &mut self,
+        channel: &RelayedChannel,
+        packet: &Packet,
+    ) -> Result<MsgTimeout, RelayerError> {
+        self.advance_past_timeout(
+            channel.connection.chain_b,
+            packet.timeout_height_on_b,
+            packet.timeout_timestamp_on_b,
+        );
+
+        let (proof_height_on_b, next_seq_recv_on_b, _) = self.packet_proofs_on_b(channel)?;
+
+        Ok(MsgTimeout {
+            packet: packet.clone(),
+            next_seq_recv_on_b,
+            proof_unreceived_on_b: relayed_proof(),
+            proof_height_on_b,
+            signer: self.chain(channel.connection.chain_a).signer(),
+        })
+    }
+
+    /// Times out the packet sent on the channel, whose end on `chain_b` of
+    /// its connection was closed, on `chain_a`.
+    pub fn timeout_on_close_packet(
+        &mut self,
+        channel: &RelayedChannel,
+        packet: &Packet,
+    ) -> Result<(), RelayerError> {
+        let msg = self.timeout_on_close_packet_msg(channel, packet)?;
+
+        self.deliver(channel.connection.chain_a, PacketMsg::TimeoutOnClose(msg))?;
+
+        Ok(())
+    }
+
+    /// Updates the clients of the chains of the connection of the channel on
+    /// each other, and returns the message timing out the packet on
+    /// `chain_a` on the closing of the channel end on `chain_b`, without
+    /// delivering it.
+    pub fn timeout_on_close_packet_msg(
+        &mut self,
+        channel: &RelayedChannel,
+        packet: &Packet,
+    ) -> Result<MsgTimeoutOnClose, RelayerError> {
+        let (proof_height_on_b, next_seq_recv_on_b, upgrade_sequence_on_b) =
+            self.packet_proofs_on_b(channel)?;
+
+        Ok(MsgTimeoutOnClose {
+            packet: packet.clone(),
+            next_seq_recv_on_b,
+            proof_unreceived_on_b: relayed_proof(),
+            proof_close_on_b: relayed_proof(),
+            proof_height_on_b,
+            upgrade_sequence_on_b,
+            signer: self.chain(channel.connection.chain_a).signer(),
+        })
+    }
+
+    /// Updates the clients of the chains of the connection of the channel on
+    /// each other, and returns the height at which the proofs of the channel
+    /// end on `chain_b` are taken, along with its next sequence to receive
+    /// and its upgrade sequence.
+    fn packet_proofs_on_b(
+        &mut self,
+        channel: &RelayedChannel,
+    ) -> Result<(Height, Sequence, u64), RelayerError> {
+        let RelayedConnection {
+            chain_a: a,
+            chain_b: b,
+            client_id_on_a,
+            client_id_on_b,
+            ..
+        } = &channel.connection;
+
+        let (a, b) = (*a, *b);
+
+        let proof_height_on_b = self.update_clients(b, client_id_on_b, a, client_id_on_a)?;
+
+        let next_seq_recv_on_b = self
+            .chain(b)
+            .get_next_sequence_recv(&SeqRecvPath::new(
+                &channel.port_id_on_b,
+                &channel.chan_id_on_b,
+            ))
+            .map_err(RelayerError::Query)?;
+
+        let chan_end_on_b = self
+            .chain(b)
+            .channel_end(&ChannelEndPath::new(
+                &channel.port_id_on_b,
+                &channel.chan_id_on_b,
+            ))
+            .map_err(RelayerError::Query)?;
+
+        Ok((
+            proof_height_on_b,
+            next_seq_recv_on_b,
+            chan_end_on_b.upgrade_sequence,
+        ))
+    }
+
+    /// Commits blocks on the chain past the timeout height and the timeout
+    /// timestamp, the timeout height being waited for only if it is in the
+    /// current revision of the chain.
+    pub(crate) fn advance_past_timeout(
+        &mut self,
+        chain: usize,
+        timeout_height: TimeoutHeight,
+        timeout_timestamp: Timestamp,
+    ) {
+        let ctx = &mut self.chain_mut(chain).ctx;
+
+        if let TimeoutHeight::At(timeout_height) = timeout_height {
+            while ctx.latest_height().revision_number() == timeout_height.revision_number()
+                && ctx.latest_height() <= timeout_height
+            {
+                ctx.advance_host_chain_height();
+            }
+        }
+
+        let latest_timestamp = ctx
+            .host_block(&ctx.latest_height())
+            .map(|block| block.timestamp())
+            .unwrap_or_else(Timestamp::none);
+
+        if let Some(duration) = timeout_timestamp.duration_since(&latest_timestamp) {
+            ctx.advance_host_chain_time(duration + Duration::from_nanos(1));
+        }
+    }
+
+    /// Updates the clients of the chains `src` and `dst` on each other, the
+    /// client on `dst` last, and returns the latest height of `src`, at which
+    /// the proofs of the message relayed from `src` to `dst` are taken.
+    ///
+    /// The client on `src` is updated too, for the consensus state of `dst`
+    /// it holds to still be in the history of `dst` for the connection
+    /// handshakes.
+    pub(crate) fn update_clients(
+        &mut self,
+        src: usize,
+        client_id_on_src: &ClientId,
+        dst: usize,
+        client_id_on_dst: &ClientId,
+    ) -> Result<Height, RelayerError> {
+        self.update_client(src, client_id_on_src, dst)?;
+        self.update_client(dst, client_id_on_dst, src)?;
+
+        Ok(self.chain(src).latest_height())
+    }
+
+    /// Delivers the message to the chain, records it in the trace, and
+    /// returns the events it emitted.
+    pub(crate) fn deliver(
+        &mut self,
+        chain: usize,
+        msg: impl Into<MsgEnvelope>,
+    ) -> Result<Vec<IbcEvent>, RelayerError> {
+        let RelayedChain { ctx, router } = &mut self.chains[chain];
+
+        let msg = msg.into();
+
+        let emitted = ctx.get_events().len();
+
+        ctx.deliver(router, msg.clone())?;
+
+        let events = ctx.get_events().split_off(emitted);
+
+        self.trace.push(TraceStep::Msg {
+            chain,
+            msg,
+            events: events.clone(),
+        });
+
+        Ok(events)
+    }
+}
+
+/// Returns the proof carried by the relayed messages, which the mock clients
+/// accept.
+pub(crate) fn relayed_proof() -> CommitmentProofBytes {
+    dummy_proof().try_into().expect("Never fails")
+}
@@ -1,2 +1,6 @@
+pub mod byzantine;
 pub mod context;
 pub mod error;
+pub mod harness;
+pub mod scenario;
+pub mod upgrade;
@@ -0,0 +1,308 @@
+//! A scenario of the IBC lifecycle between two chains, described as a chain
+//! of steps over a [`RelayerHarness`], for the tests of the applications to
+//! set up their preconditions in a few lines.
+//!
+//! ```rust,ignore
+//! let outcome = Scenario::new()
+//!     .create_clients()
+//!     .open_connection()
+//!     .open_channel("transfer")
+//!     .send_packet(data)
+//!     .relay()
+//!     .ack()
+//!     .send_packet(data)
+//!     .timeout()
+//!     .run()?;
+//!
+//! for step in outcome.harness.trace() {
+//!     // The messages delivered, the packets sent and their events.
+//! }
+//! ```
+//!
+//! The steps run as they are chained, on the chains `0` and `1` of the
+//! harness, the latter being the counterparty of the former. The first step
+//! failing is returned by [`Scenario::run`], the following ones being skipped.
+
+use core::time::Duration;
+
+use ibc::core::channel::types::acknowledgement::Acknowledgement;
+use ibc::core::channel::types::packet::Packet;
+use ibc::core::channel::types::Version as ChannelVersion;
+use ibc::core::host::types::identifiers::{ClientId, PortId};
+use ibc::core::primitives::prelude::*;
+
+use crate::fixtures::core::context::mock_chain;
+use crate::relayer::error::RelayerError;
+use crate::relayer::harness::{RelayedChannel, RelayedConnection, RelayerHarness};
+use crate::testapp::ibc::core::types::MockContext;
+
+/// The chain initiating the handshakes and sending the packets.
+const CHAIN_A: usize = 0;
+
+/// The counterparty chain.
+const CHAIN_B: usize = 1;
+
+/// The state of a [`Scenario`], built up by its steps.
+#[derive(Default)]
+struct ScenarioState {
+    clients: Option<(ClientId, ClientId)>,
+    connection: Option<RelayedConnection>,
+    channel: Option<RelayedChannel>,
+    /// The packets sent, which are not received yet.
+    sent: Vec<Packet>,
+    /// The packets received, along with their acknowledgements, which are
+    /// not relayed back yet.
+    received: Vec<(Packet, Acknowledgement)>,
+    /// The packets acknowledged, along with their acknowledgements.
+    acknowledged: Vec<(Packet, Acknowledgement)>,
+    /// The packets timed out.
+    timed_out: Vec<Packet>,
+}
+
+/// The outcome of a [`Scenario`] which ran to completion.
+pub struct ScenarioOutcome {
+    /// The harness the scenario ran on, with the trace of its messages.
+    pub harness: RelayerHarness,
+    pub connection: Option<RelayedConnection>,
+    pub channel: Option<RelayedChannel>,
+    /// The packets sent, which were not received.
+    pub sent: Vec<Packet>,
+    /// The packets received, along with their acknowledgements, which were
+    /// not relayed back.
+    pub received: Vec<(Packet, Acknowledgement)>,
+    /// The packets acknowledged, along with their acknowledgements.
+    pub acknowledged: Vec<(Packet, Acknowledgement)>,
+    /// The packets timed out.
+    pub timed_out: Vec<Packet>,
+}
+
+/// A chain of steps run over a [`RelayerHarness`] of two chains.
+pub struct Scenario {
+    harness: RelayerHarness,
+    state: Result<ScenarioState, RelayerError>,
+}
+
+impl Default for Scenario {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Scenario {
+    /// Creates a scenario between two mock chains, `mockgaiaA-1` and
+    /// `mockgaiaB-1`.
+    pub fn new() -> Self {
+        Self::with_chains(mock_chain("mockgaiaA-1"), mock_chain("mockgaiaB-1"))
+    }
+
+    /// Creates a scenario between the given chains, whose routers bind the
+    /// transfer port to the dummy transfer module.
+    pub fn with_chains(ctx_a: MockContext, ctx_b: MockContext) -> Self {
+        Self::with_harness(RelayerHarness::new().with_chain(ctx_a).with_chain(ctx_b))
+    }
+
+    /// Creates a scenario over the given harness, between its chains `0` and
+    /// `1`.
+    pub fn with_harness(harness: RelayerHarness) -> Self {
+        Self {
+            harness,
+            state: Ok(ScenarioState::default()),
+        }
+    }
+
+    /// Creates the clients of the chains on each other.
+    pub fn create_clients(self) -> Self {
+        self.step(|harness, state| {
+            let client_id_on_a = harness.create_client(CHAIN_A, CHAIN_B)?;
+            let client_id_on_b = harness.create_client(CHAIN_B, CHAIN_A)?;
+
+            state.clients = Some((client_id_on_a, client_id_on_b));
+
+            Ok(())
+        })
+    }
+
+    /// Opens a connection between the chains, on their clients of each other
+    /// if they were created, or on new clients otherwise.
+    pub fn open_connection(self) -> Self {
+        self.step(|harness, state| {
+            let connection = match state.clients.clone() {
+                Some((client_id_on_a, client_id_on_b)) => harness.open_connection_on_clients(
+                    CHAIN_A,
+                    client_id_on_a,
+                    CHAIN_B,
+                    client_id_on_b,
+                )?,
+                None => harness.open_connection(CHAIN_A, CHAIN_B)?,
+            };
+
+            state.clients = Some((
+                connection.client_id_on_a.clone(),
+                connection.client_id_on_b.clone(),
+            ));
+            state.connection = Some(connection);
+
+            Ok(())
+        })
+    }
+
+    /// Opens an unordered channel on the connection, between the given port
+    /// on both chains, proposing an empty version for the modules to pick
+    /// their default one.
+    pub fn open_channel(self, port_id: &str) -> Self {
+        self.open_channel_with_version(port_id, ChannelVersion::empty())
+    }
+
+    /// Opens an unordered channel on the connection, between the given port
+    /// on both chains, proposing the given version.
+    pub fn open_channel_with_version(self, port_id: &str, version: ChannelVersion) -> Self {
+        let port_id = port_id.to_string();
+
+        self.step(move |harness, state| {
+            let connection =
+                state
+                    .connection
+                    .as_ref()
+                    .ok_or(RelayerError::MissingScenarioStep {
+                        step: "open_connection",
+                    })?;
+
+            let port_id = PortId::new(port_id).map_err(RelayerError::Identifier)?;
+
+            state.channel = Some(harness.open_channel(connection, port_id, version)?);
+
+            Ok(())
+        })
+    }
+
+    /// Sends a packet with the given data on the channel.
+    pub fn send_packet(self, data: Vec<u8>) -> Self {
+        self.step(move |harness, state| {
+            let channel = state
+                .channel
+                .as_ref()
+                .ok_or(RelayerError::MissingScenarioStep {
+                    step: "open_channel",
+                })?;
+
+            let packet = harness.send_packet(channel, data)?;
+
+            state.sent.push(packet);
+
+            Ok(())
+        })
+    }
+
+    /// Relays the packets sent, and not received yet, to the counterparty.
+    pub fn relay(self) -> Self {
+        self.step(|harness, state| {
+            let channel = state
+                .channel
+                .as_ref()
+                .ok_or(RelayerError::MissingScenarioStep {
+                    step: "open_channel",
+                })?;
+
+            for packet in core::mem::take(&mut state.sent) {
+                let acknowledgement = harness.recv_packet(channel, &packet)?;
+
+                state.received.push((packet, acknowledgement));
+            }
+
+            Ok(())
+        })
+    }
+
+    /// Relays the acknowledgements of the packets received, and not
+    /// acknowledged yet, back to the sender.
+    pub fn ack(self) -> Self {
+        self.step(|harness, state| {
+            let channel = state
+                .channel
+                .as_ref()
+                .ok_or(RelayerError::MissingScenarioStep {
+                    step: "open_channel",
+                })?;
+
+            for (packet, acknowledgement) in core::mem::take(&mut state.received) {
+                harness.acknowledge_packet(channel, &packet, acknowledgement.clone())?;
+
+                state.acknowledged.push((packet, acknowledgement));
+            }
+
+            Ok(())
+        })
+    }
+
+    /// Times out the packets sent, and not received yet, on the sender,
+    /// committing blocks on the counterparty past their timeouts.
+    pub fn timeout(self) -> Self {
+        self.step(|harness, state| {
+            let channel = state
+                .channel
+                .as_ref()
+                .ok_or(RelayerError::MissingScenarioStep {
+                    step: "open_channel",
+                })?;
+
+            for packet in core::mem::take(&mut state.sent) {
+                harness.timeout_packet(channel, &packet)?;
+
+                state.timed_out.push(packet);
+            }
+
+            Ok(())
+        })
+    }
+
+    /// Commits the given number of blocks on both chains.
+    pub fn advance_blocks(self, blocks: u64) -> Self {
+        self.step(move |harness, _| {
+            harness.advance_blocks(blocks);
+
+            Ok(())
+        })
+    }
+
+    /// Commits a block on both chains, `duration` after their latest block.
+    pub fn advance_time(self, duration: Duration) -> Self {
+        self.step(move |harness, _| {
+            harness.advance_time(duration);
+
+            Ok(())
+        })
+    }
+
+    /// Returns the outcome of the scenario, or the error of its first failing
+    /// step.
+    pub fn run(self) -> Result<ScenarioOutcome, RelayerError> {
+        let state = self.state?;
+
+        Ok(ScenarioOutcome {
+            harness: self.harness,
+            connection: state.connection,
+            channel: state.channel,
+            sent: state.sent,
+            received: state.received,
+            acknowledged: state.acknowledged,
+            timed_out: state.timed_out,
+        })
+    }
+
+    /// Runs the step, unless a previous step failed.
+    fn step(
+        mut self,
+        step: impl FnOnce(&mut RelayerHarness, &mut ScenarioState) -> Result<(), RelayerError>,
+    ) -> Self {
+        let result = match &mut self.state {
+            Ok(state) => step(&mut self.harness, state),
+            Err(_) => return self,
+        };
+
+        if let Err(e) = result {
+            self.state = Err(e);
+        }
+
+        self
+    }
+}
@@ -0,0 +1,371 @@
+//! The channel upgrade handshakes relayed by a [`RelayerHarness`], along with
+//! the builders of their messages, for the tests to drive an upgrade to any of
+//! its steps, and to tamper with the messages before delivering them.
+//!
+//! ```rust,ignore
+//! harness.upgrade_channel(&channel, Order::Unordered, version)?;
+//!
+//! harness.upgrade_init(&channel, Order::Unordered, version)?;
+//! harness.upgrade_try(&channel)?;
+//! harness.upgrade_ack(&channel)?;
+//! harness.upgrade_timeout(&channel)?;
+//! harness.upgrade_cancel(&channel.flipped())?;
+//! ```
+//!
+//! The steps follow the handshake initiated by `chain_a` of the connection of
+//! the channel: the init, the ack and the open are delivered on `chain_a`,
+//! the try and the confirm on `chain_b`. The steps of an upgrade initiated by
+//! `chain_b` are taken on the [flipped](RelayedChannel::flipped) channel.
+
+use ibc::core::channel::types::channel::{ChannelEnd, Order};
+use ibc::core::channel::types::msgs::{
+    ChannelMsg, MsgChannelUpgradeAck, MsgChannelUpgradeCancel, MsgChannelUpgradeConfirm,
+    MsgChannelUpgradeInit, MsgChannelUpgradeOpen, MsgChannelUpgradeTimeout, MsgChannelUpgradeTry,
+};
+use ibc::core::channel::types::upgrade::{Upgrade, UpgradeFields};
+use ibc::core::channel::types::Version as ChannelVersion;
+use ibc::core::client::types::Height;
+use ibc::core::host::types::path::{ChannelEndPath, ChannelUpgradeErrorPath, ChannelUpgradePath};
+use ibc::core::host::ValidationContext;
+use ibc::core::primitives::prelude::*;
+
+use crate::relayer::context::RelayerContext;
+use crate::relayer::error::RelayerError;
+use crate::relayer::harness::{relayed_proof, RelayedChannel, RelayedConnection, RelayerHarness};
+
+impl RelayerHarness {
+    /// Upgrades the channel to the given ordering and version, on the same
+    /// connection, through the handshake initiated by `chain_a` of its
+    /// connection.
+    pub fn upgrade_channel(
+        &mut self,
+        channel: &RelayedChannel,
+        ordering: Order,
+        version: ChannelVersion,
+    ) -> Result<(), RelayerError> {
+        self.upgrade_init(channel, ordering, version)?;
+        self.upgrade_try(channel)?;
+        self.upgrade_ack(channel)?;
+        self.upgrade_confirm(channel)?;
+        self.upgrade_open(channel)?;
+        self.upgrade_open(&channel.flipped())
+    }
+
+    /// Upgrades the channel to the given ordering and version, on the same
+    /// connection, through the handshake initiated by both chains of its
+    /// connection at once.
+    pub fn upgrade_channel_crossing_hello(
+        &mut self,
+        channel: &RelayedChannel,
+        ordering: Order,
+        version: ChannelVersion,
+    ) -> Result<(), RelayerError> {
+        let flipped = channel.flipped();
+
+        self.upgrade_init(channel, ordering, version.clone())?;
+        self.upgrade_init(&flipped, ordering, version)?;
+        self.upgrade_try(channel)?;
+        self.upgrade_try(&flipped)?;
+        self.upgrade_ack(channel)?;
+        self.upgrade_confirm(channel)?;
+        self.upgrade_open(channel)?;
+        self.upgrade_open(&flipped)
+    }
+
+    /// Initiates the upgrade of the channel on `chain_a` of its connection.
+    pub fn upgrade_init(
+        &mut self,
+        channel: &RelayedChannel,
+        ordering: Order,
+        version: ChannelVersion,
+    ) -> Result<(), RelayerError> {
+        let msg = self.upgrade_init_msg(channel, ordering, version);
+
+        self.deliver(channel.connection.chain_a, ChannelMsg::UpgradeInit(msg))?;
+
+        Ok(())
+    }
+
+    /// Returns the message initiating the upgrade of the channel to the given
+    /// ordering and version, on the same connection, on `chain_a` of its
+    /// connection, without delivering it.
+    pub fn upgrade_init_msg(
+        &self,
+        channel: &RelayedChannel,
+        ordering: Order,
+        version: ChannelVersion,
+    ) -> MsgChannelUpgradeInit {
+        MsgChannelUpgradeInit {
+            port_id_on_a: channel.port_id_on_a.clone(),
+            chan_id_on_a: channel.chan_id_on_a.clone(),
+            fields: UpgradeFields::new(
+                ordering,
+                vec![channel.connection.conn_id_on_a.clone()],
+                version,
+            ),
+            signer: self.chain(channel.connection.chain_a).signer(),
+        }
+    }
+
+    /// Relays the upgrade initiated on `chain_a` of the connection of the
+    /// channel to `chain_b`.
+    pub fn upgrade_try(&mut self, channel: &RelayedChannel) -> Result<(), RelayerError> {
+        let msg = self.upgrade_try_msg(channel)?;
+
+        self.deliver(channel.connection.chain_b, ChannelMsg::UpgradeTry(msg))?;
+
+        Ok(())
+    }
+
+    /// Updates the clients of the chains of the connection of the channel on
+    /// each other, and returns the message relaying the upgrade initiated on
+    /// `chain_a` to `chain_b`, without delivering it.
+    pub fn upgrade_try_msg(
+        &mut self,
+        channel: &RelayedChannel,
+    ) -> Result<MsgChannelUpgradeTry, RelayerError> {
+        let connection = &channel.connection;
+
+        let proof_height_on_a = self.update_clients(
+            connection.chain_a,
+            &connection.client_id_on_a,
+            connection.chain_b,
+            &connection.client_id_on_b,
+        )?;
+
+        let chan_end_on_a = self.chan_end_on_a(channel)?;
+        let upgrade_on_a = self.upgrade_on_a(channel)?;
+
+        Ok(MsgChannelUpgradeTry {
+            port_id_on_b: channel.port_id_on_b.clone(),
+            chan_id_on_b: channel.chan_id_on_b.clone(),
+            proposed_conn_hops_on_b: vec![connection.conn_id_on_b.clone()],
+            upgrade_fields_on_a: upgrade_on_a.fields,
+            upgrade_sequence_on_a: chan_end_on_a.upgrade_sequence(),
+            proof_chan_end_on_a: relayed_proof(),
+            proof_upgrade_on_a: relayed_proof(),
+            proof_height_on_a,
+            signer: self.chain(connection.chain_b).signer(),
+        })
+    }
+
+    /// Relays the upgrade agreed to by `chain_b` of the connection of the
+    /// channel back to `chain_a`.
+    pub fn upgrade_ack(&mut self, channel: &RelayedChannel) -> Result<(), RelayerError> {
+        let msg = self.upgrade_ack_msg(channel)?;
+
+        self.deliver(channel.connection.chain_a, ChannelMsg::UpgradeAck(msg))?;
+
+        Ok(())
+    }
+
+    /// Updates the clients of the chains of the connection of the channel on
+    /// each other, and returns the message relaying the upgrade agreed to by
+    /// `chain_b` to `chain_a`, without delivering it.
+    pub fn upgrade_ack_msg(
+        &mut self,
+        channel: &RelayedChannel,
+    ) -> Result<MsgChannelUpgradeAck, RelayerError> {
+        let proof_height_on_b = self.update_clients_to_a(&channel.connection)?;
+
+        let upgrade_on_b = self.upgrade_on_a(&channel.flipped())?;
+
+        Ok(MsgChannelUpgradeAck {
+            port_id_on_a: channel.port_id_on_a.clone(),
+            chan_id_on_a: channel.chan_id_on_a.clone(),
+            upgrade_on_b,
+            proof_chan_end_on_b: relayed_proof(),
+            proof_upgrade_on_b: relayed_proof(),
+            proof_height_on_b,
+            signer: self.chain(channel.connection.chain_a).signer(),
+        })
+    }
+
+    /// Relays the upgrade acknowledged by `chain_a` of the connection of the
+    /// channel to `chain_b`.
+    pub fn upgrade_confirm(&mut self, channel: &RelayedChannel) -> Result<(), RelayerError> {
+        let msg = self.upgrade_confirm_msg(channel)?;
+
+        self.deliver(channel.connection.chain_b, ChannelMsg::UpgradeConfirm(msg))?;
+
+        Ok(())
+    }
+
+    /// Updates the clients of the chains of the connection of the channel on
+    /// each other, and returns the message relaying the upgrade acknowledged
+    /// by `chain_a` to `chain_b`, without delivering it.
+    pub fn upgrade_confirm_msg(
+        &mut self,
+        channel: &RelayedChannel,
+    ) -> Result<MsgChannelUpgradeConfirm, RelayerError> {
+        let connection = &channel.connection;
+
+        let proof_height_on_a = self.update_clients(
+            connection.chain_a,
+            &connection.client_id_on_a,
+            connection.chain_b,
+            &connection.client_id_on_b,
+        )?;
+
+        let chan_end_on_a = self.chan_end_on_a(channel)?;
+        let upgrade_on_a = self.upgrade_on_a(channel)?;
+
+        Ok(MsgChannelUpgradeConfirm {
+            port_id_on_b: channel.port_id_on_b.clone(),
+            chan_id_on_b: channel.chan_id_on_b.clone(),
+            chan_state_on_a: chan_end_on_a.state,
+            upgrade_on_a,
+            proof_chan_end_on_a: relayed_proof(),
+            proof_upgrade_on_a: relayed_proof(),
+            proof_height_on_a,
+            signer: self.chain(connection.chain_b).signer(),
+        })
+    }
+
+    /// Opens the upgraded channel end on `chain_a` of the connection of the
+    /// channel, once both ends are flushed.
+    pub fn upgrade_open(&mut self, channel: &RelayedChannel) -> Result<(), RelayerError> {
+        let msg = self.upgrade_open_msg(channel)?;
+
+        self.deliver(channel.connection.chain_a, ChannelMsg::UpgradeOpen(msg))?;
+
+        Ok(())
+    }
+
+    /// Updates the clients of the chains of the connection of the channel on
+    /// each other, and returns the message opening the upgraded channel end
+    /// on `chain_a`, without delivering it.
+    pub fn upgrade_open_msg(
+        &mut self,
+        channel: &RelayedChannel,
+    ) -> Result<MsgChannelUpgradeOpen, RelayerError> {
+        let proof_height_on_b = self.update_clients_to_a(&channel.connection)?;
+
+        let chan_end_on_b = self.chan_end_on_a(&channel.flipped())?;
+
+        Ok(MsgChannelUpgradeOpen {
+            port_id_on_a: channel.port_id_on_a.clone(),
+            chan_id_on_a: channel.chan_id_on_a.clone(),
+            chan_state_on_b: chan_end_on_b.state,
+            upgrade_sequence_on_b: chan_end_on_b.upgrade_sequence(),
+            proof_chan_end_on_b: relayed_proof(),
+            proof_height_on_b,
+            signer: self.chain(channel.connection.chain_a).signer(),
+        })
+    }
+
+    /// Times out the upgrade of the channel on `chain_a` of its connection,
+    /// which writes an error receipt for `chain_b` to cancel its side of the
+    /// upgrade with.
+    pub fn upgrade_timeout(&mut self, channel: &RelayedChannel) -> Result<(), RelayerError> {
+        let msg = self.upgrade_timeout_msg(channel)?;
+
+        self.deliver(channel.connection.chain_a, ChannelMsg::UpgradeTimeout(msg))?;
+
+        Ok(())
+    }
+
+    /// Commits blocks on `chain_b` of the connection of the channel past the
+    /// timeout of the upgrade on `chain_a`, updates the clients of the chains
+    /// on each other, and returns the message timing out the upgrade on
+    /// `chain_a`, without delivering it.
+    pub fn upgrade_timeout_msg(
+        &mut self,
+        channel: &RelayedChannel,
+    ) -> Result<MsgChannelUpgradeTimeout, RelayerError> {
+        let timeout = self.upgrade_on_a(channel)?.timeout;
+
+        self.advance_past_timeout(
+            channel.connection.chain_b,
+            timeout.height,
+            timeout.timestamp,
+        );
+
+        let proof_height_on_b = self.update_clients_to_a(&channel.connection)?;
+
+        let chan_end_on_b = self.chan_end_on_a(&channel.flipped())?;
+
+        Ok(MsgChannelUpgradeTimeout {
+            port_id_on_a: channel.port_id_on_a.clone(),
+            chan_id_on_a: channel.chan_id_on_a.clone(),
+            chan_end_on_b,
+            proof_chan_end_on_b: relayed_proof(),
+            proof_height_on_b,
+            signer: self.chain(channel.connection.chain_a).signer(),
+        })
+    }
+
+    /// Cancels the upgrade of the channel on `chain_a` of its connection,
+    /// with the error receipt written by `chain_b` on aborting its side of the
+    /// upgrade.
+    pub fn upgrade_cancel(&mut self, channel: &RelayedChannel) -> Result<(), RelayerError> {
+        let msg = self.upgrade_cancel_msg(channel)?;
+
+        self.deliver(channel.connection.chain_a, ChannelMsg::UpgradeCancel(msg))?;
+
+        Ok(())
+    }
+
+    /// Updates the clients of the chains of the connection of the channel on
+    /// each other, and returns the message cancelling the upgrade on
+    /// `chain_a` with the error receipt of `chain_b`, without delivering it.
+    pub fn upgrade_cancel_msg(
+        &mut self,
+        channel: &RelayedChannel,
+    ) -> Result<MsgChannelUpgradeCancel, RelayerError> {
+        let proof_height_on_b = self.update_clients_to_a(&channel.connection)?;
+
+        let error_receipt_on_b = self
+            .chain(channel.connection.chain_b)
+            .channel_upgrade_error_receipt(&ChannelUpgradeErrorPath::new(
+                &channel.port_id_on_b,
+                &channel.chan_id_on_b,
+            ))
+            .map_err(RelayerError::Query)?;
+
+        Ok(MsgChannelUpgradeCancel {
+            port_id_on_a: channel.port_id_on_a.clone(),
+            chan_id_on_a: channel.chan_id_on_a.clone(),
+            error_receipt_on_b,
+            proof_error_receipt_on_b: relayed_proof(),
+            proof_height_on_b,
+            signer: self.chain(channel.connection.chain_a).signer(),
+        })
+    }
+
+    /// Updates the clients of the chains of the connection on each other,
+    /// and returns the height of `chain_b` at which the proofs relayed to
+    /// `chain_a` are taken.
+    fn update_clients_to_a(
+        &mut self,
+        connection: &RelayedConnection,
+    ) -> Result<Height, RelayerError> {
+        self.update_clients(
+            connection.chain_b,
+            &connection.client_id_on_b,
+            connection.chain_a,
+            &connection.client_id_on_a,
+        )
+    }
+
+    /// Returns the channel end on `chain_a` of the connection of the channel.
+    fn chan_end_on_a(&self, channel: &RelayedChannel) -> Result<ChannelEnd, RelayerError> {
+        self.chain(channel.connection.chain_a)
+            .channel_end(&ChannelEndPath::new(
+                &channel.port_id_on_a,
+                &channel.chan_id_on_a,
+            ))
+            .map_err(RelayerError::Query)
+    }
+
+    /// Returns the upgrade of the channel end on `chain_a` of the connection
+    /// of the channel.
+    fn upgrade_on_a(&self, channel: &RelayedChannel) -> Result<Upgrade, RelayerError> {
+        self.chain(channel.connection.chain_a)
+            .channel_upgrade(&ChannelUpgradePath::new(
+                &channel.port_id_on_a,
+                &channel.chan_id_on_a,
+            ))
+            .map_err(RelayerError::Query)
+    }
+}
@@ -131,7 +131,7 @@ impl NftTransferValidationContext for DummyNftTransferModule {
 
 impl NftTransferExecutionContext for DummyNftTransferModule {
     fn create_or_update_class_execute(
-        &self,
+        &mut self,
         _class_id: &PrefixedClassId,
         _class_uri: Option<&ClassUri>,
         _class_data: Option<&ClassData>,
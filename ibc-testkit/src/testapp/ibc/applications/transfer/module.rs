@@ -2,12 +2,15 @@ use ibc::core::channel::types::acknowledgement::Acknowledgement;
 use ibc::core::channel::types::channel::{Counterparty, Order};
 use ibc::core::channel::types::error::{ChannelError, PacketError};
 use ibc::core::channel::types::packet::Packet;
+use ibc::core::channel::types::v2::acknowledgement::RecvPacketResult;
+use ibc::core::channel::types::v2::packet::Payload;
 use ibc::core::channel::types::Version;
-use ibc::core::host::types::identifiers::{ChannelId, ConnectionId, PortId};
+use ibc::core::host::types::identifiers::{ChannelId, ClientId, ConnectionId, PortId, Sequence};
 use ibc::core::primitives::prelude::*;
 use ibc::core::primitives::Signer;
 use ibc::core::router::module::Module;
 use ibc::core::router::types::module::ModuleExtras;
+use ibc::core::router::v2::ModuleV2;
 
 use super::types::DummyTransferModule;
 
@@ -60,14 +63,58 @@ impl Module for DummyTransferModule {
         Ok((ModuleExtras::empty(), counterparty_version.clone()))
     }
 
+    fn on_chan_upgrade_init_validate(
+        &self,
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+        _proposed_order: Order,
+        _proposed_connection_hops: &[ConnectionId],
+        proposed_version: &Version,
+    ) -> Result<Version, ChannelError> {
+        Ok(proposed_version.clone())
+    }
+
+    fn on_chan_upgrade_init_execute(
+        &mut self,
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+        _proposed_order: Order,
+        _proposed_connection_hops: &[ConnectionId],
+        proposed_version: &Version,
+    ) -> Result<(ModuleExtras, Version), ChannelError> {
+        Ok((ModuleExtras::empty(), proposed_version.clone()))
+    }
+
+    fn on_chan_upgrade_try_validate(
+        &self,
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+        _proposed_order: Order,
+        _proposed_connection_hops: &[ConnectionId],
+        counterparty_version: &Version,
+    ) -> Result<Version, ChannelError> {
+        Ok(counterparty_version.clone())
+    }
+
+    fn on_chan_upgrade_try_execute(
+        &mut self,
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+        _proposed_order: Order,
+        _proposed_connection_hops: &[ConnectionId],
+        counterparty_version: &Version,
+    ) -> Result<(ModuleExtras, Version), ChannelError> {
+        Ok((ModuleExtras::empty(), counterparty_version.clone()))
+    }
+
     fn on_recv_packet_execute(
         &mut self,
         _packet: &Packet,
         _relayer: &Signer,
-    ) -> (ModuleExtras, Acknowledgement) {
+    ) -> (ModuleExtras, Option<Acknowledgement>) {
         (
             ModuleExtras::empty(),
-            Acknowledgement::try_from(vec![1u8]).expect("Never fails"),
+            Some(Acknowledgement::try_from(vec![1u8]).expect("Never fails")),
         )
     }
 
@@ -105,3 +152,84 @@ impl Module for DummyTransferModule {
         (ModuleExtras::empty(), Ok(()))
     }
 }
+
+impl ModuleV2 for DummyTransferModule {
+    fn on_send_packet_validate(
+        &self,
+        _client_id_on_a: &ClientId,
+        _client_id_on_b: &ClientId,
+        _sequence: Sequence,
+        _payload: &Payload,
+        _signer: &Signer,
+    ) -> Result<(), PacketError> {
+        Ok(())
+    }
+
+    fn on_send_packet_execute(
+        &mut self,
+        _client_id_on_a: &ClientId,
+        _client_id_on_b: &ClientId,
+        _sequence: Sequence,
+        _payload: &Payload,
+        _signer: &Signer,
+    ) -> Result<ModuleExtras, PacketError> {
+        Ok(ModuleExtras::empty())
+    }
+
+    fn on_recv_packet_execute(
+        &mut self,
+        _client_id_on_a: &ClientId,
+        _client_id_on_b: &ClientId,
+        _sequence: Sequence,
+        _payload: &Payload,
+        _relayer: &Signer,
+    ) -> (ModuleExtras, RecvPacketResult) {
+        (ModuleExtras::empty(), RecvPacketResult::success(vec![1u8]))
+    }
+
+    fn on_acknowledgement_packet_validate(
+        &self,
+        _client_id_on_a: &ClientId,
+        _client_id_on_b: &ClientId,
+        _sequence: Sequence,
+        _payload: &Payload,
+        _acknowledgement: &[u8],
+        _relayer: &Signer,
+    ) -> Result<(), PacketError> {
+        Ok(())
+    }
+
+    fn on_acknowledgement_packet_execute(
+        &mut self,
+        _client_id_on_a: &ClientId,
+        _client_id_on_b: &ClientId,
+        _sequence: Sequence,
+        _payload: &Payload,
+        _acknowledgement: &[u8],
+        _relayer: &Signer,
+    ) -> (ModuleExtras, Result<(), PacketError>) {
+        (ModuleExtras::empty(), Ok(()))
+    }
+
+    fn on_timeout_packet_validate(
+        &self,
+        _client_id_on_a: &ClientId,
+        _client_id_on_b: &ClientId,
+        _sequence: Sequence,
+        _payload: &Payload,
+        _relayer: &Signer,
+    ) -> Result<(), PacketError> {
+        Ok(())
+    }
+
+    fn on_timeout_packet_execute(
+        &mut self,
+        _client_id_on_a: &ClientId,
+        _client_id_on_b: &ClientId,
+        _sequence: Sequence,
+        _payload: &Payload,
+        _relayer: &Signer,
+    ) -> (ModuleExtras, Result<(), PacketError>) {
+        (ModuleExtras::empty(), Ok(()))
+    }
+}
@@ -0,0 +1,242 @@
+use ibc::core::client::types::{Height, Status};
+use ibc::core::commitment_types::commitment::CommitmentRoot;
+use ibc::core::primitives::Timestamp;
+
+/// The root stored in the consensus states of the mock client when it is
+/// scripted to produce wrong roots. Proofs never verify against it.
+pub const MOCK_WRONG_ROOT: &[u8] = &[0xff];
+
+/// Scripted behaviours of the mock client, used to exercise the failure paths
+/// of the core handlers without crafting invalid proofs for a real client.
+///
+/// Behaviours are not part of the protobuf encoding of the mock client state:
+/// they only survive as long as the client state is kept in its domain form,
+/// e.g. when installed in a `MockContext` through `MockClientConfig`, and are
+/// carried over to the client states produced by updates.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct MockClientBehaviour {
+    /// Fails the verification of headers and misbehaviours at this height.
+    pub fail_verification_at: Option<Height>,
+    /// Fails the verification of all membership and non-membership proofs.
+    pub fail_proof_verification: bool,
+    /// Fails the verification of the membership and non-membership proofs of
+    /// these bytes, e.g. to tell apart the proofs of a message.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub rejected_proof: Option<&'static [u8]>,
+    /// Stores a consensus state with a wrong root when updating to this
+    /// height, so that proofs against it fail.
+    pub wrong_root_at: Option<Height>,
+    /// Reports the client as frozen once the host reaches this timestamp.
+    pub frozen_at: Option<Timestamp>,
+    /// Reports the client as expired once the host reaches this timestamp.
+    pub expired_at: Option<Timestamp>,
+    /// The steps the client follows over the heights of the host, in order.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub script: &'static [MockClientStep],
+    /// The step of the script in effect at the height of the host the client
+    /// state was last read at.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub current_step: Option<&'static MockClientStep>,
+}
+
+/// The status a [`MockClientStep`] reports the client with.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum MockClientStatus {
+    Active,
+    Frozen,
+    Expired,
+}
+
+impl From<MockClientStatus> for Status {
+    fn from(status: MockClientStatus) -> Self {
+        match status {
+            MockClientStatus::Active => Status::Active,
+            MockClientStatus::Frozen => Status::Frozen,
+            MockClientStatus::Expired => Status::Expired,
+        }
+    }
+}
+
+/// A step of the script of a mock client, in effect from a height of the
+/// host on, until the next step.
+///
+/// The scripts are borrowed for the lifetime of the tests, for the client
+/// states to stay `Copy`, e.g. by leaking them:
+///
+/// ```rust,ignore
+/// let script = vec![
+///     MockClientStep::at(Height::new(0, 10)?).status(MockClientStatus::Expired),
+///     MockClientStep::at(Height::new(0, 15)?),
+/// ]
+/// .leak();
+///
+/// let behaviour = MockClientBehaviour::default().scripted(script);
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct MockClientStep {
+    /// The height of the host from which the step is in effect.
+    pub host_height: Height,
+    /// The status reported, overriding the status derived from the state of
+    /// the client, if set.
+    pub status: Option<MockClientStatus>,
+    /// Fails the verification of all headers and misbehaviours.
+    pub fail_verification: bool,
+    /// Fails the verification of all membership and non-membership proofs.
+    pub fail_proof_verification: bool,
+    /// The latest height reported, overriding the height of the latest
+    /// header of the client, if set.
+    pub latest_height: Option<Height>,
+}
+
+impl MockClientStep {
+    /// Creates a step in effect from the given height of the host, under
+    /// which the client behaves as it does with no script.
+    pub fn at(host_height: Height) -> Self {
+        Self {
+            host_height,
+            status: None,
+            fail_verification: false,
+            fail_proof_verification: false,
+            latest_height: None,
+        }
+    }
+
+    pub fn status(self, status: MockClientStatus) -> Self {
+        Self {
+            status: Some(status),
+            ..self
+        }
+    }
+
+    pub fn fail_verification(self) -> Self {
+        Self {
+            fail_verification: true,
+            ..self
+        }
+    }
+
+    pub fn fail_proof_verification(self) -> Self {
+        Self {
+            fail_proof_verification: true,
+            ..self
+        }
+    }
+
+    pub fn latest_height(self, height: Height) -> Self {
+        Self {
+            latest_height: Some(height),
+            ..self
+        }
+    }
+}
+
+impl MockClientBehaviour {
+    pub fn fail_verification_at(self, height: Height) -> Self {
+        Self {
+            fail_verification_at: Some(height),
+            ..self
+        }
+    }
+
+    pub fn fail_proof_verification(self) -> Self {
+        Self {
+            fail_proof_verification: true,
+            ..self
+        }
+    }
+
+    pub fn reject_proof(self, proof: &'static [u8]) -> Self {
+        Self {
+            rejected_proof: Some(proof),
+            ..self
+        }
+    }
+
+    pub fn wrong_root_at(self, height: Height) -> Self {
+        Self {
+            wrong_root_at: Some(height),
+            ..self
+        }
+    }
+
+    pub fn frozen_at(self, timestamp: Timestamp) -> Self {
+        Self {
+            frozen_at: Some(timestamp),
+            ..self
+        }
+    }
+
+    pub fn expired_at(self, timestamp: Timestamp) -> Self {
+        Self {
+            expired_at: Some(timestamp),
+            ..self
+        }
+    }
+
+    pub fn scripted(self, script: &'static [MockClientStep]) -> Self {
+        Self { script, ..self }
+    }
+
+    /// Returns the step of the script in effect at the height of the host,
+    /// i.e. the last one from a height not past it.
+    pub fn step_at(&self, host_height: Height) -> Option<&'static MockClientStep> {
+        self.script
+            .iter()
+            .rev()
+            .find(|step| step.host_height <= host_height)
+    }
+
+    /// Returns the behaviour under the step of the script in effect at the
+    /// height of the host.
+    pub fn at_host_height(self, host_height: Height) -> Self {
+        Self {
+            current_step: self.step_at(host_height),
+            ..self
+        }
+    }
+
+    /// Returns the status the current step of the script reports, if any.
+    pub fn scripted_status(&self) -> Option<Status> {
+        self.current_step
+            .and_then(|step| step.status)
+            .map(Status::from)
+    }
+
+    /// Returns the latest height the current step of the script reports, if
+    /// any.
+    pub fn scripted_latest_height(&self) -> Option<Height> {
+        self.current_step.and_then(|step| step.latest_height)
+    }
+
+    pub fn fails_verification_at(&self, height: Height) -> bool {
+        self.fail_verification_at == Some(height)
+            || self.current_step.is_some_and(|step| step.fail_verification)
+    }
+
+    pub fn produces_wrong_root_at(&self, height: Height) -> bool {
+        self.wrong_root_at == Some(height)
+    }
+
+    /// Returns whether proofs against `root` must fail.
+    pub fn fails_proofs_against(&self, root: &CommitmentRoot) -> bool {
+        self.fail_proof_verification
+            || self
+                .current_step
+                .is_some_and(|step| step.fail_proof_verification)
+            || root.as_bytes() == MOCK_WRONG_ROOT
+    }
+
+    /// Returns whether the proof of the given bytes must fail.
+    pub fn rejects_proof(&self, proof: &[u8]) -> bool {
+        self.rejected_proof == Some(proof)
+    }
+
+    pub fn is_frozen_at(&self, now: Timestamp) -> bool {
+        self.frozen_at.is_some_and(|frozen_at| now >= frozen_at)
+    }
+
+    pub fn is_expired_at(&self, now: Timestamp) -> bool {
+        self.expired_at.is_some_and(|expired_at| now >= expired_at)
+    }
+}
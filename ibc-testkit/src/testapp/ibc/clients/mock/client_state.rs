@@ -14,6 +14,7 @@ use ibc::core::primitives::prelude::*;
 use ibc::core::primitives::Timestamp;
 use ibc::primitives::proto::{Any, Protobuf};
 
+use crate::testapp::ibc::clients::mock::behaviour::{MockClientBehaviour, MOCK_WRONG_ROOT};
 use crate::testapp::ibc::clients::mock::client_state::client_type as mock_client_type;
 use crate::testapp::ibc::clients::mock::consensus_state::MockConsensusState;
 use crate::testapp::ibc::clients::mock::header::{MockHeader, MOCK_HEADER_TYPE_URL};
@@ -35,6 +36,8 @@ pub struct MockClientState {
     pub header: MockHeader,
     pub trusting_period: Duration,
     pub frozen: bool,
+    /// Scripted behaviours of the client, which are not encoded.
+    pub behaviour: MockClientBehaviour,
 }
 
 impl MockClientState {
@@ -43,11 +46,16 @@ impl MockClientState {
             header,
             trusting_period: Duration::from_nanos(0),
             frozen: false,
+            behaviour: MockClientBehaviour::default(),
         }
     }
 
+    /// Returns the height of the latest header of the client, unless its
+    /// script reports another one.
     pub fn latest_height(&self) -> Height {
-        self.header.height()
+        self.behaviour
+            .scripted_latest_height()
+            .unwrap_or_else(|| self.header.height())
     }
 
     pub fn refresh_time(&self) -> Option<Duration> {
@@ -61,6 +69,19 @@ impl MockClientState {
         }
     }
 
+    pub fn with_behaviour(self, behaviour: MockClientBehaviour) -> Self {
+        Self { behaviour, ..self }
+    }
+
+    /// Returns the client state under the step of its script in effect at
+    /// the height of the host.
+    pub fn at_host_height(self, host_height: Height) -> Self {
+        Self {
+            behaviour: self.behaviour.at_host_height(host_height),
+            ..self
+        }
+    }
+
     pub fn frozen(self) -> Self {
         Self {
             frozen: true,
@@ -79,8 +100,43 @@ impl MockClientState {
         self.frozen
     }
 
-    fn expired(&self, _elapsed: Duration) -> bool {
-        false
+    /// Returns whether the trusting period elapsed, the clients with no
+    /// trusting period never expiring.
+    fn expired(&self, elapsed: Duration) -> bool {
+        !self.trusting_period.is_zero() && elapsed > self.trusting_period
+    }
+
+    /// Accepts any proof, unless the client is scripted to fail proof
+    /// verification or to reject this proof, or `root` is a deliberately
+    /// wrong root.
+    fn verify_proof(
+        &self,
+        root: &CommitmentRoot,
+        proof: &CommitmentProofBytes,
+        path: Path,
+    ) -> Result<(), ClientError> {
+        if self.behaviour.fails_proofs_against(root) || self.behaviour.rejects_proof(proof.as_ref())
+        {
+            return Err(ClientError::Other {
+                description: format!(
+                    "mock client: injected proof verification failure for path `{path}`"
+                ),
+            });
+        }
+        Ok(())
+    }
+
+    /// Fails the verification of client messages at heights the client is
+    /// scripted to fail at.
+    fn verify_height(&self, height: Height) -> Result<(), ClientError> {
+        if self.behaviour.fails_verification_at(height) {
+            return Err(ClientError::Other {
+                description: format!(
+                    "mock client: injected verification failure at height {height}"
+                ),
+            });
+        }
+        Ok(())
     }
 }
 
@@ -99,6 +155,7 @@ impl TryFrom<RawMockClientState> for MockClientState {
                 .try_into()?,
             trusting_period: Duration::from_nanos(raw.trusting_period),
             frozen: raw.frozen,
+            behaviour: MockClientBehaviour::default(),
         })
     }
 }
@@ -158,14 +215,6 @@ impl<C> ConsensusStateConverter for C where
 {
 }
 
-pub trait MockClientContext {
-    /// Returns the current timestamp of the local chain.
-    fn host_timestamp(&self) -> Result<Timestamp, ContextError>;
-
-    /// Returns the current height of the local chain.
-    fn host_height(&self) -> Result<Height, ContextError>;
-}
-
 impl ClientStateCommon for MockClientState {
     fn verify_consensus_state(&self, consensus_state: Any) -> Result<(), ClientError> {
         let _mock_consensus_state = MockConsensusState::try_from(consensus_state)?;
@@ -178,7 +227,7 @@ impl ClientStateCommon for MockClientState {
     }
 
     fn latest_height(&self) -> Height {
-        self.header.height()
+        MockClientState::latest_height(self)
     }
 
     fn validate_proof_height(&self, proof_height: Height) -> Result<(), ClientError> {
@@ -213,42 +262,47 @@ impl ClientStateCommon for MockClientState {
     fn verify_membership(
         &self,
         _prefix: &CommitmentPrefix,
-        _proof: &CommitmentProofBytes,
-        _root: &CommitmentRoot,
-        _path: Path,
+        proof: &CommitmentProofBytes,
+        root: &CommitmentRoot,
+        path: Path,
         _value: Vec<u8>,
     ) -> Result<(), ClientError> {
-        Ok(())
+        self.verify_proof(root, proof, path)
     }
 
     fn verify_non_membership(
         &self,
         _prefix: &CommitmentPrefix,
-        _proof: &CommitmentProofBytes,
-        _root: &CommitmentRoot,
-        _path: Path,
+        proof: &CommitmentProofBytes,
+        root: &CommitmentRoot,
+        path: Path,
     ) -> Result<(), ClientError> {
-        Ok(())
+        self.verify_proof(root, proof, path)
     }
 }
 
 impl<V> ClientStateValidation<V> for MockClientState
 where
-    V: ClientValidationContext + MockClientContext,
+    V: ClientValidationContext + HostClock,
     V::ConsensusStateRef: ConsensusStateConverter,
 {
     fn verify_client_message(
         &self,
-        _ctx: &V,
+        ctx: &V,
         _client_id: &ClientId,
         client_message: Any,
     ) -> Result<(), ClientError> {
+        let client_state = self.at_host_height(ctx.host_height()?);
+
         match client_message.type_url.as_str() {
             MOCK_HEADER_TYPE_URL => {
-                let _header = MockHeader::try_from(client_message)?;
+                let header = MockHeader::try_from(client_message)?;
+                client_state.verify_height(header.height())?;
             }
             MOCK_MISBEHAVIOUR_TYPE_URL => {
-                let _misbehaviour = Misbehaviour::try_from(client_message)?;
+                let misbehaviour = Misbehaviour::try_from(client_message)?;
+                client_state.verify_height(misbehaviour.header1.height())?;
+                client_state.verify_height(misbehaviour.header2.height())?;
             }
             _ => {}
         }
@@ -281,10 +335,28 @@ where
     }
 
     fn status(&self, ctx: &V, client_id: &ClientId) -> Result<Status, ClientError> {
+        if let Some(status) = self
+            .behaviour
+            .at_host_height(ctx.host_height()?)
+            .scripted_status()
+        {
+            return Ok(status);
+        }
+
         if self.is_frozen() {
             return Ok(Status::Frozen);
         }
 
+        let now = ctx.host_timestamp()?;
+
+        if self.behaviour.is_frozen_at(now) {
+            return Ok(Status::Frozen);
+        }
+
+        if self.behaviour.is_expired_at(now) {
+            return Ok(Status::Expired);
+        }
+
         let latest_consensus_state = {
             match ctx.consensus_state(&ClientConsensusStatePath::new(
                 client_id.clone(),
@@ -298,7 +370,6 @@ where
             }
         };
 
-        let now = ctx.host_timestamp()?;
         let elapsed_since_latest_consensus_state = now
             .duration_since(&latest_consensus_state.timestamp())
             .ok_or(ClientError::Other {
@@ -311,11 +382,17 @@ where
 
         Ok(Status::Active)
     }
+
+    fn check_substitute(&self, _ctx: &V, substitute_client_state: Any) -> Result<(), ClientError> {
+        MockClientState::try_from(substitute_client_state)?;
+
+        Ok(())
+    }
 }
 
 impl<E> ClientStateExecution<E> for MockClientState
 where
-    E: ClientExecutionContext + MockClientContext,
+    E: ClientExecutionContext + HostClock,
     E::ClientStateRef: From<MockClientState>,
     E::ConsensusStateRef: ConsensusStateConverter,
 {
@@ -349,8 +426,13 @@ where
         let header = MockHeader::try_from(header)?;
         let header_height = header.height;
 
-        let new_client_state = MockClientState::new(header);
-        let new_consensus_state = MockConsensusState::new(header);
+        let new_client_state = MockClientState::new(header).with_behaviour(self.behaviour);
+        let new_consensus_state = if self.behaviour.produces_wrong_root_at(header_height) {
+            MockConsensusState::new(header)
+                .with_root(CommitmentRoot::from(MOCK_WRONG_ROOT.to_vec()))
+        } else {
+            MockConsensusState::new(header)
+        };
 
         ctx.store_consensus_state(
             ClientConsensusStatePath::new(
@@ -427,6 +509,50 @@ where
 
         Ok(latest_height)
     }
+
+    fn update_on_recovery(
+        &self,
+        ctx: &mut E,
+        subject_client_id: &ClientId,
+        substitute_client_id: &ClientId,
+        substitute_client_state: Any,
+    ) -> Result<(), ClientError> {
+        let new_client_state = MockClientState::try_from(substitute_client_state)?;
+        let latest_height = new_client_state.latest_height();
+
+        let substitute_consensus_state: MockConsensusState = ctx
+            .consensus_state(&ClientConsensusStatePath::new(
+                substitute_client_id.clone(),
+                latest_height.revision_number(),
+                latest_height.revision_height(),
+            ))?
+            .try_into()?;
+
+        ctx.store_consensus_state(
+            ClientConsensusStatePath::new(
+                subject_client_id.clone(),
+                latest_height.revision_number(),
+                latest_height.revision_height(),
+            ),
+            substitute_consensus_state.into(),
+        )?;
+        ctx.store_client_state(
+            ClientStatePath::new(subject_client_id.clone()),
+            new_client_state.into(),
+        )?;
+
+        let host_timestamp = ctx.host_timestamp()?;
+        let host_height = ctx.host_height()?;
+
+        ctx.store_update_meta(
+            subject_client_id.clone(),
+            latest_height,
+            host_timestamp,
+            host_height,
+        )?;
+
+        Ok(())
+    }
 }
 
 impl From<MockConsensusState> for MockClientState {
@@ -26,6 +26,10 @@ impl MockConsensusState {
         }
     }
 
+    pub fn with_root(self, root: CommitmentRoot) -> Self {
+        Self { root, ..self }
+    }
+
     pub fn timestamp(&self) -> Timestamp {
         self.header.timestamp
     }
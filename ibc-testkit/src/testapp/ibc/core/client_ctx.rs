@@ -1,3 +1,4 @@
+use ibc::clients::tendermint::client_state::{ZkCommitVerifier, ZkPublicInputs};
 use ibc::clients::tendermint::context::ValidationContext as TmValidationContext;
 use ibc::core::client::context::{ClientExecutionContext, ClientValidationContext};
 use ibc::core::client::types::error::ClientError;
@@ -5,18 +6,16 @@ use ibc::core::client::types::Height;
 use ibc::core::handler::types::error::ContextError;
 use ibc::core::host::types::identifiers::{ChannelId, ClientId, PortId};
 use ibc::core::host::types::path::{ClientConsensusStatePath, ClientStatePath};
-use ibc::core::host::ValidationContext;
 use ibc::core::primitives::Timestamp;
 use ibc::primitives::prelude::*;
 
-use crate::testapp::ibc::clients::mock::client_state::MockClientContext;
 use crate::testapp::ibc::clients::{AnyClientState, AnyConsensusState};
 use crate::testapp::ibc::core::types::MockContext;
 
 pub type PortChannelIdMap<V> = BTreeMap<PortId, BTreeMap<ChannelId, V>>;
 
 /// A mock of an IBC client record as it is stored in a mock context.
-/// For testing ICS02 handlers mostly, cf. `MockClientContext`.
+/// For testing ICS02 handlers mostly, cf. `MockClientState`.
 #[derive(Clone, Debug)]
 pub struct MockClientRecord {
     /// The client state (representing only the latest height at the moment).
@@ -26,25 +25,36 @@ pub struct MockClientRecord {
     pub consensus_states: BTreeMap<Height, AnyConsensusState>,
 }
 
-impl MockClientContext for MockContext {
-    fn host_timestamp(&self) -> Result<Timestamp, ContextError> {
-        ValidationContext::host_timestamp(self)
-    }
+/// A stub of the verifier of the commit proofs of the ZK headers, which
+/// accepts the given proof only, whatever the public inputs.
+#[derive(Clone, Debug)]
+pub struct MockZkCommitVerifier {
+    pub valid_proof: Vec<u8>,
+}
 
-    fn host_height(&self) -> Result<Height, ContextError> {
-        ValidationContext::host_height(self)
+impl MockZkCommitVerifier {
+    pub fn new(valid_proof: Vec<u8>) -> Self {
+        Self { valid_proof }
     }
 }
 
-impl TmValidationContext for MockContext {
-    fn host_timestamp(&self) -> Result<Timestamp, ContextError> {
-        ValidationContext::host_timestamp(self)
-    }
+impl ZkCommitVerifier for MockZkCommitVerifier {
+    fn verify_commit_proof(
+        &self,
+        _public_inputs: &ZkPublicInputs,
+        proof: &[u8],
+    ) -> Result<(), ClientError> {
+        if proof != self.valid_proof.as_slice() {
+            return Err(ClientError::HeaderVerificationFailure {
+                reason: "invalid commit proof".to_string(),
+            });
+        }
 
-    fn host_height(&self) -> Result<Height, ContextError> {
-        ValidationContext::host_height(self)
+        Ok(())
     }
+}
 
+impl TmValidationContext for MockContext {
     fn consensus_state_heights(&self, client_id: &ClientId) -> Result<Vec<Height>, ContextError> {
         let ibc_store = self.ibc_store.lock();
         let client_record =
@@ -127,12 +137,20 @@ impl TmValidationContext for MockContext {
         }
         Ok(None)
     }
+
+    fn zk_commit_verifier(&self) -> Option<&dyn ZkCommitVerifier> {
+        self.zk_commit_verifier
+            .as_ref()
+            .map(|verifier| verifier as &dyn ZkCommitVerifier)
+    }
 }
 
 impl ClientValidationContext for MockContext {
     type ClientStateRef = AnyClientState;
     type ConsensusStateRef = AnyConsensusState;
 
+    /// Returns the client state, the mock clients being under the step of
+    /// their script in effect at the current height of the host.
     fn client_state(&self, client_id: &ClientId) -> Result<Self::ClientStateRef, ContextError> {
         match self.ibc_store.lock().clients.get(client_id) {
             Some(client_record) => {
@@ -147,6 +165,12 @@ impl ClientValidationContext for MockContext {
                 client_id: client_id.clone(),
             }),
         }
+        .map(|client_state| match client_state {
+            AnyClientState::Mock(client_state) => {
+                AnyClientState::Mock(client_state.at_host_height(self.latest_height()))
+            }
+            client_state => client_state,
+        })
         .map_err(ContextError::ClientError)
     }
 
@@ -3,36 +3,44 @@
 use core::ops::Add;
 use core::time::Duration;
 
+use ibc::core::channel::context::PacketStateIterationContext;
 use ibc::core::channel::types::channel::ChannelEnd;
 use ibc::core::channel::types::commitment::{AcknowledgementCommitment, PacketCommitment};
 use ibc::core::channel::types::error::{ChannelError, PacketError};
-use ibc::core::channel::types::packet::Receipt;
+use ibc::core::channel::types::events::PacketDataEventMode;
+use ibc::core::channel::types::packet::{Receipt, SequenceRange};
+use ibc::core::channel::types::upgrade::{ErrorReceipt, Upgrade};
+use ibc::core::channel::types::v2::counterparty::ClientCounterparty;
+use ibc::core::channel::v2::context::{PacketV2ExecutionContext, PacketV2ValidationContext};
 use ibc::core::client::types::error::ClientError;
+use ibc::core::client::types::params::ClientParams;
 use ibc::core::client::types::Height;
 use ibc::core::commitment_types::commitment::CommitmentPrefix;
 use ibc::core::connection::types::error::ConnectionError;
 use ibc::core::connection::types::ConnectionEnd;
 use ibc::core::handler::types::error::ContextError;
 use ibc::core::handler::types::events::IbcEvent;
-use ibc::core::host::types::identifiers::{ConnectionId, Sequence};
+use ibc::core::host::types::identifiers::{ClientId, ConnectionId, Sequence};
 use ibc::core::host::types::path::{
-    AckPath, ChannelEndPath, ClientConnectionPath, CommitmentPath, ConnectionPath, ReceiptPath,
-    SeqAckPath, SeqRecvPath, SeqSendPath,
+    AckPath, AckPathV2, ChannelEndPath, ChannelUpgradeErrorPath, ChannelUpgradePath,
+    ClientConnectionPath, CommitmentPath, CommitmentPathV2, ConnectionPath, ReceiptPath,
+    ReceiptPathV2, SeqAckPath, SeqRecvPath, SeqSendPath, SeqSendPathV2,
+};
+use ibc::core::host::{
+    Authority, BasicSelfClientValidator, ExecutionContext, HostClock, MigrationContext,
+    SelfClientValidation, ValidationContext,
 };
-use ibc::core::host::{ExecutionContext, ValidationContext};
 use ibc::core::primitives::prelude::*;
 use ibc::core::primitives::{Signer, Timestamp};
 
-use super::types::MockContext;
-use crate::testapp::ibc::clients::mock::client_state::MockClientState;
+use super::types::{MockContext, MOCK_AUTHORITY};
+use crate::testapp::ibc::clients::mock::client_state::{
+    client_type as mock_client_type, MockClientState,
+};
 use crate::testapp::ibc::clients::mock::consensus_state::MockConsensusState;
 use crate::testapp::ibc::clients::AnyConsensusState;
 
-impl ValidationContext for MockContext {
-    type V = Self;
-    type HostClientState = MockClientState;
-    type HostConsensusState = MockConsensusState;
-
+impl HostClock for MockContext {
     fn host_height(&self) -> Result<Height, ContextError> {
         Ok(self.latest_height())
     }
@@ -46,11 +54,21 @@ impl ValidationContext for MockContext {
             .add(self.block_time)
             .expect("Never fails"))
     }
+}
+
+impl ValidationContext for MockContext {
+    type V = Self;
+    type HostClientState = MockClientState;
+    type HostConsensusState = MockConsensusState;
 
     fn client_counter(&self) -> Result<u64, ContextError> {
         Ok(self.ibc_store.lock().client_ids_counter)
     }
 
+    fn client_params(&self) -> ClientParams {
+        self.ibc_store.lock().client_params.clone()
+    }
+
     fn host_consensus_state(&self, height: &Height) -> Result<MockConsensusState, ContextError> {
         let cs: AnyConsensusState = match self.host_block(height) {
             Some(block_ref) => Ok(block_ref.clone().into()),
@@ -78,40 +96,8 @@ impl ValidationContext for MockContext {
             .into());
         }
 
-        let self_chain_id = &self.host_chain_id;
-        let self_revision_number = self_chain_id.revision_number();
-        if self_revision_number
-            != client_state_of_host_on_counterparty
-                .latest_height()
-                .revision_number()
-        {
-            return Err(ContextError::ConnectionError(
-                ConnectionError::InvalidClientState {
-                    reason: format!(
-                        "client is not in the same revision as the chain. expected: {}, got: {}",
-                        self_revision_number,
-                        client_state_of_host_on_counterparty
-                            .latest_height()
-                            .revision_number()
-                    ),
-                },
-            ));
-        }
-
-        let host_current_height = self.latest_height().increment();
-        if client_state_of_host_on_counterparty.latest_height() >= host_current_height {
-            return Err(ContextError::ConnectionError(
-                ConnectionError::InvalidClientState {
-                    reason: format!(
-                        "client has latest height {} greater than or equal to chain height {}",
-                        client_state_of_host_on_counterparty.latest_height(),
-                        host_current_height
-                    ),
-                },
-            ));
-        }
-
-        Ok(())
+        BasicSelfClientValidator::new(mock_client_type(), self.latest_height().increment())
+            .validate_self_client(client_state_of_host_on_counterparty)
     }
 
     fn connection_end(&self, cid: &ConnectionId) -> Result<ConnectionEnd, ContextError> {
@@ -281,6 +267,66 @@ impl ValidationContext for MockContext {
         .map_err(ContextError::PacketError)
     }
 
+    fn channel_upgrade(&self, upgrade_path: &ChannelUpgradePath) -> Result<Upgrade, ContextError> {
+        self.ibc_store
+            .lock()
+            .channel_upgrades
+            .get(&upgrade_path.0)
+            .and_then(|map| map.get(&upgrade_path.1))
+            .cloned()
+            .ok_or(ContextError::ChannelError(ChannelError::UpgradeNotFound {
+                port_id: upgrade_path.0.clone(),
+                channel_id: upgrade_path.1.clone(),
+            }))
+    }
+
+    fn counterparty_channel_upgrade(
+        &self,
+        upgrade_path: &ChannelUpgradePath,
+    ) -> Result<Upgrade, ContextError> {
+        self.ibc_store
+            .lock()
+            .counterparty_channel_upgrades
+            .get(&upgrade_path.0)
+            .and_then(|map| map.get(&upgrade_path.1))
+            .cloned()
+            .ok_or(ContextError::ChannelError(ChannelError::UpgradeNotFound {
+                port_id: upgrade_path.0.clone(),
+                channel_id: upgrade_path.1.clone(),
+            }))
+    }
+
+    fn channel_upgrade_error_receipt(
+        &self,
+        upgrade_error_path: &ChannelUpgradeErrorPath,
+    ) -> Result<ErrorReceipt, ContextError> {
+        self.ibc_store
+            .lock()
+            .channel_upgrade_errors
+            .get(&upgrade_error_path.0)
+            .and_then(|map| map.get(&upgrade_error_path.1))
+            .cloned()
+            .ok_or(ContextError::ChannelError(
+                ChannelError::ErrorReceiptNotFound {
+                    port_id: upgrade_error_path.0.clone(),
+                    channel_id: upgrade_error_path.1.clone(),
+                },
+            ))
+    }
+
+    fn has_inflight_packets(
+        &self,
+        channel_end_path: &ChannelEndPath,
+    ) -> Result<bool, ContextError> {
+        Ok(self
+            .ibc_store
+            .lock()
+            .packet_commitment
+            .get(&channel_end_path.0)
+            .and_then(|map| map.get(&channel_end_path.1))
+            .map_or(false, |commitments| !commitments.is_empty()))
+    }
+
     fn channel_counter(&self) -> Result<u64, ContextError> {
         Ok(self.ibc_store.lock().channel_ids_counter)
     }
@@ -289,10 +335,43 @@ impl ValidationContext for MockContext {
         self.block_time
     }
 
-    fn validate_message_signer(&self, _signer: &Signer) -> Result<(), ContextError> {
+    fn max_packet_data_size(&self) -> Option<usize> {
+        self.max_packet_data_size
+    }
+
+    fn packet_data_event_mode(&self) -> PacketDataEventMode {
+        self.packet_data_event_mode
+    }
+
+    fn validate_message_signer(&self, signer: &Signer) -> Result<(), ContextError> {
+        if let Some(signer_format) = &self.signer_format {
+            signer
+                .validate(signer_format)
+                .map_err(|e| ClientError::InvalidSigner {
+                    reason: e.to_string(),
+                })?;
+        }
+
         Ok(())
     }
 
+    fn validate_connection_creator(
+        &self,
+        signer: &Signer,
+        client_id: &ClientId,
+    ) -> Result<(), ContextError> {
+        match &self.connection_creators {
+            Some(creators) if !creators.contains(signer) => {
+                Err(ConnectionError::UnauthorizedConnectionCreation {
+                    signer: signer.clone(),
+                    client_id: client_id.clone(),
+                }
+                .into())
+            }
+            _ => Ok(()),
+        }
+    }
+
     fn get_client_validation_context(&self) -> &Self::V {
         self
     }
@@ -310,6 +389,11 @@ impl ExecutionContext for MockContext {
         Ok(())
     }
 
+    fn store_client_params(&mut self, params: ClientParams) -> Result<(), ContextError> {
+        self.ibc_store.lock().client_params = params;
+        Ok(())
+    }
+
     fn store_connection(
         &mut self,
         connection_path: &ConnectionPath,
@@ -437,6 +521,64 @@ impl ExecutionContext for MockContext {
         Ok(())
     }
 
+    fn store_channel_upgrade(
+        &mut self,
+        upgrade_path: &ChannelUpgradePath,
+        upgrade: Upgrade,
+    ) -> Result<(), ContextError> {
+        self.ibc_store
+            .lock()
+            .channel_upgrades
+            .entry(upgrade_path.0.clone())
+            .or_default()
+            .insert(upgrade_path.1.clone(), upgrade);
+        Ok(())
+    }
+
+    fn store_counterparty_channel_upgrade(
+        &mut self,
+        upgrade_path: &ChannelUpgradePath,
+        upgrade: Upgrade,
+    ) -> Result<(), ContextError> {
+        self.ibc_store
+            .lock()
+            .counterparty_channel_upgrades
+            .entry(upgrade_path.0.clone())
+            .or_default()
+            .insert(upgrade_path.1.clone(), upgrade);
+        Ok(())
+    }
+
+    fn delete_channel_upgrade(
+        &mut self,
+        upgrade_path: &ChannelUpgradePath,
+    ) -> Result<(), ContextError> {
+        let mut ibc_store = self.ibc_store.lock();
+        ibc_store
+            .channel_upgrades
+            .get_mut(&upgrade_path.0)
+            .and_then(|map| map.remove(&upgrade_path.1));
+        ibc_store
+            .counterparty_channel_upgrades
+            .get_mut(&upgrade_path.0)
+            .and_then(|map| map.remove(&upgrade_path.1));
+        Ok(())
+    }
+
+    fn store_channel_upgrade_error_receipt(
+        &mut self,
+        upgrade_error_path: &ChannelUpgradeErrorPath,
+        error_receipt: ErrorReceipt,
+    ) -> Result<(), ContextError> {
+        self.ibc_store
+            .lock()
+            .channel_upgrade_errors
+            .entry(upgrade_error_path.0.clone())
+            .or_default()
+            .insert(upgrade_error_path.1.clone(), error_receipt);
+        Ok(())
+    }
+
     fn store_next_sequence_send(
         &mut self,
         seq_send_path: &SeqSendPath,
@@ -503,3 +645,218 @@ impl ExecutionContext for MockContext {
         Ok(())
     }
 }
+
+/// The governance of the mock host is the account [`MOCK_AUTHORITY`].
+impl Authority for MockContext {
+    fn is_authorized(&self, signer: &Signer) -> bool {
+        signer.as_ref() == MOCK_AUTHORITY
+    }
+}
+
+impl MigrationContext for MockContext {
+    fn store_version(&self) -> Result<Option<u64>, ContextError> {
+        Ok(self.ibc_store.lock().store_version)
+    }
+
+    fn set_store_version(&mut self, version: u64) -> Result<(), ContextError> {
+        self.ibc_store.lock().store_version = Some(version);
+        Ok(())
+    }
+}
+
+impl PacketStateIterationContext for MockContext {
+    fn packet_commitment_sequences(
+        &self,
+        channel_end_path: &ChannelEndPath,
+        range: SequenceRange,
+    ) -> Result<Vec<Sequence>, ContextError> {
+        Ok(self
+            .ibc_store
+            .lock()
+            .packet_commitment
+            .get(&channel_end_path.0)
+            .and_then(|map| map.get(&channel_end_path.1))
+            .map(|map| range.collect(map.keys().copied()))
+            .unwrap_or_default())
+    }
+
+    fn packet_receipt_sequences(
+        &self,
+        channel_end_path: &ChannelEndPath,
+        range: SequenceRange,
+    ) -> Result<Vec<Sequence>, ContextError> {
+        Ok(self
+            .ibc_store
+            .lock()
+            .packet_receipt
+            .get(&channel_end_path.0)
+            .and_then(|map| map.get(&channel_end_path.1))
+            .map(|map| range.collect(map.keys().copied()))
+            .unwrap_or_default())
+    }
+
+    fn packet_acknowledgement_sequences(
+        &self,
+        channel_end_path: &ChannelEndPath,
+        range: SequenceRange,
+    ) -> Result<Vec<Sequence>, ContextError> {
+        Ok(self
+            .ibc_store
+            .lock()
+            .packet_acknowledgement
+            .get(&channel_end_path.0)
+            .and_then(|map| map.get(&channel_end_path.1))
+            .map(|map| range.collect(map.keys().copied()))
+            .unwrap_or_default())
+    }
+}
+
+impl PacketV2ValidationContext for MockContext {
+    fn client_counterparty(
+        &self,
+        client_id: &ClientId,
+    ) -> Result<ClientCounterparty, ContextError> {
+        match self.ibc_store.lock().client_counterparties.get(client_id) {
+            Some(counterparty) => Ok(counterparty.clone()),
+            None => Err(PacketError::MissingClientCounterparty {
+                client_id: client_id.clone(),
+            }),
+        }
+        .map_err(ContextError::PacketError)
+    }
+
+    fn get_next_sequence_send_v2(
+        &self,
+        seq_send_path: &SeqSendPathV2,
+    ) -> Result<Sequence, ContextError> {
+        Ok(self
+            .ibc_store
+            .lock()
+            .next_sequence_send_v2
+            .get(&seq_send_path.0)
+            .copied()
+            .unwrap_or_else(|| Sequence::from(1)))
+    }
+
+    fn get_packet_commitment_v2(
+        &self,
+        commitment_path: &CommitmentPathV2,
+    ) -> Result<PacketCommitment, ContextError> {
+        let seq = &commitment_path.sequence;
+
+        match self
+            .ibc_store
+            .lock()
+            .packet_commitment_v2
+            .get(&commitment_path.client_id)
+            .and_then(|map| map.get(seq))
+        {
+            Some(commitment) => Ok(commitment.clone()),
+            None => Err(PacketError::PacketCommitmentNotFound { sequence: *seq }),
+        }
+        .map_err(ContextError::PacketError)
+    }
+
+    fn get_packet_receipt_v2(&self, receipt_path: &ReceiptPathV2) -> Result<Receipt, ContextError> {
+        let seq = &receipt_path.sequence;
+
+        match self
+            .ibc_store
+            .lock()
+            .packet_receipt_v2
+            .get(&receipt_path.client_id)
+            .and_then(|map| map.get(seq))
+        {
+            Some(receipt) => Ok(receipt.clone()),
+            None => Err(PacketError::PacketReceiptNotFound { sequence: *seq }),
+        }
+        .map_err(ContextError::PacketError)
+    }
+
+    fn get_packet_acknowledgement_v2(
+        &self,
+        ack_path: &AckPathV2,
+    ) -> Result<AcknowledgementCommitment, ContextError> {
+        let seq = &ack_path.sequence;
+
+        match self
+            .ibc_store
+            .lock()
+            .packet_acknowledgement_v2
+            .get(&ack_path.client_id)
+            .and_then(|map| map.get(seq))
+        {
+            Some(ack) => Ok(ack.clone()),
+            None => Err(PacketError::PacketAcknowledgementNotFound { sequence: *seq }),
+        }
+        .map_err(ContextError::PacketError)
+    }
+}
+
+impl PacketV2ExecutionContext for MockContext {
+    fn store_next_sequence_send_v2(
+        &mut self,
+        seq_send_path: &SeqSendPathV2,
+        seq: Sequence,
+    ) -> Result<(), ContextError> {
+        self.ibc_store
+            .lock()
+            .next_sequence_send_v2
+            .insert(seq_send_path.0.clone(), seq);
+        Ok(())
+    }
+
+    fn store_packet_commitment_v2(
+        &mut self,
+        commitment_path: &CommitmentPathV2,
+        commitment: PacketCommitment,
+    ) -> Result<(), ContextError> {
+        self.ibc_store
+            .lock()
+            .packet_commitment_v2
+            .entry(commitment_path.client_id.clone())
+            .or_default()
+            .insert(commitment_path.sequence, commitment);
+        Ok(())
+    }
+
+    fn delete_packet_commitment_v2(
+        &mut self,
+        commitment_path: &CommitmentPathV2,
+    ) -> Result<(), ContextError> {
+        self.ibc_store
+            .lock()
+            .packet_commitment_v2
+            .get_mut(&commitment_path.client_id)
+            .and_then(|map| map.remove(&commitment_path.sequence));
+        Ok(())
+    }
+
+    fn store_packet_receipt_v2(
+        &mut self,
+        receipt_path: &ReceiptPathV2,
+        receipt: Receipt,
+    ) -> Result<(), ContextError> {
+        self.ibc_store
+            .lock()
+            .packet_receipt_v2
+            .entry(receipt_path.client_id.clone())
+            .or_default()
+            .insert(receipt_path.sequence, receipt);
+        Ok(())
+    }
+
+    fn store_packet_acknowledgement_v2(
+        &mut self,
+        ack_path: &AckPathV2,
+        ack_commitment: AcknowledgementCommitment,
+    ) -> Result<(), ContextError> {
+        self.ibc_store
+            .lock()
+            .packet_acknowledgement_v2
+            .entry(ack_path.client_id.clone())
+            .or_default()
+            .insert(ack_path.sequence, ack_commitment);
+        Ok(())
+    }
+}
@@ -0,0 +1,578 @@
+//! A provable store of the IBC state of the mock host, in the layout of the
+//! stores of the Cosmos SDK chains: an IAVL tree of the values under the IBC
+//! paths, whose root is committed to by the multistore of the host under its
+//! commitment prefix.
+//!
+//! The proofs of the store verify through `MerkleProof::verify_membership`
+//! with `ProofSpecs::cosmos()`, as the proofs of a Cosmos SDK chain do, unlike
+//! the dummy proofs the mock clients accept without verifying them.
+
+use ibc::core::commitment_types::commitment::{
+    CommitmentPrefix, CommitmentProofBytes, CommitmentRoot,
+};
+use ibc::core::commitment_types::merkle::MerkleProof;
+use ibc::core::host::types::identifiers::{ChannelId, PortId};
+use ibc::core::host::types::path::{
+    AckPath, AckPathV2, ChannelEndPath, ChannelUpgradeErrorPath, ChannelUpgradePath,
+    ClientConsensusStatePath, ClientStatePath, CommitmentPath, CommitmentPathV2, ConnectionPath,
+    Path, ReceiptPath, ReceiptPathV2, SeqAckPath, SeqRecvPath, SeqSendPath, SeqSendPathV2,
+};
+use ibc::core::primitives::prelude::*;
+use ibc::primitives::proto::{Any, Protobuf};
+use ibc::primitives::ToVec;
+use ibc_proto::ics23::commitment_proof::Proof;
+use ibc_proto::ics23::{
+    CommitmentProof, ExistenceProof, HashOp, InnerOp, LeafOp, LengthOp, NonExistenceProof,
+};
+use sha2::{Digest, Sha256};
+
+use super::client_ctx::PortChannelIdMap;
+use super::types::MockIbcStore;
+
+/// The length prefix of the hashes of the children of the IAVL inner nodes.
+const CHILD_HASH_LENGTH: u8 = 32;
+
+/// A store of the values under the IBC paths, proving their membership, or
+/// their absence, against its root.
+///
+/// The IAVL tree of the store is balanced, and all its nodes are of the
+/// version of the store, as if all the values were set at this version.
+#[derive(Clone, Debug)]
+pub struct ProvableStore {
+    prefix: CommitmentPrefix,
+    version: u64,
+    entries: BTreeMap<Vec<u8>, Vec<u8>>,
+}
+
+type Entry<'a> = (&'a Vec<u8>, &'a Vec<u8>);
+
+/// A subtree of the IAVL tree of a [`ProvableStore`].
+struct Subtree {
+    hash: [u8; 32],
+    height: i64,
+    size: i64,
+}
+
+impl ProvableStore {
+    pub fn new(prefix: CommitmentPrefix, version: u64) -> Self {
+        Self {
+            prefix,
+            version,
+            entries: BTreeMap::new(),
+        }
+    }
+
+    /// Returns the store of the provable IBC state of the mock host, at the
+    /// given version.
+    pub fn from_ibc_store(
+        ibc_store: &MockIbcStore,
+        prefix: CommitmentPrefix,
+        version: u64,
+    ) -> Self {
+        let mut store = Self::new(prefix, version);
+
+        for (client_id, record) in &ibc_store.clients {
+            if let Some(client_state) = &record.client_state {
+                store.set(
+                    ClientStatePath::new(client_id.clone()).into(),
+                    Any::from(client_state.clone()).to_vec(),
+                );
+            }
+
+            for (height, consensus_state) in &record.consensus_states {
+                store.set(
+                    ClientConsensusStatePath::new(
+                        client_id.clone(),
+                        height.revision_number(),
+                        height.revision_height(),
+                    )
+                    .into(),
+                    Any::from(consensus_state.clone()).to_vec(),
+                );
+            }
+        }
+
+        for (conn_id, conn_end) in &ibc_store.connections {
+            store.set(
+                ConnectionPath::new(conn_id).into(),
+                conn_end.clone().encode_vec(),
+            );
+        }
+
+        for (port_id, channel_id, chan_end) in port_channel_entries(&ibc_store.channels) {
+            store.set(
+                ChannelEndPath::new(port_id, channel_id).into(),
+                chan_end.clone().encode_vec(),
+            );
+        }
+
+        for (port_id, channel_id, seq) in port_channel_entries(&ibc_store.next_sequence_send) {
+            store.set(SeqSendPath::new(port_id, channel_id).into(), seq.to_vec());
+        }
+
+        for (port_id, channel_id, seq) in port_channel_entries(&ibc_store.next_sequence_recv) {
+            store.set(SeqRecvPath::new(port_id, channel_id).into(), seq.to_vec());
+        }
+
+        for (port_id, channel_id, seq) in port_channel_entries(&ibc_store.next_sequence_ack) {
+            store.set(SeqAckPath::new(port_id, channel_id).into(), seq.to_vec());
+        }
+
+        for (port_id, channel_id, commitments) in port_channel_entries(&ibc_store.packet_commitment)
+        {
+            for (seq, commitment) in commitments {
+                store.set(
+                    CommitmentPath::new(port_id, channel_id, *seq).into(),
+                    commitment.clone().into_vec(),
+                );
+            }
+        }
+
+        for (port_id, channel_id, receipts) in port_channel_entries(&ibc_store.packet_receipt) {
+            for (seq, receipt) in receipts {
+                store.set(
+                    ReceiptPath::new(port_id, channel_id, *seq).into(),
+                    receipt.to_vec(),
+                );
+            }
+        }
+
+        for (port_id, channel_id, acks) in port_channel_entries(&ibc_store.packet_acknowledgement) {
+            for (seq, ack_commitment) in acks {
+                store.set(
+                    AckPath::new(port_id, channel_id, *seq).into(),
+                    ack_commitment.clone().into_vec(),
+                );
+            }
+        }
+
+        for (port_id, channel_id, upgrade) in port_channel_entries(&ibc_store.channel_upgrades) {
+            store.set(
+                ChannelUpgradePath::new(port_id, channel_id).into(),
+                upgrade.clone().encode_vec(),
+            );
+        }
+
+        for (port_id, channel_id, error_receipt) in
+            port_channel_entries(&ibc_store.channel_upgrade_errors)
+        {
+            store.set(
+                ChannelUpgradeErrorPath::new(port_id, channel_id).into(),
+                error_receipt.clone().encode_vec(),
+            );
+        }
+
+        for (client_id, seq) in &ibc_store.next_sequence_send_v2 {
+            store.set(SeqSendPathV2::new(client_id).into(), seq.to_vec());
+        }
+
+        for (client_id, commitments) in &ibc_store.packet_commitment_v2 {
+            for (seq, commitment) in commitments {
+                store.set(
+                    CommitmentPathV2::new(client_id, *seq).into(),
+                    commitment.clone().into_vec(),
+                );
+            }
+        }
+
+        for (client_id, receipts) in &ibc_store.packet_receipt_v2 {
+            for (seq, receipt) in receipts {
+                store.set(ReceiptPathV2::new(client_id, *seq).into(), receipt.to_vec());
+            }
+        }
+
+        for (client_id, acks) in &ibc_store.packet_acknowledgement_v2 {
+            for (seq, ack_commitment) in acks {
+                store.set(
+                    AckPathV2::new(client_id, *seq).into(),
+                    ack_commitment.clone().into_vec(),
+                );
+            }
+        }
+
+        store
+    }
+
+    pub fn version(&self) -> u64 {
+        self.version
+    }
+
+    pub fn get(&self, path: &Path) -> Option<&[u8]> {
+        self.entries.get(&path.to_key()).map(Vec::as_slice)
+    }
+
+    pub fn set(&mut self, path: Path, value: Vec<u8>) {
+        self.entries.insert(path.to_key(), value);
+    }
+
+    pub fn remove(&mut self, path: &Path) -> Option<Vec<u8>> {
+        self.entries.remove(&path.to_key())
+    }
+
+    /// Returns the root of the multistore, committing to the root of the
+    /// IAVL tree under the commitment prefix.
+    pub fn root(&self) -> CommitmentRoot {
+        let store_root = self.store_root();
+
+        CommitmentRoot::from(
+            leaf_hash(&multistore_leaf(), self.prefix.as_bytes(), &store_root).to_vec(),
+        )
+    }
+
+    /// Returns the proof of the value under the path, or of its absence if
+    /// there is none, against the root of the multistore.
+    ///
+    /// Returns `None` if the store is empty, since the absence of a value is
+    /// proven by the values next to it.
+    pub fn prove(&self, path: &Path) -> Option<CommitmentProofBytes> {
+        let key = path.to_key();
+
+        let entries: Vec<Entry<'_>> = self.entries.iter().collect();
+
+        if entries.is_empty() {
+            return None;
+        }
+
+        let store_proof = match entries.binary_search_by(|(entry_key, _)| entry_key.cmp(&&key)) {
+            Ok(index) => Proof::Exist(self.existence_proof(&entries, index)),
+            Err(index) => Proof::Nonexist(NonExistenceProof {
+                key,
+                left: index
+                    .checked_sub(1)
+                    .map(|left| self.existence_proof(&entries, left)),
+                right: (index < entries.len()).then(|| self.existence_proof(&entries, index)),
+            }),
+        };
+
+        let multistore_proof = ExistenceProof {
+            key: self.prefix.as_bytes().to_vec(),
+            value: self.store_root().to_vec(),
+            leaf: Some(multistore_leaf()),
+            path: Vec::new(),
+        };
+
+        MerkleProof {
+            proofs: vec![
+                CommitmentProof {
+                    proof: Some(store_proof),
+                },
+                CommitmentProof {
+                    proof: Some(Proof::Exist(multistore_proof)),
+                },
+            ],
+        }
+        .try_into()
+        .ok()
+    }
+
+    /// Returns the root of the IAVL tree.
+    fn store_root(&self) -> [u8; 32] {
+        let entries: Vec<Entry<'_>> = self.entries.iter().collect();
+
+        if entries.is_empty() {
+            return sha256(&[]);
+        }
+
+        self.subtree(&entries).hash
+    }
+
+    fn subtree(&self, entries: &[Entry<'_>]) -> Subtree {
+        if let [(key, value)] = entries {
+            return Subtree {
+                hash: leaf_hash(&self.leaf(), key, value),
+                height: 0,
+                size: 1,
+            };
+        }
+
+        let (left, right) = entries.split_at((entries.len() + 1) / 2);
+        let (left, right) = (self.subtree(left), self.subtree(right));
+
+        let height = left.height.max(right.height) + 1;
+        let size = left.size + right.size;
+
+        let mut data = self.inner_prefix(height, size);
+        data.push(CHILD_HASH_LENGTH);
+        data.extend(left.hash);
+        data.push(CHILD_HASH_LENGTH);
+        data.extend(right.hash);
+
+        Subtree {
+            hash: sha256(&data),
+            height,
+            size,
+        }
+    }
+
+    fn existence_proof(&self, entries: &[Entry<'_>], index: usize) -> ExistenceProof {
+        let (key, value) = entries[index];
+
+        ExistenceProof {
+            key: key.clone(),
+            value: value.clone(),
+            leaf: Some(self.leaf()),
+            path: self.inner_ops(entries, index),
+        }
+    }
+
+    /// Returns the inner nodes on the path from the leaf at the given index
+    /// up to the root of the subtree.
+    fn inner_ops(&self, entries: &[Entry<'_>], index: usize) -> Vec<InnerOp> {
+        if entries.len() == 1 {
+            return Vec::new();
+        }
+
+        let (left, right) = entries.split_at((entries.len() + 1) / 2);
+        let (left_tree, right_tree) = (self.subtree(left), self.subtree(right));
+
+        let height = left_tree.height.max(right_tree.height) + 1;
+
+        let mut prefix = self.inner_prefix(height, left_tree.size + right_tree.size);
+        prefix.push(CHILD_HASH_LENGTH);
+
+        let (mut ops, op) = if index < left.len() {
+            let mut suffix = vec![CHILD_HASH_LENGTH];
+            suffix.extend(right_tree.hash);
+
+            (self.inner_ops(left, index), inner_op(prefix, suffix))
+        } else {
+            prefix.extend(left_tree.hash);
+            prefix.push(CHILD_HASH_LENGTH);
+
+            (
+                self.inner_ops(right, index - left.len()),
+                inner_op(prefix, Vec::new()),
+            )
+        };
+
+        ops.push(op);
+        ops
+    }
+
+    /// Returns the leaf operation of the IAVL tree, whose prefix encodes the
+    /// height, size and version of the leaves.
+    fn leaf(&self) -> LeafOp {
+        let mut prefix = Vec::new();
+        encode_signed_varint(0, &mut prefix);
+        encode_signed_varint(1, &mut prefix);
+        encode_signed_varint(self.version as i64, &mut prefix);
+
+        LeafOp {
+            hash: HashOp::Sha256.into(),
+            prehash_key: HashOp::NoHash.into(),
+            prehash_value: HashOp::Sha256.into(),
+            length: LengthOp::VarProto.into(),
+            prefix,
+        }
+    }
+
+    fn inner_prefix(&self, height: i64, size: i64) -> Vec<u8> {
+        let mut prefix = Vec::new();
+        encode_signed_varint(height, &mut prefix);
+        encode_signed_varint(size, &mut prefix);
+        encode_signed_varint(self.version as i64, &mut prefix);
+        prefix
+    }
+}
+
+/// Returns the values of a map by port and channel, along with their port
+/// and channel.
+fn port_channel_entries<V>(
+    map: &PortChannelIdMap<V>,
+) -> impl Iterator<Item = (&PortId, &ChannelId, &V)> {
+    map.iter().flat_map(|(port_id, channels)| {
+        channels
+            .iter()
+            .map(move |(channel_id, value)| (port_id, channel_id, value))
+    })
+}
+
+/// Returns the leaf operation of the multistore, a simple Merkle tree of the
+/// roots of the stores.
+fn multistore_leaf() -> LeafOp {
+    LeafOp {
+        hash: HashOp::Sha256.into(),
+        prehash_key: HashOp::NoHash.into(),
+        prehash_value: HashOp::Sha256.into(),
+        length: LengthOp::VarProto.into(),
+        prefix: vec![0],
+    }
+}
+
+fn inner_op(prefix: Vec<u8>, suffix: Vec<u8>) -> InnerOp {
+    InnerOp {
+        hash: HashOp::Sha256.into(),
+        prefix,
+        suffix,
+    }
+}
+
+fn leaf_hash(leaf: &LeafOp, key: &[u8], value: &[u8]) -> [u8; 32] {
+    let mut data = leaf.prefix.clone();
+
+    encode_varint(key.len() as u64, &mut data);
+    data.extend_from_slice(key);
+
+    let value_hash = sha256(value);
+    encode_varint(value_hash.len() as u64, &mut data);
+    data.extend_from_slice(&value_hash);
+
+    sha256(&data)
+}
+
+fn sha256(data: &[u8]) -> [u8; 32] {
+    Sha256::digest(data).into()
+}
+
+/// Encodes the integer as a protobuf varint.
+fn encode_varint(mut value: u64, buf: &mut Vec<u8>) {
+    while value >= 0x80 {
+        buf.push((value as u8) | 0x80);
+        value >>= 7;
+    }
+    buf.push(value as u8);
+}
+
+/// Encodes the integer as a zigzag varint, as IAVL encodes the heights,
+/// sizes and versions of its nodes.
+fn encode_signed_varint(value: i64, buf: &mut Vec<u8>) {
+    encode_varint(((value << 1) ^ (value >> 63)) as u64, buf);
+}
+
+#[cfg(test)]
+mod tests {
+    use ibc::core::commitment_types::error::CommitmentError;
+    use ibc::core::commitment_types::merkle::{apply_prefix, VerificationArena};
+    use ibc::core::commitment_types::specs::ProofSpecs;
+    use ibc::core::host::types::identifiers::Sequence;
+    use rstest::rstest;
+
+    use super::*;
+
+    fn receipt_path(seq: u64) -> Path {
+        ReceiptPath::new(&PortId::transfer(), &ChannelId::new(0), Sequence::from(seq)).into()
+    }
+
+    fn store_with_receipts(sequences: &[u64]) -> ProvableStore {
+        let mut store = ProvableStore::new(b"ibc".to_vec().try_into().unwrap(), 42);
+
+        for seq in sequences {
+            store.set(receipt_path(*seq), vec![1]);
+        }
+
+        store
+    }
+
+    /// Counts the steps of the verification it runs.
+    #[derive(Default)]
+    struct CountingArena {
+        steps: usize,
+    }
+
+    impl VerificationArena for CountingArena {
+        fn scoped(
+            &mut self,
+            step: impl FnOnce() -> Result<(), CommitmentError>,
+        ) -> Result<(), CommitmentError> {
+            self.steps += 1;
+            step()
+        }
+    }
+
+    fn merkle_proof(store: &ProvableStore, path: &Path) -> MerkleProof {
+        MerkleProof::try_from(&store.prove(path).unwrap()).unwrap()
+    }
+
+    #[rstest]
+    #[case(&[1])]
+    #[case(&[1, 2])]
+    #[case(&[1, 2, 3, 4, 5, 6, 7])]
+    #[case(&[1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13])]
+    fn membership_proofs_verify(#[case] sequences: &[u64]) {
+        let store = store_with_receipts(sequences);
+
+        for seq in sequences {
+            let path = receipt_path(*seq);
+
+            merkle_proof(&store, &path)
+                .verify_membership(
+                    &ProofSpecs::cosmos(),
+                    store.root().into(),
+                    apply_prefix(&store.prefix, vec![path.to_string()]),
+                    vec![1],
+                    0,
+                )
+                .unwrap();
+        }
+    }
+
+    #[rstest]
+    // Before the first key, after the last one, and between two keys.
+    #[case(&[2, 3, 4], 1)]
+    #[case(&[2, 3, 4], 5)]
+    #[case(&[2, 3, 5, 6, 7], 4)]
+    fn non_membership_proofs_verify(#[case] sequences: &[u64], #[case] absent: u64) {
+        let store = store_with_receipts(sequences);
+
+        let path = receipt_path(absent);
+
+        merkle_proof(&store, &path)
+            .verify_non_membership(
+                &ProofSpecs::cosmos(),
+                store.root().into(),
+                apply_prefix(&store.prefix, vec![path.to_string()]),
+            )
+            .unwrap();
+    }
+
+    #[test]
+    fn proofs_of_other_values_fail() {
+        let store = store_with_receipts(&[1, 2, 3]);
+
+        let path = receipt_path(2);
+
+        let res = merkle_proof(&store, &path).verify_membership(
+            &ProofSpecs::cosmos(),
+            store.root().into(),
+            apply_prefix(&store.prefix, vec![path.to_string()]),
+            vec![2],
+            0,
+        );
+
+        assert!(res.is_err());
+    }
+
+    #[test]
+    fn proofs_verify_in_arena() {
+        let store = store_with_receipts(&[1, 2, 3]);
+
+        let mut arena = CountingArena::default();
+
+        let path = receipt_path(2);
+
+        merkle_proof(&store, &path)
+            .verify_membership_in(
+                &mut arena,
+                &ProofSpecs::cosmos(),
+                store.root().into(),
+                apply_prefix(&store.prefix, vec![path.to_string()]),
+                vec![1],
+                0,
+            )
+            .unwrap();
+
+        let path = receipt_path(4);
+
+        merkle_proof(&store, &path)
+            .verify_non_membership_in(
+                &mut arena,
+                &ProofSpecs::cosmos(),
+                store.root().into(),
+                apply_prefix(&store.prefix, vec![path.to_string()]),
+            )
+            .unwrap();
+
+        // One step for the proof of each of the two levels of the store.
+        assert_eq!(arena.steps, 4);
+    }
+}
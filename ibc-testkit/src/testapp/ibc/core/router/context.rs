@@ -1,9 +1,12 @@
 use alloc::sync::Arc;
 
-use ibc::core::host::types::identifiers::PortId;
+use ibc::core::host::types::identifiers::{ChannelId, PortId};
 use ibc::core::router::module::Module;
-use ibc::core::router::router::Router;
+use ibc::core::router::router::{ChannelRouter, PortRouter, Router, TransactionalRouter};
+use ibc::core::router::types::error::RouterError;
 use ibc::core::router::types::module::ModuleId;
+use ibc::core::router::types::port::{ChannelCapability, PortCapability};
+use ibc::core::router::v2::{ModuleV2, RouterV2};
 
 use super::types::MockRouter;
 
@@ -28,6 +31,84 @@ impl Router for MockRouter {
     }
 
     fn lookup_module(&self, port_id: &PortId) -> Option<ModuleId> {
-        self.port_to_module.get(port_id).cloned()
+        self.port_to_module
+            .get(port_id)
+            .or_else(|| self.port_bindings.lookup_module(port_id))
+            .cloned()
+    }
+}
+
+// The modules of the testkit keep no state of their own, so that there is
+// nothing to roll back besides the IBC store of the mock context. The states
+// of the applications routed with `add_application` are not rolled back.
+impl TransactionalRouter for MockRouter {
+    fn begin_transaction(&mut self) {}
+
+    fn commit_transaction(&mut self) {}
+
+    fn rollback_transaction(&mut self) {}
+}
+
+impl PortRouter for MockRouter {
+    fn bind_port(
+        &mut self,
+        port_id: PortId,
+        module_id: ModuleId,
+    ) -> Result<PortCapability, RouterError> {
+        if !self.router.contains_key(&module_id) {
+            return Err(RouterError::ModuleNotFound);
+        }
+
+        if let Some(bound_module_id) = self.port_to_module.get(&port_id) {
+            return Err(RouterError::PortAlreadyBound {
+                port_id,
+                module_id: bound_module_id.clone(),
+            });
+        }
+
+        self.port_bindings.bind(port_id, module_id)
+    }
+
+    fn release_port(&mut self, capability: &PortCapability) -> Result<(), RouterError> {
+        self.port_bindings.release(capability)
+    }
+
+    fn authenticate_port(&self, capability: &PortCapability) -> bool {
+        self.port_bindings.authenticate(capability)
+    }
+}
+
+impl ChannelRouter for MockRouter {
+    fn claim_channel(
+        &mut self,
+        port_capability: &PortCapability,
+        channel_id: ChannelId,
+    ) -> Result<ChannelCapability, RouterError> {
+        self.port_bindings
+            .claim_channel(port_capability, channel_id)
+    }
+
+    fn release_channel(&mut self, capability: &ChannelCapability) -> Result<(), RouterError> {
+        self.port_bindings.release_channel(capability)
+    }
+
+    fn authenticate_channel(&self, capability: &ChannelCapability) -> bool {
+        self.port_bindings.authenticate_channel(capability)
+    }
+}
+
+impl RouterV2 for MockRouter {
+    fn get_route_v2(&self, port_id: &PortId) -> Option<&dyn ModuleV2> {
+        self.router_v2.get(port_id).map(Arc::as_ref)
+    }
+
+    fn get_route_v2_mut(&mut self, port_id: &PortId) -> Option<&mut dyn ModuleV2> {
+        match self.router_v2.get_mut(port_id) {
+            Some(arc_mod) => match Arc::get_mut(arc_mod) {
+                Some(m) => Some(m),
+                None => None,
+            },
+            None => None,
+        }
     }
 }
@@ -5,6 +5,10 @@ use ibc::core::host::types::identifiers::PortId;
 use ibc::core::primitives::prelude::*;
 use ibc::core::router::module::Module;
 use ibc::core::router::types::module::ModuleId;
+use ibc::core::router::types::port::PortBindings;
+use ibc::core::router::v2::ModuleV2;
+use ibc_app_template::app::Application;
+use ibc_app_template::module::AppModule;
 
 use crate::testapp::ibc::applications::transfer::types::DummyTransferModule;
 
@@ -14,6 +18,12 @@ pub struct MockRouter {
 
     /// Maps ports to the the module that owns it
     pub port_to_module: BTreeMap<PortId, ModuleId>,
+
+    /// The ports bound by the modules at runtime
+    pub port_bindings: PortBindings,
+
+    /// Maps ports to the IBC v2 application bound to it
+    pub router_v2: BTreeMap<PortId, Arc<dyn ModuleV2>>,
 }
 
 impl MockRouter {
@@ -30,6 +40,10 @@ impl MockRouter {
             .add_route(module_id, transfer_mod)
             .expect("Never fails");
 
+        router
+            .add_route_v2(PortId::transfer(), DummyTransferModule::new())
+            .expect("Never fails");
+
         router
     }
 
@@ -44,6 +58,28 @@ impl MockRouter {
         }
     }
 
+    pub fn add_route_v2(
+        &mut self,
+        port_id: PortId,
+        module: impl ModuleV2 + 'static,
+    ) -> Result<(), String> {
+        match self.router_v2.insert(port_id, Arc::new(module)) {
+            None => Ok(()),
+            Some(_) => Err("Duplicate port_id".to_owned()),
+        }
+    }
+
+    /// Routes the given custom application under its module identifier, and
+    /// scopes its port to it.
+    pub fn add_application(&mut self, app: impl Application + 'static) -> Result<(), String> {
+        let config = app.config().clone();
+
+        self.add_route(config.module_id.clone(), AppModule::new(app))?;
+        self.scope_port_to_module(config.port_id, config.module_id);
+
+        Ok(())
+    }
+
     pub fn scope_port_to_module(&mut self, port_id: PortId, module_id: ModuleId) {
         self.port_to_module.insert(port_id, module_id);
     }
@@ -10,8 +10,16 @@ use ibc::clients::tendermint::client_state::ClientState as TmClientState;
 use ibc::clients::tendermint::types::TENDERMINT_CLIENT_TYPE;
 use ibc::core::channel::types::channel::ChannelEnd;
 use ibc::core::channel::types::commitment::{AcknowledgementCommitment, PacketCommitment};
+use ibc::core::channel::types::events::PacketDataEventMode;
 use ibc::core::channel::types::packet::Receipt;
+use ibc::core::channel::types::upgrade::{ErrorReceipt, Upgrade};
+use ibc::core::channel::types::v2::counterparty::ClientCounterparty;
+use ibc::core::client::context::client_state::{ClientStateCommon, ClientStateValidation};
+use ibc::core::client::context::consensus_state::ConsensusState;
+use ibc::core::client::types::params::ClientParams;
 use ibc::core::client::types::Height;
+use ibc::core::client::types::Status;
+use ibc::core::commitment_types::commitment::{CommitmentProofBytes, CommitmentRoot};
 use ibc::core::connection::types::ConnectionEnd;
 use ibc::core::entrypoint::dispatch;
 use ibc::core::handler::types::events::IbcEvent;
@@ -19,19 +27,23 @@ use ibc::core::handler::types::msgs::MsgEnvelope;
 use ibc::core::host::types::identifiers::{
     ChainId, ChannelId, ClientId, ClientType, ConnectionId, PortId, Sequence,
 };
-use ibc::core::host::ValidationContext;
+use ibc::core::host::types::path::Path;
+use ibc::core::host::{HostClock, ValidationContext};
 use ibc::core::primitives::prelude::*;
-use ibc::core::primitives::Timestamp;
+use ibc::core::primitives::{Signer, SignerFormat, Timestamp};
 use ibc::core::router::router::Router;
 use parking_lot::Mutex;
+use tendermint::hash::AppHash;
 use tendermint_testgen::Validator as TestgenValidator;
 use typed_builder::TypedBuilder;
 
-use super::client_ctx::{MockClientRecord, PortChannelIdMap};
+use super::client_ctx::{MockClientRecord, MockZkCommitVerifier, PortChannelIdMap};
+use super::provable_store::ProvableStore;
 use crate::fixtures::clients::tendermint::ClientStateConfig as TmClientStateConfig;
 use crate::fixtures::core::context::MockContextConfig;
 use crate::hosts::block::{HostBlock, HostType};
 use crate::relayer::error::RelayerError;
+use crate::testapp::ibc::clients::mock::behaviour::MockClientBehaviour;
 use crate::testapp::ibc::clients::mock::client_state::{
     client_type as mock_client_type, MockClientState, MOCK_CLIENT_TYPE,
 };
@@ -40,6 +52,10 @@ use crate::testapp::ibc::clients::mock::header::MockHeader;
 use crate::testapp::ibc::clients::{AnyClientState, AnyConsensusState};
 pub const DEFAULT_BLOCK_TIME_SECS: u64 = 3;
 
+/// The account of the governance of the mock host, authorized to perform the
+/// governance operations of IBC.
+pub const MOCK_AUTHORITY: &str = "cosmos10d07y265gmmuvt4z0w9aw880jnsr700j6zn9kn";
+
 /// An object that stores all IBC related data.
 #[derive(Clone, Debug, Default)]
 pub struct MockIbcStore {
@@ -91,11 +107,44 @@ pub struct MockIbcStore {
     /// Used by unordered channel
     pub packet_receipt: PortChannelIdMap<BTreeMap<Sequence, Receipt>>,
 
+    /// Upgrades proposed for channels, during the upgrade handshake.
+    pub channel_upgrades: PortChannelIdMap<Upgrade>,
+
+    /// Upgrades of the counterparties of upgrading channels.
+    pub counterparty_channel_upgrades: PortChannelIdMap<Upgrade>,
+
+    /// Error receipts of the last aborted upgrade of channels.
+    pub channel_upgrade_errors: PortChannelIdMap<ErrorReceipt>,
+
+    /// The counterparties of the clients IBC v2 packets are sent over.
+    pub client_counterparties: BTreeMap<ClientId, ClientCounterparty>,
+
+    /// Tracks the sequence number for the next IBC v2 packet to be sent.
+    pub next_sequence_send_v2: BTreeMap<ClientId, Sequence>,
+
+    /// Constant-size commitments to IBC v2 packets
+    pub packet_commitment_v2: BTreeMap<ClientId, BTreeMap<Sequence, PacketCommitment>>,
+
+    pub packet_receipt_v2: BTreeMap<ClientId, BTreeMap<Sequence, Receipt>>,
+
+    pub packet_acknowledgement_v2:
+        BTreeMap<ClientId, BTreeMap<Sequence, AcknowledgementCommitment>>,
+
+    /// The parameters of the client module
+    pub client_params: ClientParams,
+
+    /// The version of the layout of the store, if set
+    pub store_version: Option<u64>,
+
     /// Emitted IBC events in order
     pub events: Vec<IbcEvent>,
 
     /// Logs of the IBC module
     pub logs: Vec<String>,
+
+    /// The provable stores committed to by the blocks of the host, by their
+    /// height, if the host commits to its state
+    pub committed_stores: Option<BTreeMap<Height, ProvableStore>>,
 }
 
 /// A context implementing the dependencies necessary for testing any IBC module.
@@ -117,6 +166,23 @@ pub struct MockContext {
     /// Average time duration between blocks
     pub block_time: Duration,
 
+    /// Maximum size of the packet data and acknowledgements, if any
+    pub max_packet_data_size: Option<usize>,
+
+    /// How the packet data is carried by the packet events
+    pub packet_data_event_mode: PacketDataEventMode,
+
+    /// The address format the signers of the messages must be in, if any
+    pub signer_format: Option<SignerFormat>,
+
+    /// The signers allowed to create connections, if connection creation is
+    /// permissioned
+    pub connection_creators: Option<Vec<Signer>>,
+
+    /// The verifier of the commit proofs of the ZK headers, if the ZK headers
+    /// are accepted
+    pub zk_commit_verifier: Option<MockZkCommitVerifier>,
+
     /// An object that stores all IBC related data.
     pub ibc_store: Arc<Mutex<MockIbcStore>>,
 }
@@ -141,6 +207,10 @@ pub struct MockClientConfig {
     max_clock_drift: Duration,
     #[builder(default = Duration::from_secs(128_000))]
     unbonding_period: Duration,
+
+    /// Scripted behaviours of the client, only supported by mock clients.
+    #[builder(default)]
+    behaviour: MockClientBehaviour,
 }
 
 /// Returns a MockContext with bare minimum initialization: no clients, no connections and no channels are
@@ -167,11 +237,33 @@ impl Clone for MockContext {
             max_history_size: self.max_history_size,
             history: self.history.clone(),
             block_time: self.block_time,
+            max_packet_data_size: self.max_packet_data_size,
+            packet_data_event_mode: self.packet_data_event_mode,
+            signer_format: self.signer_format.clone(),
+            connection_creators: self.connection_creators.clone(),
+            zk_commit_verifier: self.zk_commit_verifier.clone(),
             ibc_store,
         }
     }
 }
 
+/// The state of a [`MockContext`] taken by [`MockContext::snapshot`], i.e. its
+/// host chain along with its clients, connections, channels, packets and
+/// provable stores, for an expensive setup to be restored in place or forked
+/// into new contexts, across test cases and fuzz iterations.
+#[derive(Clone, Debug)]
+pub struct MockContextSnapshot {
+    ctx: MockContext,
+}
+
+impl MockContextSnapshot {
+    /// Returns a new context in the state of the snapshot, independent of
+    /// the context the snapshot was taken of.
+    pub fn fork(&self) -> MockContext {
+        self.ctx.clone()
+    }
+}
+
 /// Implementation of internal interface for use in testing. The methods in this interface should
 /// _not_ be accessible to any Ics handler.
 impl MockContext {
@@ -231,6 +323,11 @@ impl MockContext {
                 })
                 .collect(),
             block_time,
+            max_packet_data_size: None,
+            packet_data_event_mode: PacketDataEventMode::default(),
+            signer_format: None,
+            connection_creators: None,
+            zk_commit_verifier: None,
             ibc_store: Arc::new(Mutex::new(MockIbcStore::default())),
         }
     }
@@ -300,6 +397,11 @@ impl MockContext {
             max_history_size,
             history,
             block_time,
+            max_packet_data_size: None,
+            packet_data_event_mode: PacketDataEventMode::default(),
+            signer_format: None,
+            connection_creators: None,
+            zk_commit_verifier: None,
             ibc_store: Arc::new(Mutex::new(MockIbcStore::default())),
         }
     }
@@ -476,7 +578,8 @@ impl MockContext {
 
                 let client_state = MockClientState::new(
                     MockHeader::new(client.latest_height).with_timestamp(client.latest_timestamp),
-                );
+                )
+                .with_behaviour(client.behaviour);
 
                 let cs_states = blocks
                     .into_iter()
@@ -613,6 +716,36 @@ impl MockContext {
         self
     }
 
+    pub fn with_max_packet_data_size(mut self, max_packet_data_size: usize) -> Self {
+        self.max_packet_data_size = Some(max_packet_data_size);
+        self
+    }
+
+    pub fn with_packet_data_event_mode(
+        mut self,
+        packet_data_event_mode: PacketDataEventMode,
+    ) -> Self {
+        self.packet_data_event_mode = packet_data_event_mode;
+        self
+    }
+
+    pub fn with_signer_format(mut self, signer_format: SignerFormat) -> Self {
+        self.signer_format = Some(signer_format);
+        self
+    }
+
+    /// Permissions the creation of connections to the given signers.
+    pub fn with_connection_creators(mut self, connection_creators: Vec<Signer>) -> Self {
+        self.connection_creators = Some(connection_creators);
+        self
+    }
+
+    /// Accepts the ZK headers whose commit proofs the given verifier accepts.
+    pub fn with_zk_commit_verifier(mut self, zk_commit_verifier: MockZkCommitVerifier) -> Self {
+        self.zk_commit_verifier = Some(zk_commit_verifier);
+        self
+    }
+
     pub fn with_height(self, target_height: Height) -> Self {
         let latest_height = self.latest_height();
         if target_height.revision_number() > latest_height.revision_number() {
@@ -652,6 +785,19 @@ impl MockContext {
         self
     }
 
+    /// Registers the counterparty of the client IBC v2 packets are sent over.
+    pub fn with_client_counterparty(
+        self,
+        client_id: ClientId,
+        counterparty: ClientCounterparty,
+    ) -> Self {
+        self.ibc_store
+            .lock()
+            .client_counterparties
+            .insert(client_id, counterparty);
+        self
+    }
+
     /// Accessor for a block of the local (host) chain from this context.
     /// Returns `None` if the block at the requested height does not exist.
     pub fn host_block(&self, target_height: &Height) -> Option<&HostBlock> {
@@ -666,18 +812,84 @@ impl MockContext {
         }
     }
 
+    /// Commits the IBC state of the host to a provable store at each of its
+    /// blocks from then on, so that the state is proven by actual Merkle
+    /// proofs, as on a Cosmos SDK chain, instead of the dummy proofs accepted
+    /// by the mock clients.
+    ///
+    /// The headers of the Tendermint blocks commit to the roots of the stores
+    /// as their application state hashes, for the proofs to verify against the
+    /// consensus states of the Tendermint clients of the host.
+    pub fn with_provable_store(self) -> Self {
+        self.ibc_store
+            .lock()
+            .committed_stores
+            .get_or_insert_with(BTreeMap::new);
+        self
+    }
+
+    /// Returns the root of the provable store committed to by the block at
+    /// the given height, if any.
+    pub fn commitment_root(&self, height: &Height) -> Option<CommitmentRoot> {
+        let ibc_store = self.ibc_store.lock();
+
+        Some(ibc_store.committed_stores.as_ref()?.get(height)?.root())
+    }
+
+    /// Returns the proof of the value under the path, or of its absence, in
+    /// the provable store committed to by the block at the given height, if
+    /// any.
+    pub fn prove(&self, height: &Height, path: &Path) -> Option<CommitmentProofBytes> {
+        let ibc_store = self.ibc_store.lock();
+
+        ibc_store
+            .committed_stores
+            .as_ref()?
+            .get(height)?
+            .prove(path)
+    }
+
     /// Triggers the advancing of the host chain, by extending the history of blocks (or headers).
     pub fn advance_host_chain_height(&mut self) {
+        self.advance_host_chain_time(self.block_time);
+    }
+
+    /// Advances the host chain by a block committed `duration` after the
+    /// latest block, instead of the block time, to travel the clock of the
+    /// host forward in time.
+    pub fn advance_host_chain_time(&mut self, duration: Duration) {
         let latest_block = self.history.last().expect("history cannot be empty");
-        let new_block = HostBlock::generate_block(
-            self.host_chain_id.clone(),
-            self.host_chain_type,
-            latest_block.height().increment().revision_height(),
-            latest_block
-                .timestamp()
-                .add(self.block_time)
-                .expect("Never fails"),
-        );
+        let height = latest_block.height().increment();
+        let timestamp = latest_block.timestamp().add(duration).expect("Never fails");
+
+        let committed_store = {
+            let ibc_store = self.ibc_store.lock();
+
+            ibc_store.committed_stores.is_some().then(|| {
+                ProvableStore::from_ibc_store(
+                    &ibc_store,
+                    self.commitment_prefix(),
+                    height.revision_height(),
+                )
+            })
+        };
+
+        let new_block = match (&committed_store, self.host_chain_type) {
+            (Some(store), HostType::SyntheticTendermint) => HostBlock::SyntheticTendermint(
+                Box::new(HostBlock::generate_tm_block_with_app_hash(
+                    self.host_chain_id.clone(),
+                    height.revision_height(),
+                    timestamp,
+                    AppHash::try_from(store.root().into_vec()).expect("Never fails"),
+                )),
+            ),
+            _ => HostBlock::generate_block(
+                self.host_chain_id.clone(),
+                self.host_chain_type,
+                height.revision_height(),
+                timestamp,
+            ),
+        };
 
         // Append the new header at the tip of the history.
         if self.history.len() as u64 >= self.max_history_size {
@@ -688,6 +900,111 @@ impl MockContext {
             // History is not full yet.
             self.history.push(new_block);
         }
+
+        // Commit the store along with the new block, pruning the stores of the
+        // pruned blocks.
+        if let Some(store) = committed_store {
+            let oldest_height = self.history[0].height();
+
+            let mut ibc_store = self.ibc_store.lock();
+            let committed_stores = ibc_store.committed_stores.get_or_insert_with(BTreeMap::new);
+
+            committed_stores.insert(height, store);
+            committed_stores.retain(|height, _| height >= &oldest_height);
+        }
+    }
+
+    /// Advances the host chain by a block committed at the given timestamp.
+    ///
+    /// Panics if the timestamp is not after the timestamp of the latest block.
+    pub fn advance_host_chain_time_to(&mut self, timestamp: Timestamp) {
+        let latest_timestamp = self
+            .history
+            .last()
+            .expect("history cannot be empty")
+            .timestamp();
+
+        let duration = timestamp
+            .duration_since(&latest_timestamp)
+            .filter(|duration| !duration.is_zero())
+            .unwrap_or_else(|| {
+                panic!(
+                    "the timestamp {timestamp} is not after the latest block at {latest_timestamp}"
+                )
+            });
+
+        self.advance_host_chain_time(duration);
+    }
+
+    /// Advances the host chain past the trusting period of the client, counted
+    /// from the consensus state at its latest height, for the client to
+    /// expire.
+    ///
+    /// The mock clients with no trusting period never expire.
+    pub fn advance_past_trusting_period(&mut self, client_id: &ClientId) {
+        let client_state = self.latest_client_states(client_id);
+
+        let trusting_period = match &client_state {
+            AnyClientState::Tendermint(client_state) => client_state.inner().trusting_period,
+            AnyClientState::Mock(client_state) => client_state.trusting_period,
+        };
+
+        let expiry = self
+            .latest_consensus_states(client_id, &client_state.latest_height())
+            .timestamp()
+            .add(trusting_period)
+            .expect("Never fails");
+
+        let latest_timestamp = self
+            .history
+            .last()
+            .expect("history cannot be empty")
+            .timestamp();
+
+        let duration = expiry
+            .duration_since(&latest_timestamp)
+            .unwrap_or_default()
+            .max(self.block_time);
+
+        self.advance_host_chain_time(duration);
+    }
+
+    /// Returns the status of the client, as evaluated at the current time of
+    /// the host.
+    pub fn client_status(&self, client_id: &ClientId) -> Status {
+        self.latest_client_states(client_id)
+            .status(self, client_id)
+            .expect("Never fails")
+    }
+
+    /// Asserts that the client is expired at the current time of the host.
+    pub fn assert_client_expired(&self, client_id: &ClientId) {
+        let status = self.client_status(client_id);
+
+        assert!(
+            status.is_expired(),
+            "the client {client_id} is {status}, not expired"
+        );
+    }
+
+    /// Asserts that the client is frozen at the current time of the host.
+    pub fn assert_client_frozen(&self, client_id: &ClientId) {
+        let status = self.client_status(client_id);
+
+        assert!(
+            status.is_frozen(),
+            "the client {client_id} is {status}, not frozen"
+        );
+    }
+
+    /// Asserts that the client is active at the current time of the host.
+    pub fn assert_client_active(&self, client_id: &ClientId) {
+        let status = self.client_status(client_id);
+
+        assert!(
+            status.is_active(),
+            "the client {client_id} is {status}, not active"
+        );
     }
 
     /// A datagram passes from the relayer to the IBC module (on host chain).
@@ -759,6 +1076,33 @@ impl MockContext {
             .height()
     }
 
+    /// Takes a snapshot of the state of the context, unaffected by the
+    /// messages delivered to the context afterwards.
+    pub fn snapshot(&self) -> MockContextSnapshot {
+        MockContextSnapshot { ctx: self.clone() }
+    }
+
+    /// Restores the context to the state of the snapshot.
+    ///
+    /// The store is restored in place, for the modules holding a share of it
+    /// (see [`Self::ibc_store_share`]) to observe the restored state too.
+    pub fn restore(&mut self, snapshot: &MockContextSnapshot) {
+        let MockContextSnapshot { ctx } = snapshot;
+
+        self.host_chain_type = ctx.host_chain_type;
+        self.host_chain_id = ctx.host_chain_id.clone();
+        self.max_history_size = ctx.max_history_size;
+        self.history = ctx.history.clone();
+        self.block_time = ctx.block_time;
+        self.max_packet_data_size = ctx.max_packet_data_size;
+        self.packet_data_event_mode = ctx.packet_data_event_mode;
+        self.signer_format = ctx.signer_format.clone();
+        self.connection_creators = ctx.connection_creators.clone();
+        self.zk_commit_verifier = ctx.zk_commit_verifier.clone();
+
+        *self.ibc_store.lock() = ctx.ibc_store.lock().clone();
+    }
+
     pub fn ibc_store_share(&self) -> Arc<Mutex<MockIbcStore>> {
         self.ibc_store.clone()
     }
@@ -992,12 +1336,12 @@ mod tests {
                 &mut self,
                 _packet: &Packet,
                 _relayer: &Signer,
-            ) -> (ModuleExtras, Acknowledgement) {
+            ) -> (ModuleExtras, Option<Acknowledgement>) {
                 self.counter += 1;
 
                 (
                     ModuleExtras::empty(),
-                    Acknowledgement::try_from(vec![1u8]).expect("Never fails"),
+                    Some(Acknowledgement::try_from(vec![1u8]).expect("Never fails")),
                 )
             }
 
@@ -1092,10 +1436,10 @@ mod tests {
                 &mut self,
                 _packet: &Packet,
                 _relayer: &Signer,
-            ) -> (ModuleExtras, Acknowledgement) {
+            ) -> (ModuleExtras, Option<Acknowledgement>) {
                 (
                     ModuleExtras::empty(),
-                    Acknowledgement::try_from(vec![1u8]).expect("Never fails"),
+                    Some(Acknowledgement::try_from(vec![1u8]).expect("Never fails")),
                 )
             }
 
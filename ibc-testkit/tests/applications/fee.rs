@@ -0,0 +1,260 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use ibc::apps::fee::context::{FeeExecutionContext, FeeValidationContext};
+use ibc::apps::fee::module::FeeMiddleware;
+use ibc::apps::fee::types::acknowledgement::IncentivizedAcknowledgement;
+use ibc::apps::fee::types::error::FeeError;
+use ibc::apps::fee::types::{PacketFee, PacketId};
+use ibc::apps::transfer::types::PrefixedCoin;
+use ibc::core::channel::types::acknowledgement::Acknowledgement;
+use ibc::core::channel::types::channel::{ChannelEnd, Counterparty, Order, State};
+use ibc::core::channel::types::commitment::compute_ack_commitment;
+use ibc::core::channel::types::packet::Packet;
+use ibc::core::channel::types::Version;
+use ibc::core::host::types::identifiers::{ChannelId, ConnectionId, PortId};
+use ibc::core::host::types::path::AckPath;
+use ibc::core::host::ValidationContext;
+use ibc::core::primitives::Signer;
+use ibc::core::router::middleware::{Middleware, ModuleStack};
+use ibc::core::router::module::Module;
+use ibc::core::router::types::module::ModuleExtras;
+use ibc_testkit::fixtures::core::channel::dummy_raw_packet;
+use ibc_testkit::fixtures::core::signer::dummy_account_id;
+use ibc_testkit::testapp::ibc::applications::transfer::types::DummyTransferModule;
+use ibc_testkit::testapp::ibc::core::types::MockContext;
+use test_log::test;
+
+/// A fee context which only tracks the fee-enabled channels and the relayers
+/// of the packets acknowledged asynchronously.
+#[derive(Debug, Default)]
+struct MockFeeContext {
+    fee_enabled: BTreeSet<(PortId, ChannelId)>,
+    async_ack_relayers: BTreeMap<PacketId, Signer>,
+}
+
+impl FeeValidationContext for MockFeeContext {
+    type AccountId = Signer;
+
+    fn is_fee_enabled(&self, port_id: &PortId, channel_id: &ChannelId) -> Result<bool, FeeError> {
+        Ok(self
+            .fee_enabled
+            .contains(&(port_id.clone(), channel_id.clone())))
+    }
+
+    fn get_payee(
+        &self,
+        _relayer: &Signer,
+        _channel_id: &ChannelId,
+    ) -> Result<Option<Signer>, FeeError> {
+        Ok(None)
+    }
+
+    fn get_counterparty_payee(
+        &self,
+        _relayer: &Signer,
+        _channel_id: &ChannelId,
+    ) -> Result<Option<Signer>, FeeError> {
+        Ok(None)
+    }
+
+    fn get_fees_in_escrow(&self, _packet_id: &PacketId) -> Result<Vec<PacketFee>, FeeError> {
+        Ok(Vec::new())
+    }
+
+    fn get_relayer_address_for_async_ack(
+        &self,
+        packet_id: &PacketId,
+    ) -> Result<Option<Signer>, FeeError> {
+        Ok(self.async_ack_relayers.get(packet_id).cloned())
+    }
+
+    fn escrow_fee_validate(
+        &self,
+        _from_account: &Self::AccountId,
+        _coins: &[PrefixedCoin],
+    ) -> Result<(), FeeError> {
+        Ok(())
+    }
+}
+
+impl FeeExecutionContext for MockFeeContext {
+    fn store_fee_enabled(
+        &mut self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<(), FeeError> {
+        self.fee_enabled
+            .insert((port_id.clone(), channel_id.clone()));
+        Ok(())
+    }
+
+    fn delete_fee_enabled(
+        &mut self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<(), FeeError> {
+        self.fee_enabled
+            .remove(&(port_id.clone(), channel_id.clone()));
+        Ok(())
+    }
+
+    fn store_payee(
+        &mut self,
+        _relayer: &Signer,
+        _channel_id: &ChannelId,
+        _payee: &Signer,
+    ) -> Result<(), FeeError> {
+        Ok(())
+    }
+
+    fn store_counterparty_payee(
+        &mut self,
+        _relayer: &Signer,
+        _channel_id: &ChannelId,
+        _counterparty_payee: &Signer,
+    ) -> Result<(), FeeError> {
+        Ok(())
+    }
+
+    fn store_fees_in_escrow(
+        &mut self,
+        _packet_id: &PacketId,
+        _packet_fees: Vec<PacketFee>,
+    ) -> Result<(), FeeError> {
+        Ok(())
+    }
+
+    fn delete_fees_in_escrow(&mut self, _packet_id: &PacketId) -> Result<(), FeeError> {
+        Ok(())
+    }
+
+    fn store_relayer_address_for_async_ack(
+        &mut self,
+        packet_id: &PacketId,
+        address: &Signer,
+    ) -> Result<(), FeeError> {
+        self.async_ack_relayers
+            .insert(packet_id.clone(), address.clone());
+        Ok(())
+    }
+
+    fn delete_relayer_address_for_async_ack(
+        &mut self,
+        packet_id: &PacketId,
+    ) -> Result<(), FeeError> {
+        self.async_ack_relayers.remove(packet_id);
+        Ok(())
+    }
+
+    fn escrow_fee_execute(
+        &mut self,
+        _from_account: &Self::AccountId,
+        _coins: &[PrefixedCoin],
+    ) -> Result<(), FeeError> {
+        Ok(())
+    }
+
+    fn unescrow_fee_execute(
+        &mut self,
+        _to_account: &Self::AccountId,
+        _coins: &[PrefixedCoin],
+    ) -> Result<(), FeeError> {
+        Ok(())
+    }
+}
+
+/// A middleware which defers the acknowledgement of the application, as
+/// applications acknowledging packets asynchronously do.
+#[derive(Debug)]
+struct DeferAckMiddleware;
+
+impl Middleware for DeferAckMiddleware {
+    fn on_recv_packet_execute(
+        &mut self,
+        next: &mut dyn Module,
+        packet: &Packet,
+        relayer: &Signer,
+    ) -> (ModuleExtras, Option<Acknowledgement>) {
+        let (extras, _ack) = next.on_recv_packet_execute(packet, relayer);
+
+        (extras, None)
+    }
+}
+
+#[test]
+fn fee_middleware_wraps_async_acknowledgement() {
+    let packet = Packet::try_from(dummy_raw_packet(10, 0)).unwrap();
+    let relayer = dummy_account_id();
+
+    let packet_id = PacketId::new(
+        packet.port_id_on_b.clone(),
+        packet.chan_id_on_b.clone(),
+        packet.seq_on_a,
+    );
+
+    let mut fee_ctx = MockFeeContext::default();
+    fee_ctx
+        .store_fee_enabled(&packet.port_id_on_b, &packet.chan_id_on_b)
+        .unwrap();
+
+    let mut stack = ModuleStack::new(
+        FeeMiddleware::new(fee_ctx),
+        ModuleStack::new(DeferAckMiddleware, DummyTransferModule::new()),
+    );
+
+    let (_extras, ack) = stack.on_recv_packet_execute(&packet, &relayer);
+
+    // The acknowledgement is deferred, and the relayer recorded until then.
+    assert_eq!(ack, None);
+    assert_eq!(
+        stack.middleware().ctx().async_ack_relayers.get(&packet_id),
+        Some(&relayer)
+    );
+
+    let chan_end_on_b = ChannelEnd::new(
+        State::Open,
+        Order::Ordered,
+        Counterparty::new(
+            packet.port_id_on_a.clone(),
+            Some(packet.chan_id_on_a.clone()),
+        ),
+        vec![ConnectionId::zero()],
+        Version::new("ics20-1".to_string()),
+    )
+    .unwrap();
+
+    let mut ctx = MockContext::default()
+        .with_channel(PortId::transfer(), ChannelId::zero(), chan_end_on_b)
+        .with_recv_sequence(
+            packet.port_id_on_b.clone(),
+            packet.chan_id_on_b.clone(),
+            packet.seq_on_a.increment(),
+        );
+
+    let app_ack = Acknowledgement::try_from(vec![1u8]).unwrap();
+
+    stack
+        .middleware_mut()
+        .write_acknowledgement(&mut ctx, packet.clone(), app_ack.clone())
+        .unwrap();
+
+    // The acknowledgement written is wrapped with the recorded relayer.
+    let expected_ack: Acknowledgement = IncentivizedAcknowledgement {
+        app_acknowledgement: app_ack,
+        forward_relayer_address: relayer,
+        underlying_app_success: true,
+    }
+    .into();
+
+    let ack_path_on_b = AckPath::new(&packet.port_id_on_b, &packet.chan_id_on_b, packet.seq_on_a);
+    assert_eq!(
+        ctx.get_packet_acknowledgement(&ack_path_on_b).unwrap(),
+        compute_ack_commitment(&expected_ack)
+    );
+    assert!(stack
+        .middleware()
+        .ctx()
+        .async_ack_relayers
+        .get(&packet_id)
+        .is_none());
+}
@@ -1,4 +1,6 @@
 #[cfg(feature = "serde")]
+pub mod fee;
+#[cfg(feature = "serde")]
 pub mod nft_transfer;
 #[cfg(feature = "serde")]
 pub mod transfer;
@@ -1,13 +1,30 @@
+use core::str::FromStr;
+
+use ibc::apps::nft_transfer::class_trace::resolve_class_id;
+use ibc::apps::nft_transfer::context::{NftTransferExecutionContext, NftTransferValidationContext};
+use ibc::apps::nft_transfer::handler::{
+    process_recv_packet_execute, refund_packet_nft_execute, refund_packet_nft_validate,
+};
 use ibc::apps::nft_transfer::module::{
     on_chan_open_init_execute, on_chan_open_init_validate, on_chan_open_try_execute,
     on_chan_open_try_validate,
 };
-use ibc::apps::nft_transfer::types::VERSION;
+use ibc::apps::nft_transfer::types::error::NftTransferError;
+use ibc::apps::nft_transfer::types::packet::PacketData;
+use ibc::apps::nft_transfer::types::{
+    ClassData, ClassTraceHash, ClassUri, Memo, PrefixedClassId, TokenData, TokenId, TokenIds,
+    TokenUri, VERSION,
+};
 use ibc::core::channel::types::channel::{Counterparty, Order};
+use ibc::core::channel::types::packet::Packet;
+use ibc::core::channel::types::timeout::TimeoutHeight;
 use ibc::core::channel::types::Version;
-use ibc::core::host::types::identifiers::{ChannelId, ConnectionId, PortId};
+use ibc::core::host::types::identifiers::{ChannelId, ConnectionId, PortId, Sequence};
 use ibc::core::primitives::prelude::*;
-use ibc_testkit::testapp::ibc::applications::nft_transfer::types::DummyNftTransferModule;
+use ibc::core::primitives::{Signer, Timestamp};
+use ibc_testkit::testapp::ibc::applications::nft_transfer::types::{
+    DummyNft, DummyNftClass, DummyNftTransferModule,
+};
 
 fn get_defaults() -> (
     DummyNftTransferModule,
@@ -133,3 +150,602 @@ fn test_on_chan_open_try_counterparty_incorrect_version() {
 
     assert!(res.is_err());
 }
+
+const SENDER: &str = "sender";
+const RECEIVER: &str = "receiver";
+const URI: &str = "http://example.com";
+const DATA: &str = r#"{"name":{"value":"Crypto Creatures"}}"#;
+
+/// The holder of an NFT of the [`NftLedger`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum Holder {
+    Account(Signer),
+    Escrow(PortId, ChannelId),
+}
+
+/// An NFT transfer context which keeps track of the classes and of the
+/// holders of the NFTs, to check the state after the handlers ran.
+#[derive(Debug, Default)]
+struct NftLedger {
+    classes: BTreeMap<PrefixedClassId, (Option<ClassUri>, Option<ClassData>)>,
+    nfts: BTreeMap<(PrefixedClassId, TokenId), (Holder, Option<TokenUri>, Option<TokenData>)>,
+    class_traces: BTreeMap<ClassTraceHash, PrefixedClassId>,
+    /// The number of times a class was created or updated.
+    class_updates: usize,
+    /// The token whose minting fails validation, if any.
+    unmintable: Option<TokenId>,
+    /// The token whose minting the `before` hook rejects, if any.
+    vetoed: Option<TokenId>,
+    /// The hooks called, in order.
+    hook_calls: Vec<String>,
+}
+
+impl NftLedger {
+    fn holder(&self, class_id: &str, token_id: &str) -> Option<Holder> {
+        self.nfts
+            .get(&(class_id.parse().unwrap(), token_id.parse().unwrap()))
+            .map(|(holder, _, _)| holder.clone())
+    }
+
+    fn check_holder(
+        &self,
+        holder: Holder,
+        class_id: &PrefixedClassId,
+        token_id: &TokenId,
+    ) -> Result<(), NftTransferError> {
+        match self.nfts.get(&(class_id.clone(), token_id.clone())) {
+            Some((owner, _, _)) if *owner == holder => Ok(()),
+            Some(_) => Err(NftTransferError::InvalidOwner {
+                sender: format!("{holder:?}"),
+            }),
+            None => Err(NftTransferError::NftNotFound),
+        }
+    }
+
+    fn set_holder(&mut self, holder: Holder, class_id: &PrefixedClassId, token_id: &TokenId) {
+        if let Some(nft) = self.nfts.get_mut(&(class_id.clone(), token_id.clone())) {
+            nft.0 = holder;
+        }
+    }
+}
+
+impl NftTransferValidationContext for NftLedger {
+    type AccountId = Signer;
+    type Nft = DummyNft;
+    type NftClass = DummyNftClass;
+
+    fn get_port(&self) -> Result<PortId, NftTransferError> {
+        Ok(PortId::transfer())
+    }
+
+    fn can_send_nft(&self) -> Result<(), NftTransferError> {
+        Ok(())
+    }
+
+    fn can_receive_nft(&self) -> Result<(), NftTransferError> {
+        Ok(())
+    }
+
+    fn create_or_update_class_validate(
+        &self,
+        _class_id: &PrefixedClassId,
+        _class_uri: Option<&ClassUri>,
+        _class_data: Option<&ClassData>,
+    ) -> Result<(), NftTransferError> {
+        Ok(())
+    }
+
+    fn escrow_nft_validate(
+        &self,
+        from_account: &Self::AccountId,
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+        class_id: &PrefixedClassId,
+        token_id: &TokenId,
+        _memo: &Memo,
+    ) -> Result<(), NftTransferError> {
+        self.check_holder(Holder::Account(from_account.clone()), class_id, token_id)
+    }
+
+    fn unescrow_nft_validate(
+        &self,
+        _to_account: &Self::AccountId,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        class_id: &PrefixedClassId,
+        token_id: &TokenId,
+    ) -> Result<(), NftTransferError> {
+        self.check_holder(
+            Holder::Escrow(port_id.clone(), channel_id.clone()),
+            class_id,
+            token_id,
+        )
+    }
+
+    fn mint_nft_validate(
+        &self,
+        _account: &Self::AccountId,
+        class_id: &PrefixedClassId,
+        token_id: &TokenId,
+        _token_uri: Option<&TokenUri>,
+        _token_data: Option<&TokenData>,
+    ) -> Result<(), NftTransferError> {
+        if self.unmintable.as_ref() == Some(token_id)
+            || self
+                .nfts
+                .contains_key(&(class_id.clone(), token_id.clone()))
+        {
+            return Err(NftTransferError::InvalidTokenId);
+        }
+
+        Ok(())
+    }
+
+    fn burn_nft_validate(
+        &self,
+        account: &Self::AccountId,
+        class_id: &PrefixedClassId,
+        token_id: &TokenId,
+        _memo: &Memo,
+    ) -> Result<(), NftTransferError> {
+        self.check_holder(Holder::Account(account.clone()), class_id, token_id)
+    }
+
+    fn get_nft(
+        &self,
+        class_id: &PrefixedClassId,
+        token_id: &TokenId,
+    ) -> Result<Self::Nft, NftTransferError> {
+        let (_, token_uri, token_data) = self
+            .nfts
+            .get(&(class_id.clone(), token_id.clone()))
+            .ok_or(NftTransferError::NftNotFound)?;
+
+        Ok(DummyNft {
+            class_id: class_id.base_class_id.clone(),
+            token_id: token_id.clone(),
+            token_uri: token_uri.clone(),
+            token_data: token_data.clone(),
+        })
+    }
+
+    fn get_nft_class(
+        &self,
+        class_id: &PrefixedClassId,
+    ) -> Result<Self::NftClass, NftTransferError> {
+        let (class_uri, class_data) = self
+            .classes
+            .get(class_id)
+            .ok_or(NftTransferError::NftClassNotFound)?;
+
+        Ok(DummyNftClass {
+            class_id: class_id.base_class_id.clone(),
+            class_uri: class_uri.clone(),
+            class_data: class_data.clone(),
+        })
+    }
+
+    fn class_trace(
+        &self,
+        hash: &ClassTraceHash,
+    ) -> Result<Option<PrefixedClassId>, NftTransferError> {
+        Ok(self.class_traces.get(hash).cloned())
+    }
+}
+
+impl NftTransferExecutionContext for NftLedger {
+    fn create_or_update_class_execute(
+        &mut self,
+        class_id: &PrefixedClassId,
+        class_uri: Option<&ClassUri>,
+        class_data: Option<&ClassData>,
+    ) -> Result<(), NftTransferError> {
+        self.classes
+            .insert(class_id.clone(), (class_uri.cloned(), class_data.cloned()));
+        self.class_updates += 1;
+
+        Ok(())
+    }
+
+    fn escrow_nft_execute(
+        &mut self,
+        _from_account: &Self::AccountId,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        class_id: &PrefixedClassId,
+        token_id: &TokenId,
+        _memo: &Memo,
+    ) -> Result<(), NftTransferError> {
+        self.set_holder(
+            Holder::Escrow(port_id.clone(), channel_id.clone()),
+            class_id,
+            token_id,
+        );
+
+        Ok(())
+    }
+
+    fn unescrow_nft_execute(
+        &mut self,
+        to_account: &Self::AccountId,
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+        class_id: &PrefixedClassId,
+        token_id: &TokenId,
+    ) -> Result<(), NftTransferError> {
+        self.set_holder(Holder::Account(to_account.clone()), class_id, token_id);
+
+        Ok(())
+    }
+
+    fn mint_nft_execute(
+        &mut self,
+        account: &Self::AccountId,
+        class_id: &PrefixedClassId,
+        token_id: &TokenId,
+        token_uri: Option<&TokenUri>,
+        token_data: Option<&TokenData>,
+    ) -> Result<(), NftTransferError> {
+        self.nfts.insert(
+            (class_id.clone(), token_id.clone()),
+            (
+                Holder::Account(account.clone()),
+                token_uri.cloned(),
+                token_data.cloned(),
+            ),
+        );
+
+        Ok(())
+    }
+
+    fn burn_nft_execute(
+        &mut self,
+        _account: &Self::AccountId,
+        class_id: &PrefixedClassId,
+        token_id: &TokenId,
+        _memo: &Memo,
+    ) -> Result<(), NftTransferError> {
+        self.nfts.remove(&(class_id.clone(), token_id.clone()));
+
+        Ok(())
+    }
+
+    fn before_mint_nft_execute(
+        &mut self,
+        _account: &Self::AccountId,
+        _class_id: &PrefixedClassId,
+        token_id: &TokenId,
+        _token_uri: Option<&TokenUri>,
+        _token_data: Option<&TokenData>,
+    ) -> Result<(), NftTransferError> {
+        if self.vetoed.as_ref() == Some(token_id) {
+            return Err(NftTransferError::Other("vetoed".to_string()));
+        }
+
+        self.hook_calls.push(format!("before_mint {token_id}"));
+
+        Ok(())
+    }
+
+    fn after_mint_nft_execute(
+        &mut self,
+        _account: &Self::AccountId,
+        class_id: &PrefixedClassId,
+        token_id: &TokenId,
+        _token_uri: Option<&TokenUri>,
+        _token_data: Option<&TokenData>,
+    ) -> Result<(), NftTransferError> {
+        // The NFT is minted by the time the hook is called.
+        assert!(self
+            .nfts
+            .contains_key(&(class_id.clone(), token_id.clone())));
+
+        self.hook_calls.push(format!("after_mint {token_id}"));
+
+        Ok(())
+    }
+
+    fn before_unescrow_nft_execute(
+        &mut self,
+        _to_account: &Self::AccountId,
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+        _class_id: &PrefixedClassId,
+        token_id: &TokenId,
+    ) -> Result<(), NftTransferError> {
+        self.hook_calls.push(format!("before_unescrow {token_id}"));
+
+        Ok(())
+    }
+
+    fn after_unescrow_nft_execute(
+        &mut self,
+        _to_account: &Self::AccountId,
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+        _class_id: &PrefixedClassId,
+        token_id: &TokenId,
+    ) -> Result<(), NftTransferError> {
+        self.hook_calls.push(format!("after_unescrow {token_id}"));
+
+        Ok(())
+    }
+
+    fn store_class_trace(&mut self, class_id: &PrefixedClassId) -> Result<(), NftTransferError> {
+        self.class_traces
+            .insert(class_id.trace_hash(), class_id.clone());
+
+        Ok(())
+    }
+}
+
+/// Returns a packet from `transfer/channel-0` to `transfer/channel-1`.
+fn dummy_packet() -> Packet {
+    Packet {
+        seq_on_a: Sequence::from(1),
+        port_id_on_a: PortId::transfer(),
+        chan_id_on_a: ChannelId::zero(),
+        port_id_on_b: PortId::transfer(),
+        chan_id_on_b: ChannelId::new(1),
+        data: Vec::new(),
+        timeout_height_on_b: TimeoutHeight::Never,
+        timeout_timestamp_on_b: Timestamp::none(),
+    }
+}
+
+fn dummy_packet_data(class_id: &str, token_ids: &[&str]) -> PacketData {
+    PacketData::new(
+        class_id.parse().unwrap(),
+        Some(ClassUri::from_str(URI).unwrap()),
+        Some(ClassData::from_str(DATA).unwrap()),
+        TokenIds::try_from(
+            token_ids
+                .iter()
+                .map(|id| id.to_string())
+                .collect::<Vec<_>>(),
+        )
+        .unwrap(),
+        token_ids
+            .iter()
+            .map(|_| TokenUri::from_str(URI).unwrap())
+            .collect(),
+        token_ids
+            .iter()
+            .map(|_| TokenData::from_str(DATA).unwrap())
+            .collect(),
+        SENDER.to_string().into(),
+        RECEIVER.to_string().into(),
+        "".into(),
+    )
+    .unwrap()
+}
+
+#[test]
+fn test_recv_packet_mints_vouchers() {
+    let mut ctx = NftLedger::default();
+    let packet = dummy_packet();
+    let data = dummy_packet_data("class_0", &["token_0", "token_1"]);
+
+    let extras = process_recv_packet_execute(&mut ctx, &packet, data).unwrap();
+
+    // One trace event per token, and the voucher class is created once.
+    assert_eq!(extras.events.len(), 2);
+    assert_eq!(ctx.class_updates, 1);
+
+    let voucher_class = "transfer/channel-1/class_0";
+    assert!(ctx.classes.contains_key(&voucher_class.parse().unwrap()));
+
+    for token_id in ["token_0", "token_1"] {
+        assert_eq!(
+            ctx.holder(voucher_class, token_id),
+            Some(Holder::Account(RECEIVER.to_string().into()))
+        );
+    }
+
+    let nft = ctx
+        .get_nft(&voucher_class.parse().unwrap(), &"token_1".parse().unwrap())
+        .unwrap();
+    assert_eq!(nft.token_uri, Some(URI.parse().unwrap()));
+    assert_eq!(nft.token_data, Some(DATA.parse().unwrap()));
+}
+
+#[test]
+fn test_recv_packet_unescrows_returning_nfts() {
+    let mut ctx = NftLedger::default();
+    let escrow = Holder::Escrow(PortId::transfer(), ChannelId::new(1));
+
+    for token_id in ["token_0", "token_1"] {
+        ctx.nfts.insert(
+            ("class_0".parse().unwrap(), token_id.parse().unwrap()),
+            (escrow.clone(), None, None),
+        );
+    }
+
+    // The NFTs come back from the chain they were sent to.
+    let data = dummy_packet_data("transfer/channel-0/class_0", &["token_0", "token_1"]);
+
+    process_recv_packet_execute(&mut ctx, &dummy_packet(), data).unwrap();
+
+    assert_eq!(ctx.class_updates, 0);
+    for token_id in ["token_0", "token_1"] {
+        assert_eq!(
+            ctx.holder("class_0", token_id),
+            Some(Holder::Account(RECEIVER.to_string().into()))
+        );
+    }
+}
+
+#[test]
+fn test_recv_packet_is_atomic() {
+    // The second NFT cannot be minted, so that none is.
+    let mut ctx = NftLedger {
+        unmintable: Some("token_1".parse().unwrap()),
+        ..Default::default()
+    };
+    let data = dummy_packet_data("class_0", &["token_0", "token_1"]);
+
+    assert!(process_recv_packet_execute(&mut ctx, &dummy_packet(), data).is_err());
+    assert!(ctx.nfts.is_empty());
+    assert!(ctx.classes.is_empty());
+
+    // Only the first NFT is escrowed, so that none is unescrowed.
+    let mut ctx = NftLedger::default();
+    ctx.nfts.insert(
+        ("class_0".parse().unwrap(), "token_0".parse().unwrap()),
+        (
+            Holder::Escrow(PortId::transfer(), ChannelId::new(1)),
+            None,
+            None,
+        ),
+    );
+    let data = dummy_packet_data("transfer/channel-0/class_0", &["token_0", "token_1"]);
+
+    assert!(process_recv_packet_execute(&mut ctx, &dummy_packet(), data).is_err());
+    assert_eq!(
+        ctx.holder("class_0", "token_0"),
+        Some(Holder::Escrow(PortId::transfer(), ChannelId::new(1)))
+    );
+}
+
+#[test]
+fn test_refund_packet_nfts() {
+    let packet = dummy_packet();
+
+    // The escrowed NFTs of the host are returned to the sender.
+    let mut ctx = NftLedger::default();
+    ctx.nfts.insert(
+        ("class_0".parse().unwrap(), "token_0".parse().unwrap()),
+        (
+            Holder::Escrow(PortId::transfer(), ChannelId::zero()),
+            None,
+            None,
+        ),
+    );
+    let data = dummy_packet_data("class_0", &["token_0"]);
+
+    refund_packet_nft_validate(&ctx, &packet, &data).unwrap();
+    refund_packet_nft_execute(&mut ctx, &packet, &data).unwrap();
+
+    assert_eq!(
+        ctx.holder("class_0", "token_0"),
+        Some(Holder::Account(SENDER.to_string().into()))
+    );
+
+    // The burnt vouchers are minted back to the sender, with their metadata.
+    let mut ctx = NftLedger::default();
+    let voucher_class = "transfer/channel-0/class_0";
+    let data = dummy_packet_data(voucher_class, &["token_0"]);
+
+    refund_packet_nft_validate(&ctx, &packet, &data).unwrap();
+    refund_packet_nft_execute(&mut ctx, &packet, &data).unwrap();
+
+    assert_eq!(
+        ctx.holder(voucher_class, "token_0"),
+        Some(Holder::Account(SENDER.to_string().into()))
+    );
+    let nft = ctx
+        .get_nft(&voucher_class.parse().unwrap(), &"token_0".parse().unwrap())
+        .unwrap();
+    assert_eq!(nft.token_uri, Some(URI.parse().unwrap()));
+}
+
+#[test]
+fn test_recv_packet_keeps_token_data() {
+    let mut ctx = NftLedger::default();
+    let royalty_data = r#"{"royalty_payment_address":"cosmos1creator","royalty_percentage":5}"#;
+
+    // Only the first NFT has data, the second carrying an empty one.
+    let mut data = dummy_packet_data("class_0", &["token_0", "token_1"]);
+    data.token_data = Some(vec![
+        TokenData::from_str(royalty_data).unwrap(),
+        TokenData::default(),
+    ]);
+
+    process_recv_packet_execute(&mut ctx, &dummy_packet(), data).unwrap();
+
+    let voucher_class: PrefixedClassId = "transfer/channel-1/class_0".parse().unwrap();
+    let nft = ctx
+        .get_nft(&voucher_class, &"token_0".parse().unwrap())
+        .unwrap();
+    assert_eq!(nft.token_data.unwrap().to_string(), royalty_data);
+
+    let nft = ctx
+        .get_nft(&voucher_class, &"token_1".parse().unwrap())
+        .unwrap();
+    assert_eq!(nft.token_data, None);
+}
+
+#[test]
+fn test_recv_packet_stores_class_trace() {
+    let mut ctx = NftLedger::default();
+    let data = dummy_packet_data("class_0", &["token_0"]);
+
+    process_recv_packet_execute(&mut ctx, &dummy_packet(), data).unwrap();
+
+    let voucher_class: PrefixedClassId = "transfer/channel-1/class_0".parse().unwrap();
+    assert_eq!(
+        resolve_class_id(&ctx, &voucher_class.ibc_class_id()).unwrap(),
+        voucher_class
+    );
+
+    // The native class IDs are not hashed.
+    assert_eq!(
+        resolve_class_id(&ctx, "class_0").unwrap(),
+        "class_0".parse().unwrap()
+    );
+
+    // The class IDs of the NFTs not received are not resolved.
+    let unknown_class: PrefixedClassId = "transfer/channel-2/class_0".parse().unwrap();
+    assert!(matches!(
+        resolve_class_id(&ctx, &unknown_class.ibc_class_id()),
+        Err(NftTransferError::ClassTraceNotFound { .. })
+    ));
+}
+
+#[test]
+fn test_nft_hooks() {
+    let mut ctx = NftLedger::default();
+    let data = dummy_packet_data("class_0", &["token_0", "token_1"]);
+
+    process_recv_packet_execute(&mut ctx, &dummy_packet(), data).unwrap();
+
+    assert_eq!(
+        ctx.hook_calls,
+        [
+            "before_mint token_0",
+            "after_mint token_0",
+            "before_mint token_1",
+            "after_mint token_1",
+        ]
+    );
+
+    // The NFTs are unescrowed in between the hooks on refund.
+    let mut ctx = NftLedger::default();
+    ctx.nfts.insert(
+        ("class_0".parse().unwrap(), "token_0".parse().unwrap()),
+        (
+            Holder::Escrow(PortId::transfer(), ChannelId::zero()),
+            None,
+            None,
+        ),
+    );
+    let data = dummy_packet_data("class_0", &["token_0"]);
+
+    refund_packet_nft_execute(&mut ctx, &dummy_packet(), &data).unwrap();
+
+    assert_eq!(
+        ctx.hook_calls,
+        ["before_unescrow token_0", "after_unescrow token_0"]
+    );
+
+    // A failing `before` hook aborts the mint.
+    let mut ctx = NftLedger {
+        vetoed: Some("token_0".parse().unwrap()),
+        ..Default::default()
+    };
+    let data = dummy_packet_data("class_0", &["token_0"]);
+
+    assert!(process_recv_packet_execute(&mut ctx, &dummy_packet(), data).is_err());
+    assert!(ctx.nfts.is_empty());
+    assert!(ctx.hook_calls.is_empty());
+}
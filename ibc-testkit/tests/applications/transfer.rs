@@ -1,13 +1,39 @@
+use ibc::apps::transfer::context::{
+    RefundMode, TokenTransferExecutionContext, TokenTransferValidationContext,
+};
+use ibc::apps::transfer::decimals::to_packet_coin;
+use ibc::apps::transfer::forward::{
+    on_recv_packet_forward_execute, ForwardHook, ForwardedTransfer,
+};
+use ibc::apps::transfer::handler::{
+    decode_packet_data, encode_packet_data, escrow_coins_with_hooks,
+    process_multi_token_recv_packet_execute, refund_packet_tokens_execute,
+    refund_packet_tokens_validate,
+};
 use ibc::apps::transfer::module::{
-    on_chan_open_init_execute, on_chan_open_init_validate, on_chan_open_try_execute,
-    on_chan_open_try_validate,
+    on_chan_open_ack_validate, on_chan_open_init_execute, on_chan_open_init_validate,
+    on_chan_open_try_execute, on_chan_open_try_validate, on_recv_packet_execute,
+};
+use ibc::apps::transfer::policy::send_policy_validate;
+use ibc::apps::transfer::types::error::TokenTransferError;
+use ibc::apps::transfer::types::packet::PacketDataV2;
+use ibc::apps::transfer::types::{
+    ack_success_b64, Amount, DecimalConversion, DenomFilter, Memo, PrefixedCoin, PrefixedDenom,
+    TransferPolicy, VERSION, VERSION_V2,
+};
+use ibc::core::channel::types::acknowledgement::{
+    Acknowledgement, AcknowledgementStatus, StatusValue,
 };
-use ibc::apps::transfer::types::VERSION;
 use ibc::core::channel::types::channel::{Counterparty, Order};
+use ibc::core::channel::types::packet::Packet;
 use ibc::core::channel::types::Version;
 use ibc::core::host::types::identifiers::{ChannelId, ConnectionId, PortId};
 use ibc::core::primitives::prelude::*;
+use ibc::core::primitives::Signer;
+use ibc::core::router::types::module::ModuleExtras;
 use ibc::cosmos_host::utils::cosmos_adr028_escrow_address;
+use ibc_testkit::fixtures::core::channel::dummy_raw_packet;
+use ibc_testkit::fixtures::core::signer::dummy_account_id;
 use ibc_testkit::testapp::ibc::applications::transfer::types::DummyTransferModule;
 use subtle_encoding::bech32;
 
@@ -166,3 +192,775 @@ fn test_on_chan_open_try_counterparty_incorrect_version() {
 
     assert!(res.is_err());
 }
+
+/// If the relayer passed in the version of the multi-token transfers, then return it
+#[test]
+fn test_on_chan_open_init_ics20_v2_version() {
+    let (mut ctx, order, connection_hops, port_id, channel_id, counterparty) = get_defaults();
+
+    let in_version = Version::new(VERSION_V2.to_string());
+
+    assert!(on_chan_open_init_validate(
+        &ctx,
+        order,
+        &connection_hops,
+        &port_id,
+        &channel_id,
+        &counterparty,
+        &in_version,
+    )
+    .is_ok());
+
+    let (_, out_version) = on_chan_open_init_execute(
+        &mut ctx,
+        order,
+        &connection_hops,
+        &port_id,
+        &channel_id,
+        &counterparty,
+        &in_version,
+    )
+    .unwrap();
+
+    assert_eq!(out_version, in_version);
+}
+
+/// If the counterparty supports the multi-token transfers, then return their
+/// version, while still accepting the counterparties of version ics20-1
+#[test]
+fn test_on_chan_open_try_counterparty_ics20_v2_version() {
+    let (mut ctx, order, connection_hops, port_id, channel_id, counterparty) = get_defaults();
+
+    let counterparty_version = Version::new(VERSION_V2.to_string());
+
+    assert!(on_chan_open_try_validate(
+        &ctx,
+        order,
+        &connection_hops,
+        &port_id,
+        &channel_id,
+        &counterparty,
+        &counterparty_version,
+    )
+    .is_ok());
+
+    let (_, out_version) = on_chan_open_try_execute(
+        &mut ctx,
+        order,
+        &connection_hops,
+        &port_id,
+        &channel_id,
+        &counterparty,
+        &counterparty_version,
+    )
+    .unwrap();
+
+    assert_eq!(out_version, counterparty_version);
+
+    for version in [VERSION, VERSION_V2] {
+        assert!(on_chan_open_ack_validate(
+            &ctx,
+            &port_id,
+            &channel_id,
+            &Version::new(version.to_string())
+        )
+        .is_ok());
+    }
+}
+
+/// Receives a packet of several tokens, of which one is unescrowed and the
+/// other is minted as vouchers
+#[test]
+fn test_on_recv_packet_multiple_tokens() {
+    let mut ctx = DummyTransferModule;
+
+    let tokens: Vec<PrefixedCoin> = vec![
+        "10uatom".parse().unwrap(),
+        "20transfer/channel-0/uatom".parse().unwrap(),
+    ];
+    let packet_data = PacketDataV2 {
+        tokens,
+        sender: dummy_account_id(),
+        receiver: dummy_account_id(),
+        memo: "".to_string().into(),
+    };
+
+    let version = Version::new(VERSION_V2.to_string());
+    let mut packet: Packet = dummy_raw_packet(10, 0).try_into().unwrap();
+    packet.data = encode_packet_data(&version, packet_data).unwrap();
+
+    let (extras, ack) = on_recv_packet_execute(&mut ctx, &packet, &version);
+
+    assert_eq!(
+        ack,
+        Acknowledgement::from(AcknowledgementStatus::success(ack_success_b64()))
+    );
+
+    // A denomination trace event for the minted vouchers, and a receive event
+    // per token.
+    let kinds: Vec<_> = extras
+        .events
+        .iter()
+        .map(|event| event.kind.as_str())
+        .collect();
+    assert_eq!(
+        kinds,
+        [
+            "denomination_trace",
+            "fungible_token_packet",
+            "fungible_token_packet"
+        ]
+    );
+}
+
+/// The packets of several tokens are not sent over the channels of version
+/// ics20-1
+#[test]
+fn test_encode_multiple_tokens_ics20_v1_fails() {
+    let packet_data = PacketDataV2 {
+        tokens: vec!["10uatom".parse().unwrap(), "20ustake".parse().unwrap()],
+        sender: dummy_account_id(),
+        receiver: dummy_account_id(),
+        memo: "".to_string().into(),
+    };
+
+    assert!(encode_packet_data(&Version::new(VERSION.to_string()), packet_data).is_err());
+}
+
+/// The packets are decoded only as the packet data of the version of their
+/// channel
+#[test]
+fn test_decode_packet_data_of_channel_version() {
+    let v1 = Version::new(VERSION.to_string());
+    let v2 = Version::new(VERSION_V2.to_string());
+    let packet_data = PacketDataV2 {
+        tokens: vec!["10uatom".parse().unwrap()],
+        sender: dummy_account_id(),
+        receiver: dummy_account_id(),
+        memo: "".to_string().into(),
+    };
+
+    let bytes_v1 = encode_packet_data(&v1, packet_data.clone()).unwrap();
+    let bytes_v2 = encode_packet_data(&v2, packet_data.clone()).unwrap();
+
+    assert_eq!(decode_packet_data(&v1, &bytes_v1).unwrap(), packet_data);
+    assert_eq!(decode_packet_data(&v2, &bytes_v2).unwrap(), packet_data);
+    assert!(matches!(
+        decode_packet_data(&v1, &bytes_v2),
+        Err(TokenTransferError::PacketDataDeserialization)
+    ));
+    assert!(matches!(
+        decode_packet_data(&v2, &bytes_v1),
+        Err(TokenTransferError::PacketDataDeserialization)
+    ));
+}
+
+/// The tokens of the same denomination are not received twice
+#[test]
+fn test_on_recv_packet_duplicate_denom() {
+    let mut ctx = DummyTransferModule;
+
+    let version = Version::new(VERSION_V2.to_string());
+    let mut packet: Packet = dummy_raw_packet(10, 0).try_into().unwrap();
+    packet.data = br#"{"tokens":[{"denom":{"base":"uatom","trace":[]},"amount":"10"},{"denom":{"base":"uatom","trace":[]},"amount":"10"}],"sender":"cosmos1wxeyh7zgn4tctjzs0vtqpc6p5cxq5t2muzl7ng","receiver":"cosmos1wxeyh7zgn4tctjzs0vtqpc6p5cxq5t2muzl7ng"}"#.to_vec();
+
+    let (_, ack) = on_recv_packet_execute(&mut ctx, &packet, &version);
+
+    assert_eq!(
+        ack,
+        Acknowledgement::from(AcknowledgementStatus::error(
+            StatusValue::from_abci_error_code(
+                TokenTransferError::PacketDataDeserialization.ack_error_code()
+            )
+        ))
+    );
+}
+
+/// Records the transfers it forwards, or fails to forward them
+struct DummyForwardHook {
+    fail: bool,
+    forwarded: Vec<ForwardedTransfer>,
+}
+
+impl ForwardHook<DummyTransferModule> for DummyForwardHook {
+    fn intermediate_receiver(
+        &self,
+        _ctx: &DummyTransferModule,
+        _packet: &Packet,
+        _original_sender: &Signer,
+    ) -> Result<Signer, TokenTransferError> {
+        Ok(Signer::from("intermediate".to_string()))
+    }
+
+    fn forward_execute(
+        &mut self,
+        _ctx: &mut DummyTransferModule,
+        _packet: &Packet,
+        transfer: &ForwardedTransfer,
+    ) -> Result<ModuleExtras, TokenTransferError> {
+        if self.fail {
+            return Err(TokenTransferError::Other("forward failed".to_string()));
+        }
+
+        self.forwarded.push(transfer.clone());
+
+        Ok(ModuleExtras::empty())
+    }
+}
+
+fn v1() -> Version {
+    Version::new(VERSION.to_string())
+}
+
+fn forward_packet(memo: &str) -> Packet {
+    let packet_data = PacketDataV2 {
+        tokens: vec!["10uatom".parse().unwrap()],
+        sender: dummy_account_id(),
+        receiver: dummy_account_id(),
+        memo: memo.into(),
+    };
+
+    let mut packet: Packet = dummy_raw_packet(10, 0).try_into().unwrap();
+    packet.data = encode_packet_data(&v1(), packet_data).unwrap();
+    packet
+}
+
+/// The packets whose memo carries forward instructions are handed off to the
+/// hook, and acknowledged asynchronously
+#[test]
+fn test_on_recv_packet_forward() {
+    let mut ctx = DummyTransferModule;
+    let mut hook = DummyForwardHook {
+        fail: false,
+        forwarded: Vec::new(),
+    };
+
+    let memo =
+        r#"{"forward":{"receiver":"cosmos1receiver","port":"transfer","channel":"channel-1"}}"#;
+    let (_, ack) =
+        on_recv_packet_forward_execute(&mut ctx, &mut hook, &forward_packet(memo), &v1());
+
+    assert_eq!(ack, None);
+    assert_eq!(hook.forwarded.len(), 1);
+
+    // The tokens are received by the intermediate account, as denominated on
+    // the host.
+    let forwarded = &hook.forwarded[0];
+    assert_eq!(
+        forwarded.intermediate_receiver,
+        Signer::from("intermediate".to_string())
+    );
+    assert_eq!(
+        forwarded.tokens,
+        vec!["10transfer/channel-0/uatom"
+            .parse::<PrefixedCoin>()
+            .unwrap()]
+    );
+    assert_eq!(forwarded.memo.as_ref(), memo);
+
+    // The other packets are received as usual.
+    let (_, ack) =
+        on_recv_packet_forward_execute(&mut ctx, &mut hook, &forward_packet("hello"), &v1());
+
+    assert_eq!(
+        ack,
+        Some(Acknowledgement::from(AcknowledgementStatus::success(
+            ack_success_b64()
+        )))
+    );
+    assert_eq!(hook.forwarded.len(), 1);
+}
+
+/// The packets the hook fails to forward are acknowledged with an error
+#[test]
+fn test_on_recv_packet_forward_fails() {
+    let mut ctx = DummyTransferModule;
+    let mut hook = DummyForwardHook {
+        fail: true,
+        forwarded: Vec::new(),
+    };
+
+    let memo =
+        r#"{"forward":{"receiver":"cosmos1receiver","port":"transfer","channel":"channel-1"}}"#;
+    let (_, ack) =
+        on_recv_packet_forward_execute(&mut ctx, &mut hook, &forward_packet(memo), &v1());
+
+    // The error of the hook is an internal error.
+    assert_eq!(
+        ack,
+        Some(Acknowledgement::from(AcknowledgementStatus::error(
+            StatusValue::from_abci_error_code(1)
+        )))
+    );
+}
+
+/// Records the moves of the tokens and the hooks called around them, and
+/// fails the mints if instructed to
+#[derive(Debug, Default)]
+struct HookRecorder {
+    fail_before_mint: bool,
+    refund_mode: RefundMode,
+    transfer_policy: TransferPolicy,
+    total_escrows: Option<BTreeMap<PrefixedDenom, Amount>>,
+    decimal_conversion: Option<DecimalConversion>,
+    calls: Vec<&'static str>,
+}
+
+impl TokenTransferValidationContext for HookRecorder {
+    type AccountId = Signer;
+
+    fn get_port(&self) -> Result<PortId, TokenTransferError> {
+        Ok(PortId::transfer())
+    }
+
+    fn can_send_coins(&self) -> Result<(), TokenTransferError> {
+        Ok(())
+    }
+
+    fn can_receive_coins(&self) -> Result<(), TokenTransferError> {
+        Ok(())
+    }
+
+    fn transfer_policy(
+        &self,
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+    ) -> Result<TransferPolicy, TokenTransferError> {
+        Ok(self.transfer_policy.clone())
+    }
+
+    fn tracks_total_escrow(&self) -> bool {
+        self.total_escrows.is_some()
+    }
+
+    fn total_escrow(&self, denom: &PrefixedDenom) -> Result<Amount, TokenTransferError> {
+        Ok(self
+            .total_escrows
+            .as_ref()
+            .and_then(|total_escrows| total_escrows.get(denom).copied())
+            .unwrap_or_else(Amount::zero))
+    }
+
+    fn decimal_conversion(
+        &self,
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+        _denom: &PrefixedDenom,
+    ) -> Result<Option<DecimalConversion>, TokenTransferError> {
+        Ok(self.decimal_conversion)
+    }
+
+    fn refund_mode(
+        &self,
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+    ) -> Result<RefundMode, TokenTransferError> {
+        Ok(self.refund_mode)
+    }
+
+    fn burn_escrowed_coins_validate(
+        &self,
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+        _coin: &PrefixedCoin,
+    ) -> Result<(), TokenTransferError> {
+        Ok(())
+    }
+
+    fn escrow_coins_validate(
+        &self,
+        _from_account: &Self::AccountId,
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+        _coin: &PrefixedCoin,
+        _memo: &Memo,
+    ) -> Result<(), TokenTransferError> {
+        Ok(())
+    }
+
+    fn unescrow_coins_validate(
+        &self,
+        _to_account: &Self::AccountId,
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+        _coin: &PrefixedCoin,
+    ) -> Result<(), TokenTransferError> {
+        Ok(())
+    }
+
+    fn mint_coins_validate(
+        &self,
+        _account: &Self::AccountId,
+        _coin: &PrefixedCoin,
+    ) -> Result<(), TokenTransferError> {
+        Ok(())
+    }
+
+    fn burn_coins_validate(
+        &self,
+        _account: &Self::AccountId,
+        _coin: &PrefixedCoin,
+        _memo: &Memo,
+    ) -> Result<(), TokenTransferError> {
+        Ok(())
+    }
+}
+
+impl TokenTransferExecutionContext for HookRecorder {
+    fn escrow_coins_execute(
+        &mut self,
+        _from_account: &Self::AccountId,
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+        _coin: &PrefixedCoin,
+        _memo: &Memo,
+    ) -> Result<(), TokenTransferError> {
+        self.calls.push("escrow");
+        Ok(())
+    }
+
+    fn unescrow_coins_execute(
+        &mut self,
+        _to_account: &Self::AccountId,
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+        _coin: &PrefixedCoin,
+    ) -> Result<(), TokenTransferError> {
+        self.calls.push("unescrow");
+        Ok(())
+    }
+
+    fn mint_coins_execute(
+        &mut self,
+        _account: &Self::AccountId,
+        _coin: &PrefixedCoin,
+    ) -> Result<(), TokenTransferError> {
+        self.calls.push("mint");
+        Ok(())
+    }
+
+    fn burn_coins_execute(
+        &mut self,
+        _account: &Self::AccountId,
+        _coin: &PrefixedCoin,
+        _memo: &Memo,
+    ) -> Result<(), TokenTransferError> {
+        self.calls.push("burn");
+        Ok(())
+    }
+
+    fn store_total_escrow(
+        &mut self,
+        denom: &PrefixedDenom,
+        amount: Amount,
+    ) -> Result<(), TokenTransferError> {
+        if let Some(total_escrows) = &mut self.total_escrows {
+            total_escrows.insert(denom.clone(), amount);
+        }
+        Ok(())
+    }
+
+    fn burn_escrowed_coins_execute(
+        &mut self,
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+        _coin: &PrefixedCoin,
+    ) -> Result<(), TokenTransferError> {
+        self.calls.push("burn_escrowed");
+        Ok(())
+    }
+
+    fn before_unescrow_coins_execute(
+        &mut self,
+        _to_account: &Self::AccountId,
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+        _coin: &PrefixedCoin,
+    ) -> Result<(), TokenTransferError> {
+        self.calls.push("before_unescrow");
+        Ok(())
+    }
+
+    fn after_unescrow_coins_execute(
+        &mut self,
+        _to_account: &Self::AccountId,
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+        _coin: &PrefixedCoin,
+    ) -> Result<(), TokenTransferError> {
+        self.calls.push("after_unescrow");
+        Ok(())
+    }
+
+    fn before_mint_coins_execute(
+        &mut self,
+        _account: &Self::AccountId,
+        _coin: &PrefixedCoin,
+    ) -> Result<(), TokenTransferError> {
+        if self.fail_before_mint {
+            return Err(TokenTransferError::Other("mint disabled".to_string()));
+        }
+
+        self.calls.push("before_mint");
+        Ok(())
+    }
+
+    fn after_mint_coins_execute(
+        &mut self,
+        _account: &Self::AccountId,
+        _coin: &PrefixedCoin,
+    ) -> Result<(), TokenTransferError> {
+        self.calls.push("after_mint");
+        Ok(())
+    }
+}
+
+/// The hooks of the host are called around each move of the received tokens,
+/// and abort the move when failing
+#[test]
+fn test_recv_packet_token_hooks() {
+    let packet_data = PacketDataV2 {
+        tokens: vec![
+            "10uatom".parse().unwrap(),
+            "20transfer/channel-0/uatom".parse().unwrap(),
+        ],
+        sender: dummy_account_id(),
+        receiver: dummy_account_id(),
+        memo: "".to_string().into(),
+    };
+    let packet: Packet = dummy_raw_packet(10, 0).try_into().unwrap();
+
+    let mut ctx = HookRecorder::default();
+    process_multi_token_recv_packet_execute(&mut ctx, &packet, packet_data.clone()).unwrap();
+
+    assert_eq!(
+        ctx.calls,
+        [
+            "before_mint",
+            "mint",
+            "after_mint",
+            "before_unescrow",
+            "unescrow",
+            "after_unescrow"
+        ]
+    );
+
+    let mut ctx = HookRecorder {
+        fail_before_mint: true,
+        ..Default::default()
+    };
+    assert!(process_multi_token_recv_packet_execute(&mut ctx, &packet, packet_data).is_err());
+    assert!(ctx.calls.is_empty());
+}
+
+/// The escrowed tokens of the non-refundable packets are burnt, and their
+/// vouchers are not minted back
+#[test]
+fn test_refund_packet_tokens_non_refundable() {
+    fn refund_calls(refund_mode: RefundMode, memo: &str) -> Vec<&'static str> {
+        let packet_data = PacketDataV2 {
+            tokens: vec![
+                "10uatom".parse().unwrap(),
+                "20transfer/channel-0/uatom".parse().unwrap(),
+            ],
+            sender: dummy_account_id(),
+            receiver: dummy_account_id(),
+            memo: memo.to_string().into(),
+        };
+        let packet: Packet = dummy_raw_packet(10, 0).try_into().unwrap();
+
+        let mut ctx = HookRecorder {
+            refund_mode,
+            ..Default::default()
+        };
+        refund_packet_tokens_validate(&ctx, &packet, &packet_data).unwrap();
+        refund_packet_tokens_execute(&mut ctx, &packet, &packet_data).unwrap();
+
+        ctx.calls
+    }
+
+    let refunded = [
+        "before_unescrow",
+        "unescrow",
+        "after_unescrow",
+        "before_mint",
+        "mint",
+        "after_mint",
+    ];
+    let non_refundable_memo = r#"{"non_refundable":true}"#;
+
+    // The `10uatom` were escrowed by transfer/channel-0, and the
+    // `20transfer/channel-0/uatom` vouchers burnt.
+    assert_eq!(
+        refund_calls(RefundMode::Refund, non_refundable_memo),
+        refunded
+    );
+    assert_eq!(refund_calls(RefundMode::Burn, ""), ["burn_escrowed"]);
+    assert_eq!(
+        refund_calls(RefundMode::Memo, non_refundable_memo),
+        ["burn_escrowed"]
+    );
+    assert_eq!(refund_calls(RefundMode::Memo, "a plain memo"), refunded);
+}
+
+/// The tokens are sent and received only if the transfer policy of the channel
+/// allows their denominations on the host
+#[test]
+fn test_transfer_policy() {
+    let packet_data = PacketDataV2 {
+        tokens: vec![
+            "10uatom".parse().unwrap(),
+            "20transfer/channel-0/uatom".parse().unwrap(),
+        ],
+        sender: dummy_account_id(),
+        receiver: dummy_account_id(),
+        memo: "".to_string().into(),
+    };
+    let packet: Packet = dummy_raw_packet(10, 0).try_into().unwrap();
+
+    // The `20transfer/channel-0/uatom` are received as `uatom`.
+    let mut ctx = HookRecorder {
+        transfer_policy: TransferPolicy {
+            send: DenomFilter::Allow(vec!["uatom".parse().unwrap()]),
+            receive: DenomFilter::Deny(vec!["uatom".parse().unwrap()]),
+        },
+        ..Default::default()
+    };
+    assert!(matches!(
+        process_multi_token_recv_packet_execute(&mut ctx, &packet, packet_data.clone()),
+        Err((_, TokenTransferError::DenomReceiveNotAllowed { .. }))
+    ));
+    assert!(ctx.calls.is_empty());
+
+    ctx.transfer_policy.receive = DenomFilter::Allow(vec![
+        "uatom".parse().unwrap(),
+        "transfer/channel-0/uatom".parse().unwrap(),
+    ]);
+    process_multi_token_recv_packet_execute(&mut ctx, &packet, packet_data.clone()).unwrap();
+
+    // Only the `uatom` can be sent.
+    let port_id = PortId::transfer();
+    let channel_id = ChannelId::zero();
+    assert!(send_policy_validate(&ctx, &port_id, &channel_id, &packet_data.tokens[..1]).is_ok());
+    assert!(matches!(
+        send_policy_validate(&ctx, &port_id, &channel_id, &packet_data.tokens),
+        Err(TokenTransferError::DenomSendNotAllowed { .. })
+    ));
+}
+
+/// The unescrowed tokens are subtracted from the tracked total escrow, and
+/// the packets of more tokens than it are rejected with an error
+/// acknowledgement before any of them is unescrowed
+#[test]
+fn test_recv_packet_total_escrow() {
+    let packet_data = PacketDataV2 {
+        tokens: vec!["20transfer/channel-0/uatom".parse().unwrap()],
+        sender: dummy_account_id(),
+        receiver: dummy_account_id(),
+        memo: "".to_string().into(),
+    };
+    let version = Version::new(VERSION_V2.to_string());
+    let mut packet: Packet = dummy_raw_packet(10, 0).try_into().unwrap();
+    packet.data = encode_packet_data(&version, packet_data).unwrap();
+    let uatom: PrefixedDenom = "uatom".parse().unwrap();
+
+    let mut ctx = HookRecorder {
+        total_escrows: Some([(uatom.clone(), Amount::from(30))].into()),
+        ..Default::default()
+    };
+    let (_, ack) = on_recv_packet_execute(&mut ctx, &packet, &version);
+    assert_eq!(
+        ack,
+        Acknowledgement::from(AcknowledgementStatus::success(ack_success_b64()))
+    );
+    assert_eq!(ctx.total_escrow(&uatom).unwrap(), Amount::from(10));
+    assert_eq!(ctx.calls, ["before_unescrow", "unescrow", "after_unescrow"]);
+
+    ctx.calls.clear();
+    let (_, ack) = on_recv_packet_execute(&mut ctx, &packet, &version);
+    assert_eq!(
+        ack,
+        Acknowledgement::from(AcknowledgementStatus::error(
+            StatusValue::from_abci_error_code(
+                TokenTransferError::TotalEscrowUnderflow {
+                    denom: uatom.clone(),
+                    amount: Amount::from(20),
+                }
+                .ack_error_code()
+            )
+        ))
+    );
+    assert_eq!(ctx.total_escrow(&uatom).unwrap(), Amount::from(10));
+    assert!(ctx.calls.is_empty());
+}
+
+/// The first escrow of a denomination starts its tracked total escrow, which
+/// is not stored yet
+#[test]
+fn test_escrow_first_total_escrow() {
+    let coin: PrefixedCoin = "20uatom".parse().unwrap();
+
+    let mut ctx = HookRecorder {
+        total_escrows: Some(BTreeMap::new()),
+        ..Default::default()
+    };
+    escrow_coins_with_hooks(
+        &mut ctx,
+        &dummy_account_id(),
+        &PortId::transfer(),
+        &ChannelId::zero(),
+        &coin,
+        &"".to_string().into(),
+    )
+    .unwrap();
+    assert_eq!(ctx.total_escrow(&coin.denom).unwrap(), Amount::from(20));
+}
+
+/// The amounts of the packets are converted to the precision of the host,
+/// rounding down, and the remainders of the tokens sent stay with the sender
+#[test]
+fn test_transfer_decimal_conversion() {
+    let packet_data = PacketDataV2 {
+        tokens: vec!["1500000000000000001transfer/channel-0/uatom"
+            .parse()
+            .unwrap()],
+        sender: dummy_account_id(),
+        receiver: dummy_account_id(),
+        memo: "".to_string().into(),
+    };
+    let packet: Packet = dummy_raw_packet(10, 0).try_into().unwrap();
+    let uatom: PrefixedDenom = "uatom".parse().unwrap();
+
+    let mut ctx = HookRecorder {
+        total_escrows: Some([(uatom.clone(), Amount::from(2_000_000))].into()),
+        decimal_conversion: Some(DecimalConversion {
+            host_decimals: 6,
+            packet_decimals: 18,
+        }),
+        ..Default::default()
+    };
+    process_multi_token_recv_packet_execute(&mut ctx, &packet, packet_data).unwrap();
+    assert_eq!(ctx.total_escrow(&uatom).unwrap(), Amount::from(500_000));
+
+    let port_id = PortId::transfer();
+    let channel_id = ChannelId::zero();
+    ctx.decimal_conversion = Some(DecimalConversion {
+        host_decimals: 18,
+        packet_decimals: 6,
+    });
+
+    let (packet_coin, moved_coin) = to_packet_coin(
+        &ctx,
+        &port_id,
+        &channel_id,
+        &"1500000000000000001uatom".parse().unwrap(),
+    )
+    .unwrap();
+    assert_eq!(packet_coin, "1500000uatom".parse().unwrap());
+    assert_eq!(moved_coin, "1500000000000000000uatom".parse().unwrap());
+
+    assert!(matches!(
+        to_packet_coin(&ctx, &port_id, &channel_id, &"999uatom".parse().unwrap()),
+        Err(TokenTransferError::ZeroConvertedAmount { .. })
+    ));
+}
@@ -0,0 +1,88 @@
+use core::fmt::Debug;
+
+use borsh::{BorshDeserialize, BorshSerialize};
+#[cfg(feature = "serde")]
+use ibc::clients::tendermint::consensus_state::ConsensusState as TmConsensusState;
+use ibc::core::channel::types::channel::Order;
+use ibc::core::channel::types::Version as ChannelVersion;
+use ibc::core::host::types::identifiers::PortId;
+use ibc::core::host::types::path::{ChannelEndPath, ChannelUpgradePath};
+use ibc::core::host::ValidationContext;
+#[cfg(feature = "serde")]
+use ibc_testkit::fixtures::clients::tendermint::{
+    dummy_tendermint_header, dummy_tm_client_state_from_header,
+};
+use ibc_testkit::fixtures::core::context::mock_chain;
+use ibc_testkit::relayer::harness::RelayerHarness;
+use test_log::test;
+
+#[track_caller]
+fn assert_borsh_roundtrip<T>(value: &T)
+where
+    T: BorshSerialize + BorshDeserialize + Debug + PartialEq,
+{
+    let serialized = borsh::to_vec(value).unwrap();
+
+    assert_eq!(&T::try_from_slice(&serialized).unwrap(), value);
+}
+
+#[test]
+fn host_stored_types_roundtrip() {
+    let mut harness = RelayerHarness::new()
+        .with_chain(mock_chain("mockgaiaA-1"))
+        .with_chain(mock_chain("mockgaiaB-1"));
+
+    let connection = harness.open_connection(0, 1).unwrap();
+    let channel = harness
+        .open_channel(
+            &connection,
+            PortId::transfer(),
+            ChannelVersion::new("ics20-1".to_string()),
+        )
+        .unwrap();
+
+    let packet = harness.send_packet(&channel, b"ping".to_vec()).unwrap();
+    harness.relay_packet(&channel, &packet).unwrap();
+
+    harness
+        .upgrade_init(
+            &channel,
+            Order::Unordered,
+            ChannelVersion::new("ics20-2".to_string()),
+        )
+        .unwrap();
+
+    let ctx_a = harness.chain(0);
+
+    assert_borsh_roundtrip(&ctx_a.connection_end(&connection.conn_id_on_a).unwrap());
+    assert_borsh_roundtrip(
+        &ctx_a
+            .channel_end(&ChannelEndPath::new(
+                &channel.port_id_on_a,
+                &channel.chan_id_on_a,
+            ))
+            .unwrap(),
+    );
+    assert_borsh_roundtrip(
+        &ctx_a
+            .channel_upgrade(&ChannelUpgradePath::new(
+                &channel.port_id_on_a,
+                &channel.chan_id_on_a,
+            ))
+            .unwrap(),
+    );
+    assert_borsh_roundtrip(&packet);
+
+    for event in ctx_a.get_events() {
+        assert_borsh_roundtrip(&event);
+    }
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn tendermint_states_roundtrip() {
+    let header = dummy_tendermint_header();
+
+    assert_borsh_roundtrip(&dummy_tm_client_state_from_header(header.clone()));
+    assert_borsh_roundtrip(&TmConsensusState::from(header));
+}
@@ -0,0 +1,65 @@
+use ibc::core::commitment_types::commitment::CommitmentPrefix;
+use ibc::core::connection::types::{ConnectionEnd, Counterparty, State};
+use ibc::core::host::types::identifiers::{ClientId, ConnectionId};
+use ibc::core::host::types::path::ConnectionPath;
+use ibc::core::host::{
+    BlockCache, ExecutionContext, OverlayContext, TransactionalExecutionContext, ValidationContext,
+};
+use ibc::core::primitives::ZERO_DURATION;
+use ibc_testkit::testapp::ibc::core::types::MockContext;
+use test_log::test;
+
+fn conn_end(state: State) -> ConnectionEnd {
+    ConnectionEnd::new(
+        state,
+        ClientId::new("07-tendermint", 0).unwrap(),
+        Counterparty::new(
+            ClientId::new("07-tendermint", 0).unwrap(),
+            None,
+            CommitmentPrefix::try_from(b"ibc".to_vec()).unwrap(),
+        ),
+        ValidationContext::get_compatible_versions(&MockContext::default()),
+        ZERO_DURATION,
+    )
+    .unwrap()
+}
+
+#[test]
+fn test_cache_serves_reads_until_written() {
+    let conn_id = ConnectionId::new(0);
+    let mut ctx = BlockCache::new(
+        MockContext::default().with_connection(conn_id.clone(), conn_end(State::Init)),
+    );
+
+    assert_eq!(ctx.connection_end(&conn_id).unwrap(), conn_end(State::Init));
+
+    // The cached end is read even though the host state changed underneath.
+    ctx.inner()
+        .ibc_store
+        .lock()
+        .connections
+        .insert(conn_id.clone(), conn_end(State::TryOpen));
+    assert_eq!(ctx.connection_end(&conn_id).unwrap(), conn_end(State::Init));
+
+    ctx.store_connection(&ConnectionPath::new(&conn_id), conn_end(State::Open))
+        .unwrap();
+
+    assert_eq!(ctx.connection_end(&conn_id).unwrap(), conn_end(State::Open));
+}
+
+#[test]
+fn test_rollback_invalidates_cache() {
+    let conn_id = ConnectionId::new(0);
+    let mut ctx = BlockCache::new(OverlayContext::new(
+        MockContext::default().with_connection(conn_id.clone(), conn_end(State::Init)),
+    ));
+
+    ctx.begin_transaction();
+    ctx.store_connection(&ConnectionPath::new(&conn_id), conn_end(State::Open))
+        .unwrap();
+    assert_eq!(ctx.connection_end(&conn_id).unwrap(), conn_end(State::Open));
+
+    ctx.rollback_transaction();
+
+    assert_eq!(ctx.connection_end(&conn_id).unwrap(), conn_end(State::Init));
+}
@@ -0,0 +1,106 @@
+//! Cross-checks the canonical encoding of the values proven to the
+//! counterparty against their encoding by ibc-go.
+
+use ibc::core::channel::types::channel::{
+    ChannelEnd, Counterparty as ChannelCounterparty, Order, State as ChannelState,
+};
+use ibc::core::channel::types::upgrade::{ErrorReceipt, Upgrade, UpgradeFields};
+use ibc::core::channel::types::Version as ChannelVersion;
+use ibc::core::commitment_types::commitment::CommitmentPrefix;
+use ibc::core::connection::types::version::Version as ConnectionVersion;
+use ibc::core::connection::types::{
+    ConnectionEnd, Counterparty as ConnectionCounterparty, State as ConnectionState,
+};
+use ibc::core::host::types::identifiers::{ChannelId, ClientId, ConnectionId, PortId};
+use ibc::core::primitives::prelude::*;
+use ibc::core::primitives::ZERO_DURATION;
+use ibc::primitives::proto::Protobuf;
+use ibc::primitives::{is_canonical, CanonicalProtobuf};
+use subtle_encoding::hex;
+use test_log::test;
+
+/// Asserts the canonical encoding of a value is the hex-encoded encoding of
+/// ibc-go, and the one of `Protobuf::encode_vec`.
+fn assert_encodes_as(canonical: Vec<u8>, encoded: Vec<u8>, expected: &str) {
+    assert_eq!(
+        String::from_utf8(hex::encode(&canonical)).unwrap(),
+        expected
+    );
+    assert!(is_canonical(&canonical));
+    assert_eq!(canonical, encoded);
+}
+
+fn connection_0() -> ConnectionId {
+    ConnectionId::new(0)
+}
+
+#[test]
+fn test_channel_end_encoding() {
+    let chan_end = ChannelEnd::new(
+        ChannelState::Open,
+        Order::Unordered,
+        ChannelCounterparty::new(PortId::transfer(), Some(ChannelId::new(1))),
+        vec![connection_0()],
+        ChannelVersion::new("ics20-1".to_string()),
+    )
+    .unwrap();
+
+    assert_encodes_as(
+        chan_end.clone().encode_vec_canonical(),
+        chan_end.encode_vec(),
+        "080310011a150a087472616e7366657212096368616e6e656c2d31\
+         220c636f6e6e656374696f6e2d302a0769637332302d31",
+    );
+}
+
+#[test]
+fn test_connection_end_encoding() {
+    let conn_end = ConnectionEnd::new(
+        ConnectionState::Open,
+        ClientId::new("07-tendermint", 0).unwrap(),
+        ConnectionCounterparty::new(
+            ClientId::new("07-tendermint", 1).unwrap(),
+            Some(ConnectionId::new(1)),
+            CommitmentPrefix::try_from(b"ibc".to_vec()).unwrap(),
+        ),
+        ConnectionVersion::compatibles(),
+        ZERO_DURATION,
+    )
+    .unwrap();
+
+    // The zero delay period is omitted
+    assert_encodes_as(
+        conn_end.clone().encode_vec_canonical(),
+        conn_end.encode_vec(),
+        "0a0f30372d74656e6465726d696e742d3012230a0131120d4f524445525f4f52444552\
+         4544120f4f524445525f554e4f524445524544180322260a0f30372d74656e6465726d\
+         696e742d31120c636f6e6e656374696f6e2d311a050a03696263",
+    );
+}
+
+#[test]
+fn test_upgrade_encoding() {
+    let upgrade = Upgrade::new(UpgradeFields::new(
+        Order::Unordered,
+        vec![connection_0()],
+        ChannelVersion::new("ics20-1".to_string()),
+    ));
+
+    // The unset timeout keeps its non-nullable, empty height
+    assert_encodes_as(
+        upgrade.clone().encode_vec_canonical(),
+        upgrade.encode_vec(),
+        "0a190801120c636f6e6e656374696f6e2d301a0769637332302d3112020a00",
+    );
+}
+
+#[test]
+fn test_error_receipt_encoding() {
+    let receipt = ErrorReceipt::new(1, "msg".to_string());
+
+    assert_encodes_as(
+        receipt.clone().encode_vec_canonical(),
+        receipt.encode_vec(),
+        "080112036d7367",
+    );
+}
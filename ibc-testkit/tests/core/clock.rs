@@ -0,0 +1,108 @@
+use core::time::Duration;
+
+use ibc::clients::tendermint::types::client_type as tm_client_type;
+use ibc::core::client::types::Height;
+use ibc::core::host::types::identifiers::ChainId;
+use ibc::core::host::HostClock;
+use ibc::core::primitives::Timestamp;
+use ibc_testkit::fixtures::core::context::{mock_chain, mock_chain_at};
+use ibc_testkit::relayer::harness::RelayerHarness;
+use ibc_testkit::testapp::ibc::clients::mock::behaviour::MockClientBehaviour;
+use ibc_testkit::testapp::ibc::clients::mock::client_state::client_type as mock_client_type;
+use ibc_testkit::testapp::ibc::core::types::MockClientConfig;
+use test_log::test;
+
+#[test]
+fn advance_host_chain_time() {
+    let mut ctx = mock_chain("mockgaiaA-1");
+
+    let height = ctx.latest_height();
+    let timestamp = ctx.host_timestamp().unwrap();
+
+    ctx.advance_host_chain_time(Duration::from_secs(3600));
+
+    assert_eq!(ctx.latest_height(), height.increment());
+    assert_eq!(
+        ctx.host_timestamp().unwrap(),
+        (timestamp + Duration::from_secs(3600)).unwrap()
+    );
+}
+
+#[test]
+#[should_panic(expected = "is not after the latest block")]
+fn advance_host_chain_time_to_the_past() {
+    let timestamp = Timestamp::now();
+
+    let mut ctx = mock_chain_at("mockgaiaA-1", timestamp);
+
+    ctx.advance_host_chain_time_to(timestamp);
+}
+
+#[test]
+fn clients_expire_past_the_trusting_period() {
+    let client_id = tm_client_type().build_client_id(0);
+
+    let timestamp = Timestamp::now();
+
+    let mut ctx = mock_chain_at("mockgaiaA-1", timestamp).with_client_config(
+        MockClientConfig::builder()
+            .client_chain_id(ChainId::new("mockgaiaB-1").unwrap())
+            .client_id(client_id.clone())
+            .latest_height(Height::new(1, 20).unwrap())
+            .client_type(tm_client_type())
+            .latest_timestamp(timestamp)
+            .trusting_period(Duration::from_secs(64))
+            .build(),
+    );
+
+    ctx.assert_client_active(&client_id);
+
+    ctx.advance_past_trusting_period(&client_id);
+
+    ctx.assert_client_expired(&client_id);
+}
+
+#[test]
+fn clients_freeze_at_the_scripted_time() {
+    let client_id = mock_client_type().build_client_id(0);
+
+    let timestamp = Timestamp::now();
+
+    let mut ctx = mock_chain_at("mockgaiaA-1", timestamp).with_client_config(
+        MockClientConfig::builder()
+            .client_chain_id(ChainId::new("mockgaiaB-1").unwrap())
+            .client_id(client_id.clone())
+            .latest_height(Height::new(1, 20).unwrap())
+            .latest_timestamp(timestamp)
+            .behaviour(
+                MockClientBehaviour::default()
+                    .frozen_at((timestamp + Duration::from_secs(600)).unwrap()),
+            )
+            .build(),
+    );
+
+    ctx.assert_client_active(&client_id);
+
+    ctx.advance_host_chain_time(Duration::from_secs(600));
+
+    ctx.assert_client_frozen(&client_id);
+}
+
+#[test]
+fn clocks_drift_between_chains() {
+    let timestamp = Timestamp::now();
+
+    let mut harness = RelayerHarness::new()
+        .with_chain(mock_chain_at("mockgaiaA-1", timestamp))
+        .with_chain(mock_chain_at("mockgaiaB-1", timestamp));
+
+    let drift = Duration::from_secs(30);
+
+    harness.set_clock_drift(1, 0, drift);
+    harness.advance_time(Duration::from_secs(10));
+
+    let timestamp_a = harness.chain(0).host_timestamp().unwrap();
+    let timestamp_b = harness.chain(1).host_timestamp().unwrap();
+
+    assert_eq!(timestamp_b.duration_since(&timestamp_a), Some(drift));
+}
@@ -0,0 +1,188 @@
+use ibc::core::client::types::msgs::MsgCreateClient;
+use ibc::core::client::types::Height;
+use ibc::core::connection::types::version::Version as ConnectionVersion;
+use ibc::core::connection::types::{
+    ConnectionEnd, Counterparty as ConnectionCounterparty, State as ConnectionState,
+};
+use ibc::core::host::types::identifiers::{ChannelId, ConnectionId, PortId, Sequence};
+use ibc::core::host::types::path::{ConnectionPath, Path, ReceiptPath};
+use ibc::core::primitives::prelude::*;
+use ibc::core::primitives::ZERO_DURATION;
+use ibc::primitives::proto::{Any, Protobuf};
+use ibc::primitives::ToProto;
+use ibc_testkit::fixtures::compat::{
+    AttributeFixture, CompatFixture, Divergence, ExpectedOutcome, ProofFixture,
+};
+use ibc_testkit::fixtures::core::connection::{
+    dummy_msg_conn_open_init, dummy_msg_conn_open_init_with_client_id, dummy_raw_counterparty_conn,
+};
+use ibc_testkit::fixtures::core::context::mock_chain;
+use ibc_testkit::fixtures::core::signer::dummy_account_id;
+use ibc_testkit::testapp::ibc::clients::mock::client_state::{
+    client_type as mock_client_type, MockClientState,
+};
+use ibc_testkit::testapp::ibc::clients::mock::consensus_state::MockConsensusState;
+use ibc_testkit::testapp::ibc::clients::mock::header::MockHeader;
+use ibc_testkit::testapp::ibc::core::router::MockRouter;
+use subtle_encoding::hex;
+use test_log::test;
+
+fn captured_msgs() -> Vec<Any> {
+    let header = MockHeader::new(Height::new(0, 42).unwrap()).with_current_timestamp();
+
+    let create_client = MsgCreateClient::new(
+        MockClientState::new(header).into(),
+        MockConsensusState::new(header).into(),
+        dummy_account_id(),
+    );
+
+    let conn_open_init = dummy_msg_conn_open_init_with_client_id(
+        dummy_msg_conn_open_init(),
+        mock_client_type().build_client_id(0),
+    );
+
+    vec![
+        // Fails, as the client is not created yet.
+        conn_open_init.clone().to_any(),
+        create_client.to_any(),
+        conn_open_init.to_any(),
+    ]
+}
+
+#[test]
+fn captured_fixtures_replay_without_divergences() {
+    let fixture = CompatFixture::capture(
+        "ibc-rs",
+        &mut mock_chain("mockgaiaA-1"),
+        &mut MockRouter::new_with_transfer(),
+        captured_msgs(),
+    );
+
+    assert_eq!(fixture.messages.len(), 3);
+    assert_eq!(fixture.messages[0].outcome, ExpectedOutcome::Failure);
+    assert!(matches!(
+        &fixture.messages[2].outcome,
+        ExpectedOutcome::Success { events } if events.iter().any(|event| event.kind == "connection_open_init")
+    ));
+
+    let fixture = CompatFixture::from_json(&fixture.to_json()).unwrap();
+
+    fixture.assert_compatible(
+        &mut mock_chain("mockgaiaA-1"),
+        &mut MockRouter::new_with_transfer(),
+    );
+}
+
+#[test]
+fn diverging_events_are_reported() {
+    let mut fixture = CompatFixture::capture(
+        "ibc-rs",
+        &mut mock_chain("mockgaiaA-1"),
+        &mut MockRouter::new_with_transfer(),
+        captured_msgs(),
+    );
+
+    let ExpectedOutcome::Success { events } = &mut fixture.messages[1].outcome else {
+        panic!("the client is created")
+    };
+
+    events[0].attributes.push(AttributeFixture {
+        key: "client_id".to_string(),
+        value: "07-tendermint-0".to_string(),
+    });
+
+    let divergences = fixture.replay(
+        &mut mock_chain("mockgaiaA-1"),
+        &mut MockRouter::new_with_transfer(),
+    );
+
+    assert!(matches!(
+        divergences.as_slice(),
+        [Divergence::Outcome { index: 1, .. }]
+    ));
+}
+
+#[test]
+fn undecodable_messages_are_reported() {
+    let mut fixture = CompatFixture::capture(
+        "ibc-rs",
+        &mut mock_chain("mockgaiaA-1"),
+        &mut MockRouter::new_with_transfer(),
+        captured_msgs(),
+    );
+
+    fixture.messages.truncate(1);
+    fixture.messages[0].value = "ff".to_string();
+
+    let divergences = fixture.replay(
+        &mut mock_chain("mockgaiaA-1"),
+        &mut MockRouter::new_with_transfer(),
+    );
+
+    assert!(matches!(
+        divergences.as_slice(),
+        [Divergence::Decoding { index: 0, .. }]
+    ));
+}
+
+#[test]
+fn proofs_of_the_provable_store_verify() {
+    let conn_id = ConnectionId::new(0);
+
+    let conn_end = ConnectionEnd::new(
+        ConnectionState::Init,
+        mock_client_type().build_client_id(0),
+        ConnectionCounterparty::try_from(dummy_raw_counterparty_conn(Some(0))).unwrap(),
+        ConnectionVersion::compatibles(),
+        ZERO_DURATION,
+    )
+    .unwrap();
+
+    let mut ctx = mock_chain("mockgaiaA-1")
+        .with_provable_store()
+        .with_connection(conn_id.clone(), conn_end.clone());
+
+    ctx.advance_host_chain_height();
+
+    let height = ctx.latest_height();
+
+    let root = hex_string(ctx.commitment_root(&height).unwrap().as_bytes());
+
+    let proof_fixture = |path: Path, value: Option<Vec<u8>>| ProofFixture {
+        prefix: "mock".to_string(),
+        path: path.to_string(),
+        value: value.as_deref().map(hex_string),
+        proof: hex_string(ctx.prove(&height, &path).unwrap().as_ref()),
+        root: root.clone(),
+    };
+
+    let conn_path = Path::Connection(ConnectionPath::new(&conn_id));
+
+    let receipt_path = Path::Receipt(ReceiptPath::new(
+        &PortId::transfer(),
+        &ChannelId::new(0),
+        Sequence::from(1),
+    ));
+
+    let mut fixture = CompatFixture {
+        source: "ibc-rs".to_string(),
+        messages: vec![],
+        proofs: vec![
+            proof_fixture(conn_path.clone(), Some(conn_end.encode_vec())),
+            proof_fixture(receipt_path, None),
+        ],
+    };
+
+    assert!(fixture.verify_proofs().is_empty());
+
+    fixture.proofs[0].value = Some(hex_string(b"tampered"));
+
+    assert!(matches!(
+        fixture.verify_proofs().as_slice(),
+        [Divergence::Proof { index: 0, .. }]
+    ));
+}
+
+fn hex_string(bytes: &[u8]) -> String {
+    String::from_utf8(hex::encode(bytes)).unwrap()
+}
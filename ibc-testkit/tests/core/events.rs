@@ -0,0 +1,120 @@
+use ibc::core::client::types::msgs::{ClientMsg, MsgCreateClient};
+use ibc::core::client::types::Height;
+use ibc::core::handler::types::events::IbcEvent;
+use ibc::core::handler::types::msgs::MsgEnvelope;
+use ibc::core::primitives::prelude::*;
+use ibc_testkit::assert_events;
+use ibc_testkit::events::{self, EventMatcher};
+use ibc_testkit::fixtures::core::signer::dummy_account_id;
+use ibc_testkit::relayer::harness::TraceStep;
+use ibc_testkit::relayer::scenario::Scenario;
+use ibc_testkit::testapp::ibc::clients::mock::client_state::MockClientState;
+use ibc_testkit::testapp::ibc::clients::mock::consensus_state::MockConsensusState;
+use ibc_testkit::testapp::ibc::clients::mock::header::MockHeader;
+use ibc_testkit::testapp::ibc::core::router::MockRouter;
+use ibc_testkit::testapp::ibc::core::types::MockContext;
+use test_log::test;
+
+fn ctx_with_mock_client() -> MockContext {
+    let mut ctx = MockContext::default();
+    let mut router = MockRouter::new_with_transfer();
+    let height = Height::new(0, 42).unwrap();
+
+    let msg = MsgCreateClient::new(
+        MockClientState::new(MockHeader::new(height)).into(),
+        MockConsensusState::new(MockHeader::new(height)).into(),
+        dummy_account_id(),
+    );
+
+    ctx.deliver(&mut router, MsgEnvelope::from(ClientMsg::from(msg)))
+        .unwrap();
+
+    ctx
+}
+
+fn sent_packets_events() -> Vec<Vec<IbcEvent>> {
+    let outcome = Scenario::new()
+        .create_clients()
+        .open_connection()
+        .open_channel("transfer")
+        .send_packet(b"ping".to_vec())
+        .send_packet(b"pong".to_vec())
+        .run()
+        .unwrap();
+
+    outcome
+        .harness
+        .trace()
+        .iter()
+        .filter(|step| matches!(step, TraceStep::SendPacket { .. }))
+        .map(|step| step.events().to_vec())
+        .collect()
+}
+
+#[test]
+fn events_are_matched_by_their_fields() {
+    let ctx = ctx_with_mock_client();
+
+    assert_events!(
+        ctx,
+        [CreateClient {
+            client: "9999-mock-0",
+            client_type: "9999-mock"
+        }]
+    );
+    assert_events!(ctx, [CreateClient, ..]);
+}
+
+#[test]
+fn packet_fields_are_aliased() {
+    let sent = sent_packets_events();
+
+    assert_eq!(sent.len(), 2);
+
+    for (events, seq) in sent.iter().zip(1u64..) {
+        let matcher = EventMatcher::new("SendPacket")
+            .field("port", "transfer")
+            .field("channel", "channel-0")
+            .field("counterparty_channel", "channel-0")
+            .field("seq", seq);
+
+        events::assert_events(events, &[matcher], true);
+    }
+
+    let matcher = EventMatcher::new("SendPacket").field("seq", 1..=2);
+
+    assert!(sent
+        .iter()
+        .all(|events| events.iter().any(|event| matcher.matches(event))));
+}
+
+#[test]
+#[should_panic(
+    expected = "field `client` (`client_id`): expected \"9999-mock-1\", found \"9999-mock-0\""
+)]
+fn mismatching_fields_are_reported() {
+    let ctx = ctx_with_mock_client();
+
+    assert_events!(
+        ctx,
+        [CreateClient {
+            client: "9999-mock-1"
+        }]
+    );
+}
+
+#[test]
+#[should_panic(expected = "[0] unexpected `CreateClient` event")]
+fn leftover_events_are_reported() {
+    let ctx = ctx_with_mock_client();
+
+    assert_events!(ctx, []);
+}
+
+#[test]
+#[should_panic(expected = "expected a `SendPacket` event, found a `CreateClient` event")]
+fn mismatching_kinds_are_reported() {
+    let ctx = ctx_with_mock_client();
+
+    assert_events!(ctx, [SendPacket { seq: 1.. }]);
+}
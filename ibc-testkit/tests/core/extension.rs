@@ -0,0 +1,115 @@
+use ibc::core::entrypoint::{dispatch_extended, validate_extended};
+use ibc::core::handler::extension::HostMsgHandler;
+use ibc::core::handler::types::error::ContextError;
+use ibc::core::handler::types::msgs::{ExtendedMsgEnvelope, HostMsg};
+use ibc::core::host::{ExecutionContext, ValidationContext};
+use ibc::core::primitives::prelude::*;
+use ibc::core::router::types::error::RouterError;
+use ibc::primitives::proto::Any;
+use ibc_testkit::testapp::ibc::core::router::MockRouter;
+use ibc_testkit::testapp::ibc::core::types::MockContext;
+use test_log::test;
+
+const INCREASE_COUNTER_TYPE_URL: &str = "/host.v1.MsgIncreaseCounter";
+
+/// A message of the host increasing the client counter by the given amount.
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct MsgIncreaseCounter {
+    by: u64,
+}
+
+impl HostMsg for MsgIncreaseCounter {
+    fn decode(any_msg: &Any) -> Option<Result<Self, RouterError>> {
+        if any_msg.type_url != INCREASE_COUNTER_TYPE_URL {
+            return None;
+        }
+
+        let by = <[u8; 8]>::try_from(any_msg.value.as_slice())
+            .map(u64::from_be_bytes)
+            .map_err(|e| RouterError::MalformedMessageBytes {
+                reason: e.to_string(),
+            });
+
+        Some(by.map(|by| Self { by }))
+    }
+}
+
+struct CounterHandler;
+
+impl<Ctx> HostMsgHandler<Ctx> for CounterHandler
+where
+    Ctx: ExecutionContext,
+{
+    type Msg = MsgIncreaseCounter;
+
+    fn validate(&self, _ctx: &Ctx, msg: &Self::Msg) -> Result<(), ContextError> {
+        if msg.by == 0 {
+            return Err(RouterError::MalformedMessageBytes {
+                reason: "the counter must be increased".to_string(),
+            }
+            .into());
+        }
+
+        Ok(())
+    }
+
+    fn execute(&mut self, ctx: &mut Ctx, msg: Self::Msg) -> Result<(), ContextError> {
+        for _ in 0..msg.by {
+            ctx.increase_client_counter()?;
+        }
+
+        ctx.log_message(format!("counter increased by {}", msg.by))
+    }
+}
+
+fn increase_counter_any(value: Vec<u8>) -> Any {
+    Any {
+        type_url: INCREASE_COUNTER_TYPE_URL.to_string(),
+        value,
+    }
+}
+
+#[test]
+fn test_decode_extended_msg() {
+    let msg = ExtendedMsgEnvelope::<MsgIncreaseCounter>::try_from(increase_counter_any(
+        3u64.to_be_bytes().to_vec(),
+    ))
+    .unwrap();
+
+    assert_eq!(msg, ExtendedMsgEnvelope::Host(MsgIncreaseCounter { by: 3 }));
+
+    let res = ExtendedMsgEnvelope::<MsgIncreaseCounter>::try_from(increase_counter_any(vec![3]));
+
+    assert!(matches!(
+        res,
+        Err(RouterError::MalformedMessageBytes { .. })
+    ));
+
+    let res = ExtendedMsgEnvelope::<MsgIncreaseCounter>::try_from(Any {
+        type_url: "/host.v1.MsgUnknown".to_string(),
+        value: vec![],
+    });
+
+    assert!(matches!(
+        res,
+        Err(RouterError::UnknownMessageTypeUrl { .. })
+    ));
+}
+
+#[test]
+fn test_dispatch_host_msg() {
+    let mut ctx = MockContext::default();
+    let mut router = MockRouter::new_with_transfer();
+    let mut handler = CounterHandler;
+
+    let msg = ExtendedMsgEnvelope::Host(MsgIncreaseCounter { by: 0 });
+
+    assert!(validate_extended(&ctx, &router, &handler, msg).is_err());
+
+    let msg = ExtendedMsgEnvelope::Host(MsgIncreaseCounter { by: 2 });
+
+    dispatch_extended(&mut ctx, &mut router, &mut handler, msg).unwrap();
+
+    assert_eq!(ctx.client_counter().unwrap(), 2);
+    assert_eq!(ctx.get_logs(), vec!["counter increased by 2".to_string()]);
+}
@@ -0,0 +1,77 @@
+use ibc::clients::tendermint::client_state::ClientState as TmClientState;
+use ibc::clients::tendermint::types::TENDERMINT_CLIENT_STATE_TYPE_URL;
+use ibc::core::client::types::error::ClientError;
+use ibc::core::client::types::registry::AnyRegistry;
+use ibc::core::client::types::Height;
+use ibc::primitives::proto::{Any, Protobuf};
+use ibc_testkit::fixtures::clients::tendermint::{
+    dummy_tendermint_header, dummy_tm_client_state_from_header,
+};
+use ibc_testkit::testapp::ibc::clients::mock::client_state::{
+    MockClientState, MOCK_CLIENT_STATE_TYPE_URL,
+};
+use ibc_testkit::testapp::ibc::clients::mock::header::MockHeader;
+use ibc_testkit::testapp::ibc::clients::AnyClientState;
+use test_log::test;
+
+fn client_state_registry() -> AnyRegistry<AnyClientState> {
+    let mut registry = AnyRegistry::new();
+
+    registry
+        .register_type::<TmClientState>(TENDERMINT_CLIENT_STATE_TYPE_URL)
+        .unwrap();
+    registry
+        .register_type::<MockClientState>(MOCK_CLIENT_STATE_TYPE_URL)
+        .unwrap();
+
+    registry
+}
+
+fn mock_client_state() -> AnyClientState {
+    MockClientState::new(MockHeader::new(Height::new(0, 42).unwrap())).into()
+}
+
+#[test]
+fn test_registry_decodes_registered_types() {
+    let registry = client_state_registry();
+
+    let tm_client_state: AnyClientState =
+        dummy_tm_client_state_from_header(dummy_tendermint_header()).into();
+
+    for client_state in [tm_client_state, mock_client_state()] {
+        let decoded = registry.decode(Any::from(client_state.clone())).unwrap();
+        assert_eq!(decoded, client_state);
+
+        let bytes = Protobuf::<Any>::encode_vec(client_state.clone());
+        assert_eq!(registry.decode_bytes(&bytes).unwrap(), client_state);
+    }
+}
+
+#[test]
+fn test_registry_rejects_unregistered_types() {
+    let mut registry = AnyRegistry::<AnyClientState>::new();
+
+    registry
+        .register_type::<TmClientState>(TENDERMINT_CLIENT_STATE_TYPE_URL)
+        .unwrap();
+
+    assert!(!registry.contains(MOCK_CLIENT_STATE_TYPE_URL));
+    assert!(matches!(
+        registry.decode(mock_client_state().into()),
+        Err(ClientError::UnregisteredTypeUrl { type_url }) if type_url == MOCK_CLIENT_STATE_TYPE_URL
+    ));
+}
+
+#[test]
+fn test_registry_rejects_duplicate_type_urls() {
+    let mut registry = client_state_registry();
+
+    assert!(matches!(
+        registry.register_type::<MockClientState>(MOCK_CLIENT_STATE_TYPE_URL),
+        Err(ClientError::DuplicateTypeUrl { .. })
+    ));
+    assert_eq!(
+        registry.type_urls().collect::<Vec<_>>(),
+        [TENDERMINT_CLIENT_STATE_TYPE_URL, MOCK_CLIENT_STATE_TYPE_URL]
+    );
+}
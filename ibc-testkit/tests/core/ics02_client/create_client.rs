@@ -3,24 +3,27 @@ use ibc::clients::tendermint::types::{
 };
 use ibc::core::client::context::client_state::ClientStateCommon;
 use ibc::core::client::context::ClientValidationContext;
+use ibc::core::client::handler::update_params;
 use ibc::core::client::types::error::ClientError;
-use ibc::core::client::types::msgs::{ClientMsg, MsgCreateClient};
+use ibc::core::client::types::msgs::{ClientMsg, MsgCreateClient, MsgUpdateClientParams};
+use ibc::core::client::types::params::ClientParams;
 use ibc::core::client::types::Height;
 use ibc::core::entrypoint::{execute, validate};
 use ibc::core::handler::types::error::ContextError;
 use ibc::core::handler::types::msgs::MsgEnvelope;
 use ibc::core::host::{ClientStateRef, ValidationContext};
+use ibc::core::primitives::SignerFormat;
 use ibc_testkit::fixtures::clients::tendermint::{
     dummy_tendermint_header, dummy_tm_client_state_from_header,
 };
-use ibc_testkit::fixtures::core::signer::dummy_account_id;
+use ibc_testkit::fixtures::core::signer::{dummy_account_id, dummy_bech32_account};
 use ibc_testkit::testapp::ibc::clients::mock::client_state::{
     client_type as mock_client_type, MockClientState,
 };
 use ibc_testkit::testapp::ibc::clients::mock::consensus_state::MockConsensusState;
 use ibc_testkit::testapp::ibc::clients::mock::header::MockHeader;
 use ibc_testkit::testapp::ibc::core::router::MockRouter;
-use ibc_testkit::testapp::ibc::core::types::MockContext;
+use ibc_testkit::testapp::ibc::core::types::{MockContext, MOCK_AUTHORITY};
 use test_log::test;
 
 #[test]
@@ -54,6 +57,73 @@ fn test_create_client_ok() {
     assert_eq!(ctx.client_state(&client_id).unwrap(), expected_client_state);
 }
 
+#[test]
+fn test_create_client_signer_format() {
+    let ctx = MockContext::default().with_signer_format(SignerFormat::bech32("cosmos"));
+    let router = MockRouter::new_with_transfer();
+    let height = Height::new(0, 42).unwrap();
+
+    let msg_envelope = |signer| {
+        MsgEnvelope::from(ClientMsg::from(MsgCreateClient::new(
+            MockClientState::new(MockHeader::new(height)).into(),
+            MockConsensusState::new(MockHeader::new(height)).into(),
+            signer,
+        )))
+    };
+
+    // The hex account of the other tests is no bech32 address.
+    let res = validate(&ctx, &router, msg_envelope(dummy_account_id()));
+
+    assert!(matches!(
+        res,
+        Err(ContextError::ClientError(ClientError::InvalidSigner { .. }))
+    ));
+
+    let res = validate(&ctx, &router, msg_envelope(dummy_bech32_account().into()));
+
+    assert!(res.is_ok(), "validation happy path");
+}
+
+#[test]
+fn test_create_client_not_allowed() {
+    let mut ctx = MockContext::default();
+    let router = MockRouter::new_with_transfer();
+    let height = Height::new(0, 42).unwrap();
+
+    let msg_update_params = MsgUpdateClientParams {
+        params: ClientParams::new(vec![tm_client_type().as_str().to_string()]),
+        signer: MOCK_AUTHORITY.to_string().into(),
+    };
+
+    // Only the authority of the host updates the parameters.
+    assert!(update_params::validate(
+        &ctx,
+        &MsgUpdateClientParams {
+            signer: dummy_account_id(),
+            ..msg_update_params.clone()
+        }
+    )
+    .is_err());
+
+    update_params::validate(&ctx, &msg_update_params).unwrap();
+    update_params::execute(&mut ctx, msg_update_params).unwrap();
+
+    let msg = MsgCreateClient::new(
+        MockClientState::new(MockHeader::new(height)).into(),
+        MockConsensusState::new(MockHeader::new(height)).into(),
+        dummy_account_id(),
+    );
+
+    let res = validate(&ctx, &router, MsgEnvelope::from(ClientMsg::from(msg)));
+
+    assert!(matches!(
+        res,
+        Err(ContextError::ClientError(
+            ClientError::ClientTypeNotAllowed { .. }
+        ))
+    ));
+}
+
 #[test]
 fn test_tm_create_client_ok() {
     let signer = dummy_account_id();
@@ -1,5 +1,8 @@
+pub mod any_registry;
 #[cfg(feature = "serde")]
 pub mod create_client;
+pub mod recover_client;
+pub mod scripted_client;
 pub mod update_client;
 #[cfg(feature = "serde")]
 pub mod upgrade_client;
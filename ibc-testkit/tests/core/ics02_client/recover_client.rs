@@ -0,0 +1,143 @@
+use ibc::core::client::context::client_state::{ClientStateCommon, ClientStateValidation};
+use ibc::core::client::context::ClientValidationContext;
+use ibc::core::client::handler::recover_client;
+use ibc::core::client::types::error::ClientError;
+use ibc::core::client::types::msgs::MsgRecoverClient;
+use ibc::core::client::types::{Height, Status};
+use ibc::core::handler::types::error::ContextError;
+use ibc::core::host::types::identifiers::ClientId;
+use ibc::core::host::types::path::ClientConsensusStatePath;
+use ibc::core::primitives::prelude::*;
+use ibc::core::primitives::Timestamp;
+use ibc_testkit::fixtures::core::context::MockContextConfig;
+use ibc_testkit::fixtures::core::signer::dummy_account_id;
+use ibc_testkit::testapp::ibc::clients::mock::behaviour::MockClientBehaviour;
+use ibc_testkit::testapp::ibc::core::types::{MockClientConfig, MockContext, MOCK_AUTHORITY};
+use rstest::*;
+
+struct Fixture {
+    ctx: MockContext,
+    subject_client_id: ClientId,
+    substitute_client_id: ClientId,
+}
+
+/// Returns a context with a subject client frozen at the height 42, and an
+/// active substitute client at the height 50.
+#[fixture]
+fn fixture() -> Fixture {
+    let timestamp = Timestamp::now();
+
+    let subject_client_id = ClientId::new("9999-mock", 0).unwrap();
+    let substitute_client_id = ClientId::new("9999-mock", 1).unwrap();
+
+    let ctx = MockContextConfig::builder()
+        .latest_timestamp(timestamp)
+        .build()
+        .with_client_config(
+            MockClientConfig::builder()
+                .client_id(subject_client_id.clone())
+                .latest_height(Height::new(0, 42).unwrap())
+                .latest_timestamp(timestamp)
+                .behaviour(MockClientBehaviour::default().frozen_at(timestamp))
+                .build(),
+        )
+        .with_client_config(
+            MockClientConfig::builder()
+                .client_id(substitute_client_id.clone())
+                .latest_height(Height::new(0, 50).unwrap())
+                .latest_timestamp(timestamp)
+                .build(),
+        );
+
+    Fixture {
+        ctx,
+        subject_client_id,
+        substitute_client_id,
+    }
+}
+
+fn msg_recover_client(fixture: &Fixture) -> MsgRecoverClient {
+    MsgRecoverClient {
+        subject_client_id: fixture.subject_client_id.clone(),
+        substitute_client_id: fixture.substitute_client_id.clone(),
+        signer: MOCK_AUTHORITY.to_string().into(),
+    }
+}
+
+#[rstest]
+fn test_recover_client_ok(fixture: Fixture) {
+    let msg = msg_recover_client(&fixture);
+    let Fixture {
+        mut ctx,
+        subject_client_id,
+        ..
+    } = fixture;
+
+    let subject_client_state = ctx.client_state(&subject_client_id).unwrap();
+    assert_eq!(
+        subject_client_state
+            .status(&ctx, &subject_client_id)
+            .unwrap(),
+        Status::Frozen
+    );
+
+    recover_client::validate(&ctx, msg.clone()).unwrap();
+    recover_client::execute(&mut ctx, msg).unwrap();
+
+    let latest_height = Height::new(0, 50).unwrap();
+
+    let subject_client_state = ctx.client_state(&subject_client_id).unwrap();
+    assert_eq!(subject_client_state.latest_height(), latest_height);
+    assert_eq!(
+        subject_client_state
+            .status(&ctx, &subject_client_id)
+            .unwrap(),
+        Status::Active
+    );
+    assert!(ctx
+        .consensus_state(&ClientConsensusStatePath::new(
+            subject_client_id,
+            latest_height.revision_number(),
+            latest_height.revision_height(),
+        ))
+        .is_ok());
+}
+
+#[rstest]
+fn test_recover_client_unauthorized(fixture: Fixture) {
+    let msg = MsgRecoverClient {
+        signer: dummy_account_id(),
+        ..msg_recover_client(&fixture)
+    };
+
+    assert!(matches!(
+        recover_client::validate(&fixture.ctx, msg),
+        Err(ContextError::ClientError(ClientError::Unauthorized { .. }))
+    ));
+}
+
+#[rstest]
+fn test_recover_client_invalid_recovery(fixture: Fixture) {
+    let msg = msg_recover_client(&fixture);
+
+    // An active client cannot be recovered.
+    let msg_active_subject = MsgRecoverClient {
+        subject_client_id: msg.substitute_client_id.clone(),
+        substitute_client_id: msg.subject_client_id.clone(),
+        ..msg.clone()
+    };
+    // A client cannot substitute itself.
+    let msg_same_clients = MsgRecoverClient {
+        substitute_client_id: msg.subject_client_id.clone(),
+        ..msg
+    };
+
+    for msg in [msg_active_subject, msg_same_clients] {
+        assert!(matches!(
+            recover_client::validate(&fixture.ctx, msg),
+            Err(ContextError::ClientError(
+                ClientError::InvalidRecovery { .. }
+            ))
+        ));
+    }
+}
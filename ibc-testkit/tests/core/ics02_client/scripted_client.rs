@@ -0,0 +1,118 @@
+use ibc::core::client::context::client_state::ClientStateCommon;
+use ibc::core::client::context::ClientValidationContext;
+use ibc::core::client::types::msgs::{ClientMsg, MsgUpdateClient};
+use ibc::core::client::types::Height;
+use ibc::core::commitment_types::commitment::CommitmentRoot;
+use ibc::core::entrypoint::validate;
+use ibc::core::handler::types::msgs::MsgEnvelope;
+use ibc::core::host::types::identifiers::{ChainId, ClientId};
+use ibc::core::primitives::Timestamp;
+use ibc_testkit::fixtures::core::context::MockContextConfig;
+use ibc_testkit::fixtures::core::signer::dummy_account_id;
+use ibc_testkit::testapp::ibc::clients::mock::behaviour::{
+    MockClientBehaviour, MockClientStatus, MockClientStep,
+};
+use ibc_testkit::testapp::ibc::clients::mock::client_state::client_type as mock_client_type;
+use ibc_testkit::testapp::ibc::clients::mock::header::MockHeader;
+use ibc_testkit::testapp::ibc::clients::AnyClientState;
+use ibc_testkit::testapp::ibc::core::router::MockRouter;
+use ibc_testkit::testapp::ibc::core::types::{MockClientConfig, MockContext};
+use test_log::test;
+
+/// Returns a host at height `1-5`, with a mock client following the script.
+fn scripted_ctx(script: Vec<MockClientStep>) -> (MockContext, ClientId) {
+    let client_id = mock_client_type().build_client_id(0);
+    let host_id = ChainId::new("mockgaiaA-1").unwrap();
+
+    let ctx = MockContextConfig::builder()
+        .latest_height(Height::new(1, 5).unwrap())
+        .host_id(host_id)
+        .build()
+        .with_client_config(
+            MockClientConfig::builder()
+                .client_chain_id(ChainId::new("mockgaiaB-1").unwrap())
+                .client_id(client_id.clone())
+                .latest_height(Height::new(1, 20).unwrap())
+                .behaviour(MockClientBehaviour::default().scripted(script.leak()))
+                .build(),
+        );
+
+    (ctx, client_id)
+}
+
+fn host_height(revision_height: u64) -> Height {
+    Height::new(1, revision_height).unwrap()
+}
+
+#[test]
+fn status_follows_the_script() {
+    let (mut ctx, client_id) = scripted_ctx(vec![
+        MockClientStep::at(host_height(6)).status(MockClientStatus::Expired),
+        MockClientStep::at(host_height(7)).status(MockClientStatus::Frozen),
+        MockClientStep::at(host_height(8)),
+    ]);
+
+    ctx.assert_client_active(&client_id);
+
+    ctx.advance_host_chain_height();
+    ctx.assert_client_expired(&client_id);
+
+    ctx.advance_host_chain_height();
+    ctx.assert_client_frozen(&client_id);
+
+    // The client recovers once the script no longer overrides its status.
+    ctx.advance_host_chain_height();
+    ctx.assert_client_active(&client_id);
+}
+
+#[test]
+fn verification_follows_the_script() {
+    let (mut ctx, client_id) = scripted_ctx(vec![MockClientStep::at(host_height(6))
+        .fail_verification()
+        .fail_proof_verification()]);
+
+    let router = MockRouter::new_with_transfer();
+
+    let msg_envelope = MsgEnvelope::from(ClientMsg::from(MsgUpdateClient {
+        client_id: client_id.clone(),
+        client_message: MockHeader::new(Height::new(1, 21).unwrap())
+            .with_timestamp(Timestamp::now())
+            .into(),
+        signer: dummy_account_id(),
+    }));
+
+    let fails_proofs = |ctx: &MockContext| {
+        let AnyClientState::Mock(client_state) = ctx.client_state(&client_id).unwrap() else {
+            panic!("the client is not a mock client");
+        };
+
+        client_state
+            .behaviour
+            .fails_proofs_against(&CommitmentRoot::from(vec![1]))
+    };
+
+    assert!(validate(&ctx, &router, msg_envelope.clone()).is_ok());
+    assert!(!fails_proofs(&ctx));
+
+    ctx.advance_host_chain_height();
+
+    assert!(validate(&ctx, &router, msg_envelope).is_err());
+    assert!(fails_proofs(&ctx));
+}
+
+#[test]
+fn latest_height_follows_the_script() {
+    let scripted_height = Height::new(1, 30).unwrap();
+
+    let (mut ctx, client_id) = scripted_ctx(vec![
+        MockClientStep::at(host_height(6)).latest_height(scripted_height)
+    ]);
+
+    let latest_height = |ctx: &MockContext| ctx.client_state(&client_id).unwrap().latest_height();
+
+    assert_eq!(latest_height(&ctx), Height::new(1, 20).unwrap());
+
+    ctx.advance_host_chain_height();
+
+    assert_eq!(latest_height(&ctx), scripted_height);
+}
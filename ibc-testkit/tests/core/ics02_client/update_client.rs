@@ -2,35 +2,41 @@ use core::str::FromStr;
 use core::time::Duration;
 
 use ibc::clients::tendermint::client_state::ClientState;
+use ibc::clients::tendermint::context::DefaultVerifier;
+use ibc::clients::tendermint::evidence::build_misbehaviour;
 use ibc::clients::tendermint::types::proto::v1::{ClientState as RawTmClientState, Fraction};
 use ibc::clients::tendermint::types::{
     client_type as tm_client_type, ClientState as TmClientState, Header as TmHeader,
-    Misbehaviour as TmMisbehaviour,
+    Misbehaviour as TmMisbehaviour, ZkHeader,
 };
 use ibc::core::client::context::client_state::{ClientStateCommon, ClientStateValidation};
+use ibc::core::client::context::consensus_state::ConsensusState;
 use ibc::core::client::context::ClientValidationContext;
 use ibc::core::client::types::msgs::{ClientMsg, MsgUpdateClient};
 use ibc::core::client::types::proto::v1::Height as RawHeight;
-use ibc::core::client::types::Height;
+use ibc::core::client::types::{Height, Status};
+use ibc::core::commitment_types::commitment::{CommitmentPrefix, CommitmentProofBytes};
 use ibc::core::commitment_types::specs::ProofSpecs;
 use ibc::core::entrypoint::{execute, validate};
 use ibc::core::handler::types::events::{IbcEvent, MessageEvent};
 use ibc::core::handler::types::msgs::MsgEnvelope;
 use ibc::core::host::types::identifiers::{ChainId, ClientId, ClientType};
-use ibc::core::host::types::path::ClientConsensusStatePath;
-use ibc::core::host::ValidationContext;
+use ibc::core::host::types::path::{ClientConsensusStatePath, ClientStatePath};
+use ibc::core::host::HostClock;
 use ibc::core::primitives::Timestamp;
 use ibc::primitives::proto::Any;
 use ibc::primitives::ToVec;
 use ibc_testkit::fixtures::core::context::MockContextConfig;
 use ibc_testkit::fixtures::core::signer::dummy_account_id;
 use ibc_testkit::hosts::block::{HostBlock, HostType};
+use ibc_testkit::testapp::ibc::clients::mock::behaviour::MockClientBehaviour;
 use ibc_testkit::testapp::ibc::clients::mock::client_state::{
     client_type as mock_client_type, MockClientState,
 };
 use ibc_testkit::testapp::ibc::clients::mock::header::MockHeader;
 use ibc_testkit::testapp::ibc::clients::mock::misbehaviour::Misbehaviour as MockMisbehaviour;
-use ibc_testkit::testapp::ibc::clients::AnyConsensusState;
+use ibc_testkit::testapp::ibc::clients::{AnyClientState, AnyConsensusState};
+use ibc_testkit::testapp::ibc::core::client_ctx::MockZkCommitVerifier;
 use ibc_testkit::testapp::ibc::core::router::MockRouter;
 use ibc_testkit::testapp::ibc::core::types::{MockClientConfig, MockContext};
 use rstest::*;
@@ -775,6 +781,106 @@ fn test_update_synthetic_tendermint_client_lower_height() {
     assert!(res.is_err());
 }
 
+/// Returns the context of chain A, with a synthetic Tendermint client of
+/// chain B and the given verifier of the commit proofs, and the update of the
+/// client with a ZK header carrying the given proof.
+fn zk_update_client_fixture(
+    zk_commit_verifier: Option<MockZkCommitVerifier>,
+    proof: Vec<u8>,
+) -> (MockContext, MsgUpdateClient) {
+    let client_id = tm_client_type().build_client_id(0);
+    let client_height = Height::new(1, 20).unwrap();
+    let update_height = Height::new(1, 21).unwrap();
+    let chain_id_b = ChainId::new("mockgaiaB-1").unwrap();
+
+    let mut ctx = MockContextConfig::builder()
+        .host_id(ChainId::new("mockgaiaA-1").unwrap())
+        .latest_height(Height::new(1, 1).unwrap())
+        .build()
+        .with_client_config(
+            MockClientConfig::builder()
+                .client_chain_id(chain_id_b.clone())
+                .client_id(client_id.clone())
+                .client_type(tm_client_type())
+                .latest_height(client_height)
+                .build(),
+        );
+
+    if let Some(zk_commit_verifier) = zk_commit_verifier {
+        ctx = ctx.with_zk_commit_verifier(zk_commit_verifier);
+    }
+
+    let ctx_b = MockContextConfig::builder()
+        .host_id(chain_id_b)
+        .host_type(HostType::SyntheticTendermint)
+        .latest_height(update_height)
+        .build();
+
+    let block = ctx_b
+        .host_block(&update_height)
+        .unwrap()
+        .clone()
+        .try_into_tm_block()
+        .unwrap();
+
+    let header = ZkHeader {
+        header: block.header().clone(),
+        trusted_height: client_height,
+        proof,
+    };
+
+    let msg = MsgUpdateClient {
+        client_id,
+        client_message: header.into(),
+        signer: dummy_account_id(),
+    };
+
+    (ctx, msg)
+}
+
+#[rstest]
+fn test_update_synthetic_tendermint_client_zk_header_ok() {
+    let (mut ctx, msg) =
+        zk_update_client_fixture(Some(MockZkCommitVerifier::new(vec![1])), vec![1]);
+
+    let mut router = MockRouter::new_with_transfer();
+
+    let msg_envelope = MsgEnvelope::from(ClientMsg::from(msg.clone()));
+
+    let res = validate(&ctx, &router, msg_envelope.clone());
+    assert!(res.is_ok(), "result: {res:?}");
+
+    let res = execute(&mut ctx, &mut router, msg_envelope);
+    assert!(res.is_ok(), "result: {res:?}");
+
+    let client_state = ctx.client_state(&msg.client_id).unwrap();
+
+    assert!(client_state
+        .status(&ctx, &msg.client_id)
+        .unwrap()
+        .is_active());
+
+    assert_eq!(client_state.latest_height(), Height::new(1, 21).unwrap());
+}
+
+#[rstest]
+// The proof is not accepted by the verifier, or the host has no verifier.
+#[case(Some(MockZkCommitVerifier::new(vec![1])), vec![2])]
+#[case(None, vec![1])]
+fn test_update_synthetic_tendermint_client_zk_header_rejected(
+    #[case] zk_commit_verifier: Option<MockZkCommitVerifier>,
+    #[case] proof: Vec<u8>,
+) {
+    let (ctx, msg) = zk_update_client_fixture(zk_commit_verifier, proof);
+
+    let router = MockRouter::new_with_transfer();
+
+    let msg_envelope = MsgEnvelope::from(ClientMsg::from(msg));
+
+    let res = validate(&ctx, &router, msg_envelope);
+    assert!(res.is_err());
+}
+
 #[rstest]
 fn test_update_client_events(fixture: Fixture) {
     let Fixture {
@@ -958,6 +1064,94 @@ fn test_misbehaviour_synthetic_tendermint_equivocation() {
     ensure_misbehaviour(&ctx_a, &client_id, &tm_client_type());
 }
 
+/// Tests that misbehaviour evidence built with the relayer-side evidence
+/// utilities from two conflicting headers is accepted by the handler.
+#[rstest]
+fn test_misbehaviour_synthetic_tendermint_built_evidence() {
+    let client_id = tm_client_type().build_client_id(0);
+    let client_height = Height::new(1, 20).unwrap();
+    let misbehaviour_height = Height::new(1, 21).unwrap();
+    let chain_id_b = ChainId::new("mockgaiaB-1").unwrap();
+
+    let mut ctx_a = MockContextConfig::builder()
+        .host_id(ChainId::new("mockgaiaA-1").unwrap())
+        .latest_height(Height::new(1, 1).unwrap())
+        .build()
+        .with_client_config(
+            MockClientConfig::builder()
+                .client_chain_id(chain_id_b.clone())
+                .client_id(client_id.clone())
+                .client_type(tm_client_type())
+                .latest_height(client_height)
+                .build(),
+        );
+
+    let mut router_a = MockRouter::new_with_transfer();
+
+    let ctx_b = MockContextConfig::builder()
+        .host_id(chain_id_b.clone())
+        .host_type(HostType::SyntheticTendermint)
+        .latest_height(misbehaviour_height)
+        .build();
+
+    // The trusted fields of both headers are left at (wrong) defaults, as the
+    // evidence builder is responsible for filling them in.
+    let header1: TmHeader = ctx_b
+        .host_block(&misbehaviour_height)
+        .unwrap()
+        .clone()
+        .try_into_tm_block()
+        .unwrap()
+        .into();
+    let header2: TmHeader = HostBlock::generate_tm_block(
+        chain_id_b,
+        misbehaviour_height.revision_height(),
+        Timestamp::now(),
+    )
+    .into();
+
+    let AnyClientState::Tendermint(client_state) = ctx_a.client_state(&client_id).unwrap() else {
+        panic!("unexpected client state type");
+    };
+    let trusted_consensus_state = ctx_a
+        .consensus_state(&ClientConsensusStatePath::new(
+            client_id.clone(),
+            client_height.revision_number(),
+            client_height.revision_height(),
+        ))
+        .unwrap();
+    let trusted_states = vec![(client_height, trusted_consensus_state.try_into().unwrap())];
+    let trusted_validators = header1.trusted_next_validator_set.clone();
+
+    let misbehaviour = build_misbehaviour(
+        client_state.inner(),
+        client_id.clone(),
+        header2,
+        header1,
+        &trusted_states,
+        &|_| Ok(trusted_validators.clone()),
+        ctx_a.host_timestamp().unwrap(),
+        &DefaultVerifier,
+    )
+    .unwrap();
+
+    assert_eq!(misbehaviour.header1().trusted_height, client_height);
+    assert_eq!(misbehaviour.header2().trusted_height, client_height);
+
+    let msg = MsgUpdateClient {
+        client_id: client_id.clone(),
+        client_message: misbehaviour.into(),
+        signer: dummy_account_id(),
+    };
+    let msg_envelope = MsgEnvelope::from(ClientMsg::from(msg));
+
+    let res = validate(&ctx_a, &router_a, msg_envelope.clone());
+    assert!(res.is_ok());
+    let res = execute(&mut ctx_a, &mut router_a, msg_envelope);
+    assert!(res.is_ok());
+    ensure_misbehaviour(&ctx_a, &client_id, &tm_client_type());
+}
+
 #[rstest]
 fn test_misbehaviour_synthetic_tendermint_bft_time() {
     let client_id = tm_client_type().build_client_id(0);
@@ -1133,3 +1327,124 @@ fn test_client_update_max_clock_drift() {
     let res = validate(&ctx_a, &router_a, msg_envelope);
     assert!(res.is_err());
 }
+
+#[rstest]
+fn test_update_mock_client_injected_verification_failure() {
+    let client_id = ClientId::new("9999-mock", 0).expect("no error");
+    let failing_height = Height::new(0, 46).unwrap();
+
+    let ctx = MockContext::default().with_client_config(
+        MockClientConfig::builder()
+            .client_id(client_id.clone())
+            .latest_height(Height::new(0, 42).unwrap())
+            .behaviour(MockClientBehaviour::default().fail_verification_at(failing_height))
+            .build(),
+    );
+    let router = MockRouter::new_with_transfer();
+
+    let msg_envelope = |height: Height| {
+        MsgEnvelope::from(ClientMsg::from(MsgUpdateClient {
+            client_id: client_id.clone(),
+            client_message: MockHeader::new(height)
+                .with_timestamp(Timestamp::now())
+                .into(),
+            signer: dummy_account_id(),
+        }))
+    };
+
+    assert!(validate(&ctx, &router, msg_envelope(failing_height)).is_err());
+    assert!(validate(&ctx, &router, msg_envelope(failing_height.increment())).is_ok());
+}
+
+#[rstest]
+fn test_update_mock_client_wrong_root() {
+    let client_id = ClientId::new("9999-mock", 0).expect("no error");
+    let latest_height = Height::new(0, 42).unwrap();
+    let update_height = Height::new(0, 46).unwrap();
+
+    let mut ctx = MockContext::default().with_client_config(
+        MockClientConfig::builder()
+            .client_id(client_id.clone())
+            .latest_height(latest_height)
+            .behaviour(MockClientBehaviour::default().wrong_root_at(update_height))
+            .build(),
+    );
+    let mut router = MockRouter::new_with_transfer();
+
+    let msg_envelope = MsgEnvelope::from(ClientMsg::from(MsgUpdateClient {
+        client_id: client_id.clone(),
+        client_message: MockHeader::new(update_height)
+            .with_timestamp(Timestamp::now())
+            .into(),
+        signer: dummy_account_id(),
+    }));
+    assert!(execute(&mut ctx, &mut router, msg_envelope).is_ok());
+
+    let client_state = ctx.client_state(&client_id).unwrap();
+    let verify_at = |height: Height| {
+        let consensus_state = ctx
+            .consensus_state(&ClientConsensusStatePath::new(
+                client_id.clone(),
+                height.revision_number(),
+                height.revision_height(),
+            ))
+            .unwrap();
+
+        client_state.verify_membership(
+            &CommitmentPrefix::try_from(b"ibc".to_vec()).unwrap(),
+            &CommitmentProofBytes::try_from(vec![1]).unwrap(),
+            consensus_state.root(),
+            ClientStatePath::new(client_id.clone()).into(),
+            vec![1],
+        )
+    };
+
+    assert!(verify_at(latest_height).is_ok());
+    assert!(verify_at(update_height).is_err());
+}
+
+#[rstest]
+fn test_mock_client_scripted_status() {
+    let client_id = ClientId::new("9999-mock", 0).expect("no error");
+    let timestamp = Timestamp::now();
+    let block_time = Duration::from_secs(1);
+
+    let ctx_with_behaviour = |behaviour: MockClientBehaviour| {
+        MockContextConfig::builder()
+            .latest_timestamp(timestamp)
+            .block_time(block_time)
+            .build()
+            .with_client_config(
+                MockClientConfig::builder()
+                    .client_id(client_id.clone())
+                    .latest_height(Height::new(0, 42).unwrap())
+                    .latest_timestamp(timestamp)
+                    .behaviour(behaviour)
+                    .build(),
+            )
+    };
+    let deadline = (timestamp + block_time * 5).expect("no error");
+
+    for (behaviour, status) in [
+        (
+            MockClientBehaviour::default().frozen_at(deadline),
+            Status::Frozen,
+        ),
+        (
+            MockClientBehaviour::default().expired_at(deadline),
+            Status::Expired,
+        ),
+    ] {
+        let mut ctx = ctx_with_behaviour(behaviour);
+        let client_state = ctx.client_state(&client_id).unwrap();
+        assert_eq!(
+            client_state.status(&ctx, &client_id).unwrap(),
+            Status::Active
+        );
+
+        while ctx.host_timestamp().expect("no error") < deadline {
+            ctx.advance_host_chain_height();
+        }
+        assert_eq!(client_state.status(&ctx, &client_id).unwrap(), status);
+    }
+}
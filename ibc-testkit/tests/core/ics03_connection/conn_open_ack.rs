@@ -162,6 +162,8 @@ fn conn_open_ack_execute(fxt: &mut Fixture<MsgConnectionOpenAck>, expect: Expect
             )
             .unwrap();
             assert_eq!(conn_end.state().clone(), State::Open);
+            assert_eq!(conn_open_try_event.version(), &conn_end.versions()[0]);
+            assert_eq!(conn_open_try_event.delay_period(), conn_end.delay_period());
         }
     }
 }
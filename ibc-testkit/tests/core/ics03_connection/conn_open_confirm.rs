@@ -112,6 +112,8 @@ fn conn_open_confirm_execute(fxt: &mut Fixture<MsgConnectionOpenConfirm>, expect
                 ValidationContext::connection_end(&fxt.ctx, conn_open_try_event.conn_id_on_b())
                     .unwrap();
             assert_eq!(conn_end.state().clone(), State::Open);
+            assert_eq!(conn_open_try_event.version(), &conn_end.versions()[0]);
+            assert_eq!(conn_open_try_event.delay_period(), conn_end.delay_period());
         }
     }
 }
@@ -1,12 +1,15 @@
 use ibc::core::client::types::Height;
+use ibc::core::connection::types::error::ConnectionError;
 use ibc::core::connection::types::msgs::{ConnectionMsg, MsgConnectionOpenInit};
 use ibc::core::connection::types::version::Version;
 use ibc::core::connection::types::State;
 use ibc::core::entrypoint::{execute, validate};
+use ibc::core::handler::types::error::ContextError;
 use ibc::core::handler::types::events::{IbcEvent, MessageEvent};
 use ibc::core::handler::types::msgs::MsgEnvelope;
 use ibc::core::host::ValidationContext;
 use ibc::core::primitives::prelude::*;
+use ibc::core::primitives::Signer;
 use ibc_testkit::fixtures::core::connection::{
     dummy_msg_conn_open_init, msg_conn_open_init_with_counterparty_conn_id,
     msg_conn_open_with_version,
@@ -143,3 +146,34 @@ fn conn_open_init_with_counterparty_conn_id() {
     let expected_version = vec![fxt.msg.version.clone().unwrap()];
     conn_open_init_execute(&mut fxt, Expect::Success, expected_version);
 }
+
+#[test]
+fn conn_open_init_authorized_creator() {
+    let mut fxt = conn_open_init_fixture(Ctx::WithClient, Msg::Default);
+    fxt.ctx = fxt
+        .ctx
+        .with_connection_creators(vec![fxt.msg.signer.clone()]);
+    conn_open_init_validate(&fxt, Expect::Success);
+}
+
+#[test]
+fn conn_open_init_unauthorized_creator() {
+    let mut fxt = conn_open_init_fixture(Ctx::WithClient, Msg::Default);
+    fxt.ctx = fxt
+        .ctx
+        .with_connection_creators(vec![Signer::from("governance".to_string())]);
+
+    let router = MockRouter::new_with_transfer();
+    let msg_envelope = MsgEnvelope::from(ConnectionMsg::from(fxt.msg.clone()));
+    let res = validate(&fxt.ctx, &router, msg_envelope);
+
+    assert!(
+        matches!(
+            res,
+            Err(ContextError::ConnectionError(
+                ConnectionError::UnauthorizedConnectionCreation { .. }
+            ))
+        ),
+        "the signer may not create connections. res: {res:?}"
+    );
+}
@@ -1,14 +1,22 @@
+use core::str::FromStr;
+
 use ibc::core::client::types::Height;
+use ibc::core::connection::types::error::ConnectionError;
 use ibc::core::connection::types::msgs::{ConnectionMsg, MsgConnectionOpenTry};
 use ibc::core::connection::types::State;
 use ibc::core::entrypoint::{execute, validate};
+use ibc::core::handler::types::error::ContextError;
 use ibc::core::handler::types::events::{IbcEvent, MessageEvent};
 use ibc::core::handler::types::msgs::MsgEnvelope;
-use ibc::core::host::ValidationContext;
+use ibc::core::host::types::identifiers::ClientType;
+use ibc::core::host::{BasicSelfClientValidator, SelfClientValidation, ValidationContext};
 use ibc::core::primitives::prelude::*;
+use ibc::core::primitives::Signer;
 use ibc_testkit::fixtures::core::connection::dummy_msg_conn_open_try;
 use ibc_testkit::fixtures::core::context::MockContextConfig;
 use ibc_testkit::fixtures::{Expect, Fixture};
+use ibc_testkit::testapp::ibc::clients::mock::client_state::MockClientState;
+use ibc_testkit::testapp::ibc::clients::mock::header::MockHeader;
 use ibc_testkit::testapp::ibc::core::router::MockRouter;
 use ibc_testkit::testapp::ibc::core::types::{MockClientConfig, MockContext};
 use test_log::test;
@@ -113,6 +121,8 @@ fn conn_open_try_execute(fxt: &mut Fixture<MsgConnectionOpenTry>, expect: Expect
                 ValidationContext::connection_end(&fxt.ctx, conn_open_try_event.conn_id_on_b())
                     .unwrap();
             assert_eq!(conn_end.state().clone(), State::TryOpen);
+            assert_eq!(conn_open_try_event.version(), &conn_end.versions()[0]);
+            assert_eq!(conn_open_try_event.delay_period(), conn_end.delay_period());
         }
     }
 }
@@ -147,3 +157,70 @@ fn conn_open_try_no_client() {
     let fxt = conn_open_try_fixture(Ctx::Default, Msg::Default);
     conn_open_try_validate(&fxt, Expect::Failure(None));
 }
+
+#[test]
+fn conn_open_try_unauthorized_creator() {
+    let mut fxt = conn_open_try_fixture(Ctx::WithClient, Msg::Default);
+    fxt.ctx = fxt
+        .ctx
+        .with_connection_creators(vec![Signer::from("governance".to_string())]);
+
+    let res = validate_res(&fxt);
+
+    assert!(
+        matches!(
+            res,
+            Err(ContextError::ConnectionError(
+                ConnectionError::UnauthorizedConnectionCreation { .. }
+            ))
+        ),
+        "the signer may not create connections. res: {res:?}"
+    );
+}
+
+#[test]
+fn conn_open_try_self_client_rejected() {
+    // The client of the host on the counterparty is ahead of the host, which
+    // the self client validator of the context rejects
+    let fxt = conn_open_try_fixture(Ctx::WithClient, Msg::HeightAdvanced);
+
+    let res = validate_res(&fxt);
+
+    assert!(
+        matches!(
+            res,
+            Err(ContextError::ConnectionError(
+                ConnectionError::InvalidClientState { .. }
+            ))
+        ),
+        "the client of the host is invalid. res: {res:?}"
+    );
+}
+
+#[test]
+fn self_client_of_other_type_rejected() {
+    let validator = BasicSelfClientValidator::new(
+        ClientType::from_str("07-tendermint").unwrap(),
+        Height::new(0, 35).unwrap(),
+    );
+    let client_state = MockClientState::new(MockHeader::new(Height::new(0, 10).unwrap()));
+
+    let res = validator.validate_self_client(client_state);
+
+    assert!(
+        matches!(
+            res,
+            Err(ContextError::ConnectionError(
+                ConnectionError::InvalidClientState { .. }
+            ))
+        ),
+        "the client does not track the host. res: {res:?}"
+    );
+}
+
+fn validate_res(fxt: &Fixture<MsgConnectionOpenTry>) -> Result<(), ContextError> {
+    let router = MockRouter::new_with_transfer();
+    let msg_envelope = MsgEnvelope::from(ConnectionMsg::from(fxt.msg.clone()));
+
+    validate(&fxt.ctx, &router, msg_envelope)
+}
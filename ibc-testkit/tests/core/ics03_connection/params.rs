@@ -0,0 +1,33 @@
+use core::time::Duration;
+
+use ibc::core::connection::types::params::ConnectionParams;
+use ibc::core::connection::types::version::Version as ConnectionVersion;
+use ibc::core::host::ValidationContext;
+use ibc_testkit::testapp::ibc::core::types::{MockContext, DEFAULT_BLOCK_TIME_SECS};
+use test_log::test;
+
+#[test]
+fn connection_params_default_to_context_values() {
+    let ctx = MockContext::default();
+
+    let params = ctx.connection_params();
+
+    assert_eq!(
+        params,
+        ConnectionParams::new(
+            Duration::from_secs(DEFAULT_BLOCK_TIME_SECS),
+            ConnectionVersion::compatibles()
+        )
+    );
+    assert!(params.validate().is_ok());
+}
+
+#[test]
+fn block_delay_from_connection_params() {
+    let ctx = MockContext::default();
+
+    // The block delay is rounded up to whole blocks.
+    let delay_period = Duration::from_secs(DEFAULT_BLOCK_TIME_SECS * 2 + 1);
+
+    assert_eq!(ctx.block_delay(&delay_period), 3);
+}
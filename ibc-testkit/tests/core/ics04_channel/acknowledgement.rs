@@ -196,6 +196,58 @@ fn ack_success_happy_path(fixture: Fixture) {
     )
 }
 
+#[rstest]
+fn ack_ordered_chan_fail_wrong_sequence(fixture: Fixture) {
+    let default_client_id = ClientId::new("07-tendermint", 0).expect("no error");
+    let Fixture {
+        ctx,
+        router,
+        msg,
+        packet_commitment,
+        conn_end_on_a,
+        chan_end_on_a_ordered,
+        client_height,
+        ..
+    } = fixture;
+    let mut ctx: MockContext = ctx
+        .with_client_config(
+            MockClientConfig::builder()
+                .latest_height(client_height)
+                .build(),
+        )
+        .with_channel(PortId::transfer(), ChannelId::zero(), chan_end_on_a_ordered)
+        .with_connection(ConnectionId::zero(), conn_end_on_a)
+        .with_packet_commitment(
+            msg.packet.port_id_on_a.clone(),
+            msg.packet.chan_id_on_a.clone(),
+            msg.packet.seq_on_a,
+            packet_commitment,
+        )
+        // The packet acknowledged is not the next one expected
+        .with_ack_sequence(
+            msg.packet.port_id_on_a.clone(),
+            msg.packet.chan_id_on_a.clone(),
+            msg.packet.seq_on_a.increment(),
+        );
+    ctx.get_client_execution_context()
+        .store_update_meta(
+            default_client_id,
+            client_height,
+            Timestamp::from_nanoseconds(1000).unwrap(),
+            Height::new(0, 4).unwrap(),
+        )
+        .unwrap();
+
+    let msg_envelope = MsgEnvelope::from(PacketMsg::from(msg));
+
+    let res = validate(&ctx, &router, msg_envelope);
+
+    assert!(
+        res.is_err(),
+        "Validation fails because the packet is acknowledged out of order"
+    )
+}
+
 #[rstest]
 fn ack_unordered_chan_execute(fixture: Fixture) {
     let Fixture {
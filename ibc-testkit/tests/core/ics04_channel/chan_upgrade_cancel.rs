@@ -0,0 +1,196 @@
+use ibc::core::channel::types::channel::{ChannelEnd, Counterparty, Order, State as ChannelState};
+use ibc::core::channel::types::msgs::{ChannelMsg, MsgChannelUpgradeCancel};
+use ibc::core::channel::types::upgrade::{ErrorReceipt, Upgrade, UpgradeFields};
+use ibc::core::channel::types::Version;
+use ibc::core::commitment_types::commitment::CommitmentProofBytes;
+use ibc::core::connection::types::version::Version as ConnectionVersion;
+use ibc::core::connection::types::{
+    ConnectionEnd, Counterparty as ConnectionCounterparty, State as ConnectionState,
+};
+use ibc::core::entrypoint::{execute, validate};
+use ibc::core::handler::types::events::{IbcEvent, MessageEvent};
+use ibc::core::handler::types::msgs::MsgEnvelope;
+use ibc::core::host::types::identifiers::{ChannelId, ConnectionId, PortId};
+use ibc::core::host::types::path::{ChannelEndPath, ChannelUpgradeErrorPath, ChannelUpgradePath};
+use ibc::core::host::{ExecutionContext, HostClock, ValidationContext};
+use ibc::core::primitives::*;
+use ibc_testkit::fixtures::core::connection::dummy_raw_counterparty_conn;
+use ibc_testkit::fixtures::core::signer::dummy_account_id;
+use ibc_testkit::testapp::ibc::clients::mock::client_state::client_type as mock_client_type;
+use ibc_testkit::testapp::ibc::core::router::MockRouter;
+use ibc_testkit::testapp::ibc::core::types::{MockClientConfig, MockContext};
+use test_log::test;
+
+pub struct Fixture {
+    pub context: MockContext,
+    pub router: MockRouter,
+    pub msg: MsgChannelUpgradeCancel,
+}
+
+fn fixture(chan_state_on_a: ChannelState, error_receipt_sequence: u64) -> Fixture {
+    let client_id = mock_client_type().build_client_id(24);
+    let conn_id = ConnectionId::new(2);
+    let port_id = PortId::transfer();
+    let chan_id = ChannelId::zero();
+
+    let conn_end = ConnectionEnd::new(
+        ConnectionState::Open,
+        client_id.clone(),
+        ConnectionCounterparty::try_from(dummy_raw_counterparty_conn(Some(0))).unwrap(),
+        ConnectionVersion::compatibles(),
+        ZERO_DURATION,
+    )
+    .unwrap();
+
+    let chan_end = ChannelEnd::new(
+        chan_state_on_a,
+        Order::Unordered,
+        Counterparty::new(PortId::transfer(), Some(ChannelId::new(1))),
+        vec![conn_id.clone()],
+        Version::new("ics20-1".to_string()),
+    )
+    .unwrap()
+    .with_upgrade_sequence(1);
+
+    let upgrade = Upgrade::new(UpgradeFields::new(
+        Order::Unordered,
+        vec![conn_id.clone()],
+        Version::new("ics20-2".to_string()),
+    ));
+
+    let mut context = {
+        let default_context = MockContext::default();
+        let client_consensus_state_height = default_context.host_height().unwrap();
+
+        default_context
+            .with_client_config(
+                MockClientConfig::builder()
+                    .client_id(client_id)
+                    .latest_height(client_consensus_state_height)
+                    .build(),
+            )
+            .with_connection(conn_id, conn_end)
+            .with_channel(port_id.clone(), chan_id.clone(), chan_end)
+    };
+    context
+        .store_channel_upgrade(&ChannelUpgradePath::new(&port_id, &chan_id), upgrade)
+        .unwrap();
+
+    let msg = MsgChannelUpgradeCancel {
+        port_id_on_a: port_id,
+        chan_id_on_a: chan_id,
+        error_receipt_on_b: ErrorReceipt::new(
+            error_receipt_sequence,
+            "upgrade timed out".to_string(),
+        ),
+        proof_error_receipt_on_b: CommitmentProofBytes::try_from(vec![1]).unwrap(),
+        proof_height_on_b: context.host_height().unwrap(),
+        signer: dummy_account_id(),
+    };
+
+    Fixture {
+        context,
+        router: MockRouter::new_with_transfer(),
+        msg,
+    }
+}
+
+#[test]
+fn chan_upgrade_cancel_validate_happy_path() {
+    let Fixture {
+        context,
+        router,
+        msg,
+    } = fixture(ChannelState::Flushing, 1);
+
+    let msg_envelope = MsgEnvelope::from(ChannelMsg::from(msg));
+
+    let res = validate(&context, &router, msg_envelope);
+
+    assert!(res.is_ok(), "Validation happy path. Error: {res:?}")
+}
+
+#[test]
+fn chan_upgrade_cancel_fail_stale_error_receipt() {
+    let Fixture {
+        context,
+        router,
+        msg,
+    } = fixture(ChannelState::Flushing, 0);
+
+    let msg_envelope = MsgEnvelope::from(ChannelMsg::from(msg));
+
+    let res = validate(&context, &router, msg_envelope);
+
+    assert!(
+        res.is_err(),
+        "Validation fails because the error receipt is for an earlier upgrade"
+    )
+}
+
+#[test]
+fn chan_upgrade_cancel_fail_flush_complete() {
+    let Fixture {
+        context,
+        router,
+        msg,
+    } = fixture(ChannelState::FlushComplete, 1);
+
+    let msg_envelope = MsgEnvelope::from(ChannelMsg::from(msg));
+
+    let res = validate(&context, &router, msg_envelope);
+
+    assert!(
+        res.is_err(),
+        "Validation fails because the channel end is done flushing"
+    )
+}
+
+#[test]
+fn chan_upgrade_cancel_execute_happy_path() {
+    let Fixture {
+        mut context,
+        mut router,
+        msg,
+    } = fixture(ChannelState::Flushing, 2);
+
+    let msg_envelope = MsgEnvelope::from(ChannelMsg::from(msg.clone()));
+
+    let res = execute(&mut context, &mut router, msg_envelope);
+
+    assert!(res.is_ok(), "Execution happy path");
+
+    let chan_end_on_a = context
+        .channel_end(&ChannelEndPath::new(&msg.port_id_on_a, &msg.chan_id_on_a))
+        .unwrap();
+    assert_eq!(chan_end_on_a.state, ChannelState::Open);
+    assert_eq!(chan_end_on_a.upgrade_sequence(), 2);
+
+    assert!(context
+        .channel_upgrade(&ChannelUpgradePath::new(
+            &msg.port_id_on_a,
+            &msg.chan_id_on_a
+        ))
+        .is_err());
+
+    let error_receipt = context
+        .channel_upgrade_error_receipt(&ChannelUpgradeErrorPath::new(
+            &msg.port_id_on_a,
+            &msg.chan_id_on_a,
+        ))
+        .unwrap();
+    assert_eq!(error_receipt.sequence, 2);
+
+    let ibc_events = context.get_events();
+
+    assert_eq!(ibc_events.len(), 3);
+
+    assert!(matches!(
+        ibc_events[0],
+        IbcEvent::Message(MessageEvent::Channel)
+    ));
+
+    assert!(matches!(ibc_events[1], IbcEvent::UpgradeCancelChannel(_)));
+
+    assert!(matches!(ibc_events[2], IbcEvent::UpgradeErrorChannel(_)));
+}
@@ -0,0 +1,198 @@
+use ibc::core::channel::types::channel::{ChannelEnd, Counterparty, Order, State as ChannelState};
+use ibc::core::channel::types::error::ChannelError;
+use ibc::core::channel::types::msgs::{ChannelMsg, MsgChannelUpgradeConfirm};
+use ibc::core::channel::types::upgrade::{Upgrade, UpgradeFields};
+use ibc::core::channel::types::Version;
+use ibc::core::commitment_types::commitment::CommitmentProofBytes;
+use ibc::core::connection::types::version::Version as ConnectionVersion;
+use ibc::core::connection::types::{
+    ConnectionEnd, Counterparty as ConnectionCounterparty, State as ConnectionState,
+};
+use ibc::core::entrypoint::{execute, validate};
+use ibc::core::handler::types::error::ContextError;
+use ibc::core::handler::types::events::{IbcEvent, MessageEvent};
+use ibc::core::handler::types::msgs::MsgEnvelope;
+use ibc::core::host::types::identifiers::{ChannelId, ConnectionId, PortId};
+use ibc::core::host::types::path::{ChannelEndPath, ChannelUpgradePath};
+use ibc::core::host::{ExecutionContext, HostClock, ValidationContext};
+use ibc::core::primitives::*;
+use ibc_testkit::fixtures::core::connection::dummy_raw_counterparty_conn;
+use ibc_testkit::fixtures::core::signer::dummy_account_id;
+use ibc_testkit::testapp::ibc::clients::mock::client_state::client_type as mock_client_type;
+use ibc_testkit::testapp::ibc::core::router::MockRouter;
+use ibc_testkit::testapp::ibc::core::types::{MockClientConfig, MockContext};
+use test_log::test;
+
+pub struct Fixture {
+    pub context: MockContext,
+    pub router: MockRouter,
+    pub msg: MsgChannelUpgradeConfirm,
+}
+
+/// Returns the fixture of chain B, which agreed to an upgrade to `ics20-2`,
+/// and is confirmed the given upgrade of chain A.
+fn fixture(upgrade_fields_on_a: UpgradeFields) -> Fixture {
+    let client_id = mock_client_type().build_client_id(24);
+    let conn_id = ConnectionId::new(2);
+    let port_id = PortId::transfer();
+    let chan_id = ChannelId::zero();
+
+    let conn_end = ConnectionEnd::new(
+        ConnectionState::Open,
+        client_id.clone(),
+        ConnectionCounterparty::try_from(dummy_raw_counterparty_conn(Some(0))).unwrap(),
+        ConnectionVersion::compatibles(),
+        ZERO_DURATION,
+    )
+    .unwrap();
+
+    let chan_end = ChannelEnd::new(
+        ChannelState::Flushing,
+        Order::Unordered,
+        Counterparty::new(PortId::transfer(), Some(ChannelId::new(1))),
+        vec![conn_id.clone()],
+        Version::new("ics20-1".to_string()),
+    )
+    .unwrap()
+    .with_upgrade_sequence(1);
+
+    let upgrade_on_b = Upgrade::new(UpgradeFields::new(
+        Order::Unordered,
+        vec![conn_id.clone()],
+        Version::new("ics20-2".to_string()),
+    ));
+
+    let mut context = {
+        let default_context = MockContext::default();
+        let client_consensus_state_height = default_context.host_height().unwrap();
+
+        default_context
+            .with_client_config(
+                MockClientConfig::builder()
+                    .client_id(client_id)
+                    .latest_height(client_consensus_state_height)
+                    .build(),
+            )
+            .with_connection(conn_id, conn_end)
+            .with_channel(port_id.clone(), chan_id.clone(), chan_end)
+    };
+    context
+        .store_channel_upgrade(&ChannelUpgradePath::new(&port_id, &chan_id), upgrade_on_b)
+        .unwrap();
+
+    let msg = MsgChannelUpgradeConfirm {
+        port_id_on_b: port_id,
+        chan_id_on_b: chan_id,
+        chan_state_on_a: ChannelState::FlushComplete,
+        upgrade_on_a: Upgrade::new(upgrade_fields_on_a),
+        proof_chan_end_on_a: CommitmentProofBytes::try_from(vec![1]).unwrap(),
+        proof_upgrade_on_a: CommitmentProofBytes::try_from(vec![1]).unwrap(),
+        proof_height_on_a: context.host_height().unwrap(),
+        signer: dummy_account_id(),
+    };
+
+    Fixture {
+        context,
+        router: MockRouter::new_with_transfer(),
+        msg,
+    }
+}
+
+fn upgrade_fields_on_a(conn_id_on_a: ConnectionId, version: &str) -> UpgradeFields {
+    UpgradeFields::new(
+        Order::Unordered,
+        vec![conn_id_on_a],
+        Version::new(version.to_string()),
+    )
+}
+
+#[test]
+fn chan_upgrade_confirm_execute_happy_path() {
+    let Fixture {
+        mut context,
+        mut router,
+        msg,
+    } = fixture(upgrade_fields_on_a(ConnectionId::zero(), "ics20-2"));
+
+    let msg_envelope = MsgEnvelope::from(ChannelMsg::from(msg.clone()));
+
+    let res = validate(&context, &router, msg_envelope.clone());
+
+    assert!(res.is_ok(), "Validation happy path. Error: {res:?}");
+
+    let res = execute(&mut context, &mut router, msg_envelope);
+
+    assert!(res.is_ok(), "Execution happy path");
+
+    let chan_end_on_b = context
+        .channel_end(&ChannelEndPath::new(&msg.port_id_on_b, &msg.chan_id_on_b))
+        .unwrap();
+    assert_eq!(chan_end_on_b.state, ChannelState::FlushComplete);
+
+    assert_eq!(
+        context
+            .counterparty_channel_upgrade(&ChannelUpgradePath::new(
+                &msg.port_id_on_b,
+                &msg.chan_id_on_b,
+            ))
+            .unwrap(),
+        msg.upgrade_on_a
+    );
+
+    let ibc_events = context.get_events();
+
+    assert_eq!(ibc_events.len(), 2);
+
+    assert!(matches!(
+        ibc_events[0],
+        IbcEvent::Message(MessageEvent::Channel)
+    ));
+
+    assert!(matches!(ibc_events[1], IbcEvent::UpgradeConfirmChannel(_)));
+}
+
+#[test]
+fn chan_upgrade_confirm_fail_mismatched_version() {
+    let Fixture {
+        context,
+        router,
+        msg,
+    } = fixture(upgrade_fields_on_a(ConnectionId::zero(), "ics20-1"));
+
+    let msg_envelope = MsgEnvelope::from(ChannelMsg::from(msg));
+
+    let res = validate(&context, &router, msg_envelope);
+
+    assert!(
+        matches!(
+            res,
+            Err(ContextError::ChannelError(
+                ChannelError::IncompatibleUpgrade { .. }
+            ))
+        ),
+        "Validation fails because chain A upgrades to another version. res: {res:?}"
+    )
+}
+
+#[test]
+fn chan_upgrade_confirm_fail_mismatched_connection() {
+    let Fixture {
+        context,
+        router,
+        msg,
+    } = fixture(upgrade_fields_on_a(ConnectionId::new(5), "ics20-2"));
+
+    let msg_envelope = MsgEnvelope::from(ChannelMsg::from(msg));
+
+    let res = validate(&context, &router, msg_envelope);
+
+    assert!(
+        matches!(
+            res,
+            Err(ContextError::ChannelError(
+                ChannelError::IncompatibleUpgrade { .. }
+            ))
+        ),
+        "Validation fails because chain A upgrades to another connection. res: {res:?}"
+    )
+}
@@ -0,0 +1,157 @@
+use ibc::core::channel::types::channel::{ChannelEnd, Counterparty, Order, State as ChannelState};
+use ibc::core::channel::types::msgs::{ChannelMsg, MsgChannelUpgradeInit};
+use ibc::core::channel::types::upgrade::UpgradeFields;
+use ibc::core::channel::types::Version;
+use ibc::core::connection::types::version::Version as ConnectionVersion;
+use ibc::core::connection::types::{
+    ConnectionEnd, Counterparty as ConnectionCounterparty, State as ConnectionState,
+};
+use ibc::core::entrypoint::{execute, validate};
+use ibc::core::handler::types::events::{IbcEvent, MessageEvent};
+use ibc::core::handler::types::msgs::MsgEnvelope;
+use ibc::core::host::types::identifiers::{ChannelId, ConnectionId, PortId};
+use ibc::core::host::types::path::{ChannelEndPath, ChannelUpgradePath};
+use ibc::core::host::{HostClock, ValidationContext};
+use ibc::core::primitives::*;
+use ibc_testkit::fixtures::core::connection::dummy_raw_counterparty_conn;
+use ibc_testkit::fixtures::core::signer::dummy_account_id;
+use ibc_testkit::testapp::ibc::clients::mock::client_state::client_type as mock_client_type;
+use ibc_testkit::testapp::ibc::core::router::MockRouter;
+use ibc_testkit::testapp::ibc::core::types::{MockClientConfig, MockContext};
+use test_log::test;
+
+pub struct Fixture {
+    pub context: MockContext,
+    pub router: MockRouter,
+    pub msg: MsgChannelUpgradeInit,
+}
+
+fn fixture(upgrade_version: &str) -> Fixture {
+    let client_id = mock_client_type().build_client_id(24);
+    let conn_id = ConnectionId::new(2);
+    let port_id = PortId::transfer();
+    let chan_id = ChannelId::zero();
+
+    let conn_end = ConnectionEnd::new(
+        ConnectionState::Open,
+        client_id.clone(),
+        ConnectionCounterparty::try_from(dummy_raw_counterparty_conn(Some(0))).unwrap(),
+        ConnectionVersion::compatibles(),
+        ZERO_DURATION,
+    )
+    .unwrap();
+
+    let chan_end = ChannelEnd::new(
+        ChannelState::Open,
+        Order::Unordered,
+        Counterparty::new(PortId::transfer(), Some(ChannelId::new(1))),
+        vec![conn_id.clone()],
+        Version::new("ics20-1".to_string()),
+    )
+    .unwrap();
+
+    let msg = MsgChannelUpgradeInit {
+        port_id_on_a: port_id.clone(),
+        chan_id_on_a: chan_id.clone(),
+        fields: UpgradeFields::new(
+            Order::Unordered,
+            vec![conn_id.clone()],
+            Version::new(upgrade_version.to_string()),
+        ),
+        signer: dummy_account_id(),
+    };
+
+    let context = {
+        let default_context = MockContext::default();
+        let client_consensus_state_height = default_context.host_height().unwrap();
+
+        default_context
+            .with_client_config(
+                MockClientConfig::builder()
+                    .client_id(client_id)
+                    .latest_height(client_consensus_state_height)
+                    .build(),
+            )
+            .with_connection(conn_id, conn_end)
+            .with_channel(port_id, chan_id, chan_end)
+    };
+
+    Fixture {
+        context,
+        router: MockRouter::new_with_transfer(),
+        msg,
+    }
+}
+
+#[test]
+fn chan_upgrade_init_validate_happy_path() {
+    let Fixture {
+        context,
+        router,
+        msg,
+    } = fixture("ics20-2");
+
+    let msg_envelope = MsgEnvelope::from(ChannelMsg::from(msg));
+
+    let res = validate(&context, &router, msg_envelope);
+
+    assert!(res.is_ok(), "Validation happy path. Error: {res:?}")
+}
+
+#[test]
+fn chan_upgrade_init_fail_identical_fields() {
+    let Fixture {
+        context,
+        router,
+        msg,
+    } = fixture("ics20-1");
+
+    let msg_envelope = MsgEnvelope::from(ChannelMsg::from(msg));
+
+    let res = validate(&context, &router, msg_envelope);
+
+    assert!(
+        res.is_err(),
+        "Validation fails because the upgrade changes nothing"
+    )
+}
+
+#[test]
+fn chan_upgrade_init_execute_happy_path() {
+    let Fixture {
+        mut context,
+        mut router,
+        msg,
+    } = fixture("ics20-2");
+
+    let msg_envelope = MsgEnvelope::from(ChannelMsg::from(msg.clone()));
+
+    let res = execute(&mut context, &mut router, msg_envelope);
+
+    assert!(res.is_ok(), "Execution happy path");
+
+    let chan_end_on_a = context
+        .channel_end(&ChannelEndPath::new(&msg.port_id_on_a, &msg.chan_id_on_a))
+        .unwrap();
+    assert_eq!(chan_end_on_a.upgrade_sequence(), 1);
+    assert_eq!(chan_end_on_a.state, ChannelState::Open);
+
+    let upgrade_on_a = context
+        .channel_upgrade(&ChannelUpgradePath::new(
+            &msg.port_id_on_a,
+            &msg.chan_id_on_a,
+        ))
+        .unwrap();
+    assert_eq!(upgrade_on_a.fields, msg.fields);
+
+    let ibc_events = context.get_events();
+
+    assert_eq!(ibc_events.len(), 2);
+
+    assert!(matches!(
+        ibc_events[0],
+        IbcEvent::Message(MessageEvent::Channel)
+    ));
+
+    assert!(matches!(ibc_events[1], IbcEvent::UpgradeInitChannel(_)));
+}
@@ -0,0 +1,249 @@
+use ibc::core::channel::types::channel::{ChannelEnd, Counterparty, Order, State as ChannelState};
+use ibc::core::channel::types::error::ChannelError;
+use ibc::core::channel::types::msgs::{ChannelMsg, MsgChannelUpgradeTry};
+use ibc::core::channel::types::upgrade::{Upgrade, UpgradeFields};
+use ibc::core::channel::types::Version;
+use ibc::core::commitment_types::commitment::CommitmentProofBytes;
+use ibc::core::connection::types::version::Version as ConnectionVersion;
+use ibc::core::connection::types::{
+    ConnectionEnd, Counterparty as ConnectionCounterparty, State as ConnectionState,
+};
+use ibc::core::entrypoint::{execute, validate};
+use ibc::core::handler::types::error::ContextError;
+use ibc::core::handler::types::events::{IbcEvent, MessageEvent};
+use ibc::core::handler::types::msgs::MsgEnvelope;
+use ibc::core::host::types::identifiers::{ChannelId, ConnectionId, PortId};
+use ibc::core::host::types::path::{ChannelEndPath, ChannelUpgradePath};
+use ibc::core::host::{ExecutionContext, HostClock, ValidationContext};
+use ibc::core::primitives::*;
+use ibc_testkit::fixtures::core::connection::dummy_raw_counterparty_conn;
+use ibc_testkit::fixtures::core::signer::dummy_account_id;
+use ibc_testkit::testapp::ibc::clients::mock::client_state::client_type as mock_client_type;
+use ibc_testkit::testapp::ibc::core::router::MockRouter;
+use ibc_testkit::testapp::ibc::core::types::{MockClientConfig, MockContext};
+use test_log::test;
+
+pub struct Fixture {
+    pub context: MockContext,
+    pub router: MockRouter,
+    pub msg: MsgChannelUpgradeTry,
+}
+
+/// Returns the fields of the upgrade of chain B, over the connection the
+/// counterparty of which chain A upgrades to.
+fn upgrade_fields_on_b(version: &str) -> UpgradeFields {
+    UpgradeFields::new(
+        Order::Unordered,
+        vec![ConnectionId::new(2)],
+        Version::new(version.to_string()),
+    )
+}
+
+/// Returns the fixture of chain B, which initiated the upgrade of the given
+/// version as well if `crossing_version` is set.
+fn fixture(crossing_version: Option<&str>) -> Fixture {
+    let client_id = mock_client_type().build_client_id(24);
+    let conn_id = ConnectionId::new(2);
+    let port_id = PortId::transfer();
+    let chan_id = ChannelId::zero();
+
+    let conn_end = ConnectionEnd::new(
+        ConnectionState::Open,
+        client_id.clone(),
+        ConnectionCounterparty::try_from(dummy_raw_counterparty_conn(Some(0))).unwrap(),
+        ConnectionVersion::compatibles(),
+        ZERO_DURATION,
+    )
+    .unwrap();
+
+    // Initiating the upgrade increments the upgrade sequence.
+    let chan_end = ChannelEnd::new(
+        ChannelState::Open,
+        Order::Unordered,
+        Counterparty::new(PortId::transfer(), Some(ChannelId::new(1))),
+        vec![conn_id.clone()],
+        Version::new("ics20-1".to_string()),
+    )
+    .unwrap()
+    .with_upgrade_sequence(u64::from(crossing_version.is_some()));
+
+    let mut context = {
+        let default_context = MockContext::default();
+        let client_consensus_state_height = default_context.host_height().unwrap();
+
+        default_context
+            .with_client_config(
+                MockClientConfig::builder()
+                    .client_id(client_id)
+                    .latest_height(client_consensus_state_height)
+                    .build(),
+            )
+            .with_connection(conn_id.clone(), conn_end)
+            .with_channel(port_id.clone(), chan_id.clone(), chan_end)
+    };
+
+    if let Some(version) = crossing_version {
+        context
+            .store_channel_upgrade(
+                &ChannelUpgradePath::new(&port_id, &chan_id),
+                Upgrade::new(upgrade_fields_on_b(version)),
+            )
+            .unwrap();
+    }
+
+    let msg = MsgChannelUpgradeTry {
+        port_id_on_b: port_id,
+        chan_id_on_b: chan_id,
+        proposed_conn_hops_on_b: vec![conn_id],
+        upgrade_fields_on_a: UpgradeFields::new(
+            Order::Unordered,
+            vec![ConnectionId::zero()],
+            Version::new("ics20-2".to_string()),
+        ),
+        upgrade_sequence_on_a: 1,
+        proof_chan_end_on_a: CommitmentProofBytes::try_from(vec![1]).unwrap(),
+        proof_upgrade_on_a: CommitmentProofBytes::try_from(vec![1]).unwrap(),
+        proof_height_on_a: context.host_height().unwrap(),
+        signer: dummy_account_id(),
+    };
+
+    Fixture {
+        context,
+        router: MockRouter::new_with_transfer(),
+        msg,
+    }
+}
+
+#[test]
+fn chan_upgrade_try_execute_happy_path() {
+    let Fixture {
+        mut context,
+        mut router,
+        msg,
+    } = fixture(None);
+
+    let msg_envelope = MsgEnvelope::from(ChannelMsg::from(msg.clone()));
+
+    let res = validate(&context, &router, msg_envelope.clone());
+
+    assert!(res.is_ok(), "Validation happy path. Error: {res:?}");
+
+    let res = execute(&mut context, &mut router, msg_envelope);
+
+    assert!(res.is_ok(), "Execution happy path");
+
+    let chan_end_on_b = context
+        .channel_end(&ChannelEndPath::new(&msg.port_id_on_b, &msg.chan_id_on_b))
+        .unwrap();
+    assert_eq!(chan_end_on_b.state, ChannelState::Flushing);
+    assert_eq!(chan_end_on_b.upgrade_sequence(), 1);
+
+    let upgrade_on_b = context
+        .channel_upgrade(&ChannelUpgradePath::new(
+            &msg.port_id_on_b,
+            &msg.chan_id_on_b,
+        ))
+        .unwrap();
+    assert_eq!(upgrade_on_b.fields, upgrade_fields_on_b("ics20-2"));
+    assert!(upgrade_on_b.timeout.is_set());
+
+    let ibc_events = context.get_events();
+
+    assert_eq!(ibc_events.len(), 2);
+
+    assert!(matches!(
+        ibc_events[0],
+        IbcEvent::Message(MessageEvent::Channel)
+    ));
+
+    assert!(matches!(ibc_events[1], IbcEvent::UpgradeTryChannel(_)));
+}
+
+#[test]
+fn chan_upgrade_try_crossing_hellos() {
+    let Fixture {
+        mut context,
+        mut router,
+        msg,
+    } = fixture(Some("ics20-2"));
+
+    let msg_envelope = MsgEnvelope::from(ChannelMsg::from(msg.clone()));
+
+    let res = validate(&context, &router, msg_envelope.clone());
+
+    assert!(
+        res.is_ok(),
+        "Validation of the crossing hellos. Error: {res:?}"
+    );
+
+    let res = execute(&mut context, &mut router, msg_envelope);
+
+    assert!(res.is_ok(), "Execution of the crossing hellos");
+
+    let chan_end_on_b = context
+        .channel_end(&ChannelEndPath::new(&msg.port_id_on_b, &msg.chan_id_on_b))
+        .unwrap();
+    assert_eq!(chan_end_on_b.state, ChannelState::Flushing);
+    assert_eq!(chan_end_on_b.upgrade_sequence(), 1);
+
+    // The upgrade initiated by chain B is kept, and starts flushing.
+    let upgrade_on_b = context
+        .channel_upgrade(&ChannelUpgradePath::new(
+            &msg.port_id_on_b,
+            &msg.chan_id_on_b,
+        ))
+        .unwrap();
+    assert_eq!(upgrade_on_b.fields, upgrade_fields_on_b("ics20-2"));
+    assert!(upgrade_on_b.timeout.is_set());
+}
+
+#[test]
+fn chan_upgrade_try_crossing_hellos_mismatched_fields() {
+    let Fixture {
+        context,
+        router,
+        msg,
+    } = fixture(Some("ics20-3"));
+
+    let msg_envelope = MsgEnvelope::from(ChannelMsg::from(msg));
+
+    let res = validate(&context, &router, msg_envelope);
+
+    assert!(
+        matches!(
+            res,
+            Err(ContextError::ChannelError(
+                ChannelError::IncompatibleUpgrade { .. }
+            ))
+        ),
+        "Validation fails because chain B initiated another upgrade. res: {res:?}"
+    )
+}
+
+#[test]
+fn chan_upgrade_try_fail_no_proposed_connection_hops() {
+    let Fixture {
+        context,
+        router,
+        mut msg,
+    } = fixture(None);
+
+    msg.proposed_conn_hops_on_b = vec![];
+
+    let msg_envelope = MsgEnvelope::from(ChannelMsg::from(msg));
+
+    let res = validate(&context, &router, msg_envelope);
+
+    assert!(
+        matches!(
+            res,
+            Err(ContextError::ChannelError(
+                ChannelError::InvalidConnectionHopsLength {
+                    expected: 1,
+                    actual: 0
+                }
+            ))
+        ),
+        "Validation fails because no connection hop is proposed. res: {res:?}"
+    )
+}
@@ -5,7 +5,18 @@ pub mod chan_open_ack;
 pub mod chan_open_confirm;
 pub mod chan_open_init;
 pub mod chan_open_try;
+pub mod chan_upgrade_ack;
+pub mod chan_upgrade_cancel;
+pub mod chan_upgrade_confirm;
+pub mod chan_upgrade_init;
+pub mod chan_upgrade_open;
+pub mod chan_upgrade_timeout;
+pub mod chan_upgrade_try;
+pub mod packet_status;
+pub mod packet_timeout;
+pub mod packet_v2;
 pub mod recv_packet;
 pub mod send_packet;
 pub mod timeout;
 pub mod timeout_on_close;
+pub mod write_acknowledgement;
@@ -0,0 +1,183 @@
+use ibc::core::channel::context::{
+    PacketStateIterationContext, PacketStatusContext, PACKET_SEQUENCES_BATCH_SIZE,
+};
+use ibc::core::channel::types::channel::{ChannelEnd, Counterparty, Order, State};
+use ibc::core::channel::types::commitment::{AcknowledgementCommitment, PacketCommitment};
+use ibc::core::channel::types::packet::Receipt;
+use ibc::core::channel::types::Version;
+use ibc::core::host::types::identifiers::{ChannelId, ConnectionId, PortId, Sequence};
+use ibc::core::host::types::path::{AckPath, ChannelEndPath, CommitmentPath, ReceiptPath};
+use ibc::core::host::ExecutionContext;
+use ibc_testkit::testapp::ibc::core::types::MockContext;
+use rstest::*;
+use test_log::test;
+
+fn context_with_channel(ordering: Order) -> MockContext {
+    let chan_end = ChannelEnd::new(
+        State::Open,
+        ordering,
+        Counterparty::new(PortId::transfer(), Some(ChannelId::new(1))),
+        vec![ConnectionId::zero()],
+        Version::new("ics20-1".to_string()),
+    )
+    .unwrap();
+
+    MockContext::default().with_channel(PortId::transfer(), ChannelId::zero(), chan_end)
+}
+
+fn channel_end_path() -> ChannelEndPath {
+    ChannelEndPath::new(&PortId::transfer(), &ChannelId::zero())
+}
+
+#[test]
+fn unordered_packet_status_from_receipts() {
+    let mut ctx = context_with_channel(Order::Unordered);
+    let chan_end_path = channel_end_path();
+
+    ctx.store_packet_receipt(
+        &ReceiptPath::new(&chan_end_path.0, &chan_end_path.1, 2.into()),
+        Receipt::Ok,
+    )
+    .unwrap();
+
+    assert!(ctx.is_packet_received(&chan_end_path, 2.into()).unwrap());
+    assert!(!ctx.is_packet_received(&chan_end_path, 1.into()).unwrap());
+
+    let unreceived = ctx
+        .unreceived_packet_sequences(&chan_end_path, (1..=3).map(Sequence::from))
+        .unwrap();
+    assert_eq!(unreceived, vec![1.into(), 3.into()]);
+}
+
+#[rstest]
+#[case(Order::Ordered)]
+#[case(Order::OrderedAllowTimeout)]
+fn ordered_packet_status_from_next_sequence_recv(#[case] ordering: Order) {
+    let ctx = context_with_channel(ordering).with_recv_sequence(
+        PortId::transfer(),
+        ChannelId::zero(),
+        3.into(),
+    );
+    let chan_end_path = channel_end_path();
+
+    assert!(ctx.is_packet_received(&chan_end_path, 2.into()).unwrap());
+    assert!(!ctx.is_packet_received(&chan_end_path, 3.into()).unwrap());
+
+    let unreceived = ctx
+        .unreceived_packet_sequences(&chan_end_path, (1..=4).map(Sequence::from))
+        .unwrap();
+    assert_eq!(unreceived, vec![3.into(), 4.into()]);
+}
+
+#[test]
+fn ordered_allow_timeout_packet_timed_out() {
+    let mut ctx = context_with_channel(Order::OrderedAllowTimeout).with_recv_sequence(
+        PortId::transfer(),
+        ChannelId::zero(),
+        3.into(),
+    );
+    let chan_end_path = channel_end_path();
+
+    ctx.store_packet_receipt(
+        &ReceiptPath::new(&chan_end_path.0, &chan_end_path.1, 2.into()),
+        Receipt::Timeout,
+    )
+    .unwrap();
+
+    assert_eq!(
+        ctx.packet_receipt_status(&chan_end_path, 2.into()).unwrap(),
+        Some(Receipt::Timeout)
+    );
+    assert!(!ctx.is_packet_received(&chan_end_path, 2.into()).unwrap());
+
+    // A timed out packet is not to be relayed anymore.
+    let unreceived = ctx
+        .unreceived_packet_sequences(&chan_end_path, (1..=3).map(Sequence::from))
+        .unwrap();
+    assert_eq!(unreceived, vec![3.into()]);
+}
+
+#[test]
+fn packet_acknowledgement_written() {
+    let mut ctx = context_with_channel(Order::Unordered);
+    let chan_end_path = channel_end_path();
+
+    ctx.store_packet_acknowledgement(
+        &AckPath::new(&chan_end_path.0, &chan_end_path.1, 1.into()),
+        AcknowledgementCommitment::from(vec![1]),
+    )
+    .unwrap();
+
+    assert!(ctx
+        .is_packet_acknowledgement_written(&chan_end_path, 1.into())
+        .unwrap());
+    assert!(!ctx
+        .is_packet_acknowledgement_written(&chan_end_path, 2.into())
+        .unwrap());
+}
+
+#[test]
+fn sent_packet_status_from_commitments() {
+    let ctx = context_with_channel(Order::Unordered)
+        .with_send_sequence(PortId::transfer(), ChannelId::zero(), 4.into())
+        .with_packet_commitment(
+            PortId::transfer(),
+            ChannelId::zero(),
+            2.into(),
+            PacketCommitment::from(vec![1]),
+        );
+    let chan_end_path = channel_end_path();
+
+    assert!(ctx
+        .is_packet_commitment_pending(&chan_end_path, 2.into())
+        .unwrap());
+
+    // Acknowledged or timed out packets have their commitment deleted.
+    assert!(ctx.is_packet_settled(&chan_end_path, 1.into()).unwrap());
+    assert!(!ctx.is_packet_settled(&chan_end_path, 2.into()).unwrap());
+
+    // Packets which are not sent yet are not settled either.
+    assert!(!ctx.is_packet_settled(&chan_end_path, 4.into()).unwrap());
+
+    let unreceived_acks = ctx
+        .unreceived_ack_sequences(&chan_end_path, (1..=3).map(Sequence::from))
+        .unwrap();
+    assert_eq!(unreceived_acks, vec![2.into()]);
+}
+
+#[test]
+fn batched_lookups_match_sequence_lookups() {
+    let mut ctx = context_with_channel(Order::Unordered);
+    let chan_end_path = channel_end_path();
+
+    // Spans several batches of the stored packet state.
+    let last = 3 * PACKET_SEQUENCES_BATCH_SIZE as u64;
+
+    for seq in (1..=last).filter(|seq| seq % 3 != 0) {
+        ctx.store_packet_receipt(
+            &ReceiptPath::new(&chan_end_path.0, &chan_end_path.1, seq.into()),
+            Receipt::Ok,
+        )
+        .unwrap();
+        ctx.store_packet_commitment(
+            &CommitmentPath::new(&chan_end_path.0, &chan_end_path.1, seq.into()),
+            PacketCommitment::from(vec![1]),
+        )
+        .unwrap();
+    }
+
+    let sequences: Vec<Sequence> = (1..=last + 2).rev().map(Sequence::from).collect();
+
+    assert_eq!(
+        ctx.unreceived_packet_sequences_batched(&chan_end_path, sequences.clone())
+            .unwrap(),
+        ctx.unreceived_packet_sequences(&chan_end_path, sequences.clone())
+            .unwrap()
+    );
+    assert_eq!(
+        ctx.unreceived_ack_sequences_batched(&chan_end_path, sequences.clone())
+            .unwrap(),
+        ctx.unreceived_ack_sequences(&chan_end_path, sequences)
+            .unwrap()
+    );
+}
@@ -0,0 +1,129 @@
+use core::ops::{Add, Sub};
+use core::time::Duration;
+
+use ibc::core::channel::handler::send_packet_validate;
+use ibc::core::channel::timeout::{PacketTimeout, PacketTimeoutBuilder};
+use ibc::core::channel::types::channel::{ChannelEnd, Counterparty, Order, State};
+use ibc::core::channel::types::packet::Packet;
+use ibc::core::channel::types::timeout::TimeoutHeight;
+use ibc::core::channel::types::Version;
+use ibc::core::client::types::Height;
+use ibc::core::commitment_types::commitment::CommitmentPrefix;
+use ibc::core::connection::types::version::Version as ConnectionVersion;
+use ibc::core::connection::types::{
+    ConnectionEnd, Counterparty as ConnectionCounterparty, State as ConnectionState,
+};
+use ibc::core::host::types::identifiers::{ChannelId, ClientId, ConnectionId, PortId};
+use ibc::core::host::types::path::ChannelEndPath;
+use ibc::core::host::HostClock;
+use ibc::core::primitives::*;
+use ibc_testkit::fixtures::core::channel::dummy_raw_packet;
+use ibc_testkit::testapp::ibc::core::types::{MockClientConfig, MockContext};
+use test_log::test;
+
+const CLIENT_HEIGHT: u64 = 5;
+
+/// Returns a context whose client of the destination chain was last updated
+/// the given time before the host clock.
+fn context_with_client_updated_before(elapsed: Duration) -> MockContext {
+    let client_id = ClientId::new("07-tendermint", 0).expect("no error");
+
+    let chan_end_on_a = ChannelEnd::new(
+        State::Open,
+        Order::Unordered,
+        Counterparty::new(PortId::transfer(), Some(ChannelId::zero())),
+        vec![ConnectionId::zero()],
+        Version::new("ics20-1".to_string()),
+    )
+    .unwrap();
+
+    let conn_end_on_a = ConnectionEnd::new(
+        ConnectionState::Open,
+        client_id.clone(),
+        ConnectionCounterparty::new(
+            client_id,
+            Some(ConnectionId::zero()),
+            CommitmentPrefix::empty(),
+        ),
+        ConnectionVersion::compatibles(),
+        ZERO_DURATION,
+    )
+    .unwrap();
+
+    let context = MockContext::default();
+    let latest_timestamp = context.host_timestamp().unwrap().sub(elapsed).unwrap();
+
+    context
+        .with_client_config(
+            MockClientConfig::builder()
+                .latest_height(Height::new(0, CLIENT_HEIGHT).unwrap())
+                .latest_timestamp(latest_timestamp)
+                .build(),
+        )
+        .with_connection(ConnectionId::zero(), conn_end_on_a)
+        .with_channel(PortId::transfer(), ChannelId::zero(), chan_end_on_a)
+        .with_send_sequence(PortId::transfer(), ChannelId::zero(), 1.into())
+}
+
+fn chan_end_path_on_a() -> ChannelEndPath {
+    ChannelEndPath::new(&PortId::transfer(), &ChannelId::zero())
+}
+
+#[test]
+fn timeout_after_counts_from_host_clock() {
+    let ctx = context_with_client_updated_before(Duration::from_secs(60));
+
+    let timeout =
+        PacketTimeout::after(&ctx, &chan_end_path_on_a(), Duration::from_secs(10)).unwrap();
+
+    // Counted from the client timestamp, the timeout would already have
+    // elapsed on the destination chain.
+    let expected_timestamp = ctx
+        .host_timestamp()
+        .unwrap()
+        .add(Duration::from_secs(10))
+        .unwrap();
+
+    assert_eq!(timeout.timeout_height_on_b, TimeoutHeight::Never);
+    assert_eq!(timeout.timeout_timestamp_on_b, expected_timestamp);
+
+    let mut packet: Packet = dummy_raw_packet(0, 0).try_into().unwrap();
+    packet.seq_on_a = 1.into();
+    packet.timeout_height_on_b = timeout.timeout_height_on_b;
+    packet.timeout_timestamp_on_b = timeout.timeout_timestamp_on_b;
+
+    assert!(send_packet_validate(&ctx, &packet).is_ok());
+}
+
+#[test]
+fn timeout_in_blocks_counts_from_client_height() {
+    let ctx = context_with_client_updated_before(Duration::from_secs(60));
+
+    let timeout = PacketTimeout::in_blocks(&ctx, &chan_end_path_on_a(), 10).unwrap();
+
+    assert_eq!(
+        timeout.timeout_height_on_b,
+        TimeoutHeight::At(Height::new(0, CLIENT_HEIGHT + 10).unwrap())
+    );
+    assert!(!timeout.timeout_timestamp_on_b.is_set());
+}
+
+#[test]
+fn timeout_in_blocks_estimates_counterparty_height() {
+    let ctx = context_with_client_updated_before(Duration::from_secs(60));
+
+    // The destination chain produced 20 blocks since the client was updated.
+    let timeout = PacketTimeoutBuilder::new(&ctx, &chan_end_path_on_a())
+        .unwrap()
+        .with_block_time_on_b(Duration::from_secs(3))
+        .in_blocks(10)
+        .after(Duration::from_secs(30))
+        .build()
+        .unwrap();
+
+    assert_eq!(
+        timeout.timeout_height_on_b,
+        TimeoutHeight::At(Height::new(0, CLIENT_HEIGHT + 20 + 10).unwrap())
+    );
+    assert!(timeout.timeout_timestamp_on_b.is_set());
+}
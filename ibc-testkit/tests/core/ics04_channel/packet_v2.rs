@@ -0,0 +1,298 @@
+use core::time::Duration;
+
+use ibc::core::channel::types::v2::acknowledgement::Acknowledgement;
+use ibc::core::channel::types::v2::counterparty::ClientCounterparty;
+use ibc::core::channel::types::v2::msgs::{
+    MsgAcknowledgement, MsgRecvPacket, MsgSendPacket, MsgTimeout, PacketMsgV2,
+};
+use ibc::core::channel::types::v2::packet::{timestamp_seconds, Packet, Payload};
+use ibc::core::channel::v2::context::PacketV2ValidationContext;
+use ibc::core::client::types::Height;
+use ibc::core::commitment_types::commitment::CommitmentPrefix;
+use ibc::core::entrypoint::{dispatch_v2, validate_v2};
+use ibc::core::handler::types::events::{IbcEvent, MessageEvent};
+use ibc::core::host::types::identifiers::{ClientId, PortId, Sequence};
+use ibc::core::host::types::path::{AckPathV2, CommitmentPathV2, ReceiptPathV2, SeqSendPathV2};
+use ibc::core::host::HostClock;
+use ibc::core::primitives::*;
+use ibc_testkit::fixtures::core::commitment::dummy_commitment_proof_bytes;
+use ibc_testkit::fixtures::core::signer::dummy_account_id;
+use ibc_testkit::testapp::ibc::core::router::MockRouter;
+use ibc_testkit::testapp::ibc::core::types::{MockClientConfig, MockContext};
+use rstest::*;
+use test_log::test;
+
+pub struct Fixture {
+    pub context: MockContext,
+    pub router: MockRouter,
+    pub client_id: ClientId,
+    pub counterparty_client_id: ClientId,
+    pub client_height: Height,
+}
+
+#[fixture]
+fn fixture() -> Fixture {
+    let client_id = ClientId::new("07-tendermint", 0).expect("no error");
+    let counterparty_client_id = ClientId::new("07-tendermint", 1).expect("no error");
+
+    let default_context = MockContext::default();
+    let client_height = default_context.host_height().unwrap();
+
+    // The counterparty chain is an hour ahead, so that the packets sent to
+    // it can be timed out.
+    let context = default_context
+        .with_client_config(
+            MockClientConfig::builder()
+                .client_id(client_id.clone())
+                .latest_height(client_height)
+                .latest_timestamp((Timestamp::now() + Duration::from_secs(3600)).unwrap())
+                .build(),
+        )
+        .with_client_counterparty(
+            client_id.clone(),
+            ClientCounterparty::new(
+                counterparty_client_id.clone(),
+                CommitmentPrefix::try_from(b"ibc".to_vec()).unwrap(),
+            ),
+        );
+
+    Fixture {
+        context,
+        router: MockRouter::new_with_transfer(),
+        client_id,
+        counterparty_client_id,
+        client_height,
+    }
+}
+
+fn dummy_payloads() -> Vec<Payload> {
+    vec![Payload {
+        port_id_on_a: PortId::transfer(),
+        port_id_on_b: PortId::transfer(),
+        version: "ics20-1".to_string(),
+        encoding: "application/json".to_string(),
+        value: vec![1],
+    }]
+}
+
+fn send_packet(context: &mut MockContext, router: &mut MockRouter, client_id: &ClientId) -> Packet {
+    let timeout_timestamp_on_b = timestamp_seconds(&context.host_timestamp().unwrap()) + 100;
+
+    let msg = MsgSendPacket {
+        client_id_on_a: client_id.clone(),
+        timeout_timestamp_on_b,
+        payloads: dummy_payloads(),
+        signer: dummy_account_id(),
+    };
+
+    dispatch_v2(context, router, PacketMsgV2::from(msg)).unwrap();
+
+    let Some(IbcEvent::SendPacketV2(event)) = context.get_events().last().cloned() else {
+        panic!("the packet is sent");
+    };
+
+    event.packet().clone()
+}
+
+#[rstest]
+fn send_packet_v2_happy_path(fixture: Fixture) {
+    let Fixture {
+        mut context,
+        mut router,
+        client_id,
+        counterparty_client_id,
+        ..
+    } = fixture;
+
+    let packet = send_packet(&mut context, &mut router, &client_id);
+
+    assert_eq!(packet.seq_on_a, Sequence::from(1));
+    assert_eq!(packet.client_id_on_b, counterparty_client_id);
+    assert!(context
+        .get_packet_commitment_v2(&CommitmentPathV2::new(&client_id, packet.seq_on_a))
+        .is_ok());
+    assert_eq!(
+        context
+            .get_next_sequence_send_v2(&SeqSendPathV2::new(&client_id))
+            .unwrap(),
+        Sequence::from(2)
+    );
+
+    let ibc_events = context.get_events();
+
+    assert_eq!(ibc_events.len(), 2);
+    assert!(matches!(
+        ibc_events[0],
+        IbcEvent::Message(MessageEvent::Channel)
+    ));
+}
+
+#[rstest]
+fn send_packet_v2_fail_timeout(fixture: Fixture) {
+    let Fixture {
+        context,
+        router,
+        client_id,
+        ..
+    } = fixture;
+
+    let host_timestamp = timestamp_seconds(&context.host_timestamp().unwrap());
+
+    for timeout_timestamp_on_b in [host_timestamp, host_timestamp + 2 * 24 * 60 * 60] {
+        let msg = MsgSendPacket {
+            client_id_on_a: client_id.clone(),
+            timeout_timestamp_on_b,
+            payloads: dummy_payloads(),
+            signer: dummy_account_id(),
+        };
+
+        let res = validate_v2(&context, &router, PacketMsgV2::from(msg));
+
+        assert!(res.is_err(), "the timeout is out of range");
+    }
+}
+
+#[rstest]
+fn recv_packet_v2_happy_path(fixture: Fixture) {
+    let Fixture {
+        mut context,
+        mut router,
+        client_id,
+        counterparty_client_id,
+        client_height,
+    } = fixture;
+
+    let packet = Packet {
+        seq_on_a: Sequence::from(1),
+        client_id_on_a: counterparty_client_id,
+        client_id_on_b: client_id.clone(),
+        timeout_timestamp_on_b: timestamp_seconds(&context.host_timestamp().unwrap()) + 100,
+        payloads: dummy_payloads(),
+    };
+
+    let msg = MsgRecvPacket {
+        packet: packet.clone(),
+        proof_commitment_on_a: dummy_commitment_proof_bytes(),
+        proof_height_on_a: client_height,
+        signer: dummy_account_id(),
+    };
+
+    let res = dispatch_v2(&mut context, &mut router, PacketMsgV2::from(msg.clone()));
+
+    assert!(res.is_ok(), "Happy path: recv should succeed. err: {res:?}");
+    assert!(context
+        .get_packet_receipt_v2(&ReceiptPathV2::new(&client_id, packet.seq_on_a))
+        .is_ok());
+    assert!(context
+        .get_packet_acknowledgement_v2(&AckPathV2::new(&client_id, packet.seq_on_a))
+        .is_ok());
+
+    let ibc_events = context.get_events();
+
+    assert_eq!(ibc_events.len(), 4);
+    assert!(matches!(ibc_events[1], IbcEvent::ReceivePacketV2(_)));
+    assert!(matches!(ibc_events[3], IbcEvent::WriteAcknowledgementV2(_)));
+
+    // Receiving the packet again is a no-op.
+    let res = dispatch_v2(&mut context, &mut router, PacketMsgV2::from(msg));
+
+    assert!(res.is_ok(), "Relaying the packet again is a no-op");
+    assert_eq!(context.get_events().len(), 4);
+}
+
+#[rstest]
+fn recv_packet_v2_fail_wrong_counterparty(fixture: Fixture) {
+    let Fixture {
+        context,
+        router,
+        client_id,
+        client_height,
+        ..
+    } = fixture;
+
+    let msg = MsgRecvPacket {
+        packet: Packet {
+            seq_on_a: Sequence::from(1),
+            client_id_on_a: ClientId::new("07-tendermint", 2).expect("no error"),
+            client_id_on_b: client_id,
+            timeout_timestamp_on_b: timestamp_seconds(&context.host_timestamp().unwrap()) + 100,
+            payloads: dummy_payloads(),
+        },
+        proof_commitment_on_a: dummy_commitment_proof_bytes(),
+        proof_height_on_a: client_height,
+        signer: dummy_account_id(),
+    };
+
+    let res = validate_v2(&context, &router, PacketMsgV2::from(msg));
+
+    assert!(
+        res.is_err(),
+        "the packet is not sent by the counterparty of the client"
+    );
+}
+
+#[rstest]
+fn acknowledgement_packet_v2_happy_path(fixture: Fixture) {
+    let Fixture {
+        mut context,
+        mut router,
+        client_id,
+        client_height,
+        ..
+    } = fixture;
+
+    let packet = send_packet(&mut context, &mut router, &client_id);
+
+    let msg = MsgAcknowledgement {
+        packet: packet.clone(),
+        acknowledgement: Acknowledgement::new(vec![vec![1]]).unwrap(),
+        proof_acked_on_b: dummy_commitment_proof_bytes(),
+        proof_height_on_b: client_height,
+        signer: dummy_account_id(),
+    };
+
+    let res = dispatch_v2(&mut context, &mut router, PacketMsgV2::from(msg));
+
+    assert!(res.is_ok(), "Happy path: ack should succeed. err: {res:?}");
+    assert!(context
+        .get_packet_commitment_v2(&CommitmentPathV2::new(&client_id, packet.seq_on_a))
+        .is_err());
+    assert!(matches!(
+        context.get_events().last(),
+        Some(IbcEvent::AcknowledgePacketV2(_))
+    ));
+}
+
+#[rstest]
+fn timeout_packet_v2_happy_path(fixture: Fixture) {
+    let Fixture {
+        mut context,
+        mut router,
+        client_id,
+        client_height,
+        ..
+    } = fixture;
+
+    let packet = send_packet(&mut context, &mut router, &client_id);
+
+    let msg = MsgTimeout {
+        packet: packet.clone(),
+        proof_unreceived_on_b: dummy_commitment_proof_bytes(),
+        proof_height_on_b: client_height,
+        signer: dummy_account_id(),
+    };
+
+    let res = dispatch_v2(&mut context, &mut router, PacketMsgV2::from(msg));
+
+    assert!(
+        res.is_ok(),
+        "Happy path: timeout should succeed. err: {res:?}"
+    );
+    assert!(context
+        .get_packet_commitment_v2(&CommitmentPathV2::new(&client_id, packet.seq_on_a))
+        .is_err());
+    assert!(matches!(
+        context.get_events().last(),
+        Some(IbcEvent::TimeoutPacketV2(_))
+    ));
+}
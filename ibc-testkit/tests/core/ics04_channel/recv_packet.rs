@@ -1,19 +1,24 @@
+use core::time::Duration;
+
 use ibc::core::channel::types::channel::{ChannelEnd, Counterparty, Order, State};
 use ibc::core::channel::types::msgs::{MsgRecvPacket, PacketMsg};
-use ibc::core::channel::types::packet::Packet;
+use ibc::core::channel::types::packet::{Packet, Receipt};
 use ibc::core::channel::types::Version;
 use ibc::core::client::context::ClientExecutionContext;
 use ibc::core::client::types::Height;
 use ibc::core::commitment_types::commitment::CommitmentPrefix;
+use ibc::core::connection::types::error::ConnectionError;
 use ibc::core::connection::types::version::Version as ConnectionVersion;
 use ibc::core::connection::types::{
     ConnectionEnd, Counterparty as ConnectionCounterparty, State as ConnectionState,
 };
-use ibc::core::entrypoint::{execute, validate};
+use ibc::core::entrypoint::{dispatch_recv_packet_batch, execute, validate};
+use ibc::core::handler::types::error::ContextError;
 use ibc::core::handler::types::events::{IbcEvent, MessageEvent};
 use ibc::core::handler::types::msgs::MsgEnvelope;
-use ibc::core::host::types::identifiers::{ChannelId, ClientId, ConnectionId, PortId};
-use ibc::core::host::ExecutionContext;
+use ibc::core::host::types::identifiers::{ChannelId, ClientId, ConnectionId, PortId, Sequence};
+use ibc::core::host::types::path::{ReceiptPath, SeqRecvPath};
+use ibc::core::host::{ExecutionContext, ValidationContext};
 use ibc::core::primitives::*;
 use ibc_testkit::fixtures::core::channel::{dummy_msg_recv_packet, dummy_raw_msg_recv_packet};
 use ibc_testkit::fixtures::core::signer::dummy_account_id;
@@ -164,6 +169,86 @@ fn recv_packet_validate_happy_path(fixture: Fixture) {
     )
 }
 
+/// Validates the message of the fixture over a connection with the given
+/// delay period, the client having been updated a block before the host
+/// height.
+fn validate_with_conn_delay(fixture: Fixture, delay_period: Duration) -> Result<(), ContextError> {
+    let Fixture {
+        context,
+        router,
+        msg,
+        conn_end_on_b,
+        chan_end_on_b,
+        client_height,
+        host_height,
+        client_id,
+    } = fixture;
+
+    let conn_end_on_b = ConnectionEnd::new(
+        *conn_end_on_b.state(),
+        conn_end_on_b.client_id().clone(),
+        conn_end_on_b.counterparty().clone(),
+        conn_end_on_b.versions().to_vec(),
+        delay_period,
+    )
+    .unwrap();
+
+    let packet = &msg.packet;
+    let mut context = context
+        .with_client_config(
+            MockClientConfig::builder()
+                .latest_height(client_height)
+                .build(),
+        )
+        .with_connection(ConnectionId::zero(), conn_end_on_b)
+        .with_channel(
+            packet.port_id_on_b.clone(),
+            packet.chan_id_on_b.clone(),
+            chan_end_on_b,
+        )
+        .with_height(host_height);
+
+    context
+        .get_client_execution_context()
+        .store_update_meta(
+            client_id,
+            client_height,
+            Timestamp::from_nanoseconds(1000).unwrap(),
+            host_height.decrement().unwrap(),
+        )
+        .unwrap();
+
+    validate(&context, &router, MsgEnvelope::from(PacketMsg::from(msg)))
+}
+
+#[rstest]
+fn recv_packet_validate_conn_delay_passed(fixture: Fixture) {
+    // A block delay period of a single block
+    let delay_period = fixture.context.max_expected_time_per_block();
+
+    let res = validate_with_conn_delay(fixture, delay_period);
+
+    assert!(res.is_ok(), "the delay period has passed. err: {res:?}")
+}
+
+#[rstest]
+fn recv_packet_validate_conn_delay_not_passed(fixture: Fixture) {
+    // A block delay period of two blocks
+    let delay_period = fixture.context.max_expected_time_per_block() * 2;
+
+    let res = validate_with_conn_delay(fixture, delay_period);
+
+    assert!(
+        matches!(
+            res,
+            Err(ContextError::ConnectionError(
+                ConnectionError::NotEnoughBlocksElapsed { .. }
+            ))
+        ),
+        "the block delay period has not passed. res: {res:?}"
+    )
+}
+
 #[rstest]
 fn recv_packet_timeout_expired(fixture: Fixture) {
     let Fixture {
@@ -256,3 +341,388 @@ fn recv_packet_execute_happy_path(fixture: Fixture) {
     ));
     assert!(matches!(&ibc_events[3], &IbcEvent::WriteAcknowledgement(_)));
 }
+
+#[rstest]
+fn recv_packet_ordered_validate_happy_path(fixture: Fixture) {
+    let Fixture {
+        context,
+        router,
+        msg,
+        conn_end_on_b,
+        mut chan_end_on_b,
+        client_height,
+        host_height,
+        client_id,
+        ..
+    } = fixture;
+
+    chan_end_on_b.ordering = Order::Ordered;
+
+    let packet = &msg.packet;
+    let mut context = context
+        .with_client_config(
+            MockClientConfig::builder()
+                .latest_height(client_height)
+                .build(),
+        )
+        .with_connection(ConnectionId::zero(), conn_end_on_b)
+        .with_channel(
+            packet.port_id_on_b.clone(),
+            packet.chan_id_on_b.clone(),
+            chan_end_on_b,
+        )
+        .with_height(host_height)
+        .with_recv_sequence(
+            packet.port_id_on_b.clone(),
+            packet.chan_id_on_b.clone(),
+            packet.seq_on_a,
+        );
+
+    context
+        .get_client_execution_context()
+        .store_update_meta(
+            client_id,
+            client_height,
+            Timestamp::from_nanoseconds(1000).unwrap(),
+            Height::new(0, 5).unwrap(),
+        )
+        .unwrap();
+
+    let msg_envelope = MsgEnvelope::from(PacketMsg::from(msg));
+
+    let res = validate(&context, &router, msg_envelope);
+
+    assert!(
+        res.is_ok(),
+        "Happy path: validation should succeed for the next expected sequence. err: {res:?}"
+    )
+}
+
+#[rstest]
+fn recv_packet_ordered_fail_sequence_gap(fixture: Fixture) {
+    let Fixture {
+        context,
+        router,
+        msg,
+        conn_end_on_b,
+        mut chan_end_on_b,
+        client_height,
+        host_height,
+        client_id,
+        ..
+    } = fixture;
+
+    chan_end_on_b.ordering = Order::Ordered;
+
+    let packet = &msg.packet;
+    let mut context = context
+        .with_client_config(
+            MockClientConfig::builder()
+                .latest_height(client_height)
+                .build(),
+        )
+        .with_connection(ConnectionId::zero(), conn_end_on_b)
+        .with_channel(
+            packet.port_id_on_b.clone(),
+            packet.chan_id_on_b.clone(),
+            chan_end_on_b,
+        )
+        .with_height(host_height)
+        // The packets preceding the one being received are still pending
+        .with_recv_sequence(
+            packet.port_id_on_b.clone(),
+            packet.chan_id_on_b.clone(),
+            Sequence::from(0),
+        );
+
+    context
+        .get_client_execution_context()
+        .store_update_meta(
+            client_id,
+            client_height,
+            Timestamp::from_nanoseconds(1000).unwrap(),
+            Height::new(0, 5).unwrap(),
+        )
+        .unwrap();
+
+    let msg_envelope = MsgEnvelope::from(PacketMsg::from(msg));
+
+    let res = validate(&context, &router, msg_envelope);
+
+    assert!(
+        res.is_err(),
+        "Validation fails because the packet is received out of order"
+    )
+}
+
+#[rstest]
+fn recv_packet_ordered_execute_happy_path(fixture: Fixture) {
+    let Fixture {
+        context,
+        mut router,
+        msg,
+        conn_end_on_b,
+        mut chan_end_on_b,
+        client_height,
+        ..
+    } = fixture;
+
+    chan_end_on_b.ordering = Order::Ordered;
+
+    let packet = msg.packet.clone();
+    let mut ctx = context
+        .with_client_config(
+            MockClientConfig::builder()
+                .latest_height(client_height)
+                .build(),
+        )
+        .with_connection(ConnectionId::zero(), conn_end_on_b)
+        .with_channel(
+            packet.port_id_on_b.clone(),
+            packet.chan_id_on_b.clone(),
+            chan_end_on_b,
+        )
+        .with_recv_sequence(
+            packet.port_id_on_b.clone(),
+            packet.chan_id_on_b.clone(),
+            packet.seq_on_a,
+        );
+
+    let msg_env = MsgEnvelope::from(PacketMsg::from(msg));
+
+    let res = execute(&mut ctx, &mut router, msg_env);
+
+    assert!(res.is_ok());
+
+    let next_seq_recv = ctx
+        .get_next_sequence_recv(&SeqRecvPath::new(
+            &packet.port_id_on_b,
+            &packet.chan_id_on_b,
+        ))
+        .unwrap();
+    assert_eq!(next_seq_recv, packet.seq_on_a.increment());
+
+    let ibc_events = ctx.get_events();
+
+    assert_eq!(ibc_events.len(), 4);
+    assert!(matches!(&ibc_events[1], &IbcEvent::ReceivePacket(_)));
+    assert!(matches!(&ibc_events[3], &IbcEvent::WriteAcknowledgement(_)));
+}
+
+#[rstest]
+fn recv_packet_ordered_execute_already_received(fixture: Fixture) {
+    let Fixture {
+        context,
+        mut router,
+        msg,
+        conn_end_on_b,
+        mut chan_end_on_b,
+        client_height,
+        ..
+    } = fixture;
+
+    chan_end_on_b.ordering = Order::Ordered;
+
+    let packet = msg.packet.clone();
+    let mut ctx = context
+        .with_client_config(
+            MockClientConfig::builder()
+                .latest_height(client_height)
+                .build(),
+        )
+        .with_connection(ConnectionId::zero(), conn_end_on_b)
+        .with_channel(
+            packet.port_id_on_b.clone(),
+            packet.chan_id_on_b.clone(),
+            chan_end_on_b,
+        )
+        .with_recv_sequence(
+            packet.port_id_on_b.clone(),
+            packet.chan_id_on_b.clone(),
+            packet.seq_on_a.increment(),
+        );
+
+    let msg_env = MsgEnvelope::from(PacketMsg::from(msg));
+
+    let res = execute(&mut ctx, &mut router, msg_env);
+
+    assert!(res.is_ok());
+
+    let next_seq_recv = ctx
+        .get_next_sequence_recv(&SeqRecvPath::new(
+            &packet.port_id_on_b,
+            &packet.chan_id_on_b,
+        ))
+        .unwrap();
+    assert_eq!(next_seq_recv, packet.seq_on_a.increment());
+
+    // A packet relayed twice is a no-op
+    assert!(ctx.get_events().is_empty());
+}
+
+#[rstest]
+fn recv_packet_ordered_allow_timeout_execute_timed_out(fixture: Fixture) {
+    let Fixture {
+        context,
+        mut router,
+        msg,
+        conn_end_on_b,
+        mut chan_end_on_b,
+        client_height,
+        ..
+    } = fixture;
+
+    chan_end_on_b.ordering = Order::OrderedAllowTimeout;
+
+    let packet_old = Packet {
+        seq_on_a: 1.into(),
+        port_id_on_a: PortId::transfer(),
+        chan_id_on_a: ChannelId::zero(),
+        port_id_on_b: PortId::transfer(),
+        chan_id_on_b: ChannelId::zero(),
+        data: Vec::new(),
+        timeout_height_on_b: client_height.into(),
+        timeout_timestamp_on_b: Timestamp::from_nanoseconds(1).unwrap(),
+    };
+
+    let msg_packet_old = dummy_msg_recv_packet(
+        packet_old.clone(),
+        msg.proof_commitment_on_a,
+        msg.proof_height_on_a,
+        dummy_account_id(),
+    );
+
+    let mut ctx = context
+        .with_client_config(
+            MockClientConfig::builder()
+                .latest_height(client_height)
+                .build(),
+        )
+        .with_connection(ConnectionId::zero(), conn_end_on_b)
+        .with_channel(PortId::transfer(), ChannelId::zero(), chan_end_on_b)
+        .with_recv_sequence(PortId::transfer(), ChannelId::zero(), packet_old.seq_on_a);
+
+    let msg_env = MsgEnvelope::from(PacketMsg::from(msg_packet_old));
+
+    let res = execute(&mut ctx, &mut router, msg_env);
+
+    assert!(res.is_ok());
+
+    // The sequence of the timed out packet is skipped
+    let next_seq_recv = ctx
+        .get_next_sequence_recv(&SeqRecvPath::new(
+            &packet_old.port_id_on_b,
+            &packet_old.chan_id_on_b,
+        ))
+        .unwrap();
+    assert_eq!(next_seq_recv, packet_old.seq_on_a.increment());
+
+    let receipt = ctx
+        .get_packet_receipt(&ReceiptPath::new(
+            &packet_old.port_id_on_b,
+            &packet_old.chan_id_on_b,
+            packet_old.seq_on_a,
+        ))
+        .unwrap();
+    assert_eq!(receipt, Receipt::Timeout);
+
+    // No acknowledgement is written for a timed out packet
+    assert!(ctx.get_events().is_empty());
+}
+
+#[rstest]
+fn recv_packet_batch_ordered_happy_path(fixture: Fixture) {
+    let Fixture {
+        context,
+        mut router,
+        msg,
+        conn_end_on_b,
+        mut chan_end_on_b,
+        client_height,
+        host_height,
+        client_id,
+        ..
+    } = fixture;
+
+    chan_end_on_b.ordering = Order::Ordered;
+
+    let packet = msg.packet.clone();
+    let mut ctx = context
+        .with_client_config(
+            MockClientConfig::builder()
+                .latest_height(client_height)
+                .build(),
+        )
+        .with_connection(ConnectionId::zero(), conn_end_on_b)
+        .with_channel(
+            packet.port_id_on_b.clone(),
+            packet.chan_id_on_b.clone(),
+            chan_end_on_b,
+        )
+        .with_height(host_height)
+        .with_recv_sequence(
+            packet.port_id_on_b.clone(),
+            packet.chan_id_on_b.clone(),
+            packet.seq_on_a,
+        );
+
+    ctx.get_client_execution_context()
+        .store_update_meta(
+            client_id,
+            client_height,
+            Timestamp::from_nanoseconds(1000).unwrap(),
+            Height::new(0, 5).unwrap(),
+        )
+        .unwrap();
+
+    // The second packet is only received once the first one is.
+    let mut next_msg = msg.clone();
+    next_msg.packet.seq_on_a = packet.seq_on_a.increment();
+
+    let res = dispatch_recv_packet_batch(&mut ctx, &mut router, vec![msg, next_msg]);
+
+    assert!(
+        res.is_ok(),
+        "Happy path: batch should succeed. err: {res:?}"
+    );
+
+    let next_seq_recv = ctx
+        .get_next_sequence_recv(&SeqRecvPath::new(
+            &packet.port_id_on_b,
+            &packet.chan_id_on_b,
+        ))
+        .unwrap();
+    assert_eq!(next_seq_recv, packet.seq_on_a.increment().increment());
+
+    let ibc_events = ctx.get_events();
+
+    assert_eq!(ibc_events.len(), 8);
+    assert!(matches!(&ibc_events[1], &IbcEvent::ReceivePacket(_)));
+    assert!(matches!(&ibc_events[5], &IbcEvent::ReceivePacket(_)));
+}
+
+#[rstest]
+fn recv_packet_batch_fail_different_channels(fixture: Fixture) {
+    let Fixture {
+        context,
+        mut router,
+        msg,
+        ..
+    } = fixture;
+
+    let mut ctx = context;
+
+    let mut other_msg = msg.clone();
+    other_msg.packet.chan_id_on_b = ChannelId::new(42);
+
+    let res = dispatch_recv_packet_batch(&mut ctx, &mut router, vec![msg, other_msg]);
+
+    assert!(
+        res.is_err(),
+        "Batch fails because the packets are received over different channels"
+    );
+
+    let res = dispatch_recv_packet_batch(&mut ctx, &mut router, vec![]);
+
+    assert!(res.is_err(), "Batch fails because it is empty");
+}
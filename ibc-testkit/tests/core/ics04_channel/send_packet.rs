@@ -3,6 +3,7 @@ use core::time::Duration;
 
 use ibc::core::channel::handler::send_packet;
 use ibc::core::channel::types::channel::{ChannelEnd, Counterparty, Order, State};
+use ibc::core::channel::types::events::PacketDataEventMode;
 use ibc::core::channel::types::packet::Packet;
 use ibc::core::channel::types::Version;
 use ibc::core::client::types::Height;
@@ -103,6 +104,38 @@ fn send_packet_processing() {
                 .with_connection(ConnectionId::zero(), conn_end_on_a.clone())
                 .with_channel(PortId::transfer(), ChannelId::zero(), chan_end_on_a.clone())
                 .with_send_sequence(PortId::transfer(), ChannelId::zero(), 1.into()),
+            packet: packet.clone(),
+            want_pass: true,
+        },
+        Test {
+            name: "Packet data larger than the maximum size".to_string(),
+            ctx: context
+                .clone()
+                .with_client_config(
+                    MockClientConfig::builder()
+                        .latest_height(client_height)
+                        .build(),
+                )
+                .with_connection(ConnectionId::zero(), conn_end_on_a.clone())
+                .with_channel(PortId::transfer(), ChannelId::zero(), chan_end_on_a.clone())
+                .with_send_sequence(PortId::transfer(), ChannelId::zero(), 1.into())
+                .with_max_packet_data_size(0),
+            packet: packet.clone(),
+            want_pass: false,
+        },
+        Test {
+            name: "Packet data emitted hashed in the events".to_string(),
+            ctx: context
+                .clone()
+                .with_client_config(
+                    MockClientConfig::builder()
+                        .latest_height(client_height)
+                        .build(),
+                )
+                .with_connection(ConnectionId::zero(), conn_end_on_a.clone())
+                .with_channel(PortId::transfer(), ChannelId::zero(), chan_end_on_a.clone())
+                .with_send_sequence(PortId::transfer(), ChannelId::zero(), 1.into())
+                .with_packet_data_event_mode(PacketDataEventMode::Hashed),
             packet,
             want_pass: true,
         },
@@ -178,6 +211,9 @@ fn send_packet_processing() {
                 ));
                 // TODO: The object in the output is a PacketResult what can we check on it?
                 assert!(matches!(&ibc_events[1], &IbcEvent::SendPacket(_)));
+                if let IbcEvent::SendPacket(event) = &ibc_events[1] {
+                    assert_eq!(event.packet_data_mode(), test.ctx.packet_data_event_mode);
+                }
             }
             Err(e) => {
                 assert!(
@@ -13,7 +13,8 @@ use ibc::core::entrypoint::{execute, validate};
 use ibc::core::handler::types::events::{IbcEvent, MessageEvent};
 use ibc::core::handler::types::msgs::MsgEnvelope;
 use ibc::core::host::types::identifiers::{ChannelId, ClientId, ConnectionId, PortId};
-use ibc::core::host::ExecutionContext;
+use ibc::core::host::types::path::{ChannelEndPath, SeqAckPath};
+use ibc::core::host::{ExecutionContext, ValidationContext};
 use ibc::core::primitives::*;
 use ibc_testkit::fixtures::core::channel::dummy_raw_msg_timeout;
 use ibc_testkit::testapp::ibc::core::router::MockRouter;
@@ -353,6 +354,58 @@ fn timeout_ordered_channel_validate(fixture: Fixture) {
     assert!(res.is_ok(), "Good parameters for unordered channels")
 }
 
+#[rstest]
+fn timeout_ordered_channel_fail_packet_already_received(fixture: Fixture) {
+    let Fixture {
+        ctx,
+        router,
+        mut msg,
+        chan_end_on_a_ordered,
+        conn_end_on_a,
+        packet_commitment,
+        client_height,
+        client_id,
+        ..
+    } = fixture;
+
+    let packet = msg.packet.clone();
+
+    // Chain B already received the packet, so it cannot time out
+    msg.next_seq_recv_on_b = packet.seq_on_a.increment();
+
+    let mut ctx = ctx
+        .with_client_config(
+            MockClientConfig::builder()
+                .latest_height(client_height)
+                .build(),
+        )
+        .with_connection(ConnectionId::zero(), conn_end_on_a)
+        .with_channel(PortId::transfer(), ChannelId::zero(), chan_end_on_a_ordered)
+        .with_packet_commitment(
+            packet.port_id_on_a,
+            packet.chan_id_on_a,
+            packet.seq_on_a,
+            packet_commitment,
+        );
+
+    ctx.store_update_meta(
+        client_id,
+        client_height,
+        Timestamp::from_nanoseconds(1000).unwrap(),
+        Height::new(0, 4).unwrap(),
+    )
+    .unwrap();
+
+    let msg_envelope = MsgEnvelope::from(PacketMsg::from(msg));
+
+    let res = validate(&ctx, &router, msg_envelope);
+
+    assert!(
+        res.is_err(),
+        "Validation fails because the packet was already received on an ordered channel"
+    )
+}
+
 #[rstest]
 fn timeout_unordered_chan_execute(fixture: Fixture) {
     let Fixture {
@@ -416,12 +469,19 @@ fn timeout_ordered_chan_execute(fixture: Fixture) {
             packet_commitment,
         );
 
+    let chan_end_path_on_a =
+        ChannelEndPath::new(&msg.packet.port_id_on_a, &msg.packet.chan_id_on_a);
+
     let msg_envelope = MsgEnvelope::from(PacketMsg::from(msg));
 
     let res = execute(&mut ctx, &mut router, msg_envelope);
 
     assert!(res.is_ok());
 
+    // Timing out a packet closes an ordered channel
+    let chan_end_on_a = ctx.channel_end(&chan_end_path_on_a).unwrap();
+    assert_eq!(chan_end_on_a.state, State::Closed);
+
     let ibc_events = ctx.get_events();
 
     // Ordered channels emit 2 events
@@ -437,3 +497,64 @@ fn timeout_ordered_chan_execute(fixture: Fixture) {
     ));
     assert!(matches!(ibc_events[3], IbcEvent::ChannelClosed(_)));
 }
+
+#[rstest]
+fn timeout_ordered_allow_timeout_chan_execute(fixture: Fixture) {
+    let Fixture {
+        ctx,
+        mut router,
+        msg,
+        packet_commitment,
+        conn_end_on_a,
+        mut chan_end_on_a_ordered,
+        ..
+    } = fixture;
+
+    chan_end_on_a_ordered.ordering = Order::OrderedAllowTimeout;
+
+    let mut ctx = ctx
+        .with_channel(PortId::transfer(), ChannelId::zero(), chan_end_on_a_ordered)
+        .with_connection(ConnectionId::zero(), conn_end_on_a)
+        .with_packet_commitment(
+            msg.packet.port_id_on_a.clone(),
+            msg.packet.chan_id_on_a.clone(),
+            msg.packet.seq_on_a,
+            packet_commitment,
+        )
+        .with_ack_sequence(
+            msg.packet.port_id_on_a.clone(),
+            msg.packet.chan_id_on_a.clone(),
+            msg.packet.seq_on_a,
+        );
+
+    let packet = msg.packet.clone();
+
+    let msg_envelope = MsgEnvelope::from(PacketMsg::from(msg));
+
+    let res = execute(&mut ctx, &mut router, msg_envelope);
+
+    assert!(res.is_ok());
+
+    // The channel end stays open, and moves on to the next packet
+    let chan_end_on_a = ctx
+        .channel_end(&ChannelEndPath::new(
+            &packet.port_id_on_a,
+            &packet.chan_id_on_a,
+        ))
+        .unwrap();
+    assert_eq!(chan_end_on_a.state, State::Open);
+
+    let next_seq_ack = ctx
+        .get_next_sequence_ack(&SeqAckPath::new(&packet.port_id_on_a, &packet.chan_id_on_a))
+        .unwrap();
+    assert_eq!(next_seq_ack, packet.seq_on_a.increment());
+
+    let ibc_events = ctx.get_events();
+
+    assert_eq!(ibc_events.len(), 2);
+    assert!(matches!(
+        ibc_events[0],
+        IbcEvent::Message(MessageEvent::Channel)
+    ));
+    assert!(matches!(ibc_events[1], IbcEvent::TimeoutPacket(_)));
+}
@@ -1,20 +1,24 @@
+use ibc::core::channel::handler::packet_proof_checks;
 use ibc::core::channel::types::channel::{ChannelEnd, Counterparty, Order, State};
 use ibc::core::channel::types::commitment::{compute_packet_commitment, PacketCommitment};
+use ibc::core::channel::types::error::{ChannelError, PacketError};
 use ibc::core::channel::types::msgs::{MsgTimeoutOnClose, PacketMsg};
 use ibc::core::channel::types::Version;
 use ibc::core::client::context::ClientExecutionContext;
 use ibc::core::client::types::Height;
-use ibc::core::commitment_types::commitment::CommitmentPrefix;
+use ibc::core::commitment_types::commitment::{CommitmentPrefix, CommitmentProofBytes};
 use ibc::core::connection::types::version::Version as ConnectionVersion;
 use ibc::core::connection::types::{
     ConnectionEnd, Counterparty as ConnectionCounterparty, State as ConnectionState,
 };
 use ibc::core::entrypoint::validate;
+use ibc::core::handler::types::error::ContextError;
 use ibc::core::handler::types::msgs::MsgEnvelope;
 use ibc::core::host::types::identifiers::{ChannelId, ClientId, ConnectionId, PortId};
-use ibc::core::host::ExecutionContext;
+use ibc::core::host::{ExecutionContext, ProofKind};
 use ibc::core::primitives::*;
 use ibc_testkit::fixtures::core::channel::dummy_raw_msg_timeout_on_close;
+use ibc_testkit::testapp::ibc::clients::mock::behaviour::MockClientBehaviour;
 use ibc_testkit::testapp::ibc::core::router::MockRouter;
 use ibc_testkit::testapp::ibc::core::types::{MockClientConfig, MockContext};
 use rstest::*;
@@ -173,3 +177,102 @@ fn timeout_on_close_success_happy_path(fixture: Fixture) {
         "Happy path: validation should succeed. err: {res:?}"
     )
 }
+
+/// The closed channel end is verified against the close proof, so that the
+/// message fails if the close proof alone is invalid
+#[rstest]
+fn timeout_on_close_fail_invalid_close_proof(fixture: Fixture) {
+    let default_client_id = ClientId::new("07-tendermint", 0).expect("no error");
+
+    let Fixture {
+        router,
+        mut msg,
+        packet_commitment,
+        conn_end_on_a,
+        chan_end_on_a,
+        ..
+    } = fixture;
+    msg.proof_unreceived_on_b = CommitmentProofBytes::try_from(vec![1]).unwrap();
+    msg.proof_close_on_b = CommitmentProofBytes::try_from(vec![2]).unwrap();
+
+    let mut context = MockContext::default()
+        .with_client_config(
+            MockClientConfig::builder()
+                .latest_height(Height::new(0, 2).unwrap())
+                .behaviour(MockClientBehaviour::default().reject_proof(&[2]))
+                .build(),
+        )
+        .with_channel(PortId::transfer(), ChannelId::zero(), chan_end_on_a)
+        .with_connection(ConnectionId::zero(), conn_end_on_a)
+        .with_packet_commitment(
+            msg.packet.port_id_on_a.clone(),
+            msg.packet.chan_id_on_a.clone(),
+            msg.packet.seq_on_a,
+            packet_commitment,
+        );
+
+    context
+        .get_client_execution_context()
+        .store_update_meta(
+            default_client_id,
+            Height::new(0, 2).unwrap(),
+            Timestamp::from_nanoseconds(5000).unwrap(),
+            Height::new(0, 5).unwrap(),
+        )
+        .unwrap();
+
+    let msg_envelope = MsgEnvelope::from(PacketMsg::from(msg));
+
+    let res = validate(&context, &router, msg_envelope);
+
+    assert!(
+        matches!(
+            res,
+            Err(ContextError::PacketError(PacketError::Channel(
+                ChannelError::VerifyChannelFailed { .. }
+            )))
+        ),
+        "Validation fails because the close proof is invalid. res: {res:?}"
+    )
+}
+
+/// The closed channel end and the unreceived packet are each checked against
+/// their own proof ahead of the dispatch
+#[rstest]
+fn timeout_on_close_proof_checks(fixture: Fixture) {
+    let Fixture {
+        context,
+        mut msg,
+        packet_commitment,
+        conn_end_on_a,
+        chan_end_on_a,
+        ..
+    } = fixture;
+    msg.proof_unreceived_on_b = CommitmentProofBytes::try_from(vec![1]).unwrap();
+    msg.proof_close_on_b = CommitmentProofBytes::try_from(vec![2]).unwrap();
+
+    let context = context
+        .with_channel(PortId::transfer(), ChannelId::zero(), chan_end_on_a)
+        .with_connection(ConnectionId::zero(), conn_end_on_a)
+        .with_packet_commitment(
+            msg.packet.port_id_on_a.clone(),
+            msg.packet.chan_id_on_a.clone(),
+            msg.packet.seq_on_a,
+            packet_commitment,
+        );
+
+    let checks = packet_proof_checks(&context, &PacketMsg::from(msg.clone()));
+    let proof_of = |kind| {
+        checks
+            .iter()
+            .find(|check| check.kind == kind)
+            .map(|check| &check.proof)
+    };
+
+    assert_eq!(checks.len(), 2);
+    assert_eq!(proof_of(ProofKind::Channel), Some(&msg.proof_close_on_b));
+    assert_eq!(
+        proof_of(ProofKind::NextSequenceRecv),
+        Some(&msg.proof_unreceived_on_b)
+    );
+}
@@ -0,0 +1,168 @@
+use ibc::core::channel::handler::write_acknowledgement;
+use ibc::core::channel::types::acknowledgement::Acknowledgement;
+use ibc::core::channel::types::channel::{ChannelEnd, Counterparty, Order, State};
+use ibc::core::channel::types::packet::{Packet, Receipt};
+use ibc::core::channel::types::Version;
+use ibc::core::handler::types::events::{IbcEvent, MessageEvent};
+use ibc::core::host::types::identifiers::{ChannelId, ConnectionId, PortId};
+use ibc::core::host::types::path::{AckPath, ReceiptPath};
+use ibc::core::host::{ExecutionContext, ValidationContext};
+use ibc_testkit::fixtures::core::channel::dummy_raw_packet;
+use ibc_testkit::testapp::ibc::core::types::MockContext;
+use rstest::*;
+use test_log::test;
+
+struct Fixture {
+    ctx: MockContext,
+    packet: Packet,
+    acknowledgement: Acknowledgement,
+}
+
+#[fixture]
+fn fixture() -> Fixture {
+    let packet = Packet::try_from(dummy_raw_packet(10, 0)).unwrap();
+
+    let chan_end_on_b = ChannelEnd::new(
+        State::Open,
+        Order::Ordered,
+        Counterparty::new(
+            packet.port_id_on_a.clone(),
+            Some(packet.chan_id_on_a.clone()),
+        ),
+        vec![ConnectionId::zero()],
+        Version::new("ics20-1".to_string()),
+    )
+    .unwrap();
+
+    let ctx =
+        MockContext::default().with_channel(PortId::transfer(), ChannelId::zero(), chan_end_on_b);
+
+    Fixture {
+        ctx,
+        packet,
+        acknowledgement: Acknowledgement::try_from(vec![1u8]).unwrap(),
+    }
+}
+
+#[rstest]
+fn write_acknowledgement_happy_path(fixture: Fixture) {
+    let Fixture {
+        ctx,
+        packet,
+        acknowledgement,
+    } = fixture;
+
+    // The packet was received, without being acknowledged
+    let mut ctx = ctx.with_recv_sequence(
+        packet.port_id_on_b.clone(),
+        packet.chan_id_on_b.clone(),
+        packet.seq_on_a.increment(),
+    );
+
+    let res = write_acknowledgement(&mut ctx, packet.clone(), acknowledgement);
+
+    assert!(
+        res.is_ok(),
+        "Happy path: writing the acknowledgement should succeed. err: {res:?}"
+    );
+
+    let ack_path_on_b = AckPath::new(&packet.port_id_on_b, &packet.chan_id_on_b, packet.seq_on_a);
+    assert!(ctx.get_packet_acknowledgement(&ack_path_on_b).is_ok());
+
+    let ibc_events = ctx.get_events();
+
+    assert_eq!(ibc_events.len(), 2);
+    assert!(matches!(
+        ibc_events[0],
+        IbcEvent::Message(MessageEvent::Channel)
+    ));
+    assert!(matches!(ibc_events[1], IbcEvent::WriteAcknowledgement(_)));
+}
+
+#[rstest]
+fn write_acknowledgement_fail_already_written(fixture: Fixture) {
+    let Fixture {
+        ctx,
+        packet,
+        acknowledgement,
+    } = fixture;
+
+    let mut ctx = ctx.with_recv_sequence(
+        packet.port_id_on_b.clone(),
+        packet.chan_id_on_b.clone(),
+        packet.seq_on_a.increment(),
+    );
+
+    write_acknowledgement(&mut ctx, packet.clone(), acknowledgement.clone()).unwrap();
+
+    let res = write_acknowledgement(&mut ctx, packet, acknowledgement);
+
+    assert!(
+        res.is_err(),
+        "Writing the acknowledgement fails because it was already written"
+    )
+}
+
+#[rstest]
+fn write_acknowledgement_fail_packet_not_received(fixture: Fixture) {
+    let Fixture {
+        ctx,
+        packet,
+        acknowledgement,
+    } = fixture;
+
+    let mut ctx = ctx.with_recv_sequence(
+        packet.port_id_on_b.clone(),
+        packet.chan_id_on_b.clone(),
+        packet.seq_on_a,
+    );
+
+    let res = write_acknowledgement(&mut ctx, packet, acknowledgement);
+
+    assert!(
+        res.is_err(),
+        "Writing the acknowledgement fails because the packet was not received"
+    )
+}
+
+#[rstest]
+fn write_acknowledgement_fail_packet_timed_out(fixture: Fixture) {
+    let Fixture {
+        ctx,
+        packet,
+        acknowledgement,
+    } = fixture;
+
+    let chan_end_on_b = ChannelEnd::new(
+        State::Open,
+        Order::OrderedAllowTimeout,
+        Counterparty::new(
+            packet.port_id_on_a.clone(),
+            Some(packet.chan_id_on_a.clone()),
+        ),
+        vec![ConnectionId::zero()],
+        Version::new("ics20-1".to_string()),
+    )
+    .unwrap();
+
+    // The sequence of the packet was skipped, as it timed out
+    let mut ctx = ctx
+        .with_channel(PortId::transfer(), ChannelId::zero(), chan_end_on_b)
+        .with_recv_sequence(
+            packet.port_id_on_b.clone(),
+            packet.chan_id_on_b.clone(),
+            packet.seq_on_a.increment(),
+        );
+    ctx.store_packet_receipt(
+        &ReceiptPath::new(&packet.port_id_on_b, &packet.chan_id_on_b, packet.seq_on_a),
+        Receipt::Timeout,
+    )
+    .unwrap();
+
+    let res = write_acknowledgement(&mut ctx, packet, acknowledgement);
+
+    assert!(
+        res.is_err(),
+        "Writing the acknowledgement fails because the packet timed out"
+    )
+}
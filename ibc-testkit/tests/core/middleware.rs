@@ -0,0 +1,70 @@
+use ibc::core::channel::types::acknowledgement::Acknowledgement;
+use ibc::core::channel::types::error::PacketError;
+use ibc::core::channel::types::packet::Packet;
+use ibc::core::primitives::prelude::*;
+use ibc::core::primitives::Signer;
+use ibc::core::router::middleware::{merge_extras, Middleware, ModuleStack};
+use ibc::core::router::module::Module;
+use ibc::core::router::types::module::ModuleExtras;
+use ibc_testkit::fixtures::core::channel::dummy_raw_packet;
+use ibc_testkit::fixtures::core::signer::dummy_account_id;
+use ibc_testkit::testapp::ibc::applications::transfer::types::DummyTransferModule;
+use test_log::test;
+
+/// A middleware which logs its name whenever it hooks into a packet.
+#[derive(Debug)]
+struct NamedMiddleware(&'static str);
+
+impl NamedMiddleware {
+    fn extras(&self) -> ModuleExtras {
+        ModuleExtras {
+            events: vec![],
+            log: vec![self.0.to_string()],
+        }
+    }
+}
+
+impl Middleware for NamedMiddleware {
+    fn on_send_packet_execute(&mut self, _packet: &Packet) -> Result<ModuleExtras, PacketError> {
+        Ok(self.extras())
+    }
+
+    fn on_recv_packet_execute(
+        &mut self,
+        next: &mut dyn Module,
+        packet: &Packet,
+        relayer: &Signer,
+    ) -> (ModuleExtras, Option<Acknowledgement>) {
+        let extras = self.extras();
+        let (next_extras, ack) = next.on_recv_packet_execute(packet, relayer);
+
+        (merge_extras(extras, next_extras), ack)
+    }
+}
+
+fn stack() -> impl Module {
+    let stack = ModuleStack::new(NamedMiddleware("inner"), DummyTransferModule::new());
+    ModuleStack::new(NamedMiddleware("outer"), stack)
+}
+
+#[test]
+fn stack_recv_packet_from_outermost_middleware() {
+    let mut stack = stack();
+    let packet = Packet::try_from(dummy_raw_packet(10, 0)).unwrap();
+
+    let (extras, ack) = stack.on_recv_packet_execute(&packet, &dummy_account_id());
+
+    assert_eq!(extras.log, vec!["outer".to_string(), "inner".to_string()]);
+    // The acknowledgement of the application is passed through.
+    assert_eq!(ack, Some(Acknowledgement::try_from(vec![1u8]).unwrap()));
+}
+
+#[test]
+fn stack_send_packet_from_application() {
+    let mut stack = stack();
+    let packet = Packet::try_from(dummy_raw_packet(10, 0)).unwrap();
+
+    let extras = stack.on_send_packet_execute(&packet).unwrap();
+
+    assert_eq!(extras.log, vec!["inner".to_string(), "outer".to_string()]);
+}
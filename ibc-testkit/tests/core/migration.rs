@@ -0,0 +1,91 @@
+use ibc::core::handler::types::error::{ContextError, MigrationError};
+use ibc::core::host::{
+    ExecutionContext, MigrationContext, Migrator, ValidationContext, INITIAL_STORE_VERSION,
+};
+use ibc::core::primitives::prelude::*;
+use ibc_testkit::testapp::ibc::core::types::MockContext;
+use test_log::test;
+
+fn migrator() -> Migrator<MockContext> {
+    let mut migrator = Migrator::new();
+
+    migrator
+        .register(1, |ctx: &mut MockContext| {
+            ctx.log_message("migrated to v2".to_string())
+        })
+        .unwrap();
+    migrator
+        .register(2, |ctx: &mut MockContext| ctx.increase_client_counter())
+        .unwrap();
+
+    migrator
+}
+
+#[test]
+fn test_migrate_store() {
+    let mut ctx = MockContext::default();
+    let migrator = migrator();
+
+    assert_eq!(migrator.latest_version(), 3);
+    assert_eq!(ctx.store_version().unwrap(), None);
+
+    assert_eq!(migrator.migrate(&mut ctx).unwrap(), 3);
+    assert_eq!(ctx.store_version().unwrap(), Some(3));
+    assert_eq!(ctx.client_counter().unwrap(), 1);
+
+    // Running the migrator again is a no-op.
+    assert_eq!(migrator.migrate(&mut ctx).unwrap(), 3);
+    assert_eq!(ctx.client_counter().unwrap(), 1);
+    assert_eq!(ctx.ibc_store.lock().logs.len(), 1);
+}
+
+#[test]
+fn test_init_store() {
+    let mut ctx = MockContext::default();
+    let migrator = migrator();
+
+    migrator.init(&mut ctx).unwrap();
+
+    assert_eq!(migrator.migrate(&mut ctx).unwrap(), 3);
+    assert_eq!(ctx.client_counter().unwrap(), 0);
+}
+
+#[test]
+fn test_migrate_store_failures() {
+    let mut ctx = MockContext::default();
+    let mut migrator = migrator();
+
+    assert_eq!(
+        migrator.register(2, |_: &mut MockContext| Ok(())),
+        Err(MigrationError::DuplicateMigration { from_version: 2 })
+    );
+    assert_eq!(
+        migrator.register(0, |_: &mut MockContext| Ok(())),
+        Err(MigrationError::InvalidStoreVersion { version: 0 })
+    );
+
+    // The migration from the version 3 is missing.
+    migrator.register(4, |_: &mut MockContext| Ok(())).unwrap();
+
+    assert!(matches!(
+        migrator.migrate(&mut ctx),
+        Err(ContextError::MigrationError(
+            MigrationError::MissingMigration { from_version: 3 }
+        ))
+    ));
+    assert_eq!(ctx.store_version().unwrap(), Some(3));
+
+    // The store is newer than the migrator.
+    let migrator = Migrator::<MockContext>::new();
+
+    assert_eq!(migrator.latest_version(), INITIAL_STORE_VERSION);
+    assert!(matches!(
+        migrator.migrate(&mut ctx),
+        Err(ContextError::MigrationError(
+            MigrationError::UnsupportedStoreVersion {
+                version: 3,
+                latest_version: 1,
+            }
+        ))
+    ));
+}
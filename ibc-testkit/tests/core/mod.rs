@@ -1,5 +1,23 @@
 pub mod ics02_client;
 pub mod ics03_connection;
 pub mod ics04_channel;
+#[cfg(feature = "borsh")]
+pub mod borsh_roundtrip;
+pub mod cache;
+pub mod canonical;
+pub mod clock;
+#[cfg(feature = "serde")]
+pub mod compat;
+pub mod events;
+pub mod extension;
+pub mod middleware;
+pub mod migration;
+pub mod profiling;
+#[cfg(feature = "proto-json")]
+pub mod proto_json;
+pub mod provable_store;
+pub mod relayer;
 #[cfg(feature = "serde")]
 pub mod router;
+pub mod snapshot;
+pub mod transaction;
@@ -0,0 +1,72 @@
+use core::cell::Cell;
+use core::time::Duration;
+
+use ibc::core::client::types::Height;
+use ibc::core::entrypoint::dispatch_profiled;
+use ibc::core::host::{ProfileClock, ProfiledContext};
+use ibc::primitives::proto::Any;
+use ibc::primitives::ToProto;
+use ibc_testkit::fixtures::core::connection::dummy_msg_conn_open_init;
+use ibc_testkit::testapp::ibc::core::router::MockRouter;
+use ibc_testkit::testapp::ibc::core::types::{MockClientConfig, MockContext};
+use test_log::test;
+
+/// A clock advancing by a millisecond each time it is read.
+#[derive(Default)]
+struct TickClock {
+    ticks: Cell<u64>,
+}
+
+impl ProfileClock for TickClock {
+    fn now(&self) -> Duration {
+        let ticks = self.ticks.get() + 1;
+        self.ticks.set(ticks);
+
+        Duration::from_millis(ticks)
+    }
+}
+
+#[test]
+fn test_dispatch_profiled() {
+    let msg = dummy_msg_conn_open_init();
+    let ctx = MockContext::default().with_client_config(
+        MockClientConfig::builder()
+            .client_id(msg.client_id_on_a.clone())
+            .latest_height(Height::new(0, 10).unwrap())
+            .build(),
+    );
+    let mut ctx = ProfiledContext::new(ctx, TickClock::default());
+    let mut router = MockRouter::new_with_transfer();
+
+    dispatch_profiled(&mut ctx, &mut router, msg.to_any()).unwrap();
+
+    let profile = ctx.profile();
+
+    for phase in [profile.decode, profile.validation, profile.execution] {
+        assert_eq!(phase.count, 1);
+        assert_eq!(phase.duration, Duration::from_millis(1));
+    }
+    assert_eq!(profile.proof_verification.count, 0);
+    assert!(profile.state_reads > 0);
+    // The connection end, the connection of the client and the counter
+    assert_eq!(profile.state_writes, 3);
+}
+
+#[test]
+fn test_failed_decode_is_profiled() {
+    let mut ctx = ProfiledContext::new(MockContext::default(), TickClock::default());
+    let mut router = MockRouter::new_with_transfer();
+
+    let msg = Any {
+        type_url: "/unknown.Msg".to_string(),
+        value: vec![],
+    };
+
+    assert!(dispatch_profiled(&mut ctx, &mut router, msg).is_err());
+
+    let profile = ctx.profile();
+
+    assert_eq!(profile.decode.count, 1);
+    assert_eq!(profile.validation.count, 0);
+    assert_eq!(profile.state_reads, 0);
+}
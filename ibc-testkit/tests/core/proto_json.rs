@@ -0,0 +1,51 @@
+use ibc::clients::tendermint::types::proto::v1::ClientState as RawTmClientState;
+use ibc::clients::tendermint::types::ClientState as TmClientState;
+use ibc::core::client::types::msgs::MsgCreateClient;
+use ibc::core::client::types::proto::v1::{
+    Height as RawHeight, MsgCreateClient as RawMsgCreateClient,
+};
+use ibc::core::connection::types::msgs::MsgConnectionOpenInit;
+use ibc::core::connection::types::proto::v1::MsgConnectionOpenInit as RawMsgConnectionOpenInit;
+use ibc_testkit::fixtures::clients::tendermint::dummy_raw_tm_client_state;
+use ibc_testkit::fixtures::core::client::dummy_raw_msg_create_client;
+use ibc_testkit::fixtures::core::connection::dummy_raw_msg_conn_open_init;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use test_log::test;
+
+/// Checks that the domain type serializes as its raw type does, in the
+/// Protobuf JSON mapping, and that it is deserialized back from it.
+fn assert_proto_json<T, Raw>(raw: Raw)
+where
+    T: Serialize + DeserializeOwned + TryFrom<Raw> + PartialEq + core::fmt::Debug,
+    <T as TryFrom<Raw>>::Error: core::fmt::Debug,
+    Raw: Serialize + Clone,
+{
+    let domain = T::try_from(raw.clone()).expect("valid raw type");
+
+    let json = serde_json::to_value(&domain).expect("serializes");
+
+    assert_eq!(json, serde_json::to_value(&raw).expect("serializes"));
+    assert_eq!(
+        serde_json::from_value::<T>(json).expect("deserializes"),
+        domain
+    );
+}
+
+#[test]
+fn test_msgs_in_proto_json() {
+    assert_proto_json::<MsgCreateClient, RawMsgCreateClient>(dummy_raw_msg_create_client());
+    assert_proto_json::<MsgConnectionOpenInit, RawMsgConnectionOpenInit>(
+        dummy_raw_msg_conn_open_init(),
+    );
+}
+
+#[test]
+fn test_client_state_in_proto_json() {
+    let raw_client_state = dummy_raw_tm_client_state(RawHeight {
+        revision_number: 0,
+        revision_height: 0,
+    });
+
+    assert_proto_json::<TmClientState, RawTmClientState>(raw_client_state);
+}
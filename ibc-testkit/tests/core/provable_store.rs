@@ -0,0 +1,115 @@
+use ibc::clients::tendermint::client_state::ClientState as TmClientState;
+use ibc::clients::tendermint::consensus_state::ConsensusState as TmConsensusState;
+use ibc::core::client::context::client_state::ClientStateCommon;
+use ibc::core::client::types::Height;
+use ibc::core::connection::types::version::Version as ConnectionVersion;
+use ibc::core::connection::types::{
+    ConnectionEnd, Counterparty as ConnectionCounterparty, State as ConnectionState,
+};
+use ibc::core::host::types::identifiers::{ChainId, ChannelId, ConnectionId, PortId, Sequence};
+use ibc::core::host::types::path::{ConnectionPath, Path, ReceiptPath};
+use ibc::core::host::ValidationContext;
+use ibc::core::primitives::prelude::*;
+use ibc::core::primitives::ZERO_DURATION;
+use ibc::primitives::proto::Protobuf;
+use ibc_testkit::fixtures::clients::tendermint::ClientStateConfig as TmClientStateConfig;
+use ibc_testkit::fixtures::core::connection::dummy_raw_counterparty_conn;
+use ibc_testkit::fixtures::core::context::MockContextConfig;
+use ibc_testkit::hosts::block::HostType;
+use ibc_testkit::testapp::ibc::clients::mock::client_state::client_type as mock_client_type;
+use ibc_testkit::testapp::ibc::core::types::MockContext;
+use test_log::test;
+
+fn provable_tm_chain() -> MockContext {
+    let host_id = ChainId::new("mockgaiaA-1").unwrap();
+
+    MockContextConfig::builder()
+        .host_type(HostType::SyntheticTendermint)
+        .latest_height(Height::new(host_id.revision_number(), 5).unwrap())
+        .host_id(host_id)
+        .build()
+        .with_provable_store()
+}
+
+#[test]
+fn proofs_verify_against_the_tendermint_headers() {
+    let conn_id = ConnectionId::new(0);
+
+    let conn_end = ConnectionEnd::new(
+        ConnectionState::Init,
+        mock_client_type().build_client_id(0),
+        ConnectionCounterparty::try_from(dummy_raw_counterparty_conn(Some(0))).unwrap(),
+        ConnectionVersion::compatibles(),
+        ZERO_DURATION,
+    )
+    .unwrap();
+
+    let mut ctx = provable_tm_chain().with_connection(conn_id.clone(), conn_end.clone());
+
+    ctx.advance_host_chain_height();
+
+    let height = ctx.latest_height();
+
+    let tm_block = ctx
+        .host_block(&height)
+        .unwrap()
+        .clone()
+        .try_into_tm_block()
+        .unwrap();
+
+    let consensus_state = TmConsensusState::from(tm_block.header().clone());
+
+    assert_eq!(
+        Some(consensus_state.inner().root.clone()),
+        ctx.commitment_root(&height)
+    );
+
+    let client_state = TmClientState::try_from(
+        TmClientStateConfig::builder()
+            .chain_id(ctx.host_chain_id.clone())
+            .latest_height(height)
+            .build(),
+    )
+    .unwrap();
+
+    let conn_path = Path::Connection(ConnectionPath::new(&conn_id));
+
+    client_state
+        .verify_membership(
+            &ctx.commitment_prefix(),
+            &ctx.prove(&height, &conn_path).unwrap(),
+            &consensus_state.inner().root,
+            conn_path,
+            conn_end.encode_vec(),
+        )
+        .unwrap();
+
+    let receipt_path = Path::Receipt(ReceiptPath::new(
+        &PortId::transfer(),
+        &ChannelId::new(0),
+        Sequence::from(1),
+    ));
+
+    client_state
+        .verify_non_membership(
+            &ctx.commitment_prefix(),
+            &ctx.prove(&height, &receipt_path).unwrap(),
+            &consensus_state.inner().root,
+            receipt_path,
+        )
+        .unwrap();
+}
+
+#[test]
+fn committed_stores_are_pruned_with_the_history() {
+    let mut ctx = provable_tm_chain();
+
+    let first_height = ctx.latest_height().increment();
+
+    for _ in 0..ctx.max_history_size + 1 {
+        ctx.advance_host_chain_height();
+    }
+
+    assert!(ctx.commitment_root(&first_height).is_none());
+    assert!(ctx.commitment_root(&ctx.latest_height()).is_some());
+}
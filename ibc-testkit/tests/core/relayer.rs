@@ -0,0 +1,492 @@
+use core::time::Duration;
+
+use ibc::core::channel::types::channel::{Order, State as ChannelState};
+use ibc::core::channel::types::msgs::{ChannelMsg, MsgChannelCloseInit, MsgRecvPacket, PacketMsg};
+use ibc::core::channel::types::packet::Receipt;
+use ibc::core::channel::types::timeout::TimeoutHeight;
+use ibc::core::channel::types::Version as ChannelVersion;
+use ibc::core::client::context::client_state::ClientStateCommon;
+use ibc::core::client::types::Height;
+use ibc::core::connection::types::State as ConnectionState;
+use ibc::core::handler::types::events::IbcEvent;
+use ibc::core::handler::types::msgs::MsgEnvelope;
+use ibc::core::host::types::identifiers::{ChainId, PortId, Sequence};
+use ibc::core::host::types::path::{
+    AckPath, ChannelEndPath, ChannelUpgradeErrorPath, ChannelUpgradePath, CommitmentPath,
+    ReceiptPath,
+};
+use ibc::core::host::ValidationContext;
+use ibc::core::primitives::prelude::*;
+use ibc::core::primitives::Signer;
+use ibc_testkit::fixtures::core::channel::{
+    dummy_raw_msg_chan_close_init, dummy_raw_msg_recv_packet,
+};
+use ibc_testkit::fixtures::core::context::{mock_chain, MockContextConfig};
+use ibc_testkit::hosts::block::HostType;
+use ibc_testkit::relayer::byzantine::{self, Mutation};
+use ibc_testkit::relayer::context::RelayerContext;
+use ibc_testkit::relayer::error::RelayerError;
+use ibc_testkit::relayer::harness::{RelayerHarness, TraceStep};
+use ibc_testkit::relayer::scenario::Scenario;
+use test_log::test;
+
+#[test]
+fn relay_handshakes_and_packets() {
+    let mut harness = RelayerHarness::new()
+        .with_chain(mock_chain("mockgaiaA-1"))
+        .with_chain(mock_chain("mockgaiaB-1"));
+
+    let connection = harness.open_connection(0, 1).unwrap();
+
+    for (chain, conn_id) in [(0, &connection.conn_id_on_a), (1, &connection.conn_id_on_b)] {
+        let conn_end = harness.chain(chain).connection_end(conn_id).unwrap();
+        assert_eq!(conn_end.state(), &ConnectionState::Open);
+    }
+
+    let channel = harness
+        .open_channel(
+            &connection,
+            PortId::transfer(),
+            ChannelVersion::new("ics20-1".to_string()),
+        )
+        .unwrap();
+
+    for (chain, chan_id) in [(0, &channel.chan_id_on_a), (1, &channel.chan_id_on_b)] {
+        let chan_end = harness
+            .chain(chain)
+            .channel_end(&ChannelEndPath::new(&PortId::transfer(), chan_id))
+            .unwrap();
+        assert_eq!(chan_end.state(), &ChannelState::Open);
+    }
+
+    for data in [b"ping".to_vec(), b"pong".to_vec()] {
+        let packet = harness.send_packet(&channel, data).unwrap();
+
+        let commitment_path =
+            CommitmentPath::new(&packet.port_id_on_a, &packet.chan_id_on_a, packet.seq_on_a);
+
+        assert!(harness
+            .chain(0)
+            .get_packet_commitment(&commitment_path)
+            .is_ok());
+
+        let acknowledgement = harness.relay_packet(&channel, &packet).unwrap();
+
+        assert_eq!(acknowledgement.as_bytes(), &[1u8]);
+
+        let ctx_b = harness.chain(1);
+
+        assert!(matches!(
+            ctx_b.get_packet_receipt(&ReceiptPath::new(
+                &packet.port_id_on_b,
+                &packet.chan_id_on_b,
+                packet.seq_on_a,
+            )),
+            Ok(Receipt::Ok)
+        ));
+        assert!(ctx_b
+            .get_packet_acknowledgement(&AckPath::new(
+                &packet.port_id_on_b,
+                &packet.chan_id_on_b,
+                packet.seq_on_a,
+            ))
+            .is_ok());
+
+        // The acknowledged packet is no longer committed to on the sender.
+        assert!(harness
+            .chain(0)
+            .get_packet_commitment(&commitment_path)
+            .is_err());
+    }
+}
+
+#[test]
+fn relay_client_updates_between_host_types() {
+    let chain_id_b = ChainId::new("mockgaiaB-1").unwrap();
+
+    let mut harness = RelayerHarness::new()
+        .with_chain(mock_chain("mockgaiaA-1"))
+        .with_chain(
+            MockContextConfig::builder()
+                .host_type(HostType::SyntheticTendermint)
+                .latest_height(Height::new(1, 5).unwrap())
+                .host_id(chain_id_b)
+                .build(),
+        );
+
+    let client_id_on_a = harness.create_client(0, 1).unwrap();
+    let client_id_on_b = harness.create_client(1, 0).unwrap();
+
+    for _ in 0..3 {
+        harness.advance_blocks(2);
+
+        harness.update_client(0, &client_id_on_a, 1).unwrap();
+        harness.update_client(1, &client_id_on_b, 0).unwrap();
+
+        let client_height_on_b = harness
+            .chain(1)
+            .query_client_full_state(&client_id_on_b)
+            .unwrap()
+            .latest_height();
+
+        assert_eq!(client_height_on_b, harness.chain(0).latest_height());
+    }
+
+    let client_height_on_a = harness
+        .chain(0)
+        .query_client_full_state(&client_id_on_a)
+        .unwrap()
+        .latest_height();
+
+    // The client on A is behind B by the block committing its update on B.
+    assert_eq!(
+        client_height_on_a.increment(),
+        harness.chain(1).latest_height()
+    );
+}
+
+#[test]
+fn scenario_traces_the_packet_lifecycle() {
+    let outcome = Scenario::new()
+        .create_clients()
+        .open_connection()
+        .open_channel("transfer")
+        .send_packet(b"ping".to_vec())
+        .send_packet(b"pong".to_vec())
+        .relay()
+        .ack()
+        .run()
+        .unwrap();
+
+    assert!(outcome.sent.is_empty());
+    assert!(outcome.received.is_empty());
+    assert_eq!(outcome.acknowledged.len(), 2);
+
+    let trace = outcome.harness.trace();
+
+    let sent_packets = trace
+        .iter()
+        .filter(|step| matches!(step, TraceStep::SendPacket { .. }))
+        .count();
+
+    assert_eq!(sent_packets, 2);
+
+    // The last step relays the acknowledgement of the last packet to the sender.
+    let last_step = trace.last().unwrap();
+
+    assert_eq!(last_step.chain(), 0);
+    assert!(matches!(
+        last_step,
+        TraceStep::Msg {
+            msg: MsgEnvelope::Packet(PacketMsg::Ack(_)),
+            ..
+        }
+    ));
+    assert!(last_step
+        .events()
+        .iter()
+        .any(|event| matches!(event, IbcEvent::AcknowledgePacket(_))));
+}
+
+#[test]
+fn packets_time_out_on_height_and_timestamp() {
+    let mut outcome = Scenario::new()
+        .open_connection()
+        .open_channel("transfer")
+        .run()
+        .unwrap();
+
+    let channel = outcome.channel.take().unwrap();
+    let harness = &mut outcome.harness;
+
+    let latest_timestamp_on_b = |harness: &RelayerHarness| {
+        let ctx_b = harness.chain(1);
+
+        ctx_b
+            .host_block(&ctx_b.latest_height())
+            .unwrap()
+            .timestamp()
+    };
+
+    let timeout_timestamp_on_b =
+        (latest_timestamp_on_b(harness) + Duration::from_secs(10)).unwrap();
+
+    let packets = [
+        harness.send_packet(&channel, b"ping".to_vec()).unwrap(),
+        harness
+            .send_packet_with_timeout(
+                &channel,
+                b"pong".to_vec(),
+                TimeoutHeight::Never,
+                timeout_timestamp_on_b,
+            )
+            .unwrap(),
+    ];
+
+    for packet in &packets {
+        harness.timeout_packet(&channel, packet).unwrap();
+
+        let commitment_path =
+            CommitmentPath::new(&packet.port_id_on_a, &packet.chan_id_on_a, packet.seq_on_a);
+
+        assert!(harness
+            .chain(0)
+            .get_packet_commitment(&commitment_path)
+            .is_err());
+    }
+
+    let TimeoutHeight::At(timeout_height_on_b) = packets[0].timeout_height_on_b else {
+        panic!("the packet has no timeout height");
+    };
+
+    assert!(harness.chain(1).latest_height() > timeout_height_on_b);
+    assert!(latest_timestamp_on_b(harness) > timeout_timestamp_on_b);
+}
+
+#[test]
+fn timeout_on_close_messages_are_proven_on_the_counterparty() {
+    let mut outcome = Scenario::new()
+        .open_connection()
+        .open_channel("transfer")
+        .run()
+        .unwrap();
+
+    let channel = outcome.channel.take().unwrap();
+    let harness = &mut outcome.harness;
+
+    let packet = harness.send_packet(&channel, b"ping".to_vec()).unwrap();
+
+    let msg = harness
+        .timeout_on_close_packet_msg(&channel, &packet)
+        .unwrap();
+
+    assert_eq!(msg.packet, packet);
+    assert_eq!(msg.proof_height_on_b, harness.chain(1).latest_height());
+    assert_eq!(msg.next_seq_recv_on_b, Sequence::from(1));
+    assert_eq!(msg.upgrade_sequence_on_b, 0);
+}
+
+#[test]
+fn scenario_times_out_the_packets_sent() {
+    let outcome = Scenario::new()
+        .open_connection()
+        .open_channel("transfer")
+        .send_packet(b"ping".to_vec())
+        .timeout()
+        .run()
+        .unwrap();
+
+    assert!(outcome.sent.is_empty());
+    assert_eq!(outcome.timed_out.len(), 1);
+
+    let last_step = outcome.harness.trace().last().unwrap();
+
+    assert!(last_step
+        .events()
+        .iter()
+        .any(|event| matches!(event, IbcEvent::TimeoutPacket(_))));
+}
+
+#[test]
+fn channel_upgrades_on_both_ends() {
+    for crossing_hello in [false, true] {
+        let mut harness = RelayerHarness::new()
+            .with_chain(mock_chain("mockgaiaA-1"))
+            .with_chain(mock_chain("mockgaiaB-1"));
+
+        let connection = harness.open_connection(0, 1).unwrap();
+        let channel = harness
+            .open_channel(
+                &connection,
+                PortId::transfer(),
+                ChannelVersion::new("ics20-1".to_string()),
+            )
+            .unwrap();
+
+        let version = ChannelVersion::new("ics20-2".to_string());
+
+        if crossing_hello {
+            harness
+                .upgrade_channel_crossing_hello(&channel, Order::Ordered, version.clone())
+                .unwrap();
+        } else {
+            harness
+                .upgrade_channel(&channel, Order::Ordered, version.clone())
+                .unwrap();
+        }
+
+        for (chain, chan_id) in [(0, &channel.chan_id_on_a), (1, &channel.chan_id_on_b)] {
+            let chan_end = harness
+                .chain(chain)
+                .channel_end(&ChannelEndPath::new(&PortId::transfer(), chan_id))
+                .unwrap();
+            assert_eq!(chan_end.state(), &ChannelState::Open);
+            assert_eq!(chan_end.ordering(), &Order::Ordered);
+            assert_eq!(chan_end.version(), &version);
+            assert_eq!(chan_end.upgrade_sequence(), 1);
+
+            assert!(harness
+                .chain(chain)
+                .channel_upgrade(&ChannelUpgradePath::new(&PortId::transfer(), chan_id))
+                .is_err());
+        }
+    }
+}
+
+#[test]
+fn channel_upgrade_times_out_and_is_cancelled() {
+    let mut harness = RelayerHarness::new()
+        .with_chain(mock_chain("mockgaiaA-1"))
+        .with_chain(mock_chain("mockgaiaB-1"));
+
+    let connection = harness.open_connection(0, 1).unwrap();
+    let version = ChannelVersion::new("ics20-1".to_string());
+    let channel = harness
+        .open_channel(&connection, PortId::transfer(), version.clone())
+        .unwrap();
+
+    harness
+        .upgrade_init(
+            &channel,
+            Order::Unordered,
+            ChannelVersion::new("ics20-2".to_string()),
+        )
+        .unwrap();
+    harness.upgrade_try(&channel).unwrap();
+    harness.upgrade_ack(&channel).unwrap();
+
+    harness.upgrade_timeout(&channel).unwrap();
+
+    let error_receipt_on_a = harness
+        .chain(0)
+        .channel_upgrade_error_receipt(&ChannelUpgradeErrorPath::new(
+            &PortId::transfer(),
+            &channel.chan_id_on_a,
+        ))
+        .unwrap();
+    assert_eq!(error_receipt_on_a.sequence, 1);
+
+    harness.upgrade_cancel(&channel.flipped()).unwrap();
+
+    for (chain, chan_id) in [(0, &channel.chan_id_on_a), (1, &channel.chan_id_on_b)] {
+        let chan_end = harness
+            .chain(chain)
+            .channel_end(&ChannelEndPath::new(&PortId::transfer(), chan_id))
+            .unwrap();
+        assert_eq!(chan_end.state(), &ChannelState::Open);
+        assert_eq!(chan_end.version(), &version);
+        assert_eq!(chan_end.upgrade_sequence(), 1);
+    }
+}
+
+#[test]
+fn scenario_fails_on_missing_steps() {
+    let res = Scenario::new()
+        .create_clients()
+        .send_packet(b"ping".to_vec())
+        .run();
+
+    assert!(matches!(
+        res,
+        Err(RelayerError::MissingScenarioStep {
+            step: "open_channel"
+        })
+    ));
+}
+
+#[test]
+fn byzantine_packet_messages_are_rejected() {
+    let outcome = Scenario::new()
+        .create_clients()
+        .open_connection()
+        .open_channel("transfer")
+        .send_packet(b"ping".to_vec())
+        .relay()
+        .ack()
+        .run()
+        .unwrap();
+
+    let mut harness = outcome.harness;
+    let channel = outcome.channel.unwrap();
+    let (relayed_packet, acknowledgement) = outcome.acknowledged[0].clone();
+
+    let packet = harness.send_packet(&channel, b"pong".to_vec()).unwrap();
+
+    let msg = MsgEnvelope::from(PacketMsg::Recv(
+        harness.recv_packet_msg(&channel, &packet).unwrap(),
+    ));
+
+    let chain_b = harness.chain_mut(channel.connection.chain_b);
+
+    byzantine::assert_rejected(
+        chain_b,
+        &msg,
+        &[
+            Mutation::StaleProofHeight,
+            Mutation::FutureProofHeight,
+            Mutation::ReplayedSequence(relayed_packet.seq_on_a),
+        ],
+    );
+    byzantine::assert_replay_rejected(chain_b, msg);
+
+    let msg = MsgEnvelope::from(PacketMsg::Ack(
+        harness
+            .acknowledge_packet_msg(&channel, &packet, acknowledgement)
+            .unwrap(),
+    ));
+
+    let chain_a = harness.chain_mut(channel.connection.chain_a);
+
+    byzantine::assert_rejected(
+        chain_a,
+        &msg,
+        &[Mutation::StaleProofHeight, Mutation::FutureProofHeight],
+    );
+    byzantine::assert_replay_rejected(chain_a, msg);
+}
+
+#[test]
+fn mutations_rewrite_the_relayed_fields() {
+    let msg = MsgEnvelope::from(PacketMsg::Recv(
+        MsgRecvPacket::try_from(dummy_raw_msg_recv_packet(10)).unwrap(),
+    ));
+
+    let MsgEnvelope::Packet(PacketMsg::Recv(original)) = msg.clone() else {
+        unreachable!()
+    };
+
+    let Some(MsgEnvelope::Packet(PacketMsg::Recv(corrupted))) = Mutation::CorruptProof.apply(&msg)
+    else {
+        panic!("the mutation applies to the packet messages")
+    };
+
+    assert_ne!(
+        corrupted.proof_commitment_on_a,
+        original.proof_commitment_on_a
+    );
+    assert_eq!(corrupted.packet, original.packet);
+
+    let Some(MsgEnvelope::Packet(PacketMsg::Recv(stale))) = Mutation::StaleProofHeight.apply(&msg)
+    else {
+        panic!("the mutation applies to the packet messages")
+    };
+
+    assert!(stale.proof_height_on_a < original.proof_height_on_a);
+
+    let signer = Signer::from("cosmos1wrong".to_string());
+
+    let Some(MsgEnvelope::Packet(PacketMsg::Recv(signed))) =
+        Mutation::WrongSigner(signer.clone()).apply(&msg)
+    else {
+        panic!("the mutation applies to the packet messages")
+    };
+
+    assert_eq!(signed.signer, signer);
+
+    // The messages originating on the chain carry no proof to mutate.
+    let msg = MsgEnvelope::from(ChannelMsg::CloseInit(
+        MsgChannelCloseInit::try_from(dummy_raw_msg_chan_close_init()).unwrap(),
+    ));
+
+    assert!(Mutation::CorruptProof.apply(&msg).is_none());
+}
@@ -1,3 +1,5 @@
+use core::str::FromStr;
+
 use ibc::apps::transfer::handler::send_transfer;
 use ibc::apps::transfer::types::error::TokenTransferError;
 use ibc::apps::transfer::types::msgs::transfer::MsgTransfer;
@@ -7,7 +9,9 @@ use ibc::core::channel::types::msgs::{
     ChannelMsg, MsgAcknowledgement, MsgChannelCloseConfirm, MsgChannelCloseInit, MsgChannelOpenAck,
     MsgChannelOpenInit, MsgChannelOpenTry, MsgRecvPacket, MsgTimeoutOnClose, PacketMsg,
 };
+use ibc::core::channel::types::packet::Packet;
 use ibc::core::channel::types::timeout::TimeoutHeight;
+use ibc::core::channel::types::Version;
 use ibc::core::client::types::msgs::{ClientMsg, MsgCreateClient, MsgUpdateClient};
 use ibc::core::client::types::Height;
 use ibc::core::connection::types::msgs::ConnectionMsg;
@@ -15,11 +19,19 @@ use ibc::core::entrypoint::dispatch;
 use ibc::core::handler::types::error::ContextError;
 use ibc::core::handler::types::events::{IbcEvent, MessageEvent};
 use ibc::core::handler::types::msgs::MsgEnvelope;
-use ibc::core::host::types::identifiers::ConnectionId;
+use ibc::core::host::types::identifiers::{ChannelId, ConnectionId, PortId};
 use ibc::core::host::types::path::CommitmentPath;
 use ibc::core::host::ValidationContext;
 use ibc::core::primitives::prelude::*;
-use ibc::core::primitives::Timestamp;
+use ibc::core::primitives::{Signer, Timestamp};
+use ibc::core::router::dynamic::ModuleRouter;
+use ibc::core::router::router::{
+    ChannelRouter, DynamicRouter, PermissionlessAuthority, PortRouter, Router, RouterAuthority,
+};
+use ibc::core::router::types::error::RouterError;
+use ibc::core::router::types::module::{ModuleExtras, ModuleId};
+use ibc_app_template::app::{AppConfig, Application};
+use ibc_app_template::module::bind_application;
 use ibc_testkit::fixtures::applications::transfer::{
     extract_transfer_packet, MsgTransferConfig, PacketDataConfig,
 };
@@ -432,3 +444,211 @@ fn routing_module_and_keepers() {
         }
     }
 }
+
+#[test]
+fn bind_and_release_ports_at_runtime() {
+    let mut router = MockRouter::new_with_transfer();
+
+    let module_id = ModuleId::new("other_transfer".to_string());
+    let port_id = PortId::from_str("other-transfer").unwrap();
+
+    // Only the modules registered against the router bind ports.
+    assert!(matches!(
+        router.bind_port(port_id.clone(), module_id.clone()),
+        Err(RouterError::ModuleNotFound)
+    ));
+
+    router
+        .add_route(module_id.clone(), DummyTransferModule::new())
+        .unwrap();
+
+    // The port of the transfer module is not available.
+    assert!(matches!(
+        router.bind_port(PortId::transfer(), module_id.clone()),
+        Err(RouterError::PortAlreadyBound { .. })
+    ));
+
+    let capability = router
+        .bind_port(port_id.clone(), module_id.clone())
+        .unwrap();
+
+    assert!(router.authenticate_port(&capability));
+    assert_eq!(router.lookup_module(&port_id), Some(module_id));
+
+    router.release_port(&capability).unwrap();
+
+    assert!(!router.authenticate_port(&capability));
+    assert_eq!(router.lookup_module(&port_id), None);
+}
+
+/// Authority letting only the allowlisted modules be registered.
+struct AllowlistAuthority(Vec<ModuleId>);
+
+impl RouterAuthority for AllowlistAuthority {
+    fn authorize_module(&self, module_id: &ModuleId) -> Result<(), RouterError> {
+        if self.0.contains(module_id) {
+            Ok(())
+        } else {
+            Err(RouterError::Unauthorized {
+                description: format!("module `{module_id}` is not allowlisted"),
+            })
+        }
+    }
+
+    fn authorize_port(&self, _port_id: &PortId, module_id: &ModuleId) -> Result<(), RouterError> {
+        self.authorize_module(module_id)
+    }
+}
+
+#[test]
+fn register_modules_at_runtime() {
+    let module_id = ModuleId::new("transfer".to_string());
+    let other_module_id = ModuleId::new("other_transfer".to_string());
+
+    let mut router = ModuleRouter::new(AllowlistAuthority(vec![module_id.clone()]));
+
+    assert!(matches!(
+        router.add_module(other_module_id, Box::new(DummyTransferModule::new())),
+        Err(RouterError::Unauthorized { .. })
+    ));
+
+    router
+        .add_module(module_id.clone(), Box::new(DummyTransferModule::new()))
+        .unwrap();
+
+    assert!(router.has_route(&module_id));
+    assert!(matches!(
+        router.add_module(module_id.clone(), Box::new(DummyTransferModule::new())),
+        Err(RouterError::ModuleAlreadyRegistered { .. })
+    ));
+
+    let capability = router
+        .bind_port(PortId::transfer(), module_id.clone())
+        .unwrap();
+
+    assert_eq!(
+        router.lookup_module(&PortId::transfer()),
+        Some(module_id.clone())
+    );
+
+    // The modules release their ports before being removed.
+    assert!(matches!(
+        router.remove_module(&module_id),
+        Err(RouterError::ModuleStillBound { .. })
+    ));
+
+    router.release_port(&capability).unwrap();
+    router.remove_module(&module_id).unwrap();
+
+    assert!(!router.has_route(&module_id));
+    assert_eq!(router.lookup_module(&PortId::transfer()), None);
+}
+
+#[test]
+fn authenticate_channels_of_modules() {
+    let module_id = ModuleId::new("contract_a".to_string());
+    let other_module_id = ModuleId::new("contract_b".to_string());
+    let port_id = PortId::from_str("wasm.contract-a").unwrap();
+    let other_port_id = PortId::from_str("wasm.contract-b").unwrap();
+
+    let mut router = ModuleRouter::new(PermissionlessAuthority);
+
+    for module_id in [&module_id, &other_module_id] {
+        router
+            .add_module(module_id.clone(), Box::new(DummyTransferModule::new()))
+            .unwrap();
+    }
+
+    let port_capability = router.bind_port(port_id.clone(), module_id).unwrap();
+    let other_port_capability = router
+        .bind_port(other_port_id.clone(), other_module_id)
+        .unwrap();
+
+    let channel_capability = router
+        .claim_channel(&port_capability, ChannelId::new(0))
+        .unwrap();
+    let other_channel_capability = router
+        .claim_channel(&other_port_capability, ChannelId::new(1))
+        .unwrap();
+
+    router
+        .verify_channel_capability(&channel_capability, &port_id, &ChannelId::new(0))
+        .unwrap();
+
+    // A module cannot use its capability over the channels of another one.
+    assert!(matches!(
+        router.verify_channel_capability(&other_channel_capability, &port_id, &ChannelId::new(0)),
+        Err(RouterError::InvalidChannelCapability { .. })
+    ));
+
+    router.release_channel(&channel_capability).unwrap();
+
+    assert!(!router.authenticate_channel(&channel_capability));
+    assert!(router.authenticate_channel(&other_channel_capability));
+}
+
+#[derive(Debug)]
+struct EchoApp {
+    config: AppConfig,
+}
+
+impl Application for EchoApp {
+    type Error = String;
+
+    fn config(&self) -> &AppConfig {
+        &self.config
+    }
+
+    fn on_recv_packet_execute(
+        &mut self,
+        packet: &Packet,
+        _relayer: &Signer,
+    ) -> Result<(ModuleExtras, Vec<u8>), Self::Error> {
+        Ok((ModuleExtras::empty(), packet.data.clone()))
+    }
+}
+
+#[test]
+fn route_custom_applications() {
+    let mut router = MockRouter::new_with_transfer();
+
+    let config = AppConfig::new(
+        ModuleId::new("echo".to_string()),
+        PortId::from_str("echo").unwrap(),
+        Version::new("echo-1".to_string()),
+    );
+
+    router
+        .add_application(EchoApp {
+            config: config.clone(),
+        })
+        .unwrap();
+
+    assert_eq!(
+        router.lookup_module(&config.port_id),
+        Some(config.module_id.clone())
+    );
+    assert!(router.get_route(&config.module_id).is_some());
+
+    // An application is routed once.
+    assert!(router
+        .add_application(EchoApp {
+            config: config.clone(),
+        })
+        .is_err());
+
+    // The applications may bind other ports at runtime.
+    let port_id = PortId::from_str("echo-other").unwrap();
+    let capability = bind_application(
+        &mut router,
+        &AppConfig::new(
+            config.module_id.clone(),
+            port_id.clone(),
+            config.version.clone(),
+        ),
+    )
+    .unwrap();
+
+    assert!(router.authenticate_port(&capability));
+    assert_eq!(router.lookup_module(&port_id), Some(config.module_id));
+}
@@ -0,0 +1,88 @@
+use ibc::core::channel::types::channel::State as ChannelState;
+use ibc::core::channel::types::Version as ChannelVersion;
+use ibc::core::client::types::Height;
+use ibc::core::host::types::identifiers::{PortId, Sequence};
+use ibc::core::host::types::path::{ChannelEndPath, CommitmentPath, SeqSendPath};
+use ibc::core::host::ValidationContext;
+use ibc_testkit::fixtures::core::context::mock_chain;
+use ibc_testkit::relayer::harness::RelayerHarness;
+use test_log::test;
+
+#[test]
+fn restore_context_to_snapshot() {
+    let mut ctx = mock_chain("mockgaiaA-1");
+    let store = ctx.ibc_store_share();
+
+    let snapshot = ctx.snapshot();
+
+    ctx.advance_host_chain_height();
+    store.lock().logs.push("after snapshot".to_string());
+
+    assert_eq!(ctx.latest_height(), Height::new(1, 6).unwrap());
+
+    ctx.restore(&snapshot);
+
+    assert_eq!(ctx.latest_height(), Height::new(1, 5).unwrap());
+    // The modules sharing the store observe the restored state.
+    assert!(store.lock().logs.is_empty());
+}
+
+#[test]
+fn fork_harness_setup_across_cases() {
+    let mut harness = RelayerHarness::new()
+        .with_chain(mock_chain("mockgaiaA-1"))
+        .with_chain(mock_chain("mockgaiaB-1"));
+
+    let connection = harness.open_connection(0, 1).unwrap();
+    let channel = harness
+        .open_channel(
+            &connection,
+            PortId::transfer(),
+            ChannelVersion::new("ics20-1".to_string()),
+        )
+        .unwrap();
+
+    let snapshot = harness.snapshot();
+    let trace_len = harness.trace().len();
+    let seq_send_path = SeqSendPath::new(&channel.port_id_on_a, &channel.chan_id_on_a);
+
+    for data in [b"ping".to_vec(), b"pong".to_vec()] {
+        harness.restore(&snapshot);
+
+        assert_eq!(harness.trace().len(), trace_len);
+        assert_eq!(
+            harness
+                .chain(0)
+                .get_next_sequence_send(&seq_send_path)
+                .unwrap(),
+            Sequence::from(1)
+        );
+
+        let packet = harness.send_packet(&channel, data).unwrap();
+        harness.relay_packet(&channel, &packet).unwrap();
+
+        assert_eq!(packet.seq_on_a, Sequence::from(1));
+    }
+
+    let commitment_path = CommitmentPath::new(
+        &channel.port_id_on_a,
+        &channel.chan_id_on_a,
+        Sequence::from(1),
+    );
+
+    harness.restore(&snapshot);
+
+    assert!(harness
+        .chain(0)
+        .get_packet_commitment(&commitment_path)
+        .is_err());
+
+    let fork = harness.snapshot().fork_chain(0);
+    let chan_end = fork
+        .channel_end(&ChannelEndPath::new(
+            &channel.port_id_on_a,
+            &channel.chan_id_on_a,
+        ))
+        .unwrap();
+    assert_eq!(chan_end.state(), &ChannelState::Open);
+}
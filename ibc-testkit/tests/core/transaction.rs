@@ -0,0 +1,305 @@
+use ibc::clients::tendermint::types::client_type as tm_client_type;
+use ibc::core::channel::types::commitment::PacketCommitment;
+use ibc::core::channel::types::error::PacketError;
+use ibc::core::client::handler::update_params;
+use ibc::core::client::types::msgs::MsgUpdateClientParams;
+use ibc::core::client::types::params::ClientParams;
+use ibc::core::client::types::Height;
+use ibc::core::connection::types::msgs::{ConnectionMsg, MsgConnectionOpenInit};
+use ibc::core::entrypoint::{dispatch_batch, simulate};
+use ibc::core::handler::types::error::{BatchError, ContextError};
+use ibc::core::handler::types::events::{IbcEvent, MessageEvent};
+use ibc::core::handler::types::msgs::MsgEnvelope;
+use ibc::core::host::types::identifiers::{ChannelId, PortId, Sequence};
+use ibc::core::host::types::path::CommitmentPath;
+use ibc::core::host::{
+    ExecutionContext, OverlayContext, TransactionalExecutionContext, ValidationContext,
+};
+use ibc_testkit::fixtures::core::connection::{
+    dummy_msg_conn_open_init, msg_conn_open_with_version,
+};
+use ibc_testkit::testapp::ibc::core::router::MockRouter;
+use ibc_testkit::testapp::ibc::core::types::{MockClientConfig, MockContext, MOCK_AUTHORITY};
+use test_log::test;
+
+fn commitment_path(sequence: u64) -> CommitmentPath {
+    CommitmentPath::new(
+        &PortId::transfer(),
+        &ChannelId::new(0),
+        Sequence::from(sequence),
+    )
+}
+
+fn commitment() -> PacketCommitment {
+    PacketCommitment::from(vec![1, 2, 3])
+}
+
+fn write_packet(ctx: &mut OverlayContext<MockContext>, sequence: u64) -> Result<(), ContextError> {
+    ctx.store_packet_commitment(&commitment_path(sequence), commitment())?;
+    ctx.increase_client_counter()?;
+    ctx.emit_ibc_event(IbcEvent::Message(MessageEvent::Channel))?;
+    ctx.log_message(format!("packet {sequence}"))
+}
+
+#[test]
+fn test_rollback_discards_writes() {
+    let mut ctx = OverlayContext::new(MockContext::default());
+
+    ctx.begin_transaction();
+    write_packet(&mut ctx, 1).unwrap();
+
+    // The writes are visible within the transaction.
+    assert!(ctx.get_packet_commitment(&commitment_path(1)).is_ok());
+    assert_eq!(ctx.client_counter().unwrap(), 1);
+
+    ctx.rollback_transaction();
+
+    assert!(ctx.get_packet_commitment(&commitment_path(1)).is_err());
+    assert_eq!(ctx.client_counter().unwrap(), 0);
+    assert!(ctx.inner().get_events().is_empty());
+    assert!(ctx.inner().get_logs().is_empty());
+}
+
+#[test]
+fn test_commit_writes_to_host() {
+    let mut ctx = OverlayContext::new(MockContext::default());
+
+    ctx.begin_transaction();
+    write_packet(&mut ctx, 1).unwrap();
+
+    assert!(ctx.inner().get_events().is_empty());
+
+    ctx.commit_transaction().unwrap();
+
+    assert!(!ctx.in_transaction());
+    assert_eq!(
+        ctx.inner()
+            .get_packet_commitment(&commitment_path(1))
+            .unwrap(),
+        commitment()
+    );
+    assert_eq!(ctx.inner().client_counter().unwrap(), 1);
+    assert_eq!(
+        ctx.inner().get_events(),
+        vec![IbcEvent::Message(MessageEvent::Channel)]
+    );
+    assert_eq!(ctx.inner().get_logs(), vec!["packet 1".to_string()]);
+}
+
+#[test]
+fn test_nested_transactions() {
+    let mut ctx = OverlayContext::new(MockContext::default());
+
+    ctx.begin_transaction();
+    write_packet(&mut ctx, 1).unwrap();
+
+    ctx.begin_transaction();
+    write_packet(&mut ctx, 2).unwrap();
+    ctx.delete_packet_commitment(&commitment_path(1)).unwrap();
+    assert!(ctx.get_packet_commitment(&commitment_path(1)).is_err());
+    ctx.rollback_transaction();
+
+    // Rolling back the nested transaction keeps the enclosing one.
+    assert!(ctx.get_packet_commitment(&commitment_path(1)).is_ok());
+    assert!(ctx.get_packet_commitment(&commitment_path(2)).is_err());
+
+    ctx.begin_transaction();
+    write_packet(&mut ctx, 3).unwrap();
+    ctx.commit_transaction().unwrap();
+
+    // Committing the nested transaction only writes to the enclosing one.
+    assert!(ctx.inner().get_events().is_empty());
+
+    ctx.commit_transaction().unwrap();
+
+    let host = ctx.into_inner();
+
+    assert!(host.get_packet_commitment(&commitment_path(1)).is_ok());
+    assert!(host.get_packet_commitment(&commitment_path(2)).is_err());
+    assert!(host.get_packet_commitment(&commitment_path(3)).is_ok());
+    assert_eq!(host.client_counter().unwrap(), 2);
+    assert_eq!(
+        host.get_logs(),
+        vec!["packet 1".to_string(), "packet 3".to_string()]
+    );
+}
+
+#[test]
+fn test_transact_rolls_back_on_error() {
+    let mut ctx = OverlayContext::new(MockContext::default());
+
+    let result = ctx.transact(|ctx| {
+        write_packet(ctx, 1)?;
+        write_packet(ctx, 2)?;
+
+        Err::<(), _>(ContextError::from(PacketError::Other {
+            description: "application callback failed".to_string(),
+        }))
+    });
+
+    assert!(result.is_err());
+    assert!(!ctx.in_transaction());
+    assert!(ctx.get_packet_commitment(&commitment_path(1)).is_err());
+    assert!(ctx.inner().get_events().is_empty());
+
+    ctx.transact(|ctx| write_packet(ctx, 1)).unwrap();
+
+    assert!(ctx
+        .inner()
+        .get_packet_commitment(&commitment_path(1))
+        .is_ok());
+    assert_eq!(ctx.inner().get_events().len(), 1);
+}
+
+#[test]
+fn test_staged_writes() {
+    let mut ctx = OverlayContext::new(MockContext::default());
+
+    let result = ctx.with_staged_writes(|ctx| {
+        write_packet(ctx, 1)?;
+
+        assert!(ctx.in_transaction());
+        assert!(ctx
+            .inner()
+            .get_packet_commitment(&commitment_path(1))
+            .is_err());
+
+        Err::<(), _>(ContextError::from(PacketError::Other {
+            description: "application callback failed".to_string(),
+        }))
+    });
+
+    assert!(result.is_err());
+    assert!(!ctx.in_transaction());
+    assert!(ctx.get_packet_commitment(&commitment_path(1)).is_err());
+
+    ctx.with_staged_writes(|ctx| write_packet(ctx, 1)).unwrap();
+
+    assert!(!ctx.in_transaction());
+    assert!(ctx
+        .inner()
+        .get_packet_commitment(&commitment_path(1))
+        .is_ok());
+}
+
+fn conn_open_init(msg: MsgConnectionOpenInit) -> MsgEnvelope {
+    MsgEnvelope::from(ConnectionMsg::from(msg))
+}
+
+fn batch_fixture() -> (OverlayContext<MockContext>, MsgConnectionOpenInit) {
+    let msg = dummy_msg_conn_open_init();
+    let ctx = MockContext::default().with_client_config(
+        MockClientConfig::builder()
+            .client_id(msg.client_id_on_a.clone())
+            .latest_height(Height::new(0, 10).unwrap())
+            .build(),
+    );
+
+    (OverlayContext::new(ctx), msg)
+}
+
+#[test]
+fn test_dispatch_batch() {
+    let (mut ctx, msg) = batch_fixture();
+    let mut router = MockRouter::new_with_transfer();
+
+    let events = dispatch_batch(
+        &mut ctx,
+        &mut router,
+        vec![conn_open_init(msg.clone()), conn_open_init(msg)],
+    )
+    .unwrap();
+
+    // Each message opens its own connection, and emits its own events.
+    assert_eq!(events.len(), 2);
+    for msg_events in &events {
+        assert_eq!(msg_events.len(), 2);
+        assert!(matches!(
+            msg_events[0],
+            IbcEvent::Message(MessageEvent::Connection)
+        ));
+        assert!(matches!(msg_events[1], IbcEvent::OpenInitConnection(_)));
+    }
+
+    assert!(!ctx.in_transaction());
+    assert_eq!(ctx.inner().connection_counter().unwrap(), 2);
+    assert_eq!(ctx.inner().get_events(), events.concat());
+}
+
+#[test]
+fn test_dispatch_batch_is_atomic() {
+    let (mut ctx, msg) = batch_fixture();
+    let mut router = MockRouter::new_with_transfer();
+
+    let bad_msg = msg_conn_open_with_version(msg.clone(), Some("random identifier 424242"));
+
+    let err = dispatch_batch(
+        &mut ctx,
+        &mut router,
+        vec![conn_open_init(msg), conn_open_init(bad_msg)],
+    )
+    .unwrap_err();
+
+    assert!(matches!(err, BatchError::Msg { index: 1, .. }));
+
+    // The connection opened by the first message is rolled back as well.
+    assert!(!ctx.in_transaction());
+    assert_eq!(ctx.inner().connection_counter().unwrap(), 0);
+    assert!(ctx.inner().get_events().is_empty());
+}
+
+#[test]
+fn test_simulate() {
+    let (mut ctx, msg) = batch_fixture();
+    let mut router = MockRouter::new_with_transfer();
+
+    let simulation = simulate(&mut ctx, &mut router, conn_open_init(msg.clone())).unwrap();
+
+    assert_eq!(simulation.events.len(), 2);
+    assert!(matches!(
+        simulation.events[1],
+        IbcEvent::OpenInitConnection(_)
+    ));
+    assert!(simulation.acknowledgement.is_none());
+    assert!(simulation.gas_usage.store_writes > 0);
+    assert_eq!(simulation.gas_usage.proof_verifications, 0);
+
+    // Nothing is written to the host.
+    assert!(!ctx.in_transaction());
+    assert_eq!(ctx.inner().connection_counter().unwrap(), 0);
+    assert!(ctx.inner().get_events().is_empty());
+
+    let bad_msg = msg_conn_open_with_version(msg, Some("random identifier 424242"));
+
+    assert!(simulate(&mut ctx, &mut router, conn_open_init(bad_msg)).is_err());
+}
+
+/// The parameters of the client module updated by the governance within a
+/// transaction are read back until it ends, and only written to the host once
+/// it is committed
+#[test]
+fn test_update_client_params_in_transaction() {
+    let mut ctx = OverlayContext::new(MockContext::default());
+    let default_params = ctx.client_params();
+    let params = ClientParams::new(vec![tm_client_type().as_str().to_string()]);
+    let msg = MsgUpdateClientParams {
+        params: params.clone(),
+        signer: MOCK_AUTHORITY.to_string().into(),
+    };
+
+    update_params::validate(&ctx, &msg).unwrap();
+
+    ctx.begin_transaction();
+    update_params::execute(&mut ctx, msg.clone()).unwrap();
+    assert_eq!(ctx.client_params(), params);
+    assert_eq!(ctx.inner().client_params(), default_params);
+    ctx.rollback_transaction();
+
+    assert_eq!(ctx.client_params(), default_params);
+
+    ctx.transact(|ctx| update_params::execute(ctx, msg))
+        .unwrap();
+
+    assert!(!ctx.in_transaction());
+    assert_eq!(ctx.inner().client_params(), params);
+}
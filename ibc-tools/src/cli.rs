@@ -0,0 +1,113 @@
+//! Parses the command line of the tool.
+
+use crate::encoding::decode_bytes;
+use crate::error::ToolError;
+use crate::proof::{ProofArgs, Specs};
+
+pub const USAGE: &str = "\
+Decodes, verifies and inspects IBC artifacts.
+
+Usage:
+  ibc-tools decode <any>
+      Decodes an Any-wrapped client state, consensus state, header,
+      misbehaviour or client message of the Tendermint or Wasm clients.
+  ibc-tools packet <packet>
+      Pretty-prints a protobuf-encoded packet, along with its commitment.
+  ibc-tools events [<file>]
+      Pretty-prints the JSON ABCI events of the file, or of the standard
+      input if no file is given.
+  ibc-tools verify-proof --root <root> --proof <proof> --path <path>
+                         [--value <value>] [--prefix <prefix>] [--specs <specs>]
+      Verifies a protobuf-encoded MerkleProof of the value at the ICS-24 path,
+      or of its absence if no value is given. The prefix defaults to `ibc`,
+      the specs to `cosmos`, or `iavl` and `tendermint` for a single store.
+  ibc-tools commitment packet <packet>
+  ibc-tools commitment ack <acknowledgement>
+      Computes the commitment of a protobuf-encoded packet, or of an
+      acknowledgement.
+  ibc-tools help
+      Prints this message.
+
+The bytes are hex-encoded, or base64-encoded if prefixed with `base64:`.";
+
+/// The commands of the tool.
+#[derive(Clone, Debug)]
+pub enum Command {
+    Decode { any: Vec<u8> },
+    Packet { packet: Vec<u8> },
+    Events { file: Option<String> },
+    VerifyProof(ProofArgs),
+    PacketCommitment { packet: Vec<u8> },
+    AckCommitment { ack: Vec<u8> },
+    Help,
+}
+
+impl Command {
+    /// Parses the command from the arguments, without the name of the binary.
+    pub fn parse(args: impl IntoIterator<Item = String>) -> Result<Self, ToolError> {
+        let mut args = args.into_iter();
+
+        let command = match args.next().as_deref() {
+            Some("decode") => Self::Decode {
+                any: decode_bytes(&required(args.next(), "<any>")?)?,
+            },
+            Some("packet") => Self::Packet {
+                packet: decode_bytes(&required(args.next(), "<packet>")?)?,
+            },
+            Some("events") => Self::Events { file: args.next() },
+            Some("verify-proof") => Self::VerifyProof(parse_proof_args(&mut args)?),
+            Some("commitment") => match args.next().as_deref() {
+                Some("packet") => Self::PacketCommitment {
+                    packet: decode_bytes(&required(args.next(), "<packet>")?)?,
+                },
+                Some("ack") => Self::AckCommitment {
+                    ack: decode_bytes(&required(args.next(), "<acknowledgement>")?)?,
+                },
+                _ => return Err(ToolError::usage("expected `commitment packet|ack`")),
+            },
+            Some("help" | "--help" | "-h") | None => Self::Help,
+            Some(command) => {
+                return Err(ToolError::usage(format!("unknown command `{command}`")));
+            }
+        };
+
+        if let Some(arg) = args.next() {
+            return Err(ToolError::usage(format!("unexpected argument `{arg}`")));
+        }
+
+        Ok(command)
+    }
+}
+
+fn parse_proof_args(args: &mut impl Iterator<Item = String>) -> Result<ProofArgs, ToolError> {
+    let (mut root, mut proof, mut path, mut value) = (None, None, None, None);
+    let mut prefix = "ibc".to_string();
+    let mut specs = Specs::default();
+
+    while let Some(flag) = args.next() {
+        let arg = required(args.next(), &flag)?;
+
+        match flag.as_str() {
+            "--root" => root = Some(decode_bytes(&arg)?),
+            "--proof" => proof = Some(decode_bytes(&arg)?),
+            "--path" => path = Some(arg),
+            "--value" => value = Some(decode_bytes(&arg)?),
+            "--prefix" => prefix = arg,
+            "--specs" => specs = arg.parse()?,
+            _ => return Err(ToolError::usage(format!("unknown option `{flag}`"))),
+        }
+    }
+
+    Ok(ProofArgs {
+        root: required(root, "--root")?,
+        proof: required(proof, "--proof")?,
+        path: required(path, "--path")?,
+        value,
+        prefix,
+        specs,
+    })
+}
+
+fn required<T>(arg: Option<T>, name: &str) -> Result<T, ToolError> {
+    arg.ok_or_else(|| ToolError::usage(format!("missing `{name}`")))
+}
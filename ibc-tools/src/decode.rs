@@ -0,0 +1,55 @@
+//! Decodes the `Any`-wrapped states and messages of the light clients.
+
+use core::fmt::Debug;
+
+use ibc::clients::tendermint::types::{
+    ClientState as TmClientState, ConsensusState as TmConsensusState, Header as TmHeader,
+    Misbehaviour as TmMisbehaviour, TENDERMINT_CLIENT_STATE_TYPE_URL,
+    TENDERMINT_CONSENSUS_STATE_TYPE_URL, TENDERMINT_HEADER_TYPE_URL,
+    TENDERMINT_MISBEHAVIOUR_TYPE_URL,
+};
+use ibc::clients::wasm_types::client_message::{
+    ClientMessage as WasmClientMessage, WASM_CLIENT_MESSAGE_TYPE_URL,
+};
+use ibc::clients::wasm_types::client_state::{
+    ClientState as WasmClientState, WASM_CLIENT_STATE_TYPE_URL,
+};
+use ibc::clients::wasm_types::consensus_state::{
+    ConsensusState as WasmConsensusState, WASM_CONSENSUS_STATE_TYPE_URL,
+};
+use ibc::primitives::proto::Any;
+use prost::Message;
+
+use crate::error::ToolError;
+
+/// Decodes the protobuf encoding of an `Any`, and pretty-prints the domain
+/// type it wraps.
+pub fn decode_any(bytes: &[u8]) -> Result<String, ToolError> {
+    let any = Any::decode(bytes).map_err(|e| ToolError::decode("Any", e))?;
+
+    let decoded = match any.type_url.as_str() {
+        TENDERMINT_CLIENT_STATE_TYPE_URL => pretty::<TmClientState>("ClientState", any),
+        TENDERMINT_CONSENSUS_STATE_TYPE_URL => pretty::<TmConsensusState>("ConsensusState", any),
+        TENDERMINT_HEADER_TYPE_URL => pretty::<TmHeader>("Header", any),
+        TENDERMINT_MISBEHAVIOUR_TYPE_URL => pretty::<TmMisbehaviour>("Misbehaviour", any),
+        WASM_CLIENT_STATE_TYPE_URL => pretty::<WasmClientState>("ClientState", any),
+        WASM_CONSENSUS_STATE_TYPE_URL => pretty::<WasmConsensusState>("ConsensusState", any),
+        WASM_CLIENT_MESSAGE_TYPE_URL => pretty::<WasmClientMessage>("ClientMessage", any),
+        _ => Err(ToolError::UnsupportedTypeUrl {
+            type_url: any.type_url,
+        }),
+    }?;
+
+    Ok(decoded)
+}
+
+fn pretty<T>(type_name: &'static str, any: Any) -> Result<String, ToolError>
+where
+    T: TryFrom<Any> + Debug,
+    T::Error: core::fmt::Display,
+{
+    let type_url = any.type_url.clone();
+    let decoded = T::try_from(any).map_err(|e| ToolError::decode(type_name, e))?;
+
+    Ok(format!("{type_url}\n{decoded:#?}"))
+}
@@ -0,0 +1,28 @@
+//! Decodes the bytes given on the command line, and encodes the bytes the
+//! commands print.
+
+use subtle_encoding::{base64, hex};
+
+use crate::error::ToolError;
+
+const BASE64_PREFIX: &str = "base64:";
+
+/// Decodes the bytes, hex-encoded with an optional `0x` prefix, or
+/// base64-encoded if prefixed with `base64:`.
+pub fn decode_bytes(input: &str) -> Result<Vec<u8>, ToolError> {
+    let input = input.trim();
+
+    let bytes = match input.strip_prefix(BASE64_PREFIX) {
+        Some(encoded) => base64::decode(encoded),
+        None => hex::decode(input.strip_prefix("0x").unwrap_or(input)),
+    };
+
+    bytes.map_err(|_| ToolError::InvalidEncoding {
+        input: input.to_string(),
+    })
+}
+
+/// Encodes the bytes as lower-case hex.
+pub fn encode_hex(bytes: impl AsRef<[u8]>) -> String {
+    String::from_utf8(hex::encode(bytes)).expect("hex is always valid UTF-8")
+}
@@ -0,0 +1,68 @@
+use displaydoc::Display;
+use ibc::core::channel::types::error::PacketError;
+use ibc::core::commitment_types::error::CommitmentError;
+use ibc::core::host::types::path::PathError;
+
+#[derive(Debug, Display)]
+pub enum ToolError {
+    /// invalid usage: {reason}
+    Usage { reason: String },
+    /// invalid encoding of `{input}`: expected hex, or base64 prefixed with `base64:`
+    InvalidEncoding { input: String },
+    /// failed to decode `{type_name}`: {description}
+    Decode {
+        type_name: &'static str,
+        description: String,
+    },
+    /// unsupported type url `{type_url}`
+    UnsupportedTypeUrl { type_url: String },
+    /// invalid path: {0}
+    Path(PathError),
+    /// commitment error: {0}
+    Commitment(CommitmentError),
+    /// packet error: {0}
+    Packet(PacketError),
+    /// failed to read the input: {0}
+    Io(std::io::Error),
+}
+
+impl ToolError {
+    pub fn usage(reason: impl Into<String>) -> Self {
+        Self::Usage {
+            reason: reason.into(),
+        }
+    }
+
+    pub fn decode(type_name: &'static str, error: impl core::fmt::Display) -> Self {
+        Self::Decode {
+            type_name,
+            description: error.to_string(),
+        }
+    }
+}
+
+impl From<PathError> for ToolError {
+    fn from(e: PathError) -> Self {
+        Self::Path(e)
+    }
+}
+
+impl From<CommitmentError> for ToolError {
+    fn from(e: CommitmentError) -> Self {
+        Self::Commitment(e)
+    }
+}
+
+impl From<PacketError> for ToolError {
+    fn from(e: PacketError) -> Self {
+        Self::Packet(e)
+    }
+}
+
+impl From<std::io::Error> for ToolError {
+    fn from(e: std::io::Error) -> Self {
+        Self::Io(e)
+    }
+}
+
+impl std::error::Error for ToolError {}
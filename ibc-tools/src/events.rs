@@ -0,0 +1,47 @@
+//! Pretty-prints the ABCI events emitted by the IBC handlers.
+
+use std::fmt::Write;
+
+use serde_json::Value;
+use subtle_encoding::hex;
+use tendermint::abci;
+
+use crate::error::ToolError;
+
+/// Pretty-prints the events of the JSON input, which is either one event or
+/// an array of them, as returned by the block results of the CometBFT RPC.
+pub fn print_events(input: &str) -> Result<String, ToolError> {
+    let value: Value = serde_json::from_str(input).map_err(|e| ToolError::decode("events", e))?;
+
+    let events: Vec<abci::Event> = match value {
+        Value::Array(_) => serde_json::from_value(value),
+        _ => serde_json::from_value(value).map(|event| vec![event]),
+    }
+    .map_err(|e| ToolError::decode("events", e))?;
+
+    let mut output = String::new();
+
+    for event in events {
+        writeln!(output, "{}", event.kind).expect("writing to a string never fails");
+
+        for attribute in event.attributes {
+            write!(output, "  {}: {}", attribute.key, attribute.value)
+                .expect("writing to a string never fails");
+
+            // The packet data and acknowledgements are also emitted as hex,
+            // which is shown decoded whenever it is valid UTF-8.
+            if attribute.key.ends_with("_hex") {
+                if let Some(decoded) = hex::decode(&attribute.value)
+                    .ok()
+                    .and_then(|bytes| String::from_utf8(bytes).ok())
+                {
+                    write!(output, " ({decoded})").expect("writing to a string never fails");
+                }
+            }
+
+            output.push('\n');
+        }
+    }
+
+    Ok(output.trim_end().to_string())
+}
@@ -0,0 +1,67 @@
+//! A command-line tool to decode, verify and inspect the IBC artifacts, e.g.
+//! client states, packets, events, proofs and commitments, for debugging IBC
+//! deployments. Run `ibc-tools help` for its usage.
+#![forbid(unsafe_code)]
+#![deny(
+    warnings,
+    trivial_numeric_casts,
+    unused_import_braces,
+    unused_qualifications,
+    rust_2018_idioms
+)]
+
+use std::io::Read;
+use std::process::ExitCode;
+
+mod cli;
+mod decode;
+mod encoding;
+mod error;
+mod events;
+mod packet;
+mod proof;
+
+use cli::{Command, USAGE};
+use error::ToolError;
+
+fn main() -> ExitCode {
+    match Command::parse(std::env::args().skip(1)).and_then(run) {
+        Ok(output) => {
+            println!("{output}");
+            ExitCode::SUCCESS
+        }
+        Err(e @ ToolError::Usage { .. }) => {
+            eprintln!("error: {e}\n\n{USAGE}");
+            ExitCode::from(2)
+        }
+        Err(e) => {
+            eprintln!("error: {e}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run(command: Command) -> Result<String, ToolError> {
+    match command {
+        Command::Decode { any } => decode::decode_any(&any),
+        Command::Packet { packet } => packet::print_packet(&packet::decode_packet(&packet)?),
+        Command::Events { file } => {
+            let input = match file {
+                Some(file) => std::fs::read_to_string(file)?,
+                None => {
+                    let mut input = String::new();
+                    std::io::stdin().read_to_string(&mut input)?;
+                    input
+                }
+            };
+
+            events::print_events(&input)
+        }
+        Command::VerifyProof(args) => proof::verify_proof(args),
+        Command::PacketCommitment { packet } => {
+            Ok(packet::packet_commitment(&packet::decode_packet(&packet)?))
+        }
+        Command::AckCommitment { ack } => packet::ack_commitment(ack),
+        Command::Help => Ok(USAGE.to_string()),
+    }
+}
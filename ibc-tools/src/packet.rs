@@ -0,0 +1,51 @@
+//! Pretty-prints packets, and computes the commitments of packets and
+//! acknowledgements.
+
+use ibc::core::channel::types::acknowledgement::Acknowledgement;
+use ibc::core::channel::types::commitment::{compute_ack_commitment, compute_packet_commitment};
+use ibc::core::channel::types::packet::Packet;
+use ibc::core::channel::types::proto::v1::Packet as RawPacket;
+use ibc::primitives::proto::Protobuf;
+
+use crate::encoding::encode_hex;
+use crate::error::ToolError;
+
+/// Decodes the protobuf encoding of a packet.
+pub fn decode_packet(bytes: &[u8]) -> Result<Packet, ToolError> {
+    Protobuf::<RawPacket>::decode_vec(bytes).map_err(|e| ToolError::decode("Packet", e))
+}
+
+/// Pretty-prints the packet as JSON, along with its data as UTF-8 if it is
+/// valid UTF-8, e.g. the JSON data of the ICS-20 packets, and its commitment.
+pub fn print_packet(packet: &Packet) -> Result<String, ToolError> {
+    let mut output =
+        serde_json::to_string_pretty(packet).map_err(|e| ToolError::decode("Packet", e))?;
+
+    if let Ok(data) = core::str::from_utf8(&packet.data) {
+        output.push_str(&format!("\ndata (utf-8): {data}"));
+    }
+    output.push_str(&format!("\ncommitment: {}", packet_commitment(packet)));
+
+    Ok(output)
+}
+
+/// Returns the hex-encoded commitment of the packet, as stored by its sending
+/// chain.
+pub fn packet_commitment(packet: &Packet) -> String {
+    encode_hex(
+        compute_packet_commitment(
+            &packet.data,
+            &packet.timeout_height_on_b,
+            &packet.timeout_timestamp_on_b,
+        )
+        .into_vec(),
+    )
+}
+
+/// Returns the hex-encoded commitment of the acknowledgement, as stored by the
+/// receiving chain of its packet.
+pub fn ack_commitment(ack: Vec<u8>) -> Result<String, ToolError> {
+    let ack = Acknowledgement::try_from(ack)?;
+
+    Ok(encode_hex(compute_ack_commitment(&ack).into_vec()))
+}
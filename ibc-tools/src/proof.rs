@@ -0,0 +1,86 @@
+//! Verifies the Merkle proofs of the states committed by the IBC hosts.
+
+use core::str::FromStr;
+
+use ibc::core::commitment_types::commitment::CommitmentPrefix;
+use ibc::core::commitment_types::merkle::{apply_prefix, MerkleProof};
+use ibc::core::commitment_types::proto::ics23;
+use ibc::core::commitment_types::proto::v1::{
+    MerklePath, MerkleProof as RawMerkleProof, MerkleRoot,
+};
+use ibc::core::commitment_types::specs::ProofSpecs;
+use ibc::core::host::types::path::Path;
+use ibc::primitives::proto::Protobuf;
+
+use crate::error::ToolError;
+
+/// The proof specs of the store the proof is verified against.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Specs {
+    /// An IAVL store within a Tendermint multistore, as on Cosmos SDK chains
+    #[default]
+    Cosmos,
+    Iavl,
+    Tendermint,
+}
+
+impl Specs {
+    fn proof_specs(self) -> ProofSpecs {
+        match self {
+            Self::Cosmos => ProofSpecs::cosmos(),
+            Self::Iavl => vec![ics23::iavl_spec()].into(),
+            Self::Tendermint => vec![ics23::tendermint_spec()].into(),
+        }
+    }
+}
+
+impl FromStr for Specs {
+    type Err = ToolError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "cosmos" => Ok(Self::Cosmos),
+            "iavl" => Ok(Self::Iavl),
+            "tendermint" => Ok(Self::Tendermint),
+            _ => Err(ToolError::usage(format!(
+                "unknown proof specs `{s}`, expected `cosmos`, `iavl` or `tendermint`"
+            ))),
+        }
+    }
+}
+
+/// The arguments of the verification of a proof.
+#[derive(Clone, Debug)]
+pub struct ProofArgs {
+    pub root: Vec<u8>,
+    pub proof: Vec<u8>,
+    pub path: String,
+    /// The value proven at the path, or `None` to verify its absence
+    pub value: Option<Vec<u8>>,
+    pub prefix: String,
+    pub specs: Specs,
+}
+
+/// Verifies the protobuf-encoded `MerkleProof` of the value at the ICS-24
+/// path under the store prefix, or of its absence if no value is given.
+pub fn verify_proof(args: ProofArgs) -> Result<String, ToolError> {
+    let proof: MerkleProof = Protobuf::<RawMerkleProof>::decode_vec(&args.proof)
+        .map_err(|e| ToolError::decode("MerkleProof", e))?;
+    let path = Path::from_str(&args.path)?;
+    let prefix = CommitmentPrefix::try_from(args.prefix.into_bytes())?;
+
+    let merkle_path: MerklePath = apply_prefix(&prefix, vec![path.to_string()]);
+    let root = MerkleRoot { hash: args.root };
+    let specs = args.specs.proof_specs();
+
+    match args.value {
+        Some(value) => {
+            proof.verify_membership(&specs, root, merkle_path, value, 0)?;
+            Ok(format!("proof of the value at `{path}` is valid"))
+        }
+        None => {
+            proof.verify_non_membership(&specs, root, merkle_path)?;
+            Ok(format!("proof of the absence of `{path}` is valid"))
+        }
+    }
+}
@@ -26,9 +26,19 @@
 //! for specific consensus algorithms. A chain uses these verification
 //! algorithms to verify the state of remote chains.
 //!
-//! + [Applications](apps) consists of implementations of some IBC applications.
-//! This is the part of the protocol that abstracts away the core protocol and
-//! focuses solely on business logic.
+//! + Applications (`apps`) consists of implementations of some IBC
+//! applications. This is the part of the protocol that abstracts away the core
+//! protocol and focuses solely on business logic.
+//!
+//! The applications, the Cosmos SDK host utilities and the light clients other
+//! than Tendermint are enabled by the default `full` feature. Disabling the
+//! default features and enabling `minimal` instead builds the core
+//! verification subset only, i.e. the client update and proof verification and
+//! the packet validation, with `no_std`, e.g. for zkVMs or embedded targets:
+//!
+//! ```toml
+//! ibc = { version = "0.50.0", default-features = false, features = ["minimal"] }
+//! ```
 //!
 //! [ibc-standard]: https://github.com/cosmos/ibc
 //! [ibc-rs]: https://github.com/cosmos/ibc-rs
@@ -54,12 +64,14 @@ pub mod clients {
 }
 
 /// Re-exports implementations of various IBC applications.
+#[cfg(feature = "full")]
 pub mod apps {
     #[doc(inline)]
     pub use ibc_apps::*;
 }
 
 /// Re-exports Cosmos-specific utility types, traits, and implementations.
+#[cfg(feature = "full")]
 pub mod cosmos_host {
     pub use ibc_core_host_cosmos::*;
 }